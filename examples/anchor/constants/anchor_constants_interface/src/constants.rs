@@ -0,0 +1,10 @@
+use solana_program::pubkey::Pubkey;
+pub const VAULT_SEED: &[u8] = b"vault";
+pub const CONFIG_SEED: &[u8] = &[99, 111, 110, 102, 105, 103];
+pub const PROTOCOL_NAME: &str = "anchor-constants";
+pub const MAX_DEPOSITS: u8 = 16;
+pub const MIN_DEPOSIT_LAMPORTS: u64 = 10 * 1_000_000;
+pub const FEE_OFFSET: i64 = -5;
+pub const PAUSED_BY_DEFAULT: bool = false;
+pub const ADMIN: Pubkey = solana_program::pubkey!("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
+pub const VERSION_BYTES: [u8; 4] = [0, 1, 0, 0];
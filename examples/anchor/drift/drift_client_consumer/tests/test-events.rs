@@ -0,0 +1,171 @@
+use base64::Engine;
+use drift_interface::{
+    parse_cpi_events, parse_events_from_logs, parse_events_from_logs_with_program_id, DriftEvent,
+    NewUserRecord, NewUserRecordEvent, EVENT_IX_TAG_LE,
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+
+const OTHER_PROGRAM: Pubkey = Pubkey::new_from_array([1; 32]);
+
+fn new_user_record(ts: i64) -> NewUserRecord {
+    NewUserRecord {
+        ts,
+        user_authority: Pubkey::new_unique(),
+        user: Pubkey::new_unique(),
+        sub_account_id: 0,
+        name: [b' '; 32],
+        referrer: Pubkey::default(),
+    }
+}
+
+fn program_data_log(record: &NewUserRecord) -> String {
+    let data = borsh::to_vec(&NewUserRecordEvent(record.clone())).unwrap();
+    format!(
+        "Program data: {}",
+        base64::engine::general_purpose::STANDARD.encode(data)
+    )
+}
+
+fn invoke_log(program: &Pubkey, depth: usize) -> String {
+    format!("Program {} invoke [{}]", program, depth)
+}
+
+fn success_log(program: &Pubkey) -> String {
+    format!("Program {} success", program)
+}
+
+#[test]
+fn test_parse_events_from_logs() {
+    let record = new_user_record(1);
+    let logs = [
+        invoke_log(&drift_interface::ID, 1),
+        "Program log: Instruction: InitializeUser".to_owned(),
+        program_data_log(&record),
+        format!(
+            "Program {} consumed 1000 of 200000 compute units",
+            drift_interface::ID
+        ),
+        success_log(&drift_interface::ID),
+    ];
+    assert_eq!(
+        parse_events_from_logs(&logs),
+        vec![DriftEvent::NewUserRecord(record)]
+    );
+}
+
+#[test]
+fn test_parse_events_from_logs_nested_cpi() {
+    let outer = new_user_record(1);
+    let inner = new_user_record(2);
+    let after_inner = new_user_record(3);
+    let logs = [
+        invoke_log(&OTHER_PROGRAM, 1),
+        // emitted by the other program, not drift
+        program_data_log(&outer),
+        invoke_log(&drift_interface::ID, 2),
+        program_data_log(&inner),
+        invoke_log(&OTHER_PROGRAM, 3),
+        program_data_log(&outer),
+        success_log(&OTHER_PROGRAM),
+        program_data_log(&after_inner),
+        success_log(&drift_interface::ID),
+        program_data_log(&outer),
+        success_log(&OTHER_PROGRAM),
+    ];
+    assert_eq!(
+        parse_events_from_logs(&logs),
+        vec![
+            DriftEvent::NewUserRecord(inner),
+            DriftEvent::NewUserRecord(after_inner)
+        ]
+    );
+}
+
+#[test]
+fn test_parse_events_from_logs_failed_frame() {
+    let record = new_user_record(1);
+    let logs = [
+        invoke_log(&drift_interface::ID, 1),
+        invoke_log(&OTHER_PROGRAM, 2),
+        format!(
+            "Program {} failed: custom program error: 0x1",
+            OTHER_PROGRAM
+        ),
+        program_data_log(&record),
+        success_log(&drift_interface::ID),
+    ];
+    assert_eq!(
+        parse_events_from_logs(&logs),
+        vec![DriftEvent::NewUserRecord(record)]
+    );
+}
+
+#[test]
+fn test_parse_events_from_logs_other_program() {
+    let record = new_user_record(1);
+    let logs = [
+        invoke_log(&OTHER_PROGRAM, 1),
+        program_data_log(&record),
+        success_log(&OTHER_PROGRAM),
+    ];
+    assert!(parse_events_from_logs(&logs).is_empty());
+    assert_eq!(
+        parse_events_from_logs_with_program_id(OTHER_PROGRAM, &logs),
+        vec![DriftEvent::NewUserRecord(record)]
+    );
+}
+
+#[test]
+fn test_parse_events_from_logs_skips_invalid_data() {
+    let record = new_user_record(1);
+    let logs = [
+        invoke_log(&drift_interface::ID, 1),
+        "Program data: not base64!".to_owned(),
+        // valid base64 but not an event
+        format!(
+            "Program data: {}",
+            base64::engine::general_purpose::STANDARD.encode([0u8; 16])
+        ),
+        program_data_log(&record),
+        success_log(&drift_interface::ID),
+    ];
+    assert_eq!(
+        parse_events_from_logs(&logs),
+        vec![DriftEvent::NewUserRecord(record)]
+    );
+}
+
+/// Instruction data of an `emit_cpi!()` self-invocation
+fn cpi_ix_data(record: &NewUserRecord) -> Vec<u8> {
+    let mut data = EVENT_IX_TAG_LE.to_vec();
+    data.extend(borsh::to_vec(&NewUserRecordEvent(record.clone())).unwrap());
+    data
+}
+
+#[test]
+fn test_from_cpi_ix_data() {
+    let record = new_user_record(1);
+    let data = cpi_ix_data(&record);
+    assert_eq!(
+        DriftEvent::from_cpi_ix_data(&data).unwrap(),
+        DriftEvent::NewUserRecord(record)
+    );
+    // event data without the tag
+    assert!(DriftEvent::from_cpi_ix_data(&data[8..]).is_err());
+}
+
+#[test]
+fn test_parse_cpi_events() {
+    let record = new_user_record(1);
+    let inner_ixs = [
+        Instruction::new_with_bytes(drift_interface::ID, &cpi_ix_data(&record), vec![]),
+        // not a self-invocation
+        Instruction::new_with_bytes(OTHER_PROGRAM, &cpi_ix_data(&record), vec![]),
+        // a regular drift instruction
+        Instruction::new_with_bytes(drift_interface::ID, &[0; 16], vec![]),
+    ];
+    assert_eq!(
+        parse_cpi_events(&inner_ixs),
+        vec![DriftEvent::NewUserRecord(record)]
+    );
+}
@@ -0,0 +1,69 @@
+use drift_interface::*;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+#[test]
+fn test_keys_from_slice_with_remaining() {
+    let pubkeys: Vec<Pubkey> = (0..PLACE_PERP_ORDER_IX_ACCOUNTS_LEN + 2)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    let (keys, remaining) = PlacePerpOrderKeys::from_slice_with_remaining(&pubkeys).unwrap();
+    assert_eq!(
+        keys,
+        PlacePerpOrderKeys {
+            state: pubkeys[0],
+            user: pubkeys[1],
+            authority: pubkeys[2],
+        }
+    );
+    assert_eq!(remaining, &pubkeys[PLACE_PERP_ORDER_IX_ACCOUNTS_LEN..]);
+    assert_eq!(
+        PlacePerpOrderKeys::try_from(pubkeys.as_slice()).unwrap(),
+        keys
+    );
+
+    let (_, remaining) =
+        PlacePerpOrderKeys::from_slice_with_remaining(&pubkeys[..PLACE_PERP_ORDER_IX_ACCOUNTS_LEN])
+            .unwrap();
+    assert!(remaining.is_empty());
+}
+
+#[test]
+fn test_keys_not_enough_accounts_err() {
+    let pubkeys = [Pubkey::new_unique(); PLACE_PERP_ORDER_IX_ACCOUNTS_LEN - 1];
+    assert_eq!(
+        PlacePerpOrderKeys::try_from(pubkeys.as_slice()),
+        Err(ProgramError::NotEnoughAccountKeys)
+    );
+}
+
+#[test]
+fn test_accounts_from_slice_with_remaining() {
+    let pubkeys: Vec<Pubkey> = (0..PLACE_PERP_ORDER_IX_ACCOUNTS_LEN + 1)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    let owner = drift_interface::ID;
+    let mut lamports = vec![0; pubkeys.len()];
+    let mut data = vec![vec![]; pubkeys.len()];
+    let account_infos: Vec<AccountInfo> = pubkeys
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .map(|((key, lamports), data)| {
+            AccountInfo::new(key, false, false, lamports, data, &owner, false, 0)
+        })
+        .collect();
+
+    let (accounts, remaining) =
+        PlacePerpOrderAccounts::from_slice_with_remaining(&account_infos).unwrap();
+    assert_eq!(
+        PlacePerpOrderKeys::from(accounts),
+        PlacePerpOrderKeys::try_from(pubkeys.as_slice()).unwrap()
+    );
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(*remaining[0].key, pubkeys[PLACE_PERP_ORDER_IX_ACCOUNTS_LEN]);
+
+    assert!(matches!(
+        PlacePerpOrderAccounts::try_from(&account_infos[..1]),
+        Err(ProgramError::NotEnoughAccountKeys)
+    ));
+}
@@ -0,0 +1,1890 @@
+use crate::*;
+use solana_account_decoder::UiAccountEncoding;
+use solana_program::pubkey::Pubkey;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Result as ClientResult;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::RpcFilterType,
+};
+use solana_sdk::{
+    message::Message, signature::Signature, signer::Signer, transaction::Transaction,
+};
+/// Fetches and deserializes the [`PhoenixV1FulfillmentConfigAccount`] at `pubkey`
+pub fn fetch_phoenix_v1_fulfillment_config(
+    client: &RpcClient,
+    pubkey: &Pubkey,
+) -> ClientResult<PhoenixV1FulfillmentConfigAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(PhoenixV1FulfillmentConfigAccount::deserialize(&data)?)
+}
+/// Fetches all [`PhoenixV1FulfillmentConfigAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_phoenix_v1_fulfillment_config(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, PhoenixV1FulfillmentConfigAccount)>> {
+    let filters = PhoenixV1FulfillmentConfigAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| {
+            Ok((
+                pubkey,
+                PhoenixV1FulfillmentConfigAccount::deserialize(&account.data)?,
+            ))
+        })
+        .collect()
+}
+/// Fetches and deserializes the [`SerumV3FulfillmentConfigAccount`] at `pubkey`
+pub fn fetch_serum_v3_fulfillment_config(
+    client: &RpcClient,
+    pubkey: &Pubkey,
+) -> ClientResult<SerumV3FulfillmentConfigAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(SerumV3FulfillmentConfigAccount::deserialize(&data)?)
+}
+/// Fetches all [`SerumV3FulfillmentConfigAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_serum_v3_fulfillment_config(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, SerumV3FulfillmentConfigAccount)>> {
+    let filters = SerumV3FulfillmentConfigAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| {
+            Ok((
+                pubkey,
+                SerumV3FulfillmentConfigAccount::deserialize(&account.data)?,
+            ))
+        })
+        .collect()
+}
+/// Fetches and deserializes the [`InsuranceFundStakeAccount`] at `pubkey`
+pub fn fetch_insurance_fund_stake(
+    client: &RpcClient,
+    pubkey: &Pubkey,
+) -> ClientResult<InsuranceFundStakeAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(InsuranceFundStakeAccount::deserialize(&data)?)
+}
+/// Fetches all [`InsuranceFundStakeAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_insurance_fund_stake(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, InsuranceFundStakeAccount)>> {
+    let filters = InsuranceFundStakeAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| {
+            Ok((
+                pubkey,
+                InsuranceFundStakeAccount::deserialize(&account.data)?,
+            ))
+        })
+        .collect()
+}
+/// Fetches and deserializes the [`PerpMarketAccount`] at `pubkey`
+pub fn fetch_perp_market(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<PerpMarketAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(PerpMarketAccount::deserialize(&data)?)
+}
+/// Fetches all [`PerpMarketAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_perp_market(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, PerpMarketAccount)>> {
+    let filters = PerpMarketAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, PerpMarketAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Fetches and deserializes the [`SpotMarketAccount`] at `pubkey`
+pub fn fetch_spot_market(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<SpotMarketAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(SpotMarketAccount::deserialize(&data)?)
+}
+/// Fetches all [`SpotMarketAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_spot_market(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, SpotMarketAccount)>> {
+    let filters = SpotMarketAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, SpotMarketAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Fetches and deserializes the [`StateAccount`] at `pubkey`
+pub fn fetch_state(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<StateAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(StateAccount::deserialize(&data)?)
+}
+/// Fetches all [`StateAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_state(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, StateAccount)>> {
+    let filters = StateAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, StateAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Fetches and deserializes the [`UserAccount`] at `pubkey`
+pub fn fetch_user(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<UserAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(UserAccount::deserialize(&data)?)
+}
+/// Fetches all [`UserAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_user(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, UserAccount)>> {
+    let filters = UserAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, UserAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Fetches and deserializes the [`UserStatsAccount`] at `pubkey`
+pub fn fetch_user_stats(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<UserStatsAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(UserStatsAccount::deserialize(&data)?)
+}
+/// Fetches all [`UserStatsAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_user_stats(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, UserStatsAccount)>> {
+    let filters = UserStatsAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, UserStatsAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Fetches and deserializes the [`ReferrerNameAccount`] at `pubkey`
+pub fn fetch_referrer_name(
+    client: &RpcClient,
+    pubkey: &Pubkey,
+) -> ClientResult<ReferrerNameAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(ReferrerNameAccount::deserialize(&data)?)
+}
+/// Fetches all [`ReferrerNameAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_referrer_name(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, ReferrerNameAccount)>> {
+    let filters = ReferrerNameAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, ReferrerNameAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Sends a transaction of [`initialize_user_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_initialize_user(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitializeUserKeys,
+    args: InitializeUserIxArgs,
+) -> ClientResult<Signature> {
+    let ix = initialize_user_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`initialize_user_stats_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_initialize_user_stats(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitializeUserStatsKeys,
+) -> ClientResult<Signature> {
+    let ix = initialize_user_stats_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`initialize_referrer_name_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_initialize_referrer_name(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitializeReferrerNameKeys,
+    args: InitializeReferrerNameIxArgs,
+) -> ClientResult<Signature> {
+    let ix = initialize_referrer_name_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`deposit_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_deposit(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: DepositKeys,
+    args: DepositIxArgs,
+) -> ClientResult<Signature> {
+    let ix = deposit_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`withdraw_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_withdraw(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: WithdrawKeys,
+    args: WithdrawIxArgs,
+) -> ClientResult<Signature> {
+    let ix = withdraw_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`transfer_deposit_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_transfer_deposit(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: TransferDepositKeys,
+    args: TransferDepositIxArgs,
+) -> ClientResult<Signature> {
+    let ix = transfer_deposit_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`place_perp_order_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_place_perp_order(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: PlacePerpOrderKeys,
+    args: PlacePerpOrderIxArgs,
+) -> ClientResult<Signature> {
+    let ix = place_perp_order_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`cancel_order_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_cancel_order(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: CancelOrderKeys,
+    args: CancelOrderIxArgs,
+) -> ClientResult<Signature> {
+    let ix = cancel_order_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`cancel_order_by_user_id_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_cancel_order_by_user_id(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: CancelOrderByUserIdKeys,
+    args: CancelOrderByUserIdIxArgs,
+) -> ClientResult<Signature> {
+    let ix = cancel_order_by_user_id_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`cancel_orders_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_cancel_orders(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: CancelOrdersKeys,
+    args: CancelOrdersIxArgs,
+) -> ClientResult<Signature> {
+    let ix = cancel_orders_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`modify_order_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_modify_order(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ModifyOrderKeys,
+    args: ModifyOrderIxArgs,
+) -> ClientResult<Signature> {
+    let ix = modify_order_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`modify_order_by_user_id_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_modify_order_by_user_id(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ModifyOrderByUserIdKeys,
+    args: ModifyOrderByUserIdIxArgs,
+) -> ClientResult<Signature> {
+    let ix = modify_order_by_user_id_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`place_and_take_perp_order_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_place_and_take_perp_order(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: PlaceAndTakePerpOrderKeys,
+    args: PlaceAndTakePerpOrderIxArgs,
+) -> ClientResult<Signature> {
+    let ix = place_and_take_perp_order_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`place_and_make_perp_order_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_place_and_make_perp_order(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: PlaceAndMakePerpOrderKeys,
+    args: PlaceAndMakePerpOrderIxArgs,
+) -> ClientResult<Signature> {
+    let ix = place_and_make_perp_order_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`place_spot_order_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_place_spot_order(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: PlaceSpotOrderKeys,
+    args: PlaceSpotOrderIxArgs,
+) -> ClientResult<Signature> {
+    let ix = place_spot_order_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`place_and_take_spot_order_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_place_and_take_spot_order(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: PlaceAndTakeSpotOrderKeys,
+    args: PlaceAndTakeSpotOrderIxArgs,
+) -> ClientResult<Signature> {
+    let ix = place_and_take_spot_order_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`place_and_make_spot_order_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_place_and_make_spot_order(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: PlaceAndMakeSpotOrderKeys,
+    args: PlaceAndMakeSpotOrderIxArgs,
+) -> ClientResult<Signature> {
+    let ix = place_and_make_spot_order_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`begin_swap_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_begin_swap(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: BeginSwapKeys,
+    args: BeginSwapIxArgs,
+) -> ClientResult<Signature> {
+    let ix = begin_swap_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`end_swap_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_end_swap(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: EndSwapKeys,
+    args: EndSwapIxArgs,
+) -> ClientResult<Signature> {
+    let ix = end_swap_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`add_perp_lp_shares_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_add_perp_lp_shares(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: AddPerpLpSharesKeys,
+    args: AddPerpLpSharesIxArgs,
+) -> ClientResult<Signature> {
+    let ix = add_perp_lp_shares_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`remove_perp_lp_shares_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_remove_perp_lp_shares(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: RemovePerpLpSharesKeys,
+    args: RemovePerpLpSharesIxArgs,
+) -> ClientResult<Signature> {
+    let ix = remove_perp_lp_shares_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`remove_perp_lp_shares_in_expiring_market_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_remove_perp_lp_shares_in_expiring_market(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: RemovePerpLpSharesInExpiringMarketKeys,
+    args: RemovePerpLpSharesInExpiringMarketIxArgs,
+) -> ClientResult<Signature> {
+    let ix = remove_perp_lp_shares_in_expiring_market_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_user_name_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_user_name(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateUserNameKeys,
+    args: UpdateUserNameIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_user_name_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_user_custom_margin_ratio_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_user_custom_margin_ratio(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateUserCustomMarginRatioKeys,
+    args: UpdateUserCustomMarginRatioIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_user_custom_margin_ratio_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_user_margin_trading_enabled_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_user_margin_trading_enabled(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateUserMarginTradingEnabledKeys,
+    args: UpdateUserMarginTradingEnabledIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_user_margin_trading_enabled_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_user_delegate_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_user_delegate(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateUserDelegateKeys,
+    args: UpdateUserDelegateIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_user_delegate_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`delete_user_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_delete_user(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: DeleteUserKeys,
+) -> ClientResult<Signature> {
+    let ix = delete_user_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`fill_perp_order_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_fill_perp_order(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: FillPerpOrderKeys,
+    args: FillPerpOrderIxArgs,
+) -> ClientResult<Signature> {
+    let ix = fill_perp_order_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`revert_fill_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_revert_fill(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: RevertFillKeys,
+) -> ClientResult<Signature> {
+    let ix = revert_fill_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`fill_spot_order_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_fill_spot_order(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: FillSpotOrderKeys,
+    args: FillSpotOrderIxArgs,
+) -> ClientResult<Signature> {
+    let ix = fill_spot_order_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`trigger_order_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_trigger_order(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: TriggerOrderKeys,
+    args: TriggerOrderIxArgs,
+) -> ClientResult<Signature> {
+    let ix = trigger_order_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`force_cancel_orders_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_force_cancel_orders(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ForceCancelOrdersKeys,
+) -> ClientResult<Signature> {
+    let ix = force_cancel_orders_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_user_idle_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_user_idle(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateUserIdleKeys,
+) -> ClientResult<Signature> {
+    let ix = update_user_idle_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_user_open_orders_count_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_user_open_orders_count(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateUserOpenOrdersCountKeys,
+) -> ClientResult<Signature> {
+    let ix = update_user_open_orders_count_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`settle_pnl_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_settle_pnl(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SettlePnlKeys,
+    args: SettlePnlIxArgs,
+) -> ClientResult<Signature> {
+    let ix = settle_pnl_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`settle_funding_payment_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_settle_funding_payment(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SettleFundingPaymentKeys,
+) -> ClientResult<Signature> {
+    let ix = settle_funding_payment_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`settle_lp_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_settle_lp(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SettleLpKeys,
+    args: SettleLpIxArgs,
+) -> ClientResult<Signature> {
+    let ix = settle_lp_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`settle_expired_market_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_settle_expired_market(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SettleExpiredMarketKeys,
+    args: SettleExpiredMarketIxArgs,
+) -> ClientResult<Signature> {
+    let ix = settle_expired_market_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`liquidate_perp_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_liquidate_perp(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: LiquidatePerpKeys,
+    args: LiquidatePerpIxArgs,
+) -> ClientResult<Signature> {
+    let ix = liquidate_perp_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`liquidate_spot_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_liquidate_spot(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: LiquidateSpotKeys,
+    args: LiquidateSpotIxArgs,
+) -> ClientResult<Signature> {
+    let ix = liquidate_spot_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`liquidate_borrow_for_perp_pnl_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_liquidate_borrow_for_perp_pnl(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: LiquidateBorrowForPerpPnlKeys,
+    args: LiquidateBorrowForPerpPnlIxArgs,
+) -> ClientResult<Signature> {
+    let ix = liquidate_borrow_for_perp_pnl_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`liquidate_perp_pnl_for_deposit_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_liquidate_perp_pnl_for_deposit(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: LiquidatePerpPnlForDepositKeys,
+    args: LiquidatePerpPnlForDepositIxArgs,
+) -> ClientResult<Signature> {
+    let ix = liquidate_perp_pnl_for_deposit_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`resolve_perp_pnl_deficit_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_resolve_perp_pnl_deficit(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ResolvePerpPnlDeficitKeys,
+    args: ResolvePerpPnlDeficitIxArgs,
+) -> ClientResult<Signature> {
+    let ix = resolve_perp_pnl_deficit_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`resolve_perp_bankruptcy_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_resolve_perp_bankruptcy(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ResolvePerpBankruptcyKeys,
+    args: ResolvePerpBankruptcyIxArgs,
+) -> ClientResult<Signature> {
+    let ix = resolve_perp_bankruptcy_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`resolve_spot_bankruptcy_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_resolve_spot_bankruptcy(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ResolveSpotBankruptcyKeys,
+    args: ResolveSpotBankruptcyIxArgs,
+) -> ClientResult<Signature> {
+    let ix = resolve_spot_bankruptcy_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`settle_revenue_to_insurance_fund_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_settle_revenue_to_insurance_fund(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SettleRevenueToInsuranceFundKeys,
+    args: SettleRevenueToInsuranceFundIxArgs,
+) -> ClientResult<Signature> {
+    let ix = settle_revenue_to_insurance_fund_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_funding_rate_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_funding_rate(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateFundingRateKeys,
+    args: UpdateFundingRateIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_funding_rate_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_cumulative_interest_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_cumulative_interest(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketCumulativeInterestKeys,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_cumulative_interest_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_amms_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_amms(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateAmmsKeys,
+    args: UpdateAmmsIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_amms_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_expiry_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_expiry(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketExpiryKeys,
+    args: UpdateSpotMarketExpiryIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_expiry_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_user_quote_asset_insurance_stake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_user_quote_asset_insurance_stake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateUserQuoteAssetInsuranceStakeKeys,
+) -> ClientResult<Signature> {
+    let ix = update_user_quote_asset_insurance_stake_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`initialize_insurance_fund_stake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_initialize_insurance_fund_stake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitializeInsuranceFundStakeKeys,
+    args: InitializeInsuranceFundStakeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = initialize_insurance_fund_stake_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`add_insurance_fund_stake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_add_insurance_fund_stake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: AddInsuranceFundStakeKeys,
+    args: AddInsuranceFundStakeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = add_insurance_fund_stake_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`request_remove_insurance_fund_stake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_request_remove_insurance_fund_stake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: RequestRemoveInsuranceFundStakeKeys,
+    args: RequestRemoveInsuranceFundStakeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = request_remove_insurance_fund_stake_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`cancel_request_remove_insurance_fund_stake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_cancel_request_remove_insurance_fund_stake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: CancelRequestRemoveInsuranceFundStakeKeys,
+    args: CancelRequestRemoveInsuranceFundStakeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = cancel_request_remove_insurance_fund_stake_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`remove_insurance_fund_stake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_remove_insurance_fund_stake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: RemoveInsuranceFundStakeKeys,
+    args: RemoveInsuranceFundStakeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = remove_insurance_fund_stake_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`initialize_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_initialize(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitializeKeys,
+) -> ClientResult<Signature> {
+    let ix = initialize_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`initialize_spot_market_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_initialize_spot_market(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitializeSpotMarketKeys,
+    args: InitializeSpotMarketIxArgs,
+) -> ClientResult<Signature> {
+    let ix = initialize_spot_market_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`initialize_serum_fulfillment_config_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_initialize_serum_fulfillment_config(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitializeSerumFulfillmentConfigKeys,
+    args: InitializeSerumFulfillmentConfigIxArgs,
+) -> ClientResult<Signature> {
+    let ix = initialize_serum_fulfillment_config_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_serum_fulfillment_config_status_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_serum_fulfillment_config_status(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSerumFulfillmentConfigStatusKeys,
+    args: UpdateSerumFulfillmentConfigStatusIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_serum_fulfillment_config_status_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`initialize_phoenix_fulfillment_config_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_initialize_phoenix_fulfillment_config(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitializePhoenixFulfillmentConfigKeys,
+    args: InitializePhoenixFulfillmentConfigIxArgs,
+) -> ClientResult<Signature> {
+    let ix = initialize_phoenix_fulfillment_config_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`phoenix_fulfillment_config_status_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_phoenix_fulfillment_config_status(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: PhoenixFulfillmentConfigStatusKeys,
+    args: PhoenixFulfillmentConfigStatusIxArgs,
+) -> ClientResult<Signature> {
+    let ix = phoenix_fulfillment_config_status_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_serum_vault_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_serum_vault(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSerumVaultKeys,
+) -> ClientResult<Signature> {
+    let ix = update_serum_vault_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`initialize_perp_market_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_initialize_perp_market(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitializePerpMarketKeys,
+    args: InitializePerpMarketIxArgs,
+) -> ClientResult<Signature> {
+    let ix = initialize_perp_market_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`delete_initialized_perp_market_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_delete_initialized_perp_market(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: DeleteInitializedPerpMarketKeys,
+    args: DeleteInitializedPerpMarketIxArgs,
+) -> ClientResult<Signature> {
+    let ix = delete_initialized_perp_market_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`move_amm_price_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_move_amm_price(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: MoveAmmPriceKeys,
+    args: MoveAmmPriceIxArgs,
+) -> ClientResult<Signature> {
+    let ix = move_amm_price_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_expiry_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_expiry(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketExpiryKeys,
+    args: UpdatePerpMarketExpiryIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_expiry_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`settle_expired_market_pools_to_revenue_pool_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_settle_expired_market_pools_to_revenue_pool(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SettleExpiredMarketPoolsToRevenuePoolKeys,
+) -> ClientResult<Signature> {
+    let ix = settle_expired_market_pools_to_revenue_pool_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`deposit_into_perp_market_fee_pool_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_deposit_into_perp_market_fee_pool(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: DepositIntoPerpMarketFeePoolKeys,
+    args: DepositIntoPerpMarketFeePoolIxArgs,
+) -> ClientResult<Signature> {
+    let ix = deposit_into_perp_market_fee_pool_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`repeg_amm_curve_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_repeg_amm_curve(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: RepegAmmCurveKeys,
+    args: RepegAmmCurveIxArgs,
+) -> ClientResult<Signature> {
+    let ix = repeg_amm_curve_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_amm_oracle_twap_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_amm_oracle_twap(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketAmmOracleTwapKeys,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_amm_oracle_twap_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`reset_perp_market_amm_oracle_twap_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_reset_perp_market_amm_oracle_twap(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ResetPerpMarketAmmOracleTwapKeys,
+) -> ClientResult<Signature> {
+    let ix = reset_perp_market_amm_oracle_twap_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_k_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_k(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateKKeys,
+    args: UpdateKIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_k_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_margin_ratio_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_margin_ratio(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketMarginRatioKeys,
+    args: UpdatePerpMarketMarginRatioIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_margin_ratio_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_max_imbalances_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_max_imbalances(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketMaxImbalancesKeys,
+    args: UpdatePerpMarketMaxImbalancesIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_max_imbalances_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_liquidation_fee_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_liquidation_fee(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketLiquidationFeeKeys,
+    args: UpdatePerpMarketLiquidationFeeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_liquidation_fee_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_insurance_fund_unstaking_period_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_insurance_fund_unstaking_period(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateInsuranceFundUnstakingPeriodKeys,
+    args: UpdateInsuranceFundUnstakingPeriodIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_insurance_fund_unstaking_period_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_liquidation_fee_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_liquidation_fee(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketLiquidationFeeKeys,
+    args: UpdateSpotMarketLiquidationFeeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_liquidation_fee_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_withdraw_guard_threshold_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_withdraw_guard_threshold(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateWithdrawGuardThresholdKeys,
+    args: UpdateWithdrawGuardThresholdIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_withdraw_guard_threshold_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_if_factor_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_if_factor(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketIfFactorKeys,
+    args: UpdateSpotMarketIfFactorIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_if_factor_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_revenue_settle_period_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_revenue_settle_period(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketRevenueSettlePeriodKeys,
+    args: UpdateSpotMarketRevenueSettlePeriodIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_revenue_settle_period_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_status_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_status(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketStatusKeys,
+    args: UpdateSpotMarketStatusIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_status_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_asset_tier_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_asset_tier(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketAssetTierKeys,
+    args: UpdateSpotMarketAssetTierIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_asset_tier_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_margin_weights_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_margin_weights(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketMarginWeightsKeys,
+    args: UpdateSpotMarketMarginWeightsIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_margin_weights_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_borrow_rate_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_borrow_rate(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketBorrowRateKeys,
+    args: UpdateSpotMarketBorrowRateIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_borrow_rate_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_max_token_deposits_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_max_token_deposits(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketMaxTokenDepositsKeys,
+    args: UpdateSpotMarketMaxTokenDepositsIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_max_token_deposits_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_oracle_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_oracle(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketOracleKeys,
+    args: UpdateSpotMarketOracleIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_oracle_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_step_size_and_tick_size_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_step_size_and_tick_size(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketStepSizeAndTickSizeKeys,
+    args: UpdateSpotMarketStepSizeAndTickSizeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_step_size_and_tick_size_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_min_order_size_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_min_order_size(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketMinOrderSizeKeys,
+    args: UpdateSpotMarketMinOrderSizeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_min_order_size_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_orders_enabled_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_orders_enabled(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketOrdersEnabledKeys,
+    args: UpdateSpotMarketOrdersEnabledIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_orders_enabled_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_market_name_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_market_name(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotMarketNameKeys,
+    args: UpdateSpotMarketNameIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_market_name_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_status_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_status(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketStatusKeys,
+    args: UpdatePerpMarketStatusIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_status_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_contract_tier_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_contract_tier(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketContractTierKeys,
+    args: UpdatePerpMarketContractTierIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_contract_tier_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_imf_factor_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_imf_factor(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketImfFactorKeys,
+    args: UpdatePerpMarketImfFactorIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_imf_factor_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_unrealized_asset_weight_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_unrealized_asset_weight(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketUnrealizedAssetWeightKeys,
+    args: UpdatePerpMarketUnrealizedAssetWeightIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_unrealized_asset_weight_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_concentration_coef_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_concentration_coef(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketConcentrationCoefKeys,
+    args: UpdatePerpMarketConcentrationCoefIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_concentration_coef_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_curve_update_intensity_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_curve_update_intensity(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketCurveUpdateIntensityKeys,
+    args: UpdatePerpMarketCurveUpdateIntensityIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_curve_update_intensity_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_target_base_asset_amount_per_lp_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_target_base_asset_amount_per_lp(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketTargetBaseAssetAmountPerLpKeys,
+    args: UpdatePerpMarketTargetBaseAssetAmountPerLpIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_target_base_asset_amount_per_lp_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_lp_cooldown_time_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_lp_cooldown_time(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateLpCooldownTimeKeys,
+    args: UpdateLpCooldownTimeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_lp_cooldown_time_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_fee_structure_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_fee_structure(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpFeeStructureKeys,
+    args: UpdatePerpFeeStructureIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_fee_structure_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_fee_structure_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_fee_structure(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotFeeStructureKeys,
+    args: UpdateSpotFeeStructureIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_fee_structure_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_initial_pct_to_liquidate_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_initial_pct_to_liquidate(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateInitialPctToLiquidateKeys,
+    args: UpdateInitialPctToLiquidateIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_initial_pct_to_liquidate_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_liquidation_duration_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_liquidation_duration(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateLiquidationDurationKeys,
+    args: UpdateLiquidationDurationIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_liquidation_duration_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_oracle_guard_rails_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_oracle_guard_rails(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateOracleGuardRailsKeys,
+    args: UpdateOracleGuardRailsIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_oracle_guard_rails_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_state_settlement_duration_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_state_settlement_duration(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateStateSettlementDurationKeys,
+    args: UpdateStateSettlementDurationIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_state_settlement_duration_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_oracle_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_oracle(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketOracleKeys,
+    args: UpdatePerpMarketOracleIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_oracle_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_base_spread_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_base_spread(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketBaseSpreadKeys,
+    args: UpdatePerpMarketBaseSpreadIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_base_spread_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_amm_jit_intensity_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_amm_jit_intensity(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateAmmJitIntensityKeys,
+    args: UpdateAmmJitIntensityIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_amm_jit_intensity_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_max_spread_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_max_spread(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketMaxSpreadKeys,
+    args: UpdatePerpMarketMaxSpreadIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_max_spread_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_step_size_and_tick_size_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_step_size_and_tick_size(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketStepSizeAndTickSizeKeys,
+    args: UpdatePerpMarketStepSizeAndTickSizeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_step_size_and_tick_size_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_name_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_name(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketNameKeys,
+    args: UpdatePerpMarketNameIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_name_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_min_order_size_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_min_order_size(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketMinOrderSizeKeys,
+    args: UpdatePerpMarketMinOrderSizeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_min_order_size_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_max_slippage_ratio_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_max_slippage_ratio(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketMaxSlippageRatioKeys,
+    args: UpdatePerpMarketMaxSlippageRatioIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_max_slippage_ratio_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_max_fill_reserve_fraction_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_max_fill_reserve_fraction(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketMaxFillReserveFractionKeys,
+    args: UpdatePerpMarketMaxFillReserveFractionIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_max_fill_reserve_fraction_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_market_max_open_interest_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_market_max_open_interest(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpMarketMaxOpenInterestKeys,
+    args: UpdatePerpMarketMaxOpenInterestIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_market_max_open_interest_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_admin_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_admin(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateAdminKeys,
+    args: UpdateAdminIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_admin_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_whitelist_mint_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_whitelist_mint(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateWhitelistMintKeys,
+    args: UpdateWhitelistMintIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_whitelist_mint_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_discount_mint_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_discount_mint(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateDiscountMintKeys,
+    args: UpdateDiscountMintIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_discount_mint_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_exchange_status_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_exchange_status(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateExchangeStatusKeys,
+    args: UpdateExchangeStatusIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_exchange_status_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_perp_auction_duration_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_perp_auction_duration(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdatePerpAuctionDurationKeys,
+    args: UpdatePerpAuctionDurationIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_perp_auction_duration_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_spot_auction_duration_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_spot_auction_duration(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateSpotAuctionDurationKeys,
+    args: UpdateSpotAuctionDurationIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_spot_auction_duration_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`admin_remove_insurance_fund_stake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_admin_remove_insurance_fund_stake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: AdminRemoveInsuranceFundStakeKeys,
+    args: AdminRemoveInsuranceFundStakeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = admin_remove_insurance_fund_stake_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
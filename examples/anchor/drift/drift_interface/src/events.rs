@@ -1,15 +1,17 @@
 use crate::*;
+use base64::Engine;
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
 pub const NEW_USER_RECORD_EVENT_DISCM: [u8; 8] = [236, 186, 113, 219, 42, 51, 149, 249];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NewUserRecord {
-    ts: i64,
-    user_authority: Pubkey,
-    user: Pubkey,
-    sub_account_id: u16,
-    name: [u8; 32],
-    referrer: Pubkey,
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub user: Pubkey,
+    pub sub_account_id: u16,
+    pub name: [u8; 32],
+    pub referrer: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct NewUserRecordEvent(pub NewUserRecord);
@@ -35,24 +37,25 @@ impl NewUserRecordEvent {
     }
 }
 pub const DEPOSIT_RECORD_EVENT_DISCM: [u8; 8] = [180, 241, 218, 207, 102, 135, 44, 134];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositRecord {
-    ts: i64,
-    user_authority: Pubkey,
-    user: Pubkey,
-    direction: DepositDirection,
-    deposit_record_id: u64,
-    amount: u64,
-    market_index: u16,
-    oracle_price: i64,
-    market_deposit_balance: u128,
-    market_withdraw_balance: u128,
-    market_cumulative_deposit_interest: u128,
-    market_cumulative_borrow_interest: u128,
-    total_deposits_after: u64,
-    total_withdraws_after: u64,
-    explanation: DepositExplanation,
-    transfer_user: Option<Pubkey>,
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub user: Pubkey,
+    pub direction: DepositDirection,
+    pub deposit_record_id: u64,
+    pub amount: u64,
+    pub market_index: u16,
+    pub oracle_price: i64,
+    pub market_deposit_balance: u128,
+    pub market_withdraw_balance: u128,
+    pub market_cumulative_deposit_interest: u128,
+    pub market_cumulative_borrow_interest: u128,
+    pub total_deposits_after: u64,
+    pub total_withdraws_after: u64,
+    pub explanation: DepositExplanation,
+    pub transfer_user: Option<Pubkey>,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct DepositRecordEvent(pub DepositRecord);
@@ -78,17 +81,18 @@ impl DepositRecordEvent {
     }
 }
 pub const SPOT_INTEREST_RECORD_EVENT_DISCM: [u8; 8] = [183, 186, 203, 186, 225, 187, 95, 130];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpotInterestRecord {
-    ts: i64,
-    market_index: u16,
-    deposit_balance: u128,
-    cumulative_deposit_interest: u128,
-    borrow_balance: u128,
-    cumulative_borrow_interest: u128,
-    optimal_utilization: u32,
-    optimal_borrow_rate: u32,
-    max_borrow_rate: u32,
+    pub ts: i64,
+    pub market_index: u16,
+    pub deposit_balance: u128,
+    pub cumulative_deposit_interest: u128,
+    pub borrow_balance: u128,
+    pub cumulative_borrow_interest: u128,
+    pub optimal_utilization: u32,
+    pub optimal_borrow_rate: u32,
+    pub max_borrow_rate: u32,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct SpotInterestRecordEvent(pub SpotInterestRecord);
@@ -114,17 +118,18 @@ impl SpotInterestRecordEvent {
     }
 }
 pub const FUNDING_PAYMENT_RECORD_EVENT_DISCM: [u8; 8] = [8, 59, 96, 20, 137, 201, 56, 95];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FundingPaymentRecord {
-    ts: i64,
-    user_authority: Pubkey,
-    user: Pubkey,
-    market_index: u16,
-    funding_payment: i64,
-    base_asset_amount: i64,
-    user_last_cumulative_funding: i64,
-    amm_cumulative_funding_long: i128,
-    amm_cumulative_funding_short: i128,
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub user: Pubkey,
+    pub market_index: u16,
+    pub funding_payment: i64,
+    pub base_asset_amount: i64,
+    pub user_last_cumulative_funding: i64,
+    pub amm_cumulative_funding_long: i128,
+    pub amm_cumulative_funding_short: i128,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct FundingPaymentRecordEvent(pub FundingPaymentRecord);
@@ -150,21 +155,22 @@ impl FundingPaymentRecordEvent {
     }
 }
 pub const FUNDING_RATE_RECORD_EVENT_DISCM: [u8; 8] = [68, 3, 255, 26, 133, 91, 147, 254];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FundingRateRecord {
-    ts: i64,
-    record_id: u64,
-    market_index: u16,
-    funding_rate: i64,
-    funding_rate_long: i128,
-    funding_rate_short: i128,
-    cumulative_funding_rate_long: i128,
-    cumulative_funding_rate_short: i128,
-    oracle_price_twap: i64,
-    mark_price_twap: u64,
-    period_revenue: i64,
-    base_asset_amount_with_amm: i128,
-    base_asset_amount_with_unsettled_lp: i128,
+    pub ts: i64,
+    pub record_id: u64,
+    pub market_index: u16,
+    pub funding_rate: i64,
+    pub funding_rate_long: i128,
+    pub funding_rate_short: i128,
+    pub cumulative_funding_rate_long: i128,
+    pub cumulative_funding_rate_short: i128,
+    pub oracle_price_twap: i64,
+    pub mark_price_twap: u64,
+    pub period_revenue: i64,
+    pub base_asset_amount_with_amm: i128,
+    pub base_asset_amount_with_unsettled_lp: i128,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct FundingRateRecordEvent(pub FundingRateRecord);
@@ -190,28 +196,29 @@ impl FundingRateRecordEvent {
     }
 }
 pub const CURVE_RECORD_EVENT_DISCM: [u8; 8] = [101, 238, 40, 228, 70, 46, 61, 117];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CurveRecord {
-    ts: i64,
-    record_id: u64,
-    peg_multiplier_before: u128,
-    base_asset_reserve_before: u128,
-    quote_asset_reserve_before: u128,
-    sqrt_k_before: u128,
-    peg_multiplier_after: u128,
-    base_asset_reserve_after: u128,
-    quote_asset_reserve_after: u128,
-    sqrt_k_after: u128,
-    base_asset_amount_long: u128,
-    base_asset_amount_short: u128,
-    base_asset_amount_with_amm: i128,
-    total_fee: i128,
-    total_fee_minus_distributions: i128,
-    adjustment_cost: i128,
-    oracle_price: i64,
-    fill_record: u128,
-    number_of_users: u32,
-    market_index: u16,
+    pub ts: i64,
+    pub record_id: u64,
+    pub peg_multiplier_before: u128,
+    pub base_asset_reserve_before: u128,
+    pub quote_asset_reserve_before: u128,
+    pub sqrt_k_before: u128,
+    pub peg_multiplier_after: u128,
+    pub base_asset_reserve_after: u128,
+    pub quote_asset_reserve_after: u128,
+    pub sqrt_k_after: u128,
+    pub base_asset_amount_long: u128,
+    pub base_asset_amount_short: u128,
+    pub base_asset_amount_with_amm: i128,
+    pub total_fee: i128,
+    pub total_fee_minus_distributions: i128,
+    pub adjustment_cost: i128,
+    pub oracle_price: i64,
+    pub fill_record: u128,
+    pub number_of_users: u32,
+    pub market_index: u16,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct CurveRecordEvent(pub CurveRecord);
@@ -237,11 +244,12 @@ impl CurveRecordEvent {
     }
 }
 pub const ORDER_RECORD_EVENT_DISCM: [u8; 8] = [104, 19, 64, 56, 89, 21, 2, 90];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderRecord {
-    ts: i64,
-    user: Pubkey,
-    order: Order,
+    pub ts: i64,
+    pub user: Pubkey,
+    pub order: Order,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct OrderRecordEvent(pub OrderRecord);
@@ -267,36 +275,37 @@ impl OrderRecordEvent {
     }
 }
 pub const ORDER_ACTION_RECORD_EVENT_DISCM: [u8; 8] = [224, 52, 67, 71, 194, 237, 109, 1];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderActionRecord {
-    ts: i64,
-    action: OrderAction,
-    action_explanation: OrderActionExplanation,
-    market_index: u16,
-    market_type: MarketType,
-    filler: Option<Pubkey>,
-    filler_reward: Option<u64>,
-    fill_record_id: Option<u64>,
-    base_asset_amount_filled: Option<u64>,
-    quote_asset_amount_filled: Option<u64>,
-    taker_fee: Option<u64>,
-    maker_fee: Option<i64>,
-    referrer_reward: Option<u32>,
-    quote_asset_amount_surplus: Option<i64>,
-    spot_fulfillment_method_fee: Option<u64>,
-    taker: Option<Pubkey>,
-    taker_order_id: Option<u32>,
-    taker_order_direction: Option<PositionDirection>,
-    taker_order_base_asset_amount: Option<u64>,
-    taker_order_cumulative_base_asset_amount_filled: Option<u64>,
-    taker_order_cumulative_quote_asset_amount_filled: Option<u64>,
-    maker: Option<Pubkey>,
-    maker_order_id: Option<u32>,
-    maker_order_direction: Option<PositionDirection>,
-    maker_order_base_asset_amount: Option<u64>,
-    maker_order_cumulative_base_asset_amount_filled: Option<u64>,
-    maker_order_cumulative_quote_asset_amount_filled: Option<u64>,
-    oracle_price: i64,
+    pub ts: i64,
+    pub action: OrderAction,
+    pub action_explanation: OrderActionExplanation,
+    pub market_index: u16,
+    pub market_type: MarketType,
+    pub filler: Option<Pubkey>,
+    pub filler_reward: Option<u64>,
+    pub fill_record_id: Option<u64>,
+    pub base_asset_amount_filled: Option<u64>,
+    pub quote_asset_amount_filled: Option<u64>,
+    pub taker_fee: Option<u64>,
+    pub maker_fee: Option<i64>,
+    pub referrer_reward: Option<u32>,
+    pub quote_asset_amount_surplus: Option<i64>,
+    pub spot_fulfillment_method_fee: Option<u64>,
+    pub taker: Option<Pubkey>,
+    pub taker_order_id: Option<u32>,
+    pub taker_order_direction: Option<PositionDirection>,
+    pub taker_order_base_asset_amount: Option<u64>,
+    pub taker_order_cumulative_base_asset_amount_filled: Option<u64>,
+    pub taker_order_cumulative_quote_asset_amount_filled: Option<u64>,
+    pub maker: Option<Pubkey>,
+    pub maker_order_id: Option<u32>,
+    pub maker_order_direction: Option<PositionDirection>,
+    pub maker_order_base_asset_amount: Option<u64>,
+    pub maker_order_cumulative_base_asset_amount_filled: Option<u64>,
+    pub maker_order_cumulative_quote_asset_amount_filled: Option<u64>,
+    pub oracle_price: i64,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct OrderActionRecordEvent(pub OrderActionRecord);
@@ -322,16 +331,17 @@ impl OrderActionRecordEvent {
     }
 }
 pub const LP_RECORD_EVENT_DISCM: [u8; 8] = [101, 22, 54, 38, 178, 13, 142, 111];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LpRecord {
-    ts: i64,
-    user: Pubkey,
-    action: LPAction,
-    n_shares: u64,
-    market_index: u16,
-    delta_base_asset_amount: i64,
-    delta_quote_asset_amount: i64,
-    pnl: i64,
+    pub ts: i64,
+    pub user: Pubkey,
+    pub action: LPAction,
+    pub n_shares: u64,
+    pub market_index: u16,
+    pub delta_base_asset_amount: i64,
+    pub delta_quote_asset_amount: i64,
+    pub pnl: i64,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct LpRecordEvent(pub LpRecord);
@@ -357,24 +367,25 @@ impl LpRecordEvent {
     }
 }
 pub const LIQUIDATION_RECORD_EVENT_DISCM: [u8; 8] = [127, 17, 0, 108, 182, 13, 231, 53];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidationRecord {
-    ts: i64,
-    liquidation_type: LiquidationType,
-    user: Pubkey,
-    liquidator: Pubkey,
-    margin_requirement: u128,
-    total_collateral: i128,
-    margin_freed: u64,
-    liquidation_id: u16,
-    bankrupt: bool,
-    canceled_order_ids: Vec<u32>,
-    liquidate_perp: LiquidatePerpRecord,
-    liquidate_spot: LiquidateSpotRecord,
-    liquidate_borrow_for_perp_pnl: LiquidateBorrowForPerpPnlRecord,
-    liquidate_perp_pnl_for_deposit: LiquidatePerpPnlForDepositRecord,
-    perp_bankruptcy: PerpBankruptcyRecord,
-    spot_bankruptcy: SpotBankruptcyRecord,
+    pub ts: i64,
+    pub liquidation_type: LiquidationType,
+    pub user: Pubkey,
+    pub liquidator: Pubkey,
+    pub margin_requirement: u128,
+    pub total_collateral: i128,
+    pub margin_freed: u64,
+    pub liquidation_id: u16,
+    pub bankrupt: bool,
+    pub canceled_order_ids: Vec<u32>,
+    pub liquidate_perp: LiquidatePerpRecord,
+    pub liquidate_spot: LiquidateSpotRecord,
+    pub liquidate_borrow_for_perp_pnl: LiquidateBorrowForPerpPnlRecord,
+    pub liquidate_perp_pnl_for_deposit: LiquidatePerpPnlForDepositRecord,
+    pub perp_bankruptcy: PerpBankruptcyRecord,
+    pub spot_bankruptcy: SpotBankruptcyRecord,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct LiquidationRecordEvent(pub LiquidationRecord);
@@ -400,17 +411,18 @@ impl LiquidationRecordEvent {
     }
 }
 pub const SETTLE_PNL_RECORD_EVENT_DISCM: [u8; 8] = [57, 68, 105, 26, 119, 198, 213, 89];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SettlePnlRecord {
-    ts: i64,
-    user: Pubkey,
-    market_index: u16,
-    pnl: i128,
-    base_asset_amount: i64,
-    quote_asset_amount_after: i64,
-    quote_entry_amount: i64,
-    settle_price: i64,
-    explanation: SettlePnlExplanation,
+    pub ts: i64,
+    pub user: Pubkey,
+    pub market_index: u16,
+    pub pnl: i128,
+    pub base_asset_amount: i64,
+    pub quote_asset_amount_after: i64,
+    pub quote_entry_amount: i64,
+    pub settle_price: i64,
+    pub explanation: SettlePnlExplanation,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct SettlePnlRecordEvent(pub SettlePnlRecord);
@@ -436,18 +448,19 @@ impl SettlePnlRecordEvent {
     }
 }
 pub const INSURANCE_FUND_RECORD_EVENT_DISCM: [u8; 8] = [56, 222, 215, 235, 78, 197, 99, 146];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsuranceFundRecord {
-    ts: i64,
-    spot_market_index: u16,
-    perp_market_index: u16,
-    user_if_factor: u32,
-    total_if_factor: u32,
-    vault_amount_before: u64,
-    insurance_vault_amount_before: u64,
-    total_if_shares_before: u128,
-    total_if_shares_after: u128,
-    amount: i64,
+    pub ts: i64,
+    pub spot_market_index: u16,
+    pub perp_market_index: u16,
+    pub user_if_factor: u32,
+    pub total_if_factor: u32,
+    pub vault_amount_before: u64,
+    pub insurance_vault_amount_before: u64,
+    pub total_if_shares_before: u128,
+    pub total_if_shares_after: u128,
+    pub amount: i64,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct InsuranceFundRecordEvent(pub InsuranceFundRecord);
@@ -473,20 +486,21 @@ impl InsuranceFundRecordEvent {
     }
 }
 pub const INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM: [u8; 8] = [68, 66, 156, 7, 216, 148, 250, 114];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsuranceFundStakeRecord {
-    ts: i64,
-    user_authority: Pubkey,
-    action: StakeAction,
-    amount: u64,
-    market_index: u16,
-    insurance_vault_amount_before: u64,
-    if_shares_before: u128,
-    user_if_shares_before: u128,
-    total_if_shares_before: u128,
-    if_shares_after: u128,
-    user_if_shares_after: u128,
-    total_if_shares_after: u128,
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub action: StakeAction,
+    pub amount: u64,
+    pub market_index: u16,
+    pub insurance_vault_amount_before: u64,
+    pub if_shares_before: u128,
+    pub user_if_shares_before: u128,
+    pub total_if_shares_before: u128,
+    pub if_shares_after: u128,
+    pub user_if_shares_after: u128,
+    pub total_if_shares_after: u128,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct InsuranceFundStakeRecordEvent(pub InsuranceFundStakeRecord);
@@ -512,17 +526,18 @@ impl InsuranceFundStakeRecordEvent {
     }
 }
 pub const SWAP_RECORD_EVENT_DISCM: [u8; 8] = [162, 187, 123, 194, 138, 56, 250, 241];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwapRecord {
-    ts: i64,
-    user: Pubkey,
-    amount_out: u64,
-    amount_in: u64,
-    out_market_index: u16,
-    in_market_index: u16,
-    out_oracle_price: i64,
-    in_oracle_price: i64,
-    fee: u64,
+    pub ts: i64,
+    pub user: Pubkey,
+    pub amount_out: u64,
+    pub amount_in: u64,
+    pub out_market_index: u16,
+    pub in_market_index: u16,
+    pub out_oracle_price: i64,
+    pub in_oracle_price: i64,
+    pub fee: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct SwapRecordEvent(pub SwapRecord);
@@ -547,3 +562,145 @@ impl SwapRecordEvent {
         Ok(Self(SwapRecord::deserialize(buf)?))
     }
 }
+#[derive(Clone, Debug, PartialEq)]
+pub enum DriftEvent {
+    NewUserRecord(NewUserRecord),
+    DepositRecord(DepositRecord),
+    SpotInterestRecord(SpotInterestRecord),
+    FundingPaymentRecord(FundingPaymentRecord),
+    FundingRateRecord(FundingRateRecord),
+    CurveRecord(CurveRecord),
+    OrderRecord(OrderRecord),
+    OrderActionRecord(OrderActionRecord),
+    LpRecord(LpRecord),
+    LiquidationRecord(LiquidationRecord),
+    SettlePnlRecord(SettlePnlRecord),
+    InsuranceFundRecord(InsuranceFundRecord),
+    InsuranceFundStakeRecord(InsuranceFundStakeRecord),
+    SwapRecord(SwapRecord),
+}
+impl DriftEvent {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let maybe_discm = <[u8; 8]>::deserialize(&mut reader)?;
+        match maybe_discm {
+            NEW_USER_RECORD_EVENT_DISCM => Ok(Self::NewUserRecord(NewUserRecord::deserialize(
+                &mut reader,
+            )?)),
+            DEPOSIT_RECORD_EVENT_DISCM => Ok(Self::DepositRecord(DepositRecord::deserialize(
+                &mut reader,
+            )?)),
+            SPOT_INTEREST_RECORD_EVENT_DISCM => Ok(Self::SpotInterestRecord(
+                SpotInterestRecord::deserialize(&mut reader)?,
+            )),
+            FUNDING_PAYMENT_RECORD_EVENT_DISCM => Ok(Self::FundingPaymentRecord(
+                FundingPaymentRecord::deserialize(&mut reader)?,
+            )),
+            FUNDING_RATE_RECORD_EVENT_DISCM => Ok(Self::FundingRateRecord(
+                FundingRateRecord::deserialize(&mut reader)?,
+            )),
+            CURVE_RECORD_EVENT_DISCM => {
+                Ok(Self::CurveRecord(CurveRecord::deserialize(&mut reader)?))
+            }
+            ORDER_RECORD_EVENT_DISCM => {
+                Ok(Self::OrderRecord(OrderRecord::deserialize(&mut reader)?))
+            }
+            ORDER_ACTION_RECORD_EVENT_DISCM => Ok(Self::OrderActionRecord(
+                OrderActionRecord::deserialize(&mut reader)?,
+            )),
+            LP_RECORD_EVENT_DISCM => Ok(Self::LpRecord(LpRecord::deserialize(&mut reader)?)),
+            LIQUIDATION_RECORD_EVENT_DISCM => Ok(Self::LiquidationRecord(
+                LiquidationRecord::deserialize(&mut reader)?,
+            )),
+            SETTLE_PNL_RECORD_EVENT_DISCM => Ok(Self::SettlePnlRecord(
+                SettlePnlRecord::deserialize(&mut reader)?,
+            )),
+            INSURANCE_FUND_RECORD_EVENT_DISCM => Ok(Self::InsuranceFundRecord(
+                InsuranceFundRecord::deserialize(&mut reader)?,
+            )),
+            INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM => Ok(Self::InsuranceFundStakeRecord(
+                InsuranceFundStakeRecord::deserialize(&mut reader)?,
+            )),
+            SWAP_RECORD_EVENT_DISCM => Ok(Self::SwapRecord(SwapRecord::deserialize(&mut reader)?)),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+}
+/// Parses the events emitted by the program from a transaction's log messages.
+///
+/// `Program data: ` logs are attributed to the program at the top of the invoke stack.
+/// Logs that are not a base64-encoded event of the program are skipped.
+pub fn parse_events_from_logs_with_program_id(
+    program_id: Pubkey,
+    logs: &[String],
+) -> Vec<DriftEvent> {
+    let mut invoke_stack: Vec<Pubkey> = Vec::new();
+    let mut events = Vec::new();
+    for log in logs {
+        if let Some(data) = log.strip_prefix("Program data: ") {
+            if invoke_stack.last() != Some(&program_id) {
+                continue;
+            }
+            let event = base64::engine::general_purpose::STANDARD
+                .decode(data)
+                .ok()
+                .and_then(|data| DriftEvent::deserialize(&data).ok());
+            events.extend(event);
+        } else if let Some(rest) = log.strip_prefix("Program ") {
+            let mut words = rest.split(' ');
+            let program = match words.next().map(|w| w.parse::<Pubkey>()) {
+                Some(Ok(program)) => program,
+                _ => continue,
+            };
+            match words.next() {
+                Some("invoke") => invoke_stack.push(program),
+                Some("success" | "failed:") => {
+                    invoke_stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+    events
+}
+pub fn parse_events_from_logs(logs: &[String]) -> Vec<DriftEvent> {
+    parse_events_from_logs_with_program_id(crate::ID, logs)
+}
+/// Prefix of the instruction data of the self-invocations `emit_cpi!()` emits events with
+pub const EVENT_IX_TAG_LE: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+impl DriftEvent {
+    /// Decodes the event from the data of an `emit_cpi!()` self-invocation
+    pub fn from_cpi_ix_data(data: &[u8]) -> std::io::Result<Self> {
+        let mut reader = data;
+        let maybe_tag = <[u8; 8]>::deserialize(&mut reader)?;
+        if maybe_tag != EVENT_IX_TAG_LE {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "event ix tag does not match. Expected: {:?}. Received: {:?}",
+                    EVENT_IX_TAG_LE, maybe_tag
+                ),
+            ));
+        }
+        Self::deserialize(reader)
+    }
+}
+/// Decodes the events emitted with `emit_cpi!()` from a transaction's inner instructions.
+///
+/// Instructions that are not `emit_cpi!()` self-invocations of the program are skipped.
+pub fn parse_cpi_events_with_program_id(
+    program_id: Pubkey,
+    inner_ixs: &[Instruction],
+) -> Vec<DriftEvent> {
+    inner_ixs
+        .iter()
+        .filter(|ix| ix.program_id == program_id)
+        .filter_map(|ix| DriftEvent::from_cpi_ix_data(&ix.data).ok())
+        .collect()
+}
+pub fn parse_cpi_events(inner_ixs: &[Instruction]) -> Vec<DriftEvent> {
+    parse_cpi_events_with_program_id(crate::ID, inner_ixs)
+}
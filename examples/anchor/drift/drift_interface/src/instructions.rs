@@ -1119,6 +1119,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_USER_IX_ACCOUNTS_LEN]
         }
     }
 }
+impl InitializeUserKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_USER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_USER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INITIALIZE_USER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeUserKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeUserAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_USER_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_USER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INITIALIZE_USER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeUserAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INITIALIZE_USER_IX_DISCM: [u8; 8] = [111, 17, 185, 250, 60, 122, 38, 254];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1178,6 +1216,112 @@ pub fn initialize_user_ix(
 ) -> std::io::Result<Instruction> {
     initialize_user_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`initialize_user_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeUserIxBuilder {
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    sub_account_id: Option<u16>,
+    name: Option<[u8; 32]>,
+}
+impl Default for InitializeUserIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeUserIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            user: None,
+            user_stats: None,
+            state: None,
+            authority: None,
+            payer: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            sub_account_id: None,
+            name: None,
+        }
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn sub_account_id(mut self, sub_account_id: u16) -> Self {
+        self.sub_account_id = Some(sub_account_id);
+        self
+    }
+    pub fn name(mut self, name: [u8; 32]) -> Self {
+        self.name = Some(name);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeUserIxBuilder is missing {}", field),
+            )
+        };
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = InitializeUserIxArgs {
+            sub_account_id: self
+                .sub_account_id
+                .ok_or_else(|| missing("sub_account_id"))?,
+            name: self.name.ok_or_else(|| missing("name"))?,
+        };
+        let keys = InitializeUserKeys {
+            user,
+            user_stats,
+            state,
+            authority,
+            payer,
+            rent,
+            system_program,
+        };
+        initialize_user_ix(keys, args)
+    }
+}
 pub fn initialize_user_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeUserAccounts<'_, '_>,
@@ -1368,6 +1512,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_USER_STATS_IX_ACCOUNT
         }
     }
 }
+impl InitializeUserStatsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_USER_STATS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_USER_STATS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[INITIALIZE_USER_STATS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeUserStatsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeUserStatsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_USER_STATS_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_USER_STATS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[INITIALIZE_USER_STATS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeUserStatsAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INITIALIZE_USER_STATS_IX_DISCM: [u8; 8] = [254, 243, 72, 98, 251, 130, 168, 213];
 #[derive(Clone, Debug, PartialEq)]
 pub struct InitializeUserStatsIxData;
@@ -1410,6 +1598,86 @@ pub fn initialize_user_stats_ix_with_program_id(
 pub fn initialize_user_stats_ix(keys: InitializeUserStatsKeys) -> std::io::Result<Instruction> {
     initialize_user_stats_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`initialize_user_stats_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeUserStatsIxBuilder {
+    user_stats: Option<Pubkey>,
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+}
+impl Default for InitializeUserStatsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeUserStatsIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            user_stats: None,
+            state: None,
+            authority: None,
+            payer: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+        }
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeUserStatsIxBuilder is missing {}", field),
+            )
+        };
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let keys = InitializeUserStatsKeys {
+            user_stats,
+            state,
+            authority,
+            payer,
+            rent,
+            system_program,
+        };
+        initialize_user_stats_ix(keys)
+    }
+}
 pub fn initialize_user_stats_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeUserStatsAccounts<'_, '_>,
@@ -1601,6 +1869,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_REFERRER_NAME_IX_ACCO
         }
     }
 }
+impl InitializeReferrerNameKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_REFERRER_NAME_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_REFERRER_NAME_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[INITIALIZE_REFERRER_NAME_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeReferrerNameKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeReferrerNameAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_REFERRER_NAME_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_REFERRER_NAME_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[INITIALIZE_REFERRER_NAME_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeReferrerNameAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INITIALIZE_REFERRER_NAME_IX_DISCM: [u8; 8] = [235, 126, 231, 10, 42, 164, 26, 61];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1661,6 +1973,103 @@ pub fn initialize_referrer_name_ix(
 ) -> std::io::Result<Instruction> {
     initialize_referrer_name_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`initialize_referrer_name_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeReferrerNameIxBuilder {
+    referrer_name: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    name: Option<[u8; 32]>,
+}
+impl Default for InitializeReferrerNameIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeReferrerNameIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            referrer_name: None,
+            user: None,
+            user_stats: None,
+            authority: None,
+            payer: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            name: None,
+        }
+    }
+    pub fn referrer_name(mut self, referrer_name: Pubkey) -> Self {
+        self.referrer_name = Some(referrer_name);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn name(mut self, name: [u8; 32]) -> Self {
+        self.name = Some(name);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeReferrerNameIxBuilder is missing {}", field),
+            )
+        };
+        let referrer_name = self.referrer_name.ok_or_else(|| missing("referrer_name"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = InitializeReferrerNameIxArgs {
+            name: self.name.ok_or_else(|| missing("name"))?,
+        };
+        let keys = InitializeReferrerNameKeys {
+            referrer_name,
+            user,
+            user_stats,
+            authority,
+            payer,
+            rent,
+            system_program,
+        };
+        initialize_referrer_name_ix(keys, args)
+    }
+}
 pub fn initialize_referrer_name_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeReferrerNameAccounts<'_, '_>,
@@ -1860,6 +2269,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl DepositKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPOSIT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEPOSIT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[DEPOSIT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for DepositKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DepositAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts
+            .get(..DEPOSIT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[DEPOSIT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for DepositAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEPOSIT_IX_DISCM: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1917,6 +2364,119 @@ pub fn deposit_ix_with_program_id(
 pub fn deposit_ix(keys: DepositKeys, args: DepositIxArgs) -> std::io::Result<Instruction> {
     deposit_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`deposit_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DepositIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    spot_market_vault: Option<Pubkey>,
+    user_token_account: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    market_index: Option<u16>,
+    amount: Option<u64>,
+    reduce_only: Option<bool>,
+}
+impl Default for DepositIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DepositIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            user_stats: None,
+            authority: None,
+            spot_market_vault: None,
+            user_token_account: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            market_index: None,
+            amount: None,
+            reduce_only: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn spot_market_vault(mut self, spot_market_vault: Pubkey) -> Self {
+        self.spot_market_vault = Some(spot_market_vault);
+        self
+    }
+    pub fn user_token_account(mut self, user_token_account: Pubkey) -> Self {
+        self.user_token_account = Some(user_token_account);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DepositIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let spot_market_vault = self
+            .spot_market_vault
+            .ok_or_else(|| missing("spot_market_vault"))?;
+        let user_token_account = self
+            .user_token_account
+            .ok_or_else(|| missing("user_token_account"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = DepositIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+            amount: self.amount.ok_or_else(|| missing("amount"))?,
+            reduce_only: self.reduce_only.ok_or_else(|| missing("reduce_only"))?,
+        };
+        let keys = DepositKeys {
+            state,
+            user,
+            user_stats,
+            authority,
+            spot_market_vault,
+            user_token_account,
+            token_program,
+        };
+        deposit_ix(keys, args)
+    }
+}
 pub fn deposit_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DepositAccounts<'_, '_>,
@@ -2124,6 +2684,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl WithdrawKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; WITHDRAW_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..WITHDRAW_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[WITHDRAW_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for WithdrawKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> WithdrawAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] = accounts
+            .get(..WITHDRAW_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[WITHDRAW_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for WithdrawAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const WITHDRAW_IX_DISCM: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2181,6 +2779,127 @@ pub fn withdraw_ix_with_program_id(
 pub fn withdraw_ix(keys: WithdrawKeys, args: WithdrawIxArgs) -> std::io::Result<Instruction> {
     withdraw_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`withdraw_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct WithdrawIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    spot_market_vault: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    user_token_account: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    market_index: Option<u16>,
+    amount: Option<u64>,
+    reduce_only: Option<bool>,
+}
+impl Default for WithdrawIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl WithdrawIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            user_stats: None,
+            authority: None,
+            spot_market_vault: None,
+            drift_signer: None,
+            user_token_account: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            market_index: None,
+            amount: None,
+            reduce_only: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn spot_market_vault(mut self, spot_market_vault: Pubkey) -> Self {
+        self.spot_market_vault = Some(spot_market_vault);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn user_token_account(mut self, user_token_account: Pubkey) -> Self {
+        self.user_token_account = Some(user_token_account);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("WithdrawIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let spot_market_vault = self
+            .spot_market_vault
+            .ok_or_else(|| missing("spot_market_vault"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let user_token_account = self
+            .user_token_account
+            .ok_or_else(|| missing("user_token_account"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = WithdrawIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+            amount: self.amount.ok_or_else(|| missing("amount"))?,
+            reduce_only: self.reduce_only.ok_or_else(|| missing("reduce_only"))?,
+        };
+        let keys = WithdrawKeys {
+            state,
+            user,
+            user_stats,
+            authority,
+            spot_market_vault,
+            drift_signer,
+            user_token_account,
+            token_program,
+        };
+        withdraw_ix(keys, args)
+    }
+}
 pub fn withdraw_invoke_with_program_id(
     program_id: Pubkey,
     accounts: WithdrawAccounts<'_, '_>,
@@ -2369,6 +3088,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN
         }
     }
 }
+impl TransferDepositKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for TransferDepositKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> TransferDepositAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN] = accounts
+            .get(..TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for TransferDepositAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const TRANSFER_DEPOSIT_IX_DISCM: [u8; 8] = [20, 20, 147, 223, 41, 63, 204, 111];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2428,6 +3185,100 @@ pub fn transfer_deposit_ix(
 ) -> std::io::Result<Instruction> {
     transfer_deposit_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`transfer_deposit_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct TransferDepositIxBuilder {
+    from_user: Option<Pubkey>,
+    to_user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market_vault: Option<Pubkey>,
+    market_index: Option<u16>,
+    amount: Option<u64>,
+}
+impl Default for TransferDepositIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl TransferDepositIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            from_user: None,
+            to_user: None,
+            user_stats: None,
+            authority: None,
+            state: None,
+            spot_market_vault: None,
+            market_index: None,
+            amount: None,
+        }
+    }
+    pub fn from_user(mut self, from_user: Pubkey) -> Self {
+        self.from_user = Some(from_user);
+        self
+    }
+    pub fn to_user(mut self, to_user: Pubkey) -> Self {
+        self.to_user = Some(to_user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market_vault(mut self, spot_market_vault: Pubkey) -> Self {
+        self.spot_market_vault = Some(spot_market_vault);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("TransferDepositIxBuilder is missing {}", field),
+            )
+        };
+        let from_user = self.from_user.ok_or_else(|| missing("from_user"))?;
+        let to_user = self.to_user.ok_or_else(|| missing("to_user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market_vault = self
+            .spot_market_vault
+            .ok_or_else(|| missing("spot_market_vault"))?;
+        let args = TransferDepositIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+            amount: self.amount.ok_or_else(|| missing("amount"))?,
+        };
+        let keys = TransferDepositKeys {
+            from_user,
+            to_user,
+            user_stats,
+            authority,
+            state,
+            spot_market_vault,
+        };
+        transfer_deposit_ix(keys, args)
+    }
+}
 pub fn transfer_deposit_invoke_with_program_id(
     program_id: Pubkey,
     accounts: TransferDepositAccounts<'_, '_>,
@@ -2579,6 +3430,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_PERP_ORDER_IX_ACCOUNTS_LEN
         }
     }
 }
+impl PlacePerpOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PLACE_PERP_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..PLACE_PERP_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[PLACE_PERP_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for PlacePerpOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PlacePerpOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; PLACE_PERP_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..PLACE_PERP_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[PLACE_PERP_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for PlacePerpOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PLACE_PERP_ORDER_IX_DISCM: [u8; 8] = [69, 161, 93, 202, 120, 126, 76, 185];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2637,6 +3526,67 @@ pub fn place_perp_order_ix(
 ) -> std::io::Result<Instruction> {
     place_perp_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`place_perp_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PlacePerpOrderIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    params: Option<OrderParams>,
+}
+impl Default for PlacePerpOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PlacePerpOrderIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            authority: None,
+            params: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn params(mut self, params: OrderParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("PlacePerpOrderIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = PlacePerpOrderIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let keys = PlacePerpOrderKeys {
+            state,
+            user,
+            authority,
+        };
+        place_perp_order_ix(keys, args)
+    }
+}
 pub fn place_perp_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlacePerpOrderAccounts<'_, '_>,
@@ -2785,6 +3735,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ORDER_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl CancelOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CANCEL_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CANCEL_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CANCEL_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for CancelOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CancelOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CANCEL_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..CANCEL_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CANCEL_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CancelOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CANCEL_ORDER_IX_DISCM: [u8; 8] = [95, 129, 237, 240, 8, 49, 223, 132];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2843,6 +3831,67 @@ pub fn cancel_order_ix(
 ) -> std::io::Result<Instruction> {
     cancel_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`cancel_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CancelOrderIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    order_id: Option<Option<u32>>,
+}
+impl Default for CancelOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CancelOrderIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            authority: None,
+            order_id: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn order_id(mut self, order_id: Option<u32>) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CancelOrderIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = CancelOrderIxArgs {
+            order_id: self.order_id.ok_or_else(|| missing("order_id"))?,
+        };
+        let keys = CancelOrderKeys {
+            state,
+            user,
+            authority,
+        };
+        cancel_order_ix(keys, args)
+    }
+}
 pub fn cancel_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelOrderAccounts<'_, '_>,
@@ -2991,6 +4040,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ORDER_BY_USER_ID_IX_ACCOU
         }
     }
 }
+impl CancelOrderByUserIdKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CANCEL_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CANCEL_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CANCEL_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for CancelOrderByUserIdKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CancelOrderByUserIdAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CANCEL_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN] = accounts
+            .get(..CANCEL_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CANCEL_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CancelOrderByUserIdAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CANCEL_ORDER_BY_USER_ID_IX_DISCM: [u8; 8] = [107, 211, 250, 133, 18, 37, 57, 100];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3049,6 +4142,67 @@ pub fn cancel_order_by_user_id_ix(
 ) -> std::io::Result<Instruction> {
     cancel_order_by_user_id_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`cancel_order_by_user_id_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CancelOrderByUserIdIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    user_order_id: Option<u8>,
+}
+impl Default for CancelOrderByUserIdIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CancelOrderByUserIdIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            authority: None,
+            user_order_id: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn user_order_id(mut self, user_order_id: u8) -> Self {
+        self.user_order_id = Some(user_order_id);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CancelOrderByUserIdIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = CancelOrderByUserIdIxArgs {
+            user_order_id: self.user_order_id.ok_or_else(|| missing("user_order_id"))?,
+        };
+        let keys = CancelOrderByUserIdKeys {
+            state,
+            user,
+            authority,
+        };
+        cancel_order_by_user_id_ix(keys, args)
+    }
+}
 pub fn cancel_order_by_user_id_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelOrderByUserIdAccounts<'_, '_>,
@@ -3197,6 +4351,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ORDERS_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl CancelOrdersKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CANCEL_ORDERS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CANCEL_ORDERS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CANCEL_ORDERS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for CancelOrdersKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CancelOrdersAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CANCEL_ORDERS_IX_ACCOUNTS_LEN] = accounts
+            .get(..CANCEL_ORDERS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CANCEL_ORDERS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CancelOrdersAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CANCEL_ORDERS_IX_DISCM: [u8; 8] = [238, 225, 95, 158, 227, 103, 8, 194];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3257,6 +4449,81 @@ pub fn cancel_orders_ix(
 ) -> std::io::Result<Instruction> {
     cancel_orders_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`cancel_orders_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CancelOrdersIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    market_type: Option<Option<MarketType>>,
+    market_index: Option<Option<u16>>,
+    direction: Option<Option<PositionDirection>>,
+}
+impl Default for CancelOrdersIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CancelOrdersIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            authority: None,
+            market_type: None,
+            market_index: None,
+            direction: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn market_type(mut self, market_type: Option<MarketType>) -> Self {
+        self.market_type = Some(market_type);
+        self
+    }
+    pub fn market_index(mut self, market_index: Option<u16>) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    pub fn direction(mut self, direction: Option<PositionDirection>) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CancelOrdersIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = CancelOrdersIxArgs {
+            market_type: self.market_type.ok_or_else(|| missing("market_type"))?,
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+            direction: self.direction.ok_or_else(|| missing("direction"))?,
+        };
+        let keys = CancelOrdersKeys {
+            state,
+            user,
+            authority,
+        };
+        cancel_orders_ix(keys, args)
+    }
+}
 pub fn cancel_orders_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelOrdersAccounts<'_, '_>,
@@ -3405,6 +4672,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; MODIFY_ORDER_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl ModifyOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; MODIFY_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..MODIFY_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[MODIFY_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for ModifyOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ModifyOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; MODIFY_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..MODIFY_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[MODIFY_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ModifyOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const MODIFY_ORDER_IX_DISCM: [u8; 8] = [47, 124, 117, 255, 201, 197, 130, 94];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3464,6 +4769,76 @@ pub fn modify_order_ix(
 ) -> std::io::Result<Instruction> {
     modify_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`modify_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ModifyOrderIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    order_id: Option<Option<u32>>,
+    modify_order_params: Option<ModifyOrderParams>,
+}
+impl Default for ModifyOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ModifyOrderIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            authority: None,
+            order_id: None,
+            modify_order_params: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn order_id(mut self, order_id: Option<u32>) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+    pub fn modify_order_params(mut self, modify_order_params: ModifyOrderParams) -> Self {
+        self.modify_order_params = Some(modify_order_params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ModifyOrderIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = ModifyOrderIxArgs {
+            order_id: self.order_id.ok_or_else(|| missing("order_id"))?,
+            modify_order_params: self
+                .modify_order_params
+                .ok_or_else(|| missing("modify_order_params"))?,
+        };
+        let keys = ModifyOrderKeys {
+            state,
+            user,
+            authority,
+        };
+        modify_order_ix(keys, args)
+    }
+}
 pub fn modify_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ModifyOrderAccounts<'_, '_>,
@@ -3612,6 +4987,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; MODIFY_ORDER_BY_USER_ID_IX_ACCOU
         }
     }
 }
+impl ModifyOrderByUserIdKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; MODIFY_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..MODIFY_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[MODIFY_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ModifyOrderByUserIdKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ModifyOrderByUserIdAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; MODIFY_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN] = accounts
+            .get(..MODIFY_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[MODIFY_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ModifyOrderByUserIdAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const MODIFY_ORDER_BY_USER_ID_IX_DISCM: [u8; 8] = [158, 77, 4, 253, 252, 194, 161, 179];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3671,6 +5090,76 @@ pub fn modify_order_by_user_id_ix(
 ) -> std::io::Result<Instruction> {
     modify_order_by_user_id_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`modify_order_by_user_id_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ModifyOrderByUserIdIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    user_order_id: Option<u8>,
+    modify_order_params: Option<ModifyOrderParams>,
+}
+impl Default for ModifyOrderByUserIdIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ModifyOrderByUserIdIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            authority: None,
+            user_order_id: None,
+            modify_order_params: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn user_order_id(mut self, user_order_id: u8) -> Self {
+        self.user_order_id = Some(user_order_id);
+        self
+    }
+    pub fn modify_order_params(mut self, modify_order_params: ModifyOrderParams) -> Self {
+        self.modify_order_params = Some(modify_order_params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ModifyOrderByUserIdIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = ModifyOrderByUserIdIxArgs {
+            user_order_id: self.user_order_id.ok_or_else(|| missing("user_order_id"))?,
+            modify_order_params: self
+                .modify_order_params
+                .ok_or_else(|| missing("modify_order_params"))?,
+        };
+        let keys = ModifyOrderByUserIdKeys {
+            state,
+            user,
+            authority,
+        };
+        modify_order_by_user_id_ix(keys, args)
+    }
+}
 pub fn modify_order_by_user_id_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ModifyOrderByUserIdAccounts<'_, '_>,
@@ -3830,6 +5319,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_TAKE_PERP_ORDER_IX_ACC
         }
     }
 }
+impl PlaceAndTakePerpOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PLACE_AND_TAKE_PERP_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..PLACE_AND_TAKE_PERP_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[PLACE_AND_TAKE_PERP_ORDER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for PlaceAndTakePerpOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PlaceAndTakePerpOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; PLACE_AND_TAKE_PERP_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..PLACE_AND_TAKE_PERP_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[PLACE_AND_TAKE_PERP_ORDER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for PlaceAndTakePerpOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PLACE_AND_TAKE_PERP_ORDER_IX_DISCM: [u8; 8] = [213, 51, 1, 187, 108, 220, 230, 224];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3889,6 +5422,84 @@ pub fn place_and_take_perp_order_ix(
 ) -> std::io::Result<Instruction> {
     place_and_take_perp_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`place_and_take_perp_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PlaceAndTakePerpOrderIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    params: Option<OrderParams>,
+    maker_order_id: Option<Option<u32>>,
+}
+impl Default for PlaceAndTakePerpOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PlaceAndTakePerpOrderIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            user_stats: None,
+            authority: None,
+            params: None,
+            maker_order_id: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn params(mut self, params: OrderParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    pub fn maker_order_id(mut self, maker_order_id: Option<u32>) -> Self {
+        self.maker_order_id = Some(maker_order_id);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("PlaceAndTakePerpOrderIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = PlaceAndTakePerpOrderIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+            maker_order_id: self
+                .maker_order_id
+                .ok_or_else(|| missing("maker_order_id"))?,
+        };
+        let keys = PlaceAndTakePerpOrderKeys {
+            state,
+            user,
+            user_stats,
+            authority,
+        };
+        place_and_take_perp_order_ix(keys, args)
+    }
+}
 pub fn place_and_take_perp_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceAndTakePerpOrderAccounts<'_, '_>,
@@ -4071,6 +5682,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_MAKE_PERP_ORDER_IX_ACC
         }
     }
 }
+impl PlaceAndMakePerpOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PLACE_AND_MAKE_PERP_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..PLACE_AND_MAKE_PERP_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[PLACE_AND_MAKE_PERP_ORDER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for PlaceAndMakePerpOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PlaceAndMakePerpOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; PLACE_AND_MAKE_PERP_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..PLACE_AND_MAKE_PERP_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[PLACE_AND_MAKE_PERP_ORDER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for PlaceAndMakePerpOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PLACE_AND_MAKE_PERP_ORDER_IX_DISCM: [u8; 8] = [149, 117, 11, 237, 47, 95, 89, 237];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4130,6 +5785,100 @@ pub fn place_and_make_perp_order_ix(
 ) -> std::io::Result<Instruction> {
     place_and_make_perp_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`place_and_make_perp_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PlaceAndMakePerpOrderIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    taker: Option<Pubkey>,
+    taker_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    params: Option<OrderParams>,
+    taker_order_id: Option<u32>,
+}
+impl Default for PlaceAndMakePerpOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PlaceAndMakePerpOrderIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            user_stats: None,
+            taker: None,
+            taker_stats: None,
+            authority: None,
+            params: None,
+            taker_order_id: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn taker(mut self, taker: Pubkey) -> Self {
+        self.taker = Some(taker);
+        self
+    }
+    pub fn taker_stats(mut self, taker_stats: Pubkey) -> Self {
+        self.taker_stats = Some(taker_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn params(mut self, params: OrderParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    pub fn taker_order_id(mut self, taker_order_id: u32) -> Self {
+        self.taker_order_id = Some(taker_order_id);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("PlaceAndMakePerpOrderIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let taker = self.taker.ok_or_else(|| missing("taker"))?;
+        let taker_stats = self.taker_stats.ok_or_else(|| missing("taker_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = PlaceAndMakePerpOrderIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+            taker_order_id: self
+                .taker_order_id
+                .ok_or_else(|| missing("taker_order_id"))?,
+        };
+        let keys = PlaceAndMakePerpOrderKeys {
+            state,
+            user,
+            user_stats,
+            taker,
+            taker_stats,
+            authority,
+        };
+        place_and_make_perp_order_ix(keys, args)
+    }
+}
 pub fn place_and_make_perp_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceAndMakePerpOrderAccounts<'_, '_>,
@@ -4286,6 +6035,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN
         }
     }
 }
+impl PlaceSpotOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for PlaceSpotOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PlaceSpotOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for PlaceSpotOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PLACE_SPOT_ORDER_IX_DISCM: [u8; 8] = [45, 79, 81, 160, 248, 90, 91, 220];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4344,6 +6131,67 @@ pub fn place_spot_order_ix(
 ) -> std::io::Result<Instruction> {
     place_spot_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`place_spot_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PlaceSpotOrderIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    params: Option<OrderParams>,
+}
+impl Default for PlaceSpotOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PlaceSpotOrderIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            authority: None,
+            params: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn params(mut self, params: OrderParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("PlaceSpotOrderIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = PlaceSpotOrderIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let keys = PlaceSpotOrderKeys {
+            state,
+            user,
+            authority,
+        };
+        place_spot_order_ix(keys, args)
+    }
+}
 pub fn place_spot_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceSpotOrderAccounts<'_, '_>,
@@ -4503,6 +6351,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_TAKE_SPOT_ORDER_IX_ACC
         }
     }
 }
+impl PlaceAndTakeSpotOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PLACE_AND_TAKE_SPOT_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..PLACE_AND_TAKE_SPOT_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[PLACE_AND_TAKE_SPOT_ORDER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for PlaceAndTakeSpotOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PlaceAndTakeSpotOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; PLACE_AND_TAKE_SPOT_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..PLACE_AND_TAKE_SPOT_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[PLACE_AND_TAKE_SPOT_ORDER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for PlaceAndTakeSpotOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PLACE_AND_TAKE_SPOT_ORDER_IX_DISCM: [u8; 8] = [191, 3, 138, 71, 114, 198, 202, 100];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4563,6 +6455,93 @@ pub fn place_and_take_spot_order_ix(
 ) -> std::io::Result<Instruction> {
     place_and_take_spot_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`place_and_take_spot_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PlaceAndTakeSpotOrderIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    params: Option<OrderParams>,
+    fulfillment_type: Option<Option<SpotFulfillmentType>>,
+    maker_order_id: Option<Option<u32>>,
+}
+impl Default for PlaceAndTakeSpotOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PlaceAndTakeSpotOrderIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            user_stats: None,
+            authority: None,
+            params: None,
+            fulfillment_type: None,
+            maker_order_id: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn params(mut self, params: OrderParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    pub fn fulfillment_type(mut self, fulfillment_type: Option<SpotFulfillmentType>) -> Self {
+        self.fulfillment_type = Some(fulfillment_type);
+        self
+    }
+    pub fn maker_order_id(mut self, maker_order_id: Option<u32>) -> Self {
+        self.maker_order_id = Some(maker_order_id);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("PlaceAndTakeSpotOrderIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = PlaceAndTakeSpotOrderIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+            fulfillment_type: self
+                .fulfillment_type
+                .ok_or_else(|| missing("fulfillment_type"))?,
+            maker_order_id: self
+                .maker_order_id
+                .ok_or_else(|| missing("maker_order_id"))?,
+        };
+        let keys = PlaceAndTakeSpotOrderKeys {
+            state,
+            user,
+            user_stats,
+            authority,
+        };
+        place_and_take_spot_order_ix(keys, args)
+    }
+}
 pub fn place_and_take_spot_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceAndTakeSpotOrderAccounts<'_, '_>,
@@ -4745,6 +6724,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_MAKE_SPOT_ORDER_IX_ACC
         }
     }
 }
+impl PlaceAndMakeSpotOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PLACE_AND_MAKE_SPOT_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..PLACE_AND_MAKE_SPOT_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[PLACE_AND_MAKE_SPOT_ORDER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for PlaceAndMakeSpotOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PlaceAndMakeSpotOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; PLACE_AND_MAKE_SPOT_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..PLACE_AND_MAKE_SPOT_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[PLACE_AND_MAKE_SPOT_ORDER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for PlaceAndMakeSpotOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PLACE_AND_MAKE_SPOT_ORDER_IX_DISCM: [u8; 8] = [149, 158, 85, 66, 239, 9, 243, 98];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4805,6 +6828,109 @@ pub fn place_and_make_spot_order_ix(
 ) -> std::io::Result<Instruction> {
     place_and_make_spot_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`place_and_make_spot_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PlaceAndMakeSpotOrderIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    taker: Option<Pubkey>,
+    taker_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    params: Option<OrderParams>,
+    taker_order_id: Option<u32>,
+    fulfillment_type: Option<Option<SpotFulfillmentType>>,
+}
+impl Default for PlaceAndMakeSpotOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PlaceAndMakeSpotOrderIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            user_stats: None,
+            taker: None,
+            taker_stats: None,
+            authority: None,
+            params: None,
+            taker_order_id: None,
+            fulfillment_type: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn taker(mut self, taker: Pubkey) -> Self {
+        self.taker = Some(taker);
+        self
+    }
+    pub fn taker_stats(mut self, taker_stats: Pubkey) -> Self {
+        self.taker_stats = Some(taker_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn params(mut self, params: OrderParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    pub fn taker_order_id(mut self, taker_order_id: u32) -> Self {
+        self.taker_order_id = Some(taker_order_id);
+        self
+    }
+    pub fn fulfillment_type(mut self, fulfillment_type: Option<SpotFulfillmentType>) -> Self {
+        self.fulfillment_type = Some(fulfillment_type);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("PlaceAndMakeSpotOrderIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let taker = self.taker.ok_or_else(|| missing("taker"))?;
+        let taker_stats = self.taker_stats.ok_or_else(|| missing("taker_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = PlaceAndMakeSpotOrderIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+            taker_order_id: self
+                .taker_order_id
+                .ok_or_else(|| missing("taker_order_id"))?,
+            fulfillment_type: self
+                .fulfillment_type
+                .ok_or_else(|| missing("fulfillment_type"))?,
+        };
+        let keys = PlaceAndMakeSpotOrderKeys {
+            state,
+            user,
+            user_stats,
+            taker,
+            taker_stats,
+            authority,
+        };
+        place_and_make_spot_order_ix(keys, args)
+    }
+}
 pub fn place_and_make_spot_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceAndMakeSpotOrderAccounts<'_, '_>,
@@ -4900,6 +7026,7 @@ pub struct BeginSwapAccounts<'me, 'info> {
     pub in_token_account: &'me AccountInfo<'info>,
     pub token_program: &'me AccountInfo<'info>,
     pub drift_signer: &'me AccountInfo<'info>,
+    /// Instructions Sysvar for instruction introspection
     pub instructions: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -4914,6 +7041,7 @@ pub struct BeginSwapKeys {
     pub in_token_account: Pubkey,
     pub token_program: Pubkey,
     pub drift_signer: Pubkey,
+    /// Instructions Sysvar for instruction introspection
     pub instructions: Pubkey,
 }
 impl From<BeginSwapAccounts<'_, '_>> for BeginSwapKeys {
@@ -5049,6 +7177,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; BEGIN_SWAP_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl BeginSwapKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; BEGIN_SWAP_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..BEGIN_SWAP_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[BEGIN_SWAP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for BeginSwapKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> BeginSwapAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; BEGIN_SWAP_IX_ACCOUNTS_LEN] = accounts
+            .get(..BEGIN_SWAP_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[BEGIN_SWAP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for BeginSwapAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const BEGIN_SWAP_IX_DISCM: [u8; 8] = [174, 109, 228, 1, 242, 105, 232, 105];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5106,6 +7272,159 @@ pub fn begin_swap_ix_with_program_id(
 pub fn begin_swap_ix(keys: BeginSwapKeys, args: BeginSwapIxArgs) -> std::io::Result<Instruction> {
     begin_swap_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`begin_swap_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct BeginSwapIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    out_spot_market_vault: Option<Pubkey>,
+    in_spot_market_vault: Option<Pubkey>,
+    out_token_account: Option<Pubkey>,
+    in_token_account: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    instructions: Option<Pubkey>,
+    in_market_index: Option<u16>,
+    out_market_index: Option<u16>,
+    amount_in: Option<u64>,
+}
+impl Default for BeginSwapIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl BeginSwapIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            user_stats: None,
+            authority: None,
+            out_spot_market_vault: None,
+            in_spot_market_vault: None,
+            out_token_account: None,
+            in_token_account: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            drift_signer: None,
+            instructions: None,
+            in_market_index: None,
+            out_market_index: None,
+            amount_in: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn out_spot_market_vault(mut self, out_spot_market_vault: Pubkey) -> Self {
+        self.out_spot_market_vault = Some(out_spot_market_vault);
+        self
+    }
+    pub fn in_spot_market_vault(mut self, in_spot_market_vault: Pubkey) -> Self {
+        self.in_spot_market_vault = Some(in_spot_market_vault);
+        self
+    }
+    pub fn out_token_account(mut self, out_token_account: Pubkey) -> Self {
+        self.out_token_account = Some(out_token_account);
+        self
+    }
+    pub fn in_token_account(mut self, in_token_account: Pubkey) -> Self {
+        self.in_token_account = Some(in_token_account);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn instructions(mut self, instructions: Pubkey) -> Self {
+        self.instructions = Some(instructions);
+        self
+    }
+    pub fn in_market_index(mut self, in_market_index: u16) -> Self {
+        self.in_market_index = Some(in_market_index);
+        self
+    }
+    pub fn out_market_index(mut self, out_market_index: u16) -> Self {
+        self.out_market_index = Some(out_market_index);
+        self
+    }
+    pub fn amount_in(mut self, amount_in: u64) -> Self {
+        self.amount_in = Some(amount_in);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("BeginSwapIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let out_spot_market_vault = self
+            .out_spot_market_vault
+            .ok_or_else(|| missing("out_spot_market_vault"))?;
+        let in_spot_market_vault = self
+            .in_spot_market_vault
+            .ok_or_else(|| missing("in_spot_market_vault"))?;
+        let out_token_account = self
+            .out_token_account
+            .ok_or_else(|| missing("out_token_account"))?;
+        let in_token_account = self
+            .in_token_account
+            .ok_or_else(|| missing("in_token_account"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let instructions = self.instructions.ok_or_else(|| missing("instructions"))?;
+        let args = BeginSwapIxArgs {
+            in_market_index: self
+                .in_market_index
+                .ok_or_else(|| missing("in_market_index"))?,
+            out_market_index: self
+                .out_market_index
+                .ok_or_else(|| missing("out_market_index"))?,
+            amount_in: self.amount_in.ok_or_else(|| missing("amount_in"))?,
+        };
+        let keys = BeginSwapKeys {
+            state,
+            user,
+            user_stats,
+            authority,
+            out_spot_market_vault,
+            in_spot_market_vault,
+            out_token_account,
+            in_token_account,
+            token_program,
+            drift_signer,
+            instructions,
+        };
+        begin_swap_ix(keys, args)
+    }
+}
 pub fn begin_swap_invoke_with_program_id(
     program_id: Pubkey,
     accounts: BeginSwapAccounts<'_, '_>,
@@ -5214,6 +7533,7 @@ pub struct EndSwapAccounts<'me, 'info> {
     pub in_token_account: &'me AccountInfo<'info>,
     pub token_program: &'me AccountInfo<'info>,
     pub drift_signer: &'me AccountInfo<'info>,
+    /// Instructions Sysvar for instruction introspection
     pub instructions: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -5228,6 +7548,7 @@ pub struct EndSwapKeys {
     pub in_token_account: Pubkey,
     pub token_program: Pubkey,
     pub drift_signer: Pubkey,
+    /// Instructions Sysvar for instruction introspection
     pub instructions: Pubkey,
 }
 impl From<EndSwapAccounts<'_, '_>> for EndSwapKeys {
@@ -5361,6 +7682,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; END_SWAP_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl EndSwapKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; END_SWAP_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..END_SWAP_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[END_SWAP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for EndSwapKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> EndSwapAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; END_SWAP_IX_ACCOUNTS_LEN] = accounts
+            .get(..END_SWAP_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[END_SWAP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for EndSwapAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const END_SWAP_IX_DISCM: [u8; 8] = [177, 184, 27, 193, 34, 13, 210, 145];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5419,6 +7778,166 @@ pub fn end_swap_ix_with_program_id(
 pub fn end_swap_ix(keys: EndSwapKeys, args: EndSwapIxArgs) -> std::io::Result<Instruction> {
     end_swap_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`end_swap_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct EndSwapIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    out_spot_market_vault: Option<Pubkey>,
+    in_spot_market_vault: Option<Pubkey>,
+    out_token_account: Option<Pubkey>,
+    in_token_account: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    instructions: Option<Pubkey>,
+    in_market_index: Option<u16>,
+    out_market_index: Option<u16>,
+    limit_price: Option<Option<u64>>,
+    reduce_only: Option<Option<SwapReduceOnly>>,
+}
+impl Default for EndSwapIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl EndSwapIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            user_stats: None,
+            authority: None,
+            out_spot_market_vault: None,
+            in_spot_market_vault: None,
+            out_token_account: None,
+            in_token_account: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            drift_signer: None,
+            instructions: None,
+            in_market_index: None,
+            out_market_index: None,
+            limit_price: None,
+            reduce_only: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn out_spot_market_vault(mut self, out_spot_market_vault: Pubkey) -> Self {
+        self.out_spot_market_vault = Some(out_spot_market_vault);
+        self
+    }
+    pub fn in_spot_market_vault(mut self, in_spot_market_vault: Pubkey) -> Self {
+        self.in_spot_market_vault = Some(in_spot_market_vault);
+        self
+    }
+    pub fn out_token_account(mut self, out_token_account: Pubkey) -> Self {
+        self.out_token_account = Some(out_token_account);
+        self
+    }
+    pub fn in_token_account(mut self, in_token_account: Pubkey) -> Self {
+        self.in_token_account = Some(in_token_account);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn instructions(mut self, instructions: Pubkey) -> Self {
+        self.instructions = Some(instructions);
+        self
+    }
+    pub fn in_market_index(mut self, in_market_index: u16) -> Self {
+        self.in_market_index = Some(in_market_index);
+        self
+    }
+    pub fn out_market_index(mut self, out_market_index: u16) -> Self {
+        self.out_market_index = Some(out_market_index);
+        self
+    }
+    pub fn limit_price(mut self, limit_price: Option<u64>) -> Self {
+        self.limit_price = Some(limit_price);
+        self
+    }
+    pub fn reduce_only(mut self, reduce_only: Option<SwapReduceOnly>) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("EndSwapIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let out_spot_market_vault = self
+            .out_spot_market_vault
+            .ok_or_else(|| missing("out_spot_market_vault"))?;
+        let in_spot_market_vault = self
+            .in_spot_market_vault
+            .ok_or_else(|| missing("in_spot_market_vault"))?;
+        let out_token_account = self
+            .out_token_account
+            .ok_or_else(|| missing("out_token_account"))?;
+        let in_token_account = self
+            .in_token_account
+            .ok_or_else(|| missing("in_token_account"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let instructions = self.instructions.ok_or_else(|| missing("instructions"))?;
+        let args = EndSwapIxArgs {
+            in_market_index: self
+                .in_market_index
+                .ok_or_else(|| missing("in_market_index"))?,
+            out_market_index: self
+                .out_market_index
+                .ok_or_else(|| missing("out_market_index"))?,
+            limit_price: self.limit_price.ok_or_else(|| missing("limit_price"))?,
+            reduce_only: self.reduce_only.ok_or_else(|| missing("reduce_only"))?,
+        };
+        let keys = EndSwapKeys {
+            state,
+            user,
+            user_stats,
+            authority,
+            out_spot_market_vault,
+            in_spot_market_vault,
+            out_token_account,
+            in_token_account,
+            token_program,
+            drift_signer,
+            instructions,
+        };
+        end_swap_ix(keys, args)
+    }
+}
 pub fn end_swap_invoke_with_program_id(
     program_id: Pubkey,
     accounts: EndSwapAccounts<'_, '_>,
@@ -5585,6 +8104,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_PERP_LP_SHARES_IX_ACCOUNTS_L
         }
     }
 }
+impl AddPerpLpSharesKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ADD_PERP_LP_SHARES_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ADD_PERP_LP_SHARES_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[ADD_PERP_LP_SHARES_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AddPerpLpSharesKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AddPerpLpSharesAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ADD_PERP_LP_SHARES_IX_ACCOUNTS_LEN] = accounts
+            .get(..ADD_PERP_LP_SHARES_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[ADD_PERP_LP_SHARES_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AddPerpLpSharesAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const ADD_PERP_LP_SHARES_IX_DISCM: [u8; 8] = [56, 209, 56, 197, 119, 254, 188, 117];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5644,6 +8201,74 @@ pub fn add_perp_lp_shares_ix(
 ) -> std::io::Result<Instruction> {
     add_perp_lp_shares_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`add_perp_lp_shares_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AddPerpLpSharesIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    n_shares: Option<u64>,
+    market_index: Option<u16>,
+}
+impl Default for AddPerpLpSharesIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AddPerpLpSharesIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            authority: None,
+            n_shares: None,
+            market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn n_shares(mut self, n_shares: u64) -> Self {
+        self.n_shares = Some(n_shares);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AddPerpLpSharesIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = AddPerpLpSharesIxArgs {
+            n_shares: self.n_shares.ok_or_else(|| missing("n_shares"))?,
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = AddPerpLpSharesKeys {
+            state,
+            user,
+            authority,
+        };
+        add_perp_lp_shares_ix(keys, args)
+    }
+}
 pub fn add_perp_lp_shares_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AddPerpLpSharesAccounts<'_, '_>,
@@ -5792,6 +8417,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_PERP_LP_SHARES_IX_ACCOUNT
         }
     }
 }
+impl RemovePerpLpSharesKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REMOVE_PERP_LP_SHARES_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REMOVE_PERP_LP_SHARES_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[REMOVE_PERP_LP_SHARES_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for RemovePerpLpSharesKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RemovePerpLpSharesAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REMOVE_PERP_LP_SHARES_IX_ACCOUNTS_LEN] = accounts
+            .get(..REMOVE_PERP_LP_SHARES_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[REMOVE_PERP_LP_SHARES_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for RemovePerpLpSharesAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REMOVE_PERP_LP_SHARES_IX_DISCM: [u8; 8] = [213, 89, 217, 18, 160, 55, 53, 141];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5851,6 +8520,76 @@ pub fn remove_perp_lp_shares_ix(
 ) -> std::io::Result<Instruction> {
     remove_perp_lp_shares_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`remove_perp_lp_shares_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RemovePerpLpSharesIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    shares_to_burn: Option<u64>,
+    market_index: Option<u16>,
+}
+impl Default for RemovePerpLpSharesIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RemovePerpLpSharesIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            authority: None,
+            shares_to_burn: None,
+            market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn shares_to_burn(mut self, shares_to_burn: u64) -> Self {
+        self.shares_to_burn = Some(shares_to_burn);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RemovePerpLpSharesIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = RemovePerpLpSharesIxArgs {
+            shares_to_burn: self
+                .shares_to_burn
+                .ok_or_else(|| missing("shares_to_burn"))?,
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = RemovePerpLpSharesKeys {
+            state,
+            user,
+            authority,
+        };
+        remove_perp_lp_shares_ix(keys, args)
+    }
+}
 pub fn remove_perp_lp_shares_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RemovePerpLpSharesAccounts<'_, '_>,
@@ -5994,6 +8733,53 @@ impl<'me, 'info>
         }
     }
 }
+impl RemovePerpLpSharesInExpiringMarketKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for RemovePerpLpSharesInExpiringMarketKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RemovePerpLpSharesInExpiringMarketAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_ACCOUNTS_LEN] = accounts
+            .get(..REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for RemovePerpLpSharesInExpiringMarketAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_DISCM: [u8; 8] =
     [83, 254, 253, 137, 59, 122, 68, 156];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -6057,6 +8843,68 @@ pub fn remove_perp_lp_shares_in_expiring_market_ix(
 ) -> std::io::Result<Instruction> {
     remove_perp_lp_shares_in_expiring_market_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`remove_perp_lp_shares_in_expiring_market_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RemovePerpLpSharesInExpiringMarketIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    shares_to_burn: Option<u64>,
+    market_index: Option<u16>,
+}
+impl Default for RemovePerpLpSharesInExpiringMarketIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RemovePerpLpSharesInExpiringMarketIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            shares_to_burn: None,
+            market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn shares_to_burn(mut self, shares_to_burn: u64) -> Self {
+        self.shares_to_burn = Some(shares_to_burn);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "RemovePerpLpSharesInExpiringMarketIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let args = RemovePerpLpSharesInExpiringMarketIxArgs {
+            shares_to_burn: self
+                .shares_to_burn
+                .ok_or_else(|| missing("shares_to_burn"))?,
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = RemovePerpLpSharesInExpiringMarketKeys { state, user };
+        remove_perp_lp_shares_in_expiring_market_ix(keys, args)
+    }
+}
 pub fn remove_perp_lp_shares_in_expiring_market_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RemovePerpLpSharesInExpiringMarketAccounts<'_, '_>,
@@ -6184,6 +9032,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_NAME_IX_ACCOUNTS_LEN
         }
     }
 }
+impl UpdateUserNameKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_USER_NAME_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_USER_NAME_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UPDATE_USER_NAME_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateUserNameKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateUserNameAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_USER_NAME_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_USER_NAME_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UPDATE_USER_NAME_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateUserNameAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_USER_NAME_IX_DISCM: [u8; 8] = [135, 25, 185, 56, 165, 53, 34, 136];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -6243,6 +9129,65 @@ pub fn update_user_name_ix(
 ) -> std::io::Result<Instruction> {
     update_user_name_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_user_name_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateUserNameIxBuilder {
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    sub_account_id: Option<u16>,
+    name: Option<[u8; 32]>,
+}
+impl Default for UpdateUserNameIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateUserNameIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            user: None,
+            authority: None,
+            sub_account_id: None,
+            name: None,
+        }
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn sub_account_id(mut self, sub_account_id: u16) -> Self {
+        self.sub_account_id = Some(sub_account_id);
+        self
+    }
+    pub fn name(mut self, name: [u8; 32]) -> Self {
+        self.name = Some(name);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateUserNameIxBuilder is missing {}", field),
+            )
+        };
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = UpdateUserNameIxArgs {
+            sub_account_id: self
+                .sub_account_id
+                .ok_or_else(|| missing("sub_account_id"))?,
+            name: self.name.ok_or_else(|| missing("name"))?,
+        };
+        let keys = UpdateUserNameKeys { user, authority };
+        update_user_name_ix(keys, args)
+    }
+}
 pub fn update_user_name_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateUserNameAccounts<'_, '_>,
@@ -6382,6 +9327,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_CUSTOM_MARGIN_RATIO_
         }
     }
 }
+impl UpdateUserCustomMarginRatioKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateUserCustomMarginRatioKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateUserCustomMarginRatioAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateUserCustomMarginRatioAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_DISCM: [u8; 8] = [21, 221, 140, 187, 32, 129, 11, 123];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -6443,7 +9435,66 @@ pub fn update_user_custom_margin_ratio_ix(
 ) -> std::io::Result<Instruction> {
     update_user_custom_margin_ratio_ix_with_program_id(crate::ID, keys, args)
 }
-pub fn update_user_custom_margin_ratio_invoke_with_program_id(
+/// Builds a [`update_user_custom_margin_ratio_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateUserCustomMarginRatioIxBuilder {
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    sub_account_id: Option<u16>,
+    margin_ratio: Option<u32>,
+}
+impl Default for UpdateUserCustomMarginRatioIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateUserCustomMarginRatioIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            user: None,
+            authority: None,
+            sub_account_id: None,
+            margin_ratio: None,
+        }
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn sub_account_id(mut self, sub_account_id: u16) -> Self {
+        self.sub_account_id = Some(sub_account_id);
+        self
+    }
+    pub fn margin_ratio(mut self, margin_ratio: u32) -> Self {
+        self.margin_ratio = Some(margin_ratio);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateUserCustomMarginRatioIxBuilder is missing {}", field),
+            )
+        };
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = UpdateUserCustomMarginRatioIxArgs {
+            sub_account_id: self
+                .sub_account_id
+                .ok_or_else(|| missing("sub_account_id"))?,
+            margin_ratio: self.margin_ratio.ok_or_else(|| missing("margin_ratio"))?,
+        };
+        let keys = UpdateUserCustomMarginRatioKeys { user, authority };
+        update_user_custom_margin_ratio_ix(keys, args)
+    }
+}
+pub fn update_user_custom_margin_ratio_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateUserCustomMarginRatioAccounts<'_, '_>,
     args: UpdateUserCustomMarginRatioIxArgs,
@@ -6582,6 +9633,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_MARGIN_TRADING_ENABL
         }
     }
 }
+impl UpdateUserMarginTradingEnabledKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_USER_MARGIN_TRADING_ENABLED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_USER_MARGIN_TRADING_ENABLED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_USER_MARGIN_TRADING_ENABLED_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateUserMarginTradingEnabledKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateUserMarginTradingEnabledAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_USER_MARGIN_TRADING_ENABLED_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_USER_MARGIN_TRADING_ENABLED_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_USER_MARGIN_TRADING_ENABLED_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateUserMarginTradingEnabledAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_USER_MARGIN_TRADING_ENABLED_IX_DISCM: [u8; 8] =
     [194, 92, 204, 223, 246, 188, 31, 203];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -6644,6 +9742,70 @@ pub fn update_user_margin_trading_enabled_ix(
 ) -> std::io::Result<Instruction> {
     update_user_margin_trading_enabled_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_user_margin_trading_enabled_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateUserMarginTradingEnabledIxBuilder {
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    sub_account_id: Option<u16>,
+    margin_trading_enabled: Option<bool>,
+}
+impl Default for UpdateUserMarginTradingEnabledIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateUserMarginTradingEnabledIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            user: None,
+            authority: None,
+            sub_account_id: None,
+            margin_trading_enabled: None,
+        }
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn sub_account_id(mut self, sub_account_id: u16) -> Self {
+        self.sub_account_id = Some(sub_account_id);
+        self
+    }
+    pub fn margin_trading_enabled(mut self, margin_trading_enabled: bool) -> Self {
+        self.margin_trading_enabled = Some(margin_trading_enabled);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateUserMarginTradingEnabledIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = UpdateUserMarginTradingEnabledIxArgs {
+            sub_account_id: self
+                .sub_account_id
+                .ok_or_else(|| missing("sub_account_id"))?,
+            margin_trading_enabled: self
+                .margin_trading_enabled
+                .ok_or_else(|| missing("margin_trading_enabled"))?,
+        };
+        let keys = UpdateUserMarginTradingEnabledKeys { user, authority };
+        update_user_margin_trading_enabled_ix(keys, args)
+    }
+}
 pub fn update_user_margin_trading_enabled_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateUserMarginTradingEnabledAccounts<'_, '_>,
@@ -6782,6 +9944,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_DELEGATE_IX_ACCOUNTS
         }
     }
 }
+impl UpdateUserDelegateKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_USER_DELEGATE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_USER_DELEGATE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_USER_DELEGATE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateUserDelegateKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateUserDelegateAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_USER_DELEGATE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_USER_DELEGATE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_USER_DELEGATE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateUserDelegateAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_USER_DELEGATE_IX_DISCM: [u8; 8] = [139, 205, 141, 141, 113, 36, 94, 187];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -6841,6 +10047,65 @@ pub fn update_user_delegate_ix(
 ) -> std::io::Result<Instruction> {
     update_user_delegate_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_user_delegate_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateUserDelegateIxBuilder {
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    sub_account_id: Option<u16>,
+    delegate: Option<Pubkey>,
+}
+impl Default for UpdateUserDelegateIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateUserDelegateIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            user: None,
+            authority: None,
+            sub_account_id: None,
+            delegate: None,
+        }
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn sub_account_id(mut self, sub_account_id: u16) -> Self {
+        self.sub_account_id = Some(sub_account_id);
+        self
+    }
+    pub fn delegate(mut self, delegate: Pubkey) -> Self {
+        self.delegate = Some(delegate);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateUserDelegateIxBuilder is missing {}", field),
+            )
+        };
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = UpdateUserDelegateIxArgs {
+            sub_account_id: self
+                .sub_account_id
+                .ok_or_else(|| missing("sub_account_id"))?,
+            delegate: self.delegate.ok_or_else(|| missing("delegate"))?,
+        };
+        let keys = UpdateUserDelegateKeys { user, authority };
+        update_user_delegate_ix(keys, args)
+    }
+}
 pub fn update_user_delegate_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateUserDelegateAccounts<'_, '_>,
@@ -6999,6 +10264,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DELETE_USER_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl DeleteUserKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DELETE_USER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DELETE_USER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[DELETE_USER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeleteUserKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeleteUserAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DELETE_USER_IX_ACCOUNTS_LEN] = accounts
+            .get(..DELETE_USER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[DELETE_USER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for DeleteUserAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DELETE_USER_IX_DISCM: [u8; 8] = [186, 85, 17, 249, 219, 231, 98, 251];
 #[derive(Clone, Debug, PartialEq)]
 pub struct DeleteUserIxData;
@@ -7041,6 +10344,66 @@ pub fn delete_user_ix_with_program_id(
 pub fn delete_user_ix(keys: DeleteUserKeys) -> std::io::Result<Instruction> {
     delete_user_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`delete_user_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeleteUserIxBuilder {
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+}
+impl Default for DeleteUserIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeleteUserIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            user: None,
+            user_stats: None,
+            state: None,
+            authority: None,
+        }
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DeleteUserIxBuilder is missing {}", field),
+            )
+        };
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let keys = DeleteUserKeys {
+            user,
+            user_stats,
+            state,
+            authority,
+        };
+        delete_user_ix(keys)
+    }
+}
 pub fn delete_user_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeleteUserAccounts<'_, '_>,
@@ -7217,6 +10580,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; FILL_PERP_ORDER_IX_ACCOUNTS_LEN]
         }
     }
 }
+impl FillPerpOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; FILL_PERP_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..FILL_PERP_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[FILL_PERP_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for FillPerpOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> FillPerpOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; FILL_PERP_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..FILL_PERP_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[FILL_PERP_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for FillPerpOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const FILL_PERP_ORDER_IX_DISCM: [u8; 8] = [13, 188, 248, 103, 134, 217, 106, 240];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -7276,6 +10677,100 @@ pub fn fill_perp_order_ix(
 ) -> std::io::Result<Instruction> {
     fill_perp_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`fill_perp_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct FillPerpOrderIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    filler: Option<Pubkey>,
+    filler_stats: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    order_id: Option<Option<u32>>,
+    maker_order_id: Option<Option<u32>>,
+}
+impl Default for FillPerpOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl FillPerpOrderIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            filler: None,
+            filler_stats: None,
+            user: None,
+            user_stats: None,
+            order_id: None,
+            maker_order_id: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn filler(mut self, filler: Pubkey) -> Self {
+        self.filler = Some(filler);
+        self
+    }
+    pub fn filler_stats(mut self, filler_stats: Pubkey) -> Self {
+        self.filler_stats = Some(filler_stats);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn order_id(mut self, order_id: Option<u32>) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+    pub fn maker_order_id(mut self, maker_order_id: Option<u32>) -> Self {
+        self.maker_order_id = Some(maker_order_id);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("FillPerpOrderIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let filler = self.filler.ok_or_else(|| missing("filler"))?;
+        let filler_stats = self.filler_stats.ok_or_else(|| missing("filler_stats"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let args = FillPerpOrderIxArgs {
+            order_id: self.order_id.ok_or_else(|| missing("order_id"))?,
+            maker_order_id: self
+                .maker_order_id
+                .ok_or_else(|| missing("maker_order_id"))?,
+        };
+        let keys = FillPerpOrderKeys {
+            state,
+            authority,
+            filler,
+            filler_stats,
+            user,
+            user_stats,
+        };
+        fill_perp_order_ix(keys, args)
+    }
+}
 pub fn fill_perp_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: FillPerpOrderAccounts<'_, '_>,
@@ -7443,6 +10938,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REVERT_FILL_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl RevertFillKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REVERT_FILL_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REVERT_FILL_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[REVERT_FILL_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for RevertFillKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RevertFillAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REVERT_FILL_IX_ACCOUNTS_LEN] = accounts
+            .get(..REVERT_FILL_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[REVERT_FILL_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for RevertFillAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REVERT_FILL_IX_DISCM: [u8; 8] = [236, 238, 176, 69, 239, 10, 181, 193];
 #[derive(Clone, Debug, PartialEq)]
 pub struct RevertFillIxData;
@@ -7485,6 +11018,66 @@ pub fn revert_fill_ix_with_program_id(
 pub fn revert_fill_ix(keys: RevertFillKeys) -> std::io::Result<Instruction> {
     revert_fill_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`revert_fill_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RevertFillIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    filler: Option<Pubkey>,
+    filler_stats: Option<Pubkey>,
+}
+impl Default for RevertFillIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RevertFillIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            filler: None,
+            filler_stats: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn filler(mut self, filler: Pubkey) -> Self {
+        self.filler = Some(filler);
+        self
+    }
+    pub fn filler_stats(mut self, filler_stats: Pubkey) -> Self {
+        self.filler_stats = Some(filler_stats);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RevertFillIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let filler = self.filler.ok_or_else(|| missing("filler"))?;
+        let filler_stats = self.filler_stats.ok_or_else(|| missing("filler_stats"))?;
+        let keys = RevertFillKeys {
+            state,
+            authority,
+            filler,
+            filler_stats,
+        };
+        revert_fill_ix(keys)
+    }
+}
 pub fn revert_fill_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RevertFillAccounts<'_, '_>,
@@ -7661,6 +11254,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; FILL_SPOT_ORDER_IX_ACCOUNTS_LEN]
         }
     }
 }
+impl FillSpotOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; FILL_SPOT_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..FILL_SPOT_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[FILL_SPOT_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for FillSpotOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> FillSpotOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; FILL_SPOT_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..FILL_SPOT_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[FILL_SPOT_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for FillSpotOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const FILL_SPOT_ORDER_IX_DISCM: [u8; 8] = [212, 206, 130, 173, 21, 34, 199, 40];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -7721,6 +11352,109 @@ pub fn fill_spot_order_ix(
 ) -> std::io::Result<Instruction> {
     fill_spot_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`fill_spot_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct FillSpotOrderIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    filler: Option<Pubkey>,
+    filler_stats: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    order_id: Option<Option<u32>>,
+    fulfillment_type: Option<Option<SpotFulfillmentType>>,
+    maker_order_id: Option<Option<u32>>,
+}
+impl Default for FillSpotOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl FillSpotOrderIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            filler: None,
+            filler_stats: None,
+            user: None,
+            user_stats: None,
+            order_id: None,
+            fulfillment_type: None,
+            maker_order_id: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn filler(mut self, filler: Pubkey) -> Self {
+        self.filler = Some(filler);
+        self
+    }
+    pub fn filler_stats(mut self, filler_stats: Pubkey) -> Self {
+        self.filler_stats = Some(filler_stats);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn order_id(mut self, order_id: Option<u32>) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+    pub fn fulfillment_type(mut self, fulfillment_type: Option<SpotFulfillmentType>) -> Self {
+        self.fulfillment_type = Some(fulfillment_type);
+        self
+    }
+    pub fn maker_order_id(mut self, maker_order_id: Option<u32>) -> Self {
+        self.maker_order_id = Some(maker_order_id);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("FillSpotOrderIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let filler = self.filler.ok_or_else(|| missing("filler"))?;
+        let filler_stats = self.filler_stats.ok_or_else(|| missing("filler_stats"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let args = FillSpotOrderIxArgs {
+            order_id: self.order_id.ok_or_else(|| missing("order_id"))?,
+            fulfillment_type: self
+                .fulfillment_type
+                .ok_or_else(|| missing("fulfillment_type"))?,
+            maker_order_id: self
+                .maker_order_id
+                .ok_or_else(|| missing("maker_order_id"))?,
+        };
+        let keys = FillSpotOrderKeys {
+            state,
+            authority,
+            filler,
+            filler_stats,
+            user,
+            user_stats,
+        };
+        fill_spot_order_ix(keys, args)
+    }
+}
 pub fn fill_spot_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: FillSpotOrderAccounts<'_, '_>,
@@ -7888,6 +11622,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; TRIGGER_ORDER_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl TriggerOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; TRIGGER_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..TRIGGER_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[TRIGGER_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for TriggerOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> TriggerOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; TRIGGER_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..TRIGGER_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[TRIGGER_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for TriggerOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const TRIGGER_ORDER_IX_DISCM: [u8; 8] = [63, 112, 51, 233, 232, 47, 240, 199];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -7946,6 +11718,75 @@ pub fn trigger_order_ix(
 ) -> std::io::Result<Instruction> {
     trigger_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`trigger_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct TriggerOrderIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    filler: Option<Pubkey>,
+    user: Option<Pubkey>,
+    order_id: Option<u32>,
+}
+impl Default for TriggerOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl TriggerOrderIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            filler: None,
+            user: None,
+            order_id: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn filler(mut self, filler: Pubkey) -> Self {
+        self.filler = Some(filler);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn order_id(mut self, order_id: u32) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("TriggerOrderIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let filler = self.filler.ok_or_else(|| missing("filler"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let args = TriggerOrderIxArgs {
+            order_id: self.order_id.ok_or_else(|| missing("order_id"))?,
+        };
+        let keys = TriggerOrderKeys {
+            state,
+            authority,
+            filler,
+            user,
+        };
+        trigger_order_ix(keys, args)
+    }
+}
 pub fn trigger_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: TriggerOrderAccounts<'_, '_>,
@@ -8106,6 +11947,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_
         }
     }
 }
+impl ForceCancelOrdersKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for ForceCancelOrdersKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ForceCancelOrdersAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN] = accounts
+            .get(..FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ForceCancelOrdersAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const FORCE_CANCEL_ORDERS_IX_DISCM: [u8; 8] = [64, 181, 196, 63, 222, 72, 64, 232];
 #[derive(Clone, Debug, PartialEq)]
 pub struct ForceCancelOrdersIxData;
@@ -8148,6 +12027,66 @@ pub fn force_cancel_orders_ix_with_program_id(
 pub fn force_cancel_orders_ix(keys: ForceCancelOrdersKeys) -> std::io::Result<Instruction> {
     force_cancel_orders_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`force_cancel_orders_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ForceCancelOrdersIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    filler: Option<Pubkey>,
+    user: Option<Pubkey>,
+}
+impl Default for ForceCancelOrdersIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ForceCancelOrdersIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            filler: None,
+            user: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn filler(mut self, filler: Pubkey) -> Self {
+        self.filler = Some(filler);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ForceCancelOrdersIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let filler = self.filler.ok_or_else(|| missing("filler"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let keys = ForceCancelOrdersKeys {
+            state,
+            authority,
+            filler,
+            user,
+        };
+        force_cancel_orders_ix(keys)
+    }
+}
 pub fn force_cancel_orders_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ForceCancelOrdersAccounts<'_, '_>,
@@ -8302,6 +12241,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_IDLE_IX_ACCOUNTS_LEN
         }
     }
 }
+impl UpdateUserIdleKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_USER_IDLE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_USER_IDLE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UPDATE_USER_IDLE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateUserIdleKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateUserIdleAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_USER_IDLE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_USER_IDLE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UPDATE_USER_IDLE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateUserIdleAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_USER_IDLE_IX_DISCM: [u8; 8] = [253, 133, 67, 22, 103, 161, 20, 100];
 #[derive(Clone, Debug, PartialEq)]
 pub struct UpdateUserIdleIxData;
@@ -8344,6 +12321,66 @@ pub fn update_user_idle_ix_with_program_id(
 pub fn update_user_idle_ix(keys: UpdateUserIdleKeys) -> std::io::Result<Instruction> {
     update_user_idle_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`update_user_idle_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateUserIdleIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    filler: Option<Pubkey>,
+    user: Option<Pubkey>,
+}
+impl Default for UpdateUserIdleIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateUserIdleIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            filler: None,
+            user: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn filler(mut self, filler: Pubkey) -> Self {
+        self.filler = Some(filler);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateUserIdleIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let filler = self.filler.ok_or_else(|| missing("filler"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let keys = UpdateUserIdleKeys {
+            state,
+            authority,
+            filler,
+            user,
+        };
+        update_user_idle_ix(keys)
+    }
+}
 pub fn update_user_idle_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateUserIdleAccounts<'_, '_>,
@@ -8502,6 +12539,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_OPEN_ORDERS_COUNT_IX
         }
     }
 }
+impl UpdateUserOpenOrdersCountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_USER_OPEN_ORDERS_COUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_USER_OPEN_ORDERS_COUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_USER_OPEN_ORDERS_COUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateUserOpenOrdersCountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateUserOpenOrdersCountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_USER_OPEN_ORDERS_COUNT_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_USER_OPEN_ORDERS_COUNT_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_USER_OPEN_ORDERS_COUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateUserOpenOrdersCountAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_USER_OPEN_ORDERS_COUNT_IX_DISCM: [u8; 8] = [104, 39, 65, 210, 250, 163, 100, 134];
 #[derive(Clone, Debug, PartialEq)]
 pub struct UpdateUserOpenOrdersCountIxData;
@@ -8546,6 +12630,66 @@ pub fn update_user_open_orders_count_ix(
 ) -> std::io::Result<Instruction> {
     update_user_open_orders_count_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`update_user_open_orders_count_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateUserOpenOrdersCountIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    filler: Option<Pubkey>,
+    user: Option<Pubkey>,
+}
+impl Default for UpdateUserOpenOrdersCountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateUserOpenOrdersCountIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            filler: None,
+            user: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn filler(mut self, filler: Pubkey) -> Self {
+        self.filler = Some(filler);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateUserOpenOrdersCountIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let filler = self.filler.ok_or_else(|| missing("filler"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let keys = UpdateUserOpenOrdersCountKeys {
+            state,
+            authority,
+            filler,
+            user,
+        };
+        update_user_open_orders_count_ix(keys)
+    }
+}
 pub fn update_user_open_orders_count_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateUserOpenOrdersCountAccounts<'_, '_>,
@@ -8702,6 +12846,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_PNL_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl SettlePnlKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SETTLE_PNL_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SETTLE_PNL_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SETTLE_PNL_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SettlePnlKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SettlePnlAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SETTLE_PNL_IX_ACCOUNTS_LEN] = accounts
+            .get(..SETTLE_PNL_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SETTLE_PNL_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SettlePnlAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SETTLE_PNL_IX_DISCM: [u8; 8] = [43, 61, 234, 45, 15, 95, 152, 153];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -8757,6 +12939,77 @@ pub fn settle_pnl_ix_with_program_id(
 pub fn settle_pnl_ix(keys: SettlePnlKeys, args: SettlePnlIxArgs) -> std::io::Result<Instruction> {
     settle_pnl_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`settle_pnl_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SettlePnlIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    spot_market_vault: Option<Pubkey>,
+    market_index: Option<u16>,
+}
+impl Default for SettlePnlIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SettlePnlIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            authority: None,
+            spot_market_vault: None,
+            market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn spot_market_vault(mut self, spot_market_vault: Pubkey) -> Self {
+        self.spot_market_vault = Some(spot_market_vault);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SettlePnlIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let spot_market_vault = self
+            .spot_market_vault
+            .ok_or_else(|| missing("spot_market_vault"))?;
+        let args = SettlePnlIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = SettlePnlKeys {
+            state,
+            user,
+            authority,
+            spot_market_vault,
+        };
+        settle_pnl_ix(keys, args)
+    }
+}
 pub fn settle_pnl_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SettlePnlAccounts<'_, '_>,
@@ -8892,6 +13145,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_FUNDING_PAYMENT_IX_ACCOUN
         }
     }
 }
+impl SettleFundingPaymentKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SETTLE_FUNDING_PAYMENT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SETTLE_FUNDING_PAYMENT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[SETTLE_FUNDING_PAYMENT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for SettleFundingPaymentKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SettleFundingPaymentAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SETTLE_FUNDING_PAYMENT_IX_ACCOUNTS_LEN] = accounts
+            .get(..SETTLE_FUNDING_PAYMENT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[SETTLE_FUNDING_PAYMENT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SettleFundingPaymentAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SETTLE_FUNDING_PAYMENT_IX_DISCM: [u8; 8] = [222, 90, 202, 94, 28, 45, 115, 183];
 #[derive(Clone, Debug, PartialEq)]
 pub struct SettleFundingPaymentIxData;
@@ -8934,6 +13231,47 @@ pub fn settle_funding_payment_ix_with_program_id(
 pub fn settle_funding_payment_ix(keys: SettleFundingPaymentKeys) -> std::io::Result<Instruction> {
     settle_funding_payment_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`settle_funding_payment_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SettleFundingPaymentIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+}
+impl Default for SettleFundingPaymentIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SettleFundingPaymentIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SettleFundingPaymentIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let keys = SettleFundingPaymentKeys { state, user };
+        settle_funding_payment_ix(keys)
+    }
+}
 pub fn settle_funding_payment_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SettleFundingPaymentAccounts<'_, '_>,
@@ -9050,6 +13388,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_LP_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl SettleLpKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SETTLE_LP_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SETTLE_LP_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SETTLE_LP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SettleLpKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SettleLpAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SETTLE_LP_IX_ACCOUNTS_LEN] = accounts
+            .get(..SETTLE_LP_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SETTLE_LP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SettleLpAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SETTLE_LP_IX_DISCM: [u8; 8] = [155, 231, 116, 113, 97, 229, 139, 141];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -9105,6 +13481,56 @@ pub fn settle_lp_ix_with_program_id(
 pub fn settle_lp_ix(keys: SettleLpKeys, args: SettleLpIxArgs) -> std::io::Result<Instruction> {
     settle_lp_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`settle_lp_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SettleLpIxBuilder {
+    state: Option<Pubkey>,
+    user: Option<Pubkey>,
+    market_index: Option<u16>,
+}
+impl Default for SettleLpIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SettleLpIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            user: None,
+            market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SettleLpIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let args = SettleLpIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = SettleLpKeys { state, user };
+        settle_lp_ix(keys, args)
+    }
+}
 pub fn settle_lp_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SettleLpAccounts<'_, '_>,
@@ -9224,6 +13650,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_EXPIRED_MARKET_IX_ACCOUNT
         }
     }
 }
+impl SettleExpiredMarketKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SETTLE_EXPIRED_MARKET_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SETTLE_EXPIRED_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[SETTLE_EXPIRED_MARKET_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for SettleExpiredMarketKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SettleExpiredMarketAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SETTLE_EXPIRED_MARKET_IX_ACCOUNTS_LEN] = accounts
+            .get(..SETTLE_EXPIRED_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[SETTLE_EXPIRED_MARKET_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SettleExpiredMarketAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SETTLE_EXPIRED_MARKET_IX_DISCM: [u8; 8] = [120, 89, 11, 25, 122, 77, 72, 193];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -9282,6 +13752,56 @@ pub fn settle_expired_market_ix(
 ) -> std::io::Result<Instruction> {
     settle_expired_market_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`settle_expired_market_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SettleExpiredMarketIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    market_index: Option<u16>,
+}
+impl Default for SettleExpiredMarketIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SettleExpiredMarketIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SettleExpiredMarketIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = SettleExpiredMarketIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = SettleExpiredMarketKeys { state, authority };
+        settle_expired_market_ix(keys, args)
+    }
+}
 pub fn settle_expired_market_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SettleExpiredMarketAccounts<'_, '_>,
@@ -9451,6 +13971,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_PERP_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl LiquidatePerpKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; LIQUIDATE_PERP_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..LIQUIDATE_PERP_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[LIQUIDATE_PERP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for LiquidatePerpKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> LiquidatePerpAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; LIQUIDATE_PERP_IX_ACCOUNTS_LEN] = accounts
+            .get(..LIQUIDATE_PERP_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[LIQUIDATE_PERP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for LiquidatePerpAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const LIQUIDATE_PERP_IX_DISCM: [u8; 8] = [75, 35, 119, 247, 191, 18, 139, 2];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -9511,6 +14069,112 @@ pub fn liquidate_perp_ix(
 ) -> std::io::Result<Instruction> {
     liquidate_perp_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`liquidate_perp_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct LiquidatePerpIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    liquidator: Option<Pubkey>,
+    liquidator_stats: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    market_index: Option<u16>,
+    liquidator_max_base_asset_amount: Option<u64>,
+    limit_price: Option<Option<u64>>,
+}
+impl Default for LiquidatePerpIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl LiquidatePerpIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            liquidator: None,
+            liquidator_stats: None,
+            user: None,
+            user_stats: None,
+            market_index: None,
+            liquidator_max_base_asset_amount: None,
+            limit_price: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn liquidator(mut self, liquidator: Pubkey) -> Self {
+        self.liquidator = Some(liquidator);
+        self
+    }
+    pub fn liquidator_stats(mut self, liquidator_stats: Pubkey) -> Self {
+        self.liquidator_stats = Some(liquidator_stats);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    pub fn liquidator_max_base_asset_amount(
+        mut self,
+        liquidator_max_base_asset_amount: u64,
+    ) -> Self {
+        self.liquidator_max_base_asset_amount = Some(liquidator_max_base_asset_amount);
+        self
+    }
+    pub fn limit_price(mut self, limit_price: Option<u64>) -> Self {
+        self.limit_price = Some(limit_price);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("LiquidatePerpIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let liquidator = self.liquidator.ok_or_else(|| missing("liquidator"))?;
+        let liquidator_stats = self
+            .liquidator_stats
+            .ok_or_else(|| missing("liquidator_stats"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let args = LiquidatePerpIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+            liquidator_max_base_asset_amount: self
+                .liquidator_max_base_asset_amount
+                .ok_or_else(|| missing("liquidator_max_base_asset_amount"))?,
+            limit_price: self.limit_price.ok_or_else(|| missing("limit_price"))?,
+        };
+        let keys = LiquidatePerpKeys {
+            state,
+            authority,
+            liquidator,
+            liquidator_stats,
+            user,
+            user_stats,
+        };
+        liquidate_perp_ix(keys, args)
+    }
+}
 pub fn liquidate_perp_invoke_with_program_id(
     program_id: Pubkey,
     accounts: LiquidatePerpAccounts<'_, '_>,
@@ -9700,6 +14364,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_SPOT_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl LiquidateSpotKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; LIQUIDATE_SPOT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..LIQUIDATE_SPOT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[LIQUIDATE_SPOT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for LiquidateSpotKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> LiquidateSpotAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; LIQUIDATE_SPOT_IX_ACCOUNTS_LEN] = accounts
+            .get(..LIQUIDATE_SPOT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[LIQUIDATE_SPOT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for LiquidateSpotAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const LIQUIDATE_SPOT_IX_DISCM: [u8; 8] = [107, 0, 128, 41, 35, 229, 251, 18];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -9761,6 +14463,123 @@ pub fn liquidate_spot_ix(
 ) -> std::io::Result<Instruction> {
     liquidate_spot_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`liquidate_spot_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct LiquidateSpotIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    liquidator: Option<Pubkey>,
+    liquidator_stats: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    asset_market_index: Option<u16>,
+    liability_market_index: Option<u16>,
+    liquidator_max_liability_transfer: Option<u128>,
+    limit_price: Option<Option<u64>>,
+}
+impl Default for LiquidateSpotIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl LiquidateSpotIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            liquidator: None,
+            liquidator_stats: None,
+            user: None,
+            user_stats: None,
+            asset_market_index: None,
+            liability_market_index: None,
+            liquidator_max_liability_transfer: None,
+            limit_price: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn liquidator(mut self, liquidator: Pubkey) -> Self {
+        self.liquidator = Some(liquidator);
+        self
+    }
+    pub fn liquidator_stats(mut self, liquidator_stats: Pubkey) -> Self {
+        self.liquidator_stats = Some(liquidator_stats);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn asset_market_index(mut self, asset_market_index: u16) -> Self {
+        self.asset_market_index = Some(asset_market_index);
+        self
+    }
+    pub fn liability_market_index(mut self, liability_market_index: u16) -> Self {
+        self.liability_market_index = Some(liability_market_index);
+        self
+    }
+    pub fn liquidator_max_liability_transfer(
+        mut self,
+        liquidator_max_liability_transfer: u128,
+    ) -> Self {
+        self.liquidator_max_liability_transfer = Some(liquidator_max_liability_transfer);
+        self
+    }
+    pub fn limit_price(mut self, limit_price: Option<u64>) -> Self {
+        self.limit_price = Some(limit_price);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("LiquidateSpotIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let liquidator = self.liquidator.ok_or_else(|| missing("liquidator"))?;
+        let liquidator_stats = self
+            .liquidator_stats
+            .ok_or_else(|| missing("liquidator_stats"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let args = LiquidateSpotIxArgs {
+            asset_market_index: self
+                .asset_market_index
+                .ok_or_else(|| missing("asset_market_index"))?,
+            liability_market_index: self
+                .liability_market_index
+                .ok_or_else(|| missing("liability_market_index"))?,
+            liquidator_max_liability_transfer: self
+                .liquidator_max_liability_transfer
+                .ok_or_else(|| missing("liquidator_max_liability_transfer"))?,
+            limit_price: self.limit_price.ok_or_else(|| missing("limit_price"))?,
+        };
+        let keys = LiquidateSpotKeys {
+            state,
+            authority,
+            liquidator,
+            liquidator_stats,
+            user,
+            user_stats,
+        };
+        liquidate_spot_ix(keys, args)
+    }
+}
 pub fn liquidate_spot_invoke_with_program_id(
     program_id: Pubkey,
     accounts: LiquidateSpotAccounts<'_, '_>,
@@ -9954,6 +14773,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_BORROW_FOR_PERP_PNL_IX
         }
     }
 }
+impl LiquidateBorrowForPerpPnlKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; LIQUIDATE_BORROW_FOR_PERP_PNL_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..LIQUIDATE_BORROW_FOR_PERP_PNL_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[LIQUIDATE_BORROW_FOR_PERP_PNL_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for LiquidateBorrowForPerpPnlKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> LiquidateBorrowForPerpPnlAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; LIQUIDATE_BORROW_FOR_PERP_PNL_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..LIQUIDATE_BORROW_FOR_PERP_PNL_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[LIQUIDATE_BORROW_FOR_PERP_PNL_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for LiquidateBorrowForPerpPnlAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const LIQUIDATE_BORROW_FOR_PERP_PNL_IX_DISCM: [u8; 8] = [169, 17, 32, 90, 207, 148, 209, 27];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -10017,6 +14883,123 @@ pub fn liquidate_borrow_for_perp_pnl_ix(
 ) -> std::io::Result<Instruction> {
     liquidate_borrow_for_perp_pnl_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`liquidate_borrow_for_perp_pnl_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct LiquidateBorrowForPerpPnlIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    liquidator: Option<Pubkey>,
+    liquidator_stats: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    perp_market_index: Option<u16>,
+    spot_market_index: Option<u16>,
+    liquidator_max_liability_transfer: Option<u128>,
+    limit_price: Option<Option<u64>>,
+}
+impl Default for LiquidateBorrowForPerpPnlIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl LiquidateBorrowForPerpPnlIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            liquidator: None,
+            liquidator_stats: None,
+            user: None,
+            user_stats: None,
+            perp_market_index: None,
+            spot_market_index: None,
+            liquidator_max_liability_transfer: None,
+            limit_price: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn liquidator(mut self, liquidator: Pubkey) -> Self {
+        self.liquidator = Some(liquidator);
+        self
+    }
+    pub fn liquidator_stats(mut self, liquidator_stats: Pubkey) -> Self {
+        self.liquidator_stats = Some(liquidator_stats);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn perp_market_index(mut self, perp_market_index: u16) -> Self {
+        self.perp_market_index = Some(perp_market_index);
+        self
+    }
+    pub fn spot_market_index(mut self, spot_market_index: u16) -> Self {
+        self.spot_market_index = Some(spot_market_index);
+        self
+    }
+    pub fn liquidator_max_liability_transfer(
+        mut self,
+        liquidator_max_liability_transfer: u128,
+    ) -> Self {
+        self.liquidator_max_liability_transfer = Some(liquidator_max_liability_transfer);
+        self
+    }
+    pub fn limit_price(mut self, limit_price: Option<u64>) -> Self {
+        self.limit_price = Some(limit_price);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("LiquidateBorrowForPerpPnlIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let liquidator = self.liquidator.ok_or_else(|| missing("liquidator"))?;
+        let liquidator_stats = self
+            .liquidator_stats
+            .ok_or_else(|| missing("liquidator_stats"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let args = LiquidateBorrowForPerpPnlIxArgs {
+            perp_market_index: self
+                .perp_market_index
+                .ok_or_else(|| missing("perp_market_index"))?,
+            spot_market_index: self
+                .spot_market_index
+                .ok_or_else(|| missing("spot_market_index"))?,
+            liquidator_max_liability_transfer: self
+                .liquidator_max_liability_transfer
+                .ok_or_else(|| missing("liquidator_max_liability_transfer"))?,
+            limit_price: self.limit_price.ok_or_else(|| missing("limit_price"))?,
+        };
+        let keys = LiquidateBorrowForPerpPnlKeys {
+            state,
+            authority,
+            liquidator,
+            liquidator_stats,
+            user,
+            user_stats,
+        };
+        liquidate_borrow_for_perp_pnl_ix(keys, args)
+    }
+}
 pub fn liquidate_borrow_for_perp_pnl_invoke_with_program_id(
     program_id: Pubkey,
     accounts: LiquidateBorrowForPerpPnlAccounts<'_, '_>,
@@ -10212,6 +15195,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_PERP_PNL_FOR_DEPOSIT_I
         }
     }
 }
+impl LiquidatePerpPnlForDepositKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for LiquidatePerpPnlForDepositKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> LiquidatePerpPnlForDepositAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for LiquidatePerpPnlForDepositAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_DISCM: [u8; 8] = [237, 75, 198, 235, 233, 186, 75, 35];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -10275,6 +15305,120 @@ pub fn liquidate_perp_pnl_for_deposit_ix(
 ) -> std::io::Result<Instruction> {
     liquidate_perp_pnl_for_deposit_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`liquidate_perp_pnl_for_deposit_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct LiquidatePerpPnlForDepositIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    liquidator: Option<Pubkey>,
+    liquidator_stats: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    perp_market_index: Option<u16>,
+    spot_market_index: Option<u16>,
+    liquidator_max_pnl_transfer: Option<u128>,
+    limit_price: Option<Option<u64>>,
+}
+impl Default for LiquidatePerpPnlForDepositIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl LiquidatePerpPnlForDepositIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            liquidator: None,
+            liquidator_stats: None,
+            user: None,
+            user_stats: None,
+            perp_market_index: None,
+            spot_market_index: None,
+            liquidator_max_pnl_transfer: None,
+            limit_price: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn liquidator(mut self, liquidator: Pubkey) -> Self {
+        self.liquidator = Some(liquidator);
+        self
+    }
+    pub fn liquidator_stats(mut self, liquidator_stats: Pubkey) -> Self {
+        self.liquidator_stats = Some(liquidator_stats);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn perp_market_index(mut self, perp_market_index: u16) -> Self {
+        self.perp_market_index = Some(perp_market_index);
+        self
+    }
+    pub fn spot_market_index(mut self, spot_market_index: u16) -> Self {
+        self.spot_market_index = Some(spot_market_index);
+        self
+    }
+    pub fn liquidator_max_pnl_transfer(mut self, liquidator_max_pnl_transfer: u128) -> Self {
+        self.liquidator_max_pnl_transfer = Some(liquidator_max_pnl_transfer);
+        self
+    }
+    pub fn limit_price(mut self, limit_price: Option<u64>) -> Self {
+        self.limit_price = Some(limit_price);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("LiquidatePerpPnlForDepositIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let liquidator = self.liquidator.ok_or_else(|| missing("liquidator"))?;
+        let liquidator_stats = self
+            .liquidator_stats
+            .ok_or_else(|| missing("liquidator_stats"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let args = LiquidatePerpPnlForDepositIxArgs {
+            perp_market_index: self
+                .perp_market_index
+                .ok_or_else(|| missing("perp_market_index"))?,
+            spot_market_index: self
+                .spot_market_index
+                .ok_or_else(|| missing("spot_market_index"))?,
+            liquidator_max_pnl_transfer: self
+                .liquidator_max_pnl_transfer
+                .ok_or_else(|| missing("liquidator_max_pnl_transfer"))?,
+            limit_price: self.limit_price.ok_or_else(|| missing("limit_price"))?,
+        };
+        let keys = LiquidatePerpPnlForDepositKeys {
+            state,
+            authority,
+            liquidator,
+            liquidator_stats,
+            user,
+            user_stats,
+        };
+        liquidate_perp_pnl_for_deposit_ix(keys, args)
+    }
+}
 pub fn liquidate_perp_pnl_for_deposit_invoke_with_program_id(
     program_id: Pubkey,
     accounts: LiquidatePerpPnlForDepositAccounts<'_, '_>,
@@ -10464,6 +15608,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; RESOLVE_PERP_PNL_DEFICIT_IX_ACCO
         }
     }
 }
+impl ResolvePerpPnlDeficitKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ResolvePerpPnlDeficitKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ResolvePerpPnlDeficitAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN] = accounts
+            .get(..RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ResolvePerpPnlDeficitAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const RESOLVE_PERP_PNL_DEFICIT_IX_DISCM: [u8; 8] = [168, 204, 68, 150, 159, 126, 95, 148];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -10523,6 +15711,108 @@ pub fn resolve_perp_pnl_deficit_ix(
 ) -> std::io::Result<Instruction> {
     resolve_perp_pnl_deficit_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`resolve_perp_pnl_deficit_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ResolvePerpPnlDeficitIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    spot_market_vault: Option<Pubkey>,
+    insurance_fund_vault: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    spot_market_index: Option<u16>,
+    perp_market_index: Option<u16>,
+}
+impl Default for ResolvePerpPnlDeficitIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ResolvePerpPnlDeficitIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            spot_market_vault: None,
+            insurance_fund_vault: None,
+            drift_signer: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            spot_market_index: None,
+            perp_market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn spot_market_vault(mut self, spot_market_vault: Pubkey) -> Self {
+        self.spot_market_vault = Some(spot_market_vault);
+        self
+    }
+    pub fn insurance_fund_vault(mut self, insurance_fund_vault: Pubkey) -> Self {
+        self.insurance_fund_vault = Some(insurance_fund_vault);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn spot_market_index(mut self, spot_market_index: u16) -> Self {
+        self.spot_market_index = Some(spot_market_index);
+        self
+    }
+    pub fn perp_market_index(mut self, perp_market_index: u16) -> Self {
+        self.perp_market_index = Some(perp_market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ResolvePerpPnlDeficitIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let spot_market_vault = self
+            .spot_market_vault
+            .ok_or_else(|| missing("spot_market_vault"))?;
+        let insurance_fund_vault = self
+            .insurance_fund_vault
+            .ok_or_else(|| missing("insurance_fund_vault"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = ResolvePerpPnlDeficitIxArgs {
+            spot_market_index: self
+                .spot_market_index
+                .ok_or_else(|| missing("spot_market_index"))?,
+            perp_market_index: self
+                .perp_market_index
+                .ok_or_else(|| missing("perp_market_index"))?,
+        };
+        let keys = ResolvePerpPnlDeficitKeys {
+            state,
+            authority,
+            spot_market_vault,
+            insurance_fund_vault,
+            drift_signer,
+            token_program,
+        };
+        resolve_perp_pnl_deficit_ix(keys, args)
+    }
+}
 pub fn resolve_perp_pnl_deficit_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ResolvePerpPnlDeficitAccounts<'_, '_>,
@@ -10754,6 +16044,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; RESOLVE_PERP_BANKRUPTCY_IX_ACCOU
         }
     }
 }
+impl ResolvePerpBankruptcyKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; RESOLVE_PERP_BANKRUPTCY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..RESOLVE_PERP_BANKRUPTCY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[RESOLVE_PERP_BANKRUPTCY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ResolvePerpBankruptcyKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ResolvePerpBankruptcyAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; RESOLVE_PERP_BANKRUPTCY_IX_ACCOUNTS_LEN] = accounts
+            .get(..RESOLVE_PERP_BANKRUPTCY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[RESOLVE_PERP_BANKRUPTCY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ResolvePerpBankruptcyAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const RESOLVE_PERP_BANKRUPTCY_IX_DISCM: [u8; 8] = [224, 16, 176, 214, 162, 213, 183, 222];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -10813,6 +16147,140 @@ pub fn resolve_perp_bankruptcy_ix(
 ) -> std::io::Result<Instruction> {
     resolve_perp_bankruptcy_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`resolve_perp_bankruptcy_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ResolvePerpBankruptcyIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    liquidator: Option<Pubkey>,
+    liquidator_stats: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    spot_market_vault: Option<Pubkey>,
+    insurance_fund_vault: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    quote_spot_market_index: Option<u16>,
+    market_index: Option<u16>,
+}
+impl Default for ResolvePerpBankruptcyIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ResolvePerpBankruptcyIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            liquidator: None,
+            liquidator_stats: None,
+            user: None,
+            user_stats: None,
+            spot_market_vault: None,
+            insurance_fund_vault: None,
+            drift_signer: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            quote_spot_market_index: None,
+            market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn liquidator(mut self, liquidator: Pubkey) -> Self {
+        self.liquidator = Some(liquidator);
+        self
+    }
+    pub fn liquidator_stats(mut self, liquidator_stats: Pubkey) -> Self {
+        self.liquidator_stats = Some(liquidator_stats);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn spot_market_vault(mut self, spot_market_vault: Pubkey) -> Self {
+        self.spot_market_vault = Some(spot_market_vault);
+        self
+    }
+    pub fn insurance_fund_vault(mut self, insurance_fund_vault: Pubkey) -> Self {
+        self.insurance_fund_vault = Some(insurance_fund_vault);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn quote_spot_market_index(mut self, quote_spot_market_index: u16) -> Self {
+        self.quote_spot_market_index = Some(quote_spot_market_index);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ResolvePerpBankruptcyIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let liquidator = self.liquidator.ok_or_else(|| missing("liquidator"))?;
+        let liquidator_stats = self
+            .liquidator_stats
+            .ok_or_else(|| missing("liquidator_stats"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let spot_market_vault = self
+            .spot_market_vault
+            .ok_or_else(|| missing("spot_market_vault"))?;
+        let insurance_fund_vault = self
+            .insurance_fund_vault
+            .ok_or_else(|| missing("insurance_fund_vault"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = ResolvePerpBankruptcyIxArgs {
+            quote_spot_market_index: self
+                .quote_spot_market_index
+                .ok_or_else(|| missing("quote_spot_market_index"))?,
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = ResolvePerpBankruptcyKeys {
+            state,
+            authority,
+            liquidator,
+            liquidator_stats,
+            user,
+            user_stats,
+            spot_market_vault,
+            insurance_fund_vault,
+            drift_signer,
+            token_program,
+        };
+        resolve_perp_bankruptcy_ix(keys, args)
+    }
+}
 pub fn resolve_perp_bankruptcy_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ResolvePerpBankruptcyAccounts<'_, '_>,
@@ -11055,8 +16523,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; RESOLVE_SPOT_BANKRUPTCY_IX_ACCOU
         }
     }
 }
-pub const RESOLVE_SPOT_BANKRUPTCY_IX_DISCM: [u8; 8] = [124, 194, 240, 254, 198, 213, 52, 122];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+impl ResolveSpotBankruptcyKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; RESOLVE_SPOT_BANKRUPTCY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..RESOLVE_SPOT_BANKRUPTCY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[RESOLVE_SPOT_BANKRUPTCY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ResolveSpotBankruptcyKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ResolveSpotBankruptcyAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; RESOLVE_SPOT_BANKRUPTCY_IX_ACCOUNTS_LEN] = accounts
+            .get(..RESOLVE_SPOT_BANKRUPTCY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[RESOLVE_SPOT_BANKRUPTCY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ResolveSpotBankruptcyAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const RESOLVE_SPOT_BANKRUPTCY_IX_DISCM: [u8; 8] = [124, 194, 240, 254, 198, 213, 52, 122];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResolveSpotBankruptcyIxArgs {
     pub market_index: u16,
@@ -11113,6 +16625,131 @@ pub fn resolve_spot_bankruptcy_ix(
 ) -> std::io::Result<Instruction> {
     resolve_spot_bankruptcy_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`resolve_spot_bankruptcy_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ResolveSpotBankruptcyIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    liquidator: Option<Pubkey>,
+    liquidator_stats: Option<Pubkey>,
+    user: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    spot_market_vault: Option<Pubkey>,
+    insurance_fund_vault: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    market_index: Option<u16>,
+}
+impl Default for ResolveSpotBankruptcyIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ResolveSpotBankruptcyIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            liquidator: None,
+            liquidator_stats: None,
+            user: None,
+            user_stats: None,
+            spot_market_vault: None,
+            insurance_fund_vault: None,
+            drift_signer: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn liquidator(mut self, liquidator: Pubkey) -> Self {
+        self.liquidator = Some(liquidator);
+        self
+    }
+    pub fn liquidator_stats(mut self, liquidator_stats: Pubkey) -> Self {
+        self.liquidator_stats = Some(liquidator_stats);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn spot_market_vault(mut self, spot_market_vault: Pubkey) -> Self {
+        self.spot_market_vault = Some(spot_market_vault);
+        self
+    }
+    pub fn insurance_fund_vault(mut self, insurance_fund_vault: Pubkey) -> Self {
+        self.insurance_fund_vault = Some(insurance_fund_vault);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ResolveSpotBankruptcyIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let liquidator = self.liquidator.ok_or_else(|| missing("liquidator"))?;
+        let liquidator_stats = self
+            .liquidator_stats
+            .ok_or_else(|| missing("liquidator_stats"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let spot_market_vault = self
+            .spot_market_vault
+            .ok_or_else(|| missing("spot_market_vault"))?;
+        let insurance_fund_vault = self
+            .insurance_fund_vault
+            .ok_or_else(|| missing("insurance_fund_vault"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = ResolveSpotBankruptcyIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = ResolveSpotBankruptcyKeys {
+            state,
+            authority,
+            liquidator,
+            liquidator_stats,
+            user,
+            user_stats,
+            spot_market_vault,
+            insurance_fund_vault,
+            drift_signer,
+            token_program,
+        };
+        resolve_spot_bankruptcy_ix(keys, args)
+    }
+}
 pub fn resolve_spot_bankruptcy_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ResolveSpotBankruptcyAccounts<'_, '_>,
@@ -11317,6 +16954,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_REVENUE_TO_INSURANCE_FUND
         }
     }
 }
+impl SettleRevenueToInsuranceFundKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SETTLE_REVENUE_TO_INSURANCE_FUND_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SETTLE_REVENUE_TO_INSURANCE_FUND_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[SETTLE_REVENUE_TO_INSURANCE_FUND_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for SettleRevenueToInsuranceFundKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SettleRevenueToInsuranceFundAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SETTLE_REVENUE_TO_INSURANCE_FUND_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..SETTLE_REVENUE_TO_INSURANCE_FUND_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[SETTLE_REVENUE_TO_INSURANCE_FUND_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for SettleRevenueToInsuranceFundAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SETTLE_REVENUE_TO_INSURANCE_FUND_IX_DISCM: [u8; 8] =
     [200, 120, 93, 136, 69, 38, 199, 159];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -11378,6 +17062,99 @@ pub fn settle_revenue_to_insurance_fund_ix(
 ) -> std::io::Result<Instruction> {
     settle_revenue_to_insurance_fund_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`settle_revenue_to_insurance_fund_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SettleRevenueToInsuranceFundIxBuilder {
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    spot_market_vault: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    insurance_fund_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    spot_market_index: Option<u16>,
+}
+impl Default for SettleRevenueToInsuranceFundIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SettleRevenueToInsuranceFundIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            spot_market: None,
+            spot_market_vault: None,
+            drift_signer: None,
+            insurance_fund_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            spot_market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn spot_market_vault(mut self, spot_market_vault: Pubkey) -> Self {
+        self.spot_market_vault = Some(spot_market_vault);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn insurance_fund_vault(mut self, insurance_fund_vault: Pubkey) -> Self {
+        self.insurance_fund_vault = Some(insurance_fund_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn spot_market_index(mut self, spot_market_index: u16) -> Self {
+        self.spot_market_index = Some(spot_market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SettleRevenueToInsuranceFundIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let spot_market_vault = self
+            .spot_market_vault
+            .ok_or_else(|| missing("spot_market_vault"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let insurance_fund_vault = self
+            .insurance_fund_vault
+            .ok_or_else(|| missing("insurance_fund_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = SettleRevenueToInsuranceFundIxArgs {
+            spot_market_index: self
+                .spot_market_index
+                .ok_or_else(|| missing("spot_market_index"))?,
+        };
+        let keys = SettleRevenueToInsuranceFundKeys {
+            state,
+            spot_market,
+            spot_market_vault,
+            drift_signer,
+            insurance_fund_vault,
+            token_program,
+        };
+        settle_revenue_to_insurance_fund_ix(keys, args)
+    }
+}
 pub fn settle_revenue_to_insurance_fund_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SettleRevenueToInsuranceFundAccounts<'_, '_>,
@@ -11525,6 +17302,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_FUNDING_RATE_IX_ACCOUNTS_
         }
     }
 }
+impl UpdateFundingRateKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_FUNDING_RATE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_FUNDING_RATE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UPDATE_FUNDING_RATE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateFundingRateKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateFundingRateAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_FUNDING_RATE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_FUNDING_RATE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UPDATE_FUNDING_RATE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateFundingRateAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_FUNDING_RATE_IX_DISCM: [u8; 8] = [201, 178, 116, 212, 166, 144, 72, 238];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -11583,6 +17398,67 @@ pub fn update_funding_rate_ix(
 ) -> std::io::Result<Instruction> {
     update_funding_rate_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_funding_rate_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateFundingRateIxBuilder {
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    oracle: Option<Pubkey>,
+    market_index: Option<u16>,
+}
+impl Default for UpdateFundingRateIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateFundingRateIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            perp_market: None,
+            oracle: None,
+            market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn oracle(mut self, oracle: Pubkey) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateFundingRateIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let oracle = self.oracle.ok_or_else(|| missing("oracle"))?;
+        let args = UpdateFundingRateIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = UpdateFundingRateKeys {
+            state,
+            perp_market,
+            oracle,
+        };
+        update_funding_rate_ix(keys, args)
+    }
+}
 pub fn update_funding_rate_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateFundingRateAccounts<'_, '_>,
@@ -11729,6 +17605,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdateSpotMarketCumulativeInterestKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketCumulativeInterestKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketCumulativeInterestAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotMarketCumulativeInterestAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_DISCM: [u8; 8] =
     [39, 166, 139, 243, 158, 165, 155, 225];
 #[derive(Clone, Debug, PartialEq)]
@@ -11774,6 +17697,61 @@ pub fn update_spot_market_cumulative_interest_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_cumulative_interest_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`update_spot_market_cumulative_interest_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketCumulativeInterestIxBuilder {
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    oracle: Option<Pubkey>,
+}
+impl Default for UpdateSpotMarketCumulativeInterestIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketCumulativeInterestIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            spot_market: None,
+            oracle: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn oracle(mut self, oracle: Pubkey) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateSpotMarketCumulativeInterestIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let oracle = self.oracle.ok_or_else(|| missing("oracle"))?;
+        let keys = UpdateSpotMarketCumulativeInterestKeys {
+            state,
+            spot_market,
+            oracle,
+        };
+        update_spot_market_cumulative_interest_ix(keys)
+    }
+}
 pub fn update_spot_market_cumulative_interest_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketCumulativeInterestAccounts<'_, '_>,
@@ -11893,6 +17871,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_AMMS_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl UpdateAmmsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_AMMS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_AMMS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UPDATE_AMMS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateAmmsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateAmmsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_AMMS_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_AMMS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UPDATE_AMMS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateAmmsAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_AMMS_IX_DISCM: [u8; 8] = [201, 106, 217, 253, 4, 175, 228, 97];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -11951,6 +17967,58 @@ pub fn update_amms_ix(
 ) -> std::io::Result<Instruction> {
     update_amms_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_amms_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateAmmsIxBuilder {
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    market_indexes: Option<[u16; 5]>,
+}
+impl Default for UpdateAmmsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateAmmsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            authority: None,
+            market_indexes: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn market_indexes(mut self, market_indexes: [u16; 5]) -> Self {
+        self.market_indexes = Some(market_indexes);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateAmmsIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = UpdateAmmsIxArgs {
+            market_indexes: self
+                .market_indexes
+                .ok_or_else(|| missing("market_indexes"))?,
+        };
+        let keys = UpdateAmmsKeys { state, authority };
+        update_amms_ix(keys, args)
+    }
+}
 pub fn update_amms_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateAmmsAccounts<'_, '_>,
@@ -12087,6 +18155,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_EXPIRY_IX_ACC
         }
     }
 }
+impl UpdateSpotMarketExpiryKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_EXPIRY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_EXPIRY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_EXPIRY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketExpiryKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketExpiryAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_EXPIRY_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_SPOT_MARKET_EXPIRY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_EXPIRY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateSpotMarketExpiryAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_EXPIRY_IX_DISCM: [u8; 8] = [208, 11, 211, 159, 226, 24, 11, 247];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -12147,6 +18259,67 @@ pub fn update_spot_market_expiry_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_expiry_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_expiry_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketExpiryIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    expiry_ts: Option<i64>,
+}
+impl Default for UpdateSpotMarketExpiryIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketExpiryIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            expiry_ts: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn expiry_ts(mut self, expiry_ts: i64) -> Self {
+        self.expiry_ts = Some(expiry_ts);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateSpotMarketExpiryIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketExpiryIxArgs {
+            expiry_ts: self.expiry_ts.ok_or_else(|| missing("expiry_ts"))?,
+        };
+        let keys = UpdateSpotMarketExpiryKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_expiry_ix(keys, args)
+    }
+}
 pub fn update_spot_market_expiry_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketExpiryAccounts<'_, '_>,
@@ -12337,6 +18510,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdateUserQuoteAssetInsuranceStakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateUserQuoteAssetInsuranceStakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateUserQuoteAssetInsuranceStakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateUserQuoteAssetInsuranceStakeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_DISCM: [u8; 8] =
     [251, 101, 156, 7, 2, 63, 30, 23];
 #[derive(Clone, Debug, PartialEq)]
@@ -12382,6 +18602,89 @@ pub fn update_user_quote_asset_insurance_stake_ix(
 ) -> std::io::Result<Instruction> {
     update_user_quote_asset_insurance_stake_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`update_user_quote_asset_insurance_stake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateUserQuoteAssetInsuranceStakeIxBuilder {
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    insurance_fund_stake: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    insurance_fund_vault: Option<Pubkey>,
+}
+impl Default for UpdateUserQuoteAssetInsuranceStakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateUserQuoteAssetInsuranceStakeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            spot_market: None,
+            insurance_fund_stake: None,
+            user_stats: None,
+            authority: None,
+            insurance_fund_vault: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn insurance_fund_stake(mut self, insurance_fund_stake: Pubkey) -> Self {
+        self.insurance_fund_stake = Some(insurance_fund_stake);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn insurance_fund_vault(mut self, insurance_fund_vault: Pubkey) -> Self {
+        self.insurance_fund_vault = Some(insurance_fund_vault);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateUserQuoteAssetInsuranceStakeIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let insurance_fund_stake = self
+            .insurance_fund_stake
+            .ok_or_else(|| missing("insurance_fund_stake"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let insurance_fund_vault = self
+            .insurance_fund_vault
+            .ok_or_else(|| missing("insurance_fund_vault"))?;
+        let keys = UpdateUserQuoteAssetInsuranceStakeKeys {
+            state,
+            spot_market,
+            insurance_fund_stake,
+            user_stats,
+            authority,
+            insurance_fund_vault,
+        };
+        update_user_quote_asset_insurance_stake_ix(keys)
+    }
+}
 pub fn update_user_quote_asset_insurance_stake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateUserQuoteAssetInsuranceStakeAccounts<'_, '_>,
@@ -12604,6 +18907,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_INSURANCE_FUND_STAKE_
         }
     }
 }
+impl InitializeInsuranceFundStakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[INITIALIZE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeInsuranceFundStakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeInsuranceFundStakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..INITIALIZE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[INITIALIZE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for InitializeInsuranceFundStakeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INITIALIZE_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] = [187, 179, 243, 70, 248, 90, 92, 147];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -12664,6 +19014,113 @@ pub fn initialize_insurance_fund_stake_ix(
 ) -> std::io::Result<Instruction> {
     initialize_insurance_fund_stake_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`initialize_insurance_fund_stake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeInsuranceFundStakeIxBuilder {
+    spot_market: Option<Pubkey>,
+    insurance_fund_stake: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    state: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    market_index: Option<u16>,
+}
+impl Default for InitializeInsuranceFundStakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeInsuranceFundStakeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            spot_market: None,
+            insurance_fund_stake: None,
+            user_stats: None,
+            state: None,
+            authority: None,
+            payer: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            market_index: None,
+        }
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn insurance_fund_stake(mut self, insurance_fund_stake: Pubkey) -> Self {
+        self.insurance_fund_stake = Some(insurance_fund_stake);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeInsuranceFundStakeIxBuilder is missing {}", field),
+            )
+        };
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let insurance_fund_stake = self
+            .insurance_fund_stake
+            .ok_or_else(|| missing("insurance_fund_stake"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = InitializeInsuranceFundStakeIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = InitializeInsuranceFundStakeKeys {
+            spot_market,
+            insurance_fund_stake,
+            user_stats,
+            state,
+            authority,
+            payer,
+            rent,
+            system_program,
+        };
+        initialize_insurance_fund_stake_ix(keys, args)
+    }
+}
 pub fn initialize_insurance_fund_stake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeInsuranceFundStakeAccounts<'_, '_>,
@@ -12901,6 +19358,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_INSURANCE_FUND_STAKE_IX_ACCO
         }
     }
 }
+impl AddInsuranceFundStakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ADD_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ADD_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[ADD_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for AddInsuranceFundStakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AddInsuranceFundStakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ADD_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = accounts
+            .get(..ADD_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[ADD_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AddInsuranceFundStakeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const ADD_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] = [251, 144, 115, 11, 222, 47, 62, 236];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -12960,6 +19461,140 @@ pub fn add_insurance_fund_stake_ix(
 ) -> std::io::Result<Instruction> {
     add_insurance_fund_stake_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`add_insurance_fund_stake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AddInsuranceFundStakeIxBuilder {
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    insurance_fund_stake: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    spot_market_vault: Option<Pubkey>,
+    insurance_fund_vault: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    user_token_account: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    market_index: Option<u16>,
+    amount: Option<u64>,
+}
+impl Default for AddInsuranceFundStakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AddInsuranceFundStakeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            spot_market: None,
+            insurance_fund_stake: None,
+            user_stats: None,
+            authority: None,
+            spot_market_vault: None,
+            insurance_fund_vault: None,
+            drift_signer: None,
+            user_token_account: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            market_index: None,
+            amount: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn insurance_fund_stake(mut self, insurance_fund_stake: Pubkey) -> Self {
+        self.insurance_fund_stake = Some(insurance_fund_stake);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn spot_market_vault(mut self, spot_market_vault: Pubkey) -> Self {
+        self.spot_market_vault = Some(spot_market_vault);
+        self
+    }
+    pub fn insurance_fund_vault(mut self, insurance_fund_vault: Pubkey) -> Self {
+        self.insurance_fund_vault = Some(insurance_fund_vault);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn user_token_account(mut self, user_token_account: Pubkey) -> Self {
+        self.user_token_account = Some(user_token_account);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AddInsuranceFundStakeIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let insurance_fund_stake = self
+            .insurance_fund_stake
+            .ok_or_else(|| missing("insurance_fund_stake"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let spot_market_vault = self
+            .spot_market_vault
+            .ok_or_else(|| missing("spot_market_vault"))?;
+        let insurance_fund_vault = self
+            .insurance_fund_vault
+            .ok_or_else(|| missing("insurance_fund_vault"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let user_token_account = self
+            .user_token_account
+            .ok_or_else(|| missing("user_token_account"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = AddInsuranceFundStakeIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+            amount: self.amount.ok_or_else(|| missing("amount"))?,
+        };
+        let keys = AddInsuranceFundStakeKeys {
+            state,
+            spot_market,
+            insurance_fund_stake,
+            user_stats,
+            authority,
+            spot_market_vault,
+            insurance_fund_vault,
+            drift_signer,
+            user_token_account,
+            token_program,
+        };
+        add_insurance_fund_stake_ix(keys, args)
+    }
+}
 pub fn add_insurance_fund_stake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AddInsuranceFundStakeAccounts<'_, '_>,
@@ -13156,6 +19791,53 @@ impl<'me, 'info>
         }
     }
 }
+impl RequestRemoveInsuranceFundStakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for RequestRemoveInsuranceFundStakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RequestRemoveInsuranceFundStakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for RequestRemoveInsuranceFundStakeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] =
     [142, 70, 204, 92, 73, 106, 180, 52];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -13218,6 +19900,97 @@ pub fn request_remove_insurance_fund_stake_ix(
 ) -> std::io::Result<Instruction> {
     request_remove_insurance_fund_stake_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`request_remove_insurance_fund_stake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RequestRemoveInsuranceFundStakeIxBuilder {
+    spot_market: Option<Pubkey>,
+    insurance_fund_stake: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    insurance_fund_vault: Option<Pubkey>,
+    market_index: Option<u16>,
+    amount: Option<u64>,
+}
+impl Default for RequestRemoveInsuranceFundStakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RequestRemoveInsuranceFundStakeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            spot_market: None,
+            insurance_fund_stake: None,
+            user_stats: None,
+            authority: None,
+            insurance_fund_vault: None,
+            market_index: None,
+            amount: None,
+        }
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn insurance_fund_stake(mut self, insurance_fund_stake: Pubkey) -> Self {
+        self.insurance_fund_stake = Some(insurance_fund_stake);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn insurance_fund_vault(mut self, insurance_fund_vault: Pubkey) -> Self {
+        self.insurance_fund_vault = Some(insurance_fund_vault);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "RequestRemoveInsuranceFundStakeIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let insurance_fund_stake = self
+            .insurance_fund_stake
+            .ok_or_else(|| missing("insurance_fund_stake"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let insurance_fund_vault = self
+            .insurance_fund_vault
+            .ok_or_else(|| missing("insurance_fund_vault"))?;
+        let args = RequestRemoveInsuranceFundStakeIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+            amount: self.amount.ok_or_else(|| missing("amount"))?,
+        };
+        let keys = RequestRemoveInsuranceFundStakeKeys {
+            spot_market,
+            insurance_fund_stake,
+            user_stats,
+            authority,
+            insurance_fund_vault,
+        };
+        request_remove_insurance_fund_stake_ix(keys, args)
+    }
+}
 pub fn request_remove_insurance_fund_stake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RequestRemoveInsuranceFundStakeAccounts<'_, '_>,
@@ -13414,6 +20187,53 @@ impl<'me, 'info>
         }
     }
 }
+impl CancelRequestRemoveInsuranceFundStakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for CancelRequestRemoveInsuranceFundStakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CancelRequestRemoveInsuranceFundStakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = accounts
+            .get(..CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for CancelRequestRemoveInsuranceFundStakeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] =
     [97, 235, 78, 62, 212, 42, 241, 127];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -13480,6 +20300,90 @@ pub fn cancel_request_remove_insurance_fund_stake_ix(
 ) -> std::io::Result<Instruction> {
     cancel_request_remove_insurance_fund_stake_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`cancel_request_remove_insurance_fund_stake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CancelRequestRemoveInsuranceFundStakeIxBuilder {
+    spot_market: Option<Pubkey>,
+    insurance_fund_stake: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    insurance_fund_vault: Option<Pubkey>,
+    market_index: Option<u16>,
+}
+impl Default for CancelRequestRemoveInsuranceFundStakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CancelRequestRemoveInsuranceFundStakeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            spot_market: None,
+            insurance_fund_stake: None,
+            user_stats: None,
+            authority: None,
+            insurance_fund_vault: None,
+            market_index: None,
+        }
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn insurance_fund_stake(mut self, insurance_fund_stake: Pubkey) -> Self {
+        self.insurance_fund_stake = Some(insurance_fund_stake);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn insurance_fund_vault(mut self, insurance_fund_vault: Pubkey) -> Self {
+        self.insurance_fund_vault = Some(insurance_fund_vault);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "CancelRequestRemoveInsuranceFundStakeIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let insurance_fund_stake = self
+            .insurance_fund_stake
+            .ok_or_else(|| missing("insurance_fund_stake"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let insurance_fund_vault = self
+            .insurance_fund_vault
+            .ok_or_else(|| missing("insurance_fund_vault"))?;
+        let args = CancelRequestRemoveInsuranceFundStakeIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = CancelRequestRemoveInsuranceFundStakeKeys {
+            spot_market,
+            insurance_fund_stake,
+            user_stats,
+            authority,
+            insurance_fund_vault,
+        };
+        cancel_request_remove_insurance_fund_stake_ix(keys, args)
+    }
+}
 pub fn cancel_request_remove_insurance_fund_stake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelRequestRemoveInsuranceFundStakeAccounts<'_, '_>,
@@ -13713,6 +20617,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_INSURANCE_FUND_STAKE_IX_A
         }
     }
 }
+impl RemoveInsuranceFundStakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for RemoveInsuranceFundStakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RemoveInsuranceFundStakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = accounts
+            .get(..REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for RemoveInsuranceFundStakeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REMOVE_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] = [128, 166, 142, 9, 254, 187, 143, 174];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -13773,6 +20723,123 @@ pub fn remove_insurance_fund_stake_ix(
 ) -> std::io::Result<Instruction> {
     remove_insurance_fund_stake_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`remove_insurance_fund_stake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RemoveInsuranceFundStakeIxBuilder {
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    insurance_fund_stake: Option<Pubkey>,
+    user_stats: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    insurance_fund_vault: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    user_token_account: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    market_index: Option<u16>,
+}
+impl Default for RemoveInsuranceFundStakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RemoveInsuranceFundStakeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            spot_market: None,
+            insurance_fund_stake: None,
+            user_stats: None,
+            authority: None,
+            insurance_fund_vault: None,
+            drift_signer: None,
+            user_token_account: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            market_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn insurance_fund_stake(mut self, insurance_fund_stake: Pubkey) -> Self {
+        self.insurance_fund_stake = Some(insurance_fund_stake);
+        self
+    }
+    pub fn user_stats(mut self, user_stats: Pubkey) -> Self {
+        self.user_stats = Some(user_stats);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn insurance_fund_vault(mut self, insurance_fund_vault: Pubkey) -> Self {
+        self.insurance_fund_vault = Some(insurance_fund_vault);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn user_token_account(mut self, user_token_account: Pubkey) -> Self {
+        self.user_token_account = Some(user_token_account);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RemoveInsuranceFundStakeIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let insurance_fund_stake = self
+            .insurance_fund_stake
+            .ok_or_else(|| missing("insurance_fund_stake"))?;
+        let user_stats = self.user_stats.ok_or_else(|| missing("user_stats"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let insurance_fund_vault = self
+            .insurance_fund_vault
+            .ok_or_else(|| missing("insurance_fund_vault"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let user_token_account = self
+            .user_token_account
+            .ok_or_else(|| missing("user_token_account"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = RemoveInsuranceFundStakeIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = RemoveInsuranceFundStakeKeys {
+            state,
+            spot_market,
+            insurance_fund_stake,
+            user_stats,
+            authority,
+            insurance_fund_vault,
+            drift_signer,
+            user_token_account,
+            token_program,
+        };
+        remove_insurance_fund_stake_ix(keys, args)
+    }
+}
 pub fn remove_insurance_fund_stake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RemoveInsuranceFundStakeAccounts<'_, '_>,
@@ -13982,6 +21049,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl InitializeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INITIALIZE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INITIALIZE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INITIALIZE_IX_DISCM: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
 #[derive(Clone, Debug, PartialEq)]
 pub struct InitializeIxData;
@@ -14024,6 +21129,98 @@ pub fn initialize_ix_with_program_id(
 pub fn initialize_ix(keys: InitializeKeys) -> std::io::Result<Instruction> {
     initialize_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`initialize_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    quote_asset_mint: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+}
+impl Default for InitializeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            quote_asset_mint: None,
+            drift_signer: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn quote_asset_mint(mut self, quote_asset_mint: Pubkey) -> Self {
+        self.quote_asset_mint = Some(quote_asset_mint);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let quote_asset_mint = self
+            .quote_asset_mint
+            .ok_or_else(|| missing("quote_asset_mint"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let keys = InitializeKeys {
+            admin,
+            state,
+            quote_asset_mint,
+            drift_signer,
+            rent,
+            system_program,
+            token_program,
+        };
+        initialize_ix(keys)
+    }
+}
 pub fn initialize_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeAccounts<'_, '_>,
@@ -14258,6 +21455,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_SPOT_MARKET_IX_ACCOUN
         }
     }
 }
+impl InitializeSpotMarketKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_SPOT_MARKET_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_SPOT_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[INITIALIZE_SPOT_MARKET_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeSpotMarketKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeSpotMarketAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_SPOT_MARKET_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_SPOT_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[INITIALIZE_SPOT_MARKET_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeSpotMarketAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INITIALIZE_SPOT_MARKET_IX_DISCM: [u8; 8] = [234, 196, 128, 44, 94, 15, 48, 201];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -14327,6 +21568,236 @@ pub fn initialize_spot_market_ix(
 ) -> std::io::Result<Instruction> {
     initialize_spot_market_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`initialize_spot_market_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeSpotMarketIxBuilder {
+    spot_market: Option<Pubkey>,
+    spot_market_mint: Option<Pubkey>,
+    spot_market_vault: Option<Pubkey>,
+    insurance_fund_vault: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    state: Option<Pubkey>,
+    oracle: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    optimal_utilization: Option<u32>,
+    optimal_borrow_rate: Option<u32>,
+    max_borrow_rate: Option<u32>,
+    oracle_source: Option<OracleSource>,
+    initial_asset_weight: Option<u32>,
+    maintenance_asset_weight: Option<u32>,
+    initial_liability_weight: Option<u32>,
+    maintenance_liability_weight: Option<u32>,
+    imf_factor: Option<u32>,
+    liquidator_fee: Option<u32>,
+    active_status: Option<bool>,
+    name: Option<[u8; 32]>,
+}
+impl Default for InitializeSpotMarketIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeSpotMarketIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            spot_market: None,
+            spot_market_mint: None,
+            spot_market_vault: None,
+            insurance_fund_vault: None,
+            drift_signer: None,
+            state: None,
+            oracle: None,
+            admin: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            optimal_utilization: None,
+            optimal_borrow_rate: None,
+            max_borrow_rate: None,
+            oracle_source: None,
+            initial_asset_weight: None,
+            maintenance_asset_weight: None,
+            initial_liability_weight: None,
+            maintenance_liability_weight: None,
+            imf_factor: None,
+            liquidator_fee: None,
+            active_status: None,
+            name: None,
+        }
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn spot_market_mint(mut self, spot_market_mint: Pubkey) -> Self {
+        self.spot_market_mint = Some(spot_market_mint);
+        self
+    }
+    pub fn spot_market_vault(mut self, spot_market_vault: Pubkey) -> Self {
+        self.spot_market_vault = Some(spot_market_vault);
+        self
+    }
+    pub fn insurance_fund_vault(mut self, insurance_fund_vault: Pubkey) -> Self {
+        self.insurance_fund_vault = Some(insurance_fund_vault);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn oracle(mut self, oracle: Pubkey) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn optimal_utilization(mut self, optimal_utilization: u32) -> Self {
+        self.optimal_utilization = Some(optimal_utilization);
+        self
+    }
+    pub fn optimal_borrow_rate(mut self, optimal_borrow_rate: u32) -> Self {
+        self.optimal_borrow_rate = Some(optimal_borrow_rate);
+        self
+    }
+    pub fn max_borrow_rate(mut self, max_borrow_rate: u32) -> Self {
+        self.max_borrow_rate = Some(max_borrow_rate);
+        self
+    }
+    pub fn oracle_source(mut self, oracle_source: OracleSource) -> Self {
+        self.oracle_source = Some(oracle_source);
+        self
+    }
+    pub fn initial_asset_weight(mut self, initial_asset_weight: u32) -> Self {
+        self.initial_asset_weight = Some(initial_asset_weight);
+        self
+    }
+    pub fn maintenance_asset_weight(mut self, maintenance_asset_weight: u32) -> Self {
+        self.maintenance_asset_weight = Some(maintenance_asset_weight);
+        self
+    }
+    pub fn initial_liability_weight(mut self, initial_liability_weight: u32) -> Self {
+        self.initial_liability_weight = Some(initial_liability_weight);
+        self
+    }
+    pub fn maintenance_liability_weight(mut self, maintenance_liability_weight: u32) -> Self {
+        self.maintenance_liability_weight = Some(maintenance_liability_weight);
+        self
+    }
+    pub fn imf_factor(mut self, imf_factor: u32) -> Self {
+        self.imf_factor = Some(imf_factor);
+        self
+    }
+    pub fn liquidator_fee(mut self, liquidator_fee: u32) -> Self {
+        self.liquidator_fee = Some(liquidator_fee);
+        self
+    }
+    pub fn active_status(mut self, active_status: bool) -> Self {
+        self.active_status = Some(active_status);
+        self
+    }
+    pub fn name(mut self, name: [u8; 32]) -> Self {
+        self.name = Some(name);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeSpotMarketIxBuilder is missing {}", field),
+            )
+        };
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let spot_market_mint = self
+            .spot_market_mint
+            .ok_or_else(|| missing("spot_market_mint"))?;
+        let spot_market_vault = self
+            .spot_market_vault
+            .ok_or_else(|| missing("spot_market_vault"))?;
+        let insurance_fund_vault = self
+            .insurance_fund_vault
+            .ok_or_else(|| missing("insurance_fund_vault"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let oracle = self.oracle.ok_or_else(|| missing("oracle"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = InitializeSpotMarketIxArgs {
+            optimal_utilization: self
+                .optimal_utilization
+                .ok_or_else(|| missing("optimal_utilization"))?,
+            optimal_borrow_rate: self
+                .optimal_borrow_rate
+                .ok_or_else(|| missing("optimal_borrow_rate"))?,
+            max_borrow_rate: self
+                .max_borrow_rate
+                .ok_or_else(|| missing("max_borrow_rate"))?,
+            oracle_source: self.oracle_source.ok_or_else(|| missing("oracle_source"))?,
+            initial_asset_weight: self
+                .initial_asset_weight
+                .ok_or_else(|| missing("initial_asset_weight"))?,
+            maintenance_asset_weight: self
+                .maintenance_asset_weight
+                .ok_or_else(|| missing("maintenance_asset_weight"))?,
+            initial_liability_weight: self
+                .initial_liability_weight
+                .ok_or_else(|| missing("initial_liability_weight"))?,
+            maintenance_liability_weight: self
+                .maintenance_liability_weight
+                .ok_or_else(|| missing("maintenance_liability_weight"))?,
+            imf_factor: self.imf_factor.ok_or_else(|| missing("imf_factor"))?,
+            liquidator_fee: self
+                .liquidator_fee
+                .ok_or_else(|| missing("liquidator_fee"))?,
+            active_status: self.active_status.ok_or_else(|| missing("active_status"))?,
+            name: self.name.ok_or_else(|| missing("name"))?,
+        };
+        let keys = InitializeSpotMarketKeys {
+            spot_market,
+            spot_market_mint,
+            spot_market_vault,
+            insurance_fund_vault,
+            drift_signer,
+            state,
+            oracle,
+            admin,
+            rent,
+            system_program,
+            token_program,
+        };
+        initialize_spot_market_ix(keys, args)
+    }
+}
 pub fn initialize_spot_market_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeSpotMarketAccounts<'_, '_>,
@@ -14589,6 +22060,53 @@ impl<'me, 'info>
         }
     }
 }
+impl InitializeSerumFulfillmentConfigKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeSerumFulfillmentConfigKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeSerumFulfillmentConfigAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for InitializeSerumFulfillmentConfigAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_DISCM: [u8; 8] =
     [193, 211, 132, 172, 70, 171, 7, 94];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -14650,6 +22168,146 @@ pub fn initialize_serum_fulfillment_config_ix(
 ) -> std::io::Result<Instruction> {
     initialize_serum_fulfillment_config_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`initialize_serum_fulfillment_config_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeSerumFulfillmentConfigIxBuilder {
+    base_spot_market: Option<Pubkey>,
+    quote_spot_market: Option<Pubkey>,
+    state: Option<Pubkey>,
+    serum_program: Option<Pubkey>,
+    serum_market: Option<Pubkey>,
+    serum_open_orders: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    serum_fulfillment_config: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    market_index: Option<u16>,
+}
+impl Default for InitializeSerumFulfillmentConfigIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeSerumFulfillmentConfigIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            base_spot_market: None,
+            quote_spot_market: None,
+            state: None,
+            serum_program: None,
+            serum_market: None,
+            serum_open_orders: None,
+            drift_signer: None,
+            serum_fulfillment_config: None,
+            admin: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            market_index: None,
+        }
+    }
+    pub fn base_spot_market(mut self, base_spot_market: Pubkey) -> Self {
+        self.base_spot_market = Some(base_spot_market);
+        self
+    }
+    pub fn quote_spot_market(mut self, quote_spot_market: Pubkey) -> Self {
+        self.quote_spot_market = Some(quote_spot_market);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn serum_program(mut self, serum_program: Pubkey) -> Self {
+        self.serum_program = Some(serum_program);
+        self
+    }
+    pub fn serum_market(mut self, serum_market: Pubkey) -> Self {
+        self.serum_market = Some(serum_market);
+        self
+    }
+    pub fn serum_open_orders(mut self, serum_open_orders: Pubkey) -> Self {
+        self.serum_open_orders = Some(serum_open_orders);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn serum_fulfillment_config(mut self, serum_fulfillment_config: Pubkey) -> Self {
+        self.serum_fulfillment_config = Some(serum_fulfillment_config);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "InitializeSerumFulfillmentConfigIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let base_spot_market = self
+            .base_spot_market
+            .ok_or_else(|| missing("base_spot_market"))?;
+        let quote_spot_market = self
+            .quote_spot_market
+            .ok_or_else(|| missing("quote_spot_market"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let serum_program = self.serum_program.ok_or_else(|| missing("serum_program"))?;
+        let serum_market = self.serum_market.ok_or_else(|| missing("serum_market"))?;
+        let serum_open_orders = self
+            .serum_open_orders
+            .ok_or_else(|| missing("serum_open_orders"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let serum_fulfillment_config = self
+            .serum_fulfillment_config
+            .ok_or_else(|| missing("serum_fulfillment_config"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = InitializeSerumFulfillmentConfigIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = InitializeSerumFulfillmentConfigKeys {
+            base_spot_market,
+            quote_spot_market,
+            state,
+            serum_program,
+            serum_market,
+            serum_open_orders,
+            drift_signer,
+            serum_fulfillment_config,
+            admin,
+            rent,
+            system_program,
+        };
+        initialize_serum_fulfillment_config_ix(keys, args)
+    }
+}
 pub fn initialize_serum_fulfillment_config_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeSerumFulfillmentConfigAccounts<'_, '_>,
@@ -14828,6 +22486,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdateSerumFulfillmentConfigStatusKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSerumFulfillmentConfigStatusKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSerumFulfillmentConfigStatusAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSerumFulfillmentConfigStatusAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_DISCM: [u8; 8] =
     [171, 109, 240, 251, 95, 1, 149, 89];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -14889,6 +22594,72 @@ pub fn update_serum_fulfillment_config_status_ix(
 ) -> std::io::Result<Instruction> {
     update_serum_fulfillment_config_status_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_serum_fulfillment_config_status_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSerumFulfillmentConfigStatusIxBuilder {
+    state: Option<Pubkey>,
+    serum_fulfillment_config: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    status: Option<SpotFulfillmentConfigStatus>,
+}
+impl Default for UpdateSerumFulfillmentConfigStatusIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSerumFulfillmentConfigStatusIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            serum_fulfillment_config: None,
+            admin: None,
+            status: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn serum_fulfillment_config(mut self, serum_fulfillment_config: Pubkey) -> Self {
+        self.serum_fulfillment_config = Some(serum_fulfillment_config);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn status(mut self, status: SpotFulfillmentConfigStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateSerumFulfillmentConfigStatusIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let serum_fulfillment_config = self
+            .serum_fulfillment_config
+            .ok_or_else(|| missing("serum_fulfillment_config"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let args = UpdateSerumFulfillmentConfigStatusIxArgs {
+            status: self.status.ok_or_else(|| missing("status"))?,
+        };
+        let keys = UpdateSerumFulfillmentConfigStatusKeys {
+            state,
+            serum_fulfillment_config,
+            admin,
+        };
+        update_serum_fulfillment_config_status_ix(keys, args)
+    }
+}
 pub fn update_serum_fulfillment_config_status_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSerumFulfillmentConfigStatusAccounts<'_, '_>,
@@ -15131,6 +22902,53 @@ impl<'me, 'info>
         }
     }
 }
+impl InitializePhoenixFulfillmentConfigKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializePhoenixFulfillmentConfigKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializePhoenixFulfillmentConfigAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for InitializePhoenixFulfillmentConfigAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_DISCM: [u8; 8] =
     [135, 132, 110, 107, 185, 160, 169, 154];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -15192,15 +23010,149 @@ pub fn initialize_phoenix_fulfillment_config_ix(
 ) -> std::io::Result<Instruction> {
     initialize_phoenix_fulfillment_config_ix_with_program_id(crate::ID, keys, args)
 }
-pub fn initialize_phoenix_fulfillment_config_invoke_with_program_id(
-    program_id: Pubkey,
-    accounts: InitializePhoenixFulfillmentConfigAccounts<'_, '_>,
-    args: InitializePhoenixFulfillmentConfigIxArgs,
-) -> ProgramResult {
-    let keys: InitializePhoenixFulfillmentConfigKeys = accounts.into();
-    let ix = initialize_phoenix_fulfillment_config_ix_with_program_id(program_id, keys, args)?;
-    invoke_instruction(&ix, accounts)
-}
+/// Builds a [`initialize_phoenix_fulfillment_config_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializePhoenixFulfillmentConfigIxBuilder {
+    base_spot_market: Option<Pubkey>,
+    quote_spot_market: Option<Pubkey>,
+    state: Option<Pubkey>,
+    phoenix_program: Option<Pubkey>,
+    phoenix_market: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    phoenix_fulfillment_config: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    market_index: Option<u16>,
+}
+impl Default for InitializePhoenixFulfillmentConfigIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializePhoenixFulfillmentConfigIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            base_spot_market: None,
+            quote_spot_market: None,
+            state: None,
+            phoenix_program: None,
+            phoenix_market: None,
+            drift_signer: None,
+            phoenix_fulfillment_config: None,
+            admin: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            market_index: None,
+        }
+    }
+    pub fn base_spot_market(mut self, base_spot_market: Pubkey) -> Self {
+        self.base_spot_market = Some(base_spot_market);
+        self
+    }
+    pub fn quote_spot_market(mut self, quote_spot_market: Pubkey) -> Self {
+        self.quote_spot_market = Some(quote_spot_market);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn phoenix_market(mut self, phoenix_market: Pubkey) -> Self {
+        self.phoenix_market = Some(phoenix_market);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn phoenix_fulfillment_config(mut self, phoenix_fulfillment_config: Pubkey) -> Self {
+        self.phoenix_fulfillment_config = Some(phoenix_fulfillment_config);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "InitializePhoenixFulfillmentConfigIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let base_spot_market = self
+            .base_spot_market
+            .ok_or_else(|| missing("base_spot_market"))?;
+        let quote_spot_market = self
+            .quote_spot_market
+            .ok_or_else(|| missing("quote_spot_market"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let phoenix_market = self
+            .phoenix_market
+            .ok_or_else(|| missing("phoenix_market"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let phoenix_fulfillment_config = self
+            .phoenix_fulfillment_config
+            .ok_or_else(|| missing("phoenix_fulfillment_config"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = InitializePhoenixFulfillmentConfigIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = InitializePhoenixFulfillmentConfigKeys {
+            base_spot_market,
+            quote_spot_market,
+            state,
+            phoenix_program,
+            phoenix_market,
+            drift_signer,
+            phoenix_fulfillment_config,
+            admin,
+            rent,
+            system_program,
+        };
+        initialize_phoenix_fulfillment_config_ix(keys, args)
+    }
+}
+pub fn initialize_phoenix_fulfillment_config_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializePhoenixFulfillmentConfigAccounts<'_, '_>,
+    args: InitializePhoenixFulfillmentConfigIxArgs,
+) -> ProgramResult {
+    let keys: InitializePhoenixFulfillmentConfigKeys = accounts.into();
+    let ix = initialize_phoenix_fulfillment_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
 pub fn initialize_phoenix_fulfillment_config_invoke(
     accounts: InitializePhoenixFulfillmentConfigAccounts<'_, '_>,
     args: InitializePhoenixFulfillmentConfigIxArgs,
@@ -15365,6 +23317,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PHOENIX_FULFILLMENT_CONFIG_STATU
         }
     }
 }
+impl PhoenixFulfillmentConfigStatusKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for PhoenixFulfillmentConfigStatusKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PhoenixFulfillmentConfigStatusAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for PhoenixFulfillmentConfigStatusAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_DISCM: [u8; 8] = [96, 31, 113, 32, 12, 203, 7, 154];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -15425,6 +23424,72 @@ pub fn phoenix_fulfillment_config_status_ix(
 ) -> std::io::Result<Instruction> {
     phoenix_fulfillment_config_status_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`phoenix_fulfillment_config_status_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PhoenixFulfillmentConfigStatusIxBuilder {
+    state: Option<Pubkey>,
+    phoenix_fulfillment_config: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    status: Option<SpotFulfillmentConfigStatus>,
+}
+impl Default for PhoenixFulfillmentConfigStatusIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PhoenixFulfillmentConfigStatusIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            phoenix_fulfillment_config: None,
+            admin: None,
+            status: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn phoenix_fulfillment_config(mut self, phoenix_fulfillment_config: Pubkey) -> Self {
+        self.phoenix_fulfillment_config = Some(phoenix_fulfillment_config);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn status(mut self, status: SpotFulfillmentConfigStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "PhoenixFulfillmentConfigStatusIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let phoenix_fulfillment_config = self
+            .phoenix_fulfillment_config
+            .ok_or_else(|| missing("phoenix_fulfillment_config"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let args = PhoenixFulfillmentConfigStatusIxArgs {
+            status: self.status.ok_or_else(|| missing("status"))?,
+        };
+        let keys = PhoenixFulfillmentConfigStatusKeys {
+            state,
+            phoenix_fulfillment_config,
+            admin,
+        };
+        phoenix_fulfillment_config_status_ix(keys, args)
+    }
+}
 pub fn phoenix_fulfillment_config_status_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PhoenixFulfillmentConfigStatusAccounts<'_, '_>,
@@ -15581,6 +23646,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SERUM_VAULT_IX_ACCOUNTS_L
         }
     }
 }
+impl UpdateSerumVaultKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SERUM_VAULT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SERUM_VAULT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UPDATE_SERUM_VAULT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSerumVaultKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSerumVaultAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SERUM_VAULT_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_SERUM_VAULT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UPDATE_SERUM_VAULT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateSerumVaultAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SERUM_VAULT_IX_DISCM: [u8; 8] = [219, 8, 246, 96, 169, 121, 91, 110];
 #[derive(Clone, Debug, PartialEq)]
 pub struct UpdateSerumVaultIxData;
@@ -15623,6 +23726,58 @@ pub fn update_serum_vault_ix_with_program_id(
 pub fn update_serum_vault_ix(keys: UpdateSerumVaultKeys) -> std::io::Result<Instruction> {
     update_serum_vault_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`update_serum_vault_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSerumVaultIxBuilder {
+    state: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    srm_vault: Option<Pubkey>,
+}
+impl Default for UpdateSerumVaultIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSerumVaultIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            admin: None,
+            srm_vault: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn srm_vault(mut self, srm_vault: Pubkey) -> Self {
+        self.srm_vault = Some(srm_vault);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateSerumVaultIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let srm_vault = self.srm_vault.ok_or_else(|| missing("srm_vault"))?;
+        let keys = UpdateSerumVaultKeys {
+            state,
+            admin,
+            srm_vault,
+        };
+        update_serum_vault_ix(keys)
+    }
+}
 pub fn update_serum_vault_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSerumVaultAccounts<'_, '_>,
@@ -15798,6 +23953,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_PERP_MARKET_IX_ACCOUN
         }
     }
 }
+impl InitializePerpMarketKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_PERP_MARKET_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_PERP_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[INITIALIZE_PERP_MARKET_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializePerpMarketKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializePerpMarketAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_PERP_MARKET_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_PERP_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[INITIALIZE_PERP_MARKET_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializePerpMarketAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INITIALIZE_PERP_MARKET_IX_DISCM: [u8; 8] = [132, 9, 229, 118, 117, 118, 117, 62];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -15866,6 +24065,179 @@ pub fn initialize_perp_market_ix(
 ) -> std::io::Result<Instruction> {
     initialize_perp_market_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`initialize_perp_market_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializePerpMarketIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    oracle: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    market_index: Option<u16>,
+    amm_base_asset_reserve: Option<u128>,
+    amm_quote_asset_reserve: Option<u128>,
+    amm_periodicity: Option<i64>,
+    amm_peg_multiplier: Option<u128>,
+    oracle_source: Option<OracleSource>,
+    margin_ratio_initial: Option<u32>,
+    margin_ratio_maintenance: Option<u32>,
+    liquidator_fee: Option<u32>,
+    active_status: Option<bool>,
+    name: Option<[u8; 32]>,
+}
+impl Default for InitializePerpMarketIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializePerpMarketIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            oracle: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            market_index: None,
+            amm_base_asset_reserve: None,
+            amm_quote_asset_reserve: None,
+            amm_periodicity: None,
+            amm_peg_multiplier: None,
+            oracle_source: None,
+            margin_ratio_initial: None,
+            margin_ratio_maintenance: None,
+            liquidator_fee: None,
+            active_status: None,
+            name: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn oracle(mut self, oracle: Pubkey) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    pub fn amm_base_asset_reserve(mut self, amm_base_asset_reserve: u128) -> Self {
+        self.amm_base_asset_reserve = Some(amm_base_asset_reserve);
+        self
+    }
+    pub fn amm_quote_asset_reserve(mut self, amm_quote_asset_reserve: u128) -> Self {
+        self.amm_quote_asset_reserve = Some(amm_quote_asset_reserve);
+        self
+    }
+    pub fn amm_periodicity(mut self, amm_periodicity: i64) -> Self {
+        self.amm_periodicity = Some(amm_periodicity);
+        self
+    }
+    pub fn amm_peg_multiplier(mut self, amm_peg_multiplier: u128) -> Self {
+        self.amm_peg_multiplier = Some(amm_peg_multiplier);
+        self
+    }
+    pub fn oracle_source(mut self, oracle_source: OracleSource) -> Self {
+        self.oracle_source = Some(oracle_source);
+        self
+    }
+    pub fn margin_ratio_initial(mut self, margin_ratio_initial: u32) -> Self {
+        self.margin_ratio_initial = Some(margin_ratio_initial);
+        self
+    }
+    pub fn margin_ratio_maintenance(mut self, margin_ratio_maintenance: u32) -> Self {
+        self.margin_ratio_maintenance = Some(margin_ratio_maintenance);
+        self
+    }
+    pub fn liquidator_fee(mut self, liquidator_fee: u32) -> Self {
+        self.liquidator_fee = Some(liquidator_fee);
+        self
+    }
+    pub fn active_status(mut self, active_status: bool) -> Self {
+        self.active_status = Some(active_status);
+        self
+    }
+    pub fn name(mut self, name: [u8; 32]) -> Self {
+        self.name = Some(name);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializePerpMarketIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let oracle = self.oracle.ok_or_else(|| missing("oracle"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = InitializePerpMarketIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+            amm_base_asset_reserve: self
+                .amm_base_asset_reserve
+                .ok_or_else(|| missing("amm_base_asset_reserve"))?,
+            amm_quote_asset_reserve: self
+                .amm_quote_asset_reserve
+                .ok_or_else(|| missing("amm_quote_asset_reserve"))?,
+            amm_periodicity: self
+                .amm_periodicity
+                .ok_or_else(|| missing("amm_periodicity"))?,
+            amm_peg_multiplier: self
+                .amm_peg_multiplier
+                .ok_or_else(|| missing("amm_peg_multiplier"))?,
+            oracle_source: self.oracle_source.ok_or_else(|| missing("oracle_source"))?,
+            margin_ratio_initial: self
+                .margin_ratio_initial
+                .ok_or_else(|| missing("margin_ratio_initial"))?,
+            margin_ratio_maintenance: self
+                .margin_ratio_maintenance
+                .ok_or_else(|| missing("margin_ratio_maintenance"))?,
+            liquidator_fee: self
+                .liquidator_fee
+                .ok_or_else(|| missing("liquidator_fee"))?,
+            active_status: self.active_status.ok_or_else(|| missing("active_status"))?,
+            name: self.name.ok_or_else(|| missing("name"))?,
+        };
+        let keys = InitializePerpMarketKeys {
+            admin,
+            state,
+            perp_market,
+            oracle,
+            rent,
+            system_program,
+        };
+        initialize_perp_market_ix(keys, args)
+    }
+}
 pub fn initialize_perp_market_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializePerpMarketAccounts<'_, '_>,
@@ -16023,6 +24395,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DELETE_INITIALIZED_PERP_MARKET_I
         }
     }
 }
+impl DeleteInitializedPerpMarketKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DELETE_INITIALIZED_PERP_MARKET_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DELETE_INITIALIZED_PERP_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[DELETE_INITIALIZED_PERP_MARKET_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeleteInitializedPerpMarketKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeleteInitializedPerpMarketAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DELETE_INITIALIZED_PERP_MARKET_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..DELETE_INITIALIZED_PERP_MARKET_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[DELETE_INITIALIZED_PERP_MARKET_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for DeleteInitializedPerpMarketAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DELETE_INITIALIZED_PERP_MARKET_IX_DISCM: [u8; 8] = [91, 154, 24, 87, 106, 59, 190, 66];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -16083,6 +24502,67 @@ pub fn delete_initialized_perp_market_ix(
 ) -> std::io::Result<Instruction> {
     delete_initialized_perp_market_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`delete_initialized_perp_market_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeleteInitializedPerpMarketIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    market_index: Option<u16>,
+}
+impl Default for DeleteInitializedPerpMarketIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeleteInitializedPerpMarketIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            market_index: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DeleteInitializedPerpMarketIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = DeleteInitializedPerpMarketIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+        };
+        let keys = DeleteInitializedPerpMarketKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        delete_initialized_perp_market_ix(keys, args)
+    }
+}
 pub fn delete_initialized_perp_market_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeleteInitializedPerpMarketAccounts<'_, '_>,
@@ -16231,6 +24711,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; MOVE_AMM_PRICE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl MoveAmmPriceKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; MOVE_AMM_PRICE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..MOVE_AMM_PRICE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[MOVE_AMM_PRICE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for MoveAmmPriceKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> MoveAmmPriceAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; MOVE_AMM_PRICE_IX_ACCOUNTS_LEN] = accounts
+            .get(..MOVE_AMM_PRICE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[MOVE_AMM_PRICE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for MoveAmmPriceAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const MOVE_AMM_PRICE_IX_DISCM: [u8; 8] = [235, 109, 2, 82, 219, 118, 6, 159];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -16291,6 +24809,85 @@ pub fn move_amm_price_ix(
 ) -> std::io::Result<Instruction> {
     move_amm_price_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`move_amm_price_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct MoveAmmPriceIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    base_asset_reserve: Option<u128>,
+    quote_asset_reserve: Option<u128>,
+    sqrt_k: Option<u128>,
+}
+impl Default for MoveAmmPriceIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl MoveAmmPriceIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            base_asset_reserve: None,
+            quote_asset_reserve: None,
+            sqrt_k: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn base_asset_reserve(mut self, base_asset_reserve: u128) -> Self {
+        self.base_asset_reserve = Some(base_asset_reserve);
+        self
+    }
+    pub fn quote_asset_reserve(mut self, quote_asset_reserve: u128) -> Self {
+        self.quote_asset_reserve = Some(quote_asset_reserve);
+        self
+    }
+    pub fn sqrt_k(mut self, sqrt_k: u128) -> Self {
+        self.sqrt_k = Some(sqrt_k);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("MoveAmmPriceIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = MoveAmmPriceIxArgs {
+            base_asset_reserve: self
+                .base_asset_reserve
+                .ok_or_else(|| missing("base_asset_reserve"))?,
+            quote_asset_reserve: self
+                .quote_asset_reserve
+                .ok_or_else(|| missing("quote_asset_reserve"))?,
+            sqrt_k: self.sqrt_k.ok_or_else(|| missing("sqrt_k"))?,
+        };
+        let keys = MoveAmmPriceKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        move_amm_price_ix(keys, args)
+    }
+}
 pub fn move_amm_price_invoke_with_program_id(
     program_id: Pubkey,
     accounts: MoveAmmPriceAccounts<'_, '_>,
@@ -16439,6 +25036,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_EXPIRY_IX_ACC
         }
     }
 }
+impl UpdatePerpMarketExpiryKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_EXPIRY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_EXPIRY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_EXPIRY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketExpiryKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketExpiryAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_EXPIRY_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_PERP_MARKET_EXPIRY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_EXPIRY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdatePerpMarketExpiryAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_EXPIRY_IX_DISCM: [u8; 8] = [44, 221, 227, 151, 131, 140, 22, 110];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -16499,6 +25140,67 @@ pub fn update_perp_market_expiry_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_expiry_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_expiry_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketExpiryIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    expiry_ts: Option<i64>,
+}
+impl Default for UpdatePerpMarketExpiryIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketExpiryIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            expiry_ts: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn expiry_ts(mut self, expiry_ts: i64) -> Self {
+        self.expiry_ts = Some(expiry_ts);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpMarketExpiryIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketExpiryIxArgs {
+            expiry_ts: self.expiry_ts.ok_or_else(|| missing("expiry_ts"))?,
+        };
+        let keys = UpdatePerpMarketExpiryKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_expiry_ix(keys, args)
+    }
+}
 pub fn update_perp_market_expiry_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketExpiryAccounts<'_, '_>,
@@ -16669,6 +25371,53 @@ impl<'me, 'info>
         }
     }
 }
+impl SettleExpiredMarketPoolsToRevenuePoolKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for SettleExpiredMarketPoolsToRevenuePoolKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SettleExpiredMarketPoolsToRevenuePoolAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_ACCOUNTS_LEN] = accounts
+            .get(..SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for SettleExpiredMarketPoolsToRevenuePoolAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_DISCM: [u8; 8] =
     [55, 19, 238, 169, 227, 90, 200, 184];
 #[derive(Clone, Debug, PartialEq)]
@@ -16715,6 +25464,69 @@ pub fn settle_expired_market_pools_to_revenue_pool_ix(
 ) -> std::io::Result<Instruction> {
     settle_expired_market_pools_to_revenue_pool_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`settle_expired_market_pools_to_revenue_pool_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SettleExpiredMarketPoolsToRevenuePoolIxBuilder {
+    state: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+}
+impl Default for SettleExpiredMarketPoolsToRevenuePoolIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SettleExpiredMarketPoolsToRevenuePoolIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            admin: None,
+            spot_market: None,
+            perp_market: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "SettleExpiredMarketPoolsToRevenuePoolIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let keys = SettleExpiredMarketPoolsToRevenuePoolKeys {
+            state,
+            admin,
+            spot_market,
+            perp_market,
+        };
+        settle_expired_market_pools_to_revenue_pool_ix(keys)
+    }
+}
 pub fn settle_expired_market_pools_to_revenue_pool_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SettleExpiredMarketPoolsToRevenuePoolAccounts<'_, '_>,
@@ -16925,6 +25737,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_INTO_PERP_MARKET_FEE_POO
         }
     }
 }
+impl DepositIntoPerpMarketFeePoolKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for DepositIntoPerpMarketFeePoolKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DepositIntoPerpMarketFeePoolAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for DepositIntoPerpMarketFeePoolAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_DISCM: [u8; 8] = [34, 58, 57, 68, 97, 80, 244, 6];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -16985,6 +25844,113 @@ pub fn deposit_into_perp_market_fee_pool_ix(
 ) -> std::io::Result<Instruction> {
     deposit_into_perp_market_fee_pool_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`deposit_into_perp_market_fee_pool_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DepositIntoPerpMarketFeePoolIxBuilder {
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    source_vault: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    quote_spot_market: Option<Pubkey>,
+    spot_market_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    amount: Option<u64>,
+}
+impl Default for DepositIntoPerpMarketFeePoolIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DepositIntoPerpMarketFeePoolIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            perp_market: None,
+            admin: None,
+            source_vault: None,
+            drift_signer: None,
+            quote_spot_market: None,
+            spot_market_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            amount: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn source_vault(mut self, source_vault: Pubkey) -> Self {
+        self.source_vault = Some(source_vault);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn quote_spot_market(mut self, quote_spot_market: Pubkey) -> Self {
+        self.quote_spot_market = Some(quote_spot_market);
+        self
+    }
+    pub fn spot_market_vault(mut self, spot_market_vault: Pubkey) -> Self {
+        self.spot_market_vault = Some(spot_market_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DepositIntoPerpMarketFeePoolIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let source_vault = self.source_vault.ok_or_else(|| missing("source_vault"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let quote_spot_market = self
+            .quote_spot_market
+            .ok_or_else(|| missing("quote_spot_market"))?;
+        let spot_market_vault = self
+            .spot_market_vault
+            .ok_or_else(|| missing("spot_market_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = DepositIntoPerpMarketFeePoolIxArgs {
+            amount: self.amount.ok_or_else(|| missing("amount"))?,
+        };
+        let keys = DepositIntoPerpMarketFeePoolKeys {
+            state,
+            perp_market,
+            admin,
+            source_vault,
+            drift_signer,
+            quote_spot_market,
+            spot_market_vault,
+            token_program,
+        };
+        deposit_into_perp_market_fee_pool_ix(keys, args)
+    }
+}
 pub fn deposit_into_perp_market_fee_pool_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DepositIntoPerpMarketFeePoolAccounts<'_, '_>,
@@ -17160,6 +26126,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REPEG_AMM_CURVE_IX_ACCOUNTS_LEN]
         }
     }
 }
+impl RepegAmmCurveKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REPEG_AMM_CURVE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REPEG_AMM_CURVE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[REPEG_AMM_CURVE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for RepegAmmCurveKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RepegAmmCurveAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REPEG_AMM_CURVE_IX_ACCOUNTS_LEN] = accounts
+            .get(..REPEG_AMM_CURVE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[REPEG_AMM_CURVE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for RepegAmmCurveAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REPEG_AMM_CURVE_IX_DISCM: [u8; 8] = [3, 36, 102, 89, 180, 128, 120, 213];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -17218,6 +26222,77 @@ pub fn repeg_amm_curve_ix(
 ) -> std::io::Result<Instruction> {
     repeg_amm_curve_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`repeg_amm_curve_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RepegAmmCurveIxBuilder {
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    oracle: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    new_peg_candidate: Option<u128>,
+}
+impl Default for RepegAmmCurveIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RepegAmmCurveIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            perp_market: None,
+            oracle: None,
+            admin: None,
+            new_peg_candidate: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn oracle(mut self, oracle: Pubkey) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn new_peg_candidate(mut self, new_peg_candidate: u128) -> Self {
+        self.new_peg_candidate = Some(new_peg_candidate);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RepegAmmCurveIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let oracle = self.oracle.ok_or_else(|| missing("oracle"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let args = RepegAmmCurveIxArgs {
+            new_peg_candidate: self
+                .new_peg_candidate
+                .ok_or_else(|| missing("new_peg_candidate"))?,
+        };
+        let keys = RepegAmmCurveKeys {
+            state,
+            perp_market,
+            oracle,
+            admin,
+        };
+        repeg_amm_curve_ix(keys, args)
+    }
+}
 pub fn repeg_amm_curve_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RepegAmmCurveAccounts<'_, '_>,
@@ -17384,6 +26459,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_AMM_ORACLE_TW
         }
     }
 }
+impl UpdatePerpMarketAmmOracleTwapKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketAmmOracleTwapKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketAmmOracleTwapAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketAmmOracleTwapAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_DISCM: [u8; 8] =
     [241, 74, 114, 123, 206, 153, 24, 202];
 #[derive(Clone, Debug, PartialEq)]
@@ -17429,6 +26551,69 @@ pub fn update_perp_market_amm_oracle_twap_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_amm_oracle_twap_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`update_perp_market_amm_oracle_twap_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketAmmOracleTwapIxBuilder {
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    oracle: Option<Pubkey>,
+    admin: Option<Pubkey>,
+}
+impl Default for UpdatePerpMarketAmmOracleTwapIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketAmmOracleTwapIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            perp_market: None,
+            oracle: None,
+            admin: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn oracle(mut self, oracle: Pubkey) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePerpMarketAmmOracleTwapIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let oracle = self.oracle.ok_or_else(|| missing("oracle"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let keys = UpdatePerpMarketAmmOracleTwapKeys {
+            state,
+            perp_market,
+            oracle,
+            admin,
+        };
+        update_perp_market_amm_oracle_twap_ix(keys)
+    }
+}
 pub fn update_perp_market_amm_oracle_twap_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketAmmOracleTwapAccounts<'_, '_>,
@@ -17591,6 +26776,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; RESET_PERP_MARKET_AMM_ORACLE_TWA
         }
     }
 }
+impl ResetPerpMarketAmmOracleTwapKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ResetPerpMarketAmmOracleTwapKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ResetPerpMarketAmmOracleTwapAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for ResetPerpMarketAmmOracleTwapAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_DISCM: [u8; 8] =
     [127, 10, 55, 164, 123, 226, 47, 24];
 #[derive(Clone, Debug, PartialEq)]
@@ -17636,6 +26868,66 @@ pub fn reset_perp_market_amm_oracle_twap_ix(
 ) -> std::io::Result<Instruction> {
     reset_perp_market_amm_oracle_twap_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`reset_perp_market_amm_oracle_twap_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ResetPerpMarketAmmOracleTwapIxBuilder {
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    oracle: Option<Pubkey>,
+    admin: Option<Pubkey>,
+}
+impl Default for ResetPerpMarketAmmOracleTwapIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ResetPerpMarketAmmOracleTwapIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            perp_market: None,
+            oracle: None,
+            admin: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn oracle(mut self, oracle: Pubkey) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ResetPerpMarketAmmOracleTwapIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let oracle = self.oracle.ok_or_else(|| missing("oracle"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let keys = ResetPerpMarketAmmOracleTwapKeys {
+            state,
+            perp_market,
+            oracle,
+            admin,
+        };
+        reset_perp_market_amm_oracle_twap_ix(keys)
+    }
+}
 pub fn reset_perp_market_amm_oracle_twap_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ResetPerpMarketAmmOracleTwapAccounts<'_, '_>,
@@ -17790,6 +27082,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_K_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl UpdateKKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_K_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_K_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UPDATE_K_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateKKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateKAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_K_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_K_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UPDATE_K_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateKAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_K_IX_DISCM: [u8; 8] = [72, 98, 9, 139, 129, 229, 172, 56];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -17845,6 +27175,75 @@ pub fn update_k_ix_with_program_id(
 pub fn update_k_ix(keys: UpdateKKeys, args: UpdateKIxArgs) -> std::io::Result<Instruction> {
     update_k_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_k_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateKIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    oracle: Option<Pubkey>,
+    sqrt_k: Option<u128>,
+}
+impl Default for UpdateKIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateKIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            oracle: None,
+            sqrt_k: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn oracle(mut self, oracle: Pubkey) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    pub fn sqrt_k(mut self, sqrt_k: u128) -> Self {
+        self.sqrt_k = Some(sqrt_k);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateKIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let oracle = self.oracle.ok_or_else(|| missing("oracle"))?;
+        let args = UpdateKIxArgs {
+            sqrt_k: self.sqrt_k.ok_or_else(|| missing("sqrt_k"))?,
+        };
+        let keys = UpdateKKeys {
+            admin,
+            state,
+            perp_market,
+            oracle,
+        };
+        update_k_ix(keys, args)
+    }
+}
 pub fn update_k_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateKAccounts<'_, '_>,
@@ -17997,6 +27396,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MARGIN_RATIO_
         }
     }
 }
+impl UpdatePerpMarketMarginRatioKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_MARGIN_RATIO_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_MARGIN_RATIO_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_MARGIN_RATIO_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketMarginRatioKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketMarginRatioAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MARGIN_RATIO_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_MARGIN_RATIO_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_MARGIN_RATIO_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketMarginRatioAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_MARGIN_RATIO_IX_DISCM: [u8; 8] =
     [130, 173, 107, 45, 119, 105, 26, 113];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -18059,6 +27505,78 @@ pub fn update_perp_market_margin_ratio_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_margin_ratio_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_margin_ratio_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketMarginRatioIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    margin_ratio_initial: Option<u32>,
+    margin_ratio_maintenance: Option<u32>,
+}
+impl Default for UpdatePerpMarketMarginRatioIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketMarginRatioIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            margin_ratio_initial: None,
+            margin_ratio_maintenance: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn margin_ratio_initial(mut self, margin_ratio_initial: u32) -> Self {
+        self.margin_ratio_initial = Some(margin_ratio_initial);
+        self
+    }
+    pub fn margin_ratio_maintenance(mut self, margin_ratio_maintenance: u32) -> Self {
+        self.margin_ratio_maintenance = Some(margin_ratio_maintenance);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpMarketMarginRatioIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketMarginRatioIxArgs {
+            margin_ratio_initial: self
+                .margin_ratio_initial
+                .ok_or_else(|| missing("margin_ratio_initial"))?,
+            margin_ratio_maintenance: self
+                .margin_ratio_maintenance
+                .ok_or_else(|| missing("margin_ratio_maintenance"))?,
+        };
+        let keys = UpdatePerpMarketMarginRatioKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_margin_ratio_ix(keys, args)
+    }
+}
 pub fn update_perp_market_margin_ratio_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketMarginRatioAccounts<'_, '_>,
@@ -18213,6 +27731,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_IMBALANCE
         }
     }
 }
+impl UpdatePerpMarketMaxImbalancesKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketMaxImbalancesKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketMaxImbalancesAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketMaxImbalancesAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_DISCM: [u8; 8] = [15, 206, 73, 133, 60, 8, 86, 89];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -18275,6 +27840,90 @@ pub fn update_perp_market_max_imbalances_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_max_imbalances_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_max_imbalances_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketMaxImbalancesIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    unrealized_max_imbalance: Option<u64>,
+    max_revenue_withdraw_per_period: Option<u64>,
+    quote_max_insurance: Option<u64>,
+}
+impl Default for UpdatePerpMarketMaxImbalancesIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketMaxImbalancesIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            unrealized_max_imbalance: None,
+            max_revenue_withdraw_per_period: None,
+            quote_max_insurance: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn unrealized_max_imbalance(mut self, unrealized_max_imbalance: u64) -> Self {
+        self.unrealized_max_imbalance = Some(unrealized_max_imbalance);
+        self
+    }
+    pub fn max_revenue_withdraw_per_period(mut self, max_revenue_withdraw_per_period: u64) -> Self {
+        self.max_revenue_withdraw_per_period = Some(max_revenue_withdraw_per_period);
+        self
+    }
+    pub fn quote_max_insurance(mut self, quote_max_insurance: u64) -> Self {
+        self.quote_max_insurance = Some(quote_max_insurance);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePerpMarketMaxImbalancesIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketMaxImbalancesIxArgs {
+            unrealized_max_imbalance: self
+                .unrealized_max_imbalance
+                .ok_or_else(|| missing("unrealized_max_imbalance"))?,
+            max_revenue_withdraw_per_period: self
+                .max_revenue_withdraw_per_period
+                .ok_or_else(|| missing("max_revenue_withdraw_per_period"))?,
+            quote_max_insurance: self
+                .quote_max_insurance
+                .ok_or_else(|| missing("quote_max_insurance"))?,
+        };
+        let keys = UpdatePerpMarketMaxImbalancesKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_max_imbalances_ix(keys, args)
+    }
+}
 pub fn update_perp_market_max_imbalances_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketMaxImbalancesAccounts<'_, '_>,
@@ -18434,6 +28083,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_LIQUIDATION_F
         }
     }
 }
+impl UpdatePerpMarketLiquidationFeeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketLiquidationFeeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketLiquidationFeeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketLiquidationFeeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_DISCM: [u8; 8] = [90, 137, 9, 145, 41, 8, 148, 117];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -18495,6 +28191,81 @@ pub fn update_perp_market_liquidation_fee_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_liquidation_fee_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_liquidation_fee_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketLiquidationFeeIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    liquidator_fee: Option<u32>,
+    if_liquidation_fee: Option<u32>,
+}
+impl Default for UpdatePerpMarketLiquidationFeeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketLiquidationFeeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            liquidator_fee: None,
+            if_liquidation_fee: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn liquidator_fee(mut self, liquidator_fee: u32) -> Self {
+        self.liquidator_fee = Some(liquidator_fee);
+        self
+    }
+    pub fn if_liquidation_fee(mut self, if_liquidation_fee: u32) -> Self {
+        self.if_liquidation_fee = Some(if_liquidation_fee);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePerpMarketLiquidationFeeIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketLiquidationFeeIxArgs {
+            liquidator_fee: self
+                .liquidator_fee
+                .ok_or_else(|| missing("liquidator_fee"))?,
+            if_liquidation_fee: self
+                .if_liquidation_fee
+                .ok_or_else(|| missing("if_liquidation_fee"))?,
+        };
+        let keys = UpdatePerpMarketLiquidationFeeKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_liquidation_fee_ix(keys, args)
+    }
+}
 pub fn update_perp_market_liquidation_fee_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketLiquidationFeeAccounts<'_, '_>,
@@ -18657,12 +28428,59 @@ impl<'me, 'info>
         }
     }
 }
-pub const UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_DISCM: [u8; 8] =
-    [44, 69, 43, 226, 204, 223, 202, 52];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct UpdateInsuranceFundUnstakingPeriodIxArgs {
-    pub insurance_fund_unstaking_period: i64,
+impl UpdateInsuranceFundUnstakingPeriodKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateInsuranceFundUnstakingPeriodKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateInsuranceFundUnstakingPeriodAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateInsuranceFundUnstakingPeriodAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_DISCM: [u8; 8] =
+    [44, 69, 43, 226, 204, 223, 202, 52];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateInsuranceFundUnstakingPeriodIxArgs {
+    pub insurance_fund_unstaking_period: i64,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct UpdateInsuranceFundUnstakingPeriodIxData(pub UpdateInsuranceFundUnstakingPeriodIxArgs);
@@ -18718,6 +28536,72 @@ pub fn update_insurance_fund_unstaking_period_ix(
 ) -> std::io::Result<Instruction> {
     update_insurance_fund_unstaking_period_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_insurance_fund_unstaking_period_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateInsuranceFundUnstakingPeriodIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    insurance_fund_unstaking_period: Option<i64>,
+}
+impl Default for UpdateInsuranceFundUnstakingPeriodIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateInsuranceFundUnstakingPeriodIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            insurance_fund_unstaking_period: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn insurance_fund_unstaking_period(mut self, insurance_fund_unstaking_period: i64) -> Self {
+        self.insurance_fund_unstaking_period = Some(insurance_fund_unstaking_period);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateInsuranceFundUnstakingPeriodIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateInsuranceFundUnstakingPeriodIxArgs {
+            insurance_fund_unstaking_period: self
+                .insurance_fund_unstaking_period
+                .ok_or_else(|| missing("insurance_fund_unstaking_period"))?,
+        };
+        let keys = UpdateInsuranceFundUnstakingPeriodKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_insurance_fund_unstaking_period_ix(keys, args)
+    }
+}
 pub fn update_insurance_fund_unstaking_period_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateInsuranceFundUnstakingPeriodAccounts<'_, '_>,
@@ -18877,6 +28761,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_LIQUIDATION_F
         }
     }
 }
+impl UpdateSpotMarketLiquidationFeeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketLiquidationFeeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketLiquidationFeeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotMarketLiquidationFeeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_DISCM: [u8; 8] =
     [11, 13, 255, 53, 56, 136, 104, 177];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -18939,6 +28870,81 @@ pub fn update_spot_market_liquidation_fee_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_liquidation_fee_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_liquidation_fee_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketLiquidationFeeIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    liquidator_fee: Option<u32>,
+    if_liquidation_fee: Option<u32>,
+}
+impl Default for UpdateSpotMarketLiquidationFeeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketLiquidationFeeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            liquidator_fee: None,
+            if_liquidation_fee: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn liquidator_fee(mut self, liquidator_fee: u32) -> Self {
+        self.liquidator_fee = Some(liquidator_fee);
+        self
+    }
+    pub fn if_liquidation_fee(mut self, if_liquidation_fee: u32) -> Self {
+        self.if_liquidation_fee = Some(if_liquidation_fee);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateSpotMarketLiquidationFeeIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketLiquidationFeeIxArgs {
+            liquidator_fee: self
+                .liquidator_fee
+                .ok_or_else(|| missing("liquidator_fee"))?,
+            if_liquidation_fee: self
+                .if_liquidation_fee
+                .ok_or_else(|| missing("if_liquidation_fee"))?,
+        };
+        let keys = UpdateSpotMarketLiquidationFeeKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_liquidation_fee_ix(keys, args)
+    }
+}
 pub fn update_spot_market_liquidation_fee_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketLiquidationFeeAccounts<'_, '_>,
@@ -19098,6 +29104,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_WITHDRAW_GUARD_THRESHOLD_
         }
     }
 }
+impl UpdateWithdrawGuardThresholdKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateWithdrawGuardThresholdKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateWithdrawGuardThresholdAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateWithdrawGuardThresholdAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_DISCM: [u8; 8] = [56, 18, 39, 61, 155, 211, 44, 133];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -19158,6 +29211,69 @@ pub fn update_withdraw_guard_threshold_ix(
 ) -> std::io::Result<Instruction> {
     update_withdraw_guard_threshold_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_withdraw_guard_threshold_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateWithdrawGuardThresholdIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    withdraw_guard_threshold: Option<u64>,
+}
+impl Default for UpdateWithdrawGuardThresholdIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateWithdrawGuardThresholdIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            withdraw_guard_threshold: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn withdraw_guard_threshold(mut self, withdraw_guard_threshold: u64) -> Self {
+        self.withdraw_guard_threshold = Some(withdraw_guard_threshold);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateWithdrawGuardThresholdIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateWithdrawGuardThresholdIxArgs {
+            withdraw_guard_threshold: self
+                .withdraw_guard_threshold
+                .ok_or_else(|| missing("withdraw_guard_threshold"))?,
+        };
+        let keys = UpdateWithdrawGuardThresholdKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_withdraw_guard_threshold_ix(keys, args)
+    }
+}
 pub fn update_withdraw_guard_threshold_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateWithdrawGuardThresholdAccounts<'_, '_>,
@@ -19308,6 +29424,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_IF_FACTOR_IX_
         }
     }
 }
+impl UpdateSpotMarketIfFactorKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_IF_FACTOR_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_IF_FACTOR_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_IF_FACTOR_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketIfFactorKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketIfFactorAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_IF_FACTOR_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_SPOT_MARKET_IF_FACTOR_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_IF_FACTOR_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotMarketIfFactorAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_IF_FACTOR_IX_DISCM: [u8; 8] = [147, 30, 224, 34, 18, 230, 105, 4];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -19370,6 +29532,87 @@ pub fn update_spot_market_if_factor_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_if_factor_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_if_factor_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketIfFactorIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    spot_market_index: Option<u16>,
+    user_if_factor: Option<u32>,
+    total_if_factor: Option<u32>,
+}
+impl Default for UpdateSpotMarketIfFactorIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketIfFactorIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            spot_market_index: None,
+            user_if_factor: None,
+            total_if_factor: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn spot_market_index(mut self, spot_market_index: u16) -> Self {
+        self.spot_market_index = Some(spot_market_index);
+        self
+    }
+    pub fn user_if_factor(mut self, user_if_factor: u32) -> Self {
+        self.user_if_factor = Some(user_if_factor);
+        self
+    }
+    pub fn total_if_factor(mut self, total_if_factor: u32) -> Self {
+        self.total_if_factor = Some(total_if_factor);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateSpotMarketIfFactorIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketIfFactorIxArgs {
+            spot_market_index: self
+                .spot_market_index
+                .ok_or_else(|| missing("spot_market_index"))?,
+            user_if_factor: self
+                .user_if_factor
+                .ok_or_else(|| missing("user_if_factor"))?,
+            total_if_factor: self
+                .total_if_factor
+                .ok_or_else(|| missing("total_if_factor"))?,
+        };
+        let keys = UpdateSpotMarketIfFactorKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_if_factor_ix(keys, args)
+    }
+}
 pub fn update_spot_market_if_factor_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketIfFactorAccounts<'_, '_>,
@@ -19527,6 +29770,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdateSpotMarketRevenueSettlePeriodKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketRevenueSettlePeriodKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketRevenueSettlePeriodAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotMarketRevenueSettlePeriodAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_DISCM: [u8; 8] =
     [81, 92, 126, 41, 250, 225, 156, 219];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -19589,6 +29879,72 @@ pub fn update_spot_market_revenue_settle_period_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_revenue_settle_period_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_revenue_settle_period_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketRevenueSettlePeriodIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    revenue_settle_period: Option<i64>,
+}
+impl Default for UpdateSpotMarketRevenueSettlePeriodIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketRevenueSettlePeriodIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            revenue_settle_period: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn revenue_settle_period(mut self, revenue_settle_period: i64) -> Self {
+        self.revenue_settle_period = Some(revenue_settle_period);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateSpotMarketRevenueSettlePeriodIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketRevenueSettlePeriodIxArgs {
+            revenue_settle_period: self
+                .revenue_settle_period
+                .ok_or_else(|| missing("revenue_settle_period"))?,
+        };
+        let keys = UpdateSpotMarketRevenueSettlePeriodKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_revenue_settle_period_ix(keys, args)
+    }
+}
 pub fn update_spot_market_revenue_settle_period_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketRevenueSettlePeriodAccounts<'_, '_>,
@@ -19742,6 +30098,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_STATUS_IX_ACC
         }
     }
 }
+impl UpdateSpotMarketStatusKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_STATUS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketStatusKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketStatusAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_STATUS_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_SPOT_MARKET_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateSpotMarketStatusAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_STATUS_IX_DISCM: [u8; 8] = [78, 94, 16, 188, 193, 110, 231, 31];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -19802,6 +30202,67 @@ pub fn update_spot_market_status_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_status_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_status_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketStatusIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    status: Option<MarketStatus>,
+}
+impl Default for UpdateSpotMarketStatusIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketStatusIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            status: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn status(mut self, status: MarketStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateSpotMarketStatusIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketStatusIxArgs {
+            status: self.status.ok_or_else(|| missing("status"))?,
+        };
+        let keys = UpdateSpotMarketStatusKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_status_ix(keys, args)
+    }
+}
 pub fn update_spot_market_status_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketStatusAccounts<'_, '_>,
@@ -19954,6 +30415,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ASSET_TIER_IX
         }
     }
 }
+impl UpdateSpotMarketAssetTierKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_ASSET_TIER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_ASSET_TIER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_ASSET_TIER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketAssetTierKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketAssetTierAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ASSET_TIER_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_SPOT_MARKET_ASSET_TIER_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_ASSET_TIER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotMarketAssetTierAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_ASSET_TIER_IX_DISCM: [u8; 8] = [253, 209, 231, 14, 242, 208, 243, 130];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -20014,6 +30522,67 @@ pub fn update_spot_market_asset_tier_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_asset_tier_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_asset_tier_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketAssetTierIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    asset_tier: Option<AssetTier>,
+}
+impl Default for UpdateSpotMarketAssetTierIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketAssetTierIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            asset_tier: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn asset_tier(mut self, asset_tier: AssetTier) -> Self {
+        self.asset_tier = Some(asset_tier);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateSpotMarketAssetTierIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketAssetTierIxArgs {
+            asset_tier: self.asset_tier.ok_or_else(|| missing("asset_tier"))?,
+        };
+        let keys = UpdateSpotMarketAssetTierKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_asset_tier_ix(keys, args)
+    }
+}
 pub fn update_spot_market_asset_tier_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketAssetTierAccounts<'_, '_>,
@@ -20168,6 +30737,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_MARGIN_WEIGHT
         }
     }
 }
+impl UpdateSpotMarketMarginWeightsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketMarginWeightsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketMarginWeightsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotMarketMarginWeightsAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_DISCM: [u8; 8] = [109, 33, 87, 195, 255, 36, 6, 81];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -20232,6 +30848,106 @@ pub fn update_spot_market_margin_weights_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_margin_weights_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_margin_weights_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketMarginWeightsIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    initial_asset_weight: Option<u32>,
+    maintenance_asset_weight: Option<u32>,
+    initial_liability_weight: Option<u32>,
+    maintenance_liability_weight: Option<u32>,
+    imf_factor: Option<u32>,
+}
+impl Default for UpdateSpotMarketMarginWeightsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketMarginWeightsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            initial_asset_weight: None,
+            maintenance_asset_weight: None,
+            initial_liability_weight: None,
+            maintenance_liability_weight: None,
+            imf_factor: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn initial_asset_weight(mut self, initial_asset_weight: u32) -> Self {
+        self.initial_asset_weight = Some(initial_asset_weight);
+        self
+    }
+    pub fn maintenance_asset_weight(mut self, maintenance_asset_weight: u32) -> Self {
+        self.maintenance_asset_weight = Some(maintenance_asset_weight);
+        self
+    }
+    pub fn initial_liability_weight(mut self, initial_liability_weight: u32) -> Self {
+        self.initial_liability_weight = Some(initial_liability_weight);
+        self
+    }
+    pub fn maintenance_liability_weight(mut self, maintenance_liability_weight: u32) -> Self {
+        self.maintenance_liability_weight = Some(maintenance_liability_weight);
+        self
+    }
+    pub fn imf_factor(mut self, imf_factor: u32) -> Self {
+        self.imf_factor = Some(imf_factor);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateSpotMarketMarginWeightsIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketMarginWeightsIxArgs {
+            initial_asset_weight: self
+                .initial_asset_weight
+                .ok_or_else(|| missing("initial_asset_weight"))?,
+            maintenance_asset_weight: self
+                .maintenance_asset_weight
+                .ok_or_else(|| missing("maintenance_asset_weight"))?,
+            initial_liability_weight: self
+                .initial_liability_weight
+                .ok_or_else(|| missing("initial_liability_weight"))?,
+            maintenance_liability_weight: self
+                .maintenance_liability_weight
+                .ok_or_else(|| missing("maintenance_liability_weight"))?,
+            imf_factor: self.imf_factor.ok_or_else(|| missing("imf_factor"))?,
+        };
+        let keys = UpdateSpotMarketMarginWeightsKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_margin_weights_ix(keys, args)
+    }
+}
 pub fn update_spot_market_margin_weights_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketMarginWeightsAccounts<'_, '_>,
@@ -20391,6 +31107,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_BORROW_RATE_I
         }
     }
 }
+impl UpdateSpotMarketBorrowRateKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_BORROW_RATE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_BORROW_RATE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_BORROW_RATE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketBorrowRateKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketBorrowRateAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_BORROW_RATE_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_SPOT_MARKET_BORROW_RATE_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_BORROW_RATE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotMarketBorrowRateAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_BORROW_RATE_IX_DISCM: [u8; 8] = [71, 239, 236, 153, 210, 62, 254, 76];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -20453,6 +31216,87 @@ pub fn update_spot_market_borrow_rate_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_borrow_rate_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_borrow_rate_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketBorrowRateIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    optimal_utilization: Option<u32>,
+    optimal_borrow_rate: Option<u32>,
+    max_borrow_rate: Option<u32>,
+}
+impl Default for UpdateSpotMarketBorrowRateIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketBorrowRateIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            optimal_utilization: None,
+            optimal_borrow_rate: None,
+            max_borrow_rate: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn optimal_utilization(mut self, optimal_utilization: u32) -> Self {
+        self.optimal_utilization = Some(optimal_utilization);
+        self
+    }
+    pub fn optimal_borrow_rate(mut self, optimal_borrow_rate: u32) -> Self {
+        self.optimal_borrow_rate = Some(optimal_borrow_rate);
+        self
+    }
+    pub fn max_borrow_rate(mut self, max_borrow_rate: u32) -> Self {
+        self.max_borrow_rate = Some(max_borrow_rate);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateSpotMarketBorrowRateIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketBorrowRateIxArgs {
+            optimal_utilization: self
+                .optimal_utilization
+                .ok_or_else(|| missing("optimal_utilization"))?,
+            optimal_borrow_rate: self
+                .optimal_borrow_rate
+                .ok_or_else(|| missing("optimal_borrow_rate"))?,
+            max_borrow_rate: self
+                .max_borrow_rate
+                .ok_or_else(|| missing("max_borrow_rate"))?,
+        };
+        let keys = UpdateSpotMarketBorrowRateKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_borrow_rate_ix(keys, args)
+    }
+}
 pub fn update_spot_market_borrow_rate_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketBorrowRateAccounts<'_, '_>,
@@ -20610,6 +31454,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdateSpotMarketMaxTokenDepositsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketMaxTokenDepositsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketMaxTokenDepositsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotMarketMaxTokenDepositsAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_DISCM: [u8; 8] =
     [56, 191, 79, 18, 26, 121, 80, 208];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -20671,6 +31562,72 @@ pub fn update_spot_market_max_token_deposits_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_max_token_deposits_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_max_token_deposits_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketMaxTokenDepositsIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    max_token_deposits: Option<u64>,
+}
+impl Default for UpdateSpotMarketMaxTokenDepositsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketMaxTokenDepositsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            max_token_deposits: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn max_token_deposits(mut self, max_token_deposits: u64) -> Self {
+        self.max_token_deposits = Some(max_token_deposits);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateSpotMarketMaxTokenDepositsIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketMaxTokenDepositsIxArgs {
+            max_token_deposits: self
+                .max_token_deposits
+                .ok_or_else(|| missing("max_token_deposits"))?,
+        };
+        let keys = UpdateSpotMarketMaxTokenDepositsKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_max_token_deposits_ix(keys, args)
+    }
+}
 pub fn update_spot_market_max_token_deposits_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketMaxTokenDepositsAccounts<'_, '_>,
@@ -20835,6 +31792,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ORACLE_IX_ACC
         }
     }
 }
+impl UpdateSpotMarketOracleKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_ORACLE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_ORACLE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_ORACLE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketOracleKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketOracleAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ORACLE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_SPOT_MARKET_ORACLE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_ORACLE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateSpotMarketOracleAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_ORACLE_IX_DISCM: [u8; 8] = [114, 184, 102, 37, 246, 186, 180, 99];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -20896,6 +31897,82 @@ pub fn update_spot_market_oracle_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_oracle_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_oracle_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketOracleIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    oracle: Option<Pubkey>,
+    oracle_arg: Option<Pubkey>,
+    oracle_source: Option<OracleSource>,
+}
+impl Default for UpdateSpotMarketOracleIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketOracleIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            oracle: None,
+            oracle_arg: None,
+            oracle_source: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn oracle(mut self, oracle: Pubkey) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    pub fn oracle_arg(mut self, oracle_arg: Pubkey) -> Self {
+        self.oracle_arg = Some(oracle_arg);
+        self
+    }
+    pub fn oracle_source(mut self, oracle_source: OracleSource) -> Self {
+        self.oracle_source = Some(oracle_source);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateSpotMarketOracleIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let oracle = self.oracle.ok_or_else(|| missing("oracle"))?;
+        let args = UpdateSpotMarketOracleIxArgs {
+            oracle: self.oracle_arg.ok_or_else(|| missing("oracle_arg"))?,
+            oracle_source: self.oracle_source.ok_or_else(|| missing("oracle_source"))?,
+        };
+        let keys = UpdateSpotMarketOracleKeys {
+            admin,
+            state,
+            spot_market,
+            oracle,
+        };
+        update_spot_market_oracle_ix(keys, args)
+    }
+}
 pub fn update_spot_market_oracle_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketOracleAccounts<'_, '_>,
@@ -21054,6 +32131,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdateSpotMarketStepSizeAndTickSizeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketStepSizeAndTickSizeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketStepSizeAndTickSizeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotMarketStepSizeAndTickSizeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_DISCM: [u8; 8] =
     [238, 153, 137, 80, 206, 59, 250, 61];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -21117,6 +32241,77 @@ pub fn update_spot_market_step_size_and_tick_size_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_step_size_and_tick_size_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_step_size_and_tick_size_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketStepSizeAndTickSizeIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    step_size: Option<u64>,
+    tick_size: Option<u64>,
+}
+impl Default for UpdateSpotMarketStepSizeAndTickSizeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketStepSizeAndTickSizeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            step_size: None,
+            tick_size: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn step_size(mut self, step_size: u64) -> Self {
+        self.step_size = Some(step_size);
+        self
+    }
+    pub fn tick_size(mut self, tick_size: u64) -> Self {
+        self.tick_size = Some(tick_size);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateSpotMarketStepSizeAndTickSizeIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketStepSizeAndTickSizeIxArgs {
+            step_size: self.step_size.ok_or_else(|| missing("step_size"))?,
+            tick_size: self.tick_size.ok_or_else(|| missing("tick_size"))?,
+        };
+        let keys = UpdateSpotMarketStepSizeAndTickSizeKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_step_size_and_tick_size_ix(keys, args)
+    }
+}
 pub fn update_spot_market_step_size_and_tick_size_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketStepSizeAndTickSizeAccounts<'_, '_>,
@@ -21276,6 +32471,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_MIN_ORDER_SIZ
         }
     }
 }
+impl UpdateSpotMarketMinOrderSizeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketMinOrderSizeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketMinOrderSizeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotMarketMinOrderSizeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_DISCM: [u8; 8] = [93, 128, 11, 119, 26, 20, 181, 50];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -21336,6 +32578,67 @@ pub fn update_spot_market_min_order_size_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_min_order_size_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_min_order_size_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketMinOrderSizeIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    order_size: Option<u64>,
+}
+impl Default for UpdateSpotMarketMinOrderSizeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketMinOrderSizeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            order_size: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn order_size(mut self, order_size: u64) -> Self {
+        self.order_size = Some(order_size);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateSpotMarketMinOrderSizeIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketMinOrderSizeIxArgs {
+            order_size: self.order_size.ok_or_else(|| missing("order_size"))?,
+        };
+        let keys = UpdateSpotMarketMinOrderSizeKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_min_order_size_ix(keys, args)
+    }
+}
 pub fn update_spot_market_min_order_size_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketMinOrderSizeAccounts<'_, '_>,
@@ -21495,6 +32798,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ORDERS_ENABLE
         }
     }
 }
+impl UpdateSpotMarketOrdersEnabledKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketOrdersEnabledKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketOrdersEnabledAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotMarketOrdersEnabledAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_DISCM: [u8; 8] =
     [190, 79, 206, 15, 26, 229, 229, 43];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -21556,6 +32906,72 @@ pub fn update_spot_market_orders_enabled_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_orders_enabled_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_orders_enabled_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketOrdersEnabledIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    orders_enabled: Option<bool>,
+}
+impl Default for UpdateSpotMarketOrdersEnabledIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketOrdersEnabledIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            orders_enabled: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn orders_enabled(mut self, orders_enabled: bool) -> Self {
+        self.orders_enabled = Some(orders_enabled);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateSpotMarketOrdersEnabledIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketOrdersEnabledIxArgs {
+            orders_enabled: self
+                .orders_enabled
+                .ok_or_else(|| missing("orders_enabled"))?,
+        };
+        let keys = UpdateSpotMarketOrdersEnabledKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_orders_enabled_ix(keys, args)
+    }
+}
 pub fn update_spot_market_orders_enabled_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketOrdersEnabledAccounts<'_, '_>,
@@ -21709,6 +33125,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_NAME_IX_ACCOU
         }
     }
 }
+impl UpdateSpotMarketNameKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_MARKET_NAME_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_MARKET_NAME_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_MARKET_NAME_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotMarketNameKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotMarketNameAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_NAME_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_SPOT_MARKET_NAME_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_MARKET_NAME_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateSpotMarketNameAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_MARKET_NAME_IX_DISCM: [u8; 8] = [17, 208, 1, 1, 162, 211, 188, 224];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -21767,6 +33227,67 @@ pub fn update_spot_market_name_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_market_name_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_market_name_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotMarketNameIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    name: Option<[u8; 32]>,
+}
+impl Default for UpdateSpotMarketNameIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotMarketNameIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            name: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn name(mut self, name: [u8; 32]) -> Self {
+        self.name = Some(name);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateSpotMarketNameIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let args = UpdateSpotMarketNameIxArgs {
+            name: self.name.ok_or_else(|| missing("name"))?,
+        };
+        let keys = UpdateSpotMarketNameKeys {
+            admin,
+            state,
+            spot_market,
+        };
+        update_spot_market_name_ix(keys, args)
+    }
+}
 pub fn update_spot_market_name_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotMarketNameAccounts<'_, '_>,
@@ -21915,8 +33436,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_STATUS_IX_ACC
         }
     }
 }
-pub const UPDATE_PERP_MARKET_STATUS_IX_DISCM: [u8; 8] = [71, 201, 175, 122, 255, 207, 196, 207];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+impl UpdatePerpMarketStatusKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_STATUS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketStatusKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketStatusAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_STATUS_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_PERP_MARKET_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdatePerpMarketStatusAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const UPDATE_PERP_MARKET_STATUS_IX_DISCM: [u8; 8] = [71, 201, 175, 122, 255, 207, 196, 207];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketStatusIxArgs {
     pub status: MarketStatus,
@@ -21975,6 +33540,67 @@ pub fn update_perp_market_status_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_status_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_status_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketStatusIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    status: Option<MarketStatus>,
+}
+impl Default for UpdatePerpMarketStatusIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketStatusIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            status: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn status(mut self, status: MarketStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpMarketStatusIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketStatusIxArgs {
+            status: self.status.ok_or_else(|| missing("status"))?,
+        };
+        let keys = UpdatePerpMarketStatusKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_status_ix(keys, args)
+    }
+}
 pub fn update_perp_market_status_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketStatusAccounts<'_, '_>,
@@ -22129,6 +33755,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_CONTRACT_TIER
         }
     }
 }
+impl UpdatePerpMarketContractTierKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_CONTRACT_TIER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_CONTRACT_TIER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_CONTRACT_TIER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketContractTierKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketContractTierAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_CONTRACT_TIER_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_CONTRACT_TIER_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_CONTRACT_TIER_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketContractTierAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_CONTRACT_TIER_IX_DISCM: [u8; 8] =
     [236, 128, 15, 95, 203, 214, 68, 117];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -22190,6 +33863,67 @@ pub fn update_perp_market_contract_tier_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_contract_tier_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_contract_tier_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketContractTierIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    contract_tier: Option<ContractTier>,
+}
+impl Default for UpdatePerpMarketContractTierIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketContractTierIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            contract_tier: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn contract_tier(mut self, contract_tier: ContractTier) -> Self {
+        self.contract_tier = Some(contract_tier);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpMarketContractTierIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketContractTierIxArgs {
+            contract_tier: self.contract_tier.ok_or_else(|| missing("contract_tier"))?,
+        };
+        let keys = UpdatePerpMarketContractTierKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_contract_tier_ix(keys, args)
+    }
+}
 pub fn update_perp_market_contract_tier_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketContractTierAccounts<'_, '_>,
@@ -22342,6 +34076,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_IMF_FACTOR_IX
         }
     }
 }
+impl UpdatePerpMarketImfFactorKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_IMF_FACTOR_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_IMF_FACTOR_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_IMF_FACTOR_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketImfFactorKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketImfFactorAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_IMF_FACTOR_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_IMF_FACTOR_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_IMF_FACTOR_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketImfFactorAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_IMF_FACTOR_IX_DISCM: [u8; 8] = [207, 194, 56, 132, 35, 67, 71, 244];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -22403,6 +34184,76 @@ pub fn update_perp_market_imf_factor_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_imf_factor_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_imf_factor_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketImfFactorIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    imf_factor: Option<u32>,
+    unrealized_pnl_imf_factor: Option<u32>,
+}
+impl Default for UpdatePerpMarketImfFactorIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketImfFactorIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            imf_factor: None,
+            unrealized_pnl_imf_factor: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn imf_factor(mut self, imf_factor: u32) -> Self {
+        self.imf_factor = Some(imf_factor);
+        self
+    }
+    pub fn unrealized_pnl_imf_factor(mut self, unrealized_pnl_imf_factor: u32) -> Self {
+        self.unrealized_pnl_imf_factor = Some(unrealized_pnl_imf_factor);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpMarketImfFactorIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketImfFactorIxArgs {
+            imf_factor: self.imf_factor.ok_or_else(|| missing("imf_factor"))?,
+            unrealized_pnl_imf_factor: self
+                .unrealized_pnl_imf_factor
+                .ok_or_else(|| missing("unrealized_pnl_imf_factor"))?,
+        };
+        let keys = UpdatePerpMarketImfFactorKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_imf_factor_ix(keys, args)
+    }
+}
 pub fn update_perp_market_imf_factor_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketImfFactorAccounts<'_, '_>,
@@ -22560,6 +34411,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdatePerpMarketUnrealizedAssetWeightKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketUnrealizedAssetWeightKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketUnrealizedAssetWeightAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketUnrealizedAssetWeightAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_DISCM: [u8; 8] =
     [135, 132, 205, 165, 109, 150, 166, 106];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -22627,6 +34525,84 @@ pub fn update_perp_market_unrealized_asset_weight_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_unrealized_asset_weight_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_unrealized_asset_weight_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketUnrealizedAssetWeightIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    unrealized_initial_asset_weight: Option<u32>,
+    unrealized_maintenance_asset_weight: Option<u32>,
+}
+impl Default for UpdatePerpMarketUnrealizedAssetWeightIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketUnrealizedAssetWeightIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            unrealized_initial_asset_weight: None,
+            unrealized_maintenance_asset_weight: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn unrealized_initial_asset_weight(mut self, unrealized_initial_asset_weight: u32) -> Self {
+        self.unrealized_initial_asset_weight = Some(unrealized_initial_asset_weight);
+        self
+    }
+    pub fn unrealized_maintenance_asset_weight(
+        mut self,
+        unrealized_maintenance_asset_weight: u32,
+    ) -> Self {
+        self.unrealized_maintenance_asset_weight = Some(unrealized_maintenance_asset_weight);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePerpMarketUnrealizedAssetWeightIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketUnrealizedAssetWeightIxArgs {
+            unrealized_initial_asset_weight: self
+                .unrealized_initial_asset_weight
+                .ok_or_else(|| missing("unrealized_initial_asset_weight"))?,
+            unrealized_maintenance_asset_weight: self
+                .unrealized_maintenance_asset_weight
+                .ok_or_else(|| missing("unrealized_maintenance_asset_weight"))?,
+        };
+        let keys = UpdatePerpMarketUnrealizedAssetWeightKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_unrealized_asset_weight_ix(keys, args)
+    }
+}
 pub fn update_perp_market_unrealized_asset_weight_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketUnrealizedAssetWeightAccounts<'_, '_>,
@@ -22789,6 +34765,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdatePerpMarketConcentrationCoefKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketConcentrationCoefKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketConcentrationCoefAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketConcentrationCoefAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_DISCM: [u8; 8] =
     [24, 78, 232, 126, 169, 176, 230, 16];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -22850,6 +34873,72 @@ pub fn update_perp_market_concentration_coef_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_concentration_coef_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_concentration_coef_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketConcentrationCoefIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    concentration_scale: Option<u128>,
+}
+impl Default for UpdatePerpMarketConcentrationCoefIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketConcentrationCoefIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            concentration_scale: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn concentration_scale(mut self, concentration_scale: u128) -> Self {
+        self.concentration_scale = Some(concentration_scale);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePerpMarketConcentrationCoefIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketConcentrationCoefIxArgs {
+            concentration_scale: self
+                .concentration_scale
+                .ok_or_else(|| missing("concentration_scale"))?,
+        };
+        let keys = UpdatePerpMarketConcentrationCoefKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_concentration_coef_ix(keys, args)
+    }
+}
 pub fn update_perp_market_concentration_coef_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketConcentrationCoefAccounts<'_, '_>,
@@ -23012,6 +35101,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdatePerpMarketCurveUpdateIntensityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketCurveUpdateIntensityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketCurveUpdateIntensityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketCurveUpdateIntensityAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_DISCM: [u8; 8] =
     [50, 131, 6, 156, 226, 231, 189, 72];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -23078,6 +35214,72 @@ pub fn update_perp_market_curve_update_intensity_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_curve_update_intensity_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_curve_update_intensity_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketCurveUpdateIntensityIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    curve_update_intensity: Option<u8>,
+}
+impl Default for UpdatePerpMarketCurveUpdateIntensityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketCurveUpdateIntensityIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            curve_update_intensity: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn curve_update_intensity(mut self, curve_update_intensity: u8) -> Self {
+        self.curve_update_intensity = Some(curve_update_intensity);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePerpMarketCurveUpdateIntensityIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketCurveUpdateIntensityIxArgs {
+            curve_update_intensity: self
+                .curve_update_intensity
+                .ok_or_else(|| missing("curve_update_intensity"))?,
+        };
+        let keys = UpdatePerpMarketCurveUpdateIntensityKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_curve_update_intensity_ix(keys, args)
+    }
+}
 pub fn update_perp_market_curve_update_intensity_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketCurveUpdateIntensityAccounts<'_, '_>,
@@ -23245,6 +35447,54 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdatePerpMarketTargetBaseAssetAmountPerLpKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_ACCOUNTS_LEN] =
+            pubkeys
+                .get(..UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_ACCOUNTS_LEN)
+                .and_then(|keys| keys.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketTargetBaseAssetAmountPerLpKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketTargetBaseAssetAmountPerLpAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketTargetBaseAssetAmountPerLpAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_DISCM: [u8; 8] =
     [62, 87, 68, 115, 29, 150, 150, 165];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -23311,6 +35561,72 @@ pub fn update_perp_market_target_base_asset_amount_per_lp_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_target_base_asset_amount_per_lp_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_target_base_asset_amount_per_lp_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketTargetBaseAssetAmountPerLpIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    target_base_asset_amount_per_lp: Option<i32>,
+}
+impl Default for UpdatePerpMarketTargetBaseAssetAmountPerLpIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketTargetBaseAssetAmountPerLpIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            target_base_asset_amount_per_lp: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn target_base_asset_amount_per_lp(mut self, target_base_asset_amount_per_lp: i32) -> Self {
+        self.target_base_asset_amount_per_lp = Some(target_base_asset_amount_per_lp);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePerpMarketTargetBaseAssetAmountPerLpIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketTargetBaseAssetAmountPerLpIxArgs {
+            target_base_asset_amount_per_lp: self
+                .target_base_asset_amount_per_lp
+                .ok_or_else(|| missing("target_base_asset_amount_per_lp"))?,
+        };
+        let keys = UpdatePerpMarketTargetBaseAssetAmountPerLpKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_target_base_asset_amount_per_lp_ix(keys, args)
+    }
+}
 pub fn update_perp_market_target_base_asset_amount_per_lp_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketTargetBaseAssetAmountPerLpAccounts<'_, '_>,
@@ -23458,6 +35774,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_LP_COOLDOWN_TIME_IX_ACCOU
         }
     }
 }
+impl UpdateLpCooldownTimeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_LP_COOLDOWN_TIME_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_LP_COOLDOWN_TIME_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_LP_COOLDOWN_TIME_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateLpCooldownTimeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateLpCooldownTimeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_LP_COOLDOWN_TIME_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_LP_COOLDOWN_TIME_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_LP_COOLDOWN_TIME_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateLpCooldownTimeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_LP_COOLDOWN_TIME_IX_DISCM: [u8; 8] = [198, 133, 88, 41, 241, 119, 61, 14];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -23516,6 +35876,58 @@ pub fn update_lp_cooldown_time_ix(
 ) -> std::io::Result<Instruction> {
     update_lp_cooldown_time_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_lp_cooldown_time_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateLpCooldownTimeIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    lp_cooldown_time: Option<u64>,
+}
+impl Default for UpdateLpCooldownTimeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateLpCooldownTimeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            lp_cooldown_time: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn lp_cooldown_time(mut self, lp_cooldown_time: u64) -> Self {
+        self.lp_cooldown_time = Some(lp_cooldown_time);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateLpCooldownTimeIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdateLpCooldownTimeIxArgs {
+            lp_cooldown_time: self
+                .lp_cooldown_time
+                .ok_or_else(|| missing("lp_cooldown_time"))?,
+        };
+        let keys = UpdateLpCooldownTimeKeys { admin, state };
+        update_lp_cooldown_time_ix(keys, args)
+    }
+}
 pub fn update_lp_cooldown_time_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateLpCooldownTimeAccounts<'_, '_>,
@@ -23649,6 +36061,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_FEE_STRUCTURE_IX_ACC
         }
     }
 }
+impl UpdatePerpFeeStructureKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_FEE_STRUCTURE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_FEE_STRUCTURE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_FEE_STRUCTURE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpFeeStructureKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpFeeStructureAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_FEE_STRUCTURE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_PERP_FEE_STRUCTURE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_FEE_STRUCTURE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdatePerpFeeStructureAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_FEE_STRUCTURE_IX_DISCM: [u8; 8] = [23, 178, 111, 203, 73, 22, 140, 75];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -23709,6 +36165,56 @@ pub fn update_perp_fee_structure_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_fee_structure_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_fee_structure_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpFeeStructureIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    fee_structure: Option<FeeStructure>,
+}
+impl Default for UpdatePerpFeeStructureIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpFeeStructureIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            fee_structure: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn fee_structure(mut self, fee_structure: FeeStructure) -> Self {
+        self.fee_structure = Some(fee_structure);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpFeeStructureIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdatePerpFeeStructureIxArgs {
+            fee_structure: self.fee_structure.ok_or_else(|| missing("fee_structure"))?,
+        };
+        let keys = UpdatePerpFeeStructureKeys { admin, state };
+        update_perp_fee_structure_ix(keys, args)
+    }
+}
 pub fn update_perp_fee_structure_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpFeeStructureAccounts<'_, '_>,
@@ -23842,6 +36348,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_FEE_STRUCTURE_IX_ACC
         }
     }
 }
+impl UpdateSpotFeeStructureKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_FEE_STRUCTURE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_FEE_STRUCTURE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_FEE_STRUCTURE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotFeeStructureKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotFeeStructureAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_FEE_STRUCTURE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_SPOT_FEE_STRUCTURE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_FEE_STRUCTURE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateSpotFeeStructureAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_FEE_STRUCTURE_IX_DISCM: [u8; 8] = [97, 216, 105, 131, 113, 246, 142, 141];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -23902,6 +36452,56 @@ pub fn update_spot_fee_structure_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_fee_structure_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_fee_structure_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotFeeStructureIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    fee_structure: Option<FeeStructure>,
+}
+impl Default for UpdateSpotFeeStructureIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotFeeStructureIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            fee_structure: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn fee_structure(mut self, fee_structure: FeeStructure) -> Self {
+        self.fee_structure = Some(fee_structure);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateSpotFeeStructureIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdateSpotFeeStructureIxArgs {
+            fee_structure: self.fee_structure.ok_or_else(|| missing("fee_structure"))?,
+        };
+        let keys = UpdateSpotFeeStructureKeys { admin, state };
+        update_spot_fee_structure_ix(keys, args)
+    }
+}
 pub fn update_spot_fee_structure_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotFeeStructureAccounts<'_, '_>,
@@ -24041,6 +36641,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_INITIAL_PCT_TO_LIQUIDATE_
         }
     }
 }
+impl UpdateInitialPctToLiquidateKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateInitialPctToLiquidateKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateInitialPctToLiquidateAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateInitialPctToLiquidateAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_DISCM: [u8; 8] =
     [210, 133, 225, 128, 194, 50, 13, 109];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -24102,6 +36749,58 @@ pub fn update_initial_pct_to_liquidate_ix(
 ) -> std::io::Result<Instruction> {
     update_initial_pct_to_liquidate_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_initial_pct_to_liquidate_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateInitialPctToLiquidateIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    initial_pct_to_liquidate: Option<u16>,
+}
+impl Default for UpdateInitialPctToLiquidateIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateInitialPctToLiquidateIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            initial_pct_to_liquidate: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn initial_pct_to_liquidate(mut self, initial_pct_to_liquidate: u16) -> Self {
+        self.initial_pct_to_liquidate = Some(initial_pct_to_liquidate);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateInitialPctToLiquidateIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdateInitialPctToLiquidateIxArgs {
+            initial_pct_to_liquidate: self
+                .initial_pct_to_liquidate
+                .ok_or_else(|| missing("initial_pct_to_liquidate"))?,
+        };
+        let keys = UpdateInitialPctToLiquidateKeys { admin, state };
+        update_initial_pct_to_liquidate_ix(keys, args)
+    }
+}
 pub fn update_initial_pct_to_liquidate_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateInitialPctToLiquidateAccounts<'_, '_>,
@@ -24237,6 +36936,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_LIQUIDATION_DURATION_IX_A
         }
     }
 }
+impl UpdateLiquidationDurationKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_LIQUIDATION_DURATION_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_LIQUIDATION_DURATION_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_LIQUIDATION_DURATION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateLiquidationDurationKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateLiquidationDurationAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_LIQUIDATION_DURATION_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_LIQUIDATION_DURATION_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_LIQUIDATION_DURATION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateLiquidationDurationAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_LIQUIDATION_DURATION_IX_DISCM: [u8; 8] = [28, 154, 20, 249, 102, 192, 73, 71];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -24297,6 +37042,58 @@ pub fn update_liquidation_duration_ix(
 ) -> std::io::Result<Instruction> {
     update_liquidation_duration_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_liquidation_duration_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateLiquidationDurationIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    liquidation_duration: Option<u8>,
+}
+impl Default for UpdateLiquidationDurationIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateLiquidationDurationIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            liquidation_duration: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn liquidation_duration(mut self, liquidation_duration: u8) -> Self {
+        self.liquidation_duration = Some(liquidation_duration);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateLiquidationDurationIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdateLiquidationDurationIxArgs {
+            liquidation_duration: self
+                .liquidation_duration
+                .ok_or_else(|| missing("liquidation_duration"))?,
+        };
+        let keys = UpdateLiquidationDurationKeys { admin, state };
+        update_liquidation_duration_ix(keys, args)
+    }
+}
 pub fn update_liquidation_duration_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateLiquidationDurationAccounts<'_, '_>,
@@ -24430,6 +37227,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_ORACLE_GUARD_RAILS_IX_ACC
         }
     }
 }
+impl UpdateOracleGuardRailsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_ORACLE_GUARD_RAILS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_ORACLE_GUARD_RAILS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_ORACLE_GUARD_RAILS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateOracleGuardRailsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateOracleGuardRailsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_ORACLE_GUARD_RAILS_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_ORACLE_GUARD_RAILS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_ORACLE_GUARD_RAILS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateOracleGuardRailsAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_ORACLE_GUARD_RAILS_IX_DISCM: [u8; 8] = [131, 112, 10, 59, 32, 54, 40, 164];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -24490,6 +37331,58 @@ pub fn update_oracle_guard_rails_ix(
 ) -> std::io::Result<Instruction> {
     update_oracle_guard_rails_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_oracle_guard_rails_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateOracleGuardRailsIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    oracle_guard_rails: Option<OracleGuardRails>,
+}
+impl Default for UpdateOracleGuardRailsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateOracleGuardRailsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            oracle_guard_rails: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn oracle_guard_rails(mut self, oracle_guard_rails: OracleGuardRails) -> Self {
+        self.oracle_guard_rails = Some(oracle_guard_rails);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateOracleGuardRailsIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdateOracleGuardRailsIxArgs {
+            oracle_guard_rails: self
+                .oracle_guard_rails
+                .ok_or_else(|| missing("oracle_guard_rails"))?,
+        };
+        let keys = UpdateOracleGuardRailsKeys { admin, state };
+        update_oracle_guard_rails_ix(keys, args)
+    }
+}
 pub fn update_oracle_guard_rails_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateOracleGuardRailsAccounts<'_, '_>,
@@ -24629,6 +37522,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_STATE_SETTLEMENT_DURATION
         }
     }
 }
+impl UpdateStateSettlementDurationKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_STATE_SETTLEMENT_DURATION_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_STATE_SETTLEMENT_DURATION_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_STATE_SETTLEMENT_DURATION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateStateSettlementDurationKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateStateSettlementDurationAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_STATE_SETTLEMENT_DURATION_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_STATE_SETTLEMENT_DURATION_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_STATE_SETTLEMENT_DURATION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateStateSettlementDurationAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_STATE_SETTLEMENT_DURATION_IX_DISCM: [u8; 8] = [97, 68, 199, 235, 131, 80, 61, 173];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -24689,6 +37629,61 @@ pub fn update_state_settlement_duration_ix(
 ) -> std::io::Result<Instruction> {
     update_state_settlement_duration_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_state_settlement_duration_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateStateSettlementDurationIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    settlement_duration: Option<u16>,
+}
+impl Default for UpdateStateSettlementDurationIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateStateSettlementDurationIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            settlement_duration: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn settlement_duration(mut self, settlement_duration: u16) -> Self {
+        self.settlement_duration = Some(settlement_duration);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdateStateSettlementDurationIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdateStateSettlementDurationIxArgs {
+            settlement_duration: self
+                .settlement_duration
+                .ok_or_else(|| missing("settlement_duration"))?,
+        };
+        let keys = UpdateStateSettlementDurationKeys { admin, state };
+        update_state_settlement_duration_ix(keys, args)
+    }
+}
 pub fn update_state_settlement_duration_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateStateSettlementDurationAccounts<'_, '_>,
@@ -24847,6 +37842,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_ORACLE_IX_ACC
         }
     }
 }
+impl UpdatePerpMarketOracleKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_ORACLE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_ORACLE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_ORACLE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketOracleKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketOracleAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_ORACLE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_PERP_MARKET_ORACLE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_ORACLE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdatePerpMarketOracleAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_ORACLE_IX_DISCM: [u8; 8] = [182, 113, 111, 160, 67, 174, 89, 191];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -24908,6 +37947,82 @@ pub fn update_perp_market_oracle_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_oracle_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_oracle_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketOracleIxBuilder {
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    oracle: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    oracle_arg: Option<Pubkey>,
+    oracle_source: Option<OracleSource>,
+}
+impl Default for UpdatePerpMarketOracleIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketOracleIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            perp_market: None,
+            oracle: None,
+            admin: None,
+            oracle_arg: None,
+            oracle_source: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn oracle(mut self, oracle: Pubkey) -> Self {
+        self.oracle = Some(oracle);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn oracle_arg(mut self, oracle_arg: Pubkey) -> Self {
+        self.oracle_arg = Some(oracle_arg);
+        self
+    }
+    pub fn oracle_source(mut self, oracle_source: OracleSource) -> Self {
+        self.oracle_source = Some(oracle_source);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpMarketOracleIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let oracle = self.oracle.ok_or_else(|| missing("oracle"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let args = UpdatePerpMarketOracleIxArgs {
+            oracle: self.oracle_arg.ok_or_else(|| missing("oracle_arg"))?,
+            oracle_source: self.oracle_source.ok_or_else(|| missing("oracle_source"))?,
+        };
+        let keys = UpdatePerpMarketOracleKeys {
+            state,
+            perp_market,
+            oracle,
+            admin,
+        };
+        update_perp_market_oracle_ix(keys, args)
+    }
+}
 pub fn update_perp_market_oracle_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketOracleAccounts<'_, '_>,
@@ -25063,14 +38178,61 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_BASE_SPREAD_I
         }
     }
 }
-pub const UPDATE_PERP_MARKET_BASE_SPREAD_IX_DISCM: [u8; 8] = [71, 95, 84, 168, 9, 157, 198, 65];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct UpdatePerpMarketBaseSpreadIxArgs {
-    pub base_spread: u32,
+impl UpdatePerpMarketBaseSpreadKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_BASE_SPREAD_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_BASE_SPREAD_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_BASE_SPREAD_IX_ACCOUNTS_LEN..],
+        ))
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
-pub struct UpdatePerpMarketBaseSpreadIxData(pub UpdatePerpMarketBaseSpreadIxArgs);
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketBaseSpreadKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketBaseSpreadAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_BASE_SPREAD_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_BASE_SPREAD_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_BASE_SPREAD_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketBaseSpreadAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const UPDATE_PERP_MARKET_BASE_SPREAD_IX_DISCM: [u8; 8] = [71, 95, 84, 168, 9, 157, 198, 65];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdatePerpMarketBaseSpreadIxArgs {
+    pub base_spread: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdatePerpMarketBaseSpreadIxData(pub UpdatePerpMarketBaseSpreadIxArgs);
 impl From<UpdatePerpMarketBaseSpreadIxArgs> for UpdatePerpMarketBaseSpreadIxData {
     fn from(args: UpdatePerpMarketBaseSpreadIxArgs) -> Self {
         Self(args)
@@ -25123,6 +38285,67 @@ pub fn update_perp_market_base_spread_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_base_spread_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_base_spread_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketBaseSpreadIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    base_spread: Option<u32>,
+}
+impl Default for UpdatePerpMarketBaseSpreadIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketBaseSpreadIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            base_spread: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn base_spread(mut self, base_spread: u32) -> Self {
+        self.base_spread = Some(base_spread);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpMarketBaseSpreadIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketBaseSpreadIxArgs {
+            base_spread: self.base_spread.ok_or_else(|| missing("base_spread"))?,
+        };
+        let keys = UpdatePerpMarketBaseSpreadKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_base_spread_ix(keys, args)
+    }
+}
 pub fn update_perp_market_base_spread_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketBaseSpreadAccounts<'_, '_>,
@@ -25271,6 +38494,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_AMM_JIT_INTENSITY_IX_ACCO
         }
     }
 }
+impl UpdateAmmJitIntensityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_AMM_JIT_INTENSITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_AMM_JIT_INTENSITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_AMM_JIT_INTENSITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateAmmJitIntensityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateAmmJitIntensityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_AMM_JIT_INTENSITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_AMM_JIT_INTENSITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_AMM_JIT_INTENSITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateAmmJitIntensityAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_AMM_JIT_INTENSITY_IX_DISCM: [u8; 8] = [181, 191, 53, 109, 166, 249, 55, 142];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -25329,6 +38596,69 @@ pub fn update_amm_jit_intensity_ix(
 ) -> std::io::Result<Instruction> {
     update_amm_jit_intensity_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_amm_jit_intensity_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateAmmJitIntensityIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    amm_jit_intensity: Option<u8>,
+}
+impl Default for UpdateAmmJitIntensityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateAmmJitIntensityIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            amm_jit_intensity: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn amm_jit_intensity(mut self, amm_jit_intensity: u8) -> Self {
+        self.amm_jit_intensity = Some(amm_jit_intensity);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateAmmJitIntensityIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdateAmmJitIntensityIxArgs {
+            amm_jit_intensity: self
+                .amm_jit_intensity
+                .ok_or_else(|| missing("amm_jit_intensity"))?,
+        };
+        let keys = UpdateAmmJitIntensityKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_amm_jit_intensity_ix(keys, args)
+    }
+}
 pub fn update_amm_jit_intensity_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateAmmJitIntensityAccounts<'_, '_>,
@@ -25481,6 +38811,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_SPREAD_IX
         }
     }
 }
+impl UpdatePerpMarketMaxSpreadKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_MAX_SPREAD_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_MAX_SPREAD_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_MAX_SPREAD_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketMaxSpreadKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketMaxSpreadAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_SPREAD_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_MAX_SPREAD_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_MAX_SPREAD_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketMaxSpreadAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_MAX_SPREAD_IX_DISCM: [u8; 8] = [80, 252, 122, 62, 40, 218, 91, 100];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -25541,6 +38918,67 @@ pub fn update_perp_market_max_spread_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_max_spread_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_max_spread_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketMaxSpreadIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    max_spread: Option<u32>,
+}
+impl Default for UpdatePerpMarketMaxSpreadIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketMaxSpreadIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            max_spread: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn max_spread(mut self, max_spread: u32) -> Self {
+        self.max_spread = Some(max_spread);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpMarketMaxSpreadIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketMaxSpreadIxArgs {
+            max_spread: self.max_spread.ok_or_else(|| missing("max_spread"))?,
+        };
+        let keys = UpdatePerpMarketMaxSpreadKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_max_spread_ix(keys, args)
+    }
+}
 pub fn update_perp_market_max_spread_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketMaxSpreadAccounts<'_, '_>,
@@ -25698,6 +39136,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdatePerpMarketStepSizeAndTickSizeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketStepSizeAndTickSizeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketStepSizeAndTickSizeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketStepSizeAndTickSizeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_DISCM: [u8; 8] =
     [231, 255, 97, 25, 146, 139, 174, 4];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -25761,6 +39246,77 @@ pub fn update_perp_market_step_size_and_tick_size_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_step_size_and_tick_size_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_step_size_and_tick_size_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketStepSizeAndTickSizeIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    step_size: Option<u64>,
+    tick_size: Option<u64>,
+}
+impl Default for UpdatePerpMarketStepSizeAndTickSizeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketStepSizeAndTickSizeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            step_size: None,
+            tick_size: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn step_size(mut self, step_size: u64) -> Self {
+        self.step_size = Some(step_size);
+        self
+    }
+    pub fn tick_size(mut self, tick_size: u64) -> Self {
+        self.tick_size = Some(tick_size);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePerpMarketStepSizeAndTickSizeIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketStepSizeAndTickSizeIxArgs {
+            step_size: self.step_size.ok_or_else(|| missing("step_size"))?,
+            tick_size: self.tick_size.ok_or_else(|| missing("tick_size"))?,
+        };
+        let keys = UpdatePerpMarketStepSizeAndTickSizeKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_step_size_and_tick_size_ix(keys, args)
+    }
+}
 pub fn update_perp_market_step_size_and_tick_size_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketStepSizeAndTickSizeAccounts<'_, '_>,
@@ -25914,6 +39470,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_NAME_IX_ACCOU
         }
     }
 }
+impl UpdatePerpMarketNameKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_NAME_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_NAME_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_NAME_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketNameKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketNameAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_NAME_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_PERP_MARKET_NAME_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_NAME_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdatePerpMarketNameAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_NAME_IX_DISCM: [u8; 8] = [211, 31, 21, 210, 64, 108, 66, 201];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -25972,6 +39572,67 @@ pub fn update_perp_market_name_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_name_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_name_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketNameIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    name: Option<[u8; 32]>,
+}
+impl Default for UpdatePerpMarketNameIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketNameIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            name: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn name(mut self, name: [u8; 32]) -> Self {
+        self.name = Some(name);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpMarketNameIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketNameIxArgs {
+            name: self.name.ok_or_else(|| missing("name"))?,
+        };
+        let keys = UpdatePerpMarketNameKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_name_ix(keys, args)
+    }
+}
 pub fn update_perp_market_name_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketNameAccounts<'_, '_>,
@@ -26126,6 +39787,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MIN_ORDER_SIZ
         }
     }
 }
+impl UpdatePerpMarketMinOrderSizeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketMinOrderSizeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketMinOrderSizeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketMinOrderSizeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_DISCM: [u8; 8] = [226, 74, 5, 89, 108, 223, 46, 141];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -26186,6 +39894,67 @@ pub fn update_perp_market_min_order_size_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_min_order_size_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_min_order_size_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketMinOrderSizeIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    order_size: Option<u64>,
+}
+impl Default for UpdatePerpMarketMinOrderSizeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketMinOrderSizeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            order_size: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn order_size(mut self, order_size: u64) -> Self {
+        self.order_size = Some(order_size);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpMarketMinOrderSizeIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketMinOrderSizeIxArgs {
+            order_size: self.order_size.ok_or_else(|| missing("order_size"))?,
+        };
+        let keys = UpdatePerpMarketMinOrderSizeKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_min_order_size_ix(keys, args)
+    }
+}
 pub fn update_perp_market_min_order_size_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketMinOrderSizeAccounts<'_, '_>,
@@ -26348,6 +40117,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdatePerpMarketMaxSlippageRatioKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketMaxSlippageRatioKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketMaxSlippageRatioAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketMaxSlippageRatioAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_DISCM: [u8; 8] =
     [235, 37, 40, 196, 70, 146, 54, 201];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -26409,6 +40225,72 @@ pub fn update_perp_market_max_slippage_ratio_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_max_slippage_ratio_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_max_slippage_ratio_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketMaxSlippageRatioIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    max_slippage_ratio: Option<u16>,
+}
+impl Default for UpdatePerpMarketMaxSlippageRatioIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketMaxSlippageRatioIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            max_slippage_ratio: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn max_slippage_ratio(mut self, max_slippage_ratio: u16) -> Self {
+        self.max_slippage_ratio = Some(max_slippage_ratio);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePerpMarketMaxSlippageRatioIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketMaxSlippageRatioIxArgs {
+            max_slippage_ratio: self
+                .max_slippage_ratio
+                .ok_or_else(|| missing("max_slippage_ratio"))?,
+        };
+        let keys = UpdatePerpMarketMaxSlippageRatioKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_max_slippage_ratio_ix(keys, args)
+    }
+}
 pub fn update_perp_market_max_slippage_ratio_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketMaxSlippageRatioAccounts<'_, '_>,
@@ -26574,6 +40456,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdatePerpMarketMaxFillReserveFractionKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketMaxFillReserveFractionKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketMaxFillReserveFractionAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketMaxFillReserveFractionAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_DISCM: [u8; 8] =
     [19, 172, 114, 154, 42, 135, 161, 133];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -26640,6 +40569,72 @@ pub fn update_perp_market_max_fill_reserve_fraction_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_max_fill_reserve_fraction_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_max_fill_reserve_fraction_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketMaxFillReserveFractionIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    max_fill_reserve_fraction: Option<u16>,
+}
+impl Default for UpdatePerpMarketMaxFillReserveFractionIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketMaxFillReserveFractionIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            max_fill_reserve_fraction: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn max_fill_reserve_fraction(mut self, max_fill_reserve_fraction: u16) -> Self {
+        self.max_fill_reserve_fraction = Some(max_fill_reserve_fraction);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePerpMarketMaxFillReserveFractionIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketMaxFillReserveFractionIxArgs {
+            max_fill_reserve_fraction: self
+                .max_fill_reserve_fraction
+                .ok_or_else(|| missing("max_fill_reserve_fraction"))?,
+        };
+        let keys = UpdatePerpMarketMaxFillReserveFractionKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_max_fill_reserve_fraction_ix(keys, args)
+    }
+}
 pub fn update_perp_market_max_fill_reserve_fraction_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketMaxFillReserveFractionAccounts<'_, '_>,
@@ -26802,6 +40797,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdatePerpMarketMaxOpenInterestKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpMarketMaxOpenInterestKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpMarketMaxOpenInterestAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpMarketMaxOpenInterestAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_DISCM: [u8; 8] =
     [194, 79, 149, 224, 246, 102, 186, 140];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -26863,6 +40905,72 @@ pub fn update_perp_market_max_open_interest_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_market_max_open_interest_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_market_max_open_interest_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpMarketMaxOpenInterestIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    perp_market: Option<Pubkey>,
+    max_open_interest: Option<u128>,
+}
+impl Default for UpdatePerpMarketMaxOpenInterestIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpMarketMaxOpenInterestIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            perp_market: None,
+            max_open_interest: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn perp_market(mut self, perp_market: Pubkey) -> Self {
+        self.perp_market = Some(perp_market);
+        self
+    }
+    pub fn max_open_interest(mut self, max_open_interest: u128) -> Self {
+        self.max_open_interest = Some(max_open_interest);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePerpMarketMaxOpenInterestIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let perp_market = self.perp_market.ok_or_else(|| missing("perp_market"))?;
+        let args = UpdatePerpMarketMaxOpenInterestIxArgs {
+            max_open_interest: self
+                .max_open_interest
+                .ok_or_else(|| missing("max_open_interest"))?,
+        };
+        let keys = UpdatePerpMarketMaxOpenInterestKeys {
+            admin,
+            state,
+            perp_market,
+        };
+        update_perp_market_max_open_interest_ix(keys, args)
+    }
+}
 pub fn update_perp_market_max_open_interest_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpMarketMaxOpenInterestAccounts<'_, '_>,
@@ -27002,6 +41110,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_ADMIN_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl UpdateAdminKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_ADMIN_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_ADMIN_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UPDATE_ADMIN_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateAdminKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateAdminAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_ADMIN_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_ADMIN_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UPDATE_ADMIN_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateAdminAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_ADMIN_IX_DISCM: [u8; 8] = [161, 176, 40, 213, 60, 184, 179, 228];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27060,6 +41206,56 @@ pub fn update_admin_ix(
 ) -> std::io::Result<Instruction> {
     update_admin_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_admin_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateAdminIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    admin_arg: Option<Pubkey>,
+}
+impl Default for UpdateAdminIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateAdminIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            admin_arg: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn admin_arg(mut self, admin_arg: Pubkey) -> Self {
+        self.admin_arg = Some(admin_arg);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateAdminIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdateAdminIxArgs {
+            admin: self.admin_arg.ok_or_else(|| missing("admin_arg"))?,
+        };
+        let keys = UpdateAdminKeys { admin, state };
+        update_admin_ix(keys, args)
+    }
+}
 pub fn update_admin_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateAdminAccounts<'_, '_>,
@@ -27193,6 +41389,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_WHITELIST_MINT_IX_ACCOUNT
         }
     }
 }
+impl UpdateWhitelistMintKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_WHITELIST_MINT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_WHITELIST_MINT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_WHITELIST_MINT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateWhitelistMintKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateWhitelistMintAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_WHITELIST_MINT_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_WHITELIST_MINT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_WHITELIST_MINT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateWhitelistMintAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_WHITELIST_MINT_IX_DISCM: [u8; 8] = [161, 15, 162, 19, 148, 120, 144, 151];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27251,6 +41491,58 @@ pub fn update_whitelist_mint_ix(
 ) -> std::io::Result<Instruction> {
     update_whitelist_mint_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_whitelist_mint_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateWhitelistMintIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    whitelist_mint: Option<Pubkey>,
+}
+impl Default for UpdateWhitelistMintIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateWhitelistMintIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            whitelist_mint: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn whitelist_mint(mut self, whitelist_mint: Pubkey) -> Self {
+        self.whitelist_mint = Some(whitelist_mint);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateWhitelistMintIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdateWhitelistMintIxArgs {
+            whitelist_mint: self
+                .whitelist_mint
+                .ok_or_else(|| missing("whitelist_mint"))?,
+        };
+        let keys = UpdateWhitelistMintKeys { admin, state };
+        update_whitelist_mint_ix(keys, args)
+    }
+}
 pub fn update_whitelist_mint_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateWhitelistMintAccounts<'_, '_>,
@@ -27384,6 +41676,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_DISCOUNT_MINT_IX_ACCOUNTS
         }
     }
 }
+impl UpdateDiscountMintKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_DISCOUNT_MINT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_DISCOUNT_MINT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_DISCOUNT_MINT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateDiscountMintKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateDiscountMintAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_DISCOUNT_MINT_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_DISCOUNT_MINT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_DISCOUNT_MINT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateDiscountMintAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_DISCOUNT_MINT_IX_DISCM: [u8; 8] = [32, 252, 122, 211, 66, 31, 47, 241];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27442,6 +41778,56 @@ pub fn update_discount_mint_ix(
 ) -> std::io::Result<Instruction> {
     update_discount_mint_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_discount_mint_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateDiscountMintIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    discount_mint: Option<Pubkey>,
+}
+impl Default for UpdateDiscountMintIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateDiscountMintIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            discount_mint: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn discount_mint(mut self, discount_mint: Pubkey) -> Self {
+        self.discount_mint = Some(discount_mint);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateDiscountMintIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdateDiscountMintIxArgs {
+            discount_mint: self.discount_mint.ok_or_else(|| missing("discount_mint"))?,
+        };
+        let keys = UpdateDiscountMintKeys { admin, state };
+        update_discount_mint_ix(keys, args)
+    }
+}
 pub fn update_discount_mint_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateDiscountMintAccounts<'_, '_>,
@@ -27575,6 +41961,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_EXCHANGE_STATUS_IX_ACCOUN
         }
     }
 }
+impl UpdateExchangeStatusKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_EXCHANGE_STATUS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_EXCHANGE_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_EXCHANGE_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateExchangeStatusKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateExchangeStatusAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_EXCHANGE_STATUS_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_EXCHANGE_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_EXCHANGE_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateExchangeStatusAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_EXCHANGE_STATUS_IX_DISCM: [u8; 8] = [83, 160, 252, 250, 129, 116, 49, 223];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27633,6 +42063,58 @@ pub fn update_exchange_status_ix(
 ) -> std::io::Result<Instruction> {
     update_exchange_status_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_exchange_status_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateExchangeStatusIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    exchange_status: Option<u8>,
+}
+impl Default for UpdateExchangeStatusIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateExchangeStatusIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            exchange_status: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn exchange_status(mut self, exchange_status: u8) -> Self {
+        self.exchange_status = Some(exchange_status);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateExchangeStatusIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdateExchangeStatusIxArgs {
+            exchange_status: self
+                .exchange_status
+                .ok_or_else(|| missing("exchange_status"))?,
+        };
+        let keys = UpdateExchangeStatusKeys { admin, state };
+        update_exchange_status_ix(keys, args)
+    }
+}
 pub fn update_exchange_status_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateExchangeStatusAccounts<'_, '_>,
@@ -27770,6 +42252,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_AUCTION_DURATION_IX_
         }
     }
 }
+impl UpdatePerpAuctionDurationKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PERP_AUCTION_DURATION_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PERP_AUCTION_DURATION_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PERP_AUCTION_DURATION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePerpAuctionDurationKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePerpAuctionDurationAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PERP_AUCTION_DURATION_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_PERP_AUCTION_DURATION_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PERP_AUCTION_DURATION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePerpAuctionDurationAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PERP_AUCTION_DURATION_IX_DISCM: [u8; 8] = [126, 110, 52, 174, 30, 206, 215, 90];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27830,6 +42358,58 @@ pub fn update_perp_auction_duration_ix(
 ) -> std::io::Result<Instruction> {
     update_perp_auction_duration_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_perp_auction_duration_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePerpAuctionDurationIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    min_perp_auction_duration: Option<u8>,
+}
+impl Default for UpdatePerpAuctionDurationIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePerpAuctionDurationIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            min_perp_auction_duration: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn min_perp_auction_duration(mut self, min_perp_auction_duration: u8) -> Self {
+        self.min_perp_auction_duration = Some(min_perp_auction_duration);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdatePerpAuctionDurationIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdatePerpAuctionDurationIxArgs {
+            min_perp_auction_duration: self
+                .min_perp_auction_duration
+                .ok_or_else(|| missing("min_perp_auction_duration"))?,
+        };
+        let keys = UpdatePerpAuctionDurationKeys { admin, state };
+        update_perp_auction_duration_ix(keys, args)
+    }
+}
 pub fn update_perp_auction_duration_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePerpAuctionDurationAccounts<'_, '_>,
@@ -27967,6 +42547,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_AUCTION_DURATION_IX_
         }
     }
 }
+impl UpdateSpotAuctionDurationKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_SPOT_AUCTION_DURATION_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_SPOT_AUCTION_DURATION_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_SPOT_AUCTION_DURATION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateSpotAuctionDurationKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateSpotAuctionDurationAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_SPOT_AUCTION_DURATION_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_SPOT_AUCTION_DURATION_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_SPOT_AUCTION_DURATION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateSpotAuctionDurationAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_SPOT_AUCTION_DURATION_IX_DISCM: [u8; 8] = [182, 178, 203, 72, 187, 143, 157, 107];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -28027,6 +42653,58 @@ pub fn update_spot_auction_duration_ix(
 ) -> std::io::Result<Instruction> {
     update_spot_auction_duration_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_spot_auction_duration_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateSpotAuctionDurationIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    default_spot_auction_duration: Option<u8>,
+}
+impl Default for UpdateSpotAuctionDurationIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateSpotAuctionDurationIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            default_spot_auction_duration: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn default_spot_auction_duration(mut self, default_spot_auction_duration: u8) -> Self {
+        self.default_spot_auction_duration = Some(default_spot_auction_duration);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateSpotAuctionDurationIxBuilder is missing {}", field),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let args = UpdateSpotAuctionDurationIxArgs {
+            default_spot_auction_duration: self
+                .default_spot_auction_duration
+                .ok_or_else(|| missing("default_spot_auction_duration"))?,
+        };
+        let keys = UpdateSpotAuctionDurationKeys { admin, state };
+        update_spot_auction_duration_ix(keys, args)
+    }
+}
 pub fn update_spot_auction_duration_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateSpotAuctionDurationAccounts<'_, '_>,
@@ -28224,6 +42902,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADMIN_REMOVE_INSURANCE_FUND_STAK
         }
     }
 }
+impl AdminRemoveInsuranceFundStakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for AdminRemoveInsuranceFundStakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AdminRemoveInsuranceFundStakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for AdminRemoveInsuranceFundStakeAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] =
     [35, 13, 111, 220, 103, 217, 174, 115];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
@@ -28286,6 +43011,115 @@ pub fn admin_remove_insurance_fund_stake_ix(
 ) -> std::io::Result<Instruction> {
     admin_remove_insurance_fund_stake_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`admin_remove_insurance_fund_stake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AdminRemoveInsuranceFundStakeIxBuilder {
+    admin: Option<Pubkey>,
+    state: Option<Pubkey>,
+    spot_market: Option<Pubkey>,
+    insurance_fund_vault: Option<Pubkey>,
+    drift_signer: Option<Pubkey>,
+    admin_token_account: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    market_index: Option<u16>,
+    amount: Option<u64>,
+}
+impl Default for AdminRemoveInsuranceFundStakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AdminRemoveInsuranceFundStakeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            admin: None,
+            state: None,
+            spot_market: None,
+            insurance_fund_vault: None,
+            drift_signer: None,
+            admin_token_account: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            market_index: None,
+            amount: None,
+        }
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn spot_market(mut self, spot_market: Pubkey) -> Self {
+        self.spot_market = Some(spot_market);
+        self
+    }
+    pub fn insurance_fund_vault(mut self, insurance_fund_vault: Pubkey) -> Self {
+        self.insurance_fund_vault = Some(insurance_fund_vault);
+        self
+    }
+    pub fn drift_signer(mut self, drift_signer: Pubkey) -> Self {
+        self.drift_signer = Some(drift_signer);
+        self
+    }
+    pub fn admin_token_account(mut self, admin_token_account: Pubkey) -> Self {
+        self.admin_token_account = Some(admin_token_account);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn market_index(mut self, market_index: u16) -> Self {
+        self.market_index = Some(market_index);
+        self
+    }
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "AdminRemoveInsuranceFundStakeIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let spot_market = self.spot_market.ok_or_else(|| missing("spot_market"))?;
+        let insurance_fund_vault = self
+            .insurance_fund_vault
+            .ok_or_else(|| missing("insurance_fund_vault"))?;
+        let drift_signer = self.drift_signer.ok_or_else(|| missing("drift_signer"))?;
+        let admin_token_account = self
+            .admin_token_account
+            .ok_or_else(|| missing("admin_token_account"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = AdminRemoveInsuranceFundStakeIxArgs {
+            market_index: self.market_index.ok_or_else(|| missing("market_index"))?,
+            amount: self.amount.ok_or_else(|| missing("amount"))?,
+        };
+        let keys = AdminRemoveInsuranceFundStakeKeys {
+            admin,
+            state,
+            spot_market,
+            insurance_fund_vault,
+            drift_signer,
+            admin_token_account,
+            token_program,
+        };
+        admin_remove_insurance_fund_stake_ix(keys, args)
+    }
+}
 pub fn admin_remove_insurance_fund_stake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AdminRemoveInsuranceFundStakeAccounts<'_, '_>,
@@ -28372,3 +43206,1082 @@ pub fn admin_remove_insurance_fund_stake_verify_account_privileges<'me, 'info>(
     admin_remove_insurance_fund_stake_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// A [`DriftProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DriftParsedIx {
+    InitializeUser {
+        keys: InitializeUserKeys,
+        args: InitializeUserIxArgs,
+    },
+    InitializeUserStats {
+        keys: InitializeUserStatsKeys,
+    },
+    InitializeReferrerName {
+        keys: InitializeReferrerNameKeys,
+        args: InitializeReferrerNameIxArgs,
+    },
+    Deposit {
+        keys: DepositKeys,
+        args: DepositIxArgs,
+    },
+    Withdraw {
+        keys: WithdrawKeys,
+        args: WithdrawIxArgs,
+    },
+    TransferDeposit {
+        keys: TransferDepositKeys,
+        args: TransferDepositIxArgs,
+    },
+    PlacePerpOrder {
+        keys: PlacePerpOrderKeys,
+        args: PlacePerpOrderIxArgs,
+    },
+    CancelOrder {
+        keys: CancelOrderKeys,
+        args: CancelOrderIxArgs,
+    },
+    CancelOrderByUserId {
+        keys: CancelOrderByUserIdKeys,
+        args: CancelOrderByUserIdIxArgs,
+    },
+    CancelOrders {
+        keys: CancelOrdersKeys,
+        args: CancelOrdersIxArgs,
+    },
+    ModifyOrder {
+        keys: ModifyOrderKeys,
+        args: ModifyOrderIxArgs,
+    },
+    ModifyOrderByUserId {
+        keys: ModifyOrderByUserIdKeys,
+        args: ModifyOrderByUserIdIxArgs,
+    },
+    PlaceAndTakePerpOrder {
+        keys: PlaceAndTakePerpOrderKeys,
+        args: PlaceAndTakePerpOrderIxArgs,
+    },
+    PlaceAndMakePerpOrder {
+        keys: PlaceAndMakePerpOrderKeys,
+        args: PlaceAndMakePerpOrderIxArgs,
+    },
+    PlaceSpotOrder {
+        keys: PlaceSpotOrderKeys,
+        args: PlaceSpotOrderIxArgs,
+    },
+    PlaceAndTakeSpotOrder {
+        keys: PlaceAndTakeSpotOrderKeys,
+        args: PlaceAndTakeSpotOrderIxArgs,
+    },
+    PlaceAndMakeSpotOrder {
+        keys: PlaceAndMakeSpotOrderKeys,
+        args: PlaceAndMakeSpotOrderIxArgs,
+    },
+    BeginSwap {
+        keys: BeginSwapKeys,
+        args: BeginSwapIxArgs,
+    },
+    EndSwap {
+        keys: EndSwapKeys,
+        args: EndSwapIxArgs,
+    },
+    AddPerpLpShares {
+        keys: AddPerpLpSharesKeys,
+        args: AddPerpLpSharesIxArgs,
+    },
+    RemovePerpLpShares {
+        keys: RemovePerpLpSharesKeys,
+        args: RemovePerpLpSharesIxArgs,
+    },
+    RemovePerpLpSharesInExpiringMarket {
+        keys: RemovePerpLpSharesInExpiringMarketKeys,
+        args: RemovePerpLpSharesInExpiringMarketIxArgs,
+    },
+    UpdateUserName {
+        keys: UpdateUserNameKeys,
+        args: UpdateUserNameIxArgs,
+    },
+    UpdateUserCustomMarginRatio {
+        keys: UpdateUserCustomMarginRatioKeys,
+        args: UpdateUserCustomMarginRatioIxArgs,
+    },
+    UpdateUserMarginTradingEnabled {
+        keys: UpdateUserMarginTradingEnabledKeys,
+        args: UpdateUserMarginTradingEnabledIxArgs,
+    },
+    UpdateUserDelegate {
+        keys: UpdateUserDelegateKeys,
+        args: UpdateUserDelegateIxArgs,
+    },
+    DeleteUser {
+        keys: DeleteUserKeys,
+    },
+    FillPerpOrder {
+        keys: FillPerpOrderKeys,
+        args: FillPerpOrderIxArgs,
+    },
+    RevertFill {
+        keys: RevertFillKeys,
+    },
+    FillSpotOrder {
+        keys: FillSpotOrderKeys,
+        args: FillSpotOrderIxArgs,
+    },
+    TriggerOrder {
+        keys: TriggerOrderKeys,
+        args: TriggerOrderIxArgs,
+    },
+    ForceCancelOrders {
+        keys: ForceCancelOrdersKeys,
+    },
+    UpdateUserIdle {
+        keys: UpdateUserIdleKeys,
+    },
+    UpdateUserOpenOrdersCount {
+        keys: UpdateUserOpenOrdersCountKeys,
+    },
+    SettlePnl {
+        keys: SettlePnlKeys,
+        args: SettlePnlIxArgs,
+    },
+    SettleFundingPayment {
+        keys: SettleFundingPaymentKeys,
+    },
+    SettleLp {
+        keys: SettleLpKeys,
+        args: SettleLpIxArgs,
+    },
+    SettleExpiredMarket {
+        keys: SettleExpiredMarketKeys,
+        args: SettleExpiredMarketIxArgs,
+    },
+    LiquidatePerp {
+        keys: LiquidatePerpKeys,
+        args: LiquidatePerpIxArgs,
+    },
+    LiquidateSpot {
+        keys: LiquidateSpotKeys,
+        args: LiquidateSpotIxArgs,
+    },
+    LiquidateBorrowForPerpPnl {
+        keys: LiquidateBorrowForPerpPnlKeys,
+        args: LiquidateBorrowForPerpPnlIxArgs,
+    },
+    LiquidatePerpPnlForDeposit {
+        keys: LiquidatePerpPnlForDepositKeys,
+        args: LiquidatePerpPnlForDepositIxArgs,
+    },
+    ResolvePerpPnlDeficit {
+        keys: ResolvePerpPnlDeficitKeys,
+        args: ResolvePerpPnlDeficitIxArgs,
+    },
+    ResolvePerpBankruptcy {
+        keys: ResolvePerpBankruptcyKeys,
+        args: ResolvePerpBankruptcyIxArgs,
+    },
+    ResolveSpotBankruptcy {
+        keys: ResolveSpotBankruptcyKeys,
+        args: ResolveSpotBankruptcyIxArgs,
+    },
+    SettleRevenueToInsuranceFund {
+        keys: SettleRevenueToInsuranceFundKeys,
+        args: SettleRevenueToInsuranceFundIxArgs,
+    },
+    UpdateFundingRate {
+        keys: UpdateFundingRateKeys,
+        args: UpdateFundingRateIxArgs,
+    },
+    UpdateSpotMarketCumulativeInterest {
+        keys: UpdateSpotMarketCumulativeInterestKeys,
+    },
+    UpdateAmms {
+        keys: UpdateAmmsKeys,
+        args: UpdateAmmsIxArgs,
+    },
+    UpdateSpotMarketExpiry {
+        keys: UpdateSpotMarketExpiryKeys,
+        args: UpdateSpotMarketExpiryIxArgs,
+    },
+    UpdateUserQuoteAssetInsuranceStake {
+        keys: UpdateUserQuoteAssetInsuranceStakeKeys,
+    },
+    InitializeInsuranceFundStake {
+        keys: InitializeInsuranceFundStakeKeys,
+        args: InitializeInsuranceFundStakeIxArgs,
+    },
+    AddInsuranceFundStake {
+        keys: AddInsuranceFundStakeKeys,
+        args: AddInsuranceFundStakeIxArgs,
+    },
+    RequestRemoveInsuranceFundStake {
+        keys: RequestRemoveInsuranceFundStakeKeys,
+        args: RequestRemoveInsuranceFundStakeIxArgs,
+    },
+    CancelRequestRemoveInsuranceFundStake {
+        keys: CancelRequestRemoveInsuranceFundStakeKeys,
+        args: CancelRequestRemoveInsuranceFundStakeIxArgs,
+    },
+    RemoveInsuranceFundStake {
+        keys: RemoveInsuranceFundStakeKeys,
+        args: RemoveInsuranceFundStakeIxArgs,
+    },
+    Initialize {
+        keys: InitializeKeys,
+    },
+    InitializeSpotMarket {
+        keys: InitializeSpotMarketKeys,
+        args: InitializeSpotMarketIxArgs,
+    },
+    InitializeSerumFulfillmentConfig {
+        keys: InitializeSerumFulfillmentConfigKeys,
+        args: InitializeSerumFulfillmentConfigIxArgs,
+    },
+    UpdateSerumFulfillmentConfigStatus {
+        keys: UpdateSerumFulfillmentConfigStatusKeys,
+        args: UpdateSerumFulfillmentConfigStatusIxArgs,
+    },
+    InitializePhoenixFulfillmentConfig {
+        keys: InitializePhoenixFulfillmentConfigKeys,
+        args: InitializePhoenixFulfillmentConfigIxArgs,
+    },
+    PhoenixFulfillmentConfigStatus {
+        keys: PhoenixFulfillmentConfigStatusKeys,
+        args: PhoenixFulfillmentConfigStatusIxArgs,
+    },
+    UpdateSerumVault {
+        keys: UpdateSerumVaultKeys,
+    },
+    InitializePerpMarket {
+        keys: InitializePerpMarketKeys,
+        args: InitializePerpMarketIxArgs,
+    },
+    DeleteInitializedPerpMarket {
+        keys: DeleteInitializedPerpMarketKeys,
+        args: DeleteInitializedPerpMarketIxArgs,
+    },
+    MoveAmmPrice {
+        keys: MoveAmmPriceKeys,
+        args: MoveAmmPriceIxArgs,
+    },
+    UpdatePerpMarketExpiry {
+        keys: UpdatePerpMarketExpiryKeys,
+        args: UpdatePerpMarketExpiryIxArgs,
+    },
+    SettleExpiredMarketPoolsToRevenuePool {
+        keys: SettleExpiredMarketPoolsToRevenuePoolKeys,
+    },
+    DepositIntoPerpMarketFeePool {
+        keys: DepositIntoPerpMarketFeePoolKeys,
+        args: DepositIntoPerpMarketFeePoolIxArgs,
+    },
+    RepegAmmCurve {
+        keys: RepegAmmCurveKeys,
+        args: RepegAmmCurveIxArgs,
+    },
+    UpdatePerpMarketAmmOracleTwap {
+        keys: UpdatePerpMarketAmmOracleTwapKeys,
+    },
+    ResetPerpMarketAmmOracleTwap {
+        keys: ResetPerpMarketAmmOracleTwapKeys,
+    },
+    UpdateK {
+        keys: UpdateKKeys,
+        args: UpdateKIxArgs,
+    },
+    UpdatePerpMarketMarginRatio {
+        keys: UpdatePerpMarketMarginRatioKeys,
+        args: UpdatePerpMarketMarginRatioIxArgs,
+    },
+    UpdatePerpMarketMaxImbalances {
+        keys: UpdatePerpMarketMaxImbalancesKeys,
+        args: UpdatePerpMarketMaxImbalancesIxArgs,
+    },
+    UpdatePerpMarketLiquidationFee {
+        keys: UpdatePerpMarketLiquidationFeeKeys,
+        args: UpdatePerpMarketLiquidationFeeIxArgs,
+    },
+    UpdateInsuranceFundUnstakingPeriod {
+        keys: UpdateInsuranceFundUnstakingPeriodKeys,
+        args: UpdateInsuranceFundUnstakingPeriodIxArgs,
+    },
+    UpdateSpotMarketLiquidationFee {
+        keys: UpdateSpotMarketLiquidationFeeKeys,
+        args: UpdateSpotMarketLiquidationFeeIxArgs,
+    },
+    UpdateWithdrawGuardThreshold {
+        keys: UpdateWithdrawGuardThresholdKeys,
+        args: UpdateWithdrawGuardThresholdIxArgs,
+    },
+    UpdateSpotMarketIfFactor {
+        keys: UpdateSpotMarketIfFactorKeys,
+        args: UpdateSpotMarketIfFactorIxArgs,
+    },
+    UpdateSpotMarketRevenueSettlePeriod {
+        keys: UpdateSpotMarketRevenueSettlePeriodKeys,
+        args: UpdateSpotMarketRevenueSettlePeriodIxArgs,
+    },
+    UpdateSpotMarketStatus {
+        keys: UpdateSpotMarketStatusKeys,
+        args: UpdateSpotMarketStatusIxArgs,
+    },
+    UpdateSpotMarketAssetTier {
+        keys: UpdateSpotMarketAssetTierKeys,
+        args: UpdateSpotMarketAssetTierIxArgs,
+    },
+    UpdateSpotMarketMarginWeights {
+        keys: UpdateSpotMarketMarginWeightsKeys,
+        args: UpdateSpotMarketMarginWeightsIxArgs,
+    },
+    UpdateSpotMarketBorrowRate {
+        keys: UpdateSpotMarketBorrowRateKeys,
+        args: UpdateSpotMarketBorrowRateIxArgs,
+    },
+    UpdateSpotMarketMaxTokenDeposits {
+        keys: UpdateSpotMarketMaxTokenDepositsKeys,
+        args: UpdateSpotMarketMaxTokenDepositsIxArgs,
+    },
+    UpdateSpotMarketOracle {
+        keys: UpdateSpotMarketOracleKeys,
+        args: UpdateSpotMarketOracleIxArgs,
+    },
+    UpdateSpotMarketStepSizeAndTickSize {
+        keys: UpdateSpotMarketStepSizeAndTickSizeKeys,
+        args: UpdateSpotMarketStepSizeAndTickSizeIxArgs,
+    },
+    UpdateSpotMarketMinOrderSize {
+        keys: UpdateSpotMarketMinOrderSizeKeys,
+        args: UpdateSpotMarketMinOrderSizeIxArgs,
+    },
+    UpdateSpotMarketOrdersEnabled {
+        keys: UpdateSpotMarketOrdersEnabledKeys,
+        args: UpdateSpotMarketOrdersEnabledIxArgs,
+    },
+    UpdateSpotMarketName {
+        keys: UpdateSpotMarketNameKeys,
+        args: UpdateSpotMarketNameIxArgs,
+    },
+    UpdatePerpMarketStatus {
+        keys: UpdatePerpMarketStatusKeys,
+        args: UpdatePerpMarketStatusIxArgs,
+    },
+    UpdatePerpMarketContractTier {
+        keys: UpdatePerpMarketContractTierKeys,
+        args: UpdatePerpMarketContractTierIxArgs,
+    },
+    UpdatePerpMarketImfFactor {
+        keys: UpdatePerpMarketImfFactorKeys,
+        args: UpdatePerpMarketImfFactorIxArgs,
+    },
+    UpdatePerpMarketUnrealizedAssetWeight {
+        keys: UpdatePerpMarketUnrealizedAssetWeightKeys,
+        args: UpdatePerpMarketUnrealizedAssetWeightIxArgs,
+    },
+    UpdatePerpMarketConcentrationCoef {
+        keys: UpdatePerpMarketConcentrationCoefKeys,
+        args: UpdatePerpMarketConcentrationCoefIxArgs,
+    },
+    UpdatePerpMarketCurveUpdateIntensity {
+        keys: UpdatePerpMarketCurveUpdateIntensityKeys,
+        args: UpdatePerpMarketCurveUpdateIntensityIxArgs,
+    },
+    UpdatePerpMarketTargetBaseAssetAmountPerLp {
+        keys: UpdatePerpMarketTargetBaseAssetAmountPerLpKeys,
+        args: UpdatePerpMarketTargetBaseAssetAmountPerLpIxArgs,
+    },
+    UpdateLpCooldownTime {
+        keys: UpdateLpCooldownTimeKeys,
+        args: UpdateLpCooldownTimeIxArgs,
+    },
+    UpdatePerpFeeStructure {
+        keys: UpdatePerpFeeStructureKeys,
+        args: UpdatePerpFeeStructureIxArgs,
+    },
+    UpdateSpotFeeStructure {
+        keys: UpdateSpotFeeStructureKeys,
+        args: UpdateSpotFeeStructureIxArgs,
+    },
+    UpdateInitialPctToLiquidate {
+        keys: UpdateInitialPctToLiquidateKeys,
+        args: UpdateInitialPctToLiquidateIxArgs,
+    },
+    UpdateLiquidationDuration {
+        keys: UpdateLiquidationDurationKeys,
+        args: UpdateLiquidationDurationIxArgs,
+    },
+    UpdateOracleGuardRails {
+        keys: UpdateOracleGuardRailsKeys,
+        args: UpdateOracleGuardRailsIxArgs,
+    },
+    UpdateStateSettlementDuration {
+        keys: UpdateStateSettlementDurationKeys,
+        args: UpdateStateSettlementDurationIxArgs,
+    },
+    UpdatePerpMarketOracle {
+        keys: UpdatePerpMarketOracleKeys,
+        args: UpdatePerpMarketOracleIxArgs,
+    },
+    UpdatePerpMarketBaseSpread {
+        keys: UpdatePerpMarketBaseSpreadKeys,
+        args: UpdatePerpMarketBaseSpreadIxArgs,
+    },
+    UpdateAmmJitIntensity {
+        keys: UpdateAmmJitIntensityKeys,
+        args: UpdateAmmJitIntensityIxArgs,
+    },
+    UpdatePerpMarketMaxSpread {
+        keys: UpdatePerpMarketMaxSpreadKeys,
+        args: UpdatePerpMarketMaxSpreadIxArgs,
+    },
+    UpdatePerpMarketStepSizeAndTickSize {
+        keys: UpdatePerpMarketStepSizeAndTickSizeKeys,
+        args: UpdatePerpMarketStepSizeAndTickSizeIxArgs,
+    },
+    UpdatePerpMarketName {
+        keys: UpdatePerpMarketNameKeys,
+        args: UpdatePerpMarketNameIxArgs,
+    },
+    UpdatePerpMarketMinOrderSize {
+        keys: UpdatePerpMarketMinOrderSizeKeys,
+        args: UpdatePerpMarketMinOrderSizeIxArgs,
+    },
+    UpdatePerpMarketMaxSlippageRatio {
+        keys: UpdatePerpMarketMaxSlippageRatioKeys,
+        args: UpdatePerpMarketMaxSlippageRatioIxArgs,
+    },
+    UpdatePerpMarketMaxFillReserveFraction {
+        keys: UpdatePerpMarketMaxFillReserveFractionKeys,
+        args: UpdatePerpMarketMaxFillReserveFractionIxArgs,
+    },
+    UpdatePerpMarketMaxOpenInterest {
+        keys: UpdatePerpMarketMaxOpenInterestKeys,
+        args: UpdatePerpMarketMaxOpenInterestIxArgs,
+    },
+    UpdateAdmin {
+        keys: UpdateAdminKeys,
+        args: UpdateAdminIxArgs,
+    },
+    UpdateWhitelistMint {
+        keys: UpdateWhitelistMintKeys,
+        args: UpdateWhitelistMintIxArgs,
+    },
+    UpdateDiscountMint {
+        keys: UpdateDiscountMintKeys,
+        args: UpdateDiscountMintIxArgs,
+    },
+    UpdateExchangeStatus {
+        keys: UpdateExchangeStatusKeys,
+        args: UpdateExchangeStatusIxArgs,
+    },
+    UpdatePerpAuctionDuration {
+        keys: UpdatePerpAuctionDurationKeys,
+        args: UpdatePerpAuctionDurationIxArgs,
+    },
+    UpdateSpotAuctionDuration {
+        keys: UpdateSpotAuctionDurationKeys,
+        args: UpdateSpotAuctionDurationIxArgs,
+    },
+    AdminRemoveInsuranceFundStake {
+        keys: AdminRemoveInsuranceFundStakeKeys,
+        args: AdminRemoveInsuranceFundStakeIxArgs,
+    },
+}
+impl DriftParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = DriftProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            DriftProgramIx::InitializeUser(args) => Self::InitializeUser {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::InitializeUserStats => Self::InitializeUserStats {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            DriftProgramIx::InitializeReferrerName(args) => Self::InitializeReferrerName {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::Deposit(args) => Self::Deposit {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::Withdraw(args) => Self::Withdraw {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::TransferDeposit(args) => Self::TransferDeposit {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::PlacePerpOrder(args) => Self::PlacePerpOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::CancelOrder(args) => Self::CancelOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::CancelOrderByUserId(args) => Self::CancelOrderByUserId {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::CancelOrders(args) => Self::CancelOrders {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::ModifyOrder(args) => Self::ModifyOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::ModifyOrderByUserId(args) => Self::ModifyOrderByUserId {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::PlaceAndTakePerpOrder(args) => Self::PlaceAndTakePerpOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::PlaceAndMakePerpOrder(args) => Self::PlaceAndMakePerpOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::PlaceSpotOrder(args) => Self::PlaceSpotOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::PlaceAndTakeSpotOrder(args) => Self::PlaceAndTakeSpotOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::PlaceAndMakeSpotOrder(args) => Self::PlaceAndMakeSpotOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::BeginSwap(args) => Self::BeginSwap {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::EndSwap(args) => Self::EndSwap {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::AddPerpLpShares(args) => Self::AddPerpLpShares {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::RemovePerpLpShares(args) => Self::RemovePerpLpShares {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::RemovePerpLpSharesInExpiringMarket(args) => {
+                Self::RemovePerpLpSharesInExpiringMarket {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateUserName(args) => Self::UpdateUserName {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateUserCustomMarginRatio(args) => {
+                Self::UpdateUserCustomMarginRatio {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateUserMarginTradingEnabled(args) => {
+                Self::UpdateUserMarginTradingEnabled {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateUserDelegate(args) => Self::UpdateUserDelegate {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::DeleteUser => Self::DeleteUser {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            DriftProgramIx::FillPerpOrder(args) => Self::FillPerpOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::RevertFill => Self::RevertFill {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            DriftProgramIx::FillSpotOrder(args) => Self::FillSpotOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::TriggerOrder(args) => Self::TriggerOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::ForceCancelOrders => Self::ForceCancelOrders {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            DriftProgramIx::UpdateUserIdle => Self::UpdateUserIdle {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            DriftProgramIx::UpdateUserOpenOrdersCount => Self::UpdateUserOpenOrdersCount {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            DriftProgramIx::SettlePnl(args) => Self::SettlePnl {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::SettleFundingPayment => Self::SettleFundingPayment {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            DriftProgramIx::SettleLp(args) => Self::SettleLp {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::SettleExpiredMarket(args) => Self::SettleExpiredMarket {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::LiquidatePerp(args) => Self::LiquidatePerp {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::LiquidateSpot(args) => Self::LiquidateSpot {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::LiquidateBorrowForPerpPnl(args) => Self::LiquidateBorrowForPerpPnl {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::LiquidatePerpPnlForDeposit(args) => Self::LiquidatePerpPnlForDeposit {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::ResolvePerpPnlDeficit(args) => Self::ResolvePerpPnlDeficit {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::ResolvePerpBankruptcy(args) => Self::ResolvePerpBankruptcy {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::ResolveSpotBankruptcy(args) => Self::ResolveSpotBankruptcy {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::SettleRevenueToInsuranceFund(args) => {
+                Self::SettleRevenueToInsuranceFund {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateFundingRate(args) => Self::UpdateFundingRate {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateSpotMarketCumulativeInterest => {
+                Self::UpdateSpotMarketCumulativeInterest {
+                    keys: parsed_ix_keys(accounts)?,
+                }
+            }
+            DriftProgramIx::UpdateAmms(args) => Self::UpdateAmms {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateSpotMarketExpiry(args) => Self::UpdateSpotMarketExpiry {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateUserQuoteAssetInsuranceStake => {
+                Self::UpdateUserQuoteAssetInsuranceStake {
+                    keys: parsed_ix_keys(accounts)?,
+                }
+            }
+            DriftProgramIx::InitializeInsuranceFundStake(args) => {
+                Self::InitializeInsuranceFundStake {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::AddInsuranceFundStake(args) => Self::AddInsuranceFundStake {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::RequestRemoveInsuranceFundStake(args) => {
+                Self::RequestRemoveInsuranceFundStake {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::CancelRequestRemoveInsuranceFundStake(args) => {
+                Self::CancelRequestRemoveInsuranceFundStake {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::RemoveInsuranceFundStake(args) => Self::RemoveInsuranceFundStake {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::Initialize => Self::Initialize {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            DriftProgramIx::InitializeSpotMarket(args) => Self::InitializeSpotMarket {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::InitializeSerumFulfillmentConfig(args) => {
+                Self::InitializeSerumFulfillmentConfig {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateSerumFulfillmentConfigStatus(args) => {
+                Self::UpdateSerumFulfillmentConfigStatus {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::InitializePhoenixFulfillmentConfig(args) => {
+                Self::InitializePhoenixFulfillmentConfig {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::PhoenixFulfillmentConfigStatus(args) => {
+                Self::PhoenixFulfillmentConfigStatus {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateSerumVault => Self::UpdateSerumVault {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            DriftProgramIx::InitializePerpMarket(args) => Self::InitializePerpMarket {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::DeleteInitializedPerpMarket(args) => {
+                Self::DeleteInitializedPerpMarket {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::MoveAmmPrice(args) => Self::MoveAmmPrice {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpMarketExpiry(args) => Self::UpdatePerpMarketExpiry {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::SettleExpiredMarketPoolsToRevenuePool => {
+                Self::SettleExpiredMarketPoolsToRevenuePool {
+                    keys: parsed_ix_keys(accounts)?,
+                }
+            }
+            DriftProgramIx::DepositIntoPerpMarketFeePool(args) => {
+                Self::DepositIntoPerpMarketFeePool {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::RepegAmmCurve(args) => Self::RepegAmmCurve {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpMarketAmmOracleTwap => Self::UpdatePerpMarketAmmOracleTwap {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            DriftProgramIx::ResetPerpMarketAmmOracleTwap => Self::ResetPerpMarketAmmOracleTwap {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            DriftProgramIx::UpdateK(args) => Self::UpdateK {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpMarketMarginRatio(args) => {
+                Self::UpdatePerpMarketMarginRatio {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdatePerpMarketMaxImbalances(args) => {
+                Self::UpdatePerpMarketMaxImbalances {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdatePerpMarketLiquidationFee(args) => {
+                Self::UpdatePerpMarketLiquidationFee {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateInsuranceFundUnstakingPeriod(args) => {
+                Self::UpdateInsuranceFundUnstakingPeriod {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateSpotMarketLiquidationFee(args) => {
+                Self::UpdateSpotMarketLiquidationFee {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateWithdrawGuardThreshold(args) => {
+                Self::UpdateWithdrawGuardThreshold {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateSpotMarketIfFactor(args) => Self::UpdateSpotMarketIfFactor {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateSpotMarketRevenueSettlePeriod(args) => {
+                Self::UpdateSpotMarketRevenueSettlePeriod {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateSpotMarketStatus(args) => Self::UpdateSpotMarketStatus {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateSpotMarketAssetTier(args) => Self::UpdateSpotMarketAssetTier {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateSpotMarketMarginWeights(args) => {
+                Self::UpdateSpotMarketMarginWeights {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateSpotMarketBorrowRate(args) => Self::UpdateSpotMarketBorrowRate {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateSpotMarketMaxTokenDeposits(args) => {
+                Self::UpdateSpotMarketMaxTokenDeposits {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateSpotMarketOracle(args) => Self::UpdateSpotMarketOracle {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateSpotMarketStepSizeAndTickSize(args) => {
+                Self::UpdateSpotMarketStepSizeAndTickSize {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateSpotMarketMinOrderSize(args) => {
+                Self::UpdateSpotMarketMinOrderSize {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateSpotMarketOrdersEnabled(args) => {
+                Self::UpdateSpotMarketOrdersEnabled {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateSpotMarketName(args) => Self::UpdateSpotMarketName {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpMarketStatus(args) => Self::UpdatePerpMarketStatus {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpMarketContractTier(args) => {
+                Self::UpdatePerpMarketContractTier {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdatePerpMarketImfFactor(args) => Self::UpdatePerpMarketImfFactor {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpMarketUnrealizedAssetWeight(args) => {
+                Self::UpdatePerpMarketUnrealizedAssetWeight {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdatePerpMarketConcentrationCoef(args) => {
+                Self::UpdatePerpMarketConcentrationCoef {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdatePerpMarketCurveUpdateIntensity(args) => {
+                Self::UpdatePerpMarketCurveUpdateIntensity {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdatePerpMarketTargetBaseAssetAmountPerLp(args) => {
+                Self::UpdatePerpMarketTargetBaseAssetAmountPerLp {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateLpCooldownTime(args) => Self::UpdateLpCooldownTime {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpFeeStructure(args) => Self::UpdatePerpFeeStructure {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateSpotFeeStructure(args) => Self::UpdateSpotFeeStructure {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateInitialPctToLiquidate(args) => {
+                Self::UpdateInitialPctToLiquidate {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateLiquidationDuration(args) => Self::UpdateLiquidationDuration {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateOracleGuardRails(args) => Self::UpdateOracleGuardRails {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateStateSettlementDuration(args) => {
+                Self::UpdateStateSettlementDuration {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdatePerpMarketOracle(args) => Self::UpdatePerpMarketOracle {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpMarketBaseSpread(args) => Self::UpdatePerpMarketBaseSpread {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateAmmJitIntensity(args) => Self::UpdateAmmJitIntensity {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpMarketMaxSpread(args) => Self::UpdatePerpMarketMaxSpread {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpMarketStepSizeAndTickSize(args) => {
+                Self::UpdatePerpMarketStepSizeAndTickSize {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdatePerpMarketName(args) => Self::UpdatePerpMarketName {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpMarketMinOrderSize(args) => {
+                Self::UpdatePerpMarketMinOrderSize {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdatePerpMarketMaxSlippageRatio(args) => {
+                Self::UpdatePerpMarketMaxSlippageRatio {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdatePerpMarketMaxFillReserveFraction(args) => {
+                Self::UpdatePerpMarketMaxFillReserveFraction {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdatePerpMarketMaxOpenInterest(args) => {
+                Self::UpdatePerpMarketMaxOpenInterest {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            DriftProgramIx::UpdateAdmin(args) => Self::UpdateAdmin {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateWhitelistMint(args) => Self::UpdateWhitelistMint {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateDiscountMint(args) => Self::UpdateDiscountMint {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateExchangeStatus(args) => Self::UpdateExchangeStatus {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdatePerpAuctionDuration(args) => Self::UpdatePerpAuctionDuration {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::UpdateSpotAuctionDuration(args) => Self::UpdateSpotAuctionDuration {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            DriftProgramIx::AdminRemoveInsuranceFundStake(args) => {
+                Self::AdminRemoveInsuranceFundStake {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
@@ -9,3 +9,7 @@ pub mod errors;
 pub use errors::*;
 pub mod events;
 pub use events::*;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "client")]
+pub use client::*;
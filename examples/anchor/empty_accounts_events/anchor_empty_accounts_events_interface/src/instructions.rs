@@ -0,0 +1,147 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    instruction::Instruction,
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorEmptyAccountsEventsProgramIx {
+    BlankIx,
+}
+impl AnchorEmptyAccountsEventsProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            BLANK_IX_IX_DISCM => Ok(Self::BlankIx),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::BlankIx => writer.write_all(&BLANK_IX_IX_DISCM),
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const BLANK_IX_IX_DISCM: [u8; 8] = [29, 47, 197, 250, 126, 165, 198, 197];
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlankIxIxData;
+impl BlankIxIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != BLANK_IX_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    BLANK_IX_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&BLANK_IX_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn blank_ix_ix_with_program_id(program_id: Pubkey) -> std::io::Result<Instruction> {
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::new(),
+        data: BlankIxIxData.try_to_vec()?,
+    })
+}
+pub fn blank_ix_ix() -> std::io::Result<Instruction> {
+    blank_ix_ix_with_program_id(crate::ID)
+}
+/// Builds a [`blank_ix_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct BlankIxIxBuilder {}
+impl Default for BlankIxIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl BlankIxIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {}
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        blank_ix_ix()
+    }
+}
+pub fn blank_ix_invoke_with_program_id(program_id: Pubkey) -> ProgramResult {
+    let ix = blank_ix_ix_with_program_id(program_id)?;
+    invoke(&ix, &[])
+}
+pub fn blank_ix_invoke() -> ProgramResult {
+    blank_ix_invoke_with_program_id(crate::ID)
+}
+pub fn blank_ix_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = blank_ix_ix_with_program_id(program_id)?;
+    invoke_signed(&ix, &[], seeds)
+}
+pub fn blank_ix_invoke_signed(seeds: &[&[&[u8]]]) -> ProgramResult {
+    blank_ix_invoke_signed_with_program_id(crate::ID, seeds)
+}
+/// A [`AnchorEmptyAccountsEventsProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorEmptyAccountsEventsParsedIx {
+    BlankIx,
+}
+impl AnchorEmptyAccountsEventsParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], _accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = AnchorEmptyAccountsEventsProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            AnchorEmptyAccountsEventsProgramIx::BlankIx => Self::BlankIx,
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
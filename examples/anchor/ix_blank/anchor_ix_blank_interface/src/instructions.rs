@@ -71,6 +71,24 @@ pub fn blank_ix_ix_with_program_id(program_id: Pubkey) -> std::io::Result<Instru
 pub fn blank_ix_ix() -> std::io::Result<Instruction> {
     blank_ix_ix_with_program_id(crate::ID)
 }
+/// Builds a [`blank_ix_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct BlankIxIxBuilder {}
+impl Default for BlankIxIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl BlankIxIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {}
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        blank_ix_ix()
+    }
+}
 pub fn blank_ix_invoke_with_program_id(program_id: Pubkey) -> ProgramResult {
     let ix = blank_ix_ix_with_program_id(program_id)?;
     invoke(&ix, &[])
@@ -88,3 +106,42 @@ pub fn blank_ix_invoke_signed_with_program_id(
 pub fn blank_ix_invoke_signed(seeds: &[&[&[u8]]]) -> ProgramResult {
     blank_ix_invoke_signed_with_program_id(crate::ID, seeds)
 }
+/// A [`AnchorIxBlankProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorIxBlankParsedIx {
+    BlankIx,
+}
+impl AnchorIxBlankParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], _accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = AnchorIxBlankProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            AnchorIxBlankProgramIx::BlankIx => Self::BlankIx,
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
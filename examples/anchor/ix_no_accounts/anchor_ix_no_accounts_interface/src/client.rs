@@ -0,0 +1,19 @@
+use crate::*;
+use solana_program::pubkey::Pubkey;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Result as ClientResult;
+use solana_sdk::{
+    message::Message, signature::Signature, signer::Signer, transaction::Transaction,
+};
+/// Sends a transaction of [`no_accounts_ix_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_no_accounts_ix(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    args: NoAccountsIxIxArgs,
+) -> ClientResult<Signature> {
+    let ix = no_accounts_ix_ix(args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
@@ -4,6 +4,7 @@ use solana_program::{
     entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
     program::{invoke, invoke_signed},
+    program_error::ProgramError,
     pubkey::Pubkey,
 };
 use std::io::Read;
@@ -97,6 +98,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCO
         Self { a: &arr[0] }
     }
 }
+impl NoPrivilegedAccountIxKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for NoPrivilegedAccountIxKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> NoPrivilegedAccountIxAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN] = accounts
+            .get(..NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for NoPrivilegedAccountIxAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM: [u8; 8] = [137, 46, 202, 239, 43, 151, 145, 176];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -155,6 +200,46 @@ pub fn no_privileged_account_ix_ix(
 ) -> std::io::Result<Instruction> {
     no_privileged_account_ix_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`no_privileged_account_ix_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct NoPrivilegedAccountIxIxBuilder {
+    a: Option<Pubkey>,
+    arg: Option<u8>,
+}
+impl Default for NoPrivilegedAccountIxIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl NoPrivilegedAccountIxIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self { a: None, arg: None }
+    }
+    pub fn a(mut self, a: Pubkey) -> Self {
+        self.a = Some(a);
+        self
+    }
+    pub fn arg(mut self, arg: u8) -> Self {
+        self.arg = Some(arg);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("NoPrivilegedAccountIxIxBuilder is missing {}", field),
+            )
+        };
+        let a = self.a.ok_or_else(|| missing("a"))?;
+        let args = NoPrivilegedAccountIxIxArgs {
+            arg: self.arg.ok_or_else(|| missing("arg"))?,
+        };
+        let keys = NoPrivilegedAccountIxKeys { a };
+        no_privileged_account_ix_ix(keys, args)
+    }
+}
 pub fn no_privileged_account_ix_invoke_with_program_id(
     program_id: Pubkey,
     accounts: NoPrivilegedAccountIxAccounts<'_, '_>,
@@ -198,3 +283,62 @@ pub fn no_privileged_account_ix_verify_account_keys(
     }
     Ok(())
 }
+/// A [`AnchorIxNoPrivilegeProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorIxNoPrivilegeParsedIx {
+    NoPrivilegedAccountIx {
+        keys: NoPrivilegedAccountIxKeys,
+        args: NoPrivilegedAccountIxIxArgs,
+    },
+}
+impl AnchorIxNoPrivilegeParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = AnchorIxNoPrivilegeProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            AnchorIxNoPrivilegeProgramIx::NoPrivilegedAccountIx(args) => {
+                Self::NoPrivilegedAccountIx {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
@@ -0,0 +1,32 @@
+use anchor_ix_optional_accounts_interface::*;
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn test_build_derives_same_pdas_as_keys_new() {
+    let payer = Pubkey::new_unique();
+    let optional_signer = Pubkey::new_unique();
+    let built = OptionalAccountsIxIxBuilder::new()
+        .payer(payer)
+        .optional_signer(optional_signer)
+        .amount(1)
+        .build()
+        .unwrap();
+    let keys = OptionalAccountsIxKeys::new(payer, None, Some(optional_signer), None);
+    assert_eq!(keys.config, find_config_address().0);
+    assert_eq!(
+        built,
+        optional_accounts_ix_ix(keys, OptionalAccountsIxIxArgs { amount: 1 }).unwrap()
+    );
+}
+
+#[test]
+fn test_build_overrides_derived_pda() {
+    let config = Pubkey::new_unique();
+    let ix = OptionalAccountsIxIxBuilder::new()
+        .payer(Pubkey::new_unique())
+        .config(config)
+        .amount(1)
+        .build()
+        .unwrap();
+    assert_eq!(ix.accounts[4].pubkey, config);
+}
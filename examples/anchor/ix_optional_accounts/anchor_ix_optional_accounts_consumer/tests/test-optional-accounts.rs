@@ -0,0 +1,109 @@
+use anchor_ix_optional_accounts_interface::*;
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+fn sample_keys() -> OptionalAccountsIxKeys {
+    OptionalAccountsIxKeys {
+        payer: Pubkey::new_unique(),
+        optional_writable: None,
+        optional_signer: Some(Pubkey::new_unique()),
+        optional_readonly: None,
+        config: Pubkey::new_unique(),
+    }
+}
+
+#[test]
+fn test_absent_optional_accounts_are_program_id() {
+    let program_id = Pubkey::new_unique();
+    let keys = sample_keys();
+    let ix = optional_accounts_ix_ix_with_program_id(
+        program_id,
+        keys,
+        OptionalAccountsIxIxArgs { amount: 1 },
+    )
+    .unwrap();
+    let pubkeys: Vec<Pubkey> = ix.accounts.iter().map(|m| m.pubkey).collect();
+    assert_eq!(
+        pubkeys,
+        [
+            keys.payer,
+            program_id,
+            keys.optional_signer.unwrap(),
+            program_id,
+            keys.config,
+        ]
+    );
+    assert!(!ix.accounts[1].is_writable);
+    assert!(ix.accounts[2].is_signer);
+
+    let pubkeys: [Pubkey; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN] = pubkeys.try_into().unwrap();
+    assert_eq!(OptionalAccountsIxKeys::from((program_id, pubkeys)), keys);
+
+    let default_metas: [_; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN] = keys.into();
+    assert_eq!(default_metas[1].pubkey, ID);
+    assert_eq!(default_metas[3].pubkey, ID);
+}
+
+#[test]
+fn test_verify_account_keys_with_program_id() {
+    let program_id = Pubkey::new_unique();
+    let keys = sample_keys();
+    let ix = optional_accounts_ix_ix_with_program_id(
+        program_id,
+        keys,
+        OptionalAccountsIxIxArgs { amount: 1 },
+    )
+    .unwrap();
+    let pubkeys: Vec<Pubkey> = ix.accounts.iter().map(|m| m.pubkey).collect();
+    let owner = ID;
+    let mut lamports = [0; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN];
+    let mut data = [(); OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN].map(|_| Vec::new());
+    let account_infos: [AccountInfo; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN] = pubkeys
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .map(|((key, lamports), data)| {
+            AccountInfo::new(key, false, false, lamports, data, &owner, false, 0)
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+
+    let accounts = OptionalAccountsIxAccounts::from((program_id, &account_infos));
+    assert!(accounts.optional_writable.is_none());
+    assert!(accounts.optional_readonly.is_none());
+    assert_eq!(OptionalAccountsIxKeys::from(accounts), keys);
+    assert_eq!(
+        optional_accounts_ix_verify_account_keys(accounts, keys),
+        Ok(())
+    );
+
+    // an optional account must be present in both or absent from both
+    let with_readonly = OptionalAccountsIxKeys {
+        optional_readonly: Some(Pubkey::new_unique()),
+        ..keys
+    };
+    assert_eq!(
+        optional_accounts_ix_verify_account_keys(accounts, with_readonly),
+        Err((ID, with_readonly.optional_readonly.unwrap()))
+    );
+    let without_signer = OptionalAccountsIxKeys {
+        optional_signer: None,
+        ..keys
+    };
+    assert_eq!(
+        optional_accounts_ix_verify_account_keys(accounts, without_signer),
+        Err((keys.optional_signer.unwrap(), ID))
+    );
+
+    let wrong_signer = OptionalAccountsIxKeys {
+        optional_signer: Some(Pubkey::new_unique()),
+        ..keys
+    };
+    assert_eq!(
+        optional_accounts_ix_verify_account_keys(accounts, wrong_signer),
+        Err((
+            keys.optional_signer.unwrap(),
+            wrong_signer.optional_signer.unwrap()
+        ))
+    );
+}
@@ -0,0 +1,9 @@
+solana_program::declare_id!("TH1S1SNoTAVAL1DPUBKEYDoNoTUSE11111111111111");
+pub mod instructions;
+pub use instructions::*;
+pub mod pdas;
+pub use pdas::*;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "client")]
+pub use client::*;
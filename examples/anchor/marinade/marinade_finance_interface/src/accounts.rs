@@ -1,6 +1,6 @@
 use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 pub const STATE_ACCOUNT_DISCM: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -46,6 +46,21 @@ impl StateAccount {
         }
         Ok(Self(State::deserialize(&mut reader)?))
     }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
         writer.write_all(&STATE_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
@@ -56,6 +71,250 @@ impl StateAccount {
         Ok(data)
     }
 }
+/// Serialized size of [`State`]
+pub const STATE_LEN: usize = 568;
+/// Offset of `msol_mint` in serialized [`State`]
+pub const STATE_MSOL_MINT_OFFSET: usize = 0;
+/// Offset of `admin_authority` in serialized [`State`]
+pub const STATE_ADMIN_AUTHORITY_OFFSET: usize = 32;
+/// Offset of `operational_sol_account` in serialized [`State`]
+pub const STATE_OPERATIONAL_SOL_ACCOUNT_OFFSET: usize = 64;
+/// Offset of `treasury_msol_account` in serialized [`State`]
+pub const STATE_TREASURY_MSOL_ACCOUNT_OFFSET: usize = 96;
+/// Offset of `reserve_bump_seed` in serialized [`State`]
+pub const STATE_RESERVE_BUMP_SEED_OFFSET: usize = 128;
+/// Offset of `msol_mint_authority_bump_seed` in serialized [`State`]
+pub const STATE_MSOL_MINT_AUTHORITY_BUMP_SEED_OFFSET: usize = 129;
+/// Offset of `rent_exempt_for_token_acc` in serialized [`State`]
+pub const STATE_RENT_EXEMPT_FOR_TOKEN_ACC_OFFSET: usize = 130;
+/// Offset of `reward_fee` in serialized [`State`]
+pub const STATE_REWARD_FEE_OFFSET: usize = 138;
+/// Offset of `stake_system` in serialized [`State`]
+pub const STATE_STAKE_SYSTEM_OFFSET: usize = 142;
+/// Offset of `validator_system` in serialized [`State`]
+pub const STATE_VALIDATOR_SYSTEM_OFFSET: usize = 256;
+/// Offset of `liq_pool` in serialized [`State`]
+pub const STATE_LIQ_POOL_OFFSET: usize = 377;
+/// Offset of `available_reserve_balance` in serialized [`State`]
+pub const STATE_AVAILABLE_RESERVE_BALANCE_OFFSET: usize = 488;
+/// Offset of `msol_supply` in serialized [`State`]
+pub const STATE_MSOL_SUPPLY_OFFSET: usize = 496;
+/// Offset of `msol_price` in serialized [`State`]
+pub const STATE_MSOL_PRICE_OFFSET: usize = 504;
+/// Offset of `circulating_ticket_count` in serialized [`State`]
+pub const STATE_CIRCULATING_TICKET_COUNT_OFFSET: usize = 512;
+/// Offset of `circulating_ticket_balance` in serialized [`State`]
+pub const STATE_CIRCULATING_TICKET_BALANCE_OFFSET: usize = 520;
+/// Offset of `lent_from_reserve` in serialized [`State`]
+pub const STATE_LENT_FROM_RESERVE_OFFSET: usize = 528;
+/// Offset of `min_deposit` in serialized [`State`]
+pub const STATE_MIN_DEPOSIT_OFFSET: usize = 536;
+/// Offset of `min_withdraw` in serialized [`State`]
+pub const STATE_MIN_WITHDRAW_OFFSET: usize = 544;
+/// Offset of `staking_sol_cap` in serialized [`State`]
+pub const STATE_STAKING_SOL_CAP_OFFSET: usize = 552;
+/// Offset of `emergency_cooling_down` in serialized [`State`]
+pub const STATE_EMERGENCY_COOLING_DOWN_OFFSET: usize = 560;
+/// Size of the account data, including the 8-byte discriminator
+pub const STATE_ACCOUNT_LEN: usize = 8 + STATE_LEN;
+/// Builds `getProgramAccounts` filters for [`StateAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateAccountFilters(Vec<AccountFilter>);
+impl StateAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: STATE_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(STATE_ACCOUNT_LEN as u64));
+        self
+    }
+    /// Matches accounts whose `msol_mint` equals `value`
+    pub fn msol_mint_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_MSOL_MINT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `admin_authority` equals `value`
+    pub fn admin_authority_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_ADMIN_AUTHORITY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `operational_sol_account` equals `value`
+    pub fn operational_sol_account_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_OPERATIONAL_SOL_ACCOUNT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `treasury_msol_account` equals `value`
+    pub fn treasury_msol_account_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_TREASURY_MSOL_ACCOUNT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `reserve_bump_seed` equals `value`
+    pub fn reserve_bump_seed_eq(mut self, value: &u8) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_RESERVE_BUMP_SEED_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `msol_mint_authority_bump_seed` equals `value`
+    pub fn msol_mint_authority_bump_seed_eq(mut self, value: &u8) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_MSOL_MINT_AUTHORITY_BUMP_SEED_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `rent_exempt_for_token_acc` equals `value`
+    pub fn rent_exempt_for_token_acc_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_RENT_EXEMPT_FOR_TOKEN_ACC_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `reward_fee` equals `value`
+    pub fn reward_fee_eq(mut self, value: &Fee) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_REWARD_FEE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `stake_system` equals `value`
+    pub fn stake_system_eq(mut self, value: &StakeSystem) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_STAKE_SYSTEM_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `validator_system` equals `value`
+    pub fn validator_system_eq(mut self, value: &ValidatorSystem) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_VALIDATOR_SYSTEM_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `liq_pool` equals `value`
+    pub fn liq_pool_eq(mut self, value: &LiqPool) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_LIQ_POOL_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `available_reserve_balance` equals `value`
+    pub fn available_reserve_balance_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_AVAILABLE_RESERVE_BALANCE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `msol_supply` equals `value`
+    pub fn msol_supply_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_MSOL_SUPPLY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `msol_price` equals `value`
+    pub fn msol_price_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_MSOL_PRICE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `circulating_ticket_count` equals `value`
+    pub fn circulating_ticket_count_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_CIRCULATING_TICKET_COUNT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `circulating_ticket_balance` equals `value`
+    pub fn circulating_ticket_balance_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_CIRCULATING_TICKET_BALANCE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `lent_from_reserve` equals `value`
+    pub fn lent_from_reserve_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_LENT_FROM_RESERVE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `min_deposit` equals `value`
+    pub fn min_deposit_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_MIN_DEPOSIT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `min_withdraw` equals `value`
+    pub fn min_withdraw_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_MIN_WITHDRAW_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `staking_sol_cap` equals `value`
+    pub fn staking_sol_cap_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_STAKING_SOL_CAP_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `emergency_cooling_down` equals `value`
+    pub fn emergency_cooling_down_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_EMERGENCY_COOLING_DOWN_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl StateAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> StateAccountFilters {
+        StateAccountFilters::default()
+    }
+}
 pub const TICKET_ACCOUNT_DATA_ACCOUNT_DISCM: [u8; 8] = [133, 77, 18, 98, 211, 1, 231, 3];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -84,6 +343,21 @@ impl TicketAccountDataAccount {
         }
         Ok(Self(TicketAccountData::deserialize(&mut reader)?))
     }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
         writer.write_all(&TICKET_ACCOUNT_DATA_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
@@ -94,3 +368,124 @@ impl TicketAccountDataAccount {
         Ok(data)
     }
 }
+/// Serialized size of [`TicketAccountData`]
+pub const TICKET_ACCOUNT_DATA_LEN: usize = 80;
+/// Offset of `state_address` in serialized [`TicketAccountData`]
+pub const TICKET_ACCOUNT_DATA_STATE_ADDRESS_OFFSET: usize = 0;
+/// Offset of `beneficiary` in serialized [`TicketAccountData`]
+pub const TICKET_ACCOUNT_DATA_BENEFICIARY_OFFSET: usize = 32;
+/// Offset of `lamports_amount` in serialized [`TicketAccountData`]
+pub const TICKET_ACCOUNT_DATA_LAMPORTS_AMOUNT_OFFSET: usize = 64;
+/// Offset of `created_epoch` in serialized [`TicketAccountData`]
+pub const TICKET_ACCOUNT_DATA_CREATED_EPOCH_OFFSET: usize = 72;
+/// Size of the account data, including the 8-byte discriminator
+pub const TICKET_ACCOUNT_DATA_ACCOUNT_LEN: usize = 8 + TICKET_ACCOUNT_DATA_LEN;
+/// Builds `getProgramAccounts` filters for [`TicketAccountDataAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TicketAccountDataAccountFilters(Vec<AccountFilter>);
+impl TicketAccountDataAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: TICKET_ACCOUNT_DATA_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0.push(AccountFilter::DataSize(
+            TICKET_ACCOUNT_DATA_ACCOUNT_LEN as u64,
+        ));
+        self
+    }
+    /// Matches accounts whose `state_address` equals `value`
+    pub fn state_address_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + TICKET_ACCOUNT_DATA_STATE_ADDRESS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `beneficiary` equals `value`
+    pub fn beneficiary_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + TICKET_ACCOUNT_DATA_BENEFICIARY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `lamports_amount` equals `value`
+    pub fn lamports_amount_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + TICKET_ACCOUNT_DATA_LAMPORTS_AMOUNT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `created_epoch` equals `value`
+    pub fn created_epoch_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + TICKET_ACCOUNT_DATA_CREATED_EPOCH_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl TicketAccountDataAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> TicketAccountDataAccountFilters {
+        TicketAccountDataAccountFilters::default()
+    }
+}
+/// A `getProgramAccounts` filter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountFilter {
+    /// Account data is exactly this many bytes long
+    DataSize(u64),
+    /// Account data at `offset` starts with `bytes`
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+#[cfg(feature = "solana-rpc-client-api")]
+impl From<AccountFilter> for solana_rpc_client_api::filter::RpcFilterType {
+    fn from(filter: AccountFilter) -> Self {
+        match filter {
+            AccountFilter::DataSize(size) => Self::DataSize(size),
+            AccountFilter::Memcmp { offset, bytes } => Self::Memcmp(
+                solana_rpc_client_api::filter::Memcmp::new_base58_encoded(offset, &bytes),
+            ),
+        }
+    }
+}
+/// The 8-byte discriminator that account data starts with, None if the data is too short
+pub fn discriminator_of(data: &[u8]) -> Option<[u8; 8]> {
+    data.get(..8).map(|discm| discm.try_into().unwrap())
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MarinadeFinanceAccount {
+    State(State),
+    TicketAccountData(TicketAccountData),
+}
+impl MarinadeFinanceAccount {
+    /// Deserializes account data into the account type its discriminator identifies
+    pub fn try_from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        let mut reader = data;
+        let maybe_discm = <[u8; 8]>::deserialize(&mut reader)?;
+        match maybe_discm {
+            STATE_ACCOUNT_DISCM => StateAccount::deserialize(data).map(|a| Self::State(a.0)),
+            TICKET_ACCOUNT_DATA_ACCOUNT_DISCM => {
+                TicketAccountDataAccount::deserialize(data).map(|a| Self::TicketAccountData(a.0))
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+}
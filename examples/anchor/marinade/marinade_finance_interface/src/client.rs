@@ -0,0 +1,354 @@
+use crate::*;
+use solana_account_decoder::UiAccountEncoding;
+use solana_program::pubkey::Pubkey;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Result as ClientResult;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::RpcFilterType,
+};
+use solana_sdk::{
+    message::Message, signature::Signature, signer::Signer, transaction::Transaction,
+};
+/// Fetches and deserializes the [`StateAccount`] at `pubkey`
+pub fn fetch_state(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<StateAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(StateAccount::deserialize(&data)?)
+}
+/// Fetches all [`StateAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_state(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, StateAccount)>> {
+    let filters = StateAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, StateAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Fetches and deserializes the [`TicketAccountDataAccount`] at `pubkey`
+pub fn fetch_ticket_account_data(
+    client: &RpcClient,
+    pubkey: &Pubkey,
+) -> ClientResult<TicketAccountDataAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(TicketAccountDataAccount::deserialize(&data)?)
+}
+/// Fetches all [`TicketAccountDataAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_ticket_account_data(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, TicketAccountDataAccount)>> {
+    let filters = TicketAccountDataAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| {
+            Ok((
+                pubkey,
+                TicketAccountDataAccount::deserialize(&account.data)?,
+            ))
+        })
+        .collect()
+}
+/// Sends a transaction of [`initialize_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_initialize(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitializeKeys,
+    args: InitializeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = initialize_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`change_authority_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_change_authority(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ChangeAuthorityKeys,
+    args: ChangeAuthorityIxArgs,
+) -> ClientResult<Signature> {
+    let ix = change_authority_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`add_validator_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_add_validator(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: AddValidatorKeys,
+    args: AddValidatorIxArgs,
+) -> ClientResult<Signature> {
+    let ix = add_validator_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`remove_validator_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_remove_validator(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: RemoveValidatorKeys,
+    args: RemoveValidatorIxArgs,
+) -> ClientResult<Signature> {
+    let ix = remove_validator_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`set_validator_score_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_set_validator_score(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SetValidatorScoreKeys,
+    args: SetValidatorScoreIxArgs,
+) -> ClientResult<Signature> {
+    let ix = set_validator_score_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`config_validator_system_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_config_validator_system(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ConfigValidatorSystemKeys,
+    args: ConfigValidatorSystemIxArgs,
+) -> ClientResult<Signature> {
+    let ix = config_validator_system_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`deposit_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_deposit(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: DepositKeys,
+    args: DepositIxArgs,
+) -> ClientResult<Signature> {
+    let ix = deposit_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`deposit_stake_account_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_deposit_stake_account(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: DepositStakeAccountKeys,
+    args: DepositStakeAccountIxArgs,
+) -> ClientResult<Signature> {
+    let ix = deposit_stake_account_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`liquid_unstake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_liquid_unstake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: LiquidUnstakeKeys,
+    args: LiquidUnstakeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = liquid_unstake_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`add_liquidity_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_add_liquidity(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: AddLiquidityKeys,
+    args: AddLiquidityIxArgs,
+) -> ClientResult<Signature> {
+    let ix = add_liquidity_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`remove_liquidity_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_remove_liquidity(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: RemoveLiquidityKeys,
+    args: RemoveLiquidityIxArgs,
+) -> ClientResult<Signature> {
+    let ix = remove_liquidity_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`set_lp_params_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_set_lp_params(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SetLpParamsKeys,
+    args: SetLpParamsIxArgs,
+) -> ClientResult<Signature> {
+    let ix = set_lp_params_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`config_marinade_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_config_marinade(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ConfigMarinadeKeys,
+    args: ConfigMarinadeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = config_marinade_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`order_unstake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_order_unstake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: OrderUnstakeKeys,
+    args: OrderUnstakeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = order_unstake_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`claim_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_claim(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ClaimKeys,
+) -> ClientResult<Signature> {
+    let ix = claim_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`stake_reserve_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_stake_reserve(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: StakeReserveKeys,
+    args: StakeReserveIxArgs,
+) -> ClientResult<Signature> {
+    let ix = stake_reserve_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_active_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_active(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateActiveKeys,
+    args: UpdateActiveIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_active_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`update_deactivated_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_update_deactivated(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UpdateDeactivatedKeys,
+    args: UpdateDeactivatedIxArgs,
+) -> ClientResult<Signature> {
+    let ix = update_deactivated_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`deactivate_stake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_deactivate_stake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: DeactivateStakeKeys,
+    args: DeactivateStakeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = deactivate_stake_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`emergency_unstake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_emergency_unstake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: EmergencyUnstakeKeys,
+    args: EmergencyUnstakeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = emergency_unstake_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`merge_stakes_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_merge_stakes(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: MergeStakesKeys,
+    args: MergeStakesIxArgs,
+) -> ClientResult<Signature> {
+    let ix = merge_stakes_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
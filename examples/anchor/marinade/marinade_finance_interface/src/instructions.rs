@@ -394,6 +394,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl InitializeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INITIALIZE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INITIALIZE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INITIALIZE_IX_DISCM: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -449,6 +487,165 @@ pub fn initialize_ix_with_program_id(
 pub fn initialize_ix(keys: InitializeKeys, args: InitializeIxArgs) -> std::io::Result<Instruction> {
     initialize_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`initialize_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeIxBuilder {
+    creator_authority: Option<Pubkey>,
+    state: Option<Pubkey>,
+    reserve_pda: Option<Pubkey>,
+    stake_list: Option<Pubkey>,
+    validator_list: Option<Pubkey>,
+    msol_mint: Option<Pubkey>,
+    operational_sol_account: Option<Pubkey>,
+    liq_pool_lp_mint: Option<Pubkey>,
+    liq_pool_sol_leg_pda: Option<Pubkey>,
+    liq_pool_msol_leg: Option<Pubkey>,
+    treasury_msol_account: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    data: Option<InitializeData>,
+}
+impl Default for InitializeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            creator_authority: None,
+            state: None,
+            reserve_pda: None,
+            stake_list: None,
+            validator_list: None,
+            msol_mint: None,
+            operational_sol_account: None,
+            liq_pool_lp_mint: None,
+            liq_pool_sol_leg_pda: None,
+            liq_pool_msol_leg: None,
+            treasury_msol_account: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            data: None,
+        }
+    }
+    pub fn creator_authority(mut self, creator_authority: Pubkey) -> Self {
+        self.creator_authority = Some(creator_authority);
+        self
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn reserve_pda(mut self, reserve_pda: Pubkey) -> Self {
+        self.reserve_pda = Some(reserve_pda);
+        self
+    }
+    pub fn stake_list(mut self, stake_list: Pubkey) -> Self {
+        self.stake_list = Some(stake_list);
+        self
+    }
+    pub fn validator_list(mut self, validator_list: Pubkey) -> Self {
+        self.validator_list = Some(validator_list);
+        self
+    }
+    pub fn msol_mint(mut self, msol_mint: Pubkey) -> Self {
+        self.msol_mint = Some(msol_mint);
+        self
+    }
+    pub fn operational_sol_account(mut self, operational_sol_account: Pubkey) -> Self {
+        self.operational_sol_account = Some(operational_sol_account);
+        self
+    }
+    pub fn liq_pool_lp_mint(mut self, liq_pool_lp_mint: Pubkey) -> Self {
+        self.liq_pool_lp_mint = Some(liq_pool_lp_mint);
+        self
+    }
+    pub fn liq_pool_sol_leg_pda(mut self, liq_pool_sol_leg_pda: Pubkey) -> Self {
+        self.liq_pool_sol_leg_pda = Some(liq_pool_sol_leg_pda);
+        self
+    }
+    pub fn liq_pool_msol_leg(mut self, liq_pool_msol_leg: Pubkey) -> Self {
+        self.liq_pool_msol_leg = Some(liq_pool_msol_leg);
+        self
+    }
+    pub fn treasury_msol_account(mut self, treasury_msol_account: Pubkey) -> Self {
+        self.treasury_msol_account = Some(treasury_msol_account);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn data(mut self, data: InitializeData) -> Self {
+        self.data = Some(data);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeIxBuilder is missing {}", field),
+            )
+        };
+        let creator_authority = self
+            .creator_authority
+            .ok_or_else(|| missing("creator_authority"))?;
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let reserve_pda = self.reserve_pda.ok_or_else(|| missing("reserve_pda"))?;
+        let stake_list = self.stake_list.ok_or_else(|| missing("stake_list"))?;
+        let validator_list = self
+            .validator_list
+            .ok_or_else(|| missing("validator_list"))?;
+        let msol_mint = self.msol_mint.ok_or_else(|| missing("msol_mint"))?;
+        let operational_sol_account = self
+            .operational_sol_account
+            .ok_or_else(|| missing("operational_sol_account"))?;
+        let liq_pool_lp_mint = self
+            .liq_pool_lp_mint
+            .ok_or_else(|| missing("liq_pool_lp_mint"))?;
+        let liq_pool_sol_leg_pda = self
+            .liq_pool_sol_leg_pda
+            .ok_or_else(|| missing("liq_pool_sol_leg_pda"))?;
+        let liq_pool_msol_leg = self
+            .liq_pool_msol_leg
+            .ok_or_else(|| missing("liq_pool_msol_leg"))?;
+        let treasury_msol_account = self
+            .treasury_msol_account
+            .ok_or_else(|| missing("treasury_msol_account"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = InitializeIxArgs {
+            data: self.data.ok_or_else(|| missing("data"))?,
+        };
+        let keys = InitializeKeys {
+            creator_authority,
+            state,
+            reserve_pda,
+            stake_list,
+            validator_list,
+            msol_mint,
+            operational_sol_account,
+            liq_pool_lp_mint,
+            liq_pool_sol_leg_pda,
+            liq_pool_msol_leg,
+            treasury_msol_account,
+            clock,
+            rent,
+        };
+        initialize_ix(keys, args)
+    }
+}
 pub fn initialize_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeAccounts<'_, '_>,
@@ -602,6 +799,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN
         }
     }
 }
+impl ChangeAuthorityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CHANGE_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CHANGE_AUTHORITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for ChangeAuthorityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ChangeAuthorityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..CHANGE_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CHANGE_AUTHORITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ChangeAuthorityAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CHANGE_AUTHORITY_IX_DISCM: [u8; 8] = [50, 106, 66, 104, 99, 118, 145, 88];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -660,6 +895,61 @@ pub fn change_authority_ix(
 ) -> std::io::Result<Instruction> {
     change_authority_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`change_authority_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ChangeAuthorityIxBuilder {
+    state: Option<Pubkey>,
+    admin_authority: Option<Pubkey>,
+    data: Option<ChangeAuthorityData>,
+}
+impl Default for ChangeAuthorityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ChangeAuthorityIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            admin_authority: None,
+            data: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn admin_authority(mut self, admin_authority: Pubkey) -> Self {
+        self.admin_authority = Some(admin_authority);
+        self
+    }
+    pub fn data(mut self, data: ChangeAuthorityData) -> Self {
+        self.data = Some(data);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ChangeAuthorityIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let admin_authority = self
+            .admin_authority
+            .ok_or_else(|| missing("admin_authority"))?;
+        let args = ChangeAuthorityIxArgs {
+            data: self.data.ok_or_else(|| missing("data"))?,
+        };
+        let keys = ChangeAuthorityKeys {
+            state,
+            admin_authority,
+        };
+        change_authority_ix(keys, args)
+    }
+}
 pub fn change_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ChangeAuthorityAccounts<'_, '_>,
@@ -873,6 +1163,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl AddValidatorKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ADD_VALIDATOR_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ADD_VALIDATOR_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[ADD_VALIDATOR_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AddValidatorKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AddValidatorAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN] = accounts
+            .get(..ADD_VALIDATOR_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[ADD_VALIDATOR_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AddValidatorAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const ADD_VALIDATOR_IX_DISCM: [u8; 8] = [250, 113, 53, 54, 141, 117, 215, 185];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -931,6 +1259,129 @@ pub fn add_validator_ix(
 ) -> std::io::Result<Instruction> {
     add_validator_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`add_validator_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AddValidatorIxBuilder {
+    state: Option<Pubkey>,
+    manager_authority: Option<Pubkey>,
+    validator_list: Option<Pubkey>,
+    validator_vote: Option<Pubkey>,
+    duplication_flag: Option<Pubkey>,
+    rent_payer: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    score: Option<u32>,
+}
+impl Default for AddValidatorIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AddValidatorIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            manager_authority: None,
+            validator_list: None,
+            validator_vote: None,
+            duplication_flag: None,
+            rent_payer: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            score: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn manager_authority(mut self, manager_authority: Pubkey) -> Self {
+        self.manager_authority = Some(manager_authority);
+        self
+    }
+    pub fn validator_list(mut self, validator_list: Pubkey) -> Self {
+        self.validator_list = Some(validator_list);
+        self
+    }
+    pub fn validator_vote(mut self, validator_vote: Pubkey) -> Self {
+        self.validator_vote = Some(validator_vote);
+        self
+    }
+    pub fn duplication_flag(mut self, duplication_flag: Pubkey) -> Self {
+        self.duplication_flag = Some(duplication_flag);
+        self
+    }
+    pub fn rent_payer(mut self, rent_payer: Pubkey) -> Self {
+        self.rent_payer = Some(rent_payer);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn score(mut self, score: u32) -> Self {
+        self.score = Some(score);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AddValidatorIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let manager_authority = self
+            .manager_authority
+            .ok_or_else(|| missing("manager_authority"))?;
+        let validator_list = self
+            .validator_list
+            .ok_or_else(|| missing("validator_list"))?;
+        let validator_vote = self
+            .validator_vote
+            .ok_or_else(|| missing("validator_vote"))?;
+        let duplication_flag = self
+            .duplication_flag
+            .ok_or_else(|| missing("duplication_flag"))?;
+        let rent_payer = self.rent_payer.ok_or_else(|| missing("rent_payer"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = AddValidatorIxArgs {
+            score: self.score.ok_or_else(|| missing("score"))?,
+        };
+        let keys = AddValidatorKeys {
+            state,
+            manager_authority,
+            validator_list,
+            validator_vote,
+            duplication_flag,
+            rent_payer,
+            clock,
+            rent,
+            system_program,
+        };
+        add_validator_ix(keys, args)
+    }
+}
 pub fn add_validator_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AddValidatorAccounts<'_, '_>,
@@ -1112,6 +1563,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN
         }
     }
 }
+impl RemoveValidatorKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REMOVE_VALIDATOR_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[REMOVE_VALIDATOR_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for RemoveValidatorKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RemoveValidatorAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN] = accounts
+            .get(..REMOVE_VALIDATOR_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[REMOVE_VALIDATOR_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for RemoveValidatorAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REMOVE_VALIDATOR_IX_DISCM: [u8; 8] = [25, 96, 211, 155, 161, 14, 168, 188];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1171,6 +1660,100 @@ pub fn remove_validator_ix(
 ) -> std::io::Result<Instruction> {
     remove_validator_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`remove_validator_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RemoveValidatorIxBuilder {
+    state: Option<Pubkey>,
+    manager_authority: Option<Pubkey>,
+    validator_list: Option<Pubkey>,
+    duplication_flag: Option<Pubkey>,
+    operational_sol_account: Option<Pubkey>,
+    index: Option<u32>,
+    validator_vote: Option<Pubkey>,
+}
+impl Default for RemoveValidatorIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RemoveValidatorIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            manager_authority: None,
+            validator_list: None,
+            duplication_flag: None,
+            operational_sol_account: None,
+            index: None,
+            validator_vote: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn manager_authority(mut self, manager_authority: Pubkey) -> Self {
+        self.manager_authority = Some(manager_authority);
+        self
+    }
+    pub fn validator_list(mut self, validator_list: Pubkey) -> Self {
+        self.validator_list = Some(validator_list);
+        self
+    }
+    pub fn duplication_flag(mut self, duplication_flag: Pubkey) -> Self {
+        self.duplication_flag = Some(duplication_flag);
+        self
+    }
+    pub fn operational_sol_account(mut self, operational_sol_account: Pubkey) -> Self {
+        self.operational_sol_account = Some(operational_sol_account);
+        self
+    }
+    pub fn index(mut self, index: u32) -> Self {
+        self.index = Some(index);
+        self
+    }
+    pub fn validator_vote(mut self, validator_vote: Pubkey) -> Self {
+        self.validator_vote = Some(validator_vote);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RemoveValidatorIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let manager_authority = self
+            .manager_authority
+            .ok_or_else(|| missing("manager_authority"))?;
+        let validator_list = self
+            .validator_list
+            .ok_or_else(|| missing("validator_list"))?;
+        let duplication_flag = self
+            .duplication_flag
+            .ok_or_else(|| missing("duplication_flag"))?;
+        let operational_sol_account = self
+            .operational_sol_account
+            .ok_or_else(|| missing("operational_sol_account"))?;
+        let args = RemoveValidatorIxArgs {
+            index: self.index.ok_or_else(|| missing("index"))?,
+            validator_vote: self
+                .validator_vote
+                .ok_or_else(|| missing("validator_vote"))?,
+        };
+        let keys = RemoveValidatorKeys {
+            state,
+            manager_authority,
+            validator_list,
+            duplication_flag,
+            operational_sol_account,
+        };
+        remove_validator_ix(keys, args)
+    }
+}
 pub fn remove_validator_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RemoveValidatorAccounts<'_, '_>,
@@ -1329,6 +1912,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_
         }
     }
 }
+impl SetValidatorScoreKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SetValidatorScoreKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SetValidatorScoreAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN] = accounts
+            .get(..SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SetValidatorScoreAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SET_VALIDATOR_SCORE_IX_DISCM: [u8; 8] = [101, 41, 206, 33, 216, 111, 25, 78];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1389,6 +2010,87 @@ pub fn set_validator_score_ix(
 ) -> std::io::Result<Instruction> {
     set_validator_score_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`set_validator_score_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SetValidatorScoreIxBuilder {
+    state: Option<Pubkey>,
+    manager_authority: Option<Pubkey>,
+    validator_list: Option<Pubkey>,
+    index: Option<u32>,
+    validator_vote: Option<Pubkey>,
+    score: Option<u32>,
+}
+impl Default for SetValidatorScoreIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SetValidatorScoreIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            manager_authority: None,
+            validator_list: None,
+            index: None,
+            validator_vote: None,
+            score: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn manager_authority(mut self, manager_authority: Pubkey) -> Self {
+        self.manager_authority = Some(manager_authority);
+        self
+    }
+    pub fn validator_list(mut self, validator_list: Pubkey) -> Self {
+        self.validator_list = Some(validator_list);
+        self
+    }
+    pub fn index(mut self, index: u32) -> Self {
+        self.index = Some(index);
+        self
+    }
+    pub fn validator_vote(mut self, validator_vote: Pubkey) -> Self {
+        self.validator_vote = Some(validator_vote);
+        self
+    }
+    pub fn score(mut self, score: u32) -> Self {
+        self.score = Some(score);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SetValidatorScoreIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let manager_authority = self
+            .manager_authority
+            .ok_or_else(|| missing("manager_authority"))?;
+        let validator_list = self
+            .validator_list
+            .ok_or_else(|| missing("validator_list"))?;
+        let args = SetValidatorScoreIxArgs {
+            index: self.index.ok_or_else(|| missing("index"))?,
+            validator_vote: self
+                .validator_vote
+                .ok_or_else(|| missing("validator_vote"))?,
+            score: self.score.ok_or_else(|| missing("score"))?,
+        };
+        let keys = SetValidatorScoreKeys {
+            state,
+            manager_authority,
+            validator_list,
+        };
+        set_validator_score_ix(keys, args)
+    }
+}
 pub fn set_validator_score_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetValidatorScoreAccounts<'_, '_>,
@@ -1523,6 +2225,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOU
         }
     }
 }
+impl ConfigValidatorSystemKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ConfigValidatorSystemKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ConfigValidatorSystemAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] = accounts
+            .get(..CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ConfigValidatorSystemAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CONFIG_VALIDATOR_SYSTEM_IX_DISCM: [u8; 8] = [27, 90, 97, 209, 17, 115, 7, 40];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1581,6 +2327,61 @@ pub fn config_validator_system_ix(
 ) -> std::io::Result<Instruction> {
     config_validator_system_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`config_validator_system_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ConfigValidatorSystemIxBuilder {
+    state: Option<Pubkey>,
+    manager_authority: Option<Pubkey>,
+    extra_runs: Option<u32>,
+}
+impl Default for ConfigValidatorSystemIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ConfigValidatorSystemIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            manager_authority: None,
+            extra_runs: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn manager_authority(mut self, manager_authority: Pubkey) -> Self {
+        self.manager_authority = Some(manager_authority);
+        self
+    }
+    pub fn extra_runs(mut self, extra_runs: u32) -> Self {
+        self.extra_runs = Some(extra_runs);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ConfigValidatorSystemIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let manager_authority = self
+            .manager_authority
+            .ok_or_else(|| missing("manager_authority"))?;
+        let args = ConfigValidatorSystemIxArgs {
+            extra_runs: self.extra_runs.ok_or_else(|| missing("extra_runs"))?,
+        };
+        let keys = ConfigValidatorSystemKeys {
+            state,
+            manager_authority,
+        };
+        config_validator_system_ix(keys, args)
+    }
+}
 pub fn config_validator_system_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ConfigValidatorSystemAccounts<'_, '_>,
@@ -1814,6 +2615,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl DepositKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPOSIT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEPOSIT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[DEPOSIT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for DepositKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DepositAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts
+            .get(..DEPOSIT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[DEPOSIT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for DepositAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEPOSIT_IX_DISCM: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1869,6 +2708,143 @@ pub fn deposit_ix_with_program_id(
 pub fn deposit_ix(keys: DepositKeys, args: DepositIxArgs) -> std::io::Result<Instruction> {
     deposit_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`deposit_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DepositIxBuilder {
+    state: Option<Pubkey>,
+    msol_mint: Option<Pubkey>,
+    liq_pool_sol_leg_pda: Option<Pubkey>,
+    liq_pool_msol_leg: Option<Pubkey>,
+    liq_pool_msol_leg_authority: Option<Pubkey>,
+    reserve_pda: Option<Pubkey>,
+    transfer_from: Option<Pubkey>,
+    mint_to: Option<Pubkey>,
+    msol_mint_authority: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    lamports: Option<u64>,
+}
+impl Default for DepositIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DepositIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            msol_mint: None,
+            liq_pool_sol_leg_pda: None,
+            liq_pool_msol_leg: None,
+            liq_pool_msol_leg_authority: None,
+            reserve_pda: None,
+            transfer_from: None,
+            mint_to: None,
+            msol_mint_authority: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            lamports: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn msol_mint(mut self, msol_mint: Pubkey) -> Self {
+        self.msol_mint = Some(msol_mint);
+        self
+    }
+    pub fn liq_pool_sol_leg_pda(mut self, liq_pool_sol_leg_pda: Pubkey) -> Self {
+        self.liq_pool_sol_leg_pda = Some(liq_pool_sol_leg_pda);
+        self
+    }
+    pub fn liq_pool_msol_leg(mut self, liq_pool_msol_leg: Pubkey) -> Self {
+        self.liq_pool_msol_leg = Some(liq_pool_msol_leg);
+        self
+    }
+    pub fn liq_pool_msol_leg_authority(mut self, liq_pool_msol_leg_authority: Pubkey) -> Self {
+        self.liq_pool_msol_leg_authority = Some(liq_pool_msol_leg_authority);
+        self
+    }
+    pub fn reserve_pda(mut self, reserve_pda: Pubkey) -> Self {
+        self.reserve_pda = Some(reserve_pda);
+        self
+    }
+    pub fn transfer_from(mut self, transfer_from: Pubkey) -> Self {
+        self.transfer_from = Some(transfer_from);
+        self
+    }
+    pub fn mint_to(mut self, mint_to: Pubkey) -> Self {
+        self.mint_to = Some(mint_to);
+        self
+    }
+    pub fn msol_mint_authority(mut self, msol_mint_authority: Pubkey) -> Self {
+        self.msol_mint_authority = Some(msol_mint_authority);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = Some(lamports);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DepositIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let msol_mint = self.msol_mint.ok_or_else(|| missing("msol_mint"))?;
+        let liq_pool_sol_leg_pda = self
+            .liq_pool_sol_leg_pda
+            .ok_or_else(|| missing("liq_pool_sol_leg_pda"))?;
+        let liq_pool_msol_leg = self
+            .liq_pool_msol_leg
+            .ok_or_else(|| missing("liq_pool_msol_leg"))?;
+        let liq_pool_msol_leg_authority = self
+            .liq_pool_msol_leg_authority
+            .ok_or_else(|| missing("liq_pool_msol_leg_authority"))?;
+        let reserve_pda = self.reserve_pda.ok_or_else(|| missing("reserve_pda"))?;
+        let transfer_from = self.transfer_from.ok_or_else(|| missing("transfer_from"))?;
+        let mint_to = self.mint_to.ok_or_else(|| missing("mint_to"))?;
+        let msol_mint_authority = self
+            .msol_mint_authority
+            .ok_or_else(|| missing("msol_mint_authority"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = DepositIxArgs {
+            lamports: self.lamports.ok_or_else(|| missing("lamports"))?,
+        };
+        let keys = DepositKeys {
+            state,
+            msol_mint,
+            liq_pool_sol_leg_pda,
+            liq_pool_msol_leg,
+            liq_pool_msol_leg_authority,
+            reserve_pda,
+            transfer_from,
+            mint_to,
+            msol_mint_authority,
+            system_program,
+            token_program,
+        };
+        deposit_ix(keys, args)
+    }
+}
 pub fn deposit_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DepositAccounts<'_, '_>,
@@ -2168,6 +3144,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNT
         }
     }
 }
+impl DepositStakeAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for DepositStakeAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DepositStakeAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for DepositStakeAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEPOSIT_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [110, 130, 115, 41, 164, 102, 2, 59];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2226,6 +3246,183 @@ pub fn deposit_stake_account_ix(
 ) -> std::io::Result<Instruction> {
     deposit_stake_account_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`deposit_stake_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DepositStakeAccountIxBuilder {
+    state: Option<Pubkey>,
+    validator_list: Option<Pubkey>,
+    stake_list: Option<Pubkey>,
+    stake_account: Option<Pubkey>,
+    stake_authority: Option<Pubkey>,
+    duplication_flag: Option<Pubkey>,
+    rent_payer: Option<Pubkey>,
+    msol_mint: Option<Pubkey>,
+    mint_to: Option<Pubkey>,
+    msol_mint_authority: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    stake_program: Option<Pubkey>,
+    validator_index: Option<u32>,
+}
+impl Default for DepositStakeAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DepositStakeAccountIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            validator_list: None,
+            stake_list: None,
+            stake_account: None,
+            stake_authority: None,
+            duplication_flag: None,
+            rent_payer: None,
+            msol_mint: None,
+            mint_to: None,
+            msol_mint_authority: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            stake_program: Some(solana_program::pubkey!(
+                "Stake11111111111111111111111111111111111111"
+            )),
+            validator_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn validator_list(mut self, validator_list: Pubkey) -> Self {
+        self.validator_list = Some(validator_list);
+        self
+    }
+    pub fn stake_list(mut self, stake_list: Pubkey) -> Self {
+        self.stake_list = Some(stake_list);
+        self
+    }
+    pub fn stake_account(mut self, stake_account: Pubkey) -> Self {
+        self.stake_account = Some(stake_account);
+        self
+    }
+    pub fn stake_authority(mut self, stake_authority: Pubkey) -> Self {
+        self.stake_authority = Some(stake_authority);
+        self
+    }
+    pub fn duplication_flag(mut self, duplication_flag: Pubkey) -> Self {
+        self.duplication_flag = Some(duplication_flag);
+        self
+    }
+    pub fn rent_payer(mut self, rent_payer: Pubkey) -> Self {
+        self.rent_payer = Some(rent_payer);
+        self
+    }
+    pub fn msol_mint(mut self, msol_mint: Pubkey) -> Self {
+        self.msol_mint = Some(msol_mint);
+        self
+    }
+    pub fn mint_to(mut self, mint_to: Pubkey) -> Self {
+        self.mint_to = Some(mint_to);
+        self
+    }
+    pub fn msol_mint_authority(mut self, msol_mint_authority: Pubkey) -> Self {
+        self.msol_mint_authority = Some(msol_mint_authority);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn stake_program(mut self, stake_program: Pubkey) -> Self {
+        self.stake_program = Some(stake_program);
+        self
+    }
+    pub fn validator_index(mut self, validator_index: u32) -> Self {
+        self.validator_index = Some(validator_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DepositStakeAccountIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let validator_list = self
+            .validator_list
+            .ok_or_else(|| missing("validator_list"))?;
+        let stake_list = self.stake_list.ok_or_else(|| missing("stake_list"))?;
+        let stake_account = self.stake_account.ok_or_else(|| missing("stake_account"))?;
+        let stake_authority = self
+            .stake_authority
+            .ok_or_else(|| missing("stake_authority"))?;
+        let duplication_flag = self
+            .duplication_flag
+            .ok_or_else(|| missing("duplication_flag"))?;
+        let rent_payer = self.rent_payer.ok_or_else(|| missing("rent_payer"))?;
+        let msol_mint = self.msol_mint.ok_or_else(|| missing("msol_mint"))?;
+        let mint_to = self.mint_to.ok_or_else(|| missing("mint_to"))?;
+        let msol_mint_authority = self
+            .msol_mint_authority
+            .ok_or_else(|| missing("msol_mint_authority"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let stake_program = self.stake_program.ok_or_else(|| missing("stake_program"))?;
+        let args = DepositStakeAccountIxArgs {
+            validator_index: self
+                .validator_index
+                .ok_or_else(|| missing("validator_index"))?,
+        };
+        let keys = DepositStakeAccountKeys {
+            state,
+            validator_list,
+            stake_list,
+            stake_account,
+            stake_authority,
+            duplication_flag,
+            rent_payer,
+            msol_mint,
+            mint_to,
+            msol_mint_authority,
+            clock,
+            rent,
+            system_program,
+            token_program,
+            stake_program,
+        };
+        deposit_stake_account_ix(keys, args)
+    }
+}
 pub fn deposit_stake_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DepositStakeAccountAccounts<'_, '_>,
@@ -2472,6 +3669,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl LiquidUnstakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..LIQUID_UNSTAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[LIQUID_UNSTAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for LiquidUnstakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> LiquidUnstakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] = accounts
+            .get(..LIQUID_UNSTAKE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[LIQUID_UNSTAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for LiquidUnstakeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const LIQUID_UNSTAKE_IX_DISCM: [u8; 8] = [30, 30, 119, 240, 191, 227, 12, 16];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2530,6 +3765,137 @@ pub fn liquid_unstake_ix(
 ) -> std::io::Result<Instruction> {
     liquid_unstake_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`liquid_unstake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct LiquidUnstakeIxBuilder {
+    state: Option<Pubkey>,
+    msol_mint: Option<Pubkey>,
+    liq_pool_sol_leg_pda: Option<Pubkey>,
+    liq_pool_msol_leg: Option<Pubkey>,
+    treasury_msol_account: Option<Pubkey>,
+    get_msol_from: Option<Pubkey>,
+    get_msol_from_authority: Option<Pubkey>,
+    transfer_sol_to: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    msol_amount: Option<u64>,
+}
+impl Default for LiquidUnstakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl LiquidUnstakeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            msol_mint: None,
+            liq_pool_sol_leg_pda: None,
+            liq_pool_msol_leg: None,
+            treasury_msol_account: None,
+            get_msol_from: None,
+            get_msol_from_authority: None,
+            transfer_sol_to: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            msol_amount: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn msol_mint(mut self, msol_mint: Pubkey) -> Self {
+        self.msol_mint = Some(msol_mint);
+        self
+    }
+    pub fn liq_pool_sol_leg_pda(mut self, liq_pool_sol_leg_pda: Pubkey) -> Self {
+        self.liq_pool_sol_leg_pda = Some(liq_pool_sol_leg_pda);
+        self
+    }
+    pub fn liq_pool_msol_leg(mut self, liq_pool_msol_leg: Pubkey) -> Self {
+        self.liq_pool_msol_leg = Some(liq_pool_msol_leg);
+        self
+    }
+    pub fn treasury_msol_account(mut self, treasury_msol_account: Pubkey) -> Self {
+        self.treasury_msol_account = Some(treasury_msol_account);
+        self
+    }
+    pub fn get_msol_from(mut self, get_msol_from: Pubkey) -> Self {
+        self.get_msol_from = Some(get_msol_from);
+        self
+    }
+    pub fn get_msol_from_authority(mut self, get_msol_from_authority: Pubkey) -> Self {
+        self.get_msol_from_authority = Some(get_msol_from_authority);
+        self
+    }
+    pub fn transfer_sol_to(mut self, transfer_sol_to: Pubkey) -> Self {
+        self.transfer_sol_to = Some(transfer_sol_to);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn msol_amount(mut self, msol_amount: u64) -> Self {
+        self.msol_amount = Some(msol_amount);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("LiquidUnstakeIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let msol_mint = self.msol_mint.ok_or_else(|| missing("msol_mint"))?;
+        let liq_pool_sol_leg_pda = self
+            .liq_pool_sol_leg_pda
+            .ok_or_else(|| missing("liq_pool_sol_leg_pda"))?;
+        let liq_pool_msol_leg = self
+            .liq_pool_msol_leg
+            .ok_or_else(|| missing("liq_pool_msol_leg"))?;
+        let treasury_msol_account = self
+            .treasury_msol_account
+            .ok_or_else(|| missing("treasury_msol_account"))?;
+        let get_msol_from = self.get_msol_from.ok_or_else(|| missing("get_msol_from"))?;
+        let get_msol_from_authority = self
+            .get_msol_from_authority
+            .ok_or_else(|| missing("get_msol_from_authority"))?;
+        let transfer_sol_to = self
+            .transfer_sol_to
+            .ok_or_else(|| missing("transfer_sol_to"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = LiquidUnstakeIxArgs {
+            msol_amount: self.msol_amount.ok_or_else(|| missing("msol_amount"))?,
+        };
+        let keys = LiquidUnstakeKeys {
+            state,
+            msol_mint,
+            liq_pool_sol_leg_pda,
+            liq_pool_msol_leg,
+            treasury_msol_account,
+            get_msol_from,
+            get_msol_from_authority,
+            transfer_sol_to,
+            system_program,
+            token_program,
+        };
+        liquid_unstake_ix(keys, args)
+    }
+}
 pub fn liquid_unstake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: LiquidUnstakeAccounts<'_, '_>,
@@ -2768,6 +4134,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl AddLiquidityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ADD_LIQUIDITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[ADD_LIQUIDITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AddLiquidityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AddLiquidityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..ADD_LIQUIDITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[ADD_LIQUIDITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AddLiquidityAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const ADD_LIQUIDITY_IX_DISCM: [u8; 8] = [181, 157, 89, 67, 143, 182, 52, 72];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2826,6 +4230,125 @@ pub fn add_liquidity_ix(
 ) -> std::io::Result<Instruction> {
     add_liquidity_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`add_liquidity_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AddLiquidityIxBuilder {
+    state: Option<Pubkey>,
+    lp_mint: Option<Pubkey>,
+    lp_mint_authority: Option<Pubkey>,
+    liq_pool_msol_leg: Option<Pubkey>,
+    liq_pool_sol_leg_pda: Option<Pubkey>,
+    transfer_from: Option<Pubkey>,
+    mint_to: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    lamports: Option<u64>,
+}
+impl Default for AddLiquidityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AddLiquidityIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            lp_mint: None,
+            lp_mint_authority: None,
+            liq_pool_msol_leg: None,
+            liq_pool_sol_leg_pda: None,
+            transfer_from: None,
+            mint_to: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            lamports: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn lp_mint(mut self, lp_mint: Pubkey) -> Self {
+        self.lp_mint = Some(lp_mint);
+        self
+    }
+    pub fn lp_mint_authority(mut self, lp_mint_authority: Pubkey) -> Self {
+        self.lp_mint_authority = Some(lp_mint_authority);
+        self
+    }
+    pub fn liq_pool_msol_leg(mut self, liq_pool_msol_leg: Pubkey) -> Self {
+        self.liq_pool_msol_leg = Some(liq_pool_msol_leg);
+        self
+    }
+    pub fn liq_pool_sol_leg_pda(mut self, liq_pool_sol_leg_pda: Pubkey) -> Self {
+        self.liq_pool_sol_leg_pda = Some(liq_pool_sol_leg_pda);
+        self
+    }
+    pub fn transfer_from(mut self, transfer_from: Pubkey) -> Self {
+        self.transfer_from = Some(transfer_from);
+        self
+    }
+    pub fn mint_to(mut self, mint_to: Pubkey) -> Self {
+        self.mint_to = Some(mint_to);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = Some(lamports);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AddLiquidityIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let lp_mint = self.lp_mint.ok_or_else(|| missing("lp_mint"))?;
+        let lp_mint_authority = self
+            .lp_mint_authority
+            .ok_or_else(|| missing("lp_mint_authority"))?;
+        let liq_pool_msol_leg = self
+            .liq_pool_msol_leg
+            .ok_or_else(|| missing("liq_pool_msol_leg"))?;
+        let liq_pool_sol_leg_pda = self
+            .liq_pool_sol_leg_pda
+            .ok_or_else(|| missing("liq_pool_sol_leg_pda"))?;
+        let transfer_from = self.transfer_from.ok_or_else(|| missing("transfer_from"))?;
+        let mint_to = self.mint_to.ok_or_else(|| missing("mint_to"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = AddLiquidityIxArgs {
+            lamports: self.lamports.ok_or_else(|| missing("lamports"))?,
+        };
+        let keys = AddLiquidityKeys {
+            state,
+            lp_mint,
+            lp_mint_authority,
+            liq_pool_msol_leg,
+            liq_pool_sol_leg_pda,
+            transfer_from,
+            mint_to,
+            system_program,
+            token_program,
+        };
+        add_liquidity_ix(keys, args)
+    }
+}
 pub fn add_liquidity_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AddLiquidityAccounts<'_, '_>,
@@ -3077,6 +4600,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN
         }
     }
 }
+impl RemoveLiquidityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for RemoveLiquidityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RemoveLiquidityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for RemoveLiquidityAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REMOVE_LIQUIDITY_IX_DISCM: [u8; 8] = [80, 85, 209, 72, 24, 206, 177, 108];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3135,6 +4696,147 @@ pub fn remove_liquidity_ix(
 ) -> std::io::Result<Instruction> {
     remove_liquidity_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`remove_liquidity_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RemoveLiquidityIxBuilder {
+    state: Option<Pubkey>,
+    lp_mint: Option<Pubkey>,
+    burn_from: Option<Pubkey>,
+    burn_from_authority: Option<Pubkey>,
+    transfer_sol_to: Option<Pubkey>,
+    transfer_msol_to: Option<Pubkey>,
+    liq_pool_sol_leg_pda: Option<Pubkey>,
+    liq_pool_msol_leg: Option<Pubkey>,
+    liq_pool_msol_leg_authority: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    tokens: Option<u64>,
+}
+impl Default for RemoveLiquidityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RemoveLiquidityIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            lp_mint: None,
+            burn_from: None,
+            burn_from_authority: None,
+            transfer_sol_to: None,
+            transfer_msol_to: None,
+            liq_pool_sol_leg_pda: None,
+            liq_pool_msol_leg: None,
+            liq_pool_msol_leg_authority: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            tokens: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn lp_mint(mut self, lp_mint: Pubkey) -> Self {
+        self.lp_mint = Some(lp_mint);
+        self
+    }
+    pub fn burn_from(mut self, burn_from: Pubkey) -> Self {
+        self.burn_from = Some(burn_from);
+        self
+    }
+    pub fn burn_from_authority(mut self, burn_from_authority: Pubkey) -> Self {
+        self.burn_from_authority = Some(burn_from_authority);
+        self
+    }
+    pub fn transfer_sol_to(mut self, transfer_sol_to: Pubkey) -> Self {
+        self.transfer_sol_to = Some(transfer_sol_to);
+        self
+    }
+    pub fn transfer_msol_to(mut self, transfer_msol_to: Pubkey) -> Self {
+        self.transfer_msol_to = Some(transfer_msol_to);
+        self
+    }
+    pub fn liq_pool_sol_leg_pda(mut self, liq_pool_sol_leg_pda: Pubkey) -> Self {
+        self.liq_pool_sol_leg_pda = Some(liq_pool_sol_leg_pda);
+        self
+    }
+    pub fn liq_pool_msol_leg(mut self, liq_pool_msol_leg: Pubkey) -> Self {
+        self.liq_pool_msol_leg = Some(liq_pool_msol_leg);
+        self
+    }
+    pub fn liq_pool_msol_leg_authority(mut self, liq_pool_msol_leg_authority: Pubkey) -> Self {
+        self.liq_pool_msol_leg_authority = Some(liq_pool_msol_leg_authority);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn tokens(mut self, tokens: u64) -> Self {
+        self.tokens = Some(tokens);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RemoveLiquidityIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let lp_mint = self.lp_mint.ok_or_else(|| missing("lp_mint"))?;
+        let burn_from = self.burn_from.ok_or_else(|| missing("burn_from"))?;
+        let burn_from_authority = self
+            .burn_from_authority
+            .ok_or_else(|| missing("burn_from_authority"))?;
+        let transfer_sol_to = self
+            .transfer_sol_to
+            .ok_or_else(|| missing("transfer_sol_to"))?;
+        let transfer_msol_to = self
+            .transfer_msol_to
+            .ok_or_else(|| missing("transfer_msol_to"))?;
+        let liq_pool_sol_leg_pda = self
+            .liq_pool_sol_leg_pda
+            .ok_or_else(|| missing("liq_pool_sol_leg_pda"))?;
+        let liq_pool_msol_leg = self
+            .liq_pool_msol_leg
+            .ok_or_else(|| missing("liq_pool_msol_leg"))?;
+        let liq_pool_msol_leg_authority = self
+            .liq_pool_msol_leg_authority
+            .ok_or_else(|| missing("liq_pool_msol_leg_authority"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = RemoveLiquidityIxArgs {
+            tokens: self.tokens.ok_or_else(|| missing("tokens"))?,
+        };
+        let keys = RemoveLiquidityKeys {
+            state,
+            lp_mint,
+            burn_from,
+            burn_from_authority,
+            transfer_sol_to,
+            transfer_msol_to,
+            liq_pool_sol_leg_pda,
+            liq_pool_msol_leg,
+            liq_pool_msol_leg_authority,
+            system_program,
+            token_program,
+        };
+        remove_liquidity_ix(keys, args)
+    }
+}
 pub fn remove_liquidity_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RemoveLiquidityAccounts<'_, '_>,
@@ -3291,6 +4993,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LP_PARAMS_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl SetLpParamsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SET_LP_PARAMS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SET_LP_PARAMS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SET_LP_PARAMS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SetLpParamsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SetLpParamsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SET_LP_PARAMS_IX_ACCOUNTS_LEN] = accounts
+            .get(..SET_LP_PARAMS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SET_LP_PARAMS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SetLpParamsAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SET_LP_PARAMS_IX_DISCM: [u8; 8] = [227, 163, 242, 45, 79, 203, 106, 44];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3351,6 +5091,77 @@ pub fn set_lp_params_ix(
 ) -> std::io::Result<Instruction> {
     set_lp_params_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`set_lp_params_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SetLpParamsIxBuilder {
+    state: Option<Pubkey>,
+    admin_authority: Option<Pubkey>,
+    min_fee: Option<Fee>,
+    max_fee: Option<Fee>,
+    liquidity_target: Option<u64>,
+}
+impl Default for SetLpParamsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SetLpParamsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            admin_authority: None,
+            min_fee: None,
+            max_fee: None,
+            liquidity_target: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn admin_authority(mut self, admin_authority: Pubkey) -> Self {
+        self.admin_authority = Some(admin_authority);
+        self
+    }
+    pub fn min_fee(mut self, min_fee: Fee) -> Self {
+        self.min_fee = Some(min_fee);
+        self
+    }
+    pub fn max_fee(mut self, max_fee: Fee) -> Self {
+        self.max_fee = Some(max_fee);
+        self
+    }
+    pub fn liquidity_target(mut self, liquidity_target: u64) -> Self {
+        self.liquidity_target = Some(liquidity_target);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SetLpParamsIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let admin_authority = self
+            .admin_authority
+            .ok_or_else(|| missing("admin_authority"))?;
+        let args = SetLpParamsIxArgs {
+            min_fee: self.min_fee.ok_or_else(|| missing("min_fee"))?,
+            max_fee: self.max_fee.ok_or_else(|| missing("max_fee"))?,
+            liquidity_target: self
+                .liquidity_target
+                .ok_or_else(|| missing("liquidity_target"))?,
+        };
+        let keys = SetLpParamsKeys {
+            state,
+            admin_authority,
+        };
+        set_lp_params_ix(keys, args)
+    }
+}
 pub fn set_lp_params_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetLpParamsAccounts<'_, '_>,
@@ -3484,6 +5295,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN]
         }
     }
 }
+impl ConfigMarinadeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CONFIG_MARINADE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CONFIG_MARINADE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CONFIG_MARINADE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for ConfigMarinadeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ConfigMarinadeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN] = accounts
+            .get(..CONFIG_MARINADE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CONFIG_MARINADE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ConfigMarinadeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CONFIG_MARINADE_IX_DISCM: [u8; 8] = [67, 3, 34, 114, 190, 185, 17, 62];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3542,6 +5391,61 @@ pub fn config_marinade_ix(
 ) -> std::io::Result<Instruction> {
     config_marinade_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`config_marinade_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ConfigMarinadeIxBuilder {
+    state: Option<Pubkey>,
+    admin_authority: Option<Pubkey>,
+    params: Option<ConfigMarinadeParams>,
+}
+impl Default for ConfigMarinadeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ConfigMarinadeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            admin_authority: None,
+            params: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn admin_authority(mut self, admin_authority: Pubkey) -> Self {
+        self.admin_authority = Some(admin_authority);
+        self
+    }
+    pub fn params(mut self, params: ConfigMarinadeParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ConfigMarinadeIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let admin_authority = self
+            .admin_authority
+            .ok_or_else(|| missing("admin_authority"))?;
+        let args = ConfigMarinadeIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let keys = ConfigMarinadeKeys {
+            state,
+            admin_authority,
+        };
+        config_marinade_ix(keys, args)
+    }
+}
 pub fn config_marinade_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ConfigMarinadeAccounts<'_, '_>,
@@ -3744,6 +5648,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl OrderUnstakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ORDER_UNSTAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[ORDER_UNSTAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for OrderUnstakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> OrderUnstakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] = accounts
+            .get(..ORDER_UNSTAKE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[ORDER_UNSTAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for OrderUnstakeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const ORDER_UNSTAKE_IX_DISCM: [u8; 8] = [97, 167, 144, 107, 117, 190, 128, 36];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3802,6 +5744,119 @@ pub fn order_unstake_ix(
 ) -> std::io::Result<Instruction> {
     order_unstake_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`order_unstake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct OrderUnstakeIxBuilder {
+    state: Option<Pubkey>,
+    msol_mint: Option<Pubkey>,
+    burn_msol_from: Option<Pubkey>,
+    burn_msol_authority: Option<Pubkey>,
+    new_ticket_account: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    msol_amount: Option<u64>,
+}
+impl Default for OrderUnstakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl OrderUnstakeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            msol_mint: None,
+            burn_msol_from: None,
+            burn_msol_authority: None,
+            new_ticket_account: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            msol_amount: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn msol_mint(mut self, msol_mint: Pubkey) -> Self {
+        self.msol_mint = Some(msol_mint);
+        self
+    }
+    pub fn burn_msol_from(mut self, burn_msol_from: Pubkey) -> Self {
+        self.burn_msol_from = Some(burn_msol_from);
+        self
+    }
+    pub fn burn_msol_authority(mut self, burn_msol_authority: Pubkey) -> Self {
+        self.burn_msol_authority = Some(burn_msol_authority);
+        self
+    }
+    pub fn new_ticket_account(mut self, new_ticket_account: Pubkey) -> Self {
+        self.new_ticket_account = Some(new_ticket_account);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn msol_amount(mut self, msol_amount: u64) -> Self {
+        self.msol_amount = Some(msol_amount);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("OrderUnstakeIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let msol_mint = self.msol_mint.ok_or_else(|| missing("msol_mint"))?;
+        let burn_msol_from = self
+            .burn_msol_from
+            .ok_or_else(|| missing("burn_msol_from"))?;
+        let burn_msol_authority = self
+            .burn_msol_authority
+            .ok_or_else(|| missing("burn_msol_authority"))?;
+        let new_ticket_account = self
+            .new_ticket_account
+            .ok_or_else(|| missing("new_ticket_account"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = OrderUnstakeIxArgs {
+            msol_amount: self.msol_amount.ok_or_else(|| missing("msol_amount"))?,
+        };
+        let keys = OrderUnstakeKeys {
+            state,
+            msol_mint,
+            burn_msol_from,
+            burn_msol_authority,
+            new_ticket_account,
+            clock,
+            rent,
+            token_program,
+        };
+        order_unstake_ix(keys, args)
+    }
+}
 pub fn order_unstake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: OrderUnstakeAccounts<'_, '_>,
@@ -3991,6 +6046,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl ClaimKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CLAIM_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CLAIM_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CLAIM_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for ClaimKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ClaimAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN] = accounts
+            .get(..CLAIM_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CLAIM_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ClaimAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CLAIM_IX_DISCM: [u8; 8] = [62, 198, 214, 193, 213, 159, 108, 210];
 #[derive(Clone, Debug, PartialEq)]
 pub struct ClaimIxData;
@@ -4033,6 +6126,90 @@ pub fn claim_ix_with_program_id(
 pub fn claim_ix(keys: ClaimKeys) -> std::io::Result<Instruction> {
     claim_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`claim_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ClaimIxBuilder {
+    state: Option<Pubkey>,
+    reserve_pda: Option<Pubkey>,
+    ticket_account: Option<Pubkey>,
+    transfer_sol_to: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+}
+impl Default for ClaimIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ClaimIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            reserve_pda: None,
+            ticket_account: None,
+            transfer_sol_to: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn reserve_pda(mut self, reserve_pda: Pubkey) -> Self {
+        self.reserve_pda = Some(reserve_pda);
+        self
+    }
+    pub fn ticket_account(mut self, ticket_account: Pubkey) -> Self {
+        self.ticket_account = Some(ticket_account);
+        self
+    }
+    pub fn transfer_sol_to(mut self, transfer_sol_to: Pubkey) -> Self {
+        self.transfer_sol_to = Some(transfer_sol_to);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ClaimIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let reserve_pda = self.reserve_pda.ok_or_else(|| missing("reserve_pda"))?;
+        let ticket_account = self
+            .ticket_account
+            .ok_or_else(|| missing("ticket_account"))?;
+        let transfer_sol_to = self
+            .transfer_sol_to
+            .ok_or_else(|| missing("transfer_sol_to"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let keys = ClaimKeys {
+            state,
+            reserve_pda,
+            ticket_account,
+            transfer_sol_to,
+            clock,
+            system_program,
+        };
+        claim_ix(keys)
+    }
+}
 pub fn claim_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ClaimAccounts<'_, '_>,
@@ -4290,6 +6467,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl StakeReserveKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; STAKE_RESERVE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..STAKE_RESERVE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[STAKE_RESERVE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for StakeReserveKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> StakeReserveAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN] = accounts
+            .get(..STAKE_RESERVE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[STAKE_RESERVE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for StakeReserveAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const STAKE_RESERVE_IX_DISCM: [u8; 8] = [87, 217, 23, 179, 205, 25, 113, 129];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4348,6 +6563,175 @@ pub fn stake_reserve_ix(
 ) -> std::io::Result<Instruction> {
     stake_reserve_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`stake_reserve_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct StakeReserveIxBuilder {
+    state: Option<Pubkey>,
+    validator_list: Option<Pubkey>,
+    stake_list: Option<Pubkey>,
+    validator_vote: Option<Pubkey>,
+    reserve_pda: Option<Pubkey>,
+    stake_account: Option<Pubkey>,
+    stake_deposit_authority: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    epoch_schedule: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    stake_history: Option<Pubkey>,
+    stake_config: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    stake_program: Option<Pubkey>,
+    validator_index: Option<u32>,
+}
+impl Default for StakeReserveIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl StakeReserveIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            validator_list: None,
+            stake_list: None,
+            validator_vote: None,
+            reserve_pda: None,
+            stake_account: None,
+            stake_deposit_authority: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            epoch_schedule: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            stake_history: Some(solana_program::pubkey!(
+                "SysvarStakeHistory1111111111111111111111111"
+            )),
+            stake_config: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            stake_program: Some(solana_program::pubkey!(
+                "Stake11111111111111111111111111111111111111"
+            )),
+            validator_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn validator_list(mut self, validator_list: Pubkey) -> Self {
+        self.validator_list = Some(validator_list);
+        self
+    }
+    pub fn stake_list(mut self, stake_list: Pubkey) -> Self {
+        self.stake_list = Some(stake_list);
+        self
+    }
+    pub fn validator_vote(mut self, validator_vote: Pubkey) -> Self {
+        self.validator_vote = Some(validator_vote);
+        self
+    }
+    pub fn reserve_pda(mut self, reserve_pda: Pubkey) -> Self {
+        self.reserve_pda = Some(reserve_pda);
+        self
+    }
+    pub fn stake_account(mut self, stake_account: Pubkey) -> Self {
+        self.stake_account = Some(stake_account);
+        self
+    }
+    pub fn stake_deposit_authority(mut self, stake_deposit_authority: Pubkey) -> Self {
+        self.stake_deposit_authority = Some(stake_deposit_authority);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn epoch_schedule(mut self, epoch_schedule: Pubkey) -> Self {
+        self.epoch_schedule = Some(epoch_schedule);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn stake_history(mut self, stake_history: Pubkey) -> Self {
+        self.stake_history = Some(stake_history);
+        self
+    }
+    pub fn stake_config(mut self, stake_config: Pubkey) -> Self {
+        self.stake_config = Some(stake_config);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn stake_program(mut self, stake_program: Pubkey) -> Self {
+        self.stake_program = Some(stake_program);
+        self
+    }
+    pub fn validator_index(mut self, validator_index: u32) -> Self {
+        self.validator_index = Some(validator_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("StakeReserveIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let validator_list = self
+            .validator_list
+            .ok_or_else(|| missing("validator_list"))?;
+        let stake_list = self.stake_list.ok_or_else(|| missing("stake_list"))?;
+        let validator_vote = self
+            .validator_vote
+            .ok_or_else(|| missing("validator_vote"))?;
+        let reserve_pda = self.reserve_pda.ok_or_else(|| missing("reserve_pda"))?;
+        let stake_account = self.stake_account.ok_or_else(|| missing("stake_account"))?;
+        let stake_deposit_authority = self
+            .stake_deposit_authority
+            .ok_or_else(|| missing("stake_deposit_authority"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let epoch_schedule = self
+            .epoch_schedule
+            .ok_or_else(|| missing("epoch_schedule"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let stake_history = self.stake_history.ok_or_else(|| missing("stake_history"))?;
+        let stake_config = self.stake_config.ok_or_else(|| missing("stake_config"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let stake_program = self.stake_program.ok_or_else(|| missing("stake_program"))?;
+        let args = StakeReserveIxArgs {
+            validator_index: self
+                .validator_index
+                .ok_or_else(|| missing("validator_index"))?,
+        };
+        let keys = StakeReserveKeys {
+            state,
+            validator_list,
+            stake_list,
+            validator_vote,
+            reserve_pda,
+            stake_account,
+            stake_deposit_authority,
+            clock,
+            epoch_schedule,
+            rent,
+            stake_history,
+            stake_config,
+            system_program,
+            stake_program,
+        };
+        stake_reserve_ix(keys, args)
+    }
+}
 pub fn stake_reserve_invoke_with_program_id(
     program_id: Pubkey,
     accounts: StakeReserveAccounts<'_, '_>,
@@ -4616,6 +7000,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl UpdateActiveKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_ACTIVE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UPDATE_ACTIVE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateActiveKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateActiveAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_ACTIVE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UPDATE_ACTIVE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateActiveAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_ACTIVE_IX_DISCM: [u8; 8] = [4, 67, 81, 64, 136, 245, 93, 152];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4675,6 +7097,181 @@ pub fn update_active_ix(
 ) -> std::io::Result<Instruction> {
     update_active_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_active_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateActiveIxBuilder {
+    common_state: Option<Pubkey>,
+    common_stake_list: Option<Pubkey>,
+    common_stake_account: Option<Pubkey>,
+    common_stake_withdraw_authority: Option<Pubkey>,
+    common_reserve_pda: Option<Pubkey>,
+    common_msol_mint: Option<Pubkey>,
+    common_msol_mint_authority: Option<Pubkey>,
+    common_treasury_msol_account: Option<Pubkey>,
+    common_clock: Option<Pubkey>,
+    common_stake_history: Option<Pubkey>,
+    common_stake_program: Option<Pubkey>,
+    common_token_program: Option<Pubkey>,
+    validator_list: Option<Pubkey>,
+    stake_index: Option<u32>,
+    validator_index: Option<u32>,
+}
+impl Default for UpdateActiveIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateActiveIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            common_state: None,
+            common_stake_list: None,
+            common_stake_account: None,
+            common_stake_withdraw_authority: None,
+            common_reserve_pda: None,
+            common_msol_mint: None,
+            common_msol_mint_authority: None,
+            common_treasury_msol_account: None,
+            common_clock: None,
+            common_stake_history: None,
+            common_stake_program: None,
+            common_token_program: None,
+            validator_list: None,
+            stake_index: None,
+            validator_index: None,
+        }
+    }
+    pub fn common_state(mut self, common_state: Pubkey) -> Self {
+        self.common_state = Some(common_state);
+        self
+    }
+    pub fn common_stake_list(mut self, common_stake_list: Pubkey) -> Self {
+        self.common_stake_list = Some(common_stake_list);
+        self
+    }
+    pub fn common_stake_account(mut self, common_stake_account: Pubkey) -> Self {
+        self.common_stake_account = Some(common_stake_account);
+        self
+    }
+    pub fn common_stake_withdraw_authority(
+        mut self,
+        common_stake_withdraw_authority: Pubkey,
+    ) -> Self {
+        self.common_stake_withdraw_authority = Some(common_stake_withdraw_authority);
+        self
+    }
+    pub fn common_reserve_pda(mut self, common_reserve_pda: Pubkey) -> Self {
+        self.common_reserve_pda = Some(common_reserve_pda);
+        self
+    }
+    pub fn common_msol_mint(mut self, common_msol_mint: Pubkey) -> Self {
+        self.common_msol_mint = Some(common_msol_mint);
+        self
+    }
+    pub fn common_msol_mint_authority(mut self, common_msol_mint_authority: Pubkey) -> Self {
+        self.common_msol_mint_authority = Some(common_msol_mint_authority);
+        self
+    }
+    pub fn common_treasury_msol_account(mut self, common_treasury_msol_account: Pubkey) -> Self {
+        self.common_treasury_msol_account = Some(common_treasury_msol_account);
+        self
+    }
+    pub fn common_clock(mut self, common_clock: Pubkey) -> Self {
+        self.common_clock = Some(common_clock);
+        self
+    }
+    pub fn common_stake_history(mut self, common_stake_history: Pubkey) -> Self {
+        self.common_stake_history = Some(common_stake_history);
+        self
+    }
+    pub fn common_stake_program(mut self, common_stake_program: Pubkey) -> Self {
+        self.common_stake_program = Some(common_stake_program);
+        self
+    }
+    pub fn common_token_program(mut self, common_token_program: Pubkey) -> Self {
+        self.common_token_program = Some(common_token_program);
+        self
+    }
+    pub fn validator_list(mut self, validator_list: Pubkey) -> Self {
+        self.validator_list = Some(validator_list);
+        self
+    }
+    pub fn stake_index(mut self, stake_index: u32) -> Self {
+        self.stake_index = Some(stake_index);
+        self
+    }
+    pub fn validator_index(mut self, validator_index: u32) -> Self {
+        self.validator_index = Some(validator_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateActiveIxBuilder is missing {}", field),
+            )
+        };
+        let common_state = self.common_state.ok_or_else(|| missing("common_state"))?;
+        let common_stake_list = self
+            .common_stake_list
+            .ok_or_else(|| missing("common_stake_list"))?;
+        let common_stake_account = self
+            .common_stake_account
+            .ok_or_else(|| missing("common_stake_account"))?;
+        let common_stake_withdraw_authority = self
+            .common_stake_withdraw_authority
+            .ok_or_else(|| missing("common_stake_withdraw_authority"))?;
+        let common_reserve_pda = self
+            .common_reserve_pda
+            .ok_or_else(|| missing("common_reserve_pda"))?;
+        let common_msol_mint = self
+            .common_msol_mint
+            .ok_or_else(|| missing("common_msol_mint"))?;
+        let common_msol_mint_authority = self
+            .common_msol_mint_authority
+            .ok_or_else(|| missing("common_msol_mint_authority"))?;
+        let common_treasury_msol_account = self
+            .common_treasury_msol_account
+            .ok_or_else(|| missing("common_treasury_msol_account"))?;
+        let common_clock = self.common_clock.ok_or_else(|| missing("common_clock"))?;
+        let common_stake_history = self
+            .common_stake_history
+            .ok_or_else(|| missing("common_stake_history"))?;
+        let common_stake_program = self
+            .common_stake_program
+            .ok_or_else(|| missing("common_stake_program"))?;
+        let common_token_program = self
+            .common_token_program
+            .ok_or_else(|| missing("common_token_program"))?;
+        let validator_list = self
+            .validator_list
+            .ok_or_else(|| missing("validator_list"))?;
+        let args = UpdateActiveIxArgs {
+            stake_index: self.stake_index.ok_or_else(|| missing("stake_index"))?,
+            validator_index: self
+                .validator_index
+                .ok_or_else(|| missing("validator_index"))?,
+        };
+        let keys = UpdateActiveKeys {
+            common_state,
+            common_stake_list,
+            common_stake_account,
+            common_stake_withdraw_authority,
+            common_reserve_pda,
+            common_msol_mint,
+            common_msol_mint_authority,
+            common_treasury_msol_account,
+            common_clock,
+            common_stake_history,
+            common_stake_program,
+            common_token_program,
+            validator_list,
+        };
+        update_active_ix(keys, args)
+    }
+}
 pub fn update_active_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateActiveAccounts<'_, '_>,
@@ -4972,6 +7569,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_L
         }
     }
 }
+impl UpdateDeactivatedKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateDeactivatedKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateDeactivatedAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateDeactivatedAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_DEACTIVATED_IX_DISCM: [u8; 8] = [16, 232, 131, 115, 156, 100, 239, 50];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5030,6 +7665,182 @@ pub fn update_deactivated_ix(
 ) -> std::io::Result<Instruction> {
     update_deactivated_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_deactivated_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateDeactivatedIxBuilder {
+    common_state: Option<Pubkey>,
+    common_stake_list: Option<Pubkey>,
+    common_stake_account: Option<Pubkey>,
+    common_stake_withdraw_authority: Option<Pubkey>,
+    common_reserve_pda: Option<Pubkey>,
+    common_msol_mint: Option<Pubkey>,
+    common_msol_mint_authority: Option<Pubkey>,
+    common_treasury_msol_account: Option<Pubkey>,
+    common_clock: Option<Pubkey>,
+    common_stake_history: Option<Pubkey>,
+    common_stake_program: Option<Pubkey>,
+    common_token_program: Option<Pubkey>,
+    operational_sol_account: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    stake_index: Option<u32>,
+}
+impl Default for UpdateDeactivatedIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateDeactivatedIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            common_state: None,
+            common_stake_list: None,
+            common_stake_account: None,
+            common_stake_withdraw_authority: None,
+            common_reserve_pda: None,
+            common_msol_mint: None,
+            common_msol_mint_authority: None,
+            common_treasury_msol_account: None,
+            common_clock: None,
+            common_stake_history: None,
+            common_stake_program: None,
+            common_token_program: None,
+            operational_sol_account: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            stake_index: None,
+        }
+    }
+    pub fn common_state(mut self, common_state: Pubkey) -> Self {
+        self.common_state = Some(common_state);
+        self
+    }
+    pub fn common_stake_list(mut self, common_stake_list: Pubkey) -> Self {
+        self.common_stake_list = Some(common_stake_list);
+        self
+    }
+    pub fn common_stake_account(mut self, common_stake_account: Pubkey) -> Self {
+        self.common_stake_account = Some(common_stake_account);
+        self
+    }
+    pub fn common_stake_withdraw_authority(
+        mut self,
+        common_stake_withdraw_authority: Pubkey,
+    ) -> Self {
+        self.common_stake_withdraw_authority = Some(common_stake_withdraw_authority);
+        self
+    }
+    pub fn common_reserve_pda(mut self, common_reserve_pda: Pubkey) -> Self {
+        self.common_reserve_pda = Some(common_reserve_pda);
+        self
+    }
+    pub fn common_msol_mint(mut self, common_msol_mint: Pubkey) -> Self {
+        self.common_msol_mint = Some(common_msol_mint);
+        self
+    }
+    pub fn common_msol_mint_authority(mut self, common_msol_mint_authority: Pubkey) -> Self {
+        self.common_msol_mint_authority = Some(common_msol_mint_authority);
+        self
+    }
+    pub fn common_treasury_msol_account(mut self, common_treasury_msol_account: Pubkey) -> Self {
+        self.common_treasury_msol_account = Some(common_treasury_msol_account);
+        self
+    }
+    pub fn common_clock(mut self, common_clock: Pubkey) -> Self {
+        self.common_clock = Some(common_clock);
+        self
+    }
+    pub fn common_stake_history(mut self, common_stake_history: Pubkey) -> Self {
+        self.common_stake_history = Some(common_stake_history);
+        self
+    }
+    pub fn common_stake_program(mut self, common_stake_program: Pubkey) -> Self {
+        self.common_stake_program = Some(common_stake_program);
+        self
+    }
+    pub fn common_token_program(mut self, common_token_program: Pubkey) -> Self {
+        self.common_token_program = Some(common_token_program);
+        self
+    }
+    pub fn operational_sol_account(mut self, operational_sol_account: Pubkey) -> Self {
+        self.operational_sol_account = Some(operational_sol_account);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn stake_index(mut self, stake_index: u32) -> Self {
+        self.stake_index = Some(stake_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateDeactivatedIxBuilder is missing {}", field),
+            )
+        };
+        let common_state = self.common_state.ok_or_else(|| missing("common_state"))?;
+        let common_stake_list = self
+            .common_stake_list
+            .ok_or_else(|| missing("common_stake_list"))?;
+        let common_stake_account = self
+            .common_stake_account
+            .ok_or_else(|| missing("common_stake_account"))?;
+        let common_stake_withdraw_authority = self
+            .common_stake_withdraw_authority
+            .ok_or_else(|| missing("common_stake_withdraw_authority"))?;
+        let common_reserve_pda = self
+            .common_reserve_pda
+            .ok_or_else(|| missing("common_reserve_pda"))?;
+        let common_msol_mint = self
+            .common_msol_mint
+            .ok_or_else(|| missing("common_msol_mint"))?;
+        let common_msol_mint_authority = self
+            .common_msol_mint_authority
+            .ok_or_else(|| missing("common_msol_mint_authority"))?;
+        let common_treasury_msol_account = self
+            .common_treasury_msol_account
+            .ok_or_else(|| missing("common_treasury_msol_account"))?;
+        let common_clock = self.common_clock.ok_or_else(|| missing("common_clock"))?;
+        let common_stake_history = self
+            .common_stake_history
+            .ok_or_else(|| missing("common_stake_history"))?;
+        let common_stake_program = self
+            .common_stake_program
+            .ok_or_else(|| missing("common_stake_program"))?;
+        let common_token_program = self
+            .common_token_program
+            .ok_or_else(|| missing("common_token_program"))?;
+        let operational_sol_account = self
+            .operational_sol_account
+            .ok_or_else(|| missing("operational_sol_account"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = UpdateDeactivatedIxArgs {
+            stake_index: self.stake_index.ok_or_else(|| missing("stake_index"))?,
+        };
+        let keys = UpdateDeactivatedKeys {
+            common_state,
+            common_stake_list,
+            common_stake_account,
+            common_stake_withdraw_authority,
+            common_reserve_pda,
+            common_msol_mint,
+            common_msol_mint_authority,
+            common_treasury_msol_account,
+            common_clock,
+            common_stake_history,
+            common_stake_program,
+            common_token_program,
+            operational_sol_account,
+            system_program,
+        };
+        update_deactivated_ix(keys, args)
+    }
+}
 pub fn update_deactivated_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateDeactivatedAccounts<'_, '_>,
@@ -5331,6 +8142,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN
         }
     }
 }
+impl DeactivateStakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEACTIVATE_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[DEACTIVATE_STAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeactivateStakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeactivateStakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN] = accounts
+            .get(..DEACTIVATE_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[DEACTIVATE_STAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for DeactivateStakeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEACTIVATE_STAKE_IX_DISCM: [u8; 8] = [165, 158, 229, 97, 168, 220, 187, 225];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5390,6 +8239,184 @@ pub fn deactivate_stake_ix(
 ) -> std::io::Result<Instruction> {
     deactivate_stake_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`deactivate_stake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeactivateStakeIxBuilder {
+    state: Option<Pubkey>,
+    reserve_pda: Option<Pubkey>,
+    validator_list: Option<Pubkey>,
+    stake_list: Option<Pubkey>,
+    stake_account: Option<Pubkey>,
+    stake_deposit_authority: Option<Pubkey>,
+    split_stake_account: Option<Pubkey>,
+    split_stake_rent_payer: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    epoch_schedule: Option<Pubkey>,
+    stake_history: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    stake_program: Option<Pubkey>,
+    stake_index: Option<u32>,
+    validator_index: Option<u32>,
+}
+impl Default for DeactivateStakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeactivateStakeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            reserve_pda: None,
+            validator_list: None,
+            stake_list: None,
+            stake_account: None,
+            stake_deposit_authority: None,
+            split_stake_account: None,
+            split_stake_rent_payer: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            epoch_schedule: None,
+            stake_history: Some(solana_program::pubkey!(
+                "SysvarStakeHistory1111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            stake_program: Some(solana_program::pubkey!(
+                "Stake11111111111111111111111111111111111111"
+            )),
+            stake_index: None,
+            validator_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn reserve_pda(mut self, reserve_pda: Pubkey) -> Self {
+        self.reserve_pda = Some(reserve_pda);
+        self
+    }
+    pub fn validator_list(mut self, validator_list: Pubkey) -> Self {
+        self.validator_list = Some(validator_list);
+        self
+    }
+    pub fn stake_list(mut self, stake_list: Pubkey) -> Self {
+        self.stake_list = Some(stake_list);
+        self
+    }
+    pub fn stake_account(mut self, stake_account: Pubkey) -> Self {
+        self.stake_account = Some(stake_account);
+        self
+    }
+    pub fn stake_deposit_authority(mut self, stake_deposit_authority: Pubkey) -> Self {
+        self.stake_deposit_authority = Some(stake_deposit_authority);
+        self
+    }
+    pub fn split_stake_account(mut self, split_stake_account: Pubkey) -> Self {
+        self.split_stake_account = Some(split_stake_account);
+        self
+    }
+    pub fn split_stake_rent_payer(mut self, split_stake_rent_payer: Pubkey) -> Self {
+        self.split_stake_rent_payer = Some(split_stake_rent_payer);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn epoch_schedule(mut self, epoch_schedule: Pubkey) -> Self {
+        self.epoch_schedule = Some(epoch_schedule);
+        self
+    }
+    pub fn stake_history(mut self, stake_history: Pubkey) -> Self {
+        self.stake_history = Some(stake_history);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn stake_program(mut self, stake_program: Pubkey) -> Self {
+        self.stake_program = Some(stake_program);
+        self
+    }
+    pub fn stake_index(mut self, stake_index: u32) -> Self {
+        self.stake_index = Some(stake_index);
+        self
+    }
+    pub fn validator_index(mut self, validator_index: u32) -> Self {
+        self.validator_index = Some(validator_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DeactivateStakeIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let reserve_pda = self.reserve_pda.ok_or_else(|| missing("reserve_pda"))?;
+        let validator_list = self
+            .validator_list
+            .ok_or_else(|| missing("validator_list"))?;
+        let stake_list = self.stake_list.ok_or_else(|| missing("stake_list"))?;
+        let stake_account = self.stake_account.ok_or_else(|| missing("stake_account"))?;
+        let stake_deposit_authority = self
+            .stake_deposit_authority
+            .ok_or_else(|| missing("stake_deposit_authority"))?;
+        let split_stake_account = self
+            .split_stake_account
+            .ok_or_else(|| missing("split_stake_account"))?;
+        let split_stake_rent_payer = self
+            .split_stake_rent_payer
+            .ok_or_else(|| missing("split_stake_rent_payer"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let epoch_schedule = self
+            .epoch_schedule
+            .ok_or_else(|| missing("epoch_schedule"))?;
+        let stake_history = self.stake_history.ok_or_else(|| missing("stake_history"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let stake_program = self.stake_program.ok_or_else(|| missing("stake_program"))?;
+        let args = DeactivateStakeIxArgs {
+            stake_index: self.stake_index.ok_or_else(|| missing("stake_index"))?,
+            validator_index: self
+                .validator_index
+                .ok_or_else(|| missing("validator_index"))?,
+        };
+        let keys = DeactivateStakeKeys {
+            state,
+            reserve_pda,
+            validator_list,
+            stake_list,
+            stake_account,
+            stake_deposit_authority,
+            split_stake_account,
+            split_stake_rent_payer,
+            clock,
+            rent,
+            epoch_schedule,
+            stake_history,
+            system_program,
+            stake_program,
+        };
+        deactivate_stake_ix(keys, args)
+    }
+}
 pub fn deactivate_stake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateStakeAccounts<'_, '_>,
@@ -5620,6 +8647,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LE
         }
     }
 }
+impl EmergencyUnstakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for EmergencyUnstakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> EmergencyUnstakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN] = accounts
+            .get(..EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for EmergencyUnstakeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const EMERGENCY_UNSTAKE_IX_DISCM: [u8; 8] = [123, 69, 168, 195, 183, 213, 199, 214];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5679,6 +8744,126 @@ pub fn emergency_unstake_ix(
 ) -> std::io::Result<Instruction> {
     emergency_unstake_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`emergency_unstake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct EmergencyUnstakeIxBuilder {
+    state: Option<Pubkey>,
+    validator_manager_authority: Option<Pubkey>,
+    validator_list: Option<Pubkey>,
+    stake_list: Option<Pubkey>,
+    stake_account: Option<Pubkey>,
+    stake_deposit_authority: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    stake_program: Option<Pubkey>,
+    stake_index: Option<u32>,
+    validator_index: Option<u32>,
+}
+impl Default for EmergencyUnstakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl EmergencyUnstakeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            validator_manager_authority: None,
+            validator_list: None,
+            stake_list: None,
+            stake_account: None,
+            stake_deposit_authority: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            stake_program: Some(solana_program::pubkey!(
+                "Stake11111111111111111111111111111111111111"
+            )),
+            stake_index: None,
+            validator_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn validator_manager_authority(mut self, validator_manager_authority: Pubkey) -> Self {
+        self.validator_manager_authority = Some(validator_manager_authority);
+        self
+    }
+    pub fn validator_list(mut self, validator_list: Pubkey) -> Self {
+        self.validator_list = Some(validator_list);
+        self
+    }
+    pub fn stake_list(mut self, stake_list: Pubkey) -> Self {
+        self.stake_list = Some(stake_list);
+        self
+    }
+    pub fn stake_account(mut self, stake_account: Pubkey) -> Self {
+        self.stake_account = Some(stake_account);
+        self
+    }
+    pub fn stake_deposit_authority(mut self, stake_deposit_authority: Pubkey) -> Self {
+        self.stake_deposit_authority = Some(stake_deposit_authority);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn stake_program(mut self, stake_program: Pubkey) -> Self {
+        self.stake_program = Some(stake_program);
+        self
+    }
+    pub fn stake_index(mut self, stake_index: u32) -> Self {
+        self.stake_index = Some(stake_index);
+        self
+    }
+    pub fn validator_index(mut self, validator_index: u32) -> Self {
+        self.validator_index = Some(validator_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("EmergencyUnstakeIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let validator_manager_authority = self
+            .validator_manager_authority
+            .ok_or_else(|| missing("validator_manager_authority"))?;
+        let validator_list = self
+            .validator_list
+            .ok_or_else(|| missing("validator_list"))?;
+        let stake_list = self.stake_list.ok_or_else(|| missing("stake_list"))?;
+        let stake_account = self.stake_account.ok_or_else(|| missing("stake_account"))?;
+        let stake_deposit_authority = self
+            .stake_deposit_authority
+            .ok_or_else(|| missing("stake_deposit_authority"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let stake_program = self.stake_program.ok_or_else(|| missing("stake_program"))?;
+        let args = EmergencyUnstakeIxArgs {
+            stake_index: self.stake_index.ok_or_else(|| missing("stake_index"))?,
+            validator_index: self
+                .validator_index
+                .ok_or_else(|| missing("validator_index"))?,
+        };
+        let keys = EmergencyUnstakeKeys {
+            state,
+            validator_manager_authority,
+            validator_list,
+            stake_list,
+            stake_account,
+            stake_deposit_authority,
+            clock,
+            stake_program,
+        };
+        emergency_unstake_ix(keys, args)
+    }
+}
 pub fn emergency_unstake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: EmergencyUnstakeAccounts<'_, '_>,
@@ -5931,6 +9116,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl MergeStakesKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; MERGE_STAKES_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..MERGE_STAKES_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[MERGE_STAKES_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for MergeStakesKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> MergeStakesAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN] = accounts
+            .get(..MERGE_STAKES_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[MERGE_STAKES_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for MergeStakesAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const MERGE_STAKES_IX_DISCM: [u8; 8] = [216, 36, 141, 225, 243, 78, 125, 237];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5991,6 +9214,167 @@ pub fn merge_stakes_ix(
 ) -> std::io::Result<Instruction> {
     merge_stakes_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`merge_stakes_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct MergeStakesIxBuilder {
+    state: Option<Pubkey>,
+    stake_list: Option<Pubkey>,
+    validator_list: Option<Pubkey>,
+    destination_stake: Option<Pubkey>,
+    source_stake: Option<Pubkey>,
+    stake_deposit_authority: Option<Pubkey>,
+    stake_withdraw_authority: Option<Pubkey>,
+    operational_sol_account: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    stake_history: Option<Pubkey>,
+    stake_program: Option<Pubkey>,
+    destination_stake_index: Option<u32>,
+    source_stake_index: Option<u32>,
+    validator_index: Option<u32>,
+}
+impl Default for MergeStakesIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl MergeStakesIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            stake_list: None,
+            validator_list: None,
+            destination_stake: None,
+            source_stake: None,
+            stake_deposit_authority: None,
+            stake_withdraw_authority: None,
+            operational_sol_account: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            stake_history: Some(solana_program::pubkey!(
+                "SysvarStakeHistory1111111111111111111111111"
+            )),
+            stake_program: Some(solana_program::pubkey!(
+                "Stake11111111111111111111111111111111111111"
+            )),
+            destination_stake_index: None,
+            source_stake_index: None,
+            validator_index: None,
+        }
+    }
+    pub fn state(mut self, state: Pubkey) -> Self {
+        self.state = Some(state);
+        self
+    }
+    pub fn stake_list(mut self, stake_list: Pubkey) -> Self {
+        self.stake_list = Some(stake_list);
+        self
+    }
+    pub fn validator_list(mut self, validator_list: Pubkey) -> Self {
+        self.validator_list = Some(validator_list);
+        self
+    }
+    pub fn destination_stake(mut self, destination_stake: Pubkey) -> Self {
+        self.destination_stake = Some(destination_stake);
+        self
+    }
+    pub fn source_stake(mut self, source_stake: Pubkey) -> Self {
+        self.source_stake = Some(source_stake);
+        self
+    }
+    pub fn stake_deposit_authority(mut self, stake_deposit_authority: Pubkey) -> Self {
+        self.stake_deposit_authority = Some(stake_deposit_authority);
+        self
+    }
+    pub fn stake_withdraw_authority(mut self, stake_withdraw_authority: Pubkey) -> Self {
+        self.stake_withdraw_authority = Some(stake_withdraw_authority);
+        self
+    }
+    pub fn operational_sol_account(mut self, operational_sol_account: Pubkey) -> Self {
+        self.operational_sol_account = Some(operational_sol_account);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn stake_history(mut self, stake_history: Pubkey) -> Self {
+        self.stake_history = Some(stake_history);
+        self
+    }
+    pub fn stake_program(mut self, stake_program: Pubkey) -> Self {
+        self.stake_program = Some(stake_program);
+        self
+    }
+    pub fn destination_stake_index(mut self, destination_stake_index: u32) -> Self {
+        self.destination_stake_index = Some(destination_stake_index);
+        self
+    }
+    pub fn source_stake_index(mut self, source_stake_index: u32) -> Self {
+        self.source_stake_index = Some(source_stake_index);
+        self
+    }
+    pub fn validator_index(mut self, validator_index: u32) -> Self {
+        self.validator_index = Some(validator_index);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("MergeStakesIxBuilder is missing {}", field),
+            )
+        };
+        let state = self.state.ok_or_else(|| missing("state"))?;
+        let stake_list = self.stake_list.ok_or_else(|| missing("stake_list"))?;
+        let validator_list = self
+            .validator_list
+            .ok_or_else(|| missing("validator_list"))?;
+        let destination_stake = self
+            .destination_stake
+            .ok_or_else(|| missing("destination_stake"))?;
+        let source_stake = self.source_stake.ok_or_else(|| missing("source_stake"))?;
+        let stake_deposit_authority = self
+            .stake_deposit_authority
+            .ok_or_else(|| missing("stake_deposit_authority"))?;
+        let stake_withdraw_authority = self
+            .stake_withdraw_authority
+            .ok_or_else(|| missing("stake_withdraw_authority"))?;
+        let operational_sol_account = self
+            .operational_sol_account
+            .ok_or_else(|| missing("operational_sol_account"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let stake_history = self.stake_history.ok_or_else(|| missing("stake_history"))?;
+        let stake_program = self.stake_program.ok_or_else(|| missing("stake_program"))?;
+        let args = MergeStakesIxArgs {
+            destination_stake_index: self
+                .destination_stake_index
+                .ok_or_else(|| missing("destination_stake_index"))?,
+            source_stake_index: self
+                .source_stake_index
+                .ok_or_else(|| missing("source_stake_index"))?,
+            validator_index: self
+                .validator_index
+                .ok_or_else(|| missing("validator_index"))?,
+        };
+        let keys = MergeStakesKeys {
+            state,
+            stake_list,
+            validator_list,
+            destination_stake,
+            source_stake,
+            stake_deposit_authority,
+            stake_withdraw_authority,
+            operational_sol_account,
+            clock,
+            stake_history,
+            stake_program,
+        };
+        merge_stakes_ix(keys, args)
+    }
+}
 pub fn merge_stakes_invoke_with_program_id(
     program_id: Pubkey,
     accounts: MergeStakesAccounts<'_, '_>,
@@ -6078,3 +9462,218 @@ pub fn merge_stakes_verify_account_privileges<'me, 'info>(
     merge_stakes_verify_writable_privileges(accounts)?;
     Ok(())
 }
+/// A [`MarinadeFinanceProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MarinadeFinanceParsedIx {
+    Initialize {
+        keys: InitializeKeys,
+        args: InitializeIxArgs,
+    },
+    ChangeAuthority {
+        keys: ChangeAuthorityKeys,
+        args: ChangeAuthorityIxArgs,
+    },
+    AddValidator {
+        keys: AddValidatorKeys,
+        args: AddValidatorIxArgs,
+    },
+    RemoveValidator {
+        keys: RemoveValidatorKeys,
+        args: RemoveValidatorIxArgs,
+    },
+    SetValidatorScore {
+        keys: SetValidatorScoreKeys,
+        args: SetValidatorScoreIxArgs,
+    },
+    ConfigValidatorSystem {
+        keys: ConfigValidatorSystemKeys,
+        args: ConfigValidatorSystemIxArgs,
+    },
+    Deposit {
+        keys: DepositKeys,
+        args: DepositIxArgs,
+    },
+    DepositStakeAccount {
+        keys: DepositStakeAccountKeys,
+        args: DepositStakeAccountIxArgs,
+    },
+    LiquidUnstake {
+        keys: LiquidUnstakeKeys,
+        args: LiquidUnstakeIxArgs,
+    },
+    AddLiquidity {
+        keys: AddLiquidityKeys,
+        args: AddLiquidityIxArgs,
+    },
+    RemoveLiquidity {
+        keys: RemoveLiquidityKeys,
+        args: RemoveLiquidityIxArgs,
+    },
+    SetLpParams {
+        keys: SetLpParamsKeys,
+        args: SetLpParamsIxArgs,
+    },
+    ConfigMarinade {
+        keys: ConfigMarinadeKeys,
+        args: ConfigMarinadeIxArgs,
+    },
+    OrderUnstake {
+        keys: OrderUnstakeKeys,
+        args: OrderUnstakeIxArgs,
+    },
+    Claim {
+        keys: ClaimKeys,
+    },
+    StakeReserve {
+        keys: StakeReserveKeys,
+        args: StakeReserveIxArgs,
+    },
+    UpdateActive {
+        keys: UpdateActiveKeys,
+        args: UpdateActiveIxArgs,
+    },
+    UpdateDeactivated {
+        keys: UpdateDeactivatedKeys,
+        args: UpdateDeactivatedIxArgs,
+    },
+    DeactivateStake {
+        keys: DeactivateStakeKeys,
+        args: DeactivateStakeIxArgs,
+    },
+    EmergencyUnstake {
+        keys: EmergencyUnstakeKeys,
+        args: EmergencyUnstakeIxArgs,
+    },
+    MergeStakes {
+        keys: MergeStakesKeys,
+        args: MergeStakesIxArgs,
+    },
+}
+impl MarinadeFinanceParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = MarinadeFinanceProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            MarinadeFinanceProgramIx::Initialize(args) => Self::Initialize {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::ChangeAuthority(args) => Self::ChangeAuthority {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::AddValidator(args) => Self::AddValidator {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::RemoveValidator(args) => Self::RemoveValidator {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::SetValidatorScore(args) => Self::SetValidatorScore {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::ConfigValidatorSystem(args) => Self::ConfigValidatorSystem {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::Deposit(args) => Self::Deposit {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::DepositStakeAccount(args) => Self::DepositStakeAccount {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::LiquidUnstake(args) => Self::LiquidUnstake {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::AddLiquidity(args) => Self::AddLiquidity {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::RemoveLiquidity(args) => Self::RemoveLiquidity {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::SetLpParams(args) => Self::SetLpParams {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::ConfigMarinade(args) => Self::ConfigMarinade {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::OrderUnstake(args) => Self::OrderUnstake {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::Claim => Self::Claim {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            MarinadeFinanceProgramIx::StakeReserve(args) => Self::StakeReserve {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::UpdateActive(args) => Self::UpdateActive {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::UpdateDeactivated(args) => Self::UpdateDeactivated {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::DeactivateStake(args) => Self::DeactivateStake {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::EmergencyUnstake(args) => Self::EmergencyUnstake {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            MarinadeFinanceProgramIx::MergeStakes(args) => Self::MergeStakes {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
@@ -5,3 +5,7 @@ pub mod typedefs;
 pub use typedefs::*;
 pub mod instructions;
 pub use instructions::*;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "client")]
+pub use client::*;
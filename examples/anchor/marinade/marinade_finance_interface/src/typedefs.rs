@@ -5,6 +5,10 @@ use solana_program::pubkey::Pubkey;
 pub struct Fee {
     pub basis_points: u32,
 }
+/// Serialized size of [`Fee`]
+pub const FEE_LEN: usize = 4;
+/// Offset of `basis_points` in serialized [`Fee`]
+pub const FEE_BASIS_POINTS_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeData {
@@ -17,6 +21,24 @@ pub struct InitializeData {
     pub additional_validator_record_space: u32,
     pub slots_for_stake_delta: u64,
 }
+/// Serialized size of [`InitializeData`]
+pub const INITIALIZE_DATA_LEN: usize = 112;
+/// Offset of `admin_authority` in serialized [`InitializeData`]
+pub const INITIALIZE_DATA_ADMIN_AUTHORITY_OFFSET: usize = 0;
+/// Offset of `validator_manager_authority` in serialized [`InitializeData`]
+pub const INITIALIZE_DATA_VALIDATOR_MANAGER_AUTHORITY_OFFSET: usize = 32;
+/// Offset of `min_stake` in serialized [`InitializeData`]
+pub const INITIALIZE_DATA_MIN_STAKE_OFFSET: usize = 64;
+/// Offset of `reward_fee` in serialized [`InitializeData`]
+pub const INITIALIZE_DATA_REWARD_FEE_OFFSET: usize = 72;
+/// Offset of `liq_pool` in serialized [`InitializeData`]
+pub const INITIALIZE_DATA_LIQ_POOL_OFFSET: usize = 76;
+/// Offset of `additional_stake_record_space` in serialized [`InitializeData`]
+pub const INITIALIZE_DATA_ADDITIONAL_STAKE_RECORD_SPACE_OFFSET: usize = 96;
+/// Offset of `additional_validator_record_space` in serialized [`InitializeData`]
+pub const INITIALIZE_DATA_ADDITIONAL_VALIDATOR_RECORD_SPACE_OFFSET: usize = 100;
+/// Offset of `slots_for_stake_delta` in serialized [`InitializeData`]
+pub const INITIALIZE_DATA_SLOTS_FOR_STAKE_DELTA_OFFSET: usize = 104;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiqPoolInitializeData {
@@ -25,6 +47,16 @@ pub struct LiqPoolInitializeData {
     pub lp_min_fee: Fee,
     pub lp_treasury_cut: Fee,
 }
+/// Serialized size of [`LiqPoolInitializeData`]
+pub const LIQ_POOL_INITIALIZE_DATA_LEN: usize = 20;
+/// Offset of `lp_liquidity_target` in serialized [`LiqPoolInitializeData`]
+pub const LIQ_POOL_INITIALIZE_DATA_LP_LIQUIDITY_TARGET_OFFSET: usize = 0;
+/// Offset of `lp_max_fee` in serialized [`LiqPoolInitializeData`]
+pub const LIQ_POOL_INITIALIZE_DATA_LP_MAX_FEE_OFFSET: usize = 8;
+/// Offset of `lp_min_fee` in serialized [`LiqPoolInitializeData`]
+pub const LIQ_POOL_INITIALIZE_DATA_LP_MIN_FEE_OFFSET: usize = 12;
+/// Offset of `lp_treasury_cut` in serialized [`LiqPoolInitializeData`]
+pub const LIQ_POOL_INITIALIZE_DATA_LP_TREASURY_CUT_OFFSET: usize = 16;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeAuthorityData {
@@ -33,6 +65,8 @@ pub struct ChangeAuthorityData {
     pub operational_sol_account: Option<Pubkey>,
     pub treasury_msol_account: Option<Pubkey>,
 }
+/// Offset of `admin` in serialized [`ChangeAuthorityData`]
+pub const CHANGE_AUTHORITY_DATA_ADMIN_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfigMarinadeParams {
@@ -45,6 +79,8 @@ pub struct ConfigMarinadeParams {
     pub liquidity_sol_cap: Option<u64>,
     pub auto_add_validator_enabled: Option<bool>,
 }
+/// Offset of `rewards_fee` in serialized [`ConfigMarinadeParams`]
+pub const CONFIG_MARINADE_PARAMS_REWARDS_FEE_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiqPool {
@@ -61,6 +97,32 @@ pub struct LiqPool {
     pub lent_from_sol_leg: u64,
     pub liquidity_sol_cap: u64,
 }
+/// Serialized size of [`LiqPool`]
+pub const LIQ_POOL_LEN: usize = 111;
+/// Offset of `lp_mint` in serialized [`LiqPool`]
+pub const LIQ_POOL_LP_MINT_OFFSET: usize = 0;
+/// Offset of `lp_mint_authority_bump_seed` in serialized [`LiqPool`]
+pub const LIQ_POOL_LP_MINT_AUTHORITY_BUMP_SEED_OFFSET: usize = 32;
+/// Offset of `sol_leg_bump_seed` in serialized [`LiqPool`]
+pub const LIQ_POOL_SOL_LEG_BUMP_SEED_OFFSET: usize = 33;
+/// Offset of `msol_leg_authority_bump_seed` in serialized [`LiqPool`]
+pub const LIQ_POOL_MSOL_LEG_AUTHORITY_BUMP_SEED_OFFSET: usize = 34;
+/// Offset of `msol_leg` in serialized [`LiqPool`]
+pub const LIQ_POOL_MSOL_LEG_OFFSET: usize = 35;
+/// Offset of `lp_liquidity_target` in serialized [`LiqPool`]
+pub const LIQ_POOL_LP_LIQUIDITY_TARGET_OFFSET: usize = 67;
+/// Offset of `lp_max_fee` in serialized [`LiqPool`]
+pub const LIQ_POOL_LP_MAX_FEE_OFFSET: usize = 75;
+/// Offset of `lp_min_fee` in serialized [`LiqPool`]
+pub const LIQ_POOL_LP_MIN_FEE_OFFSET: usize = 79;
+/// Offset of `treasury_cut` in serialized [`LiqPool`]
+pub const LIQ_POOL_TREASURY_CUT_OFFSET: usize = 83;
+/// Offset of `lp_supply` in serialized [`LiqPool`]
+pub const LIQ_POOL_LP_SUPPLY_OFFSET: usize = 87;
+/// Offset of `lent_from_sol_leg` in serialized [`LiqPool`]
+pub const LIQ_POOL_LENT_FROM_SOL_LEG_OFFSET: usize = 95;
+/// Offset of `liquidity_sol_cap` in serialized [`LiqPool`]
+pub const LIQ_POOL_LIQUIDITY_SOL_CAP_OFFSET: usize = 103;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct List {
@@ -70,6 +132,18 @@ pub struct List {
     pub new_account: Pubkey,
     pub copied_count: u32,
 }
+/// Serialized size of [`List`]
+pub const LIST_LEN: usize = 76;
+/// Offset of `account` in serialized [`List`]
+pub const LIST_ACCOUNT_OFFSET: usize = 0;
+/// Offset of `item_size` in serialized [`List`]
+pub const LIST_ITEM_SIZE_OFFSET: usize = 32;
+/// Offset of `count` in serialized [`List`]
+pub const LIST_COUNT_OFFSET: usize = 36;
+/// Offset of `new_account` in serialized [`List`]
+pub const LIST_NEW_ACCOUNT_OFFSET: usize = 40;
+/// Offset of `copied_count` in serialized [`List`]
+pub const LIST_COPIED_COUNT_OFFSET: usize = 72;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StakeRecord {
@@ -78,6 +152,16 @@ pub struct StakeRecord {
     pub last_update_epoch: u64,
     pub is_emergency_unstaking: u8,
 }
+/// Serialized size of [`StakeRecord`]
+pub const STAKE_RECORD_LEN: usize = 49;
+/// Offset of `stake_account` in serialized [`StakeRecord`]
+pub const STAKE_RECORD_STAKE_ACCOUNT_OFFSET: usize = 0;
+/// Offset of `last_update_delegated_lamports` in serialized [`StakeRecord`]
+pub const STAKE_RECORD_LAST_UPDATE_DELEGATED_LAMPORTS_OFFSET: usize = 32;
+/// Offset of `last_update_epoch` in serialized [`StakeRecord`]
+pub const STAKE_RECORD_LAST_UPDATE_EPOCH_OFFSET: usize = 40;
+/// Offset of `is_emergency_unstaking` in serialized [`StakeRecord`]
+pub const STAKE_RECORD_IS_EMERGENCY_UNSTAKING_OFFSET: usize = 48;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StakeSystem {
@@ -90,6 +174,24 @@ pub struct StakeSystem {
     pub min_stake: u64,
     pub extra_stake_delta_runs: u32,
 }
+/// Serialized size of [`StakeSystem`]
+pub const STAKE_SYSTEM_LEN: usize = 114;
+/// Offset of `stake_list` in serialized [`StakeSystem`]
+pub const STAKE_SYSTEM_STAKE_LIST_OFFSET: usize = 0;
+/// Offset of `delayed_unstake_cooling_down` in serialized [`StakeSystem`]
+pub const STAKE_SYSTEM_DELAYED_UNSTAKE_COOLING_DOWN_OFFSET: usize = 76;
+/// Offset of `stake_deposit_bump_seed` in serialized [`StakeSystem`]
+pub const STAKE_SYSTEM_STAKE_DEPOSIT_BUMP_SEED_OFFSET: usize = 84;
+/// Offset of `stake_withdraw_bump_seed` in serialized [`StakeSystem`]
+pub const STAKE_SYSTEM_STAKE_WITHDRAW_BUMP_SEED_OFFSET: usize = 85;
+/// Offset of `slots_for_stake_delta` in serialized [`StakeSystem`]
+pub const STAKE_SYSTEM_SLOTS_FOR_STAKE_DELTA_OFFSET: usize = 86;
+/// Offset of `last_stake_delta_epoch` in serialized [`StakeSystem`]
+pub const STAKE_SYSTEM_LAST_STAKE_DELTA_EPOCH_OFFSET: usize = 94;
+/// Offset of `min_stake` in serialized [`StakeSystem`]
+pub const STAKE_SYSTEM_MIN_STAKE_OFFSET: usize = 102;
+/// Offset of `extra_stake_delta_runs` in serialized [`StakeSystem`]
+pub const STAKE_SYSTEM_EXTRA_STAKE_DELTA_RUNS_OFFSET: usize = 110;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidatorRecord {
@@ -99,6 +201,18 @@ pub struct ValidatorRecord {
     pub last_stake_delta_epoch: u64,
     pub duplication_flag_bump_seed: u8,
 }
+/// Serialized size of [`ValidatorRecord`]
+pub const VALIDATOR_RECORD_LEN: usize = 53;
+/// Offset of `validator_account` in serialized [`ValidatorRecord`]
+pub const VALIDATOR_RECORD_VALIDATOR_ACCOUNT_OFFSET: usize = 0;
+/// Offset of `active_balance` in serialized [`ValidatorRecord`]
+pub const VALIDATOR_RECORD_ACTIVE_BALANCE_OFFSET: usize = 32;
+/// Offset of `score` in serialized [`ValidatorRecord`]
+pub const VALIDATOR_RECORD_SCORE_OFFSET: usize = 40;
+/// Offset of `last_stake_delta_epoch` in serialized [`ValidatorRecord`]
+pub const VALIDATOR_RECORD_LAST_STAKE_DELTA_EPOCH_OFFSET: usize = 44;
+/// Offset of `duplication_flag_bump_seed` in serialized [`ValidatorRecord`]
+pub const VALIDATOR_RECORD_DUPLICATION_FLAG_BUMP_SEED_OFFSET: usize = 52;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidatorSystem {
@@ -108,3 +222,15 @@ pub struct ValidatorSystem {
     pub total_active_balance: u64,
     pub auto_add_validator_enabled: u8,
 }
+/// Serialized size of [`ValidatorSystem`]
+pub const VALIDATOR_SYSTEM_LEN: usize = 121;
+/// Offset of `validator_list` in serialized [`ValidatorSystem`]
+pub const VALIDATOR_SYSTEM_VALIDATOR_LIST_OFFSET: usize = 0;
+/// Offset of `manager_authority` in serialized [`ValidatorSystem`]
+pub const VALIDATOR_SYSTEM_MANAGER_AUTHORITY_OFFSET: usize = 76;
+/// Offset of `total_validator_score` in serialized [`ValidatorSystem`]
+pub const VALIDATOR_SYSTEM_TOTAL_VALIDATOR_SCORE_OFFSET: usize = 108;
+/// Offset of `total_active_balance` in serialized [`ValidatorSystem`]
+pub const VALIDATOR_SYSTEM_TOTAL_ACTIVE_BALANCE_OFFSET: usize = 112;
+/// Offset of `auto_add_validator_enabled` in serialized [`ValidatorSystem`]
+pub const VALIDATOR_SYSTEM_AUTO_ADD_VALIDATOR_ENABLED_OFFSET: usize = 120;
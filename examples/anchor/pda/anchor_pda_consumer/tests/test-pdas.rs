@@ -0,0 +1,83 @@
+use anchor_pda_interface::*;
+use solana_program::{pubkey, pubkey::Pubkey};
+
+const AUTHORITY: Pubkey = pubkey!("4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi");
+const MINT: Pubkey = pubkey!("8qbHbw2BbbTHBW1sbeqakYXVKRQM8Ne7pLK7m6CVfeR");
+const TAG: [u8; 8] = *b"tag00001";
+const LABEL: &str = "label";
+
+#[test]
+fn test_find_addresses() {
+    assert_eq!(
+        find_config_address(),
+        (pubkey!("4rLtKGqsrPZzMgSw8mhD4G8sSqRyjWDSqrDD3aHL2VfX"), 254)
+    );
+    let (vault, vault_bump) = find_vault_address(&AUTHORITY, 7);
+    assert_eq!(
+        (vault, vault_bump),
+        (pubkey!("GB1fFixz3RyBVZA1y6GHzNTYEGhfVPxgZyEqtc2zrnbu"), 249)
+    );
+    assert_eq!(
+        find_metadata_address(&vault),
+        (pubkey!("8FLhXsT7vYANbmd8BwzKVj87caH6CgxY4uD2ShQ2wrFM"), 254)
+    );
+    assert_eq!(
+        find_deposit_receipt_address(&MINT, true, &TAG, LABEL),
+        (pubkey!("GT8M9JS1D2JQpdF9Hy1PQNQbd9UTEUeZCq97sXpfqehR"), 255)
+    );
+    assert_eq!(
+        find_tagged_address(&TAG, LABEL, true),
+        (pubkey!("389nkfLWPSLW5vCMWELjrsJ4h8z4GDSgjL7p716Zz1pj"), 255)
+    );
+    assert_eq!(
+        find_withdraw_receipt_address(&AUTHORITY),
+        (pubkey!("359P5u5xN2XHY8SUqyDhNbn95rc49h84yVn7JHHgoBbW"), 255)
+    );
+}
+
+#[test]
+fn test_create_address_and_seeds_match_find() {
+    let (vault, bump) = find_vault_address(&AUTHORITY, 7);
+    assert_eq!(create_vault_address(&AUTHORITY, 7, bump).unwrap(), vault);
+    let vault_id = 7u64.to_le_bytes();
+    let bump = [bump];
+    let seeds = vault_seeds(&AUTHORITY, &vault_id, &bump);
+    assert_eq!(Pubkey::create_program_address(&seeds, &ID).unwrap(), vault);
+}
+
+#[test]
+fn test_keys_new_derives_pdas() {
+    assert_eq!(
+        WithdrawKeys::new(AUTHORITY),
+        WithdrawKeys {
+            authority: AUTHORITY,
+            receipt: pubkey!("359P5u5xN2XHY8SUqyDhNbn95rc49h84yVn7JHHgoBbW"),
+        }
+    );
+}
+
+#[test]
+fn test_unsupported_seed_account_is_not_derived() {
+    // `escrow` is seeded by a struct arg, so it has no PDA helpers and is set like any other account
+    let escrow = Pubkey::new_unique();
+    let ix = CloseVaultIxBuilder::new()
+        .authority(AUTHORITY)
+        .escrow(escrow)
+        .params(DepositParams {
+            locked: true,
+            tag: TAG,
+            label: LABEL.to_owned(),
+        })
+        .build()
+        .unwrap();
+    assert_eq!(ix.accounts[1].pubkey, escrow);
+    assert!(CloseVaultIxBuilder::new()
+        .authority(AUTHORITY)
+        .params(DepositParams {
+            locked: true,
+            tag: TAG,
+            label: LABEL.to_owned(),
+        })
+        .build()
+        .is_err());
+}
@@ -0,0 +1,160 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+pub const VAULT_ACCOUNT_DISCM: [u8; 8] = [211, 8, 232, 43, 2, 152, 117, 119];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vault {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub vault_id: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaultAccount(pub Vault);
+impl VaultAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != VAULT_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    VAULT_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(Vault::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&VAULT_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serialized size of [`Vault`]
+pub const VAULT_LEN: usize = 72;
+/// Offset of `authority` in serialized [`Vault`]
+pub const VAULT_AUTHORITY_OFFSET: usize = 0;
+/// Offset of `mint` in serialized [`Vault`]
+pub const VAULT_MINT_OFFSET: usize = 32;
+/// Offset of `vault_id` in serialized [`Vault`]
+pub const VAULT_VAULT_ID_OFFSET: usize = 64;
+/// Size of the account data, including the 8-byte discriminator
+pub const VAULT_ACCOUNT_LEN: usize = 8 + VAULT_LEN;
+/// Builds `getProgramAccounts` filters for [`VaultAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VaultAccountFilters(Vec<AccountFilter>);
+impl VaultAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: VAULT_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(VAULT_ACCOUNT_LEN as u64));
+        self
+    }
+    /// Matches accounts whose `authority` equals `value`
+    pub fn authority_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + VAULT_AUTHORITY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `mint` equals `value`
+    pub fn mint_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + VAULT_MINT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `vault_id` equals `value`
+    pub fn vault_id_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + VAULT_VAULT_ID_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl VaultAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> VaultAccountFilters {
+        VaultAccountFilters::default()
+    }
+}
+/// A `getProgramAccounts` filter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountFilter {
+    /// Account data is exactly this many bytes long
+    DataSize(u64),
+    /// Account data at `offset` starts with `bytes`
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+#[cfg(feature = "solana-rpc-client-api")]
+impl From<AccountFilter> for solana_rpc_client_api::filter::RpcFilterType {
+    fn from(filter: AccountFilter) -> Self {
+        match filter {
+            AccountFilter::DataSize(size) => Self::DataSize(size),
+            AccountFilter::Memcmp { offset, bytes } => Self::Memcmp(
+                solana_rpc_client_api::filter::Memcmp::new_base58_encoded(offset, &bytes),
+            ),
+        }
+    }
+}
+/// The 8-byte discriminator that account data starts with, None if the data is too short
+pub fn discriminator_of(data: &[u8]) -> Option<[u8; 8]> {
+    data.get(..8).map(|discm| discm.try_into().unwrap())
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorPdaAccount {
+    Vault(Vault),
+}
+impl AnchorPdaAccount {
+    /// Deserializes account data into the account type its discriminator identifies
+    pub fn try_from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        let mut reader = data;
+        let maybe_discm = <[u8; 8]>::deserialize(&mut reader)?;
+        match maybe_discm {
+            VAULT_ACCOUNT_DISCM => VaultAccount::deserialize(data).map(|a| Self::Vault(a.0)),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+}
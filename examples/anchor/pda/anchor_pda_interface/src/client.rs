@@ -0,0 +1,106 @@
+use crate::*;
+use solana_account_decoder::UiAccountEncoding;
+use solana_program::pubkey::Pubkey;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Result as ClientResult;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::RpcFilterType,
+};
+use solana_sdk::{
+    message::Message, signature::Signature, signer::Signer, transaction::Transaction,
+};
+/// Fetches and deserializes the [`VaultAccount`] at `pubkey`
+pub fn fetch_vault(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<VaultAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(VaultAccount::deserialize(&data)?)
+}
+/// Fetches all [`VaultAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_vault(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, VaultAccount)>> {
+    let filters = VaultAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, VaultAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Sends a transaction of [`init_vault_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_init_vault(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitVaultKeys,
+    args: InitVaultIxArgs,
+) -> ClientResult<Signature> {
+    let ix = init_vault_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`deposit_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_deposit(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: DepositKeys,
+    args: DepositIxArgs,
+) -> ClientResult<Signature> {
+    let ix = deposit_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`withdraw_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_withdraw(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: WithdrawKeys,
+) -> ClientResult<Signature> {
+    let ix = withdraw_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`sync_config_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_sync_config(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SyncConfigKeys,
+) -> ClientResult<Signature> {
+    let ix = sync_config_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`close_vault_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_close_vault(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: CloseVaultKeys,
+    args: CloseVaultIxArgs,
+) -> ClientResult<Signature> {
+    let ix = close_vault_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
@@ -0,0 +1,14 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositParams {
+    pub locked: bool,
+    pub tag: [u8; 8],
+    pub label: String,
+}
+/// Offset of `locked` in serialized [`DepositParams`]
+pub const DEPOSIT_PARAMS_LOCKED_OFFSET: usize = 0;
+/// Offset of `tag` in serialized [`DepositParams`]
+pub const DEPOSIT_PARAMS_TAG_OFFSET: usize = 1;
+/// Offset of `label` in serialized [`DepositParams`]
+pub const DEPOSIT_PARAMS_LABEL_OFFSET: usize = 9;
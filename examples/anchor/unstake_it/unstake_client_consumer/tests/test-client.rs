@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use solana_account_decoder::{UiAccount, UiAccountEncoding};
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::{
+    request::RpcRequest,
+    response::{Response, RpcKeyedAccount, RpcResponseContext},
+};
+use solana_sdk::{
+    account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, sysvar,
+};
+use unstake_interface::*;
+
+fn pool_account() -> (Pool, UiAccount) {
+    let pool = Pool {
+        fee_authority: Pubkey::new_unique(),
+        lp_mint: Pubkey::new_unique(),
+        incoming_stake: 1_000,
+    };
+    let account = Account {
+        lamports: 1_000_000,
+        data: PoolAccount(pool.clone()).try_to_vec().unwrap(),
+        owner: unstake_interface::ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    let ui_account = UiAccount::encode(
+        &Pubkey::new_unique(),
+        &account,
+        UiAccountEncoding::Base64,
+        None,
+        None,
+    );
+    (pool, ui_account)
+}
+
+fn context() -> RpcResponseContext {
+    RpcResponseContext {
+        slot: 1,
+        api_version: None,
+    }
+}
+
+#[test]
+fn test_fetch_pool() {
+    let (pool, ui_account) = pool_account();
+    let mocks = HashMap::from([(
+        RpcRequest::GetAccountInfo,
+        json!(Response {
+            context: context(),
+            value: ui_account,
+        }),
+    )]);
+    let client = RpcClient::new_mock_with_mocks("succeeds".to_owned(), mocks);
+    let fetched = fetch_pool(&client, &Pubkey::new_unique()).unwrap();
+    assert_eq!(fetched.0, pool);
+}
+
+#[test]
+fn test_fetch_wrong_account_err() {
+    let (_, ui_account) = pool_account();
+    let mocks = HashMap::from([(
+        RpcRequest::GetAccountInfo,
+        json!(Response {
+            context: context(),
+            value: ui_account,
+        }),
+    )]);
+    let client = RpcClient::new_mock_with_mocks("succeeds".to_owned(), mocks);
+    assert!(fetch_protocol_fee(&client, &Pubkey::new_unique()).is_err());
+}
+
+#[test]
+fn test_fetch_all_pool() {
+    let (pool, ui_account) = pool_account();
+    let pubkey = Pubkey::new_unique();
+    let mocks = HashMap::from([(
+        RpcRequest::GetProgramAccounts,
+        json!([RpcKeyedAccount {
+            pubkey: pubkey.to_string(),
+            account: ui_account,
+        }]),
+    )]);
+    let client = RpcClient::new_mock_with_mocks("succeeds".to_owned(), mocks);
+    let fetched = fetch_all_pool(
+        &client,
+        PoolAccount::filters().lp_mint_eq(&pool.lp_mint).build(),
+    )
+    .unwrap();
+    assert_eq!(fetched, vec![(pubkey, PoolAccount(pool))]);
+}
+
+#[test]
+fn test_send_set_fee() {
+    let client = RpcClient::new_mock("succeeds".to_owned());
+    let fee_authority = Keypair::new();
+    let keys = SetFeeKeys {
+        fee_authority: fee_authority.pubkey(),
+        pool_account: Pubkey::new_unique(),
+        fee_account: Pubkey::new_unique(),
+        system_program: system_program::ID,
+        rent: sysvar::rent::ID,
+    };
+    let args = SetFeeIxArgs {
+        fee: Fee {
+            fee: FeeEnum::Flat {
+                ratio: Rational {
+                    num: 1,
+                    denom: 10_000,
+                },
+            },
+        },
+    };
+    send_set_fee(
+        &client,
+        &fee_authority.pubkey(),
+        &[&fee_authority],
+        keys,
+        args,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_send_missing_signer_err() {
+    let client = RpcClient::new_mock("succeeds".to_owned());
+    let payer = Keypair::new();
+    let keys = SetFeeAuthorityKeys {
+        fee_authority: Pubkey::new_unique(),
+        pool_account: Pubkey::new_unique(),
+        new_fee_authority: Pubkey::new_unique(),
+    };
+    assert!(send_set_fee_authority(&client, &payer.pubkey(), &[&payer], keys).is_err());
+}
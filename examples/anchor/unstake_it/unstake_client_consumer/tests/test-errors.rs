@@ -0,0 +1,139 @@
+use solana_sdk::{instruction::InstructionError, program_error::ProgramError, pubkey::Pubkey};
+use unstake_interface::*;
+
+#[test]
+fn test_try_from_code() {
+    assert_eq!(UnstakeError::try_from(6006), Ok(UnstakeError::InvalidFee));
+    assert_eq!(UnstakeError::try_from(1), Err(1));
+}
+
+#[test]
+fn test_try_from_program_error() {
+    let e: ProgramError = UnstakeError::InvalidFee.into();
+    assert_eq!(UnstakeError::try_from(&e), Ok(UnstakeError::InvalidFee));
+    assert!(UnstakeError::try_from(&ProgramError::InvalidAccountData).is_err());
+    assert!(UnstakeError::try_from(&ProgramError::Custom(0)).is_err());
+}
+
+#[test]
+fn test_try_from_instruction_error() {
+    assert_eq!(
+        UnstakeError::try_from(&InstructionError::Custom(6001)),
+        Ok(UnstakeError::PdaBumpNotCached)
+    );
+    assert!(UnstakeError::try_from(&InstructionError::MissingRequiredSignature).is_err());
+}
+
+#[test]
+fn test_from_log() {
+    let e = UnstakeError::from_log(&format!(
+        "Program {} failed: custom program error: 0x1776",
+        unstake_interface::ID
+    ))
+    .unwrap();
+    assert_eq!(e, UnstakeError::InvalidFee);
+    assert_eq!(
+        e.to_string(),
+        "The provided description of fee violates the invariants"
+    );
+    assert_eq!(
+        UnstakeError::from_log("Program log: AnchorError occurred. Error Code: InvalidFee. Error Number: 6006. Error Message: The provided description of fee violates the invariants."),
+        Some(UnstakeError::InvalidFee)
+    );
+    assert_eq!(
+        UnstakeError::from_log("Program log: AnchorError thrown in programs/unstake/src/lib.rs:42. Error Code: PdaBumpNotCached. Error Number: 6001. Error Message: Could not find PDA bump."),
+        Some(UnstakeError::PdaBumpNotCached)
+    );
+    assert_eq!(
+        UnstakeError::from_log("Program log: Instruction: SetFee"),
+        None
+    );
+    assert_eq!(
+        UnstakeError::from_log(
+            "Program 11111111111111111111111111111111 failed: custom program error: 0x1"
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_parse_error_from_logs() {
+    let logs = vec![
+        format!("Program {} invoke [1]", unstake_interface::ID),
+        "Program log: Instruction: SetFee".to_owned(),
+        "Program log: AnchorError occurred. Error Code: InvalidFee. Error Number: 6006. Error Message: The provided description of fee violates the invariants.".to_owned(),
+        format!("Program {} consumed 1000 of 200000 compute units", unstake_interface::ID),
+        format!("Program {} failed: custom program error: 0x1776", unstake_interface::ID),
+    ];
+    assert_eq!(
+        parse_error_from_logs(&logs),
+        Some(UnstakeAnyError::Program(UnstakeError::InvalidFee))
+    );
+}
+
+#[test]
+fn test_parse_error_from_logs_invoked_program_err() {
+    let other_program = Pubkey::new_unique();
+    let logs = vec![
+        format!("Program {} invoke [1]", unstake_interface::ID),
+        format!("Program {} invoke [2]", other_program),
+        format!(
+            "Program {} failed: custom program error: 0x1776",
+            other_program
+        ),
+        format!(
+            "Program {} failed: custom program error: 0x1776",
+            unstake_interface::ID
+        ),
+    ];
+    assert_eq!(parse_error_from_logs(&logs), None);
+}
+
+#[test]
+fn test_framework_error() {
+    assert_eq!(
+        AnchorFrameworkError::try_from(&ProgramError::Custom(3002)),
+        Ok(AnchorFrameworkError::AccountDiscriminatorMismatch)
+    );
+    assert_eq!(
+        UnstakeError::try_from(&ProgramError::Custom(2006)),
+        Err(&ProgramError::Custom(2006))
+    );
+    assert_eq!(
+        UnstakeAnyError::try_from(&InstructionError::Custom(2006)),
+        Ok(UnstakeAnyError::Framework(
+            AnchorFrameworkError::ConstraintSeeds
+        ))
+    );
+    assert_eq!(
+        UnstakeAnyError::try_from(6006),
+        Ok(UnstakeAnyError::Program(UnstakeError::InvalidFee))
+    );
+    assert_eq!(UnstakeAnyError::try_from(1), Err(1));
+    assert_eq!(
+        UnstakeAnyError::try_from(2006).unwrap().to_string(),
+        "A seeds constraint was violated"
+    );
+}
+
+#[test]
+fn test_parse_framework_error_from_logs() {
+    let logs = vec![
+        format!("Program {} invoke [1]", unstake_interface::ID),
+        "Program log: Instruction: SetFee".to_owned(),
+        "Program log: AnchorError caused by account: pool_account. Error Code: ConstraintSeeds. Error Number: 2006. Error Message: A seeds constraint was violated.".to_owned(),
+        format!("Program {} failed: custom program error: 0x7d6", unstake_interface::ID),
+    ];
+    assert_eq!(
+        parse_error_from_logs(&logs),
+        Some(UnstakeAnyError::Framework(
+            AnchorFrameworkError::ConstraintSeeds
+        ))
+    );
+    assert_eq!(
+        UnstakeAnyError::from_log(&logs[2]),
+        Some(UnstakeAnyError::Framework(
+            AnchorFrameworkError::ConstraintSeeds
+        ))
+    );
+}
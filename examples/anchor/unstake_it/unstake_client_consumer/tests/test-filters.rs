@@ -0,0 +1,48 @@
+use solana_rpc_client_api::filter::RpcFilterType;
+use solana_sdk::pubkey::Pubkey;
+use unstake_interface::*;
+
+fn matches(filters: Vec<AccountFilter>, data: &[u8]) -> bool {
+    filters
+        .into_iter()
+        .map(RpcFilterType::from)
+        .all(|filter| match filter {
+            RpcFilterType::DataSize(size) => data.len() as u64 == size,
+            RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(data),
+            _ => unreachable!(),
+        })
+}
+
+#[test]
+fn test_pool_filters() {
+    let fee_authority = Pubkey::new_unique();
+    let lp_mint = Pubkey::new_unique();
+    let data = PoolAccount(Pool {
+        fee_authority,
+        lp_mint,
+        incoming_stake: 1_000,
+    })
+    .try_to_vec()
+    .unwrap();
+
+    let filters = PoolAccount::filters()
+        .discriminator()
+        .data_size()
+        .lp_mint_eq(&lp_mint)
+        .incoming_stake_eq(&1_000)
+        .build();
+    assert!(matches(filters, &data));
+
+    let filters = PoolAccount::filters()
+        .discriminator()
+        .lp_mint_eq(&fee_authority)
+        .build();
+    assert!(!matches(filters, &data));
+
+    // discriminator of another account
+    let filters = ProtocolFeeAccount::filters()
+        .discriminator()
+        .destination_eq(&fee_authority)
+        .build();
+    assert!(!matches(filters, &data));
+}
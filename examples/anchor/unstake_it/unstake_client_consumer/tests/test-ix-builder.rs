@@ -0,0 +1,115 @@
+use solana_sdk::{pubkey::Pubkey, system_program, sysvar};
+use unstake_interface::*;
+
+fn fee() -> Fee {
+    Fee {
+        fee: FeeEnum::Flat {
+            ratio: Rational {
+                num: 1,
+                denom: 10_000,
+            },
+        },
+    }
+}
+
+#[test]
+fn test_build_same_as_ix_fn() {
+    let keys = SetFeeKeys {
+        fee_authority: Pubkey::new_unique(),
+        pool_account: Pubkey::new_unique(),
+        fee_account: Pubkey::new_unique(),
+        system_program: system_program::ID,
+        rent: sysvar::rent::ID,
+    };
+    let built = SetFeeIxBuilder::new()
+        .fee_authority(keys.fee_authority)
+        .pool_account(keys.pool_account)
+        .fee_account(keys.fee_account)
+        .fee(fee())
+        .build()
+        .unwrap();
+    assert_eq!(
+        built,
+        set_fee_ix(keys, SetFeeIxArgs { fee: fee() }).unwrap()
+    );
+}
+
+#[test]
+fn test_build_fills_known_addresses() {
+    let fee_authority = Pubkey::new_unique();
+    let pool_account = Pubkey::new_unique();
+    let fee_account = Pubkey::new_unique();
+    // the IDL does not fix these addresses, so only the builder defaults them
+    let keys = SetFeeKeys {
+        fee_authority,
+        pool_account,
+        fee_account,
+        system_program: system_program::ID,
+        rent: sysvar::rent::ID,
+    };
+
+    let built = SetFeeIxBuilder::new()
+        .fee_authority(fee_authority)
+        .pool_account(pool_account)
+        .fee_account(fee_account)
+        .fee(fee())
+        .build()
+        .unwrap();
+    assert_eq!(
+        built,
+        set_fee_ix(keys, SetFeeIxArgs { fee: fee() }).unwrap()
+    );
+}
+
+#[test]
+fn test_default_same_as_new() {
+    let fee_authority = Pubkey::new_unique();
+    let pool_account = Pubkey::new_unique();
+    let fee_account = Pubkey::new_unique();
+    let build = |builder: SetFeeIxBuilder| {
+        builder
+            .fee_authority(fee_authority)
+            .pool_account(pool_account)
+            .fee_account(fee_account)
+            .fee(fee())
+            .build()
+            .unwrap()
+    };
+    assert_eq!(
+        build(SetFeeIxBuilder::default()),
+        build(SetFeeIxBuilder::new())
+    );
+}
+
+#[test]
+fn test_build_overrides_known_address() {
+    let rent = Pubkey::new_unique();
+    let ix = SetFeeIxBuilder::new()
+        .fee_authority(Pubkey::new_unique())
+        .pool_account(Pubkey::new_unique())
+        .fee_account(Pubkey::new_unique())
+        .rent(rent)
+        .fee(fee())
+        .build()
+        .unwrap();
+    assert_eq!(ix.accounts[4].pubkey, rent);
+}
+
+#[test]
+fn test_build_missing_field_err() {
+    let err = SetFeeIxBuilder::new()
+        .fee_authority(Pubkey::new_unique())
+        .fee_account(Pubkey::new_unique())
+        .fee(fee())
+        .build()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "SetFeeIxBuilder is missing pool_account");
+
+    let err = SetFeeIxBuilder::new()
+        .fee_authority(Pubkey::new_unique())
+        .pool_account(Pubkey::new_unique())
+        .fee_account(Pubkey::new_unique())
+        .build()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "SetFeeIxBuilder is missing fee");
+}
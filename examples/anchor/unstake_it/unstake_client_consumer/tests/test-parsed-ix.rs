@@ -0,0 +1,90 @@
+use solana_sdk::{
+    instruction::CompiledInstruction,
+    message::{v0::LoadedAddresses, AccountKeys, Message},
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+use unstake_interface::*;
+
+fn set_fee_keys_args() -> (SetFeeKeys, SetFeeIxArgs) {
+    let keys = SetFeeKeys {
+        fee_authority: Pubkey::new_unique(),
+        pool_account: Pubkey::new_unique(),
+        fee_account: Pubkey::new_unique(),
+        system_program: system_program::ID,
+        rent: sysvar::rent::ID,
+    };
+    let args = SetFeeIxArgs {
+        fee: Fee {
+            fee: FeeEnum::Flat {
+                ratio: Rational {
+                    num: 1,
+                    denom: 10_000,
+                },
+            },
+        },
+    };
+    (keys, args)
+}
+
+#[test]
+fn test_deserialize() {
+    let (keys, args) = set_fee_keys_args();
+    let ix = set_fee_ix(keys, args.clone()).unwrap();
+    let accounts: Vec<Pubkey> = ix.accounts.iter().map(|a| a.pubkey).collect();
+    assert_eq!(
+        UnstakeParsedIx::deserialize(&ix.data, &accounts).unwrap(),
+        UnstakeParsedIx::SetFee { keys, args }
+    );
+}
+
+#[test]
+fn test_deserialize_not_enough_accounts_err() {
+    let (keys, args) = set_fee_keys_args();
+    let ix = set_fee_ix(keys, args).unwrap();
+    let accounts: Vec<Pubkey> = ix.accounts.iter().map(|a| a.pubkey).collect();
+    assert!(UnstakeParsedIx::deserialize(&ix.data, &accounts[..accounts.len() - 1]).is_err());
+}
+
+#[test]
+fn test_from_compiled() {
+    let (keys, args) = set_fee_keys_args();
+    let ix = set_fee_ix(keys, args.clone()).unwrap();
+    let message = Message::new(&[ix], Some(&keys.fee_authority));
+    let account_keys = AccountKeys::new(&message.account_keys, None);
+    assert_eq!(
+        UnstakeParsedIx::from_compiled(&message.instructions[0], &account_keys).unwrap(),
+        UnstakeParsedIx::SetFee { keys, args }
+    );
+}
+
+#[test]
+fn test_from_compiled_lookup_table() {
+    let keys = SetFeeAuthorityKeys {
+        fee_authority: Pubkey::new_unique(),
+        pool_account: Pubkey::new_unique(),
+        new_fee_authority: Pubkey::new_unique(),
+    };
+    let ix = set_fee_authority_ix(keys).unwrap();
+    let static_keys = [keys.fee_authority, unstake_interface::ID];
+    let loaded = LoadedAddresses {
+        writable: vec![keys.pool_account],
+        readonly: vec![keys.new_fee_authority],
+    };
+    let account_keys = AccountKeys::new(&static_keys, Some(&loaded));
+    let compiled = CompiledInstruction {
+        program_id_index: 1,
+        accounts: vec![0, 2, 3],
+        data: ix.data,
+    };
+    assert_eq!(
+        UnstakeParsedIx::from_compiled(&compiled, &account_keys).unwrap(),
+        UnstakeParsedIx::SetFeeAuthority { keys }
+    );
+
+    let out_of_bounds = CompiledInstruction {
+        accounts: vec![0, 2, 4],
+        ..compiled
+    };
+    assert!(UnstakeParsedIx::from_compiled(&out_of_bounds, &account_keys).is_err());
+}
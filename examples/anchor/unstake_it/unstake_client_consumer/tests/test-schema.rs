@@ -0,0 +1,29 @@
+use schemars::schema_for;
+use serde_json::json;
+use unstake_interface::*;
+
+#[test]
+fn test_pool_schema() {
+    let schema = schema_for!(Pool);
+    let properties = &schema.as_value()["properties"];
+    assert_eq!(
+        properties["fee_authority"],
+        json!({ "type": "string", "pattern": "^[1-9A-HJ-NP-Za-km-z]{32,44}$" })
+    );
+    assert_eq!(
+        properties["incoming_stake"],
+        json!({ "type": "string", "pattern": "^-?[0-9]+$" })
+    );
+}
+
+#[test]
+fn test_ix_args_schema() {
+    let schema = schema_for!(SetFeeIxArgs);
+    let value = schema.as_value();
+    assert_eq!(value["title"], "SetFeeIxArgs");
+    assert_eq!(value["properties"]["fee"]["$ref"], "#/$defs/Fee");
+    assert_eq!(
+        value["$defs"]["Rational"]["properties"]["denom"],
+        json!({ "type": "string", "pattern": "^-?[0-9]+$" })
+    );
+}
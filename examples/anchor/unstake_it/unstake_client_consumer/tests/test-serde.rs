@@ -0,0 +1,52 @@
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use unstake_interface::*;
+
+#[test]
+fn test_pool_json() {
+    let pool = Pool {
+        fee_authority: Pubkey::new_unique(),
+        lp_mint: Pubkey::new_unique(),
+        incoming_stake: u64::MAX,
+    };
+    let value = serde_json::to_value(&pool).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "fee_authority": pool.fee_authority.to_string(),
+            "lp_mint": pool.lp_mint.to_string(),
+            "incoming_stake": u64::MAX.to_string(),
+        })
+    );
+    assert_eq!(serde_json::from_value::<Pool>(value).unwrap(), pool);
+}
+
+#[test]
+fn test_ix_args_json() {
+    let args = SetFeeIxArgs {
+        fee: Fee {
+            fee: FeeEnum::Flat {
+                ratio: Rational {
+                    num: 1,
+                    denom: 10_000,
+                },
+            },
+        },
+    };
+    let value = serde_json::to_value(&args).unwrap();
+    assert_eq!(
+        value,
+        json!({ "fee": { "fee": { "Flat": { "ratio": { "num": "1", "denom": "10000" } } } } })
+    );
+    assert_eq!(serde_json::from_value::<SetFeeIxArgs>(value).unwrap(), args);
+}
+
+#[test]
+fn test_invalid_pubkey_json() {
+    let value = json!({
+        "fee_authority": "not a pubkey",
+        "lp_mint": Pubkey::new_unique().to_string(),
+        "incoming_stake": "0",
+    });
+    assert!(serde_json::from_value::<Pool>(value).is_err());
+}
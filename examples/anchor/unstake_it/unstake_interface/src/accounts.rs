@@ -1,9 +1,10 @@
 use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 pub const FEE_ACCOUNT_DISCM: [u8; 8] = [24, 55, 150, 250, 168, 27, 101, 178];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Fee {
     pub fee: FeeEnum,
 }
@@ -26,6 +27,21 @@ impl FeeAccount {
         }
         Ok(Self(Fee::deserialize(&mut reader)?))
     }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
         writer.write_all(&FEE_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
@@ -36,12 +52,64 @@ impl FeeAccount {
         Ok(data)
     }
 }
+/// Offset of `fee` in serialized [`Fee`]
+pub const FEE_FEE_OFFSET: usize = 0;
+/// Builds `getProgramAccounts` filters for [`FeeAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FeeAccountFilters(Vec<AccountFilter>);
+impl FeeAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: FEE_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches accounts whose `fee` equals `value`
+    pub fn fee_eq(mut self, value: &FeeEnum) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + FEE_FEE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl FeeAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> FeeAccountFilters {
+        FeeAccountFilters::default()
+    }
+}
 pub const POOL_ACCOUNT_DISCM: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Pool {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub fee_authority: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub lp_mint: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub incoming_stake: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -63,6 +131,21 @@ impl PoolAccount {
         }
         Ok(Self(Pool::deserialize(&mut reader)?))
     }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
         writer.write_all(&POOL_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
@@ -73,11 +156,86 @@ impl PoolAccount {
         Ok(data)
     }
 }
+/// Serialized size of [`Pool`]
+pub const POOL_LEN: usize = 72;
+/// Offset of `fee_authority` in serialized [`Pool`]
+pub const POOL_FEE_AUTHORITY_OFFSET: usize = 0;
+/// Offset of `lp_mint` in serialized [`Pool`]
+pub const POOL_LP_MINT_OFFSET: usize = 32;
+/// Offset of `incoming_stake` in serialized [`Pool`]
+pub const POOL_INCOMING_STAKE_OFFSET: usize = 64;
+/// Size of the account data, including the 8-byte discriminator
+pub const POOL_ACCOUNT_LEN: usize = 8 + POOL_LEN;
+/// Builds `getProgramAccounts` filters for [`PoolAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PoolAccountFilters(Vec<AccountFilter>);
+impl PoolAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: POOL_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(POOL_ACCOUNT_LEN as u64));
+        self
+    }
+    /// Matches accounts whose `fee_authority` equals `value`
+    pub fn fee_authority_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + POOL_FEE_AUTHORITY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `lp_mint` equals `value`
+    pub fn lp_mint_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + POOL_LP_MINT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `incoming_stake` equals `value`
+    pub fn incoming_stake_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + POOL_INCOMING_STAKE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl PoolAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> PoolAccountFilters {
+        PoolAccountFilters::default()
+    }
+}
 pub const PROTOCOL_FEE_ACCOUNT_DISCM: [u8; 8] = [121, 127, 98, 139, 72, 110, 44, 118];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ProtocolFee {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub destination: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub authority: Pubkey,
     pub fee_ratio: Rational,
     pub referrer_fee_ratio: Rational,
@@ -101,6 +259,21 @@ impl ProtocolFeeAccount {
         }
         Ok(Self(ProtocolFee::deserialize(&mut reader)?))
     }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
         writer.write_all(&PROTOCOL_FEE_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
@@ -111,10 +284,93 @@ impl ProtocolFeeAccount {
         Ok(data)
     }
 }
+/// Serialized size of [`ProtocolFee`]
+pub const PROTOCOL_FEE_LEN: usize = 96;
+/// Offset of `destination` in serialized [`ProtocolFee`]
+pub const PROTOCOL_FEE_DESTINATION_OFFSET: usize = 0;
+/// Offset of `authority` in serialized [`ProtocolFee`]
+pub const PROTOCOL_FEE_AUTHORITY_OFFSET: usize = 32;
+/// Offset of `fee_ratio` in serialized [`ProtocolFee`]
+pub const PROTOCOL_FEE_FEE_RATIO_OFFSET: usize = 64;
+/// Offset of `referrer_fee_ratio` in serialized [`ProtocolFee`]
+pub const PROTOCOL_FEE_REFERRER_FEE_RATIO_OFFSET: usize = 80;
+/// Size of the account data, including the 8-byte discriminator
+pub const PROTOCOL_FEE_ACCOUNT_LEN: usize = 8 + PROTOCOL_FEE_LEN;
+/// Builds `getProgramAccounts` filters for [`ProtocolFeeAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProtocolFeeAccountFilters(Vec<AccountFilter>);
+impl ProtocolFeeAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: PROTOCOL_FEE_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(PROTOCOL_FEE_ACCOUNT_LEN as u64));
+        self
+    }
+    /// Matches accounts whose `destination` equals `value`
+    pub fn destination_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PROTOCOL_FEE_DESTINATION_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `authority` equals `value`
+    pub fn authority_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PROTOCOL_FEE_AUTHORITY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `fee_ratio` equals `value`
+    pub fn fee_ratio_eq(mut self, value: &Rational) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PROTOCOL_FEE_FEE_RATIO_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `referrer_fee_ratio` equals `value`
+    pub fn referrer_fee_ratio_eq(mut self, value: &Rational) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PROTOCOL_FEE_REFERRER_FEE_RATIO_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl ProtocolFeeAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> ProtocolFeeAccountFilters {
+        ProtocolFeeAccountFilters::default()
+    }
+}
 pub const STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM: [u8; 8] = [144, 205, 183, 241, 3, 250, 208, 215];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct StakeAccountRecord {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub lamports_at_creation: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -136,6 +392,21 @@ impl StakeAccountRecordAccount {
         }
         Ok(Self(StakeAccountRecord::deserialize(&mut reader)?))
     }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
         writer.write_all(&STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
@@ -146,3 +417,100 @@ impl StakeAccountRecordAccount {
         Ok(data)
     }
 }
+/// Serialized size of [`StakeAccountRecord`]
+pub const STAKE_ACCOUNT_RECORD_LEN: usize = 8;
+/// Offset of `lamports_at_creation` in serialized [`StakeAccountRecord`]
+pub const STAKE_ACCOUNT_RECORD_LAMPORTS_AT_CREATION_OFFSET: usize = 0;
+/// Size of the account data, including the 8-byte discriminator
+pub const STAKE_ACCOUNT_RECORD_ACCOUNT_LEN: usize = 8 + STAKE_ACCOUNT_RECORD_LEN;
+/// Builds `getProgramAccounts` filters for [`StakeAccountRecordAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StakeAccountRecordAccountFilters(Vec<AccountFilter>);
+impl StakeAccountRecordAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0.push(AccountFilter::DataSize(
+            STAKE_ACCOUNT_RECORD_ACCOUNT_LEN as u64,
+        ));
+        self
+    }
+    /// Matches accounts whose `lamports_at_creation` equals `value`
+    pub fn lamports_at_creation_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STAKE_ACCOUNT_RECORD_LAMPORTS_AT_CREATION_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl StakeAccountRecordAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> StakeAccountRecordAccountFilters {
+        StakeAccountRecordAccountFilters::default()
+    }
+}
+/// A `getProgramAccounts` filter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountFilter {
+    /// Account data is exactly this many bytes long
+    DataSize(u64),
+    /// Account data at `offset` starts with `bytes`
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+#[cfg(feature = "solana-rpc-client-api")]
+impl From<AccountFilter> for solana_rpc_client_api::filter::RpcFilterType {
+    fn from(filter: AccountFilter) -> Self {
+        match filter {
+            AccountFilter::DataSize(size) => Self::DataSize(size),
+            AccountFilter::Memcmp { offset, bytes } => Self::Memcmp(
+                solana_rpc_client_api::filter::Memcmp::new_base58_encoded(offset, &bytes),
+            ),
+        }
+    }
+}
+/// The 8-byte discriminator that account data starts with, None if the data is too short
+pub fn discriminator_of(data: &[u8]) -> Option<[u8; 8]> {
+    data.get(..8).map(|discm| discm.try_into().unwrap())
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnstakeAccount {
+    Fee(Fee),
+    Pool(Pool),
+    ProtocolFee(ProtocolFee),
+    StakeAccountRecord(StakeAccountRecord),
+}
+impl UnstakeAccount {
+    /// Deserializes account data into the account type its discriminator identifies
+    pub fn try_from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        let mut reader = data;
+        let maybe_discm = <[u8; 8]>::deserialize(&mut reader)?;
+        match maybe_discm {
+            FEE_ACCOUNT_DISCM => FeeAccount::deserialize(data).map(|a| Self::Fee(a.0)),
+            POOL_ACCOUNT_DISCM => PoolAccount::deserialize(data).map(|a| Self::Pool(a.0)),
+            PROTOCOL_FEE_ACCOUNT_DISCM => {
+                ProtocolFeeAccount::deserialize(data).map(|a| Self::ProtocolFee(a.0))
+            }
+            STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM => {
+                StakeAccountRecordAccount::deserialize(data).map(|a| Self::StakeAccountRecord(a.0))
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use crate::*;
+use solana_account_decoder::UiAccountEncoding;
+use solana_program::pubkey::Pubkey;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Result as ClientResult;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::RpcFilterType,
+};
+use solana_sdk::{
+    message::Message, signature::Signature, signer::Signer, transaction::Transaction,
+};
+/// Fetches and deserializes the [`FeeAccount`] at `pubkey`
+pub fn fetch_fee(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<FeeAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(FeeAccount::deserialize(&data)?)
+}
+/// Fetches all [`FeeAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_fee(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, FeeAccount)>> {
+    let filters = FeeAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, FeeAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Fetches and deserializes the [`PoolAccount`] at `pubkey`
+pub fn fetch_pool(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<PoolAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(PoolAccount::deserialize(&data)?)
+}
+/// Fetches all [`PoolAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_pool(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, PoolAccount)>> {
+    let filters = PoolAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, PoolAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Fetches and deserializes the [`ProtocolFeeAccount`] at `pubkey`
+pub fn fetch_protocol_fee(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<ProtocolFeeAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(ProtocolFeeAccount::deserialize(&data)?)
+}
+/// Fetches all [`ProtocolFeeAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_protocol_fee(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, ProtocolFeeAccount)>> {
+    let filters = ProtocolFeeAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, ProtocolFeeAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Fetches and deserializes the [`StakeAccountRecordAccount`] at `pubkey`
+pub fn fetch_stake_account_record(
+    client: &RpcClient,
+    pubkey: &Pubkey,
+) -> ClientResult<StakeAccountRecordAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(StakeAccountRecordAccount::deserialize(&data)?)
+}
+/// Fetches all [`StakeAccountRecordAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_stake_account_record(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, StakeAccountRecordAccount)>> {
+    let filters = StakeAccountRecordAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| {
+            Ok((
+                pubkey,
+                StakeAccountRecordAccount::deserialize(&account.data)?,
+            ))
+        })
+        .collect()
+}
+/// Sends a transaction of [`init_protocol_fee_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_init_protocol_fee(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitProtocolFeeKeys,
+) -> ClientResult<Signature> {
+    let ix = init_protocol_fee_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`set_protocol_fee_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_set_protocol_fee(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SetProtocolFeeKeys,
+    args: SetProtocolFeeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = set_protocol_fee_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`create_pool_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_create_pool(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: CreatePoolKeys,
+    args: CreatePoolIxArgs,
+) -> ClientResult<Signature> {
+    let ix = create_pool_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`add_liquidity_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_add_liquidity(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: AddLiquidityKeys,
+    args: AddLiquidityIxArgs,
+) -> ClientResult<Signature> {
+    let ix = add_liquidity_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`remove_liquidity_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_remove_liquidity(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: RemoveLiquidityKeys,
+    args: RemoveLiquidityIxArgs,
+) -> ClientResult<Signature> {
+    let ix = remove_liquidity_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`set_fee_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_set_fee(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SetFeeKeys,
+    args: SetFeeIxArgs,
+) -> ClientResult<Signature> {
+    let ix = set_fee_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`set_fee_authority_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_set_fee_authority(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: SetFeeAuthorityKeys,
+) -> ClientResult<Signature> {
+    let ix = set_fee_authority_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`deactivate_stake_account_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_deactivate_stake_account(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: DeactivateStakeAccountKeys,
+) -> ClientResult<Signature> {
+    let ix = deactivate_stake_account_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`reclaim_stake_account_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_reclaim_stake_account(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: ReclaimStakeAccountKeys,
+) -> ClientResult<Signature> {
+    let ix = reclaim_stake_account_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`unstake_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_unstake(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UnstakeKeys,
+) -> ClientResult<Signature> {
+    let ix = unstake_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
+/// Sends a transaction of [`unstake_wsol_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_unstake_wsol(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: UnstakeWsolKeys,
+) -> ClientResult<Signature> {
+    let ix = unstake_wsol_ix(keys)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
@@ -1,41 +1,56 @@
 use solana_program::{
     decode_error::DecodeError,
+    instruction::InstructionError,
     msg,
     program_error::{PrintProgramError, ProgramError},
+    pubkey::Pubkey,
 };
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
 pub enum UnstakeError {
+    /// The provided LP token account is invalid
     #[error("The provided LP token account is invalid")]
     InvalidLpTokenAccount = 6000,
+    /// Could not find PDA bump
     #[error("Could not find PDA bump")]
     PdaBumpNotCached = 6001,
+    /// The provided fee authority does not have the authority over the provided pool account
     #[error(
         "The provided fee authority does not have the authority over the provided pool account"
     )]
     InvalidFeeAuthority = 6002,
+    /// The Authorized of the given stake account is None (possibly an uninitialized stake account was given)
     #[error(
         "The Authorized of the given stake account is None (possibly an uninitialized stake account was given)"
     )]
     StakeAccountAuthorizedNotRetrievable = 6003,
+    /// The Lockup of the given stake account is None (possibly an uninitialized stake account was given)
     #[error(
         "The Lockup of the given stake account is None (possibly an uninitialized stake account was given)"
     )]
     StakeAccountLockupNotRetrievable = 6004,
+    /// The provided stake account is locked up
     #[error("The provided stake account is locked up")]
     StakeAccountLockupInForce = 6005,
+    /// The provided description of fee violates the invariants
     #[error("The provided description of fee violates the invariants")]
     InvalidFee = 6006,
+    /// Internal Error
     #[error("Internal Error")]
     InternalError = 6007,
+    /// Not enough liquidity to service this unstake
     #[error("Not enough liquidity to service this unstake")]
     NotEnoughLiquidity = 6008,
+    /// Liquidity to add too little
     #[error("Liquidity to add too little")]
     LiquidityToAddTooLittle = 6009,
+    /// Destination token account is not a wrapped SOL account
     #[error("Destination token account is not a wrapped SOL account")]
     DestinationNotWSol = 6010,
+    /// Wrong protocol fee destination account
     #[error("Wrong protocol fee destination account")]
     WrongProtocolFeeDestination = 6011,
+    /// The provided protocol fee authority does not have the authority over the protocol fee account
     #[error(
         "The provided protocol fee authority does not have the authority over the protocol fee account"
     )]
@@ -63,3 +78,390 @@ impl PrintProgramError for UnstakeError {
         msg!(&self.to_string());
     }
 }
+impl TryFrom<u32> for UnstakeError {
+    type Error = u32;
+    /// Returns `code` back if it is not one of this program's errors
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        <Self as num_traits::FromPrimitive>::from_u32(code).ok_or(code)
+    }
+}
+impl<'a> TryFrom<&'a ProgramError> for UnstakeError {
+    type Error = &'a ProgramError;
+    /// Returns `e` back if it is not a `ProgramError::Custom` of this program's errors
+    fn try_from(e: &'a ProgramError) -> Result<Self, Self::Error> {
+        match e {
+            ProgramError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl<'a> TryFrom<&'a InstructionError> for UnstakeError {
+    type Error = &'a InstructionError;
+    /// Returns `e` back if it is not an `InstructionError::Custom` of this program's errors
+    fn try_from(e: &'a InstructionError) -> Result<Self, Self::Error> {
+        match e {
+            InstructionError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl UnstakeError {
+    /// Parses a `custom program error: 0x...` or `AnchorError ... Error Number: N.` log line
+    /// into the error, e.g. `Program <id> failed: custom program error: 0x1770`.
+    /// Returns `None` for other logs and codes that are not of this program's errors.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let code = if let Some((_, hex)) = log.split_once("custom program error: 0x") {
+            u32::from_str_radix(hex.trim_end(), 16).ok()?
+        } else if let Some((_, rest)) = log
+            .split_once("AnchorError ")
+            .and_then(|(_, rest)| rest.split_once("Error Number: "))
+        {
+            rest.split('.').next()?.parse().ok()?
+        } else {
+            return None;
+        };
+        Self::try_from(code).ok()
+    }
+}
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum AnchorFrameworkError {
+    /// 8 byte instruction identifier not provided
+    #[error("8 byte instruction identifier not provided")]
+    InstructionMissing = 100,
+    /// Fallback functions are not supported
+    #[error("Fallback functions are not supported")]
+    InstructionFallbackNotFound = 101,
+    /// The program could not deserialize the given instruction
+    #[error("The program could not deserialize the given instruction")]
+    InstructionDidNotDeserialize = 102,
+    /// The program could not serialize the given instruction
+    #[error("The program could not serialize the given instruction")]
+    InstructionDidNotSerialize = 103,
+    /// The program was compiled without idl instructions
+    #[error("The program was compiled without idl instructions")]
+    IdlInstructionStub = 1000,
+    /// Invalid program given to the IDL instruction
+    #[error("Invalid program given to the IDL instruction")]
+    IdlInstructionInvalidProgram = 1001,
+    /// IDL account must be empty in order to resize, try closing first
+    #[error("IDL account must be empty in order to resize, try closing first")]
+    IdlAccountNotEmpty = 1002,
+    /// The program was compiled without `event-cpi` feature
+    #[error("The program was compiled without `event-cpi` feature")]
+    EventInstructionStub = 1500,
+    /// A mut constraint was violated
+    #[error("A mut constraint was violated")]
+    ConstraintMut = 2000,
+    /// A has one constraint was violated
+    #[error("A has one constraint was violated")]
+    ConstraintHasOne = 2001,
+    /// A signer constraint was violated
+    #[error("A signer constraint was violated")]
+    ConstraintSigner = 2002,
+    /// A raw constraint was violated
+    #[error("A raw constraint was violated")]
+    ConstraintRaw = 2003,
+    /// An owner constraint was violated
+    #[error("An owner constraint was violated")]
+    ConstraintOwner = 2004,
+    /// A rent exemption constraint was violated
+    #[error("A rent exemption constraint was violated")]
+    ConstraintRentExempt = 2005,
+    /// A seeds constraint was violated
+    #[error("A seeds constraint was violated")]
+    ConstraintSeeds = 2006,
+    /// An executable constraint was violated
+    #[error("An executable constraint was violated")]
+    ConstraintExecutable = 2007,
+    /// Deprecated Error, feel free to replace with something else
+    #[error("Deprecated Error, feel free to replace with something else")]
+    ConstraintState = 2008,
+    /// An associated constraint was violated
+    #[error("An associated constraint was violated")]
+    ConstraintAssociated = 2009,
+    /// An associated init constraint was violated
+    #[error("An associated init constraint was violated")]
+    ConstraintAssociatedInit = 2010,
+    /// A close constraint was violated
+    #[error("A close constraint was violated")]
+    ConstraintClose = 2011,
+    /// An address constraint was violated
+    #[error("An address constraint was violated")]
+    ConstraintAddress = 2012,
+    /// Expected zero account discriminant
+    #[error("Expected zero account discriminant")]
+    ConstraintZero = 2013,
+    /// A token mint constraint was violated
+    #[error("A token mint constraint was violated")]
+    ConstraintTokenMint = 2014,
+    /// A token owner constraint was violated
+    #[error("A token owner constraint was violated")]
+    ConstraintTokenOwner = 2015,
+    /// A mint mint authority constraint was violated
+    #[error("A mint mint authority constraint was violated")]
+    ConstraintMintMintAuthority = 2016,
+    /// A mint freeze authority constraint was violated
+    #[error("A mint freeze authority constraint was violated")]
+    ConstraintMintFreezeAuthority = 2017,
+    /// A mint decimals constraint was violated
+    #[error("A mint decimals constraint was violated")]
+    ConstraintMintDecimals = 2018,
+    /// A space constraint was violated
+    #[error("A space constraint was violated")]
+    ConstraintSpace = 2019,
+    /// A required account for the constraint is None
+    #[error("A required account for the constraint is None")]
+    ConstraintAccountIsNone = 2020,
+    /// A token account token program constraint was violated
+    #[error("A token account token program constraint was violated")]
+    ConstraintTokenTokenProgram = 2021,
+    /// A mint token program constraint was violated
+    #[error("A mint token program constraint was violated")]
+    ConstraintMintTokenProgram = 2022,
+    /// An associated token account token program constraint was violated
+    #[error("An associated token account token program constraint was violated")]
+    ConstraintAssociatedTokenTokenProgram = 2023,
+    /// A require expression was violated
+    #[error("A require expression was violated")]
+    RequireViolated = 2500,
+    /// A require_eq expression was violated
+    #[error("A require_eq expression was violated")]
+    RequireEqViolated = 2501,
+    /// A require_keys_eq expression was violated
+    #[error("A require_keys_eq expression was violated")]
+    RequireKeysEqViolated = 2502,
+    /// A require_neq expression was violated
+    #[error("A require_neq expression was violated")]
+    RequireNeqViolated = 2503,
+    /// A require_keys_neq expression was violated
+    #[error("A require_keys_neq expression was violated")]
+    RequireKeysNeqViolated = 2504,
+    /// A require_gt expression was violated
+    #[error("A require_gt expression was violated")]
+    RequireGtViolated = 2505,
+    /// A require_gte expression was violated
+    #[error("A require_gte expression was violated")]
+    RequireGteViolated = 2506,
+    /// The account discriminator was already set on this account
+    #[error("The account discriminator was already set on this account")]
+    AccountDiscriminatorAlreadySet = 3000,
+    /// No 8 byte discriminator was found on the account
+    #[error("No 8 byte discriminator was found on the account")]
+    AccountDiscriminatorNotFound = 3001,
+    /// 8 byte discriminator did not match what was expected
+    #[error("8 byte discriminator did not match what was expected")]
+    AccountDiscriminatorMismatch = 3002,
+    /// Failed to deserialize the account
+    #[error("Failed to deserialize the account")]
+    AccountDidNotDeserialize = 3003,
+    /// Failed to serialize the account
+    #[error("Failed to serialize the account")]
+    AccountDidNotSerialize = 3004,
+    /// Not enough account keys given to the instruction
+    #[error("Not enough account keys given to the instruction")]
+    AccountNotEnoughKeys = 3005,
+    /// The given account is not mutable
+    #[error("The given account is not mutable")]
+    AccountNotMutable = 3006,
+    /// The given account is owned by a different program than expected
+    #[error("The given account is owned by a different program than expected")]
+    AccountOwnedByWrongProgram = 3007,
+    /// Program ID was not as expected
+    #[error("Program ID was not as expected")]
+    InvalidProgramId = 3008,
+    /// Program account is not executable
+    #[error("Program account is not executable")]
+    InvalidProgramExecutable = 3009,
+    /// The given account did not sign
+    #[error("The given account did not sign")]
+    AccountNotSigner = 3010,
+    /// The given account is not owned by the system program
+    #[error("The given account is not owned by the system program")]
+    AccountNotSystemOwned = 3011,
+    /// The program expected this account to be already initialized
+    #[error("The program expected this account to be already initialized")]
+    AccountNotInitialized = 3012,
+    /// The given account is not a program data account
+    #[error("The given account is not a program data account")]
+    AccountNotProgramData = 3013,
+    /// The given account is not the associated token account
+    #[error("The given account is not the associated token account")]
+    AccountNotAssociatedTokenAccount = 3014,
+    /// The given public key does not match the required sysvar
+    #[error("The given public key does not match the required sysvar")]
+    AccountSysvarMismatch = 3015,
+    /// The account reallocation exceeds the MAX_PERMITTED_DATA_INCREASE limit
+    #[error("The account reallocation exceeds the MAX_PERMITTED_DATA_INCREASE limit")]
+    AccountReallocExceedsLimit = 3016,
+    /// The account was duplicated for more than one reallocation
+    #[error("The account was duplicated for more than one reallocation")]
+    AccountDuplicateReallocs = 3017,
+    /// The declared program id does not match the actual program id
+    #[error("The declared program id does not match the actual program id")]
+    DeclaredProgramIdMismatch = 4100,
+    /// You cannot/should not initialize the payer account as a program account
+    #[error("You cannot/should not initialize the payer account as a program account")]
+    TryingToInitPayerAsProgramAccount = 4101,
+    /// The program could not perform the numeric conversion, out of range integral type conversion attempted
+    #[error(
+        "The program could not perform the numeric conversion, out of range integral type conversion attempted"
+    )]
+    InvalidNumericConversion = 4102,
+    /// The API being used is deprecated and should no longer be used
+    #[error("The API being used is deprecated and should no longer be used")]
+    Deprecated = 5000,
+}
+impl From<AnchorFrameworkError> for ProgramError {
+    fn from(e: AnchorFrameworkError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for AnchorFrameworkError {
+    fn type_of() -> &'static str {
+        "AnchorFrameworkError"
+    }
+}
+impl PrintProgramError for AnchorFrameworkError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
+impl TryFrom<u32> for AnchorFrameworkError {
+    type Error = u32;
+    /// Returns `code` back if it is not one of this program's errors
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        <Self as num_traits::FromPrimitive>::from_u32(code).ok_or(code)
+    }
+}
+impl<'a> TryFrom<&'a ProgramError> for AnchorFrameworkError {
+    type Error = &'a ProgramError;
+    /// Returns `e` back if it is not a `ProgramError::Custom` of this program's errors
+    fn try_from(e: &'a ProgramError) -> Result<Self, Self::Error> {
+        match e {
+            ProgramError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl<'a> TryFrom<&'a InstructionError> for AnchorFrameworkError {
+    type Error = &'a InstructionError;
+    /// Returns `e` back if it is not an `InstructionError::Custom` of this program's errors
+    fn try_from(e: &'a InstructionError) -> Result<Self, Self::Error> {
+        match e {
+            InstructionError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl AnchorFrameworkError {
+    /// Parses a `custom program error: 0x...` or `AnchorError ... Error Number: N.` log line
+    /// into the error, e.g. `Program <id> failed: custom program error: 0x1770`.
+    /// Returns `None` for other logs and codes that are not of this program's errors.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let code = if let Some((_, hex)) = log.split_once("custom program error: 0x") {
+            u32::from_str_radix(hex.trim_end(), 16).ok()?
+        } else if let Some((_, rest)) = log
+            .split_once("AnchorError ")
+            .and_then(|(_, rest)| rest.split_once("Error Number: "))
+        {
+            rest.split('.').next()?.parse().ok()?
+        } else {
+            return None;
+        };
+        Self::try_from(code).ok()
+    }
+}
+/// Either a [`UnstakeError`] or an [`AnchorFrameworkError`]
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum UnstakeAnyError {
+    #[error(transparent)]
+    Program(#[from] UnstakeError),
+    #[error(transparent)]
+    Framework(#[from] AnchorFrameworkError),
+}
+impl TryFrom<u32> for UnstakeAnyError {
+    type Error = u32;
+    /// Tries the program's errors, then the anchor framework's.
+    /// Returns `code` back if it is neither.
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        UnstakeError::try_from(code)
+            .map(Self::Program)
+            .or_else(|code| AnchorFrameworkError::try_from(code).map(Self::Framework))
+    }
+}
+impl From<UnstakeAnyError> for ProgramError {
+    fn from(e: UnstakeAnyError) -> Self {
+        match e {
+            UnstakeAnyError::Program(e) => e.into(),
+            UnstakeAnyError::Framework(e) => e.into(),
+        }
+    }
+}
+impl<'a> TryFrom<&'a ProgramError> for UnstakeAnyError {
+    type Error = &'a ProgramError;
+    /// Returns `e` back if it is not a `ProgramError::Custom` of this program's errors
+    fn try_from(e: &'a ProgramError) -> Result<Self, Self::Error> {
+        match e {
+            ProgramError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl<'a> TryFrom<&'a InstructionError> for UnstakeAnyError {
+    type Error = &'a InstructionError;
+    /// Returns `e` back if it is not an `InstructionError::Custom` of this program's errors
+    fn try_from(e: &'a InstructionError) -> Result<Self, Self::Error> {
+        match e {
+            InstructionError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl UnstakeAnyError {
+    /// Parses a `custom program error: 0x...` or `AnchorError ... Error Number: N.` log line
+    /// into the error, e.g. `Program <id> failed: custom program error: 0x1770`.
+    /// Returns `None` for other logs and codes that are not of this program's errors.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let code = if let Some((_, hex)) = log.split_once("custom program error: 0x") {
+            u32::from_str_radix(hex.trim_end(), 16).ok()?
+        } else if let Some((_, rest)) = log
+            .split_once("AnchorError ")
+            .and_then(|(_, rest)| rest.split_once("Error Number: "))
+        {
+            rest.split('.').next()?.parse().ok()?
+        } else {
+            return None;
+        };
+        Self::try_from(code).ok()
+    }
+}
+/// Parses the error the program failed with from a transaction's log messages.
+///
+/// Only the first `Program <id> failed: ` log is considered, since programs that
+/// invoked the failing program log the same error when failing in turn.
+/// Returns `None` if the failing program is not `program_id`
+/// or it did not fail with one of its errors.
+pub fn parse_error_from_logs_with_program_id(
+    program_id: Pubkey,
+    logs: &[String],
+) -> Option<UnstakeAnyError> {
+    let (program, failed_log) = logs.iter().find_map(|log| {
+        let mut words = log.strip_prefix("Program ")?.split(' ');
+        let program = words.next()?.parse::<Pubkey>().ok()?;
+        (words.next() == Some("failed:")).then_some((program, log))
+    })?;
+    if program != program_id {
+        return None;
+    }
+    UnstakeAnyError::from_log(failed_log)
+}
+pub fn parse_error_from_logs(logs: &[String]) -> Option<UnstakeAnyError> {
+    parse_error_from_logs_with_program_id(crate::ID, logs)
+}
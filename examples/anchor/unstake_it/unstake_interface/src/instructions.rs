@@ -179,6 +179,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LE
         }
     }
 }
+impl InitProtocolFeeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitProtocolFeeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitProtocolFeeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = accounts
+            .get(..INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitProtocolFeeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INIT_PROTOCOL_FEE_IX_DISCM: [u8; 8] = [225, 155, 167, 170, 29, 145, 165, 90];
 #[derive(Clone, Debug, PartialEq)]
 pub struct InitProtocolFeeIxData;
@@ -221,6 +259,62 @@ pub fn init_protocol_fee_ix_with_program_id(
 pub fn init_protocol_fee_ix(keys: InitProtocolFeeKeys) -> std::io::Result<Instruction> {
     init_protocol_fee_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`init_protocol_fee_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitProtocolFeeIxBuilder {
+    payer: Option<Pubkey>,
+    protocol_fee_account: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+}
+impl Default for InitProtocolFeeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitProtocolFeeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            payer: None,
+            protocol_fee_account: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+        }
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn protocol_fee_account(mut self, protocol_fee_account: Pubkey) -> Self {
+        self.protocol_fee_account = Some(protocol_fee_account);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitProtocolFeeIxBuilder is missing {}", field),
+            )
+        };
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let protocol_fee_account = self
+            .protocol_fee_account
+            .ok_or_else(|| missing("protocol_fee_account"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let keys = InitProtocolFeeKeys {
+            payer,
+            protocol_fee_account,
+            system_program,
+        };
+        init_protocol_fee_ix(keys)
+    }
+}
 pub fn init_protocol_fee_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitProtocolFeeAccounts<'_, '_>,
@@ -355,9 +449,48 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN
         }
     }
 }
+impl SetProtocolFeeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SetProtocolFeeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SetProtocolFeeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = accounts
+            .get(..SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SetProtocolFeeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SET_PROTOCOL_FEE_IX_DISCM: [u8; 8] = [173, 239, 83, 242, 136, 43, 144, 217];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SetProtocolFeeIxArgs {
     pub protocol_fee: ProtocolFee,
 }
@@ -413,6 +546,61 @@ pub fn set_protocol_fee_ix(
 ) -> std::io::Result<Instruction> {
     set_protocol_fee_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`set_protocol_fee_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SetProtocolFeeIxBuilder {
+    authority: Option<Pubkey>,
+    protocol_fee_account: Option<Pubkey>,
+    protocol_fee: Option<ProtocolFee>,
+}
+impl Default for SetProtocolFeeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SetProtocolFeeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            authority: None,
+            protocol_fee_account: None,
+            protocol_fee: None,
+        }
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn protocol_fee_account(mut self, protocol_fee_account: Pubkey) -> Self {
+        self.protocol_fee_account = Some(protocol_fee_account);
+        self
+    }
+    pub fn protocol_fee(mut self, protocol_fee: ProtocolFee) -> Self {
+        self.protocol_fee = Some(protocol_fee);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SetProtocolFeeIxBuilder is missing {}", field),
+            )
+        };
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let protocol_fee_account = self
+            .protocol_fee_account
+            .ok_or_else(|| missing("protocol_fee_account"))?;
+        let args = SetProtocolFeeIxArgs {
+            protocol_fee: self.protocol_fee.ok_or_else(|| missing("protocol_fee"))?,
+        };
+        let keys = SetProtocolFeeKeys {
+            authority,
+            protocol_fee_account,
+        };
+        set_protocol_fee_ix(keys, args)
+    }
+}
 pub fn set_protocol_fee_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetProtocolFeeAccounts<'_, '_>,
@@ -629,9 +817,48 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_POOL_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl CreatePoolKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CREATE_POOL_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CREATE_POOL_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CREATE_POOL_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for CreatePoolKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CreatePoolAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CREATE_POOL_IX_ACCOUNTS_LEN] = accounts
+            .get(..CREATE_POOL_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CREATE_POOL_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CreatePoolAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CREATE_POOL_IX_DISCM: [u8; 8] = [233, 146, 209, 142, 207, 104, 64, 188];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreatePoolIxArgs {
     pub fee: Fee,
 }
@@ -687,6 +914,123 @@ pub fn create_pool_ix(
 ) -> std::io::Result<Instruction> {
     create_pool_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`create_pool_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CreatePoolIxBuilder {
+    payer: Option<Pubkey>,
+    fee_authority: Option<Pubkey>,
+    pool_account: Option<Pubkey>,
+    pool_sol_reserves: Option<Pubkey>,
+    fee_account: Option<Pubkey>,
+    lp_mint: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    fee: Option<Fee>,
+}
+impl Default for CreatePoolIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CreatePoolIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            payer: None,
+            fee_authority: None,
+            pool_account: None,
+            pool_sol_reserves: None,
+            fee_account: None,
+            lp_mint: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            fee: None,
+        }
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn fee_authority(mut self, fee_authority: Pubkey) -> Self {
+        self.fee_authority = Some(fee_authority);
+        self
+    }
+    pub fn pool_account(mut self, pool_account: Pubkey) -> Self {
+        self.pool_account = Some(pool_account);
+        self
+    }
+    pub fn pool_sol_reserves(mut self, pool_sol_reserves: Pubkey) -> Self {
+        self.pool_sol_reserves = Some(pool_sol_reserves);
+        self
+    }
+    pub fn fee_account(mut self, fee_account: Pubkey) -> Self {
+        self.fee_account = Some(fee_account);
+        self
+    }
+    pub fn lp_mint(mut self, lp_mint: Pubkey) -> Self {
+        self.lp_mint = Some(lp_mint);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn fee(mut self, fee: Fee) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CreatePoolIxBuilder is missing {}", field),
+            )
+        };
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let fee_authority = self.fee_authority.ok_or_else(|| missing("fee_authority"))?;
+        let pool_account = self.pool_account.ok_or_else(|| missing("pool_account"))?;
+        let pool_sol_reserves = self
+            .pool_sol_reserves
+            .ok_or_else(|| missing("pool_sol_reserves"))?;
+        let fee_account = self.fee_account.ok_or_else(|| missing("fee_account"))?;
+        let lp_mint = self.lp_mint.ok_or_else(|| missing("lp_mint"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = CreatePoolIxArgs {
+            fee: self.fee.ok_or_else(|| missing("fee"))?,
+        };
+        let keys = CreatePoolKeys {
+            payer,
+            fee_authority,
+            pool_account,
+            pool_sol_reserves,
+            fee_account,
+            lp_mint,
+            token_program,
+            system_program,
+            rent,
+        };
+        create_pool_ix(keys, args)
+    }
+}
 pub fn create_pool_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreatePoolAccounts<'_, '_>,
@@ -895,10 +1239,57 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl AddLiquidityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ADD_LIQUIDITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[ADD_LIQUIDITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AddLiquidityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AddLiquidityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..ADD_LIQUIDITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[ADD_LIQUIDITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AddLiquidityAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const ADD_LIQUIDITY_IX_DISCM: [u8; 8] = [181, 157, 89, 67, 143, 182, 52, 72];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AddLiquidityIxArgs {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -953,6 +1344,107 @@ pub fn add_liquidity_ix(
 ) -> std::io::Result<Instruction> {
     add_liquidity_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`add_liquidity_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AddLiquidityIxBuilder {
+    from: Option<Pubkey>,
+    pool_account: Option<Pubkey>,
+    pool_sol_reserves: Option<Pubkey>,
+    lp_mint: Option<Pubkey>,
+    mint_lp_tokens_to: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    amount: Option<u64>,
+}
+impl Default for AddLiquidityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AddLiquidityIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            from: None,
+            pool_account: None,
+            pool_sol_reserves: None,
+            lp_mint: None,
+            mint_lp_tokens_to: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            amount: None,
+        }
+    }
+    pub fn from(mut self, from: Pubkey) -> Self {
+        self.from = Some(from);
+        self
+    }
+    pub fn pool_account(mut self, pool_account: Pubkey) -> Self {
+        self.pool_account = Some(pool_account);
+        self
+    }
+    pub fn pool_sol_reserves(mut self, pool_sol_reserves: Pubkey) -> Self {
+        self.pool_sol_reserves = Some(pool_sol_reserves);
+        self
+    }
+    pub fn lp_mint(mut self, lp_mint: Pubkey) -> Self {
+        self.lp_mint = Some(lp_mint);
+        self
+    }
+    pub fn mint_lp_tokens_to(mut self, mint_lp_tokens_to: Pubkey) -> Self {
+        self.mint_lp_tokens_to = Some(mint_lp_tokens_to);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AddLiquidityIxBuilder is missing {}", field),
+            )
+        };
+        let from = self.from.ok_or_else(|| missing("from"))?;
+        let pool_account = self.pool_account.ok_or_else(|| missing("pool_account"))?;
+        let pool_sol_reserves = self
+            .pool_sol_reserves
+            .ok_or_else(|| missing("pool_sol_reserves"))?;
+        let lp_mint = self.lp_mint.ok_or_else(|| missing("lp_mint"))?;
+        let mint_lp_tokens_to = self
+            .mint_lp_tokens_to
+            .ok_or_else(|| missing("mint_lp_tokens_to"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = AddLiquidityIxArgs {
+            amount: self.amount.ok_or_else(|| missing("amount"))?,
+        };
+        let keys = AddLiquidityKeys {
+            from,
+            pool_account,
+            pool_sol_reserves,
+            lp_mint,
+            mint_lp_tokens_to,
+            token_program,
+            system_program,
+        };
+        add_liquidity_ix(keys, args)
+    }
+}
 pub fn add_liquidity_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AddLiquidityAccounts<'_, '_>,
@@ -1166,10 +1658,57 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN
         }
     }
 }
+impl RemoveLiquidityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for RemoveLiquidityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RemoveLiquidityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for RemoveLiquidityAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REMOVE_LIQUIDITY_IX_DISCM: [u8; 8] = [80, 85, 209, 72, 24, 206, 177, 108];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RemoveLiquidityIxArgs {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub amount_lp: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
@@ -1224,6 +1763,117 @@ pub fn remove_liquidity_ix(
 ) -> std::io::Result<Instruction> {
     remove_liquidity_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`remove_liquidity_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RemoveLiquidityIxBuilder {
+    burn_lp_tokens_from_authority: Option<Pubkey>,
+    to: Option<Pubkey>,
+    pool_account: Option<Pubkey>,
+    pool_sol_reserves: Option<Pubkey>,
+    lp_mint: Option<Pubkey>,
+    burn_lp_tokens_from: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    amount_lp: Option<u64>,
+}
+impl Default for RemoveLiquidityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RemoveLiquidityIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            burn_lp_tokens_from_authority: None,
+            to: None,
+            pool_account: None,
+            pool_sol_reserves: None,
+            lp_mint: None,
+            burn_lp_tokens_from: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            amount_lp: None,
+        }
+    }
+    pub fn burn_lp_tokens_from_authority(mut self, burn_lp_tokens_from_authority: Pubkey) -> Self {
+        self.burn_lp_tokens_from_authority = Some(burn_lp_tokens_from_authority);
+        self
+    }
+    pub fn to(mut self, to: Pubkey) -> Self {
+        self.to = Some(to);
+        self
+    }
+    pub fn pool_account(mut self, pool_account: Pubkey) -> Self {
+        self.pool_account = Some(pool_account);
+        self
+    }
+    pub fn pool_sol_reserves(mut self, pool_sol_reserves: Pubkey) -> Self {
+        self.pool_sol_reserves = Some(pool_sol_reserves);
+        self
+    }
+    pub fn lp_mint(mut self, lp_mint: Pubkey) -> Self {
+        self.lp_mint = Some(lp_mint);
+        self
+    }
+    pub fn burn_lp_tokens_from(mut self, burn_lp_tokens_from: Pubkey) -> Self {
+        self.burn_lp_tokens_from = Some(burn_lp_tokens_from);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn amount_lp(mut self, amount_lp: u64) -> Self {
+        self.amount_lp = Some(amount_lp);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RemoveLiquidityIxBuilder is missing {}", field),
+            )
+        };
+        let burn_lp_tokens_from_authority = self
+            .burn_lp_tokens_from_authority
+            .ok_or_else(|| missing("burn_lp_tokens_from_authority"))?;
+        let to = self.to.ok_or_else(|| missing("to"))?;
+        let pool_account = self.pool_account.ok_or_else(|| missing("pool_account"))?;
+        let pool_sol_reserves = self
+            .pool_sol_reserves
+            .ok_or_else(|| missing("pool_sol_reserves"))?;
+        let lp_mint = self.lp_mint.ok_or_else(|| missing("lp_mint"))?;
+        let burn_lp_tokens_from = self
+            .burn_lp_tokens_from
+            .ok_or_else(|| missing("burn_lp_tokens_from"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = RemoveLiquidityIxArgs {
+            amount_lp: self.amount_lp.ok_or_else(|| missing("amount_lp"))?,
+        };
+        let keys = RemoveLiquidityKeys {
+            burn_lp_tokens_from_authority,
+            to,
+            pool_account,
+            pool_sol_reserves,
+            lp_mint,
+            burn_lp_tokens_from,
+            token_program,
+            system_program,
+        };
+        remove_liquidity_ix(keys, args)
+    }
+}
 pub fn remove_liquidity_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RemoveLiquidityAccounts<'_, '_>,
@@ -1406,9 +2056,48 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl SetFeeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SET_FEE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SET_FEE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SET_FEE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SetFeeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SetFeeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN] = accounts
+            .get(..SET_FEE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SET_FEE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SetFeeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SET_FEE_IX_DISCM: [u8; 8] = [18, 154, 24, 18, 237, 214, 19, 80];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SetFeeIxArgs {
     pub fee: Fee,
 }
@@ -1461,6 +2150,87 @@ pub fn set_fee_ix_with_program_id(
 pub fn set_fee_ix(keys: SetFeeKeys, args: SetFeeIxArgs) -> std::io::Result<Instruction> {
     set_fee_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`set_fee_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SetFeeIxBuilder {
+    fee_authority: Option<Pubkey>,
+    pool_account: Option<Pubkey>,
+    fee_account: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    fee: Option<Fee>,
+}
+impl Default for SetFeeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SetFeeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            fee_authority: None,
+            pool_account: None,
+            fee_account: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            fee: None,
+        }
+    }
+    pub fn fee_authority(mut self, fee_authority: Pubkey) -> Self {
+        self.fee_authority = Some(fee_authority);
+        self
+    }
+    pub fn pool_account(mut self, pool_account: Pubkey) -> Self {
+        self.pool_account = Some(pool_account);
+        self
+    }
+    pub fn fee_account(mut self, fee_account: Pubkey) -> Self {
+        self.fee_account = Some(fee_account);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn fee(mut self, fee: Fee) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SetFeeIxBuilder is missing {}", field),
+            )
+        };
+        let fee_authority = self.fee_authority.ok_or_else(|| missing("fee_authority"))?;
+        let pool_account = self.pool_account.ok_or_else(|| missing("pool_account"))?;
+        let fee_account = self.fee_account.ok_or_else(|| missing("fee_account"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = SetFeeIxArgs {
+            fee: self.fee.ok_or_else(|| missing("fee"))?,
+        };
+        let keys = SetFeeKeys {
+            fee_authority,
+            pool_account,
+            fee_account,
+            system_program,
+            rent,
+        };
+        set_fee_ix(keys, args)
+    }
+}
 pub fn set_fee_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetFeeAccounts<'_, '_>,
@@ -1608,6 +2378,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LE
         }
     }
 }
+impl SetFeeAuthorityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SetFeeAuthorityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SetFeeAuthorityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SetFeeAuthorityAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SET_FEE_AUTHORITY_IX_DISCM: [u8; 8] = [31, 1, 50, 87, 237, 101, 97, 132];
 #[derive(Clone, Debug, PartialEq)]
 pub struct SetFeeAuthorityIxData;
@@ -1650,6 +2458,60 @@ pub fn set_fee_authority_ix_with_program_id(
 pub fn set_fee_authority_ix(keys: SetFeeAuthorityKeys) -> std::io::Result<Instruction> {
     set_fee_authority_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`set_fee_authority_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SetFeeAuthorityIxBuilder {
+    fee_authority: Option<Pubkey>,
+    pool_account: Option<Pubkey>,
+    new_fee_authority: Option<Pubkey>,
+}
+impl Default for SetFeeAuthorityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SetFeeAuthorityIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            fee_authority: None,
+            pool_account: None,
+            new_fee_authority: None,
+        }
+    }
+    pub fn fee_authority(mut self, fee_authority: Pubkey) -> Self {
+        self.fee_authority = Some(fee_authority);
+        self
+    }
+    pub fn pool_account(mut self, pool_account: Pubkey) -> Self {
+        self.pool_account = Some(pool_account);
+        self
+    }
+    pub fn new_fee_authority(mut self, new_fee_authority: Pubkey) -> Self {
+        self.new_fee_authority = Some(new_fee_authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SetFeeAuthorityIxBuilder is missing {}", field),
+            )
+        };
+        let fee_authority = self.fee_authority.ok_or_else(|| missing("fee_authority"))?;
+        let pool_account = self.pool_account.ok_or_else(|| missing("pool_account"))?;
+        let new_fee_authority = self
+            .new_fee_authority
+            .ok_or_else(|| missing("new_fee_authority"))?;
+        let keys = SetFeeAuthorityKeys {
+            fee_authority,
+            pool_account,
+            new_fee_authority,
+        };
+        set_fee_authority_ix(keys)
+    }
+}
 pub fn set_fee_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetFeeAuthorityAccounts<'_, '_>,
@@ -1814,6 +2676,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCO
         }
     }
 }
+impl DeactivateStakeAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeactivateStakeAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeactivateStakeAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for DeactivateStakeAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEACTIVATE_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [217, 64, 76, 16, 216, 77, 123, 226];
 #[derive(Clone, Debug, PartialEq)]
 pub struct DeactivateStakeAccountIxData;
@@ -1858,6 +2764,80 @@ pub fn deactivate_stake_account_ix(
 ) -> std::io::Result<Instruction> {
     deactivate_stake_account_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`deactivate_stake_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeactivateStakeAccountIxBuilder {
+    stake_account: Option<Pubkey>,
+    pool_account: Option<Pubkey>,
+    pool_sol_reserves: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    stake_program: Option<Pubkey>,
+}
+impl Default for DeactivateStakeAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeactivateStakeAccountIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            stake_account: None,
+            pool_account: None,
+            pool_sol_reserves: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            stake_program: Some(solana_program::pubkey!(
+                "Stake11111111111111111111111111111111111111"
+            )),
+        }
+    }
+    pub fn stake_account(mut self, stake_account: Pubkey) -> Self {
+        self.stake_account = Some(stake_account);
+        self
+    }
+    pub fn pool_account(mut self, pool_account: Pubkey) -> Self {
+        self.pool_account = Some(pool_account);
+        self
+    }
+    pub fn pool_sol_reserves(mut self, pool_sol_reserves: Pubkey) -> Self {
+        self.pool_sol_reserves = Some(pool_sol_reserves);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn stake_program(mut self, stake_program: Pubkey) -> Self {
+        self.stake_program = Some(stake_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DeactivateStakeAccountIxBuilder is missing {}", field),
+            )
+        };
+        let stake_account = self.stake_account.ok_or_else(|| missing("stake_account"))?;
+        let pool_account = self.pool_account.ok_or_else(|| missing("pool_account"))?;
+        let pool_sol_reserves = self
+            .pool_sol_reserves
+            .ok_or_else(|| missing("pool_sol_reserves"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let stake_program = self.stake_program.ok_or_else(|| missing("stake_program"))?;
+        let keys = DeactivateStakeAccountKeys {
+            stake_account,
+            pool_account,
+            pool_sol_reserves,
+            clock,
+            stake_program,
+        };
+        deactivate_stake_account_ix(keys)
+    }
+}
 pub fn deactivate_stake_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateStakeAccountAccounts<'_, '_>,
@@ -2037,6 +3017,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNT
         }
     }
 }
+impl ReclaimStakeAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ReclaimStakeAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ReclaimStakeAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ReclaimStakeAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const RECLAIM_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [47, 127, 90, 221, 10, 160, 183, 117];
 #[derive(Clone, Debug, PartialEq)]
 pub struct ReclaimStakeAccountIxData;
@@ -2079,6 +3103,100 @@ pub fn reclaim_stake_account_ix_with_program_id(
 pub fn reclaim_stake_account_ix(keys: ReclaimStakeAccountKeys) -> std::io::Result<Instruction> {
     reclaim_stake_account_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`reclaim_stake_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ReclaimStakeAccountIxBuilder {
+    stake_account: Option<Pubkey>,
+    pool_account: Option<Pubkey>,
+    pool_sol_reserves: Option<Pubkey>,
+    stake_account_record_account: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    stake_history: Option<Pubkey>,
+    stake_program: Option<Pubkey>,
+}
+impl Default for ReclaimStakeAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ReclaimStakeAccountIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            stake_account: None,
+            pool_account: None,
+            pool_sol_reserves: None,
+            stake_account_record_account: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            stake_history: Some(solana_program::pubkey!(
+                "SysvarStakeHistory1111111111111111111111111"
+            )),
+            stake_program: Some(solana_program::pubkey!(
+                "Stake11111111111111111111111111111111111111"
+            )),
+        }
+    }
+    pub fn stake_account(mut self, stake_account: Pubkey) -> Self {
+        self.stake_account = Some(stake_account);
+        self
+    }
+    pub fn pool_account(mut self, pool_account: Pubkey) -> Self {
+        self.pool_account = Some(pool_account);
+        self
+    }
+    pub fn pool_sol_reserves(mut self, pool_sol_reserves: Pubkey) -> Self {
+        self.pool_sol_reserves = Some(pool_sol_reserves);
+        self
+    }
+    pub fn stake_account_record_account(mut self, stake_account_record_account: Pubkey) -> Self {
+        self.stake_account_record_account = Some(stake_account_record_account);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn stake_history(mut self, stake_history: Pubkey) -> Self {
+        self.stake_history = Some(stake_history);
+        self
+    }
+    pub fn stake_program(mut self, stake_program: Pubkey) -> Self {
+        self.stake_program = Some(stake_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ReclaimStakeAccountIxBuilder is missing {}", field),
+            )
+        };
+        let stake_account = self.stake_account.ok_or_else(|| missing("stake_account"))?;
+        let pool_account = self.pool_account.ok_or_else(|| missing("pool_account"))?;
+        let pool_sol_reserves = self
+            .pool_sol_reserves
+            .ok_or_else(|| missing("pool_sol_reserves"))?;
+        let stake_account_record_account = self
+            .stake_account_record_account
+            .ok_or_else(|| missing("stake_account_record_account"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let stake_history = self.stake_history.ok_or_else(|| missing("stake_history"))?;
+        let stake_program = self.stake_program.ok_or_else(|| missing("stake_program"))?;
+        let keys = ReclaimStakeAccountKeys {
+            stake_account,
+            pool_account,
+            pool_sol_reserves,
+            stake_account_record_account,
+            clock,
+            stake_history,
+            stake_program,
+        };
+        reclaim_stake_account_ix(keys)
+    }
+}
 pub fn reclaim_stake_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ReclaimStakeAccountAccounts<'_, '_>,
@@ -2332,6 +3450,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UNSTAKE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl UnstakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UNSTAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UNSTAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UNSTAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UnstakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UnstakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UNSTAKE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UNSTAKE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UNSTAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UnstakeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UNSTAKE_IX_DISCM: [u8; 8] = [90, 95, 107, 42, 205, 124, 50, 225];
 #[derive(Clone, Debug, PartialEq)]
 pub struct UnstakeIxData;
@@ -2374,6 +3530,152 @@ pub fn unstake_ix_with_program_id(
 pub fn unstake_ix(keys: UnstakeKeys) -> std::io::Result<Instruction> {
     unstake_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`unstake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UnstakeIxBuilder {
+    payer: Option<Pubkey>,
+    unstaker: Option<Pubkey>,
+    stake_account: Option<Pubkey>,
+    destination: Option<Pubkey>,
+    pool_account: Option<Pubkey>,
+    pool_sol_reserves: Option<Pubkey>,
+    fee_account: Option<Pubkey>,
+    stake_account_record_account: Option<Pubkey>,
+    protocol_fee_account: Option<Pubkey>,
+    protocol_fee_destination: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    stake_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+}
+impl Default for UnstakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UnstakeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            payer: None,
+            unstaker: None,
+            stake_account: None,
+            destination: None,
+            pool_account: None,
+            pool_sol_reserves: None,
+            fee_account: None,
+            stake_account_record_account: None,
+            protocol_fee_account: None,
+            protocol_fee_destination: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            stake_program: Some(solana_program::pubkey!(
+                "Stake11111111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+        }
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn unstaker(mut self, unstaker: Pubkey) -> Self {
+        self.unstaker = Some(unstaker);
+        self
+    }
+    pub fn stake_account(mut self, stake_account: Pubkey) -> Self {
+        self.stake_account = Some(stake_account);
+        self
+    }
+    pub fn destination(mut self, destination: Pubkey) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+    pub fn pool_account(mut self, pool_account: Pubkey) -> Self {
+        self.pool_account = Some(pool_account);
+        self
+    }
+    pub fn pool_sol_reserves(mut self, pool_sol_reserves: Pubkey) -> Self {
+        self.pool_sol_reserves = Some(pool_sol_reserves);
+        self
+    }
+    pub fn fee_account(mut self, fee_account: Pubkey) -> Self {
+        self.fee_account = Some(fee_account);
+        self
+    }
+    pub fn stake_account_record_account(mut self, stake_account_record_account: Pubkey) -> Self {
+        self.stake_account_record_account = Some(stake_account_record_account);
+        self
+    }
+    pub fn protocol_fee_account(mut self, protocol_fee_account: Pubkey) -> Self {
+        self.protocol_fee_account = Some(protocol_fee_account);
+        self
+    }
+    pub fn protocol_fee_destination(mut self, protocol_fee_destination: Pubkey) -> Self {
+        self.protocol_fee_destination = Some(protocol_fee_destination);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn stake_program(mut self, stake_program: Pubkey) -> Self {
+        self.stake_program = Some(stake_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UnstakeIxBuilder is missing {}", field),
+            )
+        };
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let unstaker = self.unstaker.ok_or_else(|| missing("unstaker"))?;
+        let stake_account = self.stake_account.ok_or_else(|| missing("stake_account"))?;
+        let destination = self.destination.ok_or_else(|| missing("destination"))?;
+        let pool_account = self.pool_account.ok_or_else(|| missing("pool_account"))?;
+        let pool_sol_reserves = self
+            .pool_sol_reserves
+            .ok_or_else(|| missing("pool_sol_reserves"))?;
+        let fee_account = self.fee_account.ok_or_else(|| missing("fee_account"))?;
+        let stake_account_record_account = self
+            .stake_account_record_account
+            .ok_or_else(|| missing("stake_account_record_account"))?;
+        let protocol_fee_account = self
+            .protocol_fee_account
+            .ok_or_else(|| missing("protocol_fee_account"))?;
+        let protocol_fee_destination = self
+            .protocol_fee_destination
+            .ok_or_else(|| missing("protocol_fee_destination"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let stake_program = self.stake_program.ok_or_else(|| missing("stake_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let keys = UnstakeKeys {
+            payer,
+            unstaker,
+            stake_account,
+            destination,
+            pool_account,
+            pool_sol_reserves,
+            fee_account,
+            stake_account_record_account,
+            protocol_fee_account,
+            protocol_fee_destination,
+            clock,
+            stake_program,
+            system_program,
+        };
+        unstake_ix(keys)
+    }
+}
 pub fn unstake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UnstakeAccounts<'_, '_>,
@@ -2664,6 +3966,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl UnstakeWsolKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UNSTAKE_WSOL_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UNSTAKE_WSOL_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UnstakeWsolKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UnstakeWsolAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = accounts
+            .get(..UNSTAKE_WSOL_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UNSTAKE_WSOL_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UnstakeWsolAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UNSTAKE_WSOL_IX_DISCM: [u8; 8] = [125, 93, 190, 135, 89, 174, 142, 149];
 #[derive(Clone, Debug, PartialEq)]
 pub struct UnstakeWsolIxData;
@@ -2706,6 +4046,162 @@ pub fn unstake_wsol_ix_with_program_id(
 pub fn unstake_wsol_ix(keys: UnstakeWsolKeys) -> std::io::Result<Instruction> {
     unstake_wsol_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`unstake_wsol_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UnstakeWsolIxBuilder {
+    payer: Option<Pubkey>,
+    unstaker: Option<Pubkey>,
+    stake_account: Option<Pubkey>,
+    destination: Option<Pubkey>,
+    pool_account: Option<Pubkey>,
+    pool_sol_reserves: Option<Pubkey>,
+    fee_account: Option<Pubkey>,
+    stake_account_record_account: Option<Pubkey>,
+    protocol_fee_account: Option<Pubkey>,
+    protocol_fee_destination: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    stake_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+}
+impl Default for UnstakeWsolIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UnstakeWsolIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            payer: None,
+            unstaker: None,
+            stake_account: None,
+            destination: None,
+            pool_account: None,
+            pool_sol_reserves: None,
+            fee_account: None,
+            stake_account_record_account: None,
+            protocol_fee_account: None,
+            protocol_fee_destination: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            stake_program: Some(solana_program::pubkey!(
+                "Stake11111111111111111111111111111111111111"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+        }
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn unstaker(mut self, unstaker: Pubkey) -> Self {
+        self.unstaker = Some(unstaker);
+        self
+    }
+    pub fn stake_account(mut self, stake_account: Pubkey) -> Self {
+        self.stake_account = Some(stake_account);
+        self
+    }
+    pub fn destination(mut self, destination: Pubkey) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+    pub fn pool_account(mut self, pool_account: Pubkey) -> Self {
+        self.pool_account = Some(pool_account);
+        self
+    }
+    pub fn pool_sol_reserves(mut self, pool_sol_reserves: Pubkey) -> Self {
+        self.pool_sol_reserves = Some(pool_sol_reserves);
+        self
+    }
+    pub fn fee_account(mut self, fee_account: Pubkey) -> Self {
+        self.fee_account = Some(fee_account);
+        self
+    }
+    pub fn stake_account_record_account(mut self, stake_account_record_account: Pubkey) -> Self {
+        self.stake_account_record_account = Some(stake_account_record_account);
+        self
+    }
+    pub fn protocol_fee_account(mut self, protocol_fee_account: Pubkey) -> Self {
+        self.protocol_fee_account = Some(protocol_fee_account);
+        self
+    }
+    pub fn protocol_fee_destination(mut self, protocol_fee_destination: Pubkey) -> Self {
+        self.protocol_fee_destination = Some(protocol_fee_destination);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn stake_program(mut self, stake_program: Pubkey) -> Self {
+        self.stake_program = Some(stake_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UnstakeWsolIxBuilder is missing {}", field),
+            )
+        };
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let unstaker = self.unstaker.ok_or_else(|| missing("unstaker"))?;
+        let stake_account = self.stake_account.ok_or_else(|| missing("stake_account"))?;
+        let destination = self.destination.ok_or_else(|| missing("destination"))?;
+        let pool_account = self.pool_account.ok_or_else(|| missing("pool_account"))?;
+        let pool_sol_reserves = self
+            .pool_sol_reserves
+            .ok_or_else(|| missing("pool_sol_reserves"))?;
+        let fee_account = self.fee_account.ok_or_else(|| missing("fee_account"))?;
+        let stake_account_record_account = self
+            .stake_account_record_account
+            .ok_or_else(|| missing("stake_account_record_account"))?;
+        let protocol_fee_account = self
+            .protocol_fee_account
+            .ok_or_else(|| missing("protocol_fee_account"))?;
+        let protocol_fee_destination = self
+            .protocol_fee_destination
+            .ok_or_else(|| missing("protocol_fee_destination"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let stake_program = self.stake_program.ok_or_else(|| missing("stake_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let keys = UnstakeWsolKeys {
+            payer,
+            unstaker,
+            stake_account,
+            destination,
+            pool_account,
+            pool_sol_reserves,
+            fee_account,
+            stake_account_record_account,
+            protocol_fee_account,
+            protocol_fee_destination,
+            clock,
+            stake_program,
+            system_program,
+            token_program,
+        };
+        unstake_wsol_ix(keys)
+    }
+}
 pub fn unstake_wsol_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UnstakeWsolAccounts<'_, '_>,
@@ -2802,3 +4298,128 @@ pub fn unstake_wsol_verify_account_privileges<'me, 'info>(
     unstake_wsol_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// A [`UnstakeProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnstakeParsedIx {
+    InitProtocolFee {
+        keys: InitProtocolFeeKeys,
+    },
+    SetProtocolFee {
+        keys: SetProtocolFeeKeys,
+        args: SetProtocolFeeIxArgs,
+    },
+    CreatePool {
+        keys: CreatePoolKeys,
+        args: CreatePoolIxArgs,
+    },
+    AddLiquidity {
+        keys: AddLiquidityKeys,
+        args: AddLiquidityIxArgs,
+    },
+    RemoveLiquidity {
+        keys: RemoveLiquidityKeys,
+        args: RemoveLiquidityIxArgs,
+    },
+    SetFee {
+        keys: SetFeeKeys,
+        args: SetFeeIxArgs,
+    },
+    SetFeeAuthority {
+        keys: SetFeeAuthorityKeys,
+    },
+    DeactivateStakeAccount {
+        keys: DeactivateStakeAccountKeys,
+    },
+    ReclaimStakeAccount {
+        keys: ReclaimStakeAccountKeys,
+    },
+    Unstake {
+        keys: UnstakeKeys,
+    },
+    UnstakeWsol {
+        keys: UnstakeWsolKeys,
+    },
+}
+impl UnstakeParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = UnstakeProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            UnstakeProgramIx::InitProtocolFee => Self::InitProtocolFee {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            UnstakeProgramIx::SetProtocolFee(args) => Self::SetProtocolFee {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            UnstakeProgramIx::CreatePool(args) => Self::CreatePool {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            UnstakeProgramIx::AddLiquidity(args) => Self::AddLiquidity {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            UnstakeProgramIx::RemoveLiquidity(args) => Self::RemoveLiquidity {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            UnstakeProgramIx::SetFee(args) => Self::SetFee {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            UnstakeProgramIx::SetFeeAuthority => Self::SetFeeAuthority {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            UnstakeProgramIx::DeactivateStakeAccount => Self::DeactivateStakeAccount {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            UnstakeProgramIx::ReclaimStakeAccount => Self::ReclaimStakeAccount {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            UnstakeProgramIx::Unstake => Self::Unstake {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            UnstakeProgramIx::UnstakeWsol => Self::UnstakeWsol {
+                keys: parsed_ix_keys(accounts)?,
+            },
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
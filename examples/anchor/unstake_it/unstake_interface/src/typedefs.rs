@@ -1,18 +1,49 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Rational {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub num: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub denom: u64,
 }
+/// Serialized size of [`Rational`]
+pub const RATIONAL_LEN: usize = 16;
+/// Offset of `num` in serialized [`Rational`]
+pub const RATIONAL_NUM_OFFSET: usize = 0;
+/// Offset of `denom` in serialized [`Rational`]
+pub const RATIONAL_DENOM_OFFSET: usize = 8;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct LiquidityLinearParams {
     pub max_liq_remaining: Rational,
     pub zero_liq_remaining: Rational,
 }
+/// Serialized size of [`LiquidityLinearParams`]
+pub const LIQUIDITY_LINEAR_PARAMS_LEN: usize = 32;
+/// Offset of `max_liq_remaining` in serialized [`LiquidityLinearParams`]
+pub const LIQUIDITY_LINEAR_PARAMS_MAX_LIQ_REMAINING_OFFSET: usize = 0;
+/// Offset of `zero_liq_remaining` in serialized [`LiquidityLinearParams`]
+pub const LIQUIDITY_LINEAR_PARAMS_ZERO_LIQ_REMAINING_OFFSET: usize = 16;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum FeeEnum {
     Flat { ratio: Rational },
     LiquidityLinear { params: LiquidityLinearParams },
@@ -0,0 +1 @@
+//! All code in tests
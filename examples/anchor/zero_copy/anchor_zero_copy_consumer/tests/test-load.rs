@@ -0,0 +1,222 @@
+use anchor_zero_copy_interface::*;
+use solana_program::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey, pubkey::Pubkey,
+};
+
+const AUTHORITY: Pubkey = pubkey!("4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi");
+const BASE_MINT: Pubkey = pubkey!("8qbHbw2BbbTHBW1sbeqakYXVKRQM8Ne7pLK7m6CVfeR");
+const QUOTE_MINT: Pubkey = pubkey!("GB1fFixz3RyBVZA1y6GHzNTYEGhfVPxgZyEqtc2zrnbu");
+const MARKET_KEY: Pubkey = pubkey!("4rLtKGqsrPZzMgSw8mhD4G8sSqRyjWDSqrDD3aHL2VfX");
+
+/// 8-byte aligned like on-chain account data so that the struct after the discm is aligned
+fn aligned_buf(words: &mut Vec<u64>, len: usize) -> &mut [u8] {
+    words.resize(len.div_ceil(8), 0);
+    &mut bytemuck::cast_slice_mut(words)[..len]
+}
+
+fn market_account_data(buf: &mut [u8]) {
+    buf[..8].copy_from_slice(&MARKET_ACCOUNT_DISCM);
+    let data = &mut buf[8..];
+    data[MARKET_AUTHORITY_OFFSET..MARKET_AUTHORITY_OFFSET + 32].copy_from_slice(AUTHORITY.as_ref());
+    data[MARKET_BASE_MINT_OFFSET..MARKET_BASE_MINT_OFFSET + 32].copy_from_slice(BASE_MINT.as_ref());
+    data[MARKET_QUOTE_MINT_OFFSET..MARKET_QUOTE_MINT_OFFSET + 32]
+        .copy_from_slice(QUOTE_MINT.as_ref());
+    data[MARKET_TOTAL_DEPOSITS_OFFSET..MARKET_TOTAL_DEPOSITS_OFFSET + 8]
+        .copy_from_slice(&1_000u64.to_le_bytes());
+    let fee = &mut data[MARKET_FEE_OFFSET..];
+    fee[FEE_MAKER_BPS_OFFSET..FEE_MAKER_BPS_OFFSET + 4].copy_from_slice(&5u32.to_le_bytes());
+    fee[FEE_TAKER_BPS_OFFSET..FEE_TAKER_BPS_OFFSET + 4].copy_from_slice(&10u32.to_le_bytes());
+    // orders[31]
+    data[MARKET_ORDERS_OFFSET + 31 * 8..MARKET_ORDERS_OFFSET + 32 * 8]
+        .copy_from_slice(&77u64.to_le_bytes());
+}
+
+#[test]
+fn test_layout() {
+    assert_eq!(MARKET_LEN, 32 * 3 + 8 + 8 + 32 * 8);
+    assert_eq!(MARKET_ACCOUNT_LEN, 8 + MARKET_LEN);
+    assert_eq!(FEE_LEN, 8);
+}
+
+#[test]
+fn test_load() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN);
+    market_account_data(buf);
+
+    let market = MarketAccount::load(buf).unwrap();
+    assert_eq!(market.authority, AUTHORITY);
+    assert_eq!(market.base_mint, BASE_MINT);
+    assert_eq!(market.quote_mint, QUOTE_MINT);
+    assert_eq!(market.total_deposits, 1_000);
+    assert_eq!(
+        market.fee,
+        Fee {
+            maker_bps: 5,
+            taker_bps: 10
+        }
+    );
+    assert_eq!(market.orders[31], 77);
+    assert_eq!(market.orders[..31], [0; 31]);
+    // same as the copying deserialize
+    assert_eq!(MarketAccount::deserialize(buf).unwrap().0, *market);
+    assert_eq!(MarketAccount(*market).try_to_vec().unwrap(), buf);
+}
+
+#[test]
+fn test_load_trailing_data_ok() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN + 16);
+    market_account_data(buf);
+    assert_eq!(MarketAccount::load(buf).unwrap().total_deposits, 1_000);
+}
+
+#[test]
+fn test_load_mut() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN);
+    market_account_data(buf);
+
+    let market = MarketAccount::load_mut(buf).unwrap();
+    market.total_deposits += 500;
+    market.fee.taker_bps = 20;
+    market.orders[0] = 1;
+
+    let data = &buf[8..];
+    assert_eq!(
+        data[MARKET_TOTAL_DEPOSITS_OFFSET..MARKET_TOTAL_DEPOSITS_OFFSET + 8],
+        1_500u64.to_le_bytes()
+    );
+    let fee = &data[MARKET_FEE_OFFSET..];
+    assert_eq!(
+        fee[FEE_TAKER_BPS_OFFSET..FEE_TAKER_BPS_OFFSET + 4],
+        20u32.to_le_bytes()
+    );
+    assert_eq!(
+        data[MARKET_ORDERS_OFFSET..MARKET_ORDERS_OFFSET + 8],
+        1u64.to_le_bytes()
+    );
+    // discm untouched
+    assert_eq!(buf[..8], MARKET_ACCOUNT_DISCM);
+}
+
+#[test]
+fn test_load_wrong_discm() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN);
+    market_account_data(buf);
+    buf[..8].copy_from_slice(&CONFIG_ACCOUNT_DISCM);
+    assert!(MarketAccount::load(buf).is_err());
+    assert!(MarketAccount::load_mut(buf).is_err());
+}
+
+#[test]
+fn test_load_too_small() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN);
+    market_account_data(buf);
+    let short = &mut buf[..MARKET_ACCOUNT_LEN - 1];
+    assert!(MarketAccount::load(short).is_err());
+    assert!(MarketAccount::load_mut(short).is_err());
+    // shorter than the discm
+    assert!(MarketAccount::load(&MARKET_ACCOUNT_DISCM[..4]).is_err());
+    assert!(MarketAccount::load(&[]).is_err());
+}
+
+#[test]
+fn test_load_misaligned() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN + 8);
+    market_account_data(&mut buf[1..]);
+    assert!(MarketAccount::load(&buf[1..]).is_err());
+    assert!(MarketAccount::load_mut(&mut buf[1..]).is_err());
+}
+
+#[test]
+fn test_load_account_info() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN);
+    market_account_data(buf);
+    let mut lamports = 1_000_000;
+    let account_info =
+        AccountInfo::new(&MARKET_KEY, false, true, &mut lamports, buf, &ID, false, 0);
+
+    {
+        let market = MarketAccount::load_account_info(&account_info).unwrap();
+        assert_eq!(market.authority, AUTHORITY);
+        // data is still borrowed immutably
+        assert_eq!(
+            MarketAccount::load_account_info_mut(&account_info).unwrap_err(),
+            ProgramError::AccountBorrowFailed
+        );
+    }
+    {
+        let mut market = MarketAccount::load_account_info_mut(&account_info).unwrap();
+        market.total_deposits = 42;
+        assert_eq!(
+            MarketAccount::load_account_info(&account_info).unwrap_err(),
+            ProgramError::AccountBorrowFailed
+        );
+    }
+    assert_eq!(
+        MarketAccount::load_account_info(&account_info)
+            .unwrap()
+            .total_deposits,
+        42
+    );
+    assert_eq!(
+        MarketAccount::from_account_info(&account_info)
+            .unwrap()
+            .0
+            .total_deposits,
+        42
+    );
+}
+
+#[test]
+fn test_load_account_info_wrong_owner() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN);
+    market_account_data(buf);
+    let mut lamports = 1_000_000;
+    let account_info = AccountInfo::new(
+        &MARKET_KEY,
+        false,
+        true,
+        &mut lamports,
+        buf,
+        &AUTHORITY,
+        false,
+        0,
+    );
+    assert_eq!(
+        MarketAccount::load_account_info(&account_info).unwrap_err(),
+        ProgramError::IllegalOwner
+    );
+    assert_eq!(
+        MarketAccount::load_account_info_mut(&account_info).unwrap_err(),
+        ProgramError::IllegalOwner
+    );
+    assert!(MarketAccount::load_account_info_with_program_id(AUTHORITY, &account_info).is_ok());
+    assert!(MarketAccount::load_account_info_mut_with_program_id(AUTHORITY, &account_info).is_ok());
+}
+
+#[test]
+fn test_load_account_info_invalid_data() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN - 1);
+    buf[..8].copy_from_slice(&MARKET_ACCOUNT_DISCM);
+    let mut lamports = 1_000_000;
+    let account_info =
+        AccountInfo::new(&MARKET_KEY, false, true, &mut lamports, buf, &ID, false, 0);
+    assert_eq!(
+        MarketAccount::load_account_info(&account_info).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+    assert_eq!(
+        MarketAccount::load_account_info_mut(&account_info).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+    // failed loads do not leave the data borrowed
+    assert!(account_info.try_borrow_mut_data().is_ok());
+}
@@ -0,0 +1,87 @@
+use crate::*;
+use solana_account_decoder::UiAccountEncoding;
+use solana_program::pubkey::Pubkey;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Result as ClientResult;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::RpcFilterType,
+};
+use solana_sdk::{
+    message::Message, signature::Signature, signer::Signer, transaction::Transaction,
+};
+/// Fetches and deserializes the [`MarketAccount`] at `pubkey`
+pub fn fetch_market(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<MarketAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(MarketAccount::deserialize(&data)?)
+}
+/// Fetches all [`MarketAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_market(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, MarketAccount)>> {
+    let filters = MarketAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, MarketAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Fetches and deserializes the [`ConfigAccount`] at `pubkey`
+pub fn fetch_config(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<ConfigAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(ConfigAccount::deserialize(&data)?)
+}
+/// Fetches all [`ConfigAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_config(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, ConfigAccount)>> {
+    let filters = ConfigAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, ConfigAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Sends a transaction of [`init_market_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_init_market(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: InitMarketKeys,
+    args: InitMarketIxArgs,
+) -> ClientResult<Signature> {
+    let ix = init_market_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
@@ -0,0 +1,15 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Zeroable, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fee {
+    pub maker_bps: u32,
+    pub taker_bps: u32,
+}
+/// Serialized size of [`Fee`]
+pub const FEE_LEN: usize = std::mem::size_of::<Fee>();
+/// Offset of `maker_bps` in serialized [`Fee`]
+pub const FEE_MAKER_BPS_OFFSET: usize = 0;
+/// Offset of `taker_bps` in serialized [`Fee`]
+pub const FEE_TAKER_BPS_OFFSET: usize = 4;
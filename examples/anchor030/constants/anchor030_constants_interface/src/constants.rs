@@ -0,0 +1,11 @@
+use solana_program::pubkey::Pubkey;
+/// Seed prefix of the vault PDA
+pub const VAULT_SEED: &[u8] = &[118, 97, 117, 108, 116];
+pub const PROTOCOL_NAME: &str = "anchor-constants";
+pub const MAX_DEPOSITS: u8 = 16;
+/// Deposits below this amount are rejected
+pub const MIN_DEPOSIT_LAMPORTS: u64 = 10000000;
+pub const FEE_OFFSET: i64 = -5;
+pub const PAUSED_BY_DEFAULT: bool = false;
+pub const ADMIN: Pubkey = solana_program::pubkey!("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
+pub const VERSION_BYTES: [u8; 4] = [0, 1, 0, 0];
@@ -0,0 +1,3 @@
+solana_program::declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+pub mod constants;
+pub use constants::*;
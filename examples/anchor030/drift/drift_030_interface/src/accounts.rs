@@ -0,0 +1,3040 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM: [u8; 8] = [233, 45, 62, 40, 35, 129, 48, 72];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhoenixV1FulfillmentConfig {
+    pub pubkey: Pubkey,
+    pub phoenix_program_id: Pubkey,
+    pub phoenix_log_authority: Pubkey,
+    pub phoenix_market: Pubkey,
+    pub phoenix_base_vault: Pubkey,
+    pub phoenix_quote_vault: Pubkey,
+    pub market_index: u16,
+    pub fulfillment_type: SpotFulfillmentType,
+    pub status: SpotFulfillmentConfigStatus,
+    pub padding: [u8; 4],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhoenixV1FulfillmentConfigAccount(pub PhoenixV1FulfillmentConfig);
+impl PhoenixV1FulfillmentConfigAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(PhoenixV1FulfillmentConfig::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serialized size of [`PhoenixV1FulfillmentConfig`]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_LEN: usize = 200;
+/// Offset of `pubkey` in serialized [`PhoenixV1FulfillmentConfig`]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_PUBKEY_OFFSET: usize = 0;
+/// Offset of `phoenix_program_id` in serialized [`PhoenixV1FulfillmentConfig`]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_PHOENIX_PROGRAM_ID_OFFSET: usize = 32;
+/// Offset of `phoenix_log_authority` in serialized [`PhoenixV1FulfillmentConfig`]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_PHOENIX_LOG_AUTHORITY_OFFSET: usize = 64;
+/// Offset of `phoenix_market` in serialized [`PhoenixV1FulfillmentConfig`]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_PHOENIX_MARKET_OFFSET: usize = 96;
+/// Offset of `phoenix_base_vault` in serialized [`PhoenixV1FulfillmentConfig`]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_PHOENIX_BASE_VAULT_OFFSET: usize = 128;
+/// Offset of `phoenix_quote_vault` in serialized [`PhoenixV1FulfillmentConfig`]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_PHOENIX_QUOTE_VAULT_OFFSET: usize = 160;
+/// Offset of `market_index` in serialized [`PhoenixV1FulfillmentConfig`]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_MARKET_INDEX_OFFSET: usize = 192;
+/// Offset of `fulfillment_type` in serialized [`PhoenixV1FulfillmentConfig`]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_FULFILLMENT_TYPE_OFFSET: usize = 194;
+/// Offset of `status` in serialized [`PhoenixV1FulfillmentConfig`]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_STATUS_OFFSET: usize = 195;
+/// Offset of `padding` in serialized [`PhoenixV1FulfillmentConfig`]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_PADDING_OFFSET: usize = 196;
+/// Size of the account data, including the 8-byte discriminator
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_LEN: usize = 8 + PHOENIX_V1_FULFILLMENT_CONFIG_LEN;
+/// Builds `getProgramAccounts` filters for [`PhoenixV1FulfillmentConfigAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PhoenixV1FulfillmentConfigAccountFilters(Vec<AccountFilter>);
+impl PhoenixV1FulfillmentConfigAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0.push(AccountFilter::DataSize(
+            PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_LEN as u64,
+        ));
+        self
+    }
+    /// Matches accounts whose `pubkey` equals `value`
+    pub fn pubkey_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PHOENIX_V1_FULFILLMENT_CONFIG_PUBKEY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `phoenix_program_id` equals `value`
+    pub fn phoenix_program_id_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PHOENIX_V1_FULFILLMENT_CONFIG_PHOENIX_PROGRAM_ID_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `phoenix_log_authority` equals `value`
+    pub fn phoenix_log_authority_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PHOENIX_V1_FULFILLMENT_CONFIG_PHOENIX_LOG_AUTHORITY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `phoenix_market` equals `value`
+    pub fn phoenix_market_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PHOENIX_V1_FULFILLMENT_CONFIG_PHOENIX_MARKET_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `phoenix_base_vault` equals `value`
+    pub fn phoenix_base_vault_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PHOENIX_V1_FULFILLMENT_CONFIG_PHOENIX_BASE_VAULT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `phoenix_quote_vault` equals `value`
+    pub fn phoenix_quote_vault_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PHOENIX_V1_FULFILLMENT_CONFIG_PHOENIX_QUOTE_VAULT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `market_index` equals `value`
+    pub fn market_index_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PHOENIX_V1_FULFILLMENT_CONFIG_MARKET_INDEX_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `fulfillment_type` equals `value`
+    pub fn fulfillment_type_eq(mut self, value: &SpotFulfillmentType) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PHOENIX_V1_FULFILLMENT_CONFIG_FULFILLMENT_TYPE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `status` equals `value`
+    pub fn status_eq(mut self, value: &SpotFulfillmentConfigStatus) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PHOENIX_V1_FULFILLMENT_CONFIG_STATUS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `padding` equals `value`
+    pub fn padding_eq(mut self, value: &[u8; 4]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PHOENIX_V1_FULFILLMENT_CONFIG_PADDING_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl PhoenixV1FulfillmentConfigAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> PhoenixV1FulfillmentConfigAccountFilters {
+        PhoenixV1FulfillmentConfigAccountFilters::default()
+    }
+}
+pub const SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM: [u8; 8] =
+    [65, 160, 197, 112, 239, 168, 103, 185];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SerumV3FulfillmentConfig {
+    pub pubkey: Pubkey,
+    pub serum_program_id: Pubkey,
+    pub serum_market: Pubkey,
+    pub serum_request_queue: Pubkey,
+    pub serum_event_queue: Pubkey,
+    pub serum_bids: Pubkey,
+    pub serum_asks: Pubkey,
+    pub serum_base_vault: Pubkey,
+    pub serum_quote_vault: Pubkey,
+    pub serum_open_orders: Pubkey,
+    pub serum_signer_nonce: u64,
+    pub market_index: u16,
+    pub fulfillment_type: SpotFulfillmentType,
+    pub status: SpotFulfillmentConfigStatus,
+    pub padding: [u8; 4],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SerumV3FulfillmentConfigAccount(pub SerumV3FulfillmentConfig);
+impl SerumV3FulfillmentConfigAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SerumV3FulfillmentConfig::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serialized size of [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_LEN: usize = 336;
+/// Offset of `pubkey` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_PUBKEY_OFFSET: usize = 0;
+/// Offset of `serum_program_id` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_SERUM_PROGRAM_ID_OFFSET: usize = 32;
+/// Offset of `serum_market` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_SERUM_MARKET_OFFSET: usize = 64;
+/// Offset of `serum_request_queue` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_SERUM_REQUEST_QUEUE_OFFSET: usize = 96;
+/// Offset of `serum_event_queue` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_SERUM_EVENT_QUEUE_OFFSET: usize = 128;
+/// Offset of `serum_bids` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_SERUM_BIDS_OFFSET: usize = 160;
+/// Offset of `serum_asks` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_SERUM_ASKS_OFFSET: usize = 192;
+/// Offset of `serum_base_vault` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_SERUM_BASE_VAULT_OFFSET: usize = 224;
+/// Offset of `serum_quote_vault` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_SERUM_QUOTE_VAULT_OFFSET: usize = 256;
+/// Offset of `serum_open_orders` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_SERUM_OPEN_ORDERS_OFFSET: usize = 288;
+/// Offset of `serum_signer_nonce` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_SERUM_SIGNER_NONCE_OFFSET: usize = 320;
+/// Offset of `market_index` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_MARKET_INDEX_OFFSET: usize = 328;
+/// Offset of `fulfillment_type` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_FULFILLMENT_TYPE_OFFSET: usize = 330;
+/// Offset of `status` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_STATUS_OFFSET: usize = 331;
+/// Offset of `padding` in serialized [`SerumV3FulfillmentConfig`]
+pub const SERUM_V3_FULFILLMENT_CONFIG_PADDING_OFFSET: usize = 332;
+/// Size of the account data, including the 8-byte discriminator
+pub const SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_LEN: usize = 8 + SERUM_V3_FULFILLMENT_CONFIG_LEN;
+/// Builds `getProgramAccounts` filters for [`SerumV3FulfillmentConfigAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SerumV3FulfillmentConfigAccountFilters(Vec<AccountFilter>);
+impl SerumV3FulfillmentConfigAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0.push(AccountFilter::DataSize(
+            SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_LEN as u64,
+        ));
+        self
+    }
+    /// Matches accounts whose `pubkey` equals `value`
+    pub fn pubkey_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_PUBKEY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `serum_program_id` equals `value`
+    pub fn serum_program_id_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_SERUM_PROGRAM_ID_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `serum_market` equals `value`
+    pub fn serum_market_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_SERUM_MARKET_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `serum_request_queue` equals `value`
+    pub fn serum_request_queue_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_SERUM_REQUEST_QUEUE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `serum_event_queue` equals `value`
+    pub fn serum_event_queue_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_SERUM_EVENT_QUEUE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `serum_bids` equals `value`
+    pub fn serum_bids_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_SERUM_BIDS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `serum_asks` equals `value`
+    pub fn serum_asks_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_SERUM_ASKS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `serum_base_vault` equals `value`
+    pub fn serum_base_vault_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_SERUM_BASE_VAULT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `serum_quote_vault` equals `value`
+    pub fn serum_quote_vault_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_SERUM_QUOTE_VAULT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `serum_open_orders` equals `value`
+    pub fn serum_open_orders_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_SERUM_OPEN_ORDERS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `serum_signer_nonce` equals `value`
+    pub fn serum_signer_nonce_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_SERUM_SIGNER_NONCE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `market_index` equals `value`
+    pub fn market_index_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_MARKET_INDEX_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `fulfillment_type` equals `value`
+    pub fn fulfillment_type_eq(mut self, value: &SpotFulfillmentType) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_FULFILLMENT_TYPE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `status` equals `value`
+    pub fn status_eq(mut self, value: &SpotFulfillmentConfigStatus) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_STATUS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `padding` equals `value`
+    pub fn padding_eq(mut self, value: &[u8; 4]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SERUM_V3_FULFILLMENT_CONFIG_PADDING_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl SerumV3FulfillmentConfigAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> SerumV3FulfillmentConfigAccountFilters {
+        SerumV3FulfillmentConfigAccountFilters::default()
+    }
+}
+pub const INSURANCE_FUND_STAKE_ACCOUNT_DISCM: [u8; 8] = [110, 202, 14, 42, 95, 73, 90, 95];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceFundStake {
+    pub authority: Pubkey,
+    pub if_shares: u128,
+    pub last_withdraw_request_shares: u128,
+    pub if_base: u128,
+    pub last_valid_ts: i64,
+    pub last_withdraw_request_value: u64,
+    pub last_withdraw_request_ts: i64,
+    pub cost_basis: i64,
+    pub market_index: u16,
+    pub padding: [u8; 14],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InsuranceFundStakeAccount(pub InsuranceFundStake);
+impl InsuranceFundStakeAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INSURANCE_FUND_STAKE_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INSURANCE_FUND_STAKE_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(InsuranceFundStake::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INSURANCE_FUND_STAKE_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serialized size of [`InsuranceFundStake`]
+pub const INSURANCE_FUND_STAKE_LEN: usize = 128;
+/// Offset of `authority` in serialized [`InsuranceFundStake`]
+pub const INSURANCE_FUND_STAKE_AUTHORITY_OFFSET: usize = 0;
+/// Offset of `if_shares` in serialized [`InsuranceFundStake`]
+pub const INSURANCE_FUND_STAKE_IF_SHARES_OFFSET: usize = 32;
+/// Offset of `last_withdraw_request_shares` in serialized [`InsuranceFundStake`]
+pub const INSURANCE_FUND_STAKE_LAST_WITHDRAW_REQUEST_SHARES_OFFSET: usize = 48;
+/// Offset of `if_base` in serialized [`InsuranceFundStake`]
+pub const INSURANCE_FUND_STAKE_IF_BASE_OFFSET: usize = 64;
+/// Offset of `last_valid_ts` in serialized [`InsuranceFundStake`]
+pub const INSURANCE_FUND_STAKE_LAST_VALID_TS_OFFSET: usize = 80;
+/// Offset of `last_withdraw_request_value` in serialized [`InsuranceFundStake`]
+pub const INSURANCE_FUND_STAKE_LAST_WITHDRAW_REQUEST_VALUE_OFFSET: usize = 88;
+/// Offset of `last_withdraw_request_ts` in serialized [`InsuranceFundStake`]
+pub const INSURANCE_FUND_STAKE_LAST_WITHDRAW_REQUEST_TS_OFFSET: usize = 96;
+/// Offset of `cost_basis` in serialized [`InsuranceFundStake`]
+pub const INSURANCE_FUND_STAKE_COST_BASIS_OFFSET: usize = 104;
+/// Offset of `market_index` in serialized [`InsuranceFundStake`]
+pub const INSURANCE_FUND_STAKE_MARKET_INDEX_OFFSET: usize = 112;
+/// Offset of `padding` in serialized [`InsuranceFundStake`]
+pub const INSURANCE_FUND_STAKE_PADDING_OFFSET: usize = 114;
+/// Size of the account data, including the 8-byte discriminator
+pub const INSURANCE_FUND_STAKE_ACCOUNT_LEN: usize = 8 + INSURANCE_FUND_STAKE_LEN;
+/// Builds `getProgramAccounts` filters for [`InsuranceFundStakeAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InsuranceFundStakeAccountFilters(Vec<AccountFilter>);
+impl InsuranceFundStakeAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: INSURANCE_FUND_STAKE_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0.push(AccountFilter::DataSize(
+            INSURANCE_FUND_STAKE_ACCOUNT_LEN as u64,
+        ));
+        self
+    }
+    /// Matches accounts whose `authority` equals `value`
+    pub fn authority_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + INSURANCE_FUND_STAKE_AUTHORITY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `if_shares` equals `value`
+    pub fn if_shares_eq(mut self, value: &u128) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + INSURANCE_FUND_STAKE_IF_SHARES_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `last_withdraw_request_shares` equals `value`
+    pub fn last_withdraw_request_shares_eq(mut self, value: &u128) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + INSURANCE_FUND_STAKE_LAST_WITHDRAW_REQUEST_SHARES_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `if_base` equals `value`
+    pub fn if_base_eq(mut self, value: &u128) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + INSURANCE_FUND_STAKE_IF_BASE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `last_valid_ts` equals `value`
+    pub fn last_valid_ts_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + INSURANCE_FUND_STAKE_LAST_VALID_TS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `last_withdraw_request_value` equals `value`
+    pub fn last_withdraw_request_value_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + INSURANCE_FUND_STAKE_LAST_WITHDRAW_REQUEST_VALUE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `last_withdraw_request_ts` equals `value`
+    pub fn last_withdraw_request_ts_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + INSURANCE_FUND_STAKE_LAST_WITHDRAW_REQUEST_TS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `cost_basis` equals `value`
+    pub fn cost_basis_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + INSURANCE_FUND_STAKE_COST_BASIS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `market_index` equals `value`
+    pub fn market_index_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + INSURANCE_FUND_STAKE_MARKET_INDEX_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `padding` equals `value`
+    pub fn padding_eq(mut self, value: &[u8; 14]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + INSURANCE_FUND_STAKE_PADDING_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl InsuranceFundStakeAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> InsuranceFundStakeAccountFilters {
+        InsuranceFundStakeAccountFilters::default()
+    }
+}
+pub const PERP_MARKET_ACCOUNT_DISCM: [u8; 8] = [10, 223, 12, 44, 107, 245, 55, 247];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerpMarket {
+    /// The perp market's address. It is a pda of the market index
+    pub pubkey: Pubkey,
+    /// The automated market maker
+    pub amm: AMM,
+    /// The market's pnl pool. When users settle negative pnl, the balance increases.
+    /// When users settle positive pnl, the balance decreases. Can not go negative.
+    pub pnl_pool: PoolBalance,
+    /// Encoded display name for the perp market e.g. SOL-PERP
+    pub name: [u8; 32],
+    /// The perp market's claim on the insurance fund
+    pub insurance_claim: InsuranceClaim,
+    /// The max pnl imbalance before positive pnl asset weight is discounted
+    /// pnl imbalance is the difference between long and short pnl. When it's greater than 0,
+    /// the amm has negative pnl and the initial asset weight for positive pnl is discounted
+    /// precision = QUOTE_PRECISION
+    pub unrealized_pnl_max_imbalance: u64,
+    /// The ts when the market will be expired. Only set if market is in reduce only mode
+    pub expiry_ts: i64,
+    /// The price at which positions will be settled. Only set if market is expired
+    /// precision = PRICE_PRECISION
+    pub expiry_price: i64,
+    /// Every trade has a fill record id. This is the next id to be used
+    pub next_fill_record_id: u64,
+    /// Every funding rate update has a record id. This is the next id to be used
+    pub next_funding_rate_record_id: u64,
+    /// Every amm k updated has a record id. This is the next id to be used
+    pub next_curve_record_id: u64,
+    /// The initial margin fraction factor. Used to increase margin ratio for large positions
+    /// precision: MARGIN_PRECISION
+    pub imf_factor: u32,
+    /// The imf factor for unrealized pnl. Used to discount asset weight for large positive pnl
+    /// precision: MARGIN_PRECISION
+    pub unrealized_pnl_imf_factor: u32,
+    /// The fee the liquidator is paid for taking over perp position
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub liquidator_fee: u32,
+    /// The fee the insurance fund receives from liquidation
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub if_liquidation_fee: u32,
+    /// The margin ratio which determines how much collateral is required to open a position
+    /// e.g. margin ratio of .1 means a user must have $100 of total collateral to open a $1000 position
+    /// precision: MARGIN_PRECISION
+    pub margin_ratio_initial: u32,
+    /// The margin ratio which determines when a user will be liquidated
+    /// e.g. margin ratio of .05 means a user must have $50 of total collateral to maintain a $1000 position
+    /// else they will be liquidated
+    /// precision: MARGIN_PRECISION
+    pub margin_ratio_maintenance: u32,
+    /// The initial asset weight for positive pnl. Negative pnl always has an asset weight of 1
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub unrealized_pnl_initial_asset_weight: u32,
+    /// The maintenance asset weight for positive pnl. Negative pnl always has an asset weight of 1
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub unrealized_pnl_maintenance_asset_weight: u32,
+    /// number of users in a position (base)
+    pub number_of_users_with_base: u32,
+    /// number of users in a position (pnl) or pnl (quote)
+    pub number_of_users: u32,
+    pub market_index: u16,
+    /// Whether a market is active, reduce only, expired, etc
+    /// Affects whether users can open/close positions
+    pub status: MarketStatus,
+    /// Currently only Perpetual markets are supported
+    pub contract_type: ContractType,
+    /// The contract tier determines how much insurance a market can receive, with more speculative markets receiving less insurance
+    /// It also influences the order perp markets can be liquidated, with less speculative markets being liquidated first
+    pub contract_tier: ContractTier,
+    pub padding1: bool,
+    /// The spot market that pnl is settled in
+    pub quote_spot_market_index: u16,
+    pub padding: [u8; 48],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct PerpMarketAccount(pub PerpMarket);
+impl PerpMarketAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != PERP_MARKET_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    PERP_MARKET_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(PerpMarket::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&PERP_MARKET_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serialized size of [`PerpMarket`]
+pub const PERP_MARKET_LEN: usize = 1208;
+/// Offset of `pubkey` in serialized [`PerpMarket`]
+pub const PERP_MARKET_PUBKEY_OFFSET: usize = 0;
+/// Offset of `amm` in serialized [`PerpMarket`]
+pub const PERP_MARKET_AMM_OFFSET: usize = 32;
+/// Offset of `pnl_pool` in serialized [`PerpMarket`]
+pub const PERP_MARKET_PNL_POOL_OFFSET: usize = 968;
+/// Offset of `name` in serialized [`PerpMarket`]
+pub const PERP_MARKET_NAME_OFFSET: usize = 992;
+/// Offset of `insurance_claim` in serialized [`PerpMarket`]
+pub const PERP_MARKET_INSURANCE_CLAIM_OFFSET: usize = 1024;
+/// Offset of `unrealized_pnl_max_imbalance` in serialized [`PerpMarket`]
+pub const PERP_MARKET_UNREALIZED_PNL_MAX_IMBALANCE_OFFSET: usize = 1064;
+/// Offset of `expiry_ts` in serialized [`PerpMarket`]
+pub const PERP_MARKET_EXPIRY_TS_OFFSET: usize = 1072;
+/// Offset of `expiry_price` in serialized [`PerpMarket`]
+pub const PERP_MARKET_EXPIRY_PRICE_OFFSET: usize = 1080;
+/// Offset of `next_fill_record_id` in serialized [`PerpMarket`]
+pub const PERP_MARKET_NEXT_FILL_RECORD_ID_OFFSET: usize = 1088;
+/// Offset of `next_funding_rate_record_id` in serialized [`PerpMarket`]
+pub const PERP_MARKET_NEXT_FUNDING_RATE_RECORD_ID_OFFSET: usize = 1096;
+/// Offset of `next_curve_record_id` in serialized [`PerpMarket`]
+pub const PERP_MARKET_NEXT_CURVE_RECORD_ID_OFFSET: usize = 1104;
+/// Offset of `imf_factor` in serialized [`PerpMarket`]
+pub const PERP_MARKET_IMF_FACTOR_OFFSET: usize = 1112;
+/// Offset of `unrealized_pnl_imf_factor` in serialized [`PerpMarket`]
+pub const PERP_MARKET_UNREALIZED_PNL_IMF_FACTOR_OFFSET: usize = 1116;
+/// Offset of `liquidator_fee` in serialized [`PerpMarket`]
+pub const PERP_MARKET_LIQUIDATOR_FEE_OFFSET: usize = 1120;
+/// Offset of `if_liquidation_fee` in serialized [`PerpMarket`]
+pub const PERP_MARKET_IF_LIQUIDATION_FEE_OFFSET: usize = 1124;
+/// Offset of `margin_ratio_initial` in serialized [`PerpMarket`]
+pub const PERP_MARKET_MARGIN_RATIO_INITIAL_OFFSET: usize = 1128;
+/// Offset of `margin_ratio_maintenance` in serialized [`PerpMarket`]
+pub const PERP_MARKET_MARGIN_RATIO_MAINTENANCE_OFFSET: usize = 1132;
+/// Offset of `unrealized_pnl_initial_asset_weight` in serialized [`PerpMarket`]
+pub const PERP_MARKET_UNREALIZED_PNL_INITIAL_ASSET_WEIGHT_OFFSET: usize = 1136;
+/// Offset of `unrealized_pnl_maintenance_asset_weight` in serialized [`PerpMarket`]
+pub const PERP_MARKET_UNREALIZED_PNL_MAINTENANCE_ASSET_WEIGHT_OFFSET: usize = 1140;
+/// Offset of `number_of_users_with_base` in serialized [`PerpMarket`]
+pub const PERP_MARKET_NUMBER_OF_USERS_WITH_BASE_OFFSET: usize = 1144;
+/// Offset of `number_of_users` in serialized [`PerpMarket`]
+pub const PERP_MARKET_NUMBER_OF_USERS_OFFSET: usize = 1148;
+/// Offset of `market_index` in serialized [`PerpMarket`]
+pub const PERP_MARKET_MARKET_INDEX_OFFSET: usize = 1152;
+/// Offset of `status` in serialized [`PerpMarket`]
+pub const PERP_MARKET_STATUS_OFFSET: usize = 1154;
+/// Offset of `contract_type` in serialized [`PerpMarket`]
+pub const PERP_MARKET_CONTRACT_TYPE_OFFSET: usize = 1155;
+/// Offset of `contract_tier` in serialized [`PerpMarket`]
+pub const PERP_MARKET_CONTRACT_TIER_OFFSET: usize = 1156;
+/// Offset of `padding1` in serialized [`PerpMarket`]
+pub const PERP_MARKET_PADDING1_OFFSET: usize = 1157;
+/// Offset of `quote_spot_market_index` in serialized [`PerpMarket`]
+pub const PERP_MARKET_QUOTE_SPOT_MARKET_INDEX_OFFSET: usize = 1158;
+/// Offset of `padding` in serialized [`PerpMarket`]
+pub const PERP_MARKET_PADDING_OFFSET: usize = 1160;
+/// Size of the account data, including the 8-byte discriminator
+pub const PERP_MARKET_ACCOUNT_LEN: usize = 8 + PERP_MARKET_LEN;
+/// Builds `getProgramAccounts` filters for [`PerpMarketAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PerpMarketAccountFilters(Vec<AccountFilter>);
+impl PerpMarketAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: PERP_MARKET_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(PERP_MARKET_ACCOUNT_LEN as u64));
+        self
+    }
+    /// Matches accounts whose `pubkey` equals `value`
+    pub fn pubkey_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_PUBKEY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `amm` equals `value`
+    pub fn amm_eq(mut self, value: &AMM) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_AMM_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `pnl_pool` equals `value`
+    pub fn pnl_pool_eq(mut self, value: &PoolBalance) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_PNL_POOL_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `name` equals `value`
+    pub fn name_eq(mut self, value: &[u8; 32]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_NAME_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `insurance_claim` equals `value`
+    pub fn insurance_claim_eq(mut self, value: &InsuranceClaim) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_INSURANCE_CLAIM_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `unrealized_pnl_max_imbalance` equals `value`
+    pub fn unrealized_pnl_max_imbalance_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_UNREALIZED_PNL_MAX_IMBALANCE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `expiry_ts` equals `value`
+    pub fn expiry_ts_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_EXPIRY_TS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `expiry_price` equals `value`
+    pub fn expiry_price_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_EXPIRY_PRICE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `next_fill_record_id` equals `value`
+    pub fn next_fill_record_id_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_NEXT_FILL_RECORD_ID_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `next_funding_rate_record_id` equals `value`
+    pub fn next_funding_rate_record_id_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_NEXT_FUNDING_RATE_RECORD_ID_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `next_curve_record_id` equals `value`
+    pub fn next_curve_record_id_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_NEXT_CURVE_RECORD_ID_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `imf_factor` equals `value`
+    pub fn imf_factor_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_IMF_FACTOR_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `unrealized_pnl_imf_factor` equals `value`
+    pub fn unrealized_pnl_imf_factor_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_UNREALIZED_PNL_IMF_FACTOR_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `liquidator_fee` equals `value`
+    pub fn liquidator_fee_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_LIQUIDATOR_FEE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `if_liquidation_fee` equals `value`
+    pub fn if_liquidation_fee_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_IF_LIQUIDATION_FEE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `margin_ratio_initial` equals `value`
+    pub fn margin_ratio_initial_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_MARGIN_RATIO_INITIAL_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `margin_ratio_maintenance` equals `value`
+    pub fn margin_ratio_maintenance_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_MARGIN_RATIO_MAINTENANCE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `unrealized_pnl_initial_asset_weight` equals `value`
+    pub fn unrealized_pnl_initial_asset_weight_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_UNREALIZED_PNL_INITIAL_ASSET_WEIGHT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `unrealized_pnl_maintenance_asset_weight` equals `value`
+    pub fn unrealized_pnl_maintenance_asset_weight_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_UNREALIZED_PNL_MAINTENANCE_ASSET_WEIGHT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `number_of_users_with_base` equals `value`
+    pub fn number_of_users_with_base_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_NUMBER_OF_USERS_WITH_BASE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `number_of_users` equals `value`
+    pub fn number_of_users_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_NUMBER_OF_USERS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `market_index` equals `value`
+    pub fn market_index_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_MARKET_INDEX_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `status` equals `value`
+    pub fn status_eq(mut self, value: &MarketStatus) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_STATUS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `contract_type` equals `value`
+    pub fn contract_type_eq(mut self, value: &ContractType) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_CONTRACT_TYPE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `contract_tier` equals `value`
+    pub fn contract_tier_eq(mut self, value: &ContractTier) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_CONTRACT_TIER_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `padding1` equals `value`
+    pub fn padding1_eq(mut self, value: &bool) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_PADDING1_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `quote_spot_market_index` equals `value`
+    pub fn quote_spot_market_index_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_QUOTE_SPOT_MARKET_INDEX_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `padding` equals `value`
+    pub fn padding_eq(mut self, value: &[u8; 48]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + PERP_MARKET_PADDING_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl PerpMarketAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> PerpMarketAccountFilters {
+        PerpMarketAccountFilters::default()
+    }
+}
+pub const SPOT_MARKET_ACCOUNT_DISCM: [u8; 8] = [100, 177, 8, 107, 168, 65, 65, 39];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpotMarket {
+    /// The address of the spot market. It is a pda of the market index
+    pub pubkey: Pubkey,
+    /// The oracle used to price the markets deposits/borrows
+    pub oracle: Pubkey,
+    /// The token mint of the market
+    pub mint: Pubkey,
+    /// The vault used to store the market's deposits
+    /// The amount in the vault should be equal to or greater than deposits - borrows
+    pub vault: Pubkey,
+    /// The encoded display name fo the market e.g. SOL
+    pub name: [u8; 32],
+    pub historical_oracle_data: HistoricalOracleData,
+    pub historical_index_data: HistoricalIndexData,
+    /// Revenue the protocol has collected in this markets token
+    /// e.g. for SOL-PERP, funds can be settled in usdc and will flow into the USDC revenue pool
+    pub revenue_pool: PoolBalance,
+    /// The fees collected from swaps between this market and the quote market
+    /// Is settled to the quote markets revenue pool
+    pub spot_fee_pool: PoolBalance,
+    /// Details on the insurance fund covering bankruptcies in this markets token
+    /// Covers bankruptcies for borrows with this markets token and perps settling in this markets token
+    pub insurance_fund: InsuranceFund,
+    /// The total spot fees collected for this market
+    /// precision: QUOTE_PRECISION
+    pub total_spot_fee: u128,
+    /// The sum of the scaled balances for deposits across users and pool balances
+    /// To convert to the deposit token amount, multiply by the cumulative deposit interest
+    /// precision: SPOT_BALANCE_PRECISION
+    pub deposit_balance: u128,
+    /// The sum of the scaled balances for borrows across users and pool balances
+    /// To convert to the borrow token amount, multiply by the cumulative borrow interest
+    /// precision: SPOT_BALANCE_PRECISION
+    pub borrow_balance: u128,
+    /// The cumulative interest earned by depositors
+    /// Used to calculate the deposit token amount from the deposit balance
+    /// precision: SPOT_CUMULATIVE_INTEREST_PRECISION
+    pub cumulative_deposit_interest: u128,
+    /// The cumulative interest earned by borrowers
+    /// Used to calculate the borrow token amount from the borrow balance
+    /// precision: SPOT_CUMULATIVE_INTEREST_PRECISION
+    pub cumulative_borrow_interest: u128,
+    /// The total socialized loss from borrows, in the mint's token
+    /// precision: token mint precision
+    pub total_social_loss: u128,
+    /// The total socialized loss from borrows, in the quote market's token
+    /// preicision: QUOTE_PRECISION
+    pub total_quote_social_loss: u128,
+    /// no withdraw limits/guards when deposits below this threshold
+    /// precision: token mint precision
+    pub withdraw_guard_threshold: u64,
+    /// The max amount of token deposits in this market
+    /// 0 if there is no limit
+    /// precision: token mint precision
+    pub max_token_deposits: u64,
+    /// 24hr average of deposit token amount
+    /// precision: token mint precision
+    pub deposit_token_twap: u64,
+    /// 24hr average of borrow token amount
+    /// precision: token mint precision
+    pub borrow_token_twap: u64,
+    /// 24hr average of utilization
+    /// which is borrow amount over token amount
+    /// precision: SPOT_UTILIZATION_PRECISION
+    pub utilization_twap: u64,
+    /// Last time the cumulative deposit and borrow interest was updated
+    pub last_interest_ts: u64,
+    /// Last time the deposit/borrow/utilization averages were updated
+    pub last_twap_ts: u64,
+    /// The time the market is set to expire. Only set if market is in reduce only mode
+    pub expiry_ts: i64,
+    /// Spot orders must be a multiple of the step size
+    /// precision: token mint precision
+    pub order_step_size: u64,
+    /// Spot orders must be a multiple of the tick size
+    /// precision: PRICE_PRECISION
+    pub order_tick_size: u64,
+    /// The minimum order size
+    /// precision: token mint precision
+    pub min_order_size: u64,
+    /// The maximum spot position size
+    /// if the limit is 0, there is no limit
+    /// precision: token mint precision
+    pub max_position_size: u64,
+    /// Every spot trade has a fill record id. This is the next id to use
+    pub next_fill_record_id: u64,
+    /// Every deposit has a deposit record id. This is the next id to use
+    pub next_deposit_record_id: u64,
+    /// The initial asset weight used to calculate a deposits contribution to a users initial total collateral
+    /// e.g. if the asset weight is .8, $100 of deposits contributes $80 to the users initial total collateral
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub initial_asset_weight: u32,
+    /// The maintenance asset weight used to calculate a deposits contribution to a users maintenance total collateral
+    /// e.g. if the asset weight is .9, $100 of deposits contributes $90 to the users maintenance total collateral
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub maintenance_asset_weight: u32,
+    /// The initial liability weight used to calculate a borrows contribution to a users initial margin requirement
+    /// e.g. if the liability weight is .9, $100 of borrows contributes $90 to the users initial margin requirement
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub initial_liability_weight: u32,
+    /// The maintenance liability weight used to calculate a borrows contribution to a users maintenance margin requirement
+    /// e.g. if the liability weight is .8, $100 of borrows contributes $80 to the users maintenance margin requirement
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub maintenance_liability_weight: u32,
+    /// The initial margin fraction factor. Used to increase liability weight/decrease asset weight for large positions
+    /// precision: MARGIN_PRECISION
+    pub imf_factor: u32,
+    /// The fee the liquidator is paid for taking over borrow/deposit
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub liquidator_fee: u32,
+    /// The fee the insurance fund receives from liquidation
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub if_liquidation_fee: u32,
+    /// The optimal utilization rate for this market.
+    /// Used to determine the markets borrow rate
+    /// precision: SPOT_UTILIZATION_PRECISION
+    pub optimal_utilization: u32,
+    /// The borrow rate for this market when the market has optimal utilization
+    /// precision: SPOT_RATE_PRECISION
+    pub optimal_borrow_rate: u32,
+    /// The borrow rate for this market when the market has 1000 utilization
+    /// precision: SPOT_RATE_PRECISION
+    pub max_borrow_rate: u32,
+    /// The market's token mint's decimals. To from decimals to a precision, 10^decimals
+    pub decimals: u32,
+    pub market_index: u16,
+    /// Whether or not spot trading is enabled
+    pub orders_enabled: bool,
+    pub oracle_source: OracleSource,
+    pub status: MarketStatus,
+    /// The asset tier affects how a deposit can be used as collateral and the priority for a borrow being liquidated
+    pub asset_tier: AssetTier,
+    pub padding1: [u8; 6],
+    /// For swaps, the amount of token loaned out in the begin_swap ix
+    /// precision: token mint precision
+    pub flash_loan_amount: u64,
+    /// For swaps, the amount in the users token account in the begin_swap ix
+    /// Used to calculate how much of the token left the system in end_swap ix
+    /// precision: token mint precision
+    pub flash_loan_initial_token_amount: u64,
+    /// The total fees received from swaps
+    /// precision: token mint precision
+    pub total_swap_fee: u64,
+    pub padding: [u8; 56],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpotMarketAccount(pub SpotMarket);
+impl SpotMarketAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SPOT_MARKET_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SPOT_MARKET_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SpotMarket::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SPOT_MARKET_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serialized size of [`SpotMarket`]
+pub const SPOT_MARKET_LEN: usize = 768;
+/// Offset of `pubkey` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_PUBKEY_OFFSET: usize = 0;
+/// Offset of `oracle` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_ORACLE_OFFSET: usize = 32;
+/// Offset of `mint` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_MINT_OFFSET: usize = 64;
+/// Offset of `vault` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_VAULT_OFFSET: usize = 96;
+/// Offset of `name` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_NAME_OFFSET: usize = 128;
+/// Offset of `historical_oracle_data` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_HISTORICAL_ORACLE_DATA_OFFSET: usize = 160;
+/// Offset of `historical_index_data` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_HISTORICAL_INDEX_DATA_OFFSET: usize = 208;
+/// Offset of `revenue_pool` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_REVENUE_POOL_OFFSET: usize = 248;
+/// Offset of `spot_fee_pool` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_SPOT_FEE_POOL_OFFSET: usize = 272;
+/// Offset of `insurance_fund` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_INSURANCE_FUND_OFFSET: usize = 296;
+/// Offset of `total_spot_fee` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_TOTAL_SPOT_FEE_OFFSET: usize = 408;
+/// Offset of `deposit_balance` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_DEPOSIT_BALANCE_OFFSET: usize = 424;
+/// Offset of `borrow_balance` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_BORROW_BALANCE_OFFSET: usize = 440;
+/// Offset of `cumulative_deposit_interest` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_CUMULATIVE_DEPOSIT_INTEREST_OFFSET: usize = 456;
+/// Offset of `cumulative_borrow_interest` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_CUMULATIVE_BORROW_INTEREST_OFFSET: usize = 472;
+/// Offset of `total_social_loss` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_TOTAL_SOCIAL_LOSS_OFFSET: usize = 488;
+/// Offset of `total_quote_social_loss` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_TOTAL_QUOTE_SOCIAL_LOSS_OFFSET: usize = 504;
+/// Offset of `withdraw_guard_threshold` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_WITHDRAW_GUARD_THRESHOLD_OFFSET: usize = 520;
+/// Offset of `max_token_deposits` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_MAX_TOKEN_DEPOSITS_OFFSET: usize = 528;
+/// Offset of `deposit_token_twap` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_DEPOSIT_TOKEN_TWAP_OFFSET: usize = 536;
+/// Offset of `borrow_token_twap` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_BORROW_TOKEN_TWAP_OFFSET: usize = 544;
+/// Offset of `utilization_twap` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_UTILIZATION_TWAP_OFFSET: usize = 552;
+/// Offset of `last_interest_ts` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_LAST_INTEREST_TS_OFFSET: usize = 560;
+/// Offset of `last_twap_ts` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_LAST_TWAP_TS_OFFSET: usize = 568;
+/// Offset of `expiry_ts` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_EXPIRY_TS_OFFSET: usize = 576;
+/// Offset of `order_step_size` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_ORDER_STEP_SIZE_OFFSET: usize = 584;
+/// Offset of `order_tick_size` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_ORDER_TICK_SIZE_OFFSET: usize = 592;
+/// Offset of `min_order_size` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_MIN_ORDER_SIZE_OFFSET: usize = 600;
+/// Offset of `max_position_size` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_MAX_POSITION_SIZE_OFFSET: usize = 608;
+/// Offset of `next_fill_record_id` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_NEXT_FILL_RECORD_ID_OFFSET: usize = 616;
+/// Offset of `next_deposit_record_id` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_NEXT_DEPOSIT_RECORD_ID_OFFSET: usize = 624;
+/// Offset of `initial_asset_weight` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_INITIAL_ASSET_WEIGHT_OFFSET: usize = 632;
+/// Offset of `maintenance_asset_weight` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_MAINTENANCE_ASSET_WEIGHT_OFFSET: usize = 636;
+/// Offset of `initial_liability_weight` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_INITIAL_LIABILITY_WEIGHT_OFFSET: usize = 640;
+/// Offset of `maintenance_liability_weight` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_MAINTENANCE_LIABILITY_WEIGHT_OFFSET: usize = 644;
+/// Offset of `imf_factor` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_IMF_FACTOR_OFFSET: usize = 648;
+/// Offset of `liquidator_fee` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_LIQUIDATOR_FEE_OFFSET: usize = 652;
+/// Offset of `if_liquidation_fee` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_IF_LIQUIDATION_FEE_OFFSET: usize = 656;
+/// Offset of `optimal_utilization` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_OPTIMAL_UTILIZATION_OFFSET: usize = 660;
+/// Offset of `optimal_borrow_rate` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_OPTIMAL_BORROW_RATE_OFFSET: usize = 664;
+/// Offset of `max_borrow_rate` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_MAX_BORROW_RATE_OFFSET: usize = 668;
+/// Offset of `decimals` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_DECIMALS_OFFSET: usize = 672;
+/// Offset of `market_index` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_MARKET_INDEX_OFFSET: usize = 676;
+/// Offset of `orders_enabled` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_ORDERS_ENABLED_OFFSET: usize = 678;
+/// Offset of `oracle_source` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_ORACLE_SOURCE_OFFSET: usize = 679;
+/// Offset of `status` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_STATUS_OFFSET: usize = 680;
+/// Offset of `asset_tier` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_ASSET_TIER_OFFSET: usize = 681;
+/// Offset of `padding1` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_PADDING1_OFFSET: usize = 682;
+/// Offset of `flash_loan_amount` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_FLASH_LOAN_AMOUNT_OFFSET: usize = 688;
+/// Offset of `flash_loan_initial_token_amount` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_FLASH_LOAN_INITIAL_TOKEN_AMOUNT_OFFSET: usize = 696;
+/// Offset of `total_swap_fee` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_TOTAL_SWAP_FEE_OFFSET: usize = 704;
+/// Offset of `padding` in serialized [`SpotMarket`]
+pub const SPOT_MARKET_PADDING_OFFSET: usize = 712;
+/// Size of the account data, including the 8-byte discriminator
+pub const SPOT_MARKET_ACCOUNT_LEN: usize = 8 + SPOT_MARKET_LEN;
+/// Builds `getProgramAccounts` filters for [`SpotMarketAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpotMarketAccountFilters(Vec<AccountFilter>);
+impl SpotMarketAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: SPOT_MARKET_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(SPOT_MARKET_ACCOUNT_LEN as u64));
+        self
+    }
+    /// Matches accounts whose `pubkey` equals `value`
+    pub fn pubkey_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_PUBKEY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `oracle` equals `value`
+    pub fn oracle_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_ORACLE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `mint` equals `value`
+    pub fn mint_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_MINT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `vault` equals `value`
+    pub fn vault_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_VAULT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `name` equals `value`
+    pub fn name_eq(mut self, value: &[u8; 32]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_NAME_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `historical_oracle_data` equals `value`
+    pub fn historical_oracle_data_eq(mut self, value: &HistoricalOracleData) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_HISTORICAL_ORACLE_DATA_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `historical_index_data` equals `value`
+    pub fn historical_index_data_eq(mut self, value: &HistoricalIndexData) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_HISTORICAL_INDEX_DATA_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `revenue_pool` equals `value`
+    pub fn revenue_pool_eq(mut self, value: &PoolBalance) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_REVENUE_POOL_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `spot_fee_pool` equals `value`
+    pub fn spot_fee_pool_eq(mut self, value: &PoolBalance) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_SPOT_FEE_POOL_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `insurance_fund` equals `value`
+    pub fn insurance_fund_eq(mut self, value: &InsuranceFund) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_INSURANCE_FUND_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `total_spot_fee` equals `value`
+    pub fn total_spot_fee_eq(mut self, value: &u128) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_TOTAL_SPOT_FEE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `deposit_balance` equals `value`
+    pub fn deposit_balance_eq(mut self, value: &u128) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_DEPOSIT_BALANCE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `borrow_balance` equals `value`
+    pub fn borrow_balance_eq(mut self, value: &u128) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_BORROW_BALANCE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `cumulative_deposit_interest` equals `value`
+    pub fn cumulative_deposit_interest_eq(mut self, value: &u128) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_CUMULATIVE_DEPOSIT_INTEREST_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `cumulative_borrow_interest` equals `value`
+    pub fn cumulative_borrow_interest_eq(mut self, value: &u128) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_CUMULATIVE_BORROW_INTEREST_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `total_social_loss` equals `value`
+    pub fn total_social_loss_eq(mut self, value: &u128) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_TOTAL_SOCIAL_LOSS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `total_quote_social_loss` equals `value`
+    pub fn total_quote_social_loss_eq(mut self, value: &u128) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_TOTAL_QUOTE_SOCIAL_LOSS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `withdraw_guard_threshold` equals `value`
+    pub fn withdraw_guard_threshold_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_WITHDRAW_GUARD_THRESHOLD_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `max_token_deposits` equals `value`
+    pub fn max_token_deposits_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_MAX_TOKEN_DEPOSITS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `deposit_token_twap` equals `value`
+    pub fn deposit_token_twap_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_DEPOSIT_TOKEN_TWAP_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `borrow_token_twap` equals `value`
+    pub fn borrow_token_twap_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_BORROW_TOKEN_TWAP_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `utilization_twap` equals `value`
+    pub fn utilization_twap_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_UTILIZATION_TWAP_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `last_interest_ts` equals `value`
+    pub fn last_interest_ts_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_LAST_INTEREST_TS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `last_twap_ts` equals `value`
+    pub fn last_twap_ts_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_LAST_TWAP_TS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `expiry_ts` equals `value`
+    pub fn expiry_ts_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_EXPIRY_TS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `order_step_size` equals `value`
+    pub fn order_step_size_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_ORDER_STEP_SIZE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `order_tick_size` equals `value`
+    pub fn order_tick_size_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_ORDER_TICK_SIZE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `min_order_size` equals `value`
+    pub fn min_order_size_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_MIN_ORDER_SIZE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `max_position_size` equals `value`
+    pub fn max_position_size_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_MAX_POSITION_SIZE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `next_fill_record_id` equals `value`
+    pub fn next_fill_record_id_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_NEXT_FILL_RECORD_ID_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `next_deposit_record_id` equals `value`
+    pub fn next_deposit_record_id_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_NEXT_DEPOSIT_RECORD_ID_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `initial_asset_weight` equals `value`
+    pub fn initial_asset_weight_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_INITIAL_ASSET_WEIGHT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `maintenance_asset_weight` equals `value`
+    pub fn maintenance_asset_weight_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_MAINTENANCE_ASSET_WEIGHT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `initial_liability_weight` equals `value`
+    pub fn initial_liability_weight_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_INITIAL_LIABILITY_WEIGHT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `maintenance_liability_weight` equals `value`
+    pub fn maintenance_liability_weight_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_MAINTENANCE_LIABILITY_WEIGHT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `imf_factor` equals `value`
+    pub fn imf_factor_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_IMF_FACTOR_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `liquidator_fee` equals `value`
+    pub fn liquidator_fee_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_LIQUIDATOR_FEE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `if_liquidation_fee` equals `value`
+    pub fn if_liquidation_fee_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_IF_LIQUIDATION_FEE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `optimal_utilization` equals `value`
+    pub fn optimal_utilization_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_OPTIMAL_UTILIZATION_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `optimal_borrow_rate` equals `value`
+    pub fn optimal_borrow_rate_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_OPTIMAL_BORROW_RATE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `max_borrow_rate` equals `value`
+    pub fn max_borrow_rate_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_MAX_BORROW_RATE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `decimals` equals `value`
+    pub fn decimals_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_DECIMALS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `market_index` equals `value`
+    pub fn market_index_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_MARKET_INDEX_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `orders_enabled` equals `value`
+    pub fn orders_enabled_eq(mut self, value: &bool) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_ORDERS_ENABLED_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `oracle_source` equals `value`
+    pub fn oracle_source_eq(mut self, value: &OracleSource) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_ORACLE_SOURCE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `status` equals `value`
+    pub fn status_eq(mut self, value: &MarketStatus) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_STATUS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `asset_tier` equals `value`
+    pub fn asset_tier_eq(mut self, value: &AssetTier) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_ASSET_TIER_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `padding1` equals `value`
+    pub fn padding1_eq(mut self, value: &[u8; 6]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_PADDING1_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `flash_loan_amount` equals `value`
+    pub fn flash_loan_amount_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_FLASH_LOAN_AMOUNT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `flash_loan_initial_token_amount` equals `value`
+    pub fn flash_loan_initial_token_amount_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_FLASH_LOAN_INITIAL_TOKEN_AMOUNT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `total_swap_fee` equals `value`
+    pub fn total_swap_fee_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_TOTAL_SWAP_FEE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `padding` equals `value`
+    pub fn padding_eq(mut self, value: &[u8; 56]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + SPOT_MARKET_PADDING_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl SpotMarketAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> SpotMarketAccountFilters {
+        SpotMarketAccountFilters::default()
+    }
+}
+pub const STATE_ACCOUNT_DISCM: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub admin: Pubkey,
+    pub whitelist_mint: Pubkey,
+    pub discount_mint: Pubkey,
+    pub signer: Pubkey,
+    pub srm_vault: Pubkey,
+    pub perp_fee_structure: FeeStructure,
+    pub spot_fee_structure: FeeStructure,
+    pub oracle_guard_rails: OracleGuardRails,
+    pub number_of_authorities: u64,
+    pub number_of_sub_accounts: u64,
+    pub lp_cooldown_time: u64,
+    pub liquidation_margin_buffer_ratio: u32,
+    pub settlement_duration: u16,
+    pub number_of_markets: u16,
+    pub number_of_spot_markets: u16,
+    pub signer_nonce: u8,
+    pub min_perp_auction_duration: u8,
+    pub default_market_order_time_in_force: u8,
+    pub default_spot_auction_duration: u8,
+    pub exchange_status: u8,
+    pub liquidation_duration: u8,
+    pub initial_pct_to_liquidate: u16,
+    pub padding: [u8; 14],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateAccount(pub State);
+impl StateAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != STATE_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    STATE_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(State::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&STATE_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serialized size of [`State`]
+pub const STATE_LEN: usize = 984;
+/// Offset of `admin` in serialized [`State`]
+pub const STATE_ADMIN_OFFSET: usize = 0;
+/// Offset of `whitelist_mint` in serialized [`State`]
+pub const STATE_WHITELIST_MINT_OFFSET: usize = 32;
+/// Offset of `discount_mint` in serialized [`State`]
+pub const STATE_DISCOUNT_MINT_OFFSET: usize = 64;
+/// Offset of `signer` in serialized [`State`]
+pub const STATE_SIGNER_OFFSET: usize = 96;
+/// Offset of `srm_vault` in serialized [`State`]
+pub const STATE_SRM_VAULT_OFFSET: usize = 128;
+/// Offset of `perp_fee_structure` in serialized [`State`]
+pub const STATE_PERP_FEE_STRUCTURE_OFFSET: usize = 160;
+/// Offset of `spot_fee_structure` in serialized [`State`]
+pub const STATE_SPOT_FEE_STRUCTURE_OFFSET: usize = 520;
+/// Offset of `oracle_guard_rails` in serialized [`State`]
+pub const STATE_ORACLE_GUARD_RAILS_OFFSET: usize = 880;
+/// Offset of `number_of_authorities` in serialized [`State`]
+pub const STATE_NUMBER_OF_AUTHORITIES_OFFSET: usize = 928;
+/// Offset of `number_of_sub_accounts` in serialized [`State`]
+pub const STATE_NUMBER_OF_SUB_ACCOUNTS_OFFSET: usize = 936;
+/// Offset of `lp_cooldown_time` in serialized [`State`]
+pub const STATE_LP_COOLDOWN_TIME_OFFSET: usize = 944;
+/// Offset of `liquidation_margin_buffer_ratio` in serialized [`State`]
+pub const STATE_LIQUIDATION_MARGIN_BUFFER_RATIO_OFFSET: usize = 952;
+/// Offset of `settlement_duration` in serialized [`State`]
+pub const STATE_SETTLEMENT_DURATION_OFFSET: usize = 956;
+/// Offset of `number_of_markets` in serialized [`State`]
+pub const STATE_NUMBER_OF_MARKETS_OFFSET: usize = 958;
+/// Offset of `number_of_spot_markets` in serialized [`State`]
+pub const STATE_NUMBER_OF_SPOT_MARKETS_OFFSET: usize = 960;
+/// Offset of `signer_nonce` in serialized [`State`]
+pub const STATE_SIGNER_NONCE_OFFSET: usize = 962;
+/// Offset of `min_perp_auction_duration` in serialized [`State`]
+pub const STATE_MIN_PERP_AUCTION_DURATION_OFFSET: usize = 963;
+/// Offset of `default_market_order_time_in_force` in serialized [`State`]
+pub const STATE_DEFAULT_MARKET_ORDER_TIME_IN_FORCE_OFFSET: usize = 964;
+/// Offset of `default_spot_auction_duration` in serialized [`State`]
+pub const STATE_DEFAULT_SPOT_AUCTION_DURATION_OFFSET: usize = 965;
+/// Offset of `exchange_status` in serialized [`State`]
+pub const STATE_EXCHANGE_STATUS_OFFSET: usize = 966;
+/// Offset of `liquidation_duration` in serialized [`State`]
+pub const STATE_LIQUIDATION_DURATION_OFFSET: usize = 967;
+/// Offset of `initial_pct_to_liquidate` in serialized [`State`]
+pub const STATE_INITIAL_PCT_TO_LIQUIDATE_OFFSET: usize = 968;
+/// Offset of `padding` in serialized [`State`]
+pub const STATE_PADDING_OFFSET: usize = 970;
+/// Size of the account data, including the 8-byte discriminator
+pub const STATE_ACCOUNT_LEN: usize = 8 + STATE_LEN;
+/// Builds `getProgramAccounts` filters for [`StateAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateAccountFilters(Vec<AccountFilter>);
+impl StateAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: STATE_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(STATE_ACCOUNT_LEN as u64));
+        self
+    }
+    /// Matches accounts whose `admin` equals `value`
+    pub fn admin_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_ADMIN_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `whitelist_mint` equals `value`
+    pub fn whitelist_mint_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_WHITELIST_MINT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `discount_mint` equals `value`
+    pub fn discount_mint_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_DISCOUNT_MINT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `signer` equals `value`
+    pub fn signer_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_SIGNER_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `srm_vault` equals `value`
+    pub fn srm_vault_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_SRM_VAULT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `perp_fee_structure` equals `value`
+    pub fn perp_fee_structure_eq(mut self, value: &FeeStructure) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_PERP_FEE_STRUCTURE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `spot_fee_structure` equals `value`
+    pub fn spot_fee_structure_eq(mut self, value: &FeeStructure) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_SPOT_FEE_STRUCTURE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `oracle_guard_rails` equals `value`
+    pub fn oracle_guard_rails_eq(mut self, value: &OracleGuardRails) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_ORACLE_GUARD_RAILS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `number_of_authorities` equals `value`
+    pub fn number_of_authorities_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_NUMBER_OF_AUTHORITIES_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `number_of_sub_accounts` equals `value`
+    pub fn number_of_sub_accounts_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_NUMBER_OF_SUB_ACCOUNTS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `lp_cooldown_time` equals `value`
+    pub fn lp_cooldown_time_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_LP_COOLDOWN_TIME_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `liquidation_margin_buffer_ratio` equals `value`
+    pub fn liquidation_margin_buffer_ratio_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_LIQUIDATION_MARGIN_BUFFER_RATIO_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `settlement_duration` equals `value`
+    pub fn settlement_duration_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_SETTLEMENT_DURATION_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `number_of_markets` equals `value`
+    pub fn number_of_markets_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_NUMBER_OF_MARKETS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `number_of_spot_markets` equals `value`
+    pub fn number_of_spot_markets_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_NUMBER_OF_SPOT_MARKETS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `signer_nonce` equals `value`
+    pub fn signer_nonce_eq(mut self, value: &u8) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_SIGNER_NONCE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `min_perp_auction_duration` equals `value`
+    pub fn min_perp_auction_duration_eq(mut self, value: &u8) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_MIN_PERP_AUCTION_DURATION_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `default_market_order_time_in_force` equals `value`
+    pub fn default_market_order_time_in_force_eq(mut self, value: &u8) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_DEFAULT_MARKET_ORDER_TIME_IN_FORCE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `default_spot_auction_duration` equals `value`
+    pub fn default_spot_auction_duration_eq(mut self, value: &u8) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_DEFAULT_SPOT_AUCTION_DURATION_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `exchange_status` equals `value`
+    pub fn exchange_status_eq(mut self, value: &u8) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_EXCHANGE_STATUS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `liquidation_duration` equals `value`
+    pub fn liquidation_duration_eq(mut self, value: &u8) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_LIQUIDATION_DURATION_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `initial_pct_to_liquidate` equals `value`
+    pub fn initial_pct_to_liquidate_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_INITIAL_PCT_TO_LIQUIDATE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `padding` equals `value`
+    pub fn padding_eq(mut self, value: &[u8; 14]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + STATE_PADDING_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl StateAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> StateAccountFilters {
+        StateAccountFilters::default()
+    }
+}
+pub const USER_ACCOUNT_DISCM: [u8; 8] = [159, 117, 95, 227, 239, 151, 58, 236];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct User {
+    /// The owner/authority of the account
+    pub authority: Pubkey,
+    /// An addresses that can control the account on the authority's behalf. Has limited power, cant withdraw
+    pub delegate: Pubkey,
+    /// Encoded display name e.g. "toly"
+    pub name: [u8; 32],
+    /// The user's spot positions
+    pub spot_positions: [SpotPosition; 8],
+    /// The user's perp positions
+    pub perp_positions: [PerpPosition; 8],
+    /// The user's orders
+    pub orders: [Order; 32],
+    /// The last time the user added perp lp positions
+    pub last_add_perp_lp_shares_ts: i64,
+    /// The total values of deposits the user has made
+    /// precision: QUOTE_PRECISION
+    pub total_deposits: u64,
+    /// The total values of withdrawals the user has made
+    /// precision: QUOTE_PRECISION
+    pub total_withdraws: u64,
+    /// The total socialized loss the users has incurred upon the protocol
+    /// precision: QUOTE_PRECISION
+    pub total_social_loss: u64,
+    /// Fees (taker fees, maker rebate, referrer reward, filler reward) and pnl for perps
+    /// precision: QUOTE_PRECISION
+    pub settled_perp_pnl: i64,
+    /// Fees (taker fees, maker rebate, filler reward) for spot
+    /// precision: QUOTE_PRECISION
+    pub cumulative_spot_fees: i64,
+    /// Cumulative funding paid/received for perps
+    /// precision: QUOTE_PRECISION
+    pub cumulative_perp_funding: i64,
+    /// The amount of margin freed during liquidation. Used to force the liquidation to occur over a period of time
+    /// Defaults to zero when not being liquidated
+    /// precision: QUOTE_PRECISION
+    pub liquidation_margin_freed: u64,
+    /// The last slot a user was active. Used to determine if a user is idle
+    pub last_active_slot: u64,
+    /// Every user order has an order id. This is the next order id to be used
+    pub next_order_id: u32,
+    /// Custom max initial margin ratio for the user
+    pub max_margin_ratio: u32,
+    /// The next liquidation id to be used for user
+    pub next_liquidation_id: u16,
+    /// The sub account id for this user
+    pub sub_account_id: u16,
+    /// Whether the user is active, being liquidated or bankrupt
+    pub status: UserStatus,
+    /// Whether the user has enabled margin trading
+    pub is_margin_trading_enabled: bool,
+    /// User is idle if they haven't interacted with the protocol in 1 week and they have no orders, perp positions or borrows
+    /// Off-chain keeper bots can ignore users that are idle
+    pub idle: bool,
+    /// number of open orders
+    pub open_orders: u8,
+    /// Whether or not user has open order
+    pub has_open_order: bool,
+    /// number of open orders with auction
+    pub open_auctions: u8,
+    /// Whether or not user has open order with auction
+    pub has_open_auction: bool,
+    pub padding: [u8; 21],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserAccount(pub User);
+impl UserAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != USER_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    USER_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(User::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&USER_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serialized size of [`User`]
+pub const USER_LEN: usize = 4368;
+/// Offset of `authority` in serialized [`User`]
+pub const USER_AUTHORITY_OFFSET: usize = 0;
+/// Offset of `delegate` in serialized [`User`]
+pub const USER_DELEGATE_OFFSET: usize = 32;
+/// Offset of `name` in serialized [`User`]
+pub const USER_NAME_OFFSET: usize = 64;
+/// Offset of `spot_positions` in serialized [`User`]
+pub const USER_SPOT_POSITIONS_OFFSET: usize = 96;
+/// Offset of `perp_positions` in serialized [`User`]
+pub const USER_PERP_POSITIONS_OFFSET: usize = 416;
+/// Offset of `orders` in serialized [`User`]
+pub const USER_ORDERS_OFFSET: usize = 1184;
+/// Offset of `last_add_perp_lp_shares_ts` in serialized [`User`]
+pub const USER_LAST_ADD_PERP_LP_SHARES_TS_OFFSET: usize = 4256;
+/// Offset of `total_deposits` in serialized [`User`]
+pub const USER_TOTAL_DEPOSITS_OFFSET: usize = 4264;
+/// Offset of `total_withdraws` in serialized [`User`]
+pub const USER_TOTAL_WITHDRAWS_OFFSET: usize = 4272;
+/// Offset of `total_social_loss` in serialized [`User`]
+pub const USER_TOTAL_SOCIAL_LOSS_OFFSET: usize = 4280;
+/// Offset of `settled_perp_pnl` in serialized [`User`]
+pub const USER_SETTLED_PERP_PNL_OFFSET: usize = 4288;
+/// Offset of `cumulative_spot_fees` in serialized [`User`]
+pub const USER_CUMULATIVE_SPOT_FEES_OFFSET: usize = 4296;
+/// Offset of `cumulative_perp_funding` in serialized [`User`]
+pub const USER_CUMULATIVE_PERP_FUNDING_OFFSET: usize = 4304;
+/// Offset of `liquidation_margin_freed` in serialized [`User`]
+pub const USER_LIQUIDATION_MARGIN_FREED_OFFSET: usize = 4312;
+/// Offset of `last_active_slot` in serialized [`User`]
+pub const USER_LAST_ACTIVE_SLOT_OFFSET: usize = 4320;
+/// Offset of `next_order_id` in serialized [`User`]
+pub const USER_NEXT_ORDER_ID_OFFSET: usize = 4328;
+/// Offset of `max_margin_ratio` in serialized [`User`]
+pub const USER_MAX_MARGIN_RATIO_OFFSET: usize = 4332;
+/// Offset of `next_liquidation_id` in serialized [`User`]
+pub const USER_NEXT_LIQUIDATION_ID_OFFSET: usize = 4336;
+/// Offset of `sub_account_id` in serialized [`User`]
+pub const USER_SUB_ACCOUNT_ID_OFFSET: usize = 4338;
+/// Offset of `status` in serialized [`User`]
+pub const USER_STATUS_OFFSET: usize = 4340;
+/// Offset of `is_margin_trading_enabled` in serialized [`User`]
+pub const USER_IS_MARGIN_TRADING_ENABLED_OFFSET: usize = 4341;
+/// Offset of `idle` in serialized [`User`]
+pub const USER_IDLE_OFFSET: usize = 4342;
+/// Offset of `open_orders` in serialized [`User`]
+pub const USER_OPEN_ORDERS_OFFSET: usize = 4343;
+/// Offset of `has_open_order` in serialized [`User`]
+pub const USER_HAS_OPEN_ORDER_OFFSET: usize = 4344;
+/// Offset of `open_auctions` in serialized [`User`]
+pub const USER_OPEN_AUCTIONS_OFFSET: usize = 4345;
+/// Offset of `has_open_auction` in serialized [`User`]
+pub const USER_HAS_OPEN_AUCTION_OFFSET: usize = 4346;
+/// Offset of `padding` in serialized [`User`]
+pub const USER_PADDING_OFFSET: usize = 4347;
+/// Size of the account data, including the 8-byte discriminator
+pub const USER_ACCOUNT_LEN: usize = 8 + USER_LEN;
+/// Builds `getProgramAccounts` filters for [`UserAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UserAccountFilters(Vec<AccountFilter>);
+impl UserAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: USER_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(USER_ACCOUNT_LEN as u64));
+        self
+    }
+    /// Matches accounts whose `authority` equals `value`
+    pub fn authority_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_AUTHORITY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `delegate` equals `value`
+    pub fn delegate_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_DELEGATE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `name` equals `value`
+    pub fn name_eq(mut self, value: &[u8; 32]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_NAME_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `spot_positions` equals `value`
+    pub fn spot_positions_eq(mut self, value: &[SpotPosition; 8]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_SPOT_POSITIONS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `perp_positions` equals `value`
+    pub fn perp_positions_eq(mut self, value: &[PerpPosition; 8]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_PERP_POSITIONS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `orders` equals `value`
+    pub fn orders_eq(mut self, value: &[Order; 32]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_ORDERS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `last_add_perp_lp_shares_ts` equals `value`
+    pub fn last_add_perp_lp_shares_ts_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_LAST_ADD_PERP_LP_SHARES_TS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `total_deposits` equals `value`
+    pub fn total_deposits_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_TOTAL_DEPOSITS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `total_withdraws` equals `value`
+    pub fn total_withdraws_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_TOTAL_WITHDRAWS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `total_social_loss` equals `value`
+    pub fn total_social_loss_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_TOTAL_SOCIAL_LOSS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `settled_perp_pnl` equals `value`
+    pub fn settled_perp_pnl_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_SETTLED_PERP_PNL_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `cumulative_spot_fees` equals `value`
+    pub fn cumulative_spot_fees_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_CUMULATIVE_SPOT_FEES_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `cumulative_perp_funding` equals `value`
+    pub fn cumulative_perp_funding_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_CUMULATIVE_PERP_FUNDING_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `liquidation_margin_freed` equals `value`
+    pub fn liquidation_margin_freed_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_LIQUIDATION_MARGIN_FREED_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `last_active_slot` equals `value`
+    pub fn last_active_slot_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_LAST_ACTIVE_SLOT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `next_order_id` equals `value`
+    pub fn next_order_id_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_NEXT_ORDER_ID_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `max_margin_ratio` equals `value`
+    pub fn max_margin_ratio_eq(mut self, value: &u32) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_MAX_MARGIN_RATIO_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `next_liquidation_id` equals `value`
+    pub fn next_liquidation_id_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_NEXT_LIQUIDATION_ID_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `sub_account_id` equals `value`
+    pub fn sub_account_id_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_SUB_ACCOUNT_ID_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `status` equals `value`
+    pub fn status_eq(mut self, value: &UserStatus) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATUS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `is_margin_trading_enabled` equals `value`
+    pub fn is_margin_trading_enabled_eq(mut self, value: &bool) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_IS_MARGIN_TRADING_ENABLED_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `idle` equals `value`
+    pub fn idle_eq(mut self, value: &bool) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_IDLE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `open_orders` equals `value`
+    pub fn open_orders_eq(mut self, value: &u8) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_OPEN_ORDERS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `has_open_order` equals `value`
+    pub fn has_open_order_eq(mut self, value: &bool) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_HAS_OPEN_ORDER_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `open_auctions` equals `value`
+    pub fn open_auctions_eq(mut self, value: &u8) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_OPEN_AUCTIONS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `has_open_auction` equals `value`
+    pub fn has_open_auction_eq(mut self, value: &bool) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_HAS_OPEN_AUCTION_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `padding` equals `value`
+    pub fn padding_eq(mut self, value: &[u8; 21]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_PADDING_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl UserAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> UserAccountFilters {
+        UserAccountFilters::default()
+    }
+}
+pub const USER_STATS_ACCOUNT_DISCM: [u8; 8] = [176, 223, 136, 27, 122, 79, 32, 227];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UserStats {
+    /// The authority for all of a users sub accounts
+    pub authority: Pubkey,
+    /// The address that referred this user
+    pub referrer: Pubkey,
+    /// Stats on the fees paid by the user
+    pub fees: UserFees,
+    /// The timestamp of the next epoch
+    /// Epoch is used to limit referrer rewards earned in single epoch
+    pub next_epoch_ts: i64,
+    /// Rolling 30day maker volume for user
+    /// precision: QUOTE_PRECISION
+    pub maker_volume30d: u64,
+    /// Rolling 30day taker volume for user
+    /// precision: QUOTE_PRECISION
+    pub taker_volume30d: u64,
+    /// Rolling 30day filler volume for user
+    /// precision: QUOTE_PRECISION
+    pub filler_volume30d: u64,
+    /// last time the maker volume was updated
+    pub last_maker_volume30d_ts: i64,
+    /// last time the taker volume was updated
+    pub last_taker_volume30d_ts: i64,
+    /// last time the filler volume was updated
+    pub last_filler_volume30d_ts: i64,
+    /// The amount of tokens staked in the quote spot markets if
+    pub if_staked_quote_asset_amount: u64,
+    /// The current number of sub accounts
+    pub number_of_sub_accounts: u16,
+    /// The number of sub accounts created. Can be greater than the number of sub accounts if user
+    /// has deleted sub accounts
+    pub number_of_sub_accounts_created: u16,
+    /// Whether the user is a referrer. Sub account 0 can not be deleted if user is a referrer
+    pub is_referrer: bool,
+    pub padding: [u8; 51],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserStatsAccount(pub UserStats);
+impl UserStatsAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != USER_STATS_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    USER_STATS_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(UserStats::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&USER_STATS_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serialized size of [`UserStats`]
+pub const USER_STATS_LEN: usize = 232;
+/// Offset of `authority` in serialized [`UserStats`]
+pub const USER_STATS_AUTHORITY_OFFSET: usize = 0;
+/// Offset of `referrer` in serialized [`UserStats`]
+pub const USER_STATS_REFERRER_OFFSET: usize = 32;
+/// Offset of `fees` in serialized [`UserStats`]
+pub const USER_STATS_FEES_OFFSET: usize = 64;
+/// Offset of `next_epoch_ts` in serialized [`UserStats`]
+pub const USER_STATS_NEXT_EPOCH_TS_OFFSET: usize = 112;
+/// Offset of `maker_volume30d` in serialized [`UserStats`]
+pub const USER_STATS_MAKER_VOLUME30D_OFFSET: usize = 120;
+/// Offset of `taker_volume30d` in serialized [`UserStats`]
+pub const USER_STATS_TAKER_VOLUME30D_OFFSET: usize = 128;
+/// Offset of `filler_volume30d` in serialized [`UserStats`]
+pub const USER_STATS_FILLER_VOLUME30D_OFFSET: usize = 136;
+/// Offset of `last_maker_volume30d_ts` in serialized [`UserStats`]
+pub const USER_STATS_LAST_MAKER_VOLUME30D_TS_OFFSET: usize = 144;
+/// Offset of `last_taker_volume30d_ts` in serialized [`UserStats`]
+pub const USER_STATS_LAST_TAKER_VOLUME30D_TS_OFFSET: usize = 152;
+/// Offset of `last_filler_volume30d_ts` in serialized [`UserStats`]
+pub const USER_STATS_LAST_FILLER_VOLUME30D_TS_OFFSET: usize = 160;
+/// Offset of `if_staked_quote_asset_amount` in serialized [`UserStats`]
+pub const USER_STATS_IF_STAKED_QUOTE_ASSET_AMOUNT_OFFSET: usize = 168;
+/// Offset of `number_of_sub_accounts` in serialized [`UserStats`]
+pub const USER_STATS_NUMBER_OF_SUB_ACCOUNTS_OFFSET: usize = 176;
+/// Offset of `number_of_sub_accounts_created` in serialized [`UserStats`]
+pub const USER_STATS_NUMBER_OF_SUB_ACCOUNTS_CREATED_OFFSET: usize = 178;
+/// Offset of `is_referrer` in serialized [`UserStats`]
+pub const USER_STATS_IS_REFERRER_OFFSET: usize = 180;
+/// Offset of `padding` in serialized [`UserStats`]
+pub const USER_STATS_PADDING_OFFSET: usize = 181;
+/// Size of the account data, including the 8-byte discriminator
+pub const USER_STATS_ACCOUNT_LEN: usize = 8 + USER_STATS_LEN;
+/// Builds `getProgramAccounts` filters for [`UserStatsAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UserStatsAccountFilters(Vec<AccountFilter>);
+impl UserStatsAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: USER_STATS_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(USER_STATS_ACCOUNT_LEN as u64));
+        self
+    }
+    /// Matches accounts whose `authority` equals `value`
+    pub fn authority_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_AUTHORITY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `referrer` equals `value`
+    pub fn referrer_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_REFERRER_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `fees` equals `value`
+    pub fn fees_eq(mut self, value: &UserFees) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_FEES_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `next_epoch_ts` equals `value`
+    pub fn next_epoch_ts_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_NEXT_EPOCH_TS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `maker_volume30d` equals `value`
+    pub fn maker_volume30d_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_MAKER_VOLUME30D_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `taker_volume30d` equals `value`
+    pub fn taker_volume30d_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_TAKER_VOLUME30D_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `filler_volume30d` equals `value`
+    pub fn filler_volume30d_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_FILLER_VOLUME30D_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `last_maker_volume30d_ts` equals `value`
+    pub fn last_maker_volume30d_ts_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_LAST_MAKER_VOLUME30D_TS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `last_taker_volume30d_ts` equals `value`
+    pub fn last_taker_volume30d_ts_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_LAST_TAKER_VOLUME30D_TS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `last_filler_volume30d_ts` equals `value`
+    pub fn last_filler_volume30d_ts_eq(mut self, value: &i64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_LAST_FILLER_VOLUME30D_TS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `if_staked_quote_asset_amount` equals `value`
+    pub fn if_staked_quote_asset_amount_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_IF_STAKED_QUOTE_ASSET_AMOUNT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `number_of_sub_accounts` equals `value`
+    pub fn number_of_sub_accounts_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_NUMBER_OF_SUB_ACCOUNTS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `number_of_sub_accounts_created` equals `value`
+    pub fn number_of_sub_accounts_created_eq(mut self, value: &u16) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_NUMBER_OF_SUB_ACCOUNTS_CREATED_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `is_referrer` equals `value`
+    pub fn is_referrer_eq(mut self, value: &bool) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_IS_REFERRER_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `padding` equals `value`
+    pub fn padding_eq(mut self, value: &[u8; 51]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + USER_STATS_PADDING_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl UserStatsAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> UserStatsAccountFilters {
+        UserStatsAccountFilters::default()
+    }
+}
+pub const REFERRER_NAME_ACCOUNT_DISCM: [u8; 8] = [105, 133, 170, 110, 52, 42, 28, 182];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferrerName {
+    pub authority: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub name: [u8; 32],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReferrerNameAccount(pub ReferrerName);
+impl ReferrerNameAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != REFERRER_NAME_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REFERRER_NAME_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(ReferrerName::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&REFERRER_NAME_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serialized size of [`ReferrerName`]
+pub const REFERRER_NAME_LEN: usize = 128;
+/// Offset of `authority` in serialized [`ReferrerName`]
+pub const REFERRER_NAME_AUTHORITY_OFFSET: usize = 0;
+/// Offset of `user` in serialized [`ReferrerName`]
+pub const REFERRER_NAME_USER_OFFSET: usize = 32;
+/// Offset of `user_stats` in serialized [`ReferrerName`]
+pub const REFERRER_NAME_USER_STATS_OFFSET: usize = 64;
+/// Offset of `name` in serialized [`ReferrerName`]
+pub const REFERRER_NAME_NAME_OFFSET: usize = 96;
+/// Size of the account data, including the 8-byte discriminator
+pub const REFERRER_NAME_ACCOUNT_LEN: usize = 8 + REFERRER_NAME_LEN;
+/// Builds `getProgramAccounts` filters for [`ReferrerNameAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReferrerNameAccountFilters(Vec<AccountFilter>);
+impl ReferrerNameAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: REFERRER_NAME_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(REFERRER_NAME_ACCOUNT_LEN as u64));
+        self
+    }
+    /// Matches accounts whose `authority` equals `value`
+    pub fn authority_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + REFERRER_NAME_AUTHORITY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `user` equals `value`
+    pub fn user_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + REFERRER_NAME_USER_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `user_stats` equals `value`
+    pub fn user_stats_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + REFERRER_NAME_USER_STATS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `name` equals `value`
+    pub fn name_eq(mut self, value: &[u8; 32]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + REFERRER_NAME_NAME_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl ReferrerNameAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> ReferrerNameAccountFilters {
+        ReferrerNameAccountFilters::default()
+    }
+}
+/// A `getProgramAccounts` filter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountFilter {
+    /// Account data is exactly this many bytes long
+    DataSize(u64),
+    /// Account data at `offset` starts with `bytes`
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+#[cfg(feature = "solana-rpc-client-api")]
+impl From<AccountFilter> for solana_rpc_client_api::filter::RpcFilterType {
+    fn from(filter: AccountFilter) -> Self {
+        match filter {
+            AccountFilter::DataSize(size) => Self::DataSize(size),
+            AccountFilter::Memcmp { offset, bytes } => Self::Memcmp(
+                solana_rpc_client_api::filter::Memcmp::new_base58_encoded(offset, &bytes),
+            ),
+        }
+    }
+}
+/// The 8-byte discriminator that account data starts with, None if the data is too short
+pub fn discriminator_of(data: &[u8]) -> Option<[u8; 8]> {
+    data.get(..8).map(|discm| discm.try_into().unwrap())
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DriftAccount {
+    PhoenixV1FulfillmentConfig(PhoenixV1FulfillmentConfig),
+    SerumV3FulfillmentConfig(SerumV3FulfillmentConfig),
+    InsuranceFundStake(InsuranceFundStake),
+    PerpMarket(PerpMarket),
+    SpotMarket(SpotMarket),
+    State(State),
+    User(User),
+    UserStats(UserStats),
+    ReferrerName(ReferrerName),
+}
+impl DriftAccount {
+    /// Deserializes account data into the account type its discriminator identifies
+    pub fn try_from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        let mut reader = data;
+        let maybe_discm = <[u8; 8]>::deserialize(&mut reader)?;
+        match maybe_discm {
+            PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM => {
+                PhoenixV1FulfillmentConfigAccount::deserialize(data)
+                    .map(|a| Self::PhoenixV1FulfillmentConfig(a.0))
+            }
+            SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM => {
+                SerumV3FulfillmentConfigAccount::deserialize(data)
+                    .map(|a| Self::SerumV3FulfillmentConfig(a.0))
+            }
+            INSURANCE_FUND_STAKE_ACCOUNT_DISCM => {
+                InsuranceFundStakeAccount::deserialize(data).map(|a| Self::InsuranceFundStake(a.0))
+            }
+            PERP_MARKET_ACCOUNT_DISCM => {
+                PerpMarketAccount::deserialize(data).map(|a| Self::PerpMarket(a.0))
+            }
+            SPOT_MARKET_ACCOUNT_DISCM => {
+                SpotMarketAccount::deserialize(data).map(|a| Self::SpotMarket(a.0))
+            }
+            STATE_ACCOUNT_DISCM => StateAccount::deserialize(data).map(|a| Self::State(a.0)),
+            USER_ACCOUNT_DISCM => UserAccount::deserialize(data).map(|a| Self::User(a.0)),
+            USER_STATS_ACCOUNT_DISCM => {
+                UserStatsAccount::deserialize(data).map(|a| Self::UserStats(a.0))
+            }
+            REFERRER_NAME_ACCOUNT_DISCM => {
+                ReferrerNameAccount::deserialize(data).map(|a| Self::ReferrerName(a.0))
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+}
@@ -0,0 +1,857 @@
+use solana_program::{
+    decode_error::DecodeError,
+    instruction::InstructionError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+    pubkey::Pubkey,
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum DriftError {
+    /// Invalid Spot Market Authority
+    #[error("Invalid Spot Market Authority")]
+    InvalidSpotMarketAuthority = 6000,
+    /// Clearing house not insurance fund authority
+    #[error("Clearing house not insurance fund authority")]
+    InvalidInsuranceFundAuthority = 6001,
+    /// Insufficient deposit
+    #[error("Insufficient deposit")]
+    InsufficientDeposit = 6002,
+    /// Insufficient collateral
+    #[error("Insufficient collateral")]
+    InsufficientCollateral = 6003,
+    /// Sufficient collateral
+    #[error("Sufficient collateral")]
+    SufficientCollateral = 6004,
+    /// Max number of positions taken
+    #[error("Max number of positions taken")]
+    MaxNumberOfPositions = 6005,
+    /// Admin Controls Prices Disabled
+    #[error("Admin Controls Prices Disabled")]
+    AdminControlsPricesDisabled = 6006,
+    /// Market Delisted
+    #[error("Market Delisted")]
+    MarketDelisted = 6007,
+    /// Market Index Already Initialized
+    #[error("Market Index Already Initialized")]
+    MarketIndexAlreadyInitialized = 6008,
+    /// User Account And User Positions Account Mismatch
+    #[error("User Account And User Positions Account Mismatch")]
+    UserAccountAndUserPositionsAccountMismatch = 6009,
+    /// User Has No Position In Market
+    #[error("User Has No Position In Market")]
+    UserHasNoPositionInMarket = 6010,
+    /// Invalid Initial Peg
+    #[error("Invalid Initial Peg")]
+    InvalidInitialPeg = 6011,
+    /// AMM repeg already configured with amt given
+    #[error("AMM repeg already configured with amt given")]
+    InvalidRepegRedundant = 6012,
+    /// AMM repeg incorrect repeg direction
+    #[error("AMM repeg incorrect repeg direction")]
+    InvalidRepegDirection = 6013,
+    /// AMM repeg out of bounds pnl
+    #[error("AMM repeg out of bounds pnl")]
+    InvalidRepegProfitability = 6014,
+    /// Slippage Outside Limit Price
+    #[error("Slippage Outside Limit Price")]
+    SlippageOutsideLimit = 6015,
+    /// Order Size Too Small
+    #[error("Order Size Too Small")]
+    OrderSizeTooSmall = 6016,
+    /// Price change too large when updating K
+    #[error("Price change too large when updating K")]
+    InvalidUpdateK = 6017,
+    /// Admin tried to withdraw amount larger than fees collected
+    #[error("Admin tried to withdraw amount larger than fees collected")]
+    AdminWithdrawTooLarge = 6018,
+    /// Math Error
+    #[error("Math Error")]
+    MathError = 6019,
+    /// Conversion to u128/u64 failed with an overflow or underflow
+    #[error("Conversion to u128/u64 failed with an overflow or underflow")]
+    BnConversionError = 6020,
+    /// Clock unavailable
+    #[error("Clock unavailable")]
+    ClockUnavailable = 6021,
+    /// Unable To Load Oracles
+    #[error("Unable To Load Oracles")]
+    UnableToLoadOracle = 6022,
+    /// Price Bands Breached
+    #[error("Price Bands Breached")]
+    PriceBandsBreached = 6023,
+    /// Exchange is paused
+    #[error("Exchange is paused")]
+    ExchangePaused = 6024,
+    /// Invalid whitelist token
+    #[error("Invalid whitelist token")]
+    InvalidWhitelistToken = 6025,
+    /// Whitelist token not found
+    #[error("Whitelist token not found")]
+    WhitelistTokenNotFound = 6026,
+    /// Invalid discount token
+    #[error("Invalid discount token")]
+    InvalidDiscountToken = 6027,
+    /// Discount token not found
+    #[error("Discount token not found")]
+    DiscountTokenNotFound = 6028,
+    /// Referrer not found
+    #[error("Referrer not found")]
+    ReferrerNotFound = 6029,
+    /// ReferrerNotFound
+    #[error("ReferrerNotFound")]
+    ReferrerStatsNotFound = 6030,
+    /// ReferrerMustBeWritable
+    #[error("ReferrerMustBeWritable")]
+    ReferrerMustBeWritable = 6031,
+    /// ReferrerMustBeWritable
+    #[error("ReferrerMustBeWritable")]
+    ReferrerStatsMustBeWritable = 6032,
+    /// ReferrerAndReferrerStatsAuthorityUnequal
+    #[error("ReferrerAndReferrerStatsAuthorityUnequal")]
+    ReferrerAndReferrerStatsAuthorityUnequal = 6033,
+    /// InvalidReferrer
+    #[error("InvalidReferrer")]
+    InvalidReferrer = 6034,
+    /// InvalidOracle
+    #[error("InvalidOracle")]
+    InvalidOracle = 6035,
+    /// OracleNotFound
+    #[error("OracleNotFound")]
+    OracleNotFound = 6036,
+    /// Liquidations Blocked By Oracle
+    #[error("Liquidations Blocked By Oracle")]
+    LiquidationsBlockedByOracle = 6037,
+    /// Can not deposit more than max deposit
+    #[error("Can not deposit more than max deposit")]
+    MaxDeposit = 6038,
+    /// Can not delete user that still has collateral
+    #[error("Can not delete user that still has collateral")]
+    CantDeleteUserWithCollateral = 6039,
+    /// AMM funding out of bounds pnl
+    #[error("AMM funding out of bounds pnl")]
+    InvalidFundingProfitability = 6040,
+    /// Casting Failure
+    #[error("Casting Failure")]
+    CastingFailure = 6041,
+    /// InvalidOrder
+    #[error("InvalidOrder")]
+    InvalidOrder = 6042,
+    /// InvalidOrderMaxTs
+    #[error("InvalidOrderMaxTs")]
+    InvalidOrderMaxTs = 6043,
+    /// InvalidOrderMarketType
+    #[error("InvalidOrderMarketType")]
+    InvalidOrderMarketType = 6044,
+    /// InvalidOrderForInitialMarginReq
+    #[error("InvalidOrderForInitialMarginReq")]
+    InvalidOrderForInitialMarginReq = 6045,
+    /// InvalidOrderNotRiskReducing
+    #[error("InvalidOrderNotRiskReducing")]
+    InvalidOrderNotRiskReducing = 6046,
+    /// InvalidOrderSizeTooSmall
+    #[error("InvalidOrderSizeTooSmall")]
+    InvalidOrderSizeTooSmall = 6047,
+    /// InvalidOrderNotStepSizeMultiple
+    #[error("InvalidOrderNotStepSizeMultiple")]
+    InvalidOrderNotStepSizeMultiple = 6048,
+    /// InvalidOrderBaseQuoteAsset
+    #[error("InvalidOrderBaseQuoteAsset")]
+    InvalidOrderBaseQuoteAsset = 6049,
+    /// InvalidOrderIOC
+    #[error("InvalidOrderIOC")]
+    InvalidOrderIoc = 6050,
+    /// InvalidOrderPostOnly
+    #[error("InvalidOrderPostOnly")]
+    InvalidOrderPostOnly = 6051,
+    /// InvalidOrderIOCPostOnly
+    #[error("InvalidOrderIOCPostOnly")]
+    InvalidOrderIocPostOnly = 6052,
+    /// InvalidOrderTrigger
+    #[error("InvalidOrderTrigger")]
+    InvalidOrderTrigger = 6053,
+    /// InvalidOrderAuction
+    #[error("InvalidOrderAuction")]
+    InvalidOrderAuction = 6054,
+    /// InvalidOrderOracleOffset
+    #[error("InvalidOrderOracleOffset")]
+    InvalidOrderOracleOffset = 6055,
+    /// InvalidOrderMinOrderSize
+    #[error("InvalidOrderMinOrderSize")]
+    InvalidOrderMinOrderSize = 6056,
+    /// Failed to Place Post-Only Limit Order
+    #[error("Failed to Place Post-Only Limit Order")]
+    PlacePostOnlyLimitFailure = 6057,
+    /// User has no order
+    #[error("User has no order")]
+    UserHasNoOrder = 6058,
+    /// Order Amount Too Small
+    #[error("Order Amount Too Small")]
+    OrderAmountTooSmall = 6059,
+    /// Max number of orders taken
+    #[error("Max number of orders taken")]
+    MaxNumberOfOrders = 6060,
+    /// Order does not exist
+    #[error("Order does not exist")]
+    OrderDoesNotExist = 6061,
+    /// Order not open
+    #[error("Order not open")]
+    OrderNotOpen = 6062,
+    /// FillOrderDidNotUpdateState
+    #[error("FillOrderDidNotUpdateState")]
+    FillOrderDidNotUpdateState = 6063,
+    /// Reduce only order increased risk
+    #[error("Reduce only order increased risk")]
+    ReduceOnlyOrderIncreasedRisk = 6064,
+    /// Unable to load AccountLoader
+    #[error("Unable to load AccountLoader")]
+    UnableToLoadAccountLoader = 6065,
+    /// Trade Size Too Large
+    #[error("Trade Size Too Large")]
+    TradeSizeTooLarge = 6066,
+    /// User cant refer themselves
+    #[error("User cant refer themselves")]
+    UserCantReferThemselves = 6067,
+    /// Did not receive expected referrer
+    #[error("Did not receive expected referrer")]
+    DidNotReceiveExpectedReferrer = 6068,
+    /// Could not deserialize referrer
+    #[error("Could not deserialize referrer")]
+    CouldNotDeserializeReferrer = 6069,
+    /// Could not deserialize referrer stats
+    #[error("Could not deserialize referrer stats")]
+    CouldNotDeserializeReferrerStats = 6070,
+    /// User Order Id Already In Use
+    #[error("User Order Id Already In Use")]
+    UserOrderIdAlreadyInUse = 6071,
+    /// No positions liquidatable
+    #[error("No positions liquidatable")]
+    NoPositionsLiquidatable = 6072,
+    /// Invalid Margin Ratio
+    #[error("Invalid Margin Ratio")]
+    InvalidMarginRatio = 6073,
+    /// Cant Cancel Post Only Order
+    #[error("Cant Cancel Post Only Order")]
+    CantCancelPostOnlyOrder = 6074,
+    /// InvalidOracleOffset
+    #[error("InvalidOracleOffset")]
+    InvalidOracleOffset = 6075,
+    /// CantExpireOrders
+    #[error("CantExpireOrders")]
+    CantExpireOrders = 6076,
+    /// CouldNotLoadMarketData
+    #[error("CouldNotLoadMarketData")]
+    CouldNotLoadMarketData = 6077,
+    /// PerpMarketNotFound
+    #[error("PerpMarketNotFound")]
+    PerpMarketNotFound = 6078,
+    /// InvalidMarketAccount
+    #[error("InvalidMarketAccount")]
+    InvalidMarketAccount = 6079,
+    /// UnableToLoadMarketAccount
+    #[error("UnableToLoadMarketAccount")]
+    UnableToLoadPerpMarketAccount = 6080,
+    /// MarketWrongMutability
+    #[error("MarketWrongMutability")]
+    MarketWrongMutability = 6081,
+    /// UnableToCastUnixTime
+    #[error("UnableToCastUnixTime")]
+    UnableToCastUnixTime = 6082,
+    /// CouldNotFindSpotPosition
+    #[error("CouldNotFindSpotPosition")]
+    CouldNotFindSpotPosition = 6083,
+    /// NoSpotPositionAvailable
+    #[error("NoSpotPositionAvailable")]
+    NoSpotPositionAvailable = 6084,
+    /// InvalidSpotMarketInitialization
+    #[error("InvalidSpotMarketInitialization")]
+    InvalidSpotMarketInitialization = 6085,
+    /// CouldNotLoadSpotMarketData
+    #[error("CouldNotLoadSpotMarketData")]
+    CouldNotLoadSpotMarketData = 6086,
+    /// SpotMarketNotFound
+    #[error("SpotMarketNotFound")]
+    SpotMarketNotFound = 6087,
+    /// InvalidSpotMarketAccount
+    #[error("InvalidSpotMarketAccount")]
+    InvalidSpotMarketAccount = 6088,
+    /// UnableToLoadSpotMarketAccount
+    #[error("UnableToLoadSpotMarketAccount")]
+    UnableToLoadSpotMarketAccount = 6089,
+    /// SpotMarketWrongMutability
+    #[error("SpotMarketWrongMutability")]
+    SpotMarketWrongMutability = 6090,
+    /// SpotInterestNotUpToDate
+    #[error("SpotInterestNotUpToDate")]
+    SpotMarketInterestNotUpToDate = 6091,
+    /// SpotMarketInsufficientDeposits
+    #[error("SpotMarketInsufficientDeposits")]
+    SpotMarketInsufficientDeposits = 6092,
+    /// UserMustSettleTheirOwnPositiveUnsettledPNL
+    #[error("UserMustSettleTheirOwnPositiveUnsettledPNL")]
+    UserMustSettleTheirOwnPositiveUnsettledPnl = 6093,
+    /// CantUpdatePoolBalanceType
+    #[error("CantUpdatePoolBalanceType")]
+    CantUpdatePoolBalanceType = 6094,
+    /// InsufficientCollateralForSettlingPNL
+    #[error("InsufficientCollateralForSettlingPNL")]
+    InsufficientCollateralForSettlingPnl = 6095,
+    /// AMMNotUpdatedInSameSlot
+    #[error("AMMNotUpdatedInSameSlot")]
+    AmmNotUpdatedInSameSlot = 6096,
+    /// AuctionNotComplete
+    #[error("AuctionNotComplete")]
+    AuctionNotComplete = 6097,
+    /// MakerNotFound
+    #[error("MakerNotFound")]
+    MakerNotFound = 6098,
+    /// MakerNotFound
+    #[error("MakerNotFound")]
+    MakerStatsNotFound = 6099,
+    /// MakerMustBeWritable
+    #[error("MakerMustBeWritable")]
+    MakerMustBeWritable = 6100,
+    /// MakerMustBeWritable
+    #[error("MakerMustBeWritable")]
+    MakerStatsMustBeWritable = 6101,
+    /// MakerOrderNotFound
+    #[error("MakerOrderNotFound")]
+    MakerOrderNotFound = 6102,
+    /// CouldNotDeserializeMaker
+    #[error("CouldNotDeserializeMaker")]
+    CouldNotDeserializeMaker = 6103,
+    /// CouldNotDeserializeMaker
+    #[error("CouldNotDeserializeMaker")]
+    CouldNotDeserializeMakerStats = 6104,
+    /// AuctionPriceDoesNotSatisfyMaker
+    #[error("AuctionPriceDoesNotSatisfyMaker")]
+    AuctionPriceDoesNotSatisfyMaker = 6105,
+    /// MakerCantFulfillOwnOrder
+    #[error("MakerCantFulfillOwnOrder")]
+    MakerCantFulfillOwnOrder = 6106,
+    /// MakerOrderMustBePostOnly
+    #[error("MakerOrderMustBePostOnly")]
+    MakerOrderMustBePostOnly = 6107,
+    /// CantMatchTwoPostOnlys
+    #[error("CantMatchTwoPostOnlys")]
+    CantMatchTwoPostOnlys = 6108,
+    /// OrderBreachesOraclePriceLimits
+    #[error("OrderBreachesOraclePriceLimits")]
+    OrderBreachesOraclePriceLimits = 6109,
+    /// OrderMustBeTriggeredFirst
+    #[error("OrderMustBeTriggeredFirst")]
+    OrderMustBeTriggeredFirst = 6110,
+    /// OrderNotTriggerable
+    #[error("OrderNotTriggerable")]
+    OrderNotTriggerable = 6111,
+    /// OrderDidNotSatisfyTriggerCondition
+    #[error("OrderDidNotSatisfyTriggerCondition")]
+    OrderDidNotSatisfyTriggerCondition = 6112,
+    /// PositionAlreadyBeingLiquidated
+    #[error("PositionAlreadyBeingLiquidated")]
+    PositionAlreadyBeingLiquidated = 6113,
+    /// PositionDoesntHaveOpenPositionOrOrders
+    #[error("PositionDoesntHaveOpenPositionOrOrders")]
+    PositionDoesntHaveOpenPositionOrOrders = 6114,
+    /// AllOrdersAreAlreadyLiquidations
+    #[error("AllOrdersAreAlreadyLiquidations")]
+    AllOrdersAreAlreadyLiquidations = 6115,
+    /// CantCancelLiquidationOrder
+    #[error("CantCancelLiquidationOrder")]
+    CantCancelLiquidationOrder = 6116,
+    /// UserIsBeingLiquidated
+    #[error("UserIsBeingLiquidated")]
+    UserIsBeingLiquidated = 6117,
+    /// LiquidationsOngoing
+    #[error("LiquidationsOngoing")]
+    LiquidationsOngoing = 6118,
+    /// WrongSpotBalanceType
+    #[error("WrongSpotBalanceType")]
+    WrongSpotBalanceType = 6119,
+    /// UserCantLiquidateThemself
+    #[error("UserCantLiquidateThemself")]
+    UserCantLiquidateThemself = 6120,
+    /// InvalidPerpPositionToLiquidate
+    #[error("InvalidPerpPositionToLiquidate")]
+    InvalidPerpPositionToLiquidate = 6121,
+    /// InvalidBaseAssetAmountForLiquidatePerp
+    #[error("InvalidBaseAssetAmountForLiquidatePerp")]
+    InvalidBaseAssetAmountForLiquidatePerp = 6122,
+    /// InvalidPositionLastFundingRate
+    #[error("InvalidPositionLastFundingRate")]
+    InvalidPositionLastFundingRate = 6123,
+    /// InvalidPositionDelta
+    #[error("InvalidPositionDelta")]
+    InvalidPositionDelta = 6124,
+    /// UserBankrupt
+    #[error("UserBankrupt")]
+    UserBankrupt = 6125,
+    /// UserNotBankrupt
+    #[error("UserNotBankrupt")]
+    UserNotBankrupt = 6126,
+    /// UserHasInvalidBorrow
+    #[error("UserHasInvalidBorrow")]
+    UserHasInvalidBorrow = 6127,
+    /// DailyWithdrawLimit
+    #[error("DailyWithdrawLimit")]
+    DailyWithdrawLimit = 6128,
+    /// DefaultError
+    #[error("DefaultError")]
+    DefaultError = 6129,
+    /// Insufficient LP tokens
+    #[error("Insufficient LP tokens")]
+    InsufficientLpTokens = 6130,
+    /// Cant LP with a market position
+    #[error("Cant LP with a market position")]
+    CantLpWithPerpPosition = 6131,
+    /// Unable to burn LP tokens
+    #[error("Unable to burn LP tokens")]
+    UnableToBurnLpTokens = 6132,
+    /// Trying to remove liqudity too fast after adding it
+    #[error("Trying to remove liqudity too fast after adding it")]
+    TryingToRemoveLiquidityTooFast = 6133,
+    /// Invalid Spot Market Vault
+    #[error("Invalid Spot Market Vault")]
+    InvalidSpotMarketVault = 6134,
+    /// Invalid Spot Market State
+    #[error("Invalid Spot Market State")]
+    InvalidSpotMarketState = 6135,
+    /// InvalidSerumProgram
+    #[error("InvalidSerumProgram")]
+    InvalidSerumProgram = 6136,
+    /// InvalidSerumMarket
+    #[error("InvalidSerumMarket")]
+    InvalidSerumMarket = 6137,
+    /// InvalidSerumBids
+    #[error("InvalidSerumBids")]
+    InvalidSerumBids = 6138,
+    /// InvalidSerumAsks
+    #[error("InvalidSerumAsks")]
+    InvalidSerumAsks = 6139,
+    /// InvalidSerumOpenOrders
+    #[error("InvalidSerumOpenOrders")]
+    InvalidSerumOpenOrders = 6140,
+    /// FailedSerumCPI
+    #[error("FailedSerumCPI")]
+    FailedSerumCpi = 6141,
+    /// FailedToFillOnExternalMarket
+    #[error("FailedToFillOnExternalMarket")]
+    FailedToFillOnExternalMarket = 6142,
+    /// InvalidFulfillmentConfig
+    #[error("InvalidFulfillmentConfig")]
+    InvalidFulfillmentConfig = 6143,
+    /// InvalidFeeStructure
+    #[error("InvalidFeeStructure")]
+    InvalidFeeStructure = 6144,
+    /// Insufficient IF shares
+    #[error("Insufficient IF shares")]
+    InsufficientIfShares = 6145,
+    /// the Market has paused this action
+    #[error("the Market has paused this action")]
+    MarketActionPaused = 6146,
+    /// the Market status doesnt allow placing orders
+    #[error("the Market status doesnt allow placing orders")]
+    MarketPlaceOrderPaused = 6147,
+    /// the Market status doesnt allow filling orders
+    #[error("the Market status doesnt allow filling orders")]
+    MarketFillOrderPaused = 6148,
+    /// the Market status doesnt allow withdraws
+    #[error("the Market status doesnt allow withdraws")]
+    MarketWithdrawPaused = 6149,
+    /// Action violates the Protected Asset Tier rules
+    #[error("Action violates the Protected Asset Tier rules")]
+    ProtectedAssetTierViolation = 6150,
+    /// Action violates the Isolated Asset Tier rules
+    #[error("Action violates the Isolated Asset Tier rules")]
+    IsolatedAssetTierViolation = 6151,
+    /// User Cant Be Deleted
+    #[error("User Cant Be Deleted")]
+    UserCantBeDeleted = 6152,
+    /// Reduce Only Withdraw Increased Risk
+    #[error("Reduce Only Withdraw Increased Risk")]
+    ReduceOnlyWithdrawIncreasedRisk = 6153,
+    /// Max Open Interest
+    #[error("Max Open Interest")]
+    MaxOpenInterest = 6154,
+    /// Cant Resolve Perp Bankruptcy
+    #[error("Cant Resolve Perp Bankruptcy")]
+    CantResolvePerpBankruptcy = 6155,
+    /// Liquidation Doesnt Satisfy Limit Price
+    #[error("Liquidation Doesnt Satisfy Limit Price")]
+    LiquidationDoesntSatisfyLimitPrice = 6156,
+    /// Margin Trading Disabled
+    #[error("Margin Trading Disabled")]
+    MarginTradingDisabled = 6157,
+    /// Invalid Market Status to Settle Perp Pnl
+    #[error("Invalid Market Status to Settle Perp Pnl")]
+    InvalidMarketStatusToSettlePnl = 6158,
+    /// PerpMarketNotInSettlement
+    #[error("PerpMarketNotInSettlement")]
+    PerpMarketNotInSettlement = 6159,
+    /// PerpMarketNotInReduceOnly
+    #[error("PerpMarketNotInReduceOnly")]
+    PerpMarketNotInReduceOnly = 6160,
+    /// PerpMarketSettlementBufferNotReached
+    #[error("PerpMarketSettlementBufferNotReached")]
+    PerpMarketSettlementBufferNotReached = 6161,
+    /// PerpMarketSettlementUserHasOpenOrders
+    #[error("PerpMarketSettlementUserHasOpenOrders")]
+    PerpMarketSettlementUserHasOpenOrders = 6162,
+    /// PerpMarketSettlementUserHasActiveLP
+    #[error("PerpMarketSettlementUserHasActiveLP")]
+    PerpMarketSettlementUserHasActiveLp = 6163,
+    /// UnableToSettleExpiredUserPosition
+    #[error("UnableToSettleExpiredUserPosition")]
+    UnableToSettleExpiredUserPosition = 6164,
+    /// UnequalMarketIndexForSpotTransfer
+    #[error("UnequalMarketIndexForSpotTransfer")]
+    UnequalMarketIndexForSpotTransfer = 6165,
+    /// InvalidPerpPositionDetected
+    #[error("InvalidPerpPositionDetected")]
+    InvalidPerpPositionDetected = 6166,
+    /// InvalidSpotPositionDetected
+    #[error("InvalidSpotPositionDetected")]
+    InvalidSpotPositionDetected = 6167,
+    /// InvalidAmmDetected
+    #[error("InvalidAmmDetected")]
+    InvalidAmmDetected = 6168,
+    /// InvalidAmmForFillDetected
+    #[error("InvalidAmmForFillDetected")]
+    InvalidAmmForFillDetected = 6169,
+    /// InvalidAmmLimitPriceOverride
+    #[error("InvalidAmmLimitPriceOverride")]
+    InvalidAmmLimitPriceOverride = 6170,
+    /// InvalidOrderFillPrice
+    #[error("InvalidOrderFillPrice")]
+    InvalidOrderFillPrice = 6171,
+    /// SpotMarketBalanceInvariantViolated
+    #[error("SpotMarketBalanceInvariantViolated")]
+    SpotMarketBalanceInvariantViolated = 6172,
+    /// SpotMarketVaultInvariantViolated
+    #[error("SpotMarketVaultInvariantViolated")]
+    SpotMarketVaultInvariantViolated = 6173,
+    /// InvalidPDA
+    #[error("InvalidPDA")]
+    InvalidPda = 6174,
+    /// InvalidPDASigner
+    #[error("InvalidPDASigner")]
+    InvalidPdaSigner = 6175,
+    /// RevenueSettingsCannotSettleToIF
+    #[error("RevenueSettingsCannotSettleToIF")]
+    RevenueSettingsCannotSettleToIf = 6176,
+    /// NoRevenueToSettleToIF
+    #[error("NoRevenueToSettleToIF")]
+    NoRevenueToSettleToIf = 6177,
+    /// NoAmmPerpPnlDeficit
+    #[error("NoAmmPerpPnlDeficit")]
+    NoAmmPerpPnlDeficit = 6178,
+    /// SufficientPerpPnlPool
+    #[error("SufficientPerpPnlPool")]
+    SufficientPerpPnlPool = 6179,
+    /// InsufficientPerpPnlPool
+    #[error("InsufficientPerpPnlPool")]
+    InsufficientPerpPnlPool = 6180,
+    /// PerpPnlDeficitBelowThreshold
+    #[error("PerpPnlDeficitBelowThreshold")]
+    PerpPnlDeficitBelowThreshold = 6181,
+    /// MaxRevenueWithdrawPerPeriodReached
+    #[error("MaxRevenueWithdrawPerPeriodReached")]
+    MaxRevenueWithdrawPerPeriodReached = 6182,
+    /// InvalidSpotPositionDetected
+    #[error("InvalidSpotPositionDetected")]
+    MaxIfWithdrawReached = 6183,
+    /// NoIFWithdrawAvailable
+    #[error("NoIFWithdrawAvailable")]
+    NoIfWithdrawAvailable = 6184,
+    /// InvalidIFUnstake
+    #[error("InvalidIFUnstake")]
+    InvalidIfUnstake = 6185,
+    /// InvalidIFUnstakeSize
+    #[error("InvalidIFUnstakeSize")]
+    InvalidIfUnstakeSize = 6186,
+    /// InvalidIFUnstakeCancel
+    #[error("InvalidIFUnstakeCancel")]
+    InvalidIfUnstakeCancel = 6187,
+    /// InvalidIFForNewStakes
+    #[error("InvalidIFForNewStakes")]
+    InvalidIfForNewStakes = 6188,
+    /// InvalidIFRebase
+    #[error("InvalidIFRebase")]
+    InvalidIfRebase = 6189,
+    /// InvalidInsuranceUnstakeSize
+    #[error("InvalidInsuranceUnstakeSize")]
+    InvalidInsuranceUnstakeSize = 6190,
+    /// InvalidOrderLimitPrice
+    #[error("InvalidOrderLimitPrice")]
+    InvalidOrderLimitPrice = 6191,
+    /// InvalidIFDetected
+    #[error("InvalidIFDetected")]
+    InvalidIfDetected = 6192,
+    /// InvalidAmmMaxSpreadDetected
+    #[error("InvalidAmmMaxSpreadDetected")]
+    InvalidAmmMaxSpreadDetected = 6193,
+    /// InvalidConcentrationCoef
+    #[error("InvalidConcentrationCoef")]
+    InvalidConcentrationCoef = 6194,
+    /// InvalidSrmVault
+    #[error("InvalidSrmVault")]
+    InvalidSrmVault = 6195,
+    /// InvalidVaultOwner
+    #[error("InvalidVaultOwner")]
+    InvalidVaultOwner = 6196,
+    /// InvalidMarketStatusForFills
+    #[error("InvalidMarketStatusForFills")]
+    InvalidMarketStatusForFills = 6197,
+    /// IFWithdrawRequestInProgress
+    #[error("IFWithdrawRequestInProgress")]
+    IfWithdrawRequestInProgress = 6198,
+    /// NoIFWithdrawRequestInProgress
+    #[error("NoIFWithdrawRequestInProgress")]
+    NoIfWithdrawRequestInProgress = 6199,
+    /// IFWithdrawRequestTooSmall
+    #[error("IFWithdrawRequestTooSmall")]
+    IfWithdrawRequestTooSmall = 6200,
+    /// IncorrectSpotMarketAccountPassed
+    #[error("IncorrectSpotMarketAccountPassed")]
+    IncorrectSpotMarketAccountPassed = 6201,
+    /// BlockchainClockInconsistency
+    #[error("BlockchainClockInconsistency")]
+    BlockchainClockInconsistency = 6202,
+    /// InvalidIFSharesDetected
+    #[error("InvalidIFSharesDetected")]
+    InvalidIfSharesDetected = 6203,
+    /// NewLPSizeTooSmall
+    #[error("NewLPSizeTooSmall")]
+    NewLpSizeTooSmall = 6204,
+    /// MarketStatusInvalidForNewLP
+    #[error("MarketStatusInvalidForNewLP")]
+    MarketStatusInvalidForNewLp = 6205,
+    /// InvalidMarkTwapUpdateDetected
+    #[error("InvalidMarkTwapUpdateDetected")]
+    InvalidMarkTwapUpdateDetected = 6206,
+    /// MarketSettlementAttemptOnActiveMarket
+    #[error("MarketSettlementAttemptOnActiveMarket")]
+    MarketSettlementAttemptOnActiveMarket = 6207,
+    /// MarketSettlementRequiresSettledLP
+    #[error("MarketSettlementRequiresSettledLP")]
+    MarketSettlementRequiresSettledLp = 6208,
+    /// MarketSettlementAttemptTooEarly
+    #[error("MarketSettlementAttemptTooEarly")]
+    MarketSettlementAttemptTooEarly = 6209,
+    /// MarketSettlementTargetPriceInvalid
+    #[error("MarketSettlementTargetPriceInvalid")]
+    MarketSettlementTargetPriceInvalid = 6210,
+    /// UnsupportedSpotMarket
+    #[error("UnsupportedSpotMarket")]
+    UnsupportedSpotMarket = 6211,
+    /// SpotOrdersDisabled
+    #[error("SpotOrdersDisabled")]
+    SpotOrdersDisabled = 6212,
+    /// Market Being Initialized
+    #[error("Market Being Initialized")]
+    MarketBeingInitialized = 6213,
+    /// Invalid Sub Account Id
+    #[error("Invalid Sub Account Id")]
+    InvalidUserSubAccountId = 6214,
+    /// Invalid Trigger Order Condition
+    #[error("Invalid Trigger Order Condition")]
+    InvalidTriggerOrderCondition = 6215,
+    /// Invalid Spot Position
+    #[error("Invalid Spot Position")]
+    InvalidSpotPosition = 6216,
+    /// Cant transfer between same user account
+    #[error("Cant transfer between same user account")]
+    CantTransferBetweenSameUserAccount = 6217,
+    /// Invalid Perp Position
+    #[error("Invalid Perp Position")]
+    InvalidPerpPosition = 6218,
+    /// Unable To Get Limit Price
+    #[error("Unable To Get Limit Price")]
+    UnableToGetLimitPrice = 6219,
+    /// Invalid Liquidation
+    #[error("Invalid Liquidation")]
+    InvalidLiquidation = 6220,
+    /// Spot Fulfullment Config Disabled
+    #[error("Spot Fulfullment Config Disabled")]
+    SpotFulfillmentConfigDisabled = 6221,
+    /// Invalid Maker
+    #[error("Invalid Maker")]
+    InvalidMaker = 6222,
+    /// Failed Unwrap
+    #[error("Failed Unwrap")]
+    FailedUnwrap = 6223,
+    /// Max Number Of Users
+    #[error("Max Number Of Users")]
+    MaxNumberOfUsers = 6224,
+    /// InvalidOracleForSettlePnl
+    #[error("InvalidOracleForSettlePnl")]
+    InvalidOracleForSettlePnl = 6225,
+    /// MarginOrdersOpen
+    #[error("MarginOrdersOpen")]
+    MarginOrdersOpen = 6226,
+    /// TierViolationLiquidatingPerpPnl
+    #[error("TierViolationLiquidatingPerpPnl")]
+    TierViolationLiquidatingPerpPnl = 6227,
+    /// CouldNotLoadUserData
+    #[error("CouldNotLoadUserData")]
+    CouldNotLoadUserData = 6228,
+    /// UserWrongMutability
+    #[error("UserWrongMutability")]
+    UserWrongMutability = 6229,
+    /// InvalidUserAccount
+    #[error("InvalidUserAccount")]
+    InvalidUserAccount = 6230,
+    /// CouldNotLoadUserData
+    #[error("CouldNotLoadUserData")]
+    CouldNotLoadUserStatsData = 6231,
+    /// UserWrongMutability
+    #[error("UserWrongMutability")]
+    UserStatsWrongMutability = 6232,
+    /// InvalidUserAccount
+    #[error("InvalidUserAccount")]
+    InvalidUserStatsAccount = 6233,
+    /// UserNotFound
+    #[error("UserNotFound")]
+    UserNotFound = 6234,
+    /// UnableToLoadUserAccount
+    #[error("UnableToLoadUserAccount")]
+    UnableToLoadUserAccount = 6235,
+    /// UserStatsNotFound
+    #[error("UserStatsNotFound")]
+    UserStatsNotFound = 6236,
+    /// UnableToLoadUserStatsAccount
+    #[error("UnableToLoadUserStatsAccount")]
+    UnableToLoadUserStatsAccount = 6237,
+    /// User Not Inactive
+    #[error("User Not Inactive")]
+    UserNotInactive = 6238,
+    /// RevertFill
+    #[error("RevertFill")]
+    RevertFill = 6239,
+    /// Invalid MarketAccount for Deletion
+    #[error("Invalid MarketAccount for Deletion")]
+    InvalidMarketAccountforDeletion = 6240,
+    /// Invalid Spot Fulfillment Params
+    #[error("Invalid Spot Fulfillment Params")]
+    InvalidSpotFulfillmentParams = 6241,
+    /// Failed to Get Mint
+    #[error("Failed to Get Mint")]
+    FailedToGetMint = 6242,
+    /// FailedPhoenixCPI
+    #[error("FailedPhoenixCPI")]
+    FailedPhoenixCpi = 6243,
+    /// FailedToDeserializePhoenixMarket
+    #[error("FailedToDeserializePhoenixMarket")]
+    FailedToDeserializePhoenixMarket = 6244,
+    /// InvalidPricePrecision
+    #[error("InvalidPricePrecision")]
+    InvalidPricePrecision = 6245,
+    /// InvalidPhoenixProgram
+    #[error("InvalidPhoenixProgram")]
+    InvalidPhoenixProgram = 6246,
+    /// InvalidPhoenixMarket
+    #[error("InvalidPhoenixMarket")]
+    InvalidPhoenixMarket = 6247,
+    /// InvalidSwap
+    #[error("InvalidSwap")]
+    InvalidSwap = 6248,
+    /// SwapLimitPriceBreached
+    #[error("SwapLimitPriceBreached")]
+    SwapLimitPriceBreached = 6249,
+    /// SpotMarketReduceOnly
+    #[error("SpotMarketReduceOnly")]
+    SpotMarketReduceOnly = 6250,
+    /// FundingWasNotUpdated
+    #[error("FundingWasNotUpdated")]
+    FundingWasNotUpdated = 6251,
+}
+impl From<DriftError> for ProgramError {
+    fn from(e: DriftError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for DriftError {
+    fn type_of() -> &'static str {
+        "DriftError"
+    }
+}
+impl PrintProgramError for DriftError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
+impl TryFrom<u32> for DriftError {
+    type Error = u32;
+    /// Returns `code` back if it is not one of this program's errors
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        <Self as num_traits::FromPrimitive>::from_u32(code).ok_or(code)
+    }
+}
+impl<'a> TryFrom<&'a ProgramError> for DriftError {
+    type Error = &'a ProgramError;
+    /// Returns `e` back if it is not a `ProgramError::Custom` of this program's errors
+    fn try_from(e: &'a ProgramError) -> Result<Self, Self::Error> {
+        match e {
+            ProgramError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl<'a> TryFrom<&'a InstructionError> for DriftError {
+    type Error = &'a InstructionError;
+    /// Returns `e` back if it is not an `InstructionError::Custom` of this program's errors
+    fn try_from(e: &'a InstructionError) -> Result<Self, Self::Error> {
+        match e {
+            InstructionError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl DriftError {
+    /// Parses a `custom program error: 0x...` or `AnchorError ... Error Number: N.` log line
+    /// into the error, e.g. `Program <id> failed: custom program error: 0x1770`.
+    /// Returns `None` for other logs and codes that are not of this program's errors.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let code = if let Some((_, hex)) = log.split_once("custom program error: 0x") {
+            u32::from_str_radix(hex.trim_end(), 16).ok()?
+        } else if let Some((_, rest)) = log
+            .split_once("AnchorError ")
+            .and_then(|(_, rest)| rest.split_once("Error Number: "))
+        {
+            rest.split('.').next()?.parse().ok()?
+        } else {
+            return None;
+        };
+        Self::try_from(code).ok()
+    }
+}
+/// Parses the error the program failed with from a transaction's log messages.
+///
+/// Only the first `Program <id> failed: ` log is considered, since programs that
+/// invoked the failing program log the same error when failing in turn.
+/// Returns `None` if the failing program is not `program_id`
+/// or it did not fail with one of its errors.
+pub fn parse_error_from_logs_with_program_id(
+    program_id: Pubkey,
+    logs: &[String],
+) -> Option<DriftError> {
+    let (program, failed_log) = logs.iter().find_map(|log| {
+        let mut words = log.strip_prefix("Program ")?.split(' ');
+        let program = words.next()?.parse::<Pubkey>().ok()?;
+        (words.next() == Some("failed:")).then_some((program, log))
+    })?;
+    if program != program_id {
+        return None;
+    }
+    DriftError::from_log(failed_log)
+}
+pub fn parse_error_from_logs(logs: &[String]) -> Option<DriftError> {
+    parse_error_from_logs_with_program_id(crate::ID, logs)
+}
@@ -0,0 +1,706 @@
+use crate::*;
+use base64::Engine;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+pub const NEW_USER_RECORD_EVENT_DISCM: [u8; 8] = [236, 186, 113, 219, 42, 51, 149, 249];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NewUserRecord {
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub user: Pubkey,
+    pub sub_account_id: u16,
+    pub name: [u8; 32],
+    pub referrer: Pubkey,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewUserRecordEvent(pub NewUserRecord);
+impl BorshSerialize for NewUserRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        NEW_USER_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl NewUserRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != NEW_USER_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    NEW_USER_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(NewUserRecord::deserialize(buf)?))
+    }
+}
+pub const DEPOSIT_RECORD_EVENT_DISCM: [u8; 8] = [180, 241, 218, 207, 102, 135, 44, 134];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositRecord {
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub user: Pubkey,
+    pub direction: DepositDirection,
+    pub deposit_record_id: u64,
+    pub amount: u64,
+    pub market_index: u16,
+    pub oracle_price: i64,
+    pub market_deposit_balance: u128,
+    pub market_withdraw_balance: u128,
+    pub market_cumulative_deposit_interest: u128,
+    pub market_cumulative_borrow_interest: u128,
+    pub total_deposits_after: u64,
+    pub total_withdraws_after: u64,
+    pub explanation: DepositExplanation,
+    pub transfer_user: Option<Pubkey>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepositRecordEvent(pub DepositRecord);
+impl BorshSerialize for DepositRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        DEPOSIT_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl DepositRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != DEPOSIT_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    DEPOSIT_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(DepositRecord::deserialize(buf)?))
+    }
+}
+pub const SPOT_INTEREST_RECORD_EVENT_DISCM: [u8; 8] = [183, 186, 203, 186, 225, 187, 95, 130];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpotInterestRecord {
+    pub ts: i64,
+    pub market_index: u16,
+    pub deposit_balance: u128,
+    pub cumulative_deposit_interest: u128,
+    pub borrow_balance: u128,
+    pub cumulative_borrow_interest: u128,
+    pub optimal_utilization: u32,
+    pub optimal_borrow_rate: u32,
+    pub max_borrow_rate: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpotInterestRecordEvent(pub SpotInterestRecord);
+impl BorshSerialize for SpotInterestRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        SPOT_INTEREST_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl SpotInterestRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != SPOT_INTEREST_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SPOT_INTEREST_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SpotInterestRecord::deserialize(buf)?))
+    }
+}
+pub const FUNDING_PAYMENT_RECORD_EVENT_DISCM: [u8; 8] = [8, 59, 96, 20, 137, 201, 56, 95];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FundingPaymentRecord {
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub user: Pubkey,
+    pub market_index: u16,
+    pub funding_payment: i64,
+    pub base_asset_amount: i64,
+    pub user_last_cumulative_funding: i64,
+    pub amm_cumulative_funding_long: i128,
+    pub amm_cumulative_funding_short: i128,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct FundingPaymentRecordEvent(pub FundingPaymentRecord);
+impl BorshSerialize for FundingPaymentRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        FUNDING_PAYMENT_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl FundingPaymentRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != FUNDING_PAYMENT_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    FUNDING_PAYMENT_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(FundingPaymentRecord::deserialize(buf)?))
+    }
+}
+pub const FUNDING_RATE_RECORD_EVENT_DISCM: [u8; 8] = [68, 3, 255, 26, 133, 91, 147, 254];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FundingRateRecord {
+    pub ts: i64,
+    pub record_id: u64,
+    pub market_index: u16,
+    pub funding_rate: i64,
+    pub funding_rate_long: i128,
+    pub funding_rate_short: i128,
+    pub cumulative_funding_rate_long: i128,
+    pub cumulative_funding_rate_short: i128,
+    pub oracle_price_twap: i64,
+    pub mark_price_twap: u64,
+    pub period_revenue: i64,
+    pub base_asset_amount_with_amm: i128,
+    pub base_asset_amount_with_unsettled_lp: i128,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct FundingRateRecordEvent(pub FundingRateRecord);
+impl BorshSerialize for FundingRateRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        FUNDING_RATE_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl FundingRateRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != FUNDING_RATE_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    FUNDING_RATE_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(FundingRateRecord::deserialize(buf)?))
+    }
+}
+pub const CURVE_RECORD_EVENT_DISCM: [u8; 8] = [101, 238, 40, 228, 70, 46, 61, 117];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CurveRecord {
+    pub ts: i64,
+    pub record_id: u64,
+    pub peg_multiplier_before: u128,
+    pub base_asset_reserve_before: u128,
+    pub quote_asset_reserve_before: u128,
+    pub sqrt_kbefore: u128,
+    pub peg_multiplier_after: u128,
+    pub base_asset_reserve_after: u128,
+    pub quote_asset_reserve_after: u128,
+    pub sqrt_kafter: u128,
+    pub base_asset_amount_long: u128,
+    pub base_asset_amount_short: u128,
+    pub base_asset_amount_with_amm: i128,
+    pub total_fee: i128,
+    pub total_fee_minus_distributions: i128,
+    pub adjustment_cost: i128,
+    pub oracle_price: i64,
+    pub fill_record: u128,
+    pub number_of_users: u32,
+    pub market_index: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveRecordEvent(pub CurveRecord);
+impl BorshSerialize for CurveRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        CURVE_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl CurveRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != CURVE_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    CURVE_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(CurveRecord::deserialize(buf)?))
+    }
+}
+pub const ORDER_RECORD_EVENT_DISCM: [u8; 8] = [104, 19, 64, 56, 89, 21, 2, 90];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderRecord {
+    pub ts: i64,
+    pub user: Pubkey,
+    pub order: Order,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderRecordEvent(pub OrderRecord);
+impl BorshSerialize for OrderRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        ORDER_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl OrderRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != ORDER_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ORDER_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(OrderRecord::deserialize(buf)?))
+    }
+}
+pub const ORDER_ACTION_RECORD_EVENT_DISCM: [u8; 8] = [224, 52, 67, 71, 194, 237, 109, 1];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderActionRecord {
+    pub ts: i64,
+    pub action: OrderAction,
+    pub action_explanation: OrderActionExplanation,
+    pub market_index: u16,
+    pub market_type: MarketType,
+    pub filler: Option<Pubkey>,
+    pub filler_reward: Option<u64>,
+    pub fill_record_id: Option<u64>,
+    pub base_asset_amount_filled: Option<u64>,
+    pub quote_asset_amount_filled: Option<u64>,
+    pub taker_fee: Option<u64>,
+    pub maker_fee: Option<i64>,
+    pub referrer_reward: Option<u32>,
+    pub quote_asset_amount_surplus: Option<i64>,
+    pub spot_fulfillment_method_fee: Option<u64>,
+    pub taker: Option<Pubkey>,
+    pub taker_order_id: Option<u32>,
+    pub taker_order_direction: Option<PositionDirection>,
+    pub taker_order_base_asset_amount: Option<u64>,
+    pub taker_order_cumulative_base_asset_amount_filled: Option<u64>,
+    pub taker_order_cumulative_quote_asset_amount_filled: Option<u64>,
+    pub maker: Option<Pubkey>,
+    pub maker_order_id: Option<u32>,
+    pub maker_order_direction: Option<PositionDirection>,
+    pub maker_order_base_asset_amount: Option<u64>,
+    pub maker_order_cumulative_base_asset_amount_filled: Option<u64>,
+    pub maker_order_cumulative_quote_asset_amount_filled: Option<u64>,
+    pub oracle_price: i64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderActionRecordEvent(pub OrderActionRecord);
+impl BorshSerialize for OrderActionRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        ORDER_ACTION_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl OrderActionRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != ORDER_ACTION_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ORDER_ACTION_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(OrderActionRecord::deserialize(buf)?))
+    }
+}
+pub const LP_RECORD_EVENT_DISCM: [u8; 8] = [101, 22, 54, 38, 178, 13, 142, 111];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LPRecord {
+    pub ts: i64,
+    pub user: Pubkey,
+    pub action: LPAction,
+    pub n_shares: u64,
+    pub market_index: u16,
+    pub delta_base_asset_amount: i64,
+    pub delta_quote_asset_amount: i64,
+    pub pnl: i64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct LPRecordEvent(pub LPRecord);
+impl BorshSerialize for LPRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        LP_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl LPRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != LP_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    LP_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(LPRecord::deserialize(buf)?))
+    }
+}
+pub const LIQUIDATION_RECORD_EVENT_DISCM: [u8; 8] = [127, 17, 0, 108, 182, 13, 231, 53];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidationRecord {
+    pub ts: i64,
+    pub liquidation_type: LiquidationType,
+    pub user: Pubkey,
+    pub liquidator: Pubkey,
+    pub margin_requirement: u128,
+    pub total_collateral: i128,
+    pub margin_freed: u64,
+    pub liquidation_id: u16,
+    pub bankrupt: bool,
+    pub canceled_order_ids: Vec<u32>,
+    pub liquidate_perp: LiquidatePerpRecord,
+    pub liquidate_spot: LiquidateSpotRecord,
+    pub liquidate_borrow_for_perp_pnl: LiquidateBorrowForPerpPnlRecord,
+    pub liquidate_perp_pnl_for_deposit: LiquidatePerpPnlForDepositRecord,
+    pub perp_bankruptcy: PerpBankruptcyRecord,
+    pub spot_bankruptcy: SpotBankruptcyRecord,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiquidationRecordEvent(pub LiquidationRecord);
+impl BorshSerialize for LiquidationRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        LIQUIDATION_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl LiquidationRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != LIQUIDATION_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    LIQUIDATION_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(LiquidationRecord::deserialize(buf)?))
+    }
+}
+pub const SETTLE_PNL_RECORD_EVENT_DISCM: [u8; 8] = [57, 68, 105, 26, 119, 198, 213, 89];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SettlePnlRecord {
+    pub ts: i64,
+    pub user: Pubkey,
+    pub market_index: u16,
+    pub pnl: i128,
+    pub base_asset_amount: i64,
+    pub quote_asset_amount_after: i64,
+    pub quote_entry_amount: i64,
+    pub settle_price: i64,
+    pub explanation: SettlePnlExplanation,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettlePnlRecordEvent(pub SettlePnlRecord);
+impl BorshSerialize for SettlePnlRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        SETTLE_PNL_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl SettlePnlRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != SETTLE_PNL_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SETTLE_PNL_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SettlePnlRecord::deserialize(buf)?))
+    }
+}
+pub const INSURANCE_FUND_RECORD_EVENT_DISCM: [u8; 8] = [56, 222, 215, 235, 78, 197, 99, 146];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceFundRecord {
+    pub ts: i64,
+    pub spot_market_index: u16,
+    pub perp_market_index: u16,
+    pub user_if_factor: u32,
+    pub total_if_factor: u32,
+    pub vault_amount_before: u64,
+    pub insurance_vault_amount_before: u64,
+    pub total_if_shares_before: u128,
+    pub total_if_shares_after: u128,
+    pub amount: i64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InsuranceFundRecordEvent(pub InsuranceFundRecord);
+impl BorshSerialize for InsuranceFundRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        INSURANCE_FUND_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl InsuranceFundRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != INSURANCE_FUND_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INSURANCE_FUND_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(InsuranceFundRecord::deserialize(buf)?))
+    }
+}
+pub const INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM: [u8; 8] = [68, 66, 156, 7, 216, 148, 250, 114];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceFundStakeRecord {
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub action: StakeAction,
+    pub amount: u64,
+    pub market_index: u16,
+    pub insurance_vault_amount_before: u64,
+    pub if_shares_before: u128,
+    pub user_if_shares_before: u128,
+    pub total_if_shares_before: u128,
+    pub if_shares_after: u128,
+    pub user_if_shares_after: u128,
+    pub total_if_shares_after: u128,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InsuranceFundStakeRecordEvent(pub InsuranceFundStakeRecord);
+impl BorshSerialize for InsuranceFundStakeRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl InsuranceFundStakeRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(InsuranceFundStakeRecord::deserialize(buf)?))
+    }
+}
+pub const SWAP_RECORD_EVENT_DISCM: [u8; 8] = [162, 187, 123, 194, 138, 56, 250, 241];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapRecord {
+    pub ts: i64,
+    pub user: Pubkey,
+    pub amount_out: u64,
+    pub amount_in: u64,
+    pub out_market_index: u16,
+    pub in_market_index: u16,
+    pub out_oracle_price: i64,
+    pub in_oracle_price: i64,
+    pub fee: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapRecordEvent(pub SwapRecord);
+impl BorshSerialize for SwapRecordEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        SWAP_RECORD_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl SwapRecordEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != SWAP_RECORD_EVENT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SWAP_RECORD_EVENT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SwapRecord::deserialize(buf)?))
+    }
+}
+#[derive(Clone, Debug, PartialEq)]
+pub enum DriftEvent {
+    NewUserRecord(NewUserRecord),
+    DepositRecord(DepositRecord),
+    SpotInterestRecord(SpotInterestRecord),
+    FundingPaymentRecord(FundingPaymentRecord),
+    FundingRateRecord(FundingRateRecord),
+    CurveRecord(CurveRecord),
+    OrderRecord(OrderRecord),
+    OrderActionRecord(OrderActionRecord),
+    LPRecord(LPRecord),
+    LiquidationRecord(LiquidationRecord),
+    SettlePnlRecord(SettlePnlRecord),
+    InsuranceFundRecord(InsuranceFundRecord),
+    InsuranceFundStakeRecord(InsuranceFundStakeRecord),
+    SwapRecord(SwapRecord),
+}
+impl DriftEvent {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let maybe_discm = <[u8; 8]>::deserialize(&mut reader)?;
+        match maybe_discm {
+            NEW_USER_RECORD_EVENT_DISCM => Ok(Self::NewUserRecord(NewUserRecord::deserialize(
+                &mut reader,
+            )?)),
+            DEPOSIT_RECORD_EVENT_DISCM => Ok(Self::DepositRecord(DepositRecord::deserialize(
+                &mut reader,
+            )?)),
+            SPOT_INTEREST_RECORD_EVENT_DISCM => Ok(Self::SpotInterestRecord(
+                SpotInterestRecord::deserialize(&mut reader)?,
+            )),
+            FUNDING_PAYMENT_RECORD_EVENT_DISCM => Ok(Self::FundingPaymentRecord(
+                FundingPaymentRecord::deserialize(&mut reader)?,
+            )),
+            FUNDING_RATE_RECORD_EVENT_DISCM => Ok(Self::FundingRateRecord(
+                FundingRateRecord::deserialize(&mut reader)?,
+            )),
+            CURVE_RECORD_EVENT_DISCM => {
+                Ok(Self::CurveRecord(CurveRecord::deserialize(&mut reader)?))
+            }
+            ORDER_RECORD_EVENT_DISCM => {
+                Ok(Self::OrderRecord(OrderRecord::deserialize(&mut reader)?))
+            }
+            ORDER_ACTION_RECORD_EVENT_DISCM => Ok(Self::OrderActionRecord(
+                OrderActionRecord::deserialize(&mut reader)?,
+            )),
+            LP_RECORD_EVENT_DISCM => Ok(Self::LPRecord(LPRecord::deserialize(&mut reader)?)),
+            LIQUIDATION_RECORD_EVENT_DISCM => Ok(Self::LiquidationRecord(
+                LiquidationRecord::deserialize(&mut reader)?,
+            )),
+            SETTLE_PNL_RECORD_EVENT_DISCM => Ok(Self::SettlePnlRecord(
+                SettlePnlRecord::deserialize(&mut reader)?,
+            )),
+            INSURANCE_FUND_RECORD_EVENT_DISCM => Ok(Self::InsuranceFundRecord(
+                InsuranceFundRecord::deserialize(&mut reader)?,
+            )),
+            INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM => Ok(Self::InsuranceFundStakeRecord(
+                InsuranceFundStakeRecord::deserialize(&mut reader)?,
+            )),
+            SWAP_RECORD_EVENT_DISCM => Ok(Self::SwapRecord(SwapRecord::deserialize(&mut reader)?)),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+}
+/// Parses the events emitted by the program from a transaction's log messages.
+///
+/// `Program data: ` logs are attributed to the program at the top of the invoke stack.
+/// Logs that are not a base64-encoded event of the program are skipped.
+pub fn parse_events_from_logs_with_program_id(
+    program_id: Pubkey,
+    logs: &[String],
+) -> Vec<DriftEvent> {
+    let mut invoke_stack: Vec<Pubkey> = Vec::new();
+    let mut events = Vec::new();
+    for log in logs {
+        if let Some(data) = log.strip_prefix("Program data: ") {
+            if invoke_stack.last() != Some(&program_id) {
+                continue;
+            }
+            let event = base64::engine::general_purpose::STANDARD
+                .decode(data)
+                .ok()
+                .and_then(|data| DriftEvent::deserialize(&data).ok());
+            events.extend(event);
+        } else if let Some(rest) = log.strip_prefix("Program ") {
+            let mut words = rest.split(' ');
+            let program = match words.next().map(|w| w.parse::<Pubkey>()) {
+                Some(Ok(program)) => program,
+                _ => continue,
+            };
+            match words.next() {
+                Some("invoke") => invoke_stack.push(program),
+                Some("success" | "failed:") => {
+                    invoke_stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+    events
+}
+pub fn parse_events_from_logs(logs: &[String]) -> Vec<DriftEvent> {
+    parse_events_from_logs_with_program_id(crate::ID, logs)
+}
+/// Prefix of the instruction data of the self-invocations `emit_cpi!()` emits events with
+pub const EVENT_IX_TAG_LE: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+impl DriftEvent {
+    /// Decodes the event from the data of an `emit_cpi!()` self-invocation
+    pub fn from_cpi_ix_data(data: &[u8]) -> std::io::Result<Self> {
+        let mut reader = data;
+        let maybe_tag = <[u8; 8]>::deserialize(&mut reader)?;
+        if maybe_tag != EVENT_IX_TAG_LE {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "event ix tag does not match. Expected: {:?}. Received: {:?}",
+                    EVENT_IX_TAG_LE, maybe_tag
+                ),
+            ));
+        }
+        Self::deserialize(reader)
+    }
+}
+/// Decodes the events emitted with `emit_cpi!()` from a transaction's inner instructions.
+///
+/// Instructions that are not `emit_cpi!()` self-invocations of the program are skipped.
+pub fn parse_cpi_events_with_program_id(
+    program_id: Pubkey,
+    inner_ixs: &[Instruction],
+) -> Vec<DriftEvent> {
+    inner_ixs
+        .iter()
+        .filter(|ix| ix.program_id == program_id)
+        .filter_map(|ix| DriftEvent::from_cpi_ix_data(&ix.data).ok())
+        .collect()
+}
+pub fn parse_cpi_events(inner_ixs: &[Instruction]) -> Vec<DriftEvent> {
+    parse_cpi_events_with_program_id(crate::ID, inner_ixs)
+}
@@ -0,0 +1,1431 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderParams {
+    pub order_type: OrderType,
+    pub market_type: MarketType,
+    pub direction: PositionDirection,
+    pub user_order_id: u8,
+    pub base_asset_amount: u64,
+    pub price: u64,
+    pub market_index: u16,
+    pub reduce_only: bool,
+    pub post_only: PostOnlyParam,
+    pub immediate_or_cancel: bool,
+    pub max_ts: Option<i64>,
+    pub trigger_price: Option<u64>,
+    pub trigger_condition: OrderTriggerCondition,
+    pub oracle_price_offset: Option<i32>,
+    pub auction_duration: Option<u8>,
+    pub auction_start_price: Option<i64>,
+    pub auction_end_price: Option<i64>,
+}
+/// Offset of `order_type` in serialized [`OrderParams`]
+pub const ORDER_PARAMS_ORDER_TYPE_OFFSET: usize = 0;
+/// Offset of `market_type` in serialized [`OrderParams`]
+pub const ORDER_PARAMS_MARKET_TYPE_OFFSET: usize = 1;
+/// Offset of `direction` in serialized [`OrderParams`]
+pub const ORDER_PARAMS_DIRECTION_OFFSET: usize = 2;
+/// Offset of `user_order_id` in serialized [`OrderParams`]
+pub const ORDER_PARAMS_USER_ORDER_ID_OFFSET: usize = 3;
+/// Offset of `base_asset_amount` in serialized [`OrderParams`]
+pub const ORDER_PARAMS_BASE_ASSET_AMOUNT_OFFSET: usize = 4;
+/// Offset of `price` in serialized [`OrderParams`]
+pub const ORDER_PARAMS_PRICE_OFFSET: usize = 12;
+/// Offset of `market_index` in serialized [`OrderParams`]
+pub const ORDER_PARAMS_MARKET_INDEX_OFFSET: usize = 20;
+/// Offset of `reduce_only` in serialized [`OrderParams`]
+pub const ORDER_PARAMS_REDUCE_ONLY_OFFSET: usize = 22;
+/// Offset of `post_only` in serialized [`OrderParams`]
+pub const ORDER_PARAMS_POST_ONLY_OFFSET: usize = 23;
+/// Offset of `immediate_or_cancel` in serialized [`OrderParams`]
+pub const ORDER_PARAMS_IMMEDIATE_OR_CANCEL_OFFSET: usize = 24;
+/// Offset of `max_ts` in serialized [`OrderParams`]
+pub const ORDER_PARAMS_MAX_TS_OFFSET: usize = 25;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModifyOrderParams {
+    pub direction: Option<PositionDirection>,
+    pub base_asset_amount: Option<u64>,
+    pub price: Option<u64>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<PostOnlyParam>,
+    pub immediate_or_cancel: Option<bool>,
+    pub max_ts: Option<i64>,
+    pub trigger_price: Option<u64>,
+    pub trigger_condition: Option<OrderTriggerCondition>,
+    pub oracle_price_offset: Option<i32>,
+    pub auction_duration: Option<u8>,
+    pub auction_start_price: Option<i64>,
+    pub auction_end_price: Option<i64>,
+    pub policy: Option<ModifyOrderPolicy>,
+}
+/// Offset of `direction` in serialized [`ModifyOrderParams`]
+pub const MODIFY_ORDER_PARAMS_DIRECTION_OFFSET: usize = 0;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidatePerpRecord {
+    pub market_index: u16,
+    pub oracle_price: i64,
+    pub base_asset_amount: i64,
+    pub quote_asset_amount: i64,
+    pub lp_shares: u64,
+    pub fill_record_id: u64,
+    pub user_order_id: u32,
+    pub liquidator_order_id: u32,
+    pub liquidator_fee: u64,
+    pub if_fee: u64,
+}
+/// Serialized size of [`LiquidatePerpRecord`]
+pub const LIQUIDATE_PERP_RECORD_LEN: usize = 66;
+/// Offset of `market_index` in serialized [`LiquidatePerpRecord`]
+pub const LIQUIDATE_PERP_RECORD_MARKET_INDEX_OFFSET: usize = 0;
+/// Offset of `oracle_price` in serialized [`LiquidatePerpRecord`]
+pub const LIQUIDATE_PERP_RECORD_ORACLE_PRICE_OFFSET: usize = 2;
+/// Offset of `base_asset_amount` in serialized [`LiquidatePerpRecord`]
+pub const LIQUIDATE_PERP_RECORD_BASE_ASSET_AMOUNT_OFFSET: usize = 10;
+/// Offset of `quote_asset_amount` in serialized [`LiquidatePerpRecord`]
+pub const LIQUIDATE_PERP_RECORD_QUOTE_ASSET_AMOUNT_OFFSET: usize = 18;
+/// Offset of `lp_shares` in serialized [`LiquidatePerpRecord`]
+pub const LIQUIDATE_PERP_RECORD_LP_SHARES_OFFSET: usize = 26;
+/// Offset of `fill_record_id` in serialized [`LiquidatePerpRecord`]
+pub const LIQUIDATE_PERP_RECORD_FILL_RECORD_ID_OFFSET: usize = 34;
+/// Offset of `user_order_id` in serialized [`LiquidatePerpRecord`]
+pub const LIQUIDATE_PERP_RECORD_USER_ORDER_ID_OFFSET: usize = 42;
+/// Offset of `liquidator_order_id` in serialized [`LiquidatePerpRecord`]
+pub const LIQUIDATE_PERP_RECORD_LIQUIDATOR_ORDER_ID_OFFSET: usize = 46;
+/// Offset of `liquidator_fee` in serialized [`LiquidatePerpRecord`]
+pub const LIQUIDATE_PERP_RECORD_LIQUIDATOR_FEE_OFFSET: usize = 50;
+/// Offset of `if_fee` in serialized [`LiquidatePerpRecord`]
+pub const LIQUIDATE_PERP_RECORD_IF_FEE_OFFSET: usize = 58;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidateSpotRecord {
+    pub asset_market_index: u16,
+    pub asset_price: i64,
+    pub asset_transfer: u128,
+    pub liability_market_index: u16,
+    pub liability_price: i64,
+    pub liability_transfer: u128,
+    pub if_fee: u64,
+}
+/// Serialized size of [`LiquidateSpotRecord`]
+pub const LIQUIDATE_SPOT_RECORD_LEN: usize = 60;
+/// Offset of `asset_market_index` in serialized [`LiquidateSpotRecord`]
+pub const LIQUIDATE_SPOT_RECORD_ASSET_MARKET_INDEX_OFFSET: usize = 0;
+/// Offset of `asset_price` in serialized [`LiquidateSpotRecord`]
+pub const LIQUIDATE_SPOT_RECORD_ASSET_PRICE_OFFSET: usize = 2;
+/// Offset of `asset_transfer` in serialized [`LiquidateSpotRecord`]
+pub const LIQUIDATE_SPOT_RECORD_ASSET_TRANSFER_OFFSET: usize = 10;
+/// Offset of `liability_market_index` in serialized [`LiquidateSpotRecord`]
+pub const LIQUIDATE_SPOT_RECORD_LIABILITY_MARKET_INDEX_OFFSET: usize = 26;
+/// Offset of `liability_price` in serialized [`LiquidateSpotRecord`]
+pub const LIQUIDATE_SPOT_RECORD_LIABILITY_PRICE_OFFSET: usize = 28;
+/// Offset of `liability_transfer` in serialized [`LiquidateSpotRecord`]
+pub const LIQUIDATE_SPOT_RECORD_LIABILITY_TRANSFER_OFFSET: usize = 36;
+/// Offset of `if_fee` in serialized [`LiquidateSpotRecord`]
+pub const LIQUIDATE_SPOT_RECORD_IF_FEE_OFFSET: usize = 52;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidateBorrowForPerpPnlRecord {
+    pub perp_market_index: u16,
+    pub market_oracle_price: i64,
+    pub pnl_transfer: u128,
+    pub liability_market_index: u16,
+    pub liability_price: i64,
+    pub liability_transfer: u128,
+}
+/// Serialized size of [`LiquidateBorrowForPerpPnlRecord`]
+pub const LIQUIDATE_BORROW_FOR_PERP_PNL_RECORD_LEN: usize = 52;
+/// Offset of `perp_market_index` in serialized [`LiquidateBorrowForPerpPnlRecord`]
+pub const LIQUIDATE_BORROW_FOR_PERP_PNL_RECORD_PERP_MARKET_INDEX_OFFSET: usize = 0;
+/// Offset of `market_oracle_price` in serialized [`LiquidateBorrowForPerpPnlRecord`]
+pub const LIQUIDATE_BORROW_FOR_PERP_PNL_RECORD_MARKET_ORACLE_PRICE_OFFSET: usize = 2;
+/// Offset of `pnl_transfer` in serialized [`LiquidateBorrowForPerpPnlRecord`]
+pub const LIQUIDATE_BORROW_FOR_PERP_PNL_RECORD_PNL_TRANSFER_OFFSET: usize = 10;
+/// Offset of `liability_market_index` in serialized [`LiquidateBorrowForPerpPnlRecord`]
+pub const LIQUIDATE_BORROW_FOR_PERP_PNL_RECORD_LIABILITY_MARKET_INDEX_OFFSET: usize = 26;
+/// Offset of `liability_price` in serialized [`LiquidateBorrowForPerpPnlRecord`]
+pub const LIQUIDATE_BORROW_FOR_PERP_PNL_RECORD_LIABILITY_PRICE_OFFSET: usize = 28;
+/// Offset of `liability_transfer` in serialized [`LiquidateBorrowForPerpPnlRecord`]
+pub const LIQUIDATE_BORROW_FOR_PERP_PNL_RECORD_LIABILITY_TRANSFER_OFFSET: usize = 36;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidatePerpPnlForDepositRecord {
+    pub perp_market_index: u16,
+    pub market_oracle_price: i64,
+    pub pnl_transfer: u128,
+    pub asset_market_index: u16,
+    pub asset_price: i64,
+    pub asset_transfer: u128,
+}
+/// Serialized size of [`LiquidatePerpPnlForDepositRecord`]
+pub const LIQUIDATE_PERP_PNL_FOR_DEPOSIT_RECORD_LEN: usize = 52;
+/// Offset of `perp_market_index` in serialized [`LiquidatePerpPnlForDepositRecord`]
+pub const LIQUIDATE_PERP_PNL_FOR_DEPOSIT_RECORD_PERP_MARKET_INDEX_OFFSET: usize = 0;
+/// Offset of `market_oracle_price` in serialized [`LiquidatePerpPnlForDepositRecord`]
+pub const LIQUIDATE_PERP_PNL_FOR_DEPOSIT_RECORD_MARKET_ORACLE_PRICE_OFFSET: usize = 2;
+/// Offset of `pnl_transfer` in serialized [`LiquidatePerpPnlForDepositRecord`]
+pub const LIQUIDATE_PERP_PNL_FOR_DEPOSIT_RECORD_PNL_TRANSFER_OFFSET: usize = 10;
+/// Offset of `asset_market_index` in serialized [`LiquidatePerpPnlForDepositRecord`]
+pub const LIQUIDATE_PERP_PNL_FOR_DEPOSIT_RECORD_ASSET_MARKET_INDEX_OFFSET: usize = 26;
+/// Offset of `asset_price` in serialized [`LiquidatePerpPnlForDepositRecord`]
+pub const LIQUIDATE_PERP_PNL_FOR_DEPOSIT_RECORD_ASSET_PRICE_OFFSET: usize = 28;
+/// Offset of `asset_transfer` in serialized [`LiquidatePerpPnlForDepositRecord`]
+pub const LIQUIDATE_PERP_PNL_FOR_DEPOSIT_RECORD_ASSET_TRANSFER_OFFSET: usize = 36;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerpBankruptcyRecord {
+    pub market_index: u16,
+    pub pnl: i128,
+    pub if_payment: u128,
+    pub clawback_user: Option<Pubkey>,
+    pub clawback_user_payment: Option<u128>,
+    pub cumulative_funding_rate_delta: i128,
+}
+/// Offset of `market_index` in serialized [`PerpBankruptcyRecord`]
+pub const PERP_BANKRUPTCY_RECORD_MARKET_INDEX_OFFSET: usize = 0;
+/// Offset of `pnl` in serialized [`PerpBankruptcyRecord`]
+pub const PERP_BANKRUPTCY_RECORD_PNL_OFFSET: usize = 2;
+/// Offset of `if_payment` in serialized [`PerpBankruptcyRecord`]
+pub const PERP_BANKRUPTCY_RECORD_IF_PAYMENT_OFFSET: usize = 18;
+/// Offset of `clawback_user` in serialized [`PerpBankruptcyRecord`]
+pub const PERP_BANKRUPTCY_RECORD_CLAWBACK_USER_OFFSET: usize = 34;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpotBankruptcyRecord {
+    pub market_index: u16,
+    pub borrow_amount: u128,
+    pub if_payment: u128,
+    pub cumulative_deposit_interest_delta: u128,
+}
+/// Serialized size of [`SpotBankruptcyRecord`]
+pub const SPOT_BANKRUPTCY_RECORD_LEN: usize = 50;
+/// Offset of `market_index` in serialized [`SpotBankruptcyRecord`]
+pub const SPOT_BANKRUPTCY_RECORD_MARKET_INDEX_OFFSET: usize = 0;
+/// Offset of `borrow_amount` in serialized [`SpotBankruptcyRecord`]
+pub const SPOT_BANKRUPTCY_RECORD_BORROW_AMOUNT_OFFSET: usize = 2;
+/// Offset of `if_payment` in serialized [`SpotBankruptcyRecord`]
+pub const SPOT_BANKRUPTCY_RECORD_IF_PAYMENT_OFFSET: usize = 18;
+/// Offset of `cumulative_deposit_interest_delta` in serialized [`SpotBankruptcyRecord`]
+pub const SPOT_BANKRUPTCY_RECORD_CUMULATIVE_DEPOSIT_INTEREST_DELTA_OFFSET: usize = 34;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoricalOracleData {
+    /// precision: PRICE_PRECISION
+    pub last_oracle_price: i64,
+    /// precision: PRICE_PRECISION
+    pub last_oracle_conf: u64,
+    pub last_oracle_delay: i64,
+    /// precision: PRICE_PRECISION
+    pub last_oracle_price_twap: i64,
+    /// precision: PRICE_PRECISION
+    pub last_oracle_price_twap5min: i64,
+    pub last_oracle_price_twap_ts: i64,
+}
+/// Serialized size of [`HistoricalOracleData`]
+pub const HISTORICAL_ORACLE_DATA_LEN: usize = 48;
+/// Offset of `last_oracle_price` in serialized [`HistoricalOracleData`]
+pub const HISTORICAL_ORACLE_DATA_LAST_ORACLE_PRICE_OFFSET: usize = 0;
+/// Offset of `last_oracle_conf` in serialized [`HistoricalOracleData`]
+pub const HISTORICAL_ORACLE_DATA_LAST_ORACLE_CONF_OFFSET: usize = 8;
+/// Offset of `last_oracle_delay` in serialized [`HistoricalOracleData`]
+pub const HISTORICAL_ORACLE_DATA_LAST_ORACLE_DELAY_OFFSET: usize = 16;
+/// Offset of `last_oracle_price_twap` in serialized [`HistoricalOracleData`]
+pub const HISTORICAL_ORACLE_DATA_LAST_ORACLE_PRICE_TWAP_OFFSET: usize = 24;
+/// Offset of `last_oracle_price_twap5min` in serialized [`HistoricalOracleData`]
+pub const HISTORICAL_ORACLE_DATA_LAST_ORACLE_PRICE_TWAP5MIN_OFFSET: usize = 32;
+/// Offset of `last_oracle_price_twap_ts` in serialized [`HistoricalOracleData`]
+pub const HISTORICAL_ORACLE_DATA_LAST_ORACLE_PRICE_TWAP_TS_OFFSET: usize = 40;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoricalIndexData {
+    /// precision: PRICE_PRECISION
+    pub last_index_bid_price: u64,
+    /// precision: PRICE_PRECISION
+    pub last_index_ask_price: u64,
+    /// precision: PRICE_PRECISION
+    pub last_index_price_twap: u64,
+    /// precision: PRICE_PRECISION
+    pub last_index_price_twap5min: u64,
+    pub last_index_price_twap_ts: i64,
+}
+/// Serialized size of [`HistoricalIndexData`]
+pub const HISTORICAL_INDEX_DATA_LEN: usize = 40;
+/// Offset of `last_index_bid_price` in serialized [`HistoricalIndexData`]
+pub const HISTORICAL_INDEX_DATA_LAST_INDEX_BID_PRICE_OFFSET: usize = 0;
+/// Offset of `last_index_ask_price` in serialized [`HistoricalIndexData`]
+pub const HISTORICAL_INDEX_DATA_LAST_INDEX_ASK_PRICE_OFFSET: usize = 8;
+/// Offset of `last_index_price_twap` in serialized [`HistoricalIndexData`]
+pub const HISTORICAL_INDEX_DATA_LAST_INDEX_PRICE_TWAP_OFFSET: usize = 16;
+/// Offset of `last_index_price_twap5min` in serialized [`HistoricalIndexData`]
+pub const HISTORICAL_INDEX_DATA_LAST_INDEX_PRICE_TWAP5MIN_OFFSET: usize = 24;
+/// Offset of `last_index_price_twap_ts` in serialized [`HistoricalIndexData`]
+pub const HISTORICAL_INDEX_DATA_LAST_INDEX_PRICE_TWAP_TS_OFFSET: usize = 32;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceClaim {
+    /// The amount of revenue last settled
+    /// Positive if funds left the perp market,
+    /// negative if funds were pulled into the perp market
+    /// precision: QUOTE_PRECISION
+    pub revenue_withdraw_since_last_settle: i64,
+    /// The max amount of revenue that can be withdrawn per period
+    /// precision: QUOTE_PRECISION
+    pub max_revenue_withdraw_per_period: u64,
+    /// The max amount of insurance that perp market can use to resolve bankruptcy and pnl deficits
+    /// precision: QUOTE_PRECISION
+    pub quote_max_insurance: u64,
+    /// The amount of insurance that has been used to resolve bankruptcy and pnl deficits
+    /// precision: QUOTE_PRECISION
+    pub quote_settled_insurance: u64,
+    /// The last time revenue was settled in/out of market
+    pub last_revenue_withdraw_ts: i64,
+}
+/// Serialized size of [`InsuranceClaim`]
+pub const INSURANCE_CLAIM_LEN: usize = 40;
+/// Offset of `revenue_withdraw_since_last_settle` in serialized [`InsuranceClaim`]
+pub const INSURANCE_CLAIM_REVENUE_WITHDRAW_SINCE_LAST_SETTLE_OFFSET: usize = 0;
+/// Offset of `max_revenue_withdraw_per_period` in serialized [`InsuranceClaim`]
+pub const INSURANCE_CLAIM_MAX_REVENUE_WITHDRAW_PER_PERIOD_OFFSET: usize = 8;
+/// Offset of `quote_max_insurance` in serialized [`InsuranceClaim`]
+pub const INSURANCE_CLAIM_QUOTE_MAX_INSURANCE_OFFSET: usize = 16;
+/// Offset of `quote_settled_insurance` in serialized [`InsuranceClaim`]
+pub const INSURANCE_CLAIM_QUOTE_SETTLED_INSURANCE_OFFSET: usize = 24;
+/// Offset of `last_revenue_withdraw_ts` in serialized [`InsuranceClaim`]
+pub const INSURANCE_CLAIM_LAST_REVENUE_WITHDRAW_TS_OFFSET: usize = 32;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoolBalance {
+    /// To get the pool's token amount, you must multiply the scaled balance by the market's cumulative
+    /// deposit interest
+    /// precision: SPOT_BALANCE_PRECISION
+    pub scaled_balance: u128,
+    /// The spot market the pool is for
+    pub market_index: u16,
+    pub padding: [u8; 6],
+}
+/// Serialized size of [`PoolBalance`]
+pub const POOL_BALANCE_LEN: usize = 24;
+/// Offset of `scaled_balance` in serialized [`PoolBalance`]
+pub const POOL_BALANCE_SCALED_BALANCE_OFFSET: usize = 0;
+/// Offset of `market_index` in serialized [`PoolBalance`]
+pub const POOL_BALANCE_MARKET_INDEX_OFFSET: usize = 16;
+/// Offset of `padding` in serialized [`PoolBalance`]
+pub const POOL_BALANCE_PADDING_OFFSET: usize = 18;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AMM {
+    /// oracle price data public key
+    pub oracle: Pubkey,
+    /// stores historically witnessed oracle data
+    pub historical_oracle_data: HistoricalOracleData,
+    /// accumulated base asset amount since inception per lp share
+    pub base_asset_amount_per_lp: i128,
+    /// accumulated quote asset amount since inception per lp share
+    pub quote_asset_amount_per_lp: i128,
+    /// partition of fees from perp market trading moved from pnl settlements
+    pub fee_pool: PoolBalance,
+    /// `x` reserves for constant product mm formula (x * y = k)
+    pub base_asset_reserve: u128,
+    /// `y` reserves for constant product mm formula (x * y = k)
+    pub quote_asset_reserve: u128,
+    /// determines how close the min/max base asset reserve sit vs base reserves
+    /// allow for decreasing slippage without increasing liquidity and v.v.
+    pub concentration_coef: u128,
+    /// minimum base_asset_reserve allowed before AMM is unavailable
+    pub min_base_asset_reserve: u128,
+    /// maximum base_asset_reserve allowed before AMM is unavailable
+    pub max_base_asset_reserve: u128,
+    /// `sqrt(k)` in constant product mm formula (x * y = k). stored to avoid drift caused by integer math issues
+    pub sqrt_k: u128,
+    /// normalizing numerical factor for y, its use offers lowest slippage in cp-curve when market is balanced
+    pub peg_multiplier: u128,
+    /// y when market is balanced. stored to save computation
+    pub terminal_quote_asset_reserve: u128,
+    /// tracks number of total longs in market (regardless of counterparty)
+    pub base_asset_amount_long: i128,
+    /// tracks number of total shorts in market (regardless of counterparty)
+    pub base_asset_amount_short: i128,
+    /// tracks net position (longs-shorts) in market with AMM as counterparty
+    pub base_asset_amount_with_amm: i128,
+    /// tracks net position (longs-shorts) in market with LPs as counterparty
+    pub base_asset_amount_with_unsettled_lp: i128,
+    /// max allowed open interest, blocks trades that breach this value
+    pub max_open_interest: u128,
+    /// sum of all user's perp quote_asset_amount in market
+    pub quote_asset_amount: i128,
+    /// sum of all long user's quote_entry_amount in market
+    pub quote_entry_amount_long: i128,
+    /// sum of all short user's quote_entry_amount in market
+    pub quote_entry_amount_short: i128,
+    /// sum of all long user's quote_break_even_amount in market
+    pub quote_break_even_amount_long: i128,
+    /// sum of all short user's quote_break_even_amount in market
+    pub quote_break_even_amount_short: i128,
+    /// total user lp shares of sqrt_k (protocol owned liquidity = sqrt_k - last_funding_rate)
+    pub user_lp_shares: u128,
+    /// last funding rate in this perp market (unit is quote per base)
+    pub last_funding_rate: i64,
+    /// last funding rate for longs in this perp market (unit is quote per base)
+    pub last_funding_rate_long: i64,
+    /// last funding rate for shorts in this perp market (unit is quote per base)
+    pub last_funding_rate_short: i64,
+    /// estimate of last 24h of funding rate perp market (unit is quote per base)
+    pub last24h_avg_funding_rate: i64,
+    /// total fees collected by this perp market
+    pub total_fee: i128,
+    /// total fees collected by the vAMM's bid/ask spread
+    pub total_mm_fee: i128,
+    /// total fees collected by exchange fee schedule
+    pub total_exchange_fee: u128,
+    /// total fees minus any recognized upnl and pool withdraws
+    pub total_fee_minus_distributions: i128,
+    /// sum of all fees from fee pool withdrawn to revenue pool
+    pub total_fee_withdrawn: u128,
+    /// all fees collected by market for liquidations
+    pub total_liquidation_fee: u128,
+    /// accumulated funding rate for longs since inception in market
+    pub cumulative_funding_rate_long: i128,
+    /// accumulated funding rate for shorts since inception in market
+    pub cumulative_funding_rate_short: i128,
+    /// accumulated social loss paid by users since inception in market
+    pub total_social_loss: u128,
+    /// transformed base_asset_reserve for users going long
+    pub ask_base_asset_reserve: u128,
+    /// transformed quote_asset_reserve for users going long
+    pub ask_quote_asset_reserve: u128,
+    /// transformed base_asset_reserve for users going short
+    pub bid_base_asset_reserve: u128,
+    /// transformed quote_asset_reserve for users going short
+    pub bid_quote_asset_reserve: u128,
+    /// the last seen oracle price partially shrunk toward the amm reserve price
+    /// precision: PRICE_PRECISION
+    pub last_oracle_normalised_price: i64,
+    /// the gap between the oracle price and the reserve price = y * peg_multiplier / x
+    pub last_oracle_reserve_price_spread_pct: i64,
+    /// average estimate of bid price over funding_period
+    /// precision: PRICE_PRECISION
+    pub last_bid_price_twap: u64,
+    /// average estimate of ask price over funding_period
+    /// precision: PRICE_PRECISION
+    pub last_ask_price_twap: u64,
+    /// average estimate of (bid+ask)/2 price over funding_period
+    /// precision: PRICE_PRECISION
+    pub last_mark_price_twap: u64,
+    /// average estimate of (bid+ask)/2 price over FIVE_MINUTES
+    pub last_mark_price_twap5min: u64,
+    /// the last blockchain slot the amm was updated
+    pub last_update_slot: u64,
+    /// the pct size of the oracle confidence interval
+    /// precision: PERCENTAGE_PRECISION
+    pub last_oracle_conf_pct: u64,
+    /// the total_fee_minus_distribution change since the last funding update
+    /// precision: QUOTE_PRECISION
+    pub net_revenue_since_last_funding: i64,
+    /// the last funding rate update unix_timestamp
+    pub last_funding_rate_ts: i64,
+    /// the peridocity of the funding rate updates
+    pub funding_period: i64,
+    /// the base step size (increment) of orders
+    /// precision: BASE_PRECISION
+    pub order_step_size: u64,
+    /// the price tick size of orders
+    /// precision: PRICE_PRECISION
+    pub order_tick_size: u64,
+    /// the minimum base size of an order
+    /// precision: BASE_PRECISION
+    pub min_order_size: u64,
+    /// the max base size a single user can have
+    /// precision: BASE_PRECISION
+    pub max_position_size: u64,
+    /// estimated total of volume in market
+    /// QUOTE_PRECISION
+    pub volume24h: u64,
+    /// the volume intensity of long fills against AMM
+    pub long_intensity_volume: u64,
+    /// the volume intensity of short fills against AMM
+    pub short_intensity_volume: u64,
+    /// the blockchain unix timestamp at the time of the last trade
+    pub last_trade_ts: i64,
+    /// estimate of standard deviation of the fill (mark) prices
+    /// precision: PRICE_PRECISION
+    pub mark_std: u64,
+    /// estimate of standard deviation of the oracle price at each update
+    /// precision: PRICE_PRECISION
+    pub oracle_std: u64,
+    /// the last unix_timestamp the mark twap was updated
+    pub last_mark_price_twap_ts: i64,
+    /// the minimum spread the AMM can quote. also used as step size for some spread logic increases.
+    pub base_spread: u32,
+    /// the maximum spread the AMM can quote
+    pub max_spread: u32,
+    /// the spread for asks vs the reserve price
+    pub long_spread: u32,
+    /// the spread for bids vs the reserve price
+    pub short_spread: u32,
+    /// the count intensity of long fills against AMM
+    pub long_intensity_count: u32,
+    /// the count intensity of short fills against AMM
+    pub short_intensity_count: u32,
+    /// the fraction of total available liquidity a single fill on the AMM can consume
+    pub max_fill_reserve_fraction: u16,
+    /// the maximum slippage a single fill on the AMM can push
+    pub max_slippage_ratio: u16,
+    /// the update intensity of AMM formulaic updates (adjusting k). 0-100
+    pub curve_update_intensity: u8,
+    /// the jit intensity of AMM. larger intensity means larger participation in jit. 0 means no jit participation.
+    /// (0, 100] is intensity for protocol-owned AMM. (100, 200] is intensity for user LP-owned AMM.
+    pub amm_jit_intensity: u8,
+    /// the oracle provider information. used to decode/scale the oracle public key
+    pub oracle_source: OracleSource,
+    /// tracks whether the oracle was considered valid at the last AMM update
+    pub last_oracle_valid: bool,
+    /// the target value for `base_asset_amount_per_lp`, used during AMM JIT with LP split
+    /// precision: BASE_PRECISION
+    pub target_base_asset_amount_per_lp: i32,
+    pub padding: [u8; 44],
+}
+/// Serialized size of [`AMM`]
+pub const AMM_LEN: usize = 936;
+/// Offset of `oracle` in serialized [`AMM`]
+pub const AMM_ORACLE_OFFSET: usize = 0;
+/// Offset of `historical_oracle_data` in serialized [`AMM`]
+pub const AMM_HISTORICAL_ORACLE_DATA_OFFSET: usize = 32;
+/// Offset of `base_asset_amount_per_lp` in serialized [`AMM`]
+pub const AMM_BASE_ASSET_AMOUNT_PER_LP_OFFSET: usize = 80;
+/// Offset of `quote_asset_amount_per_lp` in serialized [`AMM`]
+pub const AMM_QUOTE_ASSET_AMOUNT_PER_LP_OFFSET: usize = 96;
+/// Offset of `fee_pool` in serialized [`AMM`]
+pub const AMM_FEE_POOL_OFFSET: usize = 112;
+/// Offset of `base_asset_reserve` in serialized [`AMM`]
+pub const AMM_BASE_ASSET_RESERVE_OFFSET: usize = 136;
+/// Offset of `quote_asset_reserve` in serialized [`AMM`]
+pub const AMM_QUOTE_ASSET_RESERVE_OFFSET: usize = 152;
+/// Offset of `concentration_coef` in serialized [`AMM`]
+pub const AMM_CONCENTRATION_COEF_OFFSET: usize = 168;
+/// Offset of `min_base_asset_reserve` in serialized [`AMM`]
+pub const AMM_MIN_BASE_ASSET_RESERVE_OFFSET: usize = 184;
+/// Offset of `max_base_asset_reserve` in serialized [`AMM`]
+pub const AMM_MAX_BASE_ASSET_RESERVE_OFFSET: usize = 200;
+/// Offset of `sqrt_k` in serialized [`AMM`]
+pub const AMM_SQRT_K_OFFSET: usize = 216;
+/// Offset of `peg_multiplier` in serialized [`AMM`]
+pub const AMM_PEG_MULTIPLIER_OFFSET: usize = 232;
+/// Offset of `terminal_quote_asset_reserve` in serialized [`AMM`]
+pub const AMM_TERMINAL_QUOTE_ASSET_RESERVE_OFFSET: usize = 248;
+/// Offset of `base_asset_amount_long` in serialized [`AMM`]
+pub const AMM_BASE_ASSET_AMOUNT_LONG_OFFSET: usize = 264;
+/// Offset of `base_asset_amount_short` in serialized [`AMM`]
+pub const AMM_BASE_ASSET_AMOUNT_SHORT_OFFSET: usize = 280;
+/// Offset of `base_asset_amount_with_amm` in serialized [`AMM`]
+pub const AMM_BASE_ASSET_AMOUNT_WITH_AMM_OFFSET: usize = 296;
+/// Offset of `base_asset_amount_with_unsettled_lp` in serialized [`AMM`]
+pub const AMM_BASE_ASSET_AMOUNT_WITH_UNSETTLED_LP_OFFSET: usize = 312;
+/// Offset of `max_open_interest` in serialized [`AMM`]
+pub const AMM_MAX_OPEN_INTEREST_OFFSET: usize = 328;
+/// Offset of `quote_asset_amount` in serialized [`AMM`]
+pub const AMM_QUOTE_ASSET_AMOUNT_OFFSET: usize = 344;
+/// Offset of `quote_entry_amount_long` in serialized [`AMM`]
+pub const AMM_QUOTE_ENTRY_AMOUNT_LONG_OFFSET: usize = 360;
+/// Offset of `quote_entry_amount_short` in serialized [`AMM`]
+pub const AMM_QUOTE_ENTRY_AMOUNT_SHORT_OFFSET: usize = 376;
+/// Offset of `quote_break_even_amount_long` in serialized [`AMM`]
+pub const AMM_QUOTE_BREAK_EVEN_AMOUNT_LONG_OFFSET: usize = 392;
+/// Offset of `quote_break_even_amount_short` in serialized [`AMM`]
+pub const AMM_QUOTE_BREAK_EVEN_AMOUNT_SHORT_OFFSET: usize = 408;
+/// Offset of `user_lp_shares` in serialized [`AMM`]
+pub const AMM_USER_LP_SHARES_OFFSET: usize = 424;
+/// Offset of `last_funding_rate` in serialized [`AMM`]
+pub const AMM_LAST_FUNDING_RATE_OFFSET: usize = 440;
+/// Offset of `last_funding_rate_long` in serialized [`AMM`]
+pub const AMM_LAST_FUNDING_RATE_LONG_OFFSET: usize = 448;
+/// Offset of `last_funding_rate_short` in serialized [`AMM`]
+pub const AMM_LAST_FUNDING_RATE_SHORT_OFFSET: usize = 456;
+/// Offset of `last24h_avg_funding_rate` in serialized [`AMM`]
+pub const AMM_LAST24H_AVG_FUNDING_RATE_OFFSET: usize = 464;
+/// Offset of `total_fee` in serialized [`AMM`]
+pub const AMM_TOTAL_FEE_OFFSET: usize = 472;
+/// Offset of `total_mm_fee` in serialized [`AMM`]
+pub const AMM_TOTAL_MM_FEE_OFFSET: usize = 488;
+/// Offset of `total_exchange_fee` in serialized [`AMM`]
+pub const AMM_TOTAL_EXCHANGE_FEE_OFFSET: usize = 504;
+/// Offset of `total_fee_minus_distributions` in serialized [`AMM`]
+pub const AMM_TOTAL_FEE_MINUS_DISTRIBUTIONS_OFFSET: usize = 520;
+/// Offset of `total_fee_withdrawn` in serialized [`AMM`]
+pub const AMM_TOTAL_FEE_WITHDRAWN_OFFSET: usize = 536;
+/// Offset of `total_liquidation_fee` in serialized [`AMM`]
+pub const AMM_TOTAL_LIQUIDATION_FEE_OFFSET: usize = 552;
+/// Offset of `cumulative_funding_rate_long` in serialized [`AMM`]
+pub const AMM_CUMULATIVE_FUNDING_RATE_LONG_OFFSET: usize = 568;
+/// Offset of `cumulative_funding_rate_short` in serialized [`AMM`]
+pub const AMM_CUMULATIVE_FUNDING_RATE_SHORT_OFFSET: usize = 584;
+/// Offset of `total_social_loss` in serialized [`AMM`]
+pub const AMM_TOTAL_SOCIAL_LOSS_OFFSET: usize = 600;
+/// Offset of `ask_base_asset_reserve` in serialized [`AMM`]
+pub const AMM_ASK_BASE_ASSET_RESERVE_OFFSET: usize = 616;
+/// Offset of `ask_quote_asset_reserve` in serialized [`AMM`]
+pub const AMM_ASK_QUOTE_ASSET_RESERVE_OFFSET: usize = 632;
+/// Offset of `bid_base_asset_reserve` in serialized [`AMM`]
+pub const AMM_BID_BASE_ASSET_RESERVE_OFFSET: usize = 648;
+/// Offset of `bid_quote_asset_reserve` in serialized [`AMM`]
+pub const AMM_BID_QUOTE_ASSET_RESERVE_OFFSET: usize = 664;
+/// Offset of `last_oracle_normalised_price` in serialized [`AMM`]
+pub const AMM_LAST_ORACLE_NORMALISED_PRICE_OFFSET: usize = 680;
+/// Offset of `last_oracle_reserve_price_spread_pct` in serialized [`AMM`]
+pub const AMM_LAST_ORACLE_RESERVE_PRICE_SPREAD_PCT_OFFSET: usize = 688;
+/// Offset of `last_bid_price_twap` in serialized [`AMM`]
+pub const AMM_LAST_BID_PRICE_TWAP_OFFSET: usize = 696;
+/// Offset of `last_ask_price_twap` in serialized [`AMM`]
+pub const AMM_LAST_ASK_PRICE_TWAP_OFFSET: usize = 704;
+/// Offset of `last_mark_price_twap` in serialized [`AMM`]
+pub const AMM_LAST_MARK_PRICE_TWAP_OFFSET: usize = 712;
+/// Offset of `last_mark_price_twap5min` in serialized [`AMM`]
+pub const AMM_LAST_MARK_PRICE_TWAP5MIN_OFFSET: usize = 720;
+/// Offset of `last_update_slot` in serialized [`AMM`]
+pub const AMM_LAST_UPDATE_SLOT_OFFSET: usize = 728;
+/// Offset of `last_oracle_conf_pct` in serialized [`AMM`]
+pub const AMM_LAST_ORACLE_CONF_PCT_OFFSET: usize = 736;
+/// Offset of `net_revenue_since_last_funding` in serialized [`AMM`]
+pub const AMM_NET_REVENUE_SINCE_LAST_FUNDING_OFFSET: usize = 744;
+/// Offset of `last_funding_rate_ts` in serialized [`AMM`]
+pub const AMM_LAST_FUNDING_RATE_TS_OFFSET: usize = 752;
+/// Offset of `funding_period` in serialized [`AMM`]
+pub const AMM_FUNDING_PERIOD_OFFSET: usize = 760;
+/// Offset of `order_step_size` in serialized [`AMM`]
+pub const AMM_ORDER_STEP_SIZE_OFFSET: usize = 768;
+/// Offset of `order_tick_size` in serialized [`AMM`]
+pub const AMM_ORDER_TICK_SIZE_OFFSET: usize = 776;
+/// Offset of `min_order_size` in serialized [`AMM`]
+pub const AMM_MIN_ORDER_SIZE_OFFSET: usize = 784;
+/// Offset of `max_position_size` in serialized [`AMM`]
+pub const AMM_MAX_POSITION_SIZE_OFFSET: usize = 792;
+/// Offset of `volume24h` in serialized [`AMM`]
+pub const AMM_VOLUME24H_OFFSET: usize = 800;
+/// Offset of `long_intensity_volume` in serialized [`AMM`]
+pub const AMM_LONG_INTENSITY_VOLUME_OFFSET: usize = 808;
+/// Offset of `short_intensity_volume` in serialized [`AMM`]
+pub const AMM_SHORT_INTENSITY_VOLUME_OFFSET: usize = 816;
+/// Offset of `last_trade_ts` in serialized [`AMM`]
+pub const AMM_LAST_TRADE_TS_OFFSET: usize = 824;
+/// Offset of `mark_std` in serialized [`AMM`]
+pub const AMM_MARK_STD_OFFSET: usize = 832;
+/// Offset of `oracle_std` in serialized [`AMM`]
+pub const AMM_ORACLE_STD_OFFSET: usize = 840;
+/// Offset of `last_mark_price_twap_ts` in serialized [`AMM`]
+pub const AMM_LAST_MARK_PRICE_TWAP_TS_OFFSET: usize = 848;
+/// Offset of `base_spread` in serialized [`AMM`]
+pub const AMM_BASE_SPREAD_OFFSET: usize = 856;
+/// Offset of `max_spread` in serialized [`AMM`]
+pub const AMM_MAX_SPREAD_OFFSET: usize = 860;
+/// Offset of `long_spread` in serialized [`AMM`]
+pub const AMM_LONG_SPREAD_OFFSET: usize = 864;
+/// Offset of `short_spread` in serialized [`AMM`]
+pub const AMM_SHORT_SPREAD_OFFSET: usize = 868;
+/// Offset of `long_intensity_count` in serialized [`AMM`]
+pub const AMM_LONG_INTENSITY_COUNT_OFFSET: usize = 872;
+/// Offset of `short_intensity_count` in serialized [`AMM`]
+pub const AMM_SHORT_INTENSITY_COUNT_OFFSET: usize = 876;
+/// Offset of `max_fill_reserve_fraction` in serialized [`AMM`]
+pub const AMM_MAX_FILL_RESERVE_FRACTION_OFFSET: usize = 880;
+/// Offset of `max_slippage_ratio` in serialized [`AMM`]
+pub const AMM_MAX_SLIPPAGE_RATIO_OFFSET: usize = 882;
+/// Offset of `curve_update_intensity` in serialized [`AMM`]
+pub const AMM_CURVE_UPDATE_INTENSITY_OFFSET: usize = 884;
+/// Offset of `amm_jit_intensity` in serialized [`AMM`]
+pub const AMM_AMM_JIT_INTENSITY_OFFSET: usize = 885;
+/// Offset of `oracle_source` in serialized [`AMM`]
+pub const AMM_ORACLE_SOURCE_OFFSET: usize = 886;
+/// Offset of `last_oracle_valid` in serialized [`AMM`]
+pub const AMM_LAST_ORACLE_VALID_OFFSET: usize = 887;
+/// Offset of `target_base_asset_amount_per_lp` in serialized [`AMM`]
+pub const AMM_TARGET_BASE_ASSET_AMOUNT_PER_LP_OFFSET: usize = 888;
+/// Offset of `padding` in serialized [`AMM`]
+pub const AMM_PADDING_OFFSET: usize = 892;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceFund {
+    pub vault: Pubkey,
+    pub total_shares: u128,
+    pub user_shares: u128,
+    pub shares_base: u128,
+    pub unstaking_period: i64,
+    pub last_revenue_settle_ts: i64,
+    pub revenue_settle_period: i64,
+    pub total_factor: u32,
+    pub user_factor: u32,
+}
+/// Serialized size of [`InsuranceFund`]
+pub const INSURANCE_FUND_LEN: usize = 112;
+/// Offset of `vault` in serialized [`InsuranceFund`]
+pub const INSURANCE_FUND_VAULT_OFFSET: usize = 0;
+/// Offset of `total_shares` in serialized [`InsuranceFund`]
+pub const INSURANCE_FUND_TOTAL_SHARES_OFFSET: usize = 32;
+/// Offset of `user_shares` in serialized [`InsuranceFund`]
+pub const INSURANCE_FUND_USER_SHARES_OFFSET: usize = 48;
+/// Offset of `shares_base` in serialized [`InsuranceFund`]
+pub const INSURANCE_FUND_SHARES_BASE_OFFSET: usize = 64;
+/// Offset of `unstaking_period` in serialized [`InsuranceFund`]
+pub const INSURANCE_FUND_UNSTAKING_PERIOD_OFFSET: usize = 80;
+/// Offset of `last_revenue_settle_ts` in serialized [`InsuranceFund`]
+pub const INSURANCE_FUND_LAST_REVENUE_SETTLE_TS_OFFSET: usize = 88;
+/// Offset of `revenue_settle_period` in serialized [`InsuranceFund`]
+pub const INSURANCE_FUND_REVENUE_SETTLE_PERIOD_OFFSET: usize = 96;
+/// Offset of `total_factor` in serialized [`InsuranceFund`]
+pub const INSURANCE_FUND_TOTAL_FACTOR_OFFSET: usize = 104;
+/// Offset of `user_factor` in serialized [`InsuranceFund`]
+pub const INSURANCE_FUND_USER_FACTOR_OFFSET: usize = 108;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OracleGuardRails {
+    pub price_divergence: PriceDivergenceGuardRails,
+    pub validity: ValidityGuardRails,
+}
+/// Serialized size of [`OracleGuardRails`]
+pub const ORACLE_GUARD_RAILS_LEN: usize = 48;
+/// Offset of `price_divergence` in serialized [`OracleGuardRails`]
+pub const ORACLE_GUARD_RAILS_PRICE_DIVERGENCE_OFFSET: usize = 0;
+/// Offset of `validity` in serialized [`OracleGuardRails`]
+pub const ORACLE_GUARD_RAILS_VALIDITY_OFFSET: usize = 16;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PriceDivergenceGuardRails {
+    pub mark_oracle_divergence_numerator: u64,
+    pub mark_oracle_divergence_denominator: u64,
+}
+/// Serialized size of [`PriceDivergenceGuardRails`]
+pub const PRICE_DIVERGENCE_GUARD_RAILS_LEN: usize = 16;
+/// Offset of `mark_oracle_divergence_numerator` in serialized [`PriceDivergenceGuardRails`]
+pub const PRICE_DIVERGENCE_GUARD_RAILS_MARK_ORACLE_DIVERGENCE_NUMERATOR_OFFSET: usize = 0;
+/// Offset of `mark_oracle_divergence_denominator` in serialized [`PriceDivergenceGuardRails`]
+pub const PRICE_DIVERGENCE_GUARD_RAILS_MARK_ORACLE_DIVERGENCE_DENOMINATOR_OFFSET: usize = 8;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidityGuardRails {
+    pub slots_before_stale_for_amm: i64,
+    pub slots_before_stale_for_margin: i64,
+    pub confidence_interval_max_size: u64,
+    pub too_volatile_ratio: i64,
+}
+/// Serialized size of [`ValidityGuardRails`]
+pub const VALIDITY_GUARD_RAILS_LEN: usize = 32;
+/// Offset of `slots_before_stale_for_amm` in serialized [`ValidityGuardRails`]
+pub const VALIDITY_GUARD_RAILS_SLOTS_BEFORE_STALE_FOR_AMM_OFFSET: usize = 0;
+/// Offset of `slots_before_stale_for_margin` in serialized [`ValidityGuardRails`]
+pub const VALIDITY_GUARD_RAILS_SLOTS_BEFORE_STALE_FOR_MARGIN_OFFSET: usize = 8;
+/// Offset of `confidence_interval_max_size` in serialized [`ValidityGuardRails`]
+pub const VALIDITY_GUARD_RAILS_CONFIDENCE_INTERVAL_MAX_SIZE_OFFSET: usize = 16;
+/// Offset of `too_volatile_ratio` in serialized [`ValidityGuardRails`]
+pub const VALIDITY_GUARD_RAILS_TOO_VOLATILE_RATIO_OFFSET: usize = 24;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeStructure {
+    pub fee_tiers: [FeeTier; 10],
+    pub filler_reward_structure: OrderFillerRewardStructure,
+    pub referrer_reward_epoch_upper_bound: u64,
+    pub flat_filler_fee: u64,
+}
+/// Serialized size of [`FeeStructure`]
+pub const FEE_STRUCTURE_LEN: usize = 360;
+/// Offset of `fee_tiers` in serialized [`FeeStructure`]
+pub const FEE_STRUCTURE_FEE_TIERS_OFFSET: usize = 0;
+/// Offset of `filler_reward_structure` in serialized [`FeeStructure`]
+pub const FEE_STRUCTURE_FILLER_REWARD_STRUCTURE_OFFSET: usize = 320;
+/// Offset of `referrer_reward_epoch_upper_bound` in serialized [`FeeStructure`]
+pub const FEE_STRUCTURE_REFERRER_REWARD_EPOCH_UPPER_BOUND_OFFSET: usize = 344;
+/// Offset of `flat_filler_fee` in serialized [`FeeStructure`]
+pub const FEE_STRUCTURE_FLAT_FILLER_FEE_OFFSET: usize = 352;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeTier {
+    pub fee_numerator: u32,
+    pub fee_denominator: u32,
+    pub maker_rebate_numerator: u32,
+    pub maker_rebate_denominator: u32,
+    pub referrer_reward_numerator: u32,
+    pub referrer_reward_denominator: u32,
+    pub referee_fee_numerator: u32,
+    pub referee_fee_denominator: u32,
+}
+/// Serialized size of [`FeeTier`]
+pub const FEE_TIER_LEN: usize = 32;
+/// Offset of `fee_numerator` in serialized [`FeeTier`]
+pub const FEE_TIER_FEE_NUMERATOR_OFFSET: usize = 0;
+/// Offset of `fee_denominator` in serialized [`FeeTier`]
+pub const FEE_TIER_FEE_DENOMINATOR_OFFSET: usize = 4;
+/// Offset of `maker_rebate_numerator` in serialized [`FeeTier`]
+pub const FEE_TIER_MAKER_REBATE_NUMERATOR_OFFSET: usize = 8;
+/// Offset of `maker_rebate_denominator` in serialized [`FeeTier`]
+pub const FEE_TIER_MAKER_REBATE_DENOMINATOR_OFFSET: usize = 12;
+/// Offset of `referrer_reward_numerator` in serialized [`FeeTier`]
+pub const FEE_TIER_REFERRER_REWARD_NUMERATOR_OFFSET: usize = 16;
+/// Offset of `referrer_reward_denominator` in serialized [`FeeTier`]
+pub const FEE_TIER_REFERRER_REWARD_DENOMINATOR_OFFSET: usize = 20;
+/// Offset of `referee_fee_numerator` in serialized [`FeeTier`]
+pub const FEE_TIER_REFEREE_FEE_NUMERATOR_OFFSET: usize = 24;
+/// Offset of `referee_fee_denominator` in serialized [`FeeTier`]
+pub const FEE_TIER_REFEREE_FEE_DENOMINATOR_OFFSET: usize = 28;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderFillerRewardStructure {
+    pub reward_numerator: u32,
+    pub reward_denominator: u32,
+    pub time_based_reward_lower_bound: u128,
+}
+/// Serialized size of [`OrderFillerRewardStructure`]
+pub const ORDER_FILLER_REWARD_STRUCTURE_LEN: usize = 24;
+/// Offset of `reward_numerator` in serialized [`OrderFillerRewardStructure`]
+pub const ORDER_FILLER_REWARD_STRUCTURE_REWARD_NUMERATOR_OFFSET: usize = 0;
+/// Offset of `reward_denominator` in serialized [`OrderFillerRewardStructure`]
+pub const ORDER_FILLER_REWARD_STRUCTURE_REWARD_DENOMINATOR_OFFSET: usize = 4;
+/// Offset of `time_based_reward_lower_bound` in serialized [`OrderFillerRewardStructure`]
+pub const ORDER_FILLER_REWARD_STRUCTURE_TIME_BASED_REWARD_LOWER_BOUND_OFFSET: usize = 8;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UserFees {
+    /// Total taker fee paid
+    /// precision: QUOTE_PRECISION
+    pub total_fee_paid: u64,
+    /// Total maker fee rebate
+    /// precision: QUOTE_PRECISION
+    pub total_fee_rebate: u64,
+    /// Total discount from holding token
+    /// precision: QUOTE_PRECISION
+    pub total_token_discount: u64,
+    /// Total discount from being referred
+    /// precision: QUOTE_PRECISION
+    pub total_referee_discount: u64,
+    /// Total reward to referrer
+    /// precision: QUOTE_PRECISION
+    pub total_referrer_reward: u64,
+    /// Total reward to referrer this epoch
+    /// precision: QUOTE_PRECISION
+    pub current_epoch_referrer_reward: u64,
+}
+/// Serialized size of [`UserFees`]
+pub const USER_FEES_LEN: usize = 48;
+/// Offset of `total_fee_paid` in serialized [`UserFees`]
+pub const USER_FEES_TOTAL_FEE_PAID_OFFSET: usize = 0;
+/// Offset of `total_fee_rebate` in serialized [`UserFees`]
+pub const USER_FEES_TOTAL_FEE_REBATE_OFFSET: usize = 8;
+/// Offset of `total_token_discount` in serialized [`UserFees`]
+pub const USER_FEES_TOTAL_TOKEN_DISCOUNT_OFFSET: usize = 16;
+/// Offset of `total_referee_discount` in serialized [`UserFees`]
+pub const USER_FEES_TOTAL_REFEREE_DISCOUNT_OFFSET: usize = 24;
+/// Offset of `total_referrer_reward` in serialized [`UserFees`]
+pub const USER_FEES_TOTAL_REFERRER_REWARD_OFFSET: usize = 32;
+/// Offset of `current_epoch_referrer_reward` in serialized [`UserFees`]
+pub const USER_FEES_CURRENT_EPOCH_REFERRER_REWARD_OFFSET: usize = 40;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpotPosition {
+    /// The scaled balance of the position. To get the token amount, multiply by the cumulative deposit/borrow
+    /// interest of corresponding market.
+    /// precision: SPOT_BALANCE_PRECISION
+    pub scaled_balance: u64,
+    /// How many spot bids the user has open
+    /// precision: token mint precision
+    pub open_bids: i64,
+    /// How many spot asks the user has open
+    /// precision: token mint precision
+    pub open_asks: i64,
+    /// The cumulative deposits/borrows a user has made into a market
+    /// precision: token mint precision
+    pub cumulative_deposits: i64,
+    /// The market index of the corresponding spot market
+    pub market_index: u16,
+    /// Whether the position is deposit or borrow
+    pub balance_type: SpotBalanceType,
+    /// Number of open orders
+    pub open_orders: u8,
+    pub padding: [u8; 4],
+}
+/// Serialized size of [`SpotPosition`]
+pub const SPOT_POSITION_LEN: usize = 40;
+/// Offset of `scaled_balance` in serialized [`SpotPosition`]
+pub const SPOT_POSITION_SCALED_BALANCE_OFFSET: usize = 0;
+/// Offset of `open_bids` in serialized [`SpotPosition`]
+pub const SPOT_POSITION_OPEN_BIDS_OFFSET: usize = 8;
+/// Offset of `open_asks` in serialized [`SpotPosition`]
+pub const SPOT_POSITION_OPEN_ASKS_OFFSET: usize = 16;
+/// Offset of `cumulative_deposits` in serialized [`SpotPosition`]
+pub const SPOT_POSITION_CUMULATIVE_DEPOSITS_OFFSET: usize = 24;
+/// Offset of `market_index` in serialized [`SpotPosition`]
+pub const SPOT_POSITION_MARKET_INDEX_OFFSET: usize = 32;
+/// Offset of `balance_type` in serialized [`SpotPosition`]
+pub const SPOT_POSITION_BALANCE_TYPE_OFFSET: usize = 34;
+/// Offset of `open_orders` in serialized [`SpotPosition`]
+pub const SPOT_POSITION_OPEN_ORDERS_OFFSET: usize = 35;
+/// Offset of `padding` in serialized [`SpotPosition`]
+pub const SPOT_POSITION_PADDING_OFFSET: usize = 36;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerpPosition {
+    /// The perp market's last cumulative funding rate. Used to calculate the funding payment owed to user
+    /// precision: FUNDING_RATE_PRECISION
+    pub last_cumulative_funding_rate: i64,
+    /// the size of the users perp position
+    /// precision: BASE_PRECISION
+    pub base_asset_amount: i64,
+    /// Used to calculate the users pnl. Upon entry, is equal to base_asset_amount * avg entry price - fees
+    /// Updated when the user open/closes position or settles pnl. Includes fees/funding
+    /// precision: QUOTE_PRECISION
+    pub quote_asset_amount: i64,
+    /// The amount of quote the user would need to exit their position at to break even
+    /// Updated when the user open/closes position or settles pnl. Includes fees/funding
+    /// precision: QUOTE_PRECISION
+    pub quote_break_even_amount: i64,
+    /// The amount quote the user entered the position with. Equal to base asset amount * avg entry price
+    /// Updated when the user open/closes position. Excludes fees/funding
+    /// precision: QUOTE_PRECISION
+    pub quote_entry_amount: i64,
+    /// The amount of open bids the user has in this perp market
+    /// precision: BASE_PRECISION
+    pub open_bids: i64,
+    /// The amount of open asks the user has in this perp market
+    /// precision: BASE_PRECISION
+    pub open_asks: i64,
+    /// The amount of pnl settled in this market since opening the position
+    /// precision: QUOTE_PRECISION
+    pub settled_pnl: i64,
+    /// The number of lp (liquidity provider) shares the user has in this perp market
+    /// LP shares allow users to provide liquidity via the AMM
+    /// precision: BASE_PRECISION
+    pub lp_shares: u64,
+    /// The last base asset amount per lp the amm had
+    /// Used to settle the users lp position
+    /// precision: BASE_PRECISION
+    pub last_base_asset_amount_per_lp: i64,
+    /// The last quote asset amount per lp the amm had
+    /// Used to settle the users lp position
+    /// precision: QUOTE_PRECISION
+    pub last_quote_asset_amount_per_lp: i64,
+    /// Settling LP position can lead to a small amount of base asset being left over smaller than step size
+    /// This records that remainder so it can be settled later on
+    /// precision: BASE_PRECISION
+    pub remainder_base_asset_amount: i32,
+    /// The market index for the perp market
+    pub market_index: u16,
+    /// The number of open orders
+    pub open_orders: u8,
+    pub padding: [u8; 1],
+}
+/// Serialized size of [`PerpPosition`]
+pub const PERP_POSITION_LEN: usize = 96;
+/// Offset of `last_cumulative_funding_rate` in serialized [`PerpPosition`]
+pub const PERP_POSITION_LAST_CUMULATIVE_FUNDING_RATE_OFFSET: usize = 0;
+/// Offset of `base_asset_amount` in serialized [`PerpPosition`]
+pub const PERP_POSITION_BASE_ASSET_AMOUNT_OFFSET: usize = 8;
+/// Offset of `quote_asset_amount` in serialized [`PerpPosition`]
+pub const PERP_POSITION_QUOTE_ASSET_AMOUNT_OFFSET: usize = 16;
+/// Offset of `quote_break_even_amount` in serialized [`PerpPosition`]
+pub const PERP_POSITION_QUOTE_BREAK_EVEN_AMOUNT_OFFSET: usize = 24;
+/// Offset of `quote_entry_amount` in serialized [`PerpPosition`]
+pub const PERP_POSITION_QUOTE_ENTRY_AMOUNT_OFFSET: usize = 32;
+/// Offset of `open_bids` in serialized [`PerpPosition`]
+pub const PERP_POSITION_OPEN_BIDS_OFFSET: usize = 40;
+/// Offset of `open_asks` in serialized [`PerpPosition`]
+pub const PERP_POSITION_OPEN_ASKS_OFFSET: usize = 48;
+/// Offset of `settled_pnl` in serialized [`PerpPosition`]
+pub const PERP_POSITION_SETTLED_PNL_OFFSET: usize = 56;
+/// Offset of `lp_shares` in serialized [`PerpPosition`]
+pub const PERP_POSITION_LP_SHARES_OFFSET: usize = 64;
+/// Offset of `last_base_asset_amount_per_lp` in serialized [`PerpPosition`]
+pub const PERP_POSITION_LAST_BASE_ASSET_AMOUNT_PER_LP_OFFSET: usize = 72;
+/// Offset of `last_quote_asset_amount_per_lp` in serialized [`PerpPosition`]
+pub const PERP_POSITION_LAST_QUOTE_ASSET_AMOUNT_PER_LP_OFFSET: usize = 80;
+/// Offset of `remainder_base_asset_amount` in serialized [`PerpPosition`]
+pub const PERP_POSITION_REMAINDER_BASE_ASSET_AMOUNT_OFFSET: usize = 88;
+/// Offset of `market_index` in serialized [`PerpPosition`]
+pub const PERP_POSITION_MARKET_INDEX_OFFSET: usize = 92;
+/// Offset of `open_orders` in serialized [`PerpPosition`]
+pub const PERP_POSITION_OPEN_ORDERS_OFFSET: usize = 94;
+/// Offset of `padding` in serialized [`PerpPosition`]
+pub const PERP_POSITION_PADDING_OFFSET: usize = 95;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Order {
+    /// The slot the order was placed
+    pub slot: u64,
+    /// The limit price for the order (can be 0 for market orders)
+    /// For orders with an auction, this price isn't used until the auction is complete
+    /// precision: PRICE_PRECISION
+    pub price: u64,
+    /// The size of the order
+    /// precision for perps: BASE_PRECISION
+    /// precision for spot: token mint precision
+    pub base_asset_amount: u64,
+    /// The amount of the order filled
+    /// precision for perps: BASE_PRECISION
+    /// precision for spot: token mint precision
+    pub base_asset_amount_filled: u64,
+    /// The amount of quote filled for the order
+    /// precision: QUOTE_PRECISION
+    pub quote_asset_amount_filled: u64,
+    /// At what price the order will be triggered. Only relevant for trigger orders
+    /// precision: PRICE_PRECISION
+    pub trigger_price: u64,
+    /// The start price for the auction. Only relevant for market/oracle orders
+    /// precision: PRICE_PRECISION
+    pub auction_start_price: i64,
+    /// The end price for the auction. Only relevant for market/oracle orders
+    /// precision: PRICE_PRECISION
+    pub auction_end_price: i64,
+    /// The time when the order will expire
+    pub max_ts: i64,
+    /// If set, the order limit price is the oracle price + this offset
+    /// precision: PRICE_PRECISION
+    pub oracle_price_offset: i32,
+    /// The id for the order. Each users has their own order id space
+    pub order_id: u32,
+    /// The perp/spot market index
+    pub market_index: u16,
+    /// Whether the order is open or unused
+    pub status: OrderStatus,
+    /// The type of order
+    pub order_type: OrderType,
+    /// Whether market is spot or perp
+    pub market_type: MarketType,
+    /// User generated order id. Can make it easier to place/cancel orders
+    pub user_order_id: u8,
+    /// What the users position was when the order was placed
+    pub existing_position_direction: PositionDirection,
+    /// Whether the user is going long or short. LONG = bid, SHORT = ask
+    pub direction: PositionDirection,
+    /// Whether the order is allowed to only reduce position size
+    pub reduce_only: bool,
+    /// Whether the order must be a maker
+    pub post_only: bool,
+    /// Whether the order must be canceled the same slot it is placed
+    pub immediate_or_cancel: bool,
+    /// Whether the order is triggered above or below the trigger price. Only relevant for trigger orders
+    pub trigger_condition: OrderTriggerCondition,
+    /// How many slots the auction lasts
+    pub auction_duration: u8,
+    pub padding: [u8; 3],
+}
+/// Serialized size of [`Order`]
+pub const ORDER_LEN: usize = 96;
+/// Offset of `slot` in serialized [`Order`]
+pub const ORDER_SLOT_OFFSET: usize = 0;
+/// Offset of `price` in serialized [`Order`]
+pub const ORDER_PRICE_OFFSET: usize = 8;
+/// Offset of `base_asset_amount` in serialized [`Order`]
+pub const ORDER_BASE_ASSET_AMOUNT_OFFSET: usize = 16;
+/// Offset of `base_asset_amount_filled` in serialized [`Order`]
+pub const ORDER_BASE_ASSET_AMOUNT_FILLED_OFFSET: usize = 24;
+/// Offset of `quote_asset_amount_filled` in serialized [`Order`]
+pub const ORDER_QUOTE_ASSET_AMOUNT_FILLED_OFFSET: usize = 32;
+/// Offset of `trigger_price` in serialized [`Order`]
+pub const ORDER_TRIGGER_PRICE_OFFSET: usize = 40;
+/// Offset of `auction_start_price` in serialized [`Order`]
+pub const ORDER_AUCTION_START_PRICE_OFFSET: usize = 48;
+/// Offset of `auction_end_price` in serialized [`Order`]
+pub const ORDER_AUCTION_END_PRICE_OFFSET: usize = 56;
+/// Offset of `max_ts` in serialized [`Order`]
+pub const ORDER_MAX_TS_OFFSET: usize = 64;
+/// Offset of `oracle_price_offset` in serialized [`Order`]
+pub const ORDER_ORACLE_PRICE_OFFSET_OFFSET: usize = 72;
+/// Offset of `order_id` in serialized [`Order`]
+pub const ORDER_ORDER_ID_OFFSET: usize = 76;
+/// Offset of `market_index` in serialized [`Order`]
+pub const ORDER_MARKET_INDEX_OFFSET: usize = 80;
+/// Offset of `status` in serialized [`Order`]
+pub const ORDER_STATUS_OFFSET: usize = 82;
+/// Offset of `order_type` in serialized [`Order`]
+pub const ORDER_ORDER_TYPE_OFFSET: usize = 83;
+/// Offset of `market_type` in serialized [`Order`]
+pub const ORDER_MARKET_TYPE_OFFSET: usize = 84;
+/// Offset of `user_order_id` in serialized [`Order`]
+pub const ORDER_USER_ORDER_ID_OFFSET: usize = 85;
+/// Offset of `existing_position_direction` in serialized [`Order`]
+pub const ORDER_EXISTING_POSITION_DIRECTION_OFFSET: usize = 86;
+/// Offset of `direction` in serialized [`Order`]
+pub const ORDER_DIRECTION_OFFSET: usize = 87;
+/// Offset of `reduce_only` in serialized [`Order`]
+pub const ORDER_REDUCE_ONLY_OFFSET: usize = 88;
+/// Offset of `post_only` in serialized [`Order`]
+pub const ORDER_POST_ONLY_OFFSET: usize = 89;
+/// Offset of `immediate_or_cancel` in serialized [`Order`]
+pub const ORDER_IMMEDIATE_OR_CANCEL_OFFSET: usize = 90;
+/// Offset of `trigger_condition` in serialized [`Order`]
+pub const ORDER_TRIGGER_CONDITION_OFFSET: usize = 91;
+/// Offset of `auction_duration` in serialized [`Order`]
+pub const ORDER_AUCTION_DURATION_OFFSET: usize = 92;
+/// Offset of `padding` in serialized [`Order`]
+pub const ORDER_PADDING_OFFSET: usize = 93;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SwapDirection {
+    Add,
+    Remove,
+}
+/// Serialized size of [`SwapDirection`]
+pub const SWAP_DIRECTION_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModifyOrderId {
+    UserOrderId(u8),
+    OrderId(u32),
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PositionDirection {
+    Long,
+    Short,
+}
+/// Serialized size of [`PositionDirection`]
+pub const POSITION_DIRECTION_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpotFulfillmentType {
+    SerumV3,
+    Match,
+    PhoenixV1,
+}
+/// Serialized size of [`SpotFulfillmentType`]
+pub const SPOT_FULFILLMENT_TYPE_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PostOnlyParam {
+    None,
+    MustPostOnly,
+    TryPostOnly,
+}
+/// Serialized size of [`PostOnlyParam`]
+pub const POST_ONLY_PARAM_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModifyOrderPolicy {
+    TryModify,
+    MustModify,
+}
+/// Serialized size of [`ModifyOrderPolicy`]
+pub const MODIFY_ORDER_POLICY_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SwapReduceOnly {
+    In,
+    Out,
+}
+/// Serialized size of [`SwapReduceOnly`]
+pub const SWAP_REDUCE_ONLY_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TwapPeriod {
+    FundingPeriod,
+    FiveMin,
+}
+/// Serialized size of [`TwapPeriod`]
+pub const TWAP_PERIOD_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LiquidationMultiplierType {
+    Discount,
+    Premium,
+}
+/// Serialized size of [`LiquidationMultiplierType`]
+pub const LIQUIDATION_MULTIPLIER_TYPE_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MarginRequirementType {
+    Initial,
+    Maintenance,
+}
+/// Serialized size of [`MarginRequirementType`]
+pub const MARGIN_REQUIREMENT_TYPE_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OracleValidity {
+    Invalid,
+    TooVolatile,
+    TooUncertain,
+    StaleForMargin,
+    InsufficientDataPoints,
+    StaleForAmm,
+    Valid,
+}
+/// Serialized size of [`OracleValidity`]
+pub const ORACLE_VALIDITY_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DriftAction {
+    UpdateFunding,
+    SettlePnl,
+    TriggerOrder,
+    FillOrderMatch,
+    FillOrderAmm,
+    Liquidate,
+    MarginCalc,
+    UpdateTwap,
+    UpdateAmmCurve,
+}
+/// Serialized size of [`DriftAction`]
+pub const DRIFT_ACTION_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PositionUpdateType {
+    Open,
+    Increase,
+    Reduce,
+    Close,
+    Flip,
+}
+/// Serialized size of [`PositionUpdateType`]
+pub const POSITION_UPDATE_TYPE_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DepositExplanation {
+    None,
+    Transfer,
+}
+/// Serialized size of [`DepositExplanation`]
+pub const DEPOSIT_EXPLANATION_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DepositDirection {
+    Deposit,
+    Withdraw,
+}
+/// Serialized size of [`DepositDirection`]
+pub const DEPOSIT_DIRECTION_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrderAction {
+    Place,
+    Cancel,
+    Fill,
+    Trigger,
+    Expire,
+}
+/// Serialized size of [`OrderAction`]
+pub const ORDER_ACTION_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrderActionExplanation {
+    None,
+    InsufficientFreeCollateral,
+    OraclePriceBreachedLimitPrice,
+    MarketOrderFilledToLimitPrice,
+    OrderExpired,
+    Liquidation,
+    OrderFilledWithAmm,
+    OrderFilledWithAmmJit,
+    OrderFilledWithMatch,
+    OrderFilledWithMatchJit,
+    MarketExpired,
+    RiskingIncreasingOrder,
+    ReduceOnlyOrderIncreasedPosition,
+    OrderFillWithSerum,
+    NoBorrowLiquidity,
+    OrderFillWithPhoenix,
+    OrderFilledWithAmmJitLpSplit,
+    OrderFilledWithLpJit,
+}
+/// Serialized size of [`OrderActionExplanation`]
+pub const ORDER_ACTION_EXPLANATION_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LPAction {
+    AddLiquidity,
+    RemoveLiquidity,
+    SettleLiquidity,
+}
+/// Serialized size of [`LPAction`]
+pub const LP_ACTION_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LiquidationType {
+    LiquidatePerp,
+    LiquidateSpot,
+    LiquidateBorrowForPerpPnl,
+    LiquidatePerpPnlForDeposit,
+    PerpBankruptcy,
+    SpotBankruptcy,
+}
+/// Serialized size of [`LiquidationType`]
+pub const LIQUIDATION_TYPE_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SettlePnlExplanation {
+    None,
+    ExpiredPosition,
+}
+/// Serialized size of [`SettlePnlExplanation`]
+pub const SETTLE_PNL_EXPLANATION_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StakeAction {
+    Stake,
+    UnstakeRequest,
+    UnstakeCancelRequest,
+    Unstake,
+}
+/// Serialized size of [`StakeAction`]
+pub const STAKE_ACTION_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PerpFulfillmentMethod {
+    Amm(Option<u64>),
+    Match(Pubkey, u16),
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpotFulfillmentMethod {
+    ExternalMarket,
+    Match,
+}
+/// Serialized size of [`SpotFulfillmentMethod`]
+pub const SPOT_FULFILLMENT_METHOD_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OracleSource {
+    Pyth,
+    Switchboard,
+    QuoteAsset,
+    Pyth1K,
+    Pyth1M,
+    PythStableCoin,
+}
+/// Serialized size of [`OracleSource`]
+pub const ORACLE_SOURCE_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MarketStatus {
+    Initialized,
+    Active,
+    FundingPaused,
+    AmmPaused,
+    FillPaused,
+    WithdrawPaused,
+    ReduceOnly,
+    Settlement,
+    Delisted,
+}
+/// Serialized size of [`MarketStatus`]
+pub const MARKET_STATUS_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContractType {
+    Perpetual,
+    Future,
+}
+/// Serialized size of [`ContractType`]
+pub const CONTRACT_TYPE_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContractTier {
+    A,
+    B,
+    C,
+    Speculative,
+    Isolated,
+}
+/// Serialized size of [`ContractTier`]
+pub const CONTRACT_TIER_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AMMLiquiditySplit {
+    ProtocolOwned,
+    LpOwned,
+    Shared,
+}
+/// Serialized size of [`AMMLiquiditySplit`]
+pub const AMM_LIQUIDITY_SPLIT_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpotBalanceType {
+    Deposit,
+    Borrow,
+}
+/// Serialized size of [`SpotBalanceType`]
+pub const SPOT_BALANCE_TYPE_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpotFulfillmentConfigStatus {
+    Enabled,
+    Disabled,
+}
+/// Serialized size of [`SpotFulfillmentConfigStatus`]
+pub const SPOT_FULFILLMENT_CONFIG_STATUS_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssetTier {
+    Collateral,
+    Protected,
+    Cross,
+    Isolated,
+    Unlisted,
+}
+/// Serialized size of [`AssetTier`]
+pub const ASSET_TIER_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExchangeStatus {
+    DepositPaused,
+    WithdrawPaused,
+    AmmPaused,
+    FillPaused,
+    LiqPaused,
+    FundingPaused,
+    SettlePnlPaused,
+}
+/// Serialized size of [`ExchangeStatus`]
+pub const EXCHANGE_STATUS_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UserStatus {
+    Active,
+    BeingLiquidated,
+    Bankrupt,
+}
+/// Serialized size of [`UserStatus`]
+pub const USER_STATUS_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssetType {
+    Base,
+    Quote,
+}
+/// Serialized size of [`AssetType`]
+pub const ASSET_TYPE_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrderStatus {
+    Init,
+    Open,
+    Filled,
+    Canceled,
+}
+/// Serialized size of [`OrderStatus`]
+pub const ORDER_STATUS_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrderType {
+    Market,
+    Limit,
+    TriggerMarket,
+    TriggerLimit,
+    Oracle,
+}
+/// Serialized size of [`OrderType`]
+pub const ORDER_TYPE_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrderTriggerCondition {
+    Above,
+    Below,
+    TriggeredAbove,
+    TriggeredBelow,
+}
+/// Serialized size of [`OrderTriggerCondition`]
+pub const ORDER_TRIGGER_CONDITION_LEN: usize = 1;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MarketType {
+    Spot,
+    Perp,
+}
+/// Serialized size of [`MarketType`]
+pub const MARKET_TYPE_LEN: usize = 1;
@@ -0,0 +1,130 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+pub const GENERIC_ACCOUNT_ACCOUNT_DISCM: [u8; 8] = [10, 71, 68, 49, 51, 72, 147, 245];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericAccount {
+    pub data: GenericType<u32, u64, 10>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenericAccountAccount(pub GenericAccount);
+impl GenericAccountAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != GENERIC_ACCOUNT_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    GENERIC_ACCOUNT_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(GenericAccount::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&GENERIC_ACCOUNT_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Offset of `data` in serialized [`GenericAccount`]
+pub const GENERIC_ACCOUNT_DATA_OFFSET: usize = 0;
+/// Builds `getProgramAccounts` filters for [`GenericAccountAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GenericAccountAccountFilters(Vec<AccountFilter>);
+impl GenericAccountAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: GENERIC_ACCOUNT_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches accounts whose `data` equals `value`
+    pub fn data_eq(mut self, value: &GenericType<u32, u64, 10>) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + GENERIC_ACCOUNT_DATA_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl GenericAccountAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> GenericAccountAccountFilters {
+        GenericAccountAccountFilters::default()
+    }
+}
+/// A `getProgramAccounts` filter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountFilter {
+    /// Account data is exactly this many bytes long
+    DataSize(u64),
+    /// Account data at `offset` starts with `bytes`
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+#[cfg(feature = "solana-rpc-client-api")]
+impl From<AccountFilter> for solana_rpc_client_api::filter::RpcFilterType {
+    fn from(filter: AccountFilter) -> Self {
+        match filter {
+            AccountFilter::DataSize(size) => Self::DataSize(size),
+            AccountFilter::Memcmp { offset, bytes } => Self::Memcmp(
+                solana_rpc_client_api::filter::Memcmp::new_base58_encoded(offset, &bytes),
+            ),
+        }
+    }
+}
+/// The 8-byte discriminator that account data starts with, None if the data is too short
+pub fn discriminator_of(data: &[u8]) -> Option<[u8; 8]> {
+    data.get(..8).map(|discm| discm.try_into().unwrap())
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenericsAccount {
+    GenericAccount(GenericAccount),
+}
+impl GenericsAccount {
+    /// Deserializes account data into the account type its discriminator identifies
+    pub fn try_from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        let mut reader = data;
+        let maybe_discm = <[u8; 8]>::deserialize(&mut reader)?;
+        match maybe_discm {
+            GENERIC_ACCOUNT_ACCOUNT_DISCM => {
+                GenericAccountAccount::deserialize(data).map(|a| Self::GenericAccount(a.0))
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+}
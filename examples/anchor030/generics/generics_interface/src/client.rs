@@ -0,0 +1,59 @@
+use crate::*;
+use solana_account_decoder::UiAccountEncoding;
+use solana_program::pubkey::Pubkey;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::Result as ClientResult;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::RpcFilterType,
+};
+use solana_sdk::{
+    message::Message, signature::Signature, signer::Signer, transaction::Transaction,
+};
+/// Fetches and deserializes the [`GenericAccountAccount`] at `pubkey`
+pub fn fetch_generic_account(
+    client: &RpcClient,
+    pubkey: &Pubkey,
+) -> ClientResult<GenericAccountAccount> {
+    let data = client.get_account_data(pubkey)?;
+    Ok(GenericAccountAccount::deserialize(&data)?)
+}
+/// Fetches all [`GenericAccountAccount`]s of this program that match `filters` in addition to the discriminator
+pub fn fetch_all_generic_account(
+    client: &RpcClient,
+    filters: Vec<AccountFilter>,
+) -> ClientResult<Vec<(Pubkey, GenericAccountAccount)>> {
+    let filters = GenericAccountAccount::filters()
+        .discriminator()
+        .build()
+        .into_iter()
+        .chain(filters)
+        .map(RpcFilterType::from)
+        .collect();
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client
+        .get_program_accounts_with_config(&crate::ID, config)?
+        .into_iter()
+        .map(|(pubkey, account)| Ok((pubkey, GenericAccountAccount::deserialize(&account.data)?)))
+        .collect()
+}
+/// Sends a transaction of [`generic_ix`] signed by `signers` with `payer` as the fee payer and waits for confirmation
+pub fn send_generic(
+    client: &RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    keys: GenericKeys,
+    args: GenericIxArgs,
+) -> ClientResult<Signature> {
+    let ix = generic_ix(keys, args)?;
+    let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+    tx.try_sign(signers, client.get_latest_blockhash()?)?;
+    client.send_and_confirm_transaction(&tx)
+}
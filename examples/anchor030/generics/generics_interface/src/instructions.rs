@@ -0,0 +1,425 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenericsProgramIx {
+    Generic(GenericIxArgs),
+}
+impl GenericsProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            GENERIC_IX_DISCM => Ok(Self::Generic(GenericIxArgs::deserialize(&mut reader)?)),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::Generic(args) => {
+                writer.write_all(&GENERIC_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const GENERIC_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct GenericAccounts<'me, 'info> {
+    pub generic_acc: &'me AccountInfo<'info>,
+    pub payer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GenericKeys {
+    pub generic_acc: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl GenericKeys {
+    /// Creates the keys from the accounts that cannot be derived.
+    /// Accounts with a fixed address and PDAs are derived.
+    pub fn new(generic_acc: Pubkey, payer: Pubkey) -> Self {
+        let system_program = solana_program::pubkey!("11111111111111111111111111111111");
+        Self {
+            generic_acc,
+            payer,
+            system_program,
+        }
+    }
+}
+impl From<GenericAccounts<'_, '_>> for GenericKeys {
+    fn from(accounts: GenericAccounts) -> Self {
+        Self {
+            generic_acc: *accounts.generic_acc.key,
+            payer: *accounts.payer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<GenericKeys> for [AccountMeta; GENERIC_IX_ACCOUNTS_LEN] {
+    fn from(keys: GenericKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.generic_acc,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; GENERIC_IX_ACCOUNTS_LEN]> for GenericKeys {
+    fn from(pubkeys: [Pubkey; GENERIC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            generic_acc: pubkeys[0],
+            payer: pubkeys[1],
+            system_program: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<GenericAccounts<'_, 'info>> for [AccountInfo<'info>; GENERIC_IX_ACCOUNTS_LEN] {
+    fn from(accounts: GenericAccounts<'_, 'info>) -> Self {
+        [
+            accounts.generic_acc.clone(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; GENERIC_IX_ACCOUNTS_LEN]>
+    for GenericAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; GENERIC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            generic_acc: &arr[0],
+            payer: &arr[1],
+            system_program: &arr[2],
+        }
+    }
+}
+impl GenericKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; GENERIC_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..GENERIC_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[GENERIC_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for GenericKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> GenericAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; GENERIC_IX_ACCOUNTS_LEN] = accounts
+            .get(..GENERIC_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[GENERIC_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for GenericAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const GENERIC_IX_DISCM: [u8; 8] = [63, 235, 150, 148, 7, 255, 185, 159];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericIxArgs {
+    pub generic_field: GenericType<u32, u64, 10>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenericIxData(pub GenericIxArgs);
+impl From<GenericIxArgs> for GenericIxData {
+    fn from(args: GenericIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl GenericIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != GENERIC_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    GENERIC_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(GenericIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&GENERIC_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn generic_ix_with_program_id(
+    program_id: Pubkey,
+    keys: GenericKeys,
+    args: GenericIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; GENERIC_IX_ACCOUNTS_LEN] = keys.into();
+    let data: GenericIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn generic_ix(keys: GenericKeys, args: GenericIxArgs) -> std::io::Result<Instruction> {
+    generic_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`generic_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct GenericIxBuilder {
+    generic_acc: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    generic_field: Option<GenericType<u32, u64, 10>>,
+}
+impl Default for GenericIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl GenericIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            generic_acc: None,
+            payer: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            generic_field: None,
+        }
+    }
+    pub fn generic_acc(mut self, generic_acc: Pubkey) -> Self {
+        self.generic_acc = Some(generic_acc);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn generic_field(mut self, generic_field: GenericType<u32, u64, 10>) -> Self {
+        self.generic_field = Some(generic_field);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("GenericIxBuilder is missing {}", field),
+            )
+        };
+        let generic_acc = self.generic_acc.ok_or_else(|| missing("generic_acc"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = GenericIxArgs {
+            generic_field: self.generic_field.ok_or_else(|| missing("generic_field"))?,
+        };
+        let keys = GenericKeys {
+            generic_acc,
+            payer,
+            system_program,
+        };
+        generic_ix(keys, args)
+    }
+}
+pub fn generic_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: GenericAccounts<'_, '_>,
+    args: GenericIxArgs,
+) -> ProgramResult {
+    let keys: GenericKeys = accounts.into();
+    let ix = generic_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn generic_invoke(accounts: GenericAccounts<'_, '_>, args: GenericIxArgs) -> ProgramResult {
+    generic_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn generic_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: GenericAccounts<'_, '_>,
+    args: GenericIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: GenericKeys = accounts.into();
+    let ix = generic_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn generic_invoke_signed(
+    accounts: GenericAccounts<'_, '_>,
+    args: GenericIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    generic_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn generic_verify_account_keys(
+    accounts: GenericAccounts<'_, '_>,
+    keys: GenericKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.generic_acc.key, keys.generic_acc),
+        (*accounts.payer.key, keys.payer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn generic_verify_writable_privileges<'me, 'info>(
+    accounts: GenericAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.generic_acc, accounts.payer] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn generic_verify_signer_privileges<'me, 'info>(
+    accounts: GenericAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.generic_acc, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn generic_verify_account_privileges<'me, 'info>(
+    accounts: GenericAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    generic_verify_writable_privileges(accounts)?;
+    generic_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+/// A [`GenericsProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenericsParsedIx {
+    Generic {
+        keys: GenericKeys,
+        args: GenericIxArgs,
+    },
+}
+impl GenericsParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = GenericsProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            GenericsProgramIx::Generic(args) => Self::Generic {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
@@ -0,0 +1,64 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GenericEnum<
+    T: BorshSerialize + BorshDeserialize,
+    U: BorshSerialize + BorshDeserialize,
+    const N: usize,
+> {
+    Unnamed(T, U),
+    Named { gen1: T, gen2: U },
+    Struct(GenericNested<T, U>),
+    Arr([T; N]),
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericNested<V: BorshSerialize + BorshDeserialize, Z: BorshSerialize + BorshDeserialize>
+{
+    pub gen1: V,
+    pub gen2: Z,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericType<
+    T: BorshSerialize + BorshDeserialize,
+    U: BorshSerialize + BorshDeserialize,
+    const N: usize,
+> {
+    pub gen1: T,
+    pub gen2: U,
+    pub gen3: GenericNested<u32, U>,
+    pub gen4: GenericNested<T, MyStruct>,
+    pub gen5: GenericNested<T, U>,
+    pub gen6: GenericNested<u32, u64>,
+    pub gen7: GenericNested<T, GenericNested<T, U>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Hex>")
+    )]
+    pub arr: [u8; N],
+    pub warr: WrappedU8Array<N>,
+    pub warrval: WrappedU8Array<10>,
+    pub enm1: GenericEnum<T, U, N>,
+    pub enm2: GenericEnum<GenericNested<T, u64>, u32, 30>,
+    pub owner: Option<GenericNested<Pubkey, T>>,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MyStruct {
+    pub some_field: u8,
+}
+/// Serialized size of [`MyStruct`]
+pub const MY_STRUCT_LEN: usize = 1;
+/// Offset of `some_field` in serialized [`MyStruct`]
+pub const MY_STRUCT_SOME_FIELD_OFFSET: usize = 0;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WrappedU8Array<const N: usize>(
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Hex>")
+    )]
+    pub [u8; N],
+);
@@ -0,0 +1,864 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum Anchor030IxOptionalAccountsProgramIx {
+    OptionalAccountsIx(OptionalAccountsIxIxArgs),
+    AllOptionalIx,
+}
+impl Anchor030IxOptionalAccountsProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            OPTIONAL_ACCOUNTS_IX_IX_DISCM => Ok(Self::OptionalAccountsIx(
+                OptionalAccountsIxIxArgs::deserialize(&mut reader)?,
+            )),
+            ALL_OPTIONAL_IX_IX_DISCM => Ok(Self::AllOptionalIx),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::OptionalAccountsIx(args) => {
+                writer.write_all(&OPTIONAL_ACCOUNTS_IX_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::AllOptionalIx => writer.write_all(&ALL_OPTIONAL_IX_IX_DISCM),
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct OptionalAccountsIxAccounts<'me, 'info> {
+    pub payer: &'me AccountInfo<'info>,
+    pub optional_writable: Option<&'me AccountInfo<'info>>,
+    pub optional_signer: Option<&'me AccountInfo<'info>>,
+    pub optional_readonly: Option<&'me AccountInfo<'info>>,
+    pub config: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OptionalAccountsIxKeys {
+    pub payer: Pubkey,
+    pub optional_writable: Option<Pubkey>,
+    pub optional_signer: Option<Pubkey>,
+    pub optional_readonly: Option<Pubkey>,
+    pub config: Pubkey,
+    pub system_program: Pubkey,
+}
+impl OptionalAccountsIxKeys {
+    /// Creates the keys from the accounts that cannot be derived.
+    /// Accounts with a fixed address and PDAs are derived.
+    pub fn new(
+        payer: Pubkey,
+        optional_writable: Option<Pubkey>,
+        optional_signer: Option<Pubkey>,
+        optional_readonly: Option<Pubkey>,
+    ) -> Self {
+        let system_program = solana_program::pubkey!("11111111111111111111111111111111");
+        let config = find_config_address().0;
+        Self {
+            payer,
+            optional_writable,
+            optional_signer,
+            optional_readonly,
+            config,
+            system_program,
+        }
+    }
+}
+impl From<OptionalAccountsIxAccounts<'_, '_>> for OptionalAccountsIxKeys {
+    fn from(accounts: OptionalAccountsIxAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            optional_writable: accounts.optional_writable.map(|a| *a.key),
+            optional_signer: accounts.optional_signer.map(|a| *a.key),
+            optional_readonly: accounts.optional_readonly.map(|a| *a.key),
+            config: *accounts.config.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl OptionalAccountsIxKeys {
+    /// Absent optional accounts are replaced with `program_id`,
+    /// the ID of the program being invoked
+    pub fn to_metas(
+        self,
+        program_id: Pubkey,
+    ) -> [AccountMeta; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN] {
+        [
+            AccountMeta {
+                pubkey: self.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            match self.optional_writable {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            },
+            match self.optional_signer {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: true,
+                    is_writable: false,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            },
+            match self.optional_readonly {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            },
+            AccountMeta {
+                pubkey: self.config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: self.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<OptionalAccountsIxKeys> for [AccountMeta; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN] {
+    /// Absent optional accounts are replaced with `crate::ID`
+    fn from(keys: OptionalAccountsIxKeys) -> Self {
+        keys.to_metas(crate::ID)
+    }
+}
+impl From<(Pubkey, [Pubkey; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN])> for OptionalAccountsIxKeys {
+    /// Keys equal to `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
+    fn from(
+        (program_id, pubkeys): (Pubkey, [Pubkey; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN]),
+    ) -> Self {
+        Self {
+            payer: pubkeys[0],
+            optional_writable: Some(pubkeys[1]).filter(|k| *k != program_id),
+            optional_signer: Some(pubkeys[2]).filter(|k| *k != program_id),
+            optional_readonly: Some(pubkeys[3]).filter(|k| *k != program_id),
+            config: pubkeys[4],
+            system_program: pubkeys[5],
+        }
+    }
+}
+impl From<[Pubkey; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN]> for OptionalAccountsIxKeys {
+    /// Keys equal to `crate::ID` are absent optional accounts
+    fn from(pubkeys: [Pubkey; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN]) -> Self {
+        Self::from((crate::ID, pubkeys))
+    }
+}
+impl<'info> OptionalAccountsIxAccounts<'_, 'info> {
+    /// Absent optional accounts are replaced with `program`,
+    /// the AccountInfo of the program being invoked
+    pub fn to_account_infos(
+        self,
+        program: &AccountInfo<'info>,
+    ) -> [AccountInfo<'info>; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN] {
+        [
+            self.payer.clone(),
+            self.optional_writable.unwrap_or(program).clone(),
+            self.optional_signer.unwrap_or(program).clone(),
+            self.optional_readonly.unwrap_or(program).clone(),
+            self.config.clone(),
+            self.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info>
+    From<(
+        Pubkey,
+        &'me [AccountInfo<'info>; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN],
+    )> for OptionalAccountsIxAccounts<'me, 'info>
+{
+    /// Accounts with key `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
+    fn from(
+        (program_id, arr): (
+            Pubkey,
+            &'me [AccountInfo<'info>; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN],
+        ),
+    ) -> Self {
+        Self {
+            payer: &arr[0],
+            optional_writable: Some(&arr[1]).filter(|a| *a.key != program_id),
+            optional_signer: Some(&arr[2]).filter(|a| *a.key != program_id),
+            optional_readonly: Some(&arr[3]).filter(|a| *a.key != program_id),
+            config: &arr[4],
+            system_program: &arr[5],
+        }
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN]>
+    for OptionalAccountsIxAccounts<'me, 'info>
+{
+    /// Accounts with key `crate::ID` are absent optional accounts
+    fn from(arr: &'me [AccountInfo<'info>; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN]) -> Self {
+        Self::from((crate::ID, arr))
+    }
+}
+impl OptionalAccountsIxKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for OptionalAccountsIxKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> OptionalAccountsIxAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN] = accounts
+            .get(..OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[OPTIONAL_ACCOUNTS_IX_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for OptionalAccountsIxAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const OPTIONAL_ACCOUNTS_IX_IX_DISCM: [u8; 8] = [96, 18, 121, 249, 6, 130, 79, 90];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptionalAccountsIxIxArgs {
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptionalAccountsIxIxData(pub OptionalAccountsIxIxArgs);
+impl From<OptionalAccountsIxIxArgs> for OptionalAccountsIxIxData {
+    fn from(args: OptionalAccountsIxIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl OptionalAccountsIxIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != OPTIONAL_ACCOUNTS_IX_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    OPTIONAL_ACCOUNTS_IX_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(OptionalAccountsIxIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&OPTIONAL_ACCOUNTS_IX_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn optional_accounts_ix_ix_with_program_id(
+    program_id: Pubkey,
+    keys: OptionalAccountsIxKeys,
+    args: OptionalAccountsIxIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas = keys.to_metas(program_id);
+    let data: OptionalAccountsIxIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn optional_accounts_ix_ix(
+    keys: OptionalAccountsIxKeys,
+    args: OptionalAccountsIxIxArgs,
+) -> std::io::Result<Instruction> {
+    optional_accounts_ix_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`optional_accounts_ix_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct OptionalAccountsIxIxBuilder {
+    payer: Option<Pubkey>,
+    optional_writable: Option<Pubkey>,
+    optional_signer: Option<Pubkey>,
+    optional_readonly: Option<Pubkey>,
+    config: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    amount: Option<u64>,
+}
+impl Default for OptionalAccountsIxIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl OptionalAccountsIxIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            payer: None,
+            optional_writable: None,
+            optional_signer: None,
+            optional_readonly: None,
+            config: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            amount: None,
+        }
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn optional_writable(mut self, optional_writable: Pubkey) -> Self {
+        self.optional_writable = Some(optional_writable);
+        self
+    }
+    pub fn optional_signer(mut self, optional_signer: Pubkey) -> Self {
+        self.optional_signer = Some(optional_signer);
+        self
+    }
+    pub fn optional_readonly(mut self, optional_readonly: Pubkey) -> Self {
+        self.optional_readonly = Some(optional_readonly);
+        self
+    }
+    pub fn config(mut self, config: Pubkey) -> Self {
+        self.config = Some(config);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Errors naming the first account or arg that was not set.
+    /// PDAs that were not set are derived
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("OptionalAccountsIxIxBuilder is missing {}", field),
+            )
+        };
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let optional_writable = self.optional_writable;
+        let optional_signer = self.optional_signer;
+        let optional_readonly = self.optional_readonly;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = OptionalAccountsIxIxArgs {
+            amount: self.amount.ok_or_else(|| missing("amount"))?,
+        };
+        let config = self.config.unwrap_or_else(|| find_config_address().0);
+        let keys = OptionalAccountsIxKeys {
+            payer,
+            optional_writable,
+            optional_signer,
+            optional_readonly,
+            config,
+            system_program,
+        };
+        optional_accounts_ix_ix(keys, args)
+    }
+}
+pub fn optional_accounts_ix_invoke_with_program_id<'info>(
+    program_id: Pubkey,
+    accounts: OptionalAccountsIxAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
+    args: OptionalAccountsIxIxArgs,
+) -> ProgramResult {
+    let keys: OptionalAccountsIxKeys = accounts.into();
+    let ix = optional_accounts_ix_ix_with_program_id(program_id, keys, args)?;
+    invoke(&ix, &accounts.to_account_infos(program))
+}
+pub fn optional_accounts_ix_invoke<'info>(
+    accounts: OptionalAccountsIxAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
+    args: OptionalAccountsIxIxArgs,
+) -> ProgramResult {
+    optional_accounts_ix_invoke_with_program_id(crate::ID, accounts, program, args)
+}
+pub fn optional_accounts_ix_invoke_signed_with_program_id<'info>(
+    program_id: Pubkey,
+    accounts: OptionalAccountsIxAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
+    args: OptionalAccountsIxIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: OptionalAccountsIxKeys = accounts.into();
+    let ix = optional_accounts_ix_ix_with_program_id(program_id, keys, args)?;
+    invoke_signed(&ix, &accounts.to_account_infos(program), seeds)
+}
+pub fn optional_accounts_ix_invoke_signed<'info>(
+    accounts: OptionalAccountsIxAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
+    args: OptionalAccountsIxIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    optional_accounts_ix_invoke_signed_with_program_id(crate::ID, accounts, program, args, seeds)
+}
+/// Optional accounts must be present in both `accounts` and `keys` or absent from both.
+/// An absent account or key is returned as `crate::ID` in the mismatch
+pub fn optional_accounts_ix_verify_account_keys(
+    accounts: OptionalAccountsIxAccounts<'_, '_>,
+    keys: OptionalAccountsIxKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (Some(*accounts.payer.key), Some(keys.payer)),
+        (
+            accounts.optional_writable.map(|a| *a.key),
+            keys.optional_writable,
+        ),
+        (
+            accounts.optional_signer.map(|a| *a.key),
+            keys.optional_signer,
+        ),
+        (
+            accounts.optional_readonly.map(|a| *a.key),
+            keys.optional_readonly,
+        ),
+        (Some(*accounts.config.key), Some(keys.config)),
+        (
+            Some(*accounts.system_program.key),
+            Some(keys.system_program),
+        ),
+    ] {
+        if actual != expected {
+            return Err((actual.unwrap_or(crate::ID), expected.unwrap_or(crate::ID)));
+        }
+    }
+    Ok(())
+}
+pub fn optional_accounts_ix_verify_writable_privileges<'me, 'info>(
+    accounts: OptionalAccountsIxAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [Some(accounts.payer), accounts.optional_writable]
+        .into_iter()
+        .flatten()
+    {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn optional_accounts_ix_verify_signer_privileges<'me, 'info>(
+    accounts: OptionalAccountsIxAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [Some(accounts.payer), accounts.optional_signer]
+        .into_iter()
+        .flatten()
+    {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn optional_accounts_ix_verify_account_privileges<'me, 'info>(
+    accounts: OptionalAccountsIxAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    optional_accounts_ix_verify_writable_privileges(accounts)?;
+    optional_accounts_ix_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone, Debug)]
+pub struct AllOptionalIxAccounts<'me, 'info> {
+    pub a: Option<&'me AccountInfo<'info>>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AllOptionalIxKeys {
+    pub a: Option<Pubkey>,
+}
+impl From<AllOptionalIxAccounts<'_, '_>> for AllOptionalIxKeys {
+    fn from(accounts: AllOptionalIxAccounts) -> Self {
+        Self {
+            a: accounts.a.map(|a| *a.key),
+        }
+    }
+}
+impl AllOptionalIxKeys {
+    /// Absent optional accounts are replaced with `program_id`,
+    /// the ID of the program being invoked
+    pub fn to_metas(self, program_id: Pubkey) -> [AccountMeta; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN] {
+        [match self.a {
+            Some(pubkey) => AccountMeta {
+                pubkey,
+                is_signer: true,
+                is_writable: true,
+            },
+            None => AccountMeta {
+                pubkey: program_id,
+                is_signer: false,
+                is_writable: false,
+            },
+        }]
+    }
+}
+impl From<AllOptionalIxKeys> for [AccountMeta; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN] {
+    /// Absent optional accounts are replaced with `crate::ID`
+    fn from(keys: AllOptionalIxKeys) -> Self {
+        keys.to_metas(crate::ID)
+    }
+}
+impl From<(Pubkey, [Pubkey; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN])> for AllOptionalIxKeys {
+    /// Keys equal to `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
+    fn from((program_id, pubkeys): (Pubkey, [Pubkey; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN])) -> Self {
+        Self {
+            a: Some(pubkeys[0]).filter(|k| *k != program_id),
+        }
+    }
+}
+impl From<[Pubkey; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN]> for AllOptionalIxKeys {
+    /// Keys equal to `crate::ID` are absent optional accounts
+    fn from(pubkeys: [Pubkey; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN]) -> Self {
+        Self::from((crate::ID, pubkeys))
+    }
+}
+impl<'info> AllOptionalIxAccounts<'_, 'info> {
+    /// Absent optional accounts are replaced with `program`,
+    /// the AccountInfo of the program being invoked
+    pub fn to_account_infos(
+        self,
+        program: &AccountInfo<'info>,
+    ) -> [AccountInfo<'info>; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN] {
+        [self.a.unwrap_or(program).clone()]
+    }
+}
+impl<'me, 'info>
+    From<(
+        Pubkey,
+        &'me [AccountInfo<'info>; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN],
+    )> for AllOptionalIxAccounts<'me, 'info>
+{
+    /// Accounts with key `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
+    fn from(
+        (program_id, arr): (
+            Pubkey,
+            &'me [AccountInfo<'info>; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN],
+        ),
+    ) -> Self {
+        Self {
+            a: Some(&arr[0]).filter(|a| *a.key != program_id),
+        }
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN]>
+    for AllOptionalIxAccounts<'me, 'info>
+{
+    /// Accounts with key `crate::ID` are absent optional accounts
+    fn from(arr: &'me [AccountInfo<'info>; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN]) -> Self {
+        Self::from((crate::ID, arr))
+    }
+}
+impl AllOptionalIxKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AllOptionalIxKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AllOptionalIxAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN] = accounts
+            .get(..ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[ALL_OPTIONAL_IX_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AllOptionalIxAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const ALL_OPTIONAL_IX_IX_DISCM: [u8; 8] = [247, 245, 67, 178, 99, 121, 214, 225];
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllOptionalIxIxData;
+impl AllOptionalIxIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != ALL_OPTIONAL_IX_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ALL_OPTIONAL_IX_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&ALL_OPTIONAL_IX_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn all_optional_ix_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AllOptionalIxKeys,
+) -> std::io::Result<Instruction> {
+    let metas = keys.to_metas(program_id);
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: AllOptionalIxIxData.try_to_vec()?,
+    })
+}
+pub fn all_optional_ix_ix(keys: AllOptionalIxKeys) -> std::io::Result<Instruction> {
+    all_optional_ix_ix_with_program_id(crate::ID, keys)
+}
+/// Builds a [`all_optional_ix_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AllOptionalIxIxBuilder {
+    a: Option<Pubkey>,
+}
+impl Default for AllOptionalIxIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AllOptionalIxIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self { a: None }
+    }
+    pub fn a(mut self, a: Pubkey) -> Self {
+        self.a = Some(a);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let a = self.a;
+        let keys = AllOptionalIxKeys { a };
+        all_optional_ix_ix(keys)
+    }
+}
+pub fn all_optional_ix_invoke_with_program_id<'info>(
+    program_id: Pubkey,
+    accounts: AllOptionalIxAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
+) -> ProgramResult {
+    let keys: AllOptionalIxKeys = accounts.into();
+    let ix = all_optional_ix_ix_with_program_id(program_id, keys)?;
+    invoke(&ix, &accounts.to_account_infos(program))
+}
+pub fn all_optional_ix_invoke<'info>(
+    accounts: AllOptionalIxAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
+) -> ProgramResult {
+    all_optional_ix_invoke_with_program_id(crate::ID, accounts, program)
+}
+pub fn all_optional_ix_invoke_signed_with_program_id<'info>(
+    program_id: Pubkey,
+    accounts: AllOptionalIxAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AllOptionalIxKeys = accounts.into();
+    let ix = all_optional_ix_ix_with_program_id(program_id, keys)?;
+    invoke_signed(&ix, &accounts.to_account_infos(program), seeds)
+}
+pub fn all_optional_ix_invoke_signed<'info>(
+    accounts: AllOptionalIxAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    all_optional_ix_invoke_signed_with_program_id(crate::ID, accounts, program, seeds)
+}
+/// Optional accounts must be present in both `accounts` and `keys` or absent from both.
+/// An absent account or key is returned as `crate::ID` in the mismatch
+pub fn all_optional_ix_verify_account_keys(
+    accounts: AllOptionalIxAccounts<'_, '_>,
+    keys: AllOptionalIxKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [(accounts.a.map(|a| *a.key), keys.a)] {
+        if actual != expected {
+            return Err((actual.unwrap_or(crate::ID), expected.unwrap_or(crate::ID)));
+        }
+    }
+    Ok(())
+}
+pub fn all_optional_ix_verify_writable_privileges<'me, 'info>(
+    accounts: AllOptionalIxAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.a].into_iter().flatten() {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn all_optional_ix_verify_signer_privileges<'me, 'info>(
+    accounts: AllOptionalIxAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.a].into_iter().flatten() {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn all_optional_ix_verify_account_privileges<'me, 'info>(
+    accounts: AllOptionalIxAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    all_optional_ix_verify_writable_privileges(accounts)?;
+    all_optional_ix_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+/// A [`Anchor030IxOptionalAccountsProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Anchor030IxOptionalAccountsParsedIx {
+    OptionalAccountsIx {
+        keys: OptionalAccountsIxKeys,
+        args: OptionalAccountsIxIxArgs,
+    },
+    AllOptionalIx {
+        keys: AllOptionalIxKeys,
+    },
+}
+impl Anchor030IxOptionalAccountsParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = Anchor030IxOptionalAccountsProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            Anchor030IxOptionalAccountsProgramIx::OptionalAccountsIx(args) => {
+                Self::OptionalAccountsIx {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            Anchor030IxOptionalAccountsProgramIx::AllOptionalIx => Self::AllOptionalIx {
+                keys: parsed_ix_keys(accounts)?,
+            },
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
@@ -0,0 +1,9 @@
+solana_program::declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+pub mod instructions;
+pub use instructions::*;
+pub mod pdas;
+pub use pdas::*;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "client")]
+pub use client::*;
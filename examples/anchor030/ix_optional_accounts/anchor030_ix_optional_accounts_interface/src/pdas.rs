@@ -0,0 +1,11 @@
+use solana_program::pubkey::{Pubkey, PubkeyError};
+pub fn find_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &crate::ID)
+}
+pub fn create_config_address(bump: u8) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(&[b"config", &[bump]], &crate::ID)
+}
+/// Seeds of the PDA, including the bump seed, for use with `invoke_signed()`.
+pub fn config_seeds(bump: &[u8; 1]) -> [&[u8]; 2] {
+    [b"config", bump]
+}
@@ -0,0 +1,1749 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum Anchor030PdaProgramIx {
+    InitVault(InitVaultIxArgs),
+    Deposit(DepositIxArgs),
+    Withdraw,
+    SyncConfig,
+    CloseVault(CloseVaultIxArgs),
+}
+impl Anchor030PdaProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            INIT_VAULT_IX_DISCM => Ok(Self::InitVault(InitVaultIxArgs::deserialize(&mut reader)?)),
+            DEPOSIT_IX_DISCM => Ok(Self::Deposit(DepositIxArgs::deserialize(&mut reader)?)),
+            WITHDRAW_IX_DISCM => Ok(Self::Withdraw),
+            SYNC_CONFIG_IX_DISCM => Ok(Self::SyncConfig),
+            CLOSE_VAULT_IX_DISCM => Ok(Self::CloseVault(CloseVaultIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::InitVault(args) => {
+                writer.write_all(&INIT_VAULT_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::Deposit(args) => {
+                writer.write_all(&DEPOSIT_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::Withdraw => writer.write_all(&WITHDRAW_IX_DISCM),
+            Self::SyncConfig => writer.write_all(&SYNC_CONFIG_IX_DISCM),
+            Self::CloseVault(args) => {
+                writer.write_all(&CLOSE_VAULT_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const INIT_VAULT_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct InitVaultAccounts<'me, 'info> {
+    pub authority: &'me AccountInfo<'info>,
+    pub config: &'me AccountInfo<'info>,
+    pub vault: &'me AccountInfo<'info>,
+    pub metadata: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InitVaultKeys {
+    pub authority: Pubkey,
+    pub config: Pubkey,
+    pub vault: Pubkey,
+    pub metadata: Pubkey,
+    pub system_program: Pubkey,
+}
+impl InitVaultKeys {
+    /// Creates the keys from the accounts that cannot be derived.
+    /// Accounts with a fixed address and PDAs are derived.
+    pub fn new(authority: Pubkey, args: &InitVaultIxArgs) -> Self {
+        let system_program = solana_program::pubkey!("11111111111111111111111111111111");
+        let config = find_config_address().0;
+        let vault = find_vault_address(&authority, args.vault_id).0;
+        let metadata = find_metadata_address(&vault).0;
+        Self {
+            authority,
+            config,
+            vault,
+            metadata,
+            system_program,
+        }
+    }
+}
+impl From<InitVaultAccounts<'_, '_>> for InitVaultKeys {
+    fn from(accounts: InitVaultAccounts) -> Self {
+        Self {
+            authority: *accounts.authority.key,
+            config: *accounts.config.key,
+            vault: *accounts.vault.key,
+            metadata: *accounts.metadata.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<InitVaultKeys> for [AccountMeta; INIT_VAULT_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitVaultKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.config,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.vault,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INIT_VAULT_IX_ACCOUNTS_LEN]> for InitVaultKeys {
+    fn from(pubkeys: [Pubkey; INIT_VAULT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: pubkeys[0],
+            config: pubkeys[1],
+            vault: pubkeys[2],
+            metadata: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<InitVaultAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INIT_VAULT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitVaultAccounts<'_, 'info>) -> Self {
+        [
+            accounts.authority.clone(),
+            accounts.config.clone(),
+            accounts.vault.clone(),
+            accounts.metadata.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INIT_VAULT_IX_ACCOUNTS_LEN]>
+    for InitVaultAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INIT_VAULT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: &arr[0],
+            config: &arr[1],
+            vault: &arr[2],
+            metadata: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+impl InitVaultKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INIT_VAULT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INIT_VAULT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INIT_VAULT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitVaultKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitVaultAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INIT_VAULT_IX_ACCOUNTS_LEN] = accounts
+            .get(..INIT_VAULT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INIT_VAULT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitVaultAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const INIT_VAULT_IX_DISCM: [u8; 8] = [77, 79, 85, 150, 33, 217, 52, 106];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitVaultIxArgs {
+    pub vault_id: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitVaultIxData(pub InitVaultIxArgs);
+impl From<InitVaultIxArgs> for InitVaultIxData {
+    fn from(args: InitVaultIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitVaultIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INIT_VAULT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INIT_VAULT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(InitVaultIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INIT_VAULT_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn init_vault_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitVaultKeys,
+    args: InitVaultIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INIT_VAULT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitVaultIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn init_vault_ix(keys: InitVaultKeys, args: InitVaultIxArgs) -> std::io::Result<Instruction> {
+    init_vault_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`init_vault_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitVaultIxBuilder {
+    authority: Option<Pubkey>,
+    config: Option<Pubkey>,
+    vault: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    vault_id: Option<u64>,
+}
+impl Default for InitVaultIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitVaultIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            authority: None,
+            config: None,
+            vault: None,
+            metadata: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            vault_id: None,
+        }
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn config(mut self, config: Pubkey) -> Self {
+        self.config = Some(config);
+        self
+    }
+    pub fn vault(mut self, vault: Pubkey) -> Self {
+        self.vault = Some(vault);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn vault_id(mut self, vault_id: u64) -> Self {
+        self.vault_id = Some(vault_id);
+        self
+    }
+    /// Errors naming the first account or arg that was not set.
+    /// PDAs that were not set are derived
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitVaultIxBuilder is missing {}", field),
+            )
+        };
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = InitVaultIxArgs {
+            vault_id: self.vault_id.ok_or_else(|| missing("vault_id"))?,
+        };
+        let config = self.config.unwrap_or_else(|| find_config_address().0);
+        let vault = self
+            .vault
+            .unwrap_or_else(|| find_vault_address(&authority, args.vault_id).0);
+        let metadata = self
+            .metadata
+            .unwrap_or_else(|| find_metadata_address(&vault).0);
+        let keys = InitVaultKeys {
+            authority,
+            config,
+            vault,
+            metadata,
+            system_program,
+        };
+        init_vault_ix(keys, args)
+    }
+}
+pub fn init_vault_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitVaultAccounts<'_, '_>,
+    args: InitVaultIxArgs,
+) -> ProgramResult {
+    let keys: InitVaultKeys = accounts.into();
+    let ix = init_vault_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn init_vault_invoke(
+    accounts: InitVaultAccounts<'_, '_>,
+    args: InitVaultIxArgs,
+) -> ProgramResult {
+    init_vault_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn init_vault_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitVaultAccounts<'_, '_>,
+    args: InitVaultIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitVaultKeys = accounts.into();
+    let ix = init_vault_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn init_vault_invoke_signed(
+    accounts: InitVaultAccounts<'_, '_>,
+    args: InitVaultIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    init_vault_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn init_vault_verify_account_keys(
+    accounts: InitVaultAccounts<'_, '_>,
+    keys: InitVaultKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.authority.key, keys.authority),
+        (*accounts.config.key, keys.config),
+        (*accounts.vault.key, keys.vault),
+        (*accounts.metadata.key, keys.metadata),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn init_vault_verify_writable_privileges<'me, 'info>(
+    accounts: InitVaultAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.authority, accounts.vault, accounts.metadata] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn init_vault_verify_signer_privileges<'me, 'info>(
+    accounts: InitVaultAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn init_vault_verify_account_privileges<'me, 'info>(
+    accounts: InitVaultAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    init_vault_verify_writable_privileges(accounts)?;
+    init_vault_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DEPOSIT_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone, Debug)]
+pub struct DepositAccounts<'me, 'info> {
+    pub authority: &'me AccountInfo<'info>,
+    pub vault: &'me AccountInfo<'info>,
+    pub receipt: &'me AccountInfo<'info>,
+    pub token_program: &'me AccountInfo<'info>,
+    pub vault_token: &'me AccountInfo<'info>,
+    pub associated_token_program: &'me AccountInfo<'info>,
+    pub tagged: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DepositKeys {
+    pub authority: Pubkey,
+    pub vault: Pubkey,
+    pub receipt: Pubkey,
+    pub token_program: Pubkey,
+    pub vault_token: Pubkey,
+    pub associated_token_program: Pubkey,
+    pub tagged: Pubkey,
+}
+impl DepositKeys {
+    /// Creates the keys from the accounts that cannot be derived.
+    /// Accounts with a fixed address and PDAs are derived.
+    pub fn new(
+        authority: Pubkey,
+        receipt: Pubkey,
+        token_program: Pubkey,
+        vault_token: Pubkey,
+        associated_token_program: Pubkey,
+        args: &DepositIxArgs,
+    ) -> Self {
+        let vault = find_vault_address(&authority, args.vault_id).0;
+        let tagged =
+            find_tagged_address(&args.params.tag, &args.params.label, args.params.locked).0;
+        Self {
+            authority,
+            vault,
+            receipt,
+            token_program,
+            vault_token,
+            associated_token_program,
+            tagged,
+        }
+    }
+}
+impl From<DepositAccounts<'_, '_>> for DepositKeys {
+    fn from(accounts: DepositAccounts) -> Self {
+        Self {
+            authority: *accounts.authority.key,
+            vault: *accounts.vault.key,
+            receipt: *accounts.receipt.key,
+            token_program: *accounts.token_program.key,
+            vault_token: *accounts.vault_token.key,
+            associated_token_program: *accounts.associated_token_program.key,
+            tagged: *accounts.tagged.key,
+        }
+    }
+}
+impl From<DepositKeys> for [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] {
+    fn from(keys: DepositKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.vault,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.receipt,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.vault_token,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.associated_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.tagged,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]> for DepositKeys {
+    fn from(pubkeys: [Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: pubkeys[0],
+            vault: pubkeys[1],
+            receipt: pubkeys[2],
+            token_program: pubkeys[3],
+            vault_token: pubkeys[4],
+            associated_token_program: pubkeys[5],
+            tagged: pubkeys[6],
+        }
+    }
+}
+impl<'info> From<DepositAccounts<'_, 'info>> for [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DepositAccounts<'_, 'info>) -> Self {
+        [
+            accounts.authority.clone(),
+            accounts.vault.clone(),
+            accounts.receipt.clone(),
+            accounts.token_program.clone(),
+            accounts.vault_token.clone(),
+            accounts.associated_token_program.clone(),
+            accounts.tagged.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
+    for DepositAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: &arr[0],
+            vault: &arr[1],
+            receipt: &arr[2],
+            token_program: &arr[3],
+            vault_token: &arr[4],
+            associated_token_program: &arr[5],
+            tagged: &arr[6],
+        }
+    }
+}
+impl DepositKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPOSIT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEPOSIT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[DEPOSIT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for DepositKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DepositAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts
+            .get(..DEPOSIT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[DEPOSIT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for DepositAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const DEPOSIT_IX_DISCM: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositIxArgs {
+    pub vault_id: u64,
+    pub params: DepositParams,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepositIxData(pub DepositIxArgs);
+impl From<DepositIxArgs> for DepositIxData {
+    fn from(args: DepositIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl DepositIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != DEPOSIT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    DEPOSIT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(DepositIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&DEPOSIT_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn deposit_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DepositKeys,
+    args: DepositIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: DepositIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn deposit_ix(keys: DepositKeys, args: DepositIxArgs) -> std::io::Result<Instruction> {
+    deposit_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`deposit_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DepositIxBuilder {
+    authority: Option<Pubkey>,
+    vault: Option<Pubkey>,
+    receipt: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    vault_token: Option<Pubkey>,
+    associated_token_program: Option<Pubkey>,
+    tagged: Option<Pubkey>,
+    vault_id: Option<u64>,
+    params: Option<DepositParams>,
+}
+impl Default for DepositIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DepositIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            authority: None,
+            vault: None,
+            receipt: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            vault_token: None,
+            associated_token_program: Some(solana_program::pubkey!(
+                "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            )),
+            tagged: None,
+            vault_id: None,
+            params: None,
+        }
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn vault(mut self, vault: Pubkey) -> Self {
+        self.vault = Some(vault);
+        self
+    }
+    pub fn receipt(mut self, receipt: Pubkey) -> Self {
+        self.receipt = Some(receipt);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn vault_token(mut self, vault_token: Pubkey) -> Self {
+        self.vault_token = Some(vault_token);
+        self
+    }
+    pub fn associated_token_program(mut self, associated_token_program: Pubkey) -> Self {
+        self.associated_token_program = Some(associated_token_program);
+        self
+    }
+    pub fn tagged(mut self, tagged: Pubkey) -> Self {
+        self.tagged = Some(tagged);
+        self
+    }
+    pub fn vault_id(mut self, vault_id: u64) -> Self {
+        self.vault_id = Some(vault_id);
+        self
+    }
+    pub fn params(mut self, params: DepositParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set.
+    /// PDAs that were not set are derived
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DepositIxBuilder is missing {}", field),
+            )
+        };
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let receipt = self.receipt.ok_or_else(|| missing("receipt"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let vault_token = self.vault_token.ok_or_else(|| missing("vault_token"))?;
+        let associated_token_program = self
+            .associated_token_program
+            .ok_or_else(|| missing("associated_token_program"))?;
+        let args = DepositIxArgs {
+            vault_id: self.vault_id.ok_or_else(|| missing("vault_id"))?,
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let vault = self
+            .vault
+            .unwrap_or_else(|| find_vault_address(&authority, args.vault_id).0);
+        let tagged = self.tagged.unwrap_or_else(|| {
+            find_tagged_address(&args.params.tag, &args.params.label, args.params.locked).0
+        });
+        let keys = DepositKeys {
+            authority,
+            vault,
+            receipt,
+            token_program,
+            vault_token,
+            associated_token_program,
+            tagged,
+        };
+        deposit_ix(keys, args)
+    }
+}
+pub fn deposit_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DepositAccounts<'_, '_>,
+    args: DepositIxArgs,
+) -> ProgramResult {
+    let keys: DepositKeys = accounts.into();
+    let ix = deposit_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn deposit_invoke(accounts: DepositAccounts<'_, '_>, args: DepositIxArgs) -> ProgramResult {
+    deposit_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn deposit_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DepositAccounts<'_, '_>,
+    args: DepositIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: DepositKeys = accounts.into();
+    let ix = deposit_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn deposit_invoke_signed(
+    accounts: DepositAccounts<'_, '_>,
+    args: DepositIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    deposit_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn deposit_verify_account_keys(
+    accounts: DepositAccounts<'_, '_>,
+    keys: DepositKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.authority.key, keys.authority),
+        (*accounts.vault.key, keys.vault),
+        (*accounts.receipt.key, keys.receipt),
+        (*accounts.token_program.key, keys.token_program),
+        (*accounts.vault_token.key, keys.vault_token),
+        (
+            *accounts.associated_token_program.key,
+            keys.associated_token_program,
+        ),
+        (*accounts.tagged.key, keys.tagged),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_verify_writable_privileges<'me, 'info>(
+    accounts: DepositAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.vault,
+        accounts.receipt,
+        accounts.vault_token,
+        accounts.tagged,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_verify_signer_privileges<'me, 'info>(
+    accounts: DepositAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_verify_account_privileges<'me, 'info>(
+    accounts: DepositAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    deposit_verify_writable_privileges(accounts)?;
+    deposit_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const WITHDRAW_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawAccounts<'me, 'info> {
+    pub authority: &'me AccountInfo<'info>,
+    pub receipt: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WithdrawKeys {
+    pub authority: Pubkey,
+    pub receipt: Pubkey,
+}
+impl WithdrawKeys {
+    /// Creates the keys from the accounts that cannot be derived.
+    /// Accounts with a fixed address and PDAs are derived.
+    pub fn new(authority: Pubkey) -> Self {
+        let receipt = find_withdraw_receipt_address(&authority).0;
+        Self { authority, receipt }
+    }
+}
+impl From<WithdrawAccounts<'_, '_>> for WithdrawKeys {
+    fn from(accounts: WithdrawAccounts) -> Self {
+        Self {
+            authority: *accounts.authority.key,
+            receipt: *accounts.receipt.key,
+        }
+    }
+}
+impl From<WithdrawKeys> for [AccountMeta; WITHDRAW_IX_ACCOUNTS_LEN] {
+    fn from(keys: WithdrawKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.receipt,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; WITHDRAW_IX_ACCOUNTS_LEN]> for WithdrawKeys {
+    fn from(pubkeys: [Pubkey; WITHDRAW_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: pubkeys[0],
+            receipt: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<WithdrawAccounts<'_, 'info>> for [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] {
+    fn from(accounts: WithdrawAccounts<'_, 'info>) -> Self {
+        [accounts.authority.clone(), accounts.receipt.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]>
+    for WithdrawAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: &arr[0],
+            receipt: &arr[1],
+        }
+    }
+}
+impl WithdrawKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; WITHDRAW_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..WITHDRAW_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[WITHDRAW_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for WithdrawKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> WithdrawAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] = accounts
+            .get(..WITHDRAW_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[WITHDRAW_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for WithdrawAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const WITHDRAW_IX_DISCM: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawIxData;
+impl WithdrawIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != WITHDRAW_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    WITHDRAW_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&WITHDRAW_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn withdraw_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; WITHDRAW_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: WithdrawIxData.try_to_vec()?,
+    })
+}
+pub fn withdraw_ix(keys: WithdrawKeys) -> std::io::Result<Instruction> {
+    withdraw_ix_with_program_id(crate::ID, keys)
+}
+/// Builds a [`withdraw_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct WithdrawIxBuilder {
+    authority: Option<Pubkey>,
+    receipt: Option<Pubkey>,
+}
+impl Default for WithdrawIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl WithdrawIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            authority: None,
+            receipt: None,
+        }
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn receipt(mut self, receipt: Pubkey) -> Self {
+        self.receipt = Some(receipt);
+        self
+    }
+    /// Errors naming the first account or arg that was not set.
+    /// PDAs that were not set are derived
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("WithdrawIxBuilder is missing {}", field),
+            )
+        };
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let receipt = self
+            .receipt
+            .unwrap_or_else(|| find_withdraw_receipt_address(&authority).0);
+        let keys = WithdrawKeys { authority, receipt };
+        withdraw_ix(keys)
+    }
+}
+pub fn withdraw_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: WithdrawKeys = accounts.into();
+    let ix = withdraw_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn withdraw_invoke(accounts: WithdrawAccounts<'_, '_>) -> ProgramResult {
+    withdraw_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn withdraw_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: WithdrawKeys = accounts.into();
+    let ix = withdraw_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn withdraw_invoke_signed(
+    accounts: WithdrawAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    withdraw_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn withdraw_verify_account_keys(
+    accounts: WithdrawAccounts<'_, '_>,
+    keys: WithdrawKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.authority.key, keys.authority),
+        (*accounts.receipt.key, keys.receipt),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_verify_writable_privileges<'me, 'info>(
+    accounts: WithdrawAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.receipt] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_verify_signer_privileges<'me, 'info>(
+    accounts: WithdrawAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_verify_account_privileges<'me, 'info>(
+    accounts: WithdrawAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    withdraw_verify_writable_privileges(accounts)?;
+    withdraw_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SYNC_CONFIG_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SyncConfigAccounts<'me, 'info> {
+    pub config: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SyncConfigKeys {
+    pub config: Pubkey,
+    pub system_program: Pubkey,
+}
+impl Default for SyncConfigKeys {
+    /// All accounts have a fixed address or are PDAs that can be derived.
+    fn default() -> Self {
+        let system_program = solana_program::pubkey!("11111111111111111111111111111111");
+        let config = find_config_address().0;
+        Self {
+            config,
+            system_program,
+        }
+    }
+}
+impl From<SyncConfigAccounts<'_, '_>> for SyncConfigKeys {
+    fn from(accounts: SyncConfigAccounts) -> Self {
+        Self {
+            config: *accounts.config.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SyncConfigKeys> for [AccountMeta; SYNC_CONFIG_IX_ACCOUNTS_LEN] {
+    fn from(keys: SyncConfigKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.config,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SYNC_CONFIG_IX_ACCOUNTS_LEN]> for SyncConfigKeys {
+    fn from(pubkeys: [Pubkey; SYNC_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            config: pubkeys[0],
+            system_program: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SyncConfigAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SYNC_CONFIG_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SyncConfigAccounts<'_, 'info>) -> Self {
+        [accounts.config.clone(), accounts.system_program.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SYNC_CONFIG_IX_ACCOUNTS_LEN]>
+    for SyncConfigAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SYNC_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            config: &arr[0],
+            system_program: &arr[1],
+        }
+    }
+}
+impl SyncConfigKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SYNC_CONFIG_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SYNC_CONFIG_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SYNC_CONFIG_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SyncConfigKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SyncConfigAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SYNC_CONFIG_IX_ACCOUNTS_LEN] = accounts
+            .get(..SYNC_CONFIG_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SYNC_CONFIG_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SyncConfigAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const SYNC_CONFIG_IX_DISCM: [u8; 8] = [65, 210, 175, 219, 197, 165, 191, 242];
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyncConfigIxData;
+impl SyncConfigIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SYNC_CONFIG_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SYNC_CONFIG_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SYNC_CONFIG_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn sync_config_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SyncConfigKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SYNC_CONFIG_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: SyncConfigIxData.try_to_vec()?,
+    })
+}
+pub fn sync_config_ix(keys: SyncConfigKeys) -> std::io::Result<Instruction> {
+    sync_config_ix_with_program_id(crate::ID, keys)
+}
+/// Builds a [`sync_config_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SyncConfigIxBuilder {
+    config: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+}
+impl Default for SyncConfigIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SyncConfigIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+        }
+    }
+    pub fn config(mut self, config: Pubkey) -> Self {
+        self.config = Some(config);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set.
+    /// PDAs that were not set are derived
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SyncConfigIxBuilder is missing {}", field),
+            )
+        };
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let config = self.config.unwrap_or_else(|| find_config_address().0);
+        let keys = SyncConfigKeys {
+            config,
+            system_program,
+        };
+        sync_config_ix(keys)
+    }
+}
+pub fn sync_config_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SyncConfigAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: SyncConfigKeys = accounts.into();
+    let ix = sync_config_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn sync_config_invoke(accounts: SyncConfigAccounts<'_, '_>) -> ProgramResult {
+    sync_config_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn sync_config_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SyncConfigAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SyncConfigKeys = accounts.into();
+    let ix = sync_config_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn sync_config_invoke_signed(
+    accounts: SyncConfigAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    sync_config_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn sync_config_verify_account_keys(
+    accounts: SyncConfigAccounts<'_, '_>,
+    keys: SyncConfigKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.config.key, keys.config),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn sync_config_verify_writable_privileges<'me, 'info>(
+    accounts: SyncConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn sync_config_verify_account_privileges<'me, 'info>(
+    accounts: SyncConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    sync_config_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const CLOSE_VAULT_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct CloseVaultAccounts<'me, 'info> {
+    pub authority: &'me AccountInfo<'info>,
+    pub escrow: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CloseVaultKeys {
+    pub authority: Pubkey,
+    pub escrow: Pubkey,
+}
+impl From<CloseVaultAccounts<'_, '_>> for CloseVaultKeys {
+    fn from(accounts: CloseVaultAccounts) -> Self {
+        Self {
+            authority: *accounts.authority.key,
+            escrow: *accounts.escrow.key,
+        }
+    }
+}
+impl From<CloseVaultKeys> for [AccountMeta; CLOSE_VAULT_IX_ACCOUNTS_LEN] {
+    fn from(keys: CloseVaultKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.escrow,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CLOSE_VAULT_IX_ACCOUNTS_LEN]> for CloseVaultKeys {
+    fn from(pubkeys: [Pubkey; CLOSE_VAULT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: pubkeys[0],
+            escrow: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<CloseVaultAccounts<'_, 'info>>
+    for [AccountInfo<'info>; CLOSE_VAULT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: CloseVaultAccounts<'_, 'info>) -> Self {
+        [accounts.authority.clone(), accounts.escrow.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CLOSE_VAULT_IX_ACCOUNTS_LEN]>
+    for CloseVaultAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; CLOSE_VAULT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: &arr[0],
+            escrow: &arr[1],
+        }
+    }
+}
+impl CloseVaultKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CLOSE_VAULT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CLOSE_VAULT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CLOSE_VAULT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for CloseVaultKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CloseVaultAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CLOSE_VAULT_IX_ACCOUNTS_LEN] = accounts
+            .get(..CLOSE_VAULT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CLOSE_VAULT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CloseVaultAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const CLOSE_VAULT_IX_DISCM: [u8; 8] = [141, 103, 17, 126, 72, 75, 29, 29];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CloseVaultIxArgs {
+    pub params: DepositParams,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CloseVaultIxData(pub CloseVaultIxArgs);
+impl From<CloseVaultIxArgs> for CloseVaultIxData {
+    fn from(args: CloseVaultIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CloseVaultIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CLOSE_VAULT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    CLOSE_VAULT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(CloseVaultIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CLOSE_VAULT_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// `escrow` is seeded by a struct arg, which is not a supported seed type
+pub fn close_vault_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CloseVaultKeys,
+    args: CloseVaultIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CLOSE_VAULT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CloseVaultIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+/// `escrow` is seeded by a struct arg, which is not a supported seed type
+pub fn close_vault_ix(
+    keys: CloseVaultKeys,
+    args: CloseVaultIxArgs,
+) -> std::io::Result<Instruction> {
+    close_vault_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`close_vault_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CloseVaultIxBuilder {
+    authority: Option<Pubkey>,
+    escrow: Option<Pubkey>,
+    params: Option<DepositParams>,
+}
+impl Default for CloseVaultIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CloseVaultIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            authority: None,
+            escrow: None,
+            params: None,
+        }
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn escrow(mut self, escrow: Pubkey) -> Self {
+        self.escrow = Some(escrow);
+        self
+    }
+    pub fn params(mut self, params: DepositParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CloseVaultIxBuilder is missing {}", field),
+            )
+        };
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let escrow = self.escrow.ok_or_else(|| missing("escrow"))?;
+        let args = CloseVaultIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let keys = CloseVaultKeys { authority, escrow };
+        close_vault_ix(keys, args)
+    }
+}
+pub fn close_vault_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CloseVaultAccounts<'_, '_>,
+    args: CloseVaultIxArgs,
+) -> ProgramResult {
+    let keys: CloseVaultKeys = accounts.into();
+    let ix = close_vault_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn close_vault_invoke(
+    accounts: CloseVaultAccounts<'_, '_>,
+    args: CloseVaultIxArgs,
+) -> ProgramResult {
+    close_vault_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn close_vault_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CloseVaultAccounts<'_, '_>,
+    args: CloseVaultIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CloseVaultKeys = accounts.into();
+    let ix = close_vault_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn close_vault_invoke_signed(
+    accounts: CloseVaultAccounts<'_, '_>,
+    args: CloseVaultIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    close_vault_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn close_vault_verify_account_keys(
+    accounts: CloseVaultAccounts<'_, '_>,
+    keys: CloseVaultKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.authority.key, keys.authority),
+        (*accounts.escrow.key, keys.escrow),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn close_vault_verify_writable_privileges<'me, 'info>(
+    accounts: CloseVaultAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.escrow] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn close_vault_verify_signer_privileges<'me, 'info>(
+    accounts: CloseVaultAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn close_vault_verify_account_privileges<'me, 'info>(
+    accounts: CloseVaultAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    close_vault_verify_writable_privileges(accounts)?;
+    close_vault_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+/// A [`Anchor030PdaProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Anchor030PdaParsedIx {
+    InitVault {
+        keys: InitVaultKeys,
+        args: InitVaultIxArgs,
+    },
+    Deposit {
+        keys: DepositKeys,
+        args: DepositIxArgs,
+    },
+    Withdraw {
+        keys: WithdrawKeys,
+    },
+    SyncConfig {
+        keys: SyncConfigKeys,
+    },
+    CloseVault {
+        keys: CloseVaultKeys,
+        args: CloseVaultIxArgs,
+    },
+}
+impl Anchor030PdaParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = Anchor030PdaProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            Anchor030PdaProgramIx::InitVault(args) => Self::InitVault {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            Anchor030PdaProgramIx::Deposit(args) => Self::Deposit {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            Anchor030PdaProgramIx::Withdraw => Self::Withdraw {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            Anchor030PdaProgramIx::SyncConfig => Self::SyncConfig {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            Anchor030PdaProgramIx::CloseVault(args) => Self::CloseVault {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
@@ -0,0 +1,240 @@
+use solana_program::pubkey::{Pubkey, PubkeyError};
+pub fn find_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &crate::ID)
+}
+pub fn create_config_address(bump: u8) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(&[b"config", &[bump]], &crate::ID)
+}
+/// Seeds of the PDA, including the bump seed, for use with `invoke_signed()`.
+pub fn config_seeds(bump: &[u8; 1]) -> [&[u8]; 2] {
+    [b"config", bump]
+}
+pub fn find_vault_address(authority: &Pubkey, vault_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"vault", authority.as_ref(), &vault_id.to_le_bytes()],
+        &crate::ID,
+    )
+}
+pub fn create_vault_address(
+    authority: &Pubkey,
+    vault_id: u64,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[
+            b"vault",
+            authority.as_ref(),
+            &vault_id.to_le_bytes(),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+}
+/// Seeds of the PDA, including the bump seed, for use with `invoke_signed()`.
+///
+/// Number and bool seeds are passed as their bytes since the returned array borrows them.
+pub fn vault_seeds<'a>(
+    authority: &'a Pubkey,
+    vault_id: &'a [u8; 8],
+    bump: &'a [u8; 1],
+) -> [&'a [u8]; 4] {
+    [b"vault", authority.as_ref(), vault_id, bump]
+}
+pub fn find_metadata_address(vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            &[
+                11, 112, 101, 177, 227, 209, 124, 69, 56, 157, 82, 127, 107, 4, 195, 205, 88, 184,
+                108, 115, 26, 160, 253, 181, 73, 182, 209, 188, 3, 248, 41, 70,
+            ],
+            vault.as_ref(),
+        ],
+        &solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"),
+    )
+}
+pub fn create_metadata_address(vault: &Pubkey, bump: u8) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[
+            b"metadata",
+            &[
+                11, 112, 101, 177, 227, 209, 124, 69, 56, 157, 82, 127, 107, 4, 195, 205, 88, 184,
+                108, 115, 26, 160, 253, 181, 73, 182, 209, 188, 3, 248, 41, 70,
+            ],
+            vault.as_ref(),
+            &[bump],
+        ],
+        &solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"),
+    )
+}
+/// Seeds of the PDA, including the bump seed, for use with `invoke_signed()`.
+pub fn metadata_seeds<'a>(vault: &'a Pubkey, bump: &'a [u8; 1]) -> [&'a [u8]; 4] {
+    [
+        b"metadata",
+        &[
+            11, 112, 101, 177, 227, 209, 124, 69, 56, 157, 82, 127, 107, 4, 195, 205, 88, 184, 108,
+            115, 26, 160, 253, 181, 73, 182, 209, 188, 3, 248, 41, 70,
+        ],
+        vault.as_ref(),
+        bump,
+    ]
+}
+pub fn find_deposit_receipt_address(
+    vault_mint: &Pubkey,
+    params_locked: bool,
+    params_tag: &[u8; 8],
+    params_label: &str,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"receipt",
+            vault_mint.as_ref(),
+            &[u8::from(params_locked)],
+            params_tag,
+            params_label.as_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+pub fn create_deposit_receipt_address(
+    vault_mint: &Pubkey,
+    params_locked: bool,
+    params_tag: &[u8; 8],
+    params_label: &str,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[
+            b"receipt",
+            vault_mint.as_ref(),
+            &[u8::from(params_locked)],
+            params_tag,
+            params_label.as_bytes(),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+}
+/// Seeds of the PDA, including the bump seed, for use with `invoke_signed()`.
+///
+/// Number and bool seeds are passed as their bytes since the returned array borrows them.
+pub fn deposit_receipt_seeds<'a>(
+    vault_mint: &'a Pubkey,
+    params_locked: &'a [u8; 1],
+    params_tag: &'a [u8; 8],
+    params_label: &'a str,
+    bump: &'a [u8; 1],
+) -> [&'a [u8]; 6] {
+    [
+        b"receipt",
+        vault_mint.as_ref(),
+        params_locked,
+        params_tag,
+        params_label.as_bytes(),
+        bump,
+    ]
+}
+pub fn find_vault_token_address(
+    vault: &Pubkey,
+    token_program: &Pubkey,
+    vault_mint: &Pubkey,
+    associated_token_program: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[vault.as_ref(), token_program.as_ref(), vault_mint.as_ref()],
+        associated_token_program,
+    )
+}
+pub fn create_vault_token_address(
+    vault: &Pubkey,
+    token_program: &Pubkey,
+    vault_mint: &Pubkey,
+    associated_token_program: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[
+            vault.as_ref(),
+            token_program.as_ref(),
+            vault_mint.as_ref(),
+            &[bump],
+        ],
+        associated_token_program,
+    )
+}
+/// Seeds of the PDA, including the bump seed, for use with `invoke_signed()`.
+pub fn vault_token_seeds<'a>(
+    vault: &'a Pubkey,
+    token_program: &'a Pubkey,
+    vault_mint: &'a Pubkey,
+    bump: &'a [u8; 1],
+) -> [&'a [u8]; 4] {
+    [
+        vault.as_ref(),
+        token_program.as_ref(),
+        vault_mint.as_ref(),
+        bump,
+    ]
+}
+pub fn find_tagged_address(
+    params_tag: &[u8; 8],
+    params_label: &str,
+    params_locked: bool,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"tagged",
+            params_tag,
+            params_label.as_bytes(),
+            &[u8::from(params_locked)],
+        ],
+        &crate::ID,
+    )
+}
+pub fn create_tagged_address(
+    params_tag: &[u8; 8],
+    params_label: &str,
+    params_locked: bool,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[
+            b"tagged",
+            params_tag,
+            params_label.as_bytes(),
+            &[u8::from(params_locked)],
+            &[bump],
+        ],
+        &crate::ID,
+    )
+}
+/// Seeds of the PDA, including the bump seed, for use with `invoke_signed()`.
+///
+/// Number and bool seeds are passed as their bytes since the returned array borrows them.
+pub fn tagged_seeds<'a>(
+    params_tag: &'a [u8; 8],
+    params_label: &'a str,
+    params_locked: &'a [u8; 1],
+    bump: &'a [u8; 1],
+) -> [&'a [u8]; 5] {
+    [
+        b"tagged",
+        params_tag,
+        params_label.as_bytes(),
+        params_locked,
+        bump,
+    ]
+}
+pub fn find_withdraw_receipt_address(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[&[0, 255], authority.as_ref()], &crate::ID)
+}
+pub fn create_withdraw_receipt_address(
+    authority: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(&[&[0, 255], authority.as_ref(), &[bump]], &crate::ID)
+}
+/// Seeds of the PDA, including the bump seed, for use with `invoke_signed()`.
+pub fn withdraw_receipt_seeds<'a>(authority: &'a Pubkey, bump: &'a [u8; 1]) -> [&'a [u8]; 3] {
+    [&[0, 255], authority.as_ref(), bump]
+}
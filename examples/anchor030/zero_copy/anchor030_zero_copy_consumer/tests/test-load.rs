@@ -0,0 +1,181 @@
+use anchor030_zero_copy_interface::*;
+use solana_program::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey, pubkey::Pubkey,
+};
+
+const AUTHORITY: Pubkey = pubkey!("4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi");
+const BASE_MINT: Pubkey = pubkey!("8qbHbw2BbbTHBW1sbeqakYXVKRQM8Ne7pLK7m6CVfeR");
+const MARKET_KEY: Pubkey = pubkey!("4rLtKGqsrPZzMgSw8mhD4G8sSqRyjWDSqrDD3aHL2VfX");
+
+/// `OrderBook.prices` after `market`, `side` and 7 bytes of padding
+const ORDER_BOOK_PRICES_OFFSET: usize = 40;
+
+/// 8-byte aligned like on-chain account data so that the struct after the discm is aligned
+fn aligned_buf(words: &mut Vec<u64>, len: usize) -> &mut [u8] {
+    words.resize(len.div_ceil(8), 0);
+    &mut bytemuck::cast_slice_mut(words)[..len]
+}
+
+fn market_account_data(buf: &mut [u8]) {
+    buf[..8].copy_from_slice(&MARKET_ACCOUNT_DISCM);
+    let data = &mut buf[8..];
+    data[MARKET_AUTHORITY_OFFSET..MARKET_AUTHORITY_OFFSET + 32].copy_from_slice(AUTHORITY.as_ref());
+    data[MARKET_BASE_MINT_OFFSET..MARKET_BASE_MINT_OFFSET + 32].copy_from_slice(BASE_MINT.as_ref());
+    data[MARKET_TOTAL_DEPOSITS_OFFSET..MARKET_TOTAL_DEPOSITS_OFFSET + 8]
+        .copy_from_slice(&1_000u64.to_le_bytes());
+    let fee = &mut data[MARKET_FEE_OFFSET..];
+    fee[FEE_ENABLED_OFFSET] = 1;
+    fee[FEE_BPS_OFFSET..FEE_BPS_OFFSET + 8].copy_from_slice(&25u64.to_le_bytes());
+}
+
+fn order_book_account_data(buf: &mut [u8]) {
+    buf[..8].copy_from_slice(&ORDER_BOOK_ACCOUNT_DISCM);
+    let data = &mut buf[8..];
+    data[..32].copy_from_slice(MARKET_KEY.as_ref());
+    data[32] = 1;
+    for i in 0..16 {
+        let offset = ORDER_BOOK_PRICES_OFFSET + i * 8;
+        data[offset..offset + 8].copy_from_slice(&(100 + i as u64).to_le_bytes());
+    }
+}
+
+#[test]
+fn test_layout() {
+    // packed Fee
+    assert_eq!(FEE_LEN, 9);
+    assert_eq!(MARKET_LEN, 32 * 2 + 8 + 9 + 7);
+    assert_eq!(MARKET_PADDING_OFFSET, MARKET_FEE_OFFSET + FEE_LEN);
+    assert_eq!(MARKET_ACCOUNT_LEN, 8 + MARKET_LEN);
+    // OrderBook has implicit padding before prices
+    assert_eq!(ORDER_BOOK_LEN, ORDER_BOOK_PRICES_OFFSET + 16 * 8);
+    assert_eq!(ORDER_BOOK_ACCOUNT_LEN, 8 + ORDER_BOOK_LEN);
+}
+
+#[test]
+fn test_load() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN);
+    market_account_data(buf);
+
+    let market = MarketAccount::load(buf).unwrap();
+    assert_eq!(market.authority, AUTHORITY);
+    assert_eq!(market.base_mint, BASE_MINT);
+    assert_eq!(market.total_deposits, 1_000);
+    assert_eq!(market.fee.enabled, 1);
+    assert_eq!({ market.fee.bps }, 25);
+    assert_eq!(market.padding, [0; 7]);
+    // same as the copying deserialize
+    assert_eq!(MarketAccount::deserialize(buf).unwrap().0, *market);
+    assert_eq!(MarketAccount(*market).try_to_vec().unwrap(), buf);
+}
+
+#[test]
+fn test_load_padded() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, ORDER_BOOK_ACCOUNT_LEN);
+    order_book_account_data(buf);
+
+    let order_book = OrderBookAccount::load(buf).unwrap();
+    assert_eq!(order_book.market, MARKET_KEY);
+    assert_eq!(order_book.side, 1);
+    assert_eq!(order_book.prices[0], 100);
+    assert_eq!(order_book.prices[15], 115);
+    assert_eq!(OrderBookAccount::deserialize(buf).unwrap().0, *order_book);
+}
+
+#[test]
+fn test_load_mut() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN);
+    market_account_data(buf);
+
+    let market = MarketAccount::load_mut(buf).unwrap();
+    market.total_deposits -= 1;
+    market.fee.bps = 30;
+
+    let data = &buf[8..];
+    assert_eq!(
+        data[MARKET_TOTAL_DEPOSITS_OFFSET..MARKET_TOTAL_DEPOSITS_OFFSET + 8],
+        999u64.to_le_bytes()
+    );
+    let fee = &data[MARKET_FEE_OFFSET..];
+    assert_eq!(fee[FEE_BPS_OFFSET..FEE_BPS_OFFSET + 8], 30u64.to_le_bytes());
+    assert_eq!(buf[..8], MARKET_ACCOUNT_DISCM);
+
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, ORDER_BOOK_ACCOUNT_LEN);
+    order_book_account_data(buf);
+    OrderBookAccount::load_mut(buf).unwrap().prices[1] = 7;
+    let offset = 8 + ORDER_BOOK_PRICES_OFFSET + 8;
+    assert_eq!(buf[offset..offset + 8], 7u64.to_le_bytes());
+}
+
+#[test]
+fn test_load_wrong_discm() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, ORDER_BOOK_ACCOUNT_LEN);
+    market_account_data(buf);
+    assert!(OrderBookAccount::load(buf).is_err());
+    assert!(OrderBookAccount::load_mut(buf).is_err());
+    order_book_account_data(buf);
+    assert!(MarketAccount::load(buf).is_err());
+    assert!(MarketAccount::load_mut(buf).is_err());
+}
+
+#[test]
+fn test_load_too_small() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN);
+    market_account_data(buf);
+    let short = &mut buf[..MARKET_ACCOUNT_LEN - 1];
+    assert!(MarketAccount::load(short).is_err());
+    assert!(MarketAccount::load_mut(short).is_err());
+    assert!(MarketAccount::load(&MARKET_ACCOUNT_DISCM).is_err());
+}
+
+#[test]
+fn test_load_account_info() {
+    let mut words = Vec::new();
+    let buf = aligned_buf(&mut words, MARKET_ACCOUNT_LEN);
+    market_account_data(buf);
+    let mut lamports = 1_000_000;
+    let account_info =
+        AccountInfo::new(&MARKET_KEY, false, true, &mut lamports, buf, &ID, false, 0);
+
+    {
+        let market = MarketAccount::load_account_info(&account_info).unwrap();
+        assert_eq!(market.authority, AUTHORITY);
+        assert_eq!(
+            MarketAccount::load_account_info_mut(&account_info).unwrap_err(),
+            ProgramError::AccountBorrowFailed
+        );
+    }
+    MarketAccount::load_account_info_mut(&account_info)
+        .unwrap()
+        .fee
+        .enabled = 0;
+    assert_eq!(
+        MarketAccount::load_account_info(&account_info)
+            .unwrap()
+            .fee
+            .enabled,
+        0
+    );
+
+    assert_eq!(
+        MarketAccount::load_account_info_with_program_id(AUTHORITY, &account_info).unwrap_err(),
+        ProgramError::IllegalOwner
+    );
+    assert_eq!(
+        MarketAccount::load_account_info_mut_with_program_id(AUTHORITY, &account_info).unwrap_err(),
+        ProgramError::IllegalOwner
+    );
+    assert_eq!(
+        OrderBookAccount::load_account_info(&account_info).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+    assert_eq!(
+        OrderBookAccount::load_account_info_mut(&account_info).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+}
@@ -0,0 +1,533 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use std::cell::{Ref, RefMut};
+pub const CONFIG_ACCOUNT_DISCM: [u8; 8] = [155, 12, 170, 224, 30, 250, 204, 130];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    pub admin: Pubkey,
+    pub name: String,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigAccount(pub Config);
+impl ConfigAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CONFIG_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    CONFIG_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(Config::deserialize(&mut reader)?))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CONFIG_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Offset of `admin` in serialized [`Config`]
+pub const CONFIG_ADMIN_OFFSET: usize = 0;
+/// Offset of `name` in serialized [`Config`]
+pub const CONFIG_NAME_OFFSET: usize = 32;
+/// Builds `getProgramAccounts` filters for [`ConfigAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigAccountFilters(Vec<AccountFilter>);
+impl ConfigAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: CONFIG_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches accounts whose `admin` equals `value`
+    pub fn admin_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + CONFIG_ADMIN_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `name` equals `value`
+    pub fn name_eq(mut self, value: &str) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + CONFIG_NAME_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl ConfigAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> ConfigAccountFilters {
+        ConfigAccountFilters::default()
+    }
+}
+pub const MARKET_ACCOUNT_DISCM: [u8; 8] = [219, 190, 213, 55, 0, 227, 198, 154];
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Zeroable, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Market {
+    pub authority: Pubkey,
+    pub base_mint: Pubkey,
+    pub total_deposits: u64,
+    pub fee: Fee,
+    pub padding: [u8; 7],
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketAccount(pub Market);
+impl MarketAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != MARKET_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    MARKET_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        let data = reader.get(..std::mem::size_of::<Market>()).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "account data too small")
+        })?;
+        Ok(Self(bytemuck::pod_read_unaligned(data)))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&MARKET_ACCOUNT_DISCM)?;
+        writer.write_all(bytemuck::bytes_of(&self.0))
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+    /// Reinterprets the account data after the discriminator as the account struct without copying
+    pub fn load(buf: &[u8]) -> std::io::Result<&Market> {
+        let (maybe_discm, data) = buf.split_at(buf.len().min(8));
+        if maybe_discm != MARKET_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    MARKET_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        let data = data.get(..std::mem::size_of::<Market>()).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "account data too small")
+        })?;
+        bytemuck::try_from_bytes(data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
+    }
+    /// Mutable version of [`Self::load`]
+    pub fn load_mut(buf: &mut [u8]) -> std::io::Result<&mut Market> {
+        let (maybe_discm, data) = buf.split_at_mut(buf.len().min(8));
+        if maybe_discm != MARKET_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    MARKET_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        let data = data
+            .get_mut(..std::mem::size_of::<Market>())
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "account data too small")
+            })?;
+        bytemuck::try_from_bytes_mut(data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
+    }
+    /// Borrows the account data as the account struct after checking that it is owned by `program_id`
+    pub fn load_account_info_with_program_id<'a>(
+        program_id: Pubkey,
+        account_info: &'a AccountInfo,
+    ) -> Result<Ref<'a, Market>, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Ref::filter_map(data, |data| Self::load(data).ok())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Borrows the account data as the account struct after checking that it is owned by this program
+    pub fn load_account_info<'a>(
+        account_info: &'a AccountInfo,
+    ) -> Result<Ref<'a, Market>, ProgramError> {
+        Self::load_account_info_with_program_id(crate::ID, account_info)
+    }
+    /// Mutably borrows the account data as the account struct after checking that it is owned by `program_id`
+    pub fn load_account_info_mut_with_program_id<'a>(
+        program_id: Pubkey,
+        account_info: &'a AccountInfo,
+    ) -> Result<RefMut<'a, Market>, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_mut_data()?;
+        RefMut::filter_map(data, |data| Self::load_mut(data).ok())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Mutably borrows the account data as the account struct after checking that it is owned by this program
+    pub fn load_account_info_mut<'a>(
+        account_info: &'a AccountInfo,
+    ) -> Result<RefMut<'a, Market>, ProgramError> {
+        Self::load_account_info_mut_with_program_id(crate::ID, account_info)
+    }
+}
+/// Serialized size of [`Market`]
+pub const MARKET_LEN: usize = std::mem::size_of::<Market>();
+/// Offset of `authority` in serialized [`Market`]
+pub const MARKET_AUTHORITY_OFFSET: usize = 0;
+/// Offset of `base_mint` in serialized [`Market`]
+pub const MARKET_BASE_MINT_OFFSET: usize = 32;
+/// Offset of `total_deposits` in serialized [`Market`]
+pub const MARKET_TOTAL_DEPOSITS_OFFSET: usize = 64;
+/// Offset of `fee` in serialized [`Market`]
+pub const MARKET_FEE_OFFSET: usize = 72;
+/// Offset of `padding` in serialized [`Market`]
+pub const MARKET_PADDING_OFFSET: usize = 81;
+/// Size of the account data, including the 8-byte discriminator
+pub const MARKET_ACCOUNT_LEN: usize = 8 + MARKET_LEN;
+/// Builds `getProgramAccounts` filters for [`MarketAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MarketAccountFilters(Vec<AccountFilter>);
+impl MarketAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: MARKET_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(MARKET_ACCOUNT_LEN as u64));
+        self
+    }
+    /// Matches accounts whose `authority` equals `value`
+    pub fn authority_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + MARKET_AUTHORITY_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `base_mint` equals `value`
+    pub fn base_mint_eq(mut self, value: &Pubkey) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + MARKET_BASE_MINT_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `total_deposits` equals `value`
+    pub fn total_deposits_eq(mut self, value: &u64) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + MARKET_TOTAL_DEPOSITS_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `fee` equals `value`
+    pub fn fee_eq(mut self, value: &Fee) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + MARKET_FEE_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// Matches accounts whose `padding` equals `value`
+    pub fn padding_eq(mut self, value: &[u8; 7]) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 8 + MARKET_PADDING_OFFSET,
+            bytes: borsh::to_vec(value).unwrap(),
+        });
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl MarketAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> MarketAccountFilters {
+        MarketAccountFilters::default()
+    }
+}
+pub const ORDER_BOOK_ACCOUNT_DISCM: [u8; 8] = [55, 230, 125, 218, 149, 39, 65, 248];
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderBook {
+    pub market: Pubkey,
+    pub side: u8,
+    pub prices: [u64; 16],
+}
+unsafe impl Pod for OrderBook {}
+unsafe impl Zeroable for OrderBook {}
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderBookAccount(pub OrderBook);
+impl OrderBookAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != ORDER_BOOK_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ORDER_BOOK_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        let data = reader
+            .get(..std::mem::size_of::<OrderBook>())
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "account data too small")
+            })?;
+        Ok(Self(bytemuck::pod_read_unaligned(data)))
+    }
+    /// Deserializes the account after checking that it is owned by `program_id`
+    pub fn from_account_info_with_program_id(
+        program_id: Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<Self, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Deserializes the account after checking that it is owned by this program
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::from_account_info_with_program_id(crate::ID, account_info)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&ORDER_BOOK_ACCOUNT_DISCM)?;
+        writer.write_all(bytemuck::bytes_of(&self.0))
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+    /// Reinterprets the account data after the discriminator as the account struct without copying
+    pub fn load(buf: &[u8]) -> std::io::Result<&OrderBook> {
+        let (maybe_discm, data) = buf.split_at(buf.len().min(8));
+        if maybe_discm != ORDER_BOOK_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ORDER_BOOK_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        let data = data
+            .get(..std::mem::size_of::<OrderBook>())
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "account data too small")
+            })?;
+        bytemuck::try_from_bytes(data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
+    }
+    /// Mutable version of [`Self::load`]
+    pub fn load_mut(buf: &mut [u8]) -> std::io::Result<&mut OrderBook> {
+        let (maybe_discm, data) = buf.split_at_mut(buf.len().min(8));
+        if maybe_discm != ORDER_BOOK_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ORDER_BOOK_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        let data = data
+            .get_mut(..std::mem::size_of::<OrderBook>())
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "account data too small")
+            })?;
+        bytemuck::try_from_bytes_mut(data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
+    }
+    /// Borrows the account data as the account struct after checking that it is owned by `program_id`
+    pub fn load_account_info_with_program_id<'a>(
+        program_id: Pubkey,
+        account_info: &'a AccountInfo,
+    ) -> Result<Ref<'a, OrderBook>, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_data()?;
+        Ref::filter_map(data, |data| Self::load(data).ok())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Borrows the account data as the account struct after checking that it is owned by this program
+    pub fn load_account_info<'a>(
+        account_info: &'a AccountInfo,
+    ) -> Result<Ref<'a, OrderBook>, ProgramError> {
+        Self::load_account_info_with_program_id(crate::ID, account_info)
+    }
+    /// Mutably borrows the account data as the account struct after checking that it is owned by `program_id`
+    pub fn load_account_info_mut_with_program_id<'a>(
+        program_id: Pubkey,
+        account_info: &'a AccountInfo,
+    ) -> Result<RefMut<'a, OrderBook>, ProgramError> {
+        if *account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+        let data = account_info.try_borrow_mut_data()?;
+        RefMut::filter_map(data, |data| Self::load_mut(data).ok())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// Mutably borrows the account data as the account struct after checking that it is owned by this program
+    pub fn load_account_info_mut<'a>(
+        account_info: &'a AccountInfo,
+    ) -> Result<RefMut<'a, OrderBook>, ProgramError> {
+        Self::load_account_info_mut_with_program_id(crate::ID, account_info)
+    }
+}
+/// Serialized size of [`OrderBook`]
+pub const ORDER_BOOK_LEN: usize = std::mem::size_of::<OrderBook>();
+/// Size of the account data, including the 8-byte discriminator
+pub const ORDER_BOOK_ACCOUNT_LEN: usize = 8 + ORDER_BOOK_LEN;
+/// Builds `getProgramAccounts` filters for [`OrderBookAccount`]s
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OrderBookAccountFilters(Vec<AccountFilter>);
+impl OrderBookAccountFilters {
+    /// Matches the account discriminator
+    pub fn discriminator(mut self) -> Self {
+        self.0.push(AccountFilter::Memcmp {
+            offset: 0,
+            bytes: ORDER_BOOK_ACCOUNT_DISCM.to_vec(),
+        });
+        self
+    }
+    /// Matches the account data size.
+    /// Accounts allocated with extra space will not match.
+    pub fn data_size(mut self) -> Self {
+        self.0
+            .push(AccountFilter::DataSize(ORDER_BOOK_ACCOUNT_LEN as u64));
+        self
+    }
+    /// The filters to pass to `getProgramAccounts`
+    pub fn build(self) -> Vec<AccountFilter> {
+        self.0
+    }
+}
+impl OrderBookAccount {
+    /// Starts building `getProgramAccounts` filters for this account
+    pub fn filters() -> OrderBookAccountFilters {
+        OrderBookAccountFilters::default()
+    }
+}
+/// A `getProgramAccounts` filter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountFilter {
+    /// Account data is exactly this many bytes long
+    DataSize(u64),
+    /// Account data at `offset` starts with `bytes`
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+#[cfg(feature = "solana-rpc-client-api")]
+impl From<AccountFilter> for solana_rpc_client_api::filter::RpcFilterType {
+    fn from(filter: AccountFilter) -> Self {
+        match filter {
+            AccountFilter::DataSize(size) => Self::DataSize(size),
+            AccountFilter::Memcmp { offset, bytes } => Self::Memcmp(
+                solana_rpc_client_api::filter::Memcmp::new_base58_encoded(offset, &bytes),
+            ),
+        }
+    }
+}
+/// The 8-byte discriminator that account data starts with, None if the data is too short
+pub fn discriminator_of(data: &[u8]) -> Option<[u8; 8]> {
+    data.get(..8).map(|discm| discm.try_into().unwrap())
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Anchor030ZeroCopyAccount {
+    Config(Config),
+    Market(Market),
+    OrderBook(OrderBook),
+}
+impl Anchor030ZeroCopyAccount {
+    /// Deserializes account data into the account type its discriminator identifies
+    pub fn try_from_bytes(data: &[u8]) -> std::io::Result<Self> {
+        let mut reader = data;
+        let maybe_discm = <[u8; 8]>::deserialize(&mut reader)?;
+        match maybe_discm {
+            CONFIG_ACCOUNT_DISCM => ConfigAccount::deserialize(data).map(|a| Self::Config(a.0)),
+            MARKET_ACCOUNT_DISCM => MarketAccount::deserialize(data).map(|a| Self::Market(a.0)),
+            ORDER_BOOK_ACCOUNT_DISCM => {
+                OrderBookAccount::deserialize(data).map(|a| Self::OrderBook(a.0))
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+}
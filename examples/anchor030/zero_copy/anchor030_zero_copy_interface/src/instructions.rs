@@ -0,0 +1,395 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum Anchor030ZeroCopyProgramIx {
+    InitMarket(InitMarketIxArgs),
+}
+impl Anchor030ZeroCopyProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            INIT_MARKET_IX_DISCM => Ok(Self::InitMarket(InitMarketIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::InitMarket(args) => {
+                writer.write_all(&INIT_MARKET_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const INIT_MARKET_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct InitMarketAccounts<'me, 'info> {
+    pub authority: &'me AccountInfo<'info>,
+    pub market: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InitMarketKeys {
+    pub authority: Pubkey,
+    pub market: Pubkey,
+}
+impl From<InitMarketAccounts<'_, '_>> for InitMarketKeys {
+    fn from(accounts: InitMarketAccounts) -> Self {
+        Self {
+            authority: *accounts.authority.key,
+            market: *accounts.market.key,
+        }
+    }
+}
+impl From<InitMarketKeys> for [AccountMeta; INIT_MARKET_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitMarketKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.market,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INIT_MARKET_IX_ACCOUNTS_LEN]> for InitMarketKeys {
+    fn from(pubkeys: [Pubkey; INIT_MARKET_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: pubkeys[0],
+            market: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<InitMarketAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INIT_MARKET_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitMarketAccounts<'_, 'info>) -> Self {
+        [accounts.authority.clone(), accounts.market.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INIT_MARKET_IX_ACCOUNTS_LEN]>
+    for InitMarketAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INIT_MARKET_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: &arr[0],
+            market: &arr[1],
+        }
+    }
+}
+impl InitMarketKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INIT_MARKET_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INIT_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INIT_MARKET_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitMarketKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitMarketAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INIT_MARKET_IX_ACCOUNTS_LEN] = accounts
+            .get(..INIT_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INIT_MARKET_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitMarketAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const INIT_MARKET_IX_DISCM: [u8; 8] = [33, 253, 15, 116, 89, 25, 127, 236];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitMarketIxArgs {
+    pub fee: Fee,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitMarketIxData(pub InitMarketIxArgs);
+impl From<InitMarketIxArgs> for InitMarketIxData {
+    fn from(args: InitMarketIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitMarketIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INIT_MARKET_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INIT_MARKET_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(InitMarketIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INIT_MARKET_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn init_market_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitMarketKeys,
+    args: InitMarketIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INIT_MARKET_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitMarketIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn init_market_ix(
+    keys: InitMarketKeys,
+    args: InitMarketIxArgs,
+) -> std::io::Result<Instruction> {
+    init_market_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`init_market_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitMarketIxBuilder {
+    authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    fee: Option<Fee>,
+}
+impl Default for InitMarketIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitMarketIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            authority: None,
+            market: None,
+            fee: None,
+        }
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn fee(mut self, fee: Fee) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitMarketIxBuilder is missing {}", field),
+            )
+        };
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let args = InitMarketIxArgs {
+            fee: self.fee.ok_or_else(|| missing("fee"))?,
+        };
+        let keys = InitMarketKeys { authority, market };
+        init_market_ix(keys, args)
+    }
+}
+pub fn init_market_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitMarketAccounts<'_, '_>,
+    args: InitMarketIxArgs,
+) -> ProgramResult {
+    let keys: InitMarketKeys = accounts.into();
+    let ix = init_market_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn init_market_invoke(
+    accounts: InitMarketAccounts<'_, '_>,
+    args: InitMarketIxArgs,
+) -> ProgramResult {
+    init_market_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn init_market_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitMarketAccounts<'_, '_>,
+    args: InitMarketIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitMarketKeys = accounts.into();
+    let ix = init_market_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn init_market_invoke_signed(
+    accounts: InitMarketAccounts<'_, '_>,
+    args: InitMarketIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    init_market_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn init_market_verify_account_keys(
+    accounts: InitMarketAccounts<'_, '_>,
+    keys: InitMarketKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.authority.key, keys.authority),
+        (*accounts.market.key, keys.market),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn init_market_verify_writable_privileges<'me, 'info>(
+    accounts: InitMarketAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.authority, accounts.market] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn init_market_verify_signer_privileges<'me, 'info>(
+    accounts: InitMarketAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn init_market_verify_account_privileges<'me, 'info>(
+    accounts: InitMarketAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    init_market_verify_writable_privileges(accounts)?;
+    init_market_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+/// A [`Anchor030ZeroCopyProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Anchor030ZeroCopyParsedIx {
+    InitMarket {
+        keys: InitMarketKeys,
+        args: InitMarketIxArgs,
+    },
+}
+impl Anchor030ZeroCopyParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = Anchor030ZeroCopyProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            Anchor030ZeroCopyProgramIx::InitMarket(args) => Self::InitMarket {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
@@ -0,0 +1,27 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+#[repr(C, packed)]
+#[derive(Clone, Debug, PartialEq, Pod, Zeroable, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fee {
+    pub enabled: u8,
+    pub bps: u64,
+}
+impl BorshSerialize for Fee {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(bytemuck::bytes_of(self))
+    }
+}
+impl BorshDeserialize for Fee {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut res = Self::zeroed();
+        reader.read_exact(bytemuck::bytes_of_mut(&mut res))?;
+        Ok(res)
+    }
+}
+/// Serialized size of [`Fee`]
+pub const FEE_LEN: usize = std::mem::size_of::<Fee>();
+/// Offset of `enabled` in serialized [`Fee`]
+pub const FEE_ENABLED_OFFSET: usize = 0;
+/// Offset of `bps` in serialized [`Fee`]
+pub const FEE_BPS_OFFSET: usize = 1;
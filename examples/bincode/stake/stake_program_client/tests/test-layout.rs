@@ -0,0 +1,28 @@
+use solana_program::pubkey::Pubkey;
+use stake_program_interface::*;
+
+#[test]
+fn test_struct_len_and_offsets() {
+    let lockup = Lockup {
+        unix_timestamp: -1,
+        epoch: 2,
+        custodian: Pubkey::new_unique(),
+    };
+    let serialized = bincode::serialize(&lockup).unwrap();
+    assert_eq!(serialized.len(), LOCKUP_LEN);
+    assert_eq!(
+        serialized[LOCKUP_EPOCH_OFFSET..LOCKUP_CUSTODIAN_OFFSET],
+        2u64.to_le_bytes()
+    );
+    assert_eq!(
+        serialized[LOCKUP_CUSTODIAN_OFFSET..],
+        lockup.custodian.to_bytes()
+    );
+}
+
+#[test]
+fn test_enum_len_has_u32_tag() {
+    let serialized = bincode::serialize(&StakeAuthorize::Withdrawer).unwrap();
+    assert_eq!(serialized.len(), STAKE_AUTHORIZE_LEN);
+    assert_eq!(serialized, 1u32.to_le_bytes());
+}
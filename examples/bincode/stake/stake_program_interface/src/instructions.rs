@@ -61,16 +61,16 @@ pub fn initialize_ix(keys: InitializeKeys, args: InitializeIxArgs) -> Instructio
 pub const INITIALIZE_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct InitializeAccounts<'me, 'info> {
-    ///The stake account to initialize
+    /// The stake account to initialize
     pub stake: &'me AccountInfo<'info>,
-    ///Rent sysvar
+    /// Rent sysvar
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct InitializeKeys {
-    ///The stake account to initialize
+    /// The stake account to initialize
     pub stake: Pubkey,
-    ///Rent sysvar
+    /// Rent sysvar
     pub rent: Pubkey,
 }
 impl From<InitializeAccounts<'_, '_>> for InitializeKeys {
@@ -122,6 +122,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl InitializeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INITIALIZE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INITIALIZE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct InitializeIxArgs {
     pub authorized: Authorized,
@@ -189,6 +227,65 @@ pub fn initialize_verify_account_privileges<'me, 'info>(
     initialize_verify_writable_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`initialize_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeIxBuilder {
+    stake: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    authorized: Option<Authorized>,
+    lockup: Option<Lockup>,
+}
+impl Default for InitializeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            authorized: None,
+            lockup: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn authorized(mut self, authorized: Authorized) -> Self {
+        self.authorized = Some(authorized);
+        self
+    }
+    pub fn lockup(mut self, lockup: Lockup) -> Self {
+        self.lockup = Some(lockup);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = InitializeIxArgs {
+            authorized: self.authorized.ok_or_else(|| missing("authorized"))?,
+            lockup: self.lockup.ok_or_else(|| missing("lockup"))?,
+        };
+        let keys = InitializeKeys { stake, rent };
+        Ok(initialize_ix(keys, args))
+    }
+}
 pub const AUTHORIZE_IX_DISCM: [u8; 4] = [1, 0, 0, 0];
 pub fn authorize_ix_with_program_id(
     program_id: Pubkey,
@@ -208,20 +305,20 @@ pub fn authorize_ix(keys: AuthorizeKeys, args: AuthorizeIxArgs) -> Instruction {
 pub const AUTHORIZE_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct AuthorizeAccounts<'me, 'info> {
-    ///The stake account to be updated
+    /// The stake account to be updated
     pub stake: &'me AccountInfo<'info>,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: &'me AccountInfo<'info>,
-    ///stake's current stake or withdraw authority to change away from. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer
+    /// stake's current stake or withdraw authority to change away from. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer
     pub authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AuthorizeKeys {
-    ///The stake account to be updated
+    /// The stake account to be updated
     pub stake: Pubkey,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: Pubkey,
-    ///stake's current stake or withdraw authority to change away from. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer
+    /// stake's current stake or withdraw authority to change away from. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer
     pub authority: Pubkey,
 }
 impl From<AuthorizeAccounts<'_, '_>> for AuthorizeKeys {
@@ -283,6 +380,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl AuthorizeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; AUTHORIZE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..AUTHORIZE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[AUTHORIZE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AuthorizeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AuthorizeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; AUTHORIZE_IX_ACCOUNTS_LEN] = accounts
+            .get(..AUTHORIZE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[AUTHORIZE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AuthorizeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct AuthorizeIxArgs {
     pub new_authority: Pubkey,
@@ -362,6 +497,78 @@ pub fn authorize_verify_account_privileges<'me, 'info>(
     authorize_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`authorize_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AuthorizeIxBuilder {
+    stake: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    new_authority: Option<Pubkey>,
+    stake_authorize: Option<StakeAuthorize>,
+}
+impl Default for AuthorizeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AuthorizeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            authority: None,
+            new_authority: None,
+            stake_authorize: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn new_authority(mut self, new_authority: Pubkey) -> Self {
+        self.new_authority = Some(new_authority);
+        self
+    }
+    pub fn stake_authorize(mut self, stake_authorize: StakeAuthorize) -> Self {
+        self.stake_authorize = Some(stake_authorize);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AuthorizeIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = AuthorizeIxArgs {
+            new_authority: self.new_authority.ok_or_else(|| missing("new_authority"))?,
+            stake_authorize: self
+                .stake_authorize
+                .ok_or_else(|| missing("stake_authorize"))?,
+        };
+        let keys = AuthorizeKeys {
+            stake,
+            clock,
+            authority,
+        };
+        Ok(authorize_ix(keys, args))
+    }
+}
 pub const DELEGATE_STAKE_IX_DISCM: [u8; 4] = [2, 0, 0, 0];
 pub fn delegate_stake_ix_with_program_id(
     program_id: Pubkey,
@@ -380,32 +587,32 @@ pub fn delegate_stake_ix(keys: DelegateStakeKeys) -> Instruction {
 pub const DELEGATE_STAKE_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct DelegateStakeAccounts<'me, 'info> {
-    ///The stake account to be delegated
+    /// The stake account to be delegated
     pub stake: &'me AccountInfo<'info>,
-    ///Vote account to which stake will be delegated
+    /// Vote account to which stake will be delegated
     pub vote: &'me AccountInfo<'info>,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: &'me AccountInfo<'info>,
-    ///Stake history sysvar
+    /// Stake history sysvar
     pub stake_history: &'me AccountInfo<'info>,
-    ///Stake config native program
+    /// Stake config native program
     pub stake_config: &'me AccountInfo<'info>,
-    ///stake's stake authority
+    /// stake's stake authority
     pub stake_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DelegateStakeKeys {
-    ///The stake account to be delegated
+    /// The stake account to be delegated
     pub stake: Pubkey,
-    ///Vote account to which stake will be delegated
+    /// Vote account to which stake will be delegated
     pub vote: Pubkey,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: Pubkey,
-    ///Stake history sysvar
+    /// Stake history sysvar
     pub stake_history: Pubkey,
-    ///Stake config native program
+    /// Stake config native program
     pub stake_config: Pubkey,
-    ///stake's stake authority
+    /// stake's stake authority
     pub stake_authority: Pubkey,
 }
 impl From<DelegateStakeAccounts<'_, '_>> for DelegateStakeKeys {
@@ -496,6 +703,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DELEGATE_STAKE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl DelegateStakeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DELEGATE_STAKE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DELEGATE_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[DELEGATE_STAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for DelegateStakeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DelegateStakeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DELEGATE_STAKE_IX_ACCOUNTS_LEN] = accounts
+            .get(..DELEGATE_STAKE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[DELEGATE_STAKE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for DelegateStakeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub fn delegate_stake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DelegateStakeAccounts<'_, '_>,
@@ -567,6 +812,88 @@ pub fn delegate_stake_verify_account_privileges<'me, 'info>(
     delegate_stake_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`delegate_stake_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DelegateStakeIxBuilder {
+    stake: Option<Pubkey>,
+    vote: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    stake_history: Option<Pubkey>,
+    stake_config: Option<Pubkey>,
+    stake_authority: Option<Pubkey>,
+}
+impl Default for DelegateStakeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DelegateStakeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            vote: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            stake_history: Some(solana_program::pubkey!(
+                "SysvarStakeHistory1111111111111111111111111"
+            )),
+            stake_config: None,
+            stake_authority: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn vote(mut self, vote: Pubkey) -> Self {
+        self.vote = Some(vote);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn stake_history(mut self, stake_history: Pubkey) -> Self {
+        self.stake_history = Some(stake_history);
+        self
+    }
+    pub fn stake_config(mut self, stake_config: Pubkey) -> Self {
+        self.stake_config = Some(stake_config);
+        self
+    }
+    pub fn stake_authority(mut self, stake_authority: Pubkey) -> Self {
+        self.stake_authority = Some(stake_authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DelegateStakeIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let vote = self.vote.ok_or_else(|| missing("vote"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let stake_history = self.stake_history.ok_or_else(|| missing("stake_history"))?;
+        let stake_config = self.stake_config.ok_or_else(|| missing("stake_config"))?;
+        let stake_authority = self
+            .stake_authority
+            .ok_or_else(|| missing("stake_authority"))?;
+        let keys = DelegateStakeKeys {
+            stake,
+            vote,
+            clock,
+            stake_history,
+            stake_config,
+            stake_authority,
+        };
+        Ok(delegate_stake_ix(keys))
+    }
+}
 pub const SPLIT_IX_DISCM: [u8; 4] = [3, 0, 0, 0];
 pub fn split_ix_with_program_id(
     program_id: Pubkey,
@@ -586,20 +913,20 @@ pub fn split_ix(keys: SplitKeys, args: SplitIxArgs) -> Instruction {
 pub const SPLIT_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct SplitAccounts<'me, 'info> {
-    ///The stake account to split. Must be in the Initialized or Stake state
+    /// The stake account to split. Must be in the Initialized or Stake state
     pub from: &'me AccountInfo<'info>,
-    ///The uninitialized stake account to split to. Must be rent-exempt starting from solana 1.17.
+    /// The uninitialized stake account to split to. Must be rent-exempt starting from solana 1.17.
     pub to: &'me AccountInfo<'info>,
-    ///from's stake authority
+    /// from's stake authority
     pub stake_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct SplitKeys {
-    ///The stake account to split. Must be in the Initialized or Stake state
+    /// The stake account to split. Must be in the Initialized or Stake state
     pub from: Pubkey,
-    ///The uninitialized stake account to split to. Must be rent-exempt starting from solana 1.17.
+    /// The uninitialized stake account to split to. Must be rent-exempt starting from solana 1.17.
     pub to: Pubkey,
-    ///from's stake authority
+    /// from's stake authority
     pub stake_authority: Pubkey,
 }
 impl From<SplitAccounts<'_, '_>> for SplitKeys {
@@ -661,6 +988,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SPLIT_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl SplitKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SPLIT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SPLIT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SPLIT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SplitKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SplitAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SPLIT_IX_ACCOUNTS_LEN] = accounts
+            .get(..SPLIT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SPLIT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SplitAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct SplitIxArgs {
     pub lamports: u64,
@@ -736,6 +1101,69 @@ pub fn split_verify_account_privileges<'me, 'info>(
     split_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`split_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SplitIxBuilder {
+    from: Option<Pubkey>,
+    to: Option<Pubkey>,
+    stake_authority: Option<Pubkey>,
+    lamports: Option<u64>,
+}
+impl Default for SplitIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SplitIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            from: None,
+            to: None,
+            stake_authority: None,
+            lamports: None,
+        }
+    }
+    pub fn from(mut self, from: Pubkey) -> Self {
+        self.from = Some(from);
+        self
+    }
+    pub fn to(mut self, to: Pubkey) -> Self {
+        self.to = Some(to);
+        self
+    }
+    pub fn stake_authority(mut self, stake_authority: Pubkey) -> Self {
+        self.stake_authority = Some(stake_authority);
+        self
+    }
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = Some(lamports);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SplitIxBuilder is missing {}", field),
+            )
+        };
+        let from = self.from.ok_or_else(|| missing("from"))?;
+        let to = self.to.ok_or_else(|| missing("to"))?;
+        let stake_authority = self
+            .stake_authority
+            .ok_or_else(|| missing("stake_authority"))?;
+        let args = SplitIxArgs {
+            lamports: self.lamports.ok_or_else(|| missing("lamports"))?,
+        };
+        let keys = SplitKeys {
+            from,
+            to,
+            stake_authority,
+        };
+        Ok(split_ix(keys, args))
+    }
+}
 pub const WITHDRAW_IX_DISCM: [u8; 4] = [4, 0, 0, 0];
 pub fn withdraw_ix_with_program_id(
     program_id: Pubkey,
@@ -755,28 +1183,28 @@ pub fn withdraw_ix(keys: WithdrawKeys, args: WithdrawIxArgs) -> Instruction {
 pub const WITHDRAW_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct WithdrawAccounts<'me, 'info> {
-    ///The stake account to withdraw from
+    /// The stake account to withdraw from
     pub from: &'me AccountInfo<'info>,
-    ///Recipient account
+    /// Recipient account
     pub to: &'me AccountInfo<'info>,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: &'me AccountInfo<'info>,
-    ///Stake history sysvar
+    /// Stake history sysvar
     pub stake_history: &'me AccountInfo<'info>,
-    ///from's withdraw authority. If stake Lockup is active, the signing lockup authority must follow.
+    /// from's withdraw authority. If stake Lockup is active, the signing lockup authority must follow.
     pub withdraw_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct WithdrawKeys {
-    ///The stake account to withdraw from
+    /// The stake account to withdraw from
     pub from: Pubkey,
-    ///Recipient account
+    /// Recipient account
     pub to: Pubkey,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: Pubkey,
-    ///Stake history sysvar
+    /// Stake history sysvar
     pub stake_history: Pubkey,
-    ///from's withdraw authority. If stake Lockup is active, the signing lockup authority must follow.
+    /// from's withdraw authority. If stake Lockup is active, the signing lockup authority must follow.
     pub withdraw_authority: Pubkey,
 }
 impl From<WithdrawAccounts<'_, '_>> for WithdrawKeys {
@@ -856,6 +1284,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl WithdrawKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; WITHDRAW_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..WITHDRAW_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[WITHDRAW_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for WithdrawKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> WithdrawAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] = accounts
+            .get(..WITHDRAW_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[WITHDRAW_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for WithdrawAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct WithdrawIxArgs {
     pub lamports: u64,
@@ -933,6 +1399,89 @@ pub fn withdraw_verify_account_privileges<'me, 'info>(
     withdraw_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`withdraw_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct WithdrawIxBuilder {
+    from: Option<Pubkey>,
+    to: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    stake_history: Option<Pubkey>,
+    withdraw_authority: Option<Pubkey>,
+    lamports: Option<u64>,
+}
+impl Default for WithdrawIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl WithdrawIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            from: None,
+            to: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            stake_history: Some(solana_program::pubkey!(
+                "SysvarStakeHistory1111111111111111111111111"
+            )),
+            withdraw_authority: None,
+            lamports: None,
+        }
+    }
+    pub fn from(mut self, from: Pubkey) -> Self {
+        self.from = Some(from);
+        self
+    }
+    pub fn to(mut self, to: Pubkey) -> Self {
+        self.to = Some(to);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn stake_history(mut self, stake_history: Pubkey) -> Self {
+        self.stake_history = Some(stake_history);
+        self
+    }
+    pub fn withdraw_authority(mut self, withdraw_authority: Pubkey) -> Self {
+        self.withdraw_authority = Some(withdraw_authority);
+        self
+    }
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = Some(lamports);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("WithdrawIxBuilder is missing {}", field),
+            )
+        };
+        let from = self.from.ok_or_else(|| missing("from"))?;
+        let to = self.to.ok_or_else(|| missing("to"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let stake_history = self.stake_history.ok_or_else(|| missing("stake_history"))?;
+        let withdraw_authority = self
+            .withdraw_authority
+            .ok_or_else(|| missing("withdraw_authority"))?;
+        let args = WithdrawIxArgs {
+            lamports: self.lamports.ok_or_else(|| missing("lamports"))?,
+        };
+        let keys = WithdrawKeys {
+            from,
+            to,
+            clock,
+            stake_history,
+            withdraw_authority,
+        };
+        Ok(withdraw_ix(keys, args))
+    }
+}
 pub const DEACTIVATE_IX_DISCM: [u8; 4] = [5, 0, 0, 0];
 pub fn deactivate_ix_with_program_id(program_id: Pubkey, keys: DeactivateKeys) -> Instruction {
     let metas: [AccountMeta; DEACTIVATE_IX_ACCOUNTS_LEN] = keys.into();
@@ -948,20 +1497,20 @@ pub fn deactivate_ix(keys: DeactivateKeys) -> Instruction {
 pub const DEACTIVATE_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct DeactivateAccounts<'me, 'info> {
-    ///The stake account to deactivate
+    /// The stake account to deactivate
     pub stake: &'me AccountInfo<'info>,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: &'me AccountInfo<'info>,
-    ///stake's stake authority
+    /// stake's stake authority
     pub stake_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DeactivateKeys {
-    ///The stake account to deactivate
+    /// The stake account to deactivate
     pub stake: Pubkey,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: Pubkey,
-    ///stake's stake authority
+    /// stake's stake authority
     pub stake_authority: Pubkey,
 }
 impl From<DeactivateAccounts<'_, '_>> for DeactivateKeys {
@@ -1025,6 +1574,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl DeactivateKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEACTIVATE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEACTIVATE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[DEACTIVATE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeactivateKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeactivateAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEACTIVATE_IX_ACCOUNTS_LEN] = accounts
+            .get(..DEACTIVATE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[DEACTIVATE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for DeactivateAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub fn deactivate_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateAccounts<'_, '_>,
@@ -1093,6 +1680,62 @@ pub fn deactivate_verify_account_privileges<'me, 'info>(
     deactivate_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`deactivate_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeactivateIxBuilder {
+    stake: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    stake_authority: Option<Pubkey>,
+}
+impl Default for DeactivateIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeactivateIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            stake_authority: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn stake_authority(mut self, stake_authority: Pubkey) -> Self {
+        self.stake_authority = Some(stake_authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DeactivateIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let stake_authority = self
+            .stake_authority
+            .ok_or_else(|| missing("stake_authority"))?;
+        let keys = DeactivateKeys {
+            stake,
+            clock,
+            stake_authority,
+        };
+        Ok(deactivate_ix(keys))
+    }
+}
 pub const SET_LOCKUP_IX_DISCM: [u8; 4] = [6, 0, 0, 0];
 pub fn set_lockup_ix_with_program_id(
     program_id: Pubkey,
@@ -1112,16 +1755,16 @@ pub fn set_lockup_ix(keys: SetLockupKeys, args: SetLockupIxArgs) -> Instruction
 pub const SET_LOCKUP_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct SetLockupAccounts<'me, 'info> {
-    ///The stake account to set the lockup of
+    /// The stake account to set the lockup of
     pub stake: &'me AccountInfo<'info>,
-    ///stake's withdraw authority or lockup authority if lockup is active
+    /// stake's withdraw authority or lockup authority if lockup is active
     pub authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct SetLockupKeys {
-    ///The stake account to set the lockup of
+    /// The stake account to set the lockup of
     pub stake: Pubkey,
-    ///stake's withdraw authority or lockup authority if lockup is active
+    /// stake's withdraw authority or lockup authority if lockup is active
     pub authority: Pubkey,
 }
 impl From<SetLockupAccounts<'_, '_>> for SetLockupKeys {
@@ -1173,6 +1816,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LOCKUP_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl SetLockupKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SET_LOCKUP_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SET_LOCKUP_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SET_LOCKUP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SetLockupKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SetLockupAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SET_LOCKUP_IX_ACCOUNTS_LEN] = accounts
+            .get(..SET_LOCKUP_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SET_LOCKUP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SetLockupAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct SetLockupIxArgs {
     pub unix_timestamp: Option<i64>,
@@ -1252,6 +1933,72 @@ pub fn set_lockup_verify_account_privileges<'me, 'info>(
     set_lockup_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`set_lockup_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SetLockupIxBuilder {
+    stake: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    unix_timestamp: Option<Option<i64>>,
+    epoch: Option<Option<u64>>,
+    custodian: Option<Option<Pubkey>>,
+}
+impl Default for SetLockupIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SetLockupIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            authority: None,
+            unix_timestamp: None,
+            epoch: None,
+            custodian: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn unix_timestamp(mut self, unix_timestamp: Option<i64>) -> Self {
+        self.unix_timestamp = Some(unix_timestamp);
+        self
+    }
+    pub fn epoch(mut self, epoch: Option<u64>) -> Self {
+        self.epoch = Some(epoch);
+        self
+    }
+    pub fn custodian(mut self, custodian: Option<Pubkey>) -> Self {
+        self.custodian = Some(custodian);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SetLockupIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = SetLockupIxArgs {
+            unix_timestamp: self
+                .unix_timestamp
+                .ok_or_else(|| missing("unix_timestamp"))?,
+            epoch: self.epoch.ok_or_else(|| missing("epoch"))?,
+            custodian: self.custodian.ok_or_else(|| missing("custodian"))?,
+        };
+        let keys = SetLockupKeys { stake, authority };
+        Ok(set_lockup_ix(keys, args))
+    }
+}
 pub const MERGE_IX_DISCM: [u8; 4] = [7, 0, 0, 0];
 pub fn merge_ix_with_program_id(program_id: Pubkey, keys: MergeKeys) -> Instruction {
     let metas: [AccountMeta; MERGE_IX_ACCOUNTS_LEN] = keys.into();
@@ -1263,28 +2010,28 @@ pub fn merge_ix(keys: MergeKeys) -> Instruction {
 pub const MERGE_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct MergeAccounts<'me, 'info> {
-    ///The destination stake account to merge into
+    /// The destination stake account to merge into
     pub to: &'me AccountInfo<'info>,
-    ///The stake account to merge from. Must have exact same lockup and authority as to. This account will be drained.
+    /// The stake account to merge from. Must have exact same lockup and authority as to. This account will be drained.
     pub from: &'me AccountInfo<'info>,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: &'me AccountInfo<'info>,
-    ///Stake history sysvar
+    /// Stake history sysvar
     pub stake_history: &'me AccountInfo<'info>,
-    ///Both from and to's stake authority
+    /// Both from and to's stake authority
     pub stake_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct MergeKeys {
-    ///The destination stake account to merge into
+    /// The destination stake account to merge into
     pub to: Pubkey,
-    ///The stake account to merge from. Must have exact same lockup and authority as to. This account will be drained.
+    /// The stake account to merge from. Must have exact same lockup and authority as to. This account will be drained.
     pub from: Pubkey,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: Pubkey,
-    ///Stake history sysvar
+    /// Stake history sysvar
     pub stake_history: Pubkey,
-    ///Both from and to's stake authority
+    /// Both from and to's stake authority
     pub stake_authority: Pubkey,
 }
 impl From<MergeAccounts<'_, '_>> for MergeKeys {
@@ -1364,6 +2111,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; MERGE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl MergeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; MERGE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..MERGE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[MERGE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for MergeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> MergeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; MERGE_IX_ACCOUNTS_LEN] = accounts
+            .get(..MERGE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[MERGE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for MergeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub fn merge_invoke_with_program_id(
     program_id: Pubkey,
     accounts: MergeAccounts<'_, '_>,
@@ -1431,6 +2216,80 @@ pub fn merge_verify_account_privileges<'me, 'info>(
     merge_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`merge_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct MergeIxBuilder {
+    to: Option<Pubkey>,
+    from: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    stake_history: Option<Pubkey>,
+    stake_authority: Option<Pubkey>,
+}
+impl Default for MergeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl MergeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            to: None,
+            from: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            stake_history: Some(solana_program::pubkey!(
+                "SysvarStakeHistory1111111111111111111111111"
+            )),
+            stake_authority: None,
+        }
+    }
+    pub fn to(mut self, to: Pubkey) -> Self {
+        self.to = Some(to);
+        self
+    }
+    pub fn from(mut self, from: Pubkey) -> Self {
+        self.from = Some(from);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn stake_history(mut self, stake_history: Pubkey) -> Self {
+        self.stake_history = Some(stake_history);
+        self
+    }
+    pub fn stake_authority(mut self, stake_authority: Pubkey) -> Self {
+        self.stake_authority = Some(stake_authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("MergeIxBuilder is missing {}", field),
+            )
+        };
+        let to = self.to.ok_or_else(|| missing("to"))?;
+        let from = self.from.ok_or_else(|| missing("from"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let stake_history = self.stake_history.ok_or_else(|| missing("stake_history"))?;
+        let stake_authority = self
+            .stake_authority
+            .ok_or_else(|| missing("stake_authority"))?;
+        let keys = MergeKeys {
+            to,
+            from,
+            clock,
+            stake_history,
+            stake_authority,
+        };
+        Ok(merge_ix(keys))
+    }
+}
 pub const AUTHORIZE_WITH_SEED_IX_DISCM: [u8; 4] = [8, 0, 0, 0];
 pub fn authorize_with_seed_ix_with_program_id(
     program_id: Pubkey,
@@ -1453,20 +2312,20 @@ pub fn authorize_with_seed_ix(
 pub const AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct AuthorizeWithSeedAccounts<'me, 'info> {
-    ///The stake account to be updated, with the authority to be updated being an account created with Pubkey::create_with_seed()
+    /// The stake account to be updated, with the authority to be updated being an account created with Pubkey::create_with_seed()
     pub stake: &'me AccountInfo<'info>,
-    ///Base account of stake's authority to be updated
+    /// Base account of stake's authority to be updated
     pub authority_base: &'me AccountInfo<'info>,
-    ///Clock sysvar. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
+    /// Clock sysvar. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
     pub clock: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AuthorizeWithSeedKeys {
-    ///The stake account to be updated, with the authority to be updated being an account created with Pubkey::create_with_seed()
+    /// The stake account to be updated, with the authority to be updated being an account created with Pubkey::create_with_seed()
     pub stake: Pubkey,
-    ///Base account of stake's authority to be updated
+    /// Base account of stake's authority to be updated
     pub authority_base: Pubkey,
-    ///Clock sysvar. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
+    /// Clock sysvar. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
     pub clock: Pubkey,
 }
 impl From<AuthorizeWithSeedAccounts<'_, '_>> for AuthorizeWithSeedKeys {
@@ -1530,6 +2389,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_
         }
     }
 }
+impl AuthorizeWithSeedKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AuthorizeWithSeedKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AuthorizeWithSeedAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN] = accounts
+            .get(..AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AuthorizeWithSeedAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct AuthorizeWithSeedIxArgs {
     pub new_authority: Pubkey,
@@ -1611,6 +2508,98 @@ pub fn authorize_with_seed_verify_account_privileges<'me, 'info>(
     authorize_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`authorize_with_seed_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AuthorizeWithSeedIxBuilder {
+    stake: Option<Pubkey>,
+    authority_base: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    new_authority: Option<Pubkey>,
+    stake_authorize: Option<StakeAuthorize>,
+    authority_seed: Option<String>,
+    authority_owner: Option<Pubkey>,
+}
+impl Default for AuthorizeWithSeedIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AuthorizeWithSeedIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            authority_base: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            new_authority: None,
+            stake_authorize: None,
+            authority_seed: None,
+            authority_owner: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn authority_base(mut self, authority_base: Pubkey) -> Self {
+        self.authority_base = Some(authority_base);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn new_authority(mut self, new_authority: Pubkey) -> Self {
+        self.new_authority = Some(new_authority);
+        self
+    }
+    pub fn stake_authorize(mut self, stake_authorize: StakeAuthorize) -> Self {
+        self.stake_authorize = Some(stake_authorize);
+        self
+    }
+    pub fn authority_seed(mut self, authority_seed: String) -> Self {
+        self.authority_seed = Some(authority_seed);
+        self
+    }
+    pub fn authority_owner(mut self, authority_owner: Pubkey) -> Self {
+        self.authority_owner = Some(authority_owner);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AuthorizeWithSeedIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let authority_base = self
+            .authority_base
+            .ok_or_else(|| missing("authority_base"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let args = AuthorizeWithSeedIxArgs {
+            new_authority: self.new_authority.ok_or_else(|| missing("new_authority"))?,
+            stake_authorize: self
+                .stake_authorize
+                .ok_or_else(|| missing("stake_authorize"))?,
+            authority_seed: self
+                .authority_seed
+                .ok_or_else(|| missing("authority_seed"))?,
+            authority_owner: self
+                .authority_owner
+                .ok_or_else(|| missing("authority_owner"))?,
+        };
+        let keys = AuthorizeWithSeedKeys {
+            stake,
+            authority_base,
+            clock,
+        };
+        Ok(authorize_with_seed_ix(keys, args))
+    }
+}
 pub const INITIALIZE_CHECKED_IX_DISCM: [u8; 4] = [9, 0, 0, 0];
 pub fn initialize_checked_ix_with_program_id(
     program_id: Pubkey,
@@ -1629,24 +2618,24 @@ pub fn initialize_checked_ix(keys: InitializeCheckedKeys) -> Instruction {
 pub const INITIALIZE_CHECKED_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct InitializeCheckedAccounts<'me, 'info> {
-    ///The stake account to initialize
+    /// The stake account to initialize
     pub stake: &'me AccountInfo<'info>,
-    ///Rent sysvar
+    /// Rent sysvar
     pub rent: &'me AccountInfo<'info>,
-    ///stake's new stake authority
+    /// stake's new stake authority
     pub stake_authority: &'me AccountInfo<'info>,
-    ///stake's new withdraw authority
+    /// stake's new withdraw authority
     pub withdraw_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct InitializeCheckedKeys {
-    ///The stake account to initialize
+    /// The stake account to initialize
     pub stake: Pubkey,
-    ///Rent sysvar
+    /// Rent sysvar
     pub rent: Pubkey,
-    ///stake's new stake authority
+    /// stake's new stake authority
     pub stake_authority: Pubkey,
-    ///stake's new withdraw authority
+    /// stake's new withdraw authority
     pub withdraw_authority: Pubkey,
 }
 impl From<InitializeCheckedAccounts<'_, '_>> for InitializeCheckedKeys {
@@ -1719,6 +2708,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_CHECKED_IX_ACCOUNTS_L
         }
     }
 }
+impl InitializeCheckedKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_CHECKED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_CHECKED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INITIALIZE_CHECKED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeCheckedKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeCheckedAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_CHECKED_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_CHECKED_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INITIALIZE_CHECKED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeCheckedAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub fn initialize_checked_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeCheckedAccounts<'_, '_>,
@@ -1788,6 +2815,72 @@ pub fn initialize_checked_verify_account_privileges<'me, 'info>(
     initialize_checked_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`initialize_checked_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeCheckedIxBuilder {
+    stake: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    stake_authority: Option<Pubkey>,
+    withdraw_authority: Option<Pubkey>,
+}
+impl Default for InitializeCheckedIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeCheckedIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            stake_authority: None,
+            withdraw_authority: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn stake_authority(mut self, stake_authority: Pubkey) -> Self {
+        self.stake_authority = Some(stake_authority);
+        self
+    }
+    pub fn withdraw_authority(mut self, withdraw_authority: Pubkey) -> Self {
+        self.withdraw_authority = Some(withdraw_authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeCheckedIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let stake_authority = self
+            .stake_authority
+            .ok_or_else(|| missing("stake_authority"))?;
+        let withdraw_authority = self
+            .withdraw_authority
+            .ok_or_else(|| missing("withdraw_authority"))?;
+        let keys = InitializeCheckedKeys {
+            stake,
+            rent,
+            stake_authority,
+            withdraw_authority,
+        };
+        Ok(initialize_checked_ix(keys))
+    }
+}
 pub const AUTHORIZE_CHECKED_IX_DISCM: [u8; 4] = [10, 0, 0, 0];
 pub fn authorize_checked_ix_with_program_id(
     program_id: Pubkey,
@@ -1810,24 +2903,24 @@ pub fn authorize_checked_ix(
 pub const AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct AuthorizeCheckedAccounts<'me, 'info> {
-    ///The stake account to be updated
+    /// The stake account to be updated
     pub stake: &'me AccountInfo<'info>,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: &'me AccountInfo<'info>,
-    ///stake's current stake or withdraw authority to change away from
+    /// stake's current stake or withdraw authority to change away from
     pub authority: &'me AccountInfo<'info>,
-    ///stake's new stake or withdraw authority to change to. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
+    /// stake's new stake or withdraw authority to change to. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
     pub new_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AuthorizeCheckedKeys {
-    ///The stake account to be updated
+    /// The stake account to be updated
     pub stake: Pubkey,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: Pubkey,
-    ///stake's current stake or withdraw authority to change away from
+    /// stake's current stake or withdraw authority to change away from
     pub authority: Pubkey,
-    ///stake's new stake or withdraw authority to change to. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
+    /// stake's new stake or withdraw authority to change to. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
     pub new_authority: Pubkey,
 }
 impl From<AuthorizeCheckedAccounts<'_, '_>> for AuthorizeCheckedKeys {
@@ -1900,6 +2993,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_CHECKED_IX_ACCOUNTS_LE
         }
     }
 }
+impl AuthorizeCheckedKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AuthorizeCheckedKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AuthorizeCheckedAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN] = accounts
+            .get(..AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AuthorizeCheckedAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct AuthorizeCheckedIxArgs {
     pub stake_authorize: StakeAuthorize,
@@ -1979,6 +3110,79 @@ pub fn authorize_checked_verify_account_privileges<'me, 'info>(
     authorize_checked_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`authorize_checked_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AuthorizeCheckedIxBuilder {
+    stake: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    new_authority: Option<Pubkey>,
+    stake_authorize: Option<StakeAuthorize>,
+}
+impl Default for AuthorizeCheckedIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AuthorizeCheckedIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            authority: None,
+            new_authority: None,
+            stake_authorize: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn new_authority(mut self, new_authority: Pubkey) -> Self {
+        self.new_authority = Some(new_authority);
+        self
+    }
+    pub fn stake_authorize(mut self, stake_authorize: StakeAuthorize) -> Self {
+        self.stake_authorize = Some(stake_authorize);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AuthorizeCheckedIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let new_authority = self.new_authority.ok_or_else(|| missing("new_authority"))?;
+        let args = AuthorizeCheckedIxArgs {
+            stake_authorize: self
+                .stake_authorize
+                .ok_or_else(|| missing("stake_authorize"))?,
+        };
+        let keys = AuthorizeCheckedKeys {
+            stake,
+            clock,
+            authority,
+            new_authority,
+        };
+        Ok(authorize_checked_ix(keys, args))
+    }
+}
 pub const AUTHORIZE_CHECKED_WITH_SEED_IX_DISCM: [u8; 4] = [11, 0, 0, 0];
 pub fn authorize_checked_with_seed_ix_with_program_id(
     program_id: Pubkey,
@@ -2001,24 +3205,24 @@ pub fn authorize_checked_with_seed_ix(
 pub const AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct AuthorizeCheckedWithSeedAccounts<'me, 'info> {
-    ///The stake account to be updated
+    /// The stake account to be updated
     pub stake: &'me AccountInfo<'info>,
-    ///Base account of stake's authority to be updated
+    /// Base account of stake's authority to be updated
     pub authority_base: &'me AccountInfo<'info>,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: &'me AccountInfo<'info>,
-    ///stake's new stake or withdraw authority to change to. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
+    /// stake's new stake or withdraw authority to change to. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
     pub new_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AuthorizeCheckedWithSeedKeys {
-    ///The stake account to be updated
+    /// The stake account to be updated
     pub stake: Pubkey,
-    ///Base account of stake's authority to be updated
+    /// Base account of stake's authority to be updated
     pub authority_base: Pubkey,
-    ///Clock sysvar
+    /// Clock sysvar
     pub clock: Pubkey,
-    ///stake's new stake or withdraw authority to change to. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
+    /// stake's new stake or withdraw authority to change to. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
     pub new_authority: Pubkey,
 }
 impl From<AuthorizeCheckedWithSeedAccounts<'_, '_>> for AuthorizeCheckedWithSeedKeys {
@@ -2093,6 +3297,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_CHECKED_WITH_SEED_IX_A
         }
     }
 }
+impl AuthorizeCheckedWithSeedKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for AuthorizeCheckedWithSeedKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AuthorizeCheckedWithSeedAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN] = accounts
+            .get(..AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for AuthorizeCheckedWithSeedAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct AuthorizeCheckedWithSeedIxArgs {
     pub stake_authorize: StakeAuthorize,
@@ -2174,6 +3424,99 @@ pub fn authorize_checked_with_seed_verify_account_privileges<'me, 'info>(
     authorize_checked_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`authorize_checked_with_seed_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AuthorizeCheckedWithSeedIxBuilder {
+    stake: Option<Pubkey>,
+    authority_base: Option<Pubkey>,
+    clock: Option<Pubkey>,
+    new_authority: Option<Pubkey>,
+    stake_authorize: Option<StakeAuthorize>,
+    authority_seed: Option<String>,
+    authority_owner: Option<Pubkey>,
+}
+impl Default for AuthorizeCheckedWithSeedIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AuthorizeCheckedWithSeedIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            authority_base: None,
+            clock: Some(solana_program::pubkey!(
+                "SysvarC1ock11111111111111111111111111111111"
+            )),
+            new_authority: None,
+            stake_authorize: None,
+            authority_seed: None,
+            authority_owner: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn authority_base(mut self, authority_base: Pubkey) -> Self {
+        self.authority_base = Some(authority_base);
+        self
+    }
+    pub fn clock(mut self, clock: Pubkey) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+    pub fn new_authority(mut self, new_authority: Pubkey) -> Self {
+        self.new_authority = Some(new_authority);
+        self
+    }
+    pub fn stake_authorize(mut self, stake_authorize: StakeAuthorize) -> Self {
+        self.stake_authorize = Some(stake_authorize);
+        self
+    }
+    pub fn authority_seed(mut self, authority_seed: String) -> Self {
+        self.authority_seed = Some(authority_seed);
+        self
+    }
+    pub fn authority_owner(mut self, authority_owner: Pubkey) -> Self {
+        self.authority_owner = Some(authority_owner);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AuthorizeCheckedWithSeedIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let authority_base = self
+            .authority_base
+            .ok_or_else(|| missing("authority_base"))?;
+        let clock = self.clock.ok_or_else(|| missing("clock"))?;
+        let new_authority = self.new_authority.ok_or_else(|| missing("new_authority"))?;
+        let args = AuthorizeCheckedWithSeedIxArgs {
+            stake_authorize: self
+                .stake_authorize
+                .ok_or_else(|| missing("stake_authorize"))?,
+            authority_seed: self
+                .authority_seed
+                .ok_or_else(|| missing("authority_seed"))?,
+            authority_owner: self
+                .authority_owner
+                .ok_or_else(|| missing("authority_owner"))?,
+        };
+        let keys = AuthorizeCheckedWithSeedKeys {
+            stake,
+            authority_base,
+            clock,
+            new_authority,
+        };
+        Ok(authorize_checked_with_seed_ix(keys, args))
+    }
+}
 pub const SET_LOCKUP_CHECKED_IX_DISCM: [u8; 4] = [12, 0, 0, 0];
 pub fn set_lockup_checked_ix_with_program_id(
     program_id: Pubkey,
@@ -2196,16 +3539,16 @@ pub fn set_lockup_checked_ix(
 pub const SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct SetLockupCheckedAccounts<'me, 'info> {
-    ///The stake account to set the lockup of
+    /// The stake account to set the lockup of
     pub stake: &'me AccountInfo<'info>,
-    ///stake's withdraw authority or lockup authority if lockup is active. If setting a new lockup authority, the signing new lockup authority must follow.
+    /// stake's withdraw authority or lockup authority if lockup is active. If setting a new lockup authority, the signing new lockup authority must follow.
     pub authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct SetLockupCheckedKeys {
-    ///The stake account to set the lockup of
+    /// The stake account to set the lockup of
     pub stake: Pubkey,
-    ///stake's withdraw authority or lockup authority if lockup is active. If setting a new lockup authority, the signing new lockup authority must follow.
+    /// stake's withdraw authority or lockup authority if lockup is active. If setting a new lockup authority, the signing new lockup authority must follow.
     pub authority: Pubkey,
 }
 impl From<SetLockupCheckedAccounts<'_, '_>> for SetLockupCheckedKeys {
@@ -2257,6 +3600,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LOCKUP_CHECKED_IX_ACCOUNTS_L
         }
     }
 }
+impl SetLockupCheckedKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SetLockupCheckedKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SetLockupCheckedAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN] = accounts
+            .get(..SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SetLockupCheckedAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct SetLockupCheckedIxArgs {
     pub unix_timestamp: Option<i64>,
@@ -2335,6 +3716,65 @@ pub fn set_lockup_checked_verify_account_privileges<'me, 'info>(
     set_lockup_checked_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`set_lockup_checked_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SetLockupCheckedIxBuilder {
+    stake: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    unix_timestamp: Option<Option<i64>>,
+    epoch: Option<Option<u64>>,
+}
+impl Default for SetLockupCheckedIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SetLockupCheckedIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            authority: None,
+            unix_timestamp: None,
+            epoch: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn unix_timestamp(mut self, unix_timestamp: Option<i64>) -> Self {
+        self.unix_timestamp = Some(unix_timestamp);
+        self
+    }
+    pub fn epoch(mut self, epoch: Option<u64>) -> Self {
+        self.epoch = Some(epoch);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SetLockupCheckedIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = SetLockupCheckedIxArgs {
+            unix_timestamp: self
+                .unix_timestamp
+                .ok_or_else(|| missing("unix_timestamp"))?,
+            epoch: self.epoch.ok_or_else(|| missing("epoch"))?,
+        };
+        let keys = SetLockupCheckedKeys { stake, authority };
+        Ok(set_lockup_checked_ix(keys, args))
+    }
+}
 pub const GET_MINIMUM_DELEGATION_IX_DISCM: [u8; 4] = [13, 0, 0, 0];
 pub fn get_minimum_delegation_ix_with_program_id(program_id: Pubkey) -> Instruction {
     Instruction::new_with_bincode(
@@ -2363,6 +3803,24 @@ pub fn get_minimum_delegation_invoke_signed_with_program_id(
 pub fn get_minimum_delegation_invoke_signed(seeds: &[&[&[u8]]]) -> ProgramResult {
     get_minimum_delegation_invoke_signed_with_program_id(crate::ID, seeds)
 }
+/// Builds a [`get_minimum_delegation_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct GetMinimumDelegationIxBuilder {}
+impl Default for GetMinimumDelegationIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl GetMinimumDelegationIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {}
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        Ok(get_minimum_delegation_ix())
+    }
+}
 pub const DEACTIVATE_DELINQUENT_IX_DISCM: [u8; 4] = [14, 0, 0, 0];
 pub fn deactivate_delinquent_ix_with_program_id(
     program_id: Pubkey,
@@ -2381,20 +3839,20 @@ pub fn deactivate_delinquent_ix(keys: DeactivateDelinquentKeys) -> Instruction {
 pub const DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct DeactivateDelinquentAccounts<'me, 'info> {
-    ///The delinquent stake account to deactivate
+    /// The delinquent stake account to deactivate
     pub stake: &'me AccountInfo<'info>,
-    ///stake's delinquent vote account
+    /// stake's delinquent vote account
     pub vote: &'me AccountInfo<'info>,
-    ///Reference vote account that has voted at least once in the last MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION epochs
+    /// Reference vote account that has voted at least once in the last MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION epochs
     pub reference_vote: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DeactivateDelinquentKeys {
-    ///The delinquent stake account to deactivate
+    /// The delinquent stake account to deactivate
     pub stake: Pubkey,
-    ///stake's delinquent vote account
+    /// stake's delinquent vote account
     pub vote: Pubkey,
-    ///Reference vote account that has voted at least once in the last MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION epochs
+    /// Reference vote account that has voted at least once in the last MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION epochs
     pub reference_vote: Pubkey,
 }
 impl From<DeactivateDelinquentAccounts<'_, '_>> for DeactivateDelinquentKeys {
@@ -2458,6 +3916,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_DELINQUENT_IX_ACCOUNT
         }
     }
 }
+impl DeactivateDelinquentKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeactivateDelinquentKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeactivateDelinquentAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN] = accounts
+            .get(..DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for DeactivateDelinquentAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub fn deactivate_delinquent_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateDelinquentAccounts<'_, '_>,
@@ -2517,6 +4019,60 @@ pub fn deactivate_delinquent_verify_account_privileges<'me, 'info>(
     deactivate_delinquent_verify_writable_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`deactivate_delinquent_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeactivateDelinquentIxBuilder {
+    stake: Option<Pubkey>,
+    vote: Option<Pubkey>,
+    reference_vote: Option<Pubkey>,
+}
+impl Default for DeactivateDelinquentIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeactivateDelinquentIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            vote: None,
+            reference_vote: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn vote(mut self, vote: Pubkey) -> Self {
+        self.vote = Some(vote);
+        self
+    }
+    pub fn reference_vote(mut self, reference_vote: Pubkey) -> Self {
+        self.reference_vote = Some(reference_vote);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DeactivateDelinquentIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let vote = self.vote.ok_or_else(|| missing("vote"))?;
+        let reference_vote = self
+            .reference_vote
+            .ok_or_else(|| missing("reference_vote"))?;
+        let keys = DeactivateDelinquentKeys {
+            stake,
+            vote,
+            reference_vote,
+        };
+        Ok(deactivate_delinquent_ix(keys))
+    }
+}
 pub const REDELEGATE_IX_DISCM: [u8; 4] = [15, 0, 0, 0];
 pub fn redelegate_ix_with_program_id(program_id: Pubkey, keys: RedelegateKeys) -> Instruction {
     let metas: [AccountMeta; REDELEGATE_IX_ACCOUNTS_LEN] = keys.into();
@@ -2532,28 +4088,28 @@ pub fn redelegate_ix(keys: RedelegateKeys) -> Instruction {
 pub const REDELEGATE_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct RedelegateAccounts<'me, 'info> {
-    ///The delegated stake account to be redelegated. The account must be fully activated and carry a balance greater than or equal to the minimum delegation amount plus rent exempt minimum
+    /// The delegated stake account to be redelegated. The account must be fully activated and carry a balance greater than or equal to the minimum delegation amount plus rent exempt minimum
     pub stake: &'me AccountInfo<'info>,
-    ///Uninitialized stake account that will hold the redelegated stake
+    /// Uninitialized stake account that will hold the redelegated stake
     pub uninitialized_stake: &'me AccountInfo<'info>,
-    ///Vote account to which stake will be redelegated
+    /// Vote account to which stake will be redelegated
     pub vote: &'me AccountInfo<'info>,
-    ///Stake config native program
+    /// Stake config native program
     pub stake_config: &'me AccountInfo<'info>,
-    ///stake's stake authority
+    /// stake's stake authority
     pub stake_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct RedelegateKeys {
-    ///The delegated stake account to be redelegated. The account must be fully activated and carry a balance greater than or equal to the minimum delegation amount plus rent exempt minimum
+    /// The delegated stake account to be redelegated. The account must be fully activated and carry a balance greater than or equal to the minimum delegation amount plus rent exempt minimum
     pub stake: Pubkey,
-    ///Uninitialized stake account that will hold the redelegated stake
+    /// Uninitialized stake account that will hold the redelegated stake
     pub uninitialized_stake: Pubkey,
-    ///Vote account to which stake will be redelegated
+    /// Vote account to which stake will be redelegated
     pub vote: Pubkey,
-    ///Stake config native program
+    /// Stake config native program
     pub stake_config: Pubkey,
-    ///stake's stake authority
+    /// stake's stake authority
     pub stake_authority: Pubkey,
 }
 impl From<RedelegateAccounts<'_, '_>> for RedelegateKeys {
@@ -2635,6 +4191,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl RedelegateKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REDELEGATE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REDELEGATE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[REDELEGATE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for RedelegateKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RedelegateAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN] = accounts
+            .get(..REDELEGATE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[REDELEGATE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for RedelegateAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub fn redelegate_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RedelegateAccounts<'_, '_>,
@@ -2705,3 +4299,241 @@ pub fn redelegate_verify_account_privileges<'me, 'info>(
     redelegate_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`redelegate_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RedelegateIxBuilder {
+    stake: Option<Pubkey>,
+    uninitialized_stake: Option<Pubkey>,
+    vote: Option<Pubkey>,
+    stake_config: Option<Pubkey>,
+    stake_authority: Option<Pubkey>,
+}
+impl Default for RedelegateIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RedelegateIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            stake: None,
+            uninitialized_stake: None,
+            vote: None,
+            stake_config: None,
+            stake_authority: None,
+        }
+    }
+    pub fn stake(mut self, stake: Pubkey) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+    pub fn uninitialized_stake(mut self, uninitialized_stake: Pubkey) -> Self {
+        self.uninitialized_stake = Some(uninitialized_stake);
+        self
+    }
+    pub fn vote(mut self, vote: Pubkey) -> Self {
+        self.vote = Some(vote);
+        self
+    }
+    pub fn stake_config(mut self, stake_config: Pubkey) -> Self {
+        self.stake_config = Some(stake_config);
+        self
+    }
+    pub fn stake_authority(mut self, stake_authority: Pubkey) -> Self {
+        self.stake_authority = Some(stake_authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RedelegateIxBuilder is missing {}", field),
+            )
+        };
+        let stake = self.stake.ok_or_else(|| missing("stake"))?;
+        let uninitialized_stake = self
+            .uninitialized_stake
+            .ok_or_else(|| missing("uninitialized_stake"))?;
+        let vote = self.vote.ok_or_else(|| missing("vote"))?;
+        let stake_config = self.stake_config.ok_or_else(|| missing("stake_config"))?;
+        let stake_authority = self
+            .stake_authority
+            .ok_or_else(|| missing("stake_authority"))?;
+        let keys = RedelegateKeys {
+            stake,
+            uninitialized_stake,
+            vote,
+            stake_config,
+            stake_authority,
+        };
+        Ok(redelegate_ix(keys))
+    }
+}
+/// A [`StakeProgramProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum StakeProgramParsedIx {
+    Initialize {
+        keys: InitializeKeys,
+        args: InitializeIxArgs,
+    },
+    Authorize {
+        keys: AuthorizeKeys,
+        args: AuthorizeIxArgs,
+    },
+    DelegateStake {
+        keys: DelegateStakeKeys,
+    },
+    Split {
+        keys: SplitKeys,
+        args: SplitIxArgs,
+    },
+    Withdraw {
+        keys: WithdrawKeys,
+        args: WithdrawIxArgs,
+    },
+    Deactivate {
+        keys: DeactivateKeys,
+    },
+    SetLockup {
+        keys: SetLockupKeys,
+        args: SetLockupIxArgs,
+    },
+    Merge {
+        keys: MergeKeys,
+    },
+    AuthorizeWithSeed {
+        keys: AuthorizeWithSeedKeys,
+        args: AuthorizeWithSeedIxArgs,
+    },
+    InitializeChecked {
+        keys: InitializeCheckedKeys,
+    },
+    AuthorizeChecked {
+        keys: AuthorizeCheckedKeys,
+        args: AuthorizeCheckedIxArgs,
+    },
+    AuthorizeCheckedWithSeed {
+        keys: AuthorizeCheckedWithSeedKeys,
+        args: AuthorizeCheckedWithSeedIxArgs,
+    },
+    SetLockupChecked {
+        keys: SetLockupCheckedKeys,
+        args: SetLockupCheckedIxArgs,
+    },
+    GetMinimumDelegation,
+    DeactivateDelinquent {
+        keys: DeactivateDelinquentKeys,
+    },
+    Redelegate {
+        keys: RedelegateKeys,
+    },
+}
+impl StakeProgramParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix =
+            solana_program::program_utils::limited_deserialize::<StakeProgramProgramIx>(
+                data,
+                data.len() as u64,
+            )
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        Ok(match program_ix {
+            StakeProgramProgramIx::Initialize(args) => Self::Initialize {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            StakeProgramProgramIx::Authorize(args) => Self::Authorize {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            StakeProgramProgramIx::DelegateStake => Self::DelegateStake {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            StakeProgramProgramIx::Split(args) => Self::Split {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            StakeProgramProgramIx::Withdraw(args) => Self::Withdraw {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            StakeProgramProgramIx::Deactivate => Self::Deactivate {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            StakeProgramProgramIx::SetLockup(args) => Self::SetLockup {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            StakeProgramProgramIx::Merge => Self::Merge {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            StakeProgramProgramIx::AuthorizeWithSeed(args) => Self::AuthorizeWithSeed {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            StakeProgramProgramIx::InitializeChecked => Self::InitializeChecked {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            StakeProgramProgramIx::AuthorizeChecked(args) => Self::AuthorizeChecked {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            StakeProgramProgramIx::AuthorizeCheckedWithSeed(args) => {
+                Self::AuthorizeCheckedWithSeed {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            StakeProgramProgramIx::SetLockupChecked(args) => Self::SetLockupChecked {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            StakeProgramProgramIx::GetMinimumDelegation => Self::GetMinimumDelegation,
+            StakeProgramProgramIx::DeactivateDelinquent => Self::DeactivateDelinquent {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            StakeProgramProgramIx::Redelegate => Self::Redelegate {
+                keys: parsed_ix_keys(accounts)?,
+            },
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
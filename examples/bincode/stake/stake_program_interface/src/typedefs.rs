@@ -5,14 +5,30 @@ pub struct Authorized {
     pub staker: Pubkey,
     pub withdrawer: Pubkey,
 }
+/// Serialized size of [`Authorized`]
+pub const AUTHORIZED_LEN: usize = 64;
+/// Offset of `staker` in serialized [`Authorized`]
+pub const AUTHORIZED_STAKER_OFFSET: usize = 0;
+/// Offset of `withdrawer` in serialized [`Authorized`]
+pub const AUTHORIZED_WITHDRAWER_OFFSET: usize = 32;
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct Lockup {
     pub unix_timestamp: i64,
     pub epoch: u64,
     pub custodian: Pubkey,
 }
+/// Serialized size of [`Lockup`]
+pub const LOCKUP_LEN: usize = 48;
+/// Offset of `unix_timestamp` in serialized [`Lockup`]
+pub const LOCKUP_UNIX_TIMESTAMP_OFFSET: usize = 0;
+/// Offset of `epoch` in serialized [`Lockup`]
+pub const LOCKUP_EPOCH_OFFSET: usize = 8;
+/// Offset of `custodian` in serialized [`Lockup`]
+pub const LOCKUP_CUSTODIAN_OFFSET: usize = 16;
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub enum StakeAuthorize {
     Staker,
     Withdrawer,
 }
+/// Serialized size of [`StakeAuthorize`]
+pub const STAKE_AUTHORIZE_LEN: usize = 4;
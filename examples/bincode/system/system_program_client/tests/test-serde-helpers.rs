@@ -0,0 +1,100 @@
+// only the codecs example is generated with `--serde-bytes-encoding hex`
+use codecs_test_interface::serde_helpers as hex_helpers;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_program::pubkey::Pubkey;
+use system_program_interface::serde_helpers::{As, Base58, Base64, DisplayFromStr};
+
+/// Fields with the adapters of the generated `serde_helpers` modules
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct WithAdapters {
+    #[serde(with = "As::<Base58>")]
+    pubkey: Pubkey,
+    #[serde(with = "As::<DisplayFromStr>")]
+    int: u64,
+    #[serde(with = "As::<Option<DisplayFromStr>>")]
+    big_int: Option<i128>,
+    #[serde(with = "hex_helpers::As::<hex_helpers::Hex>")]
+    hex: [u8; 4],
+    #[serde(with = "As::<Base64>")]
+    base64: Vec<u8>,
+}
+
+/// The same fields with their own serde impls
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Plain {
+    pubkey: Pubkey,
+    int: u64,
+    big_int: Option<i128>,
+    hex: [u8; 4],
+    base64: Vec<u8>,
+}
+
+fn sample() -> (WithAdapters, Plain) {
+    let pubkey = Pubkey::new_unique();
+    let big_int = Some(i128::MIN);
+    (
+        WithAdapters {
+            pubkey,
+            int: u64::MAX,
+            big_int,
+            hex: [0xde, 0xad, 0xbe, 0xef],
+            base64: b"hello".to_vec(),
+        },
+        Plain {
+            pubkey,
+            int: u64::MAX,
+            big_int,
+            hex: [0xde, 0xad, 0xbe, 0xef],
+            base64: b"hello".to_vec(),
+        },
+    )
+}
+
+#[test]
+fn test_adapters_json_strings_roundtrip() {
+    let (with_adapters, _) = sample();
+    let value = serde_json::to_value(&with_adapters).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "pubkey": with_adapters.pubkey.to_string(),
+            "int": u64::MAX.to_string(),
+            "big_int": i128::MIN.to_string(),
+            "hex": "deadbeef",
+            "base64": "aGVsbG8=",
+        })
+    );
+    assert_eq!(
+        serde_json::from_value::<WithAdapters>(value).unwrap(),
+        with_adapters
+    );
+}
+
+#[test]
+fn test_adapters_bincode_unchanged() {
+    let (with_adapters, plain) = sample();
+    let serialized = bincode::serialize(&with_adapters).unwrap();
+    assert_eq!(serialized, bincode::serialize(&plain).unwrap());
+    assert_eq!(
+        bincode::deserialize::<WithAdapters>(&serialized).unwrap(),
+        with_adapters
+    );
+}
+
+#[test]
+fn test_adapters_invalid_json_err() {
+    let (with_adapters, _) = sample();
+    let mut value = serde_json::to_value(with_adapters).unwrap();
+    for (field, invalid) in [
+        ("pubkey", "not a pubkey"),
+        ("int", "-1"),
+        ("hex", "deadbee"),
+        ("base64", "not base64!"),
+    ] {
+        let valid = value[field].clone();
+        value[field] = json!(invalid);
+        assert!(serde_json::from_value::<WithAdapters>(value.clone()).is_err());
+        value[field] = valid;
+    }
+}
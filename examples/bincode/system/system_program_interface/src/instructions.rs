@@ -8,6 +8,7 @@ use solana_program::{
     pubkey::Pubkey,
 };
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum SystemProgramProgramIx {
     CreateAccount(CreateAccountIxArgs),
     Assign(AssignIxArgs),
@@ -57,16 +58,16 @@ pub fn create_account_ix(keys: CreateAccountKeys, args: CreateAccountIxArgs) ->
 pub const CREATE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct CreateAccountAccounts<'me, 'info> {
-    ///Funding account
+    /// Funding account
     pub from: &'me AccountInfo<'info>,
-    ///The new account to be created
+    /// The new account to be created
     pub to: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CreateAccountKeys {
-    ///Funding account
+    /// Funding account
     pub from: Pubkey,
-    ///The new account to be created
+    /// The new account to be created
     pub to: Pubkey,
 }
 impl From<CreateAccountAccounts<'_, '_>> for CreateAccountKeys {
@@ -118,10 +119,61 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_ACCOUNT_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl CreateAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CREATE_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CREATE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CREATE_ACCOUNT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for CreateAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CreateAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CREATE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..CREATE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CREATE_ACCOUNT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CreateAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateAccountIxArgs {
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub lamports: u64,
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub space: u64,
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub owner: Pubkey,
 }
 pub fn create_account_invoke_with_program_id(
@@ -194,6 +246,70 @@ pub fn create_account_verify_account_privileges<'me, 'info>(
     create_account_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`create_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CreateAccountIxBuilder {
+    from: Option<Pubkey>,
+    to: Option<Pubkey>,
+    lamports: Option<u64>,
+    space: Option<u64>,
+    owner: Option<Pubkey>,
+}
+impl Default for CreateAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CreateAccountIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            from: None,
+            to: None,
+            lamports: None,
+            space: None,
+            owner: None,
+        }
+    }
+    pub fn from(mut self, from: Pubkey) -> Self {
+        self.from = Some(from);
+        self
+    }
+    pub fn to(mut self, to: Pubkey) -> Self {
+        self.to = Some(to);
+        self
+    }
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = Some(lamports);
+        self
+    }
+    pub fn space(mut self, space: u64) -> Self {
+        self.space = Some(space);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CreateAccountIxBuilder is missing {}", field),
+            )
+        };
+        let from = self.from.ok_or_else(|| missing("from"))?;
+        let to = self.to.ok_or_else(|| missing("to"))?;
+        let args = CreateAccountIxArgs {
+            lamports: self.lamports.ok_or_else(|| missing("lamports"))?,
+            space: self.space.ok_or_else(|| missing("space"))?,
+            owner: self.owner.ok_or_else(|| missing("owner"))?,
+        };
+        let keys = CreateAccountKeys { from, to };
+        Ok(create_account_ix(keys, args))
+    }
+}
 pub const ASSIGN_IX_DISCM: [u8; 4] = [1, 0, 0, 0];
 pub fn assign_ix_with_program_id(
     program_id: Pubkey,
@@ -213,12 +329,12 @@ pub fn assign_ix(keys: AssignKeys, args: AssignIxArgs) -> Instruction {
 pub const ASSIGN_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
 pub struct AssignAccounts<'me, 'info> {
-    ///The system account to assign a new program owner to
+    /// The system account to assign a new program owner to
     pub assign: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AssignKeys {
-    ///The system account to assign a new program owner to
+    /// The system account to assign a new program owner to
     pub assign: Pubkey,
 }
 impl From<AssignAccounts<'_, '_>> for AssignKeys {
@@ -254,8 +370,49 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ASSIGN_IX_ACCOUNTS_LEN]>
         Self { assign: &arr[0] }
     }
 }
+impl AssignKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ASSIGN_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ASSIGN_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[ASSIGN_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AssignKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AssignAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ASSIGN_IX_ACCOUNTS_LEN] = accounts
+            .get(..ASSIGN_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[ASSIGN_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AssignAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AssignIxArgs {
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub owner: Pubkey,
 }
 pub fn assign_invoke_with_program_id(
@@ -325,6 +482,49 @@ pub fn assign_verify_account_privileges<'me, 'info>(
     assign_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`assign_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AssignIxBuilder {
+    assign: Option<Pubkey>,
+    owner: Option<Pubkey>,
+}
+impl Default for AssignIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AssignIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            assign: None,
+            owner: None,
+        }
+    }
+    pub fn assign(mut self, assign: Pubkey) -> Self {
+        self.assign = Some(assign);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AssignIxBuilder is missing {}", field),
+            )
+        };
+        let assign = self.assign.ok_or_else(|| missing("assign"))?;
+        let args = AssignIxArgs {
+            owner: self.owner.ok_or_else(|| missing("owner"))?,
+        };
+        let keys = AssignKeys { assign };
+        Ok(assign_ix(keys, args))
+    }
+}
 pub const TRANSFER_IX_DISCM: [u8; 4] = [2, 0, 0, 0];
 pub fn transfer_ix_with_program_id(
     program_id: Pubkey,
@@ -344,16 +544,16 @@ pub fn transfer_ix(keys: TransferKeys, args: TransferIxArgs) -> Instruction {
 pub const TRANSFER_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct TransferAccounts<'me, 'info> {
-    ///Funding account
+    /// Funding account
     pub from: &'me AccountInfo<'info>,
-    ///Recipient account
+    /// Recipient account
     pub to: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct TransferKeys {
-    ///Funding account
+    /// Funding account
     pub from: Pubkey,
-    ///Recipient account
+    /// Recipient account
     pub to: Pubkey,
 }
 impl From<TransferAccounts<'_, '_>> for TransferKeys {
@@ -403,8 +603,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; TRANSFER_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl TransferKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; TRANSFER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..TRANSFER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[TRANSFER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for TransferKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> TransferAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; TRANSFER_IX_ACCOUNTS_LEN] = accounts
+            .get(..TRANSFER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[TRANSFER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for TransferAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TransferIxArgs {
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub lamports: u64,
 }
 pub fn transfer_invoke_with_program_id(
@@ -474,6 +718,56 @@ pub fn transfer_verify_account_privileges<'me, 'info>(
     transfer_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`transfer_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct TransferIxBuilder {
+    from: Option<Pubkey>,
+    to: Option<Pubkey>,
+    lamports: Option<u64>,
+}
+impl Default for TransferIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl TransferIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            from: None,
+            to: None,
+            lamports: None,
+        }
+    }
+    pub fn from(mut self, from: Pubkey) -> Self {
+        self.from = Some(from);
+        self
+    }
+    pub fn to(mut self, to: Pubkey) -> Self {
+        self.to = Some(to);
+        self
+    }
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = Some(lamports);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("TransferIxBuilder is missing {}", field),
+            )
+        };
+        let from = self.from.ok_or_else(|| missing("from"))?;
+        let to = self.to.ok_or_else(|| missing("to"))?;
+        let args = TransferIxArgs {
+            lamports: self.lamports.ok_or_else(|| missing("lamports"))?,
+        };
+        let keys = TransferKeys { from, to };
+        Ok(transfer_ix(keys, args))
+    }
+}
 pub const CREATE_ACCOUNT_WITH_SEED_IX_DISCM: [u8; 4] = [3, 0, 0, 0];
 pub fn create_account_with_seed_ix_with_program_id(
     program_id: Pubkey,
@@ -496,20 +790,20 @@ pub fn create_account_with_seed_ix(
 pub const CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct CreateAccountWithSeedAccounts<'me, 'info> {
-    ///Funding account
+    /// Funding account
     pub from: &'me AccountInfo<'info>,
-    ///The new account to be created
+    /// The new account to be created
     pub to: &'me AccountInfo<'info>,
-    ///Base account. Optional. The account matching the base Pubkey below must be provided as a signer, but may be the same as from
+    /// Base account. Optional. The account matching the base Pubkey below must be provided as a signer, but may be the same as from
     pub base: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CreateAccountWithSeedKeys {
-    ///Funding account
+    /// Funding account
     pub from: Pubkey,
-    ///The new account to be created
+    /// The new account to be created
     pub to: Pubkey,
-    ///Base account. Optional. The account matching the base Pubkey below must be provided as a signer, but may be the same as from
+    /// Base account. Optional. The account matching the base Pubkey below must be provided as a signer, but may be the same as from
     pub base: Pubkey,
 }
 impl From<CreateAccountWithSeedAccounts<'_, '_>> for CreateAccountWithSeedKeys {
@@ -573,12 +867,71 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_ACCOUNT_WITH_SEED_IX_ACCO
         }
     }
 }
+impl CreateAccountWithSeedKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for CreateAccountWithSeedKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CreateAccountWithSeedAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN] = accounts
+            .get(..CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CreateAccountWithSeedAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateAccountWithSeedIxArgs {
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub base: Pubkey,
     pub seed: String,
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub lamports: u64,
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub space: u64,
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub owner: Pubkey,
 }
 pub fn create_account_with_seed_invoke_with_program_id(
@@ -655,6 +1008,91 @@ pub fn create_account_with_seed_verify_account_privileges<'me, 'info>(
     create_account_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`create_account_with_seed_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CreateAccountWithSeedIxBuilder {
+    from: Option<Pubkey>,
+    to: Option<Pubkey>,
+    base: Option<Pubkey>,
+    base_arg: Option<Pubkey>,
+    seed: Option<String>,
+    lamports: Option<u64>,
+    space: Option<u64>,
+    owner: Option<Pubkey>,
+}
+impl Default for CreateAccountWithSeedIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CreateAccountWithSeedIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            from: None,
+            to: None,
+            base: None,
+            base_arg: None,
+            seed: None,
+            lamports: None,
+            space: None,
+            owner: None,
+        }
+    }
+    pub fn from(mut self, from: Pubkey) -> Self {
+        self.from = Some(from);
+        self
+    }
+    pub fn to(mut self, to: Pubkey) -> Self {
+        self.to = Some(to);
+        self
+    }
+    pub fn base(mut self, base: Pubkey) -> Self {
+        self.base = Some(base);
+        self
+    }
+    pub fn base_arg(mut self, base_arg: Pubkey) -> Self {
+        self.base_arg = Some(base_arg);
+        self
+    }
+    pub fn seed(mut self, seed: String) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = Some(lamports);
+        self
+    }
+    pub fn space(mut self, space: u64) -> Self {
+        self.space = Some(space);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CreateAccountWithSeedIxBuilder is missing {}", field),
+            )
+        };
+        let from = self.from.ok_or_else(|| missing("from"))?;
+        let to = self.to.ok_or_else(|| missing("to"))?;
+        let base = self.base.ok_or_else(|| missing("base"))?;
+        let args = CreateAccountWithSeedIxArgs {
+            base: self.base_arg.ok_or_else(|| missing("base_arg"))?,
+            seed: self.seed.ok_or_else(|| missing("seed"))?,
+            lamports: self.lamports.ok_or_else(|| missing("lamports"))?,
+            space: self.space.ok_or_else(|| missing("space"))?,
+            owner: self.owner.ok_or_else(|| missing("owner"))?,
+        };
+        let keys = CreateAccountWithSeedKeys { from, to, base };
+        Ok(create_account_with_seed_ix(keys, args))
+    }
+}
 pub const ADVANCE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [4, 0, 0, 0];
 pub fn advance_nonce_account_ix_with_program_id(
     program_id: Pubkey,
@@ -673,20 +1111,20 @@ pub fn advance_nonce_account_ix(keys: AdvanceNonceAccountKeys) -> Instruction {
 pub const ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct AdvanceNonceAccountAccounts<'me, 'info> {
-    ///Nonce account
+    /// Nonce account
     pub nonce: &'me AccountInfo<'info>,
-    ///RecentBlockhashes sysvar
+    /// RecentBlockhashes sysvar
     pub recent_blockhashes: &'me AccountInfo<'info>,
-    ///nonce's authority
+    /// nonce's authority
     pub authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AdvanceNonceAccountKeys {
-    ///Nonce account
+    /// Nonce account
     pub nonce: Pubkey,
-    ///RecentBlockhashes sysvar
+    /// RecentBlockhashes sysvar
     pub recent_blockhashes: Pubkey,
-    ///nonce's authority
+    /// nonce's authority
     pub authority: Pubkey,
 }
 impl From<AdvanceNonceAccountAccounts<'_, '_>> for AdvanceNonceAccountKeys {
@@ -750,6 +1188,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNT
         }
     }
 }
+impl AdvanceNonceAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for AdvanceNonceAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AdvanceNonceAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AdvanceNonceAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub fn advance_nonce_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AdvanceNonceAccountAccounts<'_, '_>,
@@ -820,6 +1302,60 @@ pub fn advance_nonce_account_verify_account_privileges<'me, 'info>(
     advance_nonce_account_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`advance_nonce_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AdvanceNonceAccountIxBuilder {
+    nonce: Option<Pubkey>,
+    recent_blockhashes: Option<Pubkey>,
+    authority: Option<Pubkey>,
+}
+impl Default for AdvanceNonceAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AdvanceNonceAccountIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            nonce: None,
+            recent_blockhashes: None,
+            authority: None,
+        }
+    }
+    pub fn nonce(mut self, nonce: Pubkey) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+    pub fn recent_blockhashes(mut self, recent_blockhashes: Pubkey) -> Self {
+        self.recent_blockhashes = Some(recent_blockhashes);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AdvanceNonceAccountIxBuilder is missing {}", field),
+            )
+        };
+        let nonce = self.nonce.ok_or_else(|| missing("nonce"))?;
+        let recent_blockhashes = self
+            .recent_blockhashes
+            .ok_or_else(|| missing("recent_blockhashes"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let keys = AdvanceNonceAccountKeys {
+            nonce,
+            recent_blockhashes,
+            authority,
+        };
+        Ok(advance_nonce_account_ix(keys))
+    }
+}
 pub const WITHDRAW_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [5, 0, 0, 0];
 pub fn withdraw_nonce_account_ix_with_program_id(
     program_id: Pubkey,
@@ -842,28 +1378,28 @@ pub fn withdraw_nonce_account_ix(
 pub const WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct WithdrawNonceAccountAccounts<'me, 'info> {
-    ///Nonce account
+    /// Nonce account
     pub nonce: &'me AccountInfo<'info>,
-    ///Recipient account
+    /// Recipient account
     pub to: &'me AccountInfo<'info>,
-    ///RecentBlockhashes sysvar
+    /// RecentBlockhashes sysvar
     pub recent_blockhashes: &'me AccountInfo<'info>,
-    ///Rent sysvar
+    /// Rent sysvar
     pub rent: &'me AccountInfo<'info>,
-    ///nonce's authority
+    /// nonce's authority
     pub authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct WithdrawNonceAccountKeys {
-    ///Nonce account
+    /// Nonce account
     pub nonce: Pubkey,
-    ///Recipient account
+    /// Recipient account
     pub to: Pubkey,
-    ///RecentBlockhashes sysvar
+    /// RecentBlockhashes sysvar
     pub recent_blockhashes: Pubkey,
-    ///Rent sysvar
+    /// Rent sysvar
     pub rent: Pubkey,
-    ///nonce's authority
+    /// nonce's authority
     pub authority: Pubkey,
 }
 impl From<WithdrawNonceAccountAccounts<'_, '_>> for WithdrawNonceAccountKeys {
@@ -945,8 +1481,58 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUN
         }
     }
 }
+impl WithdrawNonceAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for WithdrawNonceAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> WithdrawNonceAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for WithdrawNonceAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct WithdrawNonceAccountIxArgs {
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub lamports: u64,
 }
 pub fn withdraw_nonce_account_invoke_with_program_id(
@@ -1025,6 +1611,87 @@ pub fn withdraw_nonce_account_verify_account_privileges<'me, 'info>(
     withdraw_nonce_account_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`withdraw_nonce_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct WithdrawNonceAccountIxBuilder {
+    nonce: Option<Pubkey>,
+    to: Option<Pubkey>,
+    recent_blockhashes: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    lamports: Option<u64>,
+}
+impl Default for WithdrawNonceAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl WithdrawNonceAccountIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            nonce: None,
+            to: None,
+            recent_blockhashes: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            authority: None,
+            lamports: None,
+        }
+    }
+    pub fn nonce(mut self, nonce: Pubkey) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+    pub fn to(mut self, to: Pubkey) -> Self {
+        self.to = Some(to);
+        self
+    }
+    pub fn recent_blockhashes(mut self, recent_blockhashes: Pubkey) -> Self {
+        self.recent_blockhashes = Some(recent_blockhashes);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = Some(lamports);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("WithdrawNonceAccountIxBuilder is missing {}", field),
+            )
+        };
+        let nonce = self.nonce.ok_or_else(|| missing("nonce"))?;
+        let to = self.to.ok_or_else(|| missing("to"))?;
+        let recent_blockhashes = self
+            .recent_blockhashes
+            .ok_or_else(|| missing("recent_blockhashes"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = WithdrawNonceAccountIxArgs {
+            lamports: self.lamports.ok_or_else(|| missing("lamports"))?,
+        };
+        let keys = WithdrawNonceAccountKeys {
+            nonce,
+            to,
+            recent_blockhashes,
+            rent,
+            authority,
+        };
+        Ok(withdraw_nonce_account_ix(keys, args))
+    }
+}
 pub const INITIALIZE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [6, 0, 0, 0];
 pub fn initialize_nonce_account_ix_with_program_id(
     program_id: Pubkey,
@@ -1047,20 +1714,20 @@ pub fn initialize_nonce_account_ix(
 pub const INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct InitializeNonceAccountAccounts<'me, 'info> {
-    ///Nonce account
+    /// Nonce account
     pub nonce: &'me AccountInfo<'info>,
-    ///RecentBlockhashes sysvar
+    /// RecentBlockhashes sysvar
     pub recent_blockhashes: &'me AccountInfo<'info>,
-    ///Rent sysvar
+    /// Rent sysvar
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct InitializeNonceAccountKeys {
-    ///Nonce account
+    /// Nonce account
     pub nonce: Pubkey,
-    ///RecentBlockhashes sysvar
+    /// RecentBlockhashes sysvar
     pub recent_blockhashes: Pubkey,
-    ///Rent sysvar
+    /// Rent sysvar
     pub rent: Pubkey,
 }
 impl From<InitializeNonceAccountAccounts<'_, '_>> for InitializeNonceAccountKeys {
@@ -1124,8 +1791,55 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_NONCE_ACCOUNT_IX_ACCO
         }
     }
 }
+impl InitializeNonceAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeNonceAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeNonceAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeNonceAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct InitializeNonceAccountIxArgs {
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub authority: Pubkey,
 }
 pub fn initialize_nonce_account_invoke_with_program_id(
@@ -1191,6 +1905,71 @@ pub fn initialize_nonce_account_verify_account_privileges<'me, 'info>(
     initialize_nonce_account_verify_writable_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`initialize_nonce_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeNonceAccountIxBuilder {
+    nonce: Option<Pubkey>,
+    recent_blockhashes: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    authority: Option<Pubkey>,
+}
+impl Default for InitializeNonceAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeNonceAccountIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            nonce: None,
+            recent_blockhashes: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            authority: None,
+        }
+    }
+    pub fn nonce(mut self, nonce: Pubkey) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+    pub fn recent_blockhashes(mut self, recent_blockhashes: Pubkey) -> Self {
+        self.recent_blockhashes = Some(recent_blockhashes);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeNonceAccountIxBuilder is missing {}", field),
+            )
+        };
+        let nonce = self.nonce.ok_or_else(|| missing("nonce"))?;
+        let recent_blockhashes = self
+            .recent_blockhashes
+            .ok_or_else(|| missing("recent_blockhashes"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = InitializeNonceAccountIxArgs {
+            authority: self.authority.ok_or_else(|| missing("authority"))?,
+        };
+        let keys = InitializeNonceAccountKeys {
+            nonce,
+            recent_blockhashes,
+            rent,
+        };
+        Ok(initialize_nonce_account_ix(keys, args))
+    }
+}
 pub const AUTHORIZE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [7, 0, 0, 0];
 pub fn authorize_nonce_account_ix_with_program_id(
     program_id: Pubkey,
@@ -1213,16 +1992,16 @@ pub fn authorize_nonce_account_ix(
 pub const AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct AuthorizeNonceAccountAccounts<'me, 'info> {
-    ///Nonce account
+    /// Nonce account
     pub nonce: &'me AccountInfo<'info>,
-    ///nonce's authority
+    /// nonce's authority
     pub authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AuthorizeNonceAccountKeys {
-    ///Nonce account
+    /// Nonce account
     pub nonce: Pubkey,
-    ///nonce's authority
+    /// nonce's authority
     pub authority: Pubkey,
 }
 impl From<AuthorizeNonceAccountAccounts<'_, '_>> for AuthorizeNonceAccountKeys {
@@ -1274,8 +2053,55 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOU
         }
     }
 }
+impl AuthorizeNonceAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for AuthorizeNonceAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AuthorizeNonceAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AuthorizeNonceAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AuthorizeNonceAccountIxArgs {
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub new_authority: Pubkey,
 }
 pub fn authorize_nonce_account_invoke_with_program_id(
@@ -1351,6 +2177,56 @@ pub fn authorize_nonce_account_verify_account_privileges<'me, 'info>(
     authorize_nonce_account_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`authorize_nonce_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AuthorizeNonceAccountIxBuilder {
+    nonce: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    new_authority: Option<Pubkey>,
+}
+impl Default for AuthorizeNonceAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AuthorizeNonceAccountIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            nonce: None,
+            authority: None,
+            new_authority: None,
+        }
+    }
+    pub fn nonce(mut self, nonce: Pubkey) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn new_authority(mut self, new_authority: Pubkey) -> Self {
+        self.new_authority = Some(new_authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AuthorizeNonceAccountIxBuilder is missing {}", field),
+            )
+        };
+        let nonce = self.nonce.ok_or_else(|| missing("nonce"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = AuthorizeNonceAccountIxArgs {
+            new_authority: self.new_authority.ok_or_else(|| missing("new_authority"))?,
+        };
+        let keys = AuthorizeNonceAccountKeys { nonce, authority };
+        Ok(authorize_nonce_account_ix(keys, args))
+    }
+}
 pub const ALLOCATE_IX_DISCM: [u8; 4] = [8, 0, 0, 0];
 pub fn allocate_ix_with_program_id(
     program_id: Pubkey,
@@ -1370,12 +2246,12 @@ pub fn allocate_ix(keys: AllocateKeys, args: AllocateIxArgs) -> Instruction {
 pub const ALLOCATE_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
 pub struct AllocateAccounts<'me, 'info> {
-    ///The new account to allocate space for
+    /// The new account to allocate space for
     pub allocate: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AllocateKeys {
-    ///The new account to allocate space for
+    /// The new account to allocate space for
     pub allocate: Pubkey,
 }
 impl From<AllocateAccounts<'_, '_>> for AllocateKeys {
@@ -1413,8 +2289,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ALLOCATE_IX_ACCOUNTS_LEN]>
         Self { allocate: &arr[0] }
     }
 }
+impl AllocateKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ALLOCATE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ALLOCATE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[ALLOCATE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AllocateKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AllocateAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ALLOCATE_IX_ACCOUNTS_LEN] = accounts
+            .get(..ALLOCATE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[ALLOCATE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AllocateAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AllocateIxArgs {
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub space: u64,
 }
 pub fn allocate_invoke_with_program_id(
@@ -1484,6 +2404,49 @@ pub fn allocate_verify_account_privileges<'me, 'info>(
     allocate_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`allocate_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AllocateIxBuilder {
+    allocate: Option<Pubkey>,
+    space: Option<u64>,
+}
+impl Default for AllocateIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AllocateIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            allocate: None,
+            space: None,
+        }
+    }
+    pub fn allocate(mut self, allocate: Pubkey) -> Self {
+        self.allocate = Some(allocate);
+        self
+    }
+    pub fn space(mut self, space: u64) -> Self {
+        self.space = Some(space);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AllocateIxBuilder is missing {}", field),
+            )
+        };
+        let allocate = self.allocate.ok_or_else(|| missing("allocate"))?;
+        let args = AllocateIxArgs {
+            space: self.space.ok_or_else(|| missing("space"))?,
+        };
+        let keys = AllocateKeys { allocate };
+        Ok(allocate_ix(keys, args))
+    }
+}
 pub const ALLOCATE_WITH_SEED_IX_DISCM: [u8; 4] = [9, 0, 0, 0];
 pub fn allocate_with_seed_ix_with_program_id(
     program_id: Pubkey,
@@ -1506,16 +2469,16 @@ pub fn allocate_with_seed_ix(
 pub const ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct AllocateWithSeedAccounts<'me, 'info> {
-    ///The new account to allocate space for
+    /// The new account to allocate space for
     pub allocate: &'me AccountInfo<'info>,
-    ///Base account
+    /// Base account
     pub base: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AllocateWithSeedKeys {
-    ///The new account to allocate space for
+    /// The new account to allocate space for
     pub allocate: Pubkey,
-    ///Base account
+    /// Base account
     pub base: Pubkey,
 }
 impl From<AllocateWithSeedAccounts<'_, '_>> for AllocateWithSeedKeys {
@@ -1567,11 +2530,59 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ALLOCATE_WITH_SEED_IX_ACCOUNTS_L
         }
     }
 }
+impl AllocateWithSeedKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AllocateWithSeedKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AllocateWithSeedAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN] = accounts
+            .get(..ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AllocateWithSeedAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AllocateWithSeedIxArgs {
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub base: Pubkey,
     pub seed: String,
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub space: u64,
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub owner: Pubkey,
 }
 pub fn allocate_with_seed_invoke_with_program_id(
@@ -1647,6 +2658,77 @@ pub fn allocate_with_seed_verify_account_privileges<'me, 'info>(
     allocate_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`allocate_with_seed_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AllocateWithSeedIxBuilder {
+    allocate: Option<Pubkey>,
+    base: Option<Pubkey>,
+    base_arg: Option<Pubkey>,
+    seed: Option<String>,
+    space: Option<u64>,
+    owner: Option<Pubkey>,
+}
+impl Default for AllocateWithSeedIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AllocateWithSeedIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            allocate: None,
+            base: None,
+            base_arg: None,
+            seed: None,
+            space: None,
+            owner: None,
+        }
+    }
+    pub fn allocate(mut self, allocate: Pubkey) -> Self {
+        self.allocate = Some(allocate);
+        self
+    }
+    pub fn base(mut self, base: Pubkey) -> Self {
+        self.base = Some(base);
+        self
+    }
+    pub fn base_arg(mut self, base_arg: Pubkey) -> Self {
+        self.base_arg = Some(base_arg);
+        self
+    }
+    pub fn seed(mut self, seed: String) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+    pub fn space(mut self, space: u64) -> Self {
+        self.space = Some(space);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AllocateWithSeedIxBuilder is missing {}", field),
+            )
+        };
+        let allocate = self.allocate.ok_or_else(|| missing("allocate"))?;
+        let base = self.base.ok_or_else(|| missing("base"))?;
+        let args = AllocateWithSeedIxArgs {
+            base: self.base_arg.ok_or_else(|| missing("base_arg"))?,
+            seed: self.seed.ok_or_else(|| missing("seed"))?,
+            space: self.space.ok_or_else(|| missing("space"))?,
+            owner: self.owner.ok_or_else(|| missing("owner"))?,
+        };
+        let keys = AllocateWithSeedKeys { allocate, base };
+        Ok(allocate_with_seed_ix(keys, args))
+    }
+}
 pub const ASSIGN_WITH_SEED_IX_DISCM: [u8; 4] = [10, 0, 0, 0];
 pub fn assign_with_seed_ix_with_program_id(
     program_id: Pubkey,
@@ -1666,16 +2748,16 @@ pub fn assign_with_seed_ix(keys: AssignWithSeedKeys, args: AssignWithSeedIxArgs)
 pub const ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct AssignWithSeedAccounts<'me, 'info> {
-    ///The system account to assign a new program owner to
+    /// The system account to assign a new program owner to
     pub assign: &'me AccountInfo<'info>,
-    ///Base account
+    /// Base account
     pub base: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct AssignWithSeedKeys {
-    ///The system account to assign a new program owner to
+    /// The system account to assign a new program owner to
     pub assign: Pubkey,
-    ///Base account
+    /// Base account
     pub base: Pubkey,
 }
 impl From<AssignWithSeedAccounts<'_, '_>> for AssignWithSeedKeys {
@@ -1727,10 +2809,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN
         }
     }
 }
+impl AssignWithSeedKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for AssignWithSeedKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> AssignWithSeedAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN] = accounts
+            .get(..ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for AssignWithSeedAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AssignWithSeedIxArgs {
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub base: Pubkey,
     pub seed: String,
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub owner: Pubkey,
 }
 pub fn assign_with_seed_invoke_with_program_id(
@@ -1806,6 +2931,70 @@ pub fn assign_with_seed_verify_account_privileges<'me, 'info>(
     assign_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`assign_with_seed_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct AssignWithSeedIxBuilder {
+    assign: Option<Pubkey>,
+    base: Option<Pubkey>,
+    base_arg: Option<Pubkey>,
+    seed: Option<String>,
+    owner: Option<Pubkey>,
+}
+impl Default for AssignWithSeedIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl AssignWithSeedIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            assign: None,
+            base: None,
+            base_arg: None,
+            seed: None,
+            owner: None,
+        }
+    }
+    pub fn assign(mut self, assign: Pubkey) -> Self {
+        self.assign = Some(assign);
+        self
+    }
+    pub fn base(mut self, base: Pubkey) -> Self {
+        self.base = Some(base);
+        self
+    }
+    pub fn base_arg(mut self, base_arg: Pubkey) -> Self {
+        self.base_arg = Some(base_arg);
+        self
+    }
+    pub fn seed(mut self, seed: String) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("AssignWithSeedIxBuilder is missing {}", field),
+            )
+        };
+        let assign = self.assign.ok_or_else(|| missing("assign"))?;
+        let base = self.base.ok_or_else(|| missing("base"))?;
+        let args = AssignWithSeedIxArgs {
+            base: self.base_arg.ok_or_else(|| missing("base_arg"))?,
+            seed: self.seed.ok_or_else(|| missing("seed"))?,
+            owner: self.owner.ok_or_else(|| missing("owner"))?,
+        };
+        let keys = AssignWithSeedKeys { assign, base };
+        Ok(assign_with_seed_ix(keys, args))
+    }
+}
 pub const TRANSFER_WITH_SEED_IX_DISCM: [u8; 4] = [11, 0, 0, 0];
 pub fn transfer_with_seed_ix_with_program_id(
     program_id: Pubkey,
@@ -1828,20 +3017,20 @@ pub fn transfer_with_seed_ix(
 pub const TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct TransferWithSeedAccounts<'me, 'info> {
-    ///Funding account
+    /// Funding account
     pub from: &'me AccountInfo<'info>,
-    ///from's base account
+    /// from's base account
     pub base: &'me AccountInfo<'info>,
-    ///Recipient account
+    /// Recipient account
     pub to: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct TransferWithSeedKeys {
-    ///Funding account
+    /// Funding account
     pub from: Pubkey,
-    ///from's base account
+    /// from's base account
     pub base: Pubkey,
-    ///Recipient account
+    /// Recipient account
     pub to: Pubkey,
 }
 impl From<TransferWithSeedAccounts<'_, '_>> for TransferWithSeedKeys {
@@ -1905,10 +3094,56 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; TRANSFER_WITH_SEED_IX_ACCOUNTS_L
         }
     }
 }
+impl TransferWithSeedKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for TransferWithSeedKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> TransferWithSeedAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN] = accounts
+            .get(..TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for TransferWithSeedAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TransferWithSeedIxArgs {
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::DisplayFromStr>")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::serde_helpers::DisplayFromStr")
+    )]
     pub lamports: u64,
     pub from_seed: String,
+    #[serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub from_owner: Pubkey,
 }
 pub fn transfer_with_seed_invoke_with_program_id(
@@ -1985,6 +3220,77 @@ pub fn transfer_with_seed_verify_account_privileges<'me, 'info>(
     transfer_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`transfer_with_seed_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct TransferWithSeedIxBuilder {
+    from: Option<Pubkey>,
+    base: Option<Pubkey>,
+    to: Option<Pubkey>,
+    lamports: Option<u64>,
+    from_seed: Option<String>,
+    from_owner: Option<Pubkey>,
+}
+impl Default for TransferWithSeedIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl TransferWithSeedIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            from: None,
+            base: None,
+            to: None,
+            lamports: None,
+            from_seed: None,
+            from_owner: None,
+        }
+    }
+    pub fn from(mut self, from: Pubkey) -> Self {
+        self.from = Some(from);
+        self
+    }
+    pub fn base(mut self, base: Pubkey) -> Self {
+        self.base = Some(base);
+        self
+    }
+    pub fn to(mut self, to: Pubkey) -> Self {
+        self.to = Some(to);
+        self
+    }
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = Some(lamports);
+        self
+    }
+    pub fn from_seed(mut self, from_seed: String) -> Self {
+        self.from_seed = Some(from_seed);
+        self
+    }
+    pub fn from_owner(mut self, from_owner: Pubkey) -> Self {
+        self.from_owner = Some(from_owner);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("TransferWithSeedIxBuilder is missing {}", field),
+            )
+        };
+        let from = self.from.ok_or_else(|| missing("from"))?;
+        let base = self.base.ok_or_else(|| missing("base"))?;
+        let to = self.to.ok_or_else(|| missing("to"))?;
+        let args = TransferWithSeedIxArgs {
+            lamports: self.lamports.ok_or_else(|| missing("lamports"))?,
+            from_seed: self.from_seed.ok_or_else(|| missing("from_seed"))?,
+            from_owner: self.from_owner.ok_or_else(|| missing("from_owner"))?,
+        };
+        let keys = TransferWithSeedKeys { from, base, to };
+        Ok(transfer_with_seed_ix(keys, args))
+    }
+}
 pub const UPGRADE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [12, 0, 0, 0];
 pub fn upgrade_nonce_account_ix_with_program_id(
     program_id: Pubkey,
@@ -2003,12 +3309,12 @@ pub fn upgrade_nonce_account_ix(keys: UpgradeNonceAccountKeys) -> Instruction {
 pub const UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
 pub struct UpgradeNonceAccountAccounts<'me, 'info> {
-    ///Nonce account
+    /// Nonce account
     pub nonce: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct UpgradeNonceAccountKeys {
-    ///Nonce account
+    /// Nonce account
     pub nonce: Pubkey,
 }
 impl From<UpgradeNonceAccountAccounts<'_, '_>> for UpgradeNonceAccountKeys {
@@ -2046,6 +3352,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNT
         Self { nonce: &arr[0] }
     }
 }
+impl UpgradeNonceAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpgradeNonceAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpgradeNonceAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpgradeNonceAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub fn upgrade_nonce_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpgradeNonceAccountAccounts<'_, '_>,
@@ -2101,3 +3451,189 @@ pub fn upgrade_nonce_account_verify_account_privileges<'me, 'info>(
     upgrade_nonce_account_verify_writable_privileges(accounts)?;
     Ok(())
 }
+/// Builds a [`upgrade_nonce_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpgradeNonceAccountIxBuilder {
+    nonce: Option<Pubkey>,
+}
+impl Default for UpgradeNonceAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpgradeNonceAccountIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self { nonce: None }
+    }
+    pub fn nonce(mut self, nonce: Pubkey) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpgradeNonceAccountIxBuilder is missing {}", field),
+            )
+        };
+        let nonce = self.nonce.ok_or_else(|| missing("nonce"))?;
+        let keys = UpgradeNonceAccountKeys { nonce };
+        Ok(upgrade_nonce_account_ix(keys))
+    }
+}
+/// A [`SystemProgramProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SystemProgramParsedIx {
+    CreateAccount {
+        keys: CreateAccountKeys,
+        args: CreateAccountIxArgs,
+    },
+    Assign {
+        keys: AssignKeys,
+        args: AssignIxArgs,
+    },
+    Transfer {
+        keys: TransferKeys,
+        args: TransferIxArgs,
+    },
+    CreateAccountWithSeed {
+        keys: CreateAccountWithSeedKeys,
+        args: CreateAccountWithSeedIxArgs,
+    },
+    AdvanceNonceAccount {
+        keys: AdvanceNonceAccountKeys,
+    },
+    WithdrawNonceAccount {
+        keys: WithdrawNonceAccountKeys,
+        args: WithdrawNonceAccountIxArgs,
+    },
+    InitializeNonceAccount {
+        keys: InitializeNonceAccountKeys,
+        args: InitializeNonceAccountIxArgs,
+    },
+    AuthorizeNonceAccount {
+        keys: AuthorizeNonceAccountKeys,
+        args: AuthorizeNonceAccountIxArgs,
+    },
+    Allocate {
+        keys: AllocateKeys,
+        args: AllocateIxArgs,
+    },
+    AllocateWithSeed {
+        keys: AllocateWithSeedKeys,
+        args: AllocateWithSeedIxArgs,
+    },
+    AssignWithSeed {
+        keys: AssignWithSeedKeys,
+        args: AssignWithSeedIxArgs,
+    },
+    TransferWithSeed {
+        keys: TransferWithSeedKeys,
+        args: TransferWithSeedIxArgs,
+    },
+    UpgradeNonceAccount {
+        keys: UpgradeNonceAccountKeys,
+    },
+}
+impl SystemProgramParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix =
+            solana_program::program_utils::limited_deserialize::<SystemProgramProgramIx>(
+                data,
+                data.len() as u64,
+            )
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        Ok(match program_ix {
+            SystemProgramProgramIx::CreateAccount(args) => Self::CreateAccount {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SystemProgramProgramIx::Assign(args) => Self::Assign {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SystemProgramProgramIx::Transfer(args) => Self::Transfer {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SystemProgramProgramIx::CreateAccountWithSeed(args) => Self::CreateAccountWithSeed {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SystemProgramProgramIx::AdvanceNonceAccount => Self::AdvanceNonceAccount {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            SystemProgramProgramIx::WithdrawNonceAccount(args) => Self::WithdrawNonceAccount {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SystemProgramProgramIx::InitializeNonceAccount(args) => Self::InitializeNonceAccount {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SystemProgramProgramIx::AuthorizeNonceAccount(args) => Self::AuthorizeNonceAccount {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SystemProgramProgramIx::Allocate(args) => Self::Allocate {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SystemProgramProgramIx::AllocateWithSeed(args) => Self::AllocateWithSeed {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SystemProgramProgramIx::AssignWithSeed(args) => Self::AssignWithSeed {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SystemProgramProgramIx::TransferWithSeed(args) => Self::TransferWithSeed {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SystemProgramProgramIx::UpgradeNonceAccount => Self::UpgradeNonceAccount {
+                keys: parsed_ix_keys(accounts)?,
+            },
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
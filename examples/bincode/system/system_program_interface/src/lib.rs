@@ -1,3 +1,5 @@
 solana_program::declare_id!("11111111111111111111111111111111");
 pub mod instructions;
 pub use instructions::*;
+pub mod serde_helpers;
+pub use serde_helpers::*;
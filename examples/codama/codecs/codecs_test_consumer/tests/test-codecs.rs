@@ -0,0 +1,198 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use codecs_test_interface::*;
+use solana_program::{pubkey, pubkey::Pubkey};
+
+const ADMIN: Pubkey = pubkey!("4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi");
+const DELEGATE: Pubkey = pubkey!("8qbHbw2BbbTHBW1sbeqakYXVKRQM8Ne7pLK7m6CVfeR");
+
+/// Checks that `val` serializes to exactly `bytes` and deserializes back to `val`
+fn assert_bytes<T: BorshSerialize + BorshDeserialize + PartialEq + Debug>(val: T, bytes: &[u8]) {
+    assert_eq!(borsh::to_vec(&val).unwrap(), bytes);
+    assert_eq!(T::try_from_slice(bytes).unwrap(), val);
+}
+
+#[test]
+fn test_short_u16() {
+    assert_bytes(ShortU16(0), &[0]);
+    assert_bytes(ShortU16(0x7f), &[0x7f]);
+    assert_bytes(ShortU16(0x80), &[0x80, 0x01]);
+    assert_bytes(ShortU16(0x3fff), &[0xff, 0x7f]);
+    assert_bytes(ShortU16(0x4000), &[0x80, 0x80, 0x01]);
+    assert_bytes(ShortU16(u16::MAX), &[0xff, 0xff, 0x03]);
+    // overflows u16
+    assert!(ShortU16::try_from_slice(&[0xff, 0xff, 0x04]).is_err());
+    // continuation bit set on the 3rd byte
+    assert!(ShortU16::try_from_slice(&[0x80, 0x80, 0x80]).is_err());
+}
+
+#[test]
+fn test_prefixed_vec_and_string() {
+    assert_bytes(
+        PrefixedVec::<ShortU16, u32>::from(vec![1, 300]),
+        &[2, 1, 0, 0, 0, 44, 1, 0, 0],
+    );
+    assert_bytes(ShortVec::from(vec![0xaa; 128]), &{
+        let mut bytes = vec![0x80, 0x01];
+        bytes.extend_from_slice(&[0xaa; 128]);
+        bytes
+    });
+    assert_bytes(
+        PrefixedString::<u16>::from("hi".to_owned()),
+        &[2, 0, b'h', b'i'],
+    );
+    assert_bytes(
+        PrefixedVec::<u16, PrefixedString<u8>>::from(vec!["a".to_owned().into()]),
+        &[1, 0, 1, b'a'],
+    );
+    // prefix longer than the remaining bytes
+    assert!(PrefixedString::<u8>::try_from_slice(&[3, b'h', b'i']).is_err());
+    // len does not fit in the prefix
+    assert!(borsh::to_vec(&PrefixedVec::<u8, u8>::from(vec![0; 256])).is_err());
+}
+
+#[test]
+fn test_fixed_size_string() {
+    let mut bytes = b"abc".to_vec();
+    bytes.resize(8, 0);
+    assert_bytes(FixedSizeString::<8>("abc".to_owned()), &bytes);
+    assert_bytes(FixedSizeString::<4>(String::new()), &[0; 4]);
+    assert!(borsh::to_vec(&FixedSizeString::<2>("abc".to_owned())).is_err());
+    assert!(FixedSizeString::<8>::try_from_slice(b"abc").is_err());
+}
+
+#[test]
+fn test_remainder() {
+    assert_bytes(RemainderVec::<u8>(vec![1, 2, 3]), &[1, 2, 3]);
+    assert_bytes(RemainderVec::<u16>(vec![1, 2]), &[1, 0, 2, 0]);
+    assert_bytes(RemainderString("rest".to_owned()), b"rest");
+    assert_bytes(RemainderVec::<u8>(vec![]), &[]);
+}
+
+#[test]
+fn test_options() {
+    assert_bytes(PrefixedOption::<u32, i64>::from(None), &[0, 0, 0, 0]);
+    assert_bytes(
+        PrefixedOption::<u32, i64>::from(Some(-1)),
+        &[1, 0, 0, 0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+    );
+    assert!(PrefixedOption::<u8, u8>::try_from_slice(&[2, 0]).is_err());
+
+    assert_bytes(FixedOption::<u8, u64>::from(None), &[0; 9]);
+    assert_bytes(
+        FixedOption::<u8, u64>::from(Some(5)),
+        &[1, 5, 0, 0, 0, 0, 0, 0, 0],
+    );
+    assert!(FixedOption::<u8, u64>::try_from_slice(&[2; 9]).is_err());
+
+    assert_bytes(ZeroableOption::<Pubkey>(None), &[0; 32]);
+    assert_bytes(ZeroableOption(Some(DELEGATE)), DELEGATE.as_ref());
+}
+
+#[test]
+fn test_enum_variants() {
+    assert_bytes(Action::Noop, &[0]);
+    let mut bytes = vec![1];
+    bytes.extend_from_slice(ADMIN.as_ref());
+    bytes.extend_from_slice(&7u64.to_le_bytes());
+    assert_bytes(
+        Action::Transfer {
+            to: ADMIN,
+            amount: 7,
+        },
+        &bytes,
+    );
+    assert_bytes(Action::Memo("m".to_owned()), &[2, 1, 0, 0, 0, b'm']);
+}
+
+#[test]
+fn test_write_record_ix_data() {
+    let args = WriteRecordIxArgs {
+        offsets: vec![1, 300].into(),
+        memo: "hi".to_owned().into(),
+        expiry: Some(-1).into(),
+        limit: None.into(),
+        pair: (9, ADMIN),
+        data: RemainderVec(vec![0xaa, 0xbb]),
+    };
+    let mut expected = WRITE_RECORD_IX_DISCM.to_vec();
+    expected.extend_from_slice(&[2, 1, 0, 0, 0, 44, 1, 0, 0]);
+    expected.extend_from_slice(&[2, 0, b'h', b'i']);
+    expected.extend_from_slice(&[1, 0, 0, 0]);
+    expected.extend_from_slice(&[0xff; 8]);
+    expected.extend_from_slice(&[0; 9]);
+    expected.push(9);
+    expected.extend_from_slice(ADMIN.as_ref());
+    expected.extend_from_slice(&[0xaa, 0xbb]);
+
+    let data = WriteRecordIxData(args.clone()).try_to_vec().unwrap();
+    assert_eq!(data, expected);
+    assert_eq!(
+        WriteRecordIxData::deserialize(&data).unwrap(),
+        WriteRecordIxData(args)
+    );
+}
+
+#[test]
+fn test_ping_ix_data() {
+    assert_eq!(PingIxData.try_to_vec().unwrap(), b"pingping");
+    assert!(PingIxData::deserialize(b"pongpong").is_err());
+}
+
+#[test]
+fn test_account_round_trips() {
+    let config = ConfigAccount(Config {
+        admin: ADMIN,
+        name: FixedSizeString("config".to_owned()),
+        fee_bps: 30,
+        created_at: 1_700_000_000,
+        delegate: ZeroableOption(Some(DELEGATE)),
+        tags: vec!["a".to_owned().into(), "bc".to_owned().into()].into(),
+    });
+    let data = config.try_to_vec().unwrap();
+    assert_eq!(data[..8], CONFIG_ACCOUNT_DISCM);
+    // discm + admin + name + fee_bps + created_at + delegate + tags
+    assert_eq!(data.len(), 8 + 32 + 32 + 2 + 8 + 32 + (2 + 2 + 3));
+    assert_eq!(ConfigAccount::deserialize(&data).unwrap(), config);
+
+    let vault = VaultAccount(Vault {
+        owner: ADMIN,
+        balances: HashMap::from([(DELEGATE, 100)]),
+        whitelist: HashSet::from([ADMIN]),
+    });
+    let data = vault.try_to_vec().unwrap();
+    assert_eq!(data[..1], VAULT_ACCOUNT_DISCM);
+    assert_eq!(VaultAccount::deserialize(&data).unwrap(), vault);
+
+    let record = RecordAccount(Record {
+        authority: ADMIN,
+        data: RemainderVec(vec![1, 2, 3]),
+    });
+    let data = record.try_to_vec().unwrap();
+    assert_eq!(data[..6], *b"record");
+    assert_eq!(data.len(), 6 + 32 + 3);
+    assert_eq!(RecordAccount::deserialize(&data).unwrap(), record);
+
+    // wrong discm
+    assert!(ConfigAccount::deserialize(&record.try_to_vec().unwrap()).is_err());
+}
+
+#[test]
+fn test_find_addresses() {
+    assert_eq!(
+        find_vault_address(&ADMIN, 7),
+        (pubkey!("2hzuB9WGtbjp31tnnTggTMnfRBmHNK2h1MfkFSxSePkR"), 255)
+    );
+    assert_eq!(
+        create_vault_address(&ADMIN, 7, 255).unwrap(),
+        pubkey!("2hzuB9WGtbjp31tnnTggTMnfRBmHNK2h1MfkFSxSePkR")
+    );
+    assert_eq!(
+        find_record_address("label", b"seed"),
+        (pubkey!("8GzniWjTPiGXvLS6x4hsWWDGZweqyz6kqP7KkCGGJaN4"), 254)
+    );
+}
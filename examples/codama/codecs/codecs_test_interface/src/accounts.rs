@@ -0,0 +1,128 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+pub const CONFIG_ACCOUNT_DISCM: [u8; 8] = [155, 12, 170, 224, 30, 250, 204, 130];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    pub admin: Pubkey,
+    pub name: FixedSizeString<32>,
+    pub fee_bps: u16,
+    pub created_at: i64,
+    pub delegate: ZeroableOption<Pubkey>,
+    pub tags: PrefixedVec<u16, PrefixedString<u8>>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigAccount(pub Config);
+impl ConfigAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CONFIG_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    CONFIG_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(Config::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CONFIG_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const VAULT_ACCOUNT_DISCM: [u8; 1] = [2];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vault {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    pub owner: Pubkey,
+    pub balances: std::collections::HashMap<Pubkey, u64>,
+    pub whitelist: std::collections::HashSet<Pubkey>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaultAccount(pub Vault);
+impl VaultAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != VAULT_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    VAULT_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(Vault::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&VAULT_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const RECORD_ACCOUNT_DISCM: [u8; 6] = [114, 101, 99, 111, 114, 100];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Record {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    pub authority: Pubkey,
+    pub data: RemainderVec<u8>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordAccount(pub Record);
+impl RecordAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 6];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != RECORD_ACCOUNT_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    RECORD_ACCOUNT_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(Record::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&RECORD_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
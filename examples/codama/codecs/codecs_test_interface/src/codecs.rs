@@ -0,0 +1,528 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::{
+    io::{Read, Write},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+fn invalid_data(msg: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, msg)
+}
+/// Integer types that can be used as the length prefix
+/// of codama prefixed collections and options
+pub trait LenPrefix {
+    fn write_len<W: Write>(len: usize, writer: &mut W) -> std::io::Result<()>;
+    fn read_len<R: Read>(reader: &mut R) -> std::io::Result<usize>;
+}
+impl LenPrefix for u8 {
+    fn write_len<W: Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+        let len = u8::try_from(len).map_err(|_| invalid_data("len overflows prefix"))?;
+        len.serialize(writer)
+    }
+    fn read_len<R: Read>(reader: &mut R) -> std::io::Result<usize> {
+        let len = u8::deserialize_reader(reader)?;
+        Ok(len.into())
+    }
+}
+impl LenPrefix for u16 {
+    fn write_len<W: Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+        let len = u16::try_from(len).map_err(|_| invalid_data("len overflows prefix"))?;
+        len.serialize(writer)
+    }
+    fn read_len<R: Read>(reader: &mut R) -> std::io::Result<usize> {
+        let len = u16::deserialize_reader(reader)?;
+        Ok(len.into())
+    }
+}
+impl LenPrefix for u32 {
+    fn write_len<W: Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+        let len = u32::try_from(len).map_err(|_| invalid_data("len overflows prefix"))?;
+        len.serialize(writer)
+    }
+    fn read_len<R: Read>(reader: &mut R) -> std::io::Result<usize> {
+        let len = u32::deserialize_reader(reader)?;
+        usize::try_from(len).map_err(|_| invalid_data("len overflows usize"))
+    }
+}
+impl LenPrefix for u64 {
+    fn write_len<W: Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+        let len = u64::try_from(len).map_err(|_| invalid_data("len overflows prefix"))?;
+        len.serialize(writer)
+    }
+    fn read_len<R: Read>(reader: &mut R) -> std::io::Result<usize> {
+        let len = u64::deserialize_reader(reader)?;
+        usize::try_from(len).map_err(|_| invalid_data("len overflows usize"))
+    }
+}
+/// u16 encoded in 1-3 bytes, 7 bits per byte with the MSB as the continuation bit
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct ShortU16(pub u16);
+impl BorshSerialize for ShortU16 {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut n = self.0;
+        loop {
+            let byte = (n & 0x7f) as u8;
+            n >>= 7;
+            if n == 0 {
+                return writer.write_all(&[byte]);
+            }
+            writer.write_all(&[byte | 0x80])?;
+        }
+    }
+}
+impl BorshDeserialize for ShortU16 {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut res: u32 = 0;
+        for i in 0..3 {
+            let byte = u8::deserialize_reader(reader)?;
+            res |= u32::from(byte & 0x7f) << (i * 7);
+            if byte & 0x80 == 0 {
+                return u16::try_from(res)
+                    .map(Self)
+                    .map_err(|_| invalid_data("ShortU16 overflow"));
+            }
+        }
+        Err(invalid_data("ShortU16 longer than 3 bytes"))
+    }
+}
+impl LenPrefix for ShortU16 {
+    fn write_len<W: Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+        let len = u16::try_from(len).map_err(|_| invalid_data("len overflows prefix"))?;
+        ShortU16(len).serialize(writer)
+    }
+    fn read_len<R: Read>(reader: &mut R) -> std::io::Result<usize> {
+        Ok(ShortU16::deserialize_reader(reader)?.0.into())
+    }
+}
+impl Deref for ShortU16 {
+    type Target = u16;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for ShortU16 {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl From<u16> for ShortU16 {
+    fn from(inner: u16) -> Self {
+        Self(inner)
+    }
+}
+impl From<ShortU16> for u16 {
+    fn from(wrapper: ShortU16) -> Self {
+        wrapper.0
+    }
+}
+/// Vec with a length prefix of type `P`
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct PrefixedVec<P, T>(
+    pub Vec<T>,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<P>,
+);
+impl<P: LenPrefix, T: BorshSerialize> BorshSerialize for PrefixedVec<P, T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        P::write_len(self.0.len(), writer)?;
+        for item in self.0.iter() {
+            item.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+impl<P: LenPrefix, T: BorshDeserialize> BorshDeserialize for PrefixedVec<P, T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let len = P::read_len(reader)?;
+        let mut res = Vec::new();
+        for _ in 0..len {
+            res.push(T::deserialize_reader(reader)?);
+        }
+        Ok(Self(res, PhantomData))
+    }
+}
+impl<P, T> Deref for PrefixedVec<P, T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<P, T> DerefMut for PrefixedVec<P, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<P, T> From<Vec<T>> for PrefixedVec<P, T> {
+    fn from(inner: Vec<T>) -> Self {
+        Self(inner, PhantomData)
+    }
+}
+impl<P, T> From<PrefixedVec<P, T>> for Vec<T> {
+    fn from(wrapper: PrefixedVec<P, T>) -> Self {
+        wrapper.0
+    }
+}
+/// utf8 String with a byte length prefix of type `P`
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct PrefixedString<P>(
+    pub String,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<P>,
+);
+impl<P: LenPrefix> BorshSerialize for PrefixedString<P> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        P::write_len(self.0.len(), writer)?;
+        writer.write_all(self.0.as_bytes())
+    }
+}
+impl<P: LenPrefix> BorshDeserialize for PrefixedString<P> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let len = P::read_len(reader)?;
+        let mut bytes = Vec::new();
+        reader.take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        String::from_utf8(bytes)
+            .map(|s| Self(s, PhantomData))
+            .map_err(|_| invalid_data("invalid utf8"))
+    }
+}
+impl<P> Deref for PrefixedString<P> {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<P> DerefMut for PrefixedString<P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<P> From<String> for PrefixedString<P> {
+    fn from(inner: String) -> Self {
+        Self(inner, PhantomData)
+    }
+}
+impl<P> From<PrefixedString<P>> for String {
+    fn from(wrapper: PrefixedString<P>) -> Self {
+        wrapper.0
+    }
+}
+/// utf8 String that is always serialized to `N` bytes, padded with trailing zeros.
+///
+/// Trailing zeros are stripped on deserialization.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct FixedSizeString<const N: usize>(pub String);
+impl<const N: usize> BorshSerialize for FixedSizeString<N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let bytes = self.0.as_bytes();
+        if bytes.len() > N {
+            return Err(invalid_data("string longer than fixed size"));
+        }
+        writer.write_all(bytes)?;
+        writer.write_all(&vec![0u8; N - bytes.len()])
+    }
+}
+impl<const N: usize> BorshDeserialize for FixedSizeString<N> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut bytes = vec![0u8; N];
+        reader.read_exact(&mut bytes)?;
+        let len = bytes.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        bytes.truncate(len);
+        String::from_utf8(bytes)
+            .map(Self)
+            .map_err(|_| invalid_data("invalid utf8"))
+    }
+}
+impl<const N: usize> Deref for FixedSizeString<N> {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<const N: usize> DerefMut for FixedSizeString<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<const N: usize> From<String> for FixedSizeString<N> {
+    fn from(inner: String) -> Self {
+        Self(inner)
+    }
+}
+impl<const N: usize> From<FixedSizeString<N>> for String {
+    fn from(wrapper: FixedSizeString<N>) -> Self {
+        wrapper.0
+    }
+}
+/// Vec without a length prefix that consumes the rest of the buffer
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct RemainderVec<T>(pub Vec<T>);
+impl<T: BorshSerialize> BorshSerialize for RemainderVec<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for item in self.0.iter() {
+            item.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+impl<T: BorshDeserialize> BorshDeserialize for RemainderVec<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        let mut remaining = buf.as_slice();
+        let mut res = Vec::new();
+        while !remaining.is_empty() {
+            res.push(T::deserialize(&mut remaining)?);
+        }
+        Ok(Self(res))
+    }
+}
+impl<T> Deref for RemainderVec<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T> DerefMut for RemainderVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<T> From<Vec<T>> for RemainderVec<T> {
+    fn from(inner: Vec<T>) -> Self {
+        Self(inner)
+    }
+}
+impl<T> From<RemainderVec<T>> for Vec<T> {
+    fn from(wrapper: RemainderVec<T>) -> Self {
+        wrapper.0
+    }
+}
+/// utf8 String without a length prefix that consumes the rest of the buffer
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct RemainderString(pub String);
+impl BorshSerialize for RemainderString {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(self.0.as_bytes())
+    }
+}
+impl BorshDeserialize for RemainderString {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        String::from_utf8(bytes)
+            .map(Self)
+            .map_err(|_| invalid_data("invalid utf8"))
+    }
+}
+impl Deref for RemainderString {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for RemainderString {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl From<String> for RemainderString {
+    fn from(inner: String) -> Self {
+        Self(inner)
+    }
+}
+impl From<RemainderString> for String {
+    fn from(wrapper: RemainderString) -> Self {
+        wrapper.0
+    }
+}
+/// Option with a 0 (None) or 1 (Some) prefix of type `P`
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct PrefixedOption<P, T>(
+    pub Option<T>,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<P>,
+);
+impl<P: LenPrefix, T: BorshSerialize> BorshSerialize for PrefixedOption<P, T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match &self.0 {
+            None => P::write_len(0, writer),
+            Some(item) => {
+                P::write_len(1, writer)?;
+                item.serialize(writer)
+            }
+        }
+    }
+}
+impl<P: LenPrefix, T: BorshDeserialize> BorshDeserialize for PrefixedOption<P, T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        match P::read_len(reader)? {
+            0 => Ok(Self(None, PhantomData)),
+            1 => Ok(Self(Some(T::deserialize_reader(reader)?), PhantomData)),
+            _ => Err(invalid_data("invalid option prefix")),
+        }
+    }
+}
+impl<P, T> Deref for PrefixedOption<P, T> {
+    type Target = Option<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<P, T> DerefMut for PrefixedOption<P, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<P, T> From<Option<T>> for PrefixedOption<P, T> {
+    fn from(inner: Option<T>) -> Self {
+        Self(inner, PhantomData)
+    }
+}
+impl<P, T> From<PrefixedOption<P, T>> for Option<T> {
+    fn from(wrapper: PrefixedOption<P, T>) -> Self {
+        wrapper.0
+    }
+}
+/// Option with a 0 (None) or 1 (Some) prefix of type `P`
+/// that always takes up the serialized size of `T`.
+///
+/// `T::default()` is serialized in place of None,
+/// so it should serialize to all zeros.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct FixedOption<P, T>(
+    pub Option<T>,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<P>,
+);
+impl<P: LenPrefix, T: BorshSerialize + Default> BorshSerialize for FixedOption<P, T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match &self.0 {
+            None => {
+                P::write_len(0, writer)?;
+                T::default().serialize(writer)
+            }
+            Some(item) => {
+                P::write_len(1, writer)?;
+                item.serialize(writer)
+            }
+        }
+    }
+}
+impl<P: LenPrefix, T: BorshDeserialize> BorshDeserialize for FixedOption<P, T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let prefix = P::read_len(reader)?;
+        let item = T::deserialize_reader(reader)?;
+        match prefix {
+            0 => Ok(Self(None, PhantomData)),
+            1 => Ok(Self(Some(item), PhantomData)),
+            _ => Err(invalid_data("invalid option prefix")),
+        }
+    }
+}
+impl<P, T> Deref for FixedOption<P, T> {
+    type Target = Option<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<P, T> DerefMut for FixedOption<P, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<P, T> From<Option<T>> for FixedOption<P, T> {
+    fn from(inner: Option<T>) -> Self {
+        Self(inner, PhantomData)
+    }
+}
+impl<P, T> From<FixedOption<P, T>> for Option<T> {
+    fn from(wrapper: FixedOption<P, T>) -> Self {
+        wrapper.0
+    }
+}
+/// Option without a prefix where None is represented by `T::default()`,
+/// which should serialize to all zeros
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct ZeroableOption<T>(pub Option<T>);
+impl<T: BorshSerialize + Default> BorshSerialize for ZeroableOption<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match &self.0 {
+            None => T::default().serialize(writer),
+            Some(item) => item.serialize(writer),
+        }
+    }
+}
+impl<T: BorshDeserialize + Default + PartialEq> BorshDeserialize for ZeroableOption<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let item = T::deserialize_reader(reader)?;
+        if item == T::default() {
+            Ok(Self(None))
+        } else {
+            Ok(Self(Some(item)))
+        }
+    }
+}
+impl<T> Deref for ZeroableOption<T> {
+    type Target = Option<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T> DerefMut for ZeroableOption<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<T> From<Option<T>> for ZeroableOption<T> {
+    fn from(inner: Option<T>) -> Self {
+        Self(inner)
+    }
+}
+impl<T> From<ZeroableOption<T>> for Option<T> {
+    fn from(wrapper: ZeroableOption<T>) -> Self {
+        wrapper.0
+    }
+}
@@ -0,0 +1,99 @@
+use solana_program::{
+    decode_error::DecodeError,
+    instruction::InstructionError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+    pubkey::Pubkey,
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum CodecsTestError {
+    /// Fee bps must be below 10000
+    #[error("Fee bps must be below 10000")]
+    InvalidFee = 6000,
+}
+impl From<CodecsTestError> for ProgramError {
+    fn from(e: CodecsTestError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for CodecsTestError {
+    fn type_of() -> &'static str {
+        "CodecsTestError"
+    }
+}
+impl PrintProgramError for CodecsTestError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
+impl TryFrom<u32> for CodecsTestError {
+    type Error = u32;
+    /// Returns `code` back if it is not one of this program's errors
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        <Self as num_traits::FromPrimitive>::from_u32(code).ok_or(code)
+    }
+}
+impl<'a> TryFrom<&'a ProgramError> for CodecsTestError {
+    type Error = &'a ProgramError;
+    /// Returns `e` back if it is not a `ProgramError::Custom` of this program's errors
+    fn try_from(e: &'a ProgramError) -> Result<Self, Self::Error> {
+        match e {
+            ProgramError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl<'a> TryFrom<&'a InstructionError> for CodecsTestError {
+    type Error = &'a InstructionError;
+    /// Returns `e` back if it is not an `InstructionError::Custom` of this program's errors
+    fn try_from(e: &'a InstructionError) -> Result<Self, Self::Error> {
+        match e {
+            InstructionError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl CodecsTestError {
+    /// Parses a `custom program error: 0x...` log line
+    /// into the error, e.g. `Program <id> failed: custom program error: 0x1770`.
+    /// Returns `None` for other logs and codes that are not of this program's errors.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let code = if let Some((_, hex)) = log.split_once("custom program error: 0x") {
+            u32::from_str_radix(hex.trim_end(), 16).ok()?
+        } else {
+            return None;
+        };
+        Self::try_from(code).ok()
+    }
+}
+/// Parses the error the program failed with from a transaction's log messages.
+///
+/// Only the first `Program <id> failed: ` log is considered, since programs that
+/// invoked the failing program log the same error when failing in turn.
+/// Returns `None` if the failing program is not `program_id`
+/// or it did not fail with one of its errors.
+pub fn parse_error_from_logs_with_program_id(
+    program_id: Pubkey,
+    logs: &[String],
+) -> Option<CodecsTestError> {
+    let (program, failed_log) = logs.iter().find_map(|log| {
+        let mut words = log.strip_prefix("Program ")?.split(' ');
+        let program = words.next()?.parse::<Pubkey>().ok()?;
+        (words.next() == Some("failed:")).then_some((program, log))
+    })?;
+    if program != program_id {
+        return None;
+    }
+    CodecsTestError::from_log(failed_log)
+}
+pub fn parse_error_from_logs(logs: &[String]) -> Option<CodecsTestError> {
+    parse_error_from_logs_with_program_id(crate::ID, logs)
+}
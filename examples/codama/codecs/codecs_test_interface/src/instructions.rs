@@ -0,0 +1,849 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum CodecsTestProgramIx {
+    InitConfig(InitConfigIxArgs),
+    WriteRecord(WriteRecordIxArgs),
+    Ping,
+}
+impl CodecsTestProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            INIT_CONFIG_IX_DISCM => Ok(Self::InitConfig(InitConfigIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            WRITE_RECORD_IX_DISCM => Ok(Self::WriteRecord(WriteRecordIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            PING_IX_DISCM => Ok(Self::Ping),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::InitConfig(args) => {
+                writer.write_all(&INIT_CONFIG_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::WriteRecord(args) => {
+                writer.write_all(&WRITE_RECORD_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::Ping => writer.write_all(&PING_IX_DISCM),
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const INIT_CONFIG_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct InitConfigAccounts<'me, 'info> {
+    pub config: &'me AccountInfo<'info>,
+    pub admin: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InitConfigKeys {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<InitConfigAccounts<'_, '_>> for InitConfigKeys {
+    fn from(accounts: InitConfigAccounts) -> Self {
+        Self {
+            config: *accounts.config.key,
+            admin: *accounts.admin.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<InitConfigKeys> for [AccountMeta; INIT_CONFIG_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitConfigKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.config,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INIT_CONFIG_IX_ACCOUNTS_LEN]> for InitConfigKeys {
+    fn from(pubkeys: [Pubkey; INIT_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            config: pubkeys[0],
+            admin: pubkeys[1],
+            system_program: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<InitConfigAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INIT_CONFIG_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitConfigAccounts<'_, 'info>) -> Self {
+        [
+            accounts.config.clone(),
+            accounts.admin.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INIT_CONFIG_IX_ACCOUNTS_LEN]>
+    for InitConfigAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INIT_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            config: &arr[0],
+            admin: &arr[1],
+            system_program: &arr[2],
+        }
+    }
+}
+impl InitConfigKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INIT_CONFIG_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INIT_CONFIG_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INIT_CONFIG_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitConfigKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitConfigAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INIT_CONFIG_IX_ACCOUNTS_LEN] = accounts
+            .get(..INIT_CONFIG_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INIT_CONFIG_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitConfigAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const INIT_CONFIG_IX_DISCM: [u8; 8] = [23, 169, 60, 81, 2, 238, 125, 68];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitConfigIxArgs {
+    pub name: FixedSizeString<32>,
+    pub fee_bps: u16,
+    pub tags: PrefixedVec<u16, PrefixedString<u8>>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitConfigIxData(pub InitConfigIxArgs);
+impl From<InitConfigIxArgs> for InitConfigIxData {
+    fn from(args: InitConfigIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitConfigIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INIT_CONFIG_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INIT_CONFIG_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(InitConfigIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INIT_CONFIG_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn init_config_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitConfigKeys,
+    args: InitConfigIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INIT_CONFIG_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitConfigIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn init_config_ix(
+    keys: InitConfigKeys,
+    args: InitConfigIxArgs,
+) -> std::io::Result<Instruction> {
+    init_config_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`init_config_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitConfigIxBuilder {
+    config: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    name: Option<FixedSizeString<32>>,
+    fee_bps: Option<u16>,
+    tags: Option<PrefixedVec<u16, PrefixedString<u8>>>,
+}
+impl Default for InitConfigIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitConfigIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            admin: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            name: None,
+            fee_bps: None,
+            tags: None,
+        }
+    }
+    pub fn config(mut self, config: Pubkey) -> Self {
+        self.config = Some(config);
+        self
+    }
+    pub fn admin(mut self, admin: Pubkey) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn name(mut self, name: FixedSizeString<32>) -> Self {
+        self.name = Some(name);
+        self
+    }
+    pub fn fee_bps(mut self, fee_bps: u16) -> Self {
+        self.fee_bps = Some(fee_bps);
+        self
+    }
+    pub fn tags(mut self, tags: PrefixedVec<u16, PrefixedString<u8>>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitConfigIxBuilder is missing {}", field),
+            )
+        };
+        let config = self.config.ok_or_else(|| missing("config"))?;
+        let admin = self.admin.ok_or_else(|| missing("admin"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let args = InitConfigIxArgs {
+            name: self.name.ok_or_else(|| missing("name"))?,
+            fee_bps: self.fee_bps.ok_or_else(|| missing("fee_bps"))?,
+            tags: self.tags.ok_or_else(|| missing("tags"))?,
+        };
+        let keys = InitConfigKeys {
+            config,
+            admin,
+            system_program,
+        };
+        init_config_ix(keys, args)
+    }
+}
+pub fn init_config_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitConfigAccounts<'_, '_>,
+    args: InitConfigIxArgs,
+) -> ProgramResult {
+    let keys: InitConfigKeys = accounts.into();
+    let ix = init_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn init_config_invoke(
+    accounts: InitConfigAccounts<'_, '_>,
+    args: InitConfigIxArgs,
+) -> ProgramResult {
+    init_config_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn init_config_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitConfigAccounts<'_, '_>,
+    args: InitConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitConfigKeys = accounts.into();
+    let ix = init_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn init_config_invoke_signed(
+    accounts: InitConfigAccounts<'_, '_>,
+    args: InitConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    init_config_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn init_config_verify_account_keys(
+    accounts: InitConfigAccounts<'_, '_>,
+    keys: InitConfigKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.config.key, keys.config),
+        (*accounts.admin.key, keys.admin),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn init_config_verify_writable_privileges<'me, 'info>(
+    accounts: InitConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.config, accounts.admin] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn init_config_verify_signer_privileges<'me, 'info>(
+    accounts: InitConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.config, accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn init_config_verify_account_privileges<'me, 'info>(
+    accounts: InitConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    init_config_verify_writable_privileges(accounts)?;
+    init_config_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const WRITE_RECORD_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct WriteRecordAccounts<'me, 'info> {
+    pub record: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WriteRecordKeys {
+    pub record: Pubkey,
+    pub authority: Pubkey,
+}
+impl From<WriteRecordAccounts<'_, '_>> for WriteRecordKeys {
+    fn from(accounts: WriteRecordAccounts) -> Self {
+        Self {
+            record: *accounts.record.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<WriteRecordKeys> for [AccountMeta; WRITE_RECORD_IX_ACCOUNTS_LEN] {
+    fn from(keys: WriteRecordKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; WRITE_RECORD_IX_ACCOUNTS_LEN]> for WriteRecordKeys {
+    fn from(pubkeys: [Pubkey; WRITE_RECORD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            record: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<WriteRecordAccounts<'_, 'info>>
+    for [AccountInfo<'info>; WRITE_RECORD_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: WriteRecordAccounts<'_, 'info>) -> Self {
+        [accounts.record.clone(), accounts.authority.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; WRITE_RECORD_IX_ACCOUNTS_LEN]>
+    for WriteRecordAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; WRITE_RECORD_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            record: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+impl WriteRecordKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; WRITE_RECORD_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..WRITE_RECORD_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[WRITE_RECORD_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for WriteRecordKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> WriteRecordAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; WRITE_RECORD_IX_ACCOUNTS_LEN] = accounts
+            .get(..WRITE_RECORD_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[WRITE_RECORD_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for WriteRecordAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const WRITE_RECORD_IX_DISCM: [u8; 8] = [236, 3, 67, 111, 15, 100, 60, 10];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WriteRecordIxArgs {
+    pub offsets: PrefixedVec<ShortU16, u32>,
+    pub memo: PrefixedString<u16>,
+    pub expiry: PrefixedOption<u32, i64>,
+    pub limit: FixedOption<u8, u64>,
+    pub pair: (u8, Pubkey),
+    pub data: RemainderVec<u8>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct WriteRecordIxData(pub WriteRecordIxArgs);
+impl From<WriteRecordIxArgs> for WriteRecordIxData {
+    fn from(args: WriteRecordIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl WriteRecordIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != WRITE_RECORD_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    WRITE_RECORD_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(WriteRecordIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&WRITE_RECORD_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn write_record_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WriteRecordKeys,
+    args: WriteRecordIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; WRITE_RECORD_IX_ACCOUNTS_LEN] = keys.into();
+    let data: WriteRecordIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn write_record_ix(
+    keys: WriteRecordKeys,
+    args: WriteRecordIxArgs,
+) -> std::io::Result<Instruction> {
+    write_record_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`write_record_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct WriteRecordIxBuilder {
+    record: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    offsets: Option<PrefixedVec<ShortU16, u32>>,
+    memo: Option<PrefixedString<u16>>,
+    expiry: Option<PrefixedOption<u32, i64>>,
+    limit: Option<FixedOption<u8, u64>>,
+    pair: Option<(u8, Pubkey)>,
+    data: Option<RemainderVec<u8>>,
+}
+impl Default for WriteRecordIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl WriteRecordIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            record: None,
+            authority: None,
+            offsets: None,
+            memo: None,
+            expiry: None,
+            limit: None,
+            pair: None,
+            data: None,
+        }
+    }
+    pub fn record(mut self, record: Pubkey) -> Self {
+        self.record = Some(record);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn offsets(mut self, offsets: PrefixedVec<ShortU16, u32>) -> Self {
+        self.offsets = Some(offsets);
+        self
+    }
+    pub fn memo(mut self, memo: PrefixedString<u16>) -> Self {
+        self.memo = Some(memo);
+        self
+    }
+    pub fn expiry(mut self, expiry: PrefixedOption<u32, i64>) -> Self {
+        self.expiry = Some(expiry);
+        self
+    }
+    pub fn limit(mut self, limit: FixedOption<u8, u64>) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+    pub fn pair(mut self, pair: (u8, Pubkey)) -> Self {
+        self.pair = Some(pair);
+        self
+    }
+    pub fn data(mut self, data: RemainderVec<u8>) -> Self {
+        self.data = Some(data);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("WriteRecordIxBuilder is missing {}", field),
+            )
+        };
+        let record = self.record.ok_or_else(|| missing("record"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = WriteRecordIxArgs {
+            offsets: self.offsets.ok_or_else(|| missing("offsets"))?,
+            memo: self.memo.ok_or_else(|| missing("memo"))?,
+            expiry: self.expiry.ok_or_else(|| missing("expiry"))?,
+            limit: self.limit.ok_or_else(|| missing("limit"))?,
+            pair: self.pair.ok_or_else(|| missing("pair"))?,
+            data: self.data.ok_or_else(|| missing("data"))?,
+        };
+        let keys = WriteRecordKeys { record, authority };
+        write_record_ix(keys, args)
+    }
+}
+pub fn write_record_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: WriteRecordAccounts<'_, '_>,
+    args: WriteRecordIxArgs,
+) -> ProgramResult {
+    let keys: WriteRecordKeys = accounts.into();
+    let ix = write_record_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn write_record_invoke(
+    accounts: WriteRecordAccounts<'_, '_>,
+    args: WriteRecordIxArgs,
+) -> ProgramResult {
+    write_record_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn write_record_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: WriteRecordAccounts<'_, '_>,
+    args: WriteRecordIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: WriteRecordKeys = accounts.into();
+    let ix = write_record_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn write_record_invoke_signed(
+    accounts: WriteRecordAccounts<'_, '_>,
+    args: WriteRecordIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    write_record_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn write_record_verify_account_keys(
+    accounts: WriteRecordAccounts<'_, '_>,
+    keys: WriteRecordKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.record.key, keys.record),
+        (*accounts.authority.key, keys.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn write_record_verify_writable_privileges<'me, 'info>(
+    accounts: WriteRecordAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.record] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn write_record_verify_signer_privileges<'me, 'info>(
+    accounts: WriteRecordAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn write_record_verify_account_privileges<'me, 'info>(
+    accounts: WriteRecordAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    write_record_verify_writable_privileges(accounts)?;
+    write_record_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const PING_IX_DISCM: [u8; 8] = [112, 105, 110, 103, 112, 105, 110, 103];
+#[derive(Clone, Debug, PartialEq)]
+pub struct PingIxData;
+impl PingIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != PING_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    PING_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&PING_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn ping_ix_with_program_id(program_id: Pubkey) -> std::io::Result<Instruction> {
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::new(),
+        data: PingIxData.try_to_vec()?,
+    })
+}
+pub fn ping_ix() -> std::io::Result<Instruction> {
+    ping_ix_with_program_id(crate::ID)
+}
+/// Builds a [`ping_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PingIxBuilder {}
+impl Default for PingIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PingIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {}
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        ping_ix()
+    }
+}
+pub fn ping_invoke_with_program_id(program_id: Pubkey) -> ProgramResult {
+    let ix = ping_ix_with_program_id(program_id)?;
+    invoke(&ix, &[])
+}
+pub fn ping_invoke() -> ProgramResult {
+    ping_invoke_with_program_id(crate::ID)
+}
+pub fn ping_invoke_signed_with_program_id(program_id: Pubkey, seeds: &[&[&[u8]]]) -> ProgramResult {
+    let ix = ping_ix_with_program_id(program_id)?;
+    invoke_signed(&ix, &[], seeds)
+}
+pub fn ping_invoke_signed(seeds: &[&[&[u8]]]) -> ProgramResult {
+    ping_invoke_signed_with_program_id(crate::ID, seeds)
+}
+/// A [`CodecsTestProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum CodecsTestParsedIx {
+    InitConfig {
+        keys: InitConfigKeys,
+        args: InitConfigIxArgs,
+    },
+    WriteRecord {
+        keys: WriteRecordKeys,
+        args: WriteRecordIxArgs,
+    },
+    Ping,
+}
+impl CodecsTestParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = CodecsTestProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            CodecsTestProgramIx::InitConfig(args) => Self::InitConfig {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            CodecsTestProgramIx::WriteRecord(args) => Self::WriteRecord {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            CodecsTestProgramIx::Ping => Self::Ping,
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
@@ -0,0 +1,17 @@
+solana_program::declare_id!("6Dzwe83fDfUK3RyPHMqveaYBnUHMngk2hdw93EsHiefm");
+pub mod accounts;
+pub use accounts::*;
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
+pub mod pdas;
+pub use pdas::*;
+pub mod codecs;
+pub use codecs::*;
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+#[cfg(feature = "serde")]
+pub use serde_helpers::*;
@@ -0,0 +1,47 @@
+use solana_program::pubkey::{Pubkey, PubkeyError};
+pub fn find_vault_address(owner: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"vault",
+            crate::ID.as_ref(),
+            owner.as_ref(),
+            &index.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+pub fn create_vault_address(owner: &Pubkey, index: u64, bump: u8) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[
+            b"vault",
+            crate::ID.as_ref(),
+            owner.as_ref(),
+            &index.to_le_bytes(),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+}
+pub fn find_record_address(label: &str, seed: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            &[255, 0],
+            solana_program::pubkey!("6Dzwe83fDfUK3RyPHMqveaYBnUHMngk2hdw93EsHiefm").as_ref(),
+            label.as_bytes(),
+            seed,
+        ],
+        &solana_program::pubkey!("G89e8p6DjMGwrctHBuPgraoQv7GdiiCDDguTTK3mKNdi"),
+    )
+}
+pub fn create_record_address(label: &str, seed: &[u8], bump: u8) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[
+            &[255, 0],
+            solana_program::pubkey!("6Dzwe83fDfUK3RyPHMqveaYBnUHMngk2hdw93EsHiefm").as_ref(),
+            label.as_bytes(),
+            seed,
+            &[bump],
+        ],
+        &solana_program::pubkey!("G89e8p6DjMGwrctHBuPgraoQv7GdiiCDDguTTK3mKNdi"),
+    )
+}
@@ -0,0 +1,214 @@
+use serde::{
+    de::{DeserializeOwned, Error, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use solana_program::pubkey::Pubkey;
+use std::fmt::Write;
+use std::{fmt::Display, marker::PhantomData, str::FromStr};
+/// (De)serializes `T` differently from `T`'s own serde impls.
+///
+/// The adapters in this module only do so in human-readable formats
+/// and fall back to `T`'s own serde impls otherwise.
+pub trait SerdeAs<T> {
+    fn serialize_as<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error>;
+    fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>;
+}
+/// `#[serde(with = "As::<A>")]` (de)serializes a field with the [`SerdeAs`] adapter `A`
+pub struct As<A>(PhantomData<A>);
+impl<A> As<A> {
+    pub fn serialize<T, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        A: SerdeAs<T>,
+    {
+        A::serialize_as(value, serializer)
+    }
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>
+    where
+        A: SerdeAs<T>,
+    {
+        A::deserialize_as(deserializer)
+    }
+}
+struct SerializeAsWrap<'a, T, A>(&'a T, PhantomData<A>);
+impl<T, A: SerdeAs<T>> Serialize for SerializeAsWrap<'_, T, A> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        A::serialize_as(self.0, serializer)
+    }
+}
+struct DeserializeAsWrap<T, A>(T, PhantomData<A>);
+impl<'de, T, A: SerdeAs<T>> Deserialize<'de> for DeserializeAsWrap<T, A> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        A::deserialize_as(deserializer).map(|v| Self(v, PhantomData))
+    }
+}
+/// `T`'s own serde impls
+pub struct Same;
+impl<T: Serialize + DeserializeOwned> SerdeAs<T> for Same {
+    fn serialize_as<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+    fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+}
+impl<T, A: SerdeAs<T>> SerdeAs<Option<T>> for Option<A> {
+    fn serialize_as<S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => serializer.serialize_some(&SerializeAsWrap::<T, A>(v, PhantomData)),
+            None => serializer.serialize_none(),
+        }
+    }
+    fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+        Ok(Option::<DeserializeAsWrap<T, A>>::deserialize(deserializer)?.map(|w| w.0))
+    }
+}
+impl<T, A: SerdeAs<T>> SerdeAs<Vec<T>> for Vec<A> {
+    fn serialize_as<S: Serializer>(value: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            value
+                .iter()
+                .map(|v| SerializeAsWrap::<T, A>(v, PhantomData)),
+        )
+    }
+    fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<T>, D::Error> {
+        Ok(Vec::<DeserializeAsWrap<T, A>>::deserialize(deserializer)?
+            .into_iter()
+            .map(|w| w.0)
+            .collect())
+    }
+}
+struct ArrayVisitor<T, A, const N: usize>(PhantomData<(T, A)>);
+impl<'de, T, A: SerdeAs<T>, const N: usize> Visitor<'de> for ArrayVisitor<T, A, N> {
+    type Value = [T; N];
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "an array of length {}", N)
+    }
+    fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<[T; N], V::Error> {
+        let mut res = Vec::with_capacity(N);
+        while res.len() < N {
+            match seq.next_element::<DeserializeAsWrap<T, A>>()? {
+                Some(w) => res.push(w.0),
+                None => return Err(V::Error::invalid_length(res.len(), &self)),
+            }
+        }
+        match res.try_into() {
+            Ok(arr) => Ok(arr),
+            Err(_) => unreachable!(),
+        }
+    }
+}
+/// Same as serde's own array impls, which are a tuple of the elements,
+/// but for any `N`
+impl<T, A: SerdeAs<T>, const N: usize> SerdeAs<[T; N]> for [A; N] {
+    fn serialize_as<S: Serializer>(value: &[T; N], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tup = serializer.serialize_tuple(N)?;
+        for v in value {
+            tup.serialize_element(&SerializeAsWrap::<T, A>(v, PhantomData))?;
+        }
+        tup.end()
+    }
+    fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[T; N], D::Error> {
+        deserializer.deserialize_tuple(N, ArrayVisitor::<T, A, N>(PhantomData))
+    }
+}
+/// Pubkeys as base58 strings
+pub struct Base58;
+impl SerdeAs<Pubkey> for Base58 {
+    fn serialize_as<S: Serializer>(value: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(value)
+        } else {
+            value.serialize(serializer)
+        }
+    }
+    fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            Pubkey::from_str(&s).map_err(D::Error::custom)
+        } else {
+            Pubkey::deserialize(deserializer)
+        }
+    }
+}
+/// Integers as decimal strings, since JS numbers cannot represent all u64s
+pub struct DisplayFromStr;
+impl<T> SerdeAs<T> for DisplayFromStr
+where
+    T: Display + FromStr + Serialize + DeserializeOwned,
+    T::Err: Display,
+{
+    fn serialize_as<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(value)
+        } else {
+            value.serialize(serializer)
+        }
+    }
+    fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            T::from_str(&s).map_err(D::Error::custom)
+        } else {
+            T::deserialize(deserializer)
+        }
+    }
+}
+/// Byte arrays as lowercase hex strings
+pub struct Hex;
+impl Hex {
+    fn encode(bytes: &[u8]) -> String {
+        let mut res = String::with_capacity(bytes.len() * 2);
+        for b in bytes {
+            write!(res, "{:02x}", b).unwrap();
+        }
+        res
+    }
+    fn decode(s: &str) -> Result<Vec<u8>, String> {
+        if s.len() % 2 != 0 || !s.is_ascii() {
+            return Err(format!("invalid hex string {}", s));
+        }
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|e| e.to_string()))
+            .collect()
+    }
+}
+impl SerdeAs<Vec<u8>> for Hex {
+    fn serialize_as<S: Serializer>(value: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&Self::encode(value))
+        } else {
+            value.serialize(serializer)
+        }
+    }
+    fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            Self::decode(&s).map_err(D::Error::custom)
+        } else {
+            Vec::deserialize(deserializer)
+        }
+    }
+}
+impl<const N: usize> SerdeAs<[u8; N]> for Hex {
+    fn serialize_as<S: Serializer>(value: &[u8; N], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&Self::encode(value))
+        } else {
+            <[Same; N]>::serialize_as(value, serializer)
+        }
+    }
+    fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; N], D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            let bytes = Self::decode(&s).map_err(D::Error::custom)?;
+            let len = bytes.len();
+            bytes
+                .try_into()
+                .map_err(|_| D::Error::custom(format!("expected {} bytes, got {}", N, len)))
+        } else {
+            <[Same; N]>::deserialize_as(deserializer)
+        }
+    }
+}
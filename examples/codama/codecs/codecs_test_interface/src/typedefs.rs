@@ -0,0 +1,25 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccountKey {
+    Uninitialized,
+    Config,
+    Vault,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    Noop,
+    Transfer {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+        )]
+        to: Pubkey,
+        amount: u64,
+    },
+    Memo(String),
+}
+pub type ShortVec = PrefixedVec<ShortU16, u8>;
@@ -0,0 +1,155 @@
+use borsh::BorshDeserialize;
+use solana_program::{instruction::AccountMeta, pubkey, pubkey::Pubkey};
+use spl_token_interface::*;
+
+const MINT: Pubkey = pubkey!("8qbHbw2BbbTHBW1sbeqakYXVKRQM8Ne7pLK7m6CVfeR");
+const AUTHORITY: Pubkey = pubkey!("4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi");
+const SOURCE: Pubkey = pubkey!("4rLtKGqsrPZzMgSw8mhD4G8sSqRyjWDSqrDD3aHL2VfX");
+const DESTINATION: Pubkey = pubkey!("GB1fFixz3RyBVZA1y6GHzNTYEGhfVPxgZyEqtc2zrnbu");
+
+#[test]
+fn test_transfer_ix() {
+    let amount: u64 = 1_000_000_007;
+    let ix = transfer_ix(
+        TransferKeys {
+            source: SOURCE,
+            destination: DESTINATION,
+            authority: AUTHORITY,
+        },
+        TransferIxArgs { amount },
+    )
+    .unwrap();
+    assert_eq!(ix.program_id, ID);
+    let mut expected_data = vec![3];
+    expected_data.extend_from_slice(&amount.to_le_bytes());
+    assert_eq!(ix.data, expected_data);
+    assert_eq!(
+        ix.accounts,
+        vec![
+            AccountMeta::new(SOURCE, false),
+            AccountMeta::new(DESTINATION, false),
+            // authority is an `"either"` signer in the IDL, which is generated as a non-signer
+            AccountMeta::new_readonly(AUTHORITY, false),
+        ]
+    );
+    assert_eq!(
+        TransferIxData::deserialize(&ix.data).unwrap(),
+        TransferIxData(TransferIxArgs { amount })
+    );
+    let built = TransferIxBuilder::new()
+        .source(SOURCE)
+        .destination(DESTINATION)
+        .authority(AUTHORITY)
+        .amount(amount)
+        .build()
+        .unwrap();
+    assert_eq!(built, ix);
+}
+
+#[test]
+fn test_transfer_ix_data_wrong_discm() {
+    let mut data = vec![4];
+    data.extend_from_slice(&1u64.to_le_bytes());
+    assert!(TransferIxData::deserialize(&data).is_err());
+}
+
+#[test]
+fn test_initialize_mint_ix_data() {
+    let args = InitializeMintIxArgs {
+        decimals: 9,
+        mint_authority: AUTHORITY,
+        freeze_authority: None,
+    };
+    let mut expected = vec![0, 9];
+    expected.extend_from_slice(AUTHORITY.as_ref());
+    expected.push(0);
+    let data = InitializeMintIxData(args.clone()).try_to_vec().unwrap();
+    assert_eq!(data, expected);
+    assert_eq!(
+        InitializeMintIxData::deserialize(&data).unwrap(),
+        InitializeMintIxData(args)
+    );
+
+    let args = InitializeMintIxArgs {
+        decimals: 6,
+        mint_authority: AUTHORITY,
+        freeze_authority: Some(MINT),
+    };
+    let mut expected = vec![0, 6];
+    expected.extend_from_slice(AUTHORITY.as_ref());
+    expected.push(1);
+    expected.extend_from_slice(MINT.as_ref());
+    let data = InitializeMintIxData(args.clone()).try_to_vec().unwrap();
+    assert_eq!(data, expected);
+    assert_eq!(
+        InitializeMintIxData::deserialize(&data).unwrap(),
+        InitializeMintIxData(args)
+    );
+}
+
+/// spl-token packs `COption<T>` as a u32 tag followed by `T`, zeroed if None
+fn c_option(val: Option<&[u8]>, len: usize) -> Vec<u8> {
+    match val {
+        None => vec![0; 4 + len],
+        Some(v) => [&[1, 0, 0, 0], v].concat(),
+    }
+}
+
+#[test]
+fn test_mint_account() {
+    let mut data = c_option(Some(AUTHORITY.as_ref()), 32);
+    data.extend_from_slice(&21_000_000u64.to_le_bytes());
+    data.extend_from_slice(&[9, 1]);
+    data.extend_from_slice(&c_option(None, 32));
+    assert_eq!(data.len(), 82);
+
+    let mint = Mint::try_from_slice(&data).unwrap();
+    assert_eq!(
+        mint,
+        Mint {
+            mint_authority: Some(AUTHORITY).into(),
+            supply: 21_000_000,
+            decimals: 9,
+            is_initialized: true,
+            freeze_authority: None.into(),
+        }
+    );
+    assert_eq!(borsh::to_vec(&mint).unwrap(), data);
+}
+
+#[test]
+fn test_token_account() {
+    let mut data = MINT.to_bytes().to_vec();
+    data.extend_from_slice(AUTHORITY.as_ref());
+    data.extend_from_slice(&500u64.to_le_bytes());
+    data.extend_from_slice(&c_option(Some(SOURCE.as_ref()), 32));
+    data.push(2);
+    data.extend_from_slice(&c_option(Some(&2_039_280u64.to_le_bytes()), 8));
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(&c_option(None, 32));
+    assert_eq!(data.len(), 165);
+
+    let token = Token::try_from_slice(&data).unwrap();
+    assert_eq!(
+        token,
+        Token {
+            mint: MINT,
+            owner: AUTHORITY,
+            amount: 500,
+            delegate: Some(SOURCE).into(),
+            state: AccountState::Frozen,
+            is_native: Some(2_039_280).into(),
+            delegated_amount: 100,
+            close_authority: None.into(),
+        }
+    );
+    assert_eq!(borsh::to_vec(&token).unwrap(), data);
+}
+
+#[test]
+fn test_c_option_invalid_tag() {
+    let mut data = c_option(Some(AUTHORITY.as_ref()), 32);
+    data[0] = 2;
+    data.extend_from_slice(&[0; 46]);
+    assert!(Mint::try_from_slice(&data).is_err());
+}
@@ -0,0 +1,52 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Mint {
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<[u8; 32]>"))]
+    /// Optional authority used to mint new tokens.
+    pub mint_authority: FixedOption<u32, Pubkey>,
+    /// Total supply of tokens.
+    pub supply: u64,
+    /// Number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// Is `true` if this structure has been initialized.
+    pub is_initialized: bool,
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<[u8; 32]>"))]
+    /// Optional authority to freeze token accounts.
+    pub freeze_authority: FixedOption<u32, Pubkey>,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Token {
+    #[cfg_attr(feature = "schemars", schemars(with = "[u8; 32]"))]
+    /// The mint associated with this account.
+    pub mint: Pubkey,
+    #[cfg_attr(feature = "schemars", schemars(with = "[u8; 32]"))]
+    /// The owner of this account.
+    pub owner: Pubkey,
+    /// The amount of tokens this account holds.
+    pub amount: u64,
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<[u8; 32]>"))]
+    pub delegate: FixedOption<u32, Pubkey>,
+    pub state: AccountState,
+    pub is_native: FixedOption<u32, u64>,
+    pub delegated_amount: u64,
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<[u8; 32]>"))]
+    pub close_authority: FixedOption<u32, Pubkey>,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Multisig {
+    /// Number of signers required.
+    pub m: u8,
+    /// Number of valid signers.
+    pub n: u8,
+    pub is_initialized: bool,
+    #[cfg_attr(feature = "schemars", schemars(with = "[[u8; 32]; 11]"))]
+    pub signers: [Pubkey; 11],
+}
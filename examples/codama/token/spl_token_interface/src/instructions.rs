@@ -0,0 +1,2103 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum SplTokenProgramIx {
+    InitializeMint(InitializeMintIxArgs),
+    InitializeAccount,
+    Transfer(TransferIxArgs),
+    SetAuthority(SetAuthorityIxArgs),
+    MintTo(MintToIxArgs),
+    CloseAccount,
+    SyncNative,
+}
+impl SplTokenProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            INITIALIZE_MINT_IX_DISCM => Ok(Self::InitializeMint(
+                InitializeMintIxArgs::deserialize(&mut reader)?,
+            )),
+            INITIALIZE_ACCOUNT_IX_DISCM => Ok(Self::InitializeAccount),
+            TRANSFER_IX_DISCM => Ok(Self::Transfer(TransferIxArgs::deserialize(&mut reader)?)),
+            SET_AUTHORITY_IX_DISCM => Ok(Self::SetAuthority(SetAuthorityIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            MINT_TO_IX_DISCM => Ok(Self::MintTo(MintToIxArgs::deserialize(&mut reader)?)),
+            CLOSE_ACCOUNT_IX_DISCM => Ok(Self::CloseAccount),
+            SYNC_NATIVE_IX_DISCM => Ok(Self::SyncNative),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::InitializeMint(args) => {
+                writer.write_all(&INITIALIZE_MINT_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::InitializeAccount => writer.write_all(&INITIALIZE_ACCOUNT_IX_DISCM),
+            Self::Transfer(args) => {
+                writer.write_all(&TRANSFER_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SetAuthority(args) => {
+                writer.write_all(&SET_AUTHORITY_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::MintTo(args) => {
+                writer.write_all(&MINT_TO_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::CloseAccount => writer.write_all(&CLOSE_ACCOUNT_IX_DISCM),
+            Self::SyncNative => writer.write_all(&SYNC_NATIVE_IX_DISCM),
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const INITIALIZE_MINT_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeMintAccounts<'me, 'info> {
+    /// Token mint account.
+    pub mint: &'me AccountInfo<'info>,
+    /// Rent sysvar.
+    pub rent: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InitializeMintKeys {
+    /// Token mint account.
+    pub mint: Pubkey,
+    /// Rent sysvar.
+    pub rent: Pubkey,
+}
+impl From<InitializeMintAccounts<'_, '_>> for InitializeMintKeys {
+    fn from(accounts: InitializeMintAccounts) -> Self {
+        Self {
+            mint: *accounts.mint.key,
+            rent: *accounts.rent.key,
+        }
+    }
+}
+impl From<InitializeMintKeys> for [AccountMeta; INITIALIZE_MINT_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitializeMintKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INITIALIZE_MINT_IX_ACCOUNTS_LEN]> for InitializeMintKeys {
+    fn from(pubkeys: [Pubkey; INITIALIZE_MINT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            mint: pubkeys[0],
+            rent: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<InitializeMintAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INITIALIZE_MINT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitializeMintAccounts<'_, 'info>) -> Self {
+        [accounts.mint.clone(), accounts.rent.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_MINT_IX_ACCOUNTS_LEN]>
+    for InitializeMintAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_MINT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            mint: &arr[0],
+            rent: &arr[1],
+        }
+    }
+}
+impl InitializeMintKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_MINT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_MINT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INITIALIZE_MINT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeMintKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeMintAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_MINT_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_MINT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INITIALIZE_MINT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeMintAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const INITIALIZE_MINT_IX_DISCM: [u8; 1] = [0];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct InitializeMintIxArgs {
+    pub decimals: u8,
+    #[cfg_attr(feature = "schemars", schemars(with = "[u8; 32]"))]
+    pub mint_authority: Pubkey,
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<[u8; 32]>"))]
+    pub freeze_authority: Option<Pubkey>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializeMintIxData(pub InitializeMintIxArgs);
+impl From<InitializeMintIxArgs> for InitializeMintIxData {
+    fn from(args: InitializeMintIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitializeMintIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INITIALIZE_MINT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INITIALIZE_MINT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(InitializeMintIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INITIALIZE_MINT_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Initializes a new mint and optionally deposits all the newly minted tokens in an account.
+pub fn initialize_mint_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializeMintKeys,
+    args: InitializeMintIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INITIALIZE_MINT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitializeMintIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+/// Initializes a new mint and optionally deposits all the newly minted tokens in an account.
+pub fn initialize_mint_ix(
+    keys: InitializeMintKeys,
+    args: InitializeMintIxArgs,
+) -> std::io::Result<Instruction> {
+    initialize_mint_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`initialize_mint_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeMintIxBuilder {
+    mint: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    decimals: Option<u8>,
+    mint_authority: Option<Pubkey>,
+    freeze_authority: Option<Option<Pubkey>>,
+}
+impl Default for InitializeMintIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeMintIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            mint: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            decimals: None,
+            mint_authority: None,
+            freeze_authority: None,
+        }
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn decimals(mut self, decimals: u8) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+    pub fn mint_authority(mut self, mint_authority: Pubkey) -> Self {
+        self.mint_authority = Some(mint_authority);
+        self
+    }
+    pub fn freeze_authority(mut self, freeze_authority: Option<Pubkey>) -> Self {
+        self.freeze_authority = Some(freeze_authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeMintIxBuilder is missing {}", field),
+            )
+        };
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = InitializeMintIxArgs {
+            decimals: self.decimals.ok_or_else(|| missing("decimals"))?,
+            mint_authority: self
+                .mint_authority
+                .ok_or_else(|| missing("mint_authority"))?,
+            freeze_authority: self
+                .freeze_authority
+                .ok_or_else(|| missing("freeze_authority"))?,
+        };
+        let keys = InitializeMintKeys { mint, rent };
+        initialize_mint_ix(keys, args)
+    }
+}
+pub fn initialize_mint_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeMintAccounts<'_, '_>,
+    args: InitializeMintIxArgs,
+) -> ProgramResult {
+    let keys: InitializeMintKeys = accounts.into();
+    let ix = initialize_mint_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_mint_invoke(
+    accounts: InitializeMintAccounts<'_, '_>,
+    args: InitializeMintIxArgs,
+) -> ProgramResult {
+    initialize_mint_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn initialize_mint_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeMintAccounts<'_, '_>,
+    args: InitializeMintIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializeMintKeys = accounts.into();
+    let ix = initialize_mint_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_mint_invoke_signed(
+    accounts: InitializeMintAccounts<'_, '_>,
+    args: InitializeMintIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_mint_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn initialize_mint_verify_account_keys(
+    accounts: InitializeMintAccounts<'_, '_>,
+    keys: InitializeMintKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.mint.key, keys.mint),
+        (*accounts.rent.key, keys.rent),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_mint_verify_writable_privileges<'me, 'info>(
+    accounts: InitializeMintAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.mint] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_mint_verify_account_privileges<'me, 'info>(
+    accounts: InitializeMintAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_mint_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeAccountAccounts<'me, 'info> {
+    pub account: &'me AccountInfo<'info>,
+    pub mint: &'me AccountInfo<'info>,
+    pub owner: &'me AccountInfo<'info>,
+    /// Rent sysvar.
+    pub rent: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InitializeAccountKeys {
+    pub account: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    /// Rent sysvar.
+    pub rent: Pubkey,
+}
+impl From<InitializeAccountAccounts<'_, '_>> for InitializeAccountKeys {
+    fn from(accounts: InitializeAccountAccounts) -> Self {
+        Self {
+            account: *accounts.account.key,
+            mint: *accounts.mint.key,
+            owner: *accounts.owner.key,
+            rent: *accounts.rent.key,
+        }
+    }
+}
+impl From<InitializeAccountKeys> for [AccountMeta; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitializeAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.owner,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN]> for InitializeAccountKeys {
+    fn from(pubkeys: [Pubkey; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            account: pubkeys[0],
+            mint: pubkeys[1],
+            owner: pubkeys[2],
+            rent: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<InitializeAccountAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitializeAccountAccounts<'_, 'info>) -> Self {
+        [
+            accounts.account.clone(),
+            accounts.mint.clone(),
+            accounts.owner.clone(),
+            accounts.rent.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN]>
+    for InitializeAccountAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            account: &arr[0],
+            mint: &arr[1],
+            owner: &arr[2],
+            rent: &arr[3],
+        }
+    }
+}
+impl InitializeAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const INITIALIZE_ACCOUNT_IX_DISCM: [u8; 1] = [1];
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializeAccountIxData;
+impl InitializeAccountIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INITIALIZE_ACCOUNT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INITIALIZE_ACCOUNT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INITIALIZE_ACCOUNT_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn initialize_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializeAccountKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INITIALIZE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: InitializeAccountIxData.try_to_vec()?,
+    })
+}
+pub fn initialize_account_ix(keys: InitializeAccountKeys) -> std::io::Result<Instruction> {
+    initialize_account_ix_with_program_id(crate::ID, keys)
+}
+/// Builds a [`initialize_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeAccountIxBuilder {
+    account: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    owner: Option<Pubkey>,
+    rent: Option<Pubkey>,
+}
+impl Default for InitializeAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeAccountIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            account: None,
+            mint: None,
+            owner: None,
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+        }
+    }
+    pub fn account(mut self, account: Pubkey) -> Self {
+        self.account = Some(account);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeAccountIxBuilder is missing {}", field),
+            )
+        };
+        let account = self.account.ok_or_else(|| missing("account"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let owner = self.owner.ok_or_else(|| missing("owner"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let keys = InitializeAccountKeys {
+            account,
+            mint,
+            owner,
+            rent,
+        };
+        initialize_account_ix(keys)
+    }
+}
+pub fn initialize_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeAccountAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: InitializeAccountKeys = accounts.into();
+    let ix = initialize_account_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_account_invoke(accounts: InitializeAccountAccounts<'_, '_>) -> ProgramResult {
+    initialize_account_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn initialize_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeAccountAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializeAccountKeys = accounts.into();
+    let ix = initialize_account_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_account_invoke_signed(
+    accounts: InitializeAccountAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_account_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn initialize_account_verify_account_keys(
+    accounts: InitializeAccountAccounts<'_, '_>,
+    keys: InitializeAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.account.key, keys.account),
+        (*accounts.mint.key, keys.mint),
+        (*accounts.owner.key, keys.owner),
+        (*accounts.rent.key, keys.rent),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_account_verify_writable_privileges<'me, 'info>(
+    accounts: InitializeAccountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.account] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_account_verify_account_privileges<'me, 'info>(
+    accounts: InitializeAccountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_account_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const TRANSFER_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct TransferAccounts<'me, 'info> {
+    pub source: &'me AccountInfo<'info>,
+    pub destination: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TransferKeys {
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+}
+impl From<TransferAccounts<'_, '_>> for TransferKeys {
+    fn from(accounts: TransferAccounts) -> Self {
+        Self {
+            source: *accounts.source.key,
+            destination: *accounts.destination.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<TransferKeys> for [AccountMeta; TRANSFER_IX_ACCOUNTS_LEN] {
+    fn from(keys: TransferKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.source,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.destination,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; TRANSFER_IX_ACCOUNTS_LEN]> for TransferKeys {
+    fn from(pubkeys: [Pubkey; TRANSFER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            source: pubkeys[0],
+            destination: pubkeys[1],
+            authority: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<TransferAccounts<'_, 'info>> for [AccountInfo<'info>; TRANSFER_IX_ACCOUNTS_LEN] {
+    fn from(accounts: TransferAccounts<'_, 'info>) -> Self {
+        [
+            accounts.source.clone(),
+            accounts.destination.clone(),
+            accounts.authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; TRANSFER_IX_ACCOUNTS_LEN]>
+    for TransferAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; TRANSFER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            source: &arr[0],
+            destination: &arr[1],
+            authority: &arr[2],
+        }
+    }
+}
+impl TransferKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; TRANSFER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..TRANSFER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[TRANSFER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for TransferKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> TransferAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; TRANSFER_IX_ACCOUNTS_LEN] = accounts
+            .get(..TRANSFER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[TRANSFER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for TransferAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const TRANSFER_IX_DISCM: [u8; 1] = [3];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TransferIxArgs {
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferIxData(pub TransferIxArgs);
+impl From<TransferIxArgs> for TransferIxData {
+    fn from(args: TransferIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl TransferIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != TRANSFER_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    TRANSFER_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(TransferIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&TRANSFER_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn transfer_ix_with_program_id(
+    program_id: Pubkey,
+    keys: TransferKeys,
+    args: TransferIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; TRANSFER_IX_ACCOUNTS_LEN] = keys.into();
+    let data: TransferIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn transfer_ix(keys: TransferKeys, args: TransferIxArgs) -> std::io::Result<Instruction> {
+    transfer_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`transfer_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct TransferIxBuilder {
+    source: Option<Pubkey>,
+    destination: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    amount: Option<u64>,
+}
+impl Default for TransferIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl TransferIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            source: None,
+            destination: None,
+            authority: None,
+            amount: None,
+        }
+    }
+    pub fn source(mut self, source: Pubkey) -> Self {
+        self.source = Some(source);
+        self
+    }
+    pub fn destination(mut self, destination: Pubkey) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("TransferIxBuilder is missing {}", field),
+            )
+        };
+        let source = self.source.ok_or_else(|| missing("source"))?;
+        let destination = self.destination.ok_or_else(|| missing("destination"))?;
+        let authority = self.authority.ok_or_else(|| missing("authority"))?;
+        let args = TransferIxArgs {
+            amount: self.amount.ok_or_else(|| missing("amount"))?,
+        };
+        let keys = TransferKeys {
+            source,
+            destination,
+            authority,
+        };
+        transfer_ix(keys, args)
+    }
+}
+pub fn transfer_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: TransferAccounts<'_, '_>,
+    args: TransferIxArgs,
+) -> ProgramResult {
+    let keys: TransferKeys = accounts.into();
+    let ix = transfer_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn transfer_invoke(accounts: TransferAccounts<'_, '_>, args: TransferIxArgs) -> ProgramResult {
+    transfer_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn transfer_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: TransferAccounts<'_, '_>,
+    args: TransferIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: TransferKeys = accounts.into();
+    let ix = transfer_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn transfer_invoke_signed(
+    accounts: TransferAccounts<'_, '_>,
+    args: TransferIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    transfer_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn transfer_verify_account_keys(
+    accounts: TransferAccounts<'_, '_>,
+    keys: TransferKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.source.key, keys.source),
+        (*accounts.destination.key, keys.destination),
+        (*accounts.authority.key, keys.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn transfer_verify_writable_privileges<'me, 'info>(
+    accounts: TransferAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.source, accounts.destination] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn transfer_verify_account_privileges<'me, 'info>(
+    accounts: TransferAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    transfer_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_AUTHORITY_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SetAuthorityAccounts<'me, 'info> {
+    pub owned: &'me AccountInfo<'info>,
+    pub owner: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetAuthorityKeys {
+    pub owned: Pubkey,
+    pub owner: Pubkey,
+}
+impl From<SetAuthorityAccounts<'_, '_>> for SetAuthorityKeys {
+    fn from(accounts: SetAuthorityAccounts) -> Self {
+        Self {
+            owned: *accounts.owned.key,
+            owner: *accounts.owner.key,
+        }
+    }
+}
+impl From<SetAuthorityKeys> for [AccountMeta; SET_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetAuthorityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.owned,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.owner,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_AUTHORITY_IX_ACCOUNTS_LEN]> for SetAuthorityKeys {
+    fn from(pubkeys: [Pubkey; SET_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            owned: pubkeys[0],
+            owner: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SetAuthorityAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetAuthorityAccounts<'_, 'info>) -> Self {
+        [accounts.owned.clone(), accounts.owner.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_AUTHORITY_IX_ACCOUNTS_LEN]>
+    for SetAuthorityAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            owned: &arr[0],
+            owner: &arr[1],
+        }
+    }
+}
+impl SetAuthorityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SET_AUTHORITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SET_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SET_AUTHORITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SetAuthorityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SetAuthorityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SET_AUTHORITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..SET_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SET_AUTHORITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SetAuthorityAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const SET_AUTHORITY_IX_DISCM: [u8; 1] = [6];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SetAuthorityIxArgs {
+    pub authority_type: AuthorityType,
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<[u8; 32]>"))]
+    pub new_authority: Option<Pubkey>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetAuthorityIxData(pub SetAuthorityIxArgs);
+impl From<SetAuthorityIxArgs> for SetAuthorityIxData {
+    fn from(args: SetAuthorityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetAuthorityIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_AUTHORITY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_AUTHORITY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetAuthorityIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_AUTHORITY_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_authority_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetAuthorityKeys,
+    args: SetAuthorityIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetAuthorityIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_authority_ix(
+    keys: SetAuthorityKeys,
+    args: SetAuthorityIxArgs,
+) -> std::io::Result<Instruction> {
+    set_authority_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`set_authority_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SetAuthorityIxBuilder {
+    owned: Option<Pubkey>,
+    owner: Option<Pubkey>,
+    authority_type: Option<AuthorityType>,
+    new_authority: Option<Option<Pubkey>>,
+}
+impl Default for SetAuthorityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SetAuthorityIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            owned: None,
+            owner: None,
+            authority_type: None,
+            new_authority: None,
+        }
+    }
+    pub fn owned(mut self, owned: Pubkey) -> Self {
+        self.owned = Some(owned);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn authority_type(mut self, authority_type: AuthorityType) -> Self {
+        self.authority_type = Some(authority_type);
+        self
+    }
+    pub fn new_authority(mut self, new_authority: Option<Pubkey>) -> Self {
+        self.new_authority = Some(new_authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SetAuthorityIxBuilder is missing {}", field),
+            )
+        };
+        let owned = self.owned.ok_or_else(|| missing("owned"))?;
+        let owner = self.owner.ok_or_else(|| missing("owner"))?;
+        let args = SetAuthorityIxArgs {
+            authority_type: self
+                .authority_type
+                .ok_or_else(|| missing("authority_type"))?,
+            new_authority: self.new_authority.ok_or_else(|| missing("new_authority"))?,
+        };
+        let keys = SetAuthorityKeys { owned, owner };
+        set_authority_ix(keys, args)
+    }
+}
+pub fn set_authority_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetAuthorityAccounts<'_, '_>,
+    args: SetAuthorityIxArgs,
+) -> ProgramResult {
+    let keys: SetAuthorityKeys = accounts.into();
+    let ix = set_authority_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_authority_invoke(
+    accounts: SetAuthorityAccounts<'_, '_>,
+    args: SetAuthorityIxArgs,
+) -> ProgramResult {
+    set_authority_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_authority_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetAuthorityAccounts<'_, '_>,
+    args: SetAuthorityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetAuthorityKeys = accounts.into();
+    let ix = set_authority_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_authority_invoke_signed(
+    accounts: SetAuthorityAccounts<'_, '_>,
+    args: SetAuthorityIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_authority_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_authority_verify_account_keys(
+    accounts: SetAuthorityAccounts<'_, '_>,
+    keys: SetAuthorityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.owned.key, keys.owned),
+        (*accounts.owner.key, keys.owner),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_authority_verify_writable_privileges<'me, 'info>(
+    accounts: SetAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.owned] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_authority_verify_account_privileges<'me, 'info>(
+    accounts: SetAuthorityAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_authority_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const MINT_TO_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct MintToAccounts<'me, 'info> {
+    pub mint: &'me AccountInfo<'info>,
+    pub token: &'me AccountInfo<'info>,
+    pub mint_authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MintToKeys {
+    pub mint: Pubkey,
+    pub token: Pubkey,
+    pub mint_authority: Pubkey,
+}
+impl From<MintToAccounts<'_, '_>> for MintToKeys {
+    fn from(accounts: MintToAccounts) -> Self {
+        Self {
+            mint: *accounts.mint.key,
+            token: *accounts.token.key,
+            mint_authority: *accounts.mint_authority.key,
+        }
+    }
+}
+impl From<MintToKeys> for [AccountMeta; MINT_TO_IX_ACCOUNTS_LEN] {
+    fn from(keys: MintToKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.token,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; MINT_TO_IX_ACCOUNTS_LEN]> for MintToKeys {
+    fn from(pubkeys: [Pubkey; MINT_TO_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            mint: pubkeys[0],
+            token: pubkeys[1],
+            mint_authority: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<MintToAccounts<'_, 'info>> for [AccountInfo<'info>; MINT_TO_IX_ACCOUNTS_LEN] {
+    fn from(accounts: MintToAccounts<'_, 'info>) -> Self {
+        [
+            accounts.mint.clone(),
+            accounts.token.clone(),
+            accounts.mint_authority.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; MINT_TO_IX_ACCOUNTS_LEN]>
+    for MintToAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; MINT_TO_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            mint: &arr[0],
+            token: &arr[1],
+            mint_authority: &arr[2],
+        }
+    }
+}
+impl MintToKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; MINT_TO_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..MINT_TO_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[MINT_TO_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for MintToKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> MintToAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; MINT_TO_IX_ACCOUNTS_LEN] = accounts
+            .get(..MINT_TO_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[MINT_TO_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for MintToAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const MINT_TO_IX_DISCM: [u8; 1] = [7];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MintToIxArgs {
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct MintToIxData(pub MintToIxArgs);
+impl From<MintToIxArgs> for MintToIxData {
+    fn from(args: MintToIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl MintToIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != MINT_TO_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    MINT_TO_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(MintToIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&MINT_TO_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn mint_to_ix_with_program_id(
+    program_id: Pubkey,
+    keys: MintToKeys,
+    args: MintToIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; MINT_TO_IX_ACCOUNTS_LEN] = keys.into();
+    let data: MintToIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn mint_to_ix(keys: MintToKeys, args: MintToIxArgs) -> std::io::Result<Instruction> {
+    mint_to_ix_with_program_id(crate::ID, keys, args)
+}
+/// Builds a [`mint_to_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct MintToIxBuilder {
+    mint: Option<Pubkey>,
+    token: Option<Pubkey>,
+    mint_authority: Option<Pubkey>,
+    amount: Option<u64>,
+}
+impl Default for MintToIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl MintToIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            mint: None,
+            token: None,
+            mint_authority: None,
+            amount: None,
+        }
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn token(mut self, token: Pubkey) -> Self {
+        self.token = Some(token);
+        self
+    }
+    pub fn mint_authority(mut self, mint_authority: Pubkey) -> Self {
+        self.mint_authority = Some(mint_authority);
+        self
+    }
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("MintToIxBuilder is missing {}", field),
+            )
+        };
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let token = self.token.ok_or_else(|| missing("token"))?;
+        let mint_authority = self
+            .mint_authority
+            .ok_or_else(|| missing("mint_authority"))?;
+        let args = MintToIxArgs {
+            amount: self.amount.ok_or_else(|| missing("amount"))?,
+        };
+        let keys = MintToKeys {
+            mint,
+            token,
+            mint_authority,
+        };
+        mint_to_ix(keys, args)
+    }
+}
+pub fn mint_to_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: MintToAccounts<'_, '_>,
+    args: MintToIxArgs,
+) -> ProgramResult {
+    let keys: MintToKeys = accounts.into();
+    let ix = mint_to_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn mint_to_invoke(accounts: MintToAccounts<'_, '_>, args: MintToIxArgs) -> ProgramResult {
+    mint_to_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn mint_to_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: MintToAccounts<'_, '_>,
+    args: MintToIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: MintToKeys = accounts.into();
+    let ix = mint_to_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn mint_to_invoke_signed(
+    accounts: MintToAccounts<'_, '_>,
+    args: MintToIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    mint_to_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn mint_to_verify_account_keys(
+    accounts: MintToAccounts<'_, '_>,
+    keys: MintToKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.mint.key, keys.mint),
+        (*accounts.token.key, keys.token),
+        (*accounts.mint_authority.key, keys.mint_authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn mint_to_verify_writable_privileges<'me, 'info>(
+    accounts: MintToAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.mint, accounts.token] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn mint_to_verify_account_privileges<'me, 'info>(
+    accounts: MintToAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    mint_to_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const CLOSE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct CloseAccountAccounts<'me, 'info> {
+    pub account: &'me AccountInfo<'info>,
+    pub destination: &'me AccountInfo<'info>,
+    pub owner: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CloseAccountKeys {
+    pub account: Pubkey,
+    pub destination: Pubkey,
+    pub owner: Pubkey,
+}
+impl From<CloseAccountAccounts<'_, '_>> for CloseAccountKeys {
+    fn from(accounts: CloseAccountAccounts) -> Self {
+        Self {
+            account: *accounts.account.key,
+            destination: *accounts.destination.key,
+            owner: *accounts.owner.key,
+        }
+    }
+}
+impl From<CloseAccountKeys> for [AccountMeta; CLOSE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: CloseAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.destination,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.owner,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CLOSE_ACCOUNT_IX_ACCOUNTS_LEN]> for CloseAccountKeys {
+    fn from(pubkeys: [Pubkey; CLOSE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            account: pubkeys[0],
+            destination: pubkeys[1],
+            owner: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<CloseAccountAccounts<'_, 'info>>
+    for [AccountInfo<'info>; CLOSE_ACCOUNT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: CloseAccountAccounts<'_, 'info>) -> Self {
+        [
+            accounts.account.clone(),
+            accounts.destination.clone(),
+            accounts.owner.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CLOSE_ACCOUNT_IX_ACCOUNTS_LEN]>
+    for CloseAccountAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; CLOSE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            account: &arr[0],
+            destination: &arr[1],
+            owner: &arr[2],
+        }
+    }
+}
+impl CloseAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CLOSE_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CLOSE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CLOSE_ACCOUNT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for CloseAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CloseAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CLOSE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..CLOSE_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CLOSE_ACCOUNT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CloseAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const CLOSE_ACCOUNT_IX_DISCM: [u8; 1] = [9];
+#[derive(Clone, Debug, PartialEq)]
+pub struct CloseAccountIxData;
+impl CloseAccountIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CLOSE_ACCOUNT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    CLOSE_ACCOUNT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CLOSE_ACCOUNT_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn close_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CloseAccountKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CLOSE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: CloseAccountIxData.try_to_vec()?,
+    })
+}
+pub fn close_account_ix(keys: CloseAccountKeys) -> std::io::Result<Instruction> {
+    close_account_ix_with_program_id(crate::ID, keys)
+}
+/// Builds a [`close_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CloseAccountIxBuilder {
+    account: Option<Pubkey>,
+    destination: Option<Pubkey>,
+    owner: Option<Pubkey>,
+}
+impl Default for CloseAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CloseAccountIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            account: None,
+            destination: None,
+            owner: None,
+        }
+    }
+    pub fn account(mut self, account: Pubkey) -> Self {
+        self.account = Some(account);
+        self
+    }
+    pub fn destination(mut self, destination: Pubkey) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CloseAccountIxBuilder is missing {}", field),
+            )
+        };
+        let account = self.account.ok_or_else(|| missing("account"))?;
+        let destination = self.destination.ok_or_else(|| missing("destination"))?;
+        let owner = self.owner.ok_or_else(|| missing("owner"))?;
+        let keys = CloseAccountKeys {
+            account,
+            destination,
+            owner,
+        };
+        close_account_ix(keys)
+    }
+}
+pub fn close_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CloseAccountAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: CloseAccountKeys = accounts.into();
+    let ix = close_account_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn close_account_invoke(accounts: CloseAccountAccounts<'_, '_>) -> ProgramResult {
+    close_account_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn close_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CloseAccountAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CloseAccountKeys = accounts.into();
+    let ix = close_account_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn close_account_invoke_signed(
+    accounts: CloseAccountAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    close_account_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn close_account_verify_account_keys(
+    accounts: CloseAccountAccounts<'_, '_>,
+    keys: CloseAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.account.key, keys.account),
+        (*accounts.destination.key, keys.destination),
+        (*accounts.owner.key, keys.owner),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn close_account_verify_writable_privileges<'me, 'info>(
+    accounts: CloseAccountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.account, accounts.destination] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn close_account_verify_account_privileges<'me, 'info>(
+    accounts: CloseAccountAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    close_account_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const SYNC_NATIVE_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone, Debug)]
+pub struct SyncNativeAccounts<'me, 'info> {
+    pub account: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SyncNativeKeys {
+    pub account: Pubkey,
+}
+impl From<SyncNativeAccounts<'_, '_>> for SyncNativeKeys {
+    fn from(accounts: SyncNativeAccounts) -> Self {
+        Self {
+            account: *accounts.account.key,
+        }
+    }
+}
+impl From<SyncNativeKeys> for [AccountMeta; SYNC_NATIVE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SyncNativeKeys) -> Self {
+        [AccountMeta {
+            pubkey: keys.account,
+            is_signer: false,
+            is_writable: true,
+        }]
+    }
+}
+impl From<[Pubkey; SYNC_NATIVE_IX_ACCOUNTS_LEN]> for SyncNativeKeys {
+    fn from(pubkeys: [Pubkey; SYNC_NATIVE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            account: pubkeys[0],
+        }
+    }
+}
+impl<'info> From<SyncNativeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SYNC_NATIVE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SyncNativeAccounts<'_, 'info>) -> Self {
+        [accounts.account.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SYNC_NATIVE_IX_ACCOUNTS_LEN]>
+    for SyncNativeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SYNC_NATIVE_IX_ACCOUNTS_LEN]) -> Self {
+        Self { account: &arr[0] }
+    }
+}
+impl SyncNativeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SYNC_NATIVE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SYNC_NATIVE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SYNC_NATIVE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SyncNativeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SyncNativeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SYNC_NATIVE_IX_ACCOUNTS_LEN] = accounts
+            .get(..SYNC_NATIVE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SYNC_NATIVE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SyncNativeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
+pub const SYNC_NATIVE_IX_DISCM: [u8; 1] = [17];
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyncNativeIxData;
+impl SyncNativeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SYNC_NATIVE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SYNC_NATIVE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SYNC_NATIVE_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn sync_native_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SyncNativeKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SYNC_NATIVE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: SyncNativeIxData.try_to_vec()?,
+    })
+}
+pub fn sync_native_ix(keys: SyncNativeKeys) -> std::io::Result<Instruction> {
+    sync_native_ix_with_program_id(crate::ID, keys)
+}
+/// Builds a [`sync_native_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SyncNativeIxBuilder {
+    account: Option<Pubkey>,
+}
+impl Default for SyncNativeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SyncNativeIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self { account: None }
+    }
+    pub fn account(mut self, account: Pubkey) -> Self {
+        self.account = Some(account);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SyncNativeIxBuilder is missing {}", field),
+            )
+        };
+        let account = self.account.ok_or_else(|| missing("account"))?;
+        let keys = SyncNativeKeys { account };
+        sync_native_ix(keys)
+    }
+}
+pub fn sync_native_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SyncNativeAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: SyncNativeKeys = accounts.into();
+    let ix = sync_native_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn sync_native_invoke(accounts: SyncNativeAccounts<'_, '_>) -> ProgramResult {
+    sync_native_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn sync_native_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SyncNativeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SyncNativeKeys = accounts.into();
+    let ix = sync_native_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn sync_native_invoke_signed(
+    accounts: SyncNativeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    sync_native_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn sync_native_verify_account_keys(
+    accounts: SyncNativeAccounts<'_, '_>,
+    keys: SyncNativeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [(*accounts.account.key, keys.account)] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn sync_native_verify_writable_privileges<'me, 'info>(
+    accounts: SyncNativeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.account] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn sync_native_verify_account_privileges<'me, 'info>(
+    accounts: SyncNativeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    sync_native_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+/// A [`SplTokenProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SplTokenParsedIx {
+    InitializeMint {
+        keys: InitializeMintKeys,
+        args: InitializeMintIxArgs,
+    },
+    InitializeAccount {
+        keys: InitializeAccountKeys,
+    },
+    Transfer {
+        keys: TransferKeys,
+        args: TransferIxArgs,
+    },
+    SetAuthority {
+        keys: SetAuthorityKeys,
+        args: SetAuthorityIxArgs,
+    },
+    MintTo {
+        keys: MintToKeys,
+        args: MintToIxArgs,
+    },
+    CloseAccount {
+        keys: CloseAccountKeys,
+    },
+    SyncNative {
+        keys: SyncNativeKeys,
+    },
+}
+impl SplTokenParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = SplTokenProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            SplTokenProgramIx::InitializeMint(args) => Self::InitializeMint {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SplTokenProgramIx::InitializeAccount => Self::InitializeAccount {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            SplTokenProgramIx::Transfer(args) => Self::Transfer {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SplTokenProgramIx::SetAuthority(args) => Self::SetAuthority {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SplTokenProgramIx::MintTo(args) => Self::MintTo {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            SplTokenProgramIx::CloseAccount => Self::CloseAccount {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            SplTokenProgramIx::SyncNative => Self::SyncNative {
+                keys: parsed_ix_keys(accounts)?,
+            },
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
@@ -0,0 +1,27 @@
+use solana_program::pubkey::{Pubkey, PubkeyError};
+pub fn find_associated_token_address(
+    owner: &Pubkey,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA1knL"),
+    )
+}
+pub fn create_associated_token_address(
+    owner: &Pubkey,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[
+            owner.as_ref(),
+            token_program.as_ref(),
+            mint.as_ref(),
+            &[bump],
+        ],
+        &solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA1knL"),
+    )
+}
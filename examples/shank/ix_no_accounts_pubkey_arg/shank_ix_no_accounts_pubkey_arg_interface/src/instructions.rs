@@ -96,6 +96,39 @@ pub fn no_accounts_pubkey_arg_ix_ix(
 ) -> std::io::Result<Instruction> {
     no_accounts_pubkey_arg_ix_ix_with_program_id(crate::ID, args)
 }
+/// Builds a [`no_accounts_pubkey_arg_ix_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct NoAccountsPubkeyArgIxIxBuilder {
+    arg: Option<Pubkey>,
+}
+impl Default for NoAccountsPubkeyArgIxIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl NoAccountsPubkeyArgIxIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self { arg: None }
+    }
+    pub fn arg(mut self, arg: Pubkey) -> Self {
+        self.arg = Some(arg);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("NoAccountsPubkeyArgIxIxBuilder is missing {}", field),
+            )
+        };
+        let args = NoAccountsPubkeyArgIxIxArgs {
+            arg: self.arg.ok_or_else(|| missing("arg"))?,
+        };
+        no_accounts_pubkey_arg_ix_ix(args)
+    }
+}
 pub fn no_accounts_pubkey_arg_ix_invoke_with_program_id(
     program_id: Pubkey,
     args: NoAccountsPubkeyArgIxIxArgs,
@@ -120,3 +153,44 @@ pub fn no_accounts_pubkey_arg_ix_invoke_signed(
 ) -> ProgramResult {
     no_accounts_pubkey_arg_ix_invoke_signed_with_program_id(crate::ID, args, seeds)
 }
+/// A [`ShankIxNoAccountsPubkeyArgProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShankIxNoAccountsPubkeyArgParsedIx {
+    NoAccountsPubkeyArgIx { args: NoAccountsPubkeyArgIxIxArgs },
+}
+impl ShankIxNoAccountsPubkeyArgParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], _accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = ShankIxNoAccountsPubkeyArgProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            ShankIxNoAccountsPubkeyArgProgramIx::NoAccountsPubkeyArgIx(args) => {
+                Self::NoAccountsPubkeyArgIx { args }
+            }
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
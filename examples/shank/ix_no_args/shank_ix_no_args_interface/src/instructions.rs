@@ -56,7 +56,7 @@ pub const NO_ARGS_IX_IX_ACCOUNTS_LEN: usize = 1;
 pub struct NoArgsIxAccounts<'me, 'info> {
     pub b: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct NoArgsIxKeys {
     pub b: Pubkey,
 }
@@ -91,6 +91,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; NO_ARGS_IX_IX_ACCOUNTS_LEN]>
         Self { b: &arr[0] }
     }
 }
+impl NoArgsIxKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; NO_ARGS_IX_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..NO_ARGS_IX_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[NO_ARGS_IX_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for NoArgsIxKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> NoArgsIxAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; NO_ARGS_IX_IX_ACCOUNTS_LEN] = accounts
+            .get(..NO_ARGS_IX_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[NO_ARGS_IX_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for NoArgsIxAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const NO_ARGS_IX_IX_DISCM: u8 = 69u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct NoArgsIxIxData;
@@ -134,6 +172,38 @@ pub fn no_args_ix_ix_with_program_id(
 pub fn no_args_ix_ix(keys: NoArgsIxKeys) -> std::io::Result<Instruction> {
     no_args_ix_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`no_args_ix_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct NoArgsIxIxBuilder {
+    b: Option<Pubkey>,
+}
+impl Default for NoArgsIxIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl NoArgsIxIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self { b: None }
+    }
+    pub fn b(mut self, b: Pubkey) -> Self {
+        self.b = Some(b);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("NoArgsIxIxBuilder is missing {}", field),
+            )
+        };
+        let b = self.b.ok_or_else(|| missing("b"))?;
+        let keys = NoArgsIxKeys { b };
+        no_args_ix_ix(keys)
+    }
+}
 pub fn no_args_ix_invoke_with_program_id(
     program_id: Pubkey,
     accounts: NoArgsIxAccounts<'_, '_>,
@@ -187,3 +257,56 @@ pub fn no_args_ix_verify_account_privileges<'me, 'info>(
     no_args_ix_verify_writable_privileges(accounts)?;
     Ok(())
 }
+/// A [`ShankIxNoArgsProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShankIxNoArgsParsedIx {
+    NoArgsIx { keys: NoArgsIxKeys },
+}
+impl ShankIxNoArgsParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = ShankIxNoArgsProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            ShankIxNoArgsProgramIx::NoArgsIx => Self::NoArgsIx {
+                keys: parsed_ix_keys(accounts)?,
+            },
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
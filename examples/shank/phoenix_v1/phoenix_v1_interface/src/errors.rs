@@ -1,61 +1,89 @@
 use solana_program::{
     decode_error::DecodeError,
+    instruction::InstructionError,
     msg,
     program_error::{PrintProgramError, ProgramError},
+    pubkey::Pubkey,
 };
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
 pub enum PhoenixV1Error {
+    /// Invalid market parameters error
     #[error("Invalid market parameters error")]
     InvalidMarketParameters = 0,
+    /// Invalid market authority error
     #[error("Invalid market authority error")]
     InvalidMarketAuthority = 1,
+    /// Market deserialization error
     #[error("Market deserialization error")]
     FailedToLoadMarketFromAccount = 2,
+    /// Market already initialized error
     #[error("Market already initialized error")]
     MarketAlreadyInitialized = 3,
+    /// Market is not initialized error
     #[error("Market is not initialized error")]
     MarketUninitialized = 4,
+    /// Invalid state transition error
     #[error("Invalid state transition error")]
     InvalidStateTransition = 5,
+    /// Invalid market signer error
     #[error("Invalid market signer error")]
     InvalidMarketSigner = 6,
+    /// Invalid lot size error
     #[error("Invalid lot size error")]
     InvalidLotSize = 7,
+    /// Invalid tick size error
     #[error("Invalid tick size error")]
     InvalidTickSize = 8,
+    /// Invalid mint error
     #[error("Invalid mint error")]
     InvalidMint = 9,
+    /// Invalid base vault error
     #[error("Invalid base vault error")]
     InvalidBaseVault = 10,
+    /// Invalid quote vault error
     #[error("Invalid quote vault error")]
     InvalidQuoteVault = 11,
+    /// Invalid base account error
     #[error("Invalid base account error")]
     InvalidBaseAccount = 12,
+    /// Invalid quote account error
     #[error("Invalid quote account error")]
     InvalidQuoteAccount = 13,
+    /// Too many events error
     #[error("Too many events error")]
     TooManyEvents = 14,
+    /// New order error
     #[error("New order error")]
     NewOrderError = 15,
+    /// Reduce order error
     #[error("Reduce order error")]
     ReduceOrderError = 16,
+    /// Cancel multiple orders error
     #[error("Cancel multiple orders error")]
     CancelMultipleOrdersError = 17,
+    /// Withdraw funds error
     #[error("Withdraw funds error")]
     WithdrawFundsError = 18,
+    /// Remove empty orders error
     #[error("Remove empty orders error")]
     RemoveEmptyOrdersError = 19,
+    /// Trader not found error
     #[error("Trader not found error")]
     TraderNotFound = 20,
+    /// Invalid seat status
     #[error("Invalid seat status")]
     InvalidSeatStatus = 21,
+    /// Failed to evict trader
     #[error("Failed to evict trader")]
     EvictionError = 22,
+    /// Non empty scratch buffer
     #[error("Non empty scratch buffer")]
     NonEmptyScratchBuffer = 23,
+    /// Failed to serialize event
     #[error("Failed to serialize event")]
     FailedToSerializeEvent = 24,
+    /// Failed to flush buffer
     #[error("Failed to flush buffer")]
     FailedToFlushBuffer = 25,
 }
@@ -81,3 +109,66 @@ impl PrintProgramError for PhoenixV1Error {
         msg!(&self.to_string());
     }
 }
+impl TryFrom<u32> for PhoenixV1Error {
+    type Error = u32;
+    /// Returns `code` back if it is not one of this program's errors
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        <Self as num_traits::FromPrimitive>::from_u32(code).ok_or(code)
+    }
+}
+impl<'a> TryFrom<&'a ProgramError> for PhoenixV1Error {
+    type Error = &'a ProgramError;
+    /// Returns `e` back if it is not a `ProgramError::Custom` of this program's errors
+    fn try_from(e: &'a ProgramError) -> Result<Self, Self::Error> {
+        match e {
+            ProgramError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl<'a> TryFrom<&'a InstructionError> for PhoenixV1Error {
+    type Error = &'a InstructionError;
+    /// Returns `e` back if it is not an `InstructionError::Custom` of this program's errors
+    fn try_from(e: &'a InstructionError) -> Result<Self, Self::Error> {
+        match e {
+            InstructionError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl PhoenixV1Error {
+    /// Parses a `custom program error: 0x...` log line
+    /// into the error, e.g. `Program <id> failed: custom program error: 0x1770`.
+    /// Returns `None` for other logs and codes that are not of this program's errors.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let code = if let Some((_, hex)) = log.split_once("custom program error: 0x") {
+            u32::from_str_radix(hex.trim_end(), 16).ok()?
+        } else {
+            return None;
+        };
+        Self::try_from(code).ok()
+    }
+}
+/// Parses the error the program failed with from a transaction's log messages.
+///
+/// Only the first `Program <id> failed: ` log is considered, since programs that
+/// invoked the failing program log the same error when failing in turn.
+/// Returns `None` if the failing program is not `program_id`
+/// or it did not fail with one of its errors.
+pub fn parse_error_from_logs_with_program_id(
+    program_id: Pubkey,
+    logs: &[String],
+) -> Option<PhoenixV1Error> {
+    let (program, failed_log) = logs.iter().find_map(|log| {
+        let mut words = log.strip_prefix("Program ")?.split(' ');
+        let program = words.next()?.parse::<Pubkey>().ok()?;
+        (words.next() == Some("failed:")).then_some((program, log))
+    })?;
+    if program != program_id {
+        return None;
+    }
+    PhoenixV1Error::from_log(failed_log)
+}
+pub fn parse_error_from_logs(logs: &[String]) -> Option<PhoenixV1Error> {
+    parse_error_from_logs_with_program_id(crate::ID, logs)
+}
@@ -236,42 +236,42 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
 pub const SWAP_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
 pub struct SwapAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: &'me AccountInfo<'info>,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: &'me AccountInfo<'info>,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct SwapKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: Pubkey,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: Pubkey,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<SwapAccounts<'_, '_>> for SwapKeys {
@@ -387,6 +387,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SWAP_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl SwapKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SWAP_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SWAP_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SWAP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SwapKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SwapAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SWAP_IX_ACCOUNTS_LEN] = accounts
+            .get(..SWAP_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SWAP_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SwapAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SWAP_IX_DISCM: u8 = 0u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -443,6 +481,119 @@ pub fn swap_ix_with_program_id(
 pub fn swap_ix(keys: SwapKeys, args: SwapIxArgs) -> std::io::Result<Instruction> {
     swap_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`swap_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SwapIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    base_account: Option<Pubkey>,
+    quote_account: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    order_packet: Option<OrderPacket>,
+}
+impl Default for SwapIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SwapIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            base_account: None,
+            quote_account: None,
+            base_vault: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            order_packet: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn base_account(mut self, base_account: Pubkey) -> Self {
+        self.base_account = Some(base_account);
+        self
+    }
+    pub fn quote_account(mut self, quote_account: Pubkey) -> Self {
+        self.quote_account = Some(quote_account);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn order_packet(mut self, order_packet: OrderPacket) -> Self {
+        self.order_packet = Some(order_packet);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SwapIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let base_account = self.base_account.ok_or_else(|| missing("base_account"))?;
+        let quote_account = self.quote_account.ok_or_else(|| missing("quote_account"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = SwapIxArgs {
+            order_packet: self.order_packet.ok_or_else(|| missing("order_packet"))?,
+        };
+        let keys = SwapKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            token_program,
+        };
+        swap_ix(keys, args)
+    }
+}
 pub fn swap_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SwapAccounts<'_, '_>,
@@ -529,22 +680,22 @@ pub fn swap_verify_account_privileges<'me, 'info>(
 pub const SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct SwapWithFreeFundsAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
     pub seat: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct SwapWithFreeFundsKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
     pub seat: Pubkey,
@@ -628,6 +779,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS
         }
     }
 }
+impl SwapWithFreeFundsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for SwapWithFreeFundsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SwapWithFreeFundsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = accounts
+            .get(..SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SwapWithFreeFundsAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SWAP_WITH_FREE_FUNDS_IX_DISCM: u8 = 1u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -687,6 +882,85 @@ pub fn swap_with_free_funds_ix(
 ) -> std::io::Result<Instruction> {
     swap_with_free_funds_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`swap_with_free_funds_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SwapWithFreeFundsIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    seat: Option<Pubkey>,
+    order_packet: Option<OrderPacket>,
+}
+impl Default for SwapWithFreeFundsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SwapWithFreeFundsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            seat: None,
+            order_packet: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn seat(mut self, seat: Pubkey) -> Self {
+        self.seat = Some(seat);
+        self
+    }
+    pub fn order_packet(mut self, order_packet: OrderPacket) -> Self {
+        self.order_packet = Some(order_packet);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SwapWithFreeFundsIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let seat = self.seat.ok_or_else(|| missing("seat"))?;
+        let args = SwapWithFreeFundsIxArgs {
+            order_packet: self.order_packet.ok_or_else(|| missing("order_packet"))?,
+        };
+        let keys = SwapWithFreeFundsKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            seat,
+        };
+        swap_with_free_funds_ix(keys, args)
+    }
+}
 pub fn swap_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SwapWithFreeFundsAccounts<'_, '_>,
@@ -766,44 +1040,44 @@ pub fn swap_with_free_funds_verify_account_privileges<'me, 'info>(
 pub const PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN: usize = 10;
 #[derive(Copy, Clone, Debug)]
 pub struct PlaceLimitOrderAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
     pub seat: &'me AccountInfo<'info>,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: &'me AccountInfo<'info>,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: &'me AccountInfo<'info>,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct PlaceLimitOrderKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
     pub seat: Pubkey,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: Pubkey,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: Pubkey,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<PlaceLimitOrderAccounts<'_, '_>> for PlaceLimitOrderKeys {
@@ -930,6 +1204,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_LIMIT_ORDER_IX_ACCOUNTS_LE
         }
     }
 }
+impl PlaceLimitOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for PlaceLimitOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PlaceLimitOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for PlaceLimitOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PLACE_LIMIT_ORDER_IX_DISCM: u8 = 2u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -989,6 +1301,127 @@ pub fn place_limit_order_ix(
 ) -> std::io::Result<Instruction> {
     place_limit_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`place_limit_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PlaceLimitOrderIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    seat: Option<Pubkey>,
+    base_account: Option<Pubkey>,
+    quote_account: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    order_packet: Option<OrderPacket>,
+}
+impl Default for PlaceLimitOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PlaceLimitOrderIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            seat: None,
+            base_account: None,
+            quote_account: None,
+            base_vault: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            order_packet: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn seat(mut self, seat: Pubkey) -> Self {
+        self.seat = Some(seat);
+        self
+    }
+    pub fn base_account(mut self, base_account: Pubkey) -> Self {
+        self.base_account = Some(base_account);
+        self
+    }
+    pub fn quote_account(mut self, quote_account: Pubkey) -> Self {
+        self.quote_account = Some(quote_account);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn order_packet(mut self, order_packet: OrderPacket) -> Self {
+        self.order_packet = Some(order_packet);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("PlaceLimitOrderIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let seat = self.seat.ok_or_else(|| missing("seat"))?;
+        let base_account = self.base_account.ok_or_else(|| missing("base_account"))?;
+        let quote_account = self.quote_account.ok_or_else(|| missing("quote_account"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = PlaceLimitOrderIxArgs {
+            order_packet: self.order_packet.ok_or_else(|| missing("order_packet"))?,
+        };
+        let keys = PlaceLimitOrderKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            seat,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            token_program,
+        };
+        place_limit_order_ix(keys, args)
+    }
+}
 pub fn place_limit_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceLimitOrderAccounts<'_, '_>,
@@ -1079,22 +1512,22 @@ pub fn place_limit_order_verify_account_privileges<'me, 'info>(
 pub const PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct PlaceLimitOrderWithFreeFundsAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
     pub seat: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct PlaceLimitOrderWithFreeFundsKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
     pub seat: Pubkey,
@@ -1184,6 +1617,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_LIMIT_ORDER_WITH_FREE_FUND
         }
     }
 }
+impl PlaceLimitOrderWithFreeFundsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for PlaceLimitOrderWithFreeFundsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PlaceLimitOrderWithFreeFundsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for PlaceLimitOrderWithFreeFundsAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_DISCM: u8 = 3u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1245,6 +1725,85 @@ pub fn place_limit_order_with_free_funds_ix(
 ) -> std::io::Result<Instruction> {
     place_limit_order_with_free_funds_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`place_limit_order_with_free_funds_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PlaceLimitOrderWithFreeFundsIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    seat: Option<Pubkey>,
+    order_packet: Option<OrderPacket>,
+}
+impl Default for PlaceLimitOrderWithFreeFundsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PlaceLimitOrderWithFreeFundsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            seat: None,
+            order_packet: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn seat(mut self, seat: Pubkey) -> Self {
+        self.seat = Some(seat);
+        self
+    }
+    pub fn order_packet(mut self, order_packet: OrderPacket) -> Self {
+        self.order_packet = Some(order_packet);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("PlaceLimitOrderWithFreeFundsIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let seat = self.seat.ok_or_else(|| missing("seat"))?;
+        let args = PlaceLimitOrderWithFreeFundsIxArgs {
+            order_packet: self.order_packet.ok_or_else(|| missing("order_packet"))?,
+        };
+        let keys = PlaceLimitOrderWithFreeFundsKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            seat,
+        };
+        place_limit_order_with_free_funds_ix(keys, args)
+    }
+}
 pub fn place_limit_order_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceLimitOrderWithFreeFundsAccounts<'_, '_>,
@@ -1329,42 +1888,42 @@ pub fn place_limit_order_with_free_funds_verify_account_privileges<'me, 'info>(
 pub const REDUCE_ORDER_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
 pub struct ReduceOrderAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: &'me AccountInfo<'info>,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: &'me AccountInfo<'info>,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ReduceOrderKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: Pubkey,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: Pubkey,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<ReduceOrderAccounts<'_, '_>> for ReduceOrderKeys {
@@ -1482,6 +2041,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REDUCE_ORDER_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl ReduceOrderKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REDUCE_ORDER_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REDUCE_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[REDUCE_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for ReduceOrderKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ReduceOrderAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REDUCE_ORDER_IX_ACCOUNTS_LEN] = accounts
+            .get(..REDUCE_ORDER_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[REDUCE_ORDER_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ReduceOrderAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REDUCE_ORDER_IX_DISCM: u8 = 4u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1541,6 +2138,119 @@ pub fn reduce_order_ix(
 ) -> std::io::Result<Instruction> {
     reduce_order_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`reduce_order_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ReduceOrderIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    base_account: Option<Pubkey>,
+    quote_account: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    params: Option<ReduceOrderParams>,
+}
+impl Default for ReduceOrderIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ReduceOrderIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            base_account: None,
+            quote_account: None,
+            base_vault: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            params: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn base_account(mut self, base_account: Pubkey) -> Self {
+        self.base_account = Some(base_account);
+        self
+    }
+    pub fn quote_account(mut self, quote_account: Pubkey) -> Self {
+        self.quote_account = Some(quote_account);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn params(mut self, params: ReduceOrderParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ReduceOrderIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let base_account = self.base_account.ok_or_else(|| missing("base_account"))?;
+        let quote_account = self.quote_account.ok_or_else(|| missing("quote_account"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = ReduceOrderIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let keys = ReduceOrderKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            token_program,
+        };
+        reduce_order_ix(keys, args)
+    }
+}
 pub fn reduce_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ReduceOrderAccounts<'_, '_>,
@@ -1630,21 +2340,21 @@ pub fn reduce_order_verify_account_privileges<'me, 'info>(
 pub const REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct ReduceOrderWithFreeFundsAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ReduceOrderWithFreeFundsKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
 }
@@ -1720,6 +2430,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REDUCE_ORDER_WITH_FREE_FUNDS_IX_
         }
     }
 }
+impl ReduceOrderWithFreeFundsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ReduceOrderWithFreeFundsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ReduceOrderWithFreeFundsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = accounts
+            .get(..REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for ReduceOrderWithFreeFundsAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REDUCE_ORDER_WITH_FREE_FUNDS_IX_DISCM: u8 = 5u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1781,6 +2537,77 @@ pub fn reduce_order_with_free_funds_ix(
 ) -> std::io::Result<Instruction> {
     reduce_order_with_free_funds_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`reduce_order_with_free_funds_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ReduceOrderWithFreeFundsIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    params: Option<ReduceOrderParams>,
+}
+impl Default for ReduceOrderWithFreeFundsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ReduceOrderWithFreeFundsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            params: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn params(mut self, params: ReduceOrderParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ReduceOrderWithFreeFundsIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let args = ReduceOrderWithFreeFundsIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let keys = ReduceOrderWithFreeFundsKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+        };
+        reduce_order_with_free_funds_ix(keys, args)
+    }
+}
 pub fn reduce_order_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ReduceOrderWithFreeFundsAccounts<'_, '_>,
@@ -1859,42 +2686,42 @@ pub fn reduce_order_with_free_funds_verify_account_privileges<'me, 'info>(
 pub const CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
 pub struct CancelAllOrdersAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: &'me AccountInfo<'info>,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: &'me AccountInfo<'info>,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CancelAllOrdersKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: Pubkey,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: Pubkey,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<CancelAllOrdersAccounts<'_, '_>> for CancelAllOrdersKeys {
@@ -2012,6 +2839,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ALL_ORDERS_IX_ACCOUNTS_LE
         }
     }
 }
+impl CancelAllOrdersKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for CancelAllOrdersKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CancelAllOrdersAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN] = accounts
+            .get(..CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CancelAllOrdersAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CANCEL_ALL_ORDERS_IX_DISCM: u8 = 6u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct CancelAllOrdersIxData;
@@ -2055,6 +2920,110 @@ pub fn cancel_all_orders_ix_with_program_id(
 pub fn cancel_all_orders_ix(keys: CancelAllOrdersKeys) -> std::io::Result<Instruction> {
     cancel_all_orders_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`cancel_all_orders_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CancelAllOrdersIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    base_account: Option<Pubkey>,
+    quote_account: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+}
+impl Default for CancelAllOrdersIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CancelAllOrdersIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            base_account: None,
+            quote_account: None,
+            base_vault: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn base_account(mut self, base_account: Pubkey) -> Self {
+        self.base_account = Some(base_account);
+        self
+    }
+    pub fn quote_account(mut self, quote_account: Pubkey) -> Self {
+        self.quote_account = Some(quote_account);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CancelAllOrdersIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let base_account = self.base_account.ok_or_else(|| missing("base_account"))?;
+        let quote_account = self.quote_account.ok_or_else(|| missing("quote_account"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let keys = CancelAllOrdersKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            token_program,
+        };
+        cancel_all_orders_ix(keys)
+    }
+}
 pub fn cancel_all_orders_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelAllOrdersAccounts<'_, '_>,
@@ -2138,21 +3107,21 @@ pub fn cancel_all_orders_verify_account_privileges<'me, 'info>(
 pub const CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct CancelAllOrdersWithFreeFundsAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CancelAllOrdersWithFreeFundsKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
 }
@@ -2232,6 +3201,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ALL_ORDERS_WITH_FREE_FUND
         }
     }
 }
+impl CancelAllOrdersWithFreeFundsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for CancelAllOrdersWithFreeFundsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CancelAllOrdersWithFreeFundsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for CancelAllOrdersWithFreeFundsAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_DISCM: u8 = 7u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct CancelAllOrdersWithFreeFundsIxData;
@@ -2277,6 +3293,68 @@ pub fn cancel_all_orders_with_free_funds_ix(
 ) -> std::io::Result<Instruction> {
     cancel_all_orders_with_free_funds_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`cancel_all_orders_with_free_funds_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CancelAllOrdersWithFreeFundsIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+}
+impl Default for CancelAllOrdersWithFreeFundsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CancelAllOrdersWithFreeFundsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CancelAllOrdersWithFreeFundsIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let keys = CancelAllOrdersWithFreeFundsKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+        };
+        cancel_all_orders_with_free_funds_ix(keys)
+    }
+}
 pub fn cancel_all_orders_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelAllOrdersWithFreeFundsAccounts<'_, '_>,
@@ -2351,42 +3429,42 @@ pub fn cancel_all_orders_with_free_funds_verify_account_privileges<'me, 'info>(
 pub const CANCEL_UP_TO_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
 pub struct CancelUpToAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: &'me AccountInfo<'info>,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: &'me AccountInfo<'info>,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CancelUpToKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: Pubkey,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: Pubkey,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<CancelUpToAccounts<'_, '_>> for CancelUpToKeys {
@@ -2504,6 +3582,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_UP_TO_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl CancelUpToKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CANCEL_UP_TO_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CANCEL_UP_TO_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CANCEL_UP_TO_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for CancelUpToKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CancelUpToAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CANCEL_UP_TO_IX_ACCOUNTS_LEN] = accounts
+            .get(..CANCEL_UP_TO_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CANCEL_UP_TO_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CancelUpToAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CANCEL_UP_TO_IX_DISCM: u8 = 8u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2563,6 +3679,119 @@ pub fn cancel_up_to_ix(
 ) -> std::io::Result<Instruction> {
     cancel_up_to_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`cancel_up_to_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CancelUpToIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    base_account: Option<Pubkey>,
+    quote_account: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    params: Option<CancelUpToParams>,
+}
+impl Default for CancelUpToIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CancelUpToIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            base_account: None,
+            quote_account: None,
+            base_vault: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            params: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn base_account(mut self, base_account: Pubkey) -> Self {
+        self.base_account = Some(base_account);
+        self
+    }
+    pub fn quote_account(mut self, quote_account: Pubkey) -> Self {
+        self.quote_account = Some(quote_account);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn params(mut self, params: CancelUpToParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CancelUpToIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let base_account = self.base_account.ok_or_else(|| missing("base_account"))?;
+        let quote_account = self.quote_account.ok_or_else(|| missing("quote_account"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = CancelUpToIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let keys = CancelUpToKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            token_program,
+        };
+        cancel_up_to_ix(keys, args)
+    }
+}
 pub fn cancel_up_to_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelUpToAccounts<'_, '_>,
@@ -2652,21 +3881,21 @@ pub fn cancel_up_to_verify_account_privileges<'me, 'info>(
 pub const CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct CancelUpToWithFreeFundsAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CancelUpToWithFreeFundsKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
 }
@@ -2742,6 +3971,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_
         }
     }
 }
+impl CancelUpToWithFreeFundsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for CancelUpToWithFreeFundsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CancelUpToWithFreeFundsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = accounts
+            .get(..CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for CancelUpToWithFreeFundsAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CANCEL_UP_TO_WITH_FREE_FUNDS_IX_DISCM: u8 = 9u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2803,6 +4078,77 @@ pub fn cancel_up_to_with_free_funds_ix(
 ) -> std::io::Result<Instruction> {
     cancel_up_to_with_free_funds_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`cancel_up_to_with_free_funds_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CancelUpToWithFreeFundsIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    params: Option<CancelUpToParams>,
+}
+impl Default for CancelUpToWithFreeFundsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CancelUpToWithFreeFundsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            params: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn params(mut self, params: CancelUpToParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CancelUpToWithFreeFundsIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let args = CancelUpToWithFreeFundsIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let keys = CancelUpToWithFreeFundsKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+        };
+        cancel_up_to_with_free_funds_ix(keys, args)
+    }
+}
 pub fn cancel_up_to_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelUpToWithFreeFundsAccounts<'_, '_>,
@@ -2881,42 +4227,42 @@ pub fn cancel_up_to_with_free_funds_verify_account_privileges<'me, 'info>(
 pub const CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
 pub struct CancelMultipleOrdersByIdAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: &'me AccountInfo<'info>,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: &'me AccountInfo<'info>,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CancelMultipleOrdersByIdKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: Pubkey,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: Pubkey,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<CancelMultipleOrdersByIdAccounts<'_, '_>> for CancelMultipleOrdersByIdKeys {
@@ -3036,6 +4382,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_
         }
     }
 }
+impl CancelMultipleOrdersByIdKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for CancelMultipleOrdersByIdKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CancelMultipleOrdersByIdAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN] = accounts
+            .get(..CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for CancelMultipleOrdersByIdAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CANCEL_MULTIPLE_ORDERS_BY_ID_IX_DISCM: u8 = 10u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3097,6 +4489,119 @@ pub fn cancel_multiple_orders_by_id_ix(
 ) -> std::io::Result<Instruction> {
     cancel_multiple_orders_by_id_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`cancel_multiple_orders_by_id_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CancelMultipleOrdersByIdIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    base_account: Option<Pubkey>,
+    quote_account: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    params: Option<CancelMultipleOrdersByIdParams>,
+}
+impl Default for CancelMultipleOrdersByIdIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CancelMultipleOrdersByIdIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            base_account: None,
+            quote_account: None,
+            base_vault: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            params: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn base_account(mut self, base_account: Pubkey) -> Self {
+        self.base_account = Some(base_account);
+        self
+    }
+    pub fn quote_account(mut self, quote_account: Pubkey) -> Self {
+        self.quote_account = Some(quote_account);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn params(mut self, params: CancelMultipleOrdersByIdParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CancelMultipleOrdersByIdIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let base_account = self.base_account.ok_or_else(|| missing("base_account"))?;
+        let quote_account = self.quote_account.ok_or_else(|| missing("quote_account"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = CancelMultipleOrdersByIdIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let keys = CancelMultipleOrdersByIdKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            token_program,
+        };
+        cancel_multiple_orders_by_id_ix(keys, args)
+    }
+}
 pub fn cancel_multiple_orders_by_id_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelMultipleOrdersByIdAccounts<'_, '_>,
@@ -3186,21 +4691,21 @@ pub fn cancel_multiple_orders_by_id_verify_account_privileges<'me, 'info>(
 pub const CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct CancelMultipleOrdersByIdWithFreeFundsAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CancelMultipleOrdersByIdWithFreeFundsKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
 }
@@ -3286,6 +4791,53 @@ impl<'me, 'info>
         }
     }
 }
+impl CancelMultipleOrdersByIdWithFreeFundsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for CancelMultipleOrdersByIdWithFreeFundsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CancelMultipleOrdersByIdWithFreeFundsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = accounts
+            .get(..CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for CancelMultipleOrdersByIdWithFreeFundsAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_DISCM: u8 = 11u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3352,6 +4904,80 @@ pub fn cancel_multiple_orders_by_id_with_free_funds_ix(
 ) -> std::io::Result<Instruction> {
     cancel_multiple_orders_by_id_with_free_funds_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`cancel_multiple_orders_by_id_with_free_funds_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CancelMultipleOrdersByIdWithFreeFundsIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    params: Option<CancelMultipleOrdersByIdParams>,
+}
+impl Default for CancelMultipleOrdersByIdWithFreeFundsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CancelMultipleOrdersByIdWithFreeFundsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            params: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn params(mut self, params: CancelMultipleOrdersByIdParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "CancelMultipleOrdersByIdWithFreeFundsIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let args = CancelMultipleOrdersByIdWithFreeFundsIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let keys = CancelMultipleOrdersByIdWithFreeFundsKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+        };
+        cancel_multiple_orders_by_id_with_free_funds_ix(keys, args)
+    }
+}
 pub fn cancel_multiple_orders_by_id_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelMultipleOrdersByIdWithFreeFundsAccounts<'_, '_>,
@@ -3437,42 +5063,42 @@ pub fn cancel_multiple_orders_by_id_with_free_funds_verify_account_privileges<'m
 pub const WITHDRAW_FUNDS_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
 pub struct WithdrawFundsAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: &'me AccountInfo<'info>,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: &'me AccountInfo<'info>,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct WithdrawFundsKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: Pubkey,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: Pubkey,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<WithdrawFundsAccounts<'_, '_>> for WithdrawFundsKeys {
@@ -3590,6 +5216,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_FUNDS_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl WithdrawFundsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; WITHDRAW_FUNDS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..WITHDRAW_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[WITHDRAW_FUNDS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for WithdrawFundsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> WithdrawFundsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; WITHDRAW_FUNDS_IX_ACCOUNTS_LEN] = accounts
+            .get(..WITHDRAW_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[WITHDRAW_FUNDS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for WithdrawFundsAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const WITHDRAW_FUNDS_IX_DISCM: u8 = 12u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3649,6 +5313,121 @@ pub fn withdraw_funds_ix(
 ) -> std::io::Result<Instruction> {
     withdraw_funds_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`withdraw_funds_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct WithdrawFundsIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    base_account: Option<Pubkey>,
+    quote_account: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    withdraw_funds_params: Option<WithdrawParams>,
+}
+impl Default for WithdrawFundsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl WithdrawFundsIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            base_account: None,
+            quote_account: None,
+            base_vault: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            withdraw_funds_params: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn base_account(mut self, base_account: Pubkey) -> Self {
+        self.base_account = Some(base_account);
+        self
+    }
+    pub fn quote_account(mut self, quote_account: Pubkey) -> Self {
+        self.quote_account = Some(quote_account);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn withdraw_funds_params(mut self, withdraw_funds_params: WithdrawParams) -> Self {
+        self.withdraw_funds_params = Some(withdraw_funds_params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("WithdrawFundsIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let base_account = self.base_account.ok_or_else(|| missing("base_account"))?;
+        let quote_account = self.quote_account.ok_or_else(|| missing("quote_account"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = WithdrawFundsIxArgs {
+            withdraw_funds_params: self
+                .withdraw_funds_params
+                .ok_or_else(|| missing("withdraw_funds_params"))?,
+        };
+        let keys = WithdrawFundsKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            token_program,
+        };
+        withdraw_funds_ix(keys, args)
+    }
+}
 pub fn withdraw_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: WithdrawFundsAccounts<'_, '_>,
@@ -3738,44 +5517,44 @@ pub fn withdraw_funds_verify_account_privileges<'me, 'info>(
 pub const DEPOSIT_FUNDS_IX_ACCOUNTS_LEN: usize = 10;
 #[derive(Copy, Clone, Debug)]
 pub struct DepositFundsAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
     pub seat: &'me AccountInfo<'info>,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: &'me AccountInfo<'info>,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: &'me AccountInfo<'info>,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DepositFundsKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
     pub seat: Pubkey,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: Pubkey,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: Pubkey,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<DepositFundsAccounts<'_, '_>> for DepositFundsKeys {
@@ -3902,6 +5681,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_FUNDS_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl DepositFundsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPOSIT_FUNDS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEPOSIT_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[DEPOSIT_FUNDS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for DepositFundsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DepositFundsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEPOSIT_FUNDS_IX_ACCOUNTS_LEN] = accounts
+            .get(..DEPOSIT_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[DEPOSIT_FUNDS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for DepositFundsAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEPOSIT_FUNDS_IX_DISCM: u8 = 13u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3961,6 +5778,129 @@ pub fn deposit_funds_ix(
 ) -> std::io::Result<Instruction> {
     deposit_funds_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`deposit_funds_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DepositFundsIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    seat: Option<Pubkey>,
+    base_account: Option<Pubkey>,
+    quote_account: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    deposit_funds_params: Option<DepositParams>,
+}
+impl Default for DepositFundsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DepositFundsIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            seat: None,
+            base_account: None,
+            quote_account: None,
+            base_vault: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            deposit_funds_params: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn seat(mut self, seat: Pubkey) -> Self {
+        self.seat = Some(seat);
+        self
+    }
+    pub fn base_account(mut self, base_account: Pubkey) -> Self {
+        self.base_account = Some(base_account);
+        self
+    }
+    pub fn quote_account(mut self, quote_account: Pubkey) -> Self {
+        self.quote_account = Some(quote_account);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn deposit_funds_params(mut self, deposit_funds_params: DepositParams) -> Self {
+        self.deposit_funds_params = Some(deposit_funds_params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DepositFundsIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let seat = self.seat.ok_or_else(|| missing("seat"))?;
+        let base_account = self.base_account.ok_or_else(|| missing("base_account"))?;
+        let quote_account = self.quote_account.ok_or_else(|| missing("quote_account"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = DepositFundsIxArgs {
+            deposit_funds_params: self
+                .deposit_funds_params
+                .ok_or_else(|| missing("deposit_funds_params"))?,
+        };
+        let keys = DepositFundsKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            seat,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            token_program,
+        };
+        deposit_funds_ix(keys, args)
+    }
+}
 pub fn deposit_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DepositFundsAccounts<'_, '_>,
@@ -4051,28 +5991,28 @@ pub fn deposit_funds_verify_account_privileges<'me, 'info>(
 pub const REQUEST_SEAT_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct RequestSeatAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub payer: &'me AccountInfo<'info>,
     pub seat: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct RequestSeatKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub payer: Pubkey,
     pub seat: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
 }
 impl From<RequestSeatAccounts<'_, '_>> for RequestSeatKeys {
@@ -4163,6 +6103,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REQUEST_SEAT_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl RequestSeatKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REQUEST_SEAT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REQUEST_SEAT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[REQUEST_SEAT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for RequestSeatKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RequestSeatAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REQUEST_SEAT_IX_ACCOUNTS_LEN] = accounts
+            .get(..REQUEST_SEAT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[REQUEST_SEAT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for RequestSeatAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REQUEST_SEAT_IX_DISCM: u8 = 14u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct RequestSeatIxData;
@@ -4206,6 +6184,86 @@ pub fn request_seat_ix_with_program_id(
 pub fn request_seat_ix(keys: RequestSeatKeys) -> std::io::Result<Instruction> {
     request_seat_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`request_seat_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RequestSeatIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    seat: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+}
+impl Default for RequestSeatIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RequestSeatIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            payer: None,
+            seat: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn seat(mut self, seat: Pubkey) -> Self {
+        self.seat = Some(seat);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RequestSeatIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let seat = self.seat.ok_or_else(|| missing("seat"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let keys = RequestSeatKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            payer,
+            seat,
+            system_program,
+        };
+        request_seat_ix(keys)
+    }
+}
 pub fn request_seat_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RequestSeatAccounts<'_, '_>,
@@ -4280,12 +6338,12 @@ pub fn request_seat_verify_account_privileges<'me, 'info>(
 pub const LOG_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
 pub struct LogAccounts<'me, 'info> {
-    ///Log authority
+    /// Log authority
     pub log_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct LogKeys {
-    ///Log authority
+    /// Log authority
     pub log_authority: Pubkey,
 }
 impl From<LogAccounts<'_, '_>> for LogKeys {
@@ -4323,6 +6381,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LOG_IX_ACCOUNTS_LEN]> for LogAcc
         }
     }
 }
+impl LogKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; LOG_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..LOG_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[LOG_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for LogKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> LogAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; LOG_IX_ACCOUNTS_LEN] = accounts
+            .get(..LOG_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[LOG_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for LogAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const LOG_IX_DISCM: u8 = 15u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct LogIxData;
@@ -4363,6 +6459,40 @@ pub fn log_ix_with_program_id(program_id: Pubkey, keys: LogKeys) -> std::io::Res
 pub fn log_ix(keys: LogKeys) -> std::io::Result<Instruction> {
     log_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`log_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct LogIxBuilder {
+    log_authority: Option<Pubkey>,
+}
+impl Default for LogIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl LogIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            log_authority: None,
+        }
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("LogIxBuilder is missing {}", field),
+            )
+        };
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let keys = LogKeys { log_authority };
+        log_ix(keys)
+    }
+}
 pub fn log_invoke_with_program_id(
     program_id: Pubkey,
     accounts: LogAccounts<'_, '_>,
@@ -4416,44 +6546,44 @@ pub fn log_verify_account_privileges<'me, 'info>(
 pub const PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN: usize = 10;
 #[derive(Copy, Clone, Debug)]
 pub struct PlaceMultiplePostOnlyOrdersAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
     pub seat: &'me AccountInfo<'info>,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: &'me AccountInfo<'info>,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: &'me AccountInfo<'info>,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct PlaceMultiplePostOnlyOrdersKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
     pub seat: Pubkey,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: Pubkey,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: Pubkey,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<PlaceMultiplePostOnlyOrdersAccounts<'_, '_>> for PlaceMultiplePostOnlyOrdersKeys {
@@ -4586,6 +6716,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_MULTIPLE_POST_ONLY_ORDERS_
         }
     }
 }
+impl PlaceMultiplePostOnlyOrdersKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for PlaceMultiplePostOnlyOrdersKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PlaceMultiplePostOnlyOrdersAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for PlaceMultiplePostOnlyOrdersAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_DISCM: u8 = 16u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4647,6 +6824,129 @@ pub fn place_multiple_post_only_orders_ix(
 ) -> std::io::Result<Instruction> {
     place_multiple_post_only_orders_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`place_multiple_post_only_orders_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PlaceMultiplePostOnlyOrdersIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    seat: Option<Pubkey>,
+    base_account: Option<Pubkey>,
+    quote_account: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    multiple_order_packet: Option<MultipleOrderPacket>,
+}
+impl Default for PlaceMultiplePostOnlyOrdersIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PlaceMultiplePostOnlyOrdersIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            seat: None,
+            base_account: None,
+            quote_account: None,
+            base_vault: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            multiple_order_packet: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn seat(mut self, seat: Pubkey) -> Self {
+        self.seat = Some(seat);
+        self
+    }
+    pub fn base_account(mut self, base_account: Pubkey) -> Self {
+        self.base_account = Some(base_account);
+        self
+    }
+    pub fn quote_account(mut self, quote_account: Pubkey) -> Self {
+        self.quote_account = Some(quote_account);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn multiple_order_packet(mut self, multiple_order_packet: MultipleOrderPacket) -> Self {
+        self.multiple_order_packet = Some(multiple_order_packet);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("PlaceMultiplePostOnlyOrdersIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let seat = self.seat.ok_or_else(|| missing("seat"))?;
+        let base_account = self.base_account.ok_or_else(|| missing("base_account"))?;
+        let quote_account = self.quote_account.ok_or_else(|| missing("quote_account"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = PlaceMultiplePostOnlyOrdersIxArgs {
+            multiple_order_packet: self
+                .multiple_order_packet
+                .ok_or_else(|| missing("multiple_order_packet"))?,
+        };
+        let keys = PlaceMultiplePostOnlyOrdersKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            seat,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            token_program,
+        };
+        place_multiple_post_only_orders_ix(keys, args)
+    }
+}
 pub fn place_multiple_post_only_orders_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceMultiplePostOnlyOrdersAccounts<'_, '_>,
@@ -4737,22 +7037,22 @@ pub fn place_multiple_post_only_orders_verify_account_privileges<'me, 'info>(
 pub const PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
     pub seat: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct PlaceMultiplePostOnlyOrdersWithFreeFundsKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
     pub trader: Pubkey,
     pub seat: Pubkey,
@@ -4848,6 +7148,54 @@ impl<'me, 'info>
         }
     }
 }
+impl PlaceMultiplePostOnlyOrdersWithFreeFundsKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] =
+            pubkeys
+                .get(..PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+                .and_then(|keys| keys.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for PlaceMultiplePostOnlyOrdersWithFreeFundsKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = accounts
+            .get(..PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_DISCM: u8 = 17u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4914,6 +7262,90 @@ pub fn place_multiple_post_only_orders_with_free_funds_ix(
 ) -> std::io::Result<Instruction> {
     place_multiple_post_only_orders_with_free_funds_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`place_multiple_post_only_orders_with_free_funds_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PlaceMultiplePostOnlyOrdersWithFreeFundsIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    seat: Option<Pubkey>,
+    multiple_order_packet: Option<MultipleOrderPacket>,
+}
+impl Default for PlaceMultiplePostOnlyOrdersWithFreeFundsIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PlaceMultiplePostOnlyOrdersWithFreeFundsIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            trader: None,
+            seat: None,
+            multiple_order_packet: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn seat(mut self, seat: Pubkey) -> Self {
+        self.seat = Some(seat);
+        self
+    }
+    pub fn multiple_order_packet(mut self, multiple_order_packet: MultipleOrderPacket) -> Self {
+        self.multiple_order_packet = Some(multiple_order_packet);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "PlaceMultiplePostOnlyOrdersWithFreeFundsIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let seat = self.seat.ok_or_else(|| missing("seat"))?;
+        let args = PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs {
+            multiple_order_packet: self
+                .multiple_order_packet
+                .ok_or_else(|| missing("multiple_order_packet"))?,
+        };
+        let keys = PlaceMultiplePostOnlyOrdersWithFreeFundsKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            trader,
+            seat,
+        };
+        place_multiple_post_only_orders_with_free_funds_ix(keys, args)
+    }
+}
 pub fn place_multiple_post_only_orders_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, '_>,
@@ -5004,48 +7436,48 @@ pub fn place_multiple_post_only_orders_with_free_funds_verify_account_privileges
 pub const INITIALIZE_MARKET_IX_ACCOUNTS_LEN: usize = 10;
 #[derive(Copy, Clone, Debug)]
 pub struct InitializeMarketAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
-    ///The market_creator account must sign for the creation of new vaults
+    /// The market_creator account must sign for the creation of new vaults
     pub market_creator: &'me AccountInfo<'info>,
-    ///Base mint account
+    /// Base mint account
     pub base_mint: &'me AccountInfo<'info>,
-    ///Quote mint account
+    /// Quote mint account
     pub quote_mint: &'me AccountInfo<'info>,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct InitializeMarketKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
-    ///The market_creator account must sign for the creation of new vaults
+    /// The market_creator account must sign for the creation of new vaults
     pub market_creator: Pubkey,
-    ///Base mint account
+    /// Base mint account
     pub base_mint: Pubkey,
-    ///Quote mint account
+    /// Quote mint account
     pub quote_mint: Pubkey,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<InitializeMarketAccounts<'_, '_>> for InitializeMarketKeys {
@@ -5172,6 +7604,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_MARKET_IX_ACCOUNTS_LE
         }
     }
 }
+impl InitializeMarketKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; INITIALIZE_MARKET_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..INITIALIZE_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[INITIALIZE_MARKET_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for InitializeMarketKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> InitializeMarketAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; INITIALIZE_MARKET_IX_ACCOUNTS_LEN] = accounts
+            .get(..INITIALIZE_MARKET_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[INITIALIZE_MARKET_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for InitializeMarketAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const INITIALIZE_MARKET_IX_DISCM: u8 = 100u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5231,6 +7701,133 @@ pub fn initialize_market_ix(
 ) -> std::io::Result<Instruction> {
     initialize_market_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`initialize_market_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct InitializeMarketIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    market_creator: Option<Pubkey>,
+    base_mint: Option<Pubkey>,
+    quote_mint: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    initialize_params: Option<InitializeParams>,
+}
+impl Default for InitializeMarketIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl InitializeMarketIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            market_creator: None,
+            base_mint: None,
+            quote_mint: None,
+            base_vault: None,
+            quote_vault: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            initialize_params: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn market_creator(mut self, market_creator: Pubkey) -> Self {
+        self.market_creator = Some(market_creator);
+        self
+    }
+    pub fn base_mint(mut self, base_mint: Pubkey) -> Self {
+        self.base_mint = Some(base_mint);
+        self
+    }
+    pub fn quote_mint(mut self, quote_mint: Pubkey) -> Self {
+        self.quote_mint = Some(quote_mint);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn initialize_params(mut self, initialize_params: InitializeParams) -> Self {
+        self.initialize_params = Some(initialize_params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("InitializeMarketIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let market_creator = self
+            .market_creator
+            .ok_or_else(|| missing("market_creator"))?;
+        let base_mint = self.base_mint.ok_or_else(|| missing("base_mint"))?;
+        let quote_mint = self.quote_mint.ok_or_else(|| missing("quote_mint"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = InitializeMarketIxArgs {
+            initialize_params: self
+                .initialize_params
+                .ok_or_else(|| missing("initialize_params"))?,
+        };
+        let keys = InitializeMarketKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            market_creator,
+            base_mint,
+            quote_mint,
+            base_vault,
+            quote_vault,
+            system_program,
+            token_program,
+        };
+        initialize_market_ix(keys, args)
+    }
+}
 pub fn initialize_market_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeMarketAccounts<'_, '_>,
@@ -5320,24 +7917,24 @@ pub fn initialize_market_verify_account_privileges<'me, 'info>(
 pub const CLAIM_AUTHORITY_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct ClaimAuthorityAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
-    ///The successor account must sign to claim authority
+    /// The successor account must sign to claim authority
     pub successor: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ClaimAuthorityKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
-    ///The successor account must sign to claim authority
+    /// The successor account must sign to claim authority
     pub successor: Pubkey,
 }
 impl From<ClaimAuthorityAccounts<'_, '_>> for ClaimAuthorityKeys {
@@ -5410,6 +8007,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CLAIM_AUTHORITY_IX_ACCOUNTS_LEN]
         }
     }
 }
+impl ClaimAuthorityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CLAIM_AUTHORITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CLAIM_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CLAIM_AUTHORITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for ClaimAuthorityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ClaimAuthorityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CLAIM_AUTHORITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..CLAIM_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CLAIM_AUTHORITY_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ClaimAuthorityAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CLAIM_AUTHORITY_IX_DISCM: u8 = 101u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ClaimAuthorityIxData;
@@ -5453,6 +8088,68 @@ pub fn claim_authority_ix_with_program_id(
 pub fn claim_authority_ix(keys: ClaimAuthorityKeys) -> std::io::Result<Instruction> {
     claim_authority_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`claim_authority_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ClaimAuthorityIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    successor: Option<Pubkey>,
+}
+impl Default for ClaimAuthorityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ClaimAuthorityIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            successor: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn successor(mut self, successor: Pubkey) -> Self {
+        self.successor = Some(successor);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ClaimAuthorityIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let successor = self.successor.ok_or_else(|| missing("successor"))?;
+        let keys = ClaimAuthorityKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            successor,
+        };
+        claim_authority_ix(keys)
+    }
+}
 pub fn claim_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ClaimAuthorityAccounts<'_, '_>,
@@ -5525,24 +8222,24 @@ pub fn claim_authority_verify_account_privileges<'me, 'info>(
 pub const NAME_SUCCESSOR_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct NameSuccessorAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
-    ///The market_authority account must sign to name successor
+    /// The market_authority account must sign to name successor
     pub market_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct NameSuccessorKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
-    ///The market_authority account must sign to name successor
+    /// The market_authority account must sign to name successor
     pub market_authority: Pubkey,
 }
 impl From<NameSuccessorAccounts<'_, '_>> for NameSuccessorKeys {
@@ -5615,6 +8312,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; NAME_SUCCESSOR_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl NameSuccessorKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; NAME_SUCCESSOR_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..NAME_SUCCESSOR_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[NAME_SUCCESSOR_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for NameSuccessorKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> NameSuccessorAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; NAME_SUCCESSOR_IX_ACCOUNTS_LEN] = accounts
+            .get(..NAME_SUCCESSOR_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[NAME_SUCCESSOR_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for NameSuccessorAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const NAME_SUCCESSOR_IX_DISCM: u8 = 102u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5674,6 +8409,79 @@ pub fn name_successor_ix(
 ) -> std::io::Result<Instruction> {
     name_successor_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`name_successor_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct NameSuccessorIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    market_authority: Option<Pubkey>,
+    successor: Option<Pubkey>,
+}
+impl Default for NameSuccessorIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl NameSuccessorIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            market_authority: None,
+            successor: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn market_authority(mut self, market_authority: Pubkey) -> Self {
+        self.market_authority = Some(market_authority);
+        self
+    }
+    pub fn successor(mut self, successor: Pubkey) -> Self {
+        self.successor = Some(successor);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("NameSuccessorIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let market_authority = self
+            .market_authority
+            .ok_or_else(|| missing("market_authority"))?;
+        let args = NameSuccessorIxArgs {
+            successor: self.successor.ok_or_else(|| missing("successor"))?,
+        };
+        let keys = NameSuccessorKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            market_authority,
+        };
+        name_successor_ix(keys, args)
+    }
+}
 pub fn name_successor_invoke_with_program_id(
     program_id: Pubkey,
     accounts: NameSuccessorAccounts<'_, '_>,
@@ -5752,24 +8560,24 @@ pub fn name_successor_verify_account_privileges<'me, 'info>(
 pub const CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct ChangeMarketStatusAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
-    ///The market_authority account must sign to change market status
+    /// The market_authority account must sign to change market status
     pub market_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ChangeMarketStatusKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
-    ///The market_authority account must sign to change market status
+    /// The market_authority account must sign to change market status
     pub market_authority: Pubkey,
 }
 impl From<ChangeMarketStatusAccounts<'_, '_>> for ChangeMarketStatusKeys {
@@ -5842,6 +8650,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_MARKET_STATUS_IX_ACCOUNTS
         }
     }
 }
+impl ChangeMarketStatusKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ChangeMarketStatusKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ChangeMarketStatusAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN] = accounts
+            .get(..CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ChangeMarketStatusAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CHANGE_MARKET_STATUS_IX_DISCM: u8 = 103u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5901,6 +8753,79 @@ pub fn change_market_status_ix(
 ) -> std::io::Result<Instruction> {
     change_market_status_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`change_market_status_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ChangeMarketStatusIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    market_authority: Option<Pubkey>,
+    market_status: Option<MarketStatus>,
+}
+impl Default for ChangeMarketStatusIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ChangeMarketStatusIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            market_authority: None,
+            market_status: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn market_authority(mut self, market_authority: Pubkey) -> Self {
+        self.market_authority = Some(market_authority);
+        self
+    }
+    pub fn market_status(mut self, market_status: MarketStatus) -> Self {
+        self.market_status = Some(market_status);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ChangeMarketStatusIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let market_authority = self
+            .market_authority
+            .ok_or_else(|| missing("market_authority"))?;
+        let args = ChangeMarketStatusIxArgs {
+            market_status: self.market_status.ok_or_else(|| missing("market_status"))?,
+        };
+        let keys = ChangeMarketStatusKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            market_authority,
+        };
+        change_market_status_ix(keys, args)
+    }
+}
 pub fn change_market_status_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ChangeMarketStatusAccounts<'_, '_>,
@@ -5979,25 +8904,25 @@ pub fn change_market_status_verify_account_privileges<'me, 'info>(
 pub const CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct ChangeSeatStatusAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
-    ///The market_authority account must sign to change seat status
+    /// The market_authority account must sign to change seat status
     pub market_authority: &'me AccountInfo<'info>,
     pub seat: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ChangeSeatStatusKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
-    ///The market_authority account must sign to change seat status
+    /// The market_authority account must sign to change seat status
     pub market_authority: Pubkey,
     pub seat: Pubkey,
 }
@@ -6080,6 +9005,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_SEAT_STATUS_IX_ACCOUNTS_L
         }
     }
 }
+impl ChangeSeatStatusKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for ChangeSeatStatusKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ChangeSeatStatusAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN] = accounts
+            .get(..CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ChangeSeatStatusAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CHANGE_SEAT_STATUS_IX_DISCM: u8 = 104u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -6139,6 +9102,89 @@ pub fn change_seat_status_ix(
 ) -> std::io::Result<Instruction> {
     change_seat_status_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`change_seat_status_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ChangeSeatStatusIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    market_authority: Option<Pubkey>,
+    seat: Option<Pubkey>,
+    approval_status: Option<SeatApprovalStatus>,
+}
+impl Default for ChangeSeatStatusIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ChangeSeatStatusIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            market_authority: None,
+            seat: None,
+            approval_status: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn market_authority(mut self, market_authority: Pubkey) -> Self {
+        self.market_authority = Some(market_authority);
+        self
+    }
+    pub fn seat(mut self, seat: Pubkey) -> Self {
+        self.seat = Some(seat);
+        self
+    }
+    pub fn approval_status(mut self, approval_status: SeatApprovalStatus) -> Self {
+        self.approval_status = Some(approval_status);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ChangeSeatStatusIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let market_authority = self
+            .market_authority
+            .ok_or_else(|| missing("market_authority"))?;
+        let seat = self.seat.ok_or_else(|| missing("seat"))?;
+        let args = ChangeSeatStatusIxArgs {
+            approval_status: self
+                .approval_status
+                .ok_or_else(|| missing("approval_status"))?,
+        };
+        let keys = ChangeSeatStatusKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            market_authority,
+            seat,
+        };
+        change_seat_status_ix(keys, args)
+    }
+}
 pub fn change_seat_status_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ChangeSeatStatusAccounts<'_, '_>,
@@ -6218,34 +9264,34 @@ pub fn change_seat_status_verify_account_privileges<'me, 'info>(
 pub const REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN: usize = 8;
 #[derive(Copy, Clone, Debug)]
 pub struct RequestSeatAuthorizedAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
-    ///The market_authority account must sign to request a seat on behalf of a trader
+    /// The market_authority account must sign to request a seat on behalf of a trader
     pub market_authority: &'me AccountInfo<'info>,
     pub payer: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
     pub seat: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct RequestSeatAuthorizedKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
-    ///The market_authority account must sign to request a seat on behalf of a trader
+    /// The market_authority account must sign to request a seat on behalf of a trader
     pub market_authority: Pubkey,
     pub payer: Pubkey,
     pub trader: Pubkey,
     pub seat: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
 }
 impl From<RequestSeatAuthorizedAccounts<'_, '_>> for RequestSeatAuthorizedKeys {
@@ -6354,6 +9400,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REQUEST_SEAT_AUTHORIZED_IX_ACCOU
         }
     }
 }
+impl RequestSeatAuthorizedKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for RequestSeatAuthorizedKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RequestSeatAuthorizedAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN] = accounts
+            .get(..REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for RequestSeatAuthorizedAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REQUEST_SEAT_AUTHORIZED_IX_DISCM: u8 = 105u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct RequestSeatAuthorizedIxData;
@@ -6397,6 +9487,104 @@ pub fn request_seat_authorized_ix_with_program_id(
 pub fn request_seat_authorized_ix(keys: RequestSeatAuthorizedKeys) -> std::io::Result<Instruction> {
     request_seat_authorized_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`request_seat_authorized_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RequestSeatAuthorizedIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    market_authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    seat: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+}
+impl Default for RequestSeatAuthorizedIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RequestSeatAuthorizedIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            market_authority: None,
+            payer: None,
+            trader: None,
+            seat: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn market_authority(mut self, market_authority: Pubkey) -> Self {
+        self.market_authority = Some(market_authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn seat(mut self, seat: Pubkey) -> Self {
+        self.seat = Some(seat);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RequestSeatAuthorizedIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let market_authority = self
+            .market_authority
+            .ok_or_else(|| missing("market_authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let seat = self.seat.ok_or_else(|| missing("seat"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let keys = RequestSeatAuthorizedKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            market_authority,
+            payer,
+            trader,
+            seat,
+            system_program,
+        };
+        request_seat_authorized_ix(keys)
+    }
+}
 pub fn request_seat_authorized_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RequestSeatAuthorizedAccounts<'_, '_>,
@@ -6475,42 +9663,42 @@ pub fn request_seat_authorized_verify_account_privileges<'me, 'info>(
 pub const EVICT_SEAT_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
 pub struct EvictSeatAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
-    ///The market_authority account must sign to evict a seat
+    /// The market_authority account must sign to evict a seat
     pub market_authority: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
-    ///The trader's PDA seat account, seeds are [b'seat', market_address, trader_address]
+    /// The trader's PDA seat account, seeds are [b'seat', market_address, trader_address]
     pub seat: &'me AccountInfo<'info>,
     pub base_account: &'me AccountInfo<'info>,
     pub quote_account: &'me AccountInfo<'info>,
     pub base_vault: &'me AccountInfo<'info>,
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct EvictSeatKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
-    ///The market_authority account must sign to evict a seat
+    /// The market_authority account must sign to evict a seat
     pub market_authority: Pubkey,
     pub trader: Pubkey,
-    ///The trader's PDA seat account, seeds are [b'seat', market_address, trader_address]
+    /// The trader's PDA seat account, seeds are [b'seat', market_address, trader_address]
     pub seat: Pubkey,
     pub base_account: Pubkey,
     pub quote_account: Pubkey,
     pub base_vault: Pubkey,
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<EvictSeatAccounts<'_, '_>> for EvictSeatKeys {
@@ -6646,6 +9834,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; EVICT_SEAT_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl EvictSeatKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; EVICT_SEAT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..EVICT_SEAT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[EVICT_SEAT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for EvictSeatKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> EvictSeatAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; EVICT_SEAT_IX_ACCOUNTS_LEN] = accounts
+            .get(..EVICT_SEAT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[EVICT_SEAT_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for EvictSeatAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const EVICT_SEAT_IX_DISCM: u8 = 106u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct EvictSeatIxData;
@@ -6689,6 +9915,128 @@ pub fn evict_seat_ix_with_program_id(
 pub fn evict_seat_ix(keys: EvictSeatKeys) -> std::io::Result<Instruction> {
     evict_seat_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`evict_seat_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct EvictSeatIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    market_authority: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    seat: Option<Pubkey>,
+    base_account: Option<Pubkey>,
+    quote_account: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+}
+impl Default for EvictSeatIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl EvictSeatIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            market_authority: None,
+            trader: None,
+            seat: None,
+            base_account: None,
+            quote_account: None,
+            base_vault: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn market_authority(mut self, market_authority: Pubkey) -> Self {
+        self.market_authority = Some(market_authority);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn seat(mut self, seat: Pubkey) -> Self {
+        self.seat = Some(seat);
+        self
+    }
+    pub fn base_account(mut self, base_account: Pubkey) -> Self {
+        self.base_account = Some(base_account);
+        self
+    }
+    pub fn quote_account(mut self, quote_account: Pubkey) -> Self {
+        self.quote_account = Some(quote_account);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("EvictSeatIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let market_authority = self
+            .market_authority
+            .ok_or_else(|| missing("market_authority"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let seat = self.seat.ok_or_else(|| missing("seat"))?;
+        let base_account = self.base_account.ok_or_else(|| missing("base_account"))?;
+        let quote_account = self.quote_account.ok_or_else(|| missing("quote_account"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let keys = EvictSeatKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            market_authority,
+            trader,
+            seat,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            token_program,
+        };
+        evict_seat_ix(keys)
+    }
+}
 pub fn evict_seat_invoke_with_program_id(
     program_id: Pubkey,
     accounts: EvictSeatAccounts<'_, '_>,
@@ -6774,50 +10122,50 @@ pub fn evict_seat_verify_account_privileges<'me, 'info>(
 pub const FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
 pub struct ForceCancelOrdersAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
-    ///The market_authority account must sign to claim authority
+    /// The market_authority account must sign to claim authority
     pub market_authority: &'me AccountInfo<'info>,
     pub trader: &'me AccountInfo<'info>,
-    ///The trader's PDA seat account, seeds are [b'seat', market_address, trader_address]
+    /// The trader's PDA seat account, seeds are [b'seat', market_address, trader_address]
     pub seat: &'me AccountInfo<'info>,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: &'me AccountInfo<'info>,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: &'me AccountInfo<'info>,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ForceCancelOrdersKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
-    ///The market_authority account must sign to claim authority
+    /// The market_authority account must sign to claim authority
     pub market_authority: Pubkey,
     pub trader: Pubkey,
-    ///The trader's PDA seat account, seeds are [b'seat', market_address, trader_address]
+    /// The trader's PDA seat account, seeds are [b'seat', market_address, trader_address]
     pub seat: Pubkey,
-    ///Trader base token account
+    /// Trader base token account
     pub base_account: Pubkey,
-    ///Trader quote token account
+    /// Trader quote token account
     pub quote_account: Pubkey,
-    ///Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
+    /// Base vault PDA, seeds are [b'vault', market_address, base_mint_address]
     pub base_vault: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<ForceCancelOrdersAccounts<'_, '_>> for ForceCancelOrdersKeys {
@@ -6953,6 +10301,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_
         }
     }
 }
+impl ForceCancelOrdersKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for ForceCancelOrdersKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ForceCancelOrdersAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN] = accounts
+            .get(..FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ForceCancelOrdersAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const FORCE_CANCEL_ORDERS_IX_DISCM: u8 = 107u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -7012,6 +10398,137 @@ pub fn force_cancel_orders_ix(
 ) -> std::io::Result<Instruction> {
     force_cancel_orders_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`force_cancel_orders_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ForceCancelOrdersIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    market_authority: Option<Pubkey>,
+    trader: Option<Pubkey>,
+    seat: Option<Pubkey>,
+    base_account: Option<Pubkey>,
+    quote_account: Option<Pubkey>,
+    base_vault: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    params: Option<CancelUpToParams>,
+}
+impl Default for ForceCancelOrdersIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ForceCancelOrdersIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            market_authority: None,
+            trader: None,
+            seat: None,
+            base_account: None,
+            quote_account: None,
+            base_vault: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            params: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn market_authority(mut self, market_authority: Pubkey) -> Self {
+        self.market_authority = Some(market_authority);
+        self
+    }
+    pub fn trader(mut self, trader: Pubkey) -> Self {
+        self.trader = Some(trader);
+        self
+    }
+    pub fn seat(mut self, seat: Pubkey) -> Self {
+        self.seat = Some(seat);
+        self
+    }
+    pub fn base_account(mut self, base_account: Pubkey) -> Self {
+        self.base_account = Some(base_account);
+        self
+    }
+    pub fn quote_account(mut self, quote_account: Pubkey) -> Self {
+        self.quote_account = Some(quote_account);
+        self
+    }
+    pub fn base_vault(mut self, base_vault: Pubkey) -> Self {
+        self.base_vault = Some(base_vault);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn params(mut self, params: CancelUpToParams) -> Self {
+        self.params = Some(params);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ForceCancelOrdersIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let market_authority = self
+            .market_authority
+            .ok_or_else(|| missing("market_authority"))?;
+        let trader = self.trader.ok_or_else(|| missing("trader"))?;
+        let seat = self.seat.ok_or_else(|| missing("seat"))?;
+        let base_account = self.base_account.ok_or_else(|| missing("base_account"))?;
+        let quote_account = self.quote_account.ok_or_else(|| missing("quote_account"))?;
+        let base_vault = self.base_vault.ok_or_else(|| missing("base_vault"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let args = ForceCancelOrdersIxArgs {
+            params: self.params.ok_or_else(|| missing("params"))?,
+        };
+        let keys = ForceCancelOrdersKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            market_authority,
+            trader,
+            seat,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            token_program,
+        };
+        force_cancel_orders_ix(keys, args)
+    }
+}
 pub fn force_cancel_orders_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ForceCancelOrdersAccounts<'_, '_>,
@@ -7103,36 +10620,36 @@ pub fn force_cancel_orders_verify_account_privileges<'me, 'info>(
 pub const COLLECT_FEES_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
 pub struct CollectFeesAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
-    ///Signer of collect fees instruction
+    /// Signer of collect fees instruction
     pub sweeper: &'me AccountInfo<'info>,
-    ///Fee collector quote token account
+    /// Fee collector quote token account
     pub fee_recipient: &'me AccountInfo<'info>,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CollectFeesKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
-    ///Signer of collect fees instruction
+    /// Signer of collect fees instruction
     pub sweeper: Pubkey,
-    ///Fee collector quote token account
+    /// Fee collector quote token account
     pub fee_recipient: Pubkey,
-    ///Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
+    /// Quote vault PDA, seeds are [b'vault', market_address, quote_mint_address]
     pub quote_vault: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
 }
 impl From<CollectFeesAccounts<'_, '_>> for CollectFeesKeys {
@@ -7232,6 +10749,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; COLLECT_FEES_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl CollectFeesKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; COLLECT_FEES_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..COLLECT_FEES_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[COLLECT_FEES_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for CollectFeesKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CollectFeesAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; COLLECT_FEES_IX_ACCOUNTS_LEN] = accounts
+            .get(..COLLECT_FEES_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[COLLECT_FEES_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CollectFeesAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const COLLECT_FEES_IX_DISCM: u8 = 108u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct CollectFeesIxData;
@@ -7275,6 +10830,94 @@ pub fn collect_fees_ix_with_program_id(
 pub fn collect_fees_ix(keys: CollectFeesKeys) -> std::io::Result<Instruction> {
     collect_fees_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`collect_fees_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CollectFeesIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    sweeper: Option<Pubkey>,
+    fee_recipient: Option<Pubkey>,
+    quote_vault: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+}
+impl Default for CollectFeesIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CollectFeesIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            sweeper: None,
+            fee_recipient: None,
+            quote_vault: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn sweeper(mut self, sweeper: Pubkey) -> Self {
+        self.sweeper = Some(sweeper);
+        self
+    }
+    pub fn fee_recipient(mut self, fee_recipient: Pubkey) -> Self {
+        self.fee_recipient = Some(fee_recipient);
+        self
+    }
+    pub fn quote_vault(mut self, quote_vault: Pubkey) -> Self {
+        self.quote_vault = Some(quote_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CollectFeesIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let sweeper = self.sweeper.ok_or_else(|| missing("sweeper"))?;
+        let fee_recipient = self.fee_recipient.ok_or_else(|| missing("fee_recipient"))?;
+        let quote_vault = self.quote_vault.ok_or_else(|| missing("quote_vault"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let keys = CollectFeesKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            sweeper,
+            fee_recipient,
+            quote_vault,
+            token_program,
+        };
+        collect_fees_ix(keys)
+    }
+}
 pub fn collect_fees_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CollectFeesAccounts<'_, '_>,
@@ -7354,28 +10997,28 @@ pub fn collect_fees_verify_account_privileges<'me, 'info>(
 pub const CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct ChangeFeeRecipientAccounts<'me, 'info> {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: &'me AccountInfo<'info>,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: &'me AccountInfo<'info>,
-    ///The market_authority account must sign to change the free recipient
+    /// The market_authority account must sign to change the free recipient
     pub market_authority: &'me AccountInfo<'info>,
-    ///New fee recipient
+    /// New fee recipient
     pub new_fee_recipient: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ChangeFeeRecipientKeys {
-    ///Phoenix program
+    /// Phoenix program
     pub phoenix_program: Pubkey,
-    ///Phoenix log authority
+    /// Phoenix log authority
     pub log_authority: Pubkey,
-    ///This account holds the market state
+    /// This account holds the market state
     pub market: Pubkey,
-    ///The market_authority account must sign to change the free recipient
+    /// The market_authority account must sign to change the free recipient
     pub market_authority: Pubkey,
-    ///New fee recipient
+    /// New fee recipient
     pub new_fee_recipient: Pubkey,
 }
 impl From<ChangeFeeRecipientAccounts<'_, '_>> for ChangeFeeRecipientKeys {
@@ -7457,6 +11100,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS
         }
     }
 }
+impl ChangeFeeRecipientKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ChangeFeeRecipientKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ChangeFeeRecipientAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN] = accounts
+            .get(..CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ChangeFeeRecipientAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CHANGE_FEE_RECIPIENT_IX_DISCM: u8 = 109u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ChangeFeeRecipientIxData;
@@ -7500,6 +11187,80 @@ pub fn change_fee_recipient_ix_with_program_id(
 pub fn change_fee_recipient_ix(keys: ChangeFeeRecipientKeys) -> std::io::Result<Instruction> {
     change_fee_recipient_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`change_fee_recipient_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ChangeFeeRecipientIxBuilder {
+    phoenix_program: Option<Pubkey>,
+    log_authority: Option<Pubkey>,
+    market: Option<Pubkey>,
+    market_authority: Option<Pubkey>,
+    new_fee_recipient: Option<Pubkey>,
+}
+impl Default for ChangeFeeRecipientIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ChangeFeeRecipientIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            phoenix_program: None,
+            log_authority: None,
+            market: None,
+            market_authority: None,
+            new_fee_recipient: None,
+        }
+    }
+    pub fn phoenix_program(mut self, phoenix_program: Pubkey) -> Self {
+        self.phoenix_program = Some(phoenix_program);
+        self
+    }
+    pub fn log_authority(mut self, log_authority: Pubkey) -> Self {
+        self.log_authority = Some(log_authority);
+        self
+    }
+    pub fn market(mut self, market: Pubkey) -> Self {
+        self.market = Some(market);
+        self
+    }
+    pub fn market_authority(mut self, market_authority: Pubkey) -> Self {
+        self.market_authority = Some(market_authority);
+        self
+    }
+    pub fn new_fee_recipient(mut self, new_fee_recipient: Pubkey) -> Self {
+        self.new_fee_recipient = Some(new_fee_recipient);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ChangeFeeRecipientIxBuilder is missing {}", field),
+            )
+        };
+        let phoenix_program = self
+            .phoenix_program
+            .ok_or_else(|| missing("phoenix_program"))?;
+        let log_authority = self.log_authority.ok_or_else(|| missing("log_authority"))?;
+        let market = self.market.ok_or_else(|| missing("market"))?;
+        let market_authority = self
+            .market_authority
+            .ok_or_else(|| missing("market_authority"))?;
+        let new_fee_recipient = self
+            .new_fee_recipient
+            .ok_or_else(|| missing("new_fee_recipient"))?;
+        let keys = ChangeFeeRecipientKeys {
+            phoenix_program,
+            log_authority,
+            market,
+            market_authority,
+            new_fee_recipient,
+        };
+        change_fee_recipient_ix(keys)
+    }
+}
 pub fn change_fee_recipient_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ChangeFeeRecipientAccounts<'_, '_>,
@@ -7570,3 +11331,268 @@ pub fn change_fee_recipient_verify_account_privileges<'me, 'info>(
     change_fee_recipient_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// A [`PhoenixV1ProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PhoenixV1ParsedIx {
+    Swap {
+        keys: SwapKeys,
+        args: SwapIxArgs,
+    },
+    SwapWithFreeFunds {
+        keys: SwapWithFreeFundsKeys,
+        args: SwapWithFreeFundsIxArgs,
+    },
+    PlaceLimitOrder {
+        keys: PlaceLimitOrderKeys,
+        args: PlaceLimitOrderIxArgs,
+    },
+    PlaceLimitOrderWithFreeFunds {
+        keys: PlaceLimitOrderWithFreeFundsKeys,
+        args: PlaceLimitOrderWithFreeFundsIxArgs,
+    },
+    ReduceOrder {
+        keys: ReduceOrderKeys,
+        args: ReduceOrderIxArgs,
+    },
+    ReduceOrderWithFreeFunds {
+        keys: ReduceOrderWithFreeFundsKeys,
+        args: ReduceOrderWithFreeFundsIxArgs,
+    },
+    CancelAllOrders {
+        keys: CancelAllOrdersKeys,
+    },
+    CancelAllOrdersWithFreeFunds {
+        keys: CancelAllOrdersWithFreeFundsKeys,
+    },
+    CancelUpTo {
+        keys: CancelUpToKeys,
+        args: CancelUpToIxArgs,
+    },
+    CancelUpToWithFreeFunds {
+        keys: CancelUpToWithFreeFundsKeys,
+        args: CancelUpToWithFreeFundsIxArgs,
+    },
+    CancelMultipleOrdersById {
+        keys: CancelMultipleOrdersByIdKeys,
+        args: CancelMultipleOrdersByIdIxArgs,
+    },
+    CancelMultipleOrdersByIdWithFreeFunds {
+        keys: CancelMultipleOrdersByIdWithFreeFundsKeys,
+        args: CancelMultipleOrdersByIdWithFreeFundsIxArgs,
+    },
+    WithdrawFunds {
+        keys: WithdrawFundsKeys,
+        args: WithdrawFundsIxArgs,
+    },
+    DepositFunds {
+        keys: DepositFundsKeys,
+        args: DepositFundsIxArgs,
+    },
+    RequestSeat {
+        keys: RequestSeatKeys,
+    },
+    Log {
+        keys: LogKeys,
+    },
+    PlaceMultiplePostOnlyOrders {
+        keys: PlaceMultiplePostOnlyOrdersKeys,
+        args: PlaceMultiplePostOnlyOrdersIxArgs,
+    },
+    PlaceMultiplePostOnlyOrdersWithFreeFunds {
+        keys: PlaceMultiplePostOnlyOrdersWithFreeFundsKeys,
+        args: PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs,
+    },
+    InitializeMarket {
+        keys: InitializeMarketKeys,
+        args: InitializeMarketIxArgs,
+    },
+    ClaimAuthority {
+        keys: ClaimAuthorityKeys,
+    },
+    NameSuccessor {
+        keys: NameSuccessorKeys,
+        args: NameSuccessorIxArgs,
+    },
+    ChangeMarketStatus {
+        keys: ChangeMarketStatusKeys,
+        args: ChangeMarketStatusIxArgs,
+    },
+    ChangeSeatStatus {
+        keys: ChangeSeatStatusKeys,
+        args: ChangeSeatStatusIxArgs,
+    },
+    RequestSeatAuthorized {
+        keys: RequestSeatAuthorizedKeys,
+    },
+    EvictSeat {
+        keys: EvictSeatKeys,
+    },
+    ForceCancelOrders {
+        keys: ForceCancelOrdersKeys,
+        args: ForceCancelOrdersIxArgs,
+    },
+    CollectFees {
+        keys: CollectFeesKeys,
+    },
+    ChangeFeeRecipient {
+        keys: ChangeFeeRecipientKeys,
+    },
+}
+impl PhoenixV1ParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = PhoenixV1ProgramIx::deserialize(data)?;
+        Ok(match program_ix {
+            PhoenixV1ProgramIx::Swap(args) => Self::Swap {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::SwapWithFreeFunds(args) => Self::SwapWithFreeFunds {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::PlaceLimitOrder(args) => Self::PlaceLimitOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::PlaceLimitOrderWithFreeFunds(args) => {
+                Self::PlaceLimitOrderWithFreeFunds {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            PhoenixV1ProgramIx::ReduceOrder(args) => Self::ReduceOrder {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::ReduceOrderWithFreeFunds(args) => Self::ReduceOrderWithFreeFunds {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::CancelAllOrders => Self::CancelAllOrders {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            PhoenixV1ProgramIx::CancelAllOrdersWithFreeFunds => {
+                Self::CancelAllOrdersWithFreeFunds {
+                    keys: parsed_ix_keys(accounts)?,
+                }
+            }
+            PhoenixV1ProgramIx::CancelUpTo(args) => Self::CancelUpTo {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::CancelUpToWithFreeFunds(args) => Self::CancelUpToWithFreeFunds {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::CancelMultipleOrdersById(args) => Self::CancelMultipleOrdersById {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::CancelMultipleOrdersByIdWithFreeFunds(args) => {
+                Self::CancelMultipleOrdersByIdWithFreeFunds {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            PhoenixV1ProgramIx::WithdrawFunds(args) => Self::WithdrawFunds {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::DepositFunds(args) => Self::DepositFunds {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::RequestSeat => Self::RequestSeat {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            PhoenixV1ProgramIx::Log => Self::Log {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            PhoenixV1ProgramIx::PlaceMultiplePostOnlyOrders(args) => {
+                Self::PlaceMultiplePostOnlyOrders {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            PhoenixV1ProgramIx::PlaceMultiplePostOnlyOrdersWithFreeFunds(args) => {
+                Self::PlaceMultiplePostOnlyOrdersWithFreeFunds {
+                    keys: parsed_ix_keys(accounts)?,
+                    args,
+                }
+            }
+            PhoenixV1ProgramIx::InitializeMarket(args) => Self::InitializeMarket {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::ClaimAuthority => Self::ClaimAuthority {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            PhoenixV1ProgramIx::NameSuccessor(args) => Self::NameSuccessor {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::ChangeMarketStatus(args) => Self::ChangeMarketStatus {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::ChangeSeatStatus(args) => Self::ChangeSeatStatus {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::RequestSeatAuthorized => Self::RequestSeatAuthorized {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            PhoenixV1ProgramIx::EvictSeat => Self::EvictSeat {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            PhoenixV1ProgramIx::ForceCancelOrders(args) => Self::ForceCancelOrders {
+                keys: parsed_ix_keys(accounts)?,
+                args,
+            },
+            PhoenixV1ProgramIx::CollectFees => Self::CollectFees {
+                keys: parsed_ix_keys(accounts)?,
+            },
+            PhoenixV1ProgramIx::ChangeFeeRecipient => Self::ChangeFeeRecipient {
+                keys: parsed_ix_keys(accounts)?,
+            },
+        })
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
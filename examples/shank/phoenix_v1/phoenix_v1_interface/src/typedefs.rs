@@ -7,6 +7,10 @@ use solana_program::pubkey::Pubkey;
 pub struct Ticks {
     pub inner: u64,
 }
+/// Serialized size of [`Ticks`]
+pub const TICKS_LEN: usize = std::mem::size_of::<Ticks>();
+/// Offset of `inner` in serialized [`Ticks`]
+pub const TICKS_INNER_OFFSET: usize = 0;
 #[repr(C)]
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -15,6 +19,14 @@ pub struct MarketSizeParams {
     pub asks_size: u64,
     pub num_seats: u64,
 }
+/// Serialized size of [`MarketSizeParams`]
+pub const MARKET_SIZE_PARAMS_LEN: usize = std::mem::size_of::<MarketSizeParams>();
+/// Offset of `bids_size` in serialized [`MarketSizeParams`]
+pub const MARKET_SIZE_PARAMS_BIDS_SIZE_OFFSET: usize = 0;
+/// Offset of `asks_size` in serialized [`MarketSizeParams`]
+pub const MARKET_SIZE_PARAMS_ASKS_SIZE_OFFSET: usize = 8;
+/// Offset of `num_seats` in serialized [`MarketSizeParams`]
+pub const MARKET_SIZE_PARAMS_NUM_SEATS_OFFSET: usize = 16;
 #[repr(C)]
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -24,6 +36,16 @@ pub struct TokenParams {
     pub mint_key: Pubkey,
     pub vault_key: Pubkey,
 }
+/// Serialized size of [`TokenParams`]
+pub const TOKEN_PARAMS_LEN: usize = std::mem::size_of::<TokenParams>();
+/// Offset of `decimals` in serialized [`TokenParams`]
+pub const TOKEN_PARAMS_DECIMALS_OFFSET: usize = 0;
+/// Offset of `vault_bump` in serialized [`TokenParams`]
+pub const TOKEN_PARAMS_VAULT_BUMP_OFFSET: usize = 4;
+/// Offset of `mint_key` in serialized [`TokenParams`]
+pub const TOKEN_PARAMS_MINT_KEY_OFFSET: usize = 8;
+/// Offset of `vault_key` in serialized [`TokenParams`]
+pub const TOKEN_PARAMS_VAULT_KEY_OFFSET: usize = 40;
 #[repr(C)]
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -34,6 +56,18 @@ pub struct Seat {
     pub approval_status: u64,
     pub padding: [u64; 6],
 }
+/// Serialized size of [`Seat`]
+pub const SEAT_LEN: usize = std::mem::size_of::<Seat>();
+/// Offset of `discriminant` in serialized [`Seat`]
+pub const SEAT_DISCRIMINANT_OFFSET: usize = 0;
+/// Offset of `market` in serialized [`Seat`]
+pub const SEAT_MARKET_OFFSET: usize = 8;
+/// Offset of `trader` in serialized [`Seat`]
+pub const SEAT_TRADER_OFFSET: usize = 40;
+/// Offset of `approval_status` in serialized [`Seat`]
+pub const SEAT_APPROVAL_STATUS_OFFSET: usize = 72;
+/// Offset of `padding` in serialized [`Seat`]
+pub const SEAT_PADDING_OFFSET: usize = 80;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AuditLogHeader {
@@ -45,6 +79,22 @@ pub struct AuditLogHeader {
     pub signer: Pubkey,
     pub total_events: u16,
 }
+/// Serialized size of [`AuditLogHeader`]
+pub const AUDIT_LOG_HEADER_LEN: usize = 91;
+/// Offset of `instruction` in serialized [`AuditLogHeader`]
+pub const AUDIT_LOG_HEADER_INSTRUCTION_OFFSET: usize = 0;
+/// Offset of `sequence_number` in serialized [`AuditLogHeader`]
+pub const AUDIT_LOG_HEADER_SEQUENCE_NUMBER_OFFSET: usize = 1;
+/// Offset of `timestamp` in serialized [`AuditLogHeader`]
+pub const AUDIT_LOG_HEADER_TIMESTAMP_OFFSET: usize = 9;
+/// Offset of `slot` in serialized [`AuditLogHeader`]
+pub const AUDIT_LOG_HEADER_SLOT_OFFSET: usize = 17;
+/// Offset of `market` in serialized [`AuditLogHeader`]
+pub const AUDIT_LOG_HEADER_MARKET_OFFSET: usize = 25;
+/// Offset of `signer` in serialized [`AuditLogHeader`]
+pub const AUDIT_LOG_HEADER_SIGNER_OFFSET: usize = 57;
+/// Offset of `total_events` in serialized [`AuditLogHeader`]
+pub const AUDIT_LOG_HEADER_TOTAL_EVENTS_OFFSET: usize = 89;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FillEvent {
@@ -55,6 +105,20 @@ pub struct FillEvent {
     pub base_lots_filled: u64,
     pub base_lots_remaining: u64,
 }
+/// Serialized size of [`FillEvent`]
+pub const FILL_EVENT_LEN: usize = 66;
+/// Offset of `index` in serialized [`FillEvent`]
+pub const FILL_EVENT_INDEX_OFFSET: usize = 0;
+/// Offset of `maker_id` in serialized [`FillEvent`]
+pub const FILL_EVENT_MAKER_ID_OFFSET: usize = 2;
+/// Offset of `order_sequence_number` in serialized [`FillEvent`]
+pub const FILL_EVENT_ORDER_SEQUENCE_NUMBER_OFFSET: usize = 34;
+/// Offset of `price_in_ticks` in serialized [`FillEvent`]
+pub const FILL_EVENT_PRICE_IN_TICKS_OFFSET: usize = 42;
+/// Offset of `base_lots_filled` in serialized [`FillEvent`]
+pub const FILL_EVENT_BASE_LOTS_FILLED_OFFSET: usize = 50;
+/// Offset of `base_lots_remaining` in serialized [`FillEvent`]
+pub const FILL_EVENT_BASE_LOTS_REMAINING_OFFSET: usize = 58;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReduceEvent {
@@ -64,6 +128,18 @@ pub struct ReduceEvent {
     pub base_lots_removed: u64,
     pub base_lots_remaining: u64,
 }
+/// Serialized size of [`ReduceEvent`]
+pub const REDUCE_EVENT_LEN: usize = 34;
+/// Offset of `index` in serialized [`ReduceEvent`]
+pub const REDUCE_EVENT_INDEX_OFFSET: usize = 0;
+/// Offset of `order_sequence_number` in serialized [`ReduceEvent`]
+pub const REDUCE_EVENT_ORDER_SEQUENCE_NUMBER_OFFSET: usize = 2;
+/// Offset of `price_in_ticks` in serialized [`ReduceEvent`]
+pub const REDUCE_EVENT_PRICE_IN_TICKS_OFFSET: usize = 10;
+/// Offset of `base_lots_removed` in serialized [`ReduceEvent`]
+pub const REDUCE_EVENT_BASE_LOTS_REMOVED_OFFSET: usize = 18;
+/// Offset of `base_lots_remaining` in serialized [`ReduceEvent`]
+pub const REDUCE_EVENT_BASE_LOTS_REMAINING_OFFSET: usize = 26;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlaceEvent {
@@ -73,6 +149,18 @@ pub struct PlaceEvent {
     pub price_in_ticks: u64,
     pub base_lots_placed: u64,
 }
+/// Serialized size of [`PlaceEvent`]
+pub const PLACE_EVENT_LEN: usize = 42;
+/// Offset of `index` in serialized [`PlaceEvent`]
+pub const PLACE_EVENT_INDEX_OFFSET: usize = 0;
+/// Offset of `order_sequence_number` in serialized [`PlaceEvent`]
+pub const PLACE_EVENT_ORDER_SEQUENCE_NUMBER_OFFSET: usize = 2;
+/// Offset of `client_order_id` in serialized [`PlaceEvent`]
+pub const PLACE_EVENT_CLIENT_ORDER_ID_OFFSET: usize = 10;
+/// Offset of `price_in_ticks` in serialized [`PlaceEvent`]
+pub const PLACE_EVENT_PRICE_IN_TICKS_OFFSET: usize = 26;
+/// Offset of `base_lots_placed` in serialized [`PlaceEvent`]
+pub const PLACE_EVENT_BASE_LOTS_PLACED_OFFSET: usize = 34;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EvictEvent {
@@ -82,6 +170,18 @@ pub struct EvictEvent {
     pub price_in_ticks: u64,
     pub base_lots_evicted: u64,
 }
+/// Serialized size of [`EvictEvent`]
+pub const EVICT_EVENT_LEN: usize = 58;
+/// Offset of `index` in serialized [`EvictEvent`]
+pub const EVICT_EVENT_INDEX_OFFSET: usize = 0;
+/// Offset of `maker_id` in serialized [`EvictEvent`]
+pub const EVICT_EVENT_MAKER_ID_OFFSET: usize = 2;
+/// Offset of `order_sequence_number` in serialized [`EvictEvent`]
+pub const EVICT_EVENT_ORDER_SEQUENCE_NUMBER_OFFSET: usize = 34;
+/// Offset of `price_in_ticks` in serialized [`EvictEvent`]
+pub const EVICT_EVENT_PRICE_IN_TICKS_OFFSET: usize = 42;
+/// Offset of `base_lots_evicted` in serialized [`EvictEvent`]
+pub const EVICT_EVENT_BASE_LOTS_EVICTED_OFFSET: usize = 50;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FillSummaryEvent {
@@ -91,12 +191,30 @@ pub struct FillSummaryEvent {
     pub total_quote_lots_filled: u64,
     pub total_fee_in_quote_lots: u64,
 }
+/// Serialized size of [`FillSummaryEvent`]
+pub const FILL_SUMMARY_EVENT_LEN: usize = 42;
+/// Offset of `index` in serialized [`FillSummaryEvent`]
+pub const FILL_SUMMARY_EVENT_INDEX_OFFSET: usize = 0;
+/// Offset of `client_order_id` in serialized [`FillSummaryEvent`]
+pub const FILL_SUMMARY_EVENT_CLIENT_ORDER_ID_OFFSET: usize = 2;
+/// Offset of `total_base_lots_filled` in serialized [`FillSummaryEvent`]
+pub const FILL_SUMMARY_EVENT_TOTAL_BASE_LOTS_FILLED_OFFSET: usize = 18;
+/// Offset of `total_quote_lots_filled` in serialized [`FillSummaryEvent`]
+pub const FILL_SUMMARY_EVENT_TOTAL_QUOTE_LOTS_FILLED_OFFSET: usize = 26;
+/// Offset of `total_fee_in_quote_lots` in serialized [`FillSummaryEvent`]
+pub const FILL_SUMMARY_EVENT_TOTAL_FEE_IN_QUOTE_LOTS_OFFSET: usize = 34;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FeeEvent {
     pub index: u16,
     pub fees_collected_in_quote_lots: u64,
 }
+/// Serialized size of [`FeeEvent`]
+pub const FEE_EVENT_LEN: usize = 10;
+/// Offset of `index` in serialized [`FeeEvent`]
+pub const FEE_EVENT_INDEX_OFFSET: usize = 0;
+/// Offset of `fees_collected_in_quote_lots` in serialized [`FeeEvent`]
+pub const FEE_EVENT_FEES_COLLECTED_IN_QUOTE_LOTS_OFFSET: usize = 2;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimeInForceEvent {
@@ -105,6 +223,16 @@ pub struct TimeInForceEvent {
     pub last_valid_slot: u64,
     pub last_valid_unix_timestamp_in_seconds: u64,
 }
+/// Serialized size of [`TimeInForceEvent`]
+pub const TIME_IN_FORCE_EVENT_LEN: usize = 26;
+/// Offset of `index` in serialized [`TimeInForceEvent`]
+pub const TIME_IN_FORCE_EVENT_INDEX_OFFSET: usize = 0;
+/// Offset of `order_sequence_number` in serialized [`TimeInForceEvent`]
+pub const TIME_IN_FORCE_EVENT_ORDER_SEQUENCE_NUMBER_OFFSET: usize = 2;
+/// Offset of `last_valid_slot` in serialized [`TimeInForceEvent`]
+pub const TIME_IN_FORCE_EVENT_LAST_VALID_SLOT_OFFSET: usize = 10;
+/// Offset of `last_valid_unix_timestamp_in_seconds` in serialized [`TimeInForceEvent`]
+pub const TIME_IN_FORCE_EVENT_LAST_VALID_UNIX_TIMESTAMP_IN_SECONDS_OFFSET: usize = 18;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExpiredOrderEvent {
@@ -114,6 +242,18 @@ pub struct ExpiredOrderEvent {
     pub price_in_ticks: u64,
     pub base_lots_removed: u64,
 }
+/// Serialized size of [`ExpiredOrderEvent`]
+pub const EXPIRED_ORDER_EVENT_LEN: usize = 58;
+/// Offset of `index` in serialized [`ExpiredOrderEvent`]
+pub const EXPIRED_ORDER_EVENT_INDEX_OFFSET: usize = 0;
+/// Offset of `maker_id` in serialized [`ExpiredOrderEvent`]
+pub const EXPIRED_ORDER_EVENT_MAKER_ID_OFFSET: usize = 2;
+/// Offset of `order_sequence_number` in serialized [`ExpiredOrderEvent`]
+pub const EXPIRED_ORDER_EVENT_ORDER_SEQUENCE_NUMBER_OFFSET: usize = 34;
+/// Offset of `price_in_ticks` in serialized [`ExpiredOrderEvent`]
+pub const EXPIRED_ORDER_EVENT_PRICE_IN_TICKS_OFFSET: usize = 42;
+/// Offset of `base_lots_removed` in serialized [`ExpiredOrderEvent`]
+pub const EXPIRED_ORDER_EVENT_BASE_LOTS_REMOVED_OFFSET: usize = 50;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CancelUpToParams {
@@ -122,17 +262,29 @@ pub struct CancelUpToParams {
     pub num_orders_to_search: Option<u32>,
     pub num_orders_to_cancel: Option<u32>,
 }
+/// Offset of `side` in serialized [`CancelUpToParams`]
+pub const CANCEL_UP_TO_PARAMS_SIDE_OFFSET: usize = 0;
+/// Offset of `tick_limit` in serialized [`CancelUpToParams`]
+pub const CANCEL_UP_TO_PARAMS_TICK_LIMIT_OFFSET: usize = 1;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CancelMultipleOrdersByIdParams {
     pub orders: Vec<CancelOrderParams>,
 }
+/// Offset of `orders` in serialized [`CancelMultipleOrdersByIdParams`]
+pub const CANCEL_MULTIPLE_ORDERS_BY_ID_PARAMS_ORDERS_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositParams {
     pub quote_lots_to_deposit: u64,
     pub base_lots_to_deposit: u64,
 }
+/// Serialized size of [`DepositParams`]
+pub const DEPOSIT_PARAMS_LEN: usize = 16;
+/// Offset of `quote_lots_to_deposit` in serialized [`DepositParams`]
+pub const DEPOSIT_PARAMS_QUOTE_LOTS_TO_DEPOSIT_OFFSET: usize = 0;
+/// Offset of `base_lots_to_deposit` in serialized [`DepositParams`]
+pub const DEPOSIT_PARAMS_BASE_LOTS_TO_DEPOSIT_OFFSET: usize = 8;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeParams {
@@ -144,6 +296,20 @@ pub struct InitializeParams {
     pub fee_collector: Pubkey,
     pub raw_base_units_per_base_unit: Option<u32>,
 }
+/// Offset of `market_size_params` in serialized [`InitializeParams`]
+pub const INITIALIZE_PARAMS_MARKET_SIZE_PARAMS_OFFSET: usize = 0;
+/// Offset of `num_quote_lots_per_quote_unit` in serialized [`InitializeParams`]
+pub const INITIALIZE_PARAMS_NUM_QUOTE_LOTS_PER_QUOTE_UNIT_OFFSET: usize = 24;
+/// Offset of `tick_size_in_quote_lots_per_base_unit` in serialized [`InitializeParams`]
+pub const INITIALIZE_PARAMS_TICK_SIZE_IN_QUOTE_LOTS_PER_BASE_UNIT_OFFSET: usize = 32;
+/// Offset of `num_base_lots_per_base_unit` in serialized [`InitializeParams`]
+pub const INITIALIZE_PARAMS_NUM_BASE_LOTS_PER_BASE_UNIT_OFFSET: usize = 40;
+/// Offset of `taker_fee_bps` in serialized [`InitializeParams`]
+pub const INITIALIZE_PARAMS_TAKER_FEE_BPS_OFFSET: usize = 48;
+/// Offset of `fee_collector` in serialized [`InitializeParams`]
+pub const INITIALIZE_PARAMS_FEE_COLLECTOR_OFFSET: usize = 50;
+/// Offset of `raw_base_units_per_base_unit` in serialized [`InitializeParams`]
+pub const INITIALIZE_PARAMS_RAW_BASE_UNITS_PER_BASE_UNIT_OFFSET: usize = 82;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MultipleOrderPacket {
@@ -152,6 +318,8 @@ pub struct MultipleOrderPacket {
     pub client_order_id: Option<u128>,
     pub reject_post_only: bool,
 }
+/// Offset of `bids` in serialized [`MultipleOrderPacket`]
+pub const MULTIPLE_ORDER_PACKET_BIDS_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CondensedOrder {
@@ -160,6 +328,12 @@ pub struct CondensedOrder {
     pub last_valid_slot: Option<u64>,
     pub last_valid_unix_timestamp_in_seconds: Option<u64>,
 }
+/// Offset of `price_in_ticks` in serialized [`CondensedOrder`]
+pub const CONDENSED_ORDER_PRICE_IN_TICKS_OFFSET: usize = 0;
+/// Offset of `size_in_base_lots` in serialized [`CondensedOrder`]
+pub const CONDENSED_ORDER_SIZE_IN_BASE_LOTS_OFFSET: usize = 8;
+/// Offset of `last_valid_slot` in serialized [`CondensedOrder`]
+pub const CONDENSED_ORDER_LAST_VALID_SLOT_OFFSET: usize = 16;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CancelOrderParams {
@@ -167,18 +341,34 @@ pub struct CancelOrderParams {
     pub price_in_ticks: u64,
     pub order_sequence_number: u64,
 }
+/// Serialized size of [`CancelOrderParams`]
+pub const CANCEL_ORDER_PARAMS_LEN: usize = 17;
+/// Offset of `side` in serialized [`CancelOrderParams`]
+pub const CANCEL_ORDER_PARAMS_SIDE_OFFSET: usize = 0;
+/// Offset of `price_in_ticks` in serialized [`CancelOrderParams`]
+pub const CANCEL_ORDER_PARAMS_PRICE_IN_TICKS_OFFSET: usize = 1;
+/// Offset of `order_sequence_number` in serialized [`CancelOrderParams`]
+pub const CANCEL_ORDER_PARAMS_ORDER_SEQUENCE_NUMBER_OFFSET: usize = 9;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReduceOrderParams {
     pub base_params: CancelOrderParams,
     pub size: u64,
 }
+/// Serialized size of [`ReduceOrderParams`]
+pub const REDUCE_ORDER_PARAMS_LEN: usize = 25;
+/// Offset of `base_params` in serialized [`ReduceOrderParams`]
+pub const REDUCE_ORDER_PARAMS_BASE_PARAMS_OFFSET: usize = 0;
+/// Offset of `size` in serialized [`ReduceOrderParams`]
+pub const REDUCE_ORDER_PARAMS_SIZE_OFFSET: usize = 17;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawParams {
     pub quote_lots_to_withdraw: Option<u64>,
     pub base_lots_to_withdraw: Option<u64>,
 }
+/// Offset of `quote_lots_to_withdraw` in serialized [`WithdrawParams`]
+pub const WITHDRAW_PARAMS_QUOTE_LOTS_TO_WITHDRAW_OFFSET: usize = 0;
 #[repr(C)]
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -199,6 +389,38 @@ pub struct MarketHeader {
     pub padding1: u32,
     pub padding2: [u64; 32],
 }
+/// Serialized size of [`MarketHeader`]
+pub const MARKET_HEADER_LEN: usize = std::mem::size_of::<MarketHeader>();
+/// Offset of `discriminant` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_DISCRIMINANT_OFFSET: usize = 0;
+/// Offset of `status` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_STATUS_OFFSET: usize = 8;
+/// Offset of `market_size_params` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_MARKET_SIZE_PARAMS_OFFSET: usize = 16;
+/// Offset of `base_params` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_BASE_PARAMS_OFFSET: usize = 40;
+/// Offset of `base_lot_size` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_BASE_LOT_SIZE_OFFSET: usize = 112;
+/// Offset of `quote_params` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_QUOTE_PARAMS_OFFSET: usize = 120;
+/// Offset of `quote_lot_size` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_QUOTE_LOT_SIZE_OFFSET: usize = 192;
+/// Offset of `tick_size_in_quote_atoms_per_base_unit` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_TICK_SIZE_IN_QUOTE_ATOMS_PER_BASE_UNIT_OFFSET: usize = 200;
+/// Offset of `authority` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_AUTHORITY_OFFSET: usize = 208;
+/// Offset of `fee_recipient` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_FEE_RECIPIENT_OFFSET: usize = 240;
+/// Offset of `market_sequence_number` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_MARKET_SEQUENCE_NUMBER_OFFSET: usize = 272;
+/// Offset of `successor` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_SUCCESSOR_OFFSET: usize = 280;
+/// Offset of `raw_base_units_per_base_unit` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_RAW_BASE_UNITS_PER_BASE_UNIT_OFFSET: usize = 312;
+/// Offset of `padding1` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_PADDING1_OFFSET: usize = 316;
+/// Offset of `padding2` in serialized [`MarketHeader`]
+pub const MARKET_HEADER_PADDING2_OFFSET: usize = 320;
 #[repr(C)]
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -206,6 +428,12 @@ pub struct FIFOOrderId {
     pub price_in_ticks: Ticks,
     pub order_sequence_number: u64,
 }
+/// Serialized size of [`FIFOOrderId`]
+pub const FIFO_ORDER_ID_LEN: usize = std::mem::size_of::<FIFOOrderId>();
+/// Offset of `price_in_ticks` in serialized [`FIFOOrderId`]
+pub const FIFO_ORDER_ID_PRICE_IN_TICKS_OFFSET: usize = 0;
+/// Offset of `order_sequence_number` in serialized [`FIFOOrderId`]
+pub const FIFO_ORDER_ID_ORDER_SEQUENCE_NUMBER_OFFSET: usize = 8;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PhoenixMarketEvent {
@@ -230,6 +458,8 @@ pub enum MarketStatus {
     Closed,
     Tombstoned,
 }
+/// Serialized size of [`MarketStatus`]
+pub const MARKET_STATUS_LEN: usize = 1;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SeatApprovalStatus {
@@ -237,6 +467,8 @@ pub enum SeatApprovalStatus {
     Approved,
     Retired,
 }
+/// Serialized size of [`SeatApprovalStatus`]
+pub const SEAT_APPROVAL_STATUS_LEN: usize = 1;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OrderPacket {
@@ -282,6 +514,8 @@ pub enum Side {
     Bid,
     Ask,
 }
+/// Serialized size of [`Side`]
+pub const SIDE_LEN: usize = 1;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelfTradeBehavior {
@@ -289,3 +523,5 @@ pub enum SelfTradeBehavior {
     CancelProvide,
     DecrementTake,
 }
+/// Serialized size of [`SelfTradeBehavior`]
+pub const SELF_TRADE_BEHAVIOR_LEN: usize = 1;
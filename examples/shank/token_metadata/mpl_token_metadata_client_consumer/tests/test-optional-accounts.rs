@@ -0,0 +1,99 @@
+use mpl_token_metadata_interface::*;
+use solana_sdk::{account_info::AccountInfo, pubkey::Pubkey};
+
+fn sample_keys() -> UnverifyCollectionKeys {
+    UnverifyCollectionKeys {
+        metadata: Pubkey::new_unique(),
+        collection_authority: Pubkey::new_unique(),
+        collection_mint: Pubkey::new_unique(),
+        collection: Pubkey::new_unique(),
+        collection_master_edition_account: Pubkey::new_unique(),
+        collection_authority_record: None,
+    }
+}
+
+#[test]
+fn test_absent_optional_account_is_program_id() {
+    let program_id = Pubkey::new_unique();
+    let keys = sample_keys();
+    let ix = unverify_collection_ix_with_program_id(program_id, keys).unwrap();
+    assert_eq!(ix.program_id, program_id);
+    assert_eq!(ix.accounts[5].pubkey, program_id);
+    assert!(!ix.accounts[5].is_writable);
+    assert!(!ix.accounts[5].is_signer);
+
+    let pubkeys: [Pubkey; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] = ix
+        .accounts
+        .iter()
+        .map(|m| m.pubkey)
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+    assert_eq!(UnverifyCollectionKeys::from((program_id, pubkeys)), keys);
+
+    let default_ix = unverify_collection_ix(keys).unwrap();
+    assert_eq!(default_ix.accounts[5].pubkey, ID);
+    assert_eq!(
+        UnverifyCollectionKeys::from(pubkeys).collection_authority_record,
+        Some(program_id)
+    );
+}
+
+#[test]
+fn test_present_optional_account_is_kept() {
+    let program_id = Pubkey::new_unique();
+    let record = Pubkey::new_unique();
+    let keys = UnverifyCollectionKeys {
+        collection_authority_record: Some(record),
+        ..sample_keys()
+    };
+    let ix = unverify_collection_ix_with_program_id(program_id, keys).unwrap();
+    assert_eq!(ix.accounts[5].pubkey, record);
+}
+
+#[test]
+fn test_verify_account_keys_with_program_id() {
+    let program_id = Pubkey::new_unique();
+    let keys = sample_keys();
+    let ix = unverify_collection_ix_with_program_id(program_id, keys).unwrap();
+    let pubkeys: Vec<Pubkey> = ix.accounts.iter().map(|m| m.pubkey).collect();
+    let owner = ID;
+    let mut lamports = [0; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN];
+    let mut data = [(); UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN].map(|_| Vec::new());
+    let account_infos: [AccountInfo; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] = pubkeys
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .map(|((key, lamports), data)| {
+            AccountInfo::new(key, false, false, lamports, data, &owner, false, 0)
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+
+    let accounts = UnverifyCollectionAccounts::from((program_id, &account_infos));
+    assert!(accounts.collection_authority_record.is_none());
+    assert_eq!(
+        unverify_collection_verify_account_keys(accounts, keys),
+        Ok(())
+    );
+
+    // an optional account must be present in both or absent from both
+    let with_record = UnverifyCollectionKeys {
+        collection_authority_record: Some(Pubkey::new_unique()),
+        ..keys
+    };
+    assert_eq!(
+        unverify_collection_verify_account_keys(accounts, with_record),
+        Err((ID, with_record.collection_authority_record.unwrap()))
+    );
+
+    let wrong_mint = UnverifyCollectionKeys {
+        collection_mint: Pubkey::new_unique(),
+        ..keys
+    };
+    assert_eq!(
+        unverify_collection_verify_account_keys(accounts, wrong_mint),
+        Err((keys.collection_mint, wrong_mint.collection_mint))
+    );
+}
@@ -3,22 +3,49 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UseAuthorityRecord {
     pub key: Key,
     pub allowed_uses: u64,
     pub bump: u8,
 }
+/// Serialized size of [`UseAuthorityRecord`]
+pub const USE_AUTHORITY_RECORD_LEN: usize = 10;
+/// Offset of `key` in serialized [`UseAuthorityRecord`]
+pub const USE_AUTHORITY_RECORD_KEY_OFFSET: usize = 0;
+/// Offset of `allowed_uses` in serialized [`UseAuthorityRecord`]
+pub const USE_AUTHORITY_RECORD_ALLOWED_USES_OFFSET: usize = 1;
+/// Offset of `bump` in serialized [`UseAuthorityRecord`]
+pub const USE_AUTHORITY_RECORD_BUMP_OFFSET: usize = 9;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CollectionAuthorityRecord {
     pub key: Key,
     pub bump: u8,
 }
+/// Serialized size of [`CollectionAuthorityRecord`]
+pub const COLLECTION_AUTHORITY_RECORD_LEN: usize = 2;
+/// Offset of `key` in serialized [`CollectionAuthorityRecord`]
+pub const COLLECTION_AUTHORITY_RECORD_KEY_OFFSET: usize = 0;
+/// Offset of `bump` in serialized [`CollectionAuthorityRecord`]
+pub const COLLECTION_AUTHORITY_RECORD_BUMP_OFFSET: usize = 1;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Metadata {
     pub key: Key,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub update_authority: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub mint: Pubkey,
     pub data: Data,
     pub primary_sale_happened: bool,
@@ -28,50 +55,132 @@ pub struct Metadata {
     pub collection: Option<Collection>,
     pub uses: Option<Uses>,
 }
+/// Offset of `key` in serialized [`Metadata`]
+pub const METADATA_KEY_OFFSET: usize = 0;
+/// Offset of `update_authority` in serialized [`Metadata`]
+pub const METADATA_UPDATE_AUTHORITY_OFFSET: usize = 1;
+/// Offset of `mint` in serialized [`Metadata`]
+pub const METADATA_MINT_OFFSET: usize = 33;
+/// Offset of `data` in serialized [`Metadata`]
+pub const METADATA_DATA_OFFSET: usize = 65;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MasterEditionV2 {
     pub key: Key,
     pub supply: u64,
     pub max_supply: Option<u64>,
 }
+/// Offset of `key` in serialized [`MasterEditionV2`]
+pub const MASTER_EDITION_V2_KEY_OFFSET: usize = 0;
+/// Offset of `supply` in serialized [`MasterEditionV2`]
+pub const MASTER_EDITION_V2_SUPPLY_OFFSET: usize = 1;
+/// Offset of `max_supply` in serialized [`MasterEditionV2`]
+pub const MASTER_EDITION_V2_MAX_SUPPLY_OFFSET: usize = 9;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MasterEditionV1 {
     pub key: Key,
     pub supply: u64,
     pub max_supply: Option<u64>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub printing_mint: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub one_time_printing_authorization_mint: Pubkey,
 }
+/// Offset of `key` in serialized [`MasterEditionV1`]
+pub const MASTER_EDITION_V1_KEY_OFFSET: usize = 0;
+/// Offset of `supply` in serialized [`MasterEditionV1`]
+pub const MASTER_EDITION_V1_SUPPLY_OFFSET: usize = 1;
+/// Offset of `max_supply` in serialized [`MasterEditionV1`]
+pub const MASTER_EDITION_V1_MAX_SUPPLY_OFFSET: usize = 9;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Edition {
     pub key: Key,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub parent: Pubkey,
     pub edition: u64,
 }
+/// Serialized size of [`Edition`]
+pub const EDITION_LEN: usize = 41;
+/// Offset of `key` in serialized [`Edition`]
+pub const EDITION_KEY_OFFSET: usize = 0;
+/// Offset of `parent` in serialized [`Edition`]
+pub const EDITION_PARENT_OFFSET: usize = 1;
+/// Offset of `edition` in serialized [`Edition`]
+pub const EDITION_EDITION_OFFSET: usize = 33;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ReservationListV2 {
     pub key: Key,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub master_edition: Pubkey,
     pub supply_snapshot: Option<u64>,
     pub reservations: Vec<Reservation>,
     pub total_reservation_spots: u64,
     pub current_reservation_spots: u64,
 }
+/// Offset of `key` in serialized [`ReservationListV2`]
+pub const RESERVATION_LIST_V2_KEY_OFFSET: usize = 0;
+/// Offset of `master_edition` in serialized [`ReservationListV2`]
+pub const RESERVATION_LIST_V2_MASTER_EDITION_OFFSET: usize = 1;
+/// Offset of `supply_snapshot` in serialized [`ReservationListV2`]
+pub const RESERVATION_LIST_V2_SUPPLY_SNAPSHOT_OFFSET: usize = 33;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ReservationListV1 {
     pub key: Key,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub master_edition: Pubkey,
     pub supply_snapshot: Option<u64>,
     pub reservations: Vec<ReservationV1>,
 }
+/// Offset of `key` in serialized [`ReservationListV1`]
+pub const RESERVATION_LIST_V1_KEY_OFFSET: usize = 0;
+/// Offset of `master_edition` in serialized [`ReservationListV1`]
+pub const RESERVATION_LIST_V1_MASTER_EDITION_OFFSET: usize = 1;
+/// Offset of `supply_snapshot` in serialized [`ReservationListV1`]
+pub const RESERVATION_LIST_V1_SUPPLY_SNAPSHOT_OFFSET: usize = 33;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EditionMarker {
     pub key: Key,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base64>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base64"))]
     pub ledger: [u8; 31],
 }
+/// Serialized size of [`EditionMarker`]
+pub const EDITION_MARKER_LEN: usize = 32;
+/// Offset of `key` in serialized [`EditionMarker`]
+pub const EDITION_MARKER_KEY_OFFSET: usize = 0;
+/// Offset of `ledger` in serialized [`EditionMarker`]
+pub const EDITION_MARKER_LEDGER_OFFSET: usize = 1;
@@ -1,209 +1,308 @@
 use solana_program::{
     decode_error::DecodeError,
+    instruction::InstructionError,
     msg,
     program_error::{PrintProgramError, ProgramError},
+    pubkey::Pubkey,
 };
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
 pub enum MplTokenMetadataError {
+    /// Failed to unpack instruction data
     #[error("Failed to unpack instruction data")]
     InstructionUnpackError = 0,
+    /// Failed to pack instruction data
     #[error("Failed to pack instruction data")]
     InstructionPackError = 1,
+    /// Lamport balance below rent-exempt threshold
     #[error("Lamport balance below rent-exempt threshold")]
     NotRentExempt = 2,
+    /// Already initialized
     #[error("Already initialized")]
     AlreadyInitialized = 3,
+    /// Uninitialized
     #[error("Uninitialized")]
     Uninitialized = 4,
+    ///  Metadata's key must match seed of ['metadata', program id, mint] provided
     #[error(" Metadata's key must match seed of ['metadata', program id, mint] provided")]
     InvalidMetadataKey = 5,
+    /// Edition's key must match seed of ['metadata', program id, name, 'edition'] provided
     #[error("Edition's key must match seed of ['metadata', program id, name, 'edition'] provided")]
     InvalidEditionKey = 6,
+    /// Update Authority given does not match
     #[error("Update Authority given does not match")]
     UpdateAuthorityIncorrect = 7,
+    /// Update Authority needs to be signer to update metadata
     #[error("Update Authority needs to be signer to update metadata")]
     UpdateAuthorityIsNotSigner = 8,
+    /// You must be the mint authority and signer on this transaction
     #[error("You must be the mint authority and signer on this transaction")]
     NotMintAuthority = 9,
+    /// Mint authority provided does not match the authority on the mint
     #[error("Mint authority provided does not match the authority on the mint")]
     InvalidMintAuthority = 10,
+    /// Name too long
     #[error("Name too long")]
     NameTooLong = 11,
+    /// Symbol too long
     #[error("Symbol too long")]
     SymbolTooLong = 12,
+    /// URI too long
     #[error("URI too long")]
     UriTooLong = 13,
+    /// Update authority must be equivalent to the metadata's authority and also signer of this transaction
     #[error(
         "Update authority must be equivalent to the metadata's authority and also signer of this transaction"
     )]
     UpdateAuthorityMustBeEqualToMetadataAuthorityAndSigner = 14,
+    /// Mint given does not match mint on Metadata
     #[error("Mint given does not match mint on Metadata")]
     MintMismatch = 15,
+    /// Editions must have exactly one token
     #[error("Editions must have exactly one token")]
     EditionsMustHaveExactlyOneToken = 16,
+    /// Maximum editions printed already
     #[error("Maximum editions printed already")]
     MaxEditionsMintedAlready = 17,
+    /// Token mint to failed
     #[error("Token mint to failed")]
     TokenMintToFailed = 18,
+    /// The master edition record passed must match the master record on the edition given
     #[error("The master edition record passed must match the master record on the edition given")]
     MasterRecordMismatch = 19,
+    /// The destination account does not have the right mint
     #[error("The destination account does not have the right mint")]
     DestinationMintMismatch = 20,
+    /// An edition can only mint one of its kind!
     #[error("An edition can only mint one of its kind!")]
     EditionAlreadyMinted = 21,
+    /// Printing mint decimals should be zero
     #[error("Printing mint decimals should be zero")]
     PrintingMintDecimalsShouldBeZero = 22,
+    /// OneTimePrintingAuthorization mint decimals should be zero
     #[error("OneTimePrintingAuthorization mint decimals should be zero")]
     OneTimePrintingAuthorizationMintDecimalsShouldBeZero = 23,
+    /// EditionMintDecimalsShouldBeZero
     #[error("EditionMintDecimalsShouldBeZero")]
     EditionMintDecimalsShouldBeZero = 24,
+    /// Token burn failed
     #[error("Token burn failed")]
     TokenBurnFailed = 25,
+    /// The One Time authorization mint does not match that on the token account!
     #[error("The One Time authorization mint does not match that on the token account!")]
     TokenAccountOneTimeAuthMintMismatch = 26,
+    /// Derived key invalid
     #[error("Derived key invalid")]
     DerivedKeyInvalid = 27,
+    /// The Printing mint does not match that on the master edition!
     #[error("The Printing mint does not match that on the master edition!")]
     PrintingMintMismatch = 28,
+    /// The One Time Printing Auth mint does not match that on the master edition!
     #[error("The One Time Printing Auth mint does not match that on the master edition!")]
     OneTimePrintingAuthMintMismatch = 29,
+    /// The mint of the token account does not match the Printing mint!
     #[error("The mint of the token account does not match the Printing mint!")]
     TokenAccountMintMismatch = 30,
+    /// The mint of the token account does not match the master metadata mint!
     #[error("The mint of the token account does not match the master metadata mint!")]
     TokenAccountMintMismatchV2 = 31,
+    /// Not enough tokens to mint a limited edition
     #[error("Not enough tokens to mint a limited edition")]
     NotEnoughTokens = 32,
+    /// The mint on your authorization token holding account does not match your Printing mint!
     #[error(
         "The mint on your authorization token holding account does not match your Printing mint!"
     )]
     PrintingMintAuthorizationAccountMismatch = 33,
+    /// The authorization token account has a different owner than the update authority for the master edition!
     #[error(
         "The authorization token account has a different owner than the update authority for the master edition!"
     )]
     AuthorizationTokenAccountOwnerMismatch = 34,
+    /// This feature is currently disabled.
     #[error("This feature is currently disabled.")]
     Disabled = 35,
+    /// Creators list too long
     #[error("Creators list too long")]
     CreatorsTooLong = 36,
+    /// Creators must be at least one if set
     #[error("Creators must be at least one if set")]
     CreatorsMustBeAtleastOne = 37,
+    /// If using a creators array, you must be one of the creators listed
     #[error("If using a creators array, you must be one of the creators listed")]
     MustBeOneOfCreators = 38,
+    /// This metadata does not have creators
     #[error("This metadata does not have creators")]
     NoCreatorsPresentOnMetadata = 39,
+    /// This creator address was not found
     #[error("This creator address was not found")]
     CreatorNotFound = 40,
+    /// Basis points cannot be more than 10000
     #[error("Basis points cannot be more than 10000")]
     InvalidBasisPoints = 41,
+    /// Primary sale can only be flipped to true and is immutable
     #[error("Primary sale can only be flipped to true and is immutable")]
     PrimarySaleCanOnlyBeFlippedToTrue = 42,
+    /// Owner does not match that on the account given
     #[error("Owner does not match that on the account given")]
     OwnerMismatch = 43,
+    /// This account has no tokens to be used for authorization
     #[error("This account has no tokens to be used for authorization")]
     NoBalanceInAccountForAuthorization = 44,
+    /// Share total must equal 100 for creator array
     #[error("Share total must equal 100 for creator array")]
     ShareTotalMustBe100 = 45,
+    /// This reservation list already exists!
     #[error("This reservation list already exists!")]
     ReservationExists = 46,
+    /// This reservation list does not exist!
     #[error("This reservation list does not exist!")]
     ReservationDoesNotExist = 47,
+    /// This reservation list exists but was never set with reservations
     #[error("This reservation list exists but was never set with reservations")]
     ReservationNotSet = 48,
+    /// This reservation list has already been set!
     #[error("This reservation list has already been set!")]
     ReservationAlreadyMade = 49,
+    /// Provided more addresses than max allowed in single reservation
     #[error("Provided more addresses than max allowed in single reservation")]
     BeyondMaxAddressSize = 50,
+    /// NumericalOverflowError
     #[error("NumericalOverflowError")]
     NumericalOverflowError = 51,
+    /// This reservation would go beyond the maximum supply of the master edition!
     #[error("This reservation would go beyond the maximum supply of the master edition!")]
     ReservationBreachesMaximumSupply = 52,
+    /// Address not in reservation!
     #[error("Address not in reservation!")]
     AddressNotInReservation = 53,
+    /// You cannot unilaterally verify another creator, they must sign
     #[error("You cannot unilaterally verify another creator, they must sign")]
     CannotVerifyAnotherCreator = 54,
+    /// You cannot unilaterally unverify another creator
     #[error("You cannot unilaterally unverify another creator")]
     CannotUnverifyAnotherCreator = 55,
+    /// In initial reservation setting, spots remaining should equal total spots
     #[error("In initial reservation setting, spots remaining should equal total spots")]
     SpotMismatch = 56,
+    /// Incorrect account owner
     #[error("Incorrect account owner")]
     IncorrectOwner = 57,
+    /// printing these tokens would breach the maximum supply limit of the master edition
     #[error("printing these tokens would breach the maximum supply limit of the master edition")]
     PrintingWouldBreachMaximumSupply = 58,
+    /// Data is immutable
     #[error("Data is immutable")]
     DataIsImmutable = 59,
+    /// No duplicate creator addresses
     #[error("No duplicate creator addresses")]
     DuplicateCreatorAddress = 60,
+    /// Reservation spots remaining should match total spots when first being created
     #[error("Reservation spots remaining should match total spots when first being created")]
     ReservationSpotsRemainingShouldMatchTotalSpotsAtStart = 61,
+    /// Invalid token program
     #[error("Invalid token program")]
     InvalidTokenProgram = 62,
+    /// Data type mismatch
     #[error("Data type mismatch")]
     DataTypeMismatch = 63,
+    /// Beyond alotted address size in reservation!
     #[error("Beyond alotted address size in reservation!")]
     BeyondAlottedAddressSize = 64,
+    /// The reservation has only been partially alotted
     #[error("The reservation has only been partially alotted")]
     ReservationNotComplete = 65,
+    /// You cannot splice over an existing reservation!
     #[error("You cannot splice over an existing reservation!")]
     TriedToReplaceAnExistingReservation = 66,
+    /// Invalid operation
     #[error("Invalid operation")]
     InvalidOperation = 67,
+    /// Invalid Owner
     #[error("Invalid Owner")]
     InvalidOwner = 68,
+    /// Printing mint supply must be zero for conversion
     #[error("Printing mint supply must be zero for conversion")]
     PrintingMintSupplyMustBeZeroForConversion = 69,
+    /// One Time Auth mint supply must be zero for conversion
     #[error("One Time Auth mint supply must be zero for conversion")]
     OneTimeAuthMintSupplyMustBeZeroForConversion = 70,
+    /// You tried to insert one edition too many into an edition mark pda
     #[error("You tried to insert one edition too many into an edition mark pda")]
     InvalidEditionIndex = 71,
+    /// In the legacy system the reservation needs to be of size one for cpu limit reasons
     #[error("In the legacy system the reservation needs to be of size one for cpu limit reasons")]
     ReservationArrayShouldBeSizeOne = 72,
+    /// Is Mutable can only be flipped to false
     #[error("Is Mutable can only be flipped to false")]
     IsMutableCanOnlyBeFlippedToFalse = 73,
+    /// Cannont Verify Collection in this Instruction
     #[error("Cannont Verify Collection in this Instruction")]
     CollectionCannotBeVerifiedInThisInstruction = 74,
+    /// This instruction was deprecated in a previous release and is now removed
     #[error("This instruction was deprecated in a previous release and is now removed")]
     Removed = 75,
+    /// This token use method is burn and there are no remaining uses, it must be burned
     #[error("This token use method is burn and there are no remaining uses, it must be burned")]
     MustBeBurned = 76,
+    /// This use method is invalid
     #[error("This use method is invalid")]
     InvalidUseMethod = 77,
+    /// Cannot Change Use Method after the first use
     #[error("Cannot Change Use Method after the first use")]
     CannotChangeUseMethodAfterFirstUse = 78,
+    /// Cannot Change Remaining or Available uses after the first use
     #[error("Cannot Change Remaining or Available uses after the first use")]
     CannotChangeUsesAfterFirstUse = 79,
+    /// Collection Not Found on Metadata
     #[error("Collection Not Found on Metadata")]
     CollectionNotFound = 80,
+    /// Collection Update Authority is invalid
     #[error("Collection Update Authority is invalid")]
     InvalidCollectionUpdateAuthority = 81,
+    /// Collection Must Be a Unique Master Edition v2
     #[error("Collection Must Be a Unique Master Edition v2")]
     CollectionMustBeAUniqueMasterEdition = 82,
+    /// The Use Authority Record Already Exists, to modify it Revoke, then Approve
     #[error("The Use Authority Record Already Exists, to modify it Revoke, then Approve")]
     UseAuthorityRecordAlreadyExists = 83,
+    /// The Use Authority Record is empty or already revoked
     #[error("The Use Authority Record is empty or already revoked")]
     UseAuthorityRecordAlreadyRevoked = 84,
+    /// This token has no uses
     #[error("This token has no uses")]
     Unusable = 85,
+    /// There are not enough Uses left on this token.
     #[error("There are not enough Uses left on this token.")]
     NotEnoughUses = 86,
+    /// This Collection Authority Record Already Exists.
     #[error("This Collection Authority Record Already Exists.")]
     CollectionAuthorityRecordAlreadyExists = 87,
+    /// This Collection Authority Record Does Not Exist.
     #[error("This Collection Authority Record Does Not Exist.")]
     CollectionAuthorityDoesNotExist = 88,
+    /// This Use Authority Record is invalid.
     #[error("This Use Authority Record is invalid.")]
     InvalidUseAuthorityRecord = 89,
+    /// This Collection Authority Record is invalid.
     #[error("This Collection Authority Record is invalid.")]
     InvalidCollectionAuthorityRecord = 90,
+    /// Metadata does not match the freeze authority on the mint
     #[error("Metadata does not match the freeze authority on the mint")]
     InvalidFreezeAuthority = 91,
+    /// All tokens in this account have not been delegated to this user.
     #[error("All tokens in this account have not been delegated to this user.")]
     InvalidDelegate = 92,
+    /// Creator can not be adjusted once they are verified.
     #[error("Creator can not be adjusted once they are verified.")]
     CannotAdjustVerifiedCreator = 93,
+    /// Verified creators cannot be removed.
     #[error("Verified creators cannot be removed.")]
     CannotRemoveVerifiedCreator = 94,
+    /// Can not wipe verified creators.
     #[error("Can not wipe verified creators.")]
     CannotWipeVerifiedCreators = 95,
+    /// Not allowed to change seller fee basis points.
     #[error("Not allowed to change seller fee basis points.")]
     NotAllowedToChangeSellerFeeBasisPoints = 96,
 }
@@ -229,3 +328,66 @@ impl PrintProgramError for MplTokenMetadataError {
         msg!(&self.to_string());
     }
 }
+impl TryFrom<u32> for MplTokenMetadataError {
+    type Error = u32;
+    /// Returns `code` back if it is not one of this program's errors
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        <Self as num_traits::FromPrimitive>::from_u32(code).ok_or(code)
+    }
+}
+impl<'a> TryFrom<&'a ProgramError> for MplTokenMetadataError {
+    type Error = &'a ProgramError;
+    /// Returns `e` back if it is not a `ProgramError::Custom` of this program's errors
+    fn try_from(e: &'a ProgramError) -> Result<Self, Self::Error> {
+        match e {
+            ProgramError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl<'a> TryFrom<&'a InstructionError> for MplTokenMetadataError {
+    type Error = &'a InstructionError;
+    /// Returns `e` back if it is not an `InstructionError::Custom` of this program's errors
+    fn try_from(e: &'a InstructionError) -> Result<Self, Self::Error> {
+        match e {
+            InstructionError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+            _ => Err(e),
+        }
+    }
+}
+impl MplTokenMetadataError {
+    /// Parses a `custom program error: 0x...` log line
+    /// into the error, e.g. `Program <id> failed: custom program error: 0x1770`.
+    /// Returns `None` for other logs and codes that are not of this program's errors.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let code = if let Some((_, hex)) = log.split_once("custom program error: 0x") {
+            u32::from_str_radix(hex.trim_end(), 16).ok()?
+        } else {
+            return None;
+        };
+        Self::try_from(code).ok()
+    }
+}
+/// Parses the error the program failed with from a transaction's log messages.
+///
+/// Only the first `Program <id> failed: ` log is considered, since programs that
+/// invoked the failing program log the same error when failing in turn.
+/// Returns `None` if the failing program is not `program_id`
+/// or it did not fail with one of its errors.
+pub fn parse_error_from_logs_with_program_id(
+    program_id: Pubkey,
+    logs: &[String],
+) -> Option<MplTokenMetadataError> {
+    let (program, failed_log) = logs.iter().find_map(|log| {
+        let mut words = log.strip_prefix("Program ")?.split(' ');
+        let program = words.next()?.parse::<Pubkey>().ok()?;
+        (words.next() == Some("failed:")).then_some((program, log))
+    })?;
+    if program != program_id {
+        return None;
+    }
+    MplTokenMetadataError::from_log(failed_log)
+}
+pub fn parse_error_from_logs(logs: &[String]) -> Option<MplTokenMetadataError> {
+    parse_error_from_logs_with_program_id(crate::ID, logs)
+}
@@ -237,36 +237,36 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
 pub const CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
 pub struct CreateMetadataAccountAccounts<'me, 'info> {
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
-    ///Mint of token asset
+    /// Mint of token asset
     pub mint: &'me AccountInfo<'info>,
-    ///Mint authority
+    /// Mint authority
     pub mint_authority: &'me AccountInfo<'info>,
-    ///payer
+    /// payer
     pub payer: &'me AccountInfo<'info>,
-    ///update authority info
+    /// update authority info
     pub update_authority: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CreateMetadataAccountKeys {
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: Pubkey,
-    ///Mint of token asset
+    /// Mint of token asset
     pub mint: Pubkey,
-    ///Mint authority
+    /// Mint authority
     pub mint_authority: Pubkey,
-    ///payer
+    /// payer
     pub payer: Pubkey,
-    ///update authority info
+    /// update authority info
     pub update_authority: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
 }
 impl From<CreateMetadataAccountAccounts<'_, '_>> for CreateMetadataAccountKeys {
@@ -366,9 +366,54 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_IX_ACCOU
         }
     }
 }
+impl CreateMetadataAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for CreateMetadataAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CreateMetadataAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CreateMetadataAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CREATE_METADATA_ACCOUNT_IX_DISCM: u8 = 0u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateMetadataAccountIxArgs {
     pub create_metadata_account_args: CreateMetadataAccountArgs,
 }
@@ -425,6 +470,112 @@ pub fn create_metadata_account_ix(
 ) -> std::io::Result<Instruction> {
     create_metadata_account_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`create_metadata_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CreateMetadataAccountIxBuilder {
+    metadata: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    mint_authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    create_metadata_account_args: Option<CreateMetadataAccountArgs>,
+}
+impl Default for CreateMetadataAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CreateMetadataAccountIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            mint: None,
+            mint_authority: None,
+            payer: None,
+            update_authority: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            create_metadata_account_args: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn mint_authority(mut self, mint_authority: Pubkey) -> Self {
+        self.mint_authority = Some(mint_authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn create_metadata_account_args(
+        mut self,
+        create_metadata_account_args: CreateMetadataAccountArgs,
+    ) -> Self {
+        self.create_metadata_account_args = Some(create_metadata_account_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CreateMetadataAccountIxBuilder is missing {}", field),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let mint_authority = self
+            .mint_authority
+            .ok_or_else(|| missing("mint_authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = CreateMetadataAccountIxArgs {
+            create_metadata_account_args: self
+                .create_metadata_account_args
+                .ok_or_else(|| missing("create_metadata_account_args"))?,
+        };
+        let keys = CreateMetadataAccountKeys {
+            metadata,
+            mint,
+            mint_authority,
+            payer,
+            update_authority,
+            system_program,
+            rent,
+        };
+        create_metadata_account_ix(keys, args)
+    }
+}
 pub fn create_metadata_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreateMetadataAccountAccounts<'_, '_>,
@@ -506,16 +657,16 @@ pub fn create_metadata_account_verify_account_privileges<'me, 'info>(
 pub const UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct UpdateMetadataAccountAccounts<'me, 'info> {
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Update authority key
+    /// Update authority key
     pub update_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct UpdateMetadataAccountKeys {
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///Update authority key
+    /// Update authority key
     pub update_authority: Pubkey,
 }
 impl From<UpdateMetadataAccountAccounts<'_, '_>> for UpdateMetadataAccountKeys {
@@ -567,9 +718,54 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_IX_ACCOU
         }
     }
 }
+impl UpdateMetadataAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateMetadataAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateMetadataAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UpdateMetadataAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_METADATA_ACCOUNT_IX_DISCM: u8 = 1u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateMetadataAccountIxArgs {
     pub update_metadata_account_args: UpdateMetadataAccountArgs,
 }
@@ -626,6 +822,66 @@ pub fn update_metadata_account_ix(
 ) -> std::io::Result<Instruction> {
     update_metadata_account_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_metadata_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateMetadataAccountIxBuilder {
+    metadata: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    update_metadata_account_args: Option<UpdateMetadataAccountArgs>,
+}
+impl Default for UpdateMetadataAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateMetadataAccountIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            update_authority: None,
+            update_metadata_account_args: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn update_metadata_account_args(
+        mut self,
+        update_metadata_account_args: UpdateMetadataAccountArgs,
+    ) -> Self {
+        self.update_metadata_account_args = Some(update_metadata_account_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateMetadataAccountIxBuilder is missing {}", field),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let args = UpdateMetadataAccountIxArgs {
+            update_metadata_account_args: self
+                .update_metadata_account_args
+                .ok_or_else(|| missing("update_metadata_account_args"))?,
+        };
+        let keys = UpdateMetadataAccountKeys {
+            metadata,
+            update_authority,
+        };
+        update_metadata_account_ix(keys, args)
+    }
+}
 pub fn update_metadata_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateMetadataAccountAccounts<'_, '_>,
@@ -702,60 +958,60 @@ pub fn update_metadata_account_verify_account_privileges<'me, 'info>(
 pub const DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
 pub struct DeprecatedCreateMasterEditionAccounts<'me, 'info> {
-    ///Unallocated edition V1 account with address as pda of ['metadata', program id, mint, 'edition']
+    /// Unallocated edition V1 account with address as pda of ['metadata', program id, mint, 'edition']
     pub edition: &'me AccountInfo<'info>,
-    ///Metadata mint
+    /// Metadata mint
     pub mint: &'me AccountInfo<'info>,
-    ///Printing mint - A mint you control that can mint tokens that can be exchanged for limited editions of your master edition via the MintNewEditionFromMasterEditionViaToken endpoint
+    /// Printing mint - A mint you control that can mint tokens that can be exchanged for limited editions of your master edition via the MintNewEditionFromMasterEditionViaToken endpoint
     pub printing_mint: &'me AccountInfo<'info>,
-    ///One time authorization printing mint - A mint you control that prints tokens that gives the bearer permission to mint any number of tokens from the printing mint one time via an endpoint with the token-metadata program for your metadata. Also burns the token.
+    /// One time authorization printing mint - A mint you control that prints tokens that gives the bearer permission to mint any number of tokens from the printing mint one time via an endpoint with the token-metadata program for your metadata. Also burns the token.
     pub one_time_printing_authorization_mint: &'me AccountInfo<'info>,
-    ///Current Update authority key
+    /// Current Update authority key
     pub update_authority: &'me AccountInfo<'info>,
-    ///Printing mint authority - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
+    /// Printing mint authority - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
     pub printing_mint_authority: &'me AccountInfo<'info>,
-    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub mint_authority: &'me AccountInfo<'info>,
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///payer
+    /// payer
     pub payer: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
-    ///One time authorization printing mint authority - must be provided if using max supply. THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
+    /// One time authorization printing mint authority - must be provided if using max supply. THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
     pub one_time_printing_authorization_mint_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DeprecatedCreateMasterEditionKeys {
-    ///Unallocated edition V1 account with address as pda of ['metadata', program id, mint, 'edition']
+    /// Unallocated edition V1 account with address as pda of ['metadata', program id, mint, 'edition']
     pub edition: Pubkey,
-    ///Metadata mint
+    /// Metadata mint
     pub mint: Pubkey,
-    ///Printing mint - A mint you control that can mint tokens that can be exchanged for limited editions of your master edition via the MintNewEditionFromMasterEditionViaToken endpoint
+    /// Printing mint - A mint you control that can mint tokens that can be exchanged for limited editions of your master edition via the MintNewEditionFromMasterEditionViaToken endpoint
     pub printing_mint: Pubkey,
-    ///One time authorization printing mint - A mint you control that prints tokens that gives the bearer permission to mint any number of tokens from the printing mint one time via an endpoint with the token-metadata program for your metadata. Also burns the token.
+    /// One time authorization printing mint - A mint you control that prints tokens that gives the bearer permission to mint any number of tokens from the printing mint one time via an endpoint with the token-metadata program for your metadata. Also burns the token.
     pub one_time_printing_authorization_mint: Pubkey,
-    ///Current Update authority key
+    /// Current Update authority key
     pub update_authority: Pubkey,
-    ///Printing mint authority - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
+    /// Printing mint authority - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
     pub printing_mint_authority: Pubkey,
-    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub mint_authority: Pubkey,
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///payer
+    /// payer
     pub payer: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
-    ///One time authorization printing mint authority - must be provided if using max supply. THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
+    /// One time authorization printing mint authority - must be provided if using max supply. THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
     pub one_time_printing_authorization_mint_authority: Pubkey,
 }
 impl From<DeprecatedCreateMasterEditionAccounts<'_, '_>> for DeprecatedCreateMasterEditionKeys {
@@ -921,9 +1177,57 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPRECATED_CREATE_MASTER_EDITION
         }
     }
 }
+impl DeprecatedCreateMasterEditionKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeprecatedCreateMasterEditionKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeprecatedCreateMasterEditionAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for DeprecatedCreateMasterEditionAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM: u8 = 2u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DeprecatedCreateMasterEditionIxArgs {
     pub create_master_edition_args: CreateMasterEditionArgs,
 }
@@ -982,6 +1286,178 @@ pub fn deprecated_create_master_edition_ix(
 ) -> std::io::Result<Instruction> {
     deprecated_create_master_edition_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`deprecated_create_master_edition_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeprecatedCreateMasterEditionIxBuilder {
+    edition: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    printing_mint: Option<Pubkey>,
+    one_time_printing_authorization_mint: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    printing_mint_authority: Option<Pubkey>,
+    mint_authority: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    one_time_printing_authorization_mint_authority: Option<Pubkey>,
+    create_master_edition_args: Option<CreateMasterEditionArgs>,
+}
+impl Default for DeprecatedCreateMasterEditionIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeprecatedCreateMasterEditionIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            edition: None,
+            mint: None,
+            printing_mint: None,
+            one_time_printing_authorization_mint: None,
+            update_authority: None,
+            printing_mint_authority: None,
+            mint_authority: None,
+            metadata: None,
+            payer: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            one_time_printing_authorization_mint_authority: None,
+            create_master_edition_args: None,
+        }
+    }
+    pub fn edition(mut self, edition: Pubkey) -> Self {
+        self.edition = Some(edition);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn printing_mint(mut self, printing_mint: Pubkey) -> Self {
+        self.printing_mint = Some(printing_mint);
+        self
+    }
+    pub fn one_time_printing_authorization_mint(
+        mut self,
+        one_time_printing_authorization_mint: Pubkey,
+    ) -> Self {
+        self.one_time_printing_authorization_mint = Some(one_time_printing_authorization_mint);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn printing_mint_authority(mut self, printing_mint_authority: Pubkey) -> Self {
+        self.printing_mint_authority = Some(printing_mint_authority);
+        self
+    }
+    pub fn mint_authority(mut self, mint_authority: Pubkey) -> Self {
+        self.mint_authority = Some(mint_authority);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn one_time_printing_authorization_mint_authority(
+        mut self,
+        one_time_printing_authorization_mint_authority: Pubkey,
+    ) -> Self {
+        self.one_time_printing_authorization_mint_authority =
+            Some(one_time_printing_authorization_mint_authority);
+        self
+    }
+    pub fn create_master_edition_args(
+        mut self,
+        create_master_edition_args: CreateMasterEditionArgs,
+    ) -> Self {
+        self.create_master_edition_args = Some(create_master_edition_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "DeprecatedCreateMasterEditionIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let edition = self.edition.ok_or_else(|| missing("edition"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let printing_mint = self.printing_mint.ok_or_else(|| missing("printing_mint"))?;
+        let one_time_printing_authorization_mint = self
+            .one_time_printing_authorization_mint
+            .ok_or_else(|| missing("one_time_printing_authorization_mint"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let printing_mint_authority = self
+            .printing_mint_authority
+            .ok_or_else(|| missing("printing_mint_authority"))?;
+        let mint_authority = self
+            .mint_authority
+            .ok_or_else(|| missing("mint_authority"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let one_time_printing_authorization_mint_authority = self
+            .one_time_printing_authorization_mint_authority
+            .ok_or_else(|| missing("one_time_printing_authorization_mint_authority"))?;
+        let args = DeprecatedCreateMasterEditionIxArgs {
+            create_master_edition_args: self
+                .create_master_edition_args
+                .ok_or_else(|| missing("create_master_edition_args"))?,
+        };
+        let keys = DeprecatedCreateMasterEditionKeys {
+            edition,
+            mint,
+            printing_mint,
+            one_time_printing_authorization_mint,
+            update_authority,
+            printing_mint_authority,
+            mint_authority,
+            metadata,
+            payer,
+            token_program,
+            system_program,
+            rent,
+            one_time_printing_authorization_mint_authority,
+        };
+        deprecated_create_master_edition_ix(keys, args)
+    }
+}
 pub fn deprecated_create_master_edition_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedCreateMasterEditionAccounts<'_, '_>,
@@ -1090,73 +1566,73 @@ pub const DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_
     usize = 16;
 #[derive(Copy, Clone, Debug)]
 pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info> {
-    ///New Metadata key (pda of ['metadata', program id, mint id])
+    /// New Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
-    ///New Edition V1 (pda of ['metadata', program id, mint id, 'edition'])
+    /// New Edition V1 (pda of ['metadata', program id, mint id, 'edition'])
     pub edition: &'me AccountInfo<'info>,
-    ///Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    /// Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
     pub master_edition: &'me AccountInfo<'info>,
-    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub mint: &'me AccountInfo<'info>,
-    ///Mint authority of new mint
+    /// Mint authority of new mint
     pub mint_authority: &'me AccountInfo<'info>,
-    ///Printing Mint of master record edition
+    /// Printing Mint of master record edition
     pub printing_mint: &'me AccountInfo<'info>,
-    ///Token account containing Printing mint token to be transferred
+    /// Token account containing Printing mint token to be transferred
     pub master_token_account: &'me AccountInfo<'info>,
-    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master mint id, edition_number])
+    /// Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master mint id, edition_number])
     pub edition_marker: &'me AccountInfo<'info>,
-    ///Burn authority for this token
+    /// Burn authority for this token
     pub burn_authority: &'me AccountInfo<'info>,
-    ///payer
+    /// payer
     pub payer: &'me AccountInfo<'info>,
-    ///update authority info for new metadata account
+    /// update authority info for new metadata account
     pub master_update_authority: &'me AccountInfo<'info>,
-    ///Master record metadata account
+    /// Master record metadata account
     pub master_metadata: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
-    ///Reservation List - If present, and you are on this list, you can get an edition number given by your position on the list.
-    pub reservation_list: &'me AccountInfo<'info>,
+    /// Reservation List - If present, and you are on this list, you can get an edition number given by your position on the list.
+    pub reservation_list: Option<&'me AccountInfo<'info>>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys {
-    ///New Metadata key (pda of ['metadata', program id, mint id])
+    /// New Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: Pubkey,
-    ///New Edition V1 (pda of ['metadata', program id, mint id, 'edition'])
+    /// New Edition V1 (pda of ['metadata', program id, mint id, 'edition'])
     pub edition: Pubkey,
-    ///Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    /// Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
     pub master_edition: Pubkey,
-    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub mint: Pubkey,
-    ///Mint authority of new mint
+    /// Mint authority of new mint
     pub mint_authority: Pubkey,
-    ///Printing Mint of master record edition
+    /// Printing Mint of master record edition
     pub printing_mint: Pubkey,
-    ///Token account containing Printing mint token to be transferred
+    /// Token account containing Printing mint token to be transferred
     pub master_token_account: Pubkey,
-    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master mint id, edition_number])
+    /// Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master mint id, edition_number])
     pub edition_marker: Pubkey,
-    ///Burn authority for this token
+    /// Burn authority for this token
     pub burn_authority: Pubkey,
-    ///payer
+    /// payer
     pub payer: Pubkey,
-    ///update authority info for new metadata account
+    /// update authority info for new metadata account
     pub master_update_authority: Pubkey,
-    ///Master record metadata account
+    /// Master record metadata account
     pub master_metadata: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
-    ///Reservation List - If present, and you are on this list, you can get an edition number given by your position on the list.
-    pub reservation_list: Pubkey,
+    /// Reservation List - If present, and you are on this list, you can get an edition number given by your position on the list.
+    pub reservation_list: Option<Pubkey>,
 }
 impl From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>>
     for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys
@@ -1178,107 +1654,133 @@ impl From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_,
             token_program: *accounts.token_program.key,
             system_program: *accounts.system_program.key,
             rent: *accounts.rent.key,
-            reservation_list: *accounts.reservation_list.key,
+            reservation_list: accounts.reservation_list.map(|a| *a.key),
         }
     }
 }
-impl From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys>
-    for [AccountMeta;
-        DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN]
-{
-    fn from(keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys) -> Self {
+impl DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys {
+    /// Absent optional accounts are replaced with `program_id`,
+    /// the ID of the program being invoked
+    pub fn to_metas(
+        self,
+        program_id: Pubkey,
+    ) -> [AccountMeta;
+           DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] {
         [
             AccountMeta {
-                pubkey: keys.metadata,
+                pubkey: self.metadata,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.edition,
+                pubkey: self.edition,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.master_edition,
+                pubkey: self.master_edition,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.mint,
+                pubkey: self.mint,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.mint_authority,
+                pubkey: self.mint_authority,
                 is_signer: true,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.printing_mint,
+                pubkey: self.printing_mint,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.master_token_account,
+                pubkey: self.master_token_account,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.edition_marker,
+                pubkey: self.edition_marker,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.burn_authority,
+                pubkey: self.burn_authority,
                 is_signer: true,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.payer,
+                pubkey: self.payer,
                 is_signer: true,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.master_update_authority,
+                pubkey: self.master_update_authority,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.master_metadata,
+                pubkey: self.master_metadata,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.token_program,
+                pubkey: self.token_program,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.system_program,
+                pubkey: self.system_program,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.rent,
+                pubkey: self.rent,
                 is_signer: false,
                 is_writable: false,
             },
-            AccountMeta {
-                pubkey: keys.reservation_list,
-                is_signer: false,
-                is_writable: true,
+            match self.reservation_list {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
             },
         ]
     }
 }
+impl From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys>
+    for [AccountMeta;
+        DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN]
+{
+    /// Absent optional accounts are replaced with `crate::ID`
+    fn from(keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys) -> Self {
+        keys.to_metas(crate::ID)
+    }
+}
 impl
-    From<
+    From<(
+        Pubkey,
         [Pubkey;
             DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
-    > for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys
+    )> for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys
 {
+    /// Keys equal to `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
     fn from(
-        pubkeys: [Pubkey; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+        (program_id, pubkeys): (
+            Pubkey,
+            [Pubkey;
+                DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+        ),
     ) -> Self {
         Self {
             metadata: pubkeys[0],
@@ -1296,47 +1798,70 @@ impl
             token_program: pubkeys[12],
             system_program: pubkeys[13],
             rent: pubkeys[14],
-            reservation_list: pubkeys[15],
+            reservation_list: Some(pubkeys[15]).filter(|k| *k != program_id),
         }
     }
 }
-impl<'info> From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, 'info>>
-    for [AccountInfo<'info>;
-        DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN]
+impl
+    From<
+        [Pubkey;
+            DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+    > for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys
 {
+    /// Keys equal to `crate::ID` are absent optional accounts
     fn from(
-        accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, 'info>,
+        pubkeys: [Pubkey; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
     ) -> Self {
+        Self::from((crate::ID, pubkeys))
+    }
+}
+impl<'info> DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, 'info> {
+    /// Absent optional accounts are replaced with `program`,
+    /// the AccountInfo of the program being invoked
+    pub fn to_account_infos(
+        self,
+        program: &AccountInfo<'info>,
+    ) -> [AccountInfo<'info>;
+           DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] {
         [
-            accounts.metadata.clone(),
-            accounts.edition.clone(),
-            accounts.master_edition.clone(),
-            accounts.mint.clone(),
-            accounts.mint_authority.clone(),
-            accounts.printing_mint.clone(),
-            accounts.master_token_account.clone(),
-            accounts.edition_marker.clone(),
-            accounts.burn_authority.clone(),
-            accounts.payer.clone(),
-            accounts.master_update_authority.clone(),
-            accounts.master_metadata.clone(),
-            accounts.token_program.clone(),
-            accounts.system_program.clone(),
-            accounts.rent.clone(),
-            accounts.reservation_list.clone(),
+            self.metadata.clone(),
+            self.edition.clone(),
+            self.master_edition.clone(),
+            self.mint.clone(),
+            self.mint_authority.clone(),
+            self.printing_mint.clone(),
+            self.master_token_account.clone(),
+            self.edition_marker.clone(),
+            self.burn_authority.clone(),
+            self.payer.clone(),
+            self.master_update_authority.clone(),
+            self.master_metadata.clone(),
+            self.token_program.clone(),
+            self.system_program.clone(),
+            self.rent.clone(),
+            self.reservation_list.unwrap_or(program).clone(),
         ]
     }
 }
 impl<'me, 'info>
-    From<
+    From<(
+        Pubkey,
         &'me [AccountInfo<'info>;
                  DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
-    > for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info>
+    )> for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info>
 {
+    /// Accounts with key `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
     fn from(
-        arr: &'me [AccountInfo<
-            'info,
-        >; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+        (
+            program_id,
+            arr,
+        ): (
+            Pubkey,
+            &'me [AccountInfo<
+                'info,
+            >; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+        ),
     ) -> Self {
         Self {
             metadata: &arr[0],
@@ -1354,10 +1879,77 @@ impl<'me, 'info>
             token_program: &arr[12],
             system_program: &arr[13],
             rent: &arr[14],
-            reservation_list: &arr[15],
+            reservation_list: Some(&arr[15]).filter(|a| *a.key != program_id),
         }
     }
 }
+impl<'me, 'info>
+    From<
+        &'me [AccountInfo<'info>;
+                 DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+    > for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info>
+{
+    /// Accounts with key `crate::ID` are absent optional accounts
+    fn from(
+        arr: &'me [AccountInfo<
+            'info,
+        >; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self::from((crate::ID, arr))
+    }
+}
+impl DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] = pubkeys
+            .get(
+                ..DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN,
+            )
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<
+            'info,
+        >; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] = accounts
+            .get(
+                ..DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN,
+            )
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM: u8 = 3u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData;
@@ -1396,9 +1988,7 @@ pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_ix_wit
     program_id: Pubkey,
     keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta;
-        DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] =
-        keys.into();
+    let metas = keys.to_metas(program_id);
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
@@ -1413,75 +2003,290 @@ pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_ix(
         keys,
     )
 }
-pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_with_program_id(
+/// Builds a [`deprecated_mint_new_edition_from_master_edition_via_printing_token_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxBuilder {
+    metadata: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    mint_authority: Option<Pubkey>,
+    printing_mint: Option<Pubkey>,
+    master_token_account: Option<Pubkey>,
+    edition_marker: Option<Pubkey>,
+    burn_authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    master_update_authority: Option<Pubkey>,
+    master_metadata: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    reservation_list: Option<Pubkey>,
+}
+impl Default for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            edition: None,
+            master_edition: None,
+            mint: None,
+            mint_authority: None,
+            printing_mint: None,
+            master_token_account: None,
+            edition_marker: None,
+            burn_authority: None,
+            payer: None,
+            master_update_authority: None,
+            master_metadata: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            reservation_list: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn edition(mut self, edition: Pubkey) -> Self {
+        self.edition = Some(edition);
+        self
+    }
+    pub fn master_edition(mut self, master_edition: Pubkey) -> Self {
+        self.master_edition = Some(master_edition);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn mint_authority(mut self, mint_authority: Pubkey) -> Self {
+        self.mint_authority = Some(mint_authority);
+        self
+    }
+    pub fn printing_mint(mut self, printing_mint: Pubkey) -> Self {
+        self.printing_mint = Some(printing_mint);
+        self
+    }
+    pub fn master_token_account(mut self, master_token_account: Pubkey) -> Self {
+        self.master_token_account = Some(master_token_account);
+        self
+    }
+    pub fn edition_marker(mut self, edition_marker: Pubkey) -> Self {
+        self.edition_marker = Some(edition_marker);
+        self
+    }
+    pub fn burn_authority(mut self, burn_authority: Pubkey) -> Self {
+        self.burn_authority = Some(burn_authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn master_update_authority(mut self, master_update_authority: Pubkey) -> Self {
+        self.master_update_authority = Some(master_update_authority);
+        self
+    }
+    pub fn master_metadata(mut self, master_metadata: Pubkey) -> Self {
+        self.master_metadata = Some(master_metadata);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn reservation_list(mut self, reservation_list: Pubkey) -> Self {
+        self.reservation_list = Some(reservation_list);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let edition = self.edition.ok_or_else(|| missing("edition"))?;
+        let master_edition = self
+            .master_edition
+            .ok_or_else(|| missing("master_edition"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let mint_authority = self
+            .mint_authority
+            .ok_or_else(|| missing("mint_authority"))?;
+        let printing_mint = self.printing_mint.ok_or_else(|| missing("printing_mint"))?;
+        let master_token_account = self
+            .master_token_account
+            .ok_or_else(|| missing("master_token_account"))?;
+        let edition_marker = self
+            .edition_marker
+            .ok_or_else(|| missing("edition_marker"))?;
+        let burn_authority = self
+            .burn_authority
+            .ok_or_else(|| missing("burn_authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let master_update_authority = self
+            .master_update_authority
+            .ok_or_else(|| missing("master_update_authority"))?;
+        let master_metadata = self
+            .master_metadata
+            .ok_or_else(|| missing("master_metadata"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let reservation_list = self.reservation_list;
+        let keys = DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys {
+            metadata,
+            edition,
+            master_edition,
+            mint,
+            mint_authority,
+            printing_mint,
+            master_token_account,
+            edition_marker,
+            burn_authority,
+            payer,
+            master_update_authority,
+            master_metadata,
+            token_program,
+            system_program,
+            rent,
+            reservation_list,
+        };
+        deprecated_mint_new_edition_from_master_edition_via_printing_token_ix(keys)
+    }
+}
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_with_program_id<
+    'info,
+>(
     program_id: Pubkey,
-    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>,
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
 ) -> ProgramResult {
     let keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys = accounts.into();
     let ix = deprecated_mint_new_edition_from_master_edition_via_printing_token_ix_with_program_id(
         program_id, keys,
     )?;
-    invoke_instruction(&ix, accounts)
+    invoke(&ix, &accounts.to_account_infos(program))
 }
-pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke(
-    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>,
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke<'info>(
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
 ) -> ProgramResult {
     deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_with_program_id(
         crate::ID,
         accounts,
+        program,
     )
 }
-pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_signed_with_program_id(
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_signed_with_program_id<
+    'info,
+>(
     program_id: Pubkey,
-    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>,
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys = accounts.into();
     let ix = deprecated_mint_new_edition_from_master_edition_via_printing_token_ix_with_program_id(
         program_id, keys,
     )?;
-    invoke_instruction_signed(&ix, accounts, seeds)
+    invoke_signed(&ix, &accounts.to_account_infos(program), seeds)
 }
-pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_signed(
-    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>,
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_signed<'info>(
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_signed_with_program_id(
         crate::ID,
         accounts,
+        program,
         seeds,
     )
 }
+/// Optional accounts must be present in both `accounts` and `keys` or absent from both.
+/// An absent account or key is returned as `crate::ID` in the mismatch
 pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_account_keys(
     accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>,
     keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
-        (accounts.metadata.key, &keys.metadata),
-        (accounts.edition.key, &keys.edition),
-        (accounts.master_edition.key, &keys.master_edition),
-        (accounts.mint.key, &keys.mint),
-        (accounts.mint_authority.key, &keys.mint_authority),
-        (accounts.printing_mint.key, &keys.printing_mint),
+        (Some(accounts.metadata.key), Some(&keys.metadata)),
+        (Some(accounts.edition.key), Some(&keys.edition)),
         (
-            accounts.master_token_account.key,
-            &keys.master_token_account,
+            Some(accounts.master_edition.key),
+            Some(&keys.master_edition),
         ),
-        (accounts.edition_marker.key, &keys.edition_marker),
-        (accounts.burn_authority.key, &keys.burn_authority),
-        (accounts.payer.key, &keys.payer),
+        (Some(accounts.mint.key), Some(&keys.mint)),
         (
-            accounts.master_update_authority.key,
-            &keys.master_update_authority,
+            Some(accounts.mint_authority.key),
+            Some(&keys.mint_authority),
+        ),
+        (Some(accounts.printing_mint.key), Some(&keys.printing_mint)),
+        (
+            Some(accounts.master_token_account.key),
+            Some(&keys.master_token_account),
+        ),
+        (
+            Some(accounts.edition_marker.key),
+            Some(&keys.edition_marker),
+        ),
+        (
+            Some(accounts.burn_authority.key),
+            Some(&keys.burn_authority),
+        ),
+        (Some(accounts.payer.key), Some(&keys.payer)),
+        (
+            Some(accounts.master_update_authority.key),
+            Some(&keys.master_update_authority),
+        ),
+        (
+            Some(accounts.master_metadata.key),
+            Some(&keys.master_metadata),
+        ),
+        (Some(accounts.token_program.key), Some(&keys.token_program)),
+        (
+            Some(accounts.system_program.key),
+            Some(&keys.system_program),
+        ),
+        (Some(accounts.rent.key), Some(&keys.rent)),
+        (
+            accounts.reservation_list.map(|a| a.key),
+            keys.reservation_list.as_ref(),
         ),
-        (accounts.master_metadata.key, &keys.master_metadata),
-        (accounts.token_program.key, &keys.token_program),
-        (accounts.system_program.key, &keys.system_program),
-        (accounts.rent.key, &keys.rent),
-        (accounts.reservation_list.key, &keys.reservation_list),
     ] {
         if actual != expected {
-            return Err((*actual, *expected));
+            return Err((
+                *actual.unwrap_or(&crate::ID),
+                *expected.unwrap_or(&crate::ID),
+            ));
         }
     }
     Ok(())
@@ -1493,15 +2298,18 @@ pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify
     accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_writable in [
-        accounts.metadata,
-        accounts.edition,
-        accounts.master_edition,
-        accounts.mint,
-        accounts.printing_mint,
-        accounts.master_token_account,
-        accounts.edition_marker,
+        Some(accounts.metadata),
+        Some(accounts.edition),
+        Some(accounts.master_edition),
+        Some(accounts.mint),
+        Some(accounts.printing_mint),
+        Some(accounts.master_token_account),
+        Some(accounts.edition_marker),
         accounts.reservation_list,
-    ] {
+    ]
+    .into_iter()
+    .flatten()
+    {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
@@ -1542,20 +2350,20 @@ pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify
 pub const UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct UpdatePrimarySaleHappenedViaTokenAccounts<'me, 'info> {
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
-    ///Owner on the token account
+    /// Owner on the token account
     pub owner: &'me AccountInfo<'info>,
-    ///Account containing tokens from the metadata's mint
+    /// Account containing tokens from the metadata's mint
     pub token: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct UpdatePrimarySaleHappenedViaTokenKeys {
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: Pubkey,
-    ///Owner on the token account
+    /// Owner on the token account
     pub owner: Pubkey,
-    ///Account containing tokens from the metadata's mint
+    /// Account containing tokens from the metadata's mint
     pub token: Pubkey,
 }
 impl From<UpdatePrimarySaleHappenedViaTokenAccounts<'_, '_>>
@@ -1628,6 +2436,53 @@ impl<'me, 'info>
         }
     }
 }
+impl UpdatePrimarySaleHappenedViaTokenKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdatePrimarySaleHappenedViaTokenKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdatePrimarySaleHappenedViaTokenAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdatePrimarySaleHappenedViaTokenAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM: u8 = 4u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct UpdatePrimarySaleHappenedViaTokenIxData;
@@ -1673,6 +2528,61 @@ pub fn update_primary_sale_happened_via_token_ix(
 ) -> std::io::Result<Instruction> {
     update_primary_sale_happened_via_token_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`update_primary_sale_happened_via_token_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdatePrimarySaleHappenedViaTokenIxBuilder {
+    metadata: Option<Pubkey>,
+    owner: Option<Pubkey>,
+    token: Option<Pubkey>,
+}
+impl Default for UpdatePrimarySaleHappenedViaTokenIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdatePrimarySaleHappenedViaTokenIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            owner: None,
+            token: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn token(mut self, token: Pubkey) -> Self {
+        self.token = Some(token);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "UpdatePrimarySaleHappenedViaTokenIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let owner = self.owner.ok_or_else(|| missing("owner"))?;
+        let token = self.token.ok_or_else(|| missing("token"))?;
+        let keys = UpdatePrimarySaleHappenedViaTokenKeys {
+            metadata,
+            owner,
+            token,
+        };
+        update_primary_sale_happened_via_token_ix(keys)
+    }
+}
 pub fn update_primary_sale_happened_via_token_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_, '_>,
@@ -1746,20 +2656,20 @@ pub fn update_primary_sale_happened_via_token_verify_account_privileges<'me, 'in
 pub const DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct DeprecatedSetReservationListAccounts<'me, 'info> {
-    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    /// Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
     pub master_edition: &'me AccountInfo<'info>,
-    ///PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
+    /// PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
     pub reservation_list: &'me AccountInfo<'info>,
-    ///The resource you tied the reservation list too
+    /// The resource you tied the reservation list too
     pub resource: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DeprecatedSetReservationListKeys {
-    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    /// Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
     pub master_edition: Pubkey,
-    ///PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
+    /// PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
     pub reservation_list: Pubkey,
-    ///The resource you tied the reservation list too
+    /// The resource you tied the reservation list too
     pub resource: Pubkey,
 }
 impl From<DeprecatedSetReservationListAccounts<'_, '_>> for DeprecatedSetReservationListKeys {
@@ -1829,9 +2739,57 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPRECATED_SET_RESERVATION_LIST_
         }
     }
 }
+impl DeprecatedSetReservationListKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeprecatedSetReservationListKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeprecatedSetReservationListAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for DeprecatedSetReservationListAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEPRECATED_SET_RESERVATION_LIST_IX_DISCM: u8 = 5u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DeprecatedSetReservationListIxArgs {
     pub set_reservation_list_args: SetReservationListArgs,
 }
@@ -1890,6 +2848,76 @@ pub fn deprecated_set_reservation_list_ix(
 ) -> std::io::Result<Instruction> {
     deprecated_set_reservation_list_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`deprecated_set_reservation_list_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeprecatedSetReservationListIxBuilder {
+    master_edition: Option<Pubkey>,
+    reservation_list: Option<Pubkey>,
+    resource: Option<Pubkey>,
+    set_reservation_list_args: Option<SetReservationListArgs>,
+}
+impl Default for DeprecatedSetReservationListIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeprecatedSetReservationListIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            master_edition: None,
+            reservation_list: None,
+            resource: None,
+            set_reservation_list_args: None,
+        }
+    }
+    pub fn master_edition(mut self, master_edition: Pubkey) -> Self {
+        self.master_edition = Some(master_edition);
+        self
+    }
+    pub fn reservation_list(mut self, reservation_list: Pubkey) -> Self {
+        self.reservation_list = Some(reservation_list);
+        self
+    }
+    pub fn resource(mut self, resource: Pubkey) -> Self {
+        self.resource = Some(resource);
+        self
+    }
+    pub fn set_reservation_list_args(
+        mut self,
+        set_reservation_list_args: SetReservationListArgs,
+    ) -> Self {
+        self.set_reservation_list_args = Some(set_reservation_list_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DeprecatedSetReservationListIxBuilder is missing {}", field),
+            )
+        };
+        let master_edition = self
+            .master_edition
+            .ok_or_else(|| missing("master_edition"))?;
+        let reservation_list = self
+            .reservation_list
+            .ok_or_else(|| missing("reservation_list"))?;
+        let resource = self.resource.ok_or_else(|| missing("resource"))?;
+        let args = DeprecatedSetReservationListIxArgs {
+            set_reservation_list_args: self
+                .set_reservation_list_args
+                .ok_or_else(|| missing("set_reservation_list_args"))?,
+        };
+        let keys = DeprecatedSetReservationListKeys {
+            master_edition,
+            reservation_list,
+            resource,
+        };
+        deprecated_set_reservation_list_ix(keys, args)
+    }
+}
 pub fn deprecated_set_reservation_list_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedSetReservationListAccounts<'_, '_>,
@@ -1967,40 +2995,40 @@ pub fn deprecated_set_reservation_list_verify_account_privileges<'me, 'info>(
 pub const DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN: usize = 8;
 #[derive(Copy, Clone, Debug)]
 pub struct DeprecatedCreateReservationListAccounts<'me, 'info> {
-    ///PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
+    /// PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
     pub reservation_list: &'me AccountInfo<'info>,
-    ///Payer
+    /// Payer
     pub payer: &'me AccountInfo<'info>,
-    ///Update authority
+    /// Update authority
     pub update_authority: &'me AccountInfo<'info>,
-    /// Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    ///  Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
     pub master_edition: &'me AccountInfo<'info>,
-    ///A resource you wish to tie the reservation list to. This is so your later visitors who come to redeem can derive your reservation list PDA with something they can easily get at. You choose what this should be.
+    /// A resource you wish to tie the reservation list to. This is so your later visitors who come to redeem can derive your reservation list PDA with something they can easily get at. You choose what this should be.
     pub resource: &'me AccountInfo<'info>,
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DeprecatedCreateReservationListKeys {
-    ///PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
+    /// PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
     pub reservation_list: Pubkey,
-    ///Payer
+    /// Payer
     pub payer: Pubkey,
-    ///Update authority
+    /// Update authority
     pub update_authority: Pubkey,
-    /// Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    ///  Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
     pub master_edition: Pubkey,
-    ///A resource you wish to tie the reservation list to. This is so your later visitors who come to redeem can derive your reservation list PDA with something they can easily get at. You choose what this should be.
+    /// A resource you wish to tie the reservation list to. This is so your later visitors who come to redeem can derive your reservation list PDA with something they can easily get at. You choose what this should be.
     pub resource: Pubkey,
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
 }
 impl From<DeprecatedCreateReservationListAccounts<'_, '_>> for DeprecatedCreateReservationListKeys {
@@ -2115,6 +3143,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPRECATED_CREATE_RESERVATION_LI
         }
     }
 }
+impl DeprecatedCreateReservationListKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeprecatedCreateReservationListKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeprecatedCreateReservationListAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for DeprecatedCreateReservationListAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM: u8 = 6u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct DeprecatedCreateReservationListIxData;
@@ -2160,6 +3235,111 @@ pub fn deprecated_create_reservation_list_ix(
 ) -> std::io::Result<Instruction> {
     deprecated_create_reservation_list_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`deprecated_create_reservation_list_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeprecatedCreateReservationListIxBuilder {
+    reservation_list: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    resource: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+}
+impl Default for DeprecatedCreateReservationListIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeprecatedCreateReservationListIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            reservation_list: None,
+            payer: None,
+            update_authority: None,
+            master_edition: None,
+            resource: None,
+            metadata: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+        }
+    }
+    pub fn reservation_list(mut self, reservation_list: Pubkey) -> Self {
+        self.reservation_list = Some(reservation_list);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn master_edition(mut self, master_edition: Pubkey) -> Self {
+        self.master_edition = Some(master_edition);
+        self
+    }
+    pub fn resource(mut self, resource: Pubkey) -> Self {
+        self.resource = Some(resource);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "DeprecatedCreateReservationListIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let reservation_list = self
+            .reservation_list
+            .ok_or_else(|| missing("reservation_list"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let master_edition = self
+            .master_edition
+            .ok_or_else(|| missing("master_edition"))?;
+        let resource = self.resource.ok_or_else(|| missing("resource"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let keys = DeprecatedCreateReservationListKeys {
+            reservation_list,
+            payer,
+            update_authority,
+            master_edition,
+            resource,
+            metadata,
+            system_program,
+            rent,
+        };
+        deprecated_create_reservation_list_ix(keys)
+    }
+}
 pub fn deprecated_create_reservation_list_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedCreateReservationListAccounts<'_, '_>,
@@ -2238,16 +3418,16 @@ pub fn deprecated_create_reservation_list_verify_account_privileges<'me, 'info>(
 pub const SIGN_METADATA_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct SignMetadataAccounts<'me, 'info> {
-    ///Metadata (pda of ['metadata', program id, mint id])
+    /// Metadata (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
-    ///Creator
+    /// Creator
     pub creator: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct SignMetadataKeys {
-    ///Metadata (pda of ['metadata', program id, mint id])
+    /// Metadata (pda of ['metadata', program id, mint id])
     pub metadata: Pubkey,
-    ///Creator
+    /// Creator
     pub creator: Pubkey,
 }
 impl From<SignMetadataAccounts<'_, '_>> for SignMetadataKeys {
@@ -2299,6 +3479,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SIGN_METADATA_IX_ACCOUNTS_LEN]>
         }
     }
 }
+impl SignMetadataKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SIGN_METADATA_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SIGN_METADATA_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[SIGN_METADATA_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for SignMetadataKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SignMetadataAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SIGN_METADATA_IX_ACCOUNTS_LEN] = accounts
+            .get(..SIGN_METADATA_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[SIGN_METADATA_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SignMetadataAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SIGN_METADATA_IX_DISCM: u8 = 7u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct SignMetadataIxData;
@@ -2342,6 +3560,47 @@ pub fn sign_metadata_ix_with_program_id(
 pub fn sign_metadata_ix(keys: SignMetadataKeys) -> std::io::Result<Instruction> {
     sign_metadata_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`sign_metadata_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SignMetadataIxBuilder {
+    metadata: Option<Pubkey>,
+    creator: Option<Pubkey>,
+}
+impl Default for SignMetadataIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SignMetadataIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            creator: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn creator(mut self, creator: Pubkey) -> Self {
+        self.creator = Some(creator);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SignMetadataIxBuilder is missing {}", field),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let creator = self.creator.ok_or_else(|| missing("creator"))?;
+        let keys = SignMetadataKeys { metadata, creator };
+        sign_metadata_ix(keys)
+    }
+}
 pub fn sign_metadata_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SignMetadataAccounts<'_, '_>,
@@ -2412,44 +3671,44 @@ pub fn sign_metadata_verify_account_privileges<'me, 'info>(
 pub const DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
 pub struct DeprecatedMintPrintingTokensViaTokenAccounts<'me, 'info> {
-    ///Destination account
+    /// Destination account
     pub destination: &'me AccountInfo<'info>,
-    ///Token account containing one time authorization token
+    /// Token account containing one time authorization token
     pub token: &'me AccountInfo<'info>,
-    ///One time authorization mint
+    /// One time authorization mint
     pub one_time_printing_authorization_mint: &'me AccountInfo<'info>,
-    ///Printing mint
+    /// Printing mint
     pub printing_mint: &'me AccountInfo<'info>,
-    ///Burn authority
+    /// Burn authority
     pub burn_authority: &'me AccountInfo<'info>,
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
-    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    /// Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
     pub master_edition: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
-    ///Rent
+    /// Rent
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DeprecatedMintPrintingTokensViaTokenKeys {
-    ///Destination account
+    /// Destination account
     pub destination: Pubkey,
-    ///Token account containing one time authorization token
+    /// Token account containing one time authorization token
     pub token: Pubkey,
-    ///One time authorization mint
+    /// One time authorization mint
     pub one_time_printing_authorization_mint: Pubkey,
-    ///Printing mint
+    /// Printing mint
     pub printing_mint: Pubkey,
-    ///Burn authority
+    /// Burn authority
     pub burn_authority: Pubkey,
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: Pubkey,
-    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    /// Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
     pub master_edition: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
-    ///Rent
+    /// Rent
     pub rent: Pubkey,
 }
 impl From<DeprecatedMintPrintingTokensViaTokenAccounts<'_, '_>>
@@ -2578,9 +3837,57 @@ impl<'me, 'info>
         }
     }
 }
+impl DeprecatedMintPrintingTokensViaTokenKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeprecatedMintPrintingTokensViaTokenKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeprecatedMintPrintingTokensViaTokenAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN] = accounts
+            .get(..DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for DeprecatedMintPrintingTokensViaTokenAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM: u8 = 8u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DeprecatedMintPrintingTokensViaTokenIxArgs {
     pub mint_printing_tokens_via_token_args: MintPrintingTokensViaTokenArgs,
 }
@@ -2644,6 +3951,136 @@ pub fn deprecated_mint_printing_tokens_via_token_ix(
 ) -> std::io::Result<Instruction> {
     deprecated_mint_printing_tokens_via_token_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`deprecated_mint_printing_tokens_via_token_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeprecatedMintPrintingTokensViaTokenIxBuilder {
+    destination: Option<Pubkey>,
+    token: Option<Pubkey>,
+    one_time_printing_authorization_mint: Option<Pubkey>,
+    printing_mint: Option<Pubkey>,
+    burn_authority: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    mint_printing_tokens_via_token_args: Option<MintPrintingTokensViaTokenArgs>,
+}
+impl Default for DeprecatedMintPrintingTokensViaTokenIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeprecatedMintPrintingTokensViaTokenIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            destination: None,
+            token: None,
+            one_time_printing_authorization_mint: None,
+            printing_mint: None,
+            burn_authority: None,
+            metadata: None,
+            master_edition: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            mint_printing_tokens_via_token_args: None,
+        }
+    }
+    pub fn destination(mut self, destination: Pubkey) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+    pub fn token(mut self, token: Pubkey) -> Self {
+        self.token = Some(token);
+        self
+    }
+    pub fn one_time_printing_authorization_mint(
+        mut self,
+        one_time_printing_authorization_mint: Pubkey,
+    ) -> Self {
+        self.one_time_printing_authorization_mint = Some(one_time_printing_authorization_mint);
+        self
+    }
+    pub fn printing_mint(mut self, printing_mint: Pubkey) -> Self {
+        self.printing_mint = Some(printing_mint);
+        self
+    }
+    pub fn burn_authority(mut self, burn_authority: Pubkey) -> Self {
+        self.burn_authority = Some(burn_authority);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn master_edition(mut self, master_edition: Pubkey) -> Self {
+        self.master_edition = Some(master_edition);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn mint_printing_tokens_via_token_args(
+        mut self,
+        mint_printing_tokens_via_token_args: MintPrintingTokensViaTokenArgs,
+    ) -> Self {
+        self.mint_printing_tokens_via_token_args = Some(mint_printing_tokens_via_token_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "DeprecatedMintPrintingTokensViaTokenIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let destination = self.destination.ok_or_else(|| missing("destination"))?;
+        let token = self.token.ok_or_else(|| missing("token"))?;
+        let one_time_printing_authorization_mint = self
+            .one_time_printing_authorization_mint
+            .ok_or_else(|| missing("one_time_printing_authorization_mint"))?;
+        let printing_mint = self.printing_mint.ok_or_else(|| missing("printing_mint"))?;
+        let burn_authority = self
+            .burn_authority
+            .ok_or_else(|| missing("burn_authority"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let master_edition = self
+            .master_edition
+            .ok_or_else(|| missing("master_edition"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = DeprecatedMintPrintingTokensViaTokenIxArgs {
+            mint_printing_tokens_via_token_args: self
+                .mint_printing_tokens_via_token_args
+                .ok_or_else(|| missing("mint_printing_tokens_via_token_args"))?,
+        };
+        let keys = DeprecatedMintPrintingTokensViaTokenKeys {
+            destination,
+            token,
+            one_time_printing_authorization_mint,
+            printing_mint,
+            burn_authority,
+            metadata,
+            master_edition,
+            token_program,
+            rent,
+        };
+        deprecated_mint_printing_tokens_via_token_ix(keys, args)
+    }
+}
 pub fn deprecated_mint_printing_tokens_via_token_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_, '_>,
@@ -2740,36 +4177,36 @@ pub fn deprecated_mint_printing_tokens_via_token_verify_account_privileges<'me,
 pub const DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
 pub struct DeprecatedMintPrintingTokensAccounts<'me, 'info> {
-    ///Destination account
+    /// Destination account
     pub destination: &'me AccountInfo<'info>,
-    ///Printing mint
+    /// Printing mint
     pub printing_mint: &'me AccountInfo<'info>,
-    ///Update authority
+    /// Update authority
     pub update_authority: &'me AccountInfo<'info>,
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
-    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    /// Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
     pub master_edition: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
-    ///Rent
+    /// Rent
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DeprecatedMintPrintingTokensKeys {
-    ///Destination account
+    /// Destination account
     pub destination: Pubkey,
-    ///Printing mint
+    /// Printing mint
     pub printing_mint: Pubkey,
-    ///Update authority
+    /// Update authority
     pub update_authority: Pubkey,
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: Pubkey,
-    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    /// Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
     pub master_edition: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
-    ///Rent
+    /// Rent
     pub rent: Pubkey,
 }
 impl From<DeprecatedMintPrintingTokensAccounts<'_, '_>> for DeprecatedMintPrintingTokensKeys {
@@ -2875,9 +4312,57 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_
         }
     }
 }
+impl DeprecatedMintPrintingTokensKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for DeprecatedMintPrintingTokensKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> DeprecatedMintPrintingTokensAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for DeprecatedMintPrintingTokensAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM: u8 = 9u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DeprecatedMintPrintingTokensIxArgs {
     pub mint_printing_tokens_via_token_args: MintPrintingTokensViaTokenArgs,
 }
@@ -2936,6 +4421,112 @@ pub fn deprecated_mint_printing_tokens_ix(
 ) -> std::io::Result<Instruction> {
     deprecated_mint_printing_tokens_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`deprecated_mint_printing_tokens_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct DeprecatedMintPrintingTokensIxBuilder {
+    destination: Option<Pubkey>,
+    printing_mint: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    mint_printing_tokens_via_token_args: Option<MintPrintingTokensViaTokenArgs>,
+}
+impl Default for DeprecatedMintPrintingTokensIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl DeprecatedMintPrintingTokensIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            destination: None,
+            printing_mint: None,
+            update_authority: None,
+            metadata: None,
+            master_edition: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            mint_printing_tokens_via_token_args: None,
+        }
+    }
+    pub fn destination(mut self, destination: Pubkey) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+    pub fn printing_mint(mut self, printing_mint: Pubkey) -> Self {
+        self.printing_mint = Some(printing_mint);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn master_edition(mut self, master_edition: Pubkey) -> Self {
+        self.master_edition = Some(master_edition);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn mint_printing_tokens_via_token_args(
+        mut self,
+        mint_printing_tokens_via_token_args: MintPrintingTokensViaTokenArgs,
+    ) -> Self {
+        self.mint_printing_tokens_via_token_args = Some(mint_printing_tokens_via_token_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("DeprecatedMintPrintingTokensIxBuilder is missing {}", field),
+            )
+        };
+        let destination = self.destination.ok_or_else(|| missing("destination"))?;
+        let printing_mint = self.printing_mint.ok_or_else(|| missing("printing_mint"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let master_edition = self
+            .master_edition
+            .ok_or_else(|| missing("master_edition"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = DeprecatedMintPrintingTokensIxArgs {
+            mint_printing_tokens_via_token_args: self
+                .mint_printing_tokens_via_token_args
+                .ok_or_else(|| missing("mint_printing_tokens_via_token_args"))?,
+        };
+        let keys = DeprecatedMintPrintingTokensKeys {
+            destination,
+            printing_mint,
+            update_authority,
+            metadata,
+            master_edition,
+            token_program,
+            rent,
+        };
+        deprecated_mint_printing_tokens_ix(keys, args)
+    }
+}
 pub fn deprecated_mint_printing_tokens_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedMintPrintingTokensAccounts<'_, '_>,
@@ -3017,44 +4608,44 @@ pub fn deprecated_mint_printing_tokens_verify_account_privileges<'me, 'info>(
 pub const CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
 pub struct CreateMasterEditionAccounts<'me, 'info> {
-    ///Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
+    /// Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
     pub edition: &'me AccountInfo<'info>,
-    ///Metadata mint
+    /// Metadata mint
     pub mint: &'me AccountInfo<'info>,
-    ///Update authority
+    /// Update authority
     pub update_authority: &'me AccountInfo<'info>,
-    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub mint_authority: &'me AccountInfo<'info>,
-    ///payer
+    /// payer
     pub payer: &'me AccountInfo<'info>,
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CreateMasterEditionKeys {
-    ///Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
+    /// Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
     pub edition: Pubkey,
-    ///Metadata mint
+    /// Metadata mint
     pub mint: Pubkey,
-    ///Update authority
+    /// Update authority
     pub update_authority: Pubkey,
-    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub mint_authority: Pubkey,
-    ///payer
+    /// payer
     pub payer: Pubkey,
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
 }
 impl From<CreateMasterEditionAccounts<'_, '_>> for CreateMasterEditionKeys {
@@ -3172,9 +4763,54 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_MASTER_EDITION_IX_ACCOUNT
         }
     }
 }
+impl CreateMasterEditionKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for CreateMasterEditionKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CreateMasterEditionAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] = accounts
+            .get(..CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CreateMasterEditionAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CREATE_MASTER_EDITION_IX_DISCM: u8 = 10u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateMasterEditionIxArgs {
     pub create_master_edition_args: CreateMasterEditionArgs,
 }
@@ -3231,6 +4867,130 @@ pub fn create_master_edition_ix(
 ) -> std::io::Result<Instruction> {
     create_master_edition_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`create_master_edition_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CreateMasterEditionIxBuilder {
+    edition: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    mint_authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    create_master_edition_args: Option<CreateMasterEditionArgs>,
+}
+impl Default for CreateMasterEditionIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CreateMasterEditionIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            edition: None,
+            mint: None,
+            update_authority: None,
+            mint_authority: None,
+            payer: None,
+            metadata: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            create_master_edition_args: None,
+        }
+    }
+    pub fn edition(mut self, edition: Pubkey) -> Self {
+        self.edition = Some(edition);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn mint_authority(mut self, mint_authority: Pubkey) -> Self {
+        self.mint_authority = Some(mint_authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn create_master_edition_args(
+        mut self,
+        create_master_edition_args: CreateMasterEditionArgs,
+    ) -> Self {
+        self.create_master_edition_args = Some(create_master_edition_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CreateMasterEditionIxBuilder is missing {}", field),
+            )
+        };
+        let edition = self.edition.ok_or_else(|| missing("edition"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let mint_authority = self
+            .mint_authority
+            .ok_or_else(|| missing("mint_authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = CreateMasterEditionIxArgs {
+            create_master_edition_args: self
+                .create_master_edition_args
+                .ok_or_else(|| missing("create_master_edition_args"))?,
+        };
+        let keys = CreateMasterEditionKeys {
+            edition,
+            mint,
+            update_authority,
+            mint_authority,
+            payer,
+            metadata,
+            token_program,
+            system_program,
+            rent,
+        };
+        create_master_edition_ix(keys, args)
+    }
+}
 pub fn create_master_edition_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreateMasterEditionAccounts<'_, '_>,
@@ -3318,64 +5078,64 @@ pub fn create_master_edition_verify_account_privileges<'me, 'info>(
 pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN: usize = 14;
 #[derive(Copy, Clone, Debug)]
 pub struct MintNewEditionFromMasterEditionViaTokenAccounts<'me, 'info> {
-    ///New Metadata key (pda of ['metadata', program id, mint id])
+    /// New Metadata key (pda of ['metadata', program id, mint id])
     pub new_metadata: &'me AccountInfo<'info>,
-    ///New Edition (pda of ['metadata', program id, mint id, 'edition'])
+    /// New Edition (pda of ['metadata', program id, mint id, 'edition'])
     pub new_edition: &'me AccountInfo<'info>,
-    ///Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    /// Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition'])
     pub master_edition: &'me AccountInfo<'info>,
-    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub new_mint: &'me AccountInfo<'info>,
-    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
+    /// Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
     pub edition_mark_pda: &'me AccountInfo<'info>,
-    ///Mint authority of new mint
+    /// Mint authority of new mint
     pub new_mint_authority: &'me AccountInfo<'info>,
-    ///payer
+    /// payer
     pub payer: &'me AccountInfo<'info>,
-    ///owner of token account containing master token (#8)
+    /// owner of token account containing master token (#8)
     pub token_account_owner: &'me AccountInfo<'info>,
-    ///token account containing token from master metadata mint
+    /// token account containing token from master metadata mint
     pub token_account: &'me AccountInfo<'info>,
-    ///Update authority info for new metadata
+    /// Update authority info for new metadata
     pub new_metadata_update_authority: &'me AccountInfo<'info>,
-    ///Master record metadata account
+    /// Master record metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct MintNewEditionFromMasterEditionViaTokenKeys {
-    ///New Metadata key (pda of ['metadata', program id, mint id])
+    /// New Metadata key (pda of ['metadata', program id, mint id])
     pub new_metadata: Pubkey,
-    ///New Edition (pda of ['metadata', program id, mint id, 'edition'])
+    /// New Edition (pda of ['metadata', program id, mint id, 'edition'])
     pub new_edition: Pubkey,
-    ///Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    /// Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition'])
     pub master_edition: Pubkey,
-    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub new_mint: Pubkey,
-    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
+    /// Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
     pub edition_mark_pda: Pubkey,
-    ///Mint authority of new mint
+    /// Mint authority of new mint
     pub new_mint_authority: Pubkey,
-    ///payer
+    /// payer
     pub payer: Pubkey,
-    ///owner of token account containing master token (#8)
+    /// owner of token account containing master token (#8)
     pub token_account_owner: Pubkey,
-    ///token account containing token from master metadata mint
+    /// token account containing token from master metadata mint
     pub token_account: Pubkey,
-    ///Update authority info for new metadata
+    /// Update authority info for new metadata
     pub new_metadata_update_authority: Pubkey,
-    ///Master record metadata account
+    /// Master record metadata account
     pub metadata: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
 }
 impl From<MintNewEditionFromMasterEditionViaTokenAccounts<'_, '_>>
@@ -3550,9 +5310,58 @@ impl<'me, 'info>
         }
     }
 }
+impl MintNewEditionFromMasterEditionViaTokenKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN] =
+            pubkeys
+                .get(..MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN)
+                .and_then(|keys| keys.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for MintNewEditionFromMasterEditionViaTokenKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> MintNewEditionFromMasterEditionViaTokenAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN] = accounts
+            .get(..MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for MintNewEditionFromMasterEditionViaTokenAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM: u8 = 11u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MintNewEditionFromMasterEditionViaTokenIxArgs {
     pub mint_new_edition_from_master_edition_via_token_args:
         MintNewEditionFromMasterEditionViaTokenArgs,
@@ -3617,6 +5426,181 @@ pub fn mint_new_edition_from_master_edition_via_token_ix(
 ) -> std::io::Result<Instruction> {
     mint_new_edition_from_master_edition_via_token_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`mint_new_edition_from_master_edition_via_token_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct MintNewEditionFromMasterEditionViaTokenIxBuilder {
+    new_metadata: Option<Pubkey>,
+    new_edition: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    new_mint: Option<Pubkey>,
+    edition_mark_pda: Option<Pubkey>,
+    new_mint_authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    token_account_owner: Option<Pubkey>,
+    token_account: Option<Pubkey>,
+    new_metadata_update_authority: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    mint_new_edition_from_master_edition_via_token_args:
+        Option<MintNewEditionFromMasterEditionViaTokenArgs>,
+}
+impl Default for MintNewEditionFromMasterEditionViaTokenIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl MintNewEditionFromMasterEditionViaTokenIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            new_metadata: None,
+            new_edition: None,
+            master_edition: None,
+            new_mint: None,
+            edition_mark_pda: None,
+            new_mint_authority: None,
+            payer: None,
+            token_account_owner: None,
+            token_account: None,
+            new_metadata_update_authority: None,
+            metadata: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            mint_new_edition_from_master_edition_via_token_args: None,
+        }
+    }
+    pub fn new_metadata(mut self, new_metadata: Pubkey) -> Self {
+        self.new_metadata = Some(new_metadata);
+        self
+    }
+    pub fn new_edition(mut self, new_edition: Pubkey) -> Self {
+        self.new_edition = Some(new_edition);
+        self
+    }
+    pub fn master_edition(mut self, master_edition: Pubkey) -> Self {
+        self.master_edition = Some(master_edition);
+        self
+    }
+    pub fn new_mint(mut self, new_mint: Pubkey) -> Self {
+        self.new_mint = Some(new_mint);
+        self
+    }
+    pub fn edition_mark_pda(mut self, edition_mark_pda: Pubkey) -> Self {
+        self.edition_mark_pda = Some(edition_mark_pda);
+        self
+    }
+    pub fn new_mint_authority(mut self, new_mint_authority: Pubkey) -> Self {
+        self.new_mint_authority = Some(new_mint_authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn token_account_owner(mut self, token_account_owner: Pubkey) -> Self {
+        self.token_account_owner = Some(token_account_owner);
+        self
+    }
+    pub fn token_account(mut self, token_account: Pubkey) -> Self {
+        self.token_account = Some(token_account);
+        self
+    }
+    pub fn new_metadata_update_authority(mut self, new_metadata_update_authority: Pubkey) -> Self {
+        self.new_metadata_update_authority = Some(new_metadata_update_authority);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn mint_new_edition_from_master_edition_via_token_args(
+        mut self,
+        mint_new_edition_from_master_edition_via_token_args: MintNewEditionFromMasterEditionViaTokenArgs,
+    ) -> Self {
+        self.mint_new_edition_from_master_edition_via_token_args =
+            Some(mint_new_edition_from_master_edition_via_token_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "MintNewEditionFromMasterEditionViaTokenIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let new_metadata = self.new_metadata.ok_or_else(|| missing("new_metadata"))?;
+        let new_edition = self.new_edition.ok_or_else(|| missing("new_edition"))?;
+        let master_edition = self
+            .master_edition
+            .ok_or_else(|| missing("master_edition"))?;
+        let new_mint = self.new_mint.ok_or_else(|| missing("new_mint"))?;
+        let edition_mark_pda = self
+            .edition_mark_pda
+            .ok_or_else(|| missing("edition_mark_pda"))?;
+        let new_mint_authority = self
+            .new_mint_authority
+            .ok_or_else(|| missing("new_mint_authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let token_account_owner = self
+            .token_account_owner
+            .ok_or_else(|| missing("token_account_owner"))?;
+        let token_account = self.token_account.ok_or_else(|| missing("token_account"))?;
+        let new_metadata_update_authority = self
+            .new_metadata_update_authority
+            .ok_or_else(|| missing("new_metadata_update_authority"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = MintNewEditionFromMasterEditionViaTokenIxArgs {
+            mint_new_edition_from_master_edition_via_token_args: self
+                .mint_new_edition_from_master_edition_via_token_args
+                .ok_or_else(|| missing("mint_new_edition_from_master_edition_via_token_args"))?,
+        };
+        let keys = MintNewEditionFromMasterEditionViaTokenKeys {
+            new_metadata,
+            new_edition,
+            master_edition,
+            new_mint,
+            edition_mark_pda,
+            new_mint_authority,
+            payer,
+            token_account_owner,
+            token_account,
+            new_metadata_update_authority,
+            metadata,
+            token_program,
+            system_program,
+            rent,
+        };
+        mint_new_edition_from_master_edition_via_token_ix(keys, args)
+    }
+}
 pub fn mint_new_edition_from_master_edition_via_token_invoke_with_program_id(
     program_id: Pubkey,
     accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_, '_>,
@@ -3725,20 +5709,20 @@ pub fn mint_new_edition_from_master_edition_via_token_verify_account_privileges<
 pub const CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct ConvertMasterEditionV1ToV2Accounts<'me, 'info> {
-    ///Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    /// Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
     pub master_edition: &'me AccountInfo<'info>,
-    ///One time authorization mint
+    /// One time authorization mint
     pub one_time_auth: &'me AccountInfo<'info>,
-    ///Printing mint
+    /// Printing mint
     pub printing_mint: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ConvertMasterEditionV1ToV2Keys {
-    ///Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    /// Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
     pub master_edition: Pubkey,
-    ///One time authorization mint
+    /// One time authorization mint
     pub one_time_auth: Pubkey,
-    ///Printing mint
+    /// Printing mint
     pub printing_mint: Pubkey,
 }
 impl From<ConvertMasterEditionV1ToV2Accounts<'_, '_>> for ConvertMasterEditionV1ToV2Keys {
@@ -3808,6 +5792,53 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CONVERT_MASTER_EDITION_V1_TO_V2_
         }
     }
 }
+impl ConvertMasterEditionV1ToV2Keys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ConvertMasterEditionV1ToV2Keys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ConvertMasterEditionV1ToV2Accounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN] =
+            accounts
+                .get(..CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN)
+                .and_then(|arr| arr.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for ConvertMasterEditionV1ToV2Accounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM: u8 = 12u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ConvertMasterEditionV1ToV2IxData;
@@ -3853,6 +5884,60 @@ pub fn convert_master_edition_v1_to_v2_ix(
 ) -> std::io::Result<Instruction> {
     convert_master_edition_v1_to_v2_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`convert_master_edition_v1_to_v2_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ConvertMasterEditionV1ToV2IxBuilder {
+    master_edition: Option<Pubkey>,
+    one_time_auth: Option<Pubkey>,
+    printing_mint: Option<Pubkey>,
+}
+impl Default for ConvertMasterEditionV1ToV2IxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ConvertMasterEditionV1ToV2IxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            master_edition: None,
+            one_time_auth: None,
+            printing_mint: None,
+        }
+    }
+    pub fn master_edition(mut self, master_edition: Pubkey) -> Self {
+        self.master_edition = Some(master_edition);
+        self
+    }
+    pub fn one_time_auth(mut self, one_time_auth: Pubkey) -> Self {
+        self.one_time_auth = Some(one_time_auth);
+        self
+    }
+    pub fn printing_mint(mut self, printing_mint: Pubkey) -> Self {
+        self.printing_mint = Some(printing_mint);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ConvertMasterEditionV1ToV2IxBuilder is missing {}", field),
+            )
+        };
+        let master_edition = self
+            .master_edition
+            .ok_or_else(|| missing("master_edition"))?;
+        let one_time_auth = self.one_time_auth.ok_or_else(|| missing("one_time_auth"))?;
+        let printing_mint = self.printing_mint.ok_or_else(|| missing("printing_mint"))?;
+        let keys = ConvertMasterEditionV1ToV2Keys {
+            master_edition,
+            one_time_auth,
+            printing_mint,
+        };
+        convert_master_edition_v1_to_v2_ix(keys)
+    }
+}
 pub fn convert_master_edition_v1_to_v2_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ConvertMasterEditionV1ToV2Accounts<'_, '_>,
@@ -3919,76 +6004,76 @@ pub fn convert_master_edition_v1_to_v2_verify_account_privileges<'me, 'info>(
 pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN: usize = 17;
 #[derive(Copy, Clone, Debug)]
 pub struct MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me, 'info> {
-    ///New Metadata key (pda of ['metadata', program id, mint id])
+    /// New Metadata key (pda of ['metadata', program id, mint id])
     pub new_metadata: &'me AccountInfo<'info>,
-    ///New Edition (pda of ['metadata', program id, mint id, 'edition'])
+    /// New Edition (pda of ['metadata', program id, mint id, 'edition'])
     pub new_edition: &'me AccountInfo<'info>,
-    ///Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition']
+    /// Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition']
     pub master_edition: &'me AccountInfo<'info>,
-    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub new_mint: &'me AccountInfo<'info>,
-    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
+    /// Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
     pub edition_mark_pda: &'me AccountInfo<'info>,
-    ///Mint authority of new mint
+    /// Mint authority of new mint
     pub new_mint_authority: &'me AccountInfo<'info>,
-    ///payer
+    /// payer
     pub payer: &'me AccountInfo<'info>,
-    ///Vault authority
+    /// Vault authority
     pub vault_authority: &'me AccountInfo<'info>,
-    ///Safety deposit token store account
+    /// Safety deposit token store account
     pub safety_deposit_store: &'me AccountInfo<'info>,
-    ///Safety deposit box
+    /// Safety deposit box
     pub safety_deposit_box: &'me AccountInfo<'info>,
-    ///Vault
+    /// Vault
     pub vault: &'me AccountInfo<'info>,
-    ///Update authority info for new metadata
+    /// Update authority info for new metadata
     pub new_metadata_update_authority: &'me AccountInfo<'info>,
-    ///Master record metadata account
+    /// Master record metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
-    ///Token vault program
+    /// Token vault program
     pub token_vault_program: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct MintNewEditionFromMasterEditionViaVaultProxyKeys {
-    ///New Metadata key (pda of ['metadata', program id, mint id])
+    /// New Metadata key (pda of ['metadata', program id, mint id])
     pub new_metadata: Pubkey,
-    ///New Edition (pda of ['metadata', program id, mint id, 'edition'])
+    /// New Edition (pda of ['metadata', program id, mint id, 'edition'])
     pub new_edition: Pubkey,
-    ///Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition']
+    /// Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition']
     pub master_edition: Pubkey,
-    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub new_mint: Pubkey,
-    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
+    /// Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
     pub edition_mark_pda: Pubkey,
-    ///Mint authority of new mint
+    /// Mint authority of new mint
     pub new_mint_authority: Pubkey,
-    ///payer
+    /// payer
     pub payer: Pubkey,
-    ///Vault authority
+    /// Vault authority
     pub vault_authority: Pubkey,
-    ///Safety deposit token store account
+    /// Safety deposit token store account
     pub safety_deposit_store: Pubkey,
-    ///Safety deposit box
+    /// Safety deposit box
     pub safety_deposit_box: Pubkey,
-    ///Vault
+    /// Vault
     pub vault: Pubkey,
-    ///Update authority info for new metadata
+    /// Update authority info for new metadata
     pub new_metadata_update_authority: Pubkey,
-    ///Master record metadata account
+    /// Master record metadata account
     pub metadata: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
-    ///Token vault program
+    /// Token vault program
     pub token_vault_program: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
 }
 impl From<MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, '_>>
@@ -4192,9 +6277,58 @@ impl<'me, 'info>
         }
     }
 }
+impl MintNewEditionFromMasterEditionViaVaultProxyKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN] =
+            pubkeys
+                .get(..MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN)
+                .and_then(|keys| keys.try_into().ok())
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for MintNewEditionFromMasterEditionViaVaultProxyKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>;
+                 MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN] = accounts
+            .get(..MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM: u8 = 13u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MintNewEditionFromMasterEditionViaVaultProxyIxArgs {
     pub mint_new_edition_from_master_edition_via_token_args:
         MintNewEditionFromMasterEditionViaTokenArgs,
@@ -4259,6 +6393,211 @@ pub fn mint_new_edition_from_master_edition_via_vault_proxy_ix(
 ) -> std::io::Result<Instruction> {
     mint_new_edition_from_master_edition_via_vault_proxy_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`mint_new_edition_from_master_edition_via_vault_proxy_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct MintNewEditionFromMasterEditionViaVaultProxyIxBuilder {
+    new_metadata: Option<Pubkey>,
+    new_edition: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    new_mint: Option<Pubkey>,
+    edition_mark_pda: Option<Pubkey>,
+    new_mint_authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    vault_authority: Option<Pubkey>,
+    safety_deposit_store: Option<Pubkey>,
+    safety_deposit_box: Option<Pubkey>,
+    vault: Option<Pubkey>,
+    new_metadata_update_authority: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    token_vault_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    mint_new_edition_from_master_edition_via_token_args:
+        Option<MintNewEditionFromMasterEditionViaTokenArgs>,
+}
+impl Default for MintNewEditionFromMasterEditionViaVaultProxyIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl MintNewEditionFromMasterEditionViaVaultProxyIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            new_metadata: None,
+            new_edition: None,
+            master_edition: None,
+            new_mint: None,
+            edition_mark_pda: None,
+            new_mint_authority: None,
+            payer: None,
+            vault_authority: None,
+            safety_deposit_store: None,
+            safety_deposit_box: None,
+            vault: None,
+            new_metadata_update_authority: None,
+            metadata: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            token_vault_program: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            mint_new_edition_from_master_edition_via_token_args: None,
+        }
+    }
+    pub fn new_metadata(mut self, new_metadata: Pubkey) -> Self {
+        self.new_metadata = Some(new_metadata);
+        self
+    }
+    pub fn new_edition(mut self, new_edition: Pubkey) -> Self {
+        self.new_edition = Some(new_edition);
+        self
+    }
+    pub fn master_edition(mut self, master_edition: Pubkey) -> Self {
+        self.master_edition = Some(master_edition);
+        self
+    }
+    pub fn new_mint(mut self, new_mint: Pubkey) -> Self {
+        self.new_mint = Some(new_mint);
+        self
+    }
+    pub fn edition_mark_pda(mut self, edition_mark_pda: Pubkey) -> Self {
+        self.edition_mark_pda = Some(edition_mark_pda);
+        self
+    }
+    pub fn new_mint_authority(mut self, new_mint_authority: Pubkey) -> Self {
+        self.new_mint_authority = Some(new_mint_authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn vault_authority(mut self, vault_authority: Pubkey) -> Self {
+        self.vault_authority = Some(vault_authority);
+        self
+    }
+    pub fn safety_deposit_store(mut self, safety_deposit_store: Pubkey) -> Self {
+        self.safety_deposit_store = Some(safety_deposit_store);
+        self
+    }
+    pub fn safety_deposit_box(mut self, safety_deposit_box: Pubkey) -> Self {
+        self.safety_deposit_box = Some(safety_deposit_box);
+        self
+    }
+    pub fn vault(mut self, vault: Pubkey) -> Self {
+        self.vault = Some(vault);
+        self
+    }
+    pub fn new_metadata_update_authority(mut self, new_metadata_update_authority: Pubkey) -> Self {
+        self.new_metadata_update_authority = Some(new_metadata_update_authority);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn token_vault_program(mut self, token_vault_program: Pubkey) -> Self {
+        self.token_vault_program = Some(token_vault_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn mint_new_edition_from_master_edition_via_token_args(
+        mut self,
+        mint_new_edition_from_master_edition_via_token_args: MintNewEditionFromMasterEditionViaTokenArgs,
+    ) -> Self {
+        self.mint_new_edition_from_master_edition_via_token_args =
+            Some(mint_new_edition_from_master_edition_via_token_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "MintNewEditionFromMasterEditionViaVaultProxyIxBuilder is missing {}",
+                    field
+                ),
+            )
+        };
+        let new_metadata = self.new_metadata.ok_or_else(|| missing("new_metadata"))?;
+        let new_edition = self.new_edition.ok_or_else(|| missing("new_edition"))?;
+        let master_edition = self
+            .master_edition
+            .ok_or_else(|| missing("master_edition"))?;
+        let new_mint = self.new_mint.ok_or_else(|| missing("new_mint"))?;
+        let edition_mark_pda = self
+            .edition_mark_pda
+            .ok_or_else(|| missing("edition_mark_pda"))?;
+        let new_mint_authority = self
+            .new_mint_authority
+            .ok_or_else(|| missing("new_mint_authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let vault_authority = self
+            .vault_authority
+            .ok_or_else(|| missing("vault_authority"))?;
+        let safety_deposit_store = self
+            .safety_deposit_store
+            .ok_or_else(|| missing("safety_deposit_store"))?;
+        let safety_deposit_box = self
+            .safety_deposit_box
+            .ok_or_else(|| missing("safety_deposit_box"))?;
+        let vault = self.vault.ok_or_else(|| missing("vault"))?;
+        let new_metadata_update_authority = self
+            .new_metadata_update_authority
+            .ok_or_else(|| missing("new_metadata_update_authority"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let token_vault_program = self
+            .token_vault_program
+            .ok_or_else(|| missing("token_vault_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = MintNewEditionFromMasterEditionViaVaultProxyIxArgs {
+            mint_new_edition_from_master_edition_via_token_args: self
+                .mint_new_edition_from_master_edition_via_token_args
+                .ok_or_else(|| missing("mint_new_edition_from_master_edition_via_token_args"))?,
+        };
+        let keys = MintNewEditionFromMasterEditionViaVaultProxyKeys {
+            new_metadata,
+            new_edition,
+            master_edition,
+            new_mint,
+            edition_mark_pda,
+            new_mint_authority,
+            payer,
+            vault_authority,
+            safety_deposit_store,
+            safety_deposit_box,
+            vault,
+            new_metadata_update_authority,
+            metadata,
+            token_program,
+            token_vault_program,
+            system_program,
+            rent,
+        };
+        mint_new_edition_from_master_edition_via_vault_proxy_ix(keys, args)
+    }
+}
 pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke_with_program_id(
     program_id: Pubkey,
     accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, '_>,
@@ -4385,12 +6724,12 @@ pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_account_privi
 pub const PUFF_METADATA_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
 pub struct PuffMetadataAccounts<'me, 'info> {
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct PuffMetadataKeys {
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
 }
 impl From<PuffMetadataAccounts<'_, '_>> for PuffMetadataKeys {
@@ -4430,6 +6769,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PUFF_METADATA_IX_ACCOUNTS_LEN]>
         Self { metadata: &arr[0] }
     }
 }
+impl PuffMetadataKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; PUFF_METADATA_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..PUFF_METADATA_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[PUFF_METADATA_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for PuffMetadataKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> PuffMetadataAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; PUFF_METADATA_IX_ACCOUNTS_LEN] = accounts
+            .get(..PUFF_METADATA_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[PUFF_METADATA_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for PuffMetadataAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const PUFF_METADATA_IX_DISCM: u8 = 14u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct PuffMetadataIxData;
@@ -4473,6 +6850,38 @@ pub fn puff_metadata_ix_with_program_id(
 pub fn puff_metadata_ix(keys: PuffMetadataKeys) -> std::io::Result<Instruction> {
     puff_metadata_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`puff_metadata_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct PuffMetadataIxBuilder {
+    metadata: Option<Pubkey>,
+}
+impl Default for PuffMetadataIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PuffMetadataIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self { metadata: None }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("PuffMetadataIxBuilder is missing {}", field),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let keys = PuffMetadataKeys { metadata };
+        puff_metadata_ix(keys)
+    }
+}
 pub fn puff_metadata_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PuffMetadataAccounts<'_, '_>,
@@ -4529,16 +6938,16 @@ pub fn puff_metadata_verify_account_privileges<'me, 'info>(
 pub const UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct UpdateMetadataAccountV2Accounts<'me, 'info> {
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Update authority key
+    /// Update authority key
     pub update_authority: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct UpdateMetadataAccountV2Keys {
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///Update authority key
+    /// Update authority key
     pub update_authority: Pubkey,
 }
 impl From<UpdateMetadataAccountV2Accounts<'_, '_>> for UpdateMetadataAccountV2Keys {
@@ -4592,9 +7001,56 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_V2_IX_AC
         }
     }
 }
+impl UpdateMetadataAccountV2Keys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for UpdateMetadataAccountV2Keys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UpdateMetadataAccountV2Accounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] = accounts
+            .get(..UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for UpdateMetadataAccountV2Accounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UPDATE_METADATA_ACCOUNT_V2_IX_DISCM: u8 = 15u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateMetadataAccountV2IxArgs {
     pub update_metadata_account_args_v2: UpdateMetadataAccountArgsV2,
 }
@@ -4653,6 +7109,66 @@ pub fn update_metadata_account_v2_ix(
 ) -> std::io::Result<Instruction> {
     update_metadata_account_v2_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`update_metadata_account_v2_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UpdateMetadataAccountV2IxBuilder {
+    metadata: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    update_metadata_account_args_v2: Option<UpdateMetadataAccountArgsV2>,
+}
+impl Default for UpdateMetadataAccountV2IxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UpdateMetadataAccountV2IxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            update_authority: None,
+            update_metadata_account_args_v2: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn update_metadata_account_args_v2(
+        mut self,
+        update_metadata_account_args_v2: UpdateMetadataAccountArgsV2,
+    ) -> Self {
+        self.update_metadata_account_args_v2 = Some(update_metadata_account_args_v2);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UpdateMetadataAccountV2IxBuilder is missing {}", field),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let args = UpdateMetadataAccountV2IxArgs {
+            update_metadata_account_args_v2: self
+                .update_metadata_account_args_v2
+                .ok_or_else(|| missing("update_metadata_account_args_v2"))?,
+        };
+        let keys = UpdateMetadataAccountV2Keys {
+            metadata,
+            update_authority,
+        };
+        update_metadata_account_v2_ix(keys, args)
+    }
+}
 pub fn update_metadata_account_v2_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateMetadataAccountV2Accounts<'_, '_>,
@@ -4729,36 +7245,36 @@ pub fn update_metadata_account_v2_verify_account_privileges<'me, 'info>(
 pub const CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
 pub struct CreateMetadataAccountV2Accounts<'me, 'info> {
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
-    ///Mint of token asset
+    /// Mint of token asset
     pub mint: &'me AccountInfo<'info>,
-    ///Mint authority
+    /// Mint authority
     pub mint_authority: &'me AccountInfo<'info>,
-    ///payer
+    /// payer
     pub payer: &'me AccountInfo<'info>,
-    ///update authority info
+    /// update authority info
     pub update_authority: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CreateMetadataAccountV2Keys {
-    ///Metadata key (pda of ['metadata', program id, mint id])
+    /// Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: Pubkey,
-    ///Mint of token asset
+    /// Mint of token asset
     pub mint: Pubkey,
-    ///Mint authority
+    /// Mint authority
     pub mint_authority: Pubkey,
-    ///payer
+    /// payer
     pub payer: Pubkey,
-    ///update authority info
+    /// update authority info
     pub update_authority: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
 }
 impl From<CreateMetadataAccountV2Accounts<'_, '_>> for CreateMetadataAccountV2Keys {
@@ -4860,9 +7376,56 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_V2_IX_AC
         }
     }
 }
+impl CreateMetadataAccountV2Keys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for CreateMetadataAccountV2Keys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CreateMetadataAccountV2Accounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] = accounts
+            .get(..CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for CreateMetadataAccountV2Accounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CREATE_METADATA_ACCOUNT_V2_IX_DISCM: u8 = 16u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateMetadataAccountV2IxArgs {
     pub create_metadata_account_args_v2: CreateMetadataAccountArgsV2,
 }
@@ -4921,6 +7484,112 @@ pub fn create_metadata_account_v2_ix(
 ) -> std::io::Result<Instruction> {
     create_metadata_account_v2_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`create_metadata_account_v2_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CreateMetadataAccountV2IxBuilder {
+    metadata: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    mint_authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    create_metadata_account_args_v2: Option<CreateMetadataAccountArgsV2>,
+}
+impl Default for CreateMetadataAccountV2IxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CreateMetadataAccountV2IxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            mint: None,
+            mint_authority: None,
+            payer: None,
+            update_authority: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            create_metadata_account_args_v2: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn mint_authority(mut self, mint_authority: Pubkey) -> Self {
+        self.mint_authority = Some(mint_authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn create_metadata_account_args_v2(
+        mut self,
+        create_metadata_account_args_v2: CreateMetadataAccountArgsV2,
+    ) -> Self {
+        self.create_metadata_account_args_v2 = Some(create_metadata_account_args_v2);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CreateMetadataAccountV2IxBuilder is missing {}", field),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let mint_authority = self
+            .mint_authority
+            .ok_or_else(|| missing("mint_authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = CreateMetadataAccountV2IxArgs {
+            create_metadata_account_args_v2: self
+                .create_metadata_account_args_v2
+                .ok_or_else(|| missing("create_metadata_account_args_v2"))?,
+        };
+        let keys = CreateMetadataAccountV2Keys {
+            metadata,
+            mint,
+            mint_authority,
+            payer,
+            update_authority,
+            system_program,
+            rent,
+        };
+        create_metadata_account_v2_ix(keys, args)
+    }
+}
 pub fn create_metadata_account_v2_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreateMetadataAccountV2Accounts<'_, '_>,
@@ -5002,44 +7671,44 @@ pub fn create_metadata_account_v2_verify_account_privileges<'me, 'info>(
 pub const CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
 pub struct CreateMasterEditionV3Accounts<'me, 'info> {
-    ///Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
+    /// Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
     pub edition: &'me AccountInfo<'info>,
-    ///Metadata mint
+    /// Metadata mint
     pub mint: &'me AccountInfo<'info>,
-    ///Update authority
+    /// Update authority
     pub update_authority: &'me AccountInfo<'info>,
-    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub mint_authority: &'me AccountInfo<'info>,
-    ///payer
+    /// payer
     pub payer: &'me AccountInfo<'info>,
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CreateMasterEditionV3Keys {
-    ///Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
+    /// Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
     pub edition: Pubkey,
-    ///Metadata mint
+    /// Metadata mint
     pub mint: Pubkey,
-    ///Update authority
+    /// Update authority
     pub update_authority: Pubkey,
-    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    /// Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
     pub mint_authority: Pubkey,
-    ///payer
+    /// payer
     pub payer: Pubkey,
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
 }
 impl From<CreateMasterEditionV3Accounts<'_, '_>> for CreateMasterEditionV3Keys {
@@ -5157,9 +7826,54 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_MASTER_EDITION_V3_IX_ACCO
         }
     }
 }
+impl CreateMasterEditionV3Keys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for CreateMasterEditionV3Keys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> CreateMasterEditionV3Accounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN] = accounts
+            .get(..CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for CreateMasterEditionV3Accounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const CREATE_MASTER_EDITION_V3_IX_DISCM: u8 = 17u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateMasterEditionV3IxArgs {
     pub create_master_edition_args: CreateMasterEditionArgs,
 }
@@ -5216,6 +7930,130 @@ pub fn create_master_edition_v3_ix(
 ) -> std::io::Result<Instruction> {
     create_master_edition_v3_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`create_master_edition_v3_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct CreateMasterEditionV3IxBuilder {
+    edition: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    mint_authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    create_master_edition_args: Option<CreateMasterEditionArgs>,
+}
+impl Default for CreateMasterEditionV3IxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl CreateMasterEditionV3IxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            edition: None,
+            mint: None,
+            update_authority: None,
+            mint_authority: None,
+            payer: None,
+            metadata: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            create_master_edition_args: None,
+        }
+    }
+    pub fn edition(mut self, edition: Pubkey) -> Self {
+        self.edition = Some(edition);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn mint_authority(mut self, mint_authority: Pubkey) -> Self {
+        self.mint_authority = Some(mint_authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn create_master_edition_args(
+        mut self,
+        create_master_edition_args: CreateMasterEditionArgs,
+    ) -> Self {
+        self.create_master_edition_args = Some(create_master_edition_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("CreateMasterEditionV3IxBuilder is missing {}", field),
+            )
+        };
+        let edition = self.edition.ok_or_else(|| missing("edition"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let mint_authority = self
+            .mint_authority
+            .ok_or_else(|| missing("mint_authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = CreateMasterEditionV3IxArgs {
+            create_master_edition_args: self
+                .create_master_edition_args
+                .ok_or_else(|| missing("create_master_edition_args"))?,
+        };
+        let keys = CreateMasterEditionV3Keys {
+            edition,
+            mint,
+            update_authority,
+            mint_authority,
+            payer,
+            metadata,
+            token_program,
+            system_program,
+            rent,
+        };
+        create_master_edition_v3_ix(keys, args)
+    }
+}
 pub fn create_master_edition_v3_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreateMasterEditionV3Accounts<'_, '_>,
@@ -5303,32 +8141,32 @@ pub fn create_master_edition_v3_verify_account_privileges<'me, 'info>(
 pub const VERIFY_COLLECTION_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct VerifyCollectionAccounts<'me, 'info> {
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Collection Update authority
+    /// Collection Update authority
     pub collection_authority: &'me AccountInfo<'info>,
-    ///payer
+    /// payer
     pub payer: &'me AccountInfo<'info>,
-    ///Mint of the Collection
+    /// Mint of the Collection
     pub collection_mint: &'me AccountInfo<'info>,
-    ///Metadata Account of the Collection
+    /// Metadata Account of the Collection
     pub collection: &'me AccountInfo<'info>,
-    ///MasterEdition2 Account of the Collection Token
+    /// MasterEdition2 Account of the Collection Token
     pub collection_master_edition_account: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct VerifyCollectionKeys {
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///Collection Update authority
+    /// Collection Update authority
     pub collection_authority: Pubkey,
-    ///payer
+    /// payer
     pub payer: Pubkey,
-    ///Mint of the Collection
+    /// Mint of the Collection
     pub collection_mint: Pubkey,
-    ///Metadata Account of the Collection
+    /// Metadata Account of the Collection
     pub collection: Pubkey,
-    ///MasterEdition2 Account of the Collection Token
+    /// MasterEdition2 Account of the Collection Token
     pub collection_master_edition_account: Pubkey,
 }
 impl From<VerifyCollectionAccounts<'_, '_>> for VerifyCollectionKeys {
@@ -5419,6 +8257,44 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; VERIFY_COLLECTION_IX_ACCOUNTS_LE
         }
     }
 }
+impl VerifyCollectionKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; VERIFY_COLLECTION_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..VERIFY_COLLECTION_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[VERIFY_COLLECTION_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for VerifyCollectionKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> VerifyCollectionAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; VERIFY_COLLECTION_IX_ACCOUNTS_LEN] = accounts
+            .get(..VERIFY_COLLECTION_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[VERIFY_COLLECTION_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for VerifyCollectionAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const VERIFY_COLLECTION_IX_DISCM: u8 = 18u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct VerifyCollectionIxData;
@@ -5462,6 +8338,91 @@ pub fn verify_collection_ix_with_program_id(
 pub fn verify_collection_ix(keys: VerifyCollectionKeys) -> std::io::Result<Instruction> {
     verify_collection_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`verify_collection_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct VerifyCollectionIxBuilder {
+    metadata: Option<Pubkey>,
+    collection_authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    collection_mint: Option<Pubkey>,
+    collection: Option<Pubkey>,
+    collection_master_edition_account: Option<Pubkey>,
+}
+impl Default for VerifyCollectionIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl VerifyCollectionIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            collection_authority: None,
+            payer: None,
+            collection_mint: None,
+            collection: None,
+            collection_master_edition_account: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn collection_authority(mut self, collection_authority: Pubkey) -> Self {
+        self.collection_authority = Some(collection_authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn collection_mint(mut self, collection_mint: Pubkey) -> Self {
+        self.collection_mint = Some(collection_mint);
+        self
+    }
+    pub fn collection(mut self, collection: Pubkey) -> Self {
+        self.collection = Some(collection);
+        self
+    }
+    pub fn collection_master_edition_account(
+        mut self,
+        collection_master_edition_account: Pubkey,
+    ) -> Self {
+        self.collection_master_edition_account = Some(collection_master_edition_account);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("VerifyCollectionIxBuilder is missing {}", field),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let collection_authority = self
+            .collection_authority
+            .ok_or_else(|| missing("collection_authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let collection_mint = self
+            .collection_mint
+            .ok_or_else(|| missing("collection_mint"))?;
+        let collection = self.collection.ok_or_else(|| missing("collection"))?;
+        let collection_master_edition_account = self
+            .collection_master_edition_account
+            .ok_or_else(|| missing("collection_master_edition_account"))?;
+        let keys = VerifyCollectionKeys {
+            metadata,
+            collection_authority,
+            payer,
+            collection_mint,
+            collection,
+            collection_master_edition_account,
+        };
+        verify_collection_ix(keys)
+    }
+}
 pub fn verify_collection_invoke_with_program_id(
     program_id: Pubkey,
     accounts: VerifyCollectionAccounts<'_, '_>,
@@ -5542,53 +8503,53 @@ pub fn verify_collection_verify_account_privileges<'me, 'info>(
 pub const UTILIZE_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
 pub struct UtilizeAccounts<'me, 'info> {
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Token Account Of NFT
+    /// Token Account Of NFT
     pub token_account: &'me AccountInfo<'info>,
-    ///Mint of the Metadata
+    /// Mint of the Metadata
     pub mint: &'me AccountInfo<'info>,
-    ///A Use Authority / Can be the current Owner of the NFT
+    /// A Use Authority / Can be the current Owner of the NFT
     pub use_authority: &'me AccountInfo<'info>,
-    ///Owner
+    /// Owner
     pub owner: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
-    ///Associated Token program
+    /// Associated Token program
     pub ata_program: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
-    ///Use Authority Record PDA If present the program Assumes a delegated use authority
-    pub use_authority_record: &'me AccountInfo<'info>,
-    ///Program As Signer (Burner)
-    pub burner: &'me AccountInfo<'info>,
+    /// Use Authority Record PDA If present the program Assumes a delegated use authority
+    pub use_authority_record: Option<&'me AccountInfo<'info>>,
+    /// Program As Signer (Burner)
+    pub burner: Option<&'me AccountInfo<'info>>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct UtilizeKeys {
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///Token Account Of NFT
+    /// Token Account Of NFT
     pub token_account: Pubkey,
-    ///Mint of the Metadata
+    /// Mint of the Metadata
     pub mint: Pubkey,
-    ///A Use Authority / Can be the current Owner of the NFT
+    /// A Use Authority / Can be the current Owner of the NFT
     pub use_authority: Pubkey,
-    ///Owner
+    /// Owner
     pub owner: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
-    ///Associated Token program
+    /// Associated Token program
     pub ata_program: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
-    ///Use Authority Record PDA If present the program Assumes a delegated use authority
-    pub use_authority_record: Pubkey,
-    ///Program As Signer (Burner)
-    pub burner: Pubkey,
+    /// Use Authority Record PDA If present the program Assumes a delegated use authority
+    pub use_authority_record: Option<Pubkey>,
+    /// Program As Signer (Burner)
+    pub burner: Option<Pubkey>,
 }
 impl From<UtilizeAccounts<'_, '_>> for UtilizeKeys {
     fn from(accounts: UtilizeAccounts) -> Self {
@@ -5602,74 +8563,98 @@ impl From<UtilizeAccounts<'_, '_>> for UtilizeKeys {
             ata_program: *accounts.ata_program.key,
             system_program: *accounts.system_program.key,
             rent: *accounts.rent.key,
-            use_authority_record: *accounts.use_authority_record.key,
-            burner: *accounts.burner.key,
+            use_authority_record: accounts.use_authority_record.map(|a| *a.key),
+            burner: accounts.burner.map(|a| *a.key),
         }
     }
 }
-impl From<UtilizeKeys> for [AccountMeta; UTILIZE_IX_ACCOUNTS_LEN] {
-    fn from(keys: UtilizeKeys) -> Self {
+impl UtilizeKeys {
+    /// Absent optional accounts are replaced with `program_id`,
+    /// the ID of the program being invoked
+    pub fn to_metas(self, program_id: Pubkey) -> [AccountMeta; UTILIZE_IX_ACCOUNTS_LEN] {
         [
             AccountMeta {
-                pubkey: keys.metadata,
+                pubkey: self.metadata,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.token_account,
+                pubkey: self.token_account,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.mint,
+                pubkey: self.mint,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.use_authority,
+                pubkey: self.use_authority,
                 is_signer: true,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.owner,
+                pubkey: self.owner,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.token_program,
+                pubkey: self.token_program,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.ata_program,
+                pubkey: self.ata_program,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.system_program,
+                pubkey: self.system_program,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.rent,
+                pubkey: self.rent,
                 is_signer: false,
                 is_writable: false,
             },
-            AccountMeta {
-                pubkey: keys.use_authority_record,
-                is_signer: false,
-                is_writable: true,
+            match self.use_authority_record {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
             },
-            AccountMeta {
-                pubkey: keys.burner,
-                is_signer: false,
-                is_writable: false,
+            match self.burner {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
             },
         ]
     }
 }
-impl From<[Pubkey; UTILIZE_IX_ACCOUNTS_LEN]> for UtilizeKeys {
-    fn from(pubkeys: [Pubkey; UTILIZE_IX_ACCOUNTS_LEN]) -> Self {
+impl From<UtilizeKeys> for [AccountMeta; UTILIZE_IX_ACCOUNTS_LEN] {
+    /// Absent optional accounts are replaced with `crate::ID`
+    fn from(keys: UtilizeKeys) -> Self {
+        keys.to_metas(crate::ID)
+    }
+}
+impl From<(Pubkey, [Pubkey; UTILIZE_IX_ACCOUNTS_LEN])> for UtilizeKeys {
+    /// Keys equal to `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
+    fn from((program_id, pubkeys): (Pubkey, [Pubkey; UTILIZE_IX_ACCOUNTS_LEN])) -> Self {
         Self {
             metadata: pubkeys[0],
             token_account: pubkeys[1],
@@ -5680,32 +8665,47 @@ impl From<[Pubkey; UTILIZE_IX_ACCOUNTS_LEN]> for UtilizeKeys {
             ata_program: pubkeys[6],
             system_program: pubkeys[7],
             rent: pubkeys[8],
-            use_authority_record: pubkeys[9],
-            burner: pubkeys[10],
+            use_authority_record: Some(pubkeys[9]).filter(|k| *k != program_id),
+            burner: Some(pubkeys[10]).filter(|k| *k != program_id),
         }
     }
 }
-impl<'info> From<UtilizeAccounts<'_, 'info>> for [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN] {
-    fn from(accounts: UtilizeAccounts<'_, 'info>) -> Self {
+impl From<[Pubkey; UTILIZE_IX_ACCOUNTS_LEN]> for UtilizeKeys {
+    /// Keys equal to `crate::ID` are absent optional accounts
+    fn from(pubkeys: [Pubkey; UTILIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self::from((crate::ID, pubkeys))
+    }
+}
+impl<'info> UtilizeAccounts<'_, 'info> {
+    /// Absent optional accounts are replaced with `program`,
+    /// the AccountInfo of the program being invoked
+    pub fn to_account_infos(
+        self,
+        program: &AccountInfo<'info>,
+    ) -> [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN] {
         [
-            accounts.metadata.clone(),
-            accounts.token_account.clone(),
-            accounts.mint.clone(),
-            accounts.use_authority.clone(),
-            accounts.owner.clone(),
-            accounts.token_program.clone(),
-            accounts.ata_program.clone(),
-            accounts.system_program.clone(),
-            accounts.rent.clone(),
-            accounts.use_authority_record.clone(),
-            accounts.burner.clone(),
+            self.metadata.clone(),
+            self.token_account.clone(),
+            self.mint.clone(),
+            self.use_authority.clone(),
+            self.owner.clone(),
+            self.token_program.clone(),
+            self.ata_program.clone(),
+            self.system_program.clone(),
+            self.rent.clone(),
+            self.use_authority_record.unwrap_or(program).clone(),
+            self.burner.unwrap_or(program).clone(),
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN]>
+impl<'me, 'info> From<(Pubkey, &'me [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN])>
     for UtilizeAccounts<'me, 'info>
 {
-    fn from(arr: &'me [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN]) -> Self {
+    /// Accounts with key `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
+    fn from(
+        (program_id, arr): (Pubkey, &'me [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN]),
+    ) -> Self {
         Self {
             metadata: &arr[0],
             token_account: &arr[1],
@@ -5716,14 +8716,61 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN]>
             ata_program: &arr[6],
             system_program: &arr[7],
             rent: &arr[8],
-            use_authority_record: &arr[9],
-            burner: &arr[10],
+            use_authority_record: Some(&arr[9]).filter(|a| *a.key != program_id),
+            burner: Some(&arr[10]).filter(|a| *a.key != program_id),
         }
     }
 }
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN]>
+    for UtilizeAccounts<'me, 'info>
+{
+    /// Accounts with key `crate::ID` are absent optional accounts
+    fn from(arr: &'me [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self::from((crate::ID, arr))
+    }
+}
+impl UtilizeKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UTILIZE_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UTILIZE_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UTILIZE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UtilizeKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UtilizeAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN] = accounts
+            .get(..UTILIZE_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UTILIZE_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UtilizeAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UTILIZE_IX_DISCM: u8 = 19u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UtilizeIxArgs {
     pub utilize_args: UtilizeArgs,
 }
@@ -5766,7 +8813,7 @@ pub fn utilize_ix_with_program_id(
     keys: UtilizeKeys,
     args: UtilizeIxArgs,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; UTILIZE_IX_ACCOUNTS_LEN] = keys.into();
+    let metas = keys.to_metas(program_id);
     let data: UtilizeIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -5777,57 +8824,205 @@ pub fn utilize_ix_with_program_id(
 pub fn utilize_ix(keys: UtilizeKeys, args: UtilizeIxArgs) -> std::io::Result<Instruction> {
     utilize_ix_with_program_id(crate::ID, keys, args)
 }
-pub fn utilize_invoke_with_program_id(
+/// Builds a [`utilize_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UtilizeIxBuilder {
+    metadata: Option<Pubkey>,
+    token_account: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    use_authority: Option<Pubkey>,
+    owner: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    ata_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    use_authority_record: Option<Pubkey>,
+    burner: Option<Pubkey>,
+    utilize_args: Option<UtilizeArgs>,
+}
+impl Default for UtilizeIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UtilizeIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            token_account: None,
+            mint: None,
+            use_authority: None,
+            owner: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            ata_program: Some(solana_program::pubkey!(
+                "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            use_authority_record: None,
+            burner: None,
+            utilize_args: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn token_account(mut self, token_account: Pubkey) -> Self {
+        self.token_account = Some(token_account);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn use_authority(mut self, use_authority: Pubkey) -> Self {
+        self.use_authority = Some(use_authority);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn ata_program(mut self, ata_program: Pubkey) -> Self {
+        self.ata_program = Some(ata_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn use_authority_record(mut self, use_authority_record: Pubkey) -> Self {
+        self.use_authority_record = Some(use_authority_record);
+        self
+    }
+    pub fn burner(mut self, burner: Pubkey) -> Self {
+        self.burner = Some(burner);
+        self
+    }
+    pub fn utilize_args(mut self, utilize_args: UtilizeArgs) -> Self {
+        self.utilize_args = Some(utilize_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UtilizeIxBuilder is missing {}", field),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let token_account = self.token_account.ok_or_else(|| missing("token_account"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let use_authority = self.use_authority.ok_or_else(|| missing("use_authority"))?;
+        let owner = self.owner.ok_or_else(|| missing("owner"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let ata_program = self.ata_program.ok_or_else(|| missing("ata_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let use_authority_record = self.use_authority_record;
+        let burner = self.burner;
+        let args = UtilizeIxArgs {
+            utilize_args: self.utilize_args.ok_or_else(|| missing("utilize_args"))?,
+        };
+        let keys = UtilizeKeys {
+            metadata,
+            token_account,
+            mint,
+            use_authority,
+            owner,
+            token_program,
+            ata_program,
+            system_program,
+            rent,
+            use_authority_record,
+            burner,
+        };
+        utilize_ix(keys, args)
+    }
+}
+pub fn utilize_invoke_with_program_id<'info>(
     program_id: Pubkey,
-    accounts: UtilizeAccounts<'_, '_>,
+    accounts: UtilizeAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
     args: UtilizeIxArgs,
 ) -> ProgramResult {
     let keys: UtilizeKeys = accounts.into();
     let ix = utilize_ix_with_program_id(program_id, keys, args)?;
-    invoke_instruction(&ix, accounts)
+    invoke(&ix, &accounts.to_account_infos(program))
 }
-pub fn utilize_invoke(accounts: UtilizeAccounts<'_, '_>, args: UtilizeIxArgs) -> ProgramResult {
-    utilize_invoke_with_program_id(crate::ID, accounts, args)
+pub fn utilize_invoke<'info>(
+    accounts: UtilizeAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
+    args: UtilizeIxArgs,
+) -> ProgramResult {
+    utilize_invoke_with_program_id(crate::ID, accounts, program, args)
 }
-pub fn utilize_invoke_signed_with_program_id(
+pub fn utilize_invoke_signed_with_program_id<'info>(
     program_id: Pubkey,
-    accounts: UtilizeAccounts<'_, '_>,
+    accounts: UtilizeAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
     args: UtilizeIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: UtilizeKeys = accounts.into();
     let ix = utilize_ix_with_program_id(program_id, keys, args)?;
-    invoke_instruction_signed(&ix, accounts, seeds)
+    invoke_signed(&ix, &accounts.to_account_infos(program), seeds)
 }
-pub fn utilize_invoke_signed(
-    accounts: UtilizeAccounts<'_, '_>,
+pub fn utilize_invoke_signed<'info>(
+    accounts: UtilizeAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
     args: UtilizeIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    utilize_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    utilize_invoke_signed_with_program_id(crate::ID, accounts, program, args, seeds)
 }
+/// Optional accounts must be present in both `accounts` and `keys` or absent from both.
+/// An absent account or key is returned as `crate::ID` in the mismatch
 pub fn utilize_verify_account_keys(
     accounts: UtilizeAccounts<'_, '_>,
     keys: UtilizeKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
-        (accounts.metadata.key, &keys.metadata),
-        (accounts.token_account.key, &keys.token_account),
-        (accounts.mint.key, &keys.mint),
-        (accounts.use_authority.key, &keys.use_authority),
-        (accounts.owner.key, &keys.owner),
-        (accounts.token_program.key, &keys.token_program),
-        (accounts.ata_program.key, &keys.ata_program),
-        (accounts.system_program.key, &keys.system_program),
-        (accounts.rent.key, &keys.rent),
+        (Some(accounts.metadata.key), Some(&keys.metadata)),
+        (Some(accounts.token_account.key), Some(&keys.token_account)),
+        (Some(accounts.mint.key), Some(&keys.mint)),
+        (Some(accounts.use_authority.key), Some(&keys.use_authority)),
+        (Some(accounts.owner.key), Some(&keys.owner)),
+        (Some(accounts.token_program.key), Some(&keys.token_program)),
+        (Some(accounts.ata_program.key), Some(&keys.ata_program)),
         (
-            accounts.use_authority_record.key,
-            &keys.use_authority_record,
+            Some(accounts.system_program.key),
+            Some(&keys.system_program),
         ),
-        (accounts.burner.key, &keys.burner),
+        (Some(accounts.rent.key), Some(&keys.rent)),
+        (
+            accounts.use_authority_record.map(|a| a.key),
+            keys.use_authority_record.as_ref(),
+        ),
+        (accounts.burner.map(|a| a.key), keys.burner.as_ref()),
     ] {
         if actual != expected {
-            return Err((*actual, *expected));
+            return Err((
+                *actual.unwrap_or(&crate::ID),
+                *expected.unwrap_or(&crate::ID),
+            ));
         }
     }
     Ok(())
@@ -5836,11 +9031,14 @@ pub fn utilize_verify_writable_privileges<'me, 'info>(
     accounts: UtilizeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_writable in [
-        accounts.metadata,
-        accounts.token_account,
-        accounts.mint,
+        Some(accounts.metadata),
+        Some(accounts.token_account),
+        Some(accounts.mint),
         accounts.use_authority_record,
-    ] {
+    ]
+    .into_iter()
+    .flatten()
+    {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
@@ -5867,52 +9065,52 @@ pub fn utilize_verify_account_privileges<'me, 'info>(
 pub const APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
 pub struct ApproveUseAuthorityAccounts<'me, 'info> {
-    ///Use Authority Record PDA
+    /// Use Authority Record PDA
     pub use_authority_record: &'me AccountInfo<'info>,
-    ///Owner
+    /// Owner
     pub owner: &'me AccountInfo<'info>,
-    ///Payer
+    /// Payer
     pub payer: &'me AccountInfo<'info>,
-    ///A Use Authority
+    /// A Use Authority
     pub user: &'me AccountInfo<'info>,
-    ///Owned Token Account Of Mint
+    /// Owned Token Account Of Mint
     pub owner_token_account: &'me AccountInfo<'info>,
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Mint of Metadata
+    /// Mint of Metadata
     pub mint: &'me AccountInfo<'info>,
-    ///Program As Signer (Burner)
+    /// Program As Signer (Burner)
     pub burner: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ApproveUseAuthorityKeys {
-    ///Use Authority Record PDA
+    /// Use Authority Record PDA
     pub use_authority_record: Pubkey,
-    ///Owner
+    /// Owner
     pub owner: Pubkey,
-    ///Payer
+    /// Payer
     pub payer: Pubkey,
-    ///A Use Authority
+    /// A Use Authority
     pub user: Pubkey,
-    ///Owned Token Account Of Mint
+    /// Owned Token Account Of Mint
     pub owner_token_account: Pubkey,
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///Mint of Metadata
+    /// Mint of Metadata
     pub mint: Pubkey,
-    ///Program As Signer (Burner)
+    /// Program As Signer (Burner)
     pub burner: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
 }
 impl From<ApproveUseAuthorityAccounts<'_, '_>> for ApproveUseAuthorityKeys {
@@ -6048,9 +9246,54 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; APPROVE_USE_AUTHORITY_IX_ACCOUNT
         }
     }
 }
+impl ApproveUseAuthorityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ApproveUseAuthorityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ApproveUseAuthorityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ApproveUseAuthorityAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const APPROVE_USE_AUTHORITY_IX_DISCM: u8 = 20u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ApproveUseAuthorityIxArgs {
     pub approve_use_authority_args: ApproveUseAuthorityArgs,
 }
@@ -6107,6 +9350,146 @@ pub fn approve_use_authority_ix(
 ) -> std::io::Result<Instruction> {
     approve_use_authority_ix_with_program_id(crate::ID, keys, args)
 }
+/// Builds a [`approve_use_authority_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ApproveUseAuthorityIxBuilder {
+    use_authority_record: Option<Pubkey>,
+    owner: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    user: Option<Pubkey>,
+    owner_token_account: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    burner: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+    approve_use_authority_args: Option<ApproveUseAuthorityArgs>,
+}
+impl Default for ApproveUseAuthorityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ApproveUseAuthorityIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            use_authority_record: None,
+            owner: None,
+            payer: None,
+            user: None,
+            owner_token_account: None,
+            metadata: None,
+            mint: None,
+            burner: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            approve_use_authority_args: None,
+        }
+    }
+    pub fn use_authority_record(mut self, use_authority_record: Pubkey) -> Self {
+        self.use_authority_record = Some(use_authority_record);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn owner_token_account(mut self, owner_token_account: Pubkey) -> Self {
+        self.owner_token_account = Some(owner_token_account);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn burner(mut self, burner: Pubkey) -> Self {
+        self.burner = Some(burner);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn approve_use_authority_args(
+        mut self,
+        approve_use_authority_args: ApproveUseAuthorityArgs,
+    ) -> Self {
+        self.approve_use_authority_args = Some(approve_use_authority_args);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ApproveUseAuthorityIxBuilder is missing {}", field),
+            )
+        };
+        let use_authority_record = self
+            .use_authority_record
+            .ok_or_else(|| missing("use_authority_record"))?;
+        let owner = self.owner.ok_or_else(|| missing("owner"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let owner_token_account = self
+            .owner_token_account
+            .ok_or_else(|| missing("owner_token_account"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let burner = self.burner.ok_or_else(|| missing("burner"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let args = ApproveUseAuthorityIxArgs {
+            approve_use_authority_args: self
+                .approve_use_authority_args
+                .ok_or_else(|| missing("approve_use_authority_args"))?,
+        };
+        let keys = ApproveUseAuthorityKeys {
+            use_authority_record,
+            owner,
+            payer,
+            user,
+            owner_token_account,
+            metadata,
+            mint,
+            burner,
+            token_program,
+            system_program,
+            rent,
+        };
+        approve_use_authority_ix(keys, args)
+    }
+}
 pub fn approve_use_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ApproveUseAuthorityAccounts<'_, '_>,
@@ -6195,44 +9578,44 @@ pub fn approve_use_authority_verify_account_privileges<'me, 'info>(
 pub const REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
 pub struct RevokeUseAuthorityAccounts<'me, 'info> {
-    ///Use Authority Record PDA
+    /// Use Authority Record PDA
     pub use_authority_record: &'me AccountInfo<'info>,
-    ///Owner
+    /// Owner
     pub owner: &'me AccountInfo<'info>,
-    ///A Use Authority
+    /// A Use Authority
     pub user: &'me AccountInfo<'info>,
-    ///Owned Token Account Of Mint
+    /// Owned Token Account Of Mint
     pub owner_token_account: &'me AccountInfo<'info>,
-    ///Mint of Metadata
+    /// Mint of Metadata
     pub mint: &'me AccountInfo<'info>,
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Token program
+    /// Token program
     pub token_program: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct RevokeUseAuthorityKeys {
-    ///Use Authority Record PDA
+    /// Use Authority Record PDA
     pub use_authority_record: Pubkey,
-    ///Owner
+    /// Owner
     pub owner: Pubkey,
-    ///A Use Authority
+    /// A Use Authority
     pub user: Pubkey,
-    ///Owned Token Account Of Mint
+    /// Owned Token Account Of Mint
     pub owner_token_account: Pubkey,
-    ///Mint of Metadata
+    /// Mint of Metadata
     pub mint: Pubkey,
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///Token program
+    /// Token program
     pub token_program: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
 }
 impl From<RevokeUseAuthorityAccounts<'_, '_>> for RevokeUseAuthorityKeys {
@@ -6350,6 +9733,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REVOKE_USE_AUTHORITY_IX_ACCOUNTS
         }
     }
 }
+impl RevokeUseAuthorityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for RevokeUseAuthorityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RevokeUseAuthorityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for RevokeUseAuthorityAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REVOKE_USE_AUTHORITY_IX_DISCM: u8 = 21u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct RevokeUseAuthorityIxData;
@@ -6393,6 +9820,116 @@ pub fn revoke_use_authority_ix_with_program_id(
 pub fn revoke_use_authority_ix(keys: RevokeUseAuthorityKeys) -> std::io::Result<Instruction> {
     revoke_use_authority_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`revoke_use_authority_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RevokeUseAuthorityIxBuilder {
+    use_authority_record: Option<Pubkey>,
+    owner: Option<Pubkey>,
+    user: Option<Pubkey>,
+    owner_token_account: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+}
+impl Default for RevokeUseAuthorityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RevokeUseAuthorityIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            use_authority_record: None,
+            owner: None,
+            user: None,
+            owner_token_account: None,
+            mint: None,
+            metadata: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+        }
+    }
+    pub fn use_authority_record(mut self, use_authority_record: Pubkey) -> Self {
+        self.use_authority_record = Some(use_authority_record);
+        self
+    }
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn user(mut self, user: Pubkey) -> Self {
+        self.user = Some(user);
+        self
+    }
+    pub fn owner_token_account(mut self, owner_token_account: Pubkey) -> Self {
+        self.owner_token_account = Some(owner_token_account);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RevokeUseAuthorityIxBuilder is missing {}", field),
+            )
+        };
+        let use_authority_record = self
+            .use_authority_record
+            .ok_or_else(|| missing("use_authority_record"))?;
+        let owner = self.owner.ok_or_else(|| missing("owner"))?;
+        let user = self.user.ok_or_else(|| missing("user"))?;
+        let owner_token_account = self
+            .owner_token_account
+            .ok_or_else(|| missing("owner_token_account"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let keys = RevokeUseAuthorityKeys {
+            use_authority_record,
+            owner,
+            user,
+            owner_token_account,
+            mint,
+            metadata,
+            token_program,
+            system_program,
+            rent,
+        };
+        revoke_use_authority_ix(keys)
+    }
+}
 pub fn revoke_use_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RevokeUseAuthorityAccounts<'_, '_>,
@@ -6473,33 +10010,33 @@ pub fn revoke_use_authority_verify_account_privileges<'me, 'info>(
 pub const UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
 pub struct UnverifyCollectionAccounts<'me, 'info> {
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Collection Authority
+    /// Collection Authority
     pub collection_authority: &'me AccountInfo<'info>,
-    ///Mint of the Collection
+    /// Mint of the Collection
     pub collection_mint: &'me AccountInfo<'info>,
-    ///Metadata Account of the Collection
+    /// Metadata Account of the Collection
     pub collection: &'me AccountInfo<'info>,
-    ///MasterEdition2 Account of the Collection Token
+    /// MasterEdition2 Account of the Collection Token
     pub collection_master_edition_account: &'me AccountInfo<'info>,
-    ///Collection Authority Record PDA
-    pub collection_authority_record: &'me AccountInfo<'info>,
+    /// Collection Authority Record PDA
+    pub collection_authority_record: Option<&'me AccountInfo<'info>>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct UnverifyCollectionKeys {
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///Collection Authority
+    /// Collection Authority
     pub collection_authority: Pubkey,
-    ///Mint of the Collection
+    /// Mint of the Collection
     pub collection_mint: Pubkey,
-    ///Metadata Account of the Collection
+    /// Metadata Account of the Collection
     pub collection: Pubkey,
-    ///MasterEdition2 Account of the Collection Token
+    /// MasterEdition2 Account of the Collection Token
     pub collection_master_edition_account: Pubkey,
-    ///Collection Authority Record PDA
-    pub collection_authority_record: Pubkey,
+    /// Collection Authority Record PDA
+    pub collection_authority_record: Option<Pubkey>,
 }
 impl From<UnverifyCollectionAccounts<'_, '_>> for UnverifyCollectionKeys {
     fn from(accounts: UnverifyCollectionAccounts) -> Self {
@@ -6509,86 +10046,173 @@ impl From<UnverifyCollectionAccounts<'_, '_>> for UnverifyCollectionKeys {
             collection_mint: *accounts.collection_mint.key,
             collection: *accounts.collection.key,
             collection_master_edition_account: *accounts.collection_master_edition_account.key,
-            collection_authority_record: *accounts.collection_authority_record.key,
+            collection_authority_record: accounts.collection_authority_record.map(|a| *a.key),
         }
     }
 }
-impl From<UnverifyCollectionKeys> for [AccountMeta; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
-    fn from(keys: UnverifyCollectionKeys) -> Self {
+impl UnverifyCollectionKeys {
+    /// Absent optional accounts are replaced with `program_id`,
+    /// the ID of the program being invoked
+    pub fn to_metas(
+        self,
+        program_id: Pubkey,
+    ) -> [AccountMeta; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
         [
             AccountMeta {
-                pubkey: keys.metadata,
+                pubkey: self.metadata,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.collection_authority,
+                pubkey: self.collection_authority,
                 is_signer: true,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.collection_mint,
+                pubkey: self.collection_mint,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.collection,
+                pubkey: self.collection,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.collection_master_edition_account,
+                pubkey: self.collection_master_edition_account,
                 is_signer: false,
                 is_writable: false,
             },
-            AccountMeta {
-                pubkey: keys.collection_authority_record,
-                is_signer: false,
-                is_writable: false,
+            match self.collection_authority_record {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
             },
         ]
     }
 }
-impl From<[Pubkey; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]> for UnverifyCollectionKeys {
-    fn from(pubkeys: [Pubkey; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+impl From<UnverifyCollectionKeys> for [AccountMeta; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+    /// Absent optional accounts are replaced with `crate::ID`
+    fn from(keys: UnverifyCollectionKeys) -> Self {
+        keys.to_metas(crate::ID)
+    }
+}
+impl From<(Pubkey, [Pubkey; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN])> for UnverifyCollectionKeys {
+    /// Keys equal to `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
+    fn from(
+        (program_id, pubkeys): (Pubkey, [Pubkey; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]),
+    ) -> Self {
         Self {
             metadata: pubkeys[0],
             collection_authority: pubkeys[1],
             collection_mint: pubkeys[2],
             collection: pubkeys[3],
             collection_master_edition_account: pubkeys[4],
-            collection_authority_record: pubkeys[5],
+            collection_authority_record: Some(pubkeys[5]).filter(|k| *k != program_id),
         }
     }
 }
-impl<'info> From<UnverifyCollectionAccounts<'_, 'info>>
-    for [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]
-{
-    fn from(accounts: UnverifyCollectionAccounts<'_, 'info>) -> Self {
+impl From<[Pubkey; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]> for UnverifyCollectionKeys {
+    /// Keys equal to `crate::ID` are absent optional accounts
+    fn from(pubkeys: [Pubkey; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+        Self::from((crate::ID, pubkeys))
+    }
+}
+impl<'info> UnverifyCollectionAccounts<'_, 'info> {
+    /// Absent optional accounts are replaced with `program`,
+    /// the AccountInfo of the program being invoked
+    pub fn to_account_infos(
+        self,
+        program: &AccountInfo<'info>,
+    ) -> [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
         [
-            accounts.metadata.clone(),
-            accounts.collection_authority.clone(),
-            accounts.collection_mint.clone(),
-            accounts.collection.clone(),
-            accounts.collection_master_edition_account.clone(),
-            accounts.collection_authority_record.clone(),
+            self.metadata.clone(),
+            self.collection_authority.clone(),
+            self.collection_mint.clone(),
+            self.collection.clone(),
+            self.collection_master_edition_account.clone(),
+            self.collection_authority_record.unwrap_or(program).clone(),
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
-    for UnverifyCollectionAccounts<'me, 'info>
+impl<'me, 'info>
+    From<(
+        Pubkey,
+        &'me [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN],
+    )> for UnverifyCollectionAccounts<'me, 'info>
 {
-    fn from(arr: &'me [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+    /// Accounts with key `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
+    fn from(
+        (program_id, arr): (
+            Pubkey,
+            &'me [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN],
+        ),
+    ) -> Self {
         Self {
             metadata: &arr[0],
             collection_authority: &arr[1],
             collection_mint: &arr[2],
             collection: &arr[3],
             collection_master_edition_account: &arr[4],
-            collection_authority_record: &arr[5],
+            collection_authority_record: Some(&arr[5]).filter(|a| *a.key != program_id),
         }
     }
 }
+impl<'me, 'info> From<&'me [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
+    for UnverifyCollectionAccounts<'me, 'info>
+{
+    /// Accounts with key `crate::ID` are absent optional accounts
+    fn from(arr: &'me [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+        Self::from((crate::ID, arr))
+    }
+}
+impl UnverifyCollectionKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((keys.into(), &pubkeys[UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl TryFrom<&[Pubkey]> for UnverifyCollectionKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> UnverifyCollectionAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] = accounts
+            .get(..UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((arr.into(), &accounts[UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN..]))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for UnverifyCollectionAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const UNVERIFY_COLLECTION_IX_DISCM: u8 = 22u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct UnverifyCollectionIxData;
@@ -6622,7 +10246,7 @@ pub fn unverify_collection_ix_with_program_id(
     program_id: Pubkey,
     keys: UnverifyCollectionKeys,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] = keys.into();
+    let metas = keys.to_metas(program_id);
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
@@ -6632,55 +10256,154 @@ pub fn unverify_collection_ix_with_program_id(
 pub fn unverify_collection_ix(keys: UnverifyCollectionKeys) -> std::io::Result<Instruction> {
     unverify_collection_ix_with_program_id(crate::ID, keys)
 }
-pub fn unverify_collection_invoke_with_program_id(
+/// Builds a [`unverify_collection_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct UnverifyCollectionIxBuilder {
+    metadata: Option<Pubkey>,
+    collection_authority: Option<Pubkey>,
+    collection_mint: Option<Pubkey>,
+    collection: Option<Pubkey>,
+    collection_master_edition_account: Option<Pubkey>,
+    collection_authority_record: Option<Pubkey>,
+}
+impl Default for UnverifyCollectionIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl UnverifyCollectionIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            collection_authority: None,
+            collection_mint: None,
+            collection: None,
+            collection_master_edition_account: None,
+            collection_authority_record: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn collection_authority(mut self, collection_authority: Pubkey) -> Self {
+        self.collection_authority = Some(collection_authority);
+        self
+    }
+    pub fn collection_mint(mut self, collection_mint: Pubkey) -> Self {
+        self.collection_mint = Some(collection_mint);
+        self
+    }
+    pub fn collection(mut self, collection: Pubkey) -> Self {
+        self.collection = Some(collection);
+        self
+    }
+    pub fn collection_master_edition_account(
+        mut self,
+        collection_master_edition_account: Pubkey,
+    ) -> Self {
+        self.collection_master_edition_account = Some(collection_master_edition_account);
+        self
+    }
+    pub fn collection_authority_record(mut self, collection_authority_record: Pubkey) -> Self {
+        self.collection_authority_record = Some(collection_authority_record);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("UnverifyCollectionIxBuilder is missing {}", field),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let collection_authority = self
+            .collection_authority
+            .ok_or_else(|| missing("collection_authority"))?;
+        let collection_mint = self
+            .collection_mint
+            .ok_or_else(|| missing("collection_mint"))?;
+        let collection = self.collection.ok_or_else(|| missing("collection"))?;
+        let collection_master_edition_account = self
+            .collection_master_edition_account
+            .ok_or_else(|| missing("collection_master_edition_account"))?;
+        let collection_authority_record = self.collection_authority_record;
+        let keys = UnverifyCollectionKeys {
+            metadata,
+            collection_authority,
+            collection_mint,
+            collection,
+            collection_master_edition_account,
+            collection_authority_record,
+        };
+        unverify_collection_ix(keys)
+    }
+}
+pub fn unverify_collection_invoke_with_program_id<'info>(
     program_id: Pubkey,
-    accounts: UnverifyCollectionAccounts<'_, '_>,
+    accounts: UnverifyCollectionAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
 ) -> ProgramResult {
     let keys: UnverifyCollectionKeys = accounts.into();
     let ix = unverify_collection_ix_with_program_id(program_id, keys)?;
-    invoke_instruction(&ix, accounts)
+    invoke(&ix, &accounts.to_account_infos(program))
 }
-pub fn unverify_collection_invoke(accounts: UnverifyCollectionAccounts<'_, '_>) -> ProgramResult {
-    unverify_collection_invoke_with_program_id(crate::ID, accounts)
+pub fn unverify_collection_invoke<'info>(
+    accounts: UnverifyCollectionAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
+) -> ProgramResult {
+    unverify_collection_invoke_with_program_id(crate::ID, accounts, program)
 }
-pub fn unverify_collection_invoke_signed_with_program_id(
+pub fn unverify_collection_invoke_signed_with_program_id<'info>(
     program_id: Pubkey,
-    accounts: UnverifyCollectionAccounts<'_, '_>,
+    accounts: UnverifyCollectionAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: UnverifyCollectionKeys = accounts.into();
     let ix = unverify_collection_ix_with_program_id(program_id, keys)?;
-    invoke_instruction_signed(&ix, accounts, seeds)
+    invoke_signed(&ix, &accounts.to_account_infos(program), seeds)
 }
-pub fn unverify_collection_invoke_signed(
-    accounts: UnverifyCollectionAccounts<'_, '_>,
+pub fn unverify_collection_invoke_signed<'info>(
+    accounts: UnverifyCollectionAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    unverify_collection_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    unverify_collection_invoke_signed_with_program_id(crate::ID, accounts, program, seeds)
 }
+/// Optional accounts must be present in both `accounts` and `keys` or absent from both.
+/// An absent account or key is returned as `crate::ID` in the mismatch
 pub fn unverify_collection_verify_account_keys(
     accounts: UnverifyCollectionAccounts<'_, '_>,
     keys: UnverifyCollectionKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
-        (accounts.metadata.key, &keys.metadata),
+        (Some(accounts.metadata.key), Some(&keys.metadata)),
         (
-            accounts.collection_authority.key,
-            &keys.collection_authority,
+            Some(accounts.collection_authority.key),
+            Some(&keys.collection_authority),
         ),
-        (accounts.collection_mint.key, &keys.collection_mint),
-        (accounts.collection.key, &keys.collection),
         (
-            accounts.collection_master_edition_account.key,
-            &keys.collection_master_edition_account,
+            Some(accounts.collection_mint.key),
+            Some(&keys.collection_mint),
         ),
+        (Some(accounts.collection.key), Some(&keys.collection)),
         (
-            accounts.collection_authority_record.key,
-            &keys.collection_authority_record,
+            Some(accounts.collection_master_edition_account.key),
+            Some(&keys.collection_master_edition_account),
+        ),
+        (
+            accounts.collection_authority_record.map(|a| a.key),
+            keys.collection_authority_record.as_ref(),
         ),
     ] {
         if actual != expected {
-            return Err((*actual, *expected));
+            return Err((
+                *actual.unwrap_or(&crate::ID),
+                *expected.unwrap_or(&crate::ID),
+            ));
         }
     }
     Ok(())
@@ -6715,40 +10438,40 @@ pub fn unverify_collection_verify_account_privileges<'me, 'info>(
 pub const APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN: usize = 8;
 #[derive(Copy, Clone, Debug)]
 pub struct ApproveCollectionAuthorityAccounts<'me, 'info> {
-    ///Collection Authority Record PDA
+    /// Collection Authority Record PDA
     pub collection_authority_record: &'me AccountInfo<'info>,
-    ///A Collection Authority
+    /// A Collection Authority
     pub new_collection_authority: &'me AccountInfo<'info>,
-    ///Update Authority of Collection NFT
+    /// Update Authority of Collection NFT
     pub update_authority: &'me AccountInfo<'info>,
-    ///Payer
+    /// Payer
     pub payer: &'me AccountInfo<'info>,
-    ///Collection Metadata account
+    /// Collection Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Mint of Collection Metadata
+    /// Mint of Collection Metadata
     pub mint: &'me AccountInfo<'info>,
-    ///System program
+    /// System program
     pub system_program: &'me AccountInfo<'info>,
-    ///Rent info
+    /// Rent info
     pub rent: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ApproveCollectionAuthorityKeys {
-    ///Collection Authority Record PDA
+    /// Collection Authority Record PDA
     pub collection_authority_record: Pubkey,
-    ///A Collection Authority
+    /// A Collection Authority
     pub new_collection_authority: Pubkey,
-    ///Update Authority of Collection NFT
+    /// Update Authority of Collection NFT
     pub update_authority: Pubkey,
-    ///Payer
+    /// Payer
     pub payer: Pubkey,
-    ///Collection Metadata account
+    /// Collection Metadata account
     pub metadata: Pubkey,
-    ///Mint of Collection Metadata
+    /// Mint of Collection Metadata
     pub mint: Pubkey,
-    ///System program
+    /// System program
     pub system_program: Pubkey,
-    ///Rent info
+    /// Rent info
     pub rent: Pubkey,
 }
 impl From<ApproveCollectionAuthorityAccounts<'_, '_>> for ApproveCollectionAuthorityKeys {
@@ -6861,6 +10584,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; APPROVE_COLLECTION_AUTHORITY_IX_
         }
     }
 }
+impl ApproveCollectionAuthorityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ApproveCollectionAuthorityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ApproveCollectionAuthorityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for ApproveCollectionAuthorityAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const APPROVE_COLLECTION_AUTHORITY_IX_DISCM: u8 = 23u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ApproveCollectionAuthorityIxData;
@@ -6906,6 +10675,108 @@ pub fn approve_collection_authority_ix(
 ) -> std::io::Result<Instruction> {
     approve_collection_authority_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`approve_collection_authority_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ApproveCollectionAuthorityIxBuilder {
+    collection_authority_record: Option<Pubkey>,
+    new_collection_authority: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    system_program: Option<Pubkey>,
+    rent: Option<Pubkey>,
+}
+impl Default for ApproveCollectionAuthorityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ApproveCollectionAuthorityIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            collection_authority_record: None,
+            new_collection_authority: None,
+            update_authority: None,
+            payer: None,
+            metadata: None,
+            mint: None,
+            system_program: Some(solana_program::pubkey!("11111111111111111111111111111111")),
+            rent: Some(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+        }
+    }
+    pub fn collection_authority_record(mut self, collection_authority_record: Pubkey) -> Self {
+        self.collection_authority_record = Some(collection_authority_record);
+        self
+    }
+    pub fn new_collection_authority(mut self, new_collection_authority: Pubkey) -> Self {
+        self.new_collection_authority = Some(new_collection_authority);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ApproveCollectionAuthorityIxBuilder is missing {}", field),
+            )
+        };
+        let collection_authority_record = self
+            .collection_authority_record
+            .ok_or_else(|| missing("collection_authority_record"))?;
+        let new_collection_authority = self
+            .new_collection_authority
+            .ok_or_else(|| missing("new_collection_authority"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let system_program = self
+            .system_program
+            .ok_or_else(|| missing("system_program"))?;
+        let rent = self.rent.ok_or_else(|| missing("rent"))?;
+        let keys = ApproveCollectionAuthorityKeys {
+            collection_authority_record,
+            new_collection_authority,
+            update_authority,
+            payer,
+            metadata,
+            mint,
+            system_program,
+            rent,
+        };
+        approve_collection_authority_ix(keys)
+    }
+}
 pub fn approve_collection_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ApproveCollectionAuthorityAccounts<'_, '_>,
@@ -6990,24 +10861,24 @@ pub fn approve_collection_authority_verify_account_privileges<'me, 'info>(
 pub const REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
 pub struct RevokeCollectionAuthorityAccounts<'me, 'info> {
-    ///Collection Authority Record PDA
+    /// Collection Authority Record PDA
     pub collection_authority_record: &'me AccountInfo<'info>,
-    ///Update Authority of Collection NFT
+    /// Update Authority of Collection NFT
     pub update_authority: &'me AccountInfo<'info>,
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Mint of Metadata
+    /// Mint of Metadata
     pub mint: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct RevokeCollectionAuthorityKeys {
-    ///Collection Authority Record PDA
+    /// Collection Authority Record PDA
     pub collection_authority_record: Pubkey,
-    ///Update Authority of Collection NFT
+    /// Update Authority of Collection NFT
     pub update_authority: Pubkey,
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///Mint of Metadata
+    /// Mint of Metadata
     pub mint: Pubkey,
 }
 impl From<RevokeCollectionAuthorityAccounts<'_, '_>> for RevokeCollectionAuthorityKeys {
@@ -7082,6 +10953,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REVOKE_COLLECTION_AUTHORITY_IX_A
         }
     }
 }
+impl RevokeCollectionAuthorityKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for RevokeCollectionAuthorityKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RevokeCollectionAuthorityAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] = accounts
+            .get(..REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for RevokeCollectionAuthorityAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REVOKE_COLLECTION_AUTHORITY_IX_DISCM: u8 = 24u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct RevokeCollectionAuthorityIxData;
@@ -7127,6 +11044,70 @@ pub fn revoke_collection_authority_ix(
 ) -> std::io::Result<Instruction> {
     revoke_collection_authority_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`revoke_collection_authority_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RevokeCollectionAuthorityIxBuilder {
+    collection_authority_record: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    metadata: Option<Pubkey>,
+    mint: Option<Pubkey>,
+}
+impl Default for RevokeCollectionAuthorityIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RevokeCollectionAuthorityIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            collection_authority_record: None,
+            update_authority: None,
+            metadata: None,
+            mint: None,
+        }
+    }
+    pub fn collection_authority_record(mut self, collection_authority_record: Pubkey) -> Self {
+        self.collection_authority_record = Some(collection_authority_record);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RevokeCollectionAuthorityIxBuilder is missing {}", field),
+            )
+        };
+        let collection_authority_record = self
+            .collection_authority_record
+            .ok_or_else(|| missing("collection_authority_record"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let keys = RevokeCollectionAuthorityKeys {
+            collection_authority_record,
+            update_authority,
+            metadata,
+            mint,
+        };
+        revoke_collection_authority_ix(keys)
+    }
+}
 pub fn revoke_collection_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RevokeCollectionAuthorityAccounts<'_, '_>,
@@ -7204,41 +11185,41 @@ pub fn revoke_collection_authority_verify_account_privileges<'me, 'info>(
 pub const SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN: usize = 8;
 #[derive(Copy, Clone, Debug)]
 pub struct SetAndVerifyCollectionAccounts<'me, 'info> {
-    ///Metadata account
+    /// Metadata account
     pub metadata: &'me AccountInfo<'info>,
-    ///Collection Update authority
+    /// Collection Update authority
     pub collection_authority: &'me AccountInfo<'info>,
-    ///Payer
+    /// Payer
     pub payer: &'me AccountInfo<'info>,
-    ///Update Authority of Collection NFT and NFT
+    /// Update Authority of Collection NFT and NFT
     pub update_authority: &'me AccountInfo<'info>,
-    ///Mint of the Collection
+    /// Mint of the Collection
     pub collection_mint: &'me AccountInfo<'info>,
-    ///Metadata Account of the Collection
+    /// Metadata Account of the Collection
     pub collection: &'me AccountInfo<'info>,
-    ///MasterEdition2 Account of the Collection Token
+    /// MasterEdition2 Account of the Collection Token
     pub collection_master_edition_account: &'me AccountInfo<'info>,
-    ///Collection Authority Record PDA
-    pub collection_authority_record: &'me AccountInfo<'info>,
+    /// Collection Authority Record PDA
+    pub collection_authority_record: Option<&'me AccountInfo<'info>>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct SetAndVerifyCollectionKeys {
-    ///Metadata account
+    /// Metadata account
     pub metadata: Pubkey,
-    ///Collection Update authority
+    /// Collection Update authority
     pub collection_authority: Pubkey,
-    ///Payer
+    /// Payer
     pub payer: Pubkey,
-    ///Update Authority of Collection NFT and NFT
+    /// Update Authority of Collection NFT and NFT
     pub update_authority: Pubkey,
-    ///Mint of the Collection
+    /// Mint of the Collection
     pub collection_mint: Pubkey,
-    ///Metadata Account of the Collection
+    /// Metadata Account of the Collection
     pub collection: Pubkey,
-    ///MasterEdition2 Account of the Collection Token
+    /// MasterEdition2 Account of the Collection Token
     pub collection_master_edition_account: Pubkey,
-    ///Collection Authority Record PDA
-    pub collection_authority_record: Pubkey,
+    /// Collection Authority Record PDA
+    pub collection_authority_record: Option<Pubkey>,
 }
 impl From<SetAndVerifyCollectionAccounts<'_, '_>> for SetAndVerifyCollectionKeys {
     fn from(accounts: SetAndVerifyCollectionAccounts) -> Self {
@@ -7250,58 +11231,82 @@ impl From<SetAndVerifyCollectionAccounts<'_, '_>> for SetAndVerifyCollectionKeys
             collection_mint: *accounts.collection_mint.key,
             collection: *accounts.collection.key,
             collection_master_edition_account: *accounts.collection_master_edition_account.key,
-            collection_authority_record: *accounts.collection_authority_record.key,
+            collection_authority_record: accounts.collection_authority_record.map(|a| *a.key),
         }
     }
 }
-impl From<SetAndVerifyCollectionKeys> for [AccountMeta; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
-    fn from(keys: SetAndVerifyCollectionKeys) -> Self {
+impl SetAndVerifyCollectionKeys {
+    /// Absent optional accounts are replaced with `program_id`,
+    /// the ID of the program being invoked
+    pub fn to_metas(
+        self,
+        program_id: Pubkey,
+    ) -> [AccountMeta; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
         [
             AccountMeta {
-                pubkey: keys.metadata,
+                pubkey: self.metadata,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: keys.collection_authority,
+                pubkey: self.collection_authority,
                 is_signer: true,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.payer,
+                pubkey: self.payer,
                 is_signer: true,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.update_authority,
+                pubkey: self.update_authority,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.collection_mint,
+                pubkey: self.collection_mint,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.collection,
+                pubkey: self.collection,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: keys.collection_master_edition_account,
+                pubkey: self.collection_master_edition_account,
                 is_signer: false,
                 is_writable: false,
             },
-            AccountMeta {
-                pubkey: keys.collection_authority_record,
-                is_signer: false,
-                is_writable: false,
+            match self.collection_authority_record {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
             },
         ]
     }
 }
-impl From<[Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]> for SetAndVerifyCollectionKeys {
-    fn from(pubkeys: [Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+impl From<SetAndVerifyCollectionKeys> for [AccountMeta; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+    /// Absent optional accounts are replaced with `crate::ID`
+    fn from(keys: SetAndVerifyCollectionKeys) -> Self {
+        keys.to_metas(crate::ID)
+    }
+}
+impl From<(Pubkey, [Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN])>
+    for SetAndVerifyCollectionKeys
+{
+    /// Keys equal to `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
+    fn from(
+        (program_id, pubkeys): (Pubkey, [Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]),
+    ) -> Self {
         Self {
             metadata: pubkeys[0],
             collection_authority: pubkeys[1],
@@ -7310,30 +11315,49 @@ impl From<[Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]> for SetAndVerifyC
             collection_mint: pubkeys[4],
             collection: pubkeys[5],
             collection_master_edition_account: pubkeys[6],
-            collection_authority_record: pubkeys[7],
+            collection_authority_record: Some(pubkeys[7]).filter(|k| *k != program_id),
         }
     }
 }
-impl<'info> From<SetAndVerifyCollectionAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]
-{
-    fn from(accounts: SetAndVerifyCollectionAccounts<'_, 'info>) -> Self {
+impl From<[Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]> for SetAndVerifyCollectionKeys {
+    /// Keys equal to `crate::ID` are absent optional accounts
+    fn from(pubkeys: [Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+        Self::from((crate::ID, pubkeys))
+    }
+}
+impl<'info> SetAndVerifyCollectionAccounts<'_, 'info> {
+    /// Absent optional accounts are replaced with `program`,
+    /// the AccountInfo of the program being invoked
+    pub fn to_account_infos(
+        self,
+        program: &AccountInfo<'info>,
+    ) -> [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
         [
-            accounts.metadata.clone(),
-            accounts.collection_authority.clone(),
-            accounts.payer.clone(),
-            accounts.update_authority.clone(),
-            accounts.collection_mint.clone(),
-            accounts.collection.clone(),
-            accounts.collection_master_edition_account.clone(),
-            accounts.collection_authority_record.clone(),
+            self.metadata.clone(),
+            self.collection_authority.clone(),
+            self.payer.clone(),
+            self.update_authority.clone(),
+            self.collection_mint.clone(),
+            self.collection.clone(),
+            self.collection_master_edition_account.clone(),
+            self.collection_authority_record.unwrap_or(program).clone(),
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
-    for SetAndVerifyCollectionAccounts<'me, 'info>
+impl<'me, 'info>
+    From<(
+        Pubkey,
+        &'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN],
+    )> for SetAndVerifyCollectionAccounts<'me, 'info>
 {
-    fn from(arr: &'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+    /// Accounts with key `program_id`, the ID of the invoked program,
+    /// are absent optional accounts
+    fn from(
+        (program_id, arr): (
+            Pubkey,
+            &'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN],
+        ),
+    ) -> Self {
         Self {
             metadata: &arr[0],
             collection_authority: &arr[1],
@@ -7342,10 +11366,62 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACC
             collection_mint: &arr[4],
             collection: &arr[5],
             collection_master_edition_account: &arr[6],
-            collection_authority_record: &arr[7],
+            collection_authority_record: Some(&arr[7]).filter(|a| *a.key != program_id),
         }
     }
 }
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
+    for SetAndVerifyCollectionAccounts<'me, 'info>
+{
+    /// Accounts with key `crate::ID` are absent optional accounts
+    fn from(arr: &'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+        Self::from((crate::ID, arr))
+    }
+}
+impl SetAndVerifyCollectionKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for SetAndVerifyCollectionKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> SetAndVerifyCollectionAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] = accounts
+            .get(..SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for SetAndVerifyCollectionAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const SET_AND_VERIFY_COLLECTION_IX_DISCM: u8 = 25u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct SetAndVerifyCollectionIxData;
@@ -7379,7 +11455,7 @@ pub fn set_and_verify_collection_ix_with_program_id(
     program_id: Pubkey,
     keys: SetAndVerifyCollectionKeys,
 ) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] = keys.into();
+    let metas = keys.to_metas(program_id);
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
@@ -7391,59 +11467,177 @@ pub fn set_and_verify_collection_ix(
 ) -> std::io::Result<Instruction> {
     set_and_verify_collection_ix_with_program_id(crate::ID, keys)
 }
-pub fn set_and_verify_collection_invoke_with_program_id(
+/// Builds a [`set_and_verify_collection_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct SetAndVerifyCollectionIxBuilder {
+    metadata: Option<Pubkey>,
+    collection_authority: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    collection_mint: Option<Pubkey>,
+    collection: Option<Pubkey>,
+    collection_master_edition_account: Option<Pubkey>,
+    collection_authority_record: Option<Pubkey>,
+}
+impl Default for SetAndVerifyCollectionIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl SetAndVerifyCollectionIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            collection_authority: None,
+            payer: None,
+            update_authority: None,
+            collection_mint: None,
+            collection: None,
+            collection_master_edition_account: None,
+            collection_authority_record: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn collection_authority(mut self, collection_authority: Pubkey) -> Self {
+        self.collection_authority = Some(collection_authority);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+    pub fn collection_mint(mut self, collection_mint: Pubkey) -> Self {
+        self.collection_mint = Some(collection_mint);
+        self
+    }
+    pub fn collection(mut self, collection: Pubkey) -> Self {
+        self.collection = Some(collection);
+        self
+    }
+    pub fn collection_master_edition_account(
+        mut self,
+        collection_master_edition_account: Pubkey,
+    ) -> Self {
+        self.collection_master_edition_account = Some(collection_master_edition_account);
+        self
+    }
+    pub fn collection_authority_record(mut self, collection_authority_record: Pubkey) -> Self {
+        self.collection_authority_record = Some(collection_authority_record);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("SetAndVerifyCollectionIxBuilder is missing {}", field),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let collection_authority = self
+            .collection_authority
+            .ok_or_else(|| missing("collection_authority"))?;
+        let payer = self.payer.ok_or_else(|| missing("payer"))?;
+        let update_authority = self
+            .update_authority
+            .ok_or_else(|| missing("update_authority"))?;
+        let collection_mint = self
+            .collection_mint
+            .ok_or_else(|| missing("collection_mint"))?;
+        let collection = self.collection.ok_or_else(|| missing("collection"))?;
+        let collection_master_edition_account = self
+            .collection_master_edition_account
+            .ok_or_else(|| missing("collection_master_edition_account"))?;
+        let collection_authority_record = self.collection_authority_record;
+        let keys = SetAndVerifyCollectionKeys {
+            metadata,
+            collection_authority,
+            payer,
+            update_authority,
+            collection_mint,
+            collection,
+            collection_master_edition_account,
+            collection_authority_record,
+        };
+        set_and_verify_collection_ix(keys)
+    }
+}
+pub fn set_and_verify_collection_invoke_with_program_id<'info>(
     program_id: Pubkey,
-    accounts: SetAndVerifyCollectionAccounts<'_, '_>,
+    accounts: SetAndVerifyCollectionAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
 ) -> ProgramResult {
     let keys: SetAndVerifyCollectionKeys = accounts.into();
     let ix = set_and_verify_collection_ix_with_program_id(program_id, keys)?;
-    invoke_instruction(&ix, accounts)
+    invoke(&ix, &accounts.to_account_infos(program))
 }
-pub fn set_and_verify_collection_invoke(
-    accounts: SetAndVerifyCollectionAccounts<'_, '_>,
+pub fn set_and_verify_collection_invoke<'info>(
+    accounts: SetAndVerifyCollectionAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
 ) -> ProgramResult {
-    set_and_verify_collection_invoke_with_program_id(crate::ID, accounts)
+    set_and_verify_collection_invoke_with_program_id(crate::ID, accounts, program)
 }
-pub fn set_and_verify_collection_invoke_signed_with_program_id(
+pub fn set_and_verify_collection_invoke_signed_with_program_id<'info>(
     program_id: Pubkey,
-    accounts: SetAndVerifyCollectionAccounts<'_, '_>,
+    accounts: SetAndVerifyCollectionAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: SetAndVerifyCollectionKeys = accounts.into();
     let ix = set_and_verify_collection_ix_with_program_id(program_id, keys)?;
-    invoke_instruction_signed(&ix, accounts, seeds)
+    invoke_signed(&ix, &accounts.to_account_infos(program), seeds)
 }
-pub fn set_and_verify_collection_invoke_signed(
-    accounts: SetAndVerifyCollectionAccounts<'_, '_>,
+pub fn set_and_verify_collection_invoke_signed<'info>(
+    accounts: SetAndVerifyCollectionAccounts<'_, 'info>,
+    program: &AccountInfo<'info>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    set_and_verify_collection_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    set_and_verify_collection_invoke_signed_with_program_id(crate::ID, accounts, program, seeds)
 }
+/// Optional accounts must be present in both `accounts` and `keys` or absent from both.
+/// An absent account or key is returned as `crate::ID` in the mismatch
 pub fn set_and_verify_collection_verify_account_keys(
     accounts: SetAndVerifyCollectionAccounts<'_, '_>,
     keys: SetAndVerifyCollectionKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
-        (accounts.metadata.key, &keys.metadata),
+        (Some(accounts.metadata.key), Some(&keys.metadata)),
         (
-            accounts.collection_authority.key,
-            &keys.collection_authority,
+            Some(accounts.collection_authority.key),
+            Some(&keys.collection_authority),
         ),
-        (accounts.payer.key, &keys.payer),
-        (accounts.update_authority.key, &keys.update_authority),
-        (accounts.collection_mint.key, &keys.collection_mint),
-        (accounts.collection.key, &keys.collection),
+        (Some(accounts.payer.key), Some(&keys.payer)),
         (
-            accounts.collection_master_edition_account.key,
-            &keys.collection_master_edition_account,
+            Some(accounts.update_authority.key),
+            Some(&keys.update_authority),
         ),
         (
-            accounts.collection_authority_record.key,
-            &keys.collection_authority_record,
+            Some(accounts.collection_mint.key),
+            Some(&keys.collection_mint),
+        ),
+        (Some(accounts.collection.key), Some(&keys.collection)),
+        (
+            Some(accounts.collection_master_edition_account.key),
+            Some(&keys.collection_master_edition_account),
+        ),
+        (
+            accounts.collection_authority_record.map(|a| a.key),
+            keys.collection_authority_record.as_ref(),
         ),
     ] {
         if actual != expected {
-            return Err((*actual, *expected));
+            return Err((
+                *actual.unwrap_or(&crate::ID),
+                *expected.unwrap_or(&crate::ID),
+            ));
         }
     }
     Ok(())
@@ -7478,28 +11672,28 @@ pub fn set_and_verify_collection_verify_account_privileges<'me, 'info>(
 pub const FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct FreezeDelegatedAccountAccounts<'me, 'info> {
-    ///Delegate
+    /// Delegate
     pub delegate: &'me AccountInfo<'info>,
-    ///Token account to freeze
+    /// Token account to freeze
     pub token_account: &'me AccountInfo<'info>,
-    ///Edition
+    /// Edition
     pub edition: &'me AccountInfo<'info>,
-    ///Token mint
+    /// Token mint
     pub mint: &'me AccountInfo<'info>,
-    ///Token Program
+    /// Token Program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct FreezeDelegatedAccountKeys {
-    ///Delegate
+    /// Delegate
     pub delegate: Pubkey,
-    ///Token account to freeze
+    /// Token account to freeze
     pub token_account: Pubkey,
-    ///Edition
+    /// Edition
     pub edition: Pubkey,
-    ///Token mint
+    /// Token mint
     pub mint: Pubkey,
-    ///Token Program
+    /// Token Program
     pub token_program: Pubkey,
 }
 impl From<FreezeDelegatedAccountAccounts<'_, '_>> for FreezeDelegatedAccountKeys {
@@ -7581,6 +11775,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; FREEZE_DELEGATED_ACCOUNT_IX_ACCO
         }
     }
 }
+impl FreezeDelegatedAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for FreezeDelegatedAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> FreezeDelegatedAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for FreezeDelegatedAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const FREEZE_DELEGATED_ACCOUNT_IX_DISCM: u8 = 26u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct FreezeDelegatedAccountIxData;
@@ -7626,6 +11864,76 @@ pub fn freeze_delegated_account_ix(
 ) -> std::io::Result<Instruction> {
     freeze_delegated_account_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`freeze_delegated_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct FreezeDelegatedAccountIxBuilder {
+    delegate: Option<Pubkey>,
+    token_account: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+}
+impl Default for FreezeDelegatedAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl FreezeDelegatedAccountIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            delegate: None,
+            token_account: None,
+            edition: None,
+            mint: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+        }
+    }
+    pub fn delegate(mut self, delegate: Pubkey) -> Self {
+        self.delegate = Some(delegate);
+        self
+    }
+    pub fn token_account(mut self, token_account: Pubkey) -> Self {
+        self.token_account = Some(token_account);
+        self
+    }
+    pub fn edition(mut self, edition: Pubkey) -> Self {
+        self.edition = Some(edition);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("FreezeDelegatedAccountIxBuilder is missing {}", field),
+            )
+        };
+        let delegate = self.delegate.ok_or_else(|| missing("delegate"))?;
+        let token_account = self.token_account.ok_or_else(|| missing("token_account"))?;
+        let edition = self.edition.ok_or_else(|| missing("edition"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let keys = FreezeDelegatedAccountKeys {
+            delegate,
+            token_account,
+            edition,
+            mint,
+            token_program,
+        };
+        freeze_delegated_account_ix(keys)
+    }
+}
 pub fn freeze_delegated_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: FreezeDelegatedAccountAccounts<'_, '_>,
@@ -7701,28 +12009,28 @@ pub fn freeze_delegated_account_verify_account_privileges<'me, 'info>(
 pub const THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
 pub struct ThawDelegatedAccountAccounts<'me, 'info> {
-    ///Delegate
+    /// Delegate
     pub delegate: &'me AccountInfo<'info>,
-    ///Token account to thaw
+    /// Token account to thaw
     pub token_account: &'me AccountInfo<'info>,
-    ///Edition
+    /// Edition
     pub edition: &'me AccountInfo<'info>,
-    ///Token mint
+    /// Token mint
     pub mint: &'me AccountInfo<'info>,
-    ///Token Program
+    /// Token Program
     pub token_program: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ThawDelegatedAccountKeys {
-    ///Delegate
+    /// Delegate
     pub delegate: Pubkey,
-    ///Token account to thaw
+    /// Token account to thaw
     pub token_account: Pubkey,
-    ///Edition
+    /// Edition
     pub edition: Pubkey,
-    ///Token mint
+    /// Token mint
     pub mint: Pubkey,
-    ///Token Program
+    /// Token Program
     pub token_program: Pubkey,
 }
 impl From<ThawDelegatedAccountAccounts<'_, '_>> for ThawDelegatedAccountKeys {
@@ -7804,6 +12112,50 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; THAW_DELEGATED_ACCOUNT_IX_ACCOUN
         }
     }
 }
+impl ThawDelegatedAccountKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for ThawDelegatedAccountKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> ThawDelegatedAccountAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] = accounts
+            .get(..THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for ThawDelegatedAccountAccounts<'me, 'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const THAW_DELEGATED_ACCOUNT_IX_DISCM: u8 = 27u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ThawDelegatedAccountIxData;
@@ -7847,6 +12199,76 @@ pub fn thaw_delegated_account_ix_with_program_id(
 pub fn thaw_delegated_account_ix(keys: ThawDelegatedAccountKeys) -> std::io::Result<Instruction> {
     thaw_delegated_account_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`thaw_delegated_account_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct ThawDelegatedAccountIxBuilder {
+    delegate: Option<Pubkey>,
+    token_account: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+}
+impl Default for ThawDelegatedAccountIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ThawDelegatedAccountIxBuilder {
+    /// Starts a builder with the accounts that have a known address pre-filled
+    pub fn new() -> Self {
+        Self {
+            delegate: None,
+            token_account: None,
+            edition: None,
+            mint: None,
+            token_program: Some(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+        }
+    }
+    pub fn delegate(mut self, delegate: Pubkey) -> Self {
+        self.delegate = Some(delegate);
+        self
+    }
+    pub fn token_account(mut self, token_account: Pubkey) -> Self {
+        self.token_account = Some(token_account);
+        self
+    }
+    pub fn edition(mut self, edition: Pubkey) -> Self {
+        self.edition = Some(edition);
+        self
+    }
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("ThawDelegatedAccountIxBuilder is missing {}", field),
+            )
+        };
+        let delegate = self.delegate.ok_or_else(|| missing("delegate"))?;
+        let token_account = self.token_account.ok_or_else(|| missing("token_account"))?;
+        let edition = self.edition.ok_or_else(|| missing("edition"))?;
+        let mint = self.mint.ok_or_else(|| missing("mint"))?;
+        let token_program = self.token_program.ok_or_else(|| missing("token_program"))?;
+        let keys = ThawDelegatedAccountKeys {
+            delegate,
+            token_account,
+            edition,
+            mint,
+            token_program,
+        };
+        thaw_delegated_account_ix(keys)
+    }
+}
 pub fn thaw_delegated_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ThawDelegatedAccountAccounts<'_, '_>,
@@ -7922,16 +12344,16 @@ pub fn thaw_delegated_account_verify_account_privileges<'me, 'info>(
 pub const REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct RemoveCreatorVerificationAccounts<'me, 'info> {
-    ///Metadata (pda of ['metadata', program id, mint id])
+    /// Metadata (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
-    ///Creator
+    /// Creator
     pub creator: &'me AccountInfo<'info>,
 }
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct RemoveCreatorVerificationKeys {
-    ///Metadata (pda of ['metadata', program id, mint id])
+    /// Metadata (pda of ['metadata', program id, mint id])
     pub metadata: Pubkey,
-    ///Creator
+    /// Creator
     pub creator: Pubkey,
 }
 impl From<RemoveCreatorVerificationAccounts<'_, '_>> for RemoveCreatorVerificationKeys {
@@ -7985,6 +12407,52 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_CREATOR_VERIFICATION_IX_A
         }
     }
 }
+impl RemoveCreatorVerificationKeys {
+    /// Takes the instruction's keys from the start of `pubkeys`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        pubkeys: &[Pubkey],
+    ) -> Result<(Self, &[Pubkey]), ProgramError> {
+        let keys: [Pubkey; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN] = pubkeys
+            .get(..REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN)
+            .and_then(|keys| keys.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            keys.into(),
+            &pubkeys[REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl TryFrom<&[Pubkey]> for RemoveCreatorVerificationKeys {
+    type Error = ProgramError;
+    fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+    }
+}
+impl<'me, 'info> RemoveCreatorVerificationAccounts<'me, 'info> {
+    /// Takes the instruction's accounts from the start of `accounts`
+    /// and returns them with the remaining accounts after them
+    pub fn from_slice_with_remaining(
+        accounts: &'me [AccountInfo<'info>],
+    ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+        let arr: &'me [AccountInfo<'info>; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN] = accounts
+            .get(..REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN)
+            .and_then(|arr| arr.try_into().ok())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Ok((
+            arr.into(),
+            &accounts[REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN..],
+        ))
+    }
+}
+impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]>
+    for RemoveCreatorVerificationAccounts<'me, 'info>
+{
+    type Error = ProgramError;
+    fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+        Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+    }
+}
 pub const REMOVE_CREATOR_VERIFICATION_IX_DISCM: u8 = 28u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct RemoveCreatorVerificationIxData;
@@ -8030,6 +12498,47 @@ pub fn remove_creator_verification_ix(
 ) -> std::io::Result<Instruction> {
     remove_creator_verification_ix_with_program_id(crate::ID, keys)
 }
+/// Builds a [`remove_creator_verification_ix`] instruction one field at a time
+#[derive(Clone, Debug)]
+pub struct RemoveCreatorVerificationIxBuilder {
+    metadata: Option<Pubkey>,
+    creator: Option<Pubkey>,
+}
+impl Default for RemoveCreatorVerificationIxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RemoveCreatorVerificationIxBuilder {
+    /// Starts a builder with no accounts or args set
+    pub fn new() -> Self {
+        Self {
+            metadata: None,
+            creator: None,
+        }
+    }
+    pub fn metadata(mut self, metadata: Pubkey) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    pub fn creator(mut self, creator: Pubkey) -> Self {
+        self.creator = Some(creator);
+        self
+    }
+    /// Errors naming the first account or arg that was not set
+    pub fn build(self) -> std::io::Result<Instruction> {
+        let missing = |field: &str| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("RemoveCreatorVerificationIxBuilder is missing {}", field),
+            )
+        };
+        let metadata = self.metadata.ok_or_else(|| missing("metadata"))?;
+        let creator = self.creator.ok_or_else(|| missing("creator"))?;
+        let keys = RemoveCreatorVerificationKeys { metadata, creator };
+        remove_creator_verification_ix(keys)
+    }
+}
 pub fn remove_creator_verification_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RemoveCreatorVerificationAccounts<'_, '_>,
@@ -8099,3 +12608,318 @@ pub fn remove_creator_verification_verify_account_privileges<'me, 'info>(
     remove_creator_verification_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// A [`MplTokenMetadataProgramIx`] with its accounts
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MplTokenMetadataParsedIx {
+    CreateMetadataAccount {
+        keys: CreateMetadataAccountKeys,
+        args: CreateMetadataAccountIxArgs,
+    },
+    UpdateMetadataAccount {
+        keys: UpdateMetadataAccountKeys,
+        args: UpdateMetadataAccountIxArgs,
+    },
+    DeprecatedCreateMasterEdition {
+        keys: DeprecatedCreateMasterEditionKeys,
+        args: DeprecatedCreateMasterEditionIxArgs,
+    },
+    DeprecatedMintNewEditionFromMasterEditionViaPrintingToken {
+        keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys,
+    },
+    UpdatePrimarySaleHappenedViaToken {
+        keys: UpdatePrimarySaleHappenedViaTokenKeys,
+    },
+    DeprecatedSetReservationList {
+        keys: DeprecatedSetReservationListKeys,
+        args: DeprecatedSetReservationListIxArgs,
+    },
+    DeprecatedCreateReservationList {
+        keys: DeprecatedCreateReservationListKeys,
+    },
+    SignMetadata {
+        keys: SignMetadataKeys,
+    },
+    DeprecatedMintPrintingTokensViaToken {
+        keys: DeprecatedMintPrintingTokensViaTokenKeys,
+        args: DeprecatedMintPrintingTokensViaTokenIxArgs,
+    },
+    DeprecatedMintPrintingTokens {
+        keys: DeprecatedMintPrintingTokensKeys,
+        args: DeprecatedMintPrintingTokensIxArgs,
+    },
+    CreateMasterEdition {
+        keys: CreateMasterEditionKeys,
+        args: CreateMasterEditionIxArgs,
+    },
+    MintNewEditionFromMasterEditionViaToken {
+        keys: MintNewEditionFromMasterEditionViaTokenKeys,
+        args: MintNewEditionFromMasterEditionViaTokenIxArgs,
+    },
+    ConvertMasterEditionV1ToV2 {
+        keys: ConvertMasterEditionV1ToV2Keys,
+    },
+    MintNewEditionFromMasterEditionViaVaultProxy {
+        keys: MintNewEditionFromMasterEditionViaVaultProxyKeys,
+        args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
+    },
+    PuffMetadata {
+        keys: PuffMetadataKeys,
+    },
+    UpdateMetadataAccountV2 {
+        keys: UpdateMetadataAccountV2Keys,
+        args: UpdateMetadataAccountV2IxArgs,
+    },
+    CreateMetadataAccountV2 {
+        keys: CreateMetadataAccountV2Keys,
+        args: CreateMetadataAccountV2IxArgs,
+    },
+    CreateMasterEditionV3 {
+        keys: CreateMasterEditionV3Keys,
+        args: CreateMasterEditionV3IxArgs,
+    },
+    VerifyCollection {
+        keys: VerifyCollectionKeys,
+    },
+    Utilize {
+        keys: UtilizeKeys,
+        args: UtilizeIxArgs,
+    },
+    ApproveUseAuthority {
+        keys: ApproveUseAuthorityKeys,
+        args: ApproveUseAuthorityIxArgs,
+    },
+    RevokeUseAuthority {
+        keys: RevokeUseAuthorityKeys,
+    },
+    UnverifyCollection {
+        keys: UnverifyCollectionKeys,
+    },
+    ApproveCollectionAuthority {
+        keys: ApproveCollectionAuthorityKeys,
+    },
+    RevokeCollectionAuthority {
+        keys: RevokeCollectionAuthorityKeys,
+    },
+    SetAndVerifyCollection {
+        keys: SetAndVerifyCollectionKeys,
+    },
+    FreezeDelegatedAccount {
+        keys: FreezeDelegatedAccountKeys,
+    },
+    ThawDelegatedAccount {
+        keys: ThawDelegatedAccountKeys,
+    },
+    RemoveCreatorVerification {
+        keys: RemoveCreatorVerificationKeys,
+    },
+}
+impl MplTokenMetadataParsedIx {
+    /// Deserializes the instruction `data` and pairs it with `accounts`,
+    /// the instruction's account keys in order.
+    /// Accounts after the instruction's own are ignored.
+    pub fn deserialize(data: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        let program_ix = MplTokenMetadataProgramIx::deserialize(data)?;
+        Ok(
+            match program_ix {
+                MplTokenMetadataProgramIx::CreateMetadataAccount(args) => {
+                    Self::CreateMetadataAccount {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::UpdateMetadataAccount(args) => {
+                    Self::UpdateMetadataAccount {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::DeprecatedCreateMasterEdition(args) => {
+                    Self::DeprecatedCreateMasterEdition {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::DeprecatedMintNewEditionFromMasterEditionViaPrintingToken => {
+                    Self::DeprecatedMintNewEditionFromMasterEditionViaPrintingToken {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::UpdatePrimarySaleHappenedViaToken => {
+                    Self::UpdatePrimarySaleHappenedViaToken {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::DeprecatedSetReservationList(args) => {
+                    Self::DeprecatedSetReservationList {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::DeprecatedCreateReservationList => {
+                    Self::DeprecatedCreateReservationList {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::SignMetadata => {
+                    Self::SignMetadata {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::DeprecatedMintPrintingTokensViaToken(args) => {
+                    Self::DeprecatedMintPrintingTokensViaToken {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::DeprecatedMintPrintingTokens(args) => {
+                    Self::DeprecatedMintPrintingTokens {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::CreateMasterEdition(args) => {
+                    Self::CreateMasterEdition {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::MintNewEditionFromMasterEditionViaToken(
+                    args,
+                ) => {
+                    Self::MintNewEditionFromMasterEditionViaToken {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::ConvertMasterEditionV1ToV2 => {
+                    Self::ConvertMasterEditionV1ToV2 {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::MintNewEditionFromMasterEditionViaVaultProxy(
+                    args,
+                ) => {
+                    Self::MintNewEditionFromMasterEditionViaVaultProxy {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::PuffMetadata => {
+                    Self::PuffMetadata {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::UpdateMetadataAccountV2(args) => {
+                    Self::UpdateMetadataAccountV2 {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::CreateMetadataAccountV2(args) => {
+                    Self::CreateMetadataAccountV2 {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::CreateMasterEditionV3(args) => {
+                    Self::CreateMasterEditionV3 {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::VerifyCollection => {
+                    Self::VerifyCollection {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::Utilize(args) => {
+                    Self::Utilize {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::ApproveUseAuthority(args) => {
+                    Self::ApproveUseAuthority {
+                        keys: parsed_ix_keys(accounts)?,
+                        args,
+                    }
+                }
+                MplTokenMetadataProgramIx::RevokeUseAuthority => {
+                    Self::RevokeUseAuthority {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::UnverifyCollection => {
+                    Self::UnverifyCollection {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::ApproveCollectionAuthority => {
+                    Self::ApproveCollectionAuthority {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::RevokeCollectionAuthority => {
+                    Self::RevokeCollectionAuthority {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::SetAndVerifyCollection => {
+                    Self::SetAndVerifyCollection {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::FreezeDelegatedAccount => {
+                    Self::FreezeDelegatedAccount {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::ThawDelegatedAccount => {
+                    Self::ThawDelegatedAccount {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+                MplTokenMetadataProgramIx::RemoveCreatorVerification => {
+                    Self::RemoveCreatorVerification {
+                        keys: parsed_ix_keys(accounts)?,
+                    }
+                }
+            },
+        )
+    }
+    /// Parses an instruction compiled into a transaction message.
+    /// `account_keys` are the message's account keys, which must include the keys
+    /// loaded from address lookup tables for v0 messages, e.g.
+    /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+    pub fn from_compiled(
+        ix: &solana_program::instruction::CompiledInstruction,
+        account_keys: &solana_program::message::AccountKeys,
+    ) -> std::io::Result<Self> {
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|i| {
+                account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("account index {} out of bounds", i),
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+}
+fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(accounts: &[Pubkey]) -> std::io::Result<K> {
+    let keys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|keys| keys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("expected at least {} accounts, got {}", N, accounts.len()),
+            )
+        })?;
+    Ok(keys.into())
+}
@@ -7,3 +7,7 @@ pub mod instructions;
 pub use instructions::*;
 pub mod errors;
 pub use errors::*;
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+#[cfg(feature = "serde")]
+pub use serde_helpers::*;
@@ -2,66 +2,121 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MintPrintingTokensViaTokenArgs {
     pub supply: u64,
 }
+/// Serialized size of [`MintPrintingTokensViaTokenArgs`]
+pub const MINT_PRINTING_TOKENS_VIA_TOKEN_ARGS_LEN: usize = 8;
+/// Offset of `supply` in serialized [`MintPrintingTokensViaTokenArgs`]
+pub const MINT_PRINTING_TOKENS_VIA_TOKEN_ARGS_SUPPLY_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SetReservationListArgs {
     pub reservations: Vec<Reservation>,
     pub total_reservation_spots: Option<u64>,
     pub offset: u64,
     pub total_spot_offset: u64,
 }
+/// Offset of `reservations` in serialized [`SetReservationListArgs`]
+pub const SET_RESERVATION_LIST_ARGS_RESERVATIONS_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateMetadataAccountArgs {
     pub data: Option<Data>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<Option<crate::serde_helpers::Base58>>")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::serde_helpers::Base58>")
+    )]
     pub update_authority: Option<Pubkey>,
     pub primary_sale_happened: Option<bool>,
 }
+/// Offset of `data` in serialized [`UpdateMetadataAccountArgs`]
+pub const UPDATE_METADATA_ACCOUNT_ARGS_DATA_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateMetadataAccountArgsV2 {
     pub data: Option<DataV2>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<Option<crate::serde_helpers::Base58>>")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::serde_helpers::Base58>")
+    )]
     pub update_authority: Option<Pubkey>,
     pub primary_sale_happened: Option<bool>,
     pub is_mutable: Option<bool>,
 }
+/// Offset of `data` in serialized [`UpdateMetadataAccountArgsV2`]
+pub const UPDATE_METADATA_ACCOUNT_ARGS_V2_DATA_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateMetadataAccountArgs {
     pub data: Data,
     pub is_mutable: bool,
 }
+/// Offset of `data` in serialized [`CreateMetadataAccountArgs`]
+pub const CREATE_METADATA_ACCOUNT_ARGS_DATA_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateMetadataAccountArgsV2 {
     pub data: DataV2,
     pub is_mutable: bool,
 }
+/// Offset of `data` in serialized [`CreateMetadataAccountArgsV2`]
+pub const CREATE_METADATA_ACCOUNT_ARGS_V2_DATA_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CreateMasterEditionArgs {
     pub max_supply: Option<u64>,
 }
+/// Offset of `max_supply` in serialized [`CreateMasterEditionArgs`]
+pub const CREATE_MASTER_EDITION_ARGS_MAX_SUPPLY_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct MintNewEditionFromMasterEditionViaTokenArgs {
     pub edition: u64,
 }
+/// Serialized size of [`MintNewEditionFromMasterEditionViaTokenArgs`]
+pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_ARGS_LEN: usize = 8;
+/// Offset of `edition` in serialized [`MintNewEditionFromMasterEditionViaTokenArgs`]
+pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_ARGS_EDITION_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ApproveUseAuthorityArgs {
     pub number_of_uses: u64,
 }
+/// Serialized size of [`ApproveUseAuthorityArgs`]
+pub const APPROVE_USE_AUTHORITY_ARGS_LEN: usize = 8;
+/// Offset of `number_of_uses` in serialized [`ApproveUseAuthorityArgs`]
+pub const APPROVE_USE_AUTHORITY_ARGS_NUMBER_OF_USES_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UtilizeArgs {
     pub number_of_uses: u64,
 }
+/// Serialized size of [`UtilizeArgs`]
+pub const UTILIZE_ARGS_LEN: usize = 8;
+/// Offset of `number_of_uses` in serialized [`UtilizeArgs`]
+pub const UTILIZE_ARGS_NUMBER_OF_USES_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Data {
     pub name: String,
     pub symbol: String,
@@ -69,8 +124,11 @@ pub struct Data {
     pub seller_fee_basis_points: u16,
     pub creators: Option<Vec<Creator>>,
 }
+/// Offset of `name` in serialized [`Data`]
+pub const DATA_NAME_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DataV2 {
     pub name: String,
     pub symbol: String,
@@ -80,42 +138,108 @@ pub struct DataV2 {
     pub collection: Option<Collection>,
     pub uses: Option<Uses>,
 }
+/// Offset of `name` in serialized [`DataV2`]
+pub const DATA_V2_NAME_OFFSET: usize = 0;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Uses {
     pub use_method: UseMethod,
     pub remaining: u64,
     pub total: u64,
 }
+/// Serialized size of [`Uses`]
+pub const USES_LEN: usize = 17;
+/// Offset of `use_method` in serialized [`Uses`]
+pub const USES_USE_METHOD_OFFSET: usize = 0;
+/// Offset of `remaining` in serialized [`Uses`]
+pub const USES_REMAINING_OFFSET: usize = 1;
+/// Offset of `total` in serialized [`Uses`]
+pub const USES_TOTAL_OFFSET: usize = 9;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Collection {
     pub verified: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub key: Pubkey,
 }
+/// Serialized size of [`Collection`]
+pub const COLLECTION_LEN: usize = 33;
+/// Offset of `verified` in serialized [`Collection`]
+pub const COLLECTION_VERIFIED_OFFSET: usize = 0;
+/// Offset of `key` in serialized [`Collection`]
+pub const COLLECTION_KEY_OFFSET: usize = 1;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Creator {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub address: Pubkey,
     pub verified: bool,
     pub share: u8,
 }
+/// Serialized size of [`Creator`]
+pub const CREATOR_LEN: usize = 34;
+/// Offset of `address` in serialized [`Creator`]
+pub const CREATOR_ADDRESS_OFFSET: usize = 0;
+/// Offset of `verified` in serialized [`Creator`]
+pub const CREATOR_VERIFIED_OFFSET: usize = 32;
+/// Offset of `share` in serialized [`Creator`]
+pub const CREATOR_SHARE_OFFSET: usize = 33;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Reservation {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub address: Pubkey,
     pub spots_remaining: u64,
     pub total_spots: u64,
 }
+/// Serialized size of [`Reservation`]
+pub const RESERVATION_LEN: usize = 48;
+/// Offset of `address` in serialized [`Reservation`]
+pub const RESERVATION_ADDRESS_OFFSET: usize = 0;
+/// Offset of `spots_remaining` in serialized [`Reservation`]
+pub const RESERVATION_SPOTS_REMAINING_OFFSET: usize = 32;
+/// Offset of `total_spots` in serialized [`Reservation`]
+pub const RESERVATION_TOTAL_SPOTS_OFFSET: usize = 40;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ReservationV1 {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_helpers::As::<crate::serde_helpers::Base58>")
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::serde_helpers::Base58"))]
     pub address: Pubkey,
     pub spots_remaining: u8,
     pub total_spots: u8,
 }
+/// Serialized size of [`ReservationV1`]
+pub const RESERVATION_V1_LEN: usize = 34;
+/// Offset of `address` in serialized [`ReservationV1`]
+pub const RESERVATION_V1_ADDRESS_OFFSET: usize = 0;
+/// Offset of `spots_remaining` in serialized [`ReservationV1`]
+pub const RESERVATION_V1_SPOTS_REMAINING_OFFSET: usize = 32;
+/// Offset of `total_spots` in serialized [`ReservationV1`]
+pub const RESERVATION_V1_TOTAL_SPOTS_OFFSET: usize = 33;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Key {
     Uninitialized,
     EditionV1,
@@ -128,18 +252,26 @@ pub enum Key {
     UseAuthorityRecord,
     CollectionAuthorityRecord,
 }
+/// Serialized size of [`Key`]
+pub const KEY_LEN: usize = 1;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum UseMethod {
     Burn,
     Multiple,
     Single,
 }
+/// Serialized size of [`UseMethod`]
+pub const USE_METHOD_LEN: usize = 1;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum TokenStandard {
     NonFungible,
     FungibleAsset,
     Fungible,
     NonFungibleEdition,
 }
+/// Serialized size of [`TokenStandard`]
+pub const TOKEN_STANDARD_LEN: usize = 1;
@@ -0,0 +1,144 @@
+//! Errors of the anchor framework itself, which anchor programs can fail with
+//! but are not in their IDL's `errors`.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::LitInt;
+
+use crate::error_decoding::error_conversion_impls;
+
+pub const ANCHOR_FRAMEWORK_ERROR_ENUM_NAME: &str = "AnchorFrameworkError";
+
+/// (code, name, msg) of `anchor_lang::error::ErrorCode`
+const ANCHOR_FRAMEWORK_ERRORS: &[(u32, &str, &str)] = &[
+    // instructions
+    (100, "InstructionMissing", "8 byte instruction identifier not provided"),
+    (101, "InstructionFallbackNotFound", "Fallback functions are not supported"),
+    (102, "InstructionDidNotDeserialize", "The program could not deserialize the given instruction"),
+    (103, "InstructionDidNotSerialize", "The program could not serialize the given instruction"),
+    // IDL instructions
+    (1000, "IdlInstructionStub", "The program was compiled without idl instructions"),
+    (1001, "IdlInstructionInvalidProgram", "Invalid program given to the IDL instruction"),
+    (1002, "IdlAccountNotEmpty", "IDL account must be empty in order to resize, try closing first"),
+    // event instructions
+    (1500, "EventInstructionStub", "The program was compiled without `event-cpi` feature"),
+    // constraints
+    (2000, "ConstraintMut", "A mut constraint was violated"),
+    (2001, "ConstraintHasOne", "A has one constraint was violated"),
+    (2002, "ConstraintSigner", "A signer constraint was violated"),
+    (2003, "ConstraintRaw", "A raw constraint was violated"),
+    (2004, "ConstraintOwner", "An owner constraint was violated"),
+    (2005, "ConstraintRentExempt", "A rent exemption constraint was violated"),
+    (2006, "ConstraintSeeds", "A seeds constraint was violated"),
+    (2007, "ConstraintExecutable", "An executable constraint was violated"),
+    (2008, "ConstraintState", "Deprecated Error, feel free to replace with something else"),
+    (2009, "ConstraintAssociated", "An associated constraint was violated"),
+    (2010, "ConstraintAssociatedInit", "An associated init constraint was violated"),
+    (2011, "ConstraintClose", "A close constraint was violated"),
+    (2012, "ConstraintAddress", "An address constraint was violated"),
+    (2013, "ConstraintZero", "Expected zero account discriminant"),
+    (2014, "ConstraintTokenMint", "A token mint constraint was violated"),
+    (2015, "ConstraintTokenOwner", "A token owner constraint was violated"),
+    (2016, "ConstraintMintMintAuthority", "A mint mint authority constraint was violated"),
+    (2017, "ConstraintMintFreezeAuthority", "A mint freeze authority constraint was violated"),
+    (2018, "ConstraintMintDecimals", "A mint decimals constraint was violated"),
+    (2019, "ConstraintSpace", "A space constraint was violated"),
+    (2020, "ConstraintAccountIsNone", "A required account for the constraint is None"),
+    (2021, "ConstraintTokenTokenProgram", "A token account token program constraint was violated"),
+    (2022, "ConstraintMintTokenProgram", "A mint token program constraint was violated"),
+    (2023, "ConstraintAssociatedTokenTokenProgram", "An associated token account token program constraint was violated"),
+    // require
+    (2500, "RequireViolated", "A require expression was violated"),
+    (2501, "RequireEqViolated", "A require_eq expression was violated"),
+    (2502, "RequireKeysEqViolated", "A require_keys_eq expression was violated"),
+    (2503, "RequireNeqViolated", "A require_neq expression was violated"),
+    (2504, "RequireKeysNeqViolated", "A require_keys_neq expression was violated"),
+    (2505, "RequireGtViolated", "A require_gt expression was violated"),
+    (2506, "RequireGteViolated", "A require_gte expression was violated"),
+    // accounts
+    (3000, "AccountDiscriminatorAlreadySet", "The account discriminator was already set on this account"),
+    (3001, "AccountDiscriminatorNotFound", "No 8 byte discriminator was found on the account"),
+    (3002, "AccountDiscriminatorMismatch", "8 byte discriminator did not match what was expected"),
+    (3003, "AccountDidNotDeserialize", "Failed to deserialize the account"),
+    (3004, "AccountDidNotSerialize", "Failed to serialize the account"),
+    (3005, "AccountNotEnoughKeys", "Not enough account keys given to the instruction"),
+    (3006, "AccountNotMutable", "The given account is not mutable"),
+    (3007, "AccountOwnedByWrongProgram", "The given account is owned by a different program than expected"),
+    (3008, "InvalidProgramId", "Program ID was not as expected"),
+    (3009, "InvalidProgramExecutable", "Program account is not executable"),
+    (3010, "AccountNotSigner", "The given account did not sign"),
+    (3011, "AccountNotSystemOwned", "The given account is not owned by the system program"),
+    (3012, "AccountNotInitialized", "The program expected this account to be already initialized"),
+    (3013, "AccountNotProgramData", "The given account is not a program data account"),
+    (3014, "AccountNotAssociatedTokenAccount", "The given account is not the associated token account"),
+    (3015, "AccountSysvarMismatch", "The given public key does not match the required sysvar"),
+    (3016, "AccountReallocExceedsLimit", "The account reallocation exceeds the MAX_PERMITTED_DATA_INCREASE limit"),
+    (3017, "AccountDuplicateReallocs", "The account was duplicated for more than one reallocation"),
+    // miscellaneous
+    (4100, "DeclaredProgramIdMismatch", "The declared program id does not match the actual program id"),
+    (4101, "TryingToInitPayerAsProgramAccount", "You cannot/should not initialize the payer account as a program account"),
+    (4102, "InvalidNumericConversion", "The program could not perform the numeric conversion, out of range integral type conversion attempted"),
+    // deprecated
+    (5000, "Deprecated", "The API being used is deprecated and should no longer be used"),
+];
+
+/// Variants of the `AnchorFrameworkError` enum, in the same format as the IDL's errors
+pub fn anchor_framework_error_variants() -> TokenStream {
+    ANCHOR_FRAMEWORK_ERRORS
+        .iter()
+        .map(|(code, name, msg)| {
+            let variant_ident = format_ident!("{}", name);
+            let doc = format!(" {}", msg);
+            let code_literal = LitInt::new(&code.to_string(), Span::call_site());
+            quote! {
+                #[doc = #doc]
+                #[error(#msg)]
+                #variant_ident = #code_literal,
+            }
+        })
+        .collect()
+}
+
+/// `{Program}AnyError`, either a program error or an anchor framework error,
+/// with its `TryFrom<u32>` and [`error_conversion_impls`]
+pub fn any_error_enum(program_error_ident: &Ident, any_error_ident: &Ident) -> TokenStream {
+    let framework_error_ident = format_ident!("{}", ANCHOR_FRAMEWORK_ERROR_ENUM_NAME);
+    let doc = format!(
+        " Either a [`{}`] or an [`{}`]",
+        program_error_ident, framework_error_ident
+    );
+    let conversion_impls = error_conversion_impls(any_error_ident, true);
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+        pub enum #any_error_ident {
+            #[error(transparent)]
+            Program(#[from] #program_error_ident),
+            #[error(transparent)]
+            Framework(#[from] #framework_error_ident),
+        }
+
+        impl TryFrom<u32> for #any_error_ident {
+            type Error = u32;
+
+            /// Tries the program's errors, then the anchor framework's.
+            /// Returns `code` back if it is neither.
+            fn try_from(code: u32) -> Result<Self, Self::Error> {
+                #program_error_ident::try_from(code)
+                    .map(Self::Program)
+                    .or_else(|code| #framework_error_ident::try_from(code).map(Self::Framework))
+            }
+        }
+
+        impl From<#any_error_ident> for ProgramError {
+            fn from(e: #any_error_ident) -> Self {
+                match e {
+                    #any_error_ident::Program(e) => e.into(),
+                    #any_error_ident::Framework(e) => e.into(),
+                }
+            }
+        }
+
+        #conversion_impls
+    }
+}
@@ -0,0 +1,125 @@
+//! RPC client convenience fns for the generated crate's `client` feature,
+//! built on the `*Account::deserialize()` and `*_ix()` fns.
+
+use heck::ToSnakeCase;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// Cargo feature the client module is gated behind
+pub const CLIENT_FEATURE: &str = "client";
+
+/// Imports of the client module
+pub fn client_head(has_accounts: bool, has_ixs: bool) -> TokenStream {
+    let mut res = quote! {
+        use solana_program::pubkey::Pubkey;
+        use solana_rpc_client::rpc_client::RpcClient;
+        use solana_rpc_client_api::client_error::Result as ClientResult;
+        use crate::*;
+    };
+    if has_accounts {
+        res.extend(quote! {
+            use solana_account_decoder::UiAccountEncoding;
+            use solana_rpc_client_api::{
+                config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+                filter::RpcFilterType,
+            };
+        });
+    }
+    if has_ixs {
+        res.extend(quote! {
+            use solana_sdk::{
+                message::Message, signature::Signature, signer::Signer, transaction::Transaction,
+            };
+        });
+    }
+    res
+}
+
+/// `fetch_{account}()` and `fetch_all_{account}()`
+pub fn fetch_fns(account_name: &str, account_ident: &Ident) -> TokenStream {
+    let account_name = account_name.to_snake_case();
+    let fetch_ident = format_ident!("fetch_{}", account_name);
+    let fetch_all_ident = format_ident!("fetch_all_{}", account_name);
+    let fetch_doc = format!(
+        " Fetches and deserializes the [`{}`] at `pubkey`",
+        account_ident
+    );
+    let fetch_all_doc = format!(
+        " Fetches all [`{}`]s of this program that match `filters` in addition to the discriminator",
+        account_ident
+    );
+    quote! {
+        #[doc = #fetch_doc]
+        pub fn #fetch_ident(client: &RpcClient, pubkey: &Pubkey) -> ClientResult<#account_ident> {
+            let data = client.get_account_data(pubkey)?;
+            Ok(#account_ident::deserialize(&data)?)
+        }
+
+        #[doc = #fetch_all_doc]
+        pub fn #fetch_all_ident(
+            client: &RpcClient,
+            filters: Vec<AccountFilter>,
+        ) -> ClientResult<Vec<(Pubkey, #account_ident)>> {
+            let filters = #account_ident::filters()
+                .discriminator()
+                .build()
+                .into_iter()
+                .chain(filters)
+                .map(RpcFilterType::from)
+                .collect();
+            let config = RpcProgramAccountsConfig {
+                filters: Some(filters),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            client
+                .get_program_accounts_with_config(&crate::ID, config)?
+                .into_iter()
+                .map(|(pubkey, account)| Ok((pubkey, #account_ident::deserialize(&account.data)?)))
+                .collect()
+        }
+    }
+}
+
+/// `send_{ix}()`.
+///
+/// `keys_ident` and `ix_args_ident` are the params of `ix_fn_ident`, if any.
+pub fn send_fn(
+    ix_name: &str,
+    ix_fn_ident: &Ident,
+    keys_ident: Option<&Ident>,
+    ix_args_ident: Option<&Ident>,
+) -> TokenStream {
+    let send_ident = format_ident!("send_{}", ix_name.to_snake_case());
+    let doc = format!(
+        " Sends a transaction of [`{}`] signed by `signers` with `payer` as the fee payer and waits for confirmation",
+        ix_fn_ident
+    );
+    let mut fn_params = quote! {};
+    let mut fn_args = quote! {};
+    if let Some(keys_ident) = keys_ident {
+        fn_params.extend(quote! { keys: #keys_ident, });
+        fn_args.extend(quote! { keys, });
+    }
+    if let Some(ix_args_ident) = ix_args_ident {
+        fn_params.extend(quote! { args: #ix_args_ident, });
+        fn_args.extend(quote! { args, });
+    }
+    quote! {
+        #[doc = #doc]
+        pub fn #send_ident(
+            client: &RpcClient,
+            payer: &Pubkey,
+            signers: &[&dyn Signer],
+            #fn_params
+        ) -> ClientResult<Signature> {
+            let ix = #ix_fn_ident(#fn_args)?;
+            let mut tx = Transaction::new_unsigned(Message::new(&[ix], Some(payer)));
+            tx.try_sign(signers, client.get_latest_blockhash()?)?;
+            client.send_and_confirm_transaction(&tx)
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Conversions back into the generated error enums from error codes,
+//! `ProgramError`s, `InstructionError`s and transaction logs.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// `TryFrom<u32>` for an error enum deriving `num_traits::FromPrimitive`
+/// and the rest of [`error_conversion_impls`]
+pub fn error_decoding_impls(error_enum_ident: &Ident, anchor_logs: bool) -> TokenStream {
+    let conversion_impls = error_conversion_impls(error_enum_ident, anchor_logs);
+    quote! {
+        impl TryFrom<u32> for #error_enum_ident {
+            type Error = u32;
+
+            /// Returns `code` back if it is not one of this program's errors
+            fn try_from(code: u32) -> Result<Self, Self::Error> {
+                <Self as num_traits::FromPrimitive>::from_u32(code).ok_or(code)
+            }
+        }
+
+        #conversion_impls
+    }
+}
+
+/// `TryFrom<&ProgramError>`, `TryFrom<&InstructionError>` and `from_log()`,
+/// built on the error enum's `TryFrom<u32>`.
+///
+/// `anchor_logs` additionally recognizes anchor's `AnchorError ... Error Number: N.` log lines.
+/// Requires `ProgramError` and `InstructionError` to be imported.
+pub fn error_conversion_impls(error_enum_ident: &Ident, anchor_logs: bool) -> TokenStream {
+    let from_log_doc = if anchor_logs {
+        " Parses a `custom program error: 0x...` or `AnchorError ... Error Number: N.` log line"
+    } else {
+        " Parses a `custom program error: 0x...` log line"
+    };
+    let anchor_log_branch = anchor_logs.then(|| {
+        quote! {
+            else if let Some((_, rest)) = log
+                .split_once("AnchorError ")
+                .and_then(|(_, rest)| rest.split_once("Error Number: "))
+            {
+                rest.split('.').next()?.parse().ok()?
+            }
+        }
+    });
+    quote! {
+        impl<'a> TryFrom<&'a ProgramError> for #error_enum_ident {
+            type Error = &'a ProgramError;
+
+            /// Returns `e` back if it is not a `ProgramError::Custom` of this program's errors
+            fn try_from(e: &'a ProgramError) -> Result<Self, Self::Error> {
+                match e {
+                    ProgramError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+                    _ => Err(e),
+                }
+            }
+        }
+
+        impl<'a> TryFrom<&'a InstructionError> for #error_enum_ident {
+            type Error = &'a InstructionError;
+
+            /// Returns `e` back if it is not an `InstructionError::Custom` of this program's errors
+            fn try_from(e: &'a InstructionError) -> Result<Self, Self::Error> {
+                match e {
+                    InstructionError::Custom(code) => Self::try_from(*code).map_err(|_| e),
+                    _ => Err(e),
+                }
+            }
+        }
+
+        impl #error_enum_ident {
+            #[doc = #from_log_doc]
+            /// into the error, e.g. `Program <id> failed: custom program error: 0x1770`.
+            /// Returns `None` for other logs and codes that are not of this program's errors.
+            pub fn from_log(log: &str) -> Option<Self> {
+                let code = if let Some((_, hex)) = log.split_once("custom program error: 0x") {
+                    u32::from_str_radix(hex.trim_end(), 16).ok()?
+                } #anchor_log_branch else {
+                    return None;
+                };
+                Self::try_from(code).ok()
+            }
+        }
+    }
+}
+
+/// `parse_error_from_logs()` and `parse_error_from_logs_with_program_id()` into the error enum.
+///
+/// Requires `Pubkey` to be imported and the error enum's `from_log()`.
+pub fn parse_error_from_logs_fns(error_enum_ident: &Ident) -> TokenStream {
+    quote! {
+        /// Parses the error the program failed with from a transaction's log messages.
+        ///
+        /// Only the first `Program <id> failed: ` log is considered, since programs that
+        /// invoked the failing program log the same error when failing in turn.
+        /// Returns `None` if the failing program is not `program_id`
+        /// or it did not fail with one of its errors.
+        pub fn parse_error_from_logs_with_program_id(
+            program_id: Pubkey,
+            logs: &[String],
+        ) -> Option<#error_enum_ident> {
+            let (program, failed_log) = logs.iter().find_map(|log| {
+                let mut words = log.strip_prefix("Program ")?.split(' ');
+                let program = words.next()?.parse::<Pubkey>().ok()?;
+                (words.next() == Some("failed:")).then_some((program, log))
+            })?;
+            if program != program_id {
+                return None;
+            }
+            #error_enum_ident::from_log(failed_log)
+        }
+
+        pub fn parse_error_from_logs(logs: &[String]) -> Option<#error_enum_ident> {
+            parse_error_from_logs_with_program_id(crate::ID, logs)
+        }
+    }
+}
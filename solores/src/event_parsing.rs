@@ -0,0 +1,147 @@
+//! The `{Program}Event` enum over all of a program's events
+//! and the fns that parse them out of transaction logs and inner instructions.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// An event struct and its `{NAME}_EVENT_DISCM` const
+pub struct EventVariant {
+    pub struct_ident: Ident,
+    pub discm_ident: Ident,
+}
+
+/// The `{Program}Event` enum and its `deserialize()` over the event discriminators.
+///
+/// Requires `BorshDeserialize` to be imported and the event structs to be in scope
+pub fn program_event_enum(enum_ident: &Ident, variants: &[EventVariant]) -> TokenStream {
+    let enum_variants = variants.iter().map(|EventVariant { struct_ident, .. }| {
+        quote! { #struct_ident(#struct_ident) }
+    });
+    let deserialize_variant_match_arms = variants.iter().map(
+        |EventVariant {
+             struct_ident,
+             discm_ident,
+         }| {
+            quote! {
+                #discm_ident => Ok(Self::#struct_ident(#struct_ident::deserialize(&mut reader)?))
+            }
+        },
+    );
+    quote! {
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum #enum_ident {
+            #(#enum_variants),*
+        }
+
+        impl #enum_ident {
+            pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+                let mut reader = buf;
+                let maybe_discm = <[u8; 8]>::deserialize(&mut reader)?;
+                match maybe_discm {
+                    #(#deserialize_variant_match_arms,)*
+                    _ => Err(
+                        std::io::Error::new(
+                            std::io::ErrorKind::Other, format!("discm {:?} not found", maybe_discm)
+                        )
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// `parse_events_from_logs()` and `parse_events_from_logs_with_program_id()`,
+/// built on `enum_ident`'s `deserialize()`.
+///
+/// Requires `base64::Engine` and `Pubkey` to be imported
+pub fn parse_events_from_logs_fns(enum_ident: &Ident) -> TokenStream {
+    quote! {
+        /// Parses the events emitted by the program from a transaction's log messages.
+        ///
+        /// `Program data: ` logs are attributed to the program at the top of the invoke stack.
+        /// Logs that are not a base64-encoded event of the program are skipped.
+        pub fn parse_events_from_logs_with_program_id(
+            program_id: Pubkey,
+            logs: &[String],
+        ) -> Vec<#enum_ident> {
+            let mut invoke_stack: Vec<Pubkey> = Vec::new();
+            let mut events = Vec::new();
+            for log in logs {
+                if let Some(data) = log.strip_prefix("Program data: ") {
+                    if invoke_stack.last() != Some(&program_id) {
+                        continue;
+                    }
+                    let event = base64::engine::general_purpose::STANDARD
+                        .decode(data)
+                        .ok()
+                        .and_then(|data| #enum_ident::deserialize(&data).ok());
+                    events.extend(event);
+                } else if let Some(rest) = log.strip_prefix("Program ") {
+                    let mut words = rest.split(' ');
+                    let program = match words.next().map(|w| w.parse::<Pubkey>()) {
+                        Some(Ok(program)) => program,
+                        _ => continue,
+                    };
+                    match words.next() {
+                        Some("invoke") => invoke_stack.push(program),
+                        Some("success" | "failed:") => {
+                            invoke_stack.pop();
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            events
+        }
+
+        pub fn parse_events_from_logs(logs: &[String]) -> Vec<#enum_ident> {
+            parse_events_from_logs_with_program_id(crate::ID, logs)
+        }
+    }
+}
+
+/// `EVENT_IX_TAG_LE`, `enum_ident`'s `from_cpi_ix_data()`, and `parse_cpi_events()`
+/// and `parse_cpi_events_with_program_id()` for events emitted with `emit_cpi!()`,
+/// built on `enum_ident`'s `deserialize()`.
+///
+/// Requires `BorshDeserialize`, `Pubkey` and `Instruction` to be imported
+pub fn cpi_events_fns(enum_ident: &Ident) -> TokenStream {
+    quote! {
+        /// Prefix of the instruction data of the self-invocations `emit_cpi!()` emits events with
+        pub const EVENT_IX_TAG_LE: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+        impl #enum_ident {
+            /// Decodes the event from the data of an `emit_cpi!()` self-invocation
+            pub fn from_cpi_ix_data(data: &[u8]) -> std::io::Result<Self> {
+                let mut reader = data;
+                let maybe_tag = <[u8; 8]>::deserialize(&mut reader)?;
+                if maybe_tag != EVENT_IX_TAG_LE {
+                    return Err(
+                        std::io::Error::new(
+                            std::io::ErrorKind::Other, format!("event ix tag does not match. Expected: {:?}. Received: {:?}", EVENT_IX_TAG_LE, maybe_tag)
+                        )
+                    );
+                }
+                Self::deserialize(reader)
+            }
+        }
+
+        /// Decodes the events emitted with `emit_cpi!()` from a transaction's inner instructions.
+        ///
+        /// Instructions that are not `emit_cpi!()` self-invocations of the program are skipped.
+        pub fn parse_cpi_events_with_program_id(
+            program_id: Pubkey,
+            inner_ixs: &[Instruction],
+        ) -> Vec<#enum_ident> {
+            inner_ixs
+                .iter()
+                .filter(|ix| ix.program_id == program_id)
+                .filter_map(|ix| #enum_ident::from_cpi_ix_data(&ix.data).ok())
+                .collect()
+        }
+
+        pub fn parse_cpi_events(inner_ixs: &[Instruction]) -> Vec<#enum_ident> {
+            parse_cpi_events_with_program_id(crate::ID, inner_ixs)
+        }
+    }
+}
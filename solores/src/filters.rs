@@ -0,0 +1,126 @@
+//! `getProgramAccounts` filter builders for accounts,
+//! built from the account discriminators and the consts generated by [`crate::layout`].
+
+use heck::ToSnakeCase;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// A field that can be matched with a memcmp filter
+pub struct FilterField {
+    /// Field name as in the IDL
+    pub name: String,
+    /// Expression for the field's offset in the account data
+    pub offset: TokenStream,
+    /// Type of the `{field}_eq()` arg, borsh-serialized to get the bytes to compare
+    pub arg_ty: TokenStream,
+}
+
+/// The `AccountFilter` enum shared by all `*AccountFilters` builders,
+/// with a conversion into `RpcFilterType` if the `solana-rpc-client-api` feature is enabled
+pub fn account_filter_enum() -> TokenStream {
+    quote! {
+        /// A `getProgramAccounts` filter
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum AccountFilter {
+            /// Account data is exactly this many bytes long
+            DataSize(u64),
+            /// Account data at `offset` starts with `bytes`
+            Memcmp { offset: usize, bytes: Vec<u8> },
+        }
+
+        #[cfg(feature = "solana-rpc-client-api")]
+        impl From<AccountFilter> for solana_rpc_client_api::filter::RpcFilterType {
+            fn from(filter: AccountFilter) -> Self {
+                match filter {
+                    AccountFilter::DataSize(size) => Self::DataSize(size),
+                    AccountFilter::Memcmp { offset, bytes } => Self::Memcmp(
+                        solana_rpc_client_api::filter::Memcmp::new_base58_encoded(offset, &bytes),
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// `{Account}Filters` builder and `{Account}::filters()`.
+///
+/// `discm_ident` is the const the account data starts with,
+/// `len_ident` the const of the account data's size if it is fixed.
+pub fn account_filters_builder(
+    account_ident: &Ident,
+    discm_ident: Option<&Ident>,
+    len_ident: Option<&Ident>,
+    fields: &[FilterField],
+) -> TokenStream {
+    let filters_ident = format_ident!("{}Filters", account_ident);
+    let builder_doc = format!(
+        " Builds `getProgramAccounts` filters for [`{}`]s",
+        account_ident
+    );
+    let discm_fn = discm_ident.map(|discm_ident| {
+        quote! {
+            /// Matches the account discriminator
+            pub fn discriminator(mut self) -> Self {
+                self.0.push(AccountFilter::Memcmp {
+                    offset: 0,
+                    bytes: #discm_ident.to_vec(),
+                });
+                self
+            }
+        }
+    });
+    let data_size_fn = len_ident.map(|len_ident| {
+        quote! {
+            /// Matches the account data size.
+            /// Accounts allocated with extra space will not match.
+            pub fn data_size(mut self) -> Self {
+                self.0.push(AccountFilter::DataSize(#len_ident as u64));
+                self
+            }
+        }
+    });
+    let field_fns = fields.iter().map(
+        |FilterField {
+             name,
+             offset,
+             arg_ty,
+         }| {
+            let field_name = name.to_snake_case();
+            let fn_ident = format_ident!("{}_eq", field_name);
+            let doc = format!(" Matches accounts whose `{}` equals `value`", field_name);
+            quote! {
+                #[doc = #doc]
+                pub fn #fn_ident(mut self, value: #arg_ty) -> Self {
+                    self.0.push(AccountFilter::Memcmp {
+                        offset: #offset,
+                        bytes: borsh::to_vec(value).unwrap(),
+                    });
+                    self
+                }
+            }
+        },
+    );
+    quote! {
+        #[doc = #builder_doc]
+        #[derive(Clone, Debug, Default, PartialEq, Eq)]
+        pub struct #filters_ident(Vec<AccountFilter>);
+
+        impl #filters_ident {
+            #discm_fn
+            #data_size_fn
+            #(#field_fns)*
+
+            /// The filters to pass to `getProgramAccounts`
+            pub fn build(self) -> Vec<AccountFilter> {
+                self.0
+            }
+        }
+
+        impl #account_ident {
+            /// Starts building `getProgramAccounts` filters for this account
+            pub fn filters() -> #filters_ident {
+                #filters_ident::default()
+            }
+        }
+    }
+}
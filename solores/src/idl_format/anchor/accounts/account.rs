@@ -1,32 +1,128 @@
-use heck::{ToPascalCase, ToShoutySnakeCase};
-use proc_macro2::TokenStream;
+use heck::ToShoutySnakeCase;
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
-use serde::Deserialize;
-use sha2::{Digest, Sha256};
 
-use crate::idl_format::anchor::typedefs::NamedType;
+use crate::filters::{account_filters_builder, FilterField};
+use crate::idl_format::anchor::typedefs::{DefinedFields, NamedType, TypedefStruct, TypedefType};
+use crate::layout::{len_ident, offset_ident, Layouts};
 use crate::utils::conditional_pascal_case;
 
-#[derive(Deserialize)]
-pub struct NamedAccount(pub NamedType);
+pub struct NamedAccount {
+    /// The account struct's typedef, also named after the account
+    pub typedef: NamedType,
+    pub discriminator: [u8; 8],
+}
 
 impl NamedAccount {
+    pub fn name(&self) -> &str {
+        &self.typedef.name
+    }
+
+    pub fn struct_ident(&self) -> Ident {
+        format_ident!("{}", conditional_pascal_case(self.name()))
+    }
+
+    pub fn discm_ident(&self) -> Ident {
+        format_ident!("{}_ACCOUNT_DISCM", self.name().to_shouty_snake_case())
+    }
+
+    pub fn account_ident(&self) -> Ident {
+        format_ident!("{}Account", conditional_pascal_case(self.name()))
+    }
+
+    /// `{NAME}_ACCOUNT_LEN` if the account data has a fixed size
+    fn account_len_ident(&self, cli_args: &crate::Args, layouts: &Layouts) -> Option<Ident> {
+        let name = self.name();
+        let is_zero_copy = cli_args.zero_copy.iter().any(|e| e == name);
+        (is_zero_copy || layouts.size_of(name).is_some())
+            .then(|| format_ident!("{}_ACCOUNT_LEN", name.to_shouty_snake_case()))
+    }
+
+    /// `{NAME}_LEN` and `{NAME}_*_OFFSET` consts of the account struct, and
+    /// `{NAME}_ACCOUNT_LEN`, the size of the account data including the discriminator
+    pub fn layout_consts(&self, cli_args: &crate::Args, layouts: &Layouts) -> TokenStream {
+        let name = self.name();
+        let is_zero_copy = cli_args.zero_copy.iter().any(|e| e == name);
+        let mut res = layouts.consts(name, &self.struct_ident(), is_zero_copy);
+        if let Some(account_len_ident) = self.account_len_ident(cli_args, layouts) {
+            let len_ident = len_ident(name);
+            res.extend(quote! {
+                /// Size of the account data, including the 8-byte discriminator
+                pub const #account_len_ident: usize = 8 + #len_ident;
+            });
+        }
+        res
+    }
+
+    /// `{Name}AccountFilters` builder over the discriminator, the data size if fixed
+    /// and the named fields with a `{NAME}_*_OFFSET` const
+    pub fn filters_builder(&self, cli_args: &crate::Args, layouts: &Layouts) -> TokenStream {
+        let name = self.name();
+        let fields: Vec<FilterField> = match &self.typedef.r#type {
+            TypedefType::r#struct(TypedefStruct {
+                fields: Some(DefinedFields::Struct(fields)),
+            }) => fields
+                .iter()
+                .zip(layouts.field_offsets(name))
+                .map(|(f, (field, _))| {
+                    let offset_ident = offset_ident(name, field);
+                    FilterField {
+                        name: f.name.clone(),
+                        offset: quote! { 8 + #offset_ident },
+                        arg_ty: f.r#type.filter_arg_ty(),
+                    }
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        account_filters_builder(
+            &self.account_ident(),
+            Some(&self.discm_ident()),
+            self.account_len_ident(cli_args, layouts).as_ref(),
+            &fields,
+        )
+    }
+
     pub fn to_token_stream(&self, cli_args: &crate::Args) -> TokenStream {
-        let name = &self.0.name;
+        let name = self.name();
         // discriminant
-        let account_discm_ident = format_ident!("{}_ACCOUNT_DISCM", name.to_shouty_snake_case());
-        // pre-image: "account:{AccountStructName}"
-        let discm = <[u8; 8]>::try_from(
-            &Sha256::digest(format!("account:{}", name.to_pascal_case()).as_bytes()).as_slice()
-                [..8],
-        )
-        .unwrap();
-        let discm_tokens: TokenStream = format!("{:?}", discm).parse().unwrap();
+        let account_discm_ident = self.discm_ident();
+        let discm_tokens: TokenStream = format!("{:?}", self.discriminator).parse().unwrap();
 
-        let struct_def = self.0.to_token_stream(cli_args);
+        let struct_def = self.typedef.to_token_stream(cli_args);
 
-        let struct_ident = format_ident!("{}", conditional_pascal_case(name));
-        let account_ident = format_ident!("{}Account", conditional_pascal_case(name));
+        let struct_ident = self.struct_ident();
+        let account_ident = self.account_ident();
+        let is_zero_copy = cli_args.zero_copy.iter().any(|e| e == name);
+        let zero_copy_fns = if is_zero_copy {
+            zero_copy_fns(&account_discm_ident, &struct_ident)
+        } else {
+            quote! {}
+        };
+        // zero-copy account data is the struct's in-memory layout,
+        // which differs from its borsh layout if it has padding
+        let (deserialize_body, serialize_body) = if is_zero_copy {
+            (
+                quote! {
+                    let data = reader.get(..std::mem::size_of::<#struct_ident>()).ok_or_else(|| {
+                        std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "account data too small")
+                    })?;
+                    Ok(Self(bytemuck::pod_read_unaligned(data)))
+                },
+                quote! {
+                    writer.write_all(bytemuck::bytes_of(&self.0))
+                },
+            )
+        } else {
+            (
+                quote! {
+                    Ok(Self(#struct_ident::deserialize(&mut reader)?))
+                },
+                quote! {
+                    self.0.serialize(&mut writer)
+                },
+            )
+        };
         quote! {
             pub const #account_discm_ident: [u8; 8] = #discm_tokens;
 
@@ -48,12 +144,29 @@ impl NamedAccount {
                             )
                         );
                     }
-                    Ok(Self(#struct_ident::deserialize(&mut reader)?))
+                    #deserialize_body
+                }
+
+                /// Deserializes the account after checking that it is owned by `program_id`
+                pub fn from_account_info_with_program_id(
+                    program_id: Pubkey,
+                    account_info: &AccountInfo,
+                ) -> Result<Self, ProgramError> {
+                    if *account_info.owner != program_id {
+                        return Err(ProgramError::IllegalOwner);
+                    }
+                    let data = account_info.try_borrow_data()?;
+                    Self::deserialize(&data).map_err(|_| ProgramError::InvalidAccountData)
+                }
+
+                /// Deserializes the account after checking that it is owned by this program
+                pub fn from_account_info(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+                    Self::from_account_info_with_program_id(crate::ID, account_info)
                 }
 
                 pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
                     writer.write_all(&#account_discm_ident)?;
-                    self.0.serialize(&mut writer)
+                    #serialize_body
                 }
 
                 pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -61,7 +174,89 @@ impl NamedAccount {
                     self.serialize(&mut data)?;
                     Ok(data)
                 }
+
+                #zero_copy_fns
+            }
+        }
+    }
+}
+
+/// In-place accessors for `-z` accounts that reinterpret
+/// the account data instead of deserializing it
+fn zero_copy_fns(account_discm_ident: &Ident, struct_ident: &Ident) -> TokenStream {
+    quote! {
+        /// Reinterprets the account data after the discriminator as the account struct without copying
+        pub fn load(buf: &[u8]) -> std::io::Result<&#struct_ident> {
+            let (maybe_discm, data) = buf.split_at(buf.len().min(8));
+            if maybe_discm != #account_discm_ident {
+                return Err(
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #account_discm_ident, maybe_discm)
+                    )
+                );
             }
+            let data = data.get(..std::mem::size_of::<#struct_ident>()).ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "account data too small")
+            })?;
+            bytemuck::try_from_bytes(data)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
+        }
+
+        /// Mutable version of [`Self::load`]
+        pub fn load_mut(buf: &mut [u8]) -> std::io::Result<&mut #struct_ident> {
+            let (maybe_discm, data) = buf.split_at_mut(buf.len().min(8));
+            if maybe_discm != #account_discm_ident {
+                return Err(
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #account_discm_ident, maybe_discm)
+                    )
+                );
+            }
+            let data = data.get_mut(..std::mem::size_of::<#struct_ident>()).ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "account data too small")
+            })?;
+            bytemuck::try_from_bytes_mut(data)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
+        }
+
+        /// Borrows the account data as the account struct after checking that it is owned by `program_id`
+        pub fn load_account_info_with_program_id<'a>(
+            program_id: Pubkey,
+            account_info: &'a AccountInfo,
+        ) -> Result<Ref<'a, #struct_ident>, ProgramError> {
+            if *account_info.owner != program_id {
+                return Err(ProgramError::IllegalOwner);
+            }
+            let data = account_info.try_borrow_data()?;
+            Ref::filter_map(data, |data| Self::load(data).ok())
+                .map_err(|_| ProgramError::InvalidAccountData)
+        }
+
+        /// Borrows the account data as the account struct after checking that it is owned by this program
+        pub fn load_account_info<'a>(
+            account_info: &'a AccountInfo,
+        ) -> Result<Ref<'a, #struct_ident>, ProgramError> {
+            Self::load_account_info_with_program_id(crate::ID, account_info)
+        }
+
+        /// Mutably borrows the account data as the account struct after checking that it is owned by `program_id`
+        pub fn load_account_info_mut_with_program_id<'a>(
+            program_id: Pubkey,
+            account_info: &'a AccountInfo,
+        ) -> Result<RefMut<'a, #struct_ident>, ProgramError> {
+            if *account_info.owner != program_id {
+                return Err(ProgramError::IllegalOwner);
+            }
+            let data = account_info.try_borrow_mut_data()?;
+            RefMut::filter_map(data, |data| Self::load_mut(data).ok())
+                .map_err(|_| ProgramError::InvalidAccountData)
+        }
+
+        /// Mutably borrows the account data as the account struct after checking that it is owned by this program
+        pub fn load_account_info_mut<'a>(
+            account_info: &'a AccountInfo,
+        ) -> Result<RefMut<'a, #struct_ident>, ProgramError> {
+            Self::load_account_info_mut_with_program_id(crate::ID, account_info)
         }
     }
 }
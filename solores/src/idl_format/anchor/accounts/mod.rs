@@ -1,14 +1,17 @@
+use heck::ToPascalCase;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{filters::account_filter_enum, idl_format::IdlCodegenModule, layout::Layouts};
 
 mod account;
 pub use account::*;
 
 pub struct AccountsCodegenModule<'a> {
     pub cli_args: &'a crate::Args,
+    pub program_name: &'a str,
     pub named_accounts: &'a [NamedAccount],
+    pub layouts: Layouts,
 }
 
 impl IdlCodegenModule for AccountsCodegenModule<'_> {
@@ -19,41 +22,97 @@ impl IdlCodegenModule for AccountsCodegenModule<'_> {
     fn gen_head(&self) -> TokenStream {
         let mut res = quote! {
             use borsh::{BorshDeserialize, BorshSerialize};
+            use solana_program::{
+                account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
+            };
         };
         for a in self.named_accounts {
-            if self.cli_args.zero_copy.iter().any(|e| e == &a.0.name) {
+            if self.cli_args.zero_copy.iter().any(|e| e == a.name()) {
                 res.extend(quote! {
                     use bytemuck::{Pod, Zeroable};
+                    use std::cell::{Ref, RefMut};
                 });
                 break;
             }
         }
-        let mut has_pubkey = false;
-        let mut has_defined = false;
-        for a in self.named_accounts {
-            if a.0.r#type.has_pubkey_field() && !has_pubkey {
-                has_pubkey = true;
-                res.extend(quote! {
-                    use solana_program::pubkey::Pubkey;
-                });
-            }
-            if a.0.r#type.has_defined_field() && !has_defined {
-                has_defined = true;
-                res.extend(quote! {
-                    use crate::*;
-                })
-            }
-            if has_defined && has_pubkey {
-                break;
-            }
+        let has_defined = self
+            .named_accounts
+            .iter()
+            .any(|a| a.typedef.r#type.has_defined_field());
+        if has_defined {
+            res.extend(quote! {
+                use crate::*;
+            })
         }
         res
     }
 
     fn gen_body(&self) -> TokenStream {
-        self.named_accounts
+        let mut res: TokenStream = self
+            .named_accounts
             .iter()
-            .map(|e| e.to_token_stream(self.cli_args))
-            .collect()
+            .map(|e| {
+                let mut res = e.to_token_stream(self.cli_args);
+                res.extend(e.layout_consts(self.cli_args, &self.layouts));
+                res.extend(e.filters_builder(self.cli_args, &self.layouts));
+                res
+            })
+            .collect();
+        res.extend(account_filter_enum());
+        res.extend(program_account_enum(
+            self.program_name,
+            self.named_accounts.iter(),
+        ));
+        res
+    }
+}
+
+/// `{Program}Account` enum over all accounts that dispatches on the discriminator,
+/// and the `discriminator_of()` helper
+fn program_account_enum<'a>(
+    program_name: &str,
+    named_accounts: impl Iterator<Item = &'a NamedAccount> + Clone,
+) -> TokenStream {
+    let program_account_enum_ident = format_ident!("{}Account", program_name.to_pascal_case());
+    let program_account_enum_variants = named_accounts.clone().map(|a| {
+        let struct_ident = a.struct_ident();
+        quote! { #struct_ident(#struct_ident) }
+    });
+    // dispatch to *Account::deserialize() so that -z accounts are decoded from their in-memory layout
+    let deserialize_variant_match_arms = named_accounts.map(|a| {
+        let struct_ident = a.struct_ident();
+        let account_ident = a.account_ident();
+        let discm_ident = a.discm_ident();
+        quote! {
+            #discm_ident => #account_ident::deserialize(data).map(|a| Self::#struct_ident(a.0))
+        }
+    });
+    quote! {
+        /// The 8-byte discriminator that account data starts with, None if the data is too short
+        pub fn discriminator_of(data: &[u8]) -> Option<[u8; 8]> {
+            data.get(..8).map(|discm| discm.try_into().unwrap())
+        }
+
+        #[allow(clippy::large_enum_variant)]
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum #program_account_enum_ident {
+            #(#program_account_enum_variants),*
+        }
+
+        impl #program_account_enum_ident {
+            /// Deserializes account data into the account type its discriminator identifies
+            pub fn try_from_bytes(data: &[u8]) -> std::io::Result<Self> {
+                let mut reader = data;
+                let maybe_discm = <[u8; 8]>::deserialize(&mut reader)?;
+                match maybe_discm {
+                    #(#deserialize_variant_match_arms,)*
+                    _ => Err(
+                        std::io::Error::new(
+                            std::io::ErrorKind::Other, format!("discm {:?} not found", maybe_discm)
+                        )
+                    ),
+                }
+            }
+        }
     }
 }
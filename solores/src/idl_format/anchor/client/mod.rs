@@ -0,0 +1,47 @@
+use proc_macro2::TokenStream;
+
+use crate::{
+    client::{client_head, fetch_fns, send_fn, CLIENT_FEATURE},
+    idl_format::IdlCodegenModule,
+};
+
+use super::{accounts::NamedAccount, instructions::NamedInstruction};
+
+pub struct ClientCodegenModule<'a> {
+    pub named_accounts: &'a [NamedAccount],
+    pub instructions: &'a [NamedInstruction],
+}
+
+impl IdlCodegenModule for ClientCodegenModule<'_> {
+    fn name(&self) -> &str {
+        "client"
+    }
+
+    fn feature(&self) -> Option<&str> {
+        Some(CLIENT_FEATURE)
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        client_head(
+            !self.named_accounts.is_empty(),
+            !self.instructions.is_empty(),
+        )
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        let mut res: TokenStream = self
+            .named_accounts
+            .iter()
+            .map(|a| fetch_fns(a.name(), &a.account_ident()))
+            .collect();
+        res.extend(self.instructions.iter().map(|ix| {
+            send_fn(
+                &ix.name,
+                &ix.ix_fn_ident(),
+                ix.has_accounts().then(|| ix.keys_ident()).as_ref(),
+                ix.has_ix_args().then(|| ix.ix_args_ident()).as_ref(),
+            )
+        }));
+        res
+    }
+}
@@ -0,0 +1,78 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use serde::Deserialize;
+
+use crate::{
+    idl_format::anchor::typedefs::TypedefFieldType,
+    utils::{doc_attrs, primitive_or_pubkey_to_token, string_or_struct, PUBKEY_TOKEN},
+};
+
+#[derive(Deserialize)]
+pub struct Constant {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    #[serde(deserialize_with = "string_or_struct")]
+    pub r#type: TypedefFieldType,
+    /// The constant's value expression as written in the program source,
+    /// or its `Debug` output in anchor 0.30+ IDLs
+    pub value: String,
+}
+
+impl Constant {
+    fn primitive(&self) -> Option<&str> {
+        match &self.r#type {
+            TypedefFieldType::PrimitiveOrPubkey(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn is_pubkey(&self) -> bool {
+        self.primitive()
+            .map_or(false, |s| primitive_or_pubkey_to_token(s) == PUBKEY_TOKEN)
+    }
+
+    /// Type of the const, `string` and `bytes` constants are `&'static`
+    pub fn ty_tokens(&self) -> TokenStream {
+        match self.primitive() {
+            Some("string") => quote! { &str },
+            Some("bytes") => quote! { &[u8] },
+            _ => self.r#type.to_token_stream(),
+        }
+    }
+
+    pub fn value_tokens(&self) -> TokenStream {
+        let value = self.value.trim();
+        if self.is_pubkey() && is_base58_pubkey(value) {
+            return quote! { solana_program::pubkey!(#value) };
+        }
+        let tokens: TokenStream = value.parse().unwrap_or_else(|_| {
+            log::error!("Could not parse value of constant {}: {}", self.name, value);
+            panic!();
+        });
+        match self.primitive() {
+            // byte arrays may be written as `[1, 2, 3]` instead of `b"abc"`
+            Some("bytes") if value.starts_with('[') => quote! { &#tokens },
+            _ => tokens,
+        }
+    }
+}
+
+fn is_base58_pubkey(s: &str) -> bool {
+    bs58::decode(s)
+        .into_vec()
+        .map_or(false, |bytes| bytes.len() == 32)
+}
+
+impl ToTokens for Constant {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let docs = doc_attrs(&self.docs);
+        let name = format_ident!("{}", self.name);
+        let ty = self.ty_tokens();
+        let value = self.value_tokens();
+        tokens.extend(quote! {
+            #docs
+            pub const #name: #ty = #value;
+        });
+    }
+}
@@ -0,0 +1,37 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::idl_format::IdlCodegenModule;
+
+mod constant;
+pub use constant::*;
+
+pub struct ConstantsCodegenModule<'a>(pub &'a [Constant]);
+
+impl IdlCodegenModule for ConstantsCodegenModule<'_> {
+    fn name(&self) -> &str {
+        "constants"
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        let mut res = quote! {};
+        if self.0.iter().any(|c| c.r#type.is_or_has_pubkey()) {
+            res.extend(quote! {
+                use solana_program::pubkey::Pubkey;
+            });
+        }
+        if self.0.iter().any(|c| c.r#type.is_or_has_defined()) {
+            res.extend(quote! {
+                use crate::*;
+            });
+        }
+        res
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        let constants = self.0;
+        quote! {
+            #(#constants)*
+        }
+    }
+}
@@ -4,19 +4,24 @@ use quote::{format_ident, quote, ToTokens};
 use serde::Deserialize;
 use syn::LitInt;
 
+use crate::utils::doc_attrs;
+
 #[derive(Deserialize)]
 pub struct ErrorEnumVariant {
     code: u32,
     name: String,
-    msg: String,
+    msg: Option<String>,
 }
 
 impl ToTokens for ErrorEnumVariant {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let variant_ident = format_ident!("{}", self.name.to_pascal_case());
-        let msg = &self.msg;
+        // msg is optional in the 0.30 spec, fallback to the error's name
+        let msg = self.msg.as_ref().unwrap_or(&self.name);
+        let docs = doc_attrs(self.msg.as_slice());
         let code_literal = LitInt::new(&self.code.to_string(), Span::call_site());
         tokens.extend(quote! {
+            #docs
             #[error(#msg)]
             #variant_ident = #code_literal,
         })
@@ -2,14 +2,23 @@ use heck::ToPascalCase;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{
+    anchor_framework_errors::{
+        anchor_framework_error_variants, any_error_enum, ANCHOR_FRAMEWORK_ERROR_ENUM_NAME,
+    },
+    error_decoding::{error_decoding_impls, parse_error_from_logs_fns},
+    idl_format::IdlCodegenModule,
+};
 
 mod error;
 pub use error::*;
 
 pub struct ErrorsCodegenModule<'a> {
     pub program_name: &'a str,
+    /// empty if the IDL has no errors
     pub variants: &'a [ErrorEnumVariant],
+    /// also generate `AnchorFrameworkError`, and `{Program}AnyError` if the IDL has errors
+    pub framework_errors: bool,
 }
 
 impl IdlCodegenModule for ErrorsCodegenModule<'_> {
@@ -21,52 +30,95 @@ impl IdlCodegenModule for ErrorsCodegenModule<'_> {
         quote! {
             use solana_program::{
                 decode_error::DecodeError,
+                instruction::InstructionError,
                 msg,
                 program_error::{PrintProgramError, ProgramError},
+                pubkey::Pubkey,
             };
             use thiserror::Error;
         }
     }
 
     fn gen_body(&self) -> TokenStream {
-        let error_enum_variants: TokenStream = self
-            .variants
-            .iter()
-            .map(|e| e.into_token_stream())
-            .collect();
-
-        let error_enum_ident_str = format!("{}Error", self.program_name.to_pascal_case());
-        let error_enum_ident = format_ident!("{}", &error_enum_ident_str);
-        quote! {
-            #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
-            pub enum #error_enum_ident {
-                #error_enum_variants
+        let mut res = quote! {};
+        let program_error_enum_ident = if self.variants.is_empty() {
+            None
+        } else {
+            let error_enum_variants: TokenStream = self
+                .variants
+                .iter()
+                .map(|e| e.into_token_stream())
+                .collect();
+            let error_enum_ident_str = format!("{}Error", self.program_name.to_pascal_case());
+            res.extend(error_enum(&error_enum_ident_str, error_enum_variants));
+            Some(format_ident!("{}", &error_enum_ident_str))
+        };
+        if !self.framework_errors {
+            res.extend(
+                program_error_enum_ident
+                    .as_ref()
+                    .map(parse_error_from_logs_fns),
+            );
+            return res;
+        }
+        res.extend(error_enum(
+            ANCHOR_FRAMEWORK_ERROR_ENUM_NAME,
+            anchor_framework_error_variants(),
+        ));
+        match program_error_enum_ident {
+            Some(program_error_enum_ident) => {
+                let any_error_enum_ident =
+                    format_ident!("{}AnyError", self.program_name.to_pascal_case());
+                res.extend(any_error_enum(
+                    &program_error_enum_ident,
+                    &any_error_enum_ident,
+                ));
+                res.extend(parse_error_from_logs_fns(&any_error_enum_ident));
             }
+            None => res.extend(parse_error_from_logs_fns(&format_ident!(
+                "{}",
+                ANCHOR_FRAMEWORK_ERROR_ENUM_NAME
+            ))),
+        }
+        res
+    }
+}
 
-            impl From<#error_enum_ident> for ProgramError {
-                fn from(e: #error_enum_ident) -> Self {
-                    ProgramError::Custom(e as u32)
-                }
+/// The error enum with its `ProgramError` conversions
+fn error_enum(error_enum_ident_str: &str, error_enum_variants: TokenStream) -> TokenStream {
+    let error_enum_ident = format_ident!("{}", error_enum_ident_str);
+    let error_decoding = error_decoding_impls(&error_enum_ident, true);
+    quote! {
+        #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+        pub enum #error_enum_ident {
+            #error_enum_variants
+        }
+
+        impl From<#error_enum_ident> for ProgramError {
+            fn from(e: #error_enum_ident) -> Self {
+                ProgramError::Custom(e as u32)
             }
+        }
 
-            impl<T> DecodeError<T> for #error_enum_ident {
-                fn type_of() -> &'static str {
-                    #error_enum_ident_str
-                }
+        impl<T> DecodeError<T> for #error_enum_ident {
+            fn type_of() -> &'static str {
+                #error_enum_ident_str
             }
+        }
 
-            impl PrintProgramError for #error_enum_ident {
-                fn print<E>(&self)
-                where
-                    E: 'static
-                        + std::error::Error
-                        + DecodeError<E>
-                        + PrintProgramError
-                        + num_traits::FromPrimitive,
-                {
-                    msg!(&self.to_string());
-                }
+        impl PrintProgramError for #error_enum_ident {
+            fn print<E>(&self)
+            where
+                E: 'static
+                    + std::error::Error
+                    + DecodeError<E>
+                    + PrintProgramError
+                    + num_traits::FromPrimitive,
+            {
+                msg!(&self.to_string());
             }
         }
+
+        #error_decoding
     }
 }
@@ -1,12 +1,20 @@
+use heck::ToPascalCase;
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{
+    event_parsing::{cpi_events_fns, parse_events_from_logs_fns, program_event_enum, EventVariant},
+    idl_format::IdlCodegenModule,
+};
 
 mod event;
 pub use event::*;
 
-pub struct EventsCodegenModule<'a>(pub &'a [Event]);
+pub struct EventsCodegenModule<'a> {
+    pub cli_args: &'a crate::Args,
+    pub program_name: &'a str,
+    pub events: &'a [Event],
+}
 
 impl IdlCodegenModule for EventsCodegenModule<'_> {
     fn name(&self) -> &str {
@@ -15,33 +23,42 @@ impl IdlCodegenModule for EventsCodegenModule<'_> {
 
     fn gen_head(&self) -> TokenStream {
         let mut res = quote! {
+            use base64::Engine;
             use borsh::{BorshDeserialize, BorshSerialize};
+            use solana_program::{instruction::Instruction, pubkey::Pubkey};
         };
-        let mut has_pubkey = false;
-        let mut has_defined = false;
-        for a in self.0 {
-            for field in &a.0.fields {
-                if field.r#type.is_or_has_pubkey() && !has_pubkey {
-                    has_pubkey = true;
-                    res.extend(quote! {
-                        use solana_program::pubkey::Pubkey;
-                    });
-                }
-                if field.r#type.is_or_has_defined() && !has_defined {
-                    has_defined = true;
-                    res.extend(quote! {
-                        use crate::*;
-                    })
-                }
-            }
-            if has_defined && has_pubkey {
-                break;
-            }
+        let has_defined = self
+            .events
+            .iter()
+            .any(|e| e.typedef.r#type.has_defined_field());
+        if has_defined {
+            res.extend(quote! {
+                use crate::*;
+            })
         }
         res
     }
 
     fn gen_body(&self) -> TokenStream {
-        self.0.iter().map(|e| e.into_token_stream()).collect()
+        let mut res: TokenStream = self
+            .events
+            .iter()
+            .map(|e| e.to_token_stream(self.cli_args))
+            .collect();
+
+        // program event enum
+        let program_event_enum_ident = format_ident!("{}Event", self.program_name.to_pascal_case());
+        let variants: Vec<EventVariant> = self
+            .events
+            .iter()
+            .map(|e| EventVariant {
+                struct_ident: e.struct_ident(),
+                discm_ident: e.discm_ident(),
+            })
+            .collect();
+        res.extend(program_event_enum(&program_event_enum_ident, &variants));
+        res.extend(parse_events_from_logs_fns(&program_event_enum_ident));
+        res.extend(cpi_events_fns(&program_event_enum_ident));
+        res
     }
 }
@@ -1,22 +1,29 @@
 // TODO: determine borsh version for more efficient implementations of deserialize_reader
 // that makes use of ix_args' deserialize_reader method if available
 
+use std::collections::HashSet;
+
 use heck::{ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
 use itertools::Itertools;
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::{format_ident, quote, ToTokens};
-use serde::Deserialize;
-use sha2::{Digest, Sha256};
+use quote::{format_ident, quote};
 use syn::{LitBool, LitInt};
 
 use crate::{
-    idl_format::anchor::typedefs::TypedefField,
-    utils::{unique_by_report_dups, UniqueByReportDupsResult},
+    idl_format::anchor::{
+        pdas::{IxPda, KeysDerivation, ResolvedPda},
+        typedefs::TypedefField,
+    },
+    ix_builder::{ix_builder, IxBuilderField},
+    json_schema::json_schema_derive,
+    remaining_accounts::try_from_slice_impls,
+    utils::{doc_attrs, unique_by_report_dups, UniqueByReportDupsResult},
 };
 
-#[derive(Deserialize)]
 pub struct NamedInstruction {
     pub name: String,
+    pub docs: Vec<String>,
+    pub discriminator: [u8; 8],
     pub accounts: Option<Vec<IxAccountEntry>>,
     pub args: Option<Vec<TypedefField>>,
 }
@@ -100,6 +107,12 @@ impl NamedInstruction {
             .any(|b| b)
     }
 
+    pub fn has_optional_accounts(&self) -> bool {
+        self.accounts
+            .as_ref()
+            .map_or(false, |v| to_ix_accounts(v).iter().any(|a| a.is_optional))
+    }
+
     /// export accounts_len as const
     pub fn write_accounts_len(&self, tokens: &mut TokenStream, accounts_len: usize) {
         if !self.has_accounts() {
@@ -119,8 +132,15 @@ impl NamedInstruction {
         let accounts_ident = self.accounts_ident();
         let accounts_fields = accounts.iter().map(|acc| {
             let account_name = format_ident!("{}", &acc.name.to_snake_case());
+            let ty = if acc.is_optional {
+                quote! { Option<&'me AccountInfo<'info>> }
+            } else {
+                quote! { &'me AccountInfo<'info> }
+            };
+            let docs = doc_attrs(&acc.docs);
             quote! {
-                pub #account_name: &'me AccountInfo<'info>
+                #docs
+                pub #account_name: #ty
             }
         });
         tokens.extend(quote! {
@@ -138,8 +158,15 @@ impl NamedInstruction {
         let keys_ident = self.keys_ident();
         let keys_fields = accounts.iter().map(|acc| {
             let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+            let ty = if acc.is_optional {
+                quote! { Option<Pubkey> }
+            } else {
+                quote! { Pubkey }
+            };
+            let docs = doc_attrs(&acc.docs);
             quote! {
-                pub #account_ident: Pubkey
+                #docs
+                pub #account_ident: #ty
             }
         });
         tokens.extend(quote! {
@@ -150,6 +177,156 @@ impl NamedInstruction {
         });
     }
 
+    /// `XKeys::new()` that only takes the accounts that cannot be derived,
+    /// or `impl Default for XKeys` if all of them can be.
+    ///
+    /// Accounts with a fixed `address` are derived, as are PDAs whose seeds
+    /// only depend on the instruction args and other derivable or passed-in accounts.
+    pub fn write_keys_new_fn(
+        &self,
+        tokens: &mut TokenStream,
+        accounts: &[IxAccount],
+        ix_pdas: &[IxPda],
+    ) {
+        if !self.has_accounts() {
+            return;
+        }
+        let (fixed, derived) = self.derivable_keys(accounts, ix_pdas);
+        if fixed.is_empty() && derived.is_empty() {
+            return;
+        }
+
+        let mut fn_params: Vec<TokenStream> = accounts
+            .iter()
+            .filter(|acc| {
+                let name = acc.name.to_snake_case();
+                !fixed.contains(&name) && !derived.iter().any(|(d, _)| *d == name)
+            })
+            .map(|acc| {
+                let ident = acc.field_ident();
+                if acc.is_optional {
+                    quote! { #ident: Option<Pubkey> }
+                } else {
+                    quote! { #ident: Pubkey }
+                }
+            })
+            .collect();
+        if derived.iter().any(|(_, d)| d.uses_args) {
+            let ix_args_ident = self.ix_args_ident();
+            fn_params.push(quote! { args: &#ix_args_ident });
+        }
+        let fixed_lets = accounts.iter().filter_map(|acc| {
+            if !fixed.contains(&acc.name.to_snake_case()) {
+                return None;
+            }
+            let address = acc.fixed_address()?;
+            let ident = acc.field_ident();
+            Some(quote! {
+                let #ident = solana_program::pubkey!(#address);
+            })
+        });
+        let derived_lets = derived.iter().map(|(name, d)| {
+            let ident = format_ident!("{}", name);
+            let find_expr = &d.find_expr;
+            quote! {
+                let #ident = #find_expr;
+            }
+        });
+        let field_idents = accounts.iter().map(|acc| acc.field_ident());
+        let body = quote! {
+            #(#fixed_lets)*
+            #(#derived_lets)*
+            Self {
+                #(#field_idents),*
+            }
+        };
+        let keys_ident = self.keys_ident();
+        if fn_params.is_empty() {
+            tokens.extend(quote! {
+                impl Default for #keys_ident {
+                    /// All accounts have a fixed address or are PDAs that can be derived.
+                    fn default() -> Self {
+                        #body
+                    }
+                }
+            });
+            return;
+        }
+        let too_many_args_allow = if fn_params.len() > 7 {
+            quote! { #[allow(clippy::too_many_arguments)] }
+        } else {
+            quote! {}
+        };
+        tokens.extend(quote! {
+            impl #keys_ident {
+                /// Creates the keys from the accounts that cannot be derived.
+                /// Accounts with a fixed address and PDAs are derived.
+                #too_many_args_allow
+                pub fn new(#(#fn_params),*) -> Self {
+                    #body
+                }
+            }
+        });
+    }
+
+    /// Returns true if `XKeys::new()` derives any PDA with the fns in `pdas`
+    pub fn keys_derive_pdas(&self, ix_pdas: &[IxPda]) -> bool {
+        let accounts = self
+            .accounts
+            .as_ref()
+            .map_or(Vec::new(), |v| to_ix_accounts(v));
+        !self.derivable_keys(&accounts, ix_pdas).1.is_empty()
+    }
+
+    /// Returns (names of accounts with a fixed address, PDA accounts in derivation order)
+    fn derivable_keys(
+        &self,
+        accounts: &[IxAccount],
+        ix_pdas: &[IxPda],
+    ) -> (HashSet<String>, Vec<(String, KeysDerivation)>) {
+        let mut fixed: HashSet<String> = HashSet::new();
+        let mut candidates: Vec<(String, KeysDerivation)> = Vec::new();
+        // optional accounts are always passed in
+        for acc in accounts.iter().filter(|acc| !acc.is_optional) {
+            let name = acc.name.to_snake_case();
+            if acc.fixed_address().is_some() {
+                fixed.insert(name);
+                continue;
+            }
+            let derivation = ix_pdas
+                .iter()
+                .find(|p| p.ix_name == self.name && p.account_name == name)
+                .and_then(|p| p.pda.keys_derivation(&p.fns_name))
+                .filter(|d| !d.uses_args || self.has_ix_args());
+            if let Some(d) = derivation {
+                candidates.push((name, d));
+            }
+        }
+        // order PDAs so that the accounts they depend on are derived first.
+        // PDAs that can't be ordered e.g. due to cyclic dependencies are passed in instead
+        let mut resolved: HashSet<String> = accounts
+            .iter()
+            .filter(|acc| !acc.is_optional)
+            .map(|acc| acc.name.to_snake_case())
+            .filter(|name| !candidates.iter().any(|(c, _)| c == name))
+            .collect();
+        let mut derived: Vec<(String, KeysDerivation)> = Vec::new();
+        loop {
+            let (ready, rest): (Vec<_>, Vec<_>) = candidates
+                .into_iter()
+                .partition(|(_, d)| d.account_deps.iter().all(|dep| resolved.contains(dep)));
+            candidates = rest;
+            if ready.is_empty() {
+                break;
+            }
+            for (name, d) in ready {
+                resolved.insert(name.clone());
+                derived.push((name, d));
+            }
+        }
+        (fixed, derived)
+    }
+
     /// From<XAccounts> for XKeys
     pub fn write_from_accounts_for_keys(&self, tokens: &mut TokenStream, accounts: &[IxAccount]) {
         if !self.has_accounts() {
@@ -159,8 +336,14 @@ impl NamedInstruction {
         let keys_ident = self.keys_ident();
         let from_keys_fields = accounts.iter().map(|acc| {
             let account_ident = format_ident!("{}", &acc.name.to_snake_case());
-            quote! {
-                #account_ident: *accounts.#account_ident.key
+            if acc.is_optional {
+                quote! {
+                    #account_ident: accounts.#account_ident.map(|a| *a.key)
+                }
+            } else {
+                quote! {
+                    #account_ident: *accounts.#account_ident.key
+                }
             }
         });
         tokens.extend(quote! {
@@ -175,13 +358,42 @@ impl NamedInstruction {
     }
 
     /// From <&XKeys> for [AccountMeta]
+    ///
+    /// and XKeys::to_metas() if there are optional accounts,
+    /// since absent accounts are replaced with the invoked program's ID
     pub fn write_from_keys_for_meta_arr(&self, tokens: &mut TokenStream, accounts: &[IxAccount]) {
         if !self.has_accounts() {
             return;
         }
         let keys_ident = self.keys_ident();
         let accounts_len_ident = self.accounts_len_ident();
-        let from_keys_meta = accounts.iter().map(|acc| acc.to_keys_account_meta_tokens());
+        if self.has_optional_accounts() {
+            let to_metas = accounts
+                .iter()
+                .map(|acc| acc.to_keys_account_meta_tokens(&quote! { self }));
+            tokens.extend(quote! {
+                impl #keys_ident {
+                    /// Absent optional accounts are replaced with `program_id`,
+                    /// the ID of the program being invoked
+                    pub fn to_metas(self, program_id: Pubkey) -> [AccountMeta; #accounts_len_ident] {
+                        [
+                            #(#to_metas),*
+                        ]
+                    }
+                }
+
+                impl From<#keys_ident> for [AccountMeta; #accounts_len_ident] {
+                    /// Absent optional accounts are replaced with `crate::ID`
+                    fn from(keys: #keys_ident) -> Self {
+                        keys.to_metas(crate::ID)
+                    }
+                }
+            });
+            return;
+        }
+        let from_keys_meta = accounts
+            .iter()
+            .map(|acc| acc.to_keys_account_meta_tokens(&quote! { keys }));
         tokens.extend(quote! {
             impl From<#keys_ident> for [AccountMeta; #accounts_len_ident] {
                 fn from(keys: #keys_ident) -> Self {
@@ -194,6 +406,9 @@ impl NamedInstruction {
     }
 
     /// From <[Pubkey]> for XKeys
+    ///
+    /// and From <(Pubkey, [Pubkey])> if there are optional accounts,
+    /// which takes the ID of the invoked program absent accounts are set to
     pub fn write_from_pubkey_arr_for_keys(&self, tokens: &mut TokenStream, accounts: &[IxAccount]) {
         if !self.has_accounts() {
             return;
@@ -203,10 +418,37 @@ impl NamedInstruction {
         let from_pubkey_arr_fields = accounts.iter().enumerate().map(|(i, acc)| {
             let account_ident = format_ident!("{}", &acc.name.to_snake_case());
             let index_lit = LitInt::new(&i.to_string(), Span::call_site());
-            quote! {
-                #account_ident: pubkeys[#index_lit]
+            if acc.is_optional {
+                quote! {
+                    #account_ident: Some(pubkeys[#index_lit]).filter(|k| *k != program_id)
+                }
+            } else {
+                quote! {
+                    #account_ident: pubkeys[#index_lit]
+                }
             }
         });
+        if self.has_optional_accounts() {
+            tokens.extend(quote! {
+                impl From<(Pubkey, [Pubkey; #accounts_len_ident])> for #keys_ident {
+                    /// Keys equal to `program_id`, the ID of the invoked program,
+                    /// are absent optional accounts
+                    fn from((program_id, pubkeys): (Pubkey, [Pubkey; #accounts_len_ident])) -> Self {
+                        Self {
+                            #(#from_pubkey_arr_fields),*
+                        }
+                    }
+                }
+
+                impl From<[Pubkey; #accounts_len_ident]> for #keys_ident {
+                    /// Keys equal to `crate::ID` are absent optional accounts
+                    fn from(pubkeys: [Pubkey; #accounts_len_ident]) -> Self {
+                        Self::from((crate::ID, pubkeys))
+                    }
+                }
+            });
+            return;
+        }
         tokens.extend(quote! {
             impl From<[Pubkey; #accounts_len_ident]> for #keys_ident {
                 fn from(pubkeys: [Pubkey; #accounts_len_ident]) -> Self {
@@ -219,6 +461,9 @@ impl NamedInstruction {
     }
 
     /// From <XAccounts> for [AccountInfo]
+    ///
+    /// or XAccounts::to_account_infos() if there are optional accounts,
+    /// since absent accounts are replaced with the program's AccountInfo
     pub fn write_from_accounts_for_account_info_arr(
         &self,
         tokens: &mut TokenStream,
@@ -229,6 +474,28 @@ impl NamedInstruction {
         }
         let accounts_ident = self.accounts_ident();
         let accounts_len_ident = self.accounts_len_ident();
+        if self.has_optional_accounts() {
+            let account_info_clone = accounts.iter().map(|acc| {
+                let account_ident = acc.field_ident();
+                if acc.is_optional {
+                    quote! { self.#account_ident.unwrap_or(program).clone() }
+                } else {
+                    quote! { self.#account_ident.clone() }
+                }
+            });
+            tokens.extend(quote! {
+                impl<'info> #accounts_ident<'_, 'info> {
+                    /// Absent optional accounts are replaced with `program`,
+                    /// the AccountInfo of the program being invoked
+                    pub fn to_account_infos(self, program: &AccountInfo<'info>) -> [AccountInfo<'info>; #accounts_len_ident] {
+                        [
+                            #(#account_info_clone),*
+                        ]
+                    }
+                }
+            });
+            return;
+        }
         let account_info_clone = accounts.iter().map(|acc| {
             let account_ident = format_ident!("{}", &acc.name.to_snake_case());
             quote! {
@@ -247,6 +514,9 @@ impl NamedInstruction {
     }
 
     /// From <&[AccountInfo]> for XAccounts
+    ///
+    /// and From <(Pubkey, &[AccountInfo])> if there are optional accounts,
+    /// which takes the ID of the invoked program absent accounts are set to
     pub fn write_from_account_info_arr_for_accounts(
         &self,
         tokens: &mut TokenStream,
@@ -260,10 +530,37 @@ impl NamedInstruction {
         let from_account_info_fields = accounts.iter().enumerate().map(|(i, acc)| {
             let account_ident = format_ident!("{}", &acc.name.to_snake_case());
             let index_lit = LitInt::new(&i.to_string(), Span::call_site());
-            quote! {
-               #account_ident: &arr[#index_lit]
+            if acc.is_optional {
+                quote! {
+                    #account_ident: Some(&arr[#index_lit]).filter(|a| *a.key != program_id)
+                }
+            } else {
+                quote! {
+                   #account_ident: &arr[#index_lit]
+                }
             }
         });
+        if self.has_optional_accounts() {
+            tokens.extend(quote! {
+                impl<'me, 'info> From<(Pubkey, &'me [AccountInfo<'info>; #accounts_len_ident])> for #accounts_ident<'me, 'info> {
+                    /// Accounts with key `program_id`, the ID of the invoked program,
+                    /// are absent optional accounts
+                    fn from((program_id, arr): (Pubkey, &'me [AccountInfo<'info>; #accounts_len_ident])) -> Self {
+                        Self {
+                            #(#from_account_info_fields),*
+                        }
+                    }
+                }
+
+                impl<'me, 'info> From<&'me [AccountInfo<'info>; #accounts_len_ident]> for #accounts_ident<'me, 'info> {
+                    /// Accounts with key `crate::ID` are absent optional accounts
+                    fn from(arr: &'me [AccountInfo<'info>; #accounts_len_ident]) -> Self {
+                        Self::from((crate::ID, arr))
+                    }
+                }
+            });
+            return;
+        }
         tokens.extend(quote! {
             impl<'me, 'info> From<&'me [AccountInfo<'info>; #accounts_len_ident]> for #accounts_ident<'me, 'info> {
                 fn from(arr: &'me [AccountInfo<'info>; #accounts_len_ident]) -> Self {
@@ -275,17 +572,71 @@ impl NamedInstruction {
         });
     }
 
-    pub fn write_ix_args_struct(&self, tokens: &mut TokenStream) {
+    /// TryFrom <&[Pubkey]> for XKeys and TryFrom <&[AccountInfo]> for XAccounts
+    pub fn write_try_from_slices(&self, tokens: &mut TokenStream) {
+        if !self.has_accounts() {
+            return;
+        }
+        tokens.extend(try_from_slice_impls(
+            &self.keys_ident(),
+            &self.accounts_ident(),
+            &self.accounts_len_ident(),
+        ));
+    }
+
+    /// XIxBuilder, which derives the same PDAs as `XKeys::new()`
+    pub fn write_ix_builder(
+        &self,
+        tokens: &mut TokenStream,
+        accounts: &[IxAccount],
+        ix_pdas: &[IxPda],
+    ) {
+        let keys_ident = self.keys_ident();
+        let ix_args_ident = self.ix_args_ident();
+        let key_fields: Vec<IxBuilderField> = accounts
+            .iter()
+            .map(|acc| IxBuilderField::account(&acc.name, acc.is_optional, acc.fixed_address()))
+            .collect();
+        let derived_keys: Vec<(Ident, TokenStream)> = self
+            .derivable_keys(accounts, ix_pdas)
+            .1
+            .into_iter()
+            .map(|(name, d)| (format_ident!("{}", name), d.find_expr))
+            .collect();
+        let arg_fields: Vec<IxBuilderField> = self
+            .args
+            .iter()
+            .flatten()
+            .map(|arg| {
+                let ty = &arg.r#type;
+                IxBuilderField::arg(&arg.name, quote! { #ty })
+            })
+            .collect();
+        tokens.extend(ix_builder(
+            &self.name,
+            &self.ix_fn_ident(),
+            true,
+            self.has_accounts()
+                .then_some((&keys_ident, key_fields.as_slice())),
+            self.has_ix_args()
+                .then_some((&ix_args_ident, arg_fields.as_slice())),
+            &derived_keys,
+        ));
+    }
+
+    pub fn write_ix_args_struct(&self, tokens: &mut TokenStream, cli_args: &crate::Args) {
         let args = if !self.has_ix_args() {
             return;
         } else {
             self.args.as_ref().unwrap()
         };
         let ix_args_ident = self.ix_args_ident();
-        let args_fields = args.iter().map(|a| quote! { pub #a });
+        let args_fields = args.iter().map(|a| a.to_serde_pub_field_tokens(cli_args));
+        let json_schema_derive = json_schema_derive(cli_args);
         tokens.extend(quote! {
             #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
             #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            #json_schema_derive
             pub struct #ix_args_ident {
                 #(#args_fields),*
             }
@@ -294,14 +645,7 @@ impl NamedInstruction {
 
     pub fn write_discm(&self, tokens: &mut TokenStream) {
         let discm_ident = self.discm_ident();
-        // pre-image: "global:{instruction_fn_name}"
-        // TODO: handle non-global instructions (state methods - idk if thats deprecated)
-        let discm = <[u8; 8]>::try_from(
-            &Sha256::digest(format!("global:{}", self.name.to_snake_case()).as_bytes()).as_slice()
-                [..8],
-        )
-        .unwrap();
-        let discm_value_tokens: TokenStream = format!("{:?}", discm).parse().unwrap();
+        let discm_value_tokens: TokenStream = format!("{:?}", self.discriminator).parse().unwrap();
         tokens.extend(quote! {
             pub const #discm_ident: [u8; 8] = #discm_value_tokens;
 
@@ -413,7 +757,16 @@ impl NamedInstruction {
             fn_args.extend(quote! { args, });
         }
 
-        let (mut fn_body, accounts_expr) = if self.has_accounts() {
+        let (mut fn_body, accounts_expr) = if self.has_optional_accounts() {
+            (
+                quote! {
+                    let metas = keys.to_metas(program_id);
+                },
+                quote! {
+                    Vec::from(metas)
+                },
+            )
+        } else if self.has_accounts() {
             (
                 quote! {
                     let metas: [AccountMeta; #accounts_len_ident] = keys.into();
@@ -441,7 +794,9 @@ impl NamedInstruction {
             quote! { #ix_data_ident.try_to_vec()? }
         };
 
+        let docs = doc_attrs(&self.docs);
         tokens.extend(quote! {
+            #docs
             pub fn #ix_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> std::io::Result<Instruction> {
                 #fn_body
                 Ok(Instruction {
@@ -451,17 +806,33 @@ impl NamedInstruction {
                 })
             }
 
+            #docs
             pub fn #ix_fn_ident(#fn_params) -> std::io::Result<Instruction> {
                 #ix_with_program_id_fn_ident(crate::ID, #fn_args)
             }
         });
     }
 
+    /// Lifetime params of the invoke fns, required to take
+    /// the program's AccountInfo if there are optional accounts
+    fn invoke_fn_generics(&self) -> TokenStream {
+        if self.has_optional_accounts() {
+            quote! { <'info> }
+        } else {
+            quote! {}
+        }
+    }
+
     fn invoke_fn_params_prefix(&self) -> TokenStream {
         let accounts_ident = self.accounts_ident();
         let ix_args_ident = self.ix_args_ident();
         let mut fn_params = quote! {};
-        if self.has_accounts() {
+        if self.has_optional_accounts() {
+            fn_params.extend(quote! {
+                accounts: #accounts_ident<'_, 'info>,
+                program: &AccountInfo<'info>,
+            });
+        } else if self.has_accounts() {
             fn_params.extend(quote! { accounts: #accounts_ident<'_, '_>, });
         }
         if self.has_ix_args() {
@@ -472,7 +843,9 @@ impl NamedInstruction {
 
     fn invoke_fn_args_prefix(&self) -> TokenStream {
         let mut fn_args = quote! {};
-        if self.has_accounts() {
+        if self.has_optional_accounts() {
+            fn_args.extend(quote! { accounts, program, });
+        } else if self.has_accounts() {
             fn_args.extend(quote! { accounts, });
         }
         if self.has_ix_args() {
@@ -509,8 +882,13 @@ impl NamedInstruction {
             format_ident!("{}_invoke_with_program_id", self.name.to_snake_case());
         let fn_params = self.invoke_fn_params_prefix();
         let fn_args = self.invoke_fn_args_prefix();
+        let generics = self.invoke_fn_generics();
         let call_assign = self.ix_call_assign();
-        let invoke = if self.has_accounts() {
+        let invoke = if self.has_optional_accounts() {
+            quote! {
+                invoke(&ix, &accounts.to_account_infos(program))
+            }
+        } else if self.has_accounts() {
             quote! {
                 invoke_instruction(&ix, accounts)
             }
@@ -520,12 +898,12 @@ impl NamedInstruction {
             }
         };
         tokens.extend(quote! {
-            pub fn #invoke_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> ProgramResult {
+            pub fn #invoke_with_program_id_fn_ident #generics(program_id: Pubkey, #fn_params) -> ProgramResult {
                 #call_assign
                 #invoke
             }
 
-            pub fn #invoke_fn_ident(#fn_params) -> ProgramResult {
+            pub fn #invoke_fn_ident #generics(#fn_params) -> ProgramResult {
                 #invoke_with_program_id_fn_ident(crate::ID, #fn_args)
             }
         });
@@ -543,8 +921,13 @@ impl NamedInstruction {
         fn_params.extend(quote! { seeds: &[&[&[u8]]], });
         let mut fn_args = self.invoke_fn_args_prefix();
         fn_args.extend(quote! { seeds, });
+        let generics = self.invoke_fn_generics();
         let call_assign = self.ix_call_assign();
-        let invoke = if self.has_accounts() {
+        let invoke = if self.has_optional_accounts() {
+            quote! {
+                invoke_signed(&ix, &accounts.to_account_infos(program), seeds)
+            }
+        } else if self.has_accounts() {
             quote! {
                 invoke_instruction_signed(&ix, accounts, seeds)
             }
@@ -554,12 +937,12 @@ impl NamedInstruction {
             }
         };
         tokens.extend(quote! {
-            pub fn #invoke_signed_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> ProgramResult {
+            pub fn #invoke_signed_with_program_id_fn_ident #generics(program_id: Pubkey, #fn_params) -> ProgramResult {
                 #call_assign
                 #invoke
             }
 
-            pub fn #invoke_signed_fn_ident(#fn_params) -> ProgramResult {
+            pub fn #invoke_signed_fn_ident #generics(#fn_params) -> ProgramResult {
                 #invoke_signed_with_program_id_fn_ident(crate::ID, #fn_args)
             }
         });
@@ -578,6 +961,19 @@ impl NamedInstruction {
         // edge-case of accounts and keys being empty
         let pubkeys_loop_check = if accounts.is_empty() {
             quote! {}
+        } else if self.has_optional_accounts() {
+            let key_tups = accounts
+                .iter()
+                .map(IxAccount::to_verify_account_keys_option);
+            quote! {
+                for (actual, expected) in [
+                    #(#key_tups),*
+                ] {
+                    if actual != expected {
+                        return Err((actual.unwrap_or(crate::ID), expected.unwrap_or(crate::ID)));
+                    }
+                }
+            }
         } else {
             quote! {
                 for (actual, expected) in [
@@ -589,7 +985,14 @@ impl NamedInstruction {
                 }
             }
         };
+        let doc = self.has_optional_accounts().then(|| {
+            quote! {
+                /// Optional accounts must be present in both `accounts` and `keys` or absent from both.
+                /// An absent account or key is returned as `crate::ID` in the mismatch
+            }
+        });
         tokens.extend(quote! {
+            #doc
             pub fn #verify_account_keys_fn_ident(
                 accounts: #accounts_ident<'_, '_>,
                 keys: #keys_ident
@@ -620,19 +1023,24 @@ impl NamedInstruction {
         let accounts_ident = self.accounts_ident();
 
         let mut verify_fn_body = quote! {};
+        // absent optional accounts are skipped by iterating over Options
+        let has_optional_writables = accounts.iter().any(|a| a.is_mut && a.is_optional);
+        let has_optional_signers = accounts.iter().any(|a| a.is_signer && a.is_optional);
+        let writables_iter = if has_optional_writables {
+            quote! { .into_iter().flatten() }
+        } else {
+            quote! {}
+        };
+        let signers_iter = if has_optional_signers {
+            quote! { .into_iter().flatten() }
+        } else {
+            quote! {}
+        };
 
         let mut writables = accounts
             .iter()
-            .filter_map(|a| {
-                if a.is_mut {
-                    let name = a.field_ident();
-                    Some(quote! {
-                        accounts.#name
-                    })
-                } else {
-                    None
-                }
-            })
+            .filter(|a| a.is_mut)
+            .map(|a| a.to_verify_privileges_elem(has_optional_writables))
             .peekable();
         let has_writables = writables.peek().is_some();
         if has_writables {
@@ -642,7 +1050,7 @@ impl NamedInstruction {
                 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
                     for should_be_writable in [
                         #(#writables),*
-                    ] {
+                    ]#writables_iter {
                         if !should_be_writable.is_writable {
                             return Err((should_be_writable, ProgramError::InvalidAccountData));
                         }
@@ -657,16 +1065,8 @@ impl NamedInstruction {
 
         let mut signers = accounts
             .iter()
-            .filter_map(|a| {
-                if a.is_signer {
-                    let name = a.field_ident();
-                    Some(quote! {
-                        accounts.#name
-                    })
-                } else {
-                    None
-                }
-            })
+            .filter(|a| a.is_signer)
+            .map(|a| a.to_verify_privileges_elem(has_optional_signers))
             .peekable();
         let has_signers = signers.peek().is_some();
         if has_signers {
@@ -676,7 +1076,7 @@ impl NamedInstruction {
                 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
                     for should_be_signer in [
                         #(#signers),*
-                    ] {
+                    ]#signers_iter {
                         if !should_be_signer.is_signer {
                             return Err((should_be_signer, ProgramError::MissingRequiredSignature));
                         }
@@ -700,8 +1100,14 @@ impl NamedInstruction {
     }
 }
 
-impl ToTokens for NamedInstruction {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
+impl NamedInstruction {
+    /// `ix_pdas` are the resolved PDA accounts of all instructions
+    pub fn write_tokens(
+        &self,
+        tokens: &mut TokenStream,
+        cli_args: &crate::Args,
+        ix_pdas: &[IxPda],
+    ) {
         let accounts = self
             .accounts
             .as_ref()
@@ -723,19 +1129,22 @@ impl ToTokens for NamedInstruction {
         self.write_accounts_len(tokens, n_accounts);
         self.write_accounts_struct(tokens, &accounts);
         self.write_keys_struct(tokens, &accounts);
+        self.write_keys_new_fn(tokens, &accounts, ix_pdas);
         self.write_from_accounts_for_keys(tokens, &accounts);
         self.write_from_keys_for_meta_arr(tokens, &accounts);
         self.write_from_pubkey_arr_for_keys(tokens, &accounts);
         self.write_from_accounts_for_account_info_arr(tokens, &accounts);
         self.write_from_account_info_arr_for_accounts(tokens, &accounts);
+        self.write_try_from_slices(tokens);
 
         self.write_discm(tokens);
-        self.write_ix_args_struct(tokens);
+        self.write_ix_args_struct(tokens, cli_args);
         self.write_ix_data_struct(tokens);
         self.write_from_ix_args_for_ix_data(tokens);
         self.write_ix_data_impl(tokens);
 
         self.write_ix_fn(tokens);
+        self.write_ix_builder(tokens, &accounts, ix_pdas);
         self.write_invoke_fn(tokens);
         self.write_invoke_signed_fn(tokens);
 
@@ -744,7 +1153,6 @@ impl ToTokens for NamedInstruction {
     }
 }
 
-#[derive(Deserialize)]
 pub struct InnerAccountStruct {
     pub name: String,
     pub accounts: Vec<IxAccountEntry>,
@@ -759,8 +1167,6 @@ impl InnerAccountStruct {
     }
 }
 
-#[derive(Deserialize)]
-#[serde(untagged)]
 pub enum IxAccountEntry {
     Account(IxAccount),
     Struct(Box<InnerAccountStruct>),
@@ -775,15 +1181,27 @@ impl IxAccountEntry {
     }
 }
 
-#[derive(Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone)]
 pub struct IxAccount {
     pub name: String,
+    pub docs: Vec<String>,
     pub is_mut: bool,
     pub is_signer: bool,
+    pub is_optional: bool,
+    pub address: Option<String>,
+    pub pda: Option<ResolvedPda>,
 }
 
 impl IxAccount {
+    /// `address` if it is a valid pubkey
+    pub fn fixed_address(&self) -> Option<&str> {
+        let address = self.address.as_deref()?;
+        let is_pubkey = bs58::decode(address)
+            .into_vec()
+            .map_or(false, |bytes| bytes.len() == 32);
+        is_pubkey.then_some(address)
+    }
+
     pub fn field_ident(&self) -> Ident {
         format_ident!("{}", self.name.to_snake_case())
     }
@@ -792,13 +1210,31 @@ impl IxAccount {
         self.is_mut || self.is_signer
     }
 
-    pub fn to_keys_account_meta_tokens(&self) -> TokenStream {
+    /// `keys` is the `XKeys` expression e.g. `self`.
+    /// Absent optional accounts are replaced with `program_id`
+    pub fn to_keys_account_meta_tokens(&self, keys: &TokenStream) -> TokenStream {
         let is_writable_arg = LitBool::new(self.is_mut, Span::call_site());
         let is_signer_arg = LitBool::new(self.is_signer, Span::call_site());
         let name = self.field_ident();
+        if self.is_optional {
+            return quote! {
+                match #keys.#name {
+                    Some(pubkey) => AccountMeta {
+                        pubkey,
+                        is_signer: #is_signer_arg,
+                        is_writable: #is_writable_arg,
+                    },
+                    None => AccountMeta {
+                        pubkey: program_id,
+                        is_signer: false,
+                        is_writable: false,
+                    },
+                }
+            };
+        }
         quote! {
             AccountMeta {
-                pubkey: keys.#name,
+                pubkey: #keys.#name,
                 is_signer: #is_signer_arg,
                 is_writable: #is_writable_arg,
             }
@@ -811,6 +1247,29 @@ impl IxAccount {
             (*accounts.#name.key, keys.#name)
         }
     }
+
+    /// `(Option<actual>, Option<expected>)`, `None` if the account or its key is absent
+    pub fn to_verify_account_keys_option(&self) -> TokenStream {
+        let name = self.field_ident();
+        if self.is_optional {
+            return quote! {
+                (accounts.#name.map(|a| *a.key), keys.#name)
+            };
+        }
+        quote! {
+            (Some(*accounts.#name.key), Some(keys.#name))
+        }
+    }
+
+    /// `in_options` if the verify privileges loop iterates over `Option<&AccountInfo>`s
+    pub fn to_verify_privileges_elem(&self, in_options: bool) -> TokenStream {
+        let name = self.field_ident();
+        if in_options && !self.is_optional {
+            quote! { Some(accounts.#name) }
+        } else {
+            quote! { accounts.#name }
+        }
+    }
 }
 
 pub fn to_ix_accounts(accounts: &[IxAccountEntry]) -> Vec<IxAccount> {
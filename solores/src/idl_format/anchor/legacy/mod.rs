@@ -0,0 +1,249 @@
+use heck::{ToPascalCase, ToSnakeCase};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use super::{
+    accounts::NamedAccount,
+    constants::Constant,
+    errors::ErrorEnumVariant,
+    events::Event,
+    instructions::{InnerAccountStruct, IxAccount, IxAccountEntry, NamedInstruction},
+    typedefs::{DefinedFields, NamedType, TypedefField, TypedefStruct, TypedefType},
+    AnchorIdl,
+};
+
+mod pda;
+pub use pda::*;
+
+/// The IDL format of anchor versions before 0.30.
+///
+/// Discriminators are not part of the IDL and are computed from
+/// the names of instructions, accounts and events instead.
+#[derive(Deserialize)]
+pub struct LegacyAnchorIdl {
+    pub name: String,
+    pub version: String,
+    pub metadata: Option<Metadata>,
+    pub accounts: Option<Vec<NamedType>>,
+    pub types: Option<Vec<NamedType>>,
+    pub instructions: Option<Vec<LegacyInstruction>>,
+    pub errors: Option<Vec<ErrorEnumVariant>>,
+    pub events: Option<Vec<LegacyEvent>>,
+    pub constants: Option<Vec<Constant>>,
+}
+
+#[derive(Deserialize)]
+pub struct Metadata {
+    pub address: String,
+}
+
+#[derive(Deserialize)]
+pub struct LegacyInstruction {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    pub accounts: Option<Vec<LegacyIxAccountEntry>>,
+    pub args: Option<Vec<TypedefField>>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum LegacyIxAccountEntry {
+    Account(LegacyIxAccount),
+    Struct(LegacyInnerAccountStruct),
+}
+
+#[derive(Deserialize)]
+pub struct LegacyInnerAccountStruct {
+    pub name: String,
+    pub accounts: Vec<LegacyIxAccountEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyIxAccount {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    pub is_mut: bool,
+    pub is_signer: bool,
+    #[serde(default)]
+    pub is_optional: bool,
+    pub pda: Option<Pda>,
+}
+
+#[derive(Deserialize)]
+pub struct LegacyEvent {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    // NB: theres also an `index` field that's ignored for now since we dk what it does:
+    // https://github.com/coral-xyz/anchor/blob/8f30f00ec363b7e82aa0b3c7041e912919b33cf5/lang/attribute/event/src/lib.rs#L62C1-L64
+    pub fields: Vec<TypedefField>,
+}
+
+/// First 8 bytes of sha256(`pre_image`)
+fn discriminator(pre_image: &str) -> [u8; 8] {
+    <[u8; 8]>::try_from(&Sha256::digest(pre_image.as_bytes()).as_slice()[..8]).unwrap()
+}
+
+impl From<NamedType> for NamedAccount {
+    /// pre-image: "account:{AccountStructName}"
+    fn from(typedef: NamedType) -> Self {
+        let discriminator = discriminator(&format!("account:{}", typedef.name.to_pascal_case()));
+        Self {
+            typedef,
+            discriminator,
+        }
+    }
+}
+
+impl From<LegacyEvent> for Event {
+    /// pre-image: "event:{EventName}"
+    fn from(LegacyEvent { name, docs, fields }: LegacyEvent) -> Self {
+        Self {
+            discriminator: discriminator(&format!("event:{}", name)),
+            typedef: NamedType {
+                // legacy event structs were always PascalCased e.g. `LPRecord` -> `LpRecord`
+                name: name.to_pascal_case(),
+                docs,
+                generics: None,
+                serialization: Default::default(),
+                repr: None,
+                r#type: TypedefType::r#struct(TypedefStruct {
+                    fields: Some(DefinedFields::Struct(fields)),
+                }),
+            },
+        }
+    }
+}
+
+impl LegacyIxAccountEntry {
+    fn into_ix_account_entry(self) -> IxAccountEntry {
+        match self {
+            Self::Account(LegacyIxAccount {
+                name,
+                docs,
+                is_mut,
+                is_signer,
+                is_optional,
+                pda,
+            }) => IxAccountEntry::Account(IxAccount {
+                pda: pda.and_then(|p| p.resolve(&name.to_snake_case())),
+                name,
+                docs,
+                is_mut,
+                is_signer,
+                is_optional,
+                address: None,
+            }),
+            Self::Struct(LegacyInnerAccountStruct { name, accounts }) => {
+                IxAccountEntry::Struct(Box::new(InnerAccountStruct {
+                    name,
+                    accounts: accounts
+                        .into_iter()
+                        .map(Self::into_ix_account_entry)
+                        .collect(),
+                }))
+            }
+        }
+    }
+}
+
+impl From<LegacyInstruction> for NamedInstruction {
+    /// pre-image: "global:{snake_case_ix_name}"
+    // TODO: handle non-global instructions (state methods - idk if thats deprecated)
+    fn from(
+        LegacyInstruction {
+            name,
+            docs,
+            accounts,
+            args,
+        }: LegacyInstruction,
+    ) -> Self {
+        let discriminator = discriminator(&format!("global:{}", name.to_snake_case()));
+        let accounts = accounts.map(|v| {
+            v.into_iter()
+                .map(LegacyIxAccountEntry::into_ix_account_entry)
+                .collect()
+        });
+        Self {
+            name,
+            docs,
+            discriminator,
+            accounts,
+            args,
+        }
+    }
+}
+
+impl From<LegacyAnchorIdl> for AnchorIdl {
+    fn from(idl: LegacyAnchorIdl) -> Self {
+        Self {
+            name: idl.name,
+            version: idl.version,
+            address: idl.metadata.map(|m| m.address),
+            // no accounts or events modules for empty lists
+            accounts: idl
+                .accounts
+                .filter(|v| !v.is_empty())
+                .map(|v| v.into_iter().map(NamedAccount::from).collect()),
+            types: idl.types,
+            instructions: idl
+                .instructions
+                .map(|v| v.into_iter().map(NamedInstruction::from).collect()),
+            errors: idl.errors,
+            events: idl
+                .events
+                .filter(|v| !v.is_empty())
+                .map(|v| v.into_iter().map(Event::from).collect()),
+            constants: idl.constants,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+    use crate::idl_format::anchor::typedefs::TypedefFieldType;
+
+    #[test]
+    fn test_event_type_to_tokens_with_pub_fields() {
+        // Define some fields for the LegacyEvent struct.
+        let field1 = TypedefField {
+            name: "field1".to_string(),
+            docs: vec![],
+            r#type: TypedefFieldType::PrimitiveOrPubkey("u32".into()),
+        };
+
+        let field2 = TypedefField {
+            name: "field2".to_string(),
+            docs: vec![],
+            r#type: TypedefFieldType::PrimitiveOrPubkey("String".into()),
+        };
+
+        // Create a LegacyEvent with the fields.
+        let event = Event::from(LegacyEvent {
+            name: "TestEvent".to_string(),
+            docs: vec![],
+            fields: vec![field1, field2],
+        });
+
+        // Generate the tokens.
+        let tokens = event
+            .typedef
+            .to_token_stream(&crate::Args::parse_from(["solores", "idl.json"]));
+
+        // Convert the tokens to a string for comparison.
+        let generated_code = tokens.to_string();
+
+        // Check that the generated code includes "pub" for each field.
+        assert!(generated_code.contains("pub field1 : u32"));
+        assert!(generated_code.contains("pub field2 : String"));
+
+        // Check that the struct name is correct.
+        assert!(generated_code.contains("pub struct TestEvent"));
+    }
+}
@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::idl_format::anchor::pdas::{
+    number_size, unsupported_seed, ResolvedPda, ResolvedSeed, SeedParam, SeedParamType,
+};
+
+/// `pda` field of an instruction account
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Pda {
+    pub seeds: Vec<Seed>,
+    pub program_id: Option<Seed>,
+}
+
+/// `type`s are left as json values since only
+/// primitives, pubkeys and byte arrays are valid seeds
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum Seed {
+    Const { r#type: Value, value: Value },
+    Arg { r#type: Value, path: String },
+    Account { r#type: Value, path: String },
+}
+
+/// Returns the length of `{ "array": ["u8", N] }`
+fn u8_array_len(ty: &Value) -> Option<usize> {
+    let arr = ty.get("array")?.as_array()?;
+    match arr.as_slice() {
+        [item, len] if item == "u8" => len.as_u64().map(|l| l as usize),
+        _ => None,
+    }
+}
+
+fn param_type(pda_name: &str, ty: &Value) -> Option<SeedParamType> {
+    if let Some(len) = u8_array_len(ty) {
+        return Some(SeedParamType::ByteArray(len));
+    }
+    match ty.as_str() {
+        Some(p) => SeedParamType::primitive(pda_name, p),
+        None => unsupported_seed(pda_name, &format!("type {}", ty)),
+    }
+}
+
+fn const_bytes(pda_name: &str, ty: &Value, value: &Value) -> Option<Vec<u8>> {
+    let bytes = match (ty.as_str(), value) {
+        (Some("string"), Value::String(s)) => Some(s.as_bytes().to_vec()),
+        (Some("publicKey"), Value::String(s)) => bs58::decode(s).into_vec().ok(),
+        (Some(n), Value::Number(v)) => number_size(n).and_then(|size| {
+            let le = match (v.as_u64(), v.as_i64()) {
+                (Some(u), _) => u128::from(u).to_le_bytes(),
+                (None, Some(i)) => i128::from(i).to_le_bytes(),
+                _ => return None,
+            };
+            Some(le[..size].to_vec())
+        }),
+        (_, Value::Array(arr)) => arr
+            .iter()
+            .map(|b| b.as_u64().and_then(|b| u8::try_from(b).ok()))
+            .collect(),
+        _ => None,
+    };
+    bytes.or_else(|| unsupported_seed(pda_name, &format!("const value {}", value)))
+}
+
+fn resolve_seed(pda_name: &str, seed: &Seed) -> Option<ResolvedSeed> {
+    Some(match seed {
+        Seed::Const { r#type, value } => ResolvedSeed::Const(const_bytes(pda_name, r#type, value)?),
+        Seed::Arg { r#type, path } => {
+            ResolvedSeed::Param(SeedParam::arg(path, param_type(pda_name, r#type)?))
+        }
+        Seed::Account { r#type, path } => {
+            ResolvedSeed::Param(SeedParam::account(path, param_type(pda_name, r#type)?))
+        }
+    })
+}
+
+impl Pda {
+    /// `None` if any of the seeds are unsupported
+    pub fn resolve(&self, pda_name: &str) -> Option<ResolvedPda> {
+        let seeds = self
+            .seeds
+            .iter()
+            .map(|s| resolve_seed(pda_name, s))
+            .collect::<Option<_>>()?;
+        let program_id = match &self.program_id {
+            None => None,
+            Some(s) => Some(resolve_seed(pda_name, s)?),
+        };
+        ResolvedPda::new(pda_name, seeds, program_id)
+    }
+}
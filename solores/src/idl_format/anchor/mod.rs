@@ -1,42 +1,78 @@
-use serde::Deserialize;
+use std::collections::HashMap;
+
 use toml::{map::Map, Value};
 
+use crate::layout::Layouts;
+use crate::serde_helpers::{
+    has_serde_helpers, serde_helpers_needs_base64, SerdeHelpersCodegenModule,
+};
 use crate::write_cargotoml::{
-    DependencyValue, FeaturesDependencyValue, OptionalDependencyValue, BORSH_CRATE, BYTEMUCK_CRATE,
-    NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_CRATE, SOLANA_PROGRAM_CRATE, THISERROR_CRATE,
+    client_features, insert_client_dependencies, insert_schemars_dependency,
+    insert_schemars_feature, insert_serde_base64_dependency, insert_serde_base64_feature,
+    DependencyValue, FeaturesDependencyValue, OptionalDependencyValue, BASE64_CRATE, BORSH_CRATE,
+    BYTEMUCK_CRATE, NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_CRATE, SOLANA_PROGRAM_CRATE,
+    THISERROR_CRATE,
 };
+use crate::zero_copy::ZeroCopyTypedef;
 
 use super::{IdlCodegenModule, IdlFormat};
 
 use self::{
     accounts::{AccountsCodegenModule, NamedAccount},
+    client::ClientCodegenModule,
+    constants::{Constant, ConstantsCodegenModule},
     errors::{ErrorEnumVariant, ErrorsCodegenModule},
     events::{Event, EventsCodegenModule},
     instructions::{IxCodegenModule, NamedInstruction},
+    pdas::{ix_pdas, PdasCodegenModule},
     typedefs::{NamedType, TypedefsCodegenModule},
 };
 
 pub mod accounts;
+pub mod client;
+pub mod constants;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod legacy;
+pub mod pdas;
 pub mod typedefs;
 
-#[derive(Deserialize)]
+/// The program model all anchor codegen works off of,
+/// converted from either the legacy IDL format ([`legacy::LegacyAnchorIdl`])
+/// or the anchor 0.30+ one ([`super::anchor030::Anchor030Idl`])
 pub struct AnchorIdl {
     pub name: String,
     pub version: String,
-    pub metadata: Option<Metadata>,
+    pub address: Option<String>,
     pub accounts: Option<Vec<NamedAccount>>,
+    /// Does not include the typedefs of accounts and events
     pub types: Option<Vec<NamedType>>,
     pub instructions: Option<Vec<NamedInstruction>>,
     pub errors: Option<Vec<ErrorEnumVariant>>,
     pub events: Option<Vec<Event>>,
+    pub constants: Option<Vec<Constant>>,
 }
 
-#[derive(Deserialize)]
-pub struct Metadata {
-    pub address: String,
+impl AnchorIdl {
+    /// All typedefs, including those of accounts and events
+    fn all_typedefs(&self) -> impl Iterator<Item = &NamedType> {
+        let accounts = self.accounts.iter().flatten().map(|a| &a.typedef);
+        let events = self.events.iter().flatten().map(|e| &e.typedef);
+        accounts.chain(events).chain(self.types.iter().flatten())
+    }
+
+    /// Whether to generate the `client` module and feature
+    fn has_client(&self) -> bool {
+        self.accounts.is_some() || self.instructions.is_some()
+    }
+
+    /// Layouts of all typedefs
+    fn layouts(&self) -> Layouts {
+        self.all_typedefs()
+            .map(|t| (t.name.clone(), t.to_layout_typedef()))
+            .collect()
+    }
 }
 
 impl IdlFormat for AnchorIdl {
@@ -49,44 +85,90 @@ impl IdlFormat for AnchorIdl {
     }
 
     fn program_address(&self) -> Option<&str> {
-        self.metadata.as_ref().map(|m| m.address.as_ref())
+        self.address.as_deref()
     }
 
-    /// Anchor IDLs dont seem to have an identifier,
-    /// assume unindentified IDLs are anchor by default.
-    /// -> Make sure to try deserializing Anchor last
+    /// Only ever constructed from a parsed [`legacy::LegacyAnchorIdl`] or
+    /// [`super::anchor030::Anchor030Idl`], which do their own format checks
     fn is_correct_idl_format(&self) -> bool {
         true
     }
 
+    fn zero_copy_typedefs(&self) -> Option<HashMap<String, ZeroCopyTypedef>> {
+        Some(
+            self.all_typedefs()
+                .map(|t| (t.name.clone(), t.to_zero_copy_typedef()))
+                .collect(),
+        )
+    }
+
+    /// Typedefs with `serialization: "bytemuck"` or `"bytemuckunsafe"`
+    fn zero_copy_annotated(&self) -> Vec<&str> {
+        self.all_typedefs()
+            .filter(|t| t.is_bytemuck())
+            .map(|t| t.name.as_str())
+            .collect()
+    }
+
     fn modules<'me>(&'me self, args: &'me crate::Args) -> Vec<Box<dyn IdlCodegenModule + 'me>> {
         let mut res: Vec<Box<dyn IdlCodegenModule + 'me>> = Vec::new();
         if let Some(v) = &self.accounts {
             res.push(Box::new(AccountsCodegenModule {
                 cli_args: args,
+                program_name: self.program_name(),
                 named_accounts: v,
+                layouts: self.layouts(),
             }));
         }
         if let Some(v) = &self.r#types {
             res.push(Box::new(TypedefsCodegenModule {
                 cli_args: args,
                 named_types: v,
+                layouts: self.layouts(),
             }));
         }
         if let Some(v) = &self.instructions {
             res.push(Box::new(IxCodegenModule {
+                cli_args: args,
                 program_name: self.program_name(),
                 instructions: v,
+                ix_pdas: ix_pdas(v),
             }));
         }
-        if let Some(v) = &self.errors {
+        if self.errors.is_some() || args.anchor_framework_errors {
             res.push(Box::new(ErrorsCodegenModule {
                 program_name: self.program_name(),
-                variants: v,
+                variants: self.errors.as_deref().unwrap_or_default(),
+                framework_errors: args.anchor_framework_errors,
             }));
         }
         if let Some(v) = &self.events {
-            res.push(Box::new(EventsCodegenModule(v)));
+            res.push(Box::new(EventsCodegenModule {
+                cli_args: args,
+                program_name: self.program_name(),
+                events: v,
+            }));
+        }
+        if let Some(v) = &self.instructions {
+            let ix_pdas = ix_pdas(v);
+            if !ix_pdas.is_empty() {
+                res.push(Box::new(PdasCodegenModule { ix_pdas }));
+            }
+        }
+        if let Some(v) = &self.constants {
+            res.push(Box::new(ConstantsCodegenModule(v)));
+        }
+        if has_serde_helpers(args) {
+            res.push(Box::new(SerdeHelpersCodegenModule {
+                cli_args: args,
+                feature: Some(SERDE_CRATE),
+            }));
+        }
+        if self.has_client() {
+            res.push(Box::new(ClientCodegenModule {
+                named_accounts: self.accounts.as_deref().unwrap_or_default(),
+                instructions: self.instructions.as_deref().unwrap_or_default(),
+            }));
         }
         res
     }
@@ -112,7 +194,18 @@ impl IdlFormat for AnchorIdl {
             SERDE_CRATE.into(),
             OptionalDependencyValue(DependencyValue(&args.serde_vers)).into(),
         );
-        if self.errors.is_some() {
+        if self.has_client() {
+            insert_client_dependencies(&mut map, args);
+        }
+        if self.events.is_some() {
+            map.insert(
+                BASE64_CRATE.into(),
+                DependencyValue(&args.base64_vers).into(),
+            );
+        } else if serde_helpers_needs_base64(args) {
+            insert_serde_base64_dependency(&mut map, args);
+        }
+        if self.errors.is_some() || args.anchor_framework_errors {
             map.insert(
                 THISERROR_CRATE.into(),
                 DependencyValue(&args.thiserror_vers).into(),
@@ -126,6 +219,25 @@ impl IdlFormat for AnchorIdl {
                 DependencyValue(&args.num_traits_vers).into(),
             );
         }
+        if args.schemars {
+            insert_schemars_dependency(&mut map, args);
+        }
+        map
+    }
+
+    fn features(&self, args: &crate::Args) -> Map<String, Value> {
+        let mut map = if self.has_client() {
+            client_features()
+        } else {
+            Map::new()
+        };
+        // events need base64 without the serde feature too
+        if self.events.is_none() && serde_helpers_needs_base64(args) {
+            insert_serde_base64_feature(&mut map);
+        }
+        if args.schemars {
+            insert_schemars_feature(&mut map, true);
+        }
         map
     }
 }
@@ -0,0 +1,91 @@
+use std::collections::{HashMap, HashSet};
+
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::idl_format::IdlCodegenModule;
+
+use super::instructions::{to_ix_accounts, NamedInstruction};
+
+mod pda;
+pub use pda::*;
+
+/// A PDA instruction account
+pub struct IxPda {
+    pub ix_name: String,
+    /// snake_case name of the account
+    pub account_name: String,
+    /// Name the generated PDA fns are named after e.g. `find_<fns_name>_address()`.
+    ///
+    /// PDA fns are named after the instruction account.
+    /// If accounts of the same name in different instructions have different seeds,
+    /// the fns are prefixed with the instruction name instead.
+    pub fns_name: String,
+    pub pda: ResolvedPda,
+}
+
+/// The PDAs of all instruction accounts with a `pda` field
+pub fn ix_pdas(instructions: &[NamedInstruction]) -> Vec<IxPda> {
+    let ix_pdas: Vec<(&str, String, ResolvedPda)> = instructions
+        .iter()
+        .flat_map(|ix| {
+            ix.accounts
+                .as_ref()
+                .map_or(Vec::new(), |v| to_ix_accounts(v))
+                .into_iter()
+                .filter_map(move |acc| {
+                    let pda = acc.pda?;
+                    Some((ix.name.as_str(), acc.name.to_snake_case(), pda))
+                })
+        })
+        .collect();
+    let mut seeds_variants: HashMap<String, HashSet<String>> = HashMap::new();
+    for (_, name, pda) in ix_pdas.iter() {
+        seeds_variants
+            .entry(name.clone())
+            .or_default()
+            .insert(pda.to_token_stream(name).to_string());
+    }
+    ix_pdas
+        .into_iter()
+        .map(|(ix_name, account_name, pda)| {
+            let fns_name = if seeds_variants[&account_name].len() > 1 {
+                format!("{}_{}", ix_name.to_snake_case(), account_name)
+            } else {
+                account_name.clone()
+            };
+            IxPda {
+                ix_name: ix_name.to_owned(),
+                account_name,
+                fns_name,
+                pda,
+            }
+        })
+        .collect()
+}
+
+pub struct PdasCodegenModule {
+    pub ix_pdas: Vec<IxPda>,
+}
+
+impl IdlCodegenModule for PdasCodegenModule {
+    fn name(&self) -> &str {
+        "pdas"
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        quote! {
+            use solana_program::pubkey::{Pubkey, PubkeyError};
+        }
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        let mut generated = HashSet::new();
+        self.ix_pdas
+            .iter()
+            .map(|IxPda { fns_name, pda, .. }| pda.to_token_stream(fns_name))
+            .filter(|tokens| generated.insert(tokens.to_string()))
+            .collect()
+    }
+}
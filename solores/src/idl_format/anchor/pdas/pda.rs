@@ -0,0 +1,361 @@
+use heck::ToSnakeCase;
+use itertools::Itertools;
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::LitByteStr;
+
+/// Warns that the PDA can't be generated.
+/// Always `None`, the PDA's account is then treated like any other account.
+pub fn unsupported_seed<T>(pda_name: &str, reason: &str) -> Option<T> {
+    log::warn!(
+        "Unsupported seed for PDA {}: {}. Skipping its PDA helpers",
+        pda_name,
+        reason
+    );
+    None
+}
+
+#[derive(Clone)]
+pub enum SeedParamType {
+    Pubkey,
+    Str,
+    Bytes,
+    ByteArray(usize),
+    Number(Ident, usize),
+    Bool,
+}
+
+impl SeedParamType {
+    /// Type of a seed of primitive IDL type `ty` e.g. `u64`, `publicKey`
+    pub fn primitive(pda_name: &str, ty: &str) -> Option<Self> {
+        Some(match ty {
+            "publicKey" | "pubkey" => Self::Pubkey,
+            "string" => Self::Str,
+            "bytes" => Self::Bytes,
+            "bool" => Self::Bool,
+            n => match number_size(n) {
+                Some(size) => Self::Number(format_ident!("{}", n), size),
+                None => return unsupported_seed(pda_name, &format!("type {}", n)),
+            },
+        })
+    }
+}
+
+/// Where the value of a seed param comes from in the instruction
+#[derive(Clone)]
+enum SeedSource {
+    /// Path segments of the instruction arg
+    Arg(Vec<String>),
+    /// snake_case name of the instruction account
+    Account(String),
+    /// A field of an instruction account's data
+    AccountField,
+}
+
+#[derive(Clone)]
+pub struct SeedParam {
+    ident: Ident,
+    ty: SeedParamType,
+    source: SeedSource,
+}
+
+#[derive(Clone)]
+pub enum ResolvedSeed {
+    Const(Vec<u8>),
+    Param(SeedParam),
+}
+
+#[derive(Clone)]
+enum PdaProgramId {
+    Crate,
+    Const(String),
+    Param(SeedParam),
+}
+
+pub fn number_size(ty: &str) -> Option<usize> {
+    Some(match ty {
+        "u8" | "i8" => 1,
+        "u16" | "i16" => 2,
+        "u32" | "i32" => 4,
+        "u64" | "i64" => 8,
+        "u128" | "i128" => 16,
+        _ => return None,
+    })
+}
+
+/// `"a.b"` -> `a_b`
+fn path_ident(path: &str) -> Ident {
+    format_ident!("{}", path.replace('.', "_").to_snake_case())
+}
+
+impl SeedParam {
+    /// Seed param of instruction arg `path`, which may be a field of an arg e.g. `a.b`
+    pub fn arg(path: &str, ty: SeedParamType) -> Self {
+        Self {
+            ident: path_ident(path),
+            ty,
+            source: SeedSource::Arg(path.split('.').map(|s| s.to_snake_case()).collect()),
+        }
+    }
+
+    /// Seed param of the pubkey of instruction account `path`,
+    /// or of a field of the account's data if `path` is `account.field`
+    pub fn account(path: &str, ty: SeedParamType) -> Self {
+        Self {
+            ident: path_ident(path),
+            ty,
+            source: if path.contains('.') {
+                SeedSource::AccountField
+            } else {
+                SeedSource::Account(path.to_snake_case())
+            },
+        }
+    }
+
+    fn fn_param(&self) -> TokenStream {
+        let ident = &self.ident;
+        let ty = match &self.ty {
+            SeedParamType::Pubkey => quote! { &Pubkey },
+            SeedParamType::Str => quote! { &str },
+            SeedParamType::Bytes => quote! { &[u8] },
+            SeedParamType::ByteArray(len) => {
+                let len = Literal::usize_unsuffixed(*len);
+                quote! { &[u8; #len] }
+            }
+            SeedParamType::Number(n, _) => quote! { #n },
+            SeedParamType::Bool => quote! { bool },
+        };
+        quote! { #ident: #ty }
+    }
+
+    /// Numbers and bools are passed as their le bytes
+    /// since the returned seeds array borrows them
+    fn seeds_fn_param(&self) -> TokenStream {
+        let ident = &self.ident;
+        let ty = match &self.ty {
+            SeedParamType::Pubkey => quote! { &'a Pubkey },
+            SeedParamType::Str => quote! { &'a str },
+            SeedParamType::Bytes => quote! { &'a [u8] },
+            SeedParamType::ByteArray(len) | SeedParamType::Number(_, len) => {
+                let len = Literal::usize_unsuffixed(*len);
+                quote! { &'a [u8; #len] }
+            }
+            SeedParamType::Bool => quote! { &'a [u8; 1] },
+        };
+        quote! { #ident: #ty }
+    }
+
+    fn seed_expr(&self) -> TokenStream {
+        let ident = &self.ident;
+        match &self.ty {
+            SeedParamType::Pubkey => quote! { #ident.as_ref() },
+            SeedParamType::Str => quote! { #ident.as_bytes() },
+            SeedParamType::Bytes | SeedParamType::ByteArray(_) => quote! { #ident },
+            SeedParamType::Number(..) => quote! { &#ident.to_le_bytes() },
+            SeedParamType::Bool => quote! { &[u8::from(#ident)] },
+        }
+    }
+
+    fn seeds_fn_seed_expr(&self) -> TokenStream {
+        let ident = &self.ident;
+        match &self.ty {
+            SeedParamType::Pubkey => quote! { #ident.as_ref() },
+            SeedParamType::Str => quote! { #ident.as_bytes() },
+            _ => quote! { #ident },
+        }
+    }
+
+    /// The value passed for this param from within a `*Keys` constructor,
+    /// where instruction accounts are `Pubkey` local variables
+    /// and instruction args are fields of `args`
+    fn keys_arg_expr(&self) -> Option<TokenStream> {
+        match &self.source {
+            SeedSource::Account(name) => {
+                let ident = format_ident!("{}", name);
+                Some(quote! { &#ident })
+            }
+            SeedSource::Arg(segments) => {
+                let fields = segments.iter().map(|s| format_ident!("{}", s));
+                Some(match self.ty {
+                    SeedParamType::Number(..) | SeedParamType::Bool => {
+                        quote! { args.#(#fields).* }
+                    }
+                    _ => quote! { &args.#(#fields).* },
+                })
+            }
+            SeedSource::AccountField => None,
+        }
+    }
+}
+
+fn const_seed_expr(bytes: &[u8]) -> TokenStream {
+    if bytes.iter().all(|b| b.is_ascii_graphic() || *b == b' ') {
+        let lit = LitByteStr::new(bytes, Span::call_site());
+        quote! { #lit }
+    } else {
+        let bytes = bytes.iter().map(|b| Literal::u8_unsuffixed(*b));
+        quote! { &[#(#bytes),*] }
+    }
+}
+
+/// The `pda` of an instruction account with the types of its seeds resolved
+#[derive(Clone)]
+pub struct ResolvedPda {
+    seeds: Vec<ResolvedSeed>,
+    program_id: PdaProgramId,
+}
+
+/// How to derive a PDA in a `*Keys` constructor
+pub struct KeysDerivation {
+    /// snake_case names of the instruction accounts the PDA is derived from
+    pub account_deps: Vec<String>,
+    /// whether the PDA is derived from the instruction args
+    pub uses_args: bool,
+    /// `find_<name>_address(..).0`
+    pub find_expr: TokenStream,
+}
+
+impl ResolvedPda {
+    /// `program_id` is `None` for PDAs of this program
+    pub fn new(
+        pda_name: &str,
+        seeds: Vec<ResolvedSeed>,
+        program_id: Option<ResolvedSeed>,
+    ) -> Option<Self> {
+        let program_id = match program_id {
+            None => PdaProgramId::Crate,
+            Some(ResolvedSeed::Const(bytes)) => {
+                if bytes.len() != 32 {
+                    return unsupported_seed(pda_name, "program ID not 32 bytes");
+                }
+                PdaProgramId::Const(bs58::encode(bytes).into_string())
+            }
+            Some(ResolvedSeed::Param(p)) if matches!(p.ty, SeedParamType::Pubkey) => {
+                PdaProgramId::Param(p)
+            }
+            Some(ResolvedSeed::Param(_)) => {
+                return unsupported_seed(pda_name, "program ID not a pubkey")
+            }
+        };
+        Some(Self { seeds, program_id })
+    }
+}
+
+impl ResolvedPda {
+    fn seed_params(&self) -> Vec<&SeedParam> {
+        self.seeds
+            .iter()
+            .filter_map(|s| match s {
+                ResolvedSeed::Const(_) => None,
+                ResolvedSeed::Param(p) => Some(p),
+            })
+            .unique_by(|p| p.ident.to_string())
+            .collect()
+    }
+
+    /// The params of `find_<name>_address()` in order
+    fn find_fn_params(&self) -> Vec<&SeedParam> {
+        let mut params = self.seed_params();
+        if let PdaProgramId::Param(p) = &self.program_id {
+            if !params.iter().any(|sp| sp.ident == p.ident) {
+                params.push(p);
+            }
+        }
+        params
+    }
+
+    /// Returns `None` if the PDA depends on data not available
+    /// to a `*Keys` constructor e.g. fields of account data
+    pub fn keys_derivation(&self, name: &str) -> Option<KeysDerivation> {
+        let params = self.find_fn_params();
+        let find_args = params
+            .iter()
+            .map(|p| p.keys_arg_expr())
+            .collect::<Option<Vec<_>>>()?;
+        let account_deps = params
+            .iter()
+            .filter_map(|p| match &p.source {
+                SeedSource::Account(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect();
+        let uses_args = params
+            .iter()
+            .any(|p| matches!(p.source, SeedSource::Arg(_)));
+        let find_fn_ident = format_ident!("find_{}_address", name);
+        Some(KeysDerivation {
+            account_deps,
+            uses_args,
+            find_expr: quote! { #find_fn_ident(#(#find_args),*).0 },
+        })
+    }
+
+    /// `name` is the snake_case name the generated fns are named after e.g.
+    /// `find_<name>_address()`
+    pub fn to_token_stream(&self, name: &str) -> TokenStream {
+        let seeds = &self.seeds;
+        let params = self.seed_params();
+        let mut fn_params: Vec<TokenStream> = params.iter().map(|p| p.fn_param()).collect();
+        let seeds_fn_params = params.iter().map(|p| p.seeds_fn_param());
+        let seed_exprs: Vec<TokenStream> = seeds
+            .iter()
+            .map(|s| match s {
+                ResolvedSeed::Const(bytes) => const_seed_expr(bytes),
+                ResolvedSeed::Param(p) => p.seed_expr(),
+            })
+            .collect();
+        let seeds_fn_seed_exprs = seeds.iter().map(|s| match s {
+            ResolvedSeed::Const(bytes) => const_seed_expr(bytes),
+            ResolvedSeed::Param(p) => p.seeds_fn_seed_expr(),
+        });
+        let program_id = match &self.program_id {
+            PdaProgramId::Crate => quote! { &crate::ID },
+            PdaProgramId::Const(id) => quote! { &solana_program::pubkey!(#id) },
+            PdaProgramId::Param(p) => {
+                if !params.iter().any(|sp| sp.ident == p.ident) {
+                    fn_params.push(p.fn_param());
+                }
+                p.ident.to_token_stream()
+            }
+        };
+
+        let find_fn_ident = format_ident!("find_{}_address", name);
+        let create_fn_ident = format_ident!("create_{}_address", name);
+        let seeds_fn_ident = format_ident!("{}_seeds", name);
+        let n_seeds = Literal::usize_unsuffixed(seeds.len() + 1);
+        let mut seeds_fn_doc = quote! {
+            #[doc = " Seeds of the PDA, including the bump seed, for use with `invoke_signed()`."]
+        };
+        if params
+            .iter()
+            .any(|p| matches!(p.ty, SeedParamType::Number(..) | SeedParamType::Bool))
+        {
+            seeds_fn_doc.extend(quote! {
+                #[doc = ""]
+                #[doc = " Number and bool seeds are passed as their bytes since the returned array borrows them."]
+            });
+        }
+        // elided if bump is the only param to avoid clippy::needless_lifetimes
+        let seeds_fn_sig = if params.is_empty() {
+            quote! { #seeds_fn_ident(bump: &[u8; 1]) -> [&[u8]; #n_seeds] }
+        } else {
+            quote! {
+                #seeds_fn_ident<'a>(#(#seeds_fn_params,)* bump: &'a [u8; 1]) -> [&'a [u8]; #n_seeds]
+            }
+        };
+        quote! {
+            pub fn #find_fn_ident(#(#fn_params),*) -> (Pubkey, u8) {
+                Pubkey::find_program_address(&[#(#seed_exprs),*], #program_id)
+            }
+
+            pub fn #create_fn_ident(#(#fn_params,)* bump: u8) -> Result<Pubkey, PubkeyError> {
+                Pubkey::create_program_address(&[#(#seed_exprs,)* &[bump]], #program_id)
+            }
+
+            #seeds_fn_doc
+            pub fn #seeds_fn_sig {
+                [#(#seeds_fn_seed_exprs,)* bump]
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{idl_format::IdlCodegenModule, layout::Layouts, utils::conditional_pascal_case};
 
 mod typedef;
 pub use typedef::*;
@@ -9,6 +9,7 @@ pub use typedef::*;
 pub struct TypedefsCodegenModule<'a> {
     pub cli_args: &'a crate::Args,
     pub named_types: &'a [NamedType],
+    pub layouts: Layouts,
 }
 
 impl IdlCodegenModule for TypedefsCodegenModule<'_> {
@@ -42,7 +43,16 @@ impl IdlCodegenModule for TypedefsCodegenModule<'_> {
     fn gen_body(&self) -> TokenStream {
         self.named_types
             .iter()
-            .map(|e| e.to_token_stream(self.cli_args))
+            .map(|t| {
+                let mut res = t.to_token_stream(self.cli_args);
+                let is_zero_copy = self.cli_args.zero_copy.iter().any(|e| e == &t.name);
+                res.extend(self.layouts.consts(
+                    &t.name,
+                    &format_ident!("{}", conditional_pascal_case(&t.name)),
+                    is_zero_copy,
+                ));
+                res
+            })
             .collect()
     }
 }
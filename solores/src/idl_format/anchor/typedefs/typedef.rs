@@ -9,68 +9,313 @@ use serde::Deserialize;
 use syn::Index;
 use void::Void;
 
+use crate::json_schema::json_schema_derive;
+use crate::layout::{LayoutType, LayoutTypedef};
+use crate::serde_helpers::SerdeAsType;
 use crate::utils::{
-    conditional_pascal_case, primitive_or_pubkey_to_token, string_or_struct, PUBKEY_TOKEN,
+    conditional_pascal_case, doc_attrs, primitive_or_pubkey_to_token, string_or_struct,
+    PUBKEY_TOKEN,
 };
+use crate::zero_copy::{ZeroCopyRepr, ZeroCopyType, ZeroCopyTypedef};
 
 #[derive(Deserialize)]
 pub struct NamedType {
     pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    pub generics: Option<Vec<TypedefGeneric>>,
+    #[serde(default)]
+    pub serialization: TypedefSerialization,
+    pub repr: Option<TypedefRepr>,
     pub r#type: TypedefType,
 }
 
 impl NamedType {
+    /// Whether the IDL marks this typedef as a bytemuck zero-copy type
+    pub fn is_bytemuck(&self) -> bool {
+        matches!(
+            self.serialization,
+            TypedefSerialization::bytemuck | TypedefSerialization::bytemuckunsafe
+        )
+    }
+
+    pub fn is_packed(&self) -> bool {
+        matches!(&self.repr, Some(TypedefRepr::c(m) | TypedefRepr::rust(m)) if m.packed)
+    }
+
+    /// `#[repr(...)]` of zero-copy typedefs. Defaults to `#[repr(C)]`
+    pub fn zero_copy_repr_attr(&self) -> TokenStream {
+        let modifier = match &self.repr {
+            Some(TypedefRepr::transparent) => return quote! { #[repr(transparent)] },
+            Some(TypedefRepr::c(m) | TypedefRepr::rust(m)) => m,
+            None => return quote! { #[repr(C)] },
+        };
+        let packed = modifier.packed.then(|| quote! { packed });
+        let align = modifier.align.map(|n| {
+            let n = Index::from(n);
+            quote! { align(#n) }
+        });
+        let modifiers = packed.into_iter().chain(align);
+        quote! { #[repr(C #(, #modifiers)*)] }
+    }
+
+    /// This typedef's borsh layout for generating `*_LEN` and `*_OFFSET` consts.
+    /// Offsets of `bytemuckunsafe` typedefs are not generated since they may have padding.
+    pub fn to_layout_typedef(&self) -> LayoutTypedef {
+        if self.generics.as_ref().map_or(false, |g| !g.is_empty())
+            || self.serialization == TypedefSerialization::bytemuckunsafe
+        {
+            return LayoutTypedef::Variable;
+        }
+        match &self.r#type {
+            TypedefType::r#struct(s) => LayoutTypedef::Struct(match &s.fields {
+                None => Vec::new(),
+                Some(DefinedFields::Struct(v)) => v
+                    .iter()
+                    .map(|f| (f.name.clone(), f.r#type.to_layout_type()))
+                    .collect(),
+                Some(DefinedFields::Tuple(v)) => v
+                    .iter()
+                    .enumerate()
+                    .map(|(i, f)| (i.to_string(), f.0.to_layout_type()))
+                    .collect(),
+            }),
+            TypedefType::r#enum(e) => LayoutTypedef::Enum(
+                e.variants
+                    .iter()
+                    .map(|v| {
+                        v.fields
+                            .as_ref()
+                            .map_or_else(Vec::new, |f| f.layout_types())
+                    })
+                    .collect(),
+            ),
+            TypedefType::r#type(_) => LayoutTypedef::Variable,
+        }
+    }
+
+    /// This typedef's layout for checking `-z` derives
+    pub fn to_zero_copy_typedef(&self) -> ZeroCopyTypedef {
+        if self.generics.as_ref().map_or(false, |g| !g.is_empty()) {
+            return ZeroCopyTypedef::Invalid("generic typedef".into());
+        }
+        match &self.r#type {
+            TypedefType::r#struct(s) => ZeroCopyTypedef::Struct {
+                fields: match &s.fields {
+                    None => Vec::new(),
+                    Some(DefinedFields::Struct(v)) => v
+                        .iter()
+                        .map(|f| (f.name.clone(), f.r#type.to_zero_copy_type()))
+                        .collect(),
+                    Some(DefinedFields::Tuple(v)) => v
+                        .iter()
+                        .enumerate()
+                        .map(|(i, f)| (i.to_string(), f.0.to_zero_copy_type()))
+                        .collect(),
+                },
+                repr: ZeroCopyRepr {
+                    packed: self.is_packed(),
+                    align: match &self.repr {
+                        Some(TypedefRepr::c(m) | TypedefRepr::rust(m)) => m.align,
+                        _ => None,
+                    },
+                    allow_padding: self.serialization == TypedefSerialization::bytemuckunsafe,
+                },
+            },
+            TypedefType::r#enum(_) => ZeroCopyTypedef::Invalid("enum".into()),
+            TypedefType::r#type(_) => ZeroCopyTypedef::Invalid("type alias".into()),
+        }
+    }
+
     pub fn to_token_stream(&self, cli_args: &crate::Args) -> TokenStream {
+        let json_schema_derive = json_schema_derive(cli_args);
         let name = format_ident!("{}", conditional_pascal_case(&self.name));
+        let generics = self.generics_decl();
+        let docs = doc_attrs(&self.docs);
         // rust enums cannot impl Pod due to illegal bitpatterns
         let typedef_struct = match &self.r#type {
             TypedefType::r#struct(typedef_struct) => typedef_struct,
             TypedefType::r#enum(typedef_enum) => {
+                let typedef_enum = typedef_enum.to_variants_tokens(cli_args);
                 return quote! {
+                    #docs
                     #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
                     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-                    pub enum #name {
+                    #json_schema_derive
+                    pub enum #name #generics {
                         #typedef_enum
                     }
-                }
+                };
+            }
+            TypedefType::r#type(typedef_alias) => {
+                let alias = &typedef_alias.alias;
+                // bounds are not enforced in type aliases
+                let params = self.generics_params();
+                return quote! {
+                    #docs
+                    pub type #name #params = #alias;
+                };
             }
         };
 
-        let derive = if cli_args.zero_copy.iter().any(|e| e == &self.name) {
+        let is_zero_copy = cli_args.zero_copy.iter().any(|e| e == &self.name);
+        let derive = if is_zero_copy {
+            let repr = self.zero_copy_repr_attr();
+            // borsh derives take references to fields, which is not allowed for packed structs
+            let borsh = (!self.is_packed()).then(|| quote! { BorshDeserialize, BorshSerialize, });
+            let pod = (self.serialization != TypedefSerialization::bytemuckunsafe)
+                .then(|| quote! { Pod, Zeroable, });
             quote! {
-                #[repr(C)]
-                #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+                #repr
+                #[derive(Clone, Debug, #borsh PartialEq, #pod Copy)]
             }
         } else {
             quote! {
                 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
             }
         };
-        quote! {
+        let fields = typedef_struct.to_fields_tokens(cli_args);
+        let struct_body = match &typedef_struct.fields {
+            Some(DefinedFields::Tuple(_)) => quote! { #fields; },
+            _ => quote! { { #fields } },
+        };
+        let mut res = quote! {
+            #docs
             #derive
             #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-            pub struct #name {
-                #typedef_struct
+            #json_schema_derive
+            pub struct #name #generics #struct_body
+        };
+        if is_zero_copy && self.serialization == TypedefSerialization::bytemuckunsafe {
+            res.extend(quote! {
+                unsafe impl Pod for #name {}
+                unsafe impl Zeroable for #name {}
+            });
+        }
+        if is_zero_copy && self.is_packed() {
+            // the borsh layout of a packed Pod struct is its in-memory layout on little-endian targets
+            res.extend(quote! {
+                impl BorshSerialize for #name {
+                    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                        writer.write_all(bytemuck::bytes_of(self))
+                    }
+                }
+
+                impl BorshDeserialize for #name {
+                    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                        let mut res = Self::zeroed();
+                        reader.read_exact(bytemuck::bytes_of_mut(&mut res))?;
+                        Ok(res)
+                    }
+                }
+            });
+        }
+        res
+    }
+
+    /// `<T: BorshSerialize + BorshDeserialize, const N: usize>`,
+    /// empty if the type has no generics
+    pub fn generics_decl(&self) -> TokenStream {
+        match &self.generics {
+            Some(generics) if !generics.is_empty() => quote! { <#(#generics),*> },
+            _ => quote! {},
+        }
+    }
+
+    /// `<T, N>`, empty if the type has no generics
+    pub fn generics_params(&self) -> TokenStream {
+        match &self.generics {
+            Some(generics) if !generics.is_empty() => {
+                let params = generics.iter().map(|g| g.param_tokens());
+                quote! { <#(#params),*> }
+            }
+            _ => quote! {},
+        }
+    }
+}
+
+/// How the typedef is serialized in accounts
+#[derive(Deserialize, Default, PartialEq, Eq)]
+pub enum TypedefSerialization {
+    #[default]
+    borsh,
+    bytemuck,
+    /// bytemuck with `Pod` implemented unsafely, which allows padding
+    bytemuckunsafe,
+    custom(String),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind")]
+pub enum TypedefRepr {
+    rust(TypedefReprModifier),
+    c(TypedefReprModifier),
+    transparent,
+}
+
+#[derive(Deserialize)]
+pub struct TypedefReprModifier {
+    #[serde(default)]
+    pub packed: bool,
+    pub align: Option<usize>,
+}
+
+/// Generic parameter of a type definition
+#[derive(Deserialize)]
+#[serde(tag = "kind")]
+pub enum TypedefGeneric {
+    r#type { name: String },
+    r#const { name: String, r#type: String },
+}
+
+impl TypedefGeneric {
+    /// The generic param without bounds e.g. `T`, `N`
+    pub fn param_tokens(&self) -> TokenStream {
+        match self {
+            Self::r#type { name } | Self::r#const { name, .. } => {
+                format_ident!("{}", name).into_token_stream()
             }
         }
     }
 }
 
+impl ToTokens for TypedefGeneric {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let param = self.param_tokens();
+        tokens.extend(match self {
+            Self::r#type { .. } => quote! { #param: BorshSerialize + BorshDeserialize },
+            Self::r#const { r#type, .. } => {
+                let ty: TokenStream = r#type.parse().unwrap();
+                quote! { const #param: #ty }
+            }
+        })
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(tag = "kind")]
 pub enum TypedefType {
     r#struct(TypedefStruct),
     r#enum(TypedefEnum),
+    r#type(TypedefAlias),
 }
 
 #[derive(Deserialize)]
 pub struct TypedefStruct {
-    pub fields: Vec<TypedefField>,
+    pub fields: Option<DefinedFields>,
+}
+
+#[derive(Deserialize)]
+pub struct TypedefAlias {
+    #[serde(deserialize_with = "string_or_struct")]
+    pub alias: TypedefFieldType,
 }
 
 #[derive(Deserialize)]
 pub struct TypedefField {
     pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
     #[serde(deserialize_with = "string_or_struct")]
     pub r#type: TypedefFieldType,
 }
@@ -83,9 +328,13 @@ pub enum TypedefFieldType {
     PrimitiveOrPubkey(String),
 
     // rest handled by string_or_struct's struct
-    defined(String),
+    #[serde(deserialize_with = "string_or_struct")]
+    defined(DefinedType),
     array(TypedefFieldArray),
 
+    /// A generic type param of the containing typedef
+    generic(String),
+
     #[serde(deserialize_with = "string_or_struct")]
     option(Box<TypedefFieldType>),
 
@@ -93,12 +342,68 @@ pub enum TypedefFieldType {
     vec(Box<TypedefFieldType>),
 }
 
+/// Either `"MyType"` (legacy) or `{ "name": "MyType", "generics": [...] }`
+#[derive(Deserialize)]
+pub struct DefinedType {
+    pub name: String,
+    pub generics: Option<Vec<GenericArg>>,
+}
+
+impl FromStr for DefinedType {
+    type Err = Void;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            name: s.into(),
+            generics: None,
+        })
+    }
+}
+
+/// Generic argument used to instantiate a generic typedef
+#[derive(Deserialize)]
+#[serde(tag = "kind")]
+pub enum GenericArg {
+    r#type {
+        #[serde(deserialize_with = "string_or_struct")]
+        r#type: TypedefFieldType,
+    },
+    r#const {
+        value: String,
+    },
+}
+
+impl ToTokens for GenericArg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::r#type { r#type } => r#type.to_tokens(tokens),
+            Self::r#const { value } => tokens.extend(value.parse::<TokenStream>().unwrap()),
+        }
+    }
+}
+
 #[derive(Deserialize)]
 pub struct TypedefFieldArray(
-    #[serde(deserialize_with = "string_or_struct")] Box<TypedefFieldType>,
-    u32, // borsh spec says array sizes are u32
+    #[serde(deserialize_with = "string_or_struct")] pub Box<TypedefFieldType>,
+    pub ArrayLen,
 );
 
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum ArrayLen {
+    Value(u32), // borsh spec says array sizes are u32
+    Generic { generic: String },
+}
+
+impl ToTokens for ArrayLen {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Value(n) => Index::from(*n as usize).to_tokens(tokens),
+            Self::Generic { generic } => format_ident!("{}", generic).to_tokens(tokens),
+        }
+    }
+}
+
 /// serde newtype workaround for use in Vec<TypedefFieldType>:
 /// https://github.com/serde-rs/serde/issues/723#issuecomment-871016087
 #[derive(Deserialize)]
@@ -125,14 +430,23 @@ pub struct TypedefEnum {
     pub variants: Vec<EnumVariant>,
 }
 
+/// Fields of structs and enum variants.
+/// Named for `{ a: u8 }`, Tuple for `(u8)`
 #[derive(Deserialize)]
 #[serde(untagged)]
-pub enum EnumVariantFields {
+pub enum DefinedFields {
     Struct(Vec<TypedefField>),
     Tuple(Vec<TypedefFieldTypeWrap>),
 }
 
-impl EnumVariantFields {
+impl DefinedFields {
+    pub fn layout_types(&self) -> Vec<LayoutType> {
+        match self {
+            Self::Struct(v) => v.iter().map(|f| f.r#type.to_layout_type()).collect(),
+            Self::Tuple(v) => v.iter().map(|f| f.0.to_layout_type()).collect(),
+        }
+    }
+
     pub fn has_pubkey(&self) -> bool {
         match self {
             Self::Struct(v) => v.iter().any(|f| f.r#type.is_or_has_pubkey()),
@@ -151,33 +465,76 @@ impl EnumVariantFields {
 #[derive(Deserialize)]
 pub struct EnumVariant {
     pub name: String,
-    pub fields: Option<EnumVariantFields>,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    pub fields: Option<DefinedFields>,
 }
 
-impl ToTokens for TypedefStruct {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let typedef_fields = self.fields.iter().map(|f| quote! { pub #f });
-        tokens.extend(quote! {
-            #(#typedef_fields),*
-        })
+impl TypedefStruct {
+    pub fn to_fields_tokens(&self, cli_args: &crate::Args) -> TokenStream {
+        match &self.fields {
+            None => quote! {},
+            Some(DefinedFields::Struct(v)) => {
+                let typedef_fields = v.iter().map(|f| f.to_serde_pub_field_tokens(cli_args));
+                quote! {
+                    #(#typedef_fields),*
+                }
+            }
+            Some(DefinedFields::Tuple(v)) => {
+                let unnamed_fields = v.iter().map(|wrap| {
+                    let serde_with = wrap.0.to_serde_as_type().with_attr(cli_args);
+                    let ty = &wrap.0;
+                    quote! { #serde_with pub #ty }
+                });
+                quote! {
+                    ( #(#unnamed_fields),* )
+                }
+            }
+        }
     }
 }
 
 impl ToTokens for TypedefField {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let docs = doc_attrs(&self.docs);
         let name = format_ident!("{}", self.name.to_snake_case());
         let ty = &self.r#type;
         tokens.extend(quote! {
+            #docs
             #name: #ty
         })
     }
 }
 
+impl TypedefField {
+    /// `pub` struct field with the field's docs
+    pub fn to_pub_field_tokens(&self) -> TokenStream {
+        let docs = doc_attrs(&self.docs);
+        let name = format_ident!("{}", self.name.to_snake_case());
+        let ty = &self.r#type;
+        quote! {
+            #docs
+            pub #name: #ty
+        }
+    }
+
+    /// [`Self::to_pub_field_tokens`] with the field's `#[serde(with)]` adapter, if any
+    pub fn to_serde_pub_field_tokens(&self, cli_args: &crate::Args) -> TokenStream {
+        let serde_with = self.r#type.to_serde_as_type().with_attr(cli_args);
+        let field = self.to_pub_field_tokens();
+        quote! {
+            #serde_with
+            #field
+        }
+    }
+}
+
 impl ToTokens for TypedefFieldType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let ty: TokenStream = match self {
             Self::PrimitiveOrPubkey(s) => primitive_or_pubkey_to_token(s).parse().unwrap(),
-            Self::defined(s) => s.parse().unwrap(),
+            Self::defined(d) => d.to_token_stream(),
+            Self::generic(g) => format_ident!("{}", g).into_token_stream(),
             Self::array(a) => a.to_token_stream(),
             Self::vec(v) => quote! {
                 Vec<#v>
@@ -190,48 +547,67 @@ impl ToTokens for TypedefFieldType {
     }
 }
 
+impl ToTokens for DefinedType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name: TokenStream = conditional_pascal_case(&self.name).parse().unwrap();
+        tokens.extend(name);
+        if let Some(generics) = &self.generics {
+            tokens.extend(quote! { <#(#generics),*> });
+        }
+    }
+}
+
 impl ToTokens for TypedefFieldArray {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let ty = &self.0;
-        let n = Index::from(self.1 as usize);
+        let n = &self.1;
         tokens.extend(quote! {
             [#ty; #n]
         })
     }
 }
 
-impl ToTokens for TypedefEnum {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let variants = &self.variants;
-        tokens.extend(quote! {
+impl TypedefEnum {
+    pub fn to_variants_tokens(&self, cli_args: &crate::Args) -> TokenStream {
+        let variants = self.variants.iter().map(|v| v.to_variant_tokens(cli_args));
+        quote! {
             #(#variants),*
-        })
+        }
     }
 }
 
-impl ToTokens for EnumVariant {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
+impl EnumVariant {
+    pub fn to_variant_tokens(&self, cli_args: &crate::Args) -> TokenStream {
+        let docs = doc_attrs(&self.docs);
         let v = format_ident!("{}", self.name.to_pascal_case());
         let maybe_inner_fields = self
             .fields
             .as_ref()
             .map_or(quote! {}, |fields| match fields {
-                EnumVariantFields::Struct(v) => {
-                    let typedef_fields = v.iter();
+                DefinedFields::Struct(v) => {
+                    let typedef_fields = v.iter().map(|f| {
+                        let serde_with = f.r#type.to_serde_as_type().with_attr(cli_args);
+                        quote! { #serde_with #f }
+                    });
                     quote! {
                         { #(#typedef_fields),* }
                     }
                 }
-                EnumVariantFields::Tuple(v) => {
-                    let unnamed_fields = v.iter().map(|wrap| &wrap.0);
+                DefinedFields::Tuple(v) => {
+                    let unnamed_fields = v.iter().map(|wrap| {
+                        let serde_with = wrap.0.to_serde_as_type().with_attr(cli_args);
+                        let ty = &wrap.0;
+                        quote! { #serde_with #ty }
+                    });
                     quote! {
                         ( #(#unnamed_fields),* )
                     }
                 }
             });
-        tokens.extend(quote! {
+        quote! {
+            #docs
             #v #maybe_inner_fields
-        });
+        }
     }
 }
 
@@ -239,32 +615,100 @@ impl TypedefType {
     pub fn has_pubkey_field(&self) -> bool {
         match self {
             Self::r#enum(e) => e.variants.iter().any(|e| e.has_pubkey()),
-            Self::r#struct(s) => s.fields.iter().any(|f| f.r#type.is_or_has_pubkey()),
+            Self::r#struct(s) => s.fields.as_ref().map_or(false, |f| f.has_pubkey()),
+            Self::r#type(a) => a.alias.is_or_has_pubkey(),
         }
     }
 
     pub fn has_defined_field(&self) -> bool {
         match self {
             Self::r#enum(e) => e.variants.iter().any(|e| e.has_defined()),
-            Self::r#struct(s) => s.fields.iter().any(|f| f.r#type.is_or_has_defined()),
+            Self::r#struct(s) => s.fields.as_ref().map_or(false, |f| f.has_defined()),
+            Self::r#type(a) => a.alias.is_or_has_defined(),
         }
     }
 }
 
 impl TypedefFieldType {
+    /// Type of the arg of the `{field}_eq()` account filter fn,
+    /// unsized where borsh serializes it the same way
+    pub fn filter_arg_ty(&self) -> TokenStream {
+        match self {
+            Self::PrimitiveOrPubkey(s) if s == "string" => quote! { &str },
+            Self::vec(v) => quote! { &[#v] },
+            _ => quote! { &#self },
+        }
+    }
+
+    pub fn to_layout_type(&self) -> LayoutType {
+        match self {
+            Self::PrimitiveOrPubkey(s) => LayoutType::primitive(s),
+            Self::array(a) => match &a.1 {
+                ArrayLen::Value(n) => {
+                    LayoutType::Array(Box::new(a.0.to_layout_type()), *n as usize)
+                }
+                ArrayLen::Generic { .. } => LayoutType::Variable,
+            },
+            Self::defined(d) => match &d.generics {
+                Some(g) if !g.is_empty() => LayoutType::Variable,
+                _ => LayoutType::Defined(d.name.clone()),
+            },
+            Self::generic(_) | Self::option(_) | Self::vec(_) => LayoutType::Variable,
+        }
+    }
+
+    pub fn to_serde_as_type(&self) -> SerdeAsType {
+        match self {
+            Self::PrimitiveOrPubkey(s) => SerdeAsType::primitive(s),
+            Self::array(a) => {
+                let len = match &a.1 {
+                    ArrayLen::Value(n) => n.to_string(),
+                    ArrayLen::Generic { generic } => generic.clone(),
+                };
+                SerdeAsType::Array(Box::new(a.0.to_serde_as_type()), len)
+            }
+            Self::option(o) => SerdeAsType::Option(Box::new(o.to_serde_as_type())),
+            Self::vec(v) => SerdeAsType::Vec(Box::new(v.to_serde_as_type())),
+            Self::defined(_) | Self::generic(_) => SerdeAsType::Other,
+        }
+    }
+
+    pub fn to_zero_copy_type(&self) -> ZeroCopyType {
+        match self {
+            Self::PrimitiveOrPubkey(s) => ZeroCopyType::primitive(s),
+            Self::array(a) => match &a.1 {
+                ArrayLen::Value(n) => {
+                    ZeroCopyType::Array(Box::new(a.0.to_zero_copy_type()), *n as usize)
+                }
+                ArrayLen::Generic { .. } => ZeroCopyType::Invalid("generic array length".into()),
+            },
+            Self::defined(d) => match &d.generics {
+                Some(g) if !g.is_empty() => ZeroCopyType::Invalid("generic type".into()),
+                _ => ZeroCopyType::Defined(d.name.clone()),
+            },
+            Self::generic(_) => ZeroCopyType::Invalid("generic type param".into()),
+            Self::option(_) => ZeroCopyType::Invalid("Option".into()),
+            Self::vec(_) => ZeroCopyType::Invalid("Vec".into()),
+        }
+    }
+
     pub fn is_or_has_pubkey(&self) -> bool {
         match self {
             Self::PrimitiveOrPubkey(s) => primitive_or_pubkey_to_token(s) == PUBKEY_TOKEN,
             Self::array(a) => a.0.is_or_has_pubkey(),
             Self::option(o) => o.is_or_has_pubkey(),
             Self::vec(v) => v.is_or_has_pubkey(),
-            Self::defined(_) => false,
+            Self::defined(d) => d.generics.iter().flatten().any(|g| match g {
+                GenericArg::r#type { r#type } => r#type.is_or_has_pubkey(),
+                GenericArg::r#const { .. } => false,
+            }),
+            Self::generic(_) => false,
         }
     }
 
     pub fn is_or_has_defined(&self) -> bool {
         match self {
-            Self::PrimitiveOrPubkey(_) => false,
+            Self::PrimitiveOrPubkey(_) | Self::generic(_) => false,
             Self::array(a) => a.0.is_or_has_defined(),
             Self::option(o) => o.is_or_has_defined(),
             Self::vec(v) => v.is_or_has_defined(),
@@ -288,3 +732,62 @@ impl EnumVariant {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_generic_typedef_to_tokens() {
+        let named_type: NamedType = serde_json::from_str(
+            r#"{
+                "name": "GenericType",
+                "generics": [
+                    { "kind": "type", "name": "T" },
+                    { "kind": "const", "name": "N", "type": "usize" }
+                ],
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "gen1", "type": { "generic": "T" } },
+                        { "name": "arr", "type": { "array": ["u8", { "generic": "N" }] } },
+                        {
+                            "name": "nested",
+                            "type": {
+                                "defined": {
+                                    "name": "Nested",
+                                    "generics": [
+                                        { "kind": "type", "type": "publicKey" },
+                                        { "kind": "const", "value": "10" }
+                                    ]
+                                }
+                            }
+                        },
+                        { "name": "legacy", "type": { "defined": "Legacy" } }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(named_type.r#type.has_pubkey_field());
+
+        let generated_code = named_type.generics_decl().to_string();
+        assert_eq!(
+            generated_code,
+            "< T : BorshSerialize + BorshDeserialize , const N : usize >"
+        );
+
+        let TypedefType::r#struct(s) = &named_type.r#type else {
+            panic!("expected struct");
+        };
+        let generated_code = s
+            .to_fields_tokens(&crate::Args::parse_from(["solores", "idl.json"]))
+            .to_string();
+        assert!(generated_code.contains("pub gen1 : T"));
+        assert!(generated_code.contains("pub arr : [u8 ; N]"));
+        assert!(generated_code.contains("pub nested : Nested < Pubkey , 10 >"));
+        assert!(generated_code.contains("pub legacy : Legacy"));
+    }
+}
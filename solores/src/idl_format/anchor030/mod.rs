@@ -0,0 +1,220 @@
+use heck::ToSnakeCase;
+use serde::Deserialize;
+
+use super::anchor::{
+    accounts::NamedAccount,
+    constants::Constant,
+    errors::ErrorEnumVariant,
+    events::Event,
+    instructions::{InnerAccountStruct, IxAccount, IxAccountEntry, NamedInstruction},
+    typedefs::{NamedType, TypedefField},
+    AnchorIdl,
+};
+
+mod pda;
+pub use pda::*;
+
+/// The IDL format introduced in anchor 0.30, identified by `metadata.spec`.
+///
+/// Differences from the legacy [`super::anchor::legacy::LegacyAnchorIdl`]:
+/// - program address is the top-level `address` field
+/// - instructions, accounts and events come with their `discriminator` bytes
+/// - accounts and events only contain the name and discriminator,
+///   their type definitions are in `types`
+/// - PDA seeds don't come with their types
+#[derive(Deserialize)]
+pub struct Anchor030Idl {
+    pub address: String,
+    pub metadata: Metadata,
+    pub accounts: Option<Vec<DiscriminatedName>>,
+    pub types: Option<Vec<NamedType>>,
+    pub instructions: Option<Vec<Anchor030Instruction>>,
+    pub errors: Option<Vec<ErrorEnumVariant>>,
+    pub events: Option<Vec<DiscriminatedName>>,
+    pub constants: Option<Vec<Constant>>,
+}
+
+#[derive(Deserialize)]
+pub struct Metadata {
+    pub name: String,
+    pub version: String,
+    pub spec: String,
+}
+
+/// Just the `metadata.spec` field, to tell anchor 0.30+ IDLs apart
+/// before parsing the rest of the IDL
+#[derive(Deserialize)]
+pub struct Anchor030Spec {
+    metadata: SpecMetadata,
+}
+
+#[derive(Deserialize)]
+struct SpecMetadata {
+    spec: String,
+}
+
+impl Anchor030Spec {
+    pub fn is_anchor030(&self) -> bool {
+        !self.metadata.spec.is_empty()
+    }
+}
+
+/// An account or event, whose typedef is in the IDL's `types`
+#[derive(Deserialize)]
+pub struct DiscriminatedName {
+    pub name: String,
+    pub discriminator: [u8; 8],
+}
+
+#[derive(Deserialize)]
+pub struct Anchor030Instruction {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    pub discriminator: [u8; 8],
+    pub accounts: Option<Vec<Anchor030IxAccountEntry>>,
+    pub args: Option<Vec<TypedefField>>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum Anchor030IxAccountEntry {
+    Account(Anchor030IxAccount),
+    Struct(Anchor030InnerAccountStruct),
+}
+
+#[derive(Deserialize)]
+pub struct Anchor030InnerAccountStruct {
+    pub name: String,
+    pub accounts: Vec<Anchor030IxAccountEntry>,
+}
+
+#[derive(Deserialize)]
+pub struct Anchor030IxAccount {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    #[serde(default)]
+    pub writable: bool,
+    #[serde(default)]
+    pub signer: bool,
+    #[serde(default)]
+    pub optional: bool,
+    pub address: Option<String>,
+    pub pda: Option<Pda>,
+}
+
+impl Anchor030IxAccountEntry {
+    fn into_ix_account_entry(
+        self,
+        ix_args: &[TypedefField],
+        types: &[NamedType],
+    ) -> IxAccountEntry {
+        match self {
+            Self::Account(Anchor030IxAccount {
+                name,
+                docs,
+                writable,
+                signer,
+                optional,
+                address,
+                pda,
+            }) => IxAccountEntry::Account(IxAccount {
+                pda: pda.and_then(|p| p.resolve(&name.to_snake_case(), ix_args, types)),
+                name,
+                docs,
+                is_mut: writable,
+                is_signer: signer,
+                is_optional: optional,
+                address,
+            }),
+            Self::Struct(Anchor030InnerAccountStruct { name, accounts }) => {
+                IxAccountEntry::Struct(Box::new(InnerAccountStruct {
+                    name,
+                    accounts: accounts
+                        .into_iter()
+                        .map(|a| a.into_ix_account_entry(ix_args, types))
+                        .collect(),
+                }))
+            }
+        }
+    }
+}
+
+impl Anchor030Instruction {
+    /// `types` are all the IDL's typedefs, for resolving the types of PDA seeds
+    fn into_named_instruction(self, types: &[NamedType]) -> NamedInstruction {
+        let Self {
+            name,
+            docs,
+            discriminator,
+            accounts,
+            args,
+        } = self;
+        let ix_args = args.as_deref().unwrap_or_default();
+        let accounts = accounts.map(|v| {
+            v.into_iter()
+                .map(|a| a.into_ix_account_entry(ix_args, types))
+                .collect()
+        });
+        NamedInstruction {
+            name,
+            docs,
+            discriminator,
+            accounts,
+            args,
+        }
+    }
+}
+
+/// Removes the typedef of an account or event from `types`.
+///
+/// Panics if it is missing
+fn take_typedef(types: &mut Vec<NamedType>, name: &str) -> NamedType {
+    match types.iter().position(|t| t.name == name) {
+        Some(i) => types.remove(i),
+        None => {
+            log::error!("Could not find typedef of {} in IDL types", name);
+            panic!();
+        }
+    }
+}
+
+impl From<Anchor030Idl> for AnchorIdl {
+    fn from(idl: Anchor030Idl) -> Self {
+        let mut types = idl.types.unwrap_or_default();
+        let instructions = idl.instructions.map(|v| {
+            v.into_iter()
+                .map(|ix| ix.into_named_instruction(&types))
+                .collect()
+        });
+        // empty accounts and events lists generate no modules, like absent ones
+        let accounts = idl.accounts.filter(|v| !v.is_empty()).map(|v| {
+            v.into_iter()
+                .map(|a| NamedAccount {
+                    typedef: take_typedef(&mut types, &a.name),
+                    discriminator: a.discriminator,
+                })
+                .collect()
+        });
+        let events = idl.events.filter(|v| !v.is_empty()).map(|v| {
+            v.into_iter()
+                .map(|e| Event {
+                    typedef: take_typedef(&mut types, &e.name),
+                    discriminator: e.discriminator,
+                })
+                .collect()
+        });
+        Self {
+            name: idl.metadata.name,
+            version: idl.metadata.version,
+            address: Some(idl.address),
+            accounts,
+            types: (!types.is_empty()).then_some(types),
+            instructions,
+            errors: idl.errors,
+            events,
+            constants: idl.constants,
+        }
+    }
+}
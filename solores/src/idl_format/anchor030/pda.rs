@@ -0,0 +1,143 @@
+use serde::Deserialize;
+
+use crate::idl_format::anchor::{
+    pdas::{unsupported_seed, ResolvedPda, ResolvedSeed, SeedParam, SeedParamType},
+    typedefs::{
+        ArrayLen, DefinedFields, NamedType, TypedefField, TypedefFieldArray, TypedefFieldType,
+        TypedefStruct, TypedefType,
+    },
+};
+
+/// `pda` field of an instruction account
+#[derive(Deserialize)]
+pub struct Pda {
+    pub seeds: Vec<Seed>,
+    pub program: Option<Seed>,
+}
+
+/// Unlike the legacy format, seeds don't come with their types.
+/// These are resolved from the instruction args and IDL types instead.
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum Seed {
+    Const {
+        value: Vec<u8>,
+    },
+    Arg {
+        path: String,
+    },
+    Account {
+        path: String,
+        /// Name of the account's type, present if `path` refers to a field of the account
+        account: Option<String>,
+    },
+}
+
+fn param_type(pda_name: &str, ty: &TypedefFieldType) -> Option<SeedParamType> {
+    match ty {
+        TypedefFieldType::PrimitiveOrPubkey(p) => SeedParamType::primitive(pda_name, p),
+        TypedefFieldType::array(TypedefFieldArray(item, ArrayLen::Value(len))) if matches!(item.as_ref(), TypedefFieldType::PrimitiveOrPubkey(p) if p == "u8") => {
+            Some(SeedParamType::ByteArray(*len as usize))
+        }
+        _ => unsupported_seed(pda_name, "non-primitive type"),
+    }
+}
+
+/// Follows `path`'s field names through the struct typedefs starting from `ty`
+fn field_type<'a>(
+    types: &'a [NamedType],
+    ty: &'a TypedefFieldType,
+    path: &[&str],
+) -> Option<&'a TypedefFieldType> {
+    let (field, rest) = match path.split_first() {
+        None => return Some(ty),
+        Some(split) => split,
+    };
+    let defined = match ty {
+        TypedefFieldType::defined(d) => d,
+        _ => return None,
+    };
+    struct_field_type(types, &defined.name, field, rest)
+}
+
+fn struct_field_type<'a>(
+    types: &'a [NamedType],
+    typedef_name: &str,
+    field: &str,
+    rest: &[&str],
+) -> Option<&'a TypedefFieldType> {
+    let typedef = types.iter().find(|t| t.name == typedef_name)?;
+    let fields = match &typedef.r#type {
+        TypedefType::r#struct(TypedefStruct {
+            fields: Some(DefinedFields::Struct(fields)),
+        }) => fields,
+        _ => return None,
+    };
+    let f = fields.iter().find(|f| f.name == field)?;
+    field_type(types, &f.r#type, rest)
+}
+
+fn resolve_seed(
+    pda_name: &str,
+    seed: &Seed,
+    ix_args: &[TypedefField],
+    types: &[NamedType],
+) -> Option<ResolvedSeed> {
+    let (path, ty) = match seed {
+        Seed::Const { value } => return Some(ResolvedSeed::Const(value.clone())),
+        Seed::Arg { path } => {
+            let segments: Vec<&str> = path.split('.').collect();
+            let ty = ix_args
+                .iter()
+                .find(|a| a.name == segments[0])
+                .and_then(|a| field_type(types, &a.r#type, &segments[1..]));
+            let ty =
+                ty.or_else(|| unsupported_seed(pda_name, &format!("could not resolve {}", path)))?;
+            return Some(ResolvedSeed::Param(SeedParam::arg(
+                path,
+                param_type(pda_name, ty)?,
+            )));
+        }
+        Seed::Account { path, account } => {
+            let segments: Vec<&str> = path.split('.').collect();
+            if segments.len() == 1 {
+                return Some(ResolvedSeed::Param(SeedParam::account(
+                    path,
+                    SeedParamType::Pubkey,
+                )));
+            }
+            let ty = account
+                .as_ref()
+                .and_then(|a| struct_field_type(types, a, segments[1], &segments[2..]));
+            (path, ty)
+        }
+    };
+    let ty = ty.or_else(|| unsupported_seed(pda_name, &format!("could not resolve {}", path)))?;
+    Some(ResolvedSeed::Param(SeedParam::account(
+        path,
+        param_type(pda_name, ty)?,
+    )))
+}
+
+impl Pda {
+    /// `ix_args` are the args of the instruction the account belongs to,
+    /// `types` are all the IDL's typedefs, including those of accounts.
+    /// `None` if any of the seeds are unsupported
+    pub fn resolve(
+        &self,
+        pda_name: &str,
+        ix_args: &[TypedefField],
+        types: &[NamedType],
+    ) -> Option<ResolvedPda> {
+        let seeds = self
+            .seeds
+            .iter()
+            .map(|s| resolve_seed(pda_name, s, ix_args, types))
+            .collect::<Option<_>>()?;
+        let program_id = match &self.program {
+            None => None,
+            Some(s) => Some(resolve_seed(pda_name, s, ix_args, types)?),
+        };
+        ResolvedPda::new(pda_name, seeds, program_id)
+    }
+}
@@ -4,6 +4,8 @@ use quote::{format_ident, quote, ToTokens};
 use serde::Deserialize;
 use syn::LitInt;
 
+use crate::utils::doc_attrs;
+
 #[derive(Deserialize)]
 pub struct ErrorEnumVariant {
     code: u32,
@@ -15,8 +17,10 @@ impl ToTokens for ErrorEnumVariant {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let variant_ident = format_ident!("{}", self.name.to_pascal_case());
         let msg = &self.msg;
+        let docs = doc_attrs(&[msg]);
         let code_literal = LitInt::new(&self.code.to_string(), Span::call_site());
         tokens.extend(quote! {
+            #docs
             #[error(#msg)]
             #variant_ident = #code_literal,
         })
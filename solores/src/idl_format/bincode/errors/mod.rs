@@ -2,7 +2,10 @@ use heck::ToPascalCase;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{
+    error_decoding::{error_decoding_impls, parse_error_from_logs_fns},
+    idl_format::IdlCodegenModule,
+};
 
 mod error;
 pub use error::*;
@@ -21,8 +24,10 @@ impl IdlCodegenModule for ErrorsCodegenModule<'_> {
         quote! {
             use solana_program::{
                 decode_error::DecodeError,
+                instruction::InstructionError,
                 msg,
                 program_error::{PrintProgramError, ProgramError},
+                pubkey::Pubkey,
             };
             use thiserror::Error;
         }
@@ -37,6 +42,8 @@ impl IdlCodegenModule for ErrorsCodegenModule<'_> {
 
         let error_enum_ident_str = format!("{}Error", self.program_name.to_pascal_case());
         let error_enum_ident = format_ident!("{}", &error_enum_ident_str);
+        let error_decoding = error_decoding_impls(&error_enum_ident, false);
+        let parse_error_fns = parse_error_from_logs_fns(&error_enum_ident);
         quote! {
             #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
             pub enum #error_enum_ident {
@@ -67,6 +74,9 @@ impl IdlCodegenModule for ErrorsCodegenModule<'_> {
                     msg!(&self.to_string());
                 }
             }
+
+            #error_decoding
+            #parse_error_fns
         }
     }
 }
@@ -5,16 +5,25 @@ use quote::{format_ident, quote, ToTokens};
 use serde::Deserialize;
 use syn::{LitBool, LitInt};
 
-use crate::{idl_format::bincode::typedefs::TypedefField, utils::unique_by_report_dups};
+use crate::{
+    idl_format::bincode::typedefs::TypedefField,
+    ix_builder::{ix_builder, IxBuilderField},
+    json_schema::json_schema_derive,
+    remaining_accounts::try_from_slice_impls,
+    utils::{doc_attrs, unique_by_report_dups},
+};
 
 #[derive(Deserialize)]
 pub struct NamedInstruction {
     pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
     pub accounts: Option<Vec<IxAccount>>,
     pub args: Option<Vec<TypedefField>>,
 }
 
 pub struct NamedInstructionFull<'a> {
+    pub cli_args: &'a crate::Args,
     pub ix: &'a NamedInstruction,
     pub index: usize,
     pub program_ix_enum_ident: &'a Ident,
@@ -77,7 +86,9 @@ impl<'a> NamedInstructionFull<'a> {
             quote! { &#program_ix_enum_ident::#variant_ident }
         };
 
+        let docs = doc_attrs(&self.ix.docs);
         tokens.extend(quote! {
+            #docs
             pub fn #ix_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> Instruction {
                 #fn_body
                 Instruction::new_with_bincode(
@@ -87,6 +98,7 @@ impl<'a> NamedInstructionFull<'a> {
                 )
             }
 
+            #docs
             pub fn #ix_fn_ident(#fn_params) -> Instruction {
                 #ix_with_program_id_fn_ident(crate::ID, #fn_args)
             }
@@ -185,13 +197,9 @@ impl NamedInstruction {
         let accounts_ident = self.accounts_ident();
         let accounts_fields = unique_accounts.iter().map(|acc| {
             let account_name = format_ident!("{}", &acc.name.to_snake_case());
-            let maybe_doc_comment = acc.desc.as_ref().map_or(quote! {}, |desc| {
-                quote! {
-                    #[doc = #desc]
-                }
-            });
+            let docs = doc_attrs(acc.desc.as_slice());
             quote! {
-                #maybe_doc_comment
+                #docs
                 pub #account_name: &'me AccountInfo<'info>
             }
         });
@@ -210,18 +218,14 @@ impl NamedInstruction {
         let keys_ident = self.keys_ident();
         let keys_fields = unique_accounts.iter().map(|acc| {
             let account_ident = format_ident!("{}", &acc.name.to_snake_case());
-            let maybe_doc_comment = acc.desc.as_ref().map_or(quote! {}, |desc| {
-                quote! {
-                    #[doc = #desc]
-                }
-            });
+            let docs = doc_attrs(acc.desc.as_slice());
             quote! {
-                #maybe_doc_comment
+                #docs
                 pub #account_ident: Pubkey
             }
         });
         tokens.extend(quote! {
-            #[derive(Copy, Clone, Debug)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
             pub struct #keys_ident {
                 #(#keys_fields),*
             }
@@ -361,16 +365,59 @@ impl NamedInstruction {
         });
     }
 
-    pub fn write_ix_args_struct(&self, tokens: &mut TokenStream) {
+    /// TryFrom <&[Pubkey]> for XKeys and TryFrom <&[AccountInfo]> for XAccounts
+    pub fn write_try_from_slices(&self, tokens: &mut TokenStream) {
+        if !self.has_accounts() {
+            return;
+        }
+        tokens.extend(try_from_slice_impls(
+            &self.keys_ident(),
+            &self.accounts_ident(),
+            &self.accounts_len_ident(),
+        ));
+    }
+
+    /// XIxBuilder
+    pub fn write_ix_builder(&self, tokens: &mut TokenStream, accounts: &[&IxAccount]) {
+        let keys_ident = self.keys_ident();
+        let ix_args_ident = self.ix_args_ident();
+        let key_fields: Vec<IxBuilderField> = accounts
+            .iter()
+            .map(|acc| IxBuilderField::account(&acc.name, false, None))
+            .collect();
+        let arg_fields: Vec<IxBuilderField> = self
+            .args
+            .iter()
+            .flatten()
+            .map(|arg| {
+                let ty = &arg.r#type;
+                IxBuilderField::arg(&arg.name, quote! { #ty })
+            })
+            .collect();
+        tokens.extend(ix_builder(
+            &self.name,
+            &self.ix_fn_ident(),
+            false,
+            self.has_accounts()
+                .then_some((&keys_ident, key_fields.as_slice())),
+            self.has_ix_args()
+                .then_some((&ix_args_ident, arg_fields.as_slice())),
+            &[],
+        ));
+    }
+
+    pub fn write_ix_args_struct(&self, tokens: &mut TokenStream, cli_args: &crate::Args) {
         let args = if !self.has_ix_args() {
             return;
         } else {
             self.args.as_ref().unwrap()
         };
         let ix_args_ident = self.ix_args_ident();
-        let args_fields = args.iter().map(|a| quote! { pub #a });
+        let args_fields = args.iter().map(|a| a.to_serde_pub_field_tokens(cli_args));
+        let json_schema_derive = json_schema_derive(cli_args);
         tokens.extend(quote! {
             #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+            #json_schema_derive
             pub struct #ix_args_ident {
                 #(#args_fields),*
             }
@@ -630,8 +677,8 @@ impl NamedInstruction {
     }
 }
 
-impl ToTokens for NamedInstruction {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
+impl NamedInstruction {
+    pub fn write_tokens(&self, tokens: &mut TokenStream, cli_args: &crate::Args) {
         let accounts: &[IxAccount] = self.accounts.as_ref().map_or(&[], |v| v.as_slice());
         let n_accounts = accounts.len();
 
@@ -653,14 +700,16 @@ impl ToTokens for NamedInstruction {
         self.write_from_pubkey_arr_for_keys(tokens, unique_accounts);
         self.write_from_accounts_for_account_info_arr(tokens, accounts);
         self.write_from_account_info_arr_for_accounts(tokens, accounts);
+        self.write_try_from_slices(tokens);
 
-        self.write_ix_args_struct(tokens);
+        self.write_ix_args_struct(tokens, cli_args);
 
         self.write_invoke_fn(tokens);
         self.write_invoke_signed_fn(tokens);
 
         self.write_verify_account_keys_fn(tokens, unique_accounts);
         self.write_verify_account_privileges_fns(tokens, unique_accounts);
+        self.write_ix_builder(tokens, unique_accounts);
     }
 }
 
@@ -668,7 +717,7 @@ impl<'a> ToTokens for NamedInstructionFull<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.write_discm(tokens);
         self.write_ix_fn(tokens);
-        tokens.extend(self.ix.to_token_stream());
+        self.ix.write_tokens(tokens, self.cli_args);
     }
 }
 
@@ -2,12 +2,17 @@ use heck::ToPascalCase;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{
+    idl_format::IdlCodegenModule,
+    json_schema::json_schema_derive,
+    parsed_ix::{parsed_ix_enum, ParsedIxVariant},
+};
 
 mod instruction;
 pub use instruction::*;
 
 pub struct IxCodegenModule<'a> {
+    pub cli_args: &'a crate::Args,
     pub program_name: &'a str,
     pub instructions: &'a [NamedInstruction],
 }
@@ -30,12 +35,13 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
             .iter()
             .map(|ix| ix.has_accounts())
             .any(|b| b);
-        let mut solana_program_imports = if has_accounts {
+        let solana_program_imports = if has_accounts {
             quote! {
                 account_info::AccountInfo,
                 entrypoint::ProgramResult,
                 instruction::{AccountMeta, Instruction},
                 program::{invoke, invoke_signed},
+                program_error::ProgramError,
                 pubkey::Pubkey,
             }
         } else {
@@ -46,16 +52,6 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
                 pubkey::Pubkey,
             }
         };
-        let has_privileged_accounts = self
-            .instructions
-            .iter()
-            .map(|ix| ix.has_privileged_accounts())
-            .any(|b| b);
-        if has_privileged_accounts {
-            solana_program_imports.extend(quote! {
-                program_error::ProgramError,
-            });
-        }
         res.extend(quote! {
             use serde::{Serialize, Deserialize};
             use solana_program::{#solana_program_imports};
@@ -75,9 +71,11 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
         // program ix enum
         let program_ix_enum_ident = self.program_ix_enum_ident();
         let program_ix_enum_variants = self.instructions.iter().map(enum_variant);
+        let json_schema_derive = json_schema_derive(self.cli_args);
 
         res.extend(quote! {
             #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+            #json_schema_derive
             pub enum #program_ix_enum_ident {
                 #(#program_ix_enum_variants),*
             }
@@ -108,18 +106,44 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
 
     fn gen_body(&self) -> TokenStream {
         let program_ix_enum_ident = self.program_ix_enum_ident();
-        self.instructions
+        let mut res: TokenStream = self
+            .instructions
             .iter()
             .enumerate()
             .map(|(i, ix)| {
                 NamedInstructionFull {
+                    cli_args: self.cli_args,
                     ix,
                     index: i,
                     program_ix_enum_ident: &program_ix_enum_ident,
                 }
                 .into_token_stream()
             })
-            .collect()
+            .collect();
+        res.extend(parsed_ix_enum(
+            self.program_name,
+            &self
+                .instructions
+                .iter()
+                .map(parsed_ix_variant)
+                .collect::<Vec<_>>(),
+            Some(quote! {
+                solana_program::program_utils::limited_deserialize::<#program_ix_enum_ident>(
+                    data,
+                    data.len() as u64,
+                )
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
+            }),
+        ));
+        res
+    }
+}
+
+pub fn parsed_ix_variant(ix: &NamedInstruction) -> ParsedIxVariant {
+    ParsedIxVariant {
+        variant_ident: ix.enum_variant_ident(),
+        keys_ident: ix.has_accounts().then(|| ix.keys_ident()),
+        ix_args_ident: ix.has_ix_args().then(|| ix.ix_args_ident()),
     }
 }
 
@@ -1,10 +1,17 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 use toml::{map::Map, Value};
 
+use crate::layout::{Layouts, BINCODE_ENUM_TAG_SIZE};
+use crate::serde_helpers::{
+    has_serde_helpers, serde_helpers_needs_base64, SerdeHelpersCodegenModule,
+};
 use crate::write_cargotoml::{
-    DependencyValue, NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_CRATE, SOLANA_PROGRAM_CRATE,
-    THISERROR_CRATE,
+    insert_schemars_dependency, insert_schemars_feature, DependencyValue, BASE64_CRATE,
+    NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_CRATE, SOLANA_PROGRAM_CRATE, THISERROR_CRATE,
 };
+use crate::zero_copy::ZeroCopyTypedef;
 
 use super::{IdlCodegenModule, IdlFormat};
 
@@ -34,6 +41,18 @@ pub struct Metadata {
     pub origin: String,
 }
 
+impl BincodeIdl {
+    /// Bincode layouts of all typedefs
+    fn layouts(&self) -> Layouts {
+        self.types
+            .iter()
+            .flatten()
+            .map(|t| (t.name.clone(), t.to_layout_typedef()))
+            .collect::<Layouts>()
+            .with_enum_tag_size(BINCODE_ENUM_TAG_SIZE)
+    }
+}
+
 impl IdlFormat for BincodeIdl {
     fn program_name(&self) -> &str {
         &self.name
@@ -51,16 +70,28 @@ impl IdlFormat for BincodeIdl {
         self.metadata.origin == "bincode"
     }
 
+    fn zero_copy_typedefs(&self) -> Option<HashMap<String, ZeroCopyTypedef>> {
+        Some(
+            self.types
+                .iter()
+                .flatten()
+                .map(|t| (t.name.clone(), t.to_zero_copy_typedef()))
+                .collect(),
+        )
+    }
+
     fn modules<'me>(&'me self, args: &'me crate::Args) -> Vec<Box<dyn IdlCodegenModule + 'me>> {
         let mut res: Vec<Box<dyn IdlCodegenModule + 'me>> = Vec::new();
         if let Some(v) = &self.r#types {
             res.push(Box::new(TypedefsCodegenModule {
                 cli_args: args,
                 named_types: v,
+                layouts: self.layouts(),
             }));
         }
         if let Some(v) = &self.instructions {
             res.push(Box::new(IxCodegenModule {
+                cli_args: args,
                 program_name: self.program_name(),
                 instructions: v,
             }));
@@ -71,6 +102,12 @@ impl IdlFormat for BincodeIdl {
                 variants: v,
             }));
         }
+        if has_serde_helpers(args) {
+            res.push(Box::new(SerdeHelpersCodegenModule {
+                cli_args: args,
+                feature: None,
+            }));
+        }
         res
     }
 
@@ -81,6 +118,13 @@ impl IdlFormat for BincodeIdl {
             DependencyValue(&args.solana_program_vers).into(),
         );
         map.insert(SERDE_CRATE.into(), DependencyValue(&args.serde_vers).into());
+        // serde is not optional for bincode IDLs, so neither is base64
+        if serde_helpers_needs_base64(args) {
+            map.insert(
+                BASE64_CRATE.into(),
+                DependencyValue(&args.base64_vers).into(),
+            );
+        }
         if self.errors.is_some() {
             map.insert(
                 THISERROR_CRATE.into(),
@@ -95,6 +139,18 @@ impl IdlFormat for BincodeIdl {
                 DependencyValue(&args.num_traits_vers).into(),
             );
         }
+        if args.schemars {
+            insert_schemars_dependency(&mut map, args);
+        }
+        map
+    }
+
+    fn features(&self, args: &crate::Args) -> Map<String, Value> {
+        let mut map = Map::new();
+        if args.schemars {
+            // serde is not optional for bincode IDLs
+            insert_schemars_feature(&mut map, false);
+        }
         map
     }
 }
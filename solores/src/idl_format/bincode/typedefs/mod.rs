@@ -1,14 +1,15 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 mod typedef;
 pub use typedef::*;
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{idl_format::IdlCodegenModule, layout::Layouts};
 
 pub struct TypedefsCodegenModule<'a> {
     pub cli_args: &'a crate::Args,
     pub named_types: &'a [NamedType],
+    pub layouts: Layouts,
 }
 
 impl IdlCodegenModule for TypedefsCodegenModule<'_> {
@@ -42,7 +43,16 @@ impl IdlCodegenModule for TypedefsCodegenModule<'_> {
     fn gen_body(&self) -> TokenStream {
         self.named_types
             .iter()
-            .map(|e| e.to_token_stream(self.cli_args))
+            .map(|t| {
+                let mut res = t.to_token_stream(self.cli_args);
+                let is_zero_copy = self.cli_args.zero_copy.iter().any(|e| e == &t.name);
+                res.extend(self.layouts.consts(
+                    &t.name,
+                    &format_ident!("{}", t.name),
+                    is_zero_copy,
+                ));
+                res
+            })
             .collect()
     }
 }
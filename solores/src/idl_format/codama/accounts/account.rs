@@ -0,0 +1,89 @@
+use heck::ToShoutySnakeCase;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use serde::Deserialize;
+
+use crate::{
+    idl_format::codama::{
+        discriminators::{resolve_discriminator, DiscriminatorNode},
+        typedefs::{struct_to_token_stream, DefinedTypeNode, StructFieldTypeNode, StructTypeNode},
+    },
+    utils::conditional_pascal_case,
+};
+
+#[derive(Deserialize)]
+pub struct AccountNode {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    pub data: StructTypeNode,
+    #[serde(default)]
+    pub discriminators: Vec<DiscriminatorNode>,
+}
+
+impl AccountNode {
+    pub fn to_token_stream(
+        &self,
+        cli_args: &crate::Args,
+        defined_types: &[DefinedTypeNode],
+    ) -> TokenStream {
+        let name = &self.name;
+        let discm =
+            resolve_discriminator(name, &self.discriminators, &self.data.fields, defined_types);
+        let fields: Vec<&StructFieldTypeNode> = self
+            .data
+            .fields
+            .iter()
+            .filter(|f| discm.as_ref().and_then(|d| d.field) != Some(f.name.as_str()))
+            .collect();
+        let struct_def = struct_to_token_stream(cli_args, name, &self.docs, &fields);
+        // accounts without discriminators are just plain structs
+        let discm = match discm {
+            Some(d) => d,
+            None => return struct_def,
+        };
+
+        let account_discm_ident = format_ident!("{}_ACCOUNT_DISCM", name.to_shouty_snake_case());
+        let discm_len = Literal::usize_unsuffixed(discm.bytes.len());
+        let discm_tokens: TokenStream = format!("{:?}", discm.bytes).parse().unwrap();
+
+        let struct_ident = format_ident!("{}", conditional_pascal_case(name));
+        let account_ident = format_ident!("{}Account", conditional_pascal_case(name));
+        quote! {
+            pub const #account_discm_ident: [u8; #discm_len] = #discm_tokens;
+
+            #struct_def
+
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct #account_ident(pub #struct_ident);
+
+            impl #account_ident {
+                pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+                    use std::io::Read;
+                    let mut reader = buf;
+                    let mut maybe_discm = [0u8; #discm_len];
+                    reader.read_exact(&mut maybe_discm)?;
+                    if maybe_discm != #account_discm_ident {
+                        return Err(
+                            std::io::Error::new(
+                                std::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #account_discm_ident, maybe_discm)
+                            )
+                        );
+                    }
+                    Ok(Self(#struct_ident::deserialize(&mut reader)?))
+                }
+
+                pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+                    writer.write_all(&#account_discm_ident)?;
+                    self.0.serialize(&mut writer)
+                }
+
+                pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+                    let mut data = Vec::new();
+                    self.serialize(&mut data)?;
+                    Ok(data)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::idl_format::IdlCodegenModule;
+
+use super::typedefs::DefinedTypeNode;
+
+mod account;
+pub use account::*;
+
+pub struct AccountsCodegenModule<'a> {
+    pub cli_args: &'a crate::Args,
+    pub accounts: &'a [AccountNode],
+    /// Required to resolve enum discriminators
+    pub defined_types: &'a [DefinedTypeNode],
+}
+
+impl IdlCodegenModule for AccountsCodegenModule<'_> {
+    fn name(&self) -> &str {
+        "accounts"
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        let mut res = quote! {
+            use borsh::{BorshDeserialize, BorshSerialize};
+        };
+        if self
+            .accounts
+            .iter()
+            .any(|a| self.cli_args.zero_copy.iter().any(|e| e == &a.name))
+        {
+            res.extend(quote! {
+                use bytemuck::{Pod, Zeroable};
+            });
+        }
+        let fields = || self.accounts.iter().flat_map(|a| a.data.fields.iter());
+        if fields().any(|f| f.r#type.is_or_has_pubkey()) {
+            res.extend(quote! {
+                use solana_program::pubkey::Pubkey;
+            });
+        }
+        if fields().any(|f| f.r#type.is_or_has_defined() || f.r#type.is_or_has_codec()) {
+            res.extend(quote! {
+                use crate::*;
+            });
+        }
+        res
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        self.accounts
+            .iter()
+            .map(|a| a.to_token_stream(self.cli_args, self.defined_types))
+            .collect()
+    }
+}
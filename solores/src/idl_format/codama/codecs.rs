@@ -0,0 +1,503 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{idl_format::IdlCodegenModule, json_schema::json_schema_derive};
+
+/// Generates the wrapper types required to (de)serialize
+/// codama encodings that are not the same as borsh's,
+/// such as non-u32 length prefixes and fixed-size strings.
+///
+/// Only generated if the IDL uses any of them.
+pub struct CodecsCodegenModule<'a> {
+    pub cli_args: &'a crate::Args,
+}
+
+const LEN_PREFIX_INTS: [&str; 4] = ["u8", "u16", "u32", "u64"];
+
+impl IdlCodegenModule for CodecsCodegenModule<'_> {
+    fn name(&self) -> &str {
+        "codecs"
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        quote! {
+            use borsh::{BorshDeserialize, BorshSerialize};
+            use std::{
+                io::{Read, Write},
+                marker::PhantomData,
+                ops::{Deref, DerefMut},
+            };
+
+            fn invalid_data(msg: &str) -> std::io::Error {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, msg)
+            }
+        }
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        let mut res = quote! {
+            /// Integer types that can be used as the length prefix
+            /// of codama prefixed collections and options
+            pub trait LenPrefix {
+                fn write_len<W: Write>(len: usize, writer: &mut W) -> std::io::Result<()>;
+
+                fn read_len<R: Read>(reader: &mut R) -> std::io::Result<usize>;
+            }
+        };
+        for int in LEN_PREFIX_INTS {
+            // conversions of smaller ints to usize are infallible
+            let len_to_usize = match int {
+                "u8" | "u16" => quote! { Ok(len.into()) },
+                _ => quote! {
+                    usize::try_from(len).map_err(|_| invalid_data("len overflows usize"))
+                },
+            };
+            let int = format_ident!("{}", int);
+            res.extend(quote! {
+                impl LenPrefix for #int {
+                    fn write_len<W: Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+                        let len = #int::try_from(len).map_err(|_| invalid_data("len overflows prefix"))?;
+                        len.serialize(writer)
+                    }
+
+                    fn read_len<R: Read>(reader: &mut R) -> std::io::Result<usize> {
+                        let len = #int::deserialize_reader(reader)?;
+                        #len_to_usize
+                    }
+                }
+            });
+        }
+        let json_schema_derive = json_schema_derive(self.cli_args);
+        res.extend(short_u16(&json_schema_derive));
+        res.extend(prefixed_vec(&json_schema_derive));
+        res.extend(prefixed_string(&json_schema_derive));
+        res.extend(fixed_size_string(&json_schema_derive));
+        res.extend(remainder_vec(&json_schema_derive));
+        res.extend(remainder_string(&json_schema_derive));
+        res.extend(prefixed_option(&json_schema_derive));
+        res.extend(fixed_option(&json_schema_derive));
+        res.extend(zeroable_option(&json_schema_derive));
+        res
+    }
+}
+
+/// Deref, DerefMut and From conversions between `wrapper` and its `inner` type
+fn wrapper_impls(
+    generics: TokenStream,
+    wrapper: TokenStream,
+    inner: TokenStream,
+    construct: TokenStream,
+) -> TokenStream {
+    quote! {
+        impl #generics Deref for #wrapper {
+            type Target = #inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl #generics DerefMut for #wrapper {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl #generics From<#inner> for #wrapper {
+            fn from(inner: #inner) -> Self {
+                #construct
+            }
+        }
+
+        impl #generics From<#wrapper> for #inner {
+            fn from(wrapper: #wrapper) -> Self {
+                wrapper.0
+            }
+        }
+    }
+}
+
+fn short_u16(json_schema_derive: &TokenStream) -> TokenStream {
+    let mut res = quote! {
+        /// u16 encoded in 1-3 bytes, 7 bits per byte with the MSB as the continuation bit
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        #json_schema_derive
+        pub struct ShortU16(pub u16);
+
+        impl BorshSerialize for ShortU16 {
+            fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                let mut n = self.0;
+                loop {
+                    let byte = (n & 0x7f) as u8;
+                    n >>= 7;
+                    if n == 0 {
+                        return writer.write_all(&[byte]);
+                    }
+                    writer.write_all(&[byte | 0x80])?;
+                }
+            }
+        }
+
+        impl BorshDeserialize for ShortU16 {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+                let mut res: u32 = 0;
+                for i in 0..3 {
+                    let byte = u8::deserialize_reader(reader)?;
+                    res |= u32::from(byte & 0x7f) << (i * 7);
+                    if byte & 0x80 == 0 {
+                        return u16::try_from(res)
+                            .map(Self)
+                            .map_err(|_| invalid_data("ShortU16 overflow"));
+                    }
+                }
+                Err(invalid_data("ShortU16 longer than 3 bytes"))
+            }
+        }
+
+        impl LenPrefix for ShortU16 {
+            fn write_len<W: Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+                let len = u16::try_from(len).map_err(|_| invalid_data("len overflows prefix"))?;
+                ShortU16(len).serialize(writer)
+            }
+
+            fn read_len<R: Read>(reader: &mut R) -> std::io::Result<usize> {
+                Ok(ShortU16::deserialize_reader(reader)?.0.into())
+            }
+        }
+    };
+    res.extend(wrapper_impls(
+        quote! {},
+        quote! { ShortU16 },
+        quote! { u16 },
+        quote! { Self(inner) },
+    ));
+    res
+}
+
+fn prefixed_vec(json_schema_derive: &TokenStream) -> TokenStream {
+    let mut res = quote! {
+        /// Vec with a length prefix of type `P`
+        #[derive(Clone, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        #json_schema_derive
+        pub struct PrefixedVec<P, T>(
+            pub Vec<T>,
+            #[cfg_attr(feature = "serde", serde(skip))] PhantomData<P>,
+        );
+
+        impl<P: LenPrefix, T: BorshSerialize> BorshSerialize for PrefixedVec<P, T> {
+            fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                P::write_len(self.0.len(), writer)?;
+                for item in self.0.iter() {
+                    item.serialize(writer)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl<P: LenPrefix, T: BorshDeserialize> BorshDeserialize for PrefixedVec<P, T> {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+                let len = P::read_len(reader)?;
+                let mut res = Vec::new();
+                for _ in 0..len {
+                    res.push(T::deserialize_reader(reader)?);
+                }
+                Ok(Self(res, PhantomData))
+            }
+        }
+    };
+    res.extend(wrapper_impls(
+        quote! { <P, T> },
+        quote! { PrefixedVec<P, T> },
+        quote! { Vec<T> },
+        quote! { Self(inner, PhantomData) },
+    ));
+    res
+}
+
+fn prefixed_string(json_schema_derive: &TokenStream) -> TokenStream {
+    let mut res = quote! {
+        /// utf8 String with a byte length prefix of type `P`
+        #[derive(Clone, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        #json_schema_derive
+        pub struct PrefixedString<P>(
+            pub String,
+            #[cfg_attr(feature = "serde", serde(skip))] PhantomData<P>,
+        );
+
+        impl<P: LenPrefix> BorshSerialize for PrefixedString<P> {
+            fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                P::write_len(self.0.len(), writer)?;
+                writer.write_all(self.0.as_bytes())
+            }
+        }
+
+        impl<P: LenPrefix> BorshDeserialize for PrefixedString<P> {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+                let len = P::read_len(reader)?;
+                let mut bytes = Vec::new();
+                reader.take(len as u64).read_to_end(&mut bytes)?;
+                if bytes.len() != len {
+                    return Err(std::io::ErrorKind::UnexpectedEof.into());
+                }
+                String::from_utf8(bytes)
+                    .map(|s| Self(s, PhantomData))
+                    .map_err(|_| invalid_data("invalid utf8"))
+            }
+        }
+    };
+    res.extend(wrapper_impls(
+        quote! { <P> },
+        quote! { PrefixedString<P> },
+        quote! { String },
+        quote! { Self(inner, PhantomData) },
+    ));
+    res
+}
+
+fn fixed_size_string(json_schema_derive: &TokenStream) -> TokenStream {
+    let mut res = quote! {
+        /// utf8 String that is always serialized to `N` bytes, padded with trailing zeros.
+        ///
+        /// Trailing zeros are stripped on deserialization.
+        #[derive(Clone, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        #json_schema_derive
+        pub struct FixedSizeString<const N: usize>(pub String);
+
+        impl<const N: usize> BorshSerialize for FixedSizeString<N> {
+            fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                let bytes = self.0.as_bytes();
+                if bytes.len() > N {
+                    return Err(invalid_data("string longer than fixed size"));
+                }
+                writer.write_all(bytes)?;
+                writer.write_all(&vec![0u8; N - bytes.len()])
+            }
+        }
+
+        impl<const N: usize> BorshDeserialize for FixedSizeString<N> {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+                let mut bytes = vec![0u8; N];
+                reader.read_exact(&mut bytes)?;
+                let len = bytes.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+                bytes.truncate(len);
+                String::from_utf8(bytes)
+                    .map(Self)
+                    .map_err(|_| invalid_data("invalid utf8"))
+            }
+        }
+    };
+    res.extend(wrapper_impls(
+        quote! { <const N: usize> },
+        quote! { FixedSizeString<N> },
+        quote! { String },
+        quote! { Self(inner) },
+    ));
+    res
+}
+
+fn remainder_vec(json_schema_derive: &TokenStream) -> TokenStream {
+    let mut res = quote! {
+        /// Vec without a length prefix that consumes the rest of the buffer
+        #[derive(Clone, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        #json_schema_derive
+        pub struct RemainderVec<T>(pub Vec<T>);
+
+        impl<T: BorshSerialize> BorshSerialize for RemainderVec<T> {
+            fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                for item in self.0.iter() {
+                    item.serialize(writer)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl<T: BorshDeserialize> BorshDeserialize for RemainderVec<T> {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+                let mut buf = Vec::new();
+                reader.read_to_end(&mut buf)?;
+                let mut remaining = buf.as_slice();
+                let mut res = Vec::new();
+                while !remaining.is_empty() {
+                    res.push(T::deserialize(&mut remaining)?);
+                }
+                Ok(Self(res))
+            }
+        }
+    };
+    res.extend(wrapper_impls(
+        quote! { <T> },
+        quote! { RemainderVec<T> },
+        quote! { Vec<T> },
+        quote! { Self(inner) },
+    ));
+    res
+}
+
+fn remainder_string(json_schema_derive: &TokenStream) -> TokenStream {
+    let mut res = quote! {
+        /// utf8 String without a length prefix that consumes the rest of the buffer
+        #[derive(Clone, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        #json_schema_derive
+        pub struct RemainderString(pub String);
+
+        impl BorshSerialize for RemainderString {
+            fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                writer.write_all(self.0.as_bytes())
+            }
+        }
+
+        impl BorshDeserialize for RemainderString {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+                let mut bytes = Vec::new();
+                reader.read_to_end(&mut bytes)?;
+                String::from_utf8(bytes)
+                    .map(Self)
+                    .map_err(|_| invalid_data("invalid utf8"))
+            }
+        }
+    };
+    res.extend(wrapper_impls(
+        quote! {},
+        quote! { RemainderString },
+        quote! { String },
+        quote! { Self(inner) },
+    ));
+    res
+}
+
+fn prefixed_option(json_schema_derive: &TokenStream) -> TokenStream {
+    let mut res = quote! {
+        /// Option with a 0 (None) or 1 (Some) prefix of type `P`
+        #[derive(Clone, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        #json_schema_derive
+        pub struct PrefixedOption<P, T>(
+            pub Option<T>,
+            #[cfg_attr(feature = "serde", serde(skip))] PhantomData<P>,
+        );
+
+        impl<P: LenPrefix, T: BorshSerialize> BorshSerialize for PrefixedOption<P, T> {
+            fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                match &self.0 {
+                    None => P::write_len(0, writer),
+                    Some(item) => {
+                        P::write_len(1, writer)?;
+                        item.serialize(writer)
+                    }
+                }
+            }
+        }
+
+        impl<P: LenPrefix, T: BorshDeserialize> BorshDeserialize for PrefixedOption<P, T> {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+                match P::read_len(reader)? {
+                    0 => Ok(Self(None, PhantomData)),
+                    1 => Ok(Self(Some(T::deserialize_reader(reader)?), PhantomData)),
+                    _ => Err(invalid_data("invalid option prefix")),
+                }
+            }
+        }
+    };
+    res.extend(wrapper_impls(
+        quote! { <P, T> },
+        quote! { PrefixedOption<P, T> },
+        quote! { Option<T> },
+        quote! { Self(inner, PhantomData) },
+    ));
+    res
+}
+
+fn fixed_option(json_schema_derive: &TokenStream) -> TokenStream {
+    let mut res = quote! {
+        /// Option with a 0 (None) or 1 (Some) prefix of type `P`
+        /// that always takes up the serialized size of `T`.
+        ///
+        /// `T::default()` is serialized in place of None,
+        /// so it should serialize to all zeros.
+        #[derive(Clone, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        #json_schema_derive
+        pub struct FixedOption<P, T>(
+            pub Option<T>,
+            #[cfg_attr(feature = "serde", serde(skip))] PhantomData<P>,
+        );
+
+        impl<P: LenPrefix, T: BorshSerialize + Default> BorshSerialize for FixedOption<P, T> {
+            fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                match &self.0 {
+                    None => {
+                        P::write_len(0, writer)?;
+                        T::default().serialize(writer)
+                    }
+                    Some(item) => {
+                        P::write_len(1, writer)?;
+                        item.serialize(writer)
+                    }
+                }
+            }
+        }
+
+        impl<P: LenPrefix, T: BorshDeserialize> BorshDeserialize for FixedOption<P, T> {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+                let prefix = P::read_len(reader)?;
+                let item = T::deserialize_reader(reader)?;
+                match prefix {
+                    0 => Ok(Self(None, PhantomData)),
+                    1 => Ok(Self(Some(item), PhantomData)),
+                    _ => Err(invalid_data("invalid option prefix")),
+                }
+            }
+        }
+    };
+    res.extend(wrapper_impls(
+        quote! { <P, T> },
+        quote! { FixedOption<P, T> },
+        quote! { Option<T> },
+        quote! { Self(inner, PhantomData) },
+    ));
+    res
+}
+
+fn zeroable_option(json_schema_derive: &TokenStream) -> TokenStream {
+    let mut res = quote! {
+        /// Option without a prefix where None is represented by `T::default()`,
+        /// which should serialize to all zeros
+        #[derive(Clone, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        #json_schema_derive
+        pub struct ZeroableOption<T>(pub Option<T>);
+
+        impl<T: BorshSerialize + Default> BorshSerialize for ZeroableOption<T> {
+            fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                match &self.0 {
+                    None => T::default().serialize(writer),
+                    Some(item) => item.serialize(writer),
+                }
+            }
+        }
+
+        impl<T: BorshDeserialize + Default + PartialEq> BorshDeserialize for ZeroableOption<T> {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+                let item = T::deserialize_reader(reader)?;
+                if item == T::default() {
+                    Ok(Self(None))
+                } else {
+                    Ok(Self(Some(item)))
+                }
+            }
+        }
+    };
+    res.extend(wrapper_impls(
+        quote! { <T> },
+        quote! { ZeroableOption<T> },
+        quote! { Option<T> },
+        quote! { Self(inner) },
+    ));
+    res
+}
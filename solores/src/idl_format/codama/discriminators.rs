@@ -0,0 +1,86 @@
+#![allow(non_camel_case_types)]
+
+use serde::Deserialize;
+
+use super::{
+    typedefs::{DefinedTypeNode, StructFieldTypeNode},
+    values::ValueNode,
+};
+
+#[derive(Deserialize)]
+#[serde(tag = "kind")]
+pub enum DiscriminatorNode {
+    fieldDiscriminatorNode {
+        name: String,
+        #[serde(default)]
+        offset: usize,
+    },
+    constantDiscriminatorNode {
+        constant: ValueNode,
+        #[serde(default)]
+        offset: usize,
+    },
+    sizeDiscriminatorNode {
+        size: usize,
+    },
+}
+
+pub struct Discriminator<'a> {
+    pub bytes: Vec<u8>,
+    /// Name of the field the discriminator is stored in, if any.
+    /// This field is excluded from the generated struct
+    /// since its value is always the discriminator.
+    pub field: Option<&'a str>,
+}
+
+/// Resolves the first field or constant discriminator in `discriminators`.
+///
+/// Only discriminators at offset 0 are supported.
+/// Size discriminators are ignored since they cannot be serialized as a prefix.
+pub fn resolve_discriminator<'a>(
+    item_name: &str,
+    discriminators: &[DiscriminatorNode],
+    fields: &'a [StructFieldTypeNode],
+    defined_types: &[DefinedTypeNode],
+) -> Option<Discriminator<'a>> {
+    discriminators.iter().find_map(|d| match d {
+        DiscriminatorNode::fieldDiscriminatorNode { name, offset } => {
+            assert_zero_offset(item_name, *offset);
+            let field = fields.iter().find(|f| &f.name == name).unwrap_or_else(|| {
+                log::error!("Discriminator field {} not found in {}", name, item_name);
+                panic!();
+            });
+            let value = field.default_value.as_ref().unwrap_or_else(|| {
+                log::error!(
+                    "Discriminator field {} of {} has no default value",
+                    name,
+                    item_name
+                );
+                panic!();
+            });
+            Some(Discriminator {
+                bytes: value.to_bytes(&field.r#type, defined_types),
+                field: Some(&field.name),
+            })
+        }
+        DiscriminatorNode::constantDiscriminatorNode { constant, offset } => {
+            assert_zero_offset(item_name, *offset);
+            Some(Discriminator {
+                bytes: constant.constant_bytes(defined_types),
+                field: None,
+            })
+        }
+        DiscriminatorNode::sizeDiscriminatorNode { .. } => None,
+    })
+}
+
+fn assert_zero_offset(item_name: &str, offset: usize) {
+    if offset != 0 {
+        log::error!(
+            "Discriminator of {} at non-zero offset {} unsupported",
+            item_name,
+            offset
+        );
+        panic!();
+    }
+}
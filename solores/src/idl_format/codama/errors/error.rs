@@ -0,0 +1,34 @@
+use heck::ToPascalCase;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use serde::Deserialize;
+use syn::LitInt;
+
+use crate::utils::doc_attrs;
+
+#[derive(Deserialize)]
+pub struct ErrorNode {
+    code: u32,
+    name: String,
+    message: String,
+    #[serde(default)]
+    docs: Vec<String>,
+}
+
+impl ToTokens for ErrorNode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant_ident = format_ident!("{}", self.name.to_pascal_case());
+        let msg = &self.message;
+        let docs = if self.docs.is_empty() {
+            doc_attrs(&[msg])
+        } else {
+            doc_attrs(&self.docs)
+        };
+        let code_literal = LitInt::new(&self.code.to_string(), Span::call_site());
+        tokens.extend(quote! {
+            #docs
+            #[error(#msg)]
+            #variant_ident = #code_literal,
+        })
+    }
+}
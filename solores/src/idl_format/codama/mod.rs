@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use heck::ToSnakeCase;
+use serde::{Deserialize, Deserializer};
+use toml::{map::Map, Value};
+
+use crate::serde_helpers::{
+    has_serde_helpers, serde_helpers_needs_base64, SerdeHelpersCodegenModule,
+};
+use crate::write_cargotoml::{
+    insert_schemars_dependency, insert_serde_base64_dependency, DependencyValue,
+    FeaturesDependencyValue, OptionalDependencyValue, BORSH_CRATE, BYTEMUCK_CRATE,
+    NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_CRATE, SOLANA_PROGRAM_CRATE, THISERROR_CRATE,
+};
+use crate::zero_copy::ZeroCopyTypedef;
+
+use super::{IdlCodegenModule, IdlFormat};
+
+use self::{
+    accounts::{AccountNode, AccountsCodegenModule},
+    codecs::CodecsCodegenModule,
+    errors::{ErrorNode, ErrorsCodegenModule},
+    instructions::{InstructionNode, IxCodegenModule, NamedInstruction},
+    pdas::{PdaNode, PdasCodegenModule},
+    typedefs::{DefinedTypeNode, TypedefsCodegenModule},
+};
+
+pub mod accounts;
+pub mod codecs;
+pub mod discriminators;
+pub mod errors;
+pub mod instructions;
+pub mod pdas;
+pub mod typedefs;
+pub mod values;
+
+/// A [Codama](https://github.com/codama-idl/codama) IDL, identified by `kind: "rootNode"`.
+///
+/// Only the main `program` node is generated, `additionalPrograms` are ignored.
+#[derive(Deserialize)]
+pub struct CodamaIdl {
+    pub kind: String,
+    pub program: ProgramNode,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgramNode {
+    /// codama names are camelCase, snake_case it so that
+    /// the generated crate name follows that of the other IDL formats
+    #[serde(deserialize_with = "snake_case")]
+    pub name: String,
+    pub public_key: String,
+    pub version: String,
+    #[serde(default)]
+    pub accounts: Vec<AccountNode>,
+    #[serde(default)]
+    pub instructions: Vec<InstructionNode>,
+    #[serde(default)]
+    pub defined_types: Vec<DefinedTypeNode>,
+    #[serde(default)]
+    pub pdas: Vec<PdaNode>,
+    #[serde(default)]
+    pub errors: Vec<ErrorNode>,
+}
+
+fn snake_case<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    String::deserialize(deserializer).map(|s| s.to_snake_case())
+}
+
+impl CodamaIdl {
+    fn uses_codecs(&self) -> bool {
+        let p = &self.program;
+        p.defined_types.iter().any(|t| t.r#type.is_or_has_codec())
+            || p.accounts
+                .iter()
+                .flat_map(|a| a.data.fields.iter())
+                .chain(p.instructions.iter().flat_map(|ix| ix.arguments.iter()))
+                .any(|f| f.r#type.is_or_has_codec())
+    }
+}
+
+impl IdlFormat for CodamaIdl {
+    fn program_name(&self) -> &str {
+        &self.program.name
+    }
+
+    fn program_version(&self) -> &str {
+        &self.program.version
+    }
+
+    fn program_address(&self) -> Option<&str> {
+        Some(&self.program.public_key)
+    }
+
+    fn is_correct_idl_format(&self) -> bool {
+        self.kind == "rootNode"
+    }
+
+    fn zero_copy_typedefs(&self) -> Option<HashMap<String, ZeroCopyTypedef>> {
+        None
+    }
+
+    fn modules<'me>(&'me self, args: &'me crate::Args) -> Vec<Box<dyn IdlCodegenModule + 'me>> {
+        let program = &self.program;
+        let mut res: Vec<Box<dyn IdlCodegenModule + 'me>> = Vec::new();
+        if !program.accounts.is_empty() {
+            res.push(Box::new(AccountsCodegenModule {
+                cli_args: args,
+                accounts: &program.accounts,
+                defined_types: &program.defined_types,
+            }));
+        }
+        if !program.defined_types.is_empty() {
+            res.push(Box::new(TypedefsCodegenModule {
+                cli_args: args,
+                defined_types: &program.defined_types,
+            }));
+        }
+        if !program.instructions.is_empty() {
+            res.push(Box::new(IxCodegenModule {
+                cli_args: args,
+                program_name: self.program_name(),
+                instructions: program
+                    .instructions
+                    .iter()
+                    .map(|ix| NamedInstruction::resolve(ix, &program.defined_types))
+                    .collect(),
+            }));
+        }
+        if !program.errors.is_empty() {
+            res.push(Box::new(ErrorsCodegenModule {
+                program_name: self.program_name(),
+                variants: &program.errors,
+            }));
+        }
+        if !program.pdas.is_empty() {
+            res.push(Box::new(PdasCodegenModule {
+                pdas: &program.pdas,
+                defined_types: &program.defined_types,
+            }));
+        }
+        if self.uses_codecs() {
+            res.push(Box::new(CodecsCodegenModule { cli_args: args }));
+        }
+        if has_serde_helpers(args) {
+            res.push(Box::new(SerdeHelpersCodegenModule {
+                cli_args: args,
+                feature: Some(SERDE_CRATE),
+            }));
+        }
+        res
+    }
+
+    fn dependencies(&self, args: &crate::Args) -> Map<String, Value> {
+        let mut map = Map::new();
+        map.insert(BORSH_CRATE.into(), DependencyValue(&args.borsh_vers).into());
+        if !args.zero_copy.is_empty() {
+            map.insert(
+                BYTEMUCK_CRATE.into(),
+                FeaturesDependencyValue {
+                    dependency: DependencyValue(&args.bytemuck_vers),
+                    features: vec!["derive".into(), "min_const_generics".into()],
+                }
+                .into(),
+            );
+        }
+        map.insert(
+            SOLANA_PROGRAM_CRATE.into(),
+            DependencyValue(&args.solana_program_vers).into(),
+        );
+        map.insert(
+            SERDE_CRATE.into(),
+            OptionalDependencyValue(DependencyValue(&args.serde_vers)).into(),
+        );
+        if serde_helpers_needs_base64(args) {
+            insert_serde_base64_dependency(&mut map, args);
+        }
+        if !self.program.errors.is_empty() {
+            map.insert(
+                THISERROR_CRATE.into(),
+                DependencyValue(&args.thiserror_vers).into(),
+            );
+            map.insert(
+                NUM_DERIVE_CRATE.into(),
+                DependencyValue(&args.num_derive_vers).into(),
+            );
+            map.insert(
+                NUM_TRAITS_CRATE.into(),
+                DependencyValue(&args.num_traits_vers).into(),
+            );
+        }
+        if args.schemars {
+            insert_schemars_dependency(&mut map, args);
+        }
+        map
+    }
+}
@@ -0,0 +1,34 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::idl_format::IdlCodegenModule;
+
+use super::typedefs::DefinedTypeNode;
+
+mod pda;
+pub use pda::*;
+
+pub struct PdasCodegenModule<'a> {
+    pub pdas: &'a [PdaNode],
+    /// Required to encode enum constant seeds
+    pub defined_types: &'a [DefinedTypeNode],
+}
+
+impl IdlCodegenModule for PdasCodegenModule<'_> {
+    fn name(&self) -> &str {
+        "pdas"
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        quote! {
+            use solana_program::pubkey::{Pubkey, PubkeyError};
+        }
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        self.pdas
+            .iter()
+            .map(|p| p.to_token_stream(self.defined_types))
+            .collect()
+    }
+}
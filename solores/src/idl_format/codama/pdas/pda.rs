@@ -0,0 +1,107 @@
+#![allow(non_camel_case_types)]
+
+use heck::ToSnakeCase;
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote};
+use serde::Deserialize;
+use syn::LitByteStr;
+
+use crate::idl_format::codama::{
+    typedefs::{DefinedTypeNode, TypeNode},
+    values::ValueNode,
+};
+
+#[derive(Deserialize)]
+pub struct PdaNode {
+    pub name: String,
+    pub seeds: Vec<PdaSeedNode>,
+    #[serde(rename = "programId")]
+    pub program_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind")]
+pub enum PdaSeedNode {
+    constantPdaSeedNode { r#type: TypeNode, value: ValueNode },
+    variablePdaSeedNode { name: String, r#type: TypeNode },
+}
+
+impl PdaSeedNode {
+    /// fn param for variable seeds, None for constant seeds
+    fn fn_param(&self) -> Option<TokenStream> {
+        let (name, ty) = match self {
+            Self::constantPdaSeedNode { .. } => return None,
+            Self::variablePdaSeedNode { name, r#type } => (name, r#type),
+        };
+        let ident = format_ident!("{}", name.to_snake_case());
+        let ty = match ty {
+            TypeNode::publicKeyTypeNode {} => quote! { &Pubkey },
+            TypeNode::stringTypeNode {} => quote! { &str },
+            TypeNode::bytesTypeNode {} => quote! { &[u8] },
+            TypeNode::numberTypeNode(n) if n.byte_size().is_some() => quote! { #n },
+            _ => {
+                log::error!("Unsupported type for PDA seed {}", name);
+                panic!();
+            }
+        };
+        Some(quote! { #ident: #ty })
+    }
+
+    /// expression that evaluates to the seed's `&[u8]`
+    fn seed_expr(&self, defined_types: &[DefinedTypeNode]) -> TokenStream {
+        match self {
+            Self::constantPdaSeedNode { value, r#type } => match value {
+                ValueNode::programIdValueNode {} => quote! { crate::ID.as_ref() },
+                ValueNode::publicKeyValueNode { publicKey } => {
+                    quote! { solana_program::pubkey!(#publicKey).as_ref() }
+                }
+                _ => {
+                    let bytes = value.to_bytes(r#type, defined_types);
+                    if bytes.iter().all(|b| b.is_ascii_graphic() || *b == b' ') {
+                        let lit = LitByteStr::new(&bytes, Span::call_site());
+                        quote! { #lit }
+                    } else {
+                        let bytes = bytes.into_iter().map(Literal::u8_unsuffixed);
+                        quote! { &[#(#bytes),*] }
+                    }
+                }
+            },
+            Self::variablePdaSeedNode { name, r#type } => {
+                let ident = format_ident!("{}", name.to_snake_case());
+                match r#type {
+                    TypeNode::publicKeyTypeNode {} => quote! { #ident.as_ref() },
+                    TypeNode::stringTypeNode {} => quote! { #ident.as_bytes() },
+                    TypeNode::numberTypeNode(_) => quote! { &#ident.to_le_bytes() },
+                    _ => quote! { #ident },
+                }
+            }
+        }
+    }
+}
+
+impl PdaNode {
+    pub fn to_token_stream(&self, defined_types: &[DefinedTypeNode]) -> TokenStream {
+        let snake_name = self.name.to_snake_case();
+        let find_fn_ident = format_ident!("find_{}_address", snake_name);
+        let create_fn_ident = format_ident!("create_{}_address", snake_name);
+        let fn_params: Vec<TokenStream> = self.seeds.iter().filter_map(|s| s.fn_param()).collect();
+        let seed_exprs: Vec<TokenStream> = self
+            .seeds
+            .iter()
+            .map(|s| s.seed_expr(defined_types))
+            .collect();
+        let program_id = match &self.program_id {
+            Some(id) => quote! { &solana_program::pubkey!(#id) },
+            None => quote! { &crate::ID },
+        };
+        quote! {
+            pub fn #find_fn_ident(#(#fn_params),*) -> (Pubkey, u8) {
+                Pubkey::find_program_address(&[#(#seed_exprs),*], #program_id)
+            }
+
+            pub fn #create_fn_ident(#(#fn_params,)* bump: u8) -> Result<Pubkey, PubkeyError> {
+                Pubkey::create_program_address(&[#(#seed_exprs,)* &[bump]], #program_id)
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::idl_format::IdlCodegenModule;
+
+mod typedef;
+pub use typedef::*;
+
+pub struct TypedefsCodegenModule<'a> {
+    pub cli_args: &'a crate::Args,
+    pub defined_types: &'a [DefinedTypeNode],
+}
+
+impl IdlCodegenModule for TypedefsCodegenModule<'_> {
+    fn name(&self) -> &str {
+        "typedefs"
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        let mut res = quote! {
+            use borsh::{BorshDeserialize, BorshSerialize};
+        };
+        if self
+            .defined_types
+            .iter()
+            .any(|t| self.cli_args.zero_copy.iter().any(|e| e == &t.name))
+        {
+            res.extend(quote! {
+                use bytemuck::{Pod, Zeroable};
+            });
+        }
+        if self
+            .defined_types
+            .iter()
+            .any(|t| t.r#type.is_or_has_pubkey())
+        {
+            res.extend(quote! {
+                use solana_program::pubkey::Pubkey;
+            });
+        }
+        if self
+            .defined_types
+            .iter()
+            .any(|t| t.r#type.is_or_has_codec())
+        {
+            res.extend(quote! {
+                use crate::*;
+            });
+        }
+        res
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        self.defined_types
+            .iter()
+            .map(|e| e.to_token_stream(self.cli_args))
+            .collect()
+    }
+}
@@ -0,0 +1,618 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use heck::{ToPascalCase, ToSnakeCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use serde::Deserialize;
+use syn::Index;
+
+use crate::json_schema::json_schema_derive;
+use crate::serde_helpers::SerdeAsType;
+use crate::utils::{conditional_pascal_case, doc_attrs, PUBKEY_TOKEN};
+
+use super::super::values::ValueNode;
+
+#[derive(Deserialize)]
+pub struct DefinedTypeNode {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    pub r#type: TypeNode,
+}
+
+impl DefinedTypeNode {
+    pub fn struct_ident(&self) -> proc_macro2::Ident {
+        format_ident!("{}", conditional_pascal_case(&self.name))
+    }
+
+    pub fn to_token_stream(&self, cli_args: &crate::Args) -> TokenStream {
+        let name = self.struct_ident();
+        let docs = doc_attrs(&self.docs);
+        // rust enums cannot impl Pod due to illegal bitpatterns
+        let typedef_struct = match &self.r#type {
+            TypeNode::structTypeNode(typedef_struct) => typedef_struct,
+            TypeNode::enumTypeNode(typedef_enum) => {
+                typedef_enum.assert_borsh_compatible(&self.name);
+                let typedef_enum = typedef_enum.to_variants_tokens(cli_args);
+                let json_schema_derive = json_schema_derive(cli_args);
+                return quote! {
+                    #docs
+                    #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+                    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+                    #json_schema_derive
+                    pub enum #name {
+                        #typedef_enum
+                    }
+                };
+            }
+            alias => {
+                return quote! {
+                    #docs
+                    pub type #name = #alias;
+                };
+            }
+        };
+        let fields: Vec<&StructFieldTypeNode> = typedef_struct.fields.iter().collect();
+        struct_to_token_stream(cli_args, &self.name, &self.docs, &fields)
+    }
+}
+
+/// Generates the def of a struct named `name` documented with `docs` with fields `fields`
+pub fn struct_to_token_stream(
+    cli_args: &crate::Args,
+    name: &str,
+    docs: &[String],
+    fields: &[&StructFieldTypeNode],
+) -> TokenStream {
+    let json_schema_derive = json_schema_derive(cli_args);
+    let docs = doc_attrs(docs);
+    let fields = fields.iter().map(|f| f.to_serde_pub_field_tokens(cli_args));
+    let ident = format_ident!("{}", conditional_pascal_case(name));
+    let derive = if cli_args.zero_copy.iter().any(|e| e == name) {
+        quote! {
+            #[repr(C)]
+            #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+        }
+    } else {
+        quote! {
+            #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+        }
+    };
+    quote! {
+        #docs
+        #derive
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #json_schema_derive
+        pub struct #ident {
+            #(#fields),*
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct NumberTypeNode {
+    pub format: String,
+    pub endian: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind")]
+pub enum CountNode {
+    fixedCountNode { value: u32 },
+    prefixedCountNode { prefix: NumberTypeNode },
+    remainderCountNode {},
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind")]
+pub enum TypeNode {
+    numberTypeNode(NumberTypeNode),
+    booleanTypeNode {
+        size: Option<NumberTypeNode>,
+    },
+    publicKeyTypeNode {},
+    stringTypeNode {},
+    bytesTypeNode {},
+    sizePrefixTypeNode {
+        r#type: Box<TypeNode>,
+        prefix: NumberTypeNode,
+    },
+    fixedSizeTypeNode {
+        size: u32,
+        r#type: Box<TypeNode>,
+    },
+    arrayTypeNode {
+        item: Box<TypeNode>,
+        count: CountNode,
+    },
+    setTypeNode {
+        item: Box<TypeNode>,
+        count: CountNode,
+    },
+    mapTypeNode {
+        key: Box<TypeNode>,
+        value: Box<TypeNode>,
+        count: CountNode,
+    },
+    optionTypeNode {
+        item: Box<TypeNode>,
+        prefix: Option<NumberTypeNode>,
+        #[serde(default)]
+        fixed: bool,
+    },
+    zeroableOptionTypeNode {
+        item: Box<TypeNode>,
+    },
+    structTypeNode(StructTypeNode),
+    tupleTypeNode {
+        items: Vec<TypeNode>,
+    },
+    enumTypeNode(EnumTypeNode),
+    definedTypeLinkNode {
+        name: String,
+    },
+    amountTypeNode {
+        number: Box<TypeNode>,
+    },
+    dateTimeTypeNode {
+        number: Box<TypeNode>,
+    },
+    solAmountTypeNode {
+        number: Box<TypeNode>,
+    },
+}
+
+#[derive(Deserialize)]
+pub struct StructTypeNode {
+    pub fields: Vec<StructFieldTypeNode>,
+}
+
+#[derive(Deserialize)]
+pub struct StructFieldTypeNode {
+    pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
+    pub r#type: TypeNode,
+    #[serde(rename = "defaultValue")]
+    pub default_value: Option<ValueNode>,
+}
+
+#[derive(Deserialize)]
+pub struct EnumTypeNode {
+    pub variants: Vec<EnumVariantTypeNode>,
+    pub size: Option<NumberTypeNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind")]
+pub enum EnumVariantTypeNode {
+    enumEmptyVariantTypeNode {
+        name: String,
+        discriminator: Option<u32>,
+    },
+    enumStructVariantTypeNode {
+        name: String,
+        discriminator: Option<u32>,
+        r#struct: StructTypeNode,
+    },
+    enumTupleVariantTypeNode {
+        name: String,
+        discriminator: Option<u32>,
+        tuple: TupleTypeNode,
+    },
+}
+
+/// Newtype for the `tuple` field of `enumTupleVariantTypeNode`
+/// so that non-tuple type nodes are rejected at deserialization
+#[derive(Deserialize)]
+#[serde(tag = "kind")]
+pub enum TupleTypeNode {
+    tupleTypeNode { items: Vec<TypeNode> },
+}
+
+/// Log an error naming the unsupported construct and panic,
+/// since there is no sensible rust type to fallback to
+fn unsupported(what: &str) -> ! {
+    log::error!("Unsupported codama type: {}", what);
+    panic!();
+}
+
+impl NumberTypeNode {
+    pub fn is_u32(&self) -> bool {
+        self.format == "u32"
+    }
+
+    pub fn is_u8(&self) -> bool {
+        self.format == "u8"
+    }
+
+    fn assert_le(&self) {
+        if self.endian.as_deref() == Some("be") {
+            unsupported(&format!("big-endian {}", self.format));
+        }
+    }
+
+    /// Size in bytes of the number when serialized, None if variable
+    pub fn byte_size(&self) -> Option<usize> {
+        Some(match self.format.as_str() {
+            "u8" | "i8" => 1,
+            "u16" | "i16" => 2,
+            "u32" | "i32" | "f32" => 4,
+            "u64" | "i64" | "f64" => 8,
+            "u128" | "i128" => 16,
+            _ => return None,
+        })
+    }
+}
+
+impl ToTokens for NumberTypeNode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.assert_le();
+        let ty = match self.format.as_str() {
+            "shortU16" => format_ident!("ShortU16"),
+            "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128"
+            | "f32" | "f64" => format_ident!("{}", self.format),
+            other => unsupported(&format!("number format {}", other)),
+        };
+        tokens.extend(quote! { #ty });
+    }
+}
+
+impl ToTokens for StructTypeNode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let typedef_fields = self.fields.iter().map(|f| f.to_pub_field_tokens());
+        tokens.extend(quote! {
+            #(#typedef_fields),*
+        })
+    }
+}
+
+impl ToTokens for StructFieldTypeNode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let docs = doc_attrs(&self.docs);
+        let name = format_ident!("{}", self.name.to_snake_case());
+        let ty = &self.r#type;
+        tokens.extend(quote! {
+            #docs
+            #name: #ty
+        })
+    }
+}
+
+impl StructFieldTypeNode {
+    /// `pub` struct field with the field's docs
+    pub fn to_pub_field_tokens(&self) -> TokenStream {
+        let docs = doc_attrs(&self.docs);
+        let name = format_ident!("{}", self.name.to_snake_case());
+        let ty = &self.r#type;
+        quote! {
+            #docs
+            pub #name: #ty
+        }
+    }
+
+    /// [`Self::to_pub_field_tokens`] with the field's `#[serde(with)]` adapter, if any
+    pub fn to_serde_pub_field_tokens(&self, cli_args: &crate::Args) -> TokenStream {
+        let serde_with = self.r#type.to_serde_as_type().with_attr(cli_args);
+        let field = self.to_pub_field_tokens();
+        quote! {
+            #serde_with
+            #field
+        }
+    }
+}
+
+impl ToTokens for TypeNode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty: TokenStream = match self {
+            Self::numberTypeNode(n) => n.to_token_stream(),
+            Self::booleanTypeNode { size } => {
+                if size.as_ref().map_or(false, |s| !s.is_u8()) {
+                    unsupported("booleanTypeNode with non-u8 size");
+                }
+                quote! { bool }
+            }
+            Self::publicKeyTypeNode {} => PUBKEY_TOKEN.parse().unwrap(),
+            // string and bytes without size info consume the rest of the buffer
+            Self::stringTypeNode {} => quote! { RemainderString },
+            Self::bytesTypeNode {} => quote! { RemainderVec<u8> },
+            Self::sizePrefixTypeNode { r#type, prefix } => match r#type.as_ref() {
+                Self::stringTypeNode {} if prefix.is_u32() => quote! { String },
+                Self::bytesTypeNode {} if prefix.is_u32() => quote! { Vec<u8> },
+                Self::stringTypeNode {} => quote! { PrefixedString<#prefix> },
+                Self::bytesTypeNode {} => quote! { PrefixedVec<#prefix, u8> },
+                _ => unsupported("sizePrefixTypeNode of non string or bytes type"),
+            },
+            Self::fixedSizeTypeNode { size, r#type } => {
+                let n = Index::from(*size as usize);
+                match r#type.as_ref() {
+                    Self::stringTypeNode {} => quote! { FixedSizeString<#n> },
+                    Self::bytesTypeNode {} => quote! { [u8; #n] },
+                    _ => unsupported("fixedSizeTypeNode of non string or bytes type"),
+                }
+            }
+            Self::arrayTypeNode { item, count } => match count {
+                CountNode::fixedCountNode { value } => {
+                    let n = Index::from(*value as usize);
+                    quote! { [#item; #n] }
+                }
+                CountNode::prefixedCountNode { prefix } if prefix.is_u32() => {
+                    quote! { Vec<#item> }
+                }
+                CountNode::prefixedCountNode { prefix } => {
+                    quote! { PrefixedVec<#prefix, #item> }
+                }
+                CountNode::remainderCountNode {} => quote! { RemainderVec<#item> },
+            },
+            Self::setTypeNode { item, count } => match count {
+                CountNode::prefixedCountNode { prefix } if prefix.is_u32() => {
+                    quote! { std::collections::HashSet<#item> }
+                }
+                _ => unsupported("setTypeNode without u32 prefix"),
+            },
+            Self::mapTypeNode { key, value, count } => match count {
+                CountNode::prefixedCountNode { prefix } if prefix.is_u32() => {
+                    quote! { std::collections::HashMap<#key, #value> }
+                }
+                _ => unsupported("mapTypeNode without u32 prefix"),
+            },
+            Self::optionTypeNode {
+                item,
+                prefix,
+                fixed,
+            } => match (prefix, fixed) {
+                (None, false) => quote! { Option<#item> },
+                (Some(p), false) if p.is_u8() => quote! { Option<#item> },
+                (Some(p), false) => quote! { PrefixedOption<#p, #item> },
+                (None, true) => quote! { FixedOption<u8, #item> },
+                (Some(p), true) => quote! { FixedOption<#p, #item> },
+            },
+            Self::zeroableOptionTypeNode { item } => quote! { ZeroableOption<#item> },
+            Self::structTypeNode(_) => unsupported("inline structTypeNode"),
+            Self::enumTypeNode(_) => unsupported("inline enumTypeNode"),
+            Self::tupleTypeNode { items } => quote! { (#(#items),*) },
+            Self::definedTypeLinkNode { name } => conditional_pascal_case(name).parse().unwrap(),
+            Self::amountTypeNode { number }
+            | Self::dateTimeTypeNode { number }
+            | Self::solAmountTypeNode { number } => number.to_token_stream(),
+        };
+        tokens.extend(ty);
+    }
+}
+
+impl EnumTypeNode {
+    /// Borsh enums always have a u8 variant index
+    /// that counts up from 0
+    pub fn assert_borsh_compatible(&self, name: &str) {
+        if self.size.as_ref().map_or(false, |s| !s.is_u8()) {
+            unsupported(&format!("enum {} with non-u8 size", name));
+        }
+        let has_explicit_discriminators = self
+            .variants
+            .iter()
+            .enumerate()
+            .any(|(i, v)| v.discriminator().map_or(false, |d| d as usize != i));
+        if has_explicit_discriminators {
+            unsupported(&format!(
+                "enum {} with explicit variant discriminators",
+                name
+            ));
+        }
+    }
+}
+
+impl EnumTypeNode {
+    pub fn to_variants_tokens(&self, cli_args: &crate::Args) -> TokenStream {
+        let variants = self.variants.iter().map(|v| v.to_variant_tokens(cli_args));
+        quote! {
+            #(#variants),*
+        }
+    }
+}
+
+impl EnumVariantTypeNode {
+    pub fn to_variant_tokens(&self, cli_args: &crate::Args) -> TokenStream {
+        let v = format_ident!("{}", self.name().to_pascal_case());
+        let maybe_inner_fields = match self {
+            Self::enumEmptyVariantTypeNode { .. } => quote! {},
+            Self::enumStructVariantTypeNode { r#struct, .. } => {
+                let typedef_fields = r#struct.fields.iter().map(|f| {
+                    let serde_with = f.r#type.to_serde_as_type().with_attr(cli_args);
+                    quote! { #serde_with #f }
+                });
+                quote! {
+                    { #(#typedef_fields),* }
+                }
+            }
+            Self::enumTupleVariantTypeNode { tuple, .. } => {
+                let TupleTypeNode::tupleTypeNode { items } = tuple;
+                let items = items.iter().map(|item| {
+                    let serde_with = item.to_serde_as_type().with_attr(cli_args);
+                    quote! { #serde_with #item }
+                });
+                quote! {
+                    ( #(#items),* )
+                }
+            }
+        };
+        quote! {
+            #v #maybe_inner_fields
+        }
+    }
+}
+
+impl EnumVariantTypeNode {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::enumEmptyVariantTypeNode { name, .. }
+            | Self::enumStructVariantTypeNode { name, .. }
+            | Self::enumTupleVariantTypeNode { name, .. } => name,
+        }
+    }
+
+    pub fn discriminator(&self) -> Option<u32> {
+        match self {
+            Self::enumEmptyVariantTypeNode { discriminator, .. }
+            | Self::enumStructVariantTypeNode { discriminator, .. }
+            | Self::enumTupleVariantTypeNode { discriminator, .. } => *discriminator,
+        }
+    }
+
+    fn for_each_type<'a>(&'a self, f: &mut impl FnMut(&'a TypeNode) -> bool) -> bool {
+        match self {
+            Self::enumEmptyVariantTypeNode { .. } => false,
+            Self::enumStructVariantTypeNode { r#struct, .. } => {
+                r#struct.fields.iter().any(|field| f(&field.r#type))
+            }
+            Self::enumTupleVariantTypeNode { tuple, .. } => {
+                let TupleTypeNode::tupleTypeNode { items } = tuple;
+                items.iter().any(f)
+            }
+        }
+    }
+}
+
+impl TypeNode {
+    /// Returns true if `pred` returns true for this type node or any of its nested type nodes
+    pub fn any(&self, pred: &impl Fn(&TypeNode) -> bool) -> bool {
+        if pred(self) {
+            return true;
+        }
+        match self {
+            Self::sizePrefixTypeNode { r#type, .. } | Self::fixedSizeTypeNode { r#type, .. } => {
+                r#type.any(pred)
+            }
+            Self::arrayTypeNode { item, .. }
+            | Self::setTypeNode { item, .. }
+            | Self::optionTypeNode { item, .. }
+            | Self::zeroableOptionTypeNode { item } => item.any(pred),
+            Self::mapTypeNode { key, value, .. } => key.any(pred) || value.any(pred),
+            Self::structTypeNode(s) => s.fields.iter().any(|f| f.r#type.any(pred)),
+            Self::tupleTypeNode { items } => items.iter().any(|i| i.any(pred)),
+            Self::enumTypeNode(e) => e
+                .variants
+                .iter()
+                .any(|v| v.for_each_type(&mut |t| t.any(pred))),
+            Self::amountTypeNode { number }
+            | Self::dateTimeTypeNode { number }
+            | Self::solAmountTypeNode { number } => number.any(pred),
+            Self::numberTypeNode(_)
+            | Self::booleanTypeNode { .. }
+            | Self::publicKeyTypeNode {}
+            | Self::stringTypeNode {}
+            | Self::bytesTypeNode {}
+            | Self::definedTypeLinkNode { .. } => false,
+        }
+    }
+
+    pub fn to_serde_as_type(&self) -> SerdeAsType {
+        match self {
+            Self::publicKeyTypeNode {} => SerdeAsType::Pubkey,
+            Self::numberTypeNode(n) => SerdeAsType::primitive(&n.format),
+            Self::amountTypeNode { number }
+            | Self::dateTimeTypeNode { number }
+            | Self::solAmountTypeNode { number } => number.to_serde_as_type(),
+            Self::sizePrefixTypeNode { r#type, prefix }
+                if prefix.is_u32() && matches!(r#type.as_ref(), Self::bytesTypeNode {}) =>
+            {
+                SerdeAsType::Vec(Box::new(SerdeAsType::U8))
+            }
+            Self::fixedSizeTypeNode { size, r#type }
+                if matches!(r#type.as_ref(), Self::bytesTypeNode {}) =>
+            {
+                SerdeAsType::Array(Box::new(SerdeAsType::U8), size.to_string())
+            }
+            Self::arrayTypeNode { item, count } => match count {
+                CountNode::fixedCountNode { value } => {
+                    SerdeAsType::Array(Box::new(item.to_serde_as_type()), value.to_string())
+                }
+                CountNode::prefixedCountNode { prefix } if prefix.is_u32() => {
+                    SerdeAsType::Vec(Box::new(item.to_serde_as_type()))
+                }
+                _ => SerdeAsType::Transparent(Box::new(SerdeAsType::Vec(Box::new(
+                    item.to_serde_as_type(),
+                )))),
+            },
+            Self::optionTypeNode {
+                item,
+                prefix,
+                fixed: false,
+            } if prefix.as_ref().map_or(true, |p| p.is_u8()) => {
+                SerdeAsType::Option(Box::new(item.to_serde_as_type()))
+            }
+            Self::optionTypeNode { item, .. } | Self::zeroableOptionTypeNode { item } => {
+                SerdeAsType::Transparent(Box::new(SerdeAsType::Option(Box::new(
+                    item.to_serde_as_type(),
+                ))))
+            }
+            // codama codec wrappers of strings and bytes, tuples, sets, maps and other typedefs
+            _ => SerdeAsType::Other,
+        }
+    }
+
+    /// The underlying number type of number-like type nodes
+    pub fn number_format(&self) -> Option<&NumberTypeNode> {
+        match self {
+            Self::numberTypeNode(n) => Some(n),
+            Self::amountTypeNode { number }
+            | Self::dateTimeTypeNode { number }
+            | Self::solAmountTypeNode { number } => number.number_format(),
+            _ => None,
+        }
+    }
+
+    pub fn is_or_has_pubkey(&self) -> bool {
+        self.any(&|t| matches!(t, Self::publicKeyTypeNode {}))
+    }
+
+    pub fn is_or_has_defined(&self) -> bool {
+        self.any(&|t| matches!(t, Self::definedTypeLinkNode { .. }))
+    }
+
+    /// Whether the type requires one of the types in the generated `codecs` module
+    /// to represent codama encodings that differ from borsh's.
+    ///
+    /// Cannot use [`Self::any`] because the `stringTypeNode`s/`bytesTypeNode`s
+    /// wrapped by `sizePrefixTypeNode`s and `fixedSizeTypeNode`s don't require a codec.
+    pub fn is_or_has_codec(&self) -> bool {
+        match self {
+            Self::sizePrefixTypeNode { prefix, .. } => !prefix.is_u32(),
+            Self::fixedSizeTypeNode { r#type, .. } => {
+                matches!(r#type.as_ref(), Self::stringTypeNode {})
+            }
+            Self::arrayTypeNode { item, count } => {
+                let count_requires_codec = match count {
+                    CountNode::fixedCountNode { .. } => false,
+                    CountNode::prefixedCountNode { prefix } => !prefix.is_u32(),
+                    CountNode::remainderCountNode {} => true,
+                };
+                count_requires_codec || item.is_or_has_codec()
+            }
+            Self::setTypeNode { item, .. } => item.is_or_has_codec(),
+            Self::mapTypeNode { key, value, .. } => {
+                key.is_or_has_codec() || value.is_or_has_codec()
+            }
+            Self::optionTypeNode {
+                item,
+                prefix,
+                fixed,
+            } => *fixed || prefix.as_ref().map_or(false, |p| !p.is_u8()) || item.is_or_has_codec(),
+            Self::structTypeNode(s) => s.fields.iter().any(|f| f.r#type.is_or_has_codec()),
+            Self::tupleTypeNode { items } => items.iter().any(|i| i.is_or_has_codec()),
+            Self::enumTypeNode(e) => e
+                .variants
+                .iter()
+                .any(|v| v.for_each_type(&mut |t| t.is_or_has_codec())),
+            Self::numberTypeNode(n) => n.format == "shortU16",
+            Self::stringTypeNode {}
+            | Self::bytesTypeNode {}
+            | Self::zeroableOptionTypeNode { .. } => true,
+            Self::amountTypeNode { number }
+            | Self::dateTimeTypeNode { number }
+            | Self::solAmountTypeNode { number } => number.is_or_has_codec(),
+            Self::booleanTypeNode { .. }
+            | Self::publicKeyTypeNode {}
+            | Self::definedTypeLinkNode { .. } => false,
+        }
+    }
+}
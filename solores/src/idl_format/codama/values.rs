@@ -0,0 +1,220 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use base64::Engine;
+use serde::Deserialize;
+
+use super::typedefs::{DefinedTypeNode, NumberTypeNode, TypeNode};
+
+#[derive(Deserialize)]
+pub struct DefinedTypeLinkNode {
+    pub name: String,
+}
+
+/// Only the value nodes that can be encoded to constant bytes are modelled,
+/// everything else (e.g. contextual value nodes used as instruction account defaults)
+/// deserializes to [`ValueNode::other`]
+#[derive(Deserialize)]
+#[serde(tag = "kind")]
+pub enum ValueNode {
+    numberValueNode {
+        number: serde_json::Number,
+    },
+    bytesValueNode {
+        data: String,
+        encoding: String,
+    },
+    stringValueNode {
+        string: String,
+    },
+    booleanValueNode {
+        boolean: bool,
+    },
+    publicKeyValueNode {
+        publicKey: String,
+    },
+    enumValueNode {
+        r#enum: DefinedTypeLinkNode,
+        variant: String,
+    },
+    constantValueNode {
+        r#type: TypeNode,
+        value: Box<ValueNode>,
+    },
+    programIdValueNode {},
+    #[serde(other)]
+    other,
+}
+
+fn unsupported(what: &str) -> ! {
+    log::error!("Unsupported codama value: {}", what);
+    panic!();
+}
+
+impl ValueNode {
+    /// Encodes this value as type `ty`.
+    ///
+    /// `defined_types` is required to resolve the variant index of enum values
+    pub fn to_bytes(&self, ty: &TypeNode, defined_types: &[DefinedTypeNode]) -> Vec<u8> {
+        match (self, ty) {
+            (Self::constantValueNode { .. }, _) => self.constant_bytes(defined_types),
+            (Self::numberValueNode { number }, ty) => match ty.number_format() {
+                Some(format) => encode_number(format, number),
+                None => unsupported("number value of non number type"),
+            },
+            (Self::booleanValueNode { boolean }, TypeNode::booleanTypeNode { .. }) => {
+                vec![u8::from(*boolean)]
+            }
+            (Self::publicKeyValueNode { publicKey }, TypeNode::publicKeyTypeNode {}) => {
+                decode_pubkey(publicKey).to_vec()
+            }
+            (Self::bytesValueNode { .. } | Self::stringValueNode { .. }, ty) => {
+                let raw = self.raw_bytes();
+                match ty {
+                    TypeNode::stringTypeNode {} | TypeNode::bytesTypeNode {} => raw,
+                    TypeNode::sizePrefixTypeNode { prefix, .. } => {
+                        let mut res = encode_number(prefix, &raw.len().into());
+                        res.extend(raw);
+                        res
+                    }
+                    TypeNode::fixedSizeTypeNode { size, .. } => {
+                        let mut res = raw;
+                        res.resize(*size as usize, 0);
+                        res
+                    }
+                    _ => unsupported("string or bytes value of non string or bytes type"),
+                }
+            }
+            (Self::enumValueNode { r#enum, variant }, TypeNode::definedTypeLinkNode { name })
+                if &r#enum.name == name =>
+            {
+                let enum_ty = defined_types
+                    .iter()
+                    .find_map(|t| match &t.r#type {
+                        TypeNode::enumTypeNode(e) if &t.name == name => Some(e),
+                        _ => None,
+                    })
+                    .unwrap_or_else(|| unsupported(&format!("enum {} not found", name)));
+                let index = enum_ty
+                    .variants
+                    .iter()
+                    .position(|v| v.name() == variant)
+                    .unwrap_or_else(|| {
+                        unsupported(&format!("variant {} of enum {} not found", variant, name))
+                    });
+                vec![index.try_into().unwrap()]
+            }
+            _ => unsupported("value cannot be encoded to constant bytes"),
+        }
+    }
+
+    /// Encodes a `constantValueNode` as its own type
+    pub fn constant_bytes(&self, defined_types: &[DefinedTypeNode]) -> Vec<u8> {
+        match self {
+            Self::constantValueNode { r#type, value } => value.to_bytes(r#type, defined_types),
+            _ => unsupported("expected constant value"),
+        }
+    }
+
+    /// The bytes of a `bytesValueNode` or the utf8 bytes of a `stringValueNode`
+    pub fn raw_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::stringValueNode { string } => string.as_bytes().to_vec(),
+            Self::bytesValueNode { data, encoding } => match encoding.as_str() {
+                "utf8" => data.as_bytes().to_vec(),
+                "base58" => bs58::decode(data).into_vec().unwrap(),
+                "base64" => base64::engine::general_purpose::STANDARD
+                    .decode(data)
+                    .unwrap(),
+                "base16" => (0..data.len())
+                    .step_by(2)
+                    .map(|i| u8::from_str_radix(&data[i..i + 2], 16).unwrap())
+                    .collect(),
+                other => unsupported(&format!("bytes encoding {}", other)),
+            },
+            _ => unsupported("expected string or bytes value"),
+        }
+    }
+}
+
+pub fn decode_pubkey(s: &str) -> [u8; 32] {
+    bs58::decode(s)
+        .into_vec()
+        .ok()
+        .and_then(|v| v.try_into().ok())
+        .unwrap_or_else(|| unsupported(&format!("invalid public key {}", s)))
+}
+
+/// Little-endian encoding of `number` as `format`
+pub fn encode_number(format: &NumberTypeNode, number: &serde_json::Number) -> Vec<u8> {
+    if format.format == "shortU16" {
+        let mut n: u16 = number.as_u64().unwrap().try_into().unwrap();
+        let mut res = Vec::new();
+        loop {
+            let byte = (n & 0x7f) as u8;
+            n >>= 7;
+            if n == 0 {
+                res.push(byte);
+                return res;
+            }
+            res.push(byte | 0x80);
+        }
+    }
+    let size = format
+        .byte_size()
+        .unwrap_or_else(|| unsupported(&format!("number format {}", format.format)));
+    let bytes = match (number.as_u64(), number.as_i64()) {
+        (Some(n), _) => u128::from(n).to_le_bytes(),
+        (None, Some(n)) => i128::from(n).to_le_bytes(),
+        _ => unsupported(&format!("non-integer constant {}", number)),
+    };
+    bytes[..size].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number_type(format: &str) -> NumberTypeNode {
+        NumberTypeNode {
+            format: format.to_owned(),
+            endian: None,
+        }
+    }
+
+    #[test]
+    fn test_encode_number() {
+        assert_eq!(encode_number(&number_type("u8"), &3.into()), vec![3]);
+        assert_eq!(
+            encode_number(&number_type("u32"), &258.into()),
+            vec![2, 1, 0, 0]
+        );
+        assert_eq!(
+            encode_number(&number_type("i16"), &(-2).into()),
+            vec![254, 255]
+        );
+        assert_eq!(
+            encode_number(&number_type("shortU16"), &127.into()),
+            vec![127]
+        );
+        assert_eq!(
+            encode_number(&number_type("shortU16"), &16384.into()),
+            vec![0x80, 0x80, 0x01]
+        );
+    }
+
+    #[test]
+    fn test_bytes_value_encodings() {
+        let expected = vec![0xde, 0xad, 0xbe, 0xef];
+        for (data, encoding) in [
+            ("deadbeef", "base16"),
+            ("6h8cQN", "base58"),
+            ("3q2+7w==", "base64"),
+        ] {
+            let value = ValueNode::bytesValueNode {
+                data: data.to_owned(),
+                encoding: encoding.to_owned(),
+            };
+            assert_eq!(value.raw_bytes(), expected);
+        }
+    }
+}
@@ -1,16 +1,31 @@
 #![doc = include_str!("./README.md")]
 
+use std::collections::HashMap;
+
 use proc_macro2::TokenStream;
 use toml::{map::Map, Value};
 
+use crate::{
+    serde_helpers::serde_helpers_needs_base64,
+    write_cargotoml::{insert_schemars_feature, insert_serde_base64_feature},
+    zero_copy::ZeroCopyTypedef,
+};
+
 pub mod anchor;
+pub mod anchor030;
 pub mod bincode;
+pub mod codama;
 pub mod shank;
 
 pub trait IdlCodegenModule {
     /// The module file's name e.g. "errors"
     fn name(&self) -> &str;
 
+    /// The cargo feature the module is gated behind, if any
+    fn feature(&self) -> Option<&str> {
+        None
+    }
+
     /// Generate the headers to prefix the module file with.
     /// Typically import statements
     fn gen_head(&self) -> TokenStream;
@@ -30,5 +45,27 @@ pub trait IdlFormat {
 
     fn dependencies(&self, args: &crate::Args) -> Map<String, Value>;
 
+    /// The generated crate's `[features]`, besides those implied by optional dependencies
+    fn features(&self, args: &crate::Args) -> Map<String, Value> {
+        let mut map = Map::new();
+        if serde_helpers_needs_base64(args) {
+            insert_serde_base64_feature(&mut map);
+        }
+        if args.schemars {
+            insert_schemars_feature(&mut map, true);
+        }
+        map
+    }
+
+    /// The layouts of the IDL's typedefs and accounts by name for checking `-z` derives.
+    /// `None` if the IDL format does not support the check
+    fn zero_copy_typedefs(&self) -> Option<HashMap<String, ZeroCopyTypedef>>;
+
+    /// Names of the typedefs and accounts that the IDL itself marks as zero-copy,
+    /// which are treated as if they were passed via `-z`
+    fn zero_copy_annotated(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
     fn modules<'me>(&'me self, args: &'me crate::Args) -> Vec<Box<dyn IdlCodegenModule + 'me>>;
 }
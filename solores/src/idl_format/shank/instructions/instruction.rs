@@ -1,18 +1,23 @@
 use heck::{ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
 use itertools::Itertools;
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::{format_ident, quote, ToTokens};
+use quote::{format_ident, quote};
 use serde::Deserialize;
 use syn::{LitBool, LitInt};
 
 use crate::{
     idl_format::shank::typedefs::TypedefField,
-    utils::{unique_by_report_dups, UniqueByReportDupsResult},
+    ix_builder::{ix_builder, IxBuilderField},
+    json_schema::json_schema_derive,
+    remaining_accounts::try_from_slice_impls,
+    utils::{doc_attrs, unique_by_report_dups, UniqueByReportDupsResult},
 };
 
 #[derive(Deserialize)]
 pub struct NamedInstruction {
     pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
     pub accounts: Option<Vec<IxAccount>>,
     pub args: Option<Vec<TypedefField>>,
     pub discriminant: Discriminant,
@@ -94,6 +99,10 @@ impl NamedInstruction {
         accounts.iter().map(|a| a.is_privileged()).any(|b| b)
     }
 
+    pub fn has_optional_accounts(&self) -> bool {
+        self.accounts.iter().flatten().any(|a| a.optional)
+    }
+
     /// export accounts_len as const
     pub fn write_accounts_len(&self, tokens: &mut TokenStream, accounts_len: usize) {
         if !self.has_accounts() {
@@ -113,14 +122,15 @@ impl NamedInstruction {
         let accounts_ident = self.accounts_ident();
         let accounts_fields = accounts.iter().map(|acc| {
             let account_name = format_ident!("{}", &acc.name.to_snake_case());
-            let maybe_doc_comment = acc.desc.as_ref().map_or(quote! {}, |desc| {
-                quote! {
-                    #[doc = #desc]
-                }
-            });
+            let docs = doc_attrs(acc.desc.as_slice());
+            let ty = if acc.optional {
+                quote! { Option<&'me AccountInfo<'info>> }
+            } else {
+                quote! { &'me AccountInfo<'info> }
+            };
             quote! {
-                #maybe_doc_comment
-                pub #account_name: &'me AccountInfo<'info>
+                #docs
+                pub #account_name: #ty
             }
         });
         tokens.extend(quote! {
@@ -138,18 +148,19 @@ impl NamedInstruction {
         let keys_ident = self.keys_ident();
         let keys_fields = accounts.iter().map(|acc| {
             let account_ident = format_ident!("{}", &acc.name.to_snake_case());
-            let maybe_doc_comment = acc.desc.as_ref().map_or(quote! {}, |desc| {
-                quote! {
-                    #[doc = #desc]
-                }
-            });
+            let docs = doc_attrs(acc.desc.as_slice());
+            let ty = if acc.optional {
+                quote! { Option<Pubkey> }
+            } else {
+                quote! { Pubkey }
+            };
             quote! {
-                #maybe_doc_comment
-                pub #account_ident: Pubkey
+                #docs
+                pub #account_ident: #ty
             }
         });
         tokens.extend(quote! {
-            #[derive(Copy, Clone, Debug)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
             pub struct #keys_ident {
                 #(#keys_fields),*
             }
@@ -165,8 +176,14 @@ impl NamedInstruction {
         let keys_ident = self.keys_ident();
         let from_keys_fields = accounts.iter().map(|acc| {
             let account_ident = format_ident!("{}", &acc.name.to_snake_case());
-            quote! {
-                #account_ident: *accounts.#account_ident.key
+            if acc.optional {
+                quote! {
+                    #account_ident: accounts.#account_ident.map(|a| *a.key)
+                }
+            } else {
+                quote! {
+                    #account_ident: *accounts.#account_ident.key
+                }
             }
         });
         tokens.extend(quote! {
@@ -181,13 +198,42 @@ impl NamedInstruction {
     }
 
     /// From <&XKeys> for [AccountMeta]
+    ///
+    /// and XKeys::to_metas() if there are optional accounts,
+    /// since absent accounts are replaced with the invoked program's ID
     pub fn write_from_keys_for_meta_arr(&self, tokens: &mut TokenStream, accounts: &[IxAccount]) {
         if !self.has_accounts() {
             return;
         }
         let keys_ident = self.keys_ident();
         let accounts_len_ident = self.accounts_len_ident();
-        let from_keys_meta = accounts.iter().map(|acc| acc.to_keys_account_meta_tokens());
+        if self.has_optional_accounts() {
+            let to_metas = accounts
+                .iter()
+                .map(|acc| acc.to_keys_account_meta_tokens(&quote! { self }));
+            tokens.extend(quote! {
+                impl #keys_ident {
+                    /// Absent optional accounts are replaced with `program_id`,
+                    /// the ID of the program being invoked
+                    pub fn to_metas(self, program_id: Pubkey) -> [AccountMeta; #accounts_len_ident] {
+                        [
+                            #(#to_metas),*
+                        ]
+                    }
+                }
+
+                impl From<#keys_ident> for [AccountMeta; #accounts_len_ident] {
+                    /// Absent optional accounts are replaced with `crate::ID`
+                    fn from(keys: #keys_ident) -> Self {
+                        keys.to_metas(crate::ID)
+                    }
+                }
+            });
+            return;
+        }
+        let from_keys_meta = accounts
+            .iter()
+            .map(|acc| acc.to_keys_account_meta_tokens(&quote! { keys }));
         tokens.extend(quote! {
             impl From<#keys_ident> for [AccountMeta; #accounts_len_ident] {
                 fn from(keys: #keys_ident) -> Self {
@@ -200,6 +246,9 @@ impl NamedInstruction {
     }
 
     /// From <[Pubkey]> for XKeys
+    ///
+    /// and From <(Pubkey, [Pubkey])> if there are optional accounts,
+    /// which takes the ID of the invoked program absent accounts are set to
     pub fn write_from_pubkey_arr_for_keys(&self, tokens: &mut TokenStream, accounts: &[IxAccount]) {
         if !self.has_accounts() {
             return;
@@ -209,10 +258,37 @@ impl NamedInstruction {
         let from_pubkey_arr_fields = accounts.iter().enumerate().map(|(i, acc)| {
             let account_ident = format_ident!("{}", &acc.name.to_snake_case());
             let index_lit = LitInt::new(&i.to_string(), Span::call_site());
-            quote! {
-                #account_ident: pubkeys[#index_lit]
+            if acc.optional {
+                quote! {
+                    #account_ident: Some(pubkeys[#index_lit]).filter(|k| *k != program_id)
+                }
+            } else {
+                quote! {
+                    #account_ident: pubkeys[#index_lit]
+                }
             }
         });
+        if self.has_optional_accounts() {
+            tokens.extend(quote! {
+                impl From<(Pubkey, [Pubkey; #accounts_len_ident])> for #keys_ident {
+                    /// Keys equal to `program_id`, the ID of the invoked program,
+                    /// are absent optional accounts
+                    fn from((program_id, pubkeys): (Pubkey, [Pubkey; #accounts_len_ident])) -> Self {
+                        Self {
+                            #(#from_pubkey_arr_fields),*
+                        }
+                    }
+                }
+
+                impl From<[Pubkey; #accounts_len_ident]> for #keys_ident {
+                    /// Keys equal to `crate::ID` are absent optional accounts
+                    fn from(pubkeys: [Pubkey; #accounts_len_ident]) -> Self {
+                        Self::from((crate::ID, pubkeys))
+                    }
+                }
+            });
+            return;
+        }
         tokens.extend(quote! {
             impl From<[Pubkey; #accounts_len_ident]> for #keys_ident {
                 fn from(pubkeys: [Pubkey; #accounts_len_ident]) -> Self {
@@ -225,6 +301,9 @@ impl NamedInstruction {
     }
 
     /// From <XAccounts> for [AccountInfo]
+    ///
+    /// or XAccounts::to_account_infos() if there are optional accounts,
+    /// since absent accounts are replaced with the program's AccountInfo
     pub fn write_from_accounts_for_account_info_arr(
         &self,
         tokens: &mut TokenStream,
@@ -235,6 +314,28 @@ impl NamedInstruction {
         }
         let accounts_ident = self.accounts_ident();
         let accounts_len_ident = self.accounts_len_ident();
+        if self.has_optional_accounts() {
+            let account_info_clone = accounts.iter().map(|acc| {
+                let account_ident = acc.field_ident();
+                if acc.optional {
+                    quote! { self.#account_ident.unwrap_or(program).clone() }
+                } else {
+                    quote! { self.#account_ident.clone() }
+                }
+            });
+            tokens.extend(quote! {
+                impl<'info> #accounts_ident<'_, 'info> {
+                    /// Absent optional accounts are replaced with `program`,
+                    /// the AccountInfo of the program being invoked
+                    pub fn to_account_infos(self, program: &AccountInfo<'info>) -> [AccountInfo<'info>; #accounts_len_ident] {
+                        [
+                            #(#account_info_clone),*
+                        ]
+                    }
+                }
+            });
+            return;
+        }
         let account_info_clone = accounts.iter().map(|acc| {
             let account_ident = format_ident!("{}", &acc.name.to_snake_case());
             quote! {
@@ -253,6 +354,9 @@ impl NamedInstruction {
     }
 
     /// From <&[AccountInfo]> for XAccounts
+    ///
+    /// and From <(Pubkey, &[AccountInfo])> if there are optional accounts,
+    /// which takes the ID of the invoked program absent accounts are set to
     pub fn write_from_account_info_arr_for_accounts(
         &self,
         tokens: &mut TokenStream,
@@ -266,10 +370,37 @@ impl NamedInstruction {
         let from_account_info_fields = accounts.iter().enumerate().map(|(i, acc)| {
             let account_ident = format_ident!("{}", &acc.name.to_snake_case());
             let index_lit = LitInt::new(&i.to_string(), Span::call_site());
-            quote! {
-               #account_ident: &arr[#index_lit]
+            if acc.optional {
+                quote! {
+                    #account_ident: Some(&arr[#index_lit]).filter(|a| *a.key != program_id)
+                }
+            } else {
+                quote! {
+                   #account_ident: &arr[#index_lit]
+                }
             }
         });
+        if self.has_optional_accounts() {
+            tokens.extend(quote! {
+                impl<'me, 'info> From<(Pubkey, &'me [AccountInfo<'info>; #accounts_len_ident])> for #accounts_ident<'me, 'info> {
+                    /// Accounts with key `program_id`, the ID of the invoked program,
+                    /// are absent optional accounts
+                    fn from((program_id, arr): (Pubkey, &'me [AccountInfo<'info>; #accounts_len_ident])) -> Self {
+                        Self {
+                            #(#from_account_info_fields),*
+                        }
+                    }
+                }
+
+                impl<'me, 'info> From<&'me [AccountInfo<'info>; #accounts_len_ident]> for #accounts_ident<'me, 'info> {
+                    /// Accounts with key `crate::ID` are absent optional accounts
+                    fn from(arr: &'me [AccountInfo<'info>; #accounts_len_ident]) -> Self {
+                        Self::from((crate::ID, arr))
+                    }
+                }
+            });
+            return;
+        }
         tokens.extend(quote! {
             impl<'me, 'info> From<&'me [AccountInfo<'info>; #accounts_len_ident]> for #accounts_ident<'me, 'info> {
                 fn from(arr: &'me [AccountInfo<'info>; #accounts_len_ident]) -> Self {
@@ -281,17 +412,60 @@ impl NamedInstruction {
         });
     }
 
-    pub fn write_ix_args_struct(&self, tokens: &mut TokenStream) {
+    /// TryFrom <&[Pubkey]> for XKeys and TryFrom <&[AccountInfo]> for XAccounts
+    pub fn write_try_from_slices(&self, tokens: &mut TokenStream) {
+        if !self.has_accounts() {
+            return;
+        }
+        tokens.extend(try_from_slice_impls(
+            &self.keys_ident(),
+            &self.accounts_ident(),
+            &self.accounts_len_ident(),
+        ));
+    }
+
+    /// XIxBuilder
+    pub fn write_ix_builder(&self, tokens: &mut TokenStream, accounts: &[IxAccount]) {
+        let keys_ident = self.keys_ident();
+        let ix_args_ident = self.ix_args_ident();
+        let key_fields: Vec<IxBuilderField> = accounts
+            .iter()
+            .map(|acc| IxBuilderField::account(&acc.name, acc.optional, None))
+            .collect();
+        let arg_fields: Vec<IxBuilderField> = self
+            .args
+            .iter()
+            .flatten()
+            .map(|arg| {
+                let ty = &arg.r#type;
+                IxBuilderField::arg(&arg.name, quote! { #ty })
+            })
+            .collect();
+        tokens.extend(ix_builder(
+            &self.name,
+            &self.ix_fn_ident(),
+            true,
+            self.has_accounts()
+                .then_some((&keys_ident, key_fields.as_slice())),
+            self.has_ix_args()
+                .then_some((&ix_args_ident, arg_fields.as_slice())),
+            &[],
+        ));
+    }
+
+    pub fn write_ix_args_struct(&self, tokens: &mut TokenStream, cli_args: &crate::Args) {
         let args = if !self.has_ix_args() {
             return;
         } else {
             self.args.as_ref().unwrap()
         };
         let ix_args_ident = self.ix_args_ident();
-        let args_fields = args.iter().map(|a| quote! { pub #a });
+        let args_fields = args.iter().map(|a| a.to_serde_pub_field_tokens(cli_args));
+        let json_schema_derive = json_schema_derive(cli_args);
         tokens.extend(quote! {
             #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
             #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            #json_schema_derive
             pub struct #ix_args_ident {
                 #(#args_fields),*
             }
@@ -410,7 +584,16 @@ impl NamedInstruction {
             fn_args.extend(quote! { args, });
         }
 
-        let (mut fn_body, accounts_expr) = if self.has_accounts() {
+        let (mut fn_body, accounts_expr) = if self.has_optional_accounts() {
+            (
+                quote! {
+                    let metas = keys.to_metas(program_id);
+                },
+                quote! {
+                    Vec::from(metas)
+                },
+            )
+        } else if self.has_accounts() {
             (
                 quote! {
                     let metas: [AccountMeta; #accounts_len_ident] = keys.into();
@@ -438,7 +621,9 @@ impl NamedInstruction {
             quote! { #ix_data_ident.try_to_vec()? }
         };
 
+        let docs = doc_attrs(&self.docs);
         tokens.extend(quote! {
+            #docs
             pub fn #ix_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> std::io::Result<Instruction> {
                 #fn_body
                 Ok(Instruction {
@@ -448,17 +633,33 @@ impl NamedInstruction {
                 })
             }
 
+            #docs
             pub fn #ix_fn_ident(#fn_params) -> std::io::Result<Instruction> {
                 #ix_with_program_id_fn_ident(crate::ID, #fn_args)
             }
         });
     }
 
+    /// Lifetime params of the invoke fns, required to take
+    /// the program's AccountInfo if there are optional accounts
+    fn invoke_fn_generics(&self) -> TokenStream {
+        if self.has_optional_accounts() {
+            quote! { <'info> }
+        } else {
+            quote! {}
+        }
+    }
+
     fn invoke_fn_params_prefix(&self) -> TokenStream {
         let accounts_ident = self.accounts_ident();
         let ix_args_ident = self.ix_args_ident();
         let mut fn_params = quote! {};
-        if self.has_accounts() {
+        if self.has_optional_accounts() {
+            fn_params.extend(quote! {
+                accounts: #accounts_ident<'_, 'info>,
+                program: &AccountInfo<'info>,
+            });
+        } else if self.has_accounts() {
             fn_params.extend(quote! { accounts: #accounts_ident<'_, '_>, });
         }
         if self.has_ix_args() {
@@ -469,7 +670,9 @@ impl NamedInstruction {
 
     fn invoke_fn_args_prefix(&self) -> TokenStream {
         let mut fn_args = quote! {};
-        if self.has_accounts() {
+        if self.has_optional_accounts() {
+            fn_args.extend(quote! { accounts, program, });
+        } else if self.has_accounts() {
             fn_args.extend(quote! { accounts, });
         }
         if self.has_ix_args() {
@@ -506,8 +709,13 @@ impl NamedInstruction {
             format_ident!("{}_invoke_with_program_id", self.name.to_snake_case());
         let fn_params = self.invoke_fn_params_prefix();
         let fn_args = self.invoke_fn_args_prefix();
+        let generics = self.invoke_fn_generics();
         let call_assign = self.ix_call_assign();
-        let invoke = if self.has_accounts() {
+        let invoke = if self.has_optional_accounts() {
+            quote! {
+                invoke(&ix, &accounts.to_account_infos(program))
+            }
+        } else if self.has_accounts() {
             quote! {
                 invoke_instruction(&ix, accounts)
             }
@@ -517,12 +725,12 @@ impl NamedInstruction {
             }
         };
         tokens.extend(quote! {
-            pub fn #invoke_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> ProgramResult {
+            pub fn #invoke_with_program_id_fn_ident #generics(program_id: Pubkey, #fn_params) -> ProgramResult {
                 #call_assign
                 #invoke
             }
 
-            pub fn #invoke_fn_ident(#fn_params) -> ProgramResult {
+            pub fn #invoke_fn_ident #generics(#fn_params) -> ProgramResult {
                 #invoke_with_program_id_fn_ident(crate::ID, #fn_args)
             }
         });
@@ -540,8 +748,13 @@ impl NamedInstruction {
         fn_params.extend(quote! { seeds: &[&[&[u8]]], });
         let mut fn_args = self.invoke_fn_args_prefix();
         fn_args.extend(quote! { seeds, });
+        let generics = self.invoke_fn_generics();
         let call_assign = self.ix_call_assign();
-        let invoke = if self.has_accounts() {
+        let invoke = if self.has_optional_accounts() {
+            quote! {
+                invoke_signed(&ix, &accounts.to_account_infos(program), seeds)
+            }
+        } else if self.has_accounts() {
             quote! {
                 invoke_instruction_signed(&ix, accounts, seeds)
             }
@@ -551,12 +764,12 @@ impl NamedInstruction {
             }
         };
         tokens.extend(quote! {
-            pub fn #invoke_signed_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> ProgramResult {
+            pub fn #invoke_signed_with_program_id_fn_ident #generics(program_id: Pubkey, #fn_params) -> ProgramResult {
                 #call_assign
                 #invoke
             }
 
-            pub fn #invoke_signed_fn_ident(#fn_params) -> ProgramResult {
+            pub fn #invoke_signed_fn_ident #generics(#fn_params) -> ProgramResult {
                 #invoke_signed_with_program_id_fn_ident(crate::ID, #fn_args)
             }
         });
@@ -575,6 +788,19 @@ impl NamedInstruction {
         // edge-case of accounts and keys being empty
         let pubkeys_loop_check = if accounts.is_empty() {
             quote! {}
+        } else if self.has_optional_accounts() {
+            let key_tups = accounts
+                .iter()
+                .map(IxAccount::to_verify_account_keys_option);
+            quote! {
+                for (actual, expected) in [
+                    #(#key_tups),*
+                ] {
+                    if actual != expected {
+                        return Err((*actual.unwrap_or(&crate::ID), *expected.unwrap_or(&crate::ID)));
+                    }
+                }
+            }
         } else {
             quote! {
                 for (actual, expected) in [
@@ -586,7 +812,14 @@ impl NamedInstruction {
                 }
             }
         };
+        let doc = self.has_optional_accounts().then(|| {
+            quote! {
+                /// Optional accounts must be present in both `accounts` and `keys` or absent from both.
+                /// An absent account or key is returned as `crate::ID` in the mismatch
+            }
+        });
         tokens.extend(quote! {
+            #doc
             pub fn #verify_account_keys_fn_ident(
                 accounts: #accounts_ident<'_, '_>,
                 keys: #keys_ident
@@ -617,19 +850,24 @@ impl NamedInstruction {
         let accounts_ident = self.accounts_ident();
 
         let mut verify_fn_body = quote! {};
+        // absent optional accounts are skipped by iterating over Options
+        let has_optional_writables = accounts.iter().any(|a| a.is_mut && a.optional);
+        let has_optional_signers = accounts.iter().any(|a| a.is_signer && a.optional);
+        let writables_iter = if has_optional_writables {
+            quote! { .into_iter().flatten() }
+        } else {
+            quote! {}
+        };
+        let signers_iter = if has_optional_signers {
+            quote! { .into_iter().flatten() }
+        } else {
+            quote! {}
+        };
 
         let mut writables = accounts
             .iter()
-            .filter_map(|a| {
-                if a.is_mut {
-                    let name = a.field_ident();
-                    Some(quote! {
-                        accounts.#name
-                    })
-                } else {
-                    None
-                }
-            })
+            .filter(|a| a.is_mut)
+            .map(|a| a.to_verify_privileges_elem(has_optional_writables))
             .peekable();
         let has_writables = writables.peek().is_some();
         if has_writables {
@@ -639,7 +877,7 @@ impl NamedInstruction {
                 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
                     for should_be_writable in [
                         #(#writables),*
-                    ] {
+                    ]#writables_iter {
                         if !should_be_writable.is_writable {
                             return Err((should_be_writable, ProgramError::InvalidAccountData));
                         }
@@ -654,16 +892,8 @@ impl NamedInstruction {
 
         let mut signers = accounts
             .iter()
-            .filter_map(|a| {
-                if a.is_signer {
-                    let name = a.field_ident();
-                    Some(quote! {
-                        accounts.#name
-                    })
-                } else {
-                    None
-                }
-            })
+            .filter(|a| a.is_signer)
+            .map(|a| a.to_verify_privileges_elem(has_optional_signers))
             .peekable();
         let has_signers = signers.peek().is_some();
         if has_signers {
@@ -673,7 +903,7 @@ impl NamedInstruction {
                 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
                     for should_be_signer in [
                         #(#signers),*
-                    ] {
+                    ]#signers_iter {
                         if !should_be_signer.is_signer {
                             return Err((should_be_signer, ProgramError::MissingRequiredSignature));
                         }
@@ -697,8 +927,8 @@ impl NamedInstruction {
     }
 }
 
-impl ToTokens for NamedInstruction {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
+impl NamedInstruction {
+    pub fn write_tokens(&self, tokens: &mut TokenStream, cli_args: &crate::Args) {
         let accounts: &[IxAccount] = self.accounts.as_ref().map_or(&[], |v| v.as_slice());
         let n_accounts = accounts.len();
 
@@ -722,14 +952,16 @@ impl ToTokens for NamedInstruction {
         self.write_from_pubkey_arr_for_keys(tokens, accounts);
         self.write_from_accounts_for_account_info_arr(tokens, accounts);
         self.write_from_account_info_arr_for_accounts(tokens, accounts);
+        self.write_try_from_slices(tokens);
 
         self.write_discm(tokens);
-        self.write_ix_args_struct(tokens);
+        self.write_ix_args_struct(tokens, cli_args);
         self.write_ix_data_struct(tokens);
         self.write_from_ix_args_for_ix_data(tokens);
         self.write_ix_data_impl(tokens);
 
         self.write_ix_fn(tokens);
+        self.write_ix_builder(tokens, accounts);
         self.write_invoke_fn(tokens);
         self.write_invoke_signed_fn(tokens);
 
@@ -745,6 +977,8 @@ pub struct IxAccount {
     pub is_mut: bool,
     pub is_signer: bool,
     pub desc: Option<String>,
+    #[serde(default)]
+    pub optional: bool,
 }
 
 impl IxAccount {
@@ -756,13 +990,31 @@ impl IxAccount {
         self.is_mut || self.is_signer
     }
 
-    pub fn to_keys_account_meta_tokens(&self) -> TokenStream {
+    /// `keys` is the `XKeys` expression e.g. `self`.
+    /// Absent optional accounts are replaced with `program_id`
+    pub fn to_keys_account_meta_tokens(&self, keys: &TokenStream) -> TokenStream {
         let is_writable_arg = LitBool::new(self.is_mut, Span::call_site());
         let is_signer_arg = LitBool::new(self.is_signer, Span::call_site());
         let name = self.field_ident();
+        if self.optional {
+            return quote! {
+                match #keys.#name {
+                    Some(pubkey) => AccountMeta {
+                        pubkey,
+                        is_signer: #is_signer_arg,
+                        is_writable: #is_writable_arg,
+                    },
+                    None => AccountMeta {
+                        pubkey: program_id,
+                        is_signer: false,
+                        is_writable: false,
+                    },
+                }
+            };
+        }
         quote! {
             AccountMeta {
-                pubkey: keys.#name,
+                pubkey: #keys.#name,
                 is_signer: #is_signer_arg,
                 is_writable: #is_writable_arg,
             }
@@ -775,6 +1027,29 @@ impl IxAccount {
             (accounts.#name.key, &keys.#name)
         }
     }
+
+    /// `(Option<actual>, Option<expected>)`, `None` if the account or its key is absent
+    pub fn to_verify_account_keys_option(&self) -> TokenStream {
+        let name = self.field_ident();
+        if self.optional {
+            return quote! {
+                (accounts.#name.map(|a| a.key), keys.#name.as_ref())
+            };
+        }
+        quote! {
+            (Some(accounts.#name.key), Some(&keys.#name))
+        }
+    }
+
+    /// `in_options` if the verify privileges loop iterates over `Option<&AccountInfo>`s
+    pub fn to_verify_privileges_elem(&self, in_options: bool) -> TokenStream {
+        let name = self.field_ident();
+        if in_options && !self.optional {
+            quote! { Some(accounts.#name) }
+        } else {
+            quote! { accounts.#name }
+        }
+    }
 }
 
 #[derive(Deserialize)]
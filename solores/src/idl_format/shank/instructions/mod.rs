@@ -1,13 +1,17 @@
 use heck::ToPascalCase;
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote, ToTokens};
+use quote::{format_ident, quote};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{
+    idl_format::IdlCodegenModule,
+    parsed_ix::{parsed_ix_enum, ParsedIxVariant},
+};
 
 mod instruction;
 pub use instruction::*;
 
 pub struct IxCodegenModule<'a> {
+    pub cli_args: &'a crate::Args,
     pub program_name: &'a str,
     pub instructions: &'a [NamedInstruction],
 }
@@ -34,12 +38,13 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
             .iter()
             .map(|ix| ix.has_accounts())
             .any(|b| b);
-        let mut solana_program_imports = if has_accounts {
+        let solana_program_imports = if has_accounts {
             quote! {
                 account_info::AccountInfo,
                 entrypoint::ProgramResult,
                 instruction::{AccountMeta, Instruction},
                 program::{invoke, invoke_signed},
+                program_error::ProgramError,
                 pubkey::Pubkey,
             }
         } else {
@@ -50,16 +55,6 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
                 pubkey::Pubkey,
             }
         };
-        let has_privileged_accounts = self
-            .instructions
-            .iter()
-            .map(|ix| ix.has_privileged_accounts())
-            .any(|b| b);
-        if has_privileged_accounts {
-            solana_program_imports.extend(quote! {
-                program_error::ProgramError,
-            });
-        }
         res.extend(quote! {
             use solana_program::{#solana_program_imports};
             use std::io::Read;
@@ -120,7 +115,12 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
             }
         });
 
-        if has_accounts {
+        // instructions with optional accounts invoke with the program's AccountInfo instead
+        let has_required_accounts_only = self
+            .instructions
+            .iter()
+            .any(|ix| ix.has_accounts() && !ix.has_optional_accounts());
+        if has_required_accounts_only {
             res.extend(quote! {
                 fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
                     ix: &Instruction,
@@ -144,10 +144,28 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
     }
 
     fn gen_body(&self) -> TokenStream {
-        self.instructions
-            .iter()
-            .map(|e| e.into_token_stream())
-            .collect()
+        let mut res = TokenStream::new();
+        for ix in self.instructions {
+            ix.write_tokens(&mut res, self.cli_args);
+        }
+        res.extend(parsed_ix_enum(
+            self.program_name,
+            &self
+                .instructions
+                .iter()
+                .map(parsed_ix_variant)
+                .collect::<Vec<_>>(),
+            None,
+        ));
+        res
+    }
+}
+
+pub fn parsed_ix_variant(ix: &NamedInstruction) -> ParsedIxVariant {
+    ParsedIxVariant {
+        variant_ident: format_ident!("{}", ix.name.to_pascal_case()),
+        keys_ident: ix.has_accounts().then(|| ix.keys_ident()),
+        ix_args_ident: ix.has_ix_args().then(|| ix.ix_args_ident()),
     }
 }
 
@@ -1,10 +1,18 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 use toml::{map::Map, Value};
 
+use crate::layout::Layouts;
+use crate::serde_helpers::{
+    has_serde_helpers, serde_helpers_needs_base64, SerdeHelpersCodegenModule,
+};
 use crate::write_cargotoml::{
-    DependencyValue, FeaturesDependencyValue, OptionalDependencyValue, BORSH_CRATE, BYTEMUCK_CRATE,
+    insert_schemars_dependency, insert_serde_base64_dependency, DependencyValue,
+    FeaturesDependencyValue, OptionalDependencyValue, BORSH_CRATE, BYTEMUCK_CRATE,
     NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_CRATE, SOLANA_PROGRAM_CRATE, THISERROR_CRATE,
 };
+use crate::zero_copy::ZeroCopyTypedef;
 
 use super::{IdlCodegenModule, IdlFormat};
 
@@ -37,6 +45,18 @@ pub struct Metadata {
     pub origin: String,
 }
 
+impl ShankIdl {
+    /// Layouts of all accounts and typedefs
+    fn layouts(&self) -> Layouts {
+        let accounts = self.accounts.iter().flatten();
+        let types = self.types.iter().flatten();
+        accounts
+            .chain(types)
+            .map(|t| (t.name.clone(), t.to_layout_typedef()))
+            .collect()
+    }
+}
+
 impl IdlFormat for ShankIdl {
     fn program_name(&self) -> &str {
         &self.name
@@ -54,22 +74,36 @@ impl IdlFormat for ShankIdl {
         self.metadata.origin == "shank"
     }
 
+    fn zero_copy_typedefs(&self) -> Option<HashMap<String, ZeroCopyTypedef>> {
+        let accounts = self.accounts.iter().flatten();
+        let types = self.types.iter().flatten();
+        Some(
+            accounts
+                .chain(types)
+                .map(|t| (t.name.clone(), t.to_zero_copy_typedef()))
+                .collect(),
+        )
+    }
+
     fn modules<'me>(&'me self, args: &'me crate::Args) -> Vec<Box<dyn IdlCodegenModule + 'me>> {
         let mut res: Vec<Box<dyn IdlCodegenModule + 'me>> = Vec::new();
         if let Some(v) = &self.accounts {
             res.push(Box::new(AccountsCodegenModule {
                 cli_args: args,
                 named_types: v,
+                layouts: self.layouts(),
             }));
         }
         if let Some(v) = &self.r#types {
             res.push(Box::new(TypedefsCodegenModule {
                 cli_args: args,
                 named_types: v,
+                layouts: self.layouts(),
             }));
         }
         if let Some(v) = &self.instructions {
             res.push(Box::new(IxCodegenModule {
+                cli_args: args,
                 program_name: self.program_name(),
                 instructions: v,
             }));
@@ -80,6 +114,12 @@ impl IdlFormat for ShankIdl {
                 variants: v,
             }));
         }
+        if has_serde_helpers(args) {
+            res.push(Box::new(SerdeHelpersCodegenModule {
+                cli_args: args,
+                feature: Some(SERDE_CRATE),
+            }));
+        }
         res
     }
 
@@ -104,6 +144,9 @@ impl IdlFormat for ShankIdl {
             SERDE_CRATE.into(),
             OptionalDependencyValue(DependencyValue(&args.serde_vers)).into(),
         );
+        if serde_helpers_needs_base64(args) {
+            insert_serde_base64_dependency(&mut map, args);
+        }
         if self.errors.is_some() {
             map.insert(
                 THISERROR_CRATE.into(),
@@ -118,6 +161,9 @@ impl IdlFormat for ShankIdl {
                 DependencyValue(&args.num_traits_vers).into(),
             );
         }
+        if args.schemars {
+            insert_schemars_dependency(&mut map, args);
+        }
         map
     }
 }
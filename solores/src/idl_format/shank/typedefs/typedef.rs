@@ -9,28 +9,74 @@ use serde::Deserialize;
 use syn::Index;
 use void::Void;
 
-use crate::utils::{primitive_or_pubkey_to_token, string_or_struct, PUBKEY_TOKEN};
+use crate::json_schema::json_schema_derive;
+use crate::layout::{LayoutType, LayoutTypedef};
+use crate::serde_helpers::SerdeAsType;
+use crate::utils::{doc_attrs, primitive_or_pubkey_to_token, string_or_struct, PUBKEY_TOKEN};
+use crate::zero_copy::{ZeroCopyType, ZeroCopyTypedef};
 
 #[derive(Deserialize)]
 pub struct NamedType {
     pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
     pub r#type: TypedefType,
 }
 
 impl NamedType {
+    /// This typedef's borsh layout for generating `*_LEN` and `*_OFFSET` consts
+    pub fn to_layout_typedef(&self) -> LayoutTypedef {
+        match &self.r#type {
+            TypedefType::r#struct(s) => LayoutTypedef::Struct(
+                s.fields
+                    .iter()
+                    .map(|f| (f.name.clone(), f.r#type.to_layout_type()))
+                    .collect(),
+            ),
+            TypedefType::r#enum(e) => LayoutTypedef::Enum(
+                e.variants
+                    .iter()
+                    .map(|v| {
+                        v.fields
+                            .as_ref()
+                            .map_or_else(Vec::new, |f| f.layout_types())
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// This typedef's layout for checking `-z` derives
+    pub fn to_zero_copy_typedef(&self) -> ZeroCopyTypedef {
+        match &self.r#type {
+            TypedefType::r#struct(s) => ZeroCopyTypedef::c_struct(
+                s.fields
+                    .iter()
+                    .map(|f| (f.name.clone(), f.r#type.to_zero_copy_type()))
+                    .collect(),
+            ),
+            TypedefType::r#enum(_) => ZeroCopyTypedef::Invalid("enum".into()),
+        }
+    }
+
     pub fn to_token_stream(&self, cli_args: &crate::Args) -> TokenStream {
+        let json_schema_derive = json_schema_derive(cli_args);
         let name = format_ident!("{}", self.name);
+        let docs = doc_attrs(&self.docs);
         // rust enums cannot impl Pod due to illegal bitpatterns
         let typedef_struct = match &self.r#type {
             TypedefType::r#struct(typedef_struct) => typedef_struct,
             TypedefType::r#enum(typedef_enum) => {
+                let typedef_enum = typedef_enum.to_variants_tokens(cli_args);
                 return quote! {
+                    #docs
                     #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
                     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+                    #json_schema_derive
                     pub enum #name {
                         #typedef_enum
                     }
-                }
+                };
             }
         };
 
@@ -44,9 +90,12 @@ impl NamedType {
                 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
             }
         };
+        let typedef_struct = typedef_struct.to_fields_tokens(cli_args);
         quote! {
+            #docs
             #derive
             #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            #json_schema_derive
             pub struct #name {
                 #typedef_struct
             }
@@ -69,6 +118,8 @@ pub struct TypedefStruct {
 #[derive(Deserialize)]
 pub struct TypedefField {
     pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
     #[serde(deserialize_with = "string_or_struct")]
     pub r#type: TypedefFieldType,
 }
@@ -131,6 +182,13 @@ pub enum EnumVariantFields {
 }
 
 impl EnumVariantFields {
+    pub fn layout_types(&self) -> Vec<LayoutType> {
+        match self {
+            Self::Struct(v) => v.iter().map(|f| f.r#type.to_layout_type()).collect(),
+            Self::Tuple(v) => v.iter().map(|f| f.0.to_layout_type()).collect(),
+        }
+    }
+
     pub fn has_pubkey(&self) -> bool {
         match self {
             Self::Struct(v) => v.iter().any(|f| f.r#type.is_or_has_pubkey()),
@@ -149,28 +207,58 @@ impl EnumVariantFields {
 #[derive(Deserialize)]
 pub struct EnumVariant {
     pub name: String,
+    #[serde(default)]
+    pub docs: Vec<String>,
     pub fields: Option<EnumVariantFields>,
 }
 
-impl ToTokens for TypedefStruct {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let typedef_fields = self.fields.iter().map(|f| quote! { pub #f });
-        tokens.extend(quote! {
+impl TypedefStruct {
+    pub fn to_fields_tokens(&self, cli_args: &crate::Args) -> TokenStream {
+        let typedef_fields = self
+            .fields
+            .iter()
+            .map(|f| f.to_serde_pub_field_tokens(cli_args));
+        quote! {
             #(#typedef_fields),*
-        })
+        }
     }
 }
 
 impl ToTokens for TypedefField {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let docs = doc_attrs(&self.docs);
         let name = format_ident!("{}", self.name.to_snake_case());
         let ty = &self.r#type;
         tokens.extend(quote! {
+            #docs
             #name: #ty
         })
     }
 }
 
+impl TypedefField {
+    /// `pub` struct field with the field's docs
+    pub fn to_pub_field_tokens(&self) -> TokenStream {
+        let docs = doc_attrs(&self.docs);
+        let name = format_ident!("{}", self.name.to_snake_case());
+        let ty = &self.r#type;
+        quote! {
+            #docs
+            pub #name: #ty
+        }
+    }
+
+    /// [`Self::to_pub_field_tokens`] with the field's `#[serde(with)]` adapter, if any
+    pub fn to_serde_pub_field_tokens(&self, cli_args: &crate::Args) -> TokenStream {
+        let serde_with = self.r#type.to_serde_as_type().with_attr(cli_args);
+        let field = self.to_pub_field_tokens();
+        quote! {
+            #serde_with
+            #field
+        }
+    }
+}
+
 impl ToTokens for TypedefFieldType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let ty: TokenStream = match self {
@@ -198,39 +286,48 @@ impl ToTokens for TypedefFieldArray {
     }
 }
 
-impl ToTokens for TypedefEnum {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let variants = &self.variants;
-        tokens.extend(quote! {
+impl TypedefEnum {
+    pub fn to_variants_tokens(&self, cli_args: &crate::Args) -> TokenStream {
+        let variants = self.variants.iter().map(|v| v.to_variant_tokens(cli_args));
+        quote! {
             #(#variants),*
-        })
+        }
     }
 }
 
 // TODO: handle complex enum structs
-impl ToTokens for EnumVariant {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
+impl EnumVariant {
+    pub fn to_variant_tokens(&self, cli_args: &crate::Args) -> TokenStream {
+        let docs = doc_attrs(&self.docs);
         let v = format_ident!("{}", self.name.to_pascal_case());
         let maybe_inner_fields = self
             .fields
             .as_ref()
             .map_or(quote! {}, |fields| match fields {
                 EnumVariantFields::Struct(v) => {
-                    let typedef_fields = v.iter();
+                    let typedef_fields = v.iter().map(|f| {
+                        let serde_with = f.r#type.to_serde_as_type().with_attr(cli_args);
+                        quote! { #serde_with #f }
+                    });
                     quote! {
                         { #(#typedef_fields),* }
                     }
                 }
                 EnumVariantFields::Tuple(v) => {
-                    let unnamed_fields = v.iter().map(|wrap| &wrap.0);
+                    let unnamed_fields = v.iter().map(|wrap| {
+                        let serde_with = wrap.0.to_serde_as_type().with_attr(cli_args);
+                        let ty = &wrap.0;
+                        quote! { #serde_with #ty }
+                    });
                     quote! {
                         ( #(#unnamed_fields),* )
                     }
                 }
             });
-        tokens.extend(quote! {
+        quote! {
+            #docs
             #v #maybe_inner_fields
-        });
+        }
     }
 }
 
@@ -251,6 +348,35 @@ impl TypedefType {
 }
 
 impl TypedefFieldType {
+    pub fn to_layout_type(&self) -> LayoutType {
+        match self {
+            Self::PrimitiveOrPubkey(s) => LayoutType::primitive(s),
+            Self::array(a) => LayoutType::Array(Box::new(a.0.to_layout_type()), a.1 as usize),
+            Self::defined(d) => LayoutType::Defined(d.clone()),
+            Self::option(_) | Self::vec(_) => LayoutType::Variable,
+        }
+    }
+
+    pub fn to_serde_as_type(&self) -> SerdeAsType {
+        match self {
+            Self::PrimitiveOrPubkey(s) => SerdeAsType::primitive(s),
+            Self::array(a) => SerdeAsType::Array(Box::new(a.0.to_serde_as_type()), a.1.to_string()),
+            Self::option(o) => SerdeAsType::Option(Box::new(o.to_serde_as_type())),
+            Self::vec(v) => SerdeAsType::Vec(Box::new(v.to_serde_as_type())),
+            Self::defined(_) => SerdeAsType::Other,
+        }
+    }
+
+    pub fn to_zero_copy_type(&self) -> ZeroCopyType {
+        match self {
+            Self::PrimitiveOrPubkey(s) => ZeroCopyType::primitive(s),
+            Self::array(a) => ZeroCopyType::Array(Box::new(a.0.to_zero_copy_type()), a.1 as usize),
+            Self::defined(d) => ZeroCopyType::Defined(d.clone()),
+            Self::option(_) => ZeroCopyType::Invalid("Option".into()),
+            Self::vec(_) => ZeroCopyType::Invalid("Vec".into()),
+        }
+    }
+
     pub fn is_or_has_pubkey(&self) -> bool {
         match self {
             Self::PrimitiveOrPubkey(s) => primitive_or_pubkey_to_token(s) == PUBKEY_TOKEN,
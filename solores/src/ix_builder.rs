@@ -0,0 +1,241 @@
+//! `*IxBuilder`s that set an instruction's accounts and args one at a time,
+//! built on the `*_ix()` fns.
+
+use heck::{ToPascalCase, ToSnakeCase};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// An account or arg of the instruction
+pub struct IxBuilderField {
+    /// Field name as in the IDL
+    pub name: String,
+    /// Type of the `*Keys` or `*IxArgs` field.
+    /// For optional accounts, the type of the value inside the `Option`
+    pub ty: TokenStream,
+    /// The `*Keys` field is an `Option<Pubkey>` that is left `None` instead of erroring if unset
+    pub is_optional: bool,
+    /// Expression the field is pre-filled with
+    pub default: Option<TokenStream>,
+}
+
+impl IxBuilderField {
+    /// An account of the instruction, pre-filled with `fixed_address`
+    /// or the address of a well-known program or sysvar with the same name
+    pub fn account(name: &str, is_optional: bool, fixed_address: Option<&str>) -> Self {
+        let default = fixed_address
+            .or_else(|| known_address(name))
+            .map(|address| quote! { solana_program::pubkey!(#address) });
+        Self {
+            name: name.to_owned(),
+            ty: quote! { Pubkey },
+            is_optional,
+            default,
+        }
+    }
+
+    /// An arg of the instruction
+    pub fn arg(name: &str, ty: TokenStream) -> Self {
+        Self {
+            name: name.to_owned(),
+            ty,
+            is_optional: false,
+            default: None,
+        }
+    }
+}
+
+/// Address of the well-known program or sysvar an account is usually named after
+pub fn known_address(account_name: &str) -> Option<&'static str> {
+    Some(match account_name.to_snake_case().as_str() {
+        "system_program" => "11111111111111111111111111111111",
+        "token_program" => "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+        "token_2022_program" => "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+        "associated_token_program" | "ata_program" => {
+            "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+        "stake_program" => "Stake11111111111111111111111111111111111111",
+        "memo_program" => "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+        "rent" | "rent_sysvar" | "sysvar_rent" => "SysvarRent111111111111111111111111111111111",
+        "clock" | "clock_sysvar" | "sysvar_clock" => "SysvarC1ock11111111111111111111111111111111",
+        "stake_history" | "sysvar_stake_history" => "SysvarStakeHistory1111111111111111111111111",
+        "instructions_sysvar" | "sysvar_instructions" => {
+            "Sysvar1nstructions1111111111111111111111111"
+        }
+        _ => return None,
+    })
+}
+
+/// `{Ix}IxBuilder` with one setter per account and arg and a `build()` that calls `ix_fn_ident`.
+///
+/// `keys` and `ix_args` are the `*Keys` and `*IxArgs` structs and their fields, if any.
+/// Args named the same as an account get an `_arg` suffix.
+///
+/// `derived_keys` are the PDAs `build()` derives if they were not set,
+/// as (account field ident, `find_*_address()` expr) in derivation order,
+/// the same as the `*Keys` constructor.
+/// The exprs may refer to the other accounts by their field idents and to the `*IxArgs` as `args`.
+pub fn ix_builder(
+    ix_name: &str,
+    ix_fn_ident: &Ident,
+    ix_fn_returns_result: bool,
+    keys: Option<(&Ident, &[IxBuilderField])>,
+    ix_args: Option<(&Ident, &[IxBuilderField])>,
+    derived_keys: &[(Ident, TokenStream)],
+) -> TokenStream {
+    let builder_ident = format_ident!("{}IxBuilder", ix_name.to_pascal_case());
+    let missing_fmt = format!("{} is missing {{}}", builder_ident);
+    let builder_doc = format!(
+        " Builds a [`{}`] instruction one field at a time",
+        ix_fn_ident
+    );
+    let account_names: Vec<String> = keys
+        .iter()
+        .flat_map(|(_, fields)| fields.iter().map(|f| f.name.to_snake_case()))
+        .collect();
+    let key_fields = keys.iter().flat_map(|(_, fields)| fields.iter());
+    let arg_fields = ix_args.iter().flat_map(|(_, fields)| fields.iter());
+    // (builder field ident, struct field ident, field)
+    let fields: Vec<(Ident, Ident, &IxBuilderField)> = key_fields
+        .map(|f| {
+            let ident = format_ident!("{}", f.name.to_snake_case());
+            (ident.clone(), ident, f)
+        })
+        .chain(arg_fields.map(|f| {
+            let name = f.name.to_snake_case();
+            let builder_ident = if account_names.contains(&name) {
+                format_ident!("{}_arg", name)
+            } else {
+                format_ident!("{}", name)
+            };
+            (builder_ident, format_ident!("{}", name), f)
+        }))
+        .collect();
+    let is_derived = |ident: &Ident| derived_keys.iter().any(|(d, _)| d == ident);
+
+    let builder_fields = fields.iter().map(|(ident, _, IxBuilderField { ty, .. })| {
+        quote! { #ident: Option<#ty> }
+    });
+    let initial_values = fields.iter().map(|(ident, _, f)| match &f.default {
+        Some(default) => quote! { #ident: Some(#default) },
+        None => quote! { #ident: None },
+    });
+    let new_doc = if fields.iter().any(|(_, _, f)| f.default.is_some()) {
+        " Starts a builder with the accounts that have a known address pre-filled"
+    } else {
+        " Starts a builder with no accounts or args set"
+    };
+    let setters = fields.iter().map(|(ident, _, IxBuilderField { ty, .. })| {
+        quote! {
+            pub fn #ident(mut self, #ident: #ty) -> Self {
+                self.#ident = Some(#ident);
+                self
+            }
+        }
+    });
+    let field_value = |ident: &Ident, f: &IxBuilderField| {
+        if f.is_optional {
+            quote! { self.#ident }
+        } else {
+            let name = ident.to_string();
+            quote! { self.#ident.ok_or_else(|| missing(#name))? }
+        }
+    };
+    let n_keys = keys.map_or(0, |(_, fields)| fields.len());
+    let mut build_body = quote! {};
+    let mut fn_args = quote! {};
+    // accounts are bound to locals so that `derived_keys` can refer to them
+    let key_lets = fields[..n_keys]
+        .iter()
+        .filter(|(ident, _, _)| !is_derived(ident))
+        .map(|(ident, _, f)| {
+            let value = field_value(ident, f);
+            quote! { let #ident = #value; }
+        });
+    build_body.extend(quote! { #(#key_lets)* });
+    if let Some((ix_args_ident, _)) = ix_args {
+        let values = fields[n_keys..].iter().map(|(ident, field_ident, f)| {
+            let value = field_value(ident, f);
+            quote! { #field_ident: #value }
+        });
+        build_body.extend(quote! {
+            let args = #ix_args_ident {
+                #(#values),*
+            };
+        });
+    }
+    if let Some((keys_ident, _)) = keys {
+        let derived_lets = derived_keys.iter().map(|(ident, find_expr)| {
+            quote! { let #ident = self.#ident.unwrap_or_else(|| #find_expr); }
+        });
+        let key_idents = fields[..n_keys].iter().map(|(ident, _, _)| ident);
+        build_body.extend(quote! {
+            #(#derived_lets)*
+            let keys = #keys_ident {
+                #(#key_idents),*
+            };
+        });
+        fn_args.extend(quote! { keys, });
+    }
+    if ix_args.is_some() {
+        fn_args.extend(quote! { args, });
+    }
+    let missing_closure = fields
+        .iter()
+        .any(|(ident, _, f)| !f.is_optional && !is_derived(ident))
+        .then(|| {
+            quote! {
+                let missing = |field: &str| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(#missing_fmt, field),
+                    )
+                };
+            }
+        });
+    let build_doc = if derived_keys.is_empty() {
+        quote! {
+            /// Errors naming the first account or arg that was not set
+        }
+    } else {
+        quote! {
+            /// Errors naming the first account or arg that was not set.
+            /// PDAs that were not set are derived
+        }
+    };
+    let ix = if ix_fn_returns_result {
+        quote! { #ix_fn_ident(#fn_args) }
+    } else {
+        quote! { Ok(#ix_fn_ident(#fn_args)) }
+    };
+    quote! {
+        #[doc = #builder_doc]
+        #[derive(Clone, Debug)]
+        pub struct #builder_ident {
+            #(#builder_fields),*
+        }
+
+        impl Default for #builder_ident {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl #builder_ident {
+            #[doc = #new_doc]
+            pub fn new() -> Self {
+                Self {
+                    #(#initial_values),*
+                }
+            }
+
+            #(#setters)*
+
+            #build_doc
+            pub fn build(self) -> std::io::Result<Instruction> {
+                #missing_closure
+                #build_body
+                #ix
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! `schemars::JsonSchema` derives for the generated crate's `schemars` feature.
+//!
+//! The schemas describe the types' serde representation:
+//! fields with `#[serde(with)]` adapters get a matching `#[schemars(with)]`,
+//! see [`crate::serde_helpers::SerdeAsType`],
+//! and the adapters in the generated `serde_helpers` module impl `JsonSchema`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::serde_helpers::BytesEncoding;
+
+/// Cargo feature the `JsonSchema` derives are gated behind
+pub const SCHEMARS_FEATURE: &str = "schemars";
+
+/// `#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]`,
+/// empty without `--schemars`
+pub fn json_schema_derive(args: &crate::Args) -> TokenStream {
+    if args.schemars {
+        quote! { #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))] }
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// `JsonSchema` impls of the generated `serde_helpers` module's adapters
+/// for use in `#[schemars(with)]`, empty without `--schemars`
+pub fn serde_helpers_json_schema_impls(args: &crate::Args) -> TokenStream {
+    if !args.schemars {
+        return TokenStream::new();
+    }
+    let bytes_impl = if args.serde_human_readable {
+        match args.serde_bytes_encoding {
+            BytesEncoding::Hex => quote! {
+                impl JsonSchema for Hex {
+                    fn inline_schema() -> bool {
+                        true
+                    }
+
+                    fn schema_name() -> Cow<'static, str> {
+                        "Hex".into()
+                    }
+
+                    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+                        json_schema!({
+                            "type": "string",
+                            "pattern": "^([0-9a-fA-F]{2})*$",
+                        })
+                    }
+                }
+            },
+            BytesEncoding::Base64 => quote! {
+                impl JsonSchema for Base64 {
+                    fn inline_schema() -> bool {
+                        true
+                    }
+
+                    fn schema_name() -> Cow<'static, str> {
+                        "Base64".into()
+                    }
+
+                    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+                        json_schema!({
+                            "type": "string",
+                            "contentEncoding": "base64",
+                        })
+                    }
+                }
+            },
+        }
+    } else {
+        TokenStream::new()
+    };
+    quote! {
+        #[cfg(feature = "schemars")]
+        mod json_schema {
+            use super::*;
+            use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+            use std::borrow::Cow;
+
+            impl JsonSchema for Base58 {
+                fn inline_schema() -> bool {
+                    true
+                }
+
+                fn schema_name() -> Cow<'static, str> {
+                    "Base58".into()
+                }
+
+                fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+                    json_schema!({
+                        "type": "string",
+                        "pattern": "^[1-9A-HJ-NP-Za-km-z]{32,44}$",
+                    })
+                }
+            }
+
+            impl JsonSchema for DisplayFromStr {
+                fn inline_schema() -> bool {
+                    true
+                }
+
+                fn schema_name() -> Cow<'static, str> {
+                    "DisplayFromStr".into()
+                }
+
+                fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+                    json_schema!({
+                        "type": "string",
+                        "pattern": "^-?[0-9]+$",
+                    })
+                }
+            }
+
+            #bytes_impl
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! Serialized layouts of typedefs for generating `*_LEN` and `*_OFFSET` consts.
+//!
+//! Each IDL format converts its typedefs to [`LayoutTypedef`]s,
+//! sizes of defined types are resolved through [`Layouts`].
+//!
+//! Borsh and bincode's default encoding only differ in the size of enum tags
+//! for fixed-size types: length-prefixed types are variable-sized in both.
+
+use std::collections::HashMap;
+
+use heck::{ToShoutySnakeCase, ToSnakeCase};
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+
+/// The size-relevant parts of a typedef field's type
+pub enum LayoutType {
+    Fixed(usize),
+    Array(Box<LayoutType>, usize),
+    /// Name of another typedef in the IDL
+    Defined(String),
+    /// e.g. `Vec`, `String`, `Option`
+    Variable,
+}
+
+impl LayoutType {
+    /// Maps the primitive or pubkey type names used in IDLs to their serialized size
+    pub fn primitive(s: &str) -> Self {
+        match s {
+            "u8" | "i8" | "bool" => Self::Fixed(1),
+            "u16" | "i16" => Self::Fixed(2),
+            "u32" | "i32" | "f32" => Self::Fixed(4),
+            "u64" | "i64" | "f64" => Self::Fixed(8),
+            "u128" | "i128" => Self::Fixed(16),
+            "publicKey" | "pubkey" => Self::Fixed(32),
+            _ => Self::Variable,
+        }
+    }
+}
+
+pub enum LayoutTypedef {
+    /// (field name, field type)
+    Struct(Vec<(String, LayoutType)>),
+    /// Fields of each variant
+    Enum(Vec<Vec<LayoutType>>),
+    /// e.g. generic typedefs
+    Variable,
+}
+
+/// Size of borsh's `u8` enum tags
+pub const BORSH_ENUM_TAG_SIZE: usize = 1;
+
+/// Size of bincode's `u32` enum tags
+pub const BINCODE_ENUM_TAG_SIZE: usize = 4;
+
+/// Typedef layouts by name
+pub struct Layouts {
+    typedefs: HashMap<String, LayoutTypedef>,
+    enum_tag_size: usize,
+}
+
+/// Borsh layouts
+impl FromIterator<(String, LayoutTypedef)> for Layouts {
+    fn from_iter<I: IntoIterator<Item = (String, LayoutTypedef)>>(iter: I) -> Self {
+        Self {
+            typedefs: iter.into_iter().collect(),
+            enum_tag_size: BORSH_ENUM_TAG_SIZE,
+        }
+    }
+}
+
+/// `{NAME}_LEN`
+pub fn len_ident(name: &str) -> Ident {
+    format_ident!("{}_LEN", name.to_shouty_snake_case())
+}
+
+/// `{NAME}_{FIELD}_OFFSET`
+pub fn offset_ident(name: &str, field: &str) -> Ident {
+    format_ident!(
+        "{}_{}_OFFSET",
+        name.to_shouty_snake_case(),
+        field.to_shouty_snake_case()
+    )
+}
+
+impl Layouts {
+    /// Layouts of an encoding whose enum tags are `enum_tag_size` bytes
+    pub fn with_enum_tag_size(self, enum_tag_size: usize) -> Self {
+        Self {
+            enum_tag_size,
+            ..self
+        }
+    }
+
+    /// Serialized size of the typedef, `None` if it is variable
+    pub fn size_of(&self, name: &str) -> Option<usize> {
+        match self.typedefs.get(name)? {
+            LayoutTypedef::Struct(fields) => fields.iter().map(|(_, ty)| self.type_size(ty)).sum(),
+            // fixed only if every variant has the same size
+            LayoutTypedef::Enum(variants) => {
+                let mut sizes = variants.iter().map(|fields| {
+                    fields
+                        .iter()
+                        .map(|ty| self.type_size(ty))
+                        .sum::<Option<usize>>()
+                });
+                let first = sizes.next().unwrap_or(Some(0))?;
+                sizes
+                    .all(|size| size == Some(first))
+                    .then_some(self.enum_tag_size + first)
+            }
+            LayoutTypedef::Variable => None,
+        }
+    }
+
+    fn type_size(&self, ty: &LayoutType) -> Option<usize> {
+        match ty {
+            LayoutType::Fixed(size) => Some(*size),
+            LayoutType::Array(elem, n) => self.type_size(elem).map(|size| size * n),
+            LayoutType::Defined(name) => self.size_of(name),
+            LayoutType::Variable => None,
+        }
+    }
+
+    /// Offsets of the struct typedef's fields,
+    /// up to and including the first variable-length field
+    pub fn field_offsets(&self, name: &str) -> Vec<(&str, usize)> {
+        let fields = match self.typedefs.get(name) {
+            Some(LayoutTypedef::Struct(fields)) => fields,
+            _ => return Vec::new(),
+        };
+        let mut res = Vec::new();
+        let mut offset = 0;
+        for (field_name, ty) in fields {
+            res.push((field_name.as_str(), offset));
+            match self.type_size(ty) {
+                Some(size) => offset += size,
+                None => break,
+            }
+        }
+        res
+    }
+
+    /// `{NAME}_LEN` and `{NAME}_{FIELD}_OFFSET` consts of the typedef.
+    /// `{NAME}_LEN` is `size_of` the struct `ident` for zero-copy types.
+    pub fn consts(&self, name: &str, ident: &Ident, zero_copy: bool) -> TokenStream {
+        let len_ident = len_ident(name);
+        let len_doc = format!(" Serialized size of [`{}`]", ident);
+        let mut res = if zero_copy {
+            quote! {
+                #[doc = #len_doc]
+                pub const #len_ident: usize = std::mem::size_of::<#ident>();
+            }
+        } else {
+            match self.size_of(name) {
+                Some(size) => {
+                    let size = Literal::usize_unsuffixed(size);
+                    quote! {
+                        #[doc = #len_doc]
+                        pub const #len_ident: usize = #size;
+                    }
+                }
+                None => quote! {},
+            }
+        };
+        res.extend(self.field_offsets(name).into_iter().map(|(field, offset)| {
+            let offset_ident = offset_ident(name, field);
+            let offset_doc = format!(
+                " Offset of `{}` in serialized [`{}`]",
+                field.to_snake_case(),
+                ident
+            );
+            let offset = Literal::usize_unsuffixed(offset);
+            quote! {
+                #[doc = #offset_doc]
+                pub const #offset_ident: usize = #offset;
+            }
+        }));
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layouts() -> Layouts {
+        [
+            (
+                "Outer",
+                LayoutTypedef::Struct(vec![
+                    ("a".into(), LayoutType::primitive("bool")),
+                    ("inner".into(), LayoutType::Defined("Inner".into())),
+                    ("name".into(), LayoutType::primitive("string")),
+                    ("b".into(), LayoutType::primitive("u8")),
+                ]),
+            ),
+            (
+                "Inner",
+                LayoutTypedef::Struct(vec![
+                    (
+                        "keys".into(),
+                        LayoutType::Array(Box::new(LayoutType::primitive("pubkey")), 2),
+                    ),
+                    ("status".into(), LayoutType::Defined("Status".into())),
+                ]),
+            ),
+            ("Status", LayoutTypedef::Enum(vec![vec![], vec![]])),
+            (
+                "Mixed",
+                LayoutTypedef::Enum(vec![vec![], vec![LayoutType::primitive("u64")]]),
+            ),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_owned(), v))
+        .collect()
+    }
+
+    #[test]
+    fn test_sizes() {
+        let layouts = layouts();
+        assert_eq!(layouts.size_of("Status"), Some(1));
+        assert_eq!(layouts.size_of("Inner"), Some(65));
+        assert_eq!(layouts.size_of("Outer"), None);
+        assert_eq!(layouts.size_of("Mixed"), None);
+    }
+
+    #[test]
+    fn test_offsets_stop_at_variable_field() {
+        assert_eq!(
+            layouts().field_offsets("Outer"),
+            vec![("a", 0), ("inner", 1), ("name", 66)]
+        );
+    }
+
+    #[test]
+    fn test_bincode_enum_tag_size() {
+        let layouts = layouts().with_enum_tag_size(BINCODE_ENUM_TAG_SIZE);
+        assert_eq!(layouts.size_of("Status"), Some(4));
+        assert_eq!(layouts.size_of("Inner"), Some(68));
+        assert_eq!(
+            layouts.field_offsets("Outer"),
+            vec![("a", 0), ("inner", 1), ("name", 69)]
+        );
+    }
+}
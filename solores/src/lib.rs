@@ -10,19 +10,38 @@ use std::{
 use clap::{command, Parser};
 use idl_format::{bincode::BincodeIdl, IdlFormat};
 
-use crate::idl_format::{anchor::AnchorIdl, shank::ShankIdl};
+use crate::idl_format::{
+    anchor::{legacy::LegacyAnchorIdl, AnchorIdl},
+    anchor030::{Anchor030Idl, Anchor030Spec},
+    codama::CodamaIdl,
+    shank::ShankIdl,
+};
 
 // Just make all mods pub to allow ppl to use the lib
 
+pub mod anchor_framework_errors;
+pub mod client;
+pub mod error_decoding;
+pub mod event_parsing;
+pub mod filters;
 pub mod idl_format;
+pub mod ix_builder;
+pub mod json_schema;
+pub mod layout;
+pub mod parsed_ix;
+pub mod remaining_accounts;
+pub mod serde_helpers;
 pub mod utils;
 pub mod write_cargotoml;
 pub mod write_gitignore;
 pub mod write_src;
+pub mod zero_copy;
 
+use serde_helpers::BytesEncoding;
 use write_cargotoml::write_cargotoml;
 use write_gitignore::write_gitignore;
 use write_src::*;
+use zero_copy::resolve_zero_copy;
 
 const DEFAULT_OUTPUT_CRATE_NAME_MSG: &str = "<name-of-program>_interface";
 const DEFAULT_PROGRAM_ID_MSG: &str = "program ID in IDL else system program ID if absent";
@@ -54,10 +73,48 @@ pub struct Args {
     #[arg(
         long,
         short,
-        help = "typedefs and accounts to derive bytemuck::Pod for. Does not currently check validity of derivation."
+        help = "typedefs and accounts to derive bytemuck::Pod for. Errors if they cannot be Pod."
     )]
     pub zero_copy: Vec<String>,
 
+    #[arg(
+        long,
+        help = "also derive bytemuck::Pod for the typedefs contained in --zero-copy types instead of erroring"
+    )]
+    pub zero_copy_transitive: bool,
+
+    #[arg(
+        long,
+        help = "for anchor IDLs, also generate the AnchorFrameworkError enum of anchor's own error codes and a {Program}AnyError that decodes both"
+    )]
+    pub anchor_framework_errors: bool,
+
+    #[arg(
+        long,
+        help = "with serde, serialize Pubkeys as base58 strings and byte arrays as --serde-bytes-encoding strings in human-readable formats like JSON"
+    )]
+    pub serde_human_readable: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "string encoding of byte arrays with --serde-human-readable",
+        default_value = "base64"
+    )]
+    pub serde_bytes_encoding: BytesEncoding,
+
+    #[arg(
+        long,
+        help = "with serde, serialize u64, i64, u128 and i128 as decimal strings in human-readable formats like JSON"
+    )]
+    pub serde_int_as_string: bool,
+
+    #[arg(
+        long,
+        help = "generate a schemars feature that derives JsonSchema for typedefs, accounts, events and instruction args, consistent with their serde representation"
+    )]
+    pub schemars: bool,
+
     #[arg(
         long,
         short,
@@ -108,6 +165,48 @@ pub struct Args {
         default_value = "^1.16"
     )]
     pub bytemuck_vers: String,
+
+    #[arg(
+        long,
+        help = "base64 dependency version for generated crate",
+        default_value = "^0.22"
+    )]
+    pub base64_vers: String,
+
+    #[arg(
+        long,
+        help = "schemars dependency version for generated crate",
+        default_value = "^1.0"
+    )]
+    pub schemars_vers: String,
+
+    #[arg(
+        long,
+        help = "solana-rpc-client-api dependency version for generated crate",
+        default_value = "^2.0"
+    )]
+    pub solana_rpc_client_api_vers: String,
+
+    #[arg(
+        long,
+        help = "solana-rpc-client dependency version for generated crate",
+        default_value = "^2.0"
+    )]
+    pub solana_rpc_client_vers: String,
+
+    #[arg(
+        long,
+        help = "solana-account-decoder dependency version for generated crate",
+        default_value = "^2.0"
+    )]
+    pub solana_account_decoder_vers: String,
+
+    #[arg(
+        long,
+        help = "solana-sdk dependency version for generated crate",
+        default_value = "^2.0"
+    )]
+    pub solana_sdk_vers: String,
 }
 
 /// The CLI entrypoint
@@ -136,6 +235,26 @@ pub fn main() {
         }
     });
 
+    for name in idl.zero_copy_annotated() {
+        if !args.zero_copy.iter().any(|z| z == name) {
+            args.zero_copy.push(name.to_owned());
+        }
+    }
+    match idl.zero_copy_typedefs() {
+        Some(typedefs) => {
+            args.zero_copy =
+                resolve_zero_copy(&typedefs, &args.zero_copy, args.zero_copy_transitive)
+                    .unwrap_or_else(|e| {
+                        log::error!("{}", e);
+                        panic!();
+                    });
+        }
+        None if !args.zero_copy.is_empty() => {
+            log::warn!("Validity of --zero-copy derives is not checked for this IDL format");
+        }
+        None => (),
+    }
+
     args.output_dir.push(&args.output_crate_name);
     fs::create_dir_all(args.output_dir.join("src/")).unwrap();
 
@@ -166,11 +285,35 @@ pub fn load_idl(file: &mut File) -> Box<dyn IdlFormat> {
         }
     }
     file.rewind().unwrap();
-    // Assume anchor if unidentified
-    match serde_json::from_reader::<&File, AnchorIdl>(file) {
+    if let Ok(codama_idl) = serde_json::from_reader::<&File, CodamaIdl>(file) {
+        if codama_idl.is_correct_idl_format() {
+            log::info!("Successfully loaded codama IDL");
+            return Box::new(codama_idl);
+        }
+    }
+    file.rewind().unwrap();
+    if let Ok(spec) = serde_json::from_reader::<&File, Anchor030Spec>(file) {
+        if spec.is_anchor030() {
+            file.rewind().unwrap();
+            return match serde_json::from_reader::<&File, Anchor030Idl>(file) {
+                Ok(anchor030_idl) => {
+                    log::info!("Successfully loaded anchor 0.30 IDL");
+                    Box::new(AnchorIdl::from(anchor030_idl))
+                }
+                Err(e) => {
+                    panic!("Could not parse anchor 0.30 IDL: {:?}", e);
+                }
+            };
+        }
+    }
+    file.rewind().unwrap();
+    // Anchor IDLs before 0.30 dont seem to have an identifier,
+    // assume unindentified IDLs are legacy anchor by default.
+    // -> Make sure to try deserializing legacy Anchor last
+    match serde_json::from_reader::<&File, LegacyAnchorIdl>(file) {
         Ok(anchor_idl) => {
             log::info!("Successfully loaded anchor IDL");
-            Box::new(anchor_idl)
+            Box::new(AnchorIdl::from(anchor_idl))
         }
         Err(e) => {
             panic!("Could not determine IDL format: {:?}", e);
@@ -0,0 +1,137 @@
+//! `{Program}ParsedIx`, the `{Program}ProgramIx` enum paired with each instruction's `*Keys`,
+//! for decoding instructions of transactions.
+
+use heck::ToPascalCase;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+pub struct ParsedIxVariant {
+    /// Variant of both `{Program}ProgramIx` and `{Program}ParsedIx`
+    pub variant_ident: Ident,
+    /// `*Keys` if the instruction has accounts
+    pub keys_ident: Option<Ident>,
+    /// `*IxArgs` if the instruction has args
+    pub ix_args_ident: Option<Ident>,
+}
+
+/// `{Program}ParsedIx` enum and its `deserialize()` and `from_compiled()` fns.
+///
+/// Relies on `From<[Pubkey; N]> for *Keys` and `deserialize_program_ix`,
+/// an expression that deserializes `data: &[u8]` into a `std::io::Result<{Program}ProgramIx>`,
+/// `{Program}ProgramIx::deserialize(data)` if `None`
+pub fn parsed_ix_enum(
+    program_name: &str,
+    variants: &[ParsedIxVariant],
+    deserialize_program_ix: Option<TokenStream>,
+) -> TokenStream {
+    let program_ix_enum_ident = format_ident!("{}ProgramIx", program_name.to_pascal_case());
+    let deserialize_program_ix = deserialize_program_ix
+        .unwrap_or_else(|| quote! { #program_ix_enum_ident::deserialize(data) });
+    let parsed_ix_enum_ident = format_ident!("{}ParsedIx", program_name.to_pascal_case());
+    let enum_doc = format!(" A [`{}`] with its accounts", program_ix_enum_ident);
+    let enum_variants = variants.iter().map(
+        |ParsedIxVariant {
+             variant_ident,
+             keys_ident,
+             ix_args_ident,
+         }| {
+            let keys_field = keys_ident.as_ref().map(|k| quote! { keys: #k, });
+            let args_field = ix_args_ident.as_ref().map(|a| quote! { args: #a, });
+            if keys_field.is_none() && args_field.is_none() {
+                quote! { #variant_ident }
+            } else {
+                quote! { #variant_ident { #keys_field #args_field } }
+            }
+        },
+    );
+    let deserialize_match_arms = variants.iter().map(
+        |ParsedIxVariant {
+             variant_ident,
+             keys_ident,
+             ix_args_ident,
+         }| {
+            let program_ix_args = ix_args_ident.as_ref().map(|_| quote! { (args) });
+            let keys_field = keys_ident
+                .as_ref()
+                .map(|_| quote! { keys: parsed_ix_keys(accounts)?, });
+            let args_field = ix_args_ident.as_ref().map(|_| quote! { args, });
+            let parsed_ix = if keys_field.is_none() && args_field.is_none() {
+                quote! { Self::#variant_ident }
+            } else {
+                quote! { Self::#variant_ident { #keys_field #args_field } }
+            };
+            quote! {
+                #program_ix_enum_ident::#variant_ident #program_ix_args => #parsed_ix
+            }
+        },
+    );
+    let has_accounts = variants.iter().any(|v| v.keys_ident.is_some());
+    let (accounts_param, parsed_ix_keys_fn) = if has_accounts {
+        (
+            quote! { accounts },
+            quote! {
+                fn parsed_ix_keys<K: From<[Pubkey; N]>, const N: usize>(
+                    accounts: &[Pubkey],
+                ) -> std::io::Result<K> {
+                    let keys: [Pubkey; N] = accounts
+                        .get(..N)
+                        .and_then(|keys| keys.try_into().ok())
+                        .ok_or_else(|| {
+                            std::io::Error::new(
+                                std::io::ErrorKind::Other,
+                                format!("expected at least {} accounts, got {}", N, accounts.len()),
+                            )
+                        })?;
+                    Ok(keys.into())
+                }
+            },
+        )
+    } else {
+        (quote! { _accounts }, quote! {})
+    };
+    quote! {
+        #[doc = #enum_doc]
+        #[allow(clippy::large_enum_variant)]
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum #parsed_ix_enum_ident {
+            #(#enum_variants),*
+        }
+
+        impl #parsed_ix_enum_ident {
+            /// Deserializes the instruction `data` and pairs it with `accounts`,
+            /// the instruction's account keys in order.
+            /// Accounts after the instruction's own are ignored.
+            pub fn deserialize(data: &[u8], #accounts_param: &[Pubkey]) -> std::io::Result<Self> {
+                let program_ix = #deserialize_program_ix?;
+                Ok(match program_ix {
+                    #(#deserialize_match_arms),*
+                })
+            }
+
+            /// Parses an instruction compiled into a transaction message.
+            /// `account_keys` are the message's account keys, which must include the keys
+            /// loaded from address lookup tables for v0 messages, e.g.
+            /// `AccountKeys::new(&message.account_keys, Some(&loaded_addresses))`
+            pub fn from_compiled(
+                ix: &solana_program::instruction::CompiledInstruction,
+                account_keys: &solana_program::message::AccountKeys,
+            ) -> std::io::Result<Self> {
+                let accounts = ix
+                    .accounts
+                    .iter()
+                    .map(|i| {
+                        account_keys.get(usize::from(*i)).copied().ok_or_else(|| {
+                            std::io::Error::new(
+                                std::io::ErrorKind::Other,
+                                format!("account index {} out of bounds", i),
+                            )
+                        })
+                    })
+                    .collect::<std::io::Result<Vec<_>>>()?;
+                Self::deserialize(&ix.data, &accounts)
+            }
+        }
+
+        #parsed_ix_keys_fn
+    }
+}
@@ -0,0 +1,61 @@
+//! Fallible slice conversions for `*Keys` and `*Accounts` that tolerate remaining accounts,
+//! built on the exact-length `From<[Pubkey; N]>` and `From<&[AccountInfo; N]>` impls.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// `*Keys::from_slice_with_remaining()`, `*Accounts::from_slice_with_remaining()`
+/// and the `TryFrom<&[Pubkey]>` and `TryFrom<&[AccountInfo]>` impls that ignore remaining accounts.
+///
+/// Requires `ProgramError` to be imported.
+pub fn try_from_slice_impls(
+    keys_ident: &Ident,
+    accounts_ident: &Ident,
+    accounts_len_ident: &Ident,
+) -> TokenStream {
+    quote! {
+        impl #keys_ident {
+            /// Takes the instruction's keys from the start of `pubkeys`
+            /// and returns them with the remaining accounts after them
+            pub fn from_slice_with_remaining(
+                pubkeys: &[Pubkey],
+            ) -> Result<(Self, &[Pubkey]), ProgramError> {
+                let keys: [Pubkey; #accounts_len_ident] = pubkeys
+                    .get(..#accounts_len_ident)
+                    .and_then(|keys| keys.try_into().ok())
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                Ok((keys.into(), &pubkeys[#accounts_len_ident..]))
+            }
+        }
+
+        impl TryFrom<&[Pubkey]> for #keys_ident {
+            type Error = ProgramError;
+
+            fn try_from(pubkeys: &[Pubkey]) -> Result<Self, Self::Error> {
+                Self::from_slice_with_remaining(pubkeys).map(|(keys, _remaining)| keys)
+            }
+        }
+
+        impl<'me, 'info> #accounts_ident<'me, 'info> {
+            /// Takes the instruction's accounts from the start of `accounts`
+            /// and returns them with the remaining accounts after them
+            pub fn from_slice_with_remaining(
+                accounts: &'me [AccountInfo<'info>],
+            ) -> Result<(Self, &'me [AccountInfo<'info>]), ProgramError> {
+                let arr: &'me [AccountInfo<'info>; #accounts_len_ident] = accounts
+                    .get(..#accounts_len_ident)
+                    .and_then(|arr| arr.try_into().ok())
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                Ok((arr.into(), &accounts[#accounts_len_ident..]))
+            }
+        }
+
+        impl<'me, 'info> TryFrom<&'me [AccountInfo<'info>]> for #accounts_ident<'me, 'info> {
+            type Error = ProgramError;
+
+            fn try_from(accounts: &'me [AccountInfo<'info>]) -> Result<Self, Self::Error> {
+                Self::from_slice_with_remaining(accounts).map(|(accounts, _remaining)| accounts)
+            }
+        }
+    }
+}
@@ -0,0 +1,554 @@
+//! `#[serde(with)]` adapters that serialize pubkeys, big integers and byte arrays
+//! as strings in human-readable formats such as JSON.
+//!
+//! The generated `serde_helpers` module defines the adapters.
+//! Each IDL format converts its field types to [`SerdeAsType`]s
+//! to pick the adapter of each generated field.
+//! The adapters fall back to the types' own serde impls in non-human-readable formats
+//! such as bincode so that binary encodings are unchanged.
+
+use clap::ValueEnum;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{idl_format::IdlCodegenModule, json_schema::serde_helpers_json_schema_impls};
+
+pub const SERDE_HELPERS_MODULE_NAME: &str = "serde_helpers";
+
+/// String encoding of byte arrays with `--serde-human-readable`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum BytesEncoding {
+    Hex,
+    Base64,
+}
+
+impl BytesEncoding {
+    fn adapter(&self) -> &'static str {
+        match self {
+            Self::Hex => "Hex",
+            Self::Base64 => "Base64",
+        }
+    }
+}
+
+/// Whether the generated crate has a `serde_helpers` module
+pub fn has_serde_helpers(args: &crate::Args) -> bool {
+    args.serde_human_readable || args.serde_int_as_string
+}
+
+/// Whether the generated `serde_helpers` module needs the base64 crate
+pub fn serde_helpers_needs_base64(args: &crate::Args) -> bool {
+    args.serde_human_readable && args.serde_bytes_encoding == BytesEncoding::Base64
+}
+
+/// The serde-relevant parts of a field's type
+pub enum SerdeAsType {
+    Pubkey,
+    /// u64, i64, u128 and i128, which JS numbers cannot represent exactly
+    BigInt,
+    U8,
+    Option(Box<SerdeAsType>),
+    Vec(Box<SerdeAsType>),
+    /// Array with its length, which may be a const generic param
+    Array(Box<SerdeAsType>, String),
+    /// `#[serde(transparent)]` wrapper of the given type that does not use adapters,
+    /// e.g. codama codec wrappers
+    Transparent(Box<SerdeAsType>),
+    /// Types serialized with their own serde impls,
+    /// e.g. other typedefs and generic params
+    Other,
+}
+
+impl SerdeAsType {
+    /// Maps the primitive or pubkey type names used in IDLs
+    pub fn primitive(s: &str) -> Self {
+        match s {
+            "publicKey" | "pubkey" => Self::Pubkey,
+            "u64" | "i64" | "u128" | "i128" => Self::BigInt,
+            "u8" => Self::U8,
+            _ => Self::Other,
+        }
+    }
+
+    /// The `SerdeAs` adapter of this type e.g. `Option<Base58>`,
+    /// `None` if it is serialized with its own serde impls
+    fn adapter(&self, args: &crate::Args) -> Option<String> {
+        match self {
+            Self::Pubkey if args.serde_human_readable => Some(helper_path("Base58")),
+            Self::BigInt if args.serde_int_as_string => Some(helper_path("DisplayFromStr")),
+            Self::Vec(item) | Self::Array(item, _)
+                if args.serde_human_readable && matches!(item.as_ref(), Self::U8) =>
+            {
+                Some(helper_path(args.serde_bytes_encoding.adapter()))
+            }
+            Self::Option(item) => item.adapter(args).map(|a| format!("Option<{a}>")),
+            Self::Vec(item) => item.adapter(args).map(|a| format!("Vec<{a}>")),
+            Self::Array(item, n) => item.adapter(args).map(|a| format!("[{a}; {n}]")),
+            _ => None,
+        }
+    }
+
+    /// Path to use in `#[serde(with = "...")]`, `None` if no adapter is needed
+    pub fn with_path(&self, args: &crate::Args) -> Option<String> {
+        self.adapter(args)
+            .map(|a| format!("{}::<{}>", helper_path("As"), a))
+    }
+
+    /// Type whose `JsonSchema` describes this type's serde representation
+    /// for `#[schemars(with = "...")]`, `None` if this type's own `JsonSchema` does.
+    /// `Pubkey` does not impl `JsonSchema` so it is always replaced.
+    pub fn schema_type(&self, args: &crate::Args) -> Option<String> {
+        self.adapter(args)
+            .or_else(|| self.own_schema_type(Some(args)))
+    }
+
+    /// [`Self::schema_type`] of this type's own serde impls,
+    /// whose items use adapters only if `args` is `Some`
+    fn own_schema_type(&self, args: Option<&crate::Args>) -> Option<String> {
+        let item_schema_type = |item: &Self| match args {
+            Some(args) => item.schema_type(args),
+            None => item.own_schema_type(None),
+        };
+        match self {
+            Self::Pubkey => Some("[u8; 32]".to_owned()),
+            Self::Option(item) => item_schema_type(item).map(|t| format!("Option<{t}>")),
+            Self::Vec(item) => item_schema_type(item).map(|t| format!("Vec<{t}>")),
+            Self::Array(item, n) => item_schema_type(item).map(|t| format!("[{t}; {n}]")),
+            Self::Transparent(inner) => inner.own_schema_type(None),
+            _ => None,
+        }
+    }
+
+    /// `#[cfg_attr(feature = "schemars", schemars(with = "..."))]`,
+    /// empty without `--schemars` or if no override is needed
+    fn schema_attr(&self, args: &crate::Args) -> TokenStream {
+        match self.schema_type(args) {
+            Some(ty) if args.schemars => {
+                quote! { #[cfg_attr(feature = "schemars", schemars(with = #ty))] }
+            }
+            _ => TokenStream::new(),
+        }
+    }
+
+    /// `#[cfg_attr(feature = "serde", serde(with = "..."))]` and the matching
+    /// `schemars(with)`, empty if no adapter is needed
+    pub fn with_attr(&self, args: &crate::Args) -> TokenStream {
+        let mut res = self.with_path(args).map_or_else(TokenStream::new, |path| {
+            quote! { #[cfg_attr(feature = "serde", serde(with = #path))] }
+        });
+        res.extend(self.schema_attr(args));
+        res
+    }
+
+    /// `#[serde(with = "...")]` and the matching `schemars(with)`
+    /// for IDL formats whose generated types always derive serde,
+    /// empty if no adapter is needed
+    pub fn with_attr_ungated(&self, args: &crate::Args) -> TokenStream {
+        let mut res = self.with_path(args).map_or_else(TokenStream::new, |path| {
+            quote! { #[serde(with = #path)] }
+        });
+        res.extend(self.schema_attr(args));
+        res
+    }
+}
+
+fn helper_path(item: &str) -> String {
+    format!("crate::{SERDE_HELPERS_MODULE_NAME}::{item}")
+}
+
+/// Generates the `serde_helpers` module of `SerdeAs` adapters
+pub struct SerdeHelpersCodegenModule<'a> {
+    pub cli_args: &'a crate::Args,
+    /// `None` for IDL formats whose generated types always derive serde
+    pub feature: Option<&'static str>,
+}
+
+impl IdlCodegenModule for SerdeHelpersCodegenModule<'_> {
+    fn name(&self) -> &str {
+        SERDE_HELPERS_MODULE_NAME
+    }
+
+    fn feature(&self) -> Option<&str> {
+        self.feature
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        let mut res = quote! {
+            use serde::{
+                de::{DeserializeOwned, Error, SeqAccess, Visitor},
+                ser::SerializeTuple,
+                Deserialize, Deserializer, Serialize, Serializer,
+            };
+            use solana_program::pubkey::Pubkey;
+            use std::{fmt::Display, marker::PhantomData, str::FromStr};
+        };
+        if self.cli_args.serde_human_readable {
+            res.extend(match self.cli_args.serde_bytes_encoding {
+                BytesEncoding::Hex => quote! {
+                    use std::fmt::Write;
+                },
+                BytesEncoding::Base64 => quote! {
+                    use base64::{engine::general_purpose::STANDARD, Engine};
+                },
+            });
+        }
+        res
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        let mut res = quote! {
+            /// (De)serializes `T` differently from `T`'s own serde impls.
+            ///
+            /// The adapters in this module only do so in human-readable formats
+            /// and fall back to `T`'s own serde impls otherwise.
+            pub trait SerdeAs<T> {
+                fn serialize_as<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error>;
+
+                fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>;
+            }
+
+            /// `#[serde(with = "As::<A>")]` (de)serializes a field with the [`SerdeAs`] adapter `A`
+            pub struct As<A>(PhantomData<A>);
+
+            impl<A> As<A> {
+                pub fn serialize<T, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    A: SerdeAs<T>,
+                {
+                    A::serialize_as(value, serializer)
+                }
+
+                pub fn deserialize<'de, T, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>
+                where
+                    A: SerdeAs<T>,
+                {
+                    A::deserialize_as(deserializer)
+                }
+            }
+
+            struct SerializeAsWrap<'a, T, A>(&'a T, PhantomData<A>);
+
+            impl<T, A: SerdeAs<T>> Serialize for SerializeAsWrap<'_, T, A> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    A::serialize_as(self.0, serializer)
+                }
+            }
+
+            struct DeserializeAsWrap<T, A>(T, PhantomData<A>);
+
+            impl<'de, T, A: SerdeAs<T>> Deserialize<'de> for DeserializeAsWrap<T, A> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    A::deserialize_as(deserializer).map(|v| Self(v, PhantomData))
+                }
+            }
+
+            /// `T`'s own serde impls
+            pub struct Same;
+
+            impl<T: Serialize + DeserializeOwned> SerdeAs<T> for Same {
+                fn serialize_as<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+                    value.serialize(serializer)
+                }
+
+                fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+                    T::deserialize(deserializer)
+                }
+            }
+
+            impl<T, A: SerdeAs<T>> SerdeAs<Option<T>> for Option<A> {
+                fn serialize_as<S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+                    match value {
+                        Some(v) => serializer.serialize_some(&SerializeAsWrap::<T, A>(v, PhantomData)),
+                        None => serializer.serialize_none(),
+                    }
+                }
+
+                fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+                    Ok(Option::<DeserializeAsWrap<T, A>>::deserialize(deserializer)?.map(|w| w.0))
+                }
+            }
+
+            impl<T, A: SerdeAs<T>> SerdeAs<Vec<T>> for Vec<A> {
+                fn serialize_as<S: Serializer>(value: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_seq(value.iter().map(|v| SerializeAsWrap::<T, A>(v, PhantomData)))
+                }
+
+                fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<T>, D::Error> {
+                    Ok(Vec::<DeserializeAsWrap<T, A>>::deserialize(deserializer)?
+                        .into_iter()
+                        .map(|w| w.0)
+                        .collect())
+                }
+            }
+
+            struct ArrayVisitor<T, A, const N: usize>(PhantomData<(T, A)>);
+
+            impl<'de, T, A: SerdeAs<T>, const N: usize> Visitor<'de> for ArrayVisitor<T, A, N> {
+                type Value = [T; N];
+
+                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    write!(formatter, "an array of length {}", N)
+                }
+
+                fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<[T; N], V::Error> {
+                    let mut res = Vec::with_capacity(N);
+                    while res.len() < N {
+                        match seq.next_element::<DeserializeAsWrap<T, A>>()? {
+                            Some(w) => res.push(w.0),
+                            None => return Err(V::Error::invalid_length(res.len(), &self)),
+                        }
+                    }
+                    match res.try_into() {
+                        Ok(arr) => Ok(arr),
+                        Err(_) => unreachable!(),
+                    }
+                }
+            }
+
+            /// Same as serde's own array impls, which are a tuple of the elements,
+            /// but for any `N`
+            impl<T, A: SerdeAs<T>, const N: usize> SerdeAs<[T; N]> for [A; N] {
+                fn serialize_as<S: Serializer>(value: &[T; N], serializer: S) -> Result<S::Ok, S::Error> {
+                    let mut tup = serializer.serialize_tuple(N)?;
+                    for v in value {
+                        tup.serialize_element(&SerializeAsWrap::<T, A>(v, PhantomData))?;
+                    }
+                    tup.end()
+                }
+
+                fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[T; N], D::Error> {
+                    deserializer.deserialize_tuple(N, ArrayVisitor::<T, A, N>(PhantomData))
+                }
+            }
+
+            /// Pubkeys as base58 strings
+            pub struct Base58;
+
+            impl SerdeAs<Pubkey> for Base58 {
+                fn serialize_as<S: Serializer>(value: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+                    if serializer.is_human_readable() {
+                        serializer.collect_str(value)
+                    } else {
+                        value.serialize(serializer)
+                    }
+                }
+
+                fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+                    if deserializer.is_human_readable() {
+                        let s = String::deserialize(deserializer)?;
+                        Pubkey::from_str(&s).map_err(D::Error::custom)
+                    } else {
+                        Pubkey::deserialize(deserializer)
+                    }
+                }
+            }
+
+            /// Integers as decimal strings, since JS numbers cannot represent all u64s
+            pub struct DisplayFromStr;
+
+            impl<T> SerdeAs<T> for DisplayFromStr
+            where
+                T: Display + FromStr + Serialize + DeserializeOwned,
+                T::Err: Display,
+            {
+                fn serialize_as<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+                    if serializer.is_human_readable() {
+                        serializer.collect_str(value)
+                    } else {
+                        value.serialize(serializer)
+                    }
+                }
+
+                fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+                    if deserializer.is_human_readable() {
+                        let s = String::deserialize(deserializer)?;
+                        T::from_str(&s).map_err(D::Error::custom)
+                    } else {
+                        T::deserialize(deserializer)
+                    }
+                }
+            }
+        };
+        if self.cli_args.serde_human_readable {
+            res.extend(bytes_adapter(self.cli_args.serde_bytes_encoding));
+        }
+        res.extend(serde_helpers_json_schema_impls(self.cli_args));
+        res
+    }
+}
+
+/// The `Hex` or `Base64` adapter for `Vec<u8>` and `[u8; N]`
+fn bytes_adapter(encoding: BytesEncoding) -> TokenStream {
+    let (doc, encode_decode) = match encoding {
+        BytesEncoding::Hex => (
+            " Byte arrays as lowercase hex strings",
+            quote! {
+                fn encode(bytes: &[u8]) -> String {
+                    let mut res = String::with_capacity(bytes.len() * 2);
+                    for b in bytes {
+                        write!(res, "{:02x}", b).unwrap();
+                    }
+                    res
+                }
+
+                fn decode(s: &str) -> Result<Vec<u8>, String> {
+                    if s.len() % 2 != 0 || !s.is_ascii() {
+                        return Err(format!("invalid hex string {}", s));
+                    }
+                    (0..s.len())
+                        .step_by(2)
+                        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|e| e.to_string()))
+                        .collect()
+                }
+            },
+        ),
+        BytesEncoding::Base64 => (
+            " Byte arrays as standard base64 strings",
+            quote! {
+                fn encode(bytes: &[u8]) -> String {
+                    STANDARD.encode(bytes)
+                }
+
+                fn decode(s: &str) -> Result<Vec<u8>, String> {
+                    STANDARD.decode(s).map_err(|e| e.to_string())
+                }
+            },
+        ),
+    };
+    let adapter = quote::format_ident!("{}", encoding.adapter());
+    quote! {
+        #[doc = #doc]
+        pub struct #adapter;
+
+        impl #adapter {
+            #encode_decode
+        }
+
+        impl SerdeAs<Vec<u8>> for #adapter {
+            fn serialize_as<S: Serializer>(value: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(&Self::encode(value))
+                } else {
+                    value.serialize(serializer)
+                }
+            }
+
+            fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+                if deserializer.is_human_readable() {
+                    let s = String::deserialize(deserializer)?;
+                    Self::decode(&s).map_err(D::Error::custom)
+                } else {
+                    Vec::deserialize(deserializer)
+                }
+            }
+        }
+
+        impl<const N: usize> SerdeAs<[u8; N]> for #adapter {
+            fn serialize_as<S: Serializer>(value: &[u8; N], serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(&Self::encode(value))
+                } else {
+                    <[Same; N]>::serialize_as(value, serializer)
+                }
+            }
+
+            fn deserialize_as<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; N], D::Error> {
+                if deserializer.is_human_readable() {
+                    let s = String::deserialize(deserializer)?;
+                    let bytes = Self::decode(&s).map_err(D::Error::custom)?;
+                    let len = bytes.len();
+                    bytes
+                        .try_into()
+                        .map_err(|_| D::Error::custom(format!("expected {} bytes, got {}", N, len)))
+                } else {
+                    <[Same; N]>::deserialize_as(deserializer)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    fn args(extra: &[&str]) -> crate::Args {
+        crate::Args::parse_from([&["solores", "idl.json"], extra].concat())
+    }
+
+    fn nested_pubkey() -> SerdeAsType {
+        SerdeAsType::Option(Box::new(SerdeAsType::Array(
+            Box::new(SerdeAsType::primitive("publicKey")),
+            "2".to_owned(),
+        )))
+    }
+
+    #[test]
+    fn no_adapters_by_default() {
+        let args = args(&[]);
+        assert!(!has_serde_helpers(&args));
+        assert_eq!(nested_pubkey().with_path(&args), None);
+        assert!(nested_pubkey().with_attr(&args).is_empty());
+    }
+
+    #[test]
+    fn nested_adapters() {
+        let args = args(&["--serde-human-readable"]);
+        assert_eq!(
+            nested_pubkey().with_path(&args).unwrap(),
+            "crate::serde_helpers::As::<Option<[crate::serde_helpers::Base58; 2]>>"
+        );
+        assert_eq!(SerdeAsType::primitive("u64").with_path(&args), None);
+        let bytes = SerdeAsType::Vec(Box::new(SerdeAsType::Array(
+            Box::new(SerdeAsType::primitive("u8")),
+            "32".to_owned(),
+        )));
+        assert_eq!(
+            bytes.with_path(&args).unwrap(),
+            "crate::serde_helpers::As::<Vec<crate::serde_helpers::Base64>>"
+        );
+    }
+
+    #[test]
+    fn int_as_string_only() {
+        let args = args(&["--serde-int-as-string", "--serde-bytes-encoding", "hex"]);
+        assert!(has_serde_helpers(&args));
+        assert!(!serde_helpers_needs_base64(&args));
+        assert_eq!(nested_pubkey().with_path(&args), None);
+        assert_eq!(
+            SerdeAsType::Vec(Box::new(SerdeAsType::primitive("i128")))
+                .with_path(&args)
+                .unwrap(),
+            "crate::serde_helpers::As::<Vec<crate::serde_helpers::DisplayFromStr>>"
+        );
+    }
+
+    #[test]
+    fn schema_types() {
+        let plain = args(&["--schemars"]);
+        assert_eq!(
+            nested_pubkey().schema_type(&plain).unwrap(),
+            "Option<[[u8; 32]; 2]>"
+        );
+        assert!(!nested_pubkey().with_attr(&plain).is_empty());
+        assert_eq!(SerdeAsType::primitive("u64").schema_type(&plain), None);
+        assert_eq!(
+            SerdeAsType::Transparent(Box::new(SerdeAsType::Option(Box::new(SerdeAsType::Pubkey))))
+                .schema_type(&args(&["--schemars", "--serde-human-readable"]))
+                .unwrap(),
+            "Option<[u8; 32]>"
+        );
+
+        let human_readable = args(&["--schemars", "--serde-human-readable"]);
+        assert_eq!(
+            nested_pubkey().schema_type(&human_readable).unwrap(),
+            "Option<[crate::serde_helpers::Base58; 2]>"
+        );
+        assert!(nested_pubkey()
+            .with_attr(&human_readable)
+            .to_string()
+            .contains("schemars"));
+    }
+}
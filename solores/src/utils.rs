@@ -9,6 +9,8 @@ use std::{
 };
 
 use heck::ToPascalCase;
+use proc_macro2::TokenStream;
+use quote::quote;
 use serde::{
     de::{self, MapAccess, Visitor},
     Deserialize, Deserializer,
@@ -19,7 +21,7 @@ pub const PUBKEY_TOKEN: &str = "Pubkey";
 
 pub fn primitive_or_pubkey_to_token(s: &str) -> String {
     match s {
-        "publicKey" => PUBKEY_TOKEN.to_owned(),
+        "publicKey" | "pubkey" => PUBKEY_TOKEN.to_owned(),
         "string" => s.to_pascal_case(),
         "bytes" => {
             #[cfg(feature = "bytes_to_u8")]
@@ -124,6 +126,19 @@ pub fn conditional_pascal_case(s: &str) -> String {
     }
 }
 
+/// `#[doc]` attributes for the lines of an IDL `docs` array.
+///
+/// Lines are prefixed with a space so that they're rendered as `/// line`
+pub fn doc_attrs<S: AsRef<str>>(docs: &[S]) -> TokenStream {
+    let lines = docs
+        .iter()
+        .flat_map(|d| d.as_ref().lines())
+        .map(|line| format!(" {}", line.trim_end()));
+    quote! {
+        #(#[doc = #lines])*
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,6 +156,12 @@ mod tests {
         assert_eq!(result, "String");
     }
 
+    #[test]
+    fn test_anchor_030_pubkey() {
+        let result = primitive_or_pubkey_to_token("pubkey");
+        assert_eq!(result, PUBKEY_TOKEN.to_owned());
+    }
+
     #[test]
     #[cfg(not(feature = "bytes_to_u8"))]
     fn test_bytes_to_u8_feature_disabled() {
@@ -209,4 +230,19 @@ mod tests {
         let expected = "Uppercase";
         assert_eq!(conditional_pascal_case(input), expected);
     }
+
+    #[test]
+    fn test_doc_attrs() {
+        let docs = ["First line", "Second\nand third"];
+        assert_eq!(
+            doc_attrs(&docs).to_string(),
+            quote! {
+                #[doc = " First line"]
+                #[doc = " Second"]
+                #[doc = " and third"]
+            }
+            .to_string()
+        );
+        assert!(doc_attrs::<String>(&[]).is_empty());
+    }
 }
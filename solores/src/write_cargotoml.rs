@@ -3,12 +3,21 @@ use std::io::Write;
 use serde::Serialize;
 use toml::{map::Map, Value};
 
-use crate::{idl_format::IdlFormat, utils::open_file_create_overwrite, Args};
+use crate::{
+    client::CLIENT_FEATURE, idl_format::IdlFormat, json_schema::SCHEMARS_FEATURE,
+    utils::open_file_create_overwrite, Args,
+};
 
+pub const BASE64_CRATE: &str = "base64";
 pub const BORSH_CRATE: &str = "borsh";
 pub const BYTEMUCK_CRATE: &str = "bytemuck";
+pub const SCHEMARS_CRATE: &str = "schemars";
 pub const SERDE_CRATE: &str = "serde";
 pub const SOLANA_PROGRAM_CRATE: &str = "solana-program";
+pub const SOLANA_RPC_CLIENT_API_CRATE: &str = "solana-rpc-client-api";
+pub const SOLANA_RPC_CLIENT_CRATE: &str = "solana-rpc-client";
+pub const SOLANA_ACCOUNT_DECODER_CRATE: &str = "solana-account-decoder";
+pub const SOLANA_SDK_CRATE: &str = "solana-sdk";
 pub const THISERROR_CRATE: &str = "thiserror";
 pub const NUM_DERIVE_CRATE: &str = "num-derive";
 pub const NUM_TRAITS_CRATE: &str = "num-traits";
@@ -27,6 +36,8 @@ pub fn write_cargotoml(args: &Args, idl: &dyn IdlFormat) -> std::io::Result<()>
 pub struct CargoToml<'a> {
     pub package: Package<'a>,
     pub dependencies: Map<String, Value>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub features: Map<String, Value>,
 }
 
 impl<'a> CargoToml<'a> {
@@ -38,6 +49,7 @@ impl<'a> CargoToml<'a> {
                 edition: "2021",
             },
             dependencies: idl.dependencies(args),
+            features: idl.features(args),
         }
     }
 }
@@ -49,6 +61,83 @@ pub struct Package<'a> {
     pub edition: &'a str,
 }
 
+/// Adds the optional dependencies of the generated crate's `client` feature
+pub fn insert_client_dependencies(map: &mut Map<String, Value>, args: &Args) {
+    for (name, vers) in [
+        (
+            SOLANA_ACCOUNT_DECODER_CRATE,
+            &args.solana_account_decoder_vers,
+        ),
+        (SOLANA_RPC_CLIENT_CRATE, &args.solana_rpc_client_vers),
+        (
+            SOLANA_RPC_CLIENT_API_CRATE,
+            &args.solana_rpc_client_api_vers,
+        ),
+        (SOLANA_SDK_CRATE, &args.solana_sdk_vers),
+    ] {
+        map.insert(
+            name.into(),
+            OptionalDependencyValue(DependencyValue(vers)).into(),
+        );
+    }
+}
+
+/// `client = [...]`, enabling the dependencies added by [`insert_client_dependencies`]
+pub fn client_features() -> Map<String, Value> {
+    let mut map = Map::new();
+    map.insert(
+        CLIENT_FEATURE.into(),
+        vec![
+            format!("dep:{}", SOLANA_ACCOUNT_DECODER_CRATE),
+            format!("dep:{}", SOLANA_RPC_CLIENT_CRATE),
+            SOLANA_RPC_CLIENT_API_CRATE.to_owned(),
+            format!("dep:{}", SOLANA_SDK_CRATE),
+        ]
+        .into(),
+    );
+    map
+}
+
+/// Adds the optional dependency of the generated crate's `schemars` feature
+pub fn insert_schemars_dependency(map: &mut Map<String, Value>, args: &Args) {
+    map.insert(
+        SCHEMARS_CRATE.into(),
+        OptionalDependencyValue(DependencyValue(&args.schemars_vers)).into(),
+    );
+}
+
+/// `schemars = [...]`, enabling the dependency added by [`insert_schemars_dependency`]
+/// and the optional `serde` feature, if any, whose representation the schemas describe
+pub fn insert_schemars_feature(map: &mut Map<String, Value>, serde_feature: bool) {
+    let mut enables = vec![format!("dep:{}", SCHEMARS_CRATE)];
+    if serde_feature {
+        enables.push(SERDE_CRATE.to_owned());
+    }
+    map.insert(SCHEMARS_FEATURE.into(), enables.into());
+}
+
+/// Adds base64 as an optional dependency for the `serde_helpers` module,
+/// enabled by the feature added by [`insert_serde_base64_feature`]
+pub fn insert_serde_base64_dependency(map: &mut Map<String, Value>, args: &Args) {
+    map.insert(
+        BASE64_CRATE.into(),
+        OptionalDependencyValue(DependencyValue(&args.base64_vers)).into(),
+    );
+}
+
+/// `serde = [...]`, enabling the optional serde dependency
+/// and the dependency added by [`insert_serde_base64_dependency`]
+pub fn insert_serde_base64_feature(map: &mut Map<String, Value>) {
+    map.insert(
+        SERDE_CRATE.into(),
+        vec![
+            format!("dep:{}", SERDE_CRATE),
+            format!("dep:{}", BASE64_CRATE),
+        ]
+        .into(),
+    );
+}
+
 /// Contained str value is the version string arg.
 /// e.g. "^1.16", "workspace = true"
 pub struct DependencyValue<'a>(pub &'a str);
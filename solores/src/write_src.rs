@@ -1,6 +1,6 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use std::{io::Write, path::Path};
+use std::{io::Write, path::Path, process::Command};
 
 use crate::{idl_format::IdlFormat, utils::open_file_create_overwrite, Args};
 
@@ -49,8 +49,13 @@ pub fn write_lib(args: &Args, idl: &dyn IdlFormat) -> std::io::Result<()> {
     for module in idl.modules(args) {
         let module_name = module.name();
         let module_ident = Ident::new(module.name(), Span::call_site());
+        let cfg = module.feature().map(|feature| {
+            quote! { #[cfg(feature = #feature)] }
+        });
         contents.extend(quote! {
+            #cfg
             pub mod #module_ident;
+            #cfg
             pub use #module_ident::*;
         });
         let mut module_contents = module.gen_head();
@@ -58,7 +63,25 @@ pub fn write_lib(args: &Args, idl: &dyn IdlFormat) -> std::io::Result<()> {
         write_src_file(args, &format!("src/{module_name}.rs"), module_contents)?;
     }
 
-    write_src_file(args, "src/lib.rs", contents)
+    write_src_file(args, "src/lib.rs", contents)?;
+    rustfmt_lib(args);
+    Ok(())
+}
+
+/// Formats the generated crate with rustfmt so that it matches `cargo fmt`.
+/// Falls back to the prettyplease output if rustfmt is not available.
+fn rustfmt_lib(args: &Args) {
+    let lib_path = args.output_dir.join("src/lib.rs");
+    match Command::new("rustfmt")
+        .arg("--edition")
+        .arg("2021")
+        .arg(&lib_path)
+        .status()
+    {
+        Ok(status) if status.success() => (),
+        Ok(status) => log::warn!("rustfmt exited with {status}, output is not rustfmt-formatted"),
+        Err(e) => log::warn!("could not run rustfmt, output is not rustfmt-formatted: {e}"),
+    }
 }
 
 fn write_src_file<P: AsRef<Path>>(
@@ -0,0 +1,378 @@
+//! Checks that the `-z` types can derive `bytemuck::Pod` under `#[repr(C)]`
+//! before any code is generated.
+//!
+//! Each IDL format converts its typedefs to [`ZeroCopyTypedef`]s
+//! and [`resolve_zero_copy`] walks the typedef graph starting from the `-z` types.
+
+use std::collections::{HashMap, HashSet};
+
+/// The layout-relevant parts of a typedef field's type
+pub enum ZeroCopyType {
+    /// primitives and pubkeys
+    Scalar {
+        size: usize,
+        align: usize,
+    },
+    /// `u128` and `i128`, whose alignment is target-dependent
+    Int128,
+    Array(Box<ZeroCopyType>, usize),
+    /// Name of another typedef in the IDL
+    Defined(String),
+    /// A type that can never be `Pod`, e.g. `Vec<u8>`
+    Invalid(String),
+}
+
+impl ZeroCopyType {
+    /// Maps the primitive or pubkey type names used in IDLs
+    pub fn primitive(s: &str) -> Self {
+        let (size, align) = match s {
+            "u8" | "i8" => (1, 1),
+            "u16" | "i16" => (2, 2),
+            "u32" | "i32" | "f32" => (4, 4),
+            "u64" | "i64" | "f64" => (8, 8),
+            "u128" | "i128" => return Self::Int128,
+            "publicKey" | "pubkey" => (32, 1),
+            "string" => return Self::Invalid("String".into()),
+            other => return Self::Invalid(other.into()),
+        };
+        Self::Scalar { size, align }
+    }
+}
+
+pub enum ZeroCopyTypedef {
+    Struct {
+        /// (field name, field type)
+        fields: Vec<(String, ZeroCopyType)>,
+        repr: ZeroCopyRepr,
+    },
+    /// A typedef that can never be `Pod`, e.g. an enum
+    Invalid(String),
+}
+
+impl ZeroCopyTypedef {
+    /// A `#[repr(C)]` struct
+    pub fn c_struct(fields: Vec<(String, ZeroCopyType)>) -> Self {
+        Self::Struct {
+            fields,
+            repr: ZeroCopyRepr::default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ZeroCopyRepr {
+    /// `#[repr(C, packed)]`, fields are not aligned
+    pub packed: bool,
+    /// `#[repr(C, align(N))]`
+    pub align: Option<usize>,
+    /// `Pod` is implemented unsafely, so padding is allowed
+    pub allow_padding: bool,
+}
+
+#[derive(Clone, Copy)]
+struct Layout {
+    size: usize,
+    align: usize,
+}
+
+/// `align_of::<u128>()` on SBF and on x86_64 before rust 1.77
+const SBF_U128_ALIGN: usize = 8;
+
+/// `align_of::<u128>()` on aarch64 and on x86_64 since rust 1.77
+const HOST_U128_ALIGN: usize = 16;
+
+struct Resolver<'a> {
+    typedefs: &'a HashMap<String, ZeroCopyTypedef>,
+    transitive: bool,
+    u128_align: usize,
+    marked: Vec<String>,
+    layouts: HashMap<String, Layout>,
+    visiting: HashSet<String>,
+}
+
+/// Returns the full list of types to derive `Pod` for,
+/// which also includes the defined types nested in `zero_copy` types if `transitive`.
+///
+/// Errors with a message naming the offending type and field if
+/// a type contains a field that is not `Pod`, a defined type that is not `-z`
+/// (if not `transitive`), or would have implicit padding under `#[repr(C)]`.
+///
+/// Padding is checked with the SBF alignment of `u128`/`i128`.
+/// Warns if the layout only has no padding with that alignment,
+/// since the `Pod` derive then fails to compile on hosts where `u128` is 16-byte aligned.
+pub fn resolve_zero_copy(
+    typedefs: &HashMap<String, ZeroCopyTypedef>,
+    zero_copy: &[String],
+    transitive: bool,
+) -> Result<Vec<String>, String> {
+    let marked = resolve_with_u128_align(typedefs, zero_copy, transitive, SBF_U128_ALIGN)?;
+    if let Err(e) = resolve_with_u128_align(typedefs, zero_copy, transitive, HOST_U128_ALIGN) {
+        log::warn!(
+            "{} if u128 is 16-byte aligned. The layout is target-dependent: \
+            it is Pod on SBF but the Pod derive fails on x86_64 with rust >= 1.77 and on aarch64",
+            e
+        );
+    }
+    Ok(marked)
+}
+
+fn resolve_with_u128_align(
+    typedefs: &HashMap<String, ZeroCopyTypedef>,
+    zero_copy: &[String],
+    transitive: bool,
+    u128_align: usize,
+) -> Result<Vec<String>, String> {
+    let mut resolver = Resolver {
+        typedefs,
+        transitive,
+        u128_align,
+        marked: zero_copy.to_vec(),
+        layouts: HashMap::new(),
+        visiting: HashSet::new(),
+    };
+    let mut i = 0;
+    // resolver.marked grows as nested types are marked
+    while let Some(name) = resolver.marked.get(i).cloned() {
+        resolver.typedef_layout(&name)?;
+        i += 1;
+    }
+    Ok(resolver.marked)
+}
+
+impl Resolver<'_> {
+    fn typedef_layout(&mut self, name: &str) -> Result<Layout, String> {
+        if let Some(layout) = self.layouts.get(name) {
+            return Ok(*layout);
+        }
+        let (fields, repr) = match self.typedefs.get(name) {
+            Some(ZeroCopyTypedef::Struct { fields, repr }) => (fields, *repr),
+            Some(ZeroCopyTypedef::Invalid(reason)) => {
+                return Err(format!("-z type {} cannot be Pod: {}", name, reason))
+            }
+            None => return Err(format!("-z type {} not found in IDL", name)),
+        };
+        if !self.visiting.insert(name.to_owned()) {
+            return Err(format!("-z type {} contains itself", name));
+        }
+        let mut offset = 0;
+        let mut align = repr.align.unwrap_or(1);
+        for (field_name, ty) in fields {
+            let field = self
+                .field_layout(ty)
+                .map_err(|reason| format!("-z type {}.{}: {}", name, field_name, reason))?;
+            let field_align = if repr.packed { 1 } else { field.align };
+            if offset % field_align != 0 {
+                if !repr.allow_padding {
+                    return Err(format!(
+                        "-z type {}.{}: implicit padding before field at offset {} with alignment {}",
+                        name, field_name, offset, field_align
+                    ));
+                }
+                offset = offset.next_multiple_of(field_align);
+            }
+            offset += field.size;
+            align = align.max(field_align);
+        }
+        if offset % align != 0 {
+            if !repr.allow_padding {
+                return Err(format!(
+                    "-z type {}: implicit trailing padding after size {} with alignment {}",
+                    name, offset, align
+                ));
+            }
+            offset = offset.next_multiple_of(align);
+        }
+        self.visiting.remove(name);
+        let layout = Layout {
+            size: offset,
+            align,
+        };
+        self.layouts.insert(name.to_owned(), layout);
+        Ok(layout)
+    }
+
+    fn field_layout(&mut self, ty: &ZeroCopyType) -> Result<Layout, String> {
+        match ty {
+            ZeroCopyType::Scalar { size, align } => Ok(Layout {
+                size: *size,
+                align: *align,
+            }),
+            ZeroCopyType::Int128 => Ok(Layout {
+                size: 16,
+                align: self.u128_align,
+            }),
+            ZeroCopyType::Array(elem, n) => {
+                let elem = self.field_layout(elem)?;
+                Ok(Layout {
+                    size: elem.size * n,
+                    align: elem.align,
+                })
+            }
+            ZeroCopyType::Defined(name) => {
+                if !self.marked.iter().any(|m| m == name) {
+                    if !self.transitive {
+                        return Err(format!(
+                            "{} is not a -z type. Pass -z {} or --zero-copy-transitive",
+                            name, name
+                        ));
+                    }
+                    self.marked.push(name.clone());
+                }
+                self.typedef_layout(name)
+            }
+            ZeroCopyType::Invalid(reason) => Err(format!("{} is not Pod", reason)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typedefs() -> HashMap<String, ZeroCopyTypedef> {
+        [
+            (
+                "Outer",
+                ZeroCopyTypedef::c_struct(vec![
+                    ("a".into(), ZeroCopyType::primitive("u64")),
+                    ("inner".into(), ZeroCopyType::Defined("Inner".into())),
+                ]),
+            ),
+            (
+                "Inner",
+                ZeroCopyTypedef::c_struct(vec![
+                    ("b".into(), ZeroCopyType::primitive("u32")),
+                    ("c".into(), ZeroCopyType::primitive("u32")),
+                ]),
+            ),
+            (
+                "Padded",
+                ZeroCopyTypedef::c_struct(vec![
+                    ("b".into(), ZeroCopyType::primitive("u8")),
+                    ("c".into(), ZeroCopyType::primitive("u64")),
+                ]),
+            ),
+            (
+                "TrailingPadded",
+                ZeroCopyTypedef::c_struct(vec![
+                    ("c".into(), ZeroCopyType::primitive("u64")),
+                    (
+                        "b".into(),
+                        ZeroCopyType::Array(Box::new(ZeroCopyType::primitive("u8")), 3),
+                    ),
+                ]),
+            ),
+            (
+                "U64ThenU128",
+                ZeroCopyTypedef::c_struct(vec![
+                    ("a".into(), ZeroCopyType::primitive("u64")),
+                    ("b".into(), ZeroCopyType::primitive("u128")),
+                ]),
+            ),
+            (
+                "U128ThenU64Pair",
+                ZeroCopyTypedef::c_struct(vec![
+                    ("a".into(), ZeroCopyType::primitive("i128")),
+                    ("b".into(), ZeroCopyType::primitive("u64")),
+                    ("c".into(), ZeroCopyType::primitive("u64")),
+                ]),
+            ),
+            (
+                "HasBool",
+                ZeroCopyTypedef::c_struct(vec![("b".into(), ZeroCopyType::primitive("bool"))]),
+            ),
+            ("Enum", ZeroCopyTypedef::Invalid("enum".into())),
+            (
+                "Packed",
+                ZeroCopyTypedef::Struct {
+                    fields: vec![
+                        ("b".into(), ZeroCopyType::primitive("u8")),
+                        ("c".into(), ZeroCopyType::primitive("u64")),
+                    ],
+                    repr: ZeroCopyRepr {
+                        packed: true,
+                        ..Default::default()
+                    },
+                },
+            ),
+            (
+                "Unchecked",
+                ZeroCopyTypedef::Struct {
+                    fields: vec![
+                        ("b".into(), ZeroCopyType::primitive("u8")),
+                        ("c".into(), ZeroCopyType::primitive("u64")),
+                    ],
+                    repr: ZeroCopyRepr {
+                        allow_padding: true,
+                        ..Default::default()
+                    },
+                },
+            ),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_owned(), v))
+        .collect()
+    }
+
+    fn resolve(zero_copy: &[&str], transitive: bool) -> Result<Vec<String>, String> {
+        let zero_copy: Vec<String> = zero_copy.iter().map(|s| (*s).to_owned()).collect();
+        resolve_zero_copy(&typedefs(), &zero_copy, transitive)
+    }
+
+    #[test]
+    fn test_nested_marked_ok() {
+        assert_eq!(
+            resolve(&["Outer", "Inner"], false).unwrap(),
+            vec!["Outer", "Inner"]
+        );
+    }
+
+    #[test]
+    fn test_nested_unmarked_err() {
+        let err = resolve(&["Outer"], false).unwrap_err();
+        assert!(err.contains("Outer.inner"), "{}", err);
+    }
+
+    #[test]
+    fn test_nested_unmarked_transitive_ok() {
+        assert_eq!(resolve(&["Outer"], true).unwrap(), vec!["Outer", "Inner"]);
+    }
+
+    #[test]
+    fn test_padding_err() {
+        assert!(resolve(&["Padded"], false)
+            .unwrap_err()
+            .contains("Padded.c"));
+        assert!(resolve(&["TrailingPadded"], false)
+            .unwrap_err()
+            .contains("trailing padding"));
+    }
+
+    #[test]
+    fn test_u128_target_dependent_padding() {
+        let resolve_with = |name: &str, u128_align| {
+            resolve_with_u128_align(&typedefs(), &[name.to_owned()], false, u128_align)
+        };
+        assert!(resolve_with("U64ThenU128", SBF_U128_ALIGN).is_ok());
+        assert!(resolve_with("U64ThenU128", HOST_U128_ALIGN)
+            .unwrap_err()
+            .contains("U64ThenU128.b"));
+        // warns but still resolves
+        assert!(resolve(&["U64ThenU128"], false).is_ok());
+        assert!(resolve_with("U128ThenU64Pair", SBF_U128_ALIGN).is_ok());
+        assert!(resolve_with("U128ThenU64Pair", HOST_U128_ALIGN).is_ok());
+    }
+
+    #[test]
+    fn test_packed_and_unchecked_padding_ok() {
+        assert!(resolve(&["Packed"], false).is_ok());
+        assert!(resolve(&["Unchecked"], false).is_ok());
+    }
+
+    #[test]
+    fn test_not_pod_err() {
+        assert!(resolve(&["HasBool"], false).unwrap_err().contains("bool"));
+        assert!(resolve(&["Enum"], false).unwrap_err().contains("enum"));
+        assert!(resolve(&["Missing"], false).is_err());
+    }
+}
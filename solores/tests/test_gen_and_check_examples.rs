@@ -2,7 +2,7 @@
 
 use test_utils::{check_example, gen_example};
 
-const BASE_WORKSPACE_DEPS_ARGS: [&str; 14] = [
+const BASE_WORKSPACE_DEPS_ARGS: [&str; 26] = [
     "--solana-program-vers",
     "workspace=true",
     "--borsh-vers",
@@ -17,12 +17,31 @@ const BASE_WORKSPACE_DEPS_ARGS: [&str; 14] = [
     "workspace=true",
     "--bytemuck-vers",
     "workspace=true",
+    "--base64-vers",
+    "workspace=true",
+    "--schemars-vers",
+    "workspace=true",
+    "--solana-rpc-client-api-vers",
+    "workspace=true",
+    "--solana-rpc-client-vers",
+    "workspace=true",
+    "--solana-account-decoder-vers",
+    "workspace=true",
+    "--solana-sdk-vers",
+    "workspace=true",
 ];
 
 #[test]
 fn test_token_metadata() -> Result<(), Box<dyn std::error::Error>> {
     const EXAMPLE_PATH: &str = "shank/token_metadata";
-    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &["--serde-human-readable", "--schemars"],
+        ]
+        .concat(),
+    )?;
     check_example(EXAMPLE_PATH, "mpl_token_metadata_interface")
 }
 
@@ -56,7 +75,19 @@ fn test_phoenix_v1() -> Result<(), Box<dyn std::error::Error>> {
 #[test]
 fn test_unstake_it() -> Result<(), Box<dyn std::error::Error>> {
     const EXAMPLE_PATH: &str = "anchor/unstake_it";
-    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &[
+                "--anchor-framework-errors",
+                "--serde-human-readable",
+                "--serde-int-as-string",
+                "--schemars",
+            ],
+        ]
+        .concat(),
+    )?;
     check_example(EXAMPLE_PATH, "unstake_interface")
 }
 
@@ -102,6 +133,48 @@ fn test_anchor_ix_blank() -> Result<(), Box<dyn std::error::Error>> {
     check_example(EXAMPLE_PATH, "anchor_ix_blank_interface")
 }
 
+#[test]
+fn test_anchor_empty_accounts_events() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/empty_accounts_events";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor_empty_accounts_events_interface")
+}
+
+#[test]
+fn test_anchor_ix_optional_accounts() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/ix_optional_accounts";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor_ix_optional_accounts_interface")
+}
+
+#[test]
+fn test_anchor_pda() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/pda";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor_pda_interface")
+}
+
+#[test]
+fn test_anchor_zero_copy() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/zero_copy";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &["-z", "Market", "-z", "Fee"],
+        ]
+        .concat(),
+    )?;
+    check_example(EXAMPLE_PATH, "anchor_zero_copy_interface")
+}
+
+#[test]
+fn test_anchor_constants() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/constants";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor_constants_interface")
+}
+
 #[test]
 fn test_shank_ix_no_privilege() -> Result<(), Box<dyn std::error::Error>> {
     const EXAMPLE_PATH: &str = "shank/ix_no_privilege";
@@ -154,10 +227,108 @@ fn test_drift() -> Result<(), Box<dyn std::error::Error>> {
     check_example(EXAMPLE_PATH, "drift_interface")
 }
 
+#[test]
+fn test_anchor030_drift() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor030/drift";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &["--output-crate-name", "drift_030_interface"],
+        ]
+        .concat(),
+    )?;
+    check_example(EXAMPLE_PATH, "drift_030_interface")
+}
+
+#[test]
+fn test_anchor030_generics() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor030/generics";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &["--serde-human-readable", "--serde-bytes-encoding", "hex"],
+        ]
+        .concat(),
+    )?;
+    check_example(EXAMPLE_PATH, "generics_interface")
+}
+
+#[test]
+fn test_anchor030_ix_optional_accounts() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor030/ix_optional_accounts";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor030_ix_optional_accounts_interface")
+}
+
+#[test]
+fn test_anchor030_pda() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor030/pda";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &["--anchor-framework-errors"],
+        ]
+        .concat(),
+    )?;
+    check_example(EXAMPLE_PATH, "anchor030_pda_interface")
+}
+
+#[test]
+fn test_anchor030_zero_copy() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor030/zero_copy";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor030_zero_copy_interface")
+}
+
+#[test]
+fn test_anchor030_constants() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor030/constants";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor030_constants_interface")
+}
+
+#[test]
+fn test_codama_token() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "codama/token";
+    gen_example(
+        EXAMPLE_PATH,
+        &[BASE_WORKSPACE_DEPS_ARGS.as_ref(), &["--schemars"]].concat(),
+    )?;
+    check_example(EXAMPLE_PATH, "spl_token_interface")
+}
+
+#[test]
+fn test_codama_codecs() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "codama/codecs";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &["--serde-human-readable", "--serde-bytes-encoding", "hex"],
+        ]
+        .concat(),
+    )?;
+    check_example(EXAMPLE_PATH, "codecs_test_interface")
+}
+
 #[test]
 fn test_system_program() -> Result<(), Box<dyn std::error::Error>> {
     const EXAMPLE_PATH: &str = "bincode/system";
-    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &[
+                "--serde-human-readable",
+                "--serde-int-as-string",
+                "--schemars",
+            ],
+        ]
+        .concat(),
+    )?;
     check_example(EXAMPLE_PATH, "system_program_interface")
 }
 
@@ -27,15 +27,21 @@ pub fn gen_example(example_path: &str, args: &[&str]) -> Result<(), Box<dyn std:
     Ok(())
 }
 
-/// `cargo check` a generated interface crate
-/// to ensure valid rust code
+/// `rustfmt --check` and `cargo check` a generated interface crate
+/// to ensure valid, formatted rust code
 pub fn check_example(
     example_path: &str,
     gen_package_name: &str,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut generated_cargo_toml_path = example_dir(example_path);
-    generated_cargo_toml_path.push(gen_package_name);
-    generated_cargo_toml_path.push("Cargo.toml");
+    let mut generated_lib_path = example_dir(example_path);
+    generated_lib_path.extend(&[gen_package_name, "src", "lib.rs"]);
+    let mut rustfmt_check_cmd = Command::new("rustfmt");
+    rustfmt_check_cmd
+        .arg("--edition")
+        .arg("2021")
+        .arg("--check")
+        .arg(generated_lib_path);
+    rustfmt_check_cmd.assert().success();
     let mut cargo_check_cmd = Command::new("cargo");
     cargo_check_cmd
         .current_dir(example_dir(example_path))